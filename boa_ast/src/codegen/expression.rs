@@ -0,0 +1,813 @@
+//! Code generation for expressions.
+
+use super::Generator;
+use crate::{
+    expression::{
+        access::{PropertyAccess, PropertyAccessField},
+        literal::{ArrayLiteral, Literal, LiteralKind, ObjectLiteral, TemplateElement},
+        operator::{
+            assign::AssignTarget,
+            binary::{ArithmeticOp, BinaryOp, BitwiseOp, LogicalOp, RelationalOp},
+            unary::UnaryOp,
+            update::{UpdateOp, UpdateTarget},
+            Binary,
+        },
+        Expression, Optional, OptionalOperationKind, TaggedTemplate,
+    },
+    pattern::Pattern,
+    property::{PropertyDefinition, PropertyName},
+};
+use boa_interner::Sym;
+use std::fmt::Write;
+
+/// The precedence of an expression, from the loosest to the tightest binding.
+///
+/// An expression must be wrapped in parentheses if its precedence is lower than the precedence
+/// required by the position it appears in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(super) enum Precedence {
+    Comma,
+    Assignment,
+    Conditional,
+    Coalesce,
+    LogicalOr,
+    LogicalAnd,
+    BitwiseOr,
+    BitwiseXor,
+    BitwiseAnd,
+    Equality,
+    Relational,
+    Shift,
+    Additive,
+    Multiplicative,
+    Exponentiation,
+    Unary,
+    Update,
+    LeftHandSide,
+    Call,
+    Member,
+    Primary,
+}
+
+impl Precedence {
+    /// Gets the precedence of a binary operator.
+    const fn of_binary(op: BinaryOp) -> Self {
+        match op {
+            BinaryOp::Comma => Self::Comma,
+            BinaryOp::Logical(LogicalOp::Coalesce) => Self::Coalesce,
+            BinaryOp::Logical(LogicalOp::Or) => Self::LogicalOr,
+            BinaryOp::Logical(LogicalOp::And) => Self::LogicalAnd,
+            BinaryOp::Bitwise(BitwiseOp::Or) => Self::BitwiseOr,
+            BinaryOp::Bitwise(BitwiseOp::Xor) => Self::BitwiseXor,
+            BinaryOp::Bitwise(BitwiseOp::And) => Self::BitwiseAnd,
+            BinaryOp::Bitwise(BitwiseOp::Shl | BitwiseOp::Shr | BitwiseOp::UShr) => Self::Shift,
+            BinaryOp::Relational(
+                RelationalOp::Equal
+                | RelationalOp::NotEqual
+                | RelationalOp::StrictEqual
+                | RelationalOp::StrictNotEqual,
+            ) => Self::Equality,
+            BinaryOp::Relational(_) => Self::Relational,
+            BinaryOp::Arithmetic(ArithmeticOp::Add | ArithmeticOp::Sub) => Self::Additive,
+            BinaryOp::Arithmetic(ArithmeticOp::Mul | ArithmeticOp::Div | ArithmeticOp::Mod) => {
+                Self::Multiplicative
+            }
+            BinaryOp::Arithmetic(ArithmeticOp::Exp) => Self::Exponentiation,
+        }
+    }
+
+    /// Gets the precedence required by the right hand side of a left associative binary
+    /// operator with this precedence.
+    const fn next(self) -> Self {
+        match self {
+            Self::Comma => Self::Assignment,
+            Self::Assignment => Self::Conditional,
+            Self::Conditional => Self::Coalesce,
+            Self::Coalesce | Self::LogicalAnd => Self::BitwiseOr,
+            Self::LogicalOr => Self::LogicalAnd,
+            Self::BitwiseOr => Self::BitwiseXor,
+            Self::BitwiseXor => Self::BitwiseAnd,
+            Self::BitwiseAnd => Self::Equality,
+            Self::Equality => Self::Relational,
+            Self::Relational => Self::Shift,
+            Self::Shift => Self::Additive,
+            Self::Additive => Self::Multiplicative,
+            Self::Multiplicative => Self::Exponentiation,
+            Self::Exponentiation => Self::Unary,
+            Self::Unary => Self::Update,
+            Self::Update => Self::LeftHandSide,
+            Self::LeftHandSide => Self::Call,
+            Self::Call => Self::Member,
+            Self::Member | Self::Primary => Self::Primary,
+        }
+    }
+}
+
+/// Gets the precedence of an expression.
+pub(super) fn precedence(expr: &Expression) -> Precedence {
+    match expr {
+        Expression::Literal(lit) if is_negative(lit) => Precedence::Unary,
+        Expression::This(_)
+        | Expression::Identifier(..)
+        | Expression::Literal(_)
+        | Expression::ArrayLiteral(_)
+        | Expression::ObjectLiteral(_)
+        | Expression::Function(_)
+        | Expression::Generator(_)
+        | Expression::AsyncFunction(_)
+        | Expression::AsyncGenerator(_)
+        | Expression::Class(_)
+        | Expression::TemplateLiteral(_)
+        | Expression::Parenthesized(_)
+        | Expression::FormalParameterList(_) => Precedence::Primary,
+        Expression::New(_) | Expression::NewTarget(_) | Expression::ImportMeta(_) => {
+            Precedence::Member
+        }
+        Expression::PropertyAccess(access) => {
+            access_target(access).map_or(Precedence::Member, member_or_call)
+        }
+        Expression::TaggedTemplate(tag) => member_or_call(tag.tag()),
+        Expression::Call(_) | Expression::SuperCall(_) | Expression::ImportCall(_) => {
+            Precedence::Call
+        }
+        Expression::Optional(_) => Precedence::LeftHandSide,
+        Expression::Update(update) if is_prefix(update.op()) => Precedence::Unary,
+        Expression::Update(_) => Precedence::Update,
+        Expression::Unary(_) | Expression::Await(_) => Precedence::Unary,
+        Expression::Binary(binary) => Precedence::of_binary(binary.op()),
+        Expression::BinaryInPrivate(_) => Precedence::Relational,
+        Expression::Conditional(_) => Precedence::Conditional,
+        Expression::Spread(_)
+        | Expression::ArrowFunction(_)
+        | Expression::AsyncArrowFunction(_)
+        | Expression::Assign(_)
+        | Expression::Yield(_) => Precedence::Assignment,
+    }
+}
+
+/// Gets the precedence of a member access or tagged template with the given target, which is a
+/// `CallExpression` if the target is one.
+fn member_or_call(target: &Expression) -> Precedence {
+    if precedence(target) == Precedence::Call {
+        Precedence::Call
+    } else {
+        Precedence::Member
+    }
+}
+
+/// Gets the object of a property access, if it isn't a `super` property access.
+const fn access_target(access: &PropertyAccess) -> Option<&Expression> {
+    match access {
+        PropertyAccess::Simple(access) => Some(access.target()),
+        PropertyAccess::Private(access) => Some(access.target()),
+        PropertyAccess::Super(_) => None,
+    }
+}
+
+/// Gets the precedences required by the operands of a binary expression.
+fn operand_precedences(binary: &Binary) -> (Precedence, Precedence) {
+    let op = binary.op();
+    let precedence = Precedence::of_binary(op);
+    match op {
+        // The exponentiation operator is right associative, and its left hand side cannot be
+        // an unary expression.
+        BinaryOp::Arithmetic(ArithmeticOp::Exp) => (Precedence::Update, Precedence::Exponentiation),
+        // The nullish coalescing operator cannot be mixed with the other logical operators.
+        BinaryOp::Logical(LogicalOp::Coalesce) => {
+            let lhs = match binary.lhs() {
+                Expression::Binary(lhs) if lhs.op() == op => Precedence::Coalesce,
+                _ => Precedence::BitwiseOr,
+            };
+            (lhs, Precedence::BitwiseOr)
+        }
+        // The logical OR operator is associative, and the parser groups its operands from the
+        // right.
+        BinaryOp::Logical(LogicalOp::Or) => (Precedence::LogicalAnd, Precedence::LogicalOr),
+        _ => (precedence, precedence.next()),
+    }
+}
+
+/// Checks if the update operator is written before its target.
+const fn is_prefix(op: UpdateOp) -> bool {
+    matches!(op, UpdateOp::IncrementPre | UpdateOp::DecrementPre)
+}
+
+/// Checks if the literal is a negative number, which must be written as a unary minus.
+fn is_negative(lit: &Literal) -> bool {
+    match lit.kind() {
+        LiteralKind::Num(n) => n.is_sign_negative() && !n.is_nan(),
+        LiteralKind::Int(i) => *i < 0,
+        LiteralKind::BigInt(b) => b.sign() == num_bigint::Sign::Minus,
+        _ => false,
+    }
+}
+
+/// Checks if the literal is a number that can be written as a property name as is.
+fn is_numeric_property_name(lit: &Literal) -> bool {
+    matches!(
+        lit.kind(),
+        LiteralKind::Num(_) | LiteralKind::Int(_) | LiteralKind::BigInt(_)
+    ) && !is_negative(lit)
+        && !matches!(lit.kind(), LiteralKind::Num(n) if n.is_nan())
+}
+
+/// Checks if the expression is written as a sequence of decimal digits, which would absorb the
+/// dot of a following property access.
+fn is_bare_integer(expr: &Expression) -> bool {
+    match expr {
+        Expression::Literal(lit) => match lit.kind() {
+            LiteralKind::Int(i) => *i >= 0,
+            LiteralKind::Num(n) if n.is_finite() && !n.is_sign_negative() => {
+                format_number(*n).bytes().all(|b| b.is_ascii_digit())
+            }
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// The positions where some expressions cannot appear as the first token.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Start {
+    /// An expression statement, which cannot start with `{`, `function`, `async function`,
+    /// `class` or `let [`.
+    Statement,
+
+    /// The concise body of an arrow function, which cannot start with `{`.
+    ArrowBody,
+
+    /// An `export default` declaration, which cannot start with `function`, `async function` or
+    /// `class`.
+    ExportDefault,
+}
+
+/// Checks if the leftmost token of `expr` would make it ambiguous in the `start` position, in
+/// which case it must be wrapped in parentheses.
+pub(super) fn has_ambiguous_start(expr: &Expression, start: Start) -> bool {
+    match expr {
+        Expression::ObjectLiteral(_) => start != Start::ExportDefault,
+        Expression::Function(_)
+        | Expression::Generator(_)
+        | Expression::AsyncFunction(_)
+        | Expression::AsyncGenerator(_)
+        | Expression::Class(_) => start != Start::ArrowBody,
+        Expression::Assign(assign) => match assign.lhs() {
+            AssignTarget::Pattern(Pattern::Object(_)) => start != Start::ExportDefault,
+            AssignTarget::Access(access) => access_has_ambiguous_start(access, start),
+            _ => false,
+        },
+        Expression::PropertyAccess(access) => access_has_ambiguous_start(access, start),
+        Expression::Update(update) if !is_prefix(update.op()) => match update.target() {
+            UpdateTarget::PropertyAccess(access) => access_has_ambiguous_start(access, start),
+            UpdateTarget::Identifier(..) => false,
+        },
+        Expression::Binary(binary) => {
+            precedence(binary.lhs()) >= operand_precedences(binary).0
+                && has_ambiguous_start(binary.lhs(), start)
+        }
+        Expression::Conditional(cond) => {
+            precedence(cond.condition()) >= Precedence::Coalesce
+                && has_ambiguous_start(cond.condition(), start)
+        }
+        Expression::Call(call) => {
+            precedence(call.function()) >= Precedence::Call
+                && has_ambiguous_start(call.function(), start)
+        }
+        Expression::TaggedTemplate(tag) => {
+            precedence(tag.tag()) >= Precedence::Call && has_ambiguous_start(tag.tag(), start)
+        }
+        Expression::Optional(opt) => {
+            precedence(opt.target()) >= Precedence::Call && has_ambiguous_start(opt.target(), start)
+        }
+        _ => false,
+    }
+}
+
+/// Checks if the leftmost token of a property access would make it ambiguous in the `start`
+/// position.
+fn access_has_ambiguous_start(access: &PropertyAccess, start: Start) -> bool {
+    let target = match access {
+        PropertyAccess::Simple(access) => {
+            if start == Start::Statement
+                && matches!(access.field(), PropertyAccessField::Expr(_))
+                && matches!(access.target(), Expression::Identifier(ident, _) if ident.sym() == Sym::LET)
+            {
+                return true;
+            }
+            access.target()
+        }
+        PropertyAccess::Private(access) => access.target(),
+        PropertyAccess::Super(_) => return false,
+    };
+    precedence(target) >= Precedence::Call && has_ambiguous_start(target, start)
+}
+
+/// Formats a finite, non negative number using the algorithm of [`Number::toString`][spec].
+///
+/// Infinity is formatted as a literal that overflows to it, to keep it a numeric literal.
+///
+/// [spec]: https://tc39.es/ecma262/#sec-numeric-types-number-tostring
+pub(super) fn format_number(n: f64) -> String {
+    if n.is_infinite() {
+        return "1e999".to_owned();
+    }
+    if n == 0.0 {
+        return "0".to_owned();
+    }
+
+    // The exponential formatting of Rust gives the shortest digits that round trip, which are
+    // the digits required by the spec.
+    let exponential = format!("{n:e}");
+    let (mantissa, exponent) = exponential
+        .split_once('e')
+        .expect("exponential formatting must contain an exponent");
+    let digits = mantissa.replace('.', "");
+    let exponent: i32 = exponent.parse().expect("exponent must be an integer");
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+    let k = digits.len() as i32;
+    let n = exponent + 1;
+
+    #[allow(clippy::cast_sign_loss)]
+    if k <= n && n <= 21 {
+        format!("{digits}{}", "0".repeat((n - k) as usize))
+    } else if 0 < n && n <= 21 {
+        let (int, frac) = digits.split_at(n as usize);
+        format!("{int}.{frac}")
+    } else if -6 < n && n <= 0 {
+        format!("0.{}{digits}", "0".repeat((-n) as usize))
+    } else {
+        let (first, rest) = digits.split_at(1);
+        let sign = if n > 0 { '+' } else { '-' };
+        let exponent = (n - 1).abs();
+        if rest.is_empty() {
+            format!("{first}e{sign}{exponent}")
+        } else {
+            format!("{first}.{rest}e{sign}{exponent}")
+        }
+    }
+}
+
+impl Generator<'_> {
+    /// Writes an expression, wrapping it in parentheses if its precedence is lower than `min`.
+    pub(super) fn expression(&mut self, expr: &Expression, min: Precedence) {
+        let ambiguous_in = self.no_in
+            && match expr {
+                Expression::Binary(binary) => binary.op() == BinaryOp::Relational(RelationalOp::In),
+                Expression::BinaryInPrivate(_) => true,
+                _ => false,
+            };
+
+        if ambiguous_in || precedence(expr) < min {
+            self.parenthesized(expr);
+        } else {
+            self.expression_inner(expr);
+        }
+    }
+
+    /// Writes an expression in a position where its leftmost token could be ambiguous.
+    pub(super) fn expression_at(&mut self, expr: &Expression, min: Precedence, start: Start) {
+        if has_ambiguous_start(expr, start) {
+            self.parenthesized(expr);
+        } else {
+            self.expression(expr, min);
+        }
+    }
+
+    /// Writes an expression wrapped in parentheses.
+    pub(super) fn parenthesized(&mut self, expr: &Expression) {
+        self.punct("(");
+        self.nested(|gen| gen.expression(expr, Precedence::Comma));
+        self.punct(")");
+    }
+
+    fn expression_inner(&mut self, expr: &Expression) {
+        match expr {
+            Expression::This(_) => self.word("this"),
+            Expression::Identifier(ident, _) => self.sym(ident.sym()),
+            Expression::Literal(lit) => self.literal(lit),
+            Expression::ArrayLiteral(array) => self.array_literal(array),
+            Expression::ObjectLiteral(object) => self.object_literal(object),
+            Expression::Spread(spread) => {
+                self.punct("...");
+                self.expression(spread.target(), Precedence::Assignment);
+            }
+            Expression::Function(f) => self.function(f, f.has_binding_identifier()),
+            Expression::Generator(f) => self.generator(f, f.has_binding_identifier()),
+            Expression::AsyncFunction(f) => self.async_function(f, f.has_binding_identifier()),
+            Expression::AsyncGenerator(f) => self.async_generator(f, f.has_binding_identifier()),
+            Expression::ArrowFunction(f) => {
+                self.arrow_function(false, f.parameters(), f.body());
+            }
+            Expression::AsyncArrowFunction(f) => {
+                self.arrow_function(true, f.parameters(), f.body());
+            }
+            Expression::Class(class) => self.class(class, class.has_binding_identifier()),
+            Expression::TemplateLiteral(template) => {
+                self.punct("`");
+                for element in template.elements() {
+                    match element {
+                        TemplateElement::String(sym) => self.template_string(*sym),
+                        TemplateElement::Expr(expr) => self.template_substitution(expr),
+                    }
+                }
+                self.buf.push('`');
+            }
+            Expression::PropertyAccess(access) => self.property_access(access),
+            Expression::New(new) => {
+                self.word("new");
+                self.space();
+                self.expression(new.constructor(), Precedence::Member);
+                self.arguments(new.arguments());
+            }
+            Expression::Call(call) => {
+                self.expression(call.function(), Precedence::Call);
+                self.arguments(call.args());
+            }
+            Expression::SuperCall(call) => {
+                self.word("super");
+                self.arguments(call.arguments());
+            }
+            Expression::ImportCall(call) => {
+                self.word("import");
+                self.punct("(");
+                self.nested(|gen| {
+                    gen.expression(call.argument(), Precedence::Assignment);
+                    if let Some(options) = call.options() {
+                        gen.comma();
+                        gen.expression(options, Precedence::Assignment);
+                    }
+                });
+                self.punct(")");
+            }
+            Expression::Optional(opt) => self.optional(opt),
+            Expression::TaggedTemplate(tag) => self.tagged_template(tag),
+            Expression::NewTarget(_) => {
+                self.word("new");
+                self.punct(".");
+                self.word("target");
+            }
+            Expression::ImportMeta(_) => {
+                self.word("import");
+                self.punct(".");
+                self.word("meta");
+            }
+            Expression::Assign(assign) => {
+                match assign.lhs() {
+                    AssignTarget::Identifier(ident, _) => self.sym(ident.sym()),
+                    AssignTarget::Access(access) => self.property_access(access),
+                    AssignTarget::Pattern(pattern) => self.pattern(pattern),
+                }
+                self.operator(&assign.op().to_string());
+                self.expression(assign.rhs(), Precedence::Assignment);
+            }
+            Expression::Unary(unary) => {
+                match unary.op() {
+                    op @ (UnaryOp::TypeOf | UnaryOp::Delete | UnaryOp::Void) => {
+                        self.word(&op.to_string());
+                        self.space();
+                    }
+                    op => self.punct(&op.to_string()),
+                }
+                self.expression(unary.target(), Precedence::Unary);
+            }
+            Expression::Update(update) => {
+                let op = update.op().to_string();
+                if is_prefix(update.op()) {
+                    self.punct(&op);
+                }
+                match update.target() {
+                    UpdateTarget::Identifier(ident, _) => self.sym(ident.sym()),
+                    UpdateTarget::PropertyAccess(access) => self.property_access(access),
+                }
+                if !is_prefix(update.op()) {
+                    self.punct(&op);
+                }
+            }
+            Expression::Binary(binary) => self.binary(binary),
+            Expression::BinaryInPrivate(binary) => {
+                self.punct("#");
+                self.sym(binary.lhs().description());
+                self.space();
+                self.word("in");
+                self.space();
+                self.expression(binary.rhs(), Precedence::Shift);
+            }
+            Expression::Conditional(cond) => {
+                self.expression(cond.condition(), Precedence::Coalesce);
+                self.operator("?");
+                self.nested(|gen| gen.expression(cond.if_true(), Precedence::Assignment));
+                self.operator(":");
+                self.expression(cond.if_false(), Precedence::Assignment);
+            }
+            Expression::Await(expr) => {
+                self.word("await");
+                self.space();
+                self.expression(expr.target(), Precedence::Unary);
+            }
+            Expression::Yield(expr) => {
+                self.word("yield");
+                if expr.delegate() {
+                    self.punct("*");
+                }
+                if let Some(target) = expr.target() {
+                    self.space();
+                    self.expression(target, Precedence::Assignment);
+                }
+            }
+            Expression::Parenthesized(expr) => self.parenthesized(expr.expression()),
+            Expression::FormalParameterList(params) => self.formal_parameters(params),
+        }
+    }
+
+    fn binary(&mut self, binary: &Binary) {
+        let op = binary.op();
+        let (lhs, rhs) = operand_precedences(binary);
+
+        self.expression(binary.lhs(), lhs);
+        match op {
+            BinaryOp::Comma => self.comma(),
+            BinaryOp::Relational(RelationalOp::In | RelationalOp::InstanceOf) => {
+                self.space();
+                self.word(&op.to_string());
+                self.space();
+            }
+            _ => self.operator(&op.to_string()),
+        }
+        self.expression(binary.rhs(), rhs);
+    }
+
+    fn literal(&mut self, lit: &Literal) {
+        match lit.kind() {
+            LiteralKind::String(sym) => self.string_literal(*sym),
+            LiteralKind::Num(n) if n.is_nan() => self.word("NaN"),
+            LiteralKind::Num(n) => {
+                if n.is_sign_negative() {
+                    self.punct("-");
+                }
+                self.word(&format_number(n.abs()));
+            }
+            LiteralKind::Int(i) => {
+                if *i < 0 {
+                    self.punct("-");
+                }
+                self.word(&i.unsigned_abs().to_string());
+            }
+            LiteralKind::BigInt(b) => {
+                if b.sign() == num_bigint::Sign::Minus {
+                    self.punct("-");
+                }
+                self.word(&format!("{}n", b.magnitude()));
+            }
+            LiteralKind::Bool(true) => self.word("true"),
+            LiteralKind::Bool(false) => self.word("false"),
+            LiteralKind::Null => self.word("null"),
+            LiteralKind::Undefined => self.word("undefined"),
+        }
+    }
+
+    fn array_literal(&mut self, array: &ArrayLiteral) {
+        let elements = array.as_ref();
+        self.punct("[");
+        self.nested(|gen| {
+            for (i, element) in elements.iter().enumerate() {
+                if i > 0 {
+                    gen.comma();
+                }
+                if let Some(element) = element {
+                    gen.expression(element, Precedence::Assignment);
+                }
+            }
+        });
+
+        // A trailing elision needs an extra comma, since the last comma of an array literal
+        // doesn't create an element.
+        match elements.last() {
+            Some(None) => self.punct(","),
+            Some(Some(Expression::Spread(_))) if array.has_trailing_comma_spread() => {
+                self.punct(",");
+            }
+            _ => {}
+        }
+        self.punct("]");
+    }
+
+    fn object_literal(&mut self, object: &ObjectLiteral) {
+        let properties = object.properties();
+        self.punct("{");
+        if properties.is_empty() {
+            self.punct("}");
+            return;
+        }
+
+        // Objects containing methods or functions are written in multiple lines, like blocks.
+        let multiline = properties.iter().any(|p| match p {
+            PropertyDefinition::MethodDefinition(_, _) => true,
+            PropertyDefinition::Property(_, value) => matches!(
+                value,
+                Expression::Function(_)
+                    | Expression::Generator(_)
+                    | Expression::AsyncFunction(_)
+                    | Expression::AsyncGenerator(_)
+                    | Expression::Class(_)
+            ),
+            _ => false,
+        });
+
+        self.nested(|gen| {
+            if multiline {
+                gen.newline();
+                gen.depth += 1;
+                for (i, property) in properties.iter().enumerate() {
+                    gen.indent();
+                    gen.property_definition(property);
+                    if i + 1 < properties.len() {
+                        gen.punct(",");
+                    }
+                    gen.newline();
+                }
+                gen.depth -= 1;
+                gen.indent();
+            } else {
+                gen.space();
+                for (i, property) in properties.iter().enumerate() {
+                    if i > 0 {
+                        gen.comma();
+                    }
+                    gen.property_definition(property);
+                }
+                gen.space();
+            }
+        });
+        self.punct("}");
+    }
+
+    fn property_definition(&mut self, property: &PropertyDefinition) {
+        match property {
+            PropertyDefinition::IdentifierReference(ident) => self.sym(ident.sym()),
+            PropertyDefinition::Property(name, value) => {
+                self.property_name(name);
+                self.punct(":");
+                self.space();
+                self.expression(value, Precedence::Assignment);
+            }
+            PropertyDefinition::MethodDefinition(name, method) => {
+                self.method_definition(&super::function::MethodName::Property(name), method);
+            }
+            PropertyDefinition::SpreadObject(expr) => {
+                self.punct("...");
+                self.expression(expr, Precedence::Assignment);
+            }
+            PropertyDefinition::CoverInitializedName(ident, init) => {
+                self.sym(ident.sym());
+                self.operator("=");
+                self.expression(init, Precedence::Assignment);
+            }
+        }
+    }
+
+    /// Writes the name of a property, which can be an identifier name, a string or numeric
+    /// literal, or a computed property name.
+    pub(super) fn property_name(&mut self, name: &PropertyName) {
+        match name {
+            PropertyName::Literal(sym) => self.name_or_string(*sym),
+            PropertyName::Computed(Expression::Literal(lit)) if is_numeric_property_name(lit) => {
+                self.literal(lit);
+            }
+            PropertyName::Computed(expr) => {
+                self.punct("[");
+                self.nested(|gen| gen.expression(expr, Precedence::Assignment));
+                self.punct("]");
+            }
+        }
+    }
+
+    /// Writes the cooked value of a template string, escaping the characters that have a
+    /// special meaning inside template literals.
+    fn template_string(&mut self, sym: Sym) {
+        let chars = char::decode_utf16(self.interner.resolve_expect(sym).utf16().iter().copied())
+            .collect::<Vec<_>>();
+        for (i, c) in chars.iter().enumerate() {
+            match c {
+                Ok('`') => self.buf.push_str("\\`"),
+                Ok('\\') => self.buf.push_str("\\\\"),
+                Ok('$') if chars.get(i + 1) == Some(&Ok('{')) => self.buf.push_str("\\$"),
+                Ok('\r') => self.buf.push_str("\\r"),
+                Ok(c) if c.is_control() && !matches!(c, '\n' | '\t') => {
+                    let _ = write!(self.buf, "\\u{:04X}", u32::from(*c));
+                }
+                Ok(c) => self.buf.push(*c),
+                Err(e) => {
+                    let _ = write!(self.buf, "\\u{:04X}", e.unpaired_surrogate());
+                }
+            }
+        }
+    }
+
+    fn template_substitution(&mut self, expr: &Expression) {
+        self.buf.push_str("${");
+        self.nested(|gen| gen.expression(expr, Precedence::Comma));
+        self.buf.push('}');
+    }
+
+    fn tagged_template(&mut self, tag: &TaggedTemplate) {
+        self.expression(tag.tag(), Precedence::Call);
+        self.buf.push('`');
+        let interner = self.interner;
+        for (i, raw) in tag.raws().iter().enumerate() {
+            self.buf
+                .push_str(&interner.resolve_expect(*raw).to_string());
+            if let Some(expr) = tag.exprs().get(i) {
+                self.template_substitution(expr);
+            }
+        }
+        self.buf.push('`');
+    }
+
+    pub(super) fn property_access(&mut self, access: &PropertyAccess) {
+        match access {
+            PropertyAccess::Simple(access) => {
+                self.access_target(access.target());
+                self.access_field(access.field());
+            }
+            PropertyAccess::Private(access) => {
+                self.access_target(access.target());
+                self.punct(".#");
+                self.sym(access.field().description());
+            }
+            PropertyAccess::Super(access) => {
+                self.word("super");
+                self.access_field(access.field());
+            }
+        }
+    }
+
+    fn access_target(&mut self, target: &Expression) {
+        self.expression(target, Precedence::Call);
+        if is_bare_integer(target) {
+            self.buf.push(' ');
+        }
+    }
+
+    fn access_field(&mut self, field: &PropertyAccessField) {
+        match field {
+            PropertyAccessField::Const(sym) => {
+                self.punct(".");
+                self.sym(*sym);
+            }
+            PropertyAccessField::Expr(expr) => {
+                self.punct("[");
+                self.nested(|gen| gen.expression(expr, Precedence::Comma));
+                self.punct("]");
+            }
+        }
+    }
+
+    fn optional(&mut self, opt: &Optional) {
+        self.access_target(opt.target());
+        for op in opt.chain() {
+            if op.shorted() {
+                self.punct("?.");
+            }
+            match op.kind() {
+                OptionalOperationKind::SimplePropertyAccess {
+                    field: PropertyAccessField::Const(sym),
+                } => {
+                    if !op.shorted() {
+                        self.punct(".");
+                    }
+                    self.sym(*sym);
+                }
+                OptionalOperationKind::SimplePropertyAccess {
+                    field: PropertyAccessField::Expr(expr),
+                } => {
+                    self.punct("[");
+                    self.nested(|gen| gen.expression(expr, Precedence::Comma));
+                    self.punct("]");
+                }
+                OptionalOperationKind::PrivatePropertyAccess { field } => {
+                    if !op.shorted() {
+                        self.punct(".");
+                    }
+                    self.punct("#");
+                    self.sym(field.description());
+                }
+                OptionalOperationKind::Call { args } => self.arguments(args),
+            }
+        }
+    }
+
+    /// Writes the arguments of a call, including the parentheses.
+    pub(super) fn arguments(&mut self, args: &[Expression]) {
+        self.punct("(");
+        self.nested(|gen| {
+            for (i, arg) in args.iter().enumerate() {
+                if i > 0 {
+                    gen.comma();
+                }
+                gen.expression(arg, Precedence::Assignment);
+            }
+        });
+        self.punct(")");
+    }
+}
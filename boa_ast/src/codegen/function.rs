@@ -0,0 +1,332 @@
+//! Code generation for functions and classes.
+
+use super::{
+    expression::{has_ambiguous_start, Precedence, Start},
+    Generator,
+};
+use crate::{
+    expression::access::{PropertyAccess, PropertyAccessField},
+    function::{
+        AsyncFunction, AsyncGenerator, Class, ClassElement, Decorator, FormalParameterList,
+        Function, FunctionBody, Generator as GeneratorFunction, PrivateName,
+    },
+    property::{MethodDefinition, PropertyName},
+    statement::Statement,
+    Expression, StatementListItem,
+};
+use boa_interner::Sym;
+
+/// The name of a method, which can be a property name or a private name.
+#[derive(Debug, Clone, Copy)]
+pub(super) enum MethodName<'a> {
+    Property(&'a PropertyName),
+    Private(PrivateName),
+}
+
+impl Generator<'_> {
+    /// Writes the name of a function or class, if it has one that has to be written.
+    ///
+    /// The anonymous declarations of `export default` are named `default` by the parser, but
+    /// that name cannot be written back.
+    fn binding_name(&mut self, name: Option<crate::expression::Identifier>, has_name: bool) {
+        if let Some(name) = name {
+            if has_name && name.sym() != Sym::DEFAULT {
+                self.space();
+                self.sym(name.sym());
+            }
+        }
+    }
+
+    /// Writes a function, writing its name only if `has_name` is `true`.
+    pub(super) fn function(&mut self, f: &Function, has_name: bool) {
+        self.word("function");
+        self.binding_name(f.name(), has_name);
+        self.function_tail(f.parameters(), f.body());
+    }
+
+    /// Writes a generator, writing its name only if `has_name` is `true`.
+    pub(super) fn generator(&mut self, f: &GeneratorFunction, has_name: bool) {
+        self.word("function");
+        self.punct("*");
+        self.binding_name(f.name(), has_name);
+        self.function_tail(f.parameters(), f.body());
+    }
+
+    /// Writes an async function, writing its name only if `has_name` is `true`.
+    pub(super) fn async_function(&mut self, f: &AsyncFunction, has_name: bool) {
+        self.word("async");
+        self.space();
+        self.word("function");
+        self.binding_name(f.name(), has_name);
+        self.function_tail(f.parameters(), f.body());
+    }
+
+    /// Writes an async generator, writing its name only if `has_name` is `true`.
+    pub(super) fn async_generator(&mut self, f: &AsyncGenerator, has_name: bool) {
+        self.word("async");
+        self.space();
+        self.word("function");
+        self.punct("*");
+        self.binding_name(f.name(), has_name);
+        self.function_tail(f.parameters(), f.body());
+    }
+
+    /// Writes the parameters and the body of a function.
+    fn function_tail(&mut self, params: &FormalParameterList, body: &FunctionBody) {
+        self.formal_parameters(params);
+        self.space();
+        self.function_body(body);
+    }
+
+    pub(super) fn arrow_function(
+        &mut self,
+        is_async: bool,
+        params: &FormalParameterList,
+        body: &FunctionBody,
+    ) {
+        if is_async {
+            self.word("async");
+            self.space();
+        }
+        self.formal_parameters(params);
+        self.operator("=>");
+
+        // Concise bodies are parsed as a single return statement in a non strict body. Bodies
+        // that would need parentheses are written as blocks, since the parentheses would be
+        // parsed back as part of the expression.
+        if let [StatementListItem::Statement(Statement::Return(ret))] =
+            body.statements().statements()
+        {
+            match ret.target() {
+                Some(expr) if !body.strict() && !has_ambiguous_start(expr, Start::ArrowBody) => {
+                    self.expression(expr, Precedence::Assignment);
+                    return;
+                }
+                _ => {}
+            }
+        }
+        self.nested(|gen| gen.function_body(body));
+    }
+
+    /// Writes a list of formal parameters, including the parentheses.
+    pub(super) fn formal_parameters(&mut self, params: &FormalParameterList) {
+        self.punct("(");
+        self.nested(|gen| {
+            for (i, param) in params.as_ref().iter().enumerate() {
+                if i > 0 {
+                    gen.comma();
+                }
+                if param.is_rest_param() {
+                    gen.punct("...");
+                }
+                gen.binding(param.variable().binding());
+                if let Some(init) = param.init() {
+                    gen.operator("=");
+                    gen.expression(init, Precedence::Assignment);
+                }
+            }
+        });
+        self.punct(")");
+    }
+
+    /// Writes the body of a function, including the braces.
+    pub(super) fn function_body(&mut self, body: &FunctionBody) {
+        self.nested(|gen| gen.block(body.statements()));
+    }
+
+    /// Writes a method of an object literal or a class.
+    pub(super) fn method_definition(&mut self, name: &MethodName<'_>, method: &MethodDefinition) {
+        let (params, body) = match method {
+            MethodDefinition::Get(f) => {
+                self.word("get");
+                self.space();
+                (f.parameters(), f.body())
+            }
+            MethodDefinition::Set(f) => {
+                self.word("set");
+                self.space();
+                (f.parameters(), f.body())
+            }
+            MethodDefinition::Ordinary(f) => (f.parameters(), f.body()),
+            MethodDefinition::Generator(f) => {
+                self.punct("*");
+                (f.parameters(), f.body())
+            }
+            MethodDefinition::AsyncGenerator(f) => {
+                self.word("async");
+                self.space();
+                self.punct("*");
+                (f.parameters(), f.body())
+            }
+            MethodDefinition::Async(f) => {
+                self.word("async");
+                self.space();
+                (f.parameters(), f.body())
+            }
+        };
+        self.method_name(name);
+        self.function_tail(params, body);
+    }
+
+    fn method_name(&mut self, name: &MethodName<'_>) {
+        match name {
+            MethodName::Property(name) => self.property_name(name),
+            MethodName::Private(name) => {
+                self.punct("#");
+                self.sym(name.description());
+            }
+        }
+    }
+
+    /// Writes a class, writing its name only if `has_name` is `true`.
+    pub(super) fn class(&mut self, class: &Class, has_name: bool) {
+        for decorator in class.decorators() {
+            self.decorator(decorator);
+            self.space();
+        }
+        self.word("class");
+        self.binding_name(class.name(), has_name);
+        if let Some(super_ref) = class.super_ref() {
+            self.space();
+            self.word("extends");
+            self.space();
+            self.expression(super_ref, Precedence::LeftHandSide);
+        }
+        self.space();
+        self.punct("{");
+        if class.constructor().is_none() && class.elements().is_empty() {
+            self.punct("}");
+            return;
+        }
+
+        self.nested(|gen| {
+            gen.newline();
+            gen.depth += 1;
+            if let Some(constructor) = class.constructor() {
+                gen.indent();
+                gen.word("constructor");
+                gen.function_tail(constructor.parameters(), constructor.body());
+                gen.newline();
+            }
+            for element in class.elements() {
+                gen.indent();
+                gen.class_element(element);
+                gen.newline();
+            }
+            gen.depth -= 1;
+            gen.indent();
+        });
+        self.punct("}");
+    }
+
+    fn decorator(&mut self, decorator: &Decorator) {
+        self.punct("@");
+        let expression = decorator.expression();
+        let is_call = matches!(expression, Expression::Call(call)
+            if is_decorator_member_expression(call.function()));
+        if is_call || is_decorator_member_expression(expression) {
+            self.expression(expression, Precedence::LeftHandSide);
+        } else {
+            self.parenthesized(expression);
+        }
+    }
+
+    fn class_element(&mut self, element: &ClassElement) {
+        match element {
+            ClassElement::MethodDefinition(name, method) => {
+                self.method_definition(&MethodName::Property(name), method);
+            }
+            ClassElement::StaticMethodDefinition(name, method) => {
+                self.word("static");
+                self.space();
+                self.method_definition(&MethodName::Property(name), method);
+            }
+            ClassElement::FieldDefinition(name, init) => {
+                self.field_definition(&MethodName::Property(name), init.as_ref());
+            }
+            ClassElement::StaticFieldDefinition(name, init) => {
+                self.word("static");
+                self.space();
+                self.field_definition(&MethodName::Property(name), init.as_ref());
+            }
+            ClassElement::PrivateMethodDefinition(name, method) => {
+                self.method_definition(&MethodName::Private(*name), method);
+            }
+            ClassElement::PrivateStaticMethodDefinition(name, method) => {
+                self.word("static");
+                self.space();
+                self.method_definition(&MethodName::Private(*name), method);
+            }
+            ClassElement::PrivateFieldDefinition(name, init) => {
+                self.field_definition(&MethodName::Private(*name), init.as_ref());
+            }
+            ClassElement::PrivateStaticFieldDefinition(name, init) => {
+                self.word("static");
+                self.space();
+                self.field_definition(&MethodName::Private(*name), init.as_ref());
+            }
+            ClassElement::AutoAccessorDefinition(name, init) => {
+                self.word("accessor");
+                self.space();
+                self.field_definition(&MethodName::Property(name), init.as_ref());
+            }
+            ClassElement::StaticAutoAccessorDefinition(name, init) => {
+                self.word("static");
+                self.space();
+                self.word("accessor");
+                self.space();
+                self.field_definition(&MethodName::Property(name), init.as_ref());
+            }
+            ClassElement::PrivateAutoAccessorDefinition(name, init) => {
+                self.word("accessor");
+                self.space();
+                self.field_definition(&MethodName::Private(*name), init.as_ref());
+            }
+            ClassElement::PrivateStaticAutoAccessorDefinition(name, init) => {
+                self.word("static");
+                self.space();
+                self.word("accessor");
+                self.space();
+                self.field_definition(&MethodName::Private(*name), init.as_ref());
+            }
+            ClassElement::StaticBlock(body) => {
+                self.word("static");
+                self.space();
+                self.function_body(body);
+            }
+            ClassElement::Decorated(decorators, element) => {
+                for decorator in decorators.iter() {
+                    self.decorator(decorator);
+                    self.newline();
+                    self.indent();
+                }
+                self.class_element(element);
+            }
+        }
+    }
+
+    fn field_definition(&mut self, name: &MethodName<'_>, init: Option<&Expression>) {
+        self.method_name(name);
+        if let Some(init) = init {
+            self.operator("=");
+            self.expression(init, Precedence::Assignment);
+        }
+        self.punct(";");
+    }
+}
+
+/// Returns `true` if `expression` can be written after `@` without parentheses, which is the
+/// case for identifiers and non-computed property accesses on them.
+fn is_decorator_member_expression(expression: &Expression) -> bool {
+    match expression {
+        Expression::Identifier(..) => true,
+        Expression::PropertyAccess(PropertyAccess::Simple(access)) => {
+            matches!(access.field(), PropertyAccessField::Const(_))
+                && is_decorator_member_expression(access.target())
+        }
+        Expression::PropertyAccess(PropertyAccess::Private(access)) => {
+            is_decorator_member_expression(access.target())
+        }
+        _ => false,
+    }
+}
@@ -0,0 +1,358 @@
+//! Generation of ECMAScript source code from the AST.
+//!
+//! Unlike the [`ToInternedString`][boa_interner::ToInternedString] representation of the nodes,
+//! which is mainly meant for debugging, the code emitted by this module is valid ECMAScript that
+//! can be parsed again. Parsing the generated code of a node returned by the parser gives back an
+//! equivalent node, which means that transformations made over the AST (e.g. using a
+//! [`VisitorMut`][crate::visitor::VisitorMut]) can be written back to disk.
+//!
+//! Parentheses are emitted wherever the AST contains a [`Parenthesized`] expression, and
+//! additionally wherever they are needed to preserve the structure of the AST, for example when a
+//! transformation places an expression with a lower precedence inside one with a higher precedence.
+//!
+//! Comments are not stored in the AST, so they cannot be reproduced.
+//!
+//! [`Parenthesized`]: crate::expression::Parenthesized
+
+mod expression;
+mod function;
+mod statement;
+
+use crate::{
+    declaration::Declaration, expression::Expression, statement::Statement, Module, ModuleItem,
+    Script, StatementList, StatementListItem,
+};
+use boa_interner::{Interner, Sym};
+use std::fmt::Write;
+
+/// The indentation used by the code generator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Indent {
+    /// Indent every level with the given number of spaces.
+    Spaces(u8),
+
+    /// Indent every level with a single tab character.
+    Tabs,
+}
+
+/// Configuration of the code generator.
+///
+/// By default, the generated code is indented with four spaces, like the rest of Boa's output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Config {
+    indent: Indent,
+    minify: bool,
+}
+
+impl Config {
+    /// Creates the default configuration, which pretty prints the code using four spaces of
+    /// indentation.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            indent: Indent::Spaces(4),
+            minify: false,
+        }
+    }
+
+    /// Creates a configuration that emits the code without any optional whitespace.
+    #[inline]
+    #[must_use]
+    pub const fn minified() -> Self {
+        Self {
+            indent: Indent::Spaces(0),
+            minify: true,
+        }
+    }
+
+    /// Sets the indentation used for every nesting level.
+    #[inline]
+    #[must_use]
+    pub const fn with_indent(mut self, indent: Indent) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    /// Sets if the code should be emitted without any optional whitespace.
+    #[inline]
+    #[must_use]
+    pub const fn with_minify(mut self, minify: bool) -> Self {
+        self.minify = minify;
+        self
+    }
+
+    /// Gets the indentation used for every nesting level.
+    #[inline]
+    #[must_use]
+    pub const fn indent(&self) -> Indent {
+        self.indent
+    }
+
+    /// Returns `true` if the code is emitted without any optional whitespace.
+    #[inline]
+    #[must_use]
+    pub const fn minify(&self) -> bool {
+        self.minify
+    }
+}
+
+impl Default for Config {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// This trait is implemented by the AST nodes that can be converted back to ECMAScript source
+/// code.
+///
+/// See the [module level documentation][self] for more information.
+pub trait ToSource {
+    /// Generates the source code of this node, using the given configuration.
+    fn to_source(&self, interner: &Interner, config: Config) -> String;
+}
+
+impl ToSource for Script {
+    fn to_source(&self, interner: &Interner, config: Config) -> String {
+        let mut gen = Generator::new(interner, config);
+
+        // The strictness of a script is usually given by its directive prologue, but a script
+        // can also be parsed as strict code. Keep the semantics of the script in the latter case.
+        if self.strict() && !gen.has_use_strict(self.statements()) {
+            gen.punct("\"use strict\"");
+            gen.punct(";");
+            gen.newline();
+        }
+        gen.statement_list(self.statements());
+        gen.finish()
+    }
+}
+
+impl ToSource for Module {
+    fn to_source(&self, interner: &Interner, config: Config) -> String {
+        let mut gen = Generator::new(interner, config);
+        for item in self.items().items() {
+            gen.indent();
+            gen.module_item(item);
+            gen.newline();
+        }
+        gen.finish()
+    }
+}
+
+impl ToSource for ModuleItem {
+    fn to_source(&self, interner: &Interner, config: Config) -> String {
+        let mut gen = Generator::new(interner, config);
+        gen.module_item(self);
+        gen.finish()
+    }
+}
+
+impl ToSource for StatementList {
+    fn to_source(&self, interner: &Interner, config: Config) -> String {
+        let mut gen = Generator::new(interner, config);
+        gen.statement_list(self);
+        gen.finish()
+    }
+}
+
+impl ToSource for StatementListItem {
+    fn to_source(&self, interner: &Interner, config: Config) -> String {
+        let mut gen = Generator::new(interner, config);
+        gen.statement_list_item(self);
+        gen.finish()
+    }
+}
+
+impl ToSource for Statement {
+    fn to_source(&self, interner: &Interner, config: Config) -> String {
+        let mut gen = Generator::new(interner, config);
+        gen.statement(self);
+        gen.finish()
+    }
+}
+
+impl ToSource for Declaration {
+    fn to_source(&self, interner: &Interner, config: Config) -> String {
+        let mut gen = Generator::new(interner, config);
+        gen.declaration(self);
+        gen.finish()
+    }
+}
+
+impl ToSource for Expression {
+    fn to_source(&self, interner: &Interner, config: Config) -> String {
+        let mut gen = Generator::new(interner, config);
+        gen.expression(self, expression::Precedence::Comma);
+        gen.finish()
+    }
+}
+
+/// The state of the code generator.
+#[derive(Debug)]
+struct Generator<'a> {
+    interner: &'a Interner,
+    config: Config,
+    buf: String,
+    depth: usize,
+
+    /// Whether an `in` operator would be ambiguous in the current position, which happens in the
+    /// initializer of a `for` loop.
+    no_in: bool,
+}
+
+impl<'a> Generator<'a> {
+    const fn new(interner: &'a Interner, config: Config) -> Self {
+        Self {
+            interner,
+            config,
+            buf: String::new(),
+            depth: 0,
+            no_in: false,
+        }
+    }
+
+    fn finish(self) -> String {
+        self.buf
+    }
+
+    /// Writes a punctuator, separating it from the previous token if both would be merged into a
+    /// single token otherwise.
+    fn punct(&mut self, punct: &str) {
+        if let (Some(last), Some(first)) = (self.buf.chars().next_back(), punct.chars().next()) {
+            if (last == first && matches!(first, '+' | '-' | '/'))
+                || (last == '<' && first == '!')
+                || (last == '/' && first == '*')
+            {
+                self.buf.push(' ');
+            }
+        }
+        self.buf.push_str(punct);
+    }
+
+    /// Writes a keyword, an identifier or a numeric literal, separating it from the previous
+    /// token if needed.
+    fn word(&mut self, word: &str) {
+        if self.buf.chars().next_back().map_or(false, is_word_part) {
+            self.buf.push(' ');
+        }
+        self.buf.push_str(word);
+    }
+
+    /// Writes the string representation of a symbol as an identifier.
+    fn sym(&mut self, sym: Sym) {
+        let name = self.interner.resolve_expect(sym).to_string();
+        self.word(&name);
+    }
+
+    /// Writes an optional space.
+    fn space(&mut self) {
+        if !self.config.minify {
+            self.buf.push(' ');
+        }
+    }
+
+    /// Writes an optional line break.
+    fn newline(&mut self) {
+        if !self.config.minify {
+            self.buf.push('\n');
+        }
+    }
+
+    /// Writes the indentation of the current nesting level.
+    fn indent(&mut self) {
+        if self.config.minify {
+            return;
+        }
+        for _ in 0..self.depth {
+            match self.config.indent {
+                Indent::Spaces(n) => {
+                    for _ in 0..n {
+                        self.buf.push(' ');
+                    }
+                }
+                Indent::Tabs => self.buf.push('\t'),
+            }
+        }
+    }
+
+    /// Writes a comma followed by an optional space.
+    fn comma(&mut self) {
+        self.punct(",");
+        self.space();
+    }
+
+    /// Writes a punctuator surrounded by optional spaces.
+    fn operator(&mut self, op: &str) {
+        self.space();
+        self.punct(op);
+        self.space();
+    }
+
+    /// Runs `f` in a position delimited by brackets, where the `in` operator is never ambiguous.
+    fn nested<F>(&mut self, f: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+        let no_in = std::mem::replace(&mut self.no_in, false);
+        f(self);
+        self.no_in = no_in;
+    }
+
+    /// Writes a string literal, escaping any character that cannot appear in it as is.
+    fn string_literal(&mut self, sym: Sym) {
+        let mut buf = String::with_capacity(2);
+        buf.push('"');
+        for c in char::decode_utf16(self.interner.resolve_expect(sym).utf16().iter().copied()) {
+            match c {
+                Ok('"') => buf.push_str("\\\""),
+                Ok('\\') => buf.push_str("\\\\"),
+                Ok('\n') => buf.push_str("\\n"),
+                Ok('\r') => buf.push_str("\\r"),
+                Ok('\t') => buf.push_str("\\t"),
+                Ok(c @ ('\u{2028}' | '\u{2029}')) => {
+                    let _ = write!(buf, "\\u{:04X}", u32::from(c));
+                }
+                Ok(c) if c.is_control() => {
+                    let _ = write!(buf, "\\u{:04X}", u32::from(c));
+                }
+                Ok(c) => buf.push(c),
+                Err(e) => {
+                    let _ = write!(buf, "\\u{:04X}", e.unpaired_surrogate());
+                }
+            }
+        }
+        buf.push('"');
+        self.punct(&buf);
+    }
+
+    /// Writes a symbol that can be either an identifier name or a string literal, such as a
+    /// property name or a module export name.
+    fn name_or_string(&mut self, sym: Sym) {
+        let name = self.interner.resolve_expect(sym).to_string();
+        if is_identifier_name(&name) {
+            self.word(&name);
+        } else {
+            self.string_literal(sym);
+        }
+    }
+}
+
+/// Checks if a character can be part of an identifier or a numeric literal, which means that a
+/// space is needed between it and an adjacent word.
+fn is_word_part(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '_' | '$' | '\\') || !c.is_ascii()
+}
+
+/// Checks if `name` can be written as an `IdentifierName`.
+///
+/// This is conservative and only accepts ASCII names, which is fine since it is only used in
+/// places where a string literal is an alternative with the same meaning.
+fn is_identifier_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .map_or(false, |c| c.is_ascii_alphabetic() || matches!(c, '_' | '$'))
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '$'))
+}
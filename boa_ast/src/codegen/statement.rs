@@ -0,0 +1,752 @@
+//! Code generation for statements, declarations, patterns and module items.
+
+use super::{
+    expression::{Precedence, Start},
+    Generator,
+};
+use crate::{
+    declaration::{
+        Binding, Declaration, ExportDeclaration, ExportSpecifier, ImportAttribute,
+        ImportDeclaration, ImportKind, LexicalDeclaration, ReExportKind, Variable, VariableList,
+    },
+    expression::literal::LiteralKind,
+    pattern::{ArrayPatternElement, ObjectPatternElement, Pattern},
+    property::PropertyName,
+    statement::{
+        iteration::{ForLoopInitializer, IterableLoopInitializer},
+        If, LabelledItem, Statement,
+    },
+    Expression, ModuleItem, StatementList, StatementListItem,
+};
+
+/// Checks if the statement ends with an `if` statement without an `else` branch, which would
+/// take any `else` written after the statement.
+fn ends_with_dangling_if(statement: &Statement) -> bool {
+    match statement {
+        Statement::If(if_statement) => if_statement.else_node().map_or(true, ends_with_dangling_if),
+        Statement::WhileLoop(l) => ends_with_dangling_if(l.body()),
+        Statement::ForLoop(l) => ends_with_dangling_if(l.body()),
+        Statement::ForInLoop(l) => ends_with_dangling_if(l.body()),
+        Statement::ForOfLoop(l) => ends_with_dangling_if(l.body()),
+        Statement::With(w) => ends_with_dangling_if(w.statement()),
+        Statement::Labelled(l) => match l.item() {
+            LabelledItem::Statement(s) => ends_with_dangling_if(s),
+            LabelledItem::Function(_) => false,
+        },
+        _ => false,
+    }
+}
+
+impl Generator<'_> {
+    /// Checks if the directive prologue of the statement list contains a `"use strict"`
+    /// directive.
+    pub(super) fn has_use_strict(&self, list: &StatementList) -> bool {
+        for item in list.statements() {
+            let StatementListItem::Statement(Statement::Expression(Expression::Literal(literal))) =
+                item
+            else {
+                return false;
+            };
+            let LiteralKind::String(sym) = literal.kind() else {
+                return false;
+            };
+            if self.interner.resolve_expect(*sym).join(
+                |s| s == "use strict",
+                |utf16| utf16 == "use strict".encode_utf16().collect::<Vec<_>>(),
+                true,
+            ) {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Writes every item of a statement list in its own line.
+    pub(super) fn statement_list(&mut self, list: &StatementList) {
+        for item in list.statements() {
+            self.indent();
+            self.statement_list_item(item);
+            self.newline();
+        }
+    }
+
+    pub(super) fn statement_list_item(&mut self, item: &StatementListItem) {
+        match item {
+            StatementListItem::Statement(statement) => self.statement(statement),
+            StatementListItem::Declaration(declaration) => self.declaration(declaration),
+        }
+    }
+
+    /// Writes a statement list wrapped in braces.
+    pub(super) fn block(&mut self, list: &StatementList) {
+        self.punct("{");
+        if !list.statements().is_empty() {
+            self.newline();
+            self.depth += 1;
+            self.statement_list(list);
+            self.depth -= 1;
+            self.indent();
+        }
+        self.punct("}");
+    }
+
+    /// Writes the body of a compound statement, returning `true` if it was written in the same
+    /// line.
+    fn body(&mut self, body: &Statement) -> bool {
+        match body {
+            Statement::Block(block) => {
+                self.space();
+                self.block(block.statement_list());
+                true
+            }
+            Statement::Empty(_) => {
+                self.punct(";");
+                true
+            }
+            _ => {
+                self.newline();
+                self.depth += 1;
+                self.indent();
+                self.statement(body);
+                self.depth -= 1;
+                false
+            }
+        }
+    }
+
+    pub(super) fn statement(&mut self, statement: &Statement) {
+        match statement {
+            Statement::Block(block) => self.block(block.statement_list()),
+            Statement::Var(var) => {
+                self.word("var");
+                self.space();
+                self.variable_list(&var.0);
+                self.punct(";");
+            }
+            Statement::Empty(_) => self.punct(";"),
+            Statement::Expression(expr) => {
+                self.expression_at(expr, Precedence::Comma, Start::Statement);
+                self.punct(";");
+            }
+            Statement::If(if_statement) => self.if_statement(if_statement),
+            Statement::DoWhileLoop(do_while) => {
+                self.word("do");
+                if self.body(do_while.body()) {
+                    self.space();
+                } else {
+                    self.newline();
+                    self.indent();
+                }
+                self.word("while");
+                self.space();
+                self.condition(do_while.cond());
+                self.punct(";");
+            }
+            Statement::WhileLoop(while_loop) => {
+                self.word("while");
+                self.space();
+                self.condition(while_loop.condition());
+                self.body(while_loop.body());
+            }
+            Statement::ForLoop(for_loop) => {
+                self.word("for");
+                self.space();
+                self.punct("(");
+                if let Some(init) = for_loop.init() {
+                    self.no_in = true;
+                    match init {
+                        ForLoopInitializer::Expression(expr) => {
+                            self.expression(expr, Precedence::Comma);
+                        }
+                        ForLoopInitializer::Var(var) => {
+                            self.word("var");
+                            self.space();
+                            self.variable_list(&var.0);
+                        }
+                        ForLoopInitializer::Lexical(decl) => self.lexical_declaration(decl),
+                    }
+                    self.no_in = false;
+                }
+                self.punct(";");
+                if let Some(condition) = for_loop.condition() {
+                    self.space();
+                    self.expression(condition, Precedence::Comma);
+                }
+                self.punct(";");
+                if let Some(final_expr) = for_loop.final_expr() {
+                    self.space();
+                    self.expression(final_expr, Precedence::Comma);
+                }
+                self.punct(")");
+                self.body(for_loop.body());
+            }
+            Statement::ForInLoop(for_in) => {
+                self.word("for");
+                self.space();
+                self.punct("(");
+                self.iterable_loop_initializer(for_in.initializer());
+                self.space();
+                self.word("in");
+                self.space();
+                self.expression(for_in.target(), Precedence::Comma);
+                self.punct(")");
+                self.body(for_in.body());
+            }
+            Statement::ForOfLoop(for_of) => {
+                self.word("for");
+                if for_of.r#await() {
+                    self.space();
+                    self.word("await");
+                }
+                self.space();
+                self.punct("(");
+                self.iterable_loop_initializer(for_of.initializer());
+                self.space();
+                self.word("of");
+                self.space();
+                self.expression(for_of.iterable(), Precedence::Assignment);
+                self.punct(")");
+                self.body(for_of.body());
+            }
+            Statement::Switch(switch) => {
+                self.word("switch");
+                self.space();
+                self.condition(switch.val());
+                self.space();
+                self.punct("{");
+                self.newline();
+                self.depth += 1;
+                for case in switch.cases() {
+                    self.indent();
+                    if let Some(condition) = case.condition() {
+                        self.word("case");
+                        self.space();
+                        self.expression(condition, Precedence::Comma);
+                    } else {
+                        self.word("default");
+                    }
+                    self.punct(":");
+                    self.newline();
+                    self.depth += 1;
+                    self.statement_list(case.body());
+                    self.depth -= 1;
+                }
+                self.depth -= 1;
+                self.indent();
+                self.punct("}");
+            }
+            Statement::Continue(cont) => {
+                self.word("continue");
+                if let Some(label) = cont.label() {
+                    self.space();
+                    self.sym(label);
+                }
+                self.punct(";");
+            }
+            Statement::Break(brk) => {
+                self.word("break");
+                if let Some(label) = brk.label() {
+                    self.space();
+                    self.sym(label);
+                }
+                self.punct(";");
+            }
+            Statement::Return(ret) => {
+                self.word("return");
+                if let Some(target) = ret.target() {
+                    self.space();
+                    self.expression(target, Precedence::Comma);
+                }
+                self.punct(";");
+            }
+            Statement::Labelled(labelled) => {
+                self.sym(labelled.label());
+                self.punct(":");
+                self.space();
+                match labelled.item() {
+                    LabelledItem::Function(f) => self.function(f, true),
+                    LabelledItem::Statement(statement) => self.statement(statement),
+                }
+            }
+            Statement::Throw(throw) => {
+                self.word("throw");
+                self.space();
+                self.expression(throw.target(), Precedence::Comma);
+                self.punct(";");
+            }
+            Statement::Try(try_statement) => {
+                self.word("try");
+                self.space();
+                self.block(try_statement.block().statement_list());
+                if let Some(catch) = try_statement.catch() {
+                    self.space();
+                    self.word("catch");
+                    self.space();
+                    if let Some(parameter) = catch.parameter() {
+                        self.punct("(");
+                        self.binding(parameter);
+                        self.punct(")");
+                        self.space();
+                    }
+                    self.block(catch.block().statement_list());
+                }
+                if let Some(finally) = try_statement.finally() {
+                    self.space();
+                    self.word("finally");
+                    self.space();
+                    self.block(finally.block().statement_list());
+                }
+            }
+            Statement::With(with) => {
+                self.word("with");
+                self.space();
+                self.condition(with.expression());
+                self.body(with.statement());
+            }
+        }
+    }
+
+    /// Writes the parenthesized condition of a compound statement.
+    fn condition(&mut self, condition: &Expression) {
+        self.punct("(");
+        self.expression(condition, Precedence::Comma);
+        self.punct(")");
+    }
+
+    fn if_statement(&mut self, if_statement: &If) {
+        self.word("if");
+        self.space();
+        self.condition(if_statement.cond());
+        let Some(else_node) = if_statement.else_node() else {
+            self.body(if_statement.body());
+            return;
+        };
+
+        // An `if` without `else` in the body would take the `else` of this statement, so the
+        // body must be wrapped in a block to keep the structure of the AST.
+        let same_line = if ends_with_dangling_if(if_statement.body()) {
+            self.space();
+            self.punct("{");
+            self.newline();
+            self.depth += 1;
+            self.indent();
+            self.statement(if_statement.body());
+            self.newline();
+            self.depth -= 1;
+            self.indent();
+            self.punct("}");
+            true
+        } else {
+            self.body(if_statement.body())
+        };
+        if same_line {
+            self.space();
+        } else {
+            self.newline();
+            self.indent();
+        }
+        self.word("else");
+        match else_node {
+            Statement::If(_) => {
+                self.space();
+                self.statement(else_node);
+            }
+            _ => {
+                self.body(else_node);
+            }
+        }
+    }
+
+    fn iterable_loop_initializer(&mut self, initializer: &IterableLoopInitializer) {
+        match initializer {
+            IterableLoopInitializer::Identifier(ident, _) => self.sym(ident.sym()),
+            IterableLoopInitializer::Access(access) => self.property_access(access),
+            IterableLoopInitializer::Var(variable) => {
+                self.word("var");
+                self.space();
+                self.no_in = true;
+                self.variable(variable);
+                self.no_in = false;
+            }
+            IterableLoopInitializer::Let(binding) => {
+                self.word("let");
+                self.space();
+                self.binding(binding);
+            }
+            IterableLoopInitializer::Const(binding) => {
+                self.word("const");
+                self.space();
+                self.binding(binding);
+            }
+            IterableLoopInitializer::Using(binding) => {
+                self.word("using");
+                self.space();
+                self.binding(binding);
+            }
+            IterableLoopInitializer::AwaitUsing(binding) => {
+                self.word("await using");
+                self.space();
+                self.binding(binding);
+            }
+            IterableLoopInitializer::Pattern(pattern) => self.pattern(pattern),
+        }
+    }
+
+    pub(super) fn declaration(&mut self, declaration: &Declaration) {
+        match declaration {
+            Declaration::Function(f) => self.function(f, true),
+            Declaration::Generator(f) => self.generator(f, true),
+            Declaration::AsyncFunction(f) => self.async_function(f, true),
+            Declaration::AsyncGenerator(f) => self.async_generator(f, true),
+            Declaration::Class(class) => self.class(class, true),
+            Declaration::Lexical(decl) => {
+                self.lexical_declaration(decl);
+                self.punct(";");
+            }
+        }
+    }
+
+    fn lexical_declaration(&mut self, decl: &LexicalDeclaration) {
+        let keyword = match decl {
+            LexicalDeclaration::Const(_) => "const",
+            LexicalDeclaration::Let(_) => "let",
+            LexicalDeclaration::Using(_) => "using",
+            LexicalDeclaration::AwaitUsing(_) => "await using",
+        };
+        self.word(keyword);
+        self.space();
+        self.variable_list(decl.variable_list());
+    }
+
+    fn variable_list(&mut self, list: &VariableList) {
+        for (i, variable) in list.as_ref().iter().enumerate() {
+            if i > 0 {
+                self.comma();
+            }
+            self.variable(variable);
+        }
+    }
+
+    fn variable(&mut self, variable: &Variable) {
+        self.binding(variable.binding());
+        if let Some(init) = variable.init() {
+            self.operator("=");
+            self.expression(init, Precedence::Assignment);
+        }
+    }
+
+    pub(super) fn binding(&mut self, binding: &Binding) {
+        match binding {
+            Binding::Identifier(ident, _) => self.sym(ident.sym()),
+            Binding::Pattern(pattern) => self.pattern(pattern),
+        }
+    }
+
+    /// Writes the default initializer of a pattern element, if any.
+    fn default_init(&mut self, init: Option<&Expression>) {
+        if let Some(init) = init {
+            self.operator("=");
+            self.nested(|gen| gen.expression(init, Precedence::Assignment));
+        }
+    }
+
+    pub(super) fn pattern(&mut self, pattern: &Pattern) {
+        match pattern {
+            Pattern::Object(object) => {
+                let bindings = object.bindings();
+                self.punct("{");
+                if bindings.is_empty() {
+                    self.punct("}");
+                    return;
+                }
+                self.space();
+                for (i, element) in bindings.iter().enumerate() {
+                    if i > 0 {
+                        self.comma();
+                    }
+                    self.object_pattern_element(element);
+                }
+                self.space();
+                self.punct("}");
+            }
+            Pattern::Array(array) => {
+                let bindings = array.bindings();
+                self.punct("[");
+                for (i, element) in bindings.iter().enumerate() {
+                    if i > 0 {
+                        self.comma();
+                    }
+                    self.array_pattern_element(element);
+                }
+                if matches!(bindings.last(), Some(ArrayPatternElement::Elision)) {
+                    self.punct(",");
+                }
+                self.punct("]");
+            }
+        }
+    }
+
+    fn object_pattern_element(&mut self, element: &ObjectPatternElement) {
+        match element {
+            ObjectPatternElement::SingleName {
+                name,
+                ident,
+                default_init,
+            } => {
+                if !matches!(name, PropertyName::Literal(sym) if *sym == ident.sym()) {
+                    self.property_name(name);
+                    self.punct(":");
+                    self.space();
+                }
+                self.sym(ident.sym());
+                self.default_init(default_init.as_ref());
+            }
+            ObjectPatternElement::RestProperty { ident, .. } => {
+                self.punct("...");
+                self.sym(ident.sym());
+            }
+            ObjectPatternElement::AssignmentPropertyAccess {
+                name,
+                access,
+                default_init,
+            } => {
+                self.property_name(name);
+                self.punct(":");
+                self.space();
+                self.property_access(access);
+                self.default_init(default_init.as_ref());
+            }
+            ObjectPatternElement::AssignmentRestPropertyAccess { access, .. } => {
+                self.punct("...");
+                self.property_access(access);
+            }
+            ObjectPatternElement::Pattern {
+                name,
+                pattern,
+                default_init,
+            } => {
+                self.property_name(name);
+                self.punct(":");
+                self.space();
+                self.pattern(pattern);
+                self.default_init(default_init.as_ref());
+            }
+        }
+    }
+
+    fn array_pattern_element(&mut self, element: &ArrayPatternElement) {
+        match element {
+            ArrayPatternElement::Elision => {}
+            ArrayPatternElement::SingleName {
+                ident,
+                default_init,
+            } => {
+                self.sym(ident.sym());
+                self.default_init(default_init.as_ref());
+            }
+            ArrayPatternElement::PropertyAccess { access } => self.property_access(access),
+            ArrayPatternElement::Pattern {
+                pattern,
+                default_init,
+            } => {
+                self.pattern(pattern);
+                self.default_init(default_init.as_ref());
+            }
+            ArrayPatternElement::SingleNameRest { ident } => {
+                self.punct("...");
+                self.sym(ident.sym());
+            }
+            ArrayPatternElement::PropertyAccessRest { access } => {
+                self.punct("...");
+                self.property_access(access);
+            }
+            ArrayPatternElement::PatternRest { pattern } => {
+                self.punct("...");
+                self.pattern(pattern);
+            }
+        }
+    }
+
+    pub(super) fn module_item(&mut self, item: &ModuleItem) {
+        match item {
+            ModuleItem::ImportDeclaration(import) => self.import_declaration(import),
+            ModuleItem::ExportDeclaration(export) => self.export_declaration(export),
+            ModuleItem::StatementListItem(item) => self.statement_list_item(item),
+        }
+    }
+
+    fn import_declaration(&mut self, import: &ImportDeclaration) {
+        self.word("import");
+        self.space();
+        let default = import.default();
+        if let Some(default) = default {
+            self.sym(default.sym());
+        }
+        match import.kind() {
+            ImportKind::DefaultOrUnnamed => {}
+            ImportKind::Namespaced { binding } => {
+                if default.is_some() {
+                    self.comma();
+                }
+                self.punct("*");
+                self.space();
+                self.word("as");
+                self.space();
+                self.sym(binding.sym());
+            }
+            ImportKind::Named { names } => {
+                if default.is_some() {
+                    self.comma();
+                }
+                self.punct("{");
+                if !names.is_empty() {
+                    self.space();
+                    for (i, specifier) in names.iter().enumerate() {
+                        if i > 0 {
+                            self.comma();
+                        }
+                        let binding = specifier.binding().sym();
+                        if binding != specifier.export_name() {
+                            self.name_or_string(specifier.export_name());
+                            self.space();
+                            self.word("as");
+                            self.space();
+                        }
+                        self.sym(binding);
+                    }
+                    self.space();
+                }
+                self.punct("}");
+            }
+        }
+        if default.is_some() || !matches!(import.kind(), ImportKind::DefaultOrUnnamed) {
+            self.space();
+            self.word("from");
+            self.space();
+        }
+        self.string_literal(import.specifier().sym());
+        self.with_clause(import.attributes());
+        self.punct(";");
+    }
+
+    fn with_clause(&mut self, attributes: &[ImportAttribute]) {
+        if attributes.is_empty() {
+            return;
+        }
+        self.space();
+        self.word("with");
+        self.space();
+        self.punct("{");
+        self.space();
+        for (i, attribute) in attributes.iter().enumerate() {
+            if i > 0 {
+                self.comma();
+            }
+            self.name_or_string(attribute.key());
+            self.punct(":");
+            self.space();
+            self.string_literal(attribute.value());
+        }
+        self.space();
+        self.punct("}");
+    }
+
+    fn export_declaration(&mut self, export: &ExportDeclaration) {
+        self.word("export");
+        self.space();
+        match export {
+            ExportDeclaration::ReExport {
+                kind,
+                specifier,
+                attributes,
+            } => {
+                match kind {
+                    ReExportKind::Namespaced { name } => {
+                        self.punct("*");
+                        if let Some(name) = name {
+                            self.space();
+                            self.word("as");
+                            self.space();
+                            self.name_or_string(*name);
+                        }
+                    }
+                    ReExportKind::Named { names } => self.export_specifiers(names),
+                }
+                self.space();
+                self.word("from");
+                self.space();
+                self.string_literal(specifier.sym());
+                self.with_clause(attributes);
+                self.punct(";");
+            }
+            ExportDeclaration::List(names) => {
+                self.export_specifiers(names);
+                self.punct(";");
+            }
+            ExportDeclaration::VarStatement(var) => {
+                self.word("var");
+                self.space();
+                self.variable_list(&var.0);
+                self.punct(";");
+            }
+            ExportDeclaration::Declaration(declaration) => self.declaration(declaration),
+            ExportDeclaration::DefaultFunction(f) => {
+                self.word("default");
+                self.space();
+                self.function(f, true);
+            }
+            ExportDeclaration::DefaultGenerator(f) => {
+                self.word("default");
+                self.space();
+                self.generator(f, true);
+            }
+            ExportDeclaration::DefaultAsyncFunction(f) => {
+                self.word("default");
+                self.space();
+                self.async_function(f, true);
+            }
+            ExportDeclaration::DefaultAsyncGenerator(f) => {
+                self.word("default");
+                self.space();
+                self.async_generator(f, true);
+            }
+            ExportDeclaration::DefaultClassDeclaration(class) => {
+                self.word("default");
+                self.space();
+                self.class(class, true);
+            }
+            ExportDeclaration::DefaultAssignmentExpression(expr) => {
+                self.word("default");
+                self.space();
+                self.expression_at(expr, Precedence::Assignment, Start::ExportDefault);
+                self.punct(";");
+            }
+        }
+    }
+
+    fn export_specifiers(&mut self, names: &[ExportSpecifier]) {
+        self.punct("{");
+        if !names.is_empty() {
+            self.space();
+            for (i, specifier) in names.iter().enumerate() {
+                if i > 0 {
+                    self.comma();
+                }
+                let private_name = specifier.private_name();
+                if specifier.string_literal() {
+                    self.string_literal(private_name);
+                } else {
+                    self.sym(private_name);
+                }
+                if specifier.alias() != private_name {
+                    self.space();
+                    self.word("as");
+                    self.space();
+                    self.name_or_string(specifier.alias());
+                }
+            }
+            self.space();
+        }
+        self.punct("}");
+    }
+}
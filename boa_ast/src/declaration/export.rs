@@ -11,13 +11,13 @@
 
 use std::ops::ControlFlow;
 
-use super::{ModuleSpecifier, VarDeclaration};
+use super::{ImportAttribute, ModuleSpecifier, VarDeclaration};
 use crate::{
     expression::Identifier,
     function::{AsyncFunction, AsyncGenerator, Class, Function, Generator},
     try_break,
     visitor::{VisitWith, Visitor, VisitorMut},
-    Declaration, Expression,
+    Declaration, Expression, Span, Spanned,
 };
 use boa_interner::Sym;
 
@@ -87,6 +87,8 @@ pub enum ExportDeclaration {
         kind: ReExportKind,
         /// Reexported module specifier.
         specifier: ModuleSpecifier,
+        /// Import attributes of the `with` clause.
+        attributes: Box<[ImportAttribute]>,
     },
     /// List of exports.
     List(Box<[ExportSpecifier]>),
@@ -108,15 +110,40 @@ pub enum ExportDeclaration {
     DefaultAssignmentExpression(Expression),
 }
 
+impl Spanned for ExportDeclaration {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        match self {
+            Self::ReExport { .. } | Self::List(_) => None,
+            Self::VarStatement(var) => var.span(),
+            Self::Declaration(decl) => decl.span(),
+            Self::DefaultFunction(f) => f.span(),
+            Self::DefaultGenerator(g) => g.span(),
+            Self::DefaultAsyncFunction(f) => f.span(),
+            Self::DefaultAsyncGenerator(g) => g.span(),
+            Self::DefaultClassDeclaration(c) => c.span(),
+            Self::DefaultAssignmentExpression(expr) => expr.span(),
+        }
+    }
+}
+
 impl VisitWith for ExportDeclaration {
     fn visit_with<'a, V>(&'a self, visitor: &mut V) -> ControlFlow<V::BreakTy>
     where
         V: Visitor<'a>,
     {
         match self {
-            Self::ReExport { specifier, kind } => {
+            Self::ReExport {
+                specifier,
+                kind,
+                attributes,
+            } => {
                 try_break!(visitor.visit_module_specifier(specifier));
-                visitor.visit_re_export_kind(kind)
+                try_break!(visitor.visit_re_export_kind(kind));
+                for attribute in &**attributes {
+                    try_break!(visitor.visit_import_attribute(attribute));
+                }
+                ControlFlow::Continue(())
             }
             Self::List(list) => {
                 for item in &**list {
@@ -140,9 +167,17 @@ impl VisitWith for ExportDeclaration {
         V: VisitorMut<'a>,
     {
         match self {
-            Self::ReExport { specifier, kind } => {
+            Self::ReExport {
+                specifier,
+                kind,
+                attributes,
+            } => {
                 try_break!(visitor.visit_module_specifier_mut(specifier));
-                visitor.visit_re_export_kind_mut(kind)
+                try_break!(visitor.visit_re_export_kind_mut(kind));
+                for attribute in &mut **attributes {
+                    try_break!(visitor.visit_import_attribute_mut(attribute));
+                }
+                ControlFlow::Continue(())
             }
             Self::List(list) => {
                 for item in &mut **list {
@@ -15,10 +15,11 @@ use crate::{
     expression::Identifier,
     try_break,
     visitor::{VisitWith, Visitor, VisitorMut},
+    NodeSpan, Span, Spanned,
 };
 use boa_interner::Sym;
 
-use super::ModuleSpecifier;
+use super::{ImportAttribute, ModuleSpecifier};
 
 /// The kind of import in an [`ImportDeclaration`].
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -88,13 +89,16 @@ pub struct ImportDeclaration {
     kind: ImportKind,
     /// Module specifier.
     specifier: ModuleSpecifier,
+    /// Import attributes of the `with` clause.
+    attributes: Box<[ImportAttribute]>,
+    span: NodeSpan,
 }
 
 impl ImportDeclaration {
     /// Creates a new import declaration.
     #[inline]
     #[must_use]
-    pub const fn new(
+    pub fn new(
         default: Option<Identifier>,
         kind: ImportKind,
         specifier: ModuleSpecifier,
@@ -103,9 +107,19 @@ impl ImportDeclaration {
             default,
             kind,
             specifier,
+            attributes: Box::new([]),
+            span: NodeSpan::none(),
         }
     }
 
+    /// Sets the import attributes of the import declaration.
+    #[inline]
+    #[must_use]
+    pub fn with_attributes(mut self, attributes: Box<[ImportAttribute]>) -> Self {
+        self.attributes = attributes;
+        self
+    }
+
     /// Gets the binding for the default export of the module.
     #[inline]
     #[must_use]
@@ -126,6 +140,28 @@ impl ImportDeclaration {
     pub const fn kind(&self) -> &ImportKind {
         &self.kind
     }
+
+    /// Gets the import attributes of the import declaration.
+    #[inline]
+    #[must_use]
+    pub const fn attributes(&self) -> &[ImportAttribute] {
+        &self.attributes
+    }
+
+    /// Sets the location of this import declaration in the source code.
+    #[inline]
+    #[must_use]
+    pub const fn with_span(mut self, span: Span) -> Self {
+        self.span = NodeSpan::new(span);
+        self
+    }
+}
+
+impl Spanned for ImportDeclaration {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        self.span.get()
+    }
 }
 
 impl VisitWith for ImportDeclaration {
@@ -137,7 +173,11 @@ impl VisitWith for ImportDeclaration {
             try_break!(visitor.visit_identifier(default));
         }
         try_break!(visitor.visit_import_kind(&self.kind));
-        visitor.visit_module_specifier(&self.specifier)
+        try_break!(visitor.visit_module_specifier(&self.specifier));
+        for attribute in &*self.attributes {
+            try_break!(visitor.visit_import_attribute(attribute));
+        }
+        ControlFlow::Continue(())
     }
 
     fn visit_with_mut<'a, V>(&'a mut self, visitor: &mut V) -> ControlFlow<V::BreakTy>
@@ -148,7 +188,11 @@ impl VisitWith for ImportDeclaration {
             try_break!(visitor.visit_identifier_mut(default));
         }
         try_break!(visitor.visit_import_kind_mut(&mut self.kind));
-        visitor.visit_module_specifier_mut(&mut self.specifier)
+        try_break!(visitor.visit_module_specifier_mut(&mut self.specifier));
+        for attribute in &mut *self.attributes {
+            try_break!(visitor.visit_import_attribute_mut(attribute));
+        }
+        ControlFlow::Continue(())
     }
 }
 
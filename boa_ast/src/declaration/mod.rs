@@ -23,6 +23,7 @@ mod import;
 mod variable;
 
 use crate::visitor::{VisitWith, Visitor, VisitorMut};
+use crate::{try_break, Span, Spanned};
 pub use export::*;
 pub use import::*;
 pub use variable::*;
@@ -70,6 +71,20 @@ impl ToIndentedString for Declaration {
     }
 }
 
+impl Spanned for Declaration {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        match self {
+            Self::Function(f) => f.span(),
+            Self::Generator(g) => g.span(),
+            Self::AsyncFunction(f) => f.span(),
+            Self::AsyncGenerator(g) => g.span(),
+            Self::Class(c) => c.span(),
+            Self::Lexical(l) => l.span(),
+        }
+    }
+}
+
 impl VisitWith for Declaration {
     fn visit_with<'a, V>(&'a self, visitor: &mut V) -> ControlFlow<V::BreakTy>
     where
@@ -149,3 +164,58 @@ impl VisitWith for ModuleSpecifier {
         visitor.visit_sym_mut(&mut self.module)
     }
 }
+
+/// An import attribute (`type: "json"`) of a `WithClause`, as defined by the [spec].
+///
+/// Import attributes are passed to the host when loading a module, and allow changing how
+/// the imported module is loaded and evaluated.
+///
+/// [spec]: https://tc39.es/proposal-import-attributes/#prod-WithClause
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(Clone, Debug, Copy, PartialEq, Eq, Hash)]
+pub struct ImportAttribute {
+    key: Sym,
+    value: Sym,
+}
+
+impl ImportAttribute {
+    /// Creates a new `ImportAttribute` from its key and value.
+    #[inline]
+    #[must_use]
+    pub const fn new(key: Sym, value: Sym) -> Self {
+        Self { key, value }
+    }
+
+    /// Gets the key of the import attribute.
+    #[inline]
+    #[must_use]
+    pub const fn key(self) -> Sym {
+        self.key
+    }
+
+    /// Gets the value of the import attribute.
+    #[inline]
+    #[must_use]
+    pub const fn value(self) -> Sym {
+        self.value
+    }
+}
+
+impl VisitWith for ImportAttribute {
+    fn visit_with<'a, V>(&'a self, visitor: &mut V) -> ControlFlow<V::BreakTy>
+    where
+        V: Visitor<'a>,
+    {
+        try_break!(visitor.visit_sym(&self.key));
+        visitor.visit_sym(&self.value)
+    }
+
+    fn visit_with_mut<'a, V>(&'a mut self, visitor: &mut V) -> ControlFlow<V::BreakTy>
+    where
+        V: VisitorMut<'a>,
+    {
+        try_break!(visitor.visit_sym_mut(&mut self.key));
+        visitor.visit_sym_mut(&mut self.value)
+    }
+}
@@ -9,7 +9,7 @@ use crate::{
     expression::{Expression, Identifier},
     join_nodes,
     pattern::Pattern,
-    Statement,
+    NodeSpan, Span, Spanned, Statement,
 };
 use boa_interner::{Interner, ToInternedString};
 
@@ -61,6 +61,13 @@ impl ToInternedString for VarDeclaration {
     }
 }
 
+impl Spanned for VarDeclaration {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        self.0.span()
+    }
+}
+
 impl VisitWith for VarDeclaration {
     fn visit_with<'a, V>(&'a self, visitor: &mut V) -> ControlFlow<V::BreakTy>
     where
@@ -105,6 +112,25 @@ pub enum LexicalDeclaration {
     ///
     /// [let]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/let
     Let(VariableList),
+
+    /// A `using` declaration binds a disposable resource to a block-scoped constant. The
+    /// resource's `@@dispose` method is called when control leaves the enclosing block.
+    ///
+    /// More information:
+    ///  - [Explicit Resource Management proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#prod-UsingDeclaration
+    Using(VariableList),
+
+    /// An `await using` declaration binds an asynchronously disposable resource to a
+    /// block-scoped constant. The resource's `@@asyncDispose` method is called and awaited when
+    /// control leaves the enclosing block.
+    ///
+    /// More information:
+    ///  - [Explicit Resource Management proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#prod-AwaitUsingDeclaration
+    AwaitUsing(VariableList),
 }
 
 impl LexicalDeclaration {
@@ -112,14 +138,30 @@ impl LexicalDeclaration {
     #[must_use]
     pub const fn variable_list(&self) -> &VariableList {
         match self {
-            Self::Const(list) | Self::Let(list) => list,
+            Self::Const(list) | Self::Let(list) | Self::Using(list) | Self::AwaitUsing(list) => {
+                list
+            }
         }
     }
 
-    /// Returns `true` if the declaration is a `const` declaration.
+    /// Returns `true` if the declaration creates immutable bindings.
+    ///
+    /// This is the case for `const`, `using` and `await using` declarations.
     #[must_use]
     pub const fn is_const(&self) -> bool {
-        matches!(self, Self::Const(_))
+        matches!(self, Self::Const(_) | Self::Using(_) | Self::AwaitUsing(_))
+    }
+
+    /// Returns `true` if the declaration is a `using` or `await using` declaration.
+    #[must_use]
+    pub const fn is_using(&self) -> bool {
+        matches!(self, Self::Using(_) | Self::AwaitUsing(_))
+    }
+
+    /// Returns `true` if the declaration is an `await using` declaration.
+    #[must_use]
+    pub const fn is_await_using(&self) -> bool {
+        matches!(self, Self::AwaitUsing(_))
     }
 }
 
@@ -136,20 +178,27 @@ impl ToInternedString for LexicalDeclaration {
             match &self {
                 Self::Let(_) => "let",
                 Self::Const(_) => "const",
+                Self::Using(_) => "using",
+                Self::AwaitUsing(_) => "await using",
             },
             self.variable_list().to_interned_string(interner)
         )
     }
 }
 
+impl Spanned for LexicalDeclaration {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        self.variable_list().span()
+    }
+}
+
 impl VisitWith for LexicalDeclaration {
     fn visit_with<'a, V>(&'a self, visitor: &mut V) -> ControlFlow<V::BreakTy>
     where
         V: Visitor<'a>,
     {
-        match self {
-            Self::Const(vars) | Self::Let(vars) => visitor.visit_variable_list(vars),
-        }
+        visitor.visit_variable_list(self.variable_list())
     }
 
     fn visit_with_mut<'a, V>(&'a mut self, visitor: &mut V) -> ControlFlow<V::BreakTy>
@@ -157,7 +206,9 @@ impl VisitWith for LexicalDeclaration {
         V: VisitorMut<'a>,
     {
         match self {
-            Self::Const(vars) | Self::Let(vars) => visitor.visit_variable_list_mut(vars),
+            Self::Const(vars) | Self::Let(vars) | Self::Using(vars) | Self::AwaitUsing(vars) => {
+                visitor.visit_variable_list_mut(vars)
+            }
         }
     }
 }
@@ -168,6 +219,7 @@ impl VisitWith for LexicalDeclaration {
 #[derive(Clone, Debug, PartialEq)]
 pub struct VariableList {
     list: Box<[Variable]>,
+    span: NodeSpan,
 }
 
 impl VariableList {
@@ -178,7 +230,25 @@ impl VariableList {
             return None;
         }
 
-        Some(Self { list })
+        Some(Self {
+            list,
+            span: NodeSpan::none(),
+        })
+    }
+
+    /// Sets the location of this variable declaration list in the source code.
+    #[inline]
+    #[must_use]
+    pub const fn with_span(mut self, span: Span) -> Self {
+        self.span = NodeSpan::new(span);
+        self
+    }
+}
+
+impl Spanned for VariableList {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        self.span.get()
     }
 }
 
@@ -260,6 +330,7 @@ impl TryFrom<Vec<Variable>> for VariableList {
 pub struct Variable {
     binding: Binding,
     init: Option<Expression>,
+    span: NodeSpan,
 }
 
 impl ToInternedString for Variable {
@@ -279,8 +350,20 @@ impl Variable {
     #[must_use]
     pub const fn from_identifier(ident: Identifier, init: Option<Expression>) -> Self {
         Self {
-            binding: Binding::Identifier(ident),
+            binding: Binding::Identifier(ident, NodeSpan::none()),
             init,
+            span: NodeSpan::none(),
+        }
+    }
+
+    /// Creates a new variable declaration from a `Binding`.
+    #[inline]
+    #[must_use]
+    pub const fn from_binding(binding: Binding, init: Option<Expression>) -> Self {
+        Self {
+            binding,
+            init,
+            span: NodeSpan::none(),
         }
     }
 
@@ -291,6 +374,7 @@ impl Variable {
         Self {
             binding: Binding::Pattern(pattern),
             init,
+            span: NodeSpan::none(),
         }
     }
     /// Gets the variable declaration binding.
@@ -305,6 +389,21 @@ impl Variable {
     pub const fn init(&self) -> Option<&Expression> {
         self.init.as_ref()
     }
+
+    /// Sets the location of this variable declarator in the source code.
+    #[inline]
+    #[must_use]
+    pub const fn with_span(mut self, span: Span) -> Self {
+        self.span = NodeSpan::new(span);
+        self
+    }
+}
+
+impl Spanned for Variable {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        self.span.get()
+    }
 }
 
 impl VisitWith for Variable {
@@ -342,14 +441,14 @@ impl VisitWith for Variable {
 #[derive(Clone, Debug, PartialEq)]
 pub enum Binding {
     /// A single identifier binding.
-    Identifier(Identifier),
+    Identifier(Identifier, NodeSpan),
     /// A pattern binding.
     Pattern(Pattern),
 }
 
 impl From<Identifier> for Binding {
     fn from(id: Identifier) -> Self {
-        Self::Identifier(id)
+        Self::Identifier(id, NodeSpan::none())
     }
 }
 
@@ -362,19 +461,29 @@ impl From<Pattern> for Binding {
 impl ToInternedString for Binding {
     fn to_interned_string(&self, interner: &Interner) -> String {
         match self {
-            Self::Identifier(id) => id.to_interned_string(interner),
+            Self::Identifier(id, _) => id.to_interned_string(interner),
             Self::Pattern(ref pattern) => pattern.to_interned_string(interner),
         }
     }
 }
 
+impl Spanned for Binding {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        match self {
+            Self::Identifier(_, span) => span.get(),
+            Self::Pattern(pattern) => pattern.span(),
+        }
+    }
+}
+
 impl VisitWith for Binding {
     fn visit_with<'a, V>(&'a self, visitor: &mut V) -> ControlFlow<V::BreakTy>
     where
         V: Visitor<'a>,
     {
         match self {
-            Self::Identifier(id) => visitor.visit_identifier(id),
+            Self::Identifier(id, _) => visitor.visit_identifier(id),
             Self::Pattern(pattern) => visitor.visit_pattern(pattern),
         }
     }
@@ -384,7 +493,7 @@ impl VisitWith for Binding {
         V: VisitorMut<'a>,
     {
         match self {
-            Self::Identifier(id) => visitor.visit_identifier_mut(id),
+            Self::Identifier(id, _) => visitor.visit_identifier_mut(id),
             Self::Pattern(pattern) => visitor.visit_pattern_mut(pattern),
         }
     }
@@ -18,6 +18,7 @@ use crate::expression::Expression;
 use crate::function::PrivateName;
 use crate::try_break;
 use crate::visitor::{VisitWith, Visitor, VisitorMut};
+use crate::{NodeSpan, Span, Spanned};
 use boa_interner::{Interner, Sym, ToInternedString};
 use core::ops::ControlFlow;
 
@@ -103,6 +104,17 @@ impl From<PropertyAccess> for Expression {
     }
 }
 
+impl Spanned for PropertyAccess {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        match self {
+            Self::Simple(access) => access.span(),
+            Self::Private(access) => access.span(),
+            Self::Super(access) => access.span(),
+        }
+    }
+}
+
 impl VisitWith for PropertyAccess {
     fn visit_with<'a, V>(&'a self, visitor: &mut V) -> ControlFlow<V::BreakTy>
     where
@@ -134,6 +146,7 @@ impl VisitWith for PropertyAccess {
 pub struct SimplePropertyAccess {
     target: Box<Expression>,
     field: PropertyAccessField,
+    span: NodeSpan,
 }
 
 impl SimplePropertyAccess {
@@ -159,8 +172,24 @@ impl SimplePropertyAccess {
         Self {
             target: target.into(),
             field: field.into(),
+            span: NodeSpan::none(),
         }
     }
+
+    /// Sets the location of this property access in the source code.
+    #[inline]
+    #[must_use]
+    pub const fn with_span(mut self, span: Span) -> Self {
+        self.span = NodeSpan::new(span);
+        self
+    }
+}
+
+impl Spanned for SimplePropertyAccess {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        self.span.get()
+    }
 }
 
 impl ToInternedString for SimplePropertyAccess {
@@ -217,6 +246,7 @@ impl VisitWith for SimplePropertyAccess {
 pub struct PrivatePropertyAccess {
     target: Box<Expression>,
     field: PrivateName,
+    span: NodeSpan,
 }
 
 impl PrivatePropertyAccess {
@@ -227,6 +257,7 @@ impl PrivatePropertyAccess {
         Self {
             target: value.into(),
             field,
+            span: NodeSpan::none(),
         }
     }
 
@@ -243,6 +274,21 @@ impl PrivatePropertyAccess {
     pub const fn field(&self) -> PrivateName {
         self.field
     }
+
+    /// Sets the location of this private property access in the source code.
+    #[inline]
+    #[must_use]
+    pub const fn with_span(mut self, span: Span) -> Self {
+        self.span = NodeSpan::new(span);
+        self
+    }
+}
+
+impl Spanned for PrivatePropertyAccess {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        self.span.get()
+    }
 }
 
 impl ToInternedString for PrivatePropertyAccess {
@@ -293,13 +339,17 @@ impl VisitWith for PrivatePropertyAccess {
 #[derive(Clone, Debug, PartialEq)]
 pub struct SuperPropertyAccess {
     field: PropertyAccessField,
+    span: NodeSpan,
 }
 
 impl SuperPropertyAccess {
     /// Creates a new property access field node.
     #[must_use]
     pub const fn new(field: PropertyAccessField) -> Self {
-        Self { field }
+        Self {
+            field,
+            span: NodeSpan::none(),
+        }
     }
 
     /// Gets the name of the field to retrieve.
@@ -308,6 +358,21 @@ impl SuperPropertyAccess {
     pub const fn field(&self) -> &PropertyAccessField {
         &self.field
     }
+
+    /// Sets the location of this `super` property access in the source code.
+    #[inline]
+    #[must_use]
+    pub const fn with_span(mut self, span: Span) -> Self {
+        self.span = NodeSpan::new(span);
+        self
+    }
+}
+
+impl Spanned for SuperPropertyAccess {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        self.span.get()
+    }
 }
 
 impl ToInternedString for SuperPropertyAccess {
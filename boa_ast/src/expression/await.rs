@@ -4,6 +4,7 @@ use core::ops::ControlFlow;
 
 use super::Expression;
 use crate::visitor::{VisitWith, Visitor, VisitorMut};
+use crate::{NodeSpan, Span, Spanned};
 use boa_interner::{Interner, ToIndentedString, ToInternedString};
 
 /// An await expression is used within an async function to pause execution and wait for a
@@ -20,6 +21,7 @@ use boa_interner::{Interner, ToIndentedString, ToInternedString};
 #[derive(Clone, Debug, PartialEq)]
 pub struct Await {
     target: Box<Expression>,
+    span: NodeSpan,
 }
 
 impl Await {
@@ -29,6 +31,21 @@ impl Await {
     pub const fn target(&self) -> &Expression {
         &self.target
     }
+
+    /// Sets the location of this `await` expression in the source code.
+    #[inline]
+    #[must_use]
+    pub const fn with_span(mut self, span: Span) -> Self {
+        self.span = NodeSpan::new(span);
+        self
+    }
+}
+
+impl Spanned for Await {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        self.span.get()
+    }
 }
 
 impl<T> From<T> for Await
@@ -36,7 +53,10 @@ where
     T: Into<Box<Expression>>,
 {
     fn from(e: T) -> Self {
-        Self { target: e.into() }
+        Self {
+            target: e.into(),
+            span: NodeSpan::none(),
+        }
     }
 }
 
@@ -5,6 +5,7 @@ use boa_interner::{Interner, ToInternedString};
 use core::ops::ControlFlow;
 
 use super::Expression;
+use crate::{NodeSpan, Span, Spanned};
 
 /// Calling the function actually performs the specified actions with the indicated parameters.
 ///
@@ -26,6 +27,7 @@ use super::Expression;
 pub struct Call {
     function: Box<Expression>,
     args: Box<[Expression]>,
+    span: NodeSpan,
 }
 
 impl Call {
@@ -36,9 +38,18 @@ impl Call {
         Self {
             function: function.into(),
             args,
+            span: NodeSpan::none(),
         }
     }
 
+    /// Sets the location of this call expression in the source code.
+    #[inline]
+    #[must_use]
+    pub const fn with_span(mut self, span: Span) -> Self {
+        self.span = NodeSpan::new(span);
+        self
+    }
+
     /// Gets the target function of this call expression.
     #[inline]
     #[must_use]
@@ -54,6 +65,13 @@ impl Call {
     }
 }
 
+impl Spanned for Call {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        self.span.get()
+    }
+}
+
 impl ToInternedString for Call {
     #[inline]
     fn to_interned_string(&self, interner: &Interner) -> String {
@@ -109,6 +127,7 @@ impl VisitWith for Call {
 #[derive(Clone, Debug, PartialEq)]
 pub struct SuperCall {
     args: Box<[Expression]>,
+    span: NodeSpan,
 }
 
 impl SuperCall {
@@ -117,7 +136,18 @@ impl SuperCall {
     where
         A: Into<Box<[Expression]>>,
     {
-        Self { args: args.into() }
+        Self {
+            args: args.into(),
+            span: NodeSpan::none(),
+        }
+    }
+
+    /// Sets the location of this super call in the source code.
+    #[inline]
+    #[must_use]
+    pub const fn with_span(mut self, span: Span) -> Self {
+        self.span = NodeSpan::new(span);
+        self
     }
 
     /// Retrieves the arguments of the super call.
@@ -127,6 +157,13 @@ impl SuperCall {
     }
 }
 
+impl Spanned for SuperCall {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        self.span.get()
+    }
+}
+
 impl ToInternedString for SuperCall {
     #[inline]
     fn to_interned_string(&self, interner: &Interner) -> String {
@@ -178,6 +215,8 @@ impl VisitWith for SuperCall {
 #[derive(Clone, Debug, PartialEq)]
 pub struct ImportCall {
     arg: Box<Expression>,
+    options: Option<Box<Expression>>,
+    span: NodeSpan,
 }
 
 impl ImportCall {
@@ -188,20 +227,61 @@ impl ImportCall {
     {
         Self {
             arg: Box::new(arg.into()),
+            options: None,
+            span: NodeSpan::none(),
         }
     }
 
-    /// Retrieves the single argument of the import call.
+    /// Sets the options argument (`import(specifier, options)`) of the import call.
+    #[must_use]
+    pub fn with_options<O>(mut self, options: O) -> Self
+    where
+        O: Into<Expression>,
+    {
+        self.options = Some(Box::new(options.into()));
+        self
+    }
+
+    /// Retrieves the specifier argument of the import call.
     #[must_use]
     pub const fn argument(&self) -> &Expression {
         &self.arg
     }
+
+    /// Retrieves the options argument of the import call, if present.
+    #[must_use]
+    pub fn options(&self) -> Option<&Expression> {
+        self.options.as_deref()
+    }
+
+    /// Sets the location of this import call in the source code.
+    #[inline]
+    #[must_use]
+    pub const fn with_span(mut self, span: Span) -> Self {
+        self.span = NodeSpan::new(span);
+        self
+    }
+}
+
+impl Spanned for ImportCall {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        self.span.get()
+    }
 }
 
 impl ToInternedString for ImportCall {
     #[inline]
     fn to_interned_string(&self, interner: &Interner) -> String {
-        format!("import({})", self.arg.to_interned_string(interner))
+        if let Some(options) = &self.options {
+            format!(
+                "import({}, {})",
+                self.arg.to_interned_string(interner),
+                options.to_interned_string(interner)
+            )
+        } else {
+            format!("import({})", self.arg.to_interned_string(interner))
+        }
     }
 }
 
@@ -217,13 +297,21 @@ impl VisitWith for ImportCall {
     where
         V: Visitor<'a>,
     {
-        visitor.visit_expression(&self.arg)
+        try_break!(visitor.visit_expression(&self.arg));
+        if let Some(options) = &self.options {
+            try_break!(visitor.visit_expression(options));
+        }
+        ControlFlow::Continue(())
     }
 
     fn visit_with_mut<'a, V>(&'a mut self, visitor: &mut V) -> ControlFlow<V::BreakTy>
     where
         V: VisitorMut<'a>,
     {
-        visitor.visit_expression_mut(&mut self.arg)
+        try_break!(visitor.visit_expression_mut(&mut self.arg));
+        if let Some(options) = &mut self.options {
+            try_break!(visitor.visit_expression_mut(options));
+        }
+        ControlFlow::Continue(())
     }
 }
@@ -2,7 +2,7 @@
 
 use crate::{
     visitor::{VisitWith, Visitor, VisitorMut},
-    ToStringEscaped,
+    NodeSpan, ToStringEscaped,
 };
 use boa_interner::{Interner, Sym, ToInternedString};
 use core::ops::ControlFlow;
@@ -101,7 +101,7 @@ impl From<Sym> for Identifier {
 impl From<Identifier> for Expression {
     #[inline]
     fn from(local: Identifier) -> Self {
-        Self::Identifier(local)
+        Self::Identifier(local, NodeSpan::none())
     }
 }
 
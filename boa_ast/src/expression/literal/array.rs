@@ -5,6 +5,7 @@ use crate::expression::Expression;
 use crate::pattern::{ArrayPattern, ArrayPatternElement, Pattern};
 use crate::try_break;
 use crate::visitor::{VisitWith, Visitor, VisitorMut};
+use crate::{NodeSpan, Span, Spanned};
 use boa_interner::{Interner, Sym, ToInternedString};
 use core::ops::ControlFlow;
 
@@ -30,6 +31,7 @@ use core::ops::ControlFlow;
 pub struct ArrayLiteral {
     arr: Box<[Option<Expression>]>,
     has_trailing_comma_spread: bool,
+    span: NodeSpan,
 }
 
 impl ArrayLiteral {
@@ -41,6 +43,7 @@ impl ArrayLiteral {
         Self {
             arr: array.into(),
             has_trailing_comma_spread,
+            span: NodeSpan::none(),
         }
     }
 
@@ -65,7 +68,7 @@ impl ArrayLiteral {
                 continue;
             };
             match expr {
-                Expression::Identifier(ident) => {
+                Expression::Identifier(ident, _) => {
                     if strict && *ident == Sym::ARGUMENTS {
                         return None;
                     }
@@ -77,7 +80,7 @@ impl ArrayLiteral {
                 }
                 Expression::Spread(spread) => {
                     match spread.target() {
-                        Expression::Identifier(ident) => {
+                        Expression::Identifier(ident, _) => {
                             bindings.push(ArrayPatternElement::SingleNameRest { ident: *ident });
                         }
                         Expression::PropertyAccess(access) => {
@@ -100,7 +103,7 @@ impl ArrayLiteral {
                     }
                 }
                 Expression::Assign(assign) => match assign.lhs() {
-                    AssignTarget::Identifier(ident) => {
+                    AssignTarget::Identifier(ident, _) => {
                         bindings.push(ArrayPatternElement::SingleName {
                             ident: *ident,
                             default_init: Some(assign.rhs().clone()),
@@ -148,7 +151,26 @@ impl ArrayLiteral {
                 _ => return None,
             }
         }
-        Some(ArrayPattern::new(bindings.into()))
+        let pattern = ArrayPattern::new(bindings.into());
+        Some(match self.span.get() {
+            Some(span) => pattern.with_span(span),
+            None => pattern,
+        })
+    }
+
+    /// Sets the location of this array literal in the source code.
+    #[inline]
+    #[must_use]
+    pub const fn with_span(mut self, span: Span) -> Self {
+        self.span = NodeSpan::new(span);
+        self
+    }
+}
+
+impl Spanned for ArrayLiteral {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        self.span.get()
     }
 }
 
@@ -174,6 +196,7 @@ where
         Self {
             arr: decl.into(),
             has_trailing_comma_spread: false,
+            span: NodeSpan::none(),
         }
     }
 }
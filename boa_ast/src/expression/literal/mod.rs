@@ -16,7 +16,10 @@ use core::ops::ControlFlow;
 pub use object::ObjectLiteral;
 pub use template::{TemplateElement, TemplateLiteral};
 
-use crate::visitor::{VisitWith, Visitor, VisitorMut};
+use crate::{
+    visitor::{VisitWith, Visitor, VisitorMut},
+    NodeSpan, Span, Spanned,
+};
 use boa_interner::{Interner, Sym, ToInternedString};
 use num_bigint::BigInt;
 
@@ -35,7 +38,50 @@ use super::Expression;
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Clone, Debug, PartialEq)]
-pub enum Literal {
+pub struct Literal {
+    kind: LiteralKind,
+    span: NodeSpan,
+}
+
+impl Literal {
+    /// Creates a new `Literal` AST node.
+    #[inline]
+    #[must_use]
+    pub const fn new(kind: LiteralKind) -> Self {
+        Self {
+            kind,
+            span: NodeSpan::none(),
+        }
+    }
+
+    /// Gets the value of this literal.
+    #[inline]
+    #[must_use]
+    pub const fn kind(&self) -> &LiteralKind {
+        &self.kind
+    }
+
+    /// Sets the location of this literal in the source code.
+    #[inline]
+    #[must_use]
+    pub const fn with_span(mut self, span: Span) -> Self {
+        self.span = NodeSpan::new(span);
+        self
+    }
+}
+
+impl Spanned for Literal {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        self.span.get()
+    }
+}
+
+/// The value of a [`Literal`].
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(Clone, Debug, PartialEq)]
+pub enum LiteralKind {
     /// A string literal is zero or more characters enclosed in double (`"`) or single (`'`) quotation marks.
     ///
     /// A string must be delimited by quotation marks of the same type (that is, either both single quotation marks, or both double quotation marks).
@@ -118,48 +164,65 @@ pub enum Literal {
     Undefined,
 }
 
-impl From<Sym> for Literal {
+impl From<Sym> for LiteralKind {
     #[inline]
     fn from(string: Sym) -> Self {
         Self::String(string)
     }
 }
 
-impl From<f64> for Literal {
+impl From<f64> for LiteralKind {
     #[inline]
     fn from(num: f64) -> Self {
         Self::Num(num)
     }
 }
 
-impl From<i32> for Literal {
+impl From<i32> for LiteralKind {
     #[inline]
     fn from(i: i32) -> Self {
         Self::Int(i)
     }
 }
 
-impl From<BigInt> for Literal {
+impl From<BigInt> for LiteralKind {
     #[inline]
     fn from(i: BigInt) -> Self {
         Self::BigInt(Box::new(i))
     }
 }
 
-impl From<Box<BigInt>> for Literal {
+impl From<Box<BigInt>> for LiteralKind {
     #[inline]
     fn from(i: Box<BigInt>) -> Self {
         Self::BigInt(i)
     }
 }
 
-impl From<bool> for Literal {
+impl From<bool> for LiteralKind {
     #[inline]
     fn from(b: bool) -> Self {
         Self::Bool(b)
     }
 }
 
+impl<T> From<T> for Literal
+where
+    T: Into<LiteralKind>,
+{
+    #[inline]
+    fn from(kind: T) -> Self {
+        Self::new(kind.into())
+    }
+}
+
+impl From<LiteralKind> for Expression {
+    #[inline]
+    fn from(kind: LiteralKind) -> Self {
+        Self::Literal(kind.into())
+    }
+}
+
 impl From<Literal> for Expression {
     #[inline]
     fn from(lit: Literal) -> Self {
@@ -168,6 +231,13 @@ impl From<Literal> for Expression {
 }
 
 impl ToInternedString for Literal {
+    #[inline]
+    fn to_interned_string(&self, interner: &Interner) -> String {
+        self.kind.to_interned_string(interner)
+    }
+}
+
+impl ToInternedString for LiteralKind {
     #[inline]
     fn to_interned_string(&self, interner: &Interner) -> String {
         match *self {
@@ -189,7 +259,7 @@ impl VisitWith for Literal {
     where
         V: Visitor<'a>,
     {
-        if let Self::String(sym) = self {
+        if let LiteralKind::String(sym) = &self.kind {
             visitor.visit_sym(sym)
         } else {
             ControlFlow::Continue(())
@@ -200,7 +270,7 @@ impl VisitWith for Literal {
     where
         V: VisitorMut<'a>,
     {
-        if let Self::String(sym) = self {
+        if let LiteralKind::String(sym) = &mut self.kind {
             visitor.visit_sym_mut(sym)
         } else {
             ControlFlow::Continue(())
@@ -9,6 +9,7 @@ use crate::{
     property::{MethodDefinition, PropertyDefinition, PropertyName},
     try_break,
     visitor::{VisitWith, Visitor, VisitorMut},
+    NodeSpan, Span, Spanned,
 };
 use boa_interner::{Interner, Sym, ToIndentedString, ToInternedString};
 use core::ops::ControlFlow;
@@ -33,11 +34,11 @@ use core::ops::ControlFlow;
 /// [object]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object
 /// [primitive]: https://developer.mozilla.org/en-US/docs/Glossary/primitive
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(transparent))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Clone, Debug, PartialEq)]
 pub struct ObjectLiteral {
     properties: Box<[PropertyDefinition]>,
+    span: NodeSpan,
 }
 
 impl ObjectLiteral {
@@ -71,7 +72,7 @@ impl ObjectLiteral {
                     });
                 }
                 PropertyDefinition::Property(name, expr) => match (name, expr) {
-                    (PropertyName::Literal(name), Expression::Identifier(ident))
+                    (PropertyName::Literal(name), Expression::Identifier(ident, _))
                         if *name == *ident =>
                     {
                         if strict && *name == Sym::EVAL {
@@ -88,7 +89,7 @@ impl ObjectLiteral {
                             default_init: None,
                         });
                     }
-                    (PropertyName::Literal(name), Expression::Identifier(ident)) => {
+                    (PropertyName::Literal(name), Expression::Identifier(ident, _)) => {
                         bindings.push(ObjectPatternElement::SingleName {
                             ident: *ident,
                             name: PropertyName::Literal(*name),
@@ -112,7 +113,7 @@ impl ObjectLiteral {
                         });
                     }
                     (_, Expression::Assign(assign)) => match assign.lhs() {
-                        AssignTarget::Identifier(ident) => {
+                        AssignTarget::Identifier(ident, _) => {
                             if let Some(name) = name.literal() {
                                 if name == *ident {
                                     if strict && name == Sym::EVAL {
@@ -154,7 +155,7 @@ impl ObjectLiteral {
                             default_init: None,
                         });
                     }
-                    (PropertyName::Computed(name), Expression::Identifier(ident)) => {
+                    (PropertyName::Computed(name), Expression::Identifier(ident, _)) => {
                         bindings.push(ObjectPatternElement::SingleName {
                             ident: *ident,
                             name: PropertyName::Computed(name.clone()),
@@ -165,7 +166,7 @@ impl ObjectLiteral {
                 },
                 PropertyDefinition::SpreadObject(spread) => {
                     match spread {
-                        Expression::Identifier(ident) => {
+                        Expression::Identifier(ident, _) => {
                             bindings.push(ObjectPatternElement::RestProperty {
                                 ident: *ident,
                                 excluded_keys: excluded_keys.clone(),
@@ -198,7 +199,26 @@ impl ObjectLiteral {
             }
         }
 
-        Some(ObjectPattern::new(bindings.into()))
+        let pattern = ObjectPattern::new(bindings.into());
+        Some(match self.span.get() {
+            Some(span) => pattern.with_span(span),
+            None => pattern,
+        })
+    }
+
+    /// Sets the location of this object literal in the source code.
+    #[inline]
+    #[must_use]
+    pub const fn with_span(mut self, span: Span) -> Self {
+        self.span = NodeSpan::new(span);
+        self
+    }
+}
+
+impl Spanned for ObjectLiteral {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        self.span.get()
     }
 }
 
@@ -308,6 +328,7 @@ where
     fn from(props: T) -> Self {
         Self {
             properties: props.into(),
+            span: NodeSpan::none(),
         }
     }
 }
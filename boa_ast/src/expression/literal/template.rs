@@ -9,7 +9,7 @@ use crate::{
     expression::Expression,
     try_break,
     visitor::{VisitWith, Visitor, VisitorMut},
-    ToStringEscaped,
+    NodeSpan, Span, Spanned, ToStringEscaped,
 };
 
 /// Template literals are string literals allowing embedded expressions.
@@ -25,6 +25,7 @@ use crate::{
 #[derive(Clone, Debug, PartialEq)]
 pub struct TemplateLiteral {
     elements: Box<[TemplateElement]>,
+    span: NodeSpan,
 }
 
 impl From<TemplateLiteral> for Expression {
@@ -55,7 +56,10 @@ impl TemplateLiteral {
     #[inline]
     #[must_use]
     pub fn new(elements: Box<[TemplateElement]>) -> Self {
-        Self { elements }
+        Self {
+            elements,
+            span: NodeSpan::none(),
+        }
     }
 
     /// Gets the element list of this `TemplateLiteral`.
@@ -63,6 +67,21 @@ impl TemplateLiteral {
     pub const fn elements(&self) -> &[TemplateElement] {
         &self.elements
     }
+
+    /// Sets the location of this template literal in the source code.
+    #[inline]
+    #[must_use]
+    pub const fn with_span(mut self, span: Span) -> Self {
+        self.span = NodeSpan::new(span);
+        self
+    }
+}
+
+impl Spanned for TemplateLiteral {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        self.span.get()
+    }
 }
 
 impl ToInternedString for TemplateLiteral {
@@ -33,6 +33,7 @@ mod tagged_template;
 mod r#yield;
 
 use crate::visitor::{VisitWith, Visitor, VisitorMut};
+use crate::{NodeSpan, Span, Spanned};
 pub use call::{Call, ImportCall, SuperCall};
 pub use identifier::{Identifier, RESERVED_IDENTIFIERS_STRICT};
 pub use new::New;
@@ -66,10 +67,10 @@ pub enum Expression {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-this-keyword
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/this
-    This,
+    This(NodeSpan),
 
     /// See [`Identifier`].
-    Identifier(Identifier),
+    Identifier(Identifier, NodeSpan),
 
     /// See [`Literal`].
     Literal(Literal),
@@ -131,10 +132,10 @@ pub enum Expression {
     TaggedTemplate(TaggedTemplate),
 
     /// The `new.target` pseudo-property expression.
-    NewTarget,
+    NewTarget(NodeSpan),
 
     /// The `import.meta` pseudo-property expression.
-    ImportMeta,
+    ImportMeta(NodeSpan),
 
     /// See [`Assign`].
     Assign(Assign),
@@ -178,8 +179,8 @@ impl Expression {
     /// indents, use [`to_indented_string()`](Self::to_indented_string).
     pub(crate) fn to_no_indent_string(&self, interner: &Interner, indentation: usize) -> String {
         match self {
-            Self::This => "this".to_owned(),
-            Self::Identifier(id) => id.to_interned_string(interner),
+            Self::This(_) => "this".to_owned(),
+            Self::Identifier(id, _) => id.to_interned_string(interner),
             Self::Literal(lit) => lit.to_interned_string(interner),
             Self::ArrayLiteral(arr) => arr.to_interned_string(interner),
             Self::ObjectLiteral(o) => o.to_indented_string(interner, indentation),
@@ -198,8 +199,8 @@ impl Expression {
             Self::SuperCall(supc) => supc.to_interned_string(interner),
             Self::ImportCall(impc) => impc.to_interned_string(interner),
             Self::Optional(opt) => opt.to_interned_string(interner),
-            Self::NewTarget => "new.target".to_owned(),
-            Self::ImportMeta => "import.meta".to_owned(),
+            Self::NewTarget(_) => "new.target".to_owned(),
+            Self::ImportMeta(_) => "import.meta".to_owned(),
             Self::TaggedTemplate(tag) => tag.to_interned_string(interner),
             Self::Assign(assign) => assign.to_interned_string(interner),
             Self::Unary(unary) => unary.to_interned_string(interner),
@@ -283,13 +284,52 @@ impl ToIndentedString for Expression {
     }
 }
 
+impl Spanned for Expression {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        match self {
+            Self::This(span) | Self::NewTarget(span) | Self::ImportMeta(span) => span.get(),
+            Self::FormalParameterList(_) => None,
+            Self::Identifier(_, span) => span.get(),
+            Self::Literal(lit) => lit.span(),
+            Self::ArrayLiteral(arr) => arr.span(),
+            Self::ObjectLiteral(o) => o.span(),
+            Self::Spread(sp) => sp.span(),
+            Self::Function(f) => f.span(),
+            Self::ArrowFunction(f) => f.span(),
+            Self::AsyncArrowFunction(f) => f.span(),
+            Self::Generator(f) => f.span(),
+            Self::AsyncFunction(f) => f.span(),
+            Self::AsyncGenerator(f) => f.span(),
+            Self::Class(c) => c.span(),
+            Self::TemplateLiteral(t) => t.span(),
+            Self::PropertyAccess(pa) => pa.span(),
+            Self::New(n) => n.span(),
+            Self::Call(c) => c.span(),
+            Self::SuperCall(c) => c.span(),
+            Self::ImportCall(c) => c.span(),
+            Self::Optional(o) => o.span(),
+            Self::TaggedTemplate(t) => t.span(),
+            Self::Assign(a) => a.span(),
+            Self::Unary(u) => u.span(),
+            Self::Update(u) => u.span(),
+            Self::Binary(b) => b.span(),
+            Self::BinaryInPrivate(b) => b.span(),
+            Self::Conditional(c) => c.span(),
+            Self::Await(a) => a.span(),
+            Self::Yield(y) => y.span(),
+            Self::Parenthesized(p) => p.span(),
+        }
+    }
+}
+
 impl VisitWith for Expression {
     fn visit_with<'a, V>(&'a self, visitor: &mut V) -> ControlFlow<V::BreakTy>
     where
         V: Visitor<'a>,
     {
         match self {
-            Self::Identifier(id) => visitor.visit_identifier(id),
+            Self::Identifier(id, _) => visitor.visit_identifier(id),
             Self::Literal(lit) => visitor.visit_literal(lit),
             Self::ArrayLiteral(arlit) => visitor.visit_array_literal(arlit),
             Self::ObjectLiteral(olit) => visitor.visit_object_literal(olit),
@@ -319,7 +359,7 @@ impl VisitWith for Expression {
             Self::Yield(y) => visitor.visit_yield(y),
             Self::Parenthesized(e) => visitor.visit_parenthesized(e),
             Self::FormalParameterList(fpl) => visitor.visit_formal_parameter_list(fpl),
-            Self::This | Self::NewTarget | Self::ImportMeta => {
+            Self::This(_) | Self::NewTarget(_) | Self::ImportMeta(_) => {
                 // do nothing; can be handled as special case by visitor
                 ControlFlow::Continue(())
             }
@@ -331,7 +371,7 @@ impl VisitWith for Expression {
         V: VisitorMut<'a>,
     {
         match self {
-            Self::Identifier(id) => visitor.visit_identifier_mut(id),
+            Self::Identifier(id, _) => visitor.visit_identifier_mut(id),
             Self::Literal(lit) => visitor.visit_literal_mut(lit),
             Self::ArrayLiteral(arlit) => visitor.visit_array_literal_mut(arlit),
             Self::ObjectLiteral(olit) => visitor.visit_object_literal_mut(olit),
@@ -361,7 +401,7 @@ impl VisitWith for Expression {
             Self::Yield(y) => visitor.visit_yield_mut(y),
             Self::Parenthesized(e) => visitor.visit_parenthesized_mut(e),
             Self::FormalParameterList(fpl) => visitor.visit_formal_parameter_list_mut(fpl),
-            Self::This | Self::NewTarget | Self::ImportMeta => {
+            Self::This(_) | Self::NewTarget(_) | Self::ImportMeta(_) => {
                 // do nothing; can be handled as special case by visitor
                 ControlFlow::Continue(())
             }
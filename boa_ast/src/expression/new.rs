@@ -4,6 +4,7 @@ use boa_interner::{Interner, ToInternedString};
 use core::ops::ControlFlow;
 
 use super::Expression;
+use crate::{Span, Spanned};
 
 /// The `new` operator lets developers create an instance of a user-defined object type or of
 /// one of the built-in object types that has a constructor function.
@@ -70,6 +71,13 @@ impl From<New> for Expression {
     }
 }
 
+impl Spanned for New {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        self.call.span()
+    }
+}
+
 impl VisitWith for New {
     fn visit_with<'a, V>(&'a self, visitor: &mut V) -> ControlFlow<V::BreakTy>
     where
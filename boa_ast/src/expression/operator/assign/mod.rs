@@ -22,6 +22,7 @@ use crate::{
     pattern::Pattern,
     try_break,
     visitor::{VisitWith, Visitor, VisitorMut},
+    NodeSpan, Span, Spanned,
 };
 
 /// An assignment operator expression.
@@ -34,6 +35,7 @@ pub struct Assign {
     op: AssignOp,
     lhs: Box<AssignTarget>,
     rhs: Box<Expression>,
+    span: NodeSpan,
 }
 
 impl Assign {
@@ -45,6 +47,7 @@ impl Assign {
             op,
             lhs: Box::new(lhs),
             rhs: Box::new(rhs),
+            span: NodeSpan::none(),
         }
     }
 
@@ -68,6 +71,21 @@ impl Assign {
     pub const fn rhs(&self) -> &Expression {
         &self.rhs
     }
+
+    /// Sets the location of this assignment in the source code.
+    #[inline]
+    #[must_use]
+    pub const fn with_span(mut self, span: Span) -> Self {
+        self.span = NodeSpan::new(span);
+        self
+    }
+}
+
+impl Spanned for Assign {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        self.span.get()
+    }
 }
 
 impl ToInternedString for Assign {
@@ -116,7 +134,7 @@ impl VisitWith for Assign {
 #[derive(Clone, Debug, PartialEq)]
 pub enum AssignTarget {
     /// A simple identifier, such as `a`.
-    Identifier(Identifier),
+    Identifier(Identifier, NodeSpan),
     /// A property access, such as `a.prop`.
     Access(PropertyAccess),
     /// A pattern assignment, such as `{a, b, ...c}`.
@@ -148,12 +166,12 @@ impl AssignTarget {
     #[must_use]
     pub fn from_expression_simple(expression: &Expression, strict: bool) -> Option<Self> {
         match expression {
-            Expression::Identifier(id)
+            Expression::Identifier(id, _)
                 if strict && (id.sym() == Sym::EVAL || id.sym() == Sym::ARGUMENTS) =>
             {
                 None
             }
-            Expression::Identifier(id) => Some(Self::Identifier(*id)),
+            Expression::Identifier(id, span) => Some(Self::Identifier(*id, *span)),
             Expression::PropertyAccess(access) => Some(Self::Access(access.clone())),
             Expression::Parenthesized(p) => Self::from_expression_simple(p.expression(), strict),
             _ => None,
@@ -165,7 +183,7 @@ impl ToInternedString for AssignTarget {
     #[inline]
     fn to_interned_string(&self, interner: &Interner) -> String {
         match self {
-            Self::Identifier(id) => id.to_interned_string(interner),
+            Self::Identifier(id, _) => id.to_interned_string(interner),
             Self::Access(access) => access.to_interned_string(interner),
             Self::Pattern(pattern) => pattern.to_interned_string(interner),
         }
@@ -175,7 +193,18 @@ impl ToInternedString for AssignTarget {
 impl From<Identifier> for AssignTarget {
     #[inline]
     fn from(target: Identifier) -> Self {
-        Self::Identifier(target)
+        Self::Identifier(target, NodeSpan::none())
+    }
+}
+
+impl Spanned for AssignTarget {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        match self {
+            Self::Identifier(_, span) => span.get(),
+            Self::Access(access) => access.span(),
+            Self::Pattern(pattern) => pattern.span(),
+        }
     }
 }
 
@@ -185,7 +214,7 @@ impl VisitWith for AssignTarget {
         V: Visitor<'a>,
     {
         match self {
-            Self::Identifier(id) => visitor.visit_identifier(id),
+            Self::Identifier(id, _) => visitor.visit_identifier(id),
             Self::Access(pa) => visitor.visit_property_access(pa),
             Self::Pattern(pat) => visitor.visit_pattern(pat),
         }
@@ -196,7 +225,7 @@ impl VisitWith for AssignTarget {
         V: VisitorMut<'a>,
     {
         match self {
-            Self::Identifier(id) => visitor.visit_identifier_mut(id),
+            Self::Identifier(id, _) => visitor.visit_identifier_mut(id),
             Self::Access(pa) => visitor.visit_property_access_mut(pa),
             Self::Pattern(pat) => visitor.visit_pattern_mut(pat),
         }
@@ -21,6 +21,7 @@ use crate::{
     function::PrivateName,
     try_break,
     visitor::{VisitWith, Visitor, VisitorMut},
+    NodeSpan, Span, Spanned,
 };
 use boa_interner::{Interner, ToInternedString};
 use core::ops::ControlFlow;
@@ -37,6 +38,7 @@ pub struct Binary {
     op: BinaryOp,
     lhs: Box<Expression>,
     rhs: Box<Expression>,
+    span: NodeSpan,
 }
 
 impl Binary {
@@ -48,6 +50,7 @@ impl Binary {
             op,
             lhs: Box::new(lhs),
             rhs: Box::new(rhs),
+            span: NodeSpan::none(),
         }
     }
 
@@ -85,6 +88,21 @@ impl Binary {
     pub fn rhs_mut(&mut self) -> &mut Expression {
         &mut self.rhs
     }
+
+    /// Sets the location of this binary operation in the source code.
+    #[inline]
+    #[must_use]
+    pub const fn with_span(mut self, span: Span) -> Self {
+        self.span = NodeSpan::new(span);
+        self
+    }
+}
+
+impl Spanned for Binary {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        self.span.get()
+    }
 }
 
 impl ToInternedString for Binary {
@@ -135,6 +153,7 @@ impl VisitWith for Binary {
 pub struct BinaryInPrivate {
     lhs: PrivateName,
     rhs: Box<Expression>,
+    span: NodeSpan,
 }
 
 impl BinaryInPrivate {
@@ -145,6 +164,7 @@ impl BinaryInPrivate {
         Self {
             lhs,
             rhs: Box::new(rhs),
+            span: NodeSpan::none(),
         }
     }
 
@@ -161,6 +181,21 @@ impl BinaryInPrivate {
     pub const fn rhs(&self) -> &Expression {
         &self.rhs
     }
+
+    /// Sets the location of this private `in` check in the source code.
+    #[inline]
+    #[must_use]
+    pub const fn with_span(mut self, span: Span) -> Self {
+        self.span = NodeSpan::new(span);
+        self
+    }
+}
+
+impl Spanned for BinaryInPrivate {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        self.span.get()
+    }
 }
 
 impl ToInternedString for BinaryInPrivate {
@@ -2,6 +2,7 @@ use crate::{
     expression::Expression,
     try_break,
     visitor::{VisitWith, Visitor, VisitorMut},
+    NodeSpan, Span, Spanned,
 };
 use boa_interner::{Interner, ToInternedString};
 use core::ops::ControlFlow;
@@ -27,6 +28,7 @@ pub struct Conditional {
     condition: Box<Expression>,
     if_true: Box<Expression>,
     if_false: Box<Expression>,
+    span: NodeSpan,
 }
 
 impl Conditional {
@@ -59,8 +61,24 @@ impl Conditional {
             condition: Box::new(condition),
             if_true: Box::new(if_true),
             if_false: Box::new(if_false),
+            span: NodeSpan::none(),
         }
     }
+
+    /// Sets the location of this conditional expression in the source code.
+    #[inline]
+    #[must_use]
+    pub const fn with_span(mut self, span: Span) -> Self {
+        self.span = NodeSpan::new(span);
+        self
+    }
+}
+
+impl Spanned for Conditional {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        self.span.get()
+    }
 }
 
 impl ToInternedString for Conditional {
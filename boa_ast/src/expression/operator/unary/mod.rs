@@ -14,6 +14,7 @@ mod op;
 use crate::{
     expression::Expression,
     visitor::{VisitWith, Visitor, VisitorMut},
+    NodeSpan, Span, Spanned,
 };
 use boa_interner::{Interner, ToInternedString};
 use core::ops::ControlFlow;
@@ -34,6 +35,7 @@ pub use op::*;
 pub struct Unary {
     op: UnaryOp,
     target: Box<Expression>,
+    span: NodeSpan,
 }
 
 impl Unary {
@@ -44,6 +46,7 @@ impl Unary {
         Self {
             op,
             target: Box::new(target),
+            span: NodeSpan::none(),
         }
     }
 
@@ -67,6 +70,21 @@ impl Unary {
     pub fn target_mut(&mut self) -> &mut Expression {
         self.target.as_mut()
     }
+
+    /// Sets the location of this unary operation in the source code.
+    #[inline]
+    #[must_use]
+    pub const fn with_span(mut self, span: Span) -> Self {
+        self.span = NodeSpan::new(span);
+        self
+    }
+}
+
+impl Spanned for Unary {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        self.span.get()
+    }
 }
 
 impl ToInternedString for Unary {
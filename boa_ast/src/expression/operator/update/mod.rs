@@ -12,7 +12,7 @@ mod op;
 use crate::{
     expression::{access::PropertyAccess, Identifier},
     visitor::{VisitWith, Visitor, VisitorMut},
-    Expression,
+    Expression, NodeSpan, Span, Spanned,
 };
 use boa_interner::{Interner, ToInternedString};
 use core::ops::ControlFlow;
@@ -33,6 +33,7 @@ pub use op::*;
 pub struct Update {
     op: UpdateOp,
     target: Box<UpdateTarget>,
+    span: NodeSpan,
 }
 
 impl Update {
@@ -43,6 +44,7 @@ impl Update {
         Self {
             op,
             target: Box::new(target),
+            span: NodeSpan::none(),
         }
     }
 
@@ -59,6 +61,21 @@ impl Update {
     pub fn target(&self) -> &UpdateTarget {
         self.target.as_ref()
     }
+
+    /// Sets the location of this update operation in the source code.
+    #[inline]
+    #[must_use]
+    pub const fn with_span(mut self, span: Span) -> Self {
+        self.span = NodeSpan::new(span);
+        self
+    }
+}
+
+impl Spanned for Update {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        self.span.get()
+    }
 }
 
 impl ToInternedString for Update {
@@ -88,7 +105,7 @@ impl VisitWith for Update {
         V: Visitor<'a>,
     {
         match self.target.as_ref() {
-            UpdateTarget::Identifier(ident) => visitor.visit_identifier(ident),
+            UpdateTarget::Identifier(ident, _) => visitor.visit_identifier(ident),
             UpdateTarget::PropertyAccess(access) => visitor.visit_property_access(access),
         }
     }
@@ -98,7 +115,7 @@ impl VisitWith for Update {
         V: VisitorMut<'a>,
     {
         match &mut *self.target {
-            UpdateTarget::Identifier(ident) => visitor.visit_identifier_mut(ident),
+            UpdateTarget::Identifier(ident, _) => visitor.visit_identifier_mut(ident),
             UpdateTarget::PropertyAccess(access) => visitor.visit_property_access_mut(access),
         }
     }
@@ -117,17 +134,34 @@ impl VisitWith for Update {
 #[derive(Clone, Debug, PartialEq)]
 pub enum UpdateTarget {
     /// An [`Identifier`] expression.
-    Identifier(Identifier),
+    Identifier(Identifier, NodeSpan),
 
     /// An [`PropertyAccess`] expression.
     PropertyAccess(PropertyAccess),
 }
 
+impl From<Identifier> for UpdateTarget {
+    #[inline]
+    fn from(target: Identifier) -> Self {
+        Self::Identifier(target, NodeSpan::none())
+    }
+}
+
+impl Spanned for UpdateTarget {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        match self {
+            Self::Identifier(_, span) => span.get(),
+            Self::PropertyAccess(access) => access.span(),
+        }
+    }
+}
+
 impl ToInternedString for UpdateTarget {
     #[inline]
     fn to_interned_string(&self, interner: &Interner) -> String {
         match self {
-            Self::Identifier(identifier) => identifier.to_interned_string(interner),
+            Self::Identifier(identifier, _) => identifier.to_interned_string(interner),
             Self::PropertyAccess(access) => access.to_interned_string(interner),
         }
     }
@@ -3,6 +3,7 @@ use crate::{
     function::PrivateName,
     join_nodes, try_break,
     visitor::{VisitWith, Visitor, VisitorMut},
+    NodeSpan, Span, Spanned,
 };
 use boa_interner::{Interner, ToInternedString};
 use core::ops::ControlFlow;
@@ -178,6 +179,7 @@ impl VisitWith for OptionalOperation {
 pub struct Optional {
     target: Box<Expression>,
     chain: Box<[OptionalOperation]>,
+    span: NodeSpan,
 }
 
 impl VisitWith for Optional {
@@ -212,6 +214,7 @@ impl Optional {
         Self {
             target: Box::new(target),
             chain,
+            span: NodeSpan::none(),
         }
     }
 
@@ -228,6 +231,21 @@ impl Optional {
     pub fn chain(&self) -> &[OptionalOperation] {
         self.chain.as_ref()
     }
+
+    /// Sets the location of this optional chain in the source code.
+    #[inline]
+    #[must_use]
+    pub const fn with_span(mut self, span: Span) -> Self {
+        self.span = NodeSpan::new(span);
+        self
+    }
+}
+
+impl Spanned for Optional {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        self.span.get()
+    }
 }
 
 impl From<Optional> for Expression {
@@ -1,5 +1,6 @@
 use super::Expression;
 use crate::visitor::{VisitWith, Visitor, VisitorMut};
+use crate::{NodeSpan, Span, Spanned};
 use boa_interner::{Interner, ToInternedString};
 use core::ops::ControlFlow;
 
@@ -16,6 +17,7 @@ use core::ops::ControlFlow;
 #[derive(Clone, Debug, PartialEq)]
 pub struct Parenthesized {
     expression: Box<Expression>,
+    span: NodeSpan,
 }
 
 impl Parenthesized {
@@ -25,6 +27,7 @@ impl Parenthesized {
     pub fn new(expression: Expression) -> Self {
         Self {
             expression: Box::new(expression),
+            span: NodeSpan::none(),
         }
     }
 
@@ -34,6 +37,21 @@ impl Parenthesized {
     pub const fn expression(&self) -> &Expression {
         &self.expression
     }
+
+    /// Sets the location of this parenthesized expression in the source code.
+    #[inline]
+    #[must_use]
+    pub const fn with_span(mut self, span: Span) -> Self {
+        self.span = NodeSpan::new(span);
+        self
+    }
+}
+
+impl Spanned for Parenthesized {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        self.span.get()
+    }
 }
 
 impl From<Parenthesized> for Expression {
@@ -4,6 +4,7 @@ use core::ops::ControlFlow;
 use crate::visitor::{VisitWith, Visitor, VisitorMut};
 
 use super::Expression;
+use crate::{NodeSpan, Span, Spanned};
 
 /// The `spread` operator allows an iterable such as an array expression or string to be
 /// expanded.
@@ -22,11 +23,11 @@ use super::Expression;
 /// [spec]: https://tc39.es/ecma262/#prod-SpreadElement
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Spread_syntax
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(transparent))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Clone, Debug, PartialEq)]
 pub struct Spread {
     target: Box<Expression>,
+    span: NodeSpan,
 }
 
 impl Spread {
@@ -43,8 +44,24 @@ impl Spread {
     pub fn new(target: Expression) -> Self {
         Self {
             target: Box::new(target),
+            span: NodeSpan::none(),
         }
     }
+
+    /// Sets the location of this spread expression in the source code.
+    #[inline]
+    #[must_use]
+    pub const fn with_span(mut self, span: Span) -> Self {
+        self.span = NodeSpan::new(span);
+        self
+    }
+}
+
+impl Spanned for Spread {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        self.span.get()
+    }
 }
 
 impl ToInternedString for Spread {
@@ -5,6 +5,7 @@ use crate::try_break;
 use crate::visitor::{VisitWith, Visitor, VisitorMut};
 
 use super::Expression;
+use crate::{NodeSpan, Span, Spanned};
 
 /// A [`TaggedTemplate`][moz] expression, as defined by the [spec].
 ///
@@ -22,6 +23,7 @@ pub struct TaggedTemplate {
     cookeds: Box<[Option<Sym>]>,
     exprs: Box<[Expression]>,
     identifier: u64,
+    span: NodeSpan,
 }
 
 impl TaggedTemplate {
@@ -42,6 +44,7 @@ impl TaggedTemplate {
             cookeds,
             exprs,
             identifier,
+            span: NodeSpan::none(),
         }
     }
 
@@ -79,6 +82,21 @@ impl TaggedTemplate {
     pub const fn identifier(&self) -> u64 {
         self.identifier
     }
+
+    /// Sets the location of this tagged template in the source code.
+    #[inline]
+    #[must_use]
+    pub const fn with_span(mut self, span: Span) -> Self {
+        self.span = NodeSpan::new(span);
+        self
+    }
+}
+
+impl Spanned for TaggedTemplate {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        self.span.get()
+    }
 }
 
 impl ToInternedString for TaggedTemplate {
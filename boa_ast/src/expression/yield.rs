@@ -4,6 +4,7 @@ use core::ops::ControlFlow;
 use crate::visitor::{VisitWith, Visitor, VisitorMut};
 
 use super::Expression;
+use crate::{NodeSpan, Span, Spanned};
 
 /// The `yield` keyword is used to pause and resume a generator function
 ///
@@ -19,6 +20,7 @@ use super::Expression;
 pub struct Yield {
     target: Option<Box<Expression>>,
     delegate: bool,
+    span: NodeSpan,
 }
 
 impl Yield {
@@ -42,8 +44,24 @@ impl Yield {
         Self {
             target: expr.map(Box::new),
             delegate,
+            span: NodeSpan::none(),
         }
     }
+
+    /// Sets the location of this `yield` expression in the source code.
+    #[inline]
+    #[must_use]
+    pub const fn with_span(mut self, span: Span) -> Self {
+        self.span = NodeSpan::new(span);
+        self
+    }
+}
+
+impl Spanned for Yield {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        self.span.get()
+    }
 }
 
 impl From<Yield> for Expression {
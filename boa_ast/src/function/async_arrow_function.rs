@@ -5,7 +5,7 @@ use crate::try_break;
 use crate::visitor::{VisitWith, Visitor, VisitorMut};
 use crate::{
     expression::{Expression, Identifier},
-    join_nodes,
+    join_nodes, NodeSpan, Span, Spanned,
 };
 use boa_interner::{Interner, ToIndentedString};
 
@@ -25,6 +25,7 @@ pub struct AsyncArrowFunction {
     name: Option<Identifier>,
     parameters: FormalParameterList,
     body: FunctionBody,
+    span: NodeSpan,
 }
 
 impl AsyncArrowFunction {
@@ -40,6 +41,7 @@ impl AsyncArrowFunction {
             name,
             parameters,
             body,
+            span: NodeSpan::none(),
         }
     }
 
@@ -69,6 +71,21 @@ impl AsyncArrowFunction {
     pub const fn body(&self) -> &FunctionBody {
         &self.body
     }
+
+    /// Sets the location of this async arrow function in the source code.
+    #[inline]
+    #[must_use]
+    pub const fn with_span(mut self, span: Span) -> Self {
+        self.span = NodeSpan::new(span);
+        self
+    }
+}
+
+impl Spanned for AsyncArrowFunction {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        self.span.get()
+    }
 }
 
 impl ToIndentedString for AsyncArrowFunction {
@@ -4,7 +4,7 @@ use crate::try_break;
 use crate::visitor::{VisitWith, Visitor, VisitorMut};
 use crate::{
     expression::{Expression, Identifier},
-    join_nodes, Declaration,
+    join_nodes, Declaration, NodeSpan, Span, Spanned,
 };
 use boa_interner::{Interner, ToIndentedString};
 use core::ops::ControlFlow;
@@ -27,6 +27,7 @@ pub struct AsyncFunction {
     parameters: FormalParameterList,
     body: FunctionBody,
     has_binding_identifier: bool,
+    span: NodeSpan,
 }
 
 impl AsyncFunction {
@@ -44,6 +45,7 @@ impl AsyncFunction {
             parameters,
             body,
             has_binding_identifier,
+            span: NodeSpan::none(),
         }
     }
 
@@ -74,6 +76,21 @@ impl AsyncFunction {
     pub const fn has_binding_identifier(&self) -> bool {
         self.has_binding_identifier
     }
+
+    /// Sets the location of this async function in the source code.
+    #[inline]
+    #[must_use]
+    pub const fn with_span(mut self, span: Span) -> Self {
+        self.span = NodeSpan::new(span);
+        self
+    }
+}
+
+impl Spanned for AsyncFunction {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        self.span.get()
+    }
 }
 
 impl ToIndentedString for AsyncFunction {
@@ -4,7 +4,7 @@ use crate::visitor::{VisitWith, Visitor, VisitorMut};
 use crate::{
     block_to_string,
     expression::{Expression, Identifier},
-    join_nodes, Declaration,
+    join_nodes, Declaration, NodeSpan, Span, Spanned,
 };
 use boa_interner::{Interner, ToIndentedString};
 use core::ops::ControlFlow;
@@ -26,6 +26,7 @@ pub struct AsyncGenerator {
     parameters: FormalParameterList,
     body: FunctionBody,
     has_binding_identifier: bool,
+    span: NodeSpan,
 }
 
 impl AsyncGenerator {
@@ -43,6 +44,7 @@ impl AsyncGenerator {
             parameters,
             body,
             has_binding_identifier,
+            span: NodeSpan::none(),
         }
     }
 
@@ -73,6 +75,21 @@ impl AsyncGenerator {
     pub const fn has_binding_identifier(&self) -> bool {
         self.has_binding_identifier
     }
+
+    /// Sets the location of this async generator in the source code.
+    #[inline]
+    #[must_use]
+    pub const fn with_span(mut self, span: Span) -> Self {
+        self.span = NodeSpan::new(span);
+        self
+    }
+}
+
+impl Spanned for AsyncGenerator {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        self.span.get()
+    }
 }
 
 impl ToIndentedString for AsyncGenerator {
@@ -6,7 +6,7 @@ use crate::{
     property::{MethodDefinition, PropertyName},
     try_break,
     visitor::{VisitWith, Visitor, VisitorMut},
-    Declaration, ToStringEscaped,
+    Declaration, NodeSpan, Span, Spanned, ToStringEscaped,
 };
 use boa_interner::{Interner, Sym, ToIndentedString, ToInternedString};
 use core::ops::ControlFlow;
@@ -28,7 +28,9 @@ pub struct Class {
     super_ref: Option<Expression>,
     pub(crate) constructor: Option<Function>,
     pub(crate) elements: Box<[ClassElement]>,
+    pub(crate) decorators: Box<[Decorator]>,
     has_binding_identifier: bool,
+    span: NodeSpan,
 }
 
 impl Class {
@@ -47,10 +49,20 @@ impl Class {
             super_ref,
             constructor,
             elements,
+            decorators: Box::default(),
             has_binding_identifier,
+            span: NodeSpan::none(),
         }
     }
 
+    /// Sets the decorators applied to this class.
+    #[inline]
+    #[must_use]
+    pub fn with_decorators(mut self, decorators: Box<[Decorator]>) -> Self {
+        self.decorators = decorators;
+        self
+    }
+
     /// Returns the name of the class.
     #[inline]
     #[must_use]
@@ -79,12 +91,34 @@ impl Class {
         &self.elements
     }
 
+    /// Gets the list of decorators applied to the class.
+    #[inline]
+    #[must_use]
+    pub const fn decorators(&self) -> &[Decorator] {
+        &self.decorators
+    }
+
     /// Returns whether the class has a binding identifier.
     #[inline]
     #[must_use]
     pub const fn has_binding_identifier(&self) -> bool {
         self.has_binding_identifier
     }
+
+    /// Sets the location of this class in the source code.
+    #[inline]
+    #[must_use]
+    pub const fn with_span(mut self, span: Span) -> Self {
+        self.span = NodeSpan::new(span);
+        self
+    }
+}
+
+impl Spanned for Class {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        self.span.get()
+    }
 }
 
 impl ToIndentedString for Class {
@@ -96,9 +130,14 @@ impl ToIndentedString for Class {
                 true,
             )
         });
+        let mut decorators = String::new();
+        for decorator in &*self.decorators {
+            decorators.push_str(&decorator.to_interned_string(interner));
+            decorators.push(' ');
+        }
         if self.elements.is_empty() && self.constructor().is_none() {
             return format!(
-                "class {class_name}{} {{}}",
+                "{decorators}class {class_name}{} {{}}",
                 self.super_ref
                     .as_ref()
                     .map_or_else(String::new, |sup| format!(
@@ -109,7 +148,7 @@ impl ToIndentedString for Class {
         }
         let indentation = "    ".repeat(indent_n + 1);
         let mut buf = format!(
-            "class {class_name}{} {{\n",
+            "{decorators}class {class_name}{} {{\n",
             self.super_ref
                 .as_ref()
                 .map_or_else(String::new, |sup| format!(
@@ -125,6 +164,15 @@ impl ToIndentedString for Class {
             ));
         }
         for element in self.elements.iter() {
+            let mut element = element;
+            while let ClassElement::Decorated(decorators, inner) = element {
+                for decorator in &**decorators {
+                    buf.push_str(&indentation);
+                    buf.push_str(&decorator.to_interned_string(interner));
+                    buf.push('\n');
+                }
+                element = inner;
+            }
             buf.push_str(&match element {
                 ClassElement::MethodDefinition(name, method) => {
                     format!(
@@ -355,12 +403,73 @@ impl ToIndentedString for Class {
                         )
                     }
                 },
+                ClassElement::AutoAccessorDefinition(name, field) => match field {
+                    Some(expr) => {
+                        format!(
+                            "{indentation}accessor {} = {};\n",
+                            name.to_interned_string(interner),
+                            expr.to_no_indent_string(interner, indent_n + 1)
+                        )
+                    }
+                    None => {
+                        format!(
+                            "{indentation}accessor {};\n",
+                            name.to_interned_string(interner)
+                        )
+                    }
+                },
+                ClassElement::StaticAutoAccessorDefinition(name, field) => match field {
+                    Some(expr) => {
+                        format!(
+                            "{indentation}static accessor {} = {};\n",
+                            name.to_interned_string(interner),
+                            expr.to_no_indent_string(interner, indent_n + 1)
+                        )
+                    }
+                    None => {
+                        format!(
+                            "{indentation}static accessor {};\n",
+                            name.to_interned_string(interner)
+                        )
+                    }
+                },
+                ClassElement::PrivateAutoAccessorDefinition(name, field) => match field {
+                    Some(expr) => {
+                        format!(
+                            "{indentation}accessor #{} = {};\n",
+                            interner.resolve_expect(name.description()),
+                            expr.to_no_indent_string(interner, indent_n + 1)
+                        )
+                    }
+                    None => {
+                        format!(
+                            "{indentation}accessor #{};\n",
+                            interner.resolve_expect(name.description()),
+                        )
+                    }
+                },
+                ClassElement::PrivateStaticAutoAccessorDefinition(name, field) => match field {
+                    Some(expr) => {
+                        format!(
+                            "{indentation}static accessor #{} = {};\n",
+                            interner.resolve_expect(name.description()),
+                            expr.to_no_indent_string(interner, indent_n + 1)
+                        )
+                    }
+                    None => {
+                        format!(
+                            "{indentation}static accessor #{};\n",
+                            interner.resolve_expect(name.description()),
+                        )
+                    }
+                },
                 ClassElement::StaticBlock(body) => {
                     format!(
                         "{indentation}static {}\n",
                         block_to_string(body.statements(), interner, indent_n + 1)
                     )
                 }
+                ClassElement::Decorated(..) => unreachable!("decorators were printed above"),
             });
         }
         buf.push('}');
@@ -385,6 +494,9 @@ impl VisitWith for Class {
     where
         V: Visitor<'a>,
     {
+        for decorator in self.decorators.iter() {
+            try_break!(visitor.visit_decorator(decorator));
+        }
         if let Some(ident) = &self.name {
             try_break!(visitor.visit_identifier(ident));
         }
@@ -404,6 +516,9 @@ impl VisitWith for Class {
     where
         V: VisitorMut<'a>,
     {
+        for decorator in self.decorators.iter_mut() {
+            try_break!(visitor.visit_decorator_mut(decorator));
+        }
         if let Some(ident) = &mut self.name {
             try_break!(visitor.visit_identifier_mut(ident));
         }
@@ -460,8 +575,37 @@ pub enum ClassElement {
     /// class declaration.
     PrivateStaticFieldDefinition(PrivateName, Option<Expression>),
 
+    /// An auto-accessor definition (`accessor x = 1`), which defines a getter and a setter
+    /// backed by a private storage field.
+    AutoAccessorDefinition(PropertyName, Option<Expression>),
+
+    /// A static auto-accessor definition, accessible from the class constructor object.
+    StaticAutoAccessorDefinition(PropertyName, Option<Expression>),
+
+    /// A private auto-accessor definition, only accessible inside the class declaration.
+    PrivateAutoAccessorDefinition(PrivateName, Option<Expression>),
+
+    /// A private static auto-accessor definition, only accessible from static methods and fields
+    /// inside the class declaration.
+    PrivateStaticAutoAccessorDefinition(PrivateName, Option<Expression>),
+
     /// A static block, where a class can have initialization logic for its static fields.
     StaticBlock(StaticBlockBody),
+
+    /// A class element with a list of decorators applied to it.
+    Decorated(Box<[Decorator]>, Box<Self>),
+}
+
+impl ClassElement {
+    /// Returns the decorators applied to this element and the undecorated element.
+    #[inline]
+    #[must_use]
+    pub fn decorated(&self) -> (&[Decorator], &Self) {
+        match self {
+            Self::Decorated(decorators, element) => (decorators, element),
+            element => (&[], element),
+        }
+    }
 }
 
 impl VisitWith for ClassElement {
@@ -474,7 +618,10 @@ impl VisitWith for ClassElement {
                 try_break!(visitor.visit_property_name(pn));
                 visitor.visit_method_definition(md)
             }
-            Self::FieldDefinition(pn, maybe_expr) | Self::StaticFieldDefinition(pn, maybe_expr) => {
+            Self::FieldDefinition(pn, maybe_expr)
+            | Self::StaticFieldDefinition(pn, maybe_expr)
+            | Self::AutoAccessorDefinition(pn, maybe_expr)
+            | Self::StaticAutoAccessorDefinition(pn, maybe_expr) => {
                 try_break!(visitor.visit_property_name(pn));
                 if let Some(expr) = maybe_expr {
                     visitor.visit_expression(expr)
@@ -488,7 +635,9 @@ impl VisitWith for ClassElement {
                 visitor.visit_method_definition(md)
             }
             Self::PrivateFieldDefinition(name, maybe_expr)
-            | Self::PrivateStaticFieldDefinition(name, maybe_expr) => {
+            | Self::PrivateStaticFieldDefinition(name, maybe_expr)
+            | Self::PrivateAutoAccessorDefinition(name, maybe_expr)
+            | Self::PrivateStaticAutoAccessorDefinition(name, maybe_expr) => {
                 try_break!(visitor.visit_private_name(name));
                 if let Some(expr) = maybe_expr {
                     visitor.visit_expression(expr)
@@ -497,6 +646,12 @@ impl VisitWith for ClassElement {
                 }
             }
             Self::StaticBlock(sl) => visitor.visit_script(sl),
+            Self::Decorated(decorators, element) => {
+                for decorator in decorators.iter() {
+                    try_break!(visitor.visit_decorator(decorator));
+                }
+                visitor.visit_class_element(element)
+            }
         }
     }
 
@@ -509,7 +664,10 @@ impl VisitWith for ClassElement {
                 try_break!(visitor.visit_property_name_mut(pn));
                 visitor.visit_method_definition_mut(md)
             }
-            Self::FieldDefinition(pn, maybe_expr) | Self::StaticFieldDefinition(pn, maybe_expr) => {
+            Self::FieldDefinition(pn, maybe_expr)
+            | Self::StaticFieldDefinition(pn, maybe_expr)
+            | Self::AutoAccessorDefinition(pn, maybe_expr)
+            | Self::StaticAutoAccessorDefinition(pn, maybe_expr) => {
                 try_break!(visitor.visit_property_name_mut(pn));
                 if let Some(expr) = maybe_expr {
                     visitor.visit_expression_mut(expr)
@@ -523,7 +681,9 @@ impl VisitWith for ClassElement {
                 visitor.visit_method_definition_mut(md)
             }
             Self::PrivateFieldDefinition(name, maybe_expr)
-            | Self::PrivateStaticFieldDefinition(name, maybe_expr) => {
+            | Self::PrivateStaticFieldDefinition(name, maybe_expr)
+            | Self::PrivateAutoAccessorDefinition(name, maybe_expr)
+            | Self::PrivateStaticAutoAccessorDefinition(name, maybe_expr) => {
                 try_break!(visitor.visit_private_name_mut(name));
                 if let Some(expr) = maybe_expr {
                     visitor.visit_expression_mut(expr)
@@ -532,10 +692,94 @@ impl VisitWith for ClassElement {
                 }
             }
             Self::StaticBlock(sl) => visitor.visit_script_mut(sl),
+            Self::Decorated(decorators, element) => {
+                for decorator in decorators.iter_mut() {
+                    try_break!(visitor.visit_decorator_mut(decorator));
+                }
+                visitor.visit_class_element_mut(element)
+            }
+        }
+    }
+}
+
+/// A decorator applied to a class or a class element, as defined by the [spec].
+///
+/// A decorator is an expression that gets called with the decorated value during the class
+/// definition, and can replace it or add extra initialization logic to the class.
+///
+/// [spec]: https://tc39.es/proposal-decorators/
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Decorator {
+    expression: Expression,
+    span: NodeSpan,
+}
+
+impl Decorator {
+    /// Creates a new decorator from its expression.
+    #[inline]
+    #[must_use]
+    pub const fn new(expression: Expression) -> Self {
+        Self {
+            expression,
+            span: NodeSpan::none(),
+        }
+    }
+
+    /// Gets the expression of the decorator.
+    #[inline]
+    #[must_use]
+    pub const fn expression(&self) -> &Expression {
+        &self.expression
+    }
+
+    /// Sets the location of this decorator in the source code.
+    #[inline]
+    #[must_use]
+    pub const fn with_span(mut self, span: Span) -> Self {
+        self.span = NodeSpan::new(span);
+        self
+    }
+}
+
+impl Spanned for Decorator {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        self.span.get()
+    }
+}
+
+impl ToInternedString for Decorator {
+    fn to_interned_string(&self, interner: &Interner) -> String {
+        match &self.expression {
+            Expression::Identifier(..)
+            | Expression::PropertyAccess(_)
+            | Expression::Call(_)
+            | Expression::Parenthesized(_) => {
+                format!("@{}", self.expression.to_interned_string(interner))
+            }
+            expression => format!("@({})", expression.to_interned_string(interner)),
         }
     }
 }
 
+impl VisitWith for Decorator {
+    fn visit_with<'a, V>(&'a self, visitor: &mut V) -> ControlFlow<V::BreakTy>
+    where
+        V: Visitor<'a>,
+    {
+        visitor.visit_expression(&self.expression)
+    }
+
+    fn visit_with_mut<'a, V>(&'a mut self, visitor: &mut V) -> ControlFlow<V::BreakTy>
+    where
+        V: VisitorMut<'a>,
+    {
+        visitor.visit_expression_mut(&mut self.expression)
+    }
+}
+
 /// A private name as defined by the [spec].
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-private-names
@@ -545,6 +789,7 @@ impl VisitWith for ClassElement {
 pub struct PrivateName {
     /// The `[[Description]]` internal slot of the private name.
     description: Sym,
+    span: NodeSpan,
 }
 
 impl PrivateName {
@@ -552,7 +797,10 @@ impl PrivateName {
     #[inline]
     #[must_use]
     pub const fn new(description: Sym) -> Self {
-        Self { description }
+        Self {
+            description,
+            span: NodeSpan::none(),
+        }
     }
 
     /// Get the description of the private name.
@@ -561,6 +809,21 @@ impl PrivateName {
     pub const fn description(&self) -> Sym {
         self.description
     }
+
+    /// Sets the location of this private name in the source code.
+    #[inline]
+    #[must_use]
+    pub const fn with_span(mut self, span: Span) -> Self {
+        self.span = NodeSpan::new(span);
+        self
+    }
+}
+
+impl Spanned for PrivateName {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        self.span.get()
+    }
 }
 
 impl VisitWith for PrivateName {
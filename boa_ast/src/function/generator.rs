@@ -1,7 +1,7 @@
 use crate::{
     block_to_string,
     expression::{Expression, Identifier},
-    join_nodes, Declaration,
+    join_nodes, Declaration, NodeSpan, Span, Spanned,
 };
 use core::ops::ControlFlow;
 
@@ -28,6 +28,7 @@ pub struct Generator {
     parameters: FormalParameterList,
     body: FunctionBody,
     has_binding_identifier: bool,
+    span: NodeSpan,
 }
 
 impl Generator {
@@ -45,6 +46,7 @@ impl Generator {
             parameters,
             body,
             has_binding_identifier,
+            span: NodeSpan::none(),
         }
     }
 
@@ -75,6 +77,21 @@ impl Generator {
     pub const fn has_binding_identifier(&self) -> bool {
         self.has_binding_identifier
     }
+
+    /// Sets the location of this generator in the source code.
+    #[inline]
+    #[must_use]
+    pub const fn with_span(mut self, span: Span) -> Self {
+        self.span = NodeSpan::new(span);
+        self
+    }
+}
+
+impl Spanned for Generator {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        self.span.get()
+    }
 }
 
 impl ToIndentedString for Generator {
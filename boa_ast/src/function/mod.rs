@@ -33,7 +33,7 @@ pub use arrow_function::ArrowFunction;
 pub use async_arrow_function::AsyncArrowFunction;
 pub use async_function::AsyncFunction;
 pub use async_generator::AsyncGenerator;
-pub use class::{Class, ClassElement, PrivateName};
+pub use class::{Class, ClassElement, Decorator, PrivateName};
 use core::ops::ControlFlow;
 pub use generator::Generator;
 pub use parameters::{FormalParameter, FormalParameterList, FormalParameterListFlags};
@@ -45,6 +45,7 @@ use boa_interner::{Interner, ToIndentedString};
 
 use super::expression::{Expression, Identifier};
 use super::Declaration;
+use crate::{NodeSpan, Span, Spanned};
 
 /// A function definition, as defined by the [spec].
 ///
@@ -64,6 +65,7 @@ pub struct Function {
     parameters: FormalParameterList,
     body: FunctionBody,
     has_binding_identifier: bool,
+    span: NodeSpan,
 }
 
 impl Function {
@@ -80,6 +82,7 @@ impl Function {
             parameters,
             body,
             has_binding_identifier: false,
+            span: NodeSpan::none(),
         }
     }
 
@@ -97,6 +100,7 @@ impl Function {
             parameters,
             body,
             has_binding_identifier,
+            span: NodeSpan::none(),
         }
     }
 
@@ -127,6 +131,21 @@ impl Function {
     pub const fn has_binding_identifier(&self) -> bool {
         self.has_binding_identifier
     }
+
+    /// Sets the location of this function in the source code.
+    #[inline]
+    #[must_use]
+    pub const fn with_span(mut self, span: Span) -> Self {
+        self.span = NodeSpan::new(span);
+        self
+    }
+}
+
+impl Spanned for Function {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        self.span.get()
+    }
 }
 
 impl ToIndentedString for Function {
@@ -4,6 +4,7 @@ use crate::{
     operations::bound_names,
     try_break,
     visitor::{VisitWith, Visitor, VisitorMut},
+    NodeSpan, Span, Spanned,
 };
 use bitflags::bitflags;
 use boa_interner::{Interner, Sym, ToInternedString};
@@ -219,6 +220,7 @@ impl Default for FormalParameterListFlags {
 pub struct FormalParameter {
     variable: Variable,
     is_rest_param: bool,
+    span: NodeSpan,
 }
 
 impl FormalParameter {
@@ -230,6 +232,7 @@ impl FormalParameter {
         Self {
             variable: variable.into(),
             is_rest_param,
+            span: NodeSpan::none(),
         }
     }
 
@@ -254,7 +257,22 @@ impl FormalParameter {
     /// Returns `true` if the parameter is an identifier.
     #[must_use]
     pub const fn is_identifier(&self) -> bool {
-        matches!(&self.variable.binding(), Binding::Identifier(_))
+        matches!(&self.variable.binding(), Binding::Identifier(..))
+    }
+
+    /// Sets the location of this formal parameter in the source code.
+    #[inline]
+    #[must_use]
+    pub const fn with_span(mut self, span: Span) -> Self {
+        self.span = NodeSpan::new(span);
+        self
+    }
+}
+
+impl Spanned for FormalParameter {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        self.span.get()
     }
 }
 
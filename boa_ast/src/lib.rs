@@ -81,6 +81,7 @@ mod punctuator;
 mod source;
 mod statement_list;
 
+pub mod codegen;
 pub mod declaration;
 pub mod expression;
 pub mod function;
@@ -98,7 +99,7 @@ pub use self::{
     expression::Expression,
     keyword::Keyword,
     module_item_list::{ModuleItem, ModuleItemList},
-    position::{Position, Span},
+    position::{NodeSpan, Position, Span, Spanned},
     punctuator::Punctuator,
     source::{Module, Script},
     statement::Statement,
@@ -8,20 +8,20 @@
 use std::{convert::Infallible, hash::BuildHasherDefault, ops::ControlFlow};
 
 use boa_interner::Sym;
-use indexmap::IndexSet;
+use indexmap::IndexMap;
 use rustc_hash::{FxHashSet, FxHasher};
 
 use crate::{
     declaration::{
-        ExportDeclaration, ExportEntry, ExportSpecifier, ImportDeclaration, ImportEntry,
-        ImportKind, ImportName, IndirectExportEntry, LocalExportEntry, ModuleSpecifier,
-        ReExportImportName, ReExportKind,
+        ExportDeclaration, ExportEntry, ExportSpecifier, ImportAttribute, ImportDeclaration,
+        ImportEntry, ImportKind, ImportName, IndirectExportEntry, LocalExportEntry,
+        ModuleSpecifier, ReExportImportName, ReExportKind,
     },
     expression::Identifier,
     operations::{bound_names, BoundNamesVisitor},
     try_break,
     visitor::{VisitWith, Visitor, VisitorMut},
-    StatementListItem,
+    Span, Spanned, StatementListItem,
 };
 
 /// Module item list AST node.
@@ -201,14 +201,27 @@ impl ModuleItemList {
 
     /// Operation [`ModuleRequests`][spec].
     ///
-    /// Gets the list of modules that need to be fetched by the module resolver to link this module.
+    /// Gets the list of modules that need to be fetched by the module resolver to link this module,
+    /// together with the import attributes of each request.
+    ///
+    /// If a module is requested multiple times, only the attributes of the first request are kept.
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-static-semantics-modulerequests
     #[inline]
     #[must_use]
-    pub fn requests(&self) -> IndexSet<Sym, BuildHasherDefault<FxHasher>> {
+    pub fn requests(&self) -> IndexMap<Sym, Box<[ImportAttribute]>, BuildHasherDefault<FxHasher>> {
         #[derive(Debug)]
-        struct RequestsVisitor<'vec>(&'vec mut IndexSet<Sym, BuildHasherDefault<FxHasher>>);
+        struct RequestsVisitor<'vec>(
+            &'vec mut IndexMap<Sym, Box<[ImportAttribute]>, BuildHasherDefault<FxHasher>>,
+        );
+
+        impl RequestsVisitor<'_> {
+            fn insert(&mut self, specifier: ModuleSpecifier, attributes: &[ImportAttribute]) {
+                self.0
+                    .entry(specifier.sym())
+                    .or_insert_with(|| attributes.into());
+            }
+        }
 
         impl<'ast> Visitor<'ast> for RequestsVisitor<'_> {
             type BreakTy = Infallible;
@@ -219,16 +232,30 @@ impl ModuleItemList {
             ) -> ControlFlow<Self::BreakTy> {
                 ControlFlow::Continue(())
             }
-            fn visit_module_specifier(
+            fn visit_import_declaration(
+                &mut self,
+                node: &'ast ImportDeclaration,
+            ) -> ControlFlow<Self::BreakTy> {
+                self.insert(node.specifier(), node.attributes());
+                ControlFlow::Continue(())
+            }
+            fn visit_export_declaration(
                 &mut self,
-                node: &'ast ModuleSpecifier,
+                node: &'ast ExportDeclaration,
             ) -> ControlFlow<Self::BreakTy> {
-                self.0.insert(node.sym());
+                if let ExportDeclaration::ReExport {
+                    specifier,
+                    attributes,
+                    ..
+                } = node
+                {
+                    self.insert(*specifier, attributes);
+                }
                 ControlFlow::Continue(())
             }
         }
 
-        let mut requests = IndexSet::default();
+        let mut requests = IndexMap::default();
 
         RequestsVisitor(&mut requests).visit_module_item_list(self);
 
@@ -328,7 +355,9 @@ impl ModuleItemList {
                 node: &'ast ExportDeclaration,
             ) -> ControlFlow<Self::BreakTy> {
                 let name = match node {
-                    ExportDeclaration::ReExport { kind, specifier } => {
+                    ExportDeclaration::ReExport {
+                        kind, specifier, ..
+                    } => {
                         let module = specifier.sym();
 
                         match kind {
@@ -475,6 +504,17 @@ pub enum ModuleItem {
     StatementListItem(StatementListItem),
 }
 
+impl Spanned for ModuleItem {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        match self {
+            Self::ImportDeclaration(import) => import.span(),
+            Self::ExportDeclaration(export) => export.span(),
+            Self::StatementListItem(item) => item.span(),
+        }
+    }
+}
+
 impl VisitWith for ModuleItem {
     fn visit_with<'a, V>(&'a self, visitor: &mut V) -> ControlFlow<V::BreakTy>
     where
@@ -121,7 +121,15 @@ where
                 ClassElement::MethodDefinition(name, _)
                 | ClassElement::StaticMethodDefinition(name, _)
                 | ClassElement::FieldDefinition(name, _)
-                | ClassElement::StaticFieldDefinition(name, _) => name.visit_with(self),
+                | ClassElement::StaticFieldDefinition(name, _)
+                | ClassElement::AutoAccessorDefinition(name, _)
+                | ClassElement::StaticAutoAccessorDefinition(name, _) => name.visit_with(self),
+                ClassElement::Decorated(decorators, element) => {
+                    for decorator in decorators.iter() {
+                        try_break!(self.visit_decorator(decorator));
+                    }
+                    self.visit_class_element(element)
+                }
                 _ => ControlFlow::Continue(()),
             }
         }
@@ -211,11 +219,37 @@ where
             node.visit_with(self)
         }
 
+        // `await using` declarations await the disposal of their resources, so they count as
+        // await expressions.
+        fn visit_lexical_declaration(
+            &mut self,
+            node: &'ast LexicalDeclaration,
+        ) -> ControlFlow<Self::BreakTy> {
+            if node.is_await_using() && self.0 == ContainsSymbol::AwaitExpression {
+                return ControlFlow::Break(());
+            }
+
+            node.visit_with(self)
+        }
+
+        fn visit_iterable_loop_initializer(
+            &mut self,
+            node: &'ast IterableLoopInitializer,
+        ) -> ControlFlow<Self::BreakTy> {
+            if matches!(node, IterableLoopInitializer::AwaitUsing(_))
+                && self.0 == ContainsSymbol::AwaitExpression
+            {
+                return ControlFlow::Break(());
+            }
+
+            node.visit_with(self)
+        }
+
         fn visit_expression(&mut self, node: &'ast Expression) -> ControlFlow<Self::BreakTy> {
-            if node == &Expression::This && self.0 == ContainsSymbol::This {
+            if matches!(node, Expression::This(_)) && self.0 == ContainsSymbol::This {
                 return ControlFlow::Break(());
             }
-            if node == &Expression::NewTarget && self.0 == ContainsSymbol::NewTarget {
+            if matches!(node, Expression::NewTarget(_)) && self.0 == ContainsSymbol::NewTarget {
                 return ControlFlow::Break(());
             }
             node.visit_with(self)
@@ -628,7 +662,7 @@ impl<'ast> Visitor<'ast> for VarDeclaredNamesVisitor<'_> {
 
     fn visit_statement(&mut self, node: &'ast Statement) -> ControlFlow<Self::BreakTy> {
         match node {
-            Statement::Empty
+            Statement::Empty(_)
             | Statement::Expression(_)
             | Statement::Continue(_)
             | Statement::Break(_)
@@ -899,17 +933,23 @@ impl<'ast> Visitor<'ast> for AllPrivateIdentifiersValidVisitor {
     type BreakTy = ();
 
     fn visit_class(&mut self, node: &'ast Class) -> ControlFlow<Self::BreakTy> {
+        for decorator in node.decorators() {
+            try_break!(self.visit(decorator));
+        }
+
         if let Some(node) = node.super_ref() {
             try_break!(self.visit(node));
         }
 
         let mut names = self.0.clone();
         for element in node.elements() {
-            match element {
+            match element.decorated().1 {
                 ClassElement::PrivateMethodDefinition(name, _)
                 | ClassElement::PrivateStaticMethodDefinition(name, _)
                 | ClassElement::PrivateFieldDefinition(name, _)
-                | ClassElement::PrivateStaticFieldDefinition(name, _) => {
+                | ClassElement::PrivateStaticFieldDefinition(name, _)
+                | ClassElement::PrivateAutoAccessorDefinition(name, _)
+                | ClassElement::PrivateStaticAutoAccessorDefinition(name, _) => {
                     names.push(name.description());
                 }
                 _ => {}
@@ -923,6 +963,10 @@ impl<'ast> Visitor<'ast> for AllPrivateIdentifiersValidVisitor {
         }
 
         for element in node.elements() {
+            let (decorators, element) = element.decorated();
+            for decorator in decorators {
+                try_break!(visitor.visit(decorator));
+            }
             match element {
                 ClassElement::MethodDefinition(name, method)
                 | ClassElement::StaticMethodDefinition(name, method) => {
@@ -930,7 +974,9 @@ impl<'ast> Visitor<'ast> for AllPrivateIdentifiersValidVisitor {
                     try_break!(visitor.visit(method));
                 }
                 ClassElement::FieldDefinition(name, expression)
-                | ClassElement::StaticFieldDefinition(name, expression) => {
+                | ClassElement::StaticFieldDefinition(name, expression)
+                | ClassElement::AutoAccessorDefinition(name, expression)
+                | ClassElement::StaticAutoAccessorDefinition(name, expression) => {
                     try_break!(visitor.visit(name));
                     if let Some(expression) = expression {
                         try_break!(visitor.visit(expression));
@@ -941,7 +987,9 @@ impl<'ast> Visitor<'ast> for AllPrivateIdentifiersValidVisitor {
                     try_break!(visitor.visit(method));
                 }
                 ClassElement::PrivateFieldDefinition(_, expression)
-                | ClassElement::PrivateStaticFieldDefinition(_, expression) => {
+                | ClassElement::PrivateStaticFieldDefinition(_, expression)
+                | ClassElement::PrivateAutoAccessorDefinition(_, expression)
+                | ClassElement::PrivateStaticAutoAccessorDefinition(_, expression) => {
                     if let Some(expression) = expression {
                         try_break!(visitor.visit(expression));
                     }
@@ -949,6 +997,9 @@ impl<'ast> Visitor<'ast> for AllPrivateIdentifiersValidVisitor {
                 ClassElement::StaticBlock(statement_list) => {
                     try_break!(visitor.visit(statement_list));
                 }
+                ClassElement::Decorated(..) => {
+                    unreachable!("class elements cannot have nested decorators")
+                }
             }
         }
 
@@ -1059,7 +1110,7 @@ where
             match node {
                 Statement::Block(node) => self.visit_block(node),
                 Statement::Var(_)
-                | Statement::Empty
+                | Statement::Empty(_)
                 | Statement::Expression(_)
                 | Statement::Return(_)
                 | Statement::Throw(_) => ControlFlow::Continue(()),
@@ -1670,7 +1721,7 @@ impl<'ast> Visitor<'ast> for VarScopedDeclarationsVisitor<'_> {
             Statement::Labelled(s) => self.visit(s),
             Statement::Try(s) => self.visit(s),
             Statement::With(s) => self.visit(s),
-            Statement::Empty
+            Statement::Empty(_)
             | Statement::Expression(_)
             | Statement::Continue(_)
             | Statement::Break(_)
@@ -2032,11 +2083,11 @@ impl<'ast> Visitor<'ast> for AnnexBFunctionDeclarationNamesVisitor<'_> {
     ) -> ControlFlow<Self::BreakTy> {
         self.visit(node.body());
 
-        if let IterableLoopInitializer::Let(node) = node.initializer() {
-            let bound_names = bound_names(node);
-            self.0.retain(|name| !bound_names.contains(name));
-        }
-        if let IterableLoopInitializer::Const(node) = node.initializer() {
+        if let IterableLoopInitializer::Let(node)
+        | IterableLoopInitializer::Const(node)
+        | IterableLoopInitializer::Using(node)
+        | IterableLoopInitializer::AwaitUsing(node) = node.initializer()
+        {
             let bound_names = bound_names(node);
             self.0.retain(|name| !bound_names.contains(name));
         }
@@ -2050,11 +2101,11 @@ impl<'ast> Visitor<'ast> for AnnexBFunctionDeclarationNamesVisitor<'_> {
     ) -> ControlFlow<Self::BreakTy> {
         self.visit(node.body());
 
-        if let IterableLoopInitializer::Let(node) = node.initializer() {
-            let bound_names = bound_names(node);
-            self.0.retain(|name| !bound_names.contains(name));
-        }
-        if let IterableLoopInitializer::Const(node) = node.initializer() {
+        if let IterableLoopInitializer::Let(node)
+        | IterableLoopInitializer::Const(node)
+        | IterableLoopInitializer::Using(node)
+        | IterableLoopInitializer::AwaitUsing(node) = node.initializer()
+        {
             let bound_names = bound_names(node);
             self.0.retain(|name| !bound_names.contains(name));
         }
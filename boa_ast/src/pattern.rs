@@ -27,7 +27,7 @@ use crate::{
     property::PropertyName,
     try_break,
     visitor::{VisitWith, Visitor, VisitorMut},
-    Expression,
+    Expression, NodeSpan, Span, Spanned,
 };
 use boa_interner::{Interner, ToInternedString};
 use core::ops::ControlFlow;
@@ -77,6 +77,16 @@ impl ToInternedString for Pattern {
     }
 }
 
+impl Spanned for Pattern {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        match self {
+            Self::Object(o) => o.span(),
+            Self::Array(a) => a.span(),
+        }
+    }
+}
+
 impl VisitWith for Pattern {
     fn visit_with<'a, V>(&'a self, visitor: &mut V) -> ControlFlow<V::BreakTy>
     where
@@ -111,20 +121,23 @@ impl VisitWith for Pattern {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Clone, Debug, PartialEq)]
-pub struct ObjectPattern(Box<[ObjectPatternElement]>);
+pub struct ObjectPattern {
+    bindings: Box<[ObjectPatternElement]>,
+    span: NodeSpan,
+}
 
 impl From<Vec<ObjectPatternElement>> for ObjectPattern {
     fn from(elements: Vec<ObjectPatternElement>) -> Self {
-        Self(elements.into())
+        Self::new(elements.into())
     }
 }
 
 impl ToInternedString for ObjectPattern {
     fn to_interned_string(&self, interner: &Interner) -> String {
         let mut buf = "{".to_owned();
-        for (i, binding) in self.0.iter().enumerate() {
+        for (i, binding) in self.bindings.iter().enumerate() {
             let binding = binding.to_interned_string(interner);
-            let str = if i == self.0.len() - 1 {
+            let str = if i == self.bindings.len() - 1 {
                 format!("{binding} ")
             } else {
                 format!("{binding},")
@@ -132,7 +145,7 @@ impl ToInternedString for ObjectPattern {
 
             buf.push_str(&str);
         }
-        if self.0.is_empty() {
+        if self.bindings.is_empty() {
             buf.push(' ');
         }
         buf.push('}');
@@ -145,14 +158,25 @@ impl ObjectPattern {
     #[inline]
     #[must_use]
     pub fn new(bindings: Box<[ObjectPatternElement]>) -> Self {
-        Self(bindings)
+        Self {
+            bindings,
+            span: NodeSpan::none(),
+        }
     }
 
     /// Gets the bindings for the object binding pattern.
     #[inline]
     #[must_use]
     pub const fn bindings(&self) -> &[ObjectPatternElement] {
-        &self.0
+        &self.bindings
+    }
+
+    /// Sets the location of this object pattern in the source code.
+    #[inline]
+    #[must_use]
+    pub const fn with_span(mut self, span: Span) -> Self {
+        self.span = NodeSpan::new(span);
+        self
     }
 
     /// Returns true if the object binding pattern has a rest element.
@@ -160,18 +184,25 @@ impl ObjectPattern {
     #[must_use]
     pub const fn has_rest(&self) -> bool {
         matches!(
-            self.0.last(),
+            self.bindings.last(),
             Some(ObjectPatternElement::RestProperty { .. })
         )
     }
 }
 
+impl Spanned for ObjectPattern {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        self.span.get()
+    }
+}
+
 impl VisitWith for ObjectPattern {
     fn visit_with<'a, V>(&'a self, visitor: &mut V) -> ControlFlow<V::BreakTy>
     where
         V: Visitor<'a>,
     {
-        for elem in self.0.iter() {
+        for elem in self.bindings.iter() {
             try_break!(visitor.visit_object_pattern_element(elem));
         }
         ControlFlow::Continue(())
@@ -181,7 +212,7 @@ impl VisitWith for ObjectPattern {
     where
         V: VisitorMut<'a>,
     {
-        for elem in self.0.iter_mut() {
+        for elem in self.bindings.iter_mut() {
             try_break!(visitor.visit_object_pattern_element_mut(elem));
         }
         ControlFlow::Continue(())
@@ -200,19 +231,22 @@ impl VisitWith for ObjectPattern {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Clone, Debug, PartialEq)]
-pub struct ArrayPattern(Box<[ArrayPatternElement]>);
+pub struct ArrayPattern {
+    bindings: Box<[ArrayPatternElement]>,
+    span: NodeSpan,
+}
 
 impl From<Vec<ArrayPatternElement>> for ArrayPattern {
     fn from(elements: Vec<ArrayPatternElement>) -> Self {
-        Self(elements.into())
+        Self::new(elements.into())
     }
 }
 
 impl ToInternedString for ArrayPattern {
     fn to_interned_string(&self, interner: &Interner) -> String {
         let mut buf = "[".to_owned();
-        for (i, binding) in self.0.iter().enumerate() {
-            if i == self.0.len() - 1 {
+        for (i, binding) in self.bindings.iter().enumerate() {
+            if i == self.bindings.len() - 1 {
                 match binding {
                     ArrayPatternElement::Elision => {
                         buf.push_str(&format!("{}, ", binding.to_interned_string(interner)));
@@ -233,14 +267,32 @@ impl ArrayPattern {
     #[inline]
     #[must_use]
     pub fn new(bindings: Box<[ArrayPatternElement]>) -> Self {
-        Self(bindings)
+        Self {
+            bindings,
+            span: NodeSpan::none(),
+        }
     }
 
     /// Gets the bindings for the array binding pattern.
     #[inline]
     #[must_use]
     pub const fn bindings(&self) -> &[ArrayPatternElement] {
-        &self.0
+        &self.bindings
+    }
+
+    /// Sets the location of this array pattern in the source code.
+    #[inline]
+    #[must_use]
+    pub const fn with_span(mut self, span: Span) -> Self {
+        self.span = NodeSpan::new(span);
+        self
+    }
+}
+
+impl Spanned for ArrayPattern {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        self.span.get()
     }
 }
 
@@ -249,7 +301,7 @@ impl VisitWith for ArrayPattern {
     where
         V: Visitor<'a>,
     {
-        for elem in self.0.iter() {
+        for elem in self.bindings.iter() {
             try_break!(visitor.visit_array_pattern_element(elem));
         }
         ControlFlow::Continue(())
@@ -259,7 +311,7 @@ impl VisitWith for ArrayPattern {
     where
         V: VisitorMut<'a>,
     {
-        for elem in self.0.iter_mut() {
+        for elem in self.bindings.iter_mut() {
             try_break!(visitor.visit_array_pattern_element_mut(elem));
         }
         ControlFlow::Continue(())
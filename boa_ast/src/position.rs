@@ -1,4 +1,9 @@
-use std::{cmp::Ordering, fmt, num::NonZeroU32};
+use std::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    num::NonZeroU32,
+};
 
 /// A position in the ECMAScript source code.
 ///
@@ -129,11 +134,92 @@ impl fmt::Display for Span {
     }
 }
 
+/// The location of an AST node in the source code.
+///
+/// A node's location describes where it was parsed from, not what it is, so it doesn't
+/// participate in the equality and hashing of the node that holds it. Nodes that were not
+/// produced by the parser (e.g. nodes created by an optimizer pass) have no location.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NodeSpan(Option<Span>);
+
+impl NodeSpan {
+    /// Creates a new `NodeSpan` pointing to `span`.
+    #[inline]
+    #[must_use]
+    pub const fn new(span: Span) -> Self {
+        Self(Some(span))
+    }
+
+    /// Creates a new `NodeSpan` that doesn't point to any location.
+    #[inline]
+    #[must_use]
+    pub const fn none() -> Self {
+        Self(None)
+    }
+
+    /// Gets the inner span, if any.
+    #[inline]
+    #[must_use]
+    pub const fn get(self) -> Option<Span> {
+        self.0
+    }
+}
+
+impl From<Span> for NodeSpan {
+    #[inline]
+    fn from(span: Span) -> Self {
+        Self(Some(span))
+    }
+}
+
+impl From<Option<Span>> for NodeSpan {
+    #[inline]
+    fn from(span: Option<Span>) -> Self {
+        Self(span)
+    }
+}
+
+impl PartialEq for NodeSpan {
+    #[inline]
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for NodeSpan {}
+
+impl Hash for NodeSpan {
+    #[inline]
+    fn hash<H: Hasher>(&self, _: &mut H) {}
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for NodeSpan {
+    fn arbitrary(_: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self::default())
+    }
+
+    fn size_hint(_: usize) -> (usize, Option<usize>) {
+        (0, Some(0))
+    }
+}
+
+/// An AST node that knows its location in the source code.
+///
+/// The location of a node is only known if the node was produced by the parser, or if it was
+/// explicitly given one.
+pub trait Spanned {
+    /// Gets the location of this node in the source code, if known.
+    fn span(&self) -> Option<Span>;
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::similar_names)]
     #![allow(unused_must_use)]
-    use super::{Position, Span};
+    use super::{NodeSpan, Position, Span};
 
     /// Checks that we cannot create a position with 0 as the column.
     #[test]
@@ -299,4 +385,45 @@ mod tests {
         assert!(span_ab < span_cd);
         assert!(span_cd > span_ab);
     }
+
+    /// Checks that node spans don't participate in equality.
+    #[test]
+    fn node_span_equality() {
+        let a = Span::new(Position::new(1, 1), Position::new(1, 5));
+        let b = Span::new(Position::new(2, 3), Position::new(2, 9));
+
+        assert_eq!(NodeSpan::new(a), NodeSpan::new(b));
+        assert_eq!(NodeSpan::new(a), NodeSpan::default());
+        assert_eq!(NodeSpan::new(a).get(), Some(a));
+        assert_eq!(NodeSpan::default().get(), None);
+    }
+
+    /// Checks that identifiers stay the size of a symbol, since their spans are stored in the
+    /// nodes that contain them.
+    #[test]
+    fn identifier_size() {
+        use crate::expression::Identifier;
+        use boa_interner::Sym;
+        use std::mem::size_of;
+
+        assert_eq!(size_of::<Identifier>(), size_of::<Sym>());
+    }
+
+    /// Checks that spans survive a serialization round-trip, both on their own and inside nodes.
+    #[cfg(feature = "serde")]
+    #[test]
+    fn span_serde_round_trip() {
+        use crate::{Spanned, Statement};
+
+        let span = Span::new(Position::new(1, 5), Position::new(2, 3));
+        let json = serde_json::to_string(&span).expect("failed to serialize span");
+        let deserialized: Span = serde_json::from_str(&json).expect("failed to deserialize span");
+        assert_eq!(deserialized, span);
+
+        let statement = Statement::Empty(NodeSpan::new(span));
+        let json = serde_json::to_string(&statement).expect("failed to serialize statement");
+        let deserialized: Statement =
+            serde_json::from_str(&json).expect("failed to deserialize statement");
+        assert_eq!(deserialized.span(), Some(span));
+    }
 }
@@ -7,10 +7,11 @@ use boa_interner::{Interner, Sym, ToInternedString};
 use core::ops::ControlFlow;
 
 use super::{
-    expression::{literal::Literal, Identifier},
+    expression::{literal::LiteralKind, Identifier},
     function::{AsyncFunction, AsyncGenerator, Function, Generator},
     Expression,
 };
+use crate::{Span, Spanned};
 
 /// Describes the definition of a property within an object literal.
 ///
@@ -214,6 +215,18 @@ pub enum MethodDefinition {
     Async(AsyncFunction),
 }
 
+impl Spanned for MethodDefinition {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        match self {
+            Self::Get(f) | Self::Set(f) | Self::Ordinary(f) => f.span(),
+            Self::Generator(g) => g.span(),
+            Self::AsyncGenerator(g) => g.span(),
+            Self::Async(f) => f.span(),
+        }
+    }
+}
+
 impl VisitWith for MethodDefinition {
     fn visit_with<'a, V>(&'a self, visitor: &mut V) -> ControlFlow<V::BreakTy>
     where
@@ -292,9 +305,11 @@ impl PropertyName {
     #[must_use]
     pub const fn prop_name(&self) -> Option<Sym> {
         match self {
-            Self::Literal(sym) | Self::Computed(Expression::Literal(Literal::String(sym))) => {
-                Some(*sym)
-            }
+            Self::Literal(sym) => Some(*sym),
+            Self::Computed(Expression::Literal(lit)) => match lit.kind() {
+                LiteralKind::String(sym) => Some(*sym),
+                _ => None,
+            },
             Self::Computed(_) => None,
         }
     }
@@ -2,7 +2,7 @@
 
 use crate::{
     visitor::{VisitWith, Visitor, VisitorMut},
-    Statement, StatementList,
+    NodeSpan, Span, Spanned, Statement, StatementList,
 };
 use boa_interner::{Interner, ToIndentedString};
 use core::ops::ControlFlow;
@@ -28,6 +28,7 @@ use core::ops::ControlFlow;
 pub struct Block {
     #[cfg_attr(feature = "serde", serde(flatten))]
     statements: StatementList,
+    span: NodeSpan,
 }
 
 impl Block {
@@ -37,6 +38,21 @@ impl Block {
     pub const fn statement_list(&self) -> &StatementList {
         &self.statements
     }
+
+    /// Sets the location of this block in the source code.
+    #[inline]
+    #[must_use]
+    pub const fn with_span(mut self, span: Span) -> Self {
+        self.span = NodeSpan::new(span);
+        self
+    }
+}
+
+impl Spanned for Block {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        self.span.get()
+    }
 }
 
 impl<T> From<T> for Block
@@ -46,6 +62,7 @@ where
     fn from(list: T) -> Self {
         Self {
             statements: list.into(),
+            span: NodeSpan::none(),
         }
     }
 }
@@ -5,6 +5,7 @@ use crate::{
     statement::Statement,
     try_break,
     visitor::{VisitWith, Visitor, VisitorMut},
+    NodeSpan, Span, Spanned,
 };
 use boa_interner::{Interner, ToIndentedString, ToInternedString};
 use core::ops::ControlFlow;
@@ -32,6 +33,7 @@ pub struct If {
     condition: Expression,
     body: Box<Statement>,
     else_node: Option<Box<Statement>>,
+    span: NodeSpan,
 }
 
 impl If {
@@ -63,8 +65,24 @@ impl If {
             condition,
             body: body.into(),
             else_node: else_node.map(Box::new),
+            span: NodeSpan::none(),
         }
     }
+
+    /// Sets the location of this `if` statement in the source code.
+    #[inline]
+    #[must_use]
+    pub const fn with_span(mut self, span: Span) -> Self {
+        self.span = NodeSpan::new(span);
+        self
+    }
+}
+
+impl Spanned for If {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        self.span.get()
+    }
 }
 
 impl ToIndentedString for If {
@@ -3,6 +3,7 @@ use core::ops::ControlFlow;
 
 use crate::visitor::{VisitWith, Visitor, VisitorMut};
 use crate::Statement;
+use crate::{NodeSpan, Span, Spanned};
 
 /// The `break` statement terminates the current loop, switch, or label statement and transfers
 /// program control to the statement following the terminated statement.
@@ -23,13 +24,17 @@ use crate::Statement;
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Break {
     label: Option<Sym>,
+    span: NodeSpan,
 }
 
 impl Break {
     /// Creates a `Break` AST node.
     #[must_use]
     pub const fn new(label: Option<Sym>) -> Self {
-        Self { label }
+        Self {
+            label,
+            span: NodeSpan::none(),
+        }
     }
 
     /// Gets the label of the break statement, if any.
@@ -37,6 +42,21 @@ impl Break {
     pub const fn label(&self) -> Option<Sym> {
         self.label
     }
+
+    /// Sets the location of this `break` statement in the source code.
+    #[inline]
+    #[must_use]
+    pub const fn with_span(mut self, span: Span) -> Self {
+        self.span = NodeSpan::new(span);
+        self
+    }
+}
+
+impl Spanned for Break {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        self.span.get()
+    }
 }
 
 impl ToInternedString for Break {
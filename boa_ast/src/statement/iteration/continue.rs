@@ -1,5 +1,6 @@
 use crate::statement::Statement;
 use crate::visitor::{VisitWith, Visitor, VisitorMut};
+use crate::{NodeSpan, Span, Spanned};
 use boa_interner::{Interner, Sym, ToInternedString};
 use core::ops::ControlFlow;
 
@@ -21,13 +22,17 @@ use core::ops::ControlFlow;
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct Continue {
     label: Option<Sym>,
+    span: NodeSpan,
 }
 
 impl Continue {
     /// Creates a `Continue` AST node.
     #[must_use]
     pub const fn new(label: Option<Sym>) -> Self {
-        Self { label }
+        Self {
+            label,
+            span: NodeSpan::none(),
+        }
     }
 
     /// Gets the label of this `Continue` statement.
@@ -35,6 +40,21 @@ impl Continue {
     pub const fn label(&self) -> Option<Sym> {
         self.label
     }
+
+    /// Sets the location of this `continue` statement in the source code.
+    #[inline]
+    #[must_use]
+    pub const fn with_span(mut self, span: Span) -> Self {
+        self.span = NodeSpan::new(span);
+        self
+    }
+}
+
+impl Spanned for Continue {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        self.span.get()
+    }
 }
 
 impl ToInternedString for Continue {
@@ -3,6 +3,7 @@ use crate::{
     statement::Statement,
     try_break,
     visitor::{VisitWith, Visitor, VisitorMut},
+    NodeSpan, Span, Spanned,
 };
 use boa_interner::{Interner, ToIndentedString, ToInternedString};
 use core::ops::ControlFlow;
@@ -25,6 +26,7 @@ use core::ops::ControlFlow;
 pub struct DoWhileLoop {
     body: Box<Statement>,
     condition: Expression,
+    span: NodeSpan,
 }
 
 impl DoWhileLoop {
@@ -48,8 +50,24 @@ impl DoWhileLoop {
         Self {
             body: body.into(),
             condition,
+            span: NodeSpan::none(),
         }
     }
+
+    /// Sets the location of this `do...while` loop in the source code.
+    #[inline]
+    #[must_use]
+    pub const fn with_span(mut self, span: Span) -> Self {
+        self.span = NodeSpan::new(span);
+        self
+    }
+}
+
+impl Spanned for DoWhileLoop {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        self.span.get()
+    }
 }
 
 impl ToIndentedString for DoWhileLoop {
@@ -3,6 +3,7 @@ use crate::visitor::{VisitWith, Visitor, VisitorMut};
 use crate::{
     expression::Expression,
     statement::{iteration::IterableLoopInitializer, Statement},
+    NodeSpan, Span, Spanned,
 };
 use boa_interner::{Interner, ToIndentedString, ToInternedString};
 use core::ops::ControlFlow;
@@ -21,6 +22,7 @@ pub struct ForInLoop {
     initializer: IterableLoopInitializer,
     target: Expression,
     body: Box<Statement>,
+    span: NodeSpan,
 }
 
 impl ForInLoop {
@@ -32,6 +34,7 @@ impl ForInLoop {
             initializer,
             target,
             body: body.into(),
+            span: NodeSpan::none(),
         }
     }
 
@@ -55,6 +58,21 @@ impl ForInLoop {
     pub const fn body(&self) -> &Statement {
         &self.body
     }
+
+    /// Sets the location of this `for...in` loop in the source code.
+    #[inline]
+    #[must_use]
+    pub const fn with_span(mut self, span: Span) -> Self {
+        self.span = NodeSpan::new(span);
+        self
+    }
+}
+
+impl Spanned for ForInLoop {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        self.span.get()
+    }
 }
 
 impl ToIndentedString for ForInLoop {
@@ -3,7 +3,7 @@ use crate::visitor::{VisitWith, Visitor, VisitorMut};
 use crate::{
     declaration::{LexicalDeclaration, VarDeclaration},
     statement::Statement,
-    Expression,
+    Expression, NodeSpan, Span, Spanned,
 };
 use boa_interner::{Interner, ToIndentedString, ToInternedString};
 use core::ops::ControlFlow;
@@ -24,6 +24,7 @@ use core::ops::ControlFlow;
 pub struct ForLoop {
     #[cfg_attr(feature = "serde", serde(flatten))]
     inner: Box<InnerForLoop>,
+    span: NodeSpan,
 }
 
 impl ForLoop {
@@ -38,6 +39,7 @@ impl ForLoop {
     ) -> Self {
         Self {
             inner: Box::new(InnerForLoop::new(init, condition, final_expr, body)),
+            span: NodeSpan::none(),
         }
     }
 
@@ -68,6 +70,21 @@ impl ForLoop {
     pub const fn body(&self) -> &Statement {
         self.inner.body()
     }
+
+    /// Sets the location of this `for` loop in the source code.
+    #[inline]
+    #[must_use]
+    pub const fn with_span(mut self, span: Span) -> Self {
+        self.span = NodeSpan::new(span);
+        self
+    }
+}
+
+impl Spanned for ForLoop {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        self.span.get()
+    }
 }
 
 impl ToIndentedString for ForLoop {
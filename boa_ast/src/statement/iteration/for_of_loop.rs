@@ -3,6 +3,7 @@ use crate::visitor::{VisitWith, Visitor, VisitorMut};
 use crate::{
     expression::Expression,
     statement::{iteration::IterableLoopInitializer, Statement},
+    NodeSpan, Span, Spanned,
 };
 use boa_interner::{Interner, ToIndentedString, ToInternedString};
 use core::ops::ControlFlow;
@@ -27,6 +28,7 @@ pub struct ForOfLoop {
     iterable: Expression,
     body: Box<Statement>,
     r#await: bool,
+    span: NodeSpan,
 }
 
 impl ForOfLoop {
@@ -44,6 +46,7 @@ impl ForOfLoop {
             iterable,
             body: body.into(),
             r#await,
+            span: NodeSpan::none(),
         }
     }
 
@@ -74,6 +77,21 @@ impl ForOfLoop {
     pub const fn r#await(&self) -> bool {
         self.r#await
     }
+
+    /// Sets the location of this `for...of` loop in the source code.
+    #[inline]
+    #[must_use]
+    pub const fn with_span(mut self, span: Span) -> Self {
+        self.span = NodeSpan::new(span);
+        self
+    }
+}
+
+impl Spanned for ForOfLoop {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        self.span.get()
+    }
 }
 
 impl ToIndentedString for ForOfLoop {
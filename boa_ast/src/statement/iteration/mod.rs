@@ -12,6 +12,7 @@ use crate::{
     declaration::{Binding, Variable},
     expression::{access::PropertyAccess, Identifier},
     pattern::Pattern,
+    NodeSpan, Span, Spanned,
 };
 use core::ops::ControlFlow;
 
@@ -39,7 +40,7 @@ use boa_interner::{Interner, ToInternedString};
 #[derive(Clone, Debug, PartialEq)]
 pub enum IterableLoopInitializer {
     /// An already declared variable.
-    Identifier(Identifier),
+    Identifier(Identifier, NodeSpan),
     /// A property access.
     Access(PropertyAccess),
     /// A new var declaration.
@@ -48,6 +49,10 @@ pub enum IterableLoopInitializer {
     Let(Binding),
     /// A new const declaration.
     Const(Binding),
+    /// A new `using` declaration.
+    Using(Binding),
+    /// A new `await using` declaration.
+    AwaitUsing(Binding),
     /// A pattern with already declared variables.
     Pattern(Pattern),
 }
@@ -55,28 +60,48 @@ pub enum IterableLoopInitializer {
 impl ToInternedString for IterableLoopInitializer {
     fn to_interned_string(&self, interner: &Interner) -> String {
         let (binding, pre) = match self {
-            Self::Identifier(ident) => return ident.to_interned_string(interner),
+            Self::Identifier(ident, _) => return ident.to_interned_string(interner),
             Self::Pattern(pattern) => return pattern.to_interned_string(interner),
             Self::Access(access) => return access.to_interned_string(interner),
             Self::Var(binding) => (binding.to_interned_string(interner), "var"),
             Self::Let(binding) => (binding.to_interned_string(interner), "let"),
             Self::Const(binding) => (binding.to_interned_string(interner), "const"),
+            Self::Using(binding) => (binding.to_interned_string(interner), "using"),
+            Self::AwaitUsing(binding) => (binding.to_interned_string(interner), "await using"),
         };
 
         format!("{pre} {binding}")
     }
 }
 
+impl Spanned for IterableLoopInitializer {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        match self {
+            Self::Identifier(_, span) => span.get(),
+            Self::Access(access) => access.span(),
+            Self::Var(var) => var.span(),
+            Self::Let(binding)
+            | Self::Const(binding)
+            | Self::Using(binding)
+            | Self::AwaitUsing(binding) => binding.span(),
+            Self::Pattern(pattern) => pattern.span(),
+        }
+    }
+}
+
 impl VisitWith for IterableLoopInitializer {
     fn visit_with<'a, V>(&'a self, visitor: &mut V) -> ControlFlow<V::BreakTy>
     where
         V: Visitor<'a>,
     {
         match self {
-            Self::Identifier(id) => visitor.visit_identifier(id),
+            Self::Identifier(id, _) => visitor.visit_identifier(id),
             Self::Access(pa) => visitor.visit_property_access(pa),
             Self::Var(b) => visitor.visit_variable(b),
-            Self::Let(b) | Self::Const(b) => visitor.visit_binding(b),
+            Self::Let(b) | Self::Const(b) | Self::Using(b) | Self::AwaitUsing(b) => {
+                visitor.visit_binding(b)
+            }
             Self::Pattern(p) => visitor.visit_pattern(p),
         }
     }
@@ -86,10 +111,12 @@ impl VisitWith for IterableLoopInitializer {
         V: VisitorMut<'a>,
     {
         match self {
-            Self::Identifier(id) => visitor.visit_identifier_mut(id),
+            Self::Identifier(id, _) => visitor.visit_identifier_mut(id),
             Self::Access(pa) => visitor.visit_property_access_mut(pa),
             Self::Var(b) => visitor.visit_variable_mut(b),
-            Self::Let(b) | Self::Const(b) => visitor.visit_binding_mut(b),
+            Self::Let(b) | Self::Const(b) | Self::Using(b) | Self::AwaitUsing(b) => {
+                visitor.visit_binding_mut(b)
+            }
             Self::Pattern(p) => visitor.visit_pattern_mut(p),
         }
     }
@@ -3,6 +3,7 @@ use crate::{
     statement::Statement,
     try_break,
     visitor::{VisitWith, Visitor, VisitorMut},
+    NodeSpan, Span, Spanned,
 };
 use boa_interner::{Interner, ToIndentedString, ToInternedString};
 use core::ops::ControlFlow;
@@ -24,6 +25,7 @@ use core::ops::ControlFlow;
 pub struct WhileLoop {
     condition: Expression,
     body: Box<Statement>,
+    span: NodeSpan,
 }
 
 impl WhileLoop {
@@ -34,6 +36,7 @@ impl WhileLoop {
         Self {
             condition,
             body: body.into(),
+            span: NodeSpan::none(),
         }
     }
 
@@ -50,6 +53,21 @@ impl WhileLoop {
     pub const fn body(&self) -> &Statement {
         &self.body
     }
+
+    /// Sets the location of this `while` loop in the source code.
+    #[inline]
+    #[must_use]
+    pub const fn with_span(mut self, span: Span) -> Self {
+        self.span = NodeSpan::new(span);
+        self
+    }
+}
+
+impl Spanned for WhileLoop {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        self.span.get()
+    }
 }
 
 impl ToIndentedString for WhileLoop {
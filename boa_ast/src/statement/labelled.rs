@@ -2,7 +2,7 @@ use crate::{
     function::Function,
     try_break,
     visitor::{VisitWith, Visitor, VisitorMut},
-    Statement,
+    NodeSpan, Span, Spanned, Statement,
 };
 use boa_interner::{Interner, Sym, ToIndentedString, ToInternedString};
 use core::ops::ControlFlow;
@@ -53,6 +53,16 @@ impl From<Statement> for LabelledItem {
     }
 }
 
+impl Spanned for LabelledItem {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        match self {
+            Self::Function(f) => f.span(),
+            Self::Statement(stmt) => stmt.span(),
+        }
+    }
+}
+
 impl VisitWith for LabelledItem {
     fn visit_with<'a, V>(&'a self, visitor: &mut V) -> ControlFlow<V::BreakTy>
     where
@@ -87,6 +97,7 @@ impl VisitWith for LabelledItem {
 pub struct Labelled {
     item: Box<LabelledItem>,
     label: Sym,
+    span: NodeSpan,
 }
 
 impl Labelled {
@@ -97,6 +108,7 @@ impl Labelled {
         Self {
             item: Box::new(item),
             label,
+            span: NodeSpan::none(),
         }
     }
 
@@ -121,6 +133,21 @@ impl Labelled {
             self.item.to_indented_string(interner, indentation)
         )
     }
+
+    /// Sets the location of this labelled statement in the source code.
+    #[inline]
+    #[must_use]
+    pub const fn with_span(mut self, span: Span) -> Self {
+        self.span = NodeSpan::new(span);
+        self
+    }
+}
+
+impl Spanned for Labelled {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        self.span.get()
+    }
 }
 
 impl ToInternedString for Labelled {
@@ -35,6 +35,7 @@ use crate::visitor::{VisitWith, Visitor, VisitorMut};
 use boa_interner::{Interner, ToIndentedString, ToInternedString};
 
 use super::{declaration::VarDeclaration, expression::Expression};
+use crate::{NodeSpan, Span, Spanned};
 
 /// The `Statement` Parse Node.
 ///
@@ -59,7 +60,7 @@ pub enum Statement {
     ///
     /// [spec]: https://tc39.es/ecma262/#prod-EmptyStatement
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/Empty
-    Empty,
+    Empty(NodeSpan),
 
     /// See [`Expression`].
     Expression(Expression),
@@ -116,7 +117,7 @@ impl Statement {
         let mut s = match self {
             Self::Block(block) => return block.to_indented_string(interner, indentation),
             Self::Var(var) => var.to_interned_string(interner),
-            Self::Empty => return ";".to_owned(),
+            Self::Empty(_) => return ";".to_owned(),
             Self::Expression(expr) => expr.to_indented_string(interner, indentation),
             Self::If(if_smt) => return if_smt.to_indented_string(interner, indentation),
             Self::DoWhileLoop(do_while) => do_while.to_indented_string(interner, indentation),
@@ -169,7 +170,7 @@ impl Statement {
     pub const fn returns_value(&self) -> bool {
         match self {
             Self::Block(block) if block.statement_list().statements().is_empty() => false,
-            Self::Empty | Self::Var(_) | Self::Break(_) | Self::Continue(_) => false,
+            Self::Empty(_) | Self::Var(_) | Self::Break(_) | Self::Continue(_) => false,
             _ => true,
         }
     }
@@ -198,6 +199,32 @@ impl ToIndentedString for Statement {
     }
 }
 
+impl Spanned for Statement {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        match self {
+            Self::Empty(span) => span.get(),
+            Self::Block(b) => b.span(),
+            Self::Var(v) => v.span(),
+            Self::Expression(e) => e.span(),
+            Self::If(i) => i.span(),
+            Self::DoWhileLoop(l) => l.span(),
+            Self::WhileLoop(l) => l.span(),
+            Self::ForLoop(l) => l.span(),
+            Self::ForInLoop(l) => l.span(),
+            Self::ForOfLoop(l) => l.span(),
+            Self::Switch(s) => s.span(),
+            Self::Continue(c) => c.span(),
+            Self::Break(b) => b.span(),
+            Self::Return(r) => r.span(),
+            Self::Labelled(l) => l.span(),
+            Self::Throw(t) => t.span(),
+            Self::Try(t) => t.span(),
+            Self::With(w) => w.span(),
+        }
+    }
+}
+
 impl VisitWith for Statement {
     fn visit_with<'a, V>(&'a self, visitor: &mut V) -> ControlFlow<V::BreakTy>
     where
@@ -206,7 +233,7 @@ impl VisitWith for Statement {
         match self {
             Self::Block(b) => visitor.visit_block(b),
             Self::Var(v) => visitor.visit_var_declaration(v),
-            Self::Empty => {
+            Self::Empty(_) => {
                 // do nothing; there is nothing to visit here
                 ControlFlow::Continue(())
             }
@@ -235,7 +262,7 @@ impl VisitWith for Statement {
         match self {
             Self::Block(b) => visitor.visit_block_mut(b),
             Self::Var(v) => visitor.visit_var_declaration_mut(v),
-            Self::Empty => {
+            Self::Empty(_) => {
                 // do nothing; there is nothing to visit here
                 ControlFlow::Continue(())
             }
@@ -2,6 +2,7 @@ use crate::{
     expression::Expression,
     statement::Statement,
     visitor::{VisitWith, Visitor, VisitorMut},
+    NodeSpan, Span, Spanned,
 };
 use boa_interner::{Interner, ToInternedString};
 use core::ops::ControlFlow;
@@ -28,6 +29,7 @@ use core::ops::ControlFlow;
 #[derive(Clone, Debug, PartialEq)]
 pub struct Return {
     target: Option<Expression>,
+    span: NodeSpan,
 }
 
 impl Return {
@@ -40,7 +42,25 @@ impl Return {
     /// Creates a `Return` AST node.
     #[must_use]
     pub const fn new(expression: Option<Expression>) -> Self {
-        Self { target: expression }
+        Self {
+            target: expression,
+            span: NodeSpan::none(),
+        }
+    }
+
+    /// Sets the location of this `return` statement in the source code.
+    #[inline]
+    #[must_use]
+    pub const fn with_span(mut self, span: Span) -> Self {
+        self.span = NodeSpan::new(span);
+        self
+    }
+}
+
+impl Spanned for Return {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        self.span.get()
     }
 }
 
@@ -5,7 +5,7 @@ use crate::{
     statement::Statement,
     try_break,
     visitor::{VisitWith, Visitor, VisitorMut},
-    StatementList,
+    NodeSpan, Span, Spanned, StatementList,
 };
 use boa_interner::{Interner, ToIndentedString, ToInternedString};
 use core::ops::ControlFlow;
@@ -24,6 +24,7 @@ use core::ops::ControlFlow;
 pub struct Case {
     condition: Option<Expression>,
     body: StatementList,
+    span: NodeSpan,
 }
 
 impl Case {
@@ -34,6 +35,7 @@ impl Case {
         Self {
             condition: Some(condition),
             body,
+            span: NodeSpan::none(),
         }
     }
 
@@ -44,6 +46,7 @@ impl Case {
         Self {
             condition: None,
             body,
+            span: NodeSpan::none(),
         }
     }
 
@@ -70,6 +73,21 @@ impl Case {
     pub const fn is_default(&self) -> bool {
         self.condition.is_none()
     }
+
+    /// Sets the location of this `case` clause in the source code.
+    #[inline]
+    #[must_use]
+    pub const fn with_span(mut self, span: Span) -> Self {
+        self.span = NodeSpan::new(span);
+        self
+    }
+}
+
+impl Spanned for Case {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        self.span.get()
+    }
 }
 
 impl VisitWith for Case {
@@ -117,6 +135,7 @@ impl VisitWith for Case {
 pub struct Switch {
     val: Expression,
     cases: Box<[Case]>,
+    span: NodeSpan,
 }
 
 impl Switch {
@@ -124,7 +143,11 @@ impl Switch {
     #[inline]
     #[must_use]
     pub fn new(val: Expression, cases: Box<[Case]>) -> Self {
-        Self { val, cases }
+        Self {
+            val,
+            cases,
+            span: NodeSpan::none(),
+        }
     }
 
     /// Gets the value to switch.
@@ -152,6 +175,21 @@ impl Switch {
         }
         None
     }
+
+    /// Sets the location of this `switch` statement in the source code.
+    #[inline]
+    #[must_use]
+    pub const fn with_span(mut self, span: Span) -> Self {
+        self.span = NodeSpan::new(span);
+        self
+    }
+}
+
+impl Spanned for Switch {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        self.span.get()
+    }
 }
 
 impl ToIndentedString for Switch {
@@ -1,7 +1,7 @@
 use crate::{
     statement::Statement,
     visitor::{VisitWith, Visitor, VisitorMut},
-    Expression,
+    Expression, NodeSpan, Span, Spanned,
 };
 use boa_interner::{Interner, ToInternedString};
 use core::ops::ControlFlow;
@@ -25,6 +25,7 @@ use core::ops::ControlFlow;
 #[derive(Clone, Debug, PartialEq)]
 pub struct Throw {
     target: Expression,
+    span: NodeSpan,
 }
 
 impl Throw {
@@ -37,7 +38,25 @@ impl Throw {
     /// Creates a `Throw` AST node.
     #[must_use]
     pub const fn new(target: Expression) -> Self {
-        Self { target }
+        Self {
+            target,
+            span: NodeSpan::none(),
+        }
+    }
+
+    /// Sets the location of this `throw` statement in the source code.
+    #[inline]
+    #[must_use]
+    pub const fn with_span(mut self, span: Span) -> Self {
+        self.span = NodeSpan::new(span);
+        self
+    }
+}
+
+impl Spanned for Throw {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        self.span.get()
     }
 }
 
@@ -5,6 +5,7 @@ use crate::visitor::{VisitWith, Visitor, VisitorMut};
 use crate::{
     declaration::Binding,
     statement::{Block, Statement},
+    NodeSpan, Span, Spanned,
 };
 use boa_interner::{Interner, ToIndentedString, ToInternedString};
 use core::ops::ControlFlow;
@@ -28,6 +29,7 @@ use core::ops::ControlFlow;
 pub struct Try {
     block: Block,
     handler: ErrorHandler,
+    span: NodeSpan,
 }
 
 /// The type of error handler in a [`Try`] statement.
@@ -48,7 +50,11 @@ impl Try {
     #[inline]
     #[must_use]
     pub const fn new(block: Block, handler: ErrorHandler) -> Self {
-        Self { block, handler }
+        Self {
+            block,
+            handler,
+            span: NodeSpan::none(),
+        }
     }
 
     /// Gets the `try` block.
@@ -77,6 +83,21 @@ impl Try {
             ErrorHandler::Catch(_) => None,
         }
     }
+
+    /// Sets the location of this `try` statement in the source code.
+    #[inline]
+    #[must_use]
+    pub const fn with_span(mut self, span: Span) -> Self {
+        self.span = NodeSpan::new(span);
+        self
+    }
+}
+
+impl Spanned for Try {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        self.span.get()
+    }
 }
 
 impl ToIndentedString for Try {
@@ -144,6 +165,7 @@ impl VisitWith for Try {
 pub struct Catch {
     parameter: Option<Binding>,
     block: Block,
+    span: NodeSpan,
 }
 
 impl Catch {
@@ -151,7 +173,11 @@ impl Catch {
     #[inline]
     #[must_use]
     pub const fn new(parameter: Option<Binding>, block: Block) -> Self {
-        Self { parameter, block }
+        Self {
+            parameter,
+            block,
+            span: NodeSpan::none(),
+        }
     }
 
     /// Gets the parameter of the catch block.
@@ -167,6 +193,21 @@ impl Catch {
     pub const fn block(&self) -> &Block {
         &self.block
     }
+
+    /// Sets the location of this `catch` block in the source code.
+    #[inline]
+    #[must_use]
+    pub const fn with_span(mut self, span: Span) -> Self {
+        self.span = NodeSpan::new(span);
+        self
+    }
+}
+
+impl Spanned for Catch {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        self.span.get()
+    }
 }
 
 impl ToIndentedString for Catch {
@@ -212,6 +253,7 @@ impl VisitWith for Catch {
 #[derive(Clone, Debug, PartialEq)]
 pub struct Finally {
     block: Block,
+    span: NodeSpan,
 }
 
 impl Finally {
@@ -221,6 +263,21 @@ impl Finally {
     pub const fn block(&self) -> &Block {
         &self.block
     }
+
+    /// Sets the location of this `finally` block in the source code.
+    #[inline]
+    #[must_use]
+    pub const fn with_span(mut self, span: Span) -> Self {
+        self.span = NodeSpan::new(span);
+        self
+    }
+}
+
+impl Spanned for Finally {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        self.span.get()
+    }
 }
 
 impl ToIndentedString for Finally {
@@ -235,7 +292,10 @@ impl ToIndentedString for Finally {
 impl From<Block> for Finally {
     #[inline]
     fn from(block: Block) -> Self {
-        Self { block }
+        Self {
+            block,
+            span: NodeSpan::none(),
+        }
     }
 }
 
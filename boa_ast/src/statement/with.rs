@@ -3,6 +3,7 @@ use crate::{
     statement::Statement,
     try_break,
     visitor::{VisitWith, Visitor, VisitorMut},
+    NodeSpan, Span, Spanned,
 };
 use boa_interner::{Interner, ToInternedString};
 use core::ops::ControlFlow;
@@ -21,6 +22,7 @@ use core::ops::ControlFlow;
 pub struct With {
     expression: Expression,
     statement: Box<Statement>,
+    span: NodeSpan,
 }
 
 impl With {
@@ -30,6 +32,7 @@ impl With {
         Self {
             expression,
             statement: Box::new(statement),
+            span: NodeSpan::none(),
         }
     }
 
@@ -44,6 +47,21 @@ impl With {
     pub const fn statement(&self) -> &Statement {
         &self.statement
     }
+
+    /// Sets the location of this `with` statement in the source code.
+    #[inline]
+    #[must_use]
+    pub const fn with_span(mut self, span: Span) -> Self {
+        self.span = NodeSpan::new(span);
+        self
+    }
+}
+
+impl Spanned for With {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        self.span.get()
+    }
 }
 
 impl From<With> for Statement {
@@ -5,6 +5,7 @@ use crate::{
     statement::Statement,
     try_break,
     visitor::{VisitWith, Visitor, VisitorMut},
+    Span, Spanned,
 };
 use boa_interner::{Interner, ToIndentedString};
 use core::ops::ControlFlow;
@@ -67,6 +68,16 @@ impl From<Declaration> for StatementListItem {
     }
 }
 
+impl Spanned for StatementListItem {
+    #[inline]
+    fn span(&self) -> Option<Span> {
+        match self {
+            Self::Statement(s) => s.span(),
+            Self::Declaration(d) => d.span(),
+        }
+    }
+}
+
 impl VisitWith for StatementListItem {
     fn visit_with<'a, V>(&'a self, visitor: &mut V) -> ControlFlow<V::BreakTy>
     where
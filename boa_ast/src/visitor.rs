@@ -7,9 +7,9 @@ use std::ops::ControlFlow;
 
 use crate::{
     declaration::{
-        Binding, Declaration, ExportDeclaration, ExportSpecifier, ImportDeclaration, ImportKind,
-        ImportSpecifier, LexicalDeclaration, ModuleSpecifier, ReExportKind, VarDeclaration,
-        Variable, VariableList,
+        Binding, Declaration, ExportDeclaration, ExportSpecifier, ImportAttribute,
+        ImportDeclaration, ImportKind, ImportSpecifier, LexicalDeclaration, ModuleSpecifier,
+        ReExportKind, VarDeclaration, Variable, VariableList,
     },
     expression::{
         access::{
@@ -26,7 +26,7 @@ use crate::{
     },
     function::{
         ArrowFunction, AsyncArrowFunction, AsyncFunction, AsyncGenerator, Class, ClassElement,
-        FormalParameter, FormalParameterList, Function, Generator, PrivateName,
+        Decorator, FormalParameter, FormalParameterList, Function, Generator, PrivateName,
     },
     pattern::{ArrayPattern, ArrayPatternElement, ObjectPattern, ObjectPatternElement, Pattern},
     property::{MethodDefinition, PropertyDefinition, PropertyName},
@@ -150,6 +150,7 @@ node_ref! {
     Identifier,
     FormalParameterList,
     ClassElement,
+    Decorator,
     PrivateName,
     VariableList,
     Variable,
@@ -204,6 +205,7 @@ node_ref! {
     ModuleItemList,
     ModuleItem,
     ModuleSpecifier,
+    ImportAttribute,
     ImportKind,
     ImportDeclaration,
     ImportSpecifier,
@@ -252,6 +254,7 @@ pub trait Visitor<'ast>: Sized {
     define_visit!(visit_identifier, Identifier);
     define_visit!(visit_formal_parameter_list, FormalParameterList);
     define_visit!(visit_class_element, ClassElement);
+    define_visit!(visit_decorator, Decorator);
     define_visit!(visit_private_name, PrivateName);
     define_visit!(visit_variable_list, VariableList);
     define_visit!(visit_variable, Variable);
@@ -306,6 +309,7 @@ pub trait Visitor<'ast>: Sized {
     define_visit!(visit_module_item_list, ModuleItemList);
     define_visit!(visit_module_item, ModuleItem);
     define_visit!(visit_module_specifier, ModuleSpecifier);
+    define_visit!(visit_import_attribute, ImportAttribute);
     define_visit!(visit_import_kind, ImportKind);
     define_visit!(visit_import_declaration, ImportDeclaration);
     define_visit!(visit_import_specifier, ImportSpecifier);
@@ -351,6 +355,7 @@ pub trait Visitor<'ast>: Sized {
             NodeRef::Identifier(n) => self.visit_identifier(n),
             NodeRef::FormalParameterList(n) => self.visit_formal_parameter_list(n),
             NodeRef::ClassElement(n) => self.visit_class_element(n),
+            NodeRef::Decorator(n) => self.visit_decorator(n),
             NodeRef::PrivateName(n) => self.visit_private_name(n),
             NodeRef::VariableList(n) => self.visit_variable_list(n),
             NodeRef::Variable(n) => self.visit_variable(n),
@@ -405,6 +410,7 @@ pub trait Visitor<'ast>: Sized {
             NodeRef::ModuleItemList(n) => self.visit_module_item_list(n),
             NodeRef::ModuleItem(n) => self.visit_module_item(n),
             NodeRef::ModuleSpecifier(n) => self.visit_module_specifier(n),
+            NodeRef::ImportAttribute(n) => self.visit_import_attribute(n),
             NodeRef::ImportKind(n) => self.visit_import_kind(n),
             NodeRef::ImportDeclaration(n) => self.visit_import_declaration(n),
             NodeRef::ImportSpecifier(n) => self.visit_import_specifier(n),
@@ -455,6 +461,7 @@ pub trait VisitorMut<'ast>: Sized {
     define_visit_mut!(visit_identifier_mut, Identifier);
     define_visit_mut!(visit_formal_parameter_list_mut, FormalParameterList);
     define_visit_mut!(visit_class_element_mut, ClassElement);
+    define_visit_mut!(visit_decorator_mut, Decorator);
     define_visit_mut!(visit_private_name_mut, PrivateName);
     define_visit_mut!(visit_variable_list_mut, VariableList);
     define_visit_mut!(visit_variable_mut, Variable);
@@ -509,6 +516,7 @@ pub trait VisitorMut<'ast>: Sized {
     define_visit_mut!(visit_module_item_list_mut, ModuleItemList);
     define_visit_mut!(visit_module_item_mut, ModuleItem);
     define_visit_mut!(visit_module_specifier_mut, ModuleSpecifier);
+    define_visit_mut!(visit_import_attribute_mut, ImportAttribute);
     define_visit_mut!(visit_import_kind_mut, ImportKind);
     define_visit_mut!(visit_import_declaration_mut, ImportDeclaration);
     define_visit_mut!(visit_import_specifier_mut, ImportSpecifier);
@@ -554,6 +562,7 @@ pub trait VisitorMut<'ast>: Sized {
             NodeRefMut::Identifier(n) => self.visit_identifier_mut(n),
             NodeRefMut::FormalParameterList(n) => self.visit_formal_parameter_list_mut(n),
             NodeRefMut::ClassElement(n) => self.visit_class_element_mut(n),
+            NodeRefMut::Decorator(n) => self.visit_decorator_mut(n),
             NodeRefMut::PrivateName(n) => self.visit_private_name_mut(n),
             NodeRefMut::VariableList(n) => self.visit_variable_list_mut(n),
             NodeRefMut::Variable(n) => self.visit_variable_mut(n),
@@ -608,6 +617,7 @@ pub trait VisitorMut<'ast>: Sized {
             NodeRefMut::ModuleItemList(n) => self.visit_module_item_list_mut(n),
             NodeRefMut::ModuleItem(n) => self.visit_module_item_mut(n),
             NodeRefMut::ModuleSpecifier(n) => self.visit_module_specifier_mut(n),
+            NodeRefMut::ImportAttribute(n) => self.visit_import_attribute_mut(n),
             NodeRefMut::ImportKind(n) => self.visit_import_kind_mut(n),
             NodeRefMut::ImportDeclaration(n) => self.visit_import_declaration_mut(n),
             NodeRefMut::ImportSpecifier(n) => self.visit_import_specifier_mut(n),
@@ -1,4 +1,9 @@
-use boa_engine::{object::ObjectInitializer, Context, JsObject, JsResult, JsValue, NativeFunction};
+use boa_engine::{
+    object::{FunctionObjectBuilder, ObjectInitializer},
+    property::Attribute,
+    Context, JsArgs, JsNativeError, JsObject, JsResult, JsString, JsValue, NativeFunction,
+};
+use std::{fs::File, io::BufWriter, time::Duration};
 
 /// Trigger garbage collection.
 fn collect(_: &JsValue, _: &[JsValue], _: &mut Context<'_>) -> JsResult<JsValue> {
@@ -6,8 +11,234 @@ fn collect(_: &JsValue, _: &[JsValue], _: &mut Context<'_>) -> JsResult<JsValue>
     Ok(JsValue::undefined())
 }
 
+/// Returns an object with the statistics of the garbage collector.
+fn stats(_: &JsValue, _: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+    let stats = context.gc_statistics();
+    let object = ObjectInitializer::new(context)
+        .property("allocatedBytes", stats.allocated_bytes, Attribute::all())
+        .property("liveBytes", stats.live_bytes, Attribute::all())
+        .property(
+            "allocatedObjects",
+            stats.allocated_objects,
+            Attribute::all(),
+        )
+        .property(
+            "allocatedEphemerons",
+            stats.allocated_ephemerons,
+            Attribute::all(),
+        )
+        .property("threshold", stats.threshold, Attribute::all())
+        .property("collections", stats.collections, Attribute::all())
+        .property("markingSteps", stats.marking_steps, Attribute::all())
+        .property("marking", stats.marking, Attribute::all())
+        .property(
+            "lastPause",
+            stats.last_pause.as_secs_f64() * 1000.0,
+            Attribute::all(),
+        )
+        .property(
+            "maxPause",
+            stats.max_pause.as_secs_f64() * 1000.0,
+            Attribute::all(),
+        )
+        .property(
+            "totalPause",
+            stats.total_pause.as_secs_f64() * 1000.0,
+            Attribute::all(),
+        )
+        .build();
+    Ok(object.into())
+}
+
+/// Takes a heap snapshot in the `.heapsnapshot` format of V8.
+///
+/// The snapshot is written to the file at the given path, or returned as a string if there is no
+/// path.
+fn snapshot(_: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+    let path = args.get_or_undefined(0);
+    if path.is_undefined() {
+        let mut bytes = Vec::new();
+        context
+            .write_heap_snapshot(&mut bytes)
+            .map_err(|err| JsNativeError::error().with_message(err.to_string()))?;
+        let snapshot = String::from_utf8(bytes).expect("heap snapshots should be valid UTF-8");
+        return Ok(JsString::from(snapshot).into());
+    }
+
+    let path = path.to_string(context)?.to_std_string_escaped();
+    File::create(path)
+        .and_then(|file| context.write_heap_snapshot(BufWriter::new(file)))
+        .map_err(|err| JsNativeError::error().with_message(err.to_string()))?;
+    Ok(JsValue::undefined())
+}
+
+fn to_usize(value: &JsValue, context: &mut Context<'_>) -> JsResult<usize> {
+    let value = value.to_length(context)?;
+    value.try_into().map_err(|_| {
+        JsNativeError::range()
+            .with_message(format!("Argument {value} greater than usize::MAX"))
+            .into()
+    })
+}
+
+fn get_threshold(_: &JsValue, _: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+    Ok(JsValue::from(context.gc_config().threshold()))
+}
+
+fn set_threshold(_: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+    let value = to_usize(args.get_or_undefined(0), context)?;
+    let mut config = context.gc_config();
+    config.set_threshold(value);
+    context.set_gc_config(config);
+    Ok(JsValue::undefined())
+}
+
+fn get_growth_factor(_: &JsValue, _: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+    Ok(JsValue::from(context.gc_config().growth_factor()))
+}
+
+fn set_growth_factor(
+    _: &JsValue,
+    args: &[JsValue],
+    context: &mut Context<'_>,
+) -> JsResult<JsValue> {
+    let value = args.get_or_undefined(0).to_number(context)?;
+    if !value.is_finite() || value < 1.0 {
+        return Err(JsNativeError::range()
+            .with_message("growth factor must be a finite number greater or equal to 1")
+            .into());
+    }
+    let mut config = context.gc_config();
+    config.set_growth_factor(value);
+    context.set_gc_config(config);
+    Ok(JsValue::undefined())
+}
+
+fn get_heap_limit(_: &JsValue, _: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+    Ok(context
+        .runtime_limits()
+        .heap_limit()
+        .map_or_else(JsValue::null, JsValue::from))
+}
+
+fn set_heap_limit(_: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+    let value = args.get_or_undefined(0);
+    let limit = if value.is_null_or_undefined() {
+        None
+    } else {
+        Some(to_usize(value, context)?)
+    };
+    context.runtime_limits_mut().set_heap_limit(limit);
+    Ok(JsValue::undefined())
+}
+
+fn get_incremental_budget(
+    _: &JsValue,
+    _: &[JsValue],
+    context: &mut Context<'_>,
+) -> JsResult<JsValue> {
+    Ok(context
+        .gc_config()
+        .incremental_budget()
+        .map_or_else(JsValue::null, |budget| {
+            JsValue::from(budget.as_secs_f64() * 1000.0)
+        }))
+}
+
+fn set_incremental_budget(
+    _: &JsValue,
+    args: &[JsValue],
+    context: &mut Context<'_>,
+) -> JsResult<JsValue> {
+    let value = args.get_or_undefined(0);
+    let budget = if value.is_null_or_undefined() {
+        None
+    } else {
+        let millis = value.to_number(context)?;
+        let budget = Duration::try_from_secs_f64(millis / 1000.0).map_err(|_| {
+            JsNativeError::range()
+                .with_message("incremental budget must be a finite, non-negative number")
+        })?;
+        Some(budget)
+    };
+    let mut config = context.gc_config();
+    config.set_incremental_budget(budget);
+    context.set_gc_config(config);
+    Ok(JsValue::undefined())
+}
+
 pub(super) fn create_object(context: &mut Context<'_>) -> JsObject {
+    let get_threshold =
+        FunctionObjectBuilder::new(context, NativeFunction::from_fn_ptr(get_threshold))
+            .name("get threshold")
+            .length(0)
+            .build();
+    let set_threshold =
+        FunctionObjectBuilder::new(context, NativeFunction::from_fn_ptr(set_threshold))
+            .name("set threshold")
+            .length(1)
+            .build();
+
+    let get_growth_factor =
+        FunctionObjectBuilder::new(context, NativeFunction::from_fn_ptr(get_growth_factor))
+            .name("get growthFactor")
+            .length(0)
+            .build();
+    let set_growth_factor =
+        FunctionObjectBuilder::new(context, NativeFunction::from_fn_ptr(set_growth_factor))
+            .name("set growthFactor")
+            .length(1)
+            .build();
+
+    let get_heap_limit =
+        FunctionObjectBuilder::new(context, NativeFunction::from_fn_ptr(get_heap_limit))
+            .name("get heapLimit")
+            .length(0)
+            .build();
+    let set_heap_limit =
+        FunctionObjectBuilder::new(context, NativeFunction::from_fn_ptr(set_heap_limit))
+            .name("set heapLimit")
+            .length(1)
+            .build();
+
+    let get_incremental_budget =
+        FunctionObjectBuilder::new(context, NativeFunction::from_fn_ptr(get_incremental_budget))
+            .name("get incrementalBudget")
+            .length(0)
+            .build();
+    let set_incremental_budget =
+        FunctionObjectBuilder::new(context, NativeFunction::from_fn_ptr(set_incremental_budget))
+            .name("set incrementalBudget")
+            .length(1)
+            .build();
+
     ObjectInitializer::new(context)
         .function(NativeFunction::from_fn_ptr(collect), "collect", 0)
+        .function(NativeFunction::from_fn_ptr(stats), "stats", 0)
+        .function(NativeFunction::from_fn_ptr(snapshot), "snapshot", 1)
+        .accessor(
+            "threshold",
+            Some(get_threshold),
+            Some(set_threshold),
+            Attribute::WRITABLE | Attribute::CONFIGURABLE | Attribute::NON_ENUMERABLE,
+        )
+        .accessor(
+            "growthFactor",
+            Some(get_growth_factor),
+            Some(set_growth_factor),
+            Attribute::WRITABLE | Attribute::CONFIGURABLE | Attribute::NON_ENUMERABLE,
+        )
+        .accessor(
+            "heapLimit",
+            Some(get_heap_limit),
+            Some(set_heap_limit),
+            Attribute::WRITABLE | Attribute::CONFIGURABLE | Attribute::NON_ENUMERABLE,
+        )
+        .accessor(
+            "incrementalBudget",
+            Some(get_incremental_budget),
+            Some(set_incremental_budget),
+            Attribute::WRITABLE | Attribute::CONFIGURABLE | Attribute::NON_ENUMERABLE,
+        )
         .build()
 }
@@ -3,6 +3,7 @@ use boa_engine::{
     property::Attribute,
     Context, JsArgs, JsNativeError, JsObject, JsResult, JsValue, NativeFunction,
 };
+use std::time::Duration;
 
 fn get_loop(_: &JsValue, _: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
     let max = context.runtime_limits().loop_iteration_limit();
@@ -23,14 +24,49 @@ fn get_recursion(_: &JsValue, _: &[JsValue], context: &mut Context<'_>) -> JsRes
 fn set_recursion(_: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
     let value = args.get_or_undefined(0).to_length(context)?;
     let Ok(value) = value.try_into() else {
-        return Err(
-            JsNativeError::range().with_message(format!("Argument {value} greater than usize::MAX")).into()
-        );
+        return Err(JsNativeError::range()
+            .with_message(format!("Argument {value} greater than usize::MAX"))
+            .into());
     };
     context.runtime_limits_mut().set_recursion_limit(value);
     Ok(JsValue::undefined())
 }
 
+fn get_instructions(_: &JsValue, _: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+    let max = context.runtime_limits().instruction_limit();
+    Ok(JsValue::from(max))
+}
+
+fn set_instructions(_: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+    let value = args.get_or_undefined(0).to_length(context)?;
+    context.runtime_limits_mut().set_instruction_limit(value);
+    Ok(JsValue::undefined())
+}
+
+fn get_time(_: &JsValue, _: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+    Ok(context
+        .runtime_limits()
+        .time_limit()
+        .map_or_else(JsValue::null, |limit| {
+            JsValue::from(limit.as_secs_f64() * 1000.0)
+        }))
+}
+
+fn set_time(_: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+    let value = args.get_or_undefined(0);
+    let limit = if value.is_null_or_undefined() {
+        None
+    } else {
+        let millis = value.to_number(context)?;
+        let limit = Duration::try_from_secs_f64(millis / 1000.0).map_err(|_| {
+            JsNativeError::range().with_message("time limit must be a finite, non-negative number")
+        })?;
+        Some(limit)
+    };
+    context.runtime_limits_mut().set_time_limit(limit);
+    Ok(JsValue::undefined())
+}
+
 pub(super) fn create_object(context: &mut Context<'_>) -> JsObject {
     let get_loop = FunctionObjectBuilder::new(context, NativeFunction::from_fn_ptr(get_loop))
         .name("get loop")
@@ -51,6 +87,27 @@ pub(super) fn create_object(context: &mut Context<'_>) -> JsObject {
             .name("set recursion")
             .length(1)
             .build();
+
+    let get_instructions =
+        FunctionObjectBuilder::new(context, NativeFunction::from_fn_ptr(get_instructions))
+            .name("get instructions")
+            .length(0)
+            .build();
+    let set_instructions =
+        FunctionObjectBuilder::new(context, NativeFunction::from_fn_ptr(set_instructions))
+            .name("set instructions")
+            .length(1)
+            .build();
+
+    let get_time = FunctionObjectBuilder::new(context, NativeFunction::from_fn_ptr(get_time))
+        .name("get time")
+        .length(0)
+        .build();
+    let set_time = FunctionObjectBuilder::new(context, NativeFunction::from_fn_ptr(set_time))
+        .name("set time")
+        .length(1)
+        .build();
+
     ObjectInitializer::new(context)
         .accessor(
             "loop",
@@ -64,5 +121,17 @@ pub(super) fn create_object(context: &mut Context<'_>) -> JsObject {
             Some(set_recursion),
             Attribute::WRITABLE | Attribute::CONFIGURABLE | Attribute::NON_ENUMERABLE,
         )
+        .accessor(
+            "instructions",
+            Some(get_instructions),
+            Some(set_instructions),
+            Attribute::WRITABLE | Attribute::CONFIGURABLE | Attribute::NON_ENUMERABLE,
+        )
+        .accessor(
+            "time",
+            Some(get_time),
+            Some(set_time),
+            Attribute::WRITABLE | Attribute::CONFIGURABLE | Attribute::NON_ENUMERABLE,
+        )
         .build()
 }
@@ -73,7 +73,7 @@ use boa_engine::{
     property::Attribute,
     script::Script,
     vm::flowgraph::{Direction, Graph},
-    Context, JsError, JsNativeError, JsResult, Source,
+    Context, JsError, JsNativeError, JsResult, JsString, Source,
 };
 use boa_runtime::Console;
 use clap::{Parser, ValueEnum, ValueHint};
@@ -291,6 +291,24 @@ fn generate_flowgraph(
     Ok(result)
 }
 
+/// Renders an uncaught error, including its stack trace if it has one.
+fn error_stack(error: &JsError, context: &mut Context<'_>) -> String {
+    // Runtime limit errors cannot be converted to error objects.
+    if error
+        .as_native()
+        .map_or(false, JsNativeError::is_runtime_limit)
+    {
+        return error.to_string();
+    }
+
+    error
+        .to_opaque(context)
+        .as_object()
+        .and_then(|object| object.get("stack", context).ok())
+        .and_then(|stack| stack.as_string().map(JsString::to_std_string_escaped))
+        .unwrap_or_else(|| error.to_string())
+}
+
 fn evaluate_files(
     args: &Opt,
     context: &mut Context<'_>,
@@ -315,7 +333,8 @@ fn evaluate_files(
             }
         } else if args.module {
             let result = (|| {
-                let module = Module::parse(Source::from_bytes(&buffer), None, context)?;
+                let module =
+                    Module::parse(Source::from_reader(&*buffer, Some(file)), None, context)?;
 
                 loader.insert(
                     file.canonicalize()
@@ -344,7 +363,7 @@ fn evaluate_files(
                     }
                 }
                 Err(err) => {
-                    eprintln!("Uncaught {err}");
+                    eprintln!("Uncaught {}", error_stack(&err, context));
 
                     if let Ok(err) = err.try_native(context) {
                         if let Some(cause) = err.cause() {
@@ -354,9 +373,9 @@ fn evaluate_files(
                 }
             }
         } else {
-            match context.eval(Source::from_bytes(&buffer)) {
+            match context.eval(Source::from_reader(&*buffer, Some(file))) {
                 Ok(v) => println!("{}", v.display()),
-                Err(v) => eprintln!("Uncaught {v}"),
+                Err(v) => eprintln!("Uncaught {}", error_stack(&v, context)),
             }
             context.run_jobs();
         }
@@ -0,0 +1,495 @@
+// Extracts the CLDR locale data used by `Intl` from ICU4C.
+//
+// Usage: extract <command> <locale>...
+//
+// Prints one `locale \t key \t value` line per datum. Values with several fields separate them
+// with tabs. See `generate.py` for the commands and how their output is turned into Rust tables.
+
+#include <unicode/locid.h>
+#include <unicode/numberformatter.h>
+#include <unicode/plurrule.h>
+#include <unicode/ucurr.h>
+#include <unicode/uloc.h>
+#include <unicode/ulocdata.h>
+#include <unicode/unistr.h>
+#include <unicode/ures.h>
+
+#include <cmath>
+#include <iostream>
+#include <set>
+#include <string>
+#include <vector>
+
+// Internal ICU functions, used to read the unit and display name resource bundles.
+#define U_ICUDATA_UNIT U_ICUDATA_NAME "-unit"
+#define U_ICUDATA_LANG U_ICUDATA_NAME "-lang"
+#define U_ICUDATA_REGION U_ICUDATA_NAME "-region"
+U_CAPI UResourceBundle* U_EXPORT2 ures_getByKeyWithFallback(const UResourceBundle*, const char*,
+                                                            UResourceBundle*, UErrorCode*);
+U_CAPI const UChar* U_EXPORT2 ures_getStringByKeyWithFallback(const UResourceBundle*, const char*,
+                                                              int32_t*, UErrorCode*);
+
+using namespace icu;
+using namespace icu::number;
+
+namespace {
+
+std::string utf8(const UnicodeString& s) {
+    std::string out;
+    s.toUTF8String(out);
+    return out;
+}
+
+std::string utf8(const UChar* s, int32_t len) { return utf8(UnicodeString(s, len)); }
+
+void emit(const std::string& locale, const std::string& key, const std::string& value) {
+    std::cout << locale << '\t' << key << '\t' << value << '\n';
+}
+
+// A formatted number where the number is replaced by `{0}`, the sign by `{-}` and the percent
+// sign, currency, unit or compact name by `{1}`.
+struct Template {
+    std::string pattern;
+    std::string field;
+};
+
+Template make_template(const LocalizedNumberFormatter& formatter, double value) {
+    UErrorCode status = U_ZERO_ERROR;
+    FormattedNumber formatted = formatter.formatDouble(value, status);
+    UnicodeString string = formatted.toString(status);
+    int number_start = -1, number_end = -1, sign_start = -1, sign_end = -1;
+    int field_start = -1, field_end = -1;
+    ConstrainedFieldPosition position;
+    position.constrainCategory(UFIELD_CATEGORY_NUMBER);
+    while (formatted.nextPosition(position, status)) {
+        int start = position.getStart(), end = position.getLimit();
+        switch (position.getField()) {
+        case UNUM_INTEGER_FIELD:
+        case UNUM_FRACTION_FIELD:
+        case UNUM_DECIMAL_SEPARATOR_FIELD:
+        case UNUM_GROUPING_SEPARATOR_FIELD:
+            if (number_start < 0 || start < number_start) number_start = start;
+            if (end > number_end) number_end = end;
+            break;
+        case UNUM_SIGN_FIELD:
+            sign_start = start;
+            sign_end = end;
+            break;
+        case UNUM_PERCENT_FIELD:
+        case UNUM_CURRENCY_FIELD:
+        case UNUM_MEASURE_UNIT_FIELD:
+        case UNUM_COMPACT_FIELD:
+            field_start = start;
+            field_end = end;
+            break;
+        default:
+            break;
+        }
+    }
+    Template result;
+    for (int i = 0; i < string.length();) {
+        if (i == number_start) {
+            result.pattern += "{0}";
+            i = number_end;
+        } else if (i == sign_start) {
+            result.pattern += "{-}";
+            i = sign_end;
+        } else if (i == field_start) {
+            result.pattern += "{1}";
+            result.field = utf8(string.tempSubString(field_start, field_end - field_start));
+            i = field_end;
+        } else {
+            UChar32 c = string.char32At(i);
+            result.pattern += utf8(UnicodeString(c));
+            i += U16_LENGTH(c);
+        }
+    }
+    return result;
+}
+
+const char* const UNITS[] = {
+    "acre", "bit", "byte", "celsius", "centimeter", "day", "degree", "fahrenheit", "fluid-ounce",
+    "foot", "gallon", "gigabit", "gigabyte", "gram", "hectare", "hour", "inch", "kilobit",
+    "kilobyte", "kilogram", "kilometer", "liter", "megabit", "megabyte", "meter", "microsecond",
+    "mile", "mile-scandinavian", "milliliter", "millimeter", "millisecond", "minute", "month",
+    "nanosecond", "ounce", "percent", "petabyte", "pound", "second", "stone", "terabit", "terabyte",
+    "week", "yard", "year",
+    // Compound units that have their own names in CLDR.
+    "kilometer-per-hour", "liter-per-kilometer", "meter-per-second", "mile-per-gallon",
+    "mile-per-hour",
+};
+
+const char* const CURRENCIES[] = {
+    "AED", "ARS", "AUD", "BRL", "CAD", "CHF", "CLP", "CNY", "COP", "CZK", "DKK", "EGP", "EUR",
+    "GBP", "HKD", "HUF", "IDR", "ILS", "INR", "JPY", "KRW", "MXN", "MYR", "NOK", "NZD", "PHP",
+    "PKR", "PLN", "RUB", "SAR", "SEK", "SGD", "THB", "TRY", "TWD", "UAH", "USD", "VND", "ZAR",
+};
+
+const char* const WIDTHS[] = {"short", "narrow", "long"};
+
+// Numbers used to find the patterns of every plural category.
+const double SAMPLES[] = {
+    0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 25,
+    100, 101, 102, 103, 105, 111, 1000000, 0.5, 1.5, 2.5, 3.5, 5.5, 0.1, 1.1, 2.1, 0.3,
+};
+
+// Emits the template of `formatter` for each plural category, as `key/category`.
+void emit_plurals(const std::string& locale, const std::string& key,
+                  const LocalizedNumberFormatter& formatter, const PluralRules& rules) {
+    std::set<std::string> seen;
+    for (double sample : SAMPLES) {
+        UErrorCode status = U_ZERO_ERROR;
+        std::string category = utf8(rules.select(formatter.formatDouble(sample, status), status));
+        if (seen.insert(category).second) {
+            Template t = make_template(formatter, sample);
+            emit(locale, key + "/" + category, t.pattern + "\t" + t.field);
+        }
+    }
+}
+
+// Reads a string of the resource bundles of `package`, following `path`.
+bool resource(const char* package, const std::string& locale, std::vector<const char*> path,
+              std::string& out) {
+    UErrorCode status = U_ZERO_ERROR;
+    UResourceBundle* bundle = ures_open(package, locale.c_str(), &status);
+    const char* last = path.back();
+    path.pop_back();
+    for (const char* key : path) bundle = ures_getByKeyWithFallback(bundle, key, bundle, &status);
+    int32_t len;
+    const UChar* string = ures_getStringByKeyWithFallback(bundle, last, &len, &status);
+    if (U_SUCCESS(status)) out = utf8(string, len);
+    ures_close(bundle);
+    return U_SUCCESS(status);
+}
+
+// Reads a string of the unit resource bundles, following `path`.
+bool unit_resource(const std::string& locale, std::vector<const char*> path, std::string& out) {
+    return resource(U_ICUDATA_UNIT, locale, path, out);
+}
+
+void number_format(const std::string& locale) {
+    Locale icu_locale(locale.c_str());
+    UErrorCode status = U_ZERO_ERROR;
+    LocalPointer<PluralRules> rules(PluralRules::forLocale(icu_locale, status));
+    auto base = NumberFormatter::withLocale(icu_locale);
+
+    Template percent = make_template(base.unit(NoUnit::percent()), -5);
+    emit(locale, "percent", percent.pattern + "\t" + percent.field);
+
+    auto currency = base.unit(CurrencyUnit(u"EUR", status));
+    emit(locale, "currency", make_template(currency, -1).pattern);
+    emit(locale, "accounting", make_template(currency.sign(UNUM_SIGN_ACCOUNTING), -1).pattern);
+    emit_plurals(locale, "currency-name", currency.unitWidth(UNUM_UNIT_WIDTH_FULL_NAME), *rules);
+
+    LocalPointer<StringEnumeration> keywords(rules->getKeywords(status));
+    std::vector<std::string> categories;
+    while (const char* keyword = keywords->next(nullptr, status)) categories.push_back(keyword);
+    for (const char* code : CURRENCIES) {
+        UChar iso[4];
+        u_charsToUChars(code, iso, 4);
+        UBool is_choice;
+        int32_t len;
+        status = U_ZERO_ERROR;
+        const UChar* symbol =
+            ucurr_getName(iso, locale.c_str(), UCURR_SYMBOL_NAME, &is_choice, &len, &status);
+        std::string value = utf8(symbol, len);
+        const UChar* narrow = ucurr_getName(iso, locale.c_str(), UCURR_NARROW_SYMBOL_NAME,
+                                            &is_choice, &len, &status);
+        value += "\t" + utf8(narrow, len);
+        for (const std::string& category : categories) {
+            const UChar* name =
+                ucurr_getPluralName(iso, locale.c_str(), &is_choice, category.c_str(), &len, &status);
+            value += "\t" + category + "=" + utf8(name, len);
+        }
+        emit(locale, std::string("currency/") + code, value);
+    }
+
+    for (int long_names = 0; long_names < 2; long_names++) {
+        auto compact =
+            base.notation(long_names ? Notation::compactLong() : Notation::compactShort());
+        for (int magnitude = 3; magnitude <= 14; magnitude++) {
+            Template t = make_template(compact, std::pow(10.0, magnitude));
+            if (t.field.empty()) continue;
+            // The number of integer digits shown for this magnitude gives the exponent.
+            status = U_ZERO_ERROR;
+            UnicodeString two = compact.formatDouble(2 * std::pow(10.0, magnitude), status).toString(status);
+            int digits = 0;
+            for (int i = 0; i < two.length(); i++) digits += u_isdigit(two.charAt(i)) ? 1 : 0;
+            int exponent = magnitude - (digits - 1);
+            std::string key = std::string(long_names ? "compact-long/" : "compact-short/") +
+                              std::to_string(magnitude);
+            emit(locale, key, std::to_string(exponent));
+            // Compact patterns are selected by the plural category of the scaled number.
+            std::set<std::string> seen;
+            for (int i = 0; i < 40; i++) {
+                for (double fraction : {0.0, 0.5}) {
+                    double scaled = std::pow(10.0, digits - 1) + i + fraction;
+                    std::string category = utf8(rules->select(scaled));
+                    if (seen.insert(category).second) {
+                        Template t = make_template(compact, scaled * std::pow(10.0, exponent));
+                        emit(locale, key + "/" + category, t.pattern + "\t" + t.field);
+                    }
+                }
+            }
+        }
+    }
+
+    const UNumberUnitWidth unit_widths[] = {UNUM_UNIT_WIDTH_SHORT, UNUM_UNIT_WIDTH_NARROW,
+                                            UNUM_UNIT_WIDTH_FULL_NAME};
+    const char* const tables[] = {"unitsShort", "unitsNarrow", "units"};
+    for (int width = 0; width < 3; width++) {
+        std::string per;
+        if (unit_resource(locale, {tables[width], "compound", "per"}, per)) {
+            emit(locale, std::string("per/") + WIDTHS[width], per);
+        }
+    }
+    for (const char* id : UNITS) {
+        status = U_ZERO_ERROR;
+        MeasureUnit unit = MeasureUnit::forIdentifier(id, status);
+        for (int width = 0; width < 3; width++) {
+            std::string key = std::string("unit/") + id + "/" + WIDTHS[width];
+            emit_plurals(locale, key, base.unit(unit).unitWidth(unit_widths[width]), *rules);
+            std::string per;
+            if (unit_resource(locale, {tables[width], unit.getType(), unit.getSubtype(), "per"},
+                              per)) {
+                emit(locale, std::string("per-unit/") + id + "/" + WIDTHS[width], per);
+            }
+        }
+    }
+}
+
+const char* const RELATIVE_TIME_UNITS[] = {
+    "second", "minute", "hour", "day", "week", "month", "quarter", "year",
+};
+
+void relative_time(const std::string& locale) {
+    UErrorCode status = U_ZERO_ERROR;
+    LocalPointer<PluralRules> rules(PluralRules::forLocale(Locale(locale.c_str()), status));
+    LocalPointer<StringEnumeration> keywords(rules->getKeywords(status));
+    std::vector<std::string> categories;
+    while (const char* keyword = keywords->next(nullptr, status)) categories.push_back(keyword);
+
+    for (const char* unit : RELATIVE_TIME_UNITS) {
+        for (const char* style : {"", "-short", "-narrow"}) {
+            std::string field = std::string(unit) + style;
+            status = U_ZERO_ERROR;
+            UResourceBundle* bundle = ures_open(nullptr, locale.c_str(), &status);
+            bundle = ures_getByKeyWithFallback(bundle, "fields", bundle, &status);
+            bundle = ures_getByKeyWithFallback(bundle, field.c_str(), bundle, &status);
+            if (U_FAILURE(status)) {
+                ures_close(bundle);
+                continue;
+            }
+            for (const char* tense : {"future", "past"}) {
+                for (const std::string& category : categories) {
+                    std::string value;
+                    UErrorCode inner = U_ZERO_ERROR;
+                    UResourceBundle* patterns =
+                        ures_getByKeyWithFallback(bundle, "relativeTime", nullptr, &inner);
+                    patterns = ures_getByKeyWithFallback(patterns, tense, patterns, &inner);
+                    int32_t len;
+                    const UChar* pattern =
+                        ures_getStringByKeyWithFallback(patterns, category.c_str(), &len, &inner);
+                    if (U_SUCCESS(inner)) {
+                        emit(locale, field + "/" + tense + "/" + category, utf8(pattern, len));
+                    }
+                    ures_close(patterns);
+                }
+            }
+            for (const char* offset : {"-2", "-1", "0", "1", "2"}) {
+                UErrorCode inner = U_ZERO_ERROR;
+                UResourceBundle* names =
+                    ures_getByKeyWithFallback(bundle, "relative", nullptr, &inner);
+                int32_t len;
+                const UChar* name = ures_getStringByKeyWithFallback(names, offset, &len, &inner);
+                if (U_SUCCESS(inner)) {
+                    emit(locale, field + "/relative/" + offset, utf8(name, len));
+                }
+                ures_close(names);
+            }
+            ures_close(bundle);
+        }
+    }
+}
+
+// The codes that have a display name, in the format of the resource bundles.
+const char* const LANGUAGES[] = {
+    "af", "am", "ar", "ar_001", "as", "az", "be", "bg", "bn", "bo", "br", "bs", "ca", "chr", "cs",
+    "cy", "da", "de", "de_AT", "de_CH", "dz", "el", "en", "en_AU", "en_CA", "en_GB", "en_US", "eo",
+    "es", "es_419", "es_ES", "es_MX", "et", "eu", "fa", "fa_AF", "ff", "fi", "fil", "fo", "fr",
+    "fr_CA", "fr_CH", "fy", "ga", "gd", "gl", "gu", "ha", "haw", "he", "hi", "hr", "ht", "hu", "hy",
+    "id", "ig", "is", "it", "ja", "jv", "ka", "kk", "km", "kn", "ko", "ku", "ky", "la", "lb", "lo",
+    "lt", "lv", "mg", "mi", "mk", "ml", "mn", "mr", "ms", "mt", "my", "nb", "ne", "nl", "nl_BE",
+    "nn", "no", "or", "pa", "pl", "ps", "pt", "pt_BR", "pt_PT", "ro", "ro_MD", "ru", "rw", "sa",
+    "sd", "si", "sk", "sl", "so", "sq", "sr", "sv", "sw", "sw_CD", "ta", "te", "tg", "th", "ti",
+    "tk", "tr", "uk", "ur", "uz", "vi", "xh", "yi", "yo", "zh", "zh_Hans", "zh_Hant", "zu",
+};
+
+const char* const REGIONS[] = {
+    "001", "002", "003", "005", "009", "019", "142", "150", "419", "AE", "AF", "AL", "AM", "AO",
+    "AQ", "AR", "AT", "AU", "AZ", "BA", "BD", "BE", "BG", "BO", "BR", "BY", "CA", "CD", "CH", "CL",
+    "CM", "CN", "CO", "CR", "CU", "CY", "CZ", "DE", "DK", "DO", "DZ", "EC", "EE", "EG", "ES", "ET",
+    "EU", "FI", "FR", "GB", "GE", "GH", "GR", "GT", "HK", "HR", "HU", "ID", "IE", "IL", "IN", "IQ",
+    "IR", "IS", "IT", "JM", "JO", "JP", "KE", "KH", "KP", "KR", "KW", "KZ", "LB", "LK", "LT", "LU",
+    "LV", "MA", "MD", "MK", "MM", "MN", "MO", "MT", "MX", "MY", "NG", "NL", "NO", "NP", "NZ", "PA",
+    "PE", "PH", "PK", "PL", "PR", "PS", "PT", "PY", "QA", "RO", "RS", "RU", "SA", "SE", "SG", "SI",
+    "SK", "SN", "SY", "TH", "TN", "TR", "TW", "TZ", "UA", "UG", "UN", "US", "UY", "UZ", "VE", "VN",
+    "ZA", "ZM", "ZW",
+};
+
+const char* const SCRIPTS[] = {
+    "Arab", "Armn", "Beng", "Bopo", "Brai", "Cyrl", "Deva", "Ethi", "Geor", "Grek", "Gujr",
+    "Guru", "Hang", "Hani", "Hans", "Hant", "Hebr", "Hira", "Jpan", "Kana", "Khmr", "Knda",
+    "Kore", "Laoo", "Latn", "Mlym", "Mong", "Mymr", "Orya", "Sinh", "Taml", "Telu", "Thai",
+};
+
+const char* const NAMED_CURRENCIES[] = {
+    "AED", "ARS", "AUD", "BDT", "BGN", "BHD", "BOB", "BRL", "CAD", "CHF", "CLP", "CNY", "COP",
+    "CRC", "CZK", "DKK", "DOP", "DZD", "EGP", "ETB", "EUR", "GBP", "GEL", "GHS", "GTQ", "HKD",
+    "HUF", "IDR", "ILS", "INR", "IQD", "IRR", "ISK", "JMD", "JOD", "JPY", "KES", "KRW", "KWD",
+    "KZT", "LBP", "LKR", "MAD", "MDL", "MXN", "MYR", "NGN", "NOK", "NPR", "NZD", "OMR", "PAB",
+    "PEN", "PHP", "PKR", "PLN", "PYG", "QAR", "RON", "RSD", "RUB", "SAR", "SEK", "SGD", "THB",
+    "TND", "TRY", "TWD", "TZS", "UAH", "UGX", "USD", "UYU", "UZS", "VND", "XAF", "XOF", "ZAR",
+};
+
+// Pairs of Unicode calendar identifiers and their keys in the resource bundles.
+const char* const CALENDARS[][2] = {
+    {"buddhist", "buddhist"},
+    {"chinese", "chinese"},
+    {"coptic", "coptic"},
+    {"dangi", "dangi"},
+    {"ethioaa", "ethiopic-amete-alem"},
+    {"ethiopic", "ethiopic"},
+    {"gregory", "gregorian"},
+    {"hebrew", "hebrew"},
+    {"indian", "indian"},
+    {"islamic", "islamic"},
+    {"islamic-civil", "islamic-civil"},
+    {"islamic-rgsa", "islamic-rgsa"},
+    {"islamic-tbla", "islamic-tbla"},
+    {"islamic-umalqura", "islamic-umalqura"},
+    {"iso8601", "iso8601"},
+    {"japanese", "japanese"},
+    {"persian", "persian"},
+    {"roc", "roc"},
+};
+
+// Pairs of date time field codes of `Intl.DisplayNames` and their keys in the resource bundles.
+const char* const FIELDS[][2] = {
+    {"era", "era"},       {"year", "year"},           {"quarter", "quarter"},
+    {"month", "month"},   {"weekOfYear", "week"},     {"weekday", "weekday"},
+    {"day", "day"},       {"dayPeriod", "dayperiod"}, {"hour", "hour"},
+    {"minute", "minute"}, {"second", "second"},       {"timeZoneName", "zone"},
+};
+
+std::string bcp47(std::string code) {
+    for (char& c : code) {
+        if (c == '_') c = '-';
+    }
+    return code;
+}
+
+void display_names(const std::string& locale) {
+    std::string value;
+    for (const char* code : LANGUAGES) {
+        if (resource(U_ICUDATA_LANG, locale, {"Languages", code}, value)) {
+            emit(locale, "language/" + bcp47(code), value);
+        }
+        if (resource(U_ICUDATA_LANG, locale, {"Languages%short", code}, value)) {
+            emit(locale, "language-short/" + bcp47(code), value);
+        }
+    }
+    for (const char* code : REGIONS) {
+        if (resource(U_ICUDATA_REGION, locale, {"Countries", code}, value)) {
+            emit(locale, std::string("region/") + code, value);
+        }
+        if (resource(U_ICUDATA_REGION, locale, {"Countries%short", code}, value)) {
+            emit(locale, std::string("region-short/") + code, value);
+        }
+    }
+    for (const char* code : SCRIPTS) {
+        if (resource(U_ICUDATA_LANG, locale, {"Scripts", code}, value)) {
+            emit(locale, std::string("script/") + code, value);
+        }
+        if (resource(U_ICUDATA_LANG, locale, {"Scripts%stand-alone", code}, value)) {
+            emit(locale, std::string("script-stand-alone/") + code, value);
+        }
+    }
+    for (const char* code : NAMED_CURRENCIES) {
+        UChar iso[4];
+        u_charsToUChars(code, iso, 4);
+        UBool is_choice;
+        int32_t len;
+        UErrorCode status = U_ZERO_ERROR;
+        const UChar* name =
+            ucurr_getName(iso, locale.c_str(), UCURR_LONG_NAME, &is_choice, &len, &status);
+        // ICU falls back to the code itself when there's no name.
+        if (U_SUCCESS(status) && utf8(name, len) != code) {
+            emit(locale, std::string("currency/") + code, utf8(name, len));
+        }
+    }
+    for (const auto& calendar : CALENDARS) {
+        if (resource(U_ICUDATA_LANG, locale, {"Types", "calendar", calendar[1]}, value)) {
+            emit(locale, std::string("calendar/") + calendar[0], value);
+        }
+    }
+    for (const auto& field : FIELDS) {
+        for (const char* width : {"", "-short", "-narrow"}) {
+            std::string key = std::string(field[1]) + width;
+            if (resource(nullptr, locale, {"fields", key.c_str(), "dn"}, value)) {
+                emit(locale, std::string("field/") + field[0] + (*width ? width : "-long"),
+                     value);
+            }
+        }
+    }
+    for (const char* key : {"pattern", "separator"}) {
+        if (resource(U_ICUDATA_LANG, locale, {"localeDisplayPattern", key}, value)) {
+            emit(locale, key, value);
+        }
+    }
+}
+
+void parents(const std::string& locale) {
+    UErrorCode status = U_ZERO_ERROR;
+    UResourceBundle* bundle = ures_openDirect(nullptr, locale.c_str(), &status);
+    int32_t len;
+    const UChar* parent = ures_getStringByKey(bundle, "%%Parent", &len, &status);
+    if (U_SUCCESS(status)) emit(locale, "parent", utf8(parent, len));
+    ures_close(bundle);
+}
+
+}  // namespace
+
+int main(int argc, char** argv) {
+    if (argc < 2) {
+        std::cerr << "usage: extract <cldr|locales|parents|number-format|relative-time|display-names> <locale>...\n";
+        return 1;
+    }
+    std::string command = argv[1];
+    if (command == "cldr") {
+        UVersionInfo version;
+        UErrorCode status = U_ZERO_ERROR;
+        ulocdata_getCLDRVersion(version, &status);
+        std::cout << int(version[0]) << '.' << int(version[1]) << '\n';
+        return 0;
+    }
+    if (command == "locales") {
+        for (int i = 0; i < uloc_countAvailable(); i++) std::cout << uloc_getAvailable(i) << '\n';
+        return 0;
+    }
+    for (int i = 2; i < argc; i++) {
+        if (command == "parents") {
+            parents(argv[i]);
+        } else if (command == "number-format") {
+            number_format(argv[i]);
+        } else if (command == "relative-time") {
+            relative_time(argv[i]);
+        } else if (command == "display-names") {
+            display_names(argv[i]);
+        } else {
+            std::cerr << "unknown command " << command << '\n';
+            return 1;
+        }
+    }
+    return 0;
+}
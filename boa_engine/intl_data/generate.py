@@ -0,0 +1,269 @@
+#!/usr/bin/env python3
+"""Generates the CLDR locale data tables of `boa_engine/src/builtins/intl/data`.
+
+The data is extracted from the ICU4C library installed on the system, using `extract.cpp`:
+
+    c++ -std=c++17 extract.cpp -o extract -licui18n -licuuc -licudata
+    python3 generate.py ./extract
+
+Every table maps a locale to a sorted list of `(key, value)` pairs. Regional locales only store
+the values that differ from the ones of their parent locale. Values are strings in a small format:
+
+- Patterns: `{0}` is the number, `{-}` the sign, `{}` the placeholder of a currency or a name, and
+  `{text}` is a field of the pattern, like a percent sign or a unit. Any other text is a literal.
+- Plural values: either one value for all the plural categories or `one=...;other=...`, where
+  `other` always comes last.
+- Multiple values in a single entry are separated by `|`.
+- Named values: `key=...;key=...`, like the names of relative days (`-1=yesterday;0=today`).
+"""
+
+import collections
+import os
+import subprocess
+import sys
+
+LANGUAGES = [
+    'de', 'en', 'es', 'fr', 'hi', 'id', 'it', 'ja', 'ko', 'nl', 'pl', 'pt', 'ru', 'sv', 'th', 'tr',
+    'uk', 'vi', 'zh',
+]
+CATEGORIES = ['zero', 'one', 'two', 'few', 'many', 'other']
+WIDTHS = ['short', 'narrow', 'long']
+OUTPUT = os.path.join(os.path.dirname(os.path.abspath(__file__)), '..', 'src', 'builtins', 'intl',
+                      'data')
+HEADER = """//! {description}
+//! Version: CLDR {cldr}
+//!
+//! This file is generated by `boa_engine/intl_data/generate.py`. Please do not modify it directly."""
+
+
+def run(command, locales):
+    """Runs `extract` and returns the extracted data as `{locale: {key: [fields]}}`."""
+    output = subprocess.run([EXTRACT, command, *locales], check=True, capture_output=True,
+                            encoding='utf-8').stdout
+    data = collections.defaultdict(dict)
+    for line in output.splitlines():
+        locale, key, *value = line.split('\t')
+        data[locale][key] = value
+    return data
+
+
+def check(atom):
+    """Checks that a string extracted from CLDR does not collide with the value format."""
+    assert not any(c in atom for c in '{}|;='), atom
+    return atom
+
+
+def pattern(fields, placeholder=None):
+    """Converts a pattern of `extract`, where the field is `{1}`, to the value format."""
+    field = fields[1] if placeholder is None else placeholder
+    text = fields[0]
+    check(text.replace('{0}', '').replace('{-}', '').replace('{1}', ''))
+    return text.replace('{1}', '{%s}' % check(field))
+
+
+def plural(values):
+    """Merges the values of each plural category."""
+    values = {category: value for category, value in values.items() if value is not None}
+    other = values['other']
+    entries = [(c, values[c]) for c in CATEGORIES if c in values and c != 'other' and
+               values[c] != other]
+    if not entries:
+        return other
+    return ';'.join('%s=%s' % entry for entry in entries + [('other', other)])
+
+
+def plural_patterns(data, prefix, placeholder=None):
+    return plural({c: pattern(data[prefix + c], placeholder) if prefix + c in data else None
+                   for c in CATEGORIES})
+
+
+def compound_pattern(pattern):
+    """Marks everything but the number of a compound unit pattern as part of the unit."""
+    check(pattern.replace('{0}', '').replace('{1}', ''))
+    result = ''
+    for i, piece in enumerate(pattern.replace('{1}', '\0').split('{0}')):
+        if i:
+            result += '{0}'
+        for j, part in enumerate(piece.split('\0')):
+            if j:
+                result += '{}'
+            if part:
+                result += '{' + part + '}'
+    return result
+
+
+def number_format(data):
+    """Converts the data of the `number-format` command."""
+    table = {
+        'percent': pattern(data['percent']),
+        'currency': pattern(data['currency'], ''),
+        'accounting': pattern(data['accounting'], ''),
+        'currency-name': plural_patterns(data, 'currency-name/', ''),
+    }
+    for key, value in data.items():
+        kind, _, rest = key.partition('/')
+        if kind == 'currency' and rest:
+            symbol, narrow, *names = value
+            names = dict(name.split('=', 1) for name in names)
+            names = plural({c: check(names.get(c)) if names.get(c) else None for c in CATEGORIES})
+            table[key] = '|'.join([check(symbol), check(narrow), names])
+        elif kind.startswith('compact-') and rest.isdigit():
+            table[key] = '%s|%s' % (value[0], plural_patterns(data, key + '/'))
+    units = {key.split('/')[1] for key in data if key.startswith('unit/')}
+    for unit in units:
+        widths = [plural_patterns(data, 'unit/%s/%s/' % (unit, width)) for width in WIDTHS]
+        if widths[1] == widths[0]:
+            widths[1] = ''
+        table['unit/' + unit] = '|'.join(widths)
+        per_unit = [data.get('per-unit/%s/%s' % (unit, width)) for width in WIDTHS]
+        if any(per_unit):
+            table['per-unit/' + unit] = '|'.join(compound_pattern(p[0]) if p else ''
+                                                 for p in per_unit)
+    per = [data.get('per/' + width) for width in WIDTHS]
+    per = [p or data.get('per/short') or data['per/long'] for p in per]
+    table['per'] = '|'.join(compound_pattern(p[0]) for p in per)
+    return table
+
+
+def relative_time(data):
+    """Converts the data of the `relative-time` command."""
+    table = {}
+    fields = {key.split('/')[0] for key in data}
+    for field in fields:
+        tenses = [plural({c: check_pattern(data.get('%s/%s/%s' % (field, tense, c)))
+                          for c in CATEGORIES}) for tense in ['future', 'past']]
+        table[field] = '|'.join(tenses)
+        names = [(offset, data.get('%s/relative/%s' % (field, offset)))
+                 for offset in ['-2', '-1', '0', '1', '2']]
+        names = ['%s=%s' % (offset, check(name[0])) for offset, name in names if name]
+        if names:
+            table[field + '/relative'] = ';'.join(names)
+    return table
+
+
+def display_names(data):
+    """Converts the data of the `display-names` command."""
+    table = {}
+    for key, value in data.items():
+        kind, _, code = key.partition('/')
+        if kind == 'field':
+            code, width = code.rsplit('-', 1)
+            kind = 'field' if width == 'long' else 'field-' + width
+        if code:
+            table[kind + '/' + code] = check(value[0])
+    # Alternative names fall back to the short name, and then to the long name.
+    for key in sorted(table, key=lambda key: key.startswith('field-narrow/')):
+        kind, code = key.split('/')
+        fallbacks = [kind.rsplit('-', 1)[0] + '/' + code]
+        if kind == 'field-narrow':
+            fallbacks.insert(0, 'field-short/' + code)
+        fallback = next((table[k] for k in fallbacks if k in table), None)
+        if '-' in kind and table[key] == fallback:
+            del table[key]
+    # The patterns of names with qualifiers, like `{0} ({1})`, are stored as `open|close`.
+    name_pattern = data['pattern'][0]
+    assert name_pattern.startswith('{0}') and name_pattern.count('{1}') == 1, name_pattern
+    table['pattern'] = '|'.join(check(part) for part in name_pattern[3:].split('{1}'))
+    separator = data['separator'][0]
+    assert separator.startswith('{0}') and separator.endswith('{1}'), separator
+    table['separator'] = check(separator[3:-3])
+    return table
+
+
+def check_pattern(fields):
+    """Checks a pattern where `{0}` is the only placeholder."""
+    if fields is None:
+        return None
+    check(fields[0].replace('{0}', ''))
+    return fields[0]
+
+
+def parent(locale):
+    """Returns the parent of an ICU locale, or `None` for the root locale."""
+    if locale in PARENTS:
+        return None if PARENTS[locale] == 'root' else PARENTS[locale]
+    parts = locale.split('_')
+    return '_'.join(parts[:-1]) if len(parts) > 1 else None
+
+
+def resolve(tables, locale, key):
+    while locale is not None:
+        if key in tables.get(locale, {}):
+            return tables[locale][key]
+        locale = parent(locale)
+    return None
+
+
+def bcp47(locale):
+    return locale.replace('_', '-')
+
+
+def string(s):
+    escaped = ''
+    for c in s:
+        if c in '"\\':
+            escaped += '\\' + c
+        elif c.isprintable() and (c == ' ' or not c.isspace()) and c not in '‎‏؜':
+            escaped += c
+        else:
+            escaped += '\\u{%x}' % ord(c)
+    return '"' + escaped + '"'
+
+
+def write(name, description, static, tables):
+    """Writes the tables of a service, skipping the values inherited from a parent locale."""
+    lines = [HEADER.format(description=description, cldr=CLDR), '', 'use super::LocaleData;', '',
+             'pub(in crate::builtins::intl) static %s: LocaleData = LocaleData::new(&[' % static]
+    for locale in sorted(tables, key=bcp47):
+        entries = [(k, v) for k, v in sorted(tables[locale].items())
+                   if v != resolve(tables, parent(locale), k)]
+        if not entries and parent(locale) is not None:
+            continue
+        lines.append('    (')
+        lines.append('        %s,' % string(bcp47(locale)))
+        lines.append('        &[')
+        for key, value in entries:
+            entry = '            (%s, %s),' % (string(key), string(value))
+            if len(entry) > 100:
+                entry = '            (\n                %s,\n                %s,\n            ),' % (
+                    string(key), string(value))
+            lines.append(entry)
+        lines.append('        ],')
+        lines.append('    ),')
+    lines.append(']);')
+    with open(os.path.join(OUTPUT, name), 'w', encoding='utf-8') as file:
+        file.write('\n'.join(lines) + '\n')
+
+
+def write_parents():
+    lines = [HEADER.format(description='The parent locales of CLDR that differ from truncation.',
+                           cldr=CLDR),
+             '', '/// Pairs of locales and their parents. The root locale is an empty string.',
+             'pub(super) static PARENTS: [(&str, &str); %d] = [' % len(PARENTS)]
+    for locale, parent_locale in sorted(PARENTS.items(), key=lambda item: bcp47(item[0])):
+        parent_locale = '' if parent_locale == 'root' else bcp47(parent_locale)
+        lines.append('    (%s, %s),' % (string(bcp47(locale)), string(parent_locale)))
+    lines.append('];')
+    with open(os.path.join(OUTPUT, 'parents.rs'), 'w', encoding='utf-8') as file:
+        file.write('\n'.join(lines) + '\n')
+
+
+if __name__ == '__main__':
+    EXTRACT = os.path.abspath(sys.argv[1])
+    CLDR = subprocess.run([EXTRACT, 'cldr'], check=True, capture_output=True,
+                          encoding='utf-8').stdout.strip()
+    LOCALES = subprocess.run([EXTRACT, 'locales'], check=True, capture_output=True,
+                             encoding='utf-8').stdout.split()
+    LOCALES = [locale for locale in LOCALES
+               if locale.split('_')[0] in LANGUAGES and locale != 'en_US_POSIX']
+    PARENTS = {locale: value[0] for locale, data in run('parents', LOCALES).items()
+               for value in data.values()}
+
+    write_parents()
+    write('number_format.rs', 'The CLDR data used by `Intl.NumberFormat`.', 'NUMBER_FORMAT',
+          {locale: number_format(data) for locale, data in run('number-format', LOCALES).items()})
+    write('relative_time_format.rs', 'The CLDR data used by `Intl.RelativeTimeFormat`.',
+          'RELATIVE_TIME_FORMAT',
+          {locale: relative_time(data) for locale, data in run('relative-time', LOCALES).items()})
+    write('display_names.rs', 'The CLDR data used by `Intl.DisplayNames`.', 'DISPLAY_NAMES',
+          {locale: display_names(data) for locale, data in run('display-names', LOCALES).items()})
@@ -291,6 +291,11 @@ impl JsBigInt {
     pub(crate) fn as_inner(&self) -> &RawBigInt {
         &self.inner
     }
+
+    /// Converts the `BigInt` to an [`i128`], returning `None` if it doesn't fit.
+    pub(crate) fn to_i128(&self) -> Option<i128> {
+        self.inner.to_i128()
+    }
 }
 
 impl Display for JsBigInt {
@@ -390,6 +395,15 @@ impl From<u64> for JsBigInt {
     }
 }
 
+impl From<i128> for JsBigInt {
+    #[inline]
+    fn from(value: i128) -> Self {
+        Self {
+            inner: Rc::new(RawBigInt::from(value)),
+        }
+    }
+}
+
 impl From<isize> for JsBigInt {
     #[inline]
     fn from(value: isize) -> Self {
@@ -40,13 +40,7 @@ impl IntrinsicObject for ArrayIterator {
         let _timer = Profiler::global().start_event("ArrayIterator", "init");
 
         BuiltInBuilder::with_intrinsic::<Self>(realm)
-            .prototype(
-                realm
-                    .intrinsics()
-                    .objects()
-                    .iterator_prototypes()
-                    .iterator(),
-            )
+            .prototype(realm.intrinsics().constructors().iterator().prototype())
             .static_method(Self::next, "next", 0)
             .static_property(
                 JsSymbol::to_string_tag(),
@@ -120,11 +114,9 @@ impl ArrayIterator {
         }
 
         let len = if let Some(f) = array_iterator.array.borrow().as_typed_array() {
-            if f.is_detached() {
+            if f.is_out_of_bounds() {
                 return Err(JsNativeError::typ()
-                    .with_message(
-                        "Cannot get value from typed array that has a detached array buffer",
-                    )
+                    .with_message("Cannot get value from typed array that is out of bounds")
                     .into());
             }
 
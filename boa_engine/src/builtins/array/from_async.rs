@@ -0,0 +1,351 @@
+//! Boa's implementation of the `Array.fromAsync` static method.
+//!
+//! More information:
+//!  - [Array.fromAsync proposal][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/proposal-array-from-async/
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/fromAsync
+
+use boa_gc::{Finalize, Trace};
+use boa_macros::utf16;
+
+use crate::{
+    builtins::{
+        iterable::{
+            async_iterator_close, await_then, if_abrupt_close_async_iterator, reject,
+            AsyncFromSyncIterator, IteratorHint, IteratorRecord, IteratorResult,
+        },
+        promise::PromiseCapability,
+        Number,
+    },
+    error::JsNativeError,
+    object::JsObject,
+    symbol::JsSymbol,
+    Context, JsArgs, JsResult, JsValue,
+};
+
+use super::Array;
+
+impl Array {
+    /// `Array.fromAsync ( asyncItems [ , mapfn [ , thisArg ] ] )`
+    ///
+    /// Creates a new, shallow-copied `Array` instance from an async iterable, iterable or
+    /// array-like object, awaiting each of its values.
+    ///
+    /// More information:
+    ///  - [Array.fromAsync proposal][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-array-from-async/#sec-array.fromAsync
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/fromAsync
+    #[allow(clippy::unnecessary_wraps)]
+    pub(crate) fn from_async(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        let async_items = args.get_or_undefined(0);
+        let mapfn = args.get_or_undefined(1);
+        let this_arg = args.get_or_undefined(2);
+
+        // 1. Let C be the this value.
+        // 2. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+        let capability = PromiseCapability::new(
+            &context.intrinsics().constructors().promise().constructor(),
+            context,
+        )
+        .expect("cannot fail with the %Promise% constructor");
+
+        // 3. Let fromAsyncClosure be a new Abstract Closure with no parameters that captures C, mapfn, and thisArg and performs the following steps when called:
+        // 4. Perform AsyncFunctionStart(promiseCapability, fromAsyncClosure).
+        let result = FromAsync::start(this, async_items, mapfn, this_arg, context);
+
+        let result = match result {
+            Ok(result) => capability
+                .resolve()
+                .call(&JsValue::undefined(), &[result], context),
+            Err(err) => {
+                let err = err.to_opaque(context);
+                capability
+                    .reject()
+                    .call(&JsValue::undefined(), &[err], context)
+            }
+        };
+        result.expect("calling the resolving functions of %Promise% cannot fail");
+
+        // 5. Return promiseCapability.[[Promise]].
+        Ok(capability.promise().clone().into())
+    }
+}
+
+/// The source of the values of `Array.fromAsync`.
+#[derive(Debug, Clone, Trace, Finalize)]
+enum Source {
+    /// An async iterator, or a sync iterator wrapped by `CreateAsyncFromSyncIterator`.
+    Iterator(IteratorRecord),
+    /// An array-like object, with its length.
+    ArrayLike { array_like: JsObject, len: u64 },
+}
+
+/// The state of the `fromAsyncClosure` of `Array.fromAsync` between awaits.
+#[derive(Debug, Clone, Trace, Finalize)]
+struct FromAsync {
+    source: Source,
+    a: JsObject,
+    mapfn: Option<JsObject>,
+    this_arg: JsValue,
+    k: u64,
+}
+
+impl FromAsync {
+    /// Runs `fromAsyncClosure` until its first await, returning the value that settles the
+    /// promise of `Array.fromAsync`.
+    fn start(
+        c: &JsValue,
+        async_items: &JsValue,
+        mapfn: &JsValue,
+        this_arg: &JsValue,
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // a. If mapfn is undefined, let mapping be false.
+        // b. Else,
+        //     i. If IsCallable(mapfn) is false, throw a TypeError exception.
+        //     ii. Let mapping be true.
+        let mapfn = match mapfn {
+            JsValue::Undefined => None,
+            JsValue::Object(o) if o.is_callable() => Some(o.clone()),
+            _ => {
+                return Err(JsNativeError::typ()
+                    .with_message(format!("`{}` is not callable", mapfn.type_of()))
+                    .into())
+            }
+        };
+
+        // c. Let usingAsyncIterator be ? GetMethod(asyncItems, @@asyncIterator).
+        let async_method = async_items.get_method(JsSymbol::async_iterator(), context)?;
+
+        // d. If usingAsyncIterator is undefined, then
+        //     i. Let usingSyncIterator be ? GetMethod(asyncItems, @@iterator).
+        let sync_method = if async_method.is_none() {
+            async_items.get_method(JsSymbol::iterator(), context)?
+        } else {
+            None
+        };
+
+        // e. Let iteratorRecord be undefined.
+        // f. If usingAsyncIterator is not undefined, then
+        //     i. Set iteratorRecord to ? GetIterator(asyncItems, async, usingAsyncIterator).
+        // g. Else if usingSyncIterator is not undefined, then
+        //     i. Set iteratorRecord to ? CreateAsyncFromSyncIterator(GetIterator(asyncItems, sync, usingSyncIterator)).
+        let iterator_record = match (async_method, sync_method) {
+            (Some(method), _) => {
+                Some(async_items.get_iterator(context, Some(IteratorHint::Async), Some(method))?)
+            }
+            (None, Some(method)) => {
+                let sync_iterator_record =
+                    async_items.get_iterator(context, Some(IteratorHint::Sync), Some(method))?;
+                Some(AsyncFromSyncIterator::create(sync_iterator_record, context))
+            }
+            (None, None) => None,
+        };
+
+        // h. If iteratorRecord is not undefined, then
+        if let Some(iterator_record) = iterator_record {
+            // i. If IsConstructor(C) is true, then
+            //     1. Let A be ? Construct(C).
+            // ii. Else,
+            //     1. Let A be ! ArrayCreate(0).
+            let a = match c.as_constructor() {
+                Some(constructor) => constructor.construct(&[], None, context)?,
+                None => Array::array_create(0, None, context)
+                    .expect("creating an empty array with the default prototype must not fail"),
+            };
+
+            // iii. Let k be 0.
+            return Self {
+                source: Source::Iterator(iterator_record),
+                a,
+                mapfn,
+                this_arg: this_arg.clone(),
+                k: 0,
+            }
+            .step(context);
+        }
+
+        // i. Else,
+        //     i. NOTE: asyncItems is neither an AsyncIterable nor an Iterable so assume it is an array-like object.
+        //     ii. Let arrayLike be ! ToObject(asyncItems).
+        let array_like = async_items
+            .to_object(context)
+            .expect("should not fail according to spec");
+
+        //     iii. Let len be ? LengthOfArrayLike(arrayLike).
+        let len = array_like.length_of_array_like(context)?;
+
+        //     iv. If IsConstructor(C) is true, then
+        //         1. Let A be ? Construct(C, « 𝔽(len) »).
+        //     v. Else,
+        //         1. Let A be ? ArrayCreate(len).
+        let a = match c.as_constructor() {
+            Some(constructor) => constructor.construct(&[len.into()], None, context)?,
+            None => Array::array_create(len, None, context)?,
+        };
+
+        //     vi. Let k be 0.
+        Self {
+            source: Source::ArrayLike { array_like, len },
+            a,
+            mapfn,
+            this_arg: this_arg.clone(),
+            k: 0,
+        }
+        .step(context)
+    }
+
+    /// Runs an iteration of the loop of `fromAsyncClosure`, up to its first await.
+    fn step(self, context: &mut Context<'_>) -> JsResult<JsValue> {
+        match &self.source {
+            // h. iv. Repeat,
+            Source::Iterator(iterator_record) => {
+                // 1. If k ≥ 2^53 - 1, then
+                if self.k >= Number::MAX_SAFE_INTEGER as u64 {
+                    // a. Let error be ThrowCompletion(a newly created TypeError object).
+                    let error = JsNativeError::typ()
+                        .with_message("Array.fromAsync: iterator produced too many values")
+                        .into();
+
+                    // b. Return ? AsyncIteratorClose(iteratorRecord, error).
+                    return async_iterator_close(iterator_record.iterator(), Err(error), context);
+                }
+
+                // 2. Let Pk be ! ToString(𝔽(k)).
+                // 3. Let nextResult be ? Call(iteratorRecord.[[NextMethod]], iteratorRecord.[[Iterator]]).
+                let next_result = iterator_record.next_method().call(
+                    &iterator_record.iterator().clone().into(),
+                    &[],
+                    context,
+                )?;
+
+                // 4. Set nextResult to ? Await(nextResult).
+                await_then(next_result, self, Self::on_next_result, reject, context)
+            }
+            // i. vii. Repeat, while k < len,
+            Source::ArrayLike { array_like, len } => {
+                if self.k >= *len {
+                    // viii. Perform ? Set(A, "length", 𝔽(len), true).
+                    self.a.set(utf16!("length"), *len, true, context)?;
+
+                    // ix. Return Completion Record { [[Type]]: return, [[Value]]: A, [[Target]]: empty }.
+                    return Ok(self.a.clone().into());
+                }
+
+                // 1. Let Pk be ! ToString(𝔽(k)).
+                // 2. Let kValue be ? Get(arrayLike, Pk).
+                let k_value = array_like.get(self.k, context)?;
+
+                // 3. Set kValue to ? Await(kValue).
+                await_then(k_value, self, Self::on_value_awaited, reject, context)
+            }
+        }
+    }
+
+    /// Continues the iterator loop with the awaited result of the `next` method.
+    #[allow(clippy::needless_pass_by_value)]
+    fn on_next_result(
+        next_result: JsValue,
+        state: &Self,
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 5. If nextResult is not an Object, throw a TypeError exception.
+        let Some(next_result) = next_result.as_object() else {
+            return Err(JsNativeError::typ()
+                .with_message("next value should be an object")
+                .into());
+        };
+        let next_result = IteratorResult::new(next_result.clone());
+
+        // 6. Let done be ? IteratorComplete(nextResult).
+        // 7. If done is true,
+        if next_result.complete(context)? {
+            // a. Perform ? Set(A, "length", 𝔽(k), true).
+            state.a.set(utf16!("length"), state.k, true, context)?;
+
+            // b. Return Completion Record { [[Type]]: return, [[Value]]: A, [[Target]]: empty }.
+            return Ok(state.a.clone().into());
+        }
+
+        // 8. Let nextValue be ? IteratorValue(nextResult).
+        let next_value = next_result.value(context)?;
+
+        state.clone().on_value(next_value, context)
+    }
+
+    /// Maps and stores a value of the source, which has already been awaited if the source is an
+    /// array-like object.
+    fn on_value(self, value: JsValue, context: &mut Context<'_>) -> JsResult<JsValue> {
+        let Some(mapfn) = &self.mapfn else {
+            // 10. Else, let mappedValue be nextValue.
+            return self.define(value, context);
+        };
+
+        // 9. If mapping is true, then
+        //     a. Let mappedValue be Call(mapfn, thisArg, « nextValue, 𝔽(k) »).
+        let mapped_value = mapfn.call(&self.this_arg, &[value, self.k.into()], context);
+
+        let mapped_value = match &self.source {
+            //     b. IfAbruptCloseAsyncIterator(mappedValue, iteratorRecord).
+            Source::Iterator(iterator_record) => {
+                if_abrupt_close_async_iterator!(mapped_value, iterator_record, context)
+            }
+            Source::ArrayLike { .. } => mapped_value?,
+        };
+
+        //     c. Set mappedValue to Await(mappedValue).
+        await_then(
+            mapped_value,
+            self,
+            |mapped_value, state, context| state.clone().define(mapped_value, context),
+            |error, state, context| match &state.source {
+                //     d. IfAbruptCloseAsyncIterator(mappedValue, iteratorRecord).
+                Source::Iterator(iterator_record) => {
+                    async_iterator_close(iterator_record.iterator(), Err(error), context)
+                }
+                Source::ArrayLike { .. } => Err(error),
+            },
+            context,
+        )
+    }
+
+    /// Continues the closure with the awaited value of an element of an array-like source.
+    #[allow(clippy::needless_pass_by_value)]
+    fn on_value_awaited(
+        value: JsValue,
+        state: &Self,
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        state.clone().on_value(value, context)
+    }
+
+    /// Stores `mapped_value` into `A` and continues with the next iteration of the loop.
+    fn define(mut self, mapped_value: JsValue, context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 11. Let defineStatus be CreateDataPropertyOrThrow(A, Pk, mappedValue).
+        let define_status = self
+            .a
+            .create_data_property_or_throw(self.k, mapped_value, context);
+
+        match &self.source {
+            // 12. If defineStatus is an abrupt completion, return ? AsyncIteratorClose(iteratorRecord, defineStatus).
+            Source::Iterator(iterator_record) => {
+                if_abrupt_close_async_iterator!(define_status, iterator_record, context);
+            }
+            Source::ArrayLike { .. } => {
+                define_status?;
+            }
+        }
+
+        // 13. Set k to k + 1.
+        self.k += 1;
+        self.step(context)
+    }
+}
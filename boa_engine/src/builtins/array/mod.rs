@@ -32,6 +32,7 @@ use std::cmp::{max, min, Ordering};
 use super::{BuiltInBuilder, BuiltInConstructor, IntrinsicObject};
 
 mod array_iterator;
+mod from_async;
 pub(crate) use array_iterator::ArrayIterator;
 #[cfg(test)]
 mod tests;
@@ -121,8 +122,13 @@ impl IntrinsicObject for Array {
             .method(Self::keys, "keys", 0)
             .method(Self::entries, "entries", 0)
             .method(Self::copy_within, "copyWithin", 2)
+            .method(Self::to_reversed, "toReversed", 0)
+            .method(Self::to_sorted, "toSorted", 1)
+            .method(Self::to_spliced, "toSpliced", 2)
+            .method(Self::with, "with", 2)
             // Static Methods
             .static_method(Self::from, "from", 1)
+            .static_method(Self::from_async, "fromAsync", 1)
             .static_method(Self::is_array, "isArray", 1)
             .static_method(Self::of, "of", 0)
             .build();
@@ -575,12 +581,12 @@ impl Array {
 
             // iv. If next is false, then
             let Some(next) = next else {
-                    // 1. Perform ? Set(A, "length", 𝔽(k), true).
-                    a.set(utf16!("length"), k, true, context)?;
+                // 1. Perform ? Set(A, "length", 𝔽(k), true).
+                a.set(utf16!("length"), k, true, context)?;
 
-                    // 2. Return A.
-                    return Ok(a.into());
-                };
+                // 2. Return A.
+                return Ok(a.into());
+            };
 
             // v. Let nextValue be ? IteratorValue(next).
             let next_value = next.value(context)?;
@@ -2505,113 +2511,326 @@ impl Array {
             }
         };
 
-        // Abstract method `SortCompare`.
-        //
-        // More information:
-        //  - [ECMAScript reference][spec]
-        //
-        // [spec]: https://tc39.es/ecma262/#sec-sortcompare
-        let sort_compare =
-            |x: &JsValue, y: &JsValue, context: &mut Context<'_>| -> JsResult<Ordering> {
-                match (x.is_undefined(), y.is_undefined()) {
-                    // 1. If x and y are both undefined, return +0𝔽.
-                    (true, true) => return Ok(Ordering::Equal),
-                    // 2. If x is undefined, return 1𝔽.
-                    (true, false) => return Ok(Ordering::Greater),
-                    // 3. If y is undefined, return -1𝔽.
-                    (false, true) => return Ok(Ordering::Less),
-                    _ => {}
-                }
-
-                // 4. If comparefn is not undefined, then
-                if let Some(cmp) = comparefn {
-                    let args = [x.clone(), y.clone()];
-                    // a. Let v be ? ToNumber(? Call(comparefn, undefined, « x, y »)).
-                    let v = cmp
-                        .call(&JsValue::Undefined, &args, context)?
-                        .to_number(context)?;
-                    // b. If v is NaN, return +0𝔽.
-                    // c. Return v.
-                    return Ok(v.partial_cmp(&0.0).unwrap_or(Ordering::Equal));
-                }
-                // 5. Let xString be ? ToString(x).
-                // 6. Let yString be ? ToString(y).
-                let x_str = x.to_string(context)?;
-                let y_str = y.to_string(context)?;
-
-                // 7. Let xSmaller be IsLessThan(xString, yString, true).
-                // 8. If xSmaller is true, return -1𝔽.
-                // 9. Let ySmaller be IsLessThan(yString, xString, true).
-                // 10. If ySmaller is true, return 1𝔽.
-                // 11. Return +0𝔽.
-
-                // NOTE: skipped IsLessThan because it just makes a lexicographic comparison
-                // when x and y are strings
-                Ok(x_str.cmp(&y_str))
-            };
-
         // 2. Let obj be ? ToObject(this value).
         let obj = this.to_object(context)?;
 
         // 3. Let len be ? LengthOfArrayLike(obj).
         let length = obj.length_of_array_like(context)?;
 
-        // 4. Let items be a new empty List.
-        let mut items = Vec::with_capacity(length as usize);
-
-        // 5. Let k be 0.
-        // 6. Repeat, while k < len,
-        for k in 0..length {
-            // a. Let Pk be ! ToString(𝔽(k)).
-            // b. Let kPresent be ? HasProperty(obj, Pk).
-            // c. If kPresent is true, then
-            if obj.has_property(k, context)? {
-                // i. Let kValue be ? Get(obj, Pk).
-                let kval = obj.get(k, context)?;
-                // ii. Append kValue to items.
-                items.push(kval);
-            }
-            // d. Set k to k + 1.
-        }
+        // 4. Let SortCompare be a new Abstract Closure with parameters (x, y) that captures comparefn and performs the following steps when called:
+        //     a. Return ? CompareArrayElements(x, y, comparefn).
+        let sort_compare = |x: &JsValue, y: &JsValue, context: &mut Context<'_>| {
+            compare_array_elements(x, y, comparefn, context)
+        };
 
-        // 7. Let itemCount be the number of elements in items.
-        let item_count = items.len() as u64;
+        // 5. Let sortedList be ? SortIndexedProperties(obj, len, SortCompare, skip-holes).
+        let sorted = sort_indexed_properties(&obj, length, sort_compare, Holes::Skip, context)?;
 
-        // 8. Sort items using an implementation-defined sequence of calls to SortCompare.
-        // If any such call returns an abrupt completion, stop before performing any further
-        // calls to SortCompare or steps in this algorithm and return that completion.
-        let mut sort_err = Ok(());
-        items.sort_by(|x, y| {
-            if sort_err.is_ok() {
-                sort_compare(x, y, context).unwrap_or_else(|err| {
-                    sort_err = Err(err);
-                    Ordering::Equal
-                })
-            } else {
-                Ordering::Equal
-            }
-        });
-        sort_err?;
+        // 6. Let itemCount be the number of elements in sortedList.
+        let item_count = sorted.len() as u64;
 
-        // 9. Let j be 0.
-        // 10. Repeat, while j < itemCount,
-        for (j, item) in items.into_iter().enumerate() {
-            // a. Perform ? Set(obj, ! ToString(𝔽(j)), items[j], true).
+        // 7. Let j be 0.
+        // 8. Repeat, while j < itemCount,
+        for (j, item) in sorted.into_iter().enumerate() {
+            // a. Perform ? Set(obj, ! ToString(𝔽(j)), sortedList[j], true).
             obj.set(j, item, true, context)?;
             // b. Set j to j + 1.
         }
 
-        // 11. Repeat, while j < len,
+        // 9. NOTE: The call to SortIndexedProperties in step 5 uses skip-holes. The remaining indices are deleted to preserve the number of holes that were detected and excluded from the sort.
+        // 10. Repeat, while j < len,
         for j in item_count..length {
             // a. Perform ? DeletePropertyOrThrow(obj, ! ToString(𝔽(j))).
             obj.delete_property_or_throw(j, context)?;
             // b. Set j to j + 1.
         }
 
-        // 12. Return obj.
+        // 11. Return obj.
         Ok(obj.into())
     }
 
+    /// `Array.prototype.toReversed ( )`
+    ///
+    /// Returns a new array with the elements of the array in reverse order.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.toreversed
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/toReversed
+    pub(crate) fn to_reversed(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be ? ToObject(this value).
+        let o = this.to_object(context)?;
+
+        // 2. Let len be ? LengthOfArrayLike(O).
+        let len = o.length_of_array_like(context)?;
+
+        // 3. Let A be ? ArrayCreate(len).
+        let a = Self::array_create(len, None, context)?;
+
+        // 4. Let k be 0.
+        // 5. Repeat, while k < len,
+        for k in 0..len {
+            // a. Let from be ! ToString(𝔽(len - k - 1)).
+            // b. Let Pk be ! ToString(𝔽(k)).
+            // c. Let fromValue be ? Get(O, from).
+            let from_value = o.get(len - k - 1, context)?;
+
+            // d. Perform ! CreateDataPropertyOrThrow(A, Pk, fromValue).
+            a.create_data_property_or_throw(k, from_value, context)
+                .expect("cannot fail for a fresh array");
+
+            // e. Set k to k + 1.
+        }
+
+        // 6. Return A.
+        Ok(a.into())
+    }
+
+    /// `Array.prototype.toSorted ( comparefn )`
+    ///
+    /// Returns a new array with the elements of the array sorted, leaving the array untouched.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.tosorted
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/toSorted
+    pub(crate) fn to_sorted(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. If comparefn is not undefined and IsCallable(comparefn) is false, throw a TypeError exception.
+        let comparefn = match args.get_or_undefined(0) {
+            JsValue::Object(ref obj) if obj.is_callable() => Some(obj),
+            JsValue::Undefined => None,
+            _ => {
+                return Err(JsNativeError::typ()
+                    .with_message("The comparison function must be either a function or undefined")
+                    .into())
+            }
+        };
+
+        // 2. Let O be ? ToObject(this value).
+        let o = this.to_object(context)?;
+
+        // 3. Let len be ? LengthOfArrayLike(O).
+        let len = o.length_of_array_like(context)?;
+
+        // 4. Let A be ? ArrayCreate(len).
+        let a = Self::array_create(len, None, context)?;
+
+        // 5. Let SortCompare be a new Abstract Closure with parameters (x, y) that captures comparefn and performs the following steps when called:
+        //     a. Return ? CompareArrayElements(x, y, comparefn).
+        let sort_compare = |x: &JsValue, y: &JsValue, context: &mut Context<'_>| {
+            compare_array_elements(x, y, comparefn, context)
+        };
+
+        // 6. Let sortedList be ? SortIndexedProperties(O, len, SortCompare, read-through-holes).
+        let sorted = sort_indexed_properties(&o, len, sort_compare, Holes::ReadThrough, context)?;
+
+        // 7. Let j be 0.
+        // 8. Repeat, while j < len,
+        for (j, item) in sorted.into_iter().enumerate() {
+            // a. Perform ! CreateDataPropertyOrThrow(A, ! ToString(𝔽(j)), sortedList[j]).
+            a.create_data_property_or_throw(j, item, context)
+                .expect("cannot fail for a fresh array");
+
+            // b. Set j to j + 1.
+        }
+
+        // 9. Return A.
+        Ok(a.into())
+    }
+
+    /// `Array.prototype.toSpliced ( start, skipCount, ...items )`
+    ///
+    /// Returns a new array with `skipCount` elements removed from `start`, and replaced by
+    /// `items`, leaving the array untouched.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.tospliced
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/toSpliced
+    pub(crate) fn to_spliced(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        let start = args.first();
+        let skip_count = args.get(1);
+        let items = args.get(2..).unwrap_or(&[]);
+
+        // 1. Let O be ? ToObject(this value).
+        let o = this.to_object(context)?;
+
+        // 2. Let len be ? LengthOfArrayLike(O).
+        let len = o.length_of_array_like(context)?;
+
+        // 3. Let relativeStart be ? ToIntegerOrInfinity(start).
+        // 4. If relativeStart is -∞, let actualStart be 0.
+        // 5. Else if relativeStart < 0, let actualStart be max(len + relativeStart, 0).
+        // 6. Else, let actualStart be min(relativeStart, len).
+        let actual_start = Self::get_relative_start(context, start, len)?;
+
+        // 7. Let insertCount be the number of elements in items.
+        let insert_count = items.len() as u64;
+
+        let actual_skip_count = if start.is_none() {
+            // 8. If start is not present, then
+            //     a. Let actualSkipCount be 0.
+            0
+        } else if let Some(skip_count) = skip_count {
+            // 10. Else,
+            //     a. Let sc be ? ToIntegerOrInfinity(skipCount).
+            //     b. Let actualSkipCount be the result of clamping sc between 0 and len - actualStart.
+            let max = len - actual_start;
+            match skip_count.to_integer_or_infinity(context)? {
+                IntegerOrInfinity::Integer(i) => u64::try_from(i).unwrap_or_default().min(max),
+                IntegerOrInfinity::PositiveInfinity => max,
+                IntegerOrInfinity::NegativeInfinity => 0,
+            }
+        } else {
+            // 9. Else if skipCount is not present, then
+            //     a. Let actualSkipCount be len - actualStart.
+            len - actual_start
+        };
+
+        // 11. Let newLen be len + insertCount - actualSkipCount.
+        let new_len = len + insert_count - actual_skip_count;
+
+        // 12. If newLen > 2^53 - 1, throw a TypeError exception.
+        if new_len > Number::MAX_SAFE_INTEGER as u64 {
+            return Err(JsNativeError::typ()
+                .with_message("Array.prototype.toSpliced: new length exceeds the max safe integer")
+                .into());
+        }
+
+        // 13. Let A be ? ArrayCreate(newLen).
+        let a = Self::array_create(new_len, None, context)?;
+
+        // 14. Let i be 0.
+        // 15. Let r be actualStart + actualSkipCount.
+        let mut r = actual_start + actual_skip_count;
+
+        // 16. Repeat, while i < actualStart,
+        for i in 0..actual_start {
+            // a. Let Pi be ! ToString(𝔽(i)).
+            // b. Let iValue be ? Get(O, Pi).
+            let value = o.get(i, context)?;
+
+            // c. Perform ! CreateDataPropertyOrThrow(A, Pi, iValue).
+            a.create_data_property_or_throw(i, value, context)
+                .expect("cannot fail for a fresh array");
+
+            // d. Set i to i + 1.
+        }
+
+        // 17. For each element E of items, do
+        for (i, item) in (actual_start..).zip(items) {
+            // a. Let Pi be ! ToString(𝔽(i)).
+            // b. Perform ! CreateDataPropertyOrThrow(A, Pi, E).
+            a.create_data_property_or_throw(i, item.clone(), context)
+                .expect("cannot fail for a fresh array");
+
+            // c. Set i to i + 1.
+        }
+
+        // 18. Repeat, while i < newLen,
+        for i in (actual_start + insert_count)..new_len {
+            // a. Let Pi be ! ToString(𝔽(i)).
+            // b. Let from be ! ToString(𝔽(r)).
+            // c. Let fromValue be ? Get(O, from).
+            let from_value = o.get(r, context)?;
+
+            // d. Perform ! CreateDataPropertyOrThrow(A, Pi, fromValue).
+            a.create_data_property_or_throw(i, from_value, context)
+                .expect("cannot fail for a fresh array");
+
+            // e. Set i to i + 1.
+            // f. Set r to r + 1.
+            r += 1;
+        }
+
+        // 19. Return A.
+        Ok(a.into())
+    }
+
+    /// `Array.prototype.with ( index, value )`
+    ///
+    /// Returns a new array with the element at `index` replaced by `value`, leaving the array
+    /// untouched.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.with
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/with
+    pub(crate) fn with(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be ? ToObject(this value).
+        let o = this.to_object(context)?;
+
+        // 2. Let len be ? LengthOfArrayLike(O).
+        let len = o.length_of_array_like(context)?;
+
+        // 3. Let relativeIndex be ? ToIntegerOrInfinity(index).
+        let relative_index = args.get_or_undefined(0).to_integer_or_infinity(context)?;
+
+        // 4. If relativeIndex ≥ 0, let actualIndex be relativeIndex.
+        // 5. Else, let actualIndex be len + relativeIndex.
+        // 6. If actualIndex ≥ len or actualIndex < 0, throw a RangeError exception.
+        let actual_index = match relative_index {
+            IntegerOrInfinity::Integer(i) if i >= 0 && (i as u64) < len => i as u64,
+            IntegerOrInfinity::Integer(i) if i < 0 && i.unsigned_abs() <= len => {
+                len - i.unsigned_abs()
+            }
+            _ => {
+                return Err(JsNativeError::range()
+                    .with_message("Array.prototype.with: index out of range")
+                    .into())
+            }
+        };
+
+        // 7. Let A be ? ArrayCreate(len).
+        let a = Self::array_create(len, None, context)?;
+
+        // 8. Let k be 0.
+        // 9. Repeat, while k < len,
+        for k in 0..len {
+            // a. Let Pk be ! ToString(𝔽(k)).
+            // b. If k is actualIndex, let fromValue be value.
+            // c. Else, let fromValue be ? Get(O, Pk).
+            let from_value = if k == actual_index {
+                args.get_or_undefined(1).clone()
+            } else {
+                o.get(k, context)?
+            };
+
+            // d. Perform ! CreateDataPropertyOrThrow(A, Pk, fromValue).
+            a.create_data_property_or_throw(k, from_value, context)
+                .expect("cannot fail for a fresh array");
+
+            // e. Set k to k + 1.
+        }
+
+        // 10. Return A.
+        Ok(a.into())
+    }
+
     /// `Array.prototype.reduce( callbackFn [ , initialValue ] )`
     ///
     /// More information:
@@ -3028,7 +3247,7 @@ impl Array {
     /// The initial value of the 'unscopables' data property is an ordinary object
     /// with the following boolean properties set to true:
     /// 'at', 'copyWithin', 'entries', 'fill', 'find', 'findIndex', 'flat',
-    /// 'flatMap', 'includes', 'keys', 'values'
+    /// 'flatMap', 'includes', 'keys', 'toReversed', 'toSorted', 'toSpliced', 'values'
     ///
     /// More information:
     ///  - [ECMAScript reference][spec]
@@ -3066,11 +3285,139 @@ impl Array {
             obj.insert(utf16!("includes"), true_prop.clone());
             // 11. Perform ! CreateDataPropertyOrThrow(unscopableList, "keys", true).
             obj.insert(utf16!("keys"), true_prop.clone());
-            // 12. Perform ! CreateDataPropertyOrThrow(unscopableList, "values", true).
+            // 12. Perform ! CreateDataPropertyOrThrow(unscopableList, "toReversed", true).
+            obj.insert(utf16!("toReversed"), true_prop.clone());
+            // 13. Perform ! CreateDataPropertyOrThrow(unscopableList, "toSorted", true).
+            obj.insert(utf16!("toSorted"), true_prop.clone());
+            // 14. Perform ! CreateDataPropertyOrThrow(unscopableList, "toSpliced", true).
+            obj.insert(utf16!("toSpliced"), true_prop.clone());
+            // 15. Perform ! CreateDataPropertyOrThrow(unscopableList, "values", true).
             obj.insert(utf16!("values"), true_prop);
         }
 
-        // 13. Return unscopableList.
+        // 16. Return unscopableList.
         unscopable_list
     }
 }
+
+/// How `SortIndexedProperties` handles the missing indices of an object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Holes {
+    /// Missing indices are skipped.
+    Skip,
+    /// Missing indices are read as `undefined` through the prototype chain.
+    ReadThrough,
+}
+
+/// Abstract operation `SortIndexedProperties ( obj, len, SortCompare, holes )`
+///
+/// Reads the first `len` indices of `obj` and returns them sorted with `sort_compare`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-sortindexedproperties
+pub(crate) fn sort_indexed_properties<F>(
+    obj: &JsObject,
+    len: u64,
+    sort_compare: F,
+    holes: Holes,
+    context: &mut Context<'_>,
+) -> JsResult<Vec<JsValue>>
+where
+    F: Fn(&JsValue, &JsValue, &mut Context<'_>) -> JsResult<Ordering>,
+{
+    // 1. Let items be a new empty List.
+    let mut items = Vec::with_capacity(len as usize);
+
+    // 2. Let k be 0.
+    // 3. Repeat, while k < len,
+    for k in 0..len {
+        // a. Let Pk be ! ToString(𝔽(k)).
+        // b. If holes is skip-holes, then
+        //     i. Let kRead be ? HasProperty(obj, Pk).
+        // c. Else,
+        //     i. Assert: holes is read-through-holes.
+        //     ii. Let kRead be true.
+        let k_read = match holes {
+            Holes::Skip => obj.has_property(k, context)?,
+            Holes::ReadThrough => true,
+        };
+
+        // d. If kRead is true, then
+        if k_read {
+            // i. Let kValue be ? Get(obj, Pk).
+            // ii. Append kValue to items.
+            items.push(obj.get(k, context)?);
+        }
+        // e. Set k to k + 1.
+    }
+
+    // 4. Sort items using an implementation-defined sequence of calls to SortCompare.
+    // If any such call returns an abrupt completion, stop before performing any further
+    // calls to SortCompare and return that Completion Record.
+    let mut sort_err = Ok(());
+    items.sort_by(|x, y| {
+        if sort_err.is_ok() {
+            sort_compare(x, y, context).unwrap_or_else(|err| {
+                sort_err = Err(err);
+                Ordering::Equal
+            })
+        } else {
+            Ordering::Equal
+        }
+    });
+    sort_err?;
+
+    // 5. Return items.
+    Ok(items)
+}
+
+/// Abstract operation `CompareArrayElements ( x, y, comparefn )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-comparearrayelements
+pub(crate) fn compare_array_elements(
+    x: &JsValue,
+    y: &JsValue,
+    comparefn: Option<&JsObject>,
+    context: &mut Context<'_>,
+) -> JsResult<Ordering> {
+    match (x.is_undefined(), y.is_undefined()) {
+        // 1. If x and y are both undefined, return +0𝔽.
+        (true, true) => return Ok(Ordering::Equal),
+        // 2. If x is undefined, return 1𝔽.
+        (true, false) => return Ok(Ordering::Greater),
+        // 3. If y is undefined, return -1𝔽.
+        (false, true) => return Ok(Ordering::Less),
+        _ => {}
+    }
+
+    // 4. If comparefn is not undefined, then
+    if let Some(cmp) = comparefn {
+        let args = [x.clone(), y.clone()];
+        // a. Let v be ? ToNumber(? Call(comparefn, undefined, « x, y »)).
+        let v = cmp
+            .call(&JsValue::Undefined, &args, context)?
+            .to_number(context)?;
+        // b. If v is NaN, return +0𝔽.
+        // c. Return v.
+        return Ok(v.partial_cmp(&0.0).unwrap_or(Ordering::Equal));
+    }
+    // 5. Let xString be ? ToString(x).
+    // 6. Let yString be ? ToString(y).
+    let x_str = x.to_string(context)?;
+    let y_str = y.to_string(context)?;
+
+    // 7. Let xSmaller be IsLessThan(xString, yString, true).
+    // 8. If xSmaller is true, return -1𝔽.
+    // 9. Let ySmaller be IsLessThan(yString, xString, true).
+    // 10. If ySmaller is true, return 1𝔽.
+    // 11. Return +0𝔽.
+
+    // NOTE: skipped IsLessThan because it just makes a lexicographic comparison
+    // when x and y are strings
+    Ok(x_str.cmp(&y_str))
+}
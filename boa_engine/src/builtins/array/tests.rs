@@ -945,3 +945,100 @@ fn array_sort() {
             "#}),
     ]);
 }
+
+#[test]
+fn array_to_sorted() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::run("const arr = [3, undefined, 1, , 2];"),
+        TestAction::assert("arrayEquals(arr.toSorted(), [1, 2, 3, undefined, undefined])"),
+        TestAction::assert(
+            "arrayEquals(arr.toSorted((a, b) => b - a), [3, 2, 1, undefined, undefined])",
+        ),
+        TestAction::assert("!arr.hasOwnProperty(3)"),
+        TestAction::assert_eq("arr[0]", 3),
+        TestAction::assert_native_error(
+            "arr.toSorted(1)",
+            JsNativeErrorKind::Type,
+            "The comparison function must be either a function or undefined",
+        ),
+    ]);
+}
+
+#[test]
+fn array_to_reversed() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::run("const arr = [1, , 3];"),
+        TestAction::assert("arrayEquals(arr.toReversed(), [3, undefined, 1])"),
+        TestAction::assert("arr.toReversed().hasOwnProperty(1)"),
+        TestAction::assert("arrayEquals(arr, [1, undefined, 3])"),
+        TestAction::assert(
+            "arrayEquals(Array.prototype.toReversed.call({ length: 2, 0: 'a', 1: 'b' }), ['b', 'a'])",
+        ),
+    ]);
+}
+
+#[test]
+fn array_to_spliced() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::run("const arr = [1, 2, 3, 4];"),
+        TestAction::assert("arrayEquals(arr.toSpliced(1, 2), [1, 4])"),
+        TestAction::assert("arrayEquals(arr.toSpliced(1, 1, 'a', 'b'), [1, 'a', 'b', 3, 4])"),
+        TestAction::assert("arrayEquals(arr.toSpliced(-1), [1, 2, 3])"),
+        TestAction::assert("arrayEquals(arr.toSpliced(), [1, 2, 3, 4])"),
+        TestAction::assert("arrayEquals(arr, [1, 2, 3, 4])"),
+        TestAction::assert_native_error(
+            "Array.prototype.toSpliced.call({ length: 2 ** 53 - 1 }, 0, 0, 1)",
+            JsNativeErrorKind::Type,
+            "Array.prototype.toSpliced: new length exceeds the max safe integer",
+        ),
+    ]);
+}
+
+#[test]
+fn array_with() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::run("const arr = [1, 2, 3];"),
+        TestAction::assert("arrayEquals(arr.with(1, 'a'), [1, 'a', 3])"),
+        TestAction::assert("arrayEquals(arr.with(-1, 'a'), [1, 2, 'a'])"),
+        TestAction::assert("arrayEquals(arr, [1, 2, 3])"),
+        TestAction::assert_native_error(
+            "arr.with(3, 0)",
+            JsNativeErrorKind::Range,
+            "Array.prototype.with: index out of range",
+        ),
+        TestAction::assert_native_error(
+            "arr.with(-4, 0)",
+            JsNativeErrorKind::Range,
+            "Array.prototype.with: index out of range",
+        ),
+    ]);
+}
+
+#[test]
+fn array_from_async() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::run(indoc! {r"
+            let fromSync, fromAsync, fromArrayLike, mapped, rejected;
+            async function* gen() { yield 1; yield 2; yield 3; }
+            Array.fromAsync([1, Promise.resolve(2), 3]).then((a) => { fromSync = a; });
+            Array.fromAsync(gen()).then((a) => { fromAsync = a; });
+            Array.fromAsync({ length: 2, 0: 'a', 1: Promise.resolve('b') })
+                .then((a) => { fromArrayLike = a; });
+            Array.fromAsync(gen(), (x, i) => x * 10 + i).then((a) => { mapped = a; });
+            Array.fromAsync([1], 5).catch((e) => { rejected = e; });
+        "}),
+        #[allow(clippy::redundant_closure_for_method_calls)]
+        TestAction::inspect_context(|ctx| ctx.run_jobs()),
+        TestAction::assert("arrayEquals(fromSync, [1, 2, 3])"),
+        TestAction::assert("arrayEquals(fromAsync, [1, 2, 3])"),
+        TestAction::assert("arrayEquals(fromArrayLike, ['a', 'b'])"),
+        TestAction::assert("arrayEquals(mapped, [10, 21, 32])"),
+        TestAction::assert("rejected instanceof TypeError"),
+        TestAction::assert("Array.fromAsync([]) instanceof Promise"),
+    ]);
+}
@@ -7,9 +7,14 @@
 //! [spec]: https://tc39.es/ecma262/#sec-arraybuffer-objects
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer
 
+pub(crate) mod shared;
+pub(crate) mod utils;
+
 #[cfg(test)]
 mod tests;
 
+pub use shared::SharedArrayBuffer;
+
 use crate::{
     builtins::{typed_array::TypedArrayKind, BuiltInObject},
     context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
@@ -26,6 +31,8 @@ use boa_gc::{Finalize, Trace};
 use boa_profiler::Profiler;
 use num_traits::{Signed, ToPrimitive};
 
+use self::utils::{copy_data_block_bytes, SliceRef, SliceRefMut};
+
 use super::{BuiltInBuilder, BuiltInConstructor, IntrinsicObject};
 
 /// The internal representation of an `ArrayBuffer` object.
@@ -37,6 +44,11 @@ pub struct ArrayBuffer {
     /// The `[[ArrayBufferByteLength]]` internal slot.
     pub array_buffer_byte_length: u64,
 
+    /// The `[[ArrayBufferMaxByteLength]]` internal slot.
+    ///
+    /// This is `None` for fixed-length buffers.
+    pub array_buffer_max_byte_length: Option<u64>,
+
     /// The `[[ArrayBufferDetachKey]]` internal slot.
     pub array_buffer_detach_key: JsValue,
 }
@@ -45,6 +57,42 @@ impl ArrayBuffer {
     pub(crate) const fn array_buffer_byte_length(&self) -> u64 {
         self.array_buffer_byte_length
     }
+
+    /// `25.1.3.9 IsFixedLengthArrayBuffer ( arrayBuffer )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-isfixedlengtharraybuffer
+    pub(crate) const fn is_fixed_length(&self) -> bool {
+        // 1. If arrayBuffer has an [[ArrayBufferMaxByteLength]] internal slot, return false.
+        // 2. Return true.
+        self.array_buffer_max_byte_length.is_none()
+    }
+
+    /// `25.1.2.3 DetachArrayBuffer ( arrayBuffer [ , key ] )`
+    ///
+    /// Returns the data block of the buffer, or `None` if the buffer was already detached.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-detacharraybuffer
+    pub fn detach(&mut self, key: &JsValue) -> JsResult<Option<Vec<u8>>> {
+        // 1. Assert: IsSharedArrayBuffer(arrayBuffer) is false.
+        // 2. If key is not present, set key to undefined.
+        // 3. If SameValue(arrayBuffer.[[ArrayBufferDetachKey]], key) is false, throw a TypeError exception.
+        if !JsValue::same_value(&self.array_buffer_detach_key, key) {
+            return Err(JsNativeError::typ()
+                .with_message("Cannot detach array buffer with different key")
+                .into());
+        }
+
+        // 4. Set arrayBuffer.[[ArrayBufferData]] to null.
+        // 5. Set arrayBuffer.[[ArrayBufferByteLength]] to 0.
+        self.array_buffer_byte_length = 0;
+        Ok(self.array_buffer_data.take())
+    }
 }
 
 impl IntrinsicObject for ArrayBuffer {
@@ -61,6 +109,18 @@ impl IntrinsicObject for ArrayBuffer {
             .name("get byteLength")
             .build();
 
+        let get_max_byte_length = BuiltInBuilder::callable(realm, Self::get_max_byte_length)
+            .name("get maxByteLength")
+            .build();
+
+        let get_resizable = BuiltInBuilder::callable(realm, Self::get_resizable)
+            .name("get resizable")
+            .build();
+
+        let get_detached = BuiltInBuilder::callable(realm, Self::get_detached)
+            .name("get detached")
+            .build();
+
         BuiltInBuilder::from_standard_constructor::<Self>(realm)
             .accessor(
                 utf16!("byteLength"),
@@ -68,6 +128,24 @@ impl IntrinsicObject for ArrayBuffer {
                 None,
                 flag_attributes,
             )
+            .accessor(
+                utf16!("maxByteLength"),
+                Some(get_max_byte_length),
+                None,
+                flag_attributes,
+            )
+            .accessor(
+                utf16!("resizable"),
+                Some(get_resizable),
+                None,
+                flag_attributes,
+            )
+            .accessor(
+                utf16!("detached"),
+                Some(get_detached),
+                None,
+                flag_attributes,
+            )
             .static_accessor(
                 JsSymbol::species(),
                 Some(get_species),
@@ -75,7 +153,10 @@ impl IntrinsicObject for ArrayBuffer {
                 Attribute::CONFIGURABLE,
             )
             .static_method(Self::is_view, "isView", 1)
+            .method(Self::resize, "resize", 1)
             .method(Self::slice, "slice", 2)
+            .method(Self::transfer, "transfer", 0)
+            .method(Self::transfer_to_fixed_length, "transferToFixedLength", 0)
             .property(
                 JsSymbol::to_string_tag(),
                 Self::NAME,
@@ -99,7 +180,7 @@ impl BuiltInConstructor for ArrayBuffer {
     const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
         StandardConstructors::array_buffer;
 
-    /// `25.1.3.1 ArrayBuffer ( length )`
+    /// `25.1.3.1 ArrayBuffer ( length [ , options ] )`
     ///
     /// More information:
     ///  - [ECMAScript reference][spec]
@@ -120,8 +201,11 @@ impl BuiltInConstructor for ArrayBuffer {
         // 2. Let byteLength be ? ToIndex(length).
         let byte_length = args.get_or_undefined(0).to_index(context)?;
 
-        // 3. Return ? AllocateArrayBuffer(NewTarget, byteLength).
-        Ok(Self::allocate(new_target, byte_length, context)?.into())
+        // 3. Let requestedMaxByteLength be ? GetArrayBufferMaxByteLengthOption(options).
+        let max_byte_length = get_max_byte_length_option(args.get_or_undefined(1), context)?;
+
+        // 4. Return ? AllocateArrayBuffer(NewTarget, byteLength, requestedMaxByteLength).
+        Ok(Self::allocate(new_target, byte_length, max_byte_length, context)?.into())
     }
 }
 
@@ -170,6 +254,7 @@ impl ArrayBuffer {
     ) -> JsResult<JsValue> {
         // 1. Let O be the this value.
         // 2. Perform ? RequireInternalSlot(O, [[ArrayBufferData]]).
+        // 3. If IsSharedArrayBuffer(O) is true, throw a TypeError exception.
         let obj = this.as_object().ok_or_else(|| {
             JsNativeError::typ().with_message("ArrayBuffer.byteLength called with non-object value")
         })?;
@@ -178,9 +263,6 @@ impl ArrayBuffer {
             JsNativeError::typ().with_message("ArrayBuffer.byteLength called with invalid object")
         })?;
 
-        // TODO: Shared Array Buffer
-        // 3. If IsSharedArrayBuffer(O) is true, throw a TypeError exception.
-
         // 4. If IsDetachedBuffer(O) is true, return +0𝔽.
         if Self::is_detached_buffer(buf) {
             return Ok(0.into());
@@ -191,6 +273,163 @@ impl ArrayBuffer {
         Ok(buf.array_buffer_byte_length.into())
     }
 
+    /// `25.1.6.4 get ArrayBuffer.prototype.maxByteLength`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-arraybuffer.prototype.maxbytelength
+    pub(crate) fn get_max_byte_length(
+        this: &JsValue,
+        _args: &[JsValue],
+        _: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[ArrayBufferData]]).
+        // 3. If IsSharedArrayBuffer(O) is true, throw a TypeError exception.
+        let obj = this.as_object().ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("ArrayBuffer.maxByteLength called with non-object value")
+        })?;
+        let obj = obj.borrow();
+        let buf = obj.as_array_buffer().ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("ArrayBuffer.maxByteLength called with invalid object")
+        })?;
+
+        // 4. If IsDetachedBuffer(O) is true, return +0𝔽.
+        if Self::is_detached_buffer(buf) {
+            return Ok(0.into());
+        }
+
+        // 5. If IsFixedLengthArrayBuffer(O) is true, then
+        //     a. Let length be O.[[ArrayBufferByteLength]].
+        // 6. Else,
+        //     a. Let length be O.[[ArrayBufferMaxByteLength]].
+        // 7. Return 𝔽(length).
+        Ok(buf
+            .array_buffer_max_byte_length
+            .unwrap_or(buf.array_buffer_byte_length)
+            .into())
+    }
+
+    /// `25.1.6.5 get ArrayBuffer.prototype.resizable`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-arraybuffer.prototype.resizable
+    fn get_resizable(this: &JsValue, _: &[JsValue], _: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[ArrayBufferData]]).
+        // 3. If IsSharedArrayBuffer(O) is true, throw a TypeError exception.
+        let obj = this.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("ArrayBuffer.resizable called with non-object value")
+        })?;
+        let obj = obj.borrow();
+        let buf = obj.as_array_buffer().ok_or_else(|| {
+            JsNativeError::typ().with_message("ArrayBuffer.resizable called with invalid object")
+        })?;
+
+        // 4. If IsFixedLengthArrayBuffer(O) is false, return true; otherwise return false.
+        Ok((!buf.is_fixed_length()).into())
+    }
+
+    /// `25.1.6.3 get ArrayBuffer.prototype.detached`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-arraybuffer.prototype.detached
+    fn get_detached(this: &JsValue, _: &[JsValue], _: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[ArrayBufferData]]).
+        // 3. If IsSharedArrayBuffer(O) is true, throw a TypeError exception.
+        let obj = this.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("ArrayBuffer.detached called with non-object value")
+        })?;
+        let obj = obj.borrow();
+        let buf = obj.as_array_buffer().ok_or_else(|| {
+            JsNativeError::typ().with_message("ArrayBuffer.detached called with invalid object")
+        })?;
+
+        // 4. Return IsDetachedBuffer(O).
+        Ok(buf.is_detached_buffer().into())
+    }
+
+    /// `25.1.6.6 ArrayBuffer.prototype.resize ( newLength )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-arraybuffer.prototype.resize
+    pub(crate) fn resize(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[ArrayBufferMaxByteLength]]).
+        // 3. If IsSharedArrayBuffer(O) is true, throw a TypeError exception.
+        let obj = this.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("ArrayBuffer.resize called with non-object value")
+        })?;
+        if !obj
+            .borrow()
+            .as_array_buffer()
+            .map_or(false, |buf| !buf.is_fixed_length())
+        {
+            return Err(JsNativeError::typ()
+                .with_message("ArrayBuffer.resize called with invalid object")
+                .into());
+        }
+
+        // 4. Let newByteLength be ? ToIndex(newLength).
+        let new_byte_length = args.get_or_undefined(0).to_index(context)?;
+
+        let mut obj = obj.borrow_mut();
+        let buf = obj
+            .as_array_buffer_mut()
+            .expect("already checked that `this` is an `ArrayBuffer`");
+
+        // 5. If IsDetachedBuffer(O) is true, throw a TypeError exception.
+        let Some(block) = buf.array_buffer_data.as_mut() else {
+            return Err(JsNativeError::typ()
+                .with_message("ArrayBuffer.resize called with detached buffer")
+                .into());
+        };
+
+        // 6. If newByteLength > O.[[ArrayBufferMaxByteLength]], throw a RangeError exception.
+        if buf
+            .array_buffer_max_byte_length
+            .map_or(false, |max_byte_length| new_byte_length > max_byte_length)
+        {
+            return Err(JsNativeError::range()
+                .with_message("new byte length exceeds the max byte length of the ArrayBuffer")
+                .into());
+        }
+
+        // 7. Let hostHandled be ? HostResizeArrayBuffer(O, newByteLength).
+        // 8. If hostHandled is handled, return undefined.
+        // 9. Let oldBlock be O.[[ArrayBufferData]].
+        // 10. Let newBlock be ? CreateByteDataBlock(newByteLength).
+        // 11. Let copyLength be min(newByteLength, O.[[ArrayBufferByteLength]]).
+        // 12. Perform CopyDataBlockBytes(newBlock, 0, oldBlock, 0, copyLength).
+        // 13. NOTE: Neither creation of the new Data Block nor copying from the old Data Block are
+        //     observable. Implementations may implement this method as in-place growth or shrinkage.
+        // 14. Set O.[[ArrayBufferData]] to newBlock.
+        //
+        // NOTE: The whole capacity of a resizable buffer is reserved on allocation, which means
+        // the block can always be resized in place.
+        block.resize(new_byte_length as usize, 0);
+
+        // 15. Set O.[[ArrayBufferByteLength]] to newByteLength.
+        buf.array_buffer_byte_length = new_byte_length;
+
+        // 16. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
     /// `25.1.5.3 ArrayBuffer.prototype.slice ( start, end )`
     ///
     /// More information:
@@ -200,6 +439,7 @@ impl ArrayBuffer {
     fn slice(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
         // 1. Let O be the this value.
         // 2. Perform ? RequireInternalSlot(O, [[ArrayBufferData]]).
+        // 3. If IsSharedArrayBuffer(O) is true, throw a TypeError exception.
         let obj = this.as_object().ok_or_else(|| {
             JsNativeError::typ().with_message("ArrayBuffer.slice called with non-object value")
         })?;
@@ -208,9 +448,6 @@ impl ArrayBuffer {
             JsNativeError::typ().with_message("ArrayBuffer.slice called with invalid object")
         })?;
 
-        // TODO: Shared Array Buffer
-        // 3. If IsSharedArrayBuffer(O) is true, throw a TypeError exception.
-
         // 4. If IsDetachedBuffer(O) is true, throw a TypeError exception.
         if Self::is_detached_buffer(buf) {
             return Err(JsNativeError::typ()
@@ -264,13 +501,11 @@ impl ArrayBuffer {
         {
             let new_obj = new.borrow();
             // 17. Perform ? RequireInternalSlot(new, [[ArrayBufferData]]).
+            // 18. If IsSharedArrayBuffer(new) is true, throw a TypeError exception.
             let new_array_buffer = new_obj.as_array_buffer().ok_or_else(|| {
                 JsNativeError::typ().with_message("ArrayBuffer constructor returned invalid object")
             })?;
 
-            // TODO: Shared Array Buffer
-            // 18. If IsSharedArrayBuffer(new) is true, throw a TypeError exception.
-
             // 19. If IsDetachedBuffer(new) is true, throw a TypeError exception.
             if new_array_buffer.is_detached_buffer() {
                 return Err(JsNativeError::typ()
@@ -323,14 +558,163 @@ impl ArrayBuffer {
                 .expect("ArrayBuffer cannot be detached here");
 
             // 26. Perform CopyDataBlockBytes(toBuf, 0, fromBuf, first, newLen).
-            copy_data_block_bytes(to_buf, 0, from_buf, first as usize, new_len as usize);
+            copy_data_block_bytes(
+                &mut SliceRefMut::Slice(to_buf),
+                0,
+                SliceRef::Slice(from_buf),
+                first as usize,
+                new_len as usize,
+            );
         }
 
         // 27. Return new.
         Ok(new.into())
     }
 
-    /// `25.1.2.1 AllocateArrayBuffer ( constructor, byteLength )`
+    /// `25.1.6.8 ArrayBuffer.prototype.transfer ( [ newLength ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-arraybuffer.prototype.transfer
+    pub(crate) fn transfer(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Return ? ArrayBufferCopyAndDetach(O, newLength, preserve-resizability).
+        Self::copy_and_detach(this, args.get_or_undefined(0), true, context).map(Into::into)
+    }
+
+    /// `25.1.6.9 ArrayBuffer.prototype.transferToFixedLength ( [ newLength ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-arraybuffer.prototype.transfertofixedlength
+    pub(crate) fn transfer_to_fixed_length(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Return ? ArrayBufferCopyAndDetach(O, newLength, fixed-length).
+        Self::copy_and_detach(this, args.get_or_undefined(0), false, context).map(Into::into)
+    }
+
+    /// `25.1.3.3 ArrayBufferCopyAndDetach ( arrayBuffer, newLength, preserveResizability )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-arraybuffercopyanddetach
+    pub(crate) fn copy_and_detach(
+        array_buffer: &JsValue,
+        new_length: &JsValue,
+        preserve_resizability: bool,
+        context: &mut Context<'_>,
+    ) -> JsResult<JsObject> {
+        // 1. Perform ? RequireInternalSlot(arrayBuffer, [[ArrayBufferData]]).
+        // 2. If IsSharedArrayBuffer(arrayBuffer) is true, throw a TypeError exception.
+        let obj = array_buffer.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("ArrayBuffer.transfer called with non-object value")
+        })?;
+        let byte_length = obj
+            .borrow()
+            .as_array_buffer()
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message("ArrayBuffer.transfer called with invalid object")
+            })?
+            .array_buffer_byte_length;
+
+        // 3. If newLength is undefined, then
+        //     a. Let newByteLength be arrayBuffer.[[ArrayBufferByteLength]].
+        // 4. Else,
+        //     a. Let newByteLength be ? ToIndex(newLength).
+        let new_byte_length = if new_length.is_undefined() {
+            byte_length
+        } else {
+            new_length.to_index(context)?
+        };
+
+        let mut obj = obj.borrow_mut();
+        let buf = obj
+            .as_array_buffer_mut()
+            .expect("already checked that `arrayBuffer` is an `ArrayBuffer`");
+
+        // 5. If IsDetachedBuffer(arrayBuffer) is true, throw a TypeError exception.
+        if buf.is_detached_buffer() {
+            return Err(JsNativeError::typ()
+                .with_message("ArrayBuffer.transfer called with detached buffer")
+                .into());
+        }
+
+        // 6. If preserveResizability is preserve-resizability and IsFixedLengthArrayBuffer(arrayBuffer)
+        //    is false, let newMaxByteLength be arrayBuffer.[[ArrayBufferMaxByteLength]].
+        // 7. Else, let newMaxByteLength be empty.
+        let new_max_byte_length = if preserve_resizability {
+            buf.array_buffer_max_byte_length
+        } else {
+            None
+        };
+
+        // 8. If arrayBuffer.[[ArrayBufferDetachKey]] is not undefined, throw a TypeError exception.
+        if !buf.array_buffer_detach_key.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("cannot transfer an ArrayBuffer with a detach key")
+                .into());
+        }
+
+        // 9. Let newBuffer be ? AllocateArrayBuffer(%ArrayBuffer%, newByteLength, newMaxByteLength).
+        if new_max_byte_length.map_or(false, |max| new_byte_length > max) {
+            return Err(JsNativeError::range()
+                .with_message("byte length exceeds the max byte length of the ArrayBuffer")
+                .into());
+        }
+
+        // 10. Let copyLength be min(newByteLength, arrayBuffer.[[ArrayBufferByteLength]]).
+        // 11. Let fromBlock be arrayBuffer.[[ArrayBufferData]].
+        // 12. Let toBlock be newBuffer.[[ArrayBufferData]].
+        // 13. Perform CopyDataBlockBytes(toBlock, 0, fromBlock, 0, copyLength).
+        // 14. NOTE: Neither creation of the new Data Block nor copying from the old Data Block are
+        //     observable. Implementations may implement this method as a zero-copy move or a realloc.
+        //
+        // NOTE: The data block of `arrayBuffer` is moved into the new buffer, so the steps above
+        // are performed without copying the data.
+        let mut block = buf
+            .array_buffer_data
+            .take()
+            .expect("already checked that the buffer is not detached");
+        if let Err(err) = resize_byte_data_block(&mut block, new_byte_length, new_max_byte_length) {
+            buf.array_buffer_data = Some(block);
+            return Err(err);
+        }
+
+        // 15. Perform ! DetachArrayBuffer(arrayBuffer).
+        buf.array_buffer_byte_length = 0;
+        drop(obj);
+
+        let prototype = context
+            .intrinsics()
+            .constructors()
+            .array_buffer()
+            .prototype();
+
+        // 16. Return newBuffer.
+        Ok(JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            ObjectData::array_buffer(Self {
+                array_buffer_data: Some(block),
+                array_buffer_byte_length: new_byte_length,
+                array_buffer_max_byte_length: new_max_byte_length,
+                array_buffer_detach_key: JsValue::Undefined,
+            }),
+        ))
+    }
+
+    /// `25.1.2.1 AllocateArrayBuffer ( constructor, byteLength [ , maxByteLength ] )`
     ///
     /// More information:
     ///  - [ECMAScript reference][spec]
@@ -339,31 +723,54 @@ impl ArrayBuffer {
     pub(crate) fn allocate(
         constructor: &JsValue,
         byte_length: u64,
+        max_byte_length: Option<u64>,
         context: &mut Context<'_>,
     ) -> JsResult<JsObject> {
-        // 1. Let obj be ? OrdinaryCreateFromConstructor(constructor, "%ArrayBuffer.prototype%", « [[ArrayBufferData]], [[ArrayBufferByteLength]], [[ArrayBufferDetachKey]] »).
+        // 1. Let slots be « [[ArrayBufferData]], [[ArrayBufferByteLength]], [[ArrayBufferDetachKey]] ».
+        // 2. If maxByteLength is present and maxByteLength is not empty, let allocatingResizableBuffer be true; otherwise let allocatingResizableBuffer be false.
+        // 3. If allocatingResizableBuffer is true, then
+        //     a. If byteLength > maxByteLength, throw a RangeError exception.
+        //     b. Append [[ArrayBufferMaxByteLength]] to slots.
+        if let Some(max_byte_length) = max_byte_length {
+            if byte_length > max_byte_length {
+                return Err(JsNativeError::range()
+                    .with_message("byte length exceeds the max byte length of the ArrayBuffer")
+                    .into());
+            }
+        }
+
+        // 4. Let obj be ? OrdinaryCreateFromConstructor(constructor, "%ArrayBuffer.prototype%", slots).
         let prototype = get_prototype_from_constructor(
             constructor,
             StandardConstructors::array_buffer,
             context,
         )?;
 
-        // 2. Let block be ? CreateByteDataBlock(byteLength).
-        let block = create_byte_data_block(byte_length)?;
+        // 5. Let block be ? CreateByteDataBlock(byteLength).
+        let mut block = create_byte_data_block(byte_length)?;
 
-        // 3. Set obj.[[ArrayBufferData]] to block.
-        // 4. Set obj.[[ArrayBufferByteLength]] to byteLength.
+        // 8. If allocatingResizableBuffer is true, then
+        //     a. If it is not possible to create a Data Block block consisting of maxByteLength bytes, throw a RangeError exception.
+        //     b. NOTE: Resizable ArrayBuffers are designed to be implementable with in-place growth. Implementations may throw if, for example, virtual memory cannot be reserved up front.
+        if max_byte_length.is_some() {
+            resize_byte_data_block(&mut block, byte_length, max_byte_length)?;
+        }
+
+        // 6. Set obj.[[ArrayBufferData]] to block.
+        // 7. Set obj.[[ArrayBufferByteLength]] to byteLength.
+        // 8.c. Set obj.[[ArrayBufferMaxByteLength]] to maxByteLength.
         let obj = JsObject::from_proto_and_data_with_shared_shape(
             context.root_shape(),
             prototype,
             ObjectData::array_buffer(Self {
                 array_buffer_data: Some(block),
                 array_buffer_byte_length: byte_length,
+                array_buffer_max_byte_length: max_byte_length,
                 array_buffer_detach_key: JsValue::Undefined,
             }),
         );
 
-        // 5. Return obj.
+        // 9. Return obj.
         Ok(obj)
     }
 
@@ -379,52 +786,6 @@ impl ArrayBuffer {
         self.array_buffer_data.is_none()
     }
 
-    /// `25.1.2.4 CloneArrayBuffer ( srcBuffer, srcByteOffset, srcLength, cloneConstructor )`
-    ///
-    /// More information:
-    ///  - [ECMAScript reference][spec]
-    ///
-    /// [spec]: https://tc39.es/ecma262/#sec-clonearraybuffer
-    pub(crate) fn clone_array_buffer(
-        &self,
-        src_byte_offset: u64,
-        src_length: u64,
-        clone_constructor: &JsValue,
-        context: &mut Context<'_>,
-    ) -> JsResult<JsObject> {
-        // 1. Let targetBuffer be ? AllocateArrayBuffer(cloneConstructor, srcLength).
-        let target_buffer = Self::allocate(clone_constructor, src_length, context)?;
-
-        // 2. If IsDetachedBuffer(srcBuffer) is true, throw a TypeError exception.
-        // 3. Let srcBlock be srcBuffer.[[ArrayBufferData]].
-        let src_block = self.array_buffer_data.as_deref().ok_or_else(|| {
-            JsNativeError::syntax().with_message("Cannot clone detached array buffer")
-        })?;
-
-        {
-            // 4. Let targetBlock be targetBuffer.[[ArrayBufferData]].
-            let mut target_buffer_mut = target_buffer.borrow_mut();
-            let target_block = target_buffer_mut
-                .as_array_buffer_mut()
-                .expect("This must be an ArrayBuffer");
-
-            // 5. Perform CopyDataBlockBytes(targetBlock, 0, srcBlock, srcByteOffset, srcLength).
-            copy_data_block_bytes(
-                target_block
-                    .array_buffer_data
-                    .as_mut()
-                    .expect("ArrayBuffer cannot me detached here"),
-                0,
-                src_block,
-                src_byte_offset as usize,
-                src_length as usize,
-            );
-        }
-
-        // 6. Return targetBuffer.
-        Ok(target_buffer)
-    }
-
     /// `25.1.2.6 IsUnclampedIntegerElementType ( type )`
     ///
     /// More information:
@@ -463,9 +824,10 @@ impl ArrayBuffer {
     ///  - [ECMAScript reference][spec]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-isnotearconfiguration
-    // TODO: Allow unused function until shared array buffers are implemented.
-    #[allow(dead_code)]
-    const fn is_no_tear_configuration(t: TypedArrayKind, order: SharedMemoryOrder) -> bool {
+    pub(crate) const fn is_no_tear_configuration(
+        t: TypedArrayKind,
+        order: SharedMemoryOrder,
+    ) -> bool {
         // 1. If ! IsUnclampedIntegerElementType(type) is true, return true.
         if Self::is_unclamped_integer_element_type(t) {
             return true;
@@ -491,7 +853,11 @@ impl ArrayBuffer {
     ///  - [ECMAScript reference][spec]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-rawbytestonumeric
-    fn raw_bytes_to_numeric(t: TypedArrayKind, bytes: &[u8], is_little_endian: bool) -> JsValue {
+    pub(crate) fn raw_bytes_to_numeric(
+        t: TypedArrayKind,
+        bytes: &[u8],
+        is_little_endian: bool,
+    ) -> JsValue {
         let n: Numeric = match t {
             TypedArrayKind::Int8 => {
                 if is_little_endian {
@@ -584,54 +950,13 @@ impl ArrayBuffer {
         n.into()
     }
 
-    /// `25.1.2.10 GetValueFromBuffer ( arrayBuffer, byteIndex, type, isTypedArray, order [ , isLittleEndian ] )`
-    ///
-    /// More information:
-    ///  - [ECMAScript reference][spec]
-    ///
-    /// [spec]: https://tc39.es/ecma262/#sec-getvaluefrombuffer
-    pub(crate) fn get_value_from_buffer(
-        &self,
-        byte_index: u64,
-        t: TypedArrayKind,
-        _is_typed_array: bool,
-        _order: SharedMemoryOrder,
-        is_little_endian: Option<bool>,
-    ) -> JsValue {
-        // 1. Assert: IsDetachedBuffer(arrayBuffer) is false.
-        // 2. Assert: There are sufficient bytes in arrayBuffer starting at byteIndex to represent a value of type.
-        // 3. Let block be arrayBuffer.[[ArrayBufferData]].
-        let block = self
-            .array_buffer_data
-            .as_ref()
-            .expect("ArrayBuffer cannot be detached here");
-
-        // 4. Let elementSize be the Element Size value specified in Table 73 for Element Type type.
-        let element_size = t.element_size() as usize;
-
-        // TODO: Shared Array Buffer
-        // 5. If IsSharedArrayBuffer(arrayBuffer) is true, then
-
-        // 6. Else, let rawValue be a List whose elements are bytes from block at indices byteIndex (inclusive) through byteIndex + elementSize (exclusive).
-        // 7. Assert: The number of elements in rawValue is elementSize.
-        let byte_index = byte_index as usize;
-        let raw_value = &block[byte_index..byte_index + element_size];
-
-        // TODO: Agent Record [[LittleEndian]] filed
-        // 8. If isLittleEndian is not present, set isLittleEndian to the value of the [[LittleEndian]] field of the surrounding agent's Agent Record.
-        let is_little_endian = is_little_endian.unwrap_or(true);
-
-        // 9. Return RawBytesToNumeric(type, rawValue, isLittleEndian).
-        Self::raw_bytes_to_numeric(t, raw_value, is_little_endian)
-    }
-
     /// `25.1.2.11 NumericToRawBytes ( type, value, isLittleEndian )`
     ///
     /// More information:
     ///  - [ECMAScript reference][spec]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-numerictorawbytes
-    fn numeric_to_raw_bytes(
+    pub(crate) fn numeric_to_raw_bytes(
         t: TypedArrayKind,
         value: &JsValue,
         is_little_endian: bool,
@@ -716,51 +1041,6 @@ impl ArrayBuffer {
             },
         })
     }
-
-    /// `25.1.2.12 SetValueInBuffer ( arrayBuffer, byteIndex, type, value, isTypedArray, order [ , isLittleEndian ] )`
-    ///
-    /// More information:
-    ///  - [ECMAScript reference][spec]
-    ///
-    /// [spec]: https://tc39.es/ecma262/#sec-setvalueinbuffer
-    pub(crate) fn set_value_in_buffer(
-        &mut self,
-        byte_index: u64,
-        t: TypedArrayKind,
-        value: &JsValue,
-        _order: SharedMemoryOrder,
-        is_little_endian: Option<bool>,
-        context: &mut Context<'_>,
-    ) -> JsResult<JsValue> {
-        // 1. Assert: IsDetachedBuffer(arrayBuffer) is false.
-        // 2. Assert: There are sufficient bytes in arrayBuffer starting at byteIndex to represent a value of type.
-        // 3. Assert: Type(value) is BigInt if ! IsBigIntElementType(type) is true; otherwise, Type(value) is Number.
-        // 4. Let block be arrayBuffer.[[ArrayBufferData]].
-        let block = self
-            .array_buffer_data
-            .as_mut()
-            .expect("ArrayBuffer cannot be detached here");
-
-        // 5. Let elementSize be the Element Size value specified in Table 73 for Element Type type.
-
-        // TODO: Agent Record [[LittleEndian]] filed
-        // 6. If isLittleEndian is not present, set isLittleEndian to the value of the [[LittleEndian]] field of the surrounding agent's Agent Record.
-        let is_little_endian = is_little_endian.unwrap_or(true);
-
-        // 7. Let rawBytes be NumericToRawBytes(type, value, isLittleEndian).
-        let raw_bytes = Self::numeric_to_raw_bytes(t, value, is_little_endian, context)?;
-
-        // TODO: Shared Array Buffer
-        // 8. If IsSharedArrayBuffer(arrayBuffer) is true, then
-
-        // 9. Else, store the individual bytes of rawBytes into block, starting at block[byteIndex].
-        for (i, raw_byte) in raw_bytes.iter().enumerate() {
-            block[byte_index as usize + i] = *raw_byte;
-        }
-
-        // 10. Return NormalCompletion(undefined).
-        Ok(JsValue::undefined())
-    }
 }
 
 /// `CreateByteDataBlock ( size )` abstract operation.
@@ -788,56 +1068,68 @@ pub fn create_byte_data_block(size: u64) -> JsResult<Vec<u8>> {
     Ok(data_block)
 }
 
-/// `6.2.8.3 CopyDataBlockBytes ( toBlock, toIndex, fromBlock, fromIndex, count )`
+/// Resizes `block` to `byte_length` bytes, reserving enough capacity to grow it up to
+/// `max_byte_length` bytes in place, or releasing the excess capacity if `max_byte_length` is
+/// `None`.
+///
+/// If the capacity cannot be reserved, this throws a `RangeError` and leaves the block unchanged.
+fn resize_byte_data_block(
+    block: &mut Vec<u8>,
+    byte_length: u64,
+    max_byte_length: Option<u64>,
+) -> JsResult<()> {
+    let range_error = |e: &dyn std::fmt::Display| {
+        JsNativeError::range().with_message(format!("couldn't allocate the data block: {e}"))
+    };
+
+    let byte_length = usize::try_from(byte_length).map_err(|e| range_error(&e))?;
+
+    if let Some(max_byte_length) = max_byte_length {
+        let max_byte_length = usize::try_from(max_byte_length).map_err(|e| range_error(&e))?;
+        block
+            .try_reserve_exact(max_byte_length.saturating_sub(block.len()))
+            .map_err(|e| range_error(&e))?;
+        block.resize(byte_length, 0);
+    } else {
+        block
+            .try_reserve_exact(byte_length.saturating_sub(block.len()))
+            .map_err(|e| range_error(&e))?;
+        block.resize(byte_length, 0);
+        block.shrink_to_fit();
+    }
+
+    Ok(())
+}
+
+/// `GetArrayBufferMaxByteLengthOption ( options )` abstract operation.
 ///
 /// More information:
 ///  - [ECMAScript reference][spec]
 ///
-/// [spec]: https://tc39.es/ecma262/#sec-copydatablockbytes
-fn copy_data_block_bytes(
-    to_block: &mut [u8],
-    mut to_index: usize,
-    from_block: &[u8],
-    mut from_index: usize,
-    mut count: usize,
-) {
-    // 1. Assert: fromBlock and toBlock are distinct values.
-    // 2. Let fromSize be the number of bytes in fromBlock.
-    let from_size = from_block.len();
-
-    // 3. Assert: fromIndex + count ≤ fromSize.
-    assert!(from_index + count <= from_size);
-
-    // 4. Let toSize be the number of bytes in toBlock.
-    let to_size = to_block.len();
-
-    // 5. Assert: toIndex + count ≤ toSize.
-    assert!(to_index + count <= to_size);
-
-    // 6. Repeat, while count > 0,
-    while count > 0 {
-        // a. If fromBlock is a Shared Data Block, then
-        // TODO: Shared Data Block
-
-        // b. Else,
-        // i. Assert: toBlock is not a Shared Data Block.
-        // ii. Set toBlock[toIndex] to fromBlock[fromIndex].
-        to_block[to_index] = from_block[from_index];
-
-        // c. Set toIndex to toIndex + 1.
-        to_index += 1;
-
-        // d. Set fromIndex to fromIndex + 1.
-        from_index += 1;
-
-        // e. Set count to count - 1.
-        count -= 1;
+/// [spec]: https://tc39.es/ecma262/#sec-getarraybuffermaxbytelengthoption
+pub(super) fn get_max_byte_length_option(
+    options: &JsValue,
+    context: &mut Context<'_>,
+) -> JsResult<Option<u64>> {
+    // 1. If options is not an Object, return empty.
+    let Some(options) = options.as_object() else {
+        return Ok(None);
+    };
+
+    // 2. Let maxByteLength be ? Get(options, "maxByteLength").
+    let max_byte_length = options.get(utf16!("maxByteLength"), context)?;
+
+    // 3. If maxByteLength is undefined, return empty.
+    if max_byte_length.is_undefined() {
+        return Ok(None);
     }
 
-    // 7. Return NormalCompletion(empty).
+    // 4. Return ? ToIndex(maxByteLength).
+    max_byte_length.to_index(context).map(Some)
 }
 
-// TODO: Allow unused variants until shared array buffers are implemented.
+// The `Init` events of a new Shared Data Block are implicit, since its bytes are zeroed on
+// allocation.
 #[allow(dead_code)]
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub(crate) enum SharedMemoryOrder {
@@ -0,0 +1,664 @@
+//! Boa's implementation of ECMAScript's global `SharedArrayBuffer` object.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-sharedarraybuffer-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/SharedArrayBuffer
+
+use std::{
+    alloc::{self, Layout},
+    fmt,
+    sync::{
+        atomic::{AtomicU64, AtomicU8, AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+
+use crate::{
+    builtins::{BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject},
+    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    error::JsNativeError,
+    object::{internal_methods::get_prototype_from_constructor, JsObject, ObjectData},
+    property::Attribute,
+    realm::Realm,
+    string::utf16,
+    symbol::JsSymbol,
+    value::IntegerOrInfinity,
+    Context, JsArgs, JsResult, JsValue,
+};
+
+use super::get_max_byte_length_option;
+
+/// The internal representation of a `SharedArrayBuffer` object.
+///
+/// Cloning a `SharedArrayBuffer` is cheap, and every clone refers to the same Shared Data Block,
+/// which can be sent to other threads and wrapped in a new `SharedArrayBuffer` object of a
+/// different [`Context`] using [`JsSharedArrayBuffer::from_buffer`].
+///
+/// [`JsSharedArrayBuffer::from_buffer`]: crate::object::builtins::JsSharedArrayBuffer::from_buffer
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct SharedArrayBuffer {
+    /// The `[[ArrayBufferData]]` internal slot.
+    #[unsafe_ignore_trace]
+    data: Arc<SharedDataBlock>,
+}
+
+impl SharedArrayBuffer {
+    /// Creates a new `SharedArrayBuffer` of `byte_length` bytes, with all of its bytes set to 0.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `RangeError` if the Shared Data Block couldn't be allocated.
+    pub fn new(byte_length: u64) -> JsResult<Self> {
+        Ok(Self {
+            data: Arc::new(SharedDataBlock::new(byte_length, None)?),
+        })
+    }
+
+    /// Creates a new growable `SharedArrayBuffer` of `byte_length` bytes, which can grow up to
+    /// `max_byte_length` bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `RangeError` if `byte_length` is greater than `max_byte_length`, or if the
+    /// Shared Data Block couldn't be allocated.
+    pub fn new_growable(byte_length: u64, max_byte_length: u64) -> JsResult<Self> {
+        if byte_length > max_byte_length {
+            return Err(JsNativeError::range()
+                .with_message("byte length exceeds the max byte length of the SharedArrayBuffer")
+                .into());
+        }
+        Ok(Self {
+            data: Arc::new(SharedDataBlock::new(byte_length, Some(max_byte_length))?),
+        })
+    }
+
+    /// Gets the current byte length of the buffer.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.data.byte_length.load(Ordering::SeqCst)
+    }
+
+    /// Gets the maximum byte length of the buffer, or `None` if the buffer is not growable.
+    #[must_use]
+    pub fn max_len(&self) -> Option<usize> {
+        self.data.max_byte_length
+    }
+
+    /// Returns `true` if the buffer has a byte length of zero.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns `true` if both buffers refer to the same Shared Data Block.
+    #[must_use]
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.data, &other.data)
+    }
+
+    /// Copies the current contents of the buffer into a new `Vec`.
+    #[must_use]
+    pub fn to_vec(&self) -> Vec<u8> {
+        self.data()
+            .iter()
+            .map(|byte| byte.load(Ordering::SeqCst))
+            .collect()
+    }
+
+    /// `ArrayBufferByteLength ( arrayBuffer, seq-cst )`
+    pub(crate) fn array_buffer_byte_length(&self) -> u64 {
+        self.len() as u64
+    }
+
+    /// `25.1.3.9 IsFixedLengthArrayBuffer ( arrayBuffer )`
+    pub(crate) fn is_fixed_length(&self) -> bool {
+        // 1. If arrayBuffer has an [[ArrayBufferMaxByteLength]] internal slot, return false.
+        // 2. Return true.
+        self.data.max_byte_length.is_none()
+    }
+
+    /// Gets the bytes of the Shared Data Block.
+    pub(crate) fn data(&self) -> &[AtomicU8] {
+        self.data.bytes()
+    }
+}
+
+/// A Shared Data Block, which can be accessed concurrently by multiple agents.
+///
+/// The block is allocated as a slice of [`AtomicU64`] to guarantee that every byte index which
+/// is a multiple of an element size is correctly aligned for an atomic access of that size.
+///
+/// Growable blocks reserve their maximum byte length upfront, so growing them never moves their
+/// bytes, and slices of their bytes stay valid while other agents grow them. The reservation is
+/// a zeroed allocation whose bytes are never written before the block grows over them, so the
+/// operating system only commits the memory of the pages that are part of the block, as long as
+/// it provides zeroed pages lazily (like most systems do for large allocations).
+struct SharedDataBlock {
+    words: Box<[AtomicU64]>,
+    /// The `[[ArrayBufferByteLengthData]]` of growable buffers, or the `[[ArrayBufferByteLength]]`
+    /// of fixed length buffers.
+    byte_length: AtomicUsize,
+    /// The `[[ArrayBufferMaxByteLength]]` internal slot, or `None` if the block is not growable.
+    max_byte_length: Option<usize>,
+}
+
+impl SharedDataBlock {
+    /// `CreateSharedByteDataBlock ( size )` abstract operation.
+    ///
+    /// Creates a block of `size` bytes, with room for `max_size` bytes if it is growable.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-createsharedbytedatablock
+    fn new(size: u64, max_size: Option<u64>) -> JsResult<Self> {
+        let to_usize = |size: u64| -> JsResult<usize> {
+            size.try_into().map_err(|e| {
+                JsNativeError::range()
+                    .with_message(format!("couldn't allocate the data block: {e}"))
+                    .into()
+            })
+        };
+        let byte_length = to_usize(size)?;
+        let max_byte_length = max_size.map(to_usize).transpose()?;
+
+        // 1. Let db be a new Shared Data Block value consisting of size bytes. If it is impossible
+        //    to create such a Shared Data Block, throw a RangeError exception.
+        // 2-5. Set all of the bytes of db to 0, with an Init event for every byte.
+        let capacity = max_byte_length.unwrap_or(byte_length);
+        let word_count = capacity / 8 + usize::from(capacity % 8 != 0);
+        let words = allocate_zeroed(word_count).ok_or_else(|| {
+            JsNativeError::range().with_message("couldn't allocate the data block")
+        })?;
+
+        // 6. Return db.
+        Ok(Self {
+            words,
+            byte_length: AtomicUsize::new(byte_length),
+            max_byte_length,
+        })
+    }
+
+    /// Gets the bytes of the block, up to its current byte length.
+    fn bytes(&self) -> &[AtomicU8] {
+        let byte_length = self.byte_length.load(Ordering::SeqCst);
+
+        // SAFETY: `AtomicU8` has the same in-memory representation as `u8`, which can be used to
+        // access any byte of an `AtomicU64` with the same synchronization guarantees. The words
+        // slice contains at least `max_byte_length` bytes, which is never less than
+        // `byte_length`, and the returned slice borrows `self`, so the allocation outlives it.
+        unsafe { std::slice::from_raw_parts(self.words.as_ptr().cast::<AtomicU8>(), byte_length) }
+    }
+}
+
+/// Allocates `len` zeroed words, without writing to them.
+///
+/// Returns `None` if the allocation fails.
+fn allocate_zeroed(len: usize) -> Option<Box<[AtomicU64]>> {
+    if len == 0 {
+        return Some(Box::default());
+    }
+    let layout = Layout::array::<AtomicU64>(len).ok()?;
+
+    // SAFETY: `layout` has a non-zero size.
+    let ptr = unsafe { alloc::alloc_zeroed(layout) };
+    // The allocation has the alignment of `AtomicU64`, since it was allocated with its layout.
+    #[allow(clippy::cast_ptr_alignment)]
+    let ptr = ptr.cast::<AtomicU64>();
+    if ptr.is_null() {
+        return None;
+    }
+
+    // SAFETY: `ptr` was allocated by the global allocator with the layout of a slice of `len`
+    // words, and all zero bytes is a valid `AtomicU64`.
+    Some(unsafe { Box::from_raw(std::ptr::slice_from_raw_parts_mut(ptr, len)) })
+}
+
+impl fmt::Debug for SharedDataBlock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SharedDataBlock")
+            .field("byte_length", &self.byte_length)
+            .field("max_byte_length", &self.max_byte_length)
+            .finish_non_exhaustive()
+    }
+}
+
+impl IntrinsicObject for SharedArrayBuffer {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        let flag_attributes = Attribute::CONFIGURABLE | Attribute::NON_ENUMERABLE;
+
+        let get_species = BuiltInBuilder::callable(realm, Self::get_species)
+            .name("get [Symbol.species]")
+            .build();
+
+        let get_byte_length = BuiltInBuilder::callable(realm, Self::get_byte_length)
+            .name("get byteLength")
+            .build();
+
+        let get_growable = BuiltInBuilder::callable(realm, Self::get_growable)
+            .name("get growable")
+            .build();
+
+        let get_max_byte_length = BuiltInBuilder::callable(realm, Self::get_max_byte_length)
+            .name("get maxByteLength")
+            .build();
+
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .accessor(
+                utf16!("byteLength"),
+                Some(get_byte_length),
+                None,
+                flag_attributes,
+            )
+            .accessor(
+                utf16!("growable"),
+                Some(get_growable),
+                None,
+                flag_attributes,
+            )
+            .accessor(
+                utf16!("maxByteLength"),
+                Some(get_max_byte_length),
+                None,
+                flag_attributes,
+            )
+            .static_accessor(
+                JsSymbol::species(),
+                Some(get_species),
+                None,
+                Attribute::CONFIGURABLE,
+            )
+            .method(Self::grow, "grow", 1)
+            .method(Self::slice, "slice", 2)
+            .property(
+                JsSymbol::to_string_tag(),
+                Self::NAME,
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for SharedArrayBuffer {
+    const NAME: &'static str = "SharedArrayBuffer";
+}
+
+impl BuiltInConstructor for SharedArrayBuffer {
+    const LENGTH: usize = 1;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::shared_array_buffer;
+
+    /// `25.2.3.1 SharedArrayBuffer ( length [ , options ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-sharedarraybuffer-length
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("SharedArrayBuffer.constructor called with undefined new target")
+                .into());
+        }
+
+        // 2. Let byteLength be ? ToIndex(length).
+        let byte_length = args.get_or_undefined(0).to_index(context)?;
+
+        // 3. Let requestedMaxByteLength be ? GetArrayBufferMaxByteLengthOption(options).
+        let max_byte_length = get_max_byte_length_option(args.get_or_undefined(1), context)?;
+
+        // 4. Return ? AllocateSharedArrayBuffer(NewTarget, byteLength, requestedMaxByteLength).
+        Ok(Self::allocate(new_target, byte_length, max_byte_length, context)?.into())
+    }
+}
+
+impl SharedArrayBuffer {
+    /// `25.2.4.2 get SharedArrayBuffer [ @@species ]`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-sharedarraybuffer-@@species
+    #[allow(clippy::unnecessary_wraps)]
+    fn get_species(this: &JsValue, _: &[JsValue], _: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Return the this value.
+        Ok(this.clone())
+    }
+
+    /// `25.2.5.1 get SharedArrayBuffer.prototype.byteLength`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-sharedarraybuffer.prototype.bytelength
+    fn get_byte_length(this: &JsValue, _: &[JsValue], _: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[ArrayBufferData]]).
+        // 3. If IsSharedArrayBuffer(O) is false, throw a TypeError exception.
+        let obj = this.as_object().ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("SharedArrayBuffer.byteLength called with non-object value")
+        })?;
+        let obj = obj.borrow();
+        let buf = obj.as_shared_array_buffer().ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("SharedArrayBuffer.byteLength called with invalid object")
+        })?;
+
+        // 4. Let length be ArrayBufferByteLength(O, seq-cst).
+        // 5. Return 𝔽(length).
+        Ok(buf.array_buffer_byte_length().into())
+    }
+
+    /// `25.2.5.3 SharedArrayBuffer.prototype.grow ( newLength )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-sharedarraybuffer.prototype.grow
+    fn grow(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[ArrayBufferMaxByteLength]]).
+        // 3. If IsSharedArrayBuffer(O) is false, throw a TypeError exception.
+        let buf = this
+            .as_object()
+            .and_then(|obj| obj.borrow().as_shared_array_buffer().cloned())
+            .filter(|buf| !buf.is_fixed_length())
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("SharedArrayBuffer.grow called with invalid object")
+            })?;
+
+        // 4. Let newByteLength be ? ToIndex(newLength).
+        let new_byte_length = args.get_or_undefined(0).to_index(context)?;
+
+        // 5. Let hostHandled be ? HostGrowSharedArrayBuffer(O, newByteLength).
+        // 6. If hostHandled is handled, return undefined.
+        // 7. Let isLittleEndian be the value of the [[LittleEndian]] field of the surrounding agent's Agent Record.
+        // 8. Let byteLengthBlock be O.[[ArrayBufferByteLengthData]].
+        // 9. Let currentByteLengthRawBytes be GetRawBytesFromSharedBlock(byteLengthBlock, 0, biguint64, true, seq-cst).
+        // 10. Let newByteLengthRawBytes be NumericToRawBytes(biguint64, ℤ(newByteLength), isLittleEndian).
+        let max_byte_length = buf
+            .data
+            .max_byte_length
+            .expect("growable buffers must have a max byte length");
+        let byte_length = &buf.data.byte_length;
+        let mut current_byte_length = byte_length.load(Ordering::SeqCst);
+
+        // 11. Repeat,
+        loop {
+            // a. NOTE: This is a compare-and-exchange loop to ensure that parallel, racing grows
+            //    of the same buffer are totally ordered, are not lost, and do not silently do
+            //    nothing. The loop exits if it was able to attempt to grow uncontended.
+            // b. Let currentByteLength be ℝ(RawBytesToNumeric(biguint64, currentByteLengthRawBytes, isLittleEndian)).
+            // c. If newByteLength = currentByteLength, return undefined.
+            if new_byte_length == current_byte_length as u64 {
+                return Ok(JsValue::undefined());
+            }
+
+            // d. If newByteLength < currentByteLength or newByteLength > O.[[ArrayBufferMaxByteLength]], throw a RangeError exception.
+            if new_byte_length < current_byte_length as u64
+                || new_byte_length > max_byte_length as u64
+            {
+                return Err(JsNativeError::range()
+                    .with_message("SharedArrayBuffer.grow called with an invalid length")
+                    .into());
+            }
+
+            // e. Let byteLengthDelta be newByteLength - currentByteLength.
+            // f. If it is impossible to create a new Shared Data Block value consisting of
+            //    byteLengthDelta bytes, throw a RangeError exception.
+            // g. NOTE: No new Shared Data Block is constructed and used here. The observable
+            //    behaviour of growable SharedArrayBuffers is specified by allocating a max-sized
+            //    Shared Data Block at construction time, and this step captures the requirement
+            //    that implementations that run out of memory must throw a RangeError.
+            // h. Let readByteLengthRawBytes be AtomicCompareExchangeInSharedBlock(byteLengthBlock, 0, 8, currentByteLengthRawBytes, newByteLengthRawBytes).
+            // i. If ByteListEqual(readByteLengthRawBytes, currentByteLengthRawBytes) is true, return undefined.
+            // j. Set currentByteLengthRawBytes to readByteLengthRawBytes.
+            match byte_length.compare_exchange(
+                current_byte_length,
+                new_byte_length as usize,
+                Ordering::SeqCst,
+                Ordering::SeqCst,
+            ) {
+                Ok(_) => return Ok(JsValue::undefined()),
+                Err(read_byte_length) => current_byte_length = read_byte_length,
+            }
+        }
+    }
+
+    /// `25.2.5.4 get SharedArrayBuffer.prototype.growable`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-sharedarraybuffer.prototype.growable
+    fn get_growable(this: &JsValue, _: &[JsValue], _: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[ArrayBufferData]]).
+        // 3. If IsSharedArrayBuffer(O) is false, throw a TypeError exception.
+        let obj = this.as_object().ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("SharedArrayBuffer.growable called with non-object value")
+        })?;
+        let obj = obj.borrow();
+        let buf = obj.as_shared_array_buffer().ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("SharedArrayBuffer.growable called with invalid object")
+        })?;
+
+        // 4. If IsFixedLengthArrayBuffer(O) is false, return true; otherwise return false.
+        Ok((!buf.is_fixed_length()).into())
+    }
+
+    /// `25.2.5.5 get SharedArrayBuffer.prototype.maxByteLength`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-sharedarraybuffer.prototype.maxbytelength
+    fn get_max_byte_length(
+        this: &JsValue,
+        _: &[JsValue],
+        _: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[ArrayBufferData]]).
+        // 3. If IsSharedArrayBuffer(O) is false, throw a TypeError exception.
+        let obj = this.as_object().ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("SharedArrayBuffer.maxByteLength called with non-object value")
+        })?;
+        let obj = obj.borrow();
+        let buf = obj.as_shared_array_buffer().ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("SharedArrayBuffer.maxByteLength called with invalid object")
+        })?;
+
+        // 4. If IsFixedLengthArrayBuffer(O) is true, then
+        //     a. Let length be O.[[ArrayBufferByteLength]].
+        // 5. Else,
+        //     a. Let length be O.[[ArrayBufferMaxByteLength]].
+        // 6. Return 𝔽(length).
+        Ok(buf.max_len().unwrap_or_else(|| buf.len()).into())
+    }
+
+    /// `25.2.5.6 SharedArrayBuffer.prototype.slice ( start, end )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-sharedarraybuffer.prototype.slice
+    fn slice(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[ArrayBufferData]]).
+        // 3. If IsSharedArrayBuffer(O) is false, throw a TypeError exception.
+        let obj = this.as_object().ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("SharedArrayBuffer.slice called with non-object value")
+        })?;
+        let buf = obj
+            .borrow()
+            .as_shared_array_buffer()
+            .cloned()
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("SharedArrayBuffer.slice called with invalid object")
+            })?;
+
+        // 4. Let len be ArrayBufferByteLength(O, seq-cst).
+        let len = buf.array_buffer_byte_length() as i64;
+
+        // 5. Let relativeStart be ? ToIntegerOrInfinity(start).
+        let relative_start = args.get_or_undefined(0).to_integer_or_infinity(context)?;
+
+        let first = match relative_start {
+            // 6. If relativeStart is -∞, let first be 0.
+            IntegerOrInfinity::NegativeInfinity => 0,
+            // 7. Else if relativeStart < 0, let first be max(len + relativeStart, 0).
+            IntegerOrInfinity::Integer(i) if i < 0 => std::cmp::max(len + i, 0),
+            // 8. Else, let first be min(relativeStart, len).
+            IntegerOrInfinity::Integer(i) => std::cmp::min(i, len),
+            IntegerOrInfinity::PositiveInfinity => len,
+        };
+
+        // 9. If end is undefined, let relativeEnd be len; else let relativeEnd be ? ToIntegerOrInfinity(end).
+        let end = args.get_or_undefined(1);
+        let relative_end = if end.is_undefined() {
+            IntegerOrInfinity::Integer(len)
+        } else {
+            end.to_integer_or_infinity(context)?
+        };
+
+        let r#final = match relative_end {
+            // 10. If relativeEnd is -∞, let final be 0.
+            IntegerOrInfinity::NegativeInfinity => 0,
+            // 11. Else if relativeEnd < 0, let final be max(len + relativeEnd, 0).
+            IntegerOrInfinity::Integer(i) if i < 0 => std::cmp::max(len + i, 0),
+            // 12. Else, let final be min(relativeEnd, len).
+            IntegerOrInfinity::Integer(i) => std::cmp::min(i, len),
+            IntegerOrInfinity::PositiveInfinity => len,
+        };
+
+        // 13. Let newLen be max(final - first, 0).
+        let new_len = std::cmp::max(r#final - first, 0) as u64;
+
+        // 14. Let ctor be ? SpeciesConstructor(O, %SharedArrayBuffer%).
+        let ctor = obj.species_constructor(StandardConstructors::shared_array_buffer, context)?;
+
+        // 15. Let new be ? Construct(ctor, « 𝔽(newLen) »).
+        let new = ctor.construct(&[new_len.into()], Some(&ctor), context)?;
+
+        // 16. Perform ? RequireInternalSlot(new, [[ArrayBufferData]]).
+        // 17. If IsSharedArrayBuffer(new) is false, throw a TypeError exception.
+        let new_buf = new
+            .borrow()
+            .as_shared_array_buffer()
+            .cloned()
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("SharedArrayBuffer constructor returned invalid object")
+            })?;
+
+        // 18. If new.[[ArrayBufferData]] is O.[[ArrayBufferData]], throw a TypeError exception.
+        if new_buf.ptr_eq(&buf) {
+            return Err(JsNativeError::typ()
+                .with_message("cannot reuse the same SharedArrayBuffer for a slice operation")
+                .into());
+        }
+
+        // 19. If ArrayBufferByteLength(new, seq-cst) < newLen, throw a TypeError exception.
+        if new_buf.array_buffer_byte_length() < new_len {
+            return Err(JsNativeError::typ()
+                .with_message("invalid size of constructed SharedArrayBuffer")
+                .into());
+        }
+
+        // 20. Let fromBuf be O.[[ArrayBufferData]].
+        // 21. Let toBuf be new.[[ArrayBufferData]].
+        // 22. Perform CopyDataBlockBytes(toBuf, 0, fromBuf, first, newLen).
+        let first = first as usize;
+        let from_buf = &buf.data()[first..first + new_len as usize];
+        for (to, from) in new_buf.data().iter().zip(from_buf) {
+            to.store(from.load(Ordering::SeqCst), Ordering::SeqCst);
+        }
+
+        // 23. Return new.
+        Ok(new.into())
+    }
+
+    /// `25.2.2.1 AllocateSharedArrayBuffer ( constructor, byteLength [ , maxByteLength ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-allocatesharedarraybuffer
+    pub(crate) fn allocate(
+        constructor: &JsValue,
+        byte_length: u64,
+        max_byte_length: Option<u64>,
+        context: &mut Context<'_>,
+    ) -> JsResult<JsObject> {
+        // 1. Let slots be « [[ArrayBufferData]] ».
+        // 2. If maxByteLength is present and maxByteLength is not empty, let allocatingGrowableBuffer be true; otherwise let allocatingGrowableBuffer be false.
+        // 3. If allocatingGrowableBuffer is true, then
+        //     a. If byteLength > maxByteLength, throw a RangeError exception.
+        //     b. Append [[ArrayBufferByteLengthData]] and [[ArrayBufferMaxByteLength]] to slots.
+        // 4. Else,
+        //     a. Append [[ArrayBufferByteLength]] to slots.
+        if max_byte_length.map_or(false, |max_byte_length| byte_length > max_byte_length) {
+            return Err(JsNativeError::range()
+                .with_message("byte length exceeds the max byte length of the SharedArrayBuffer")
+                .into());
+        }
+
+        // 5. Let obj be ? OrdinaryCreateFromConstructor(constructor, "%SharedArrayBuffer.prototype%", slots).
+        let prototype = get_prototype_from_constructor(
+            constructor,
+            StandardConstructors::shared_array_buffer,
+            context,
+        )?;
+
+        // 6. If allocatingGrowableBuffer is true, let allocLength be maxByteLength; otherwise let allocLength be byteLength.
+        // 7. Let block be ? CreateSharedByteDataBlock(allocLength).
+        // 8. Set obj.[[ArrayBufferData]] to block.
+        // 9. If allocatingGrowableBuffer is true, then
+        //     a. Assert: byteLength ≤ maxByteLength.
+        //     b. Let byteLengthBlock be ? CreateSharedByteDataBlock(8).
+        //     c. Perform SetValueInBuffer(byteLengthBlock, 0, biguint64, ℤ(byteLength), true, seq-cst).
+        //     d. Set obj.[[ArrayBufferByteLengthData]] to byteLengthBlock.
+        //     e. Set obj.[[ArrayBufferMaxByteLength]] to maxByteLength.
+        // 10. Else,
+        //     a. Set obj.[[ArrayBufferByteLength]] to byteLength.
+        let buffer = match max_byte_length {
+            Some(max_byte_length) => Self::new_growable(byte_length, max_byte_length)?,
+            None => Self::new(byte_length)?,
+        };
+        let obj = JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            ObjectData::shared_array_buffer(buffer),
+        );
+
+        // 11. Return obj.
+        Ok(obj)
+    }
+}
@@ -1,3 +1,5 @@
+use crate::{js_string, run_test_actions, JsNativeErrorKind, TestAction};
+
 #[test]
 fn create_byte_data_block() {
     // Sunny day
@@ -6,3 +8,216 @@ fn create_byte_data_block() {
     // Rainy day
     assert!(super::create_byte_data_block(u64::MAX).is_err());
 }
+
+#[test]
+fn shared_array_buffer() {
+    run_test_actions([
+        TestAction::run("let sab = new SharedArrayBuffer(8)"),
+        TestAction::assert_eq("sab.byteLength", 8),
+        TestAction::assert_eq(
+            "Object.prototype.toString.call(sab)",
+            js_string!("[object SharedArrayBuffer]"),
+        ),
+        TestAction::run("new Uint8Array(sab).set([1, 2, 3, 4, 5, 6, 7, 8])"),
+        TestAction::assert_eq("new Uint8Array(sab.slice(2, -2)).join()", js_string!("3,4,5,6")),
+        TestAction::assert_eq("new DataView(sab).getUint16(0)", 0x0102),
+        TestAction::assert_eq("new Int32Array(sab, 4)[0]", 0x0807_0605),
+        TestAction::assert_eq("new Uint8Array(new Uint8Array(sab)).buffer.constructor.name", js_string!("ArrayBuffer")),
+        TestAction::assert_native_error(
+            "SharedArrayBuffer(8)",
+            JsNativeErrorKind::Type,
+            "SharedArrayBuffer.constructor called with undefined new target",
+        ),
+        TestAction::assert_native_error(
+            "Object.getOwnPropertyDescriptor(ArrayBuffer.prototype, 'byteLength').get.call(sab)",
+            JsNativeErrorKind::Type,
+            "ArrayBuffer.byteLength called with invalid object",
+        ),
+        TestAction::assert_native_error(
+            "Object.getOwnPropertyDescriptor(SharedArrayBuffer.prototype, 'byteLength').get.call(new ArrayBuffer(8))",
+            JsNativeErrorKind::Type,
+            "SharedArrayBuffer.byteLength called with invalid object",
+        ),
+    ]);
+}
+
+#[test]
+fn resizable() {
+    run_test_actions([
+        TestAction::run("let buffer = new ArrayBuffer(2, { maxByteLength: 8 })"),
+        TestAction::assert("buffer.resizable"),
+        TestAction::assert("!new ArrayBuffer(2).resizable"),
+        TestAction::assert_eq("buffer.maxByteLength", 8),
+        TestAction::assert_eq("new ArrayBuffer(2).maxByteLength", 2),
+        TestAction::run("new Uint8Array(buffer).set([1, 2])"),
+        TestAction::run("buffer.resize(6)"),
+        TestAction::assert_eq("buffer.byteLength", 6),
+        TestAction::assert_eq("new Uint8Array(buffer).join()", js_string!("1,2,0,0,0,0")),
+        TestAction::run("buffer.resize(1)"),
+        TestAction::assert_eq("new Uint8Array(buffer).join()", js_string!("1")),
+        TestAction::run("buffer.resize(8)"),
+        TestAction::assert_eq(
+            "new Uint8Array(buffer).join()",
+            js_string!("1,0,0,0,0,0,0,0"),
+        ),
+        TestAction::assert_native_error(
+            "buffer.resize(9)",
+            JsNativeErrorKind::Range,
+            "new byte length exceeds the max byte length of the ArrayBuffer",
+        ),
+        TestAction::assert_native_error(
+            "new ArrayBuffer(2).resize(1)",
+            JsNativeErrorKind::Type,
+            "ArrayBuffer.resize called with invalid object",
+        ),
+        TestAction::assert_native_error(
+            "new ArrayBuffer(4, { maxByteLength: 2 })",
+            JsNativeErrorKind::Range,
+            "byte length exceeds the max byte length of the ArrayBuffer",
+        ),
+    ]);
+}
+
+#[test]
+fn growable_shared() {
+    run_test_actions([
+        TestAction::run("let buffer = new SharedArrayBuffer(2, { maxByteLength: 8 })"),
+        TestAction::run("let tracking = new Uint8Array(buffer)"),
+        TestAction::assert("buffer.growable"),
+        TestAction::assert("!new SharedArrayBuffer(2).growable"),
+        TestAction::assert_eq("buffer.maxByteLength", 8),
+        TestAction::assert_eq("new SharedArrayBuffer(2).maxByteLength", 2),
+        TestAction::run("tracking.set([1, 2])"),
+        TestAction::assert("buffer.grow(6) === undefined"),
+        TestAction::assert_eq("buffer.byteLength", 6),
+        TestAction::assert_eq("tracking.length", 6),
+        TestAction::assert_eq("tracking.join()", js_string!("1,2,0,0,0,0")),
+        TestAction::run("buffer.grow(6)"),
+        TestAction::assert_eq("buffer.slice(1, 3).byteLength", 2),
+        TestAction::assert_native_error(
+            "buffer.grow(4)",
+            JsNativeErrorKind::Range,
+            "SharedArrayBuffer.grow called with an invalid length",
+        ),
+        TestAction::assert_native_error(
+            "buffer.grow(9)",
+            JsNativeErrorKind::Range,
+            "SharedArrayBuffer.grow called with an invalid length",
+        ),
+        TestAction::assert_native_error(
+            "new SharedArrayBuffer(2).grow(4)",
+            JsNativeErrorKind::Type,
+            "SharedArrayBuffer.grow called with invalid object",
+        ),
+        TestAction::assert_native_error(
+            "new SharedArrayBuffer(4, { maxByteLength: 2 })",
+            JsNativeErrorKind::Range,
+            "byte length exceeds the max byte length of the SharedArrayBuffer",
+        ),
+        // The max byte length is only reserved, the memory is committed as the buffer grows.
+        TestAction::run("let large = new SharedArrayBuffer(0, { maxByteLength: 2 ** 30 })"),
+        TestAction::run("large.grow(16)"),
+        TestAction::assert_eq("new Uint8Array(large).join('')", js_string!("0".repeat(16))),
+    ]);
+}
+
+#[test]
+fn transfer() {
+    run_test_actions([
+        TestAction::run("let buffer = new ArrayBuffer(4, { maxByteLength: 8 })"),
+        TestAction::run("new Uint8Array(buffer).set([1, 2, 3, 4])"),
+        TestAction::run("let transferred = buffer.transfer(6)"),
+        TestAction::assert("buffer.detached"),
+        TestAction::assert_eq("buffer.byteLength", 0),
+        TestAction::assert("!transferred.detached"),
+        TestAction::assert("transferred.resizable"),
+        TestAction::assert_eq("transferred.maxByteLength", 8),
+        TestAction::assert_eq(
+            "new Uint8Array(transferred).join()",
+            js_string!("1,2,3,4,0,0"),
+        ),
+        TestAction::run("let fixed = transferred.transferToFixedLength(2)"),
+        TestAction::assert("!fixed.resizable"),
+        TestAction::assert_eq("new Uint8Array(fixed).join()", js_string!("1,2")),
+        TestAction::assert_eq("fixed.transfer().byteLength", 2),
+        TestAction::assert_native_error(
+            "buffer.transfer()",
+            JsNativeErrorKind::Type,
+            "ArrayBuffer.transfer called with detached buffer",
+        ),
+        TestAction::assert_native_error(
+            "new ArrayBuffer(1, { maxByteLength: 2 }).transfer(3)",
+            JsNativeErrorKind::Range,
+            "byte length exceeds the max byte length of the ArrayBuffer",
+        ),
+    ]);
+}
+
+#[test]
+fn length_tracking_typed_array() {
+    run_test_actions([
+        TestAction::run("let buffer = new ArrayBuffer(4, { maxByteLength: 16 })"),
+        TestAction::run("let tracking = new Uint16Array(buffer, 2)"),
+        TestAction::run("let fixed = new Uint16Array(buffer, 0, 2)"),
+        TestAction::assert_eq("tracking.length", 1),
+        TestAction::run("buffer.resize(10)"),
+        TestAction::assert_eq("tracking.length", 4),
+        TestAction::assert_eq("tracking.byteLength", 8),
+        TestAction::run("tracking.fill(7)"),
+        TestAction::assert_eq("[...tracking].join()", js_string!("7,7,7,7")),
+        TestAction::assert_eq("tracking.subarray(1).length", 3),
+        TestAction::run("buffer.resize(16)"),
+        TestAction::assert_eq("tracking.length", 7),
+        TestAction::run("buffer.resize(3)"),
+        TestAction::assert_eq("tracking.length", 0),
+        TestAction::assert_eq("fixed.length", 0),
+        TestAction::assert_eq("fixed.byteOffset", 0),
+        TestAction::assert_native_error(
+            "fixed.fill(0)",
+            JsNativeErrorKind::Type,
+            "typed array is outside the bounds of its inner buffer",
+        ),
+        TestAction::run("buffer.resize(1)"),
+        TestAction::assert_eq("tracking.byteOffset", 0),
+        TestAction::assert("tracking[0] === undefined"),
+        TestAction::assert_native_error(
+            "new Uint16Array(buffer, 4)",
+            JsNativeErrorKind::Range,
+            "Invalid length for typed array",
+        ),
+    ]);
+}
+
+#[test]
+fn length_tracking_data_view() {
+    run_test_actions([
+        TestAction::run("let buffer = new ArrayBuffer(4, { maxByteLength: 8 })"),
+        TestAction::run("let tracking = new DataView(buffer, 2)"),
+        TestAction::run("let fixed = new DataView(buffer, 0, 4)"),
+        TestAction::assert_eq("tracking.byteLength", 2),
+        TestAction::run("buffer.resize(8)"),
+        TestAction::assert_eq("tracking.byteLength", 6),
+        TestAction::run("tracking.setUint32(2, 0x01020304)"),
+        TestAction::assert_eq(
+            "new Uint8Array(buffer).join()",
+            js_string!("0,0,0,0,1,2,3,4"),
+        ),
+        TestAction::run("buffer.resize(3)"),
+        TestAction::assert_eq("tracking.byteLength", 1),
+        TestAction::assert_native_error(
+            "fixed.byteLength",
+            JsNativeErrorKind::Type,
+            "DataView is outside the bounds of its buffer",
+        ),
+        TestAction::assert_native_error(
+            "fixed.getInt8(0)",
+            JsNativeErrorKind::Type,
+            "DataView is outside the bounds of its buffer",
+        ),
+        TestAction::assert_native_error(
+            "tracking.getUint16(0)",
+            JsNativeErrorKind::Range,
+            "Offset is outside the bounds of the DataView",
+        ),
+    ]);
+}
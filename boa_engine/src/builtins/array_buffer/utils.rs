@@ -0,0 +1,576 @@
+//! Utilities to access the data blocks of both `ArrayBuffer` and `SharedArrayBuffer` objects.
+
+// Required per unsafe code standards to ensure every unsafe usage is properly documented.
+#![deny(
+    unsafe_op_in_unsafe_fn,
+    clippy::undocumented_unsafe_blocks,
+    clippy::missing_safety_doc
+)]
+
+use std::{
+    ops::Range,
+    sync::atomic::{AtomicU16, AtomicU32, AtomicU64, AtomicU8, Ordering},
+};
+
+use crate::{
+    builtins::typed_array::TypedArrayKind, error::JsNativeError, object::JsObject, Context,
+    JsResult, JsValue,
+};
+
+use super::{ArrayBuffer, SharedArrayBuffer, SharedMemoryOrder};
+
+impl SharedMemoryOrder {
+    /// Gets the atomic ordering used to perform an access with this order on a Shared Data Block.
+    const fn ordering(self) -> Ordering {
+        match self {
+            Self::SeqCst => Ordering::SeqCst,
+            Self::Init | Self::Unordered => Ordering::Relaxed,
+        }
+    }
+}
+
+/// A reference to the data block of either an `ArrayBuffer` or a `SharedArrayBuffer`.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum SliceRef<'a> {
+    Slice(&'a [u8]),
+    AtomicSlice(&'a [AtomicU8]),
+}
+
+impl SliceRef<'_> {
+    /// Gets the byte length of the block.
+    pub(crate) const fn len(&self) -> usize {
+        match self {
+            Self::Slice(buf) => buf.len(),
+            Self::AtomicSlice(buf) => buf.len(),
+        }
+    }
+
+    /// Gets the subslice of the block at `range`.
+    pub(crate) fn subslice(&self, range: Range<usize>) -> Self {
+        match self {
+            Self::Slice(buf) => Self::Slice(&buf[range]),
+            Self::AtomicSlice(buf) => Self::AtomicSlice(&buf[range]),
+        }
+    }
+
+    /// Reads `out.len()` raw bytes starting at `index`.
+    ///
+    /// Accesses to a Shared Data Block are performed as a single atomic access if `no_tear` is
+    /// `true` and the accessed bytes are correctly aligned, or as byte-wise atomic accesses
+    /// otherwise.
+    #[allow(clippy::cast_ptr_alignment)]
+    fn read_raw(&self, index: usize, out: &mut [u8], order: SharedMemoryOrder, no_tear: bool) {
+        let range = index..index + out.len();
+        match self {
+            Self::Slice(buf) => out.copy_from_slice(&buf[range]),
+            Self::AtomicSlice(buf) => {
+                let bytes = &buf[range];
+                let ordering = order.ordering();
+                let ptr = bytes.as_ptr();
+                if no_tear && ptr as usize % bytes.len() == 0 {
+                    // SAFETY: `bytes` is correctly aligned for an atomic of its size, and atomics
+                    // share their in-memory representation with their underlying integers.
+                    unsafe {
+                        match bytes.len() {
+                            2 => {
+                                let atomic = &*ptr.cast::<AtomicU16>();
+                                out.copy_from_slice(&atomic.load(ordering).to_ne_bytes());
+                                return;
+                            }
+                            4 => {
+                                let atomic = &*ptr.cast::<AtomicU32>();
+                                out.copy_from_slice(&atomic.load(ordering).to_ne_bytes());
+                                return;
+                            }
+                            8 => {
+                                let atomic = &*ptr.cast::<AtomicU64>();
+                                out.copy_from_slice(&atomic.load(ordering).to_ne_bytes());
+                                return;
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+                for (out, byte) in out.iter_mut().zip(bytes) {
+                    *out = byte.load(ordering);
+                }
+            }
+        }
+    }
+
+    /// `25.1.2.10 GetValueFromBuffer ( arrayBuffer, byteIndex, type, isTypedArray, order [ , isLittleEndian ] )`
+    ///
+    /// Steps 4 to 9 of the operation, which read the value from this data block.
+    pub(crate) fn get_value(
+        &self,
+        byte_index: usize,
+        t: TypedArrayKind,
+        is_typed_array: bool,
+        order: SharedMemoryOrder,
+        is_little_endian: Option<bool>,
+    ) -> JsValue {
+        // 4. Let elementSize be the Element Size value specified in Table 73 for Element Type type.
+        let element_size = t.element_size() as usize;
+
+        let mut raw_value = [0; 8];
+        let raw_value = &mut raw_value[..element_size];
+
+        // 5. If IsSharedArrayBuffer(arrayBuffer) is true, then
+        //     a. Let execution be the [[CandidateExecution]] field of the surrounding agent's Agent Record.
+        //     b. Let eventsRecord be the Agent Events Record of execution.[[EventsRecords]] whose [[AgentSignifier]] is AgentSignifier().
+        //     c. If isTypedArray is true and IsNoTearConfiguration(type, order) is true, let noTear be true; otherwise let noTear be false.
+        //     d. Let rawValue be a List of length elementSize whose elements are nondeterministically chosen byte values.
+        //     e. NOTE: In implementations, rawValue is the result of a non-atomic or atomic read instruction on the underlying hardware.
+        //     f. Let readEvent be ReadSharedMemory { [[Order]]: order, [[NoTear]]: noTear, [[Block]]: block, [[ByteIndex]]: byteIndex, [[ElementSize]]: elementSize }.
+        //     g. Append readEvent to eventsRecord.[[EventList]].
+        //     h. Append Chosen Value Record { [[Event]]: readEvent, [[ChosenValue]]: rawValue } to execution.[[ChosenValues]].
+        // 6. Else, let rawValue be a List whose elements are bytes from block at indices byteIndex (inclusive) through byteIndex + elementSize (exclusive).
+        // 7. Assert: The number of elements in rawValue is elementSize.
+        let no_tear = order == SharedMemoryOrder::SeqCst
+            || is_typed_array && ArrayBuffer::is_no_tear_configuration(t, order);
+        self.read_raw(byte_index, raw_value, order, no_tear);
+
+        // TODO: Agent Record [[LittleEndian]] filed
+        // 8. If isLittleEndian is not present, set isLittleEndian to the value of the [[LittleEndian]] field of the surrounding agent's Agent Record.
+        let is_little_endian = is_little_endian.unwrap_or(true);
+
+        // 9. Return RawBytesToNumeric(type, rawValue, isLittleEndian).
+        ArrayBuffer::raw_bytes_to_numeric(t, raw_value, is_little_endian)
+    }
+}
+
+/// A mutable reference to the data block of either an `ArrayBuffer` or a `SharedArrayBuffer`.
+#[derive(Debug)]
+pub(crate) enum SliceRefMut<'a> {
+    Slice(&'a mut [u8]),
+    AtomicSlice(&'a [AtomicU8]),
+}
+
+impl SliceRefMut<'_> {
+    /// Gets the byte length of the block.
+    pub(crate) const fn len(&self) -> usize {
+        match self {
+            Self::Slice(buf) => buf.len(),
+            Self::AtomicSlice(buf) => buf.len(),
+        }
+    }
+
+    /// Reborrows this reference as an immutable [`SliceRef`].
+    pub(crate) fn as_slice_ref(&self) -> SliceRef<'_> {
+        match self {
+            Self::Slice(buf) => SliceRef::Slice(buf),
+            Self::AtomicSlice(buf) => SliceRef::AtomicSlice(buf),
+        }
+    }
+
+    /// Writes `raw` starting at `index`.
+    ///
+    /// Accesses to a Shared Data Block are performed as a single atomic access if the accessed
+    /// bytes are correctly aligned, or as byte-wise atomic accesses otherwise.
+    #[allow(clippy::cast_ptr_alignment)]
+    fn write_raw(&mut self, index: usize, raw: &[u8], order: SharedMemoryOrder) {
+        let range = index..index + raw.len();
+        match self {
+            Self::Slice(buf) => buf[range].copy_from_slice(raw),
+            Self::AtomicSlice(buf) => {
+                let bytes = &buf[range];
+                let ordering = order.ordering();
+                let ptr = bytes.as_ptr();
+                if ptr as usize % bytes.len() == 0 {
+                    // SAFETY: `bytes` is correctly aligned for an atomic of its size, and atomics
+                    // share their in-memory representation with their underlying integers.
+                    unsafe {
+                        match raw.len() {
+                            2 => {
+                                let raw = raw.try_into().expect("slice with incorrect length");
+                                (*ptr.cast::<AtomicU16>()).store(u16::from_ne_bytes(raw), ordering);
+                                return;
+                            }
+                            4 => {
+                                let raw = raw.try_into().expect("slice with incorrect length");
+                                (*ptr.cast::<AtomicU32>()).store(u32::from_ne_bytes(raw), ordering);
+                                return;
+                            }
+                            8 => {
+                                let raw = raw.try_into().expect("slice with incorrect length");
+                                (*ptr.cast::<AtomicU64>()).store(u64::from_ne_bytes(raw), ordering);
+                                return;
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+                for (byte, raw) in bytes.iter().zip(raw) {
+                    byte.store(*raw, ordering);
+                }
+            }
+        }
+    }
+
+    /// `25.1.2.12 SetValueInBuffer ( arrayBuffer, byteIndex, type, value, isTypedArray, order [ , isLittleEndian ] )`
+    ///
+    /// Steps 5 to 9 of the operation, which write the value into this data block.
+    pub(crate) fn set_value(
+        &mut self,
+        byte_index: usize,
+        t: TypedArrayKind,
+        value: &JsValue,
+        order: SharedMemoryOrder,
+        is_little_endian: Option<bool>,
+        context: &mut Context<'_>,
+    ) -> JsResult<()> {
+        // 5. Let elementSize be the Element Size value specified in Table 73 for Element Type type.
+
+        // TODO: Agent Record [[LittleEndian]] filed
+        // 6. If isLittleEndian is not present, set isLittleEndian to the value of the [[LittleEndian]] field of the surrounding agent's Agent Record.
+        let is_little_endian = is_little_endian.unwrap_or(true);
+
+        // 7. Let rawBytes be NumericToRawBytes(type, value, isLittleEndian).
+        let raw_bytes = ArrayBuffer::numeric_to_raw_bytes(t, value, is_little_endian, context)?;
+
+        // 8. If IsSharedArrayBuffer(arrayBuffer) is true, then
+        //     a. Let execution be the [[CandidateExecution]] field of the surrounding agent's Agent Record.
+        //     b. Let eventsRecord be the Agent Events Record of execution.[[EventsRecords]] whose [[AgentSignifier]] is AgentSignifier().
+        //     c. If isTypedArray is true and IsNoTearConfiguration(type, order) is true, let noTear be true; otherwise let noTear be false.
+        //     d. Append WriteSharedMemory { [[Order]]: order, [[NoTear]]: noTear, [[Block]]: block, [[ByteIndex]]: byteIndex, [[ElementSize]]: elementSize, [[Payload]]: rawBytes } to eventsRecord.[[EventList]].
+        // 9. Else, store the individual bytes of rawBytes into block, starting at block[byteIndex].
+        self.write_raw(byte_index, &raw_bytes, order);
+
+        Ok(())
+    }
+
+    /// Atomically replaces the element of `size` bytes at `index` with the result of calling
+    /// `op` on its current value, returning the previous value.
+    ///
+    /// The element is interpreted as a little endian unsigned integer, zero-extended to 64 bits.
+    /// The value returned by `op` is truncated to the size of the element.
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_ptr_alignment,
+        trivial_numeric_casts
+    )]
+    pub(crate) fn atomic_rmw(&mut self, index: usize, size: usize, op: impl Fn(u64) -> u64) -> u64 {
+        let range = index..index + size;
+        let bytes = match self {
+            Self::Slice(buf) => {
+                let bytes = &mut buf[range];
+                let mut old = [0; 8];
+                old[..size].copy_from_slice(bytes);
+                let old = u64::from_le_bytes(old);
+                bytes.copy_from_slice(&op(old).to_le_bytes()[..size]);
+                return old;
+            }
+            Self::AtomicSlice(buf) => &buf[range],
+        };
+
+        macro_rules! fetch_update {
+            ($atomic:ty, $int:ty) => {{
+                // SAFETY: `bytes` is correctly aligned for an atomic of its size, and atomics
+                // share their in-memory representation with their underlying integers.
+                let atomic = unsafe { &*bytes.as_ptr().cast::<$atomic>() };
+                let (Ok(old) | Err(old)) =
+                    atomic.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |current| {
+                        let current = <$int>::from_le_bytes(current.to_ne_bytes());
+                        let new = op(current.into()) as $int;
+                        Some(<$int>::from_ne_bytes(new.to_le_bytes()))
+                    });
+                <$int>::from_le_bytes(old.to_ne_bytes()).into()
+            }};
+        }
+
+        assert!(
+            bytes.as_ptr() as usize % size == 0,
+            "atomic accesses must be aligned"
+        );
+        match size {
+            1 => fetch_update!(AtomicU8, u8),
+            2 => fetch_update!(AtomicU16, u16),
+            4 => fetch_update!(AtomicU32, u32),
+            8 => fetch_update!(AtomicU64, u64),
+            _ => unreachable!("invalid element size for an atomic access"),
+        }
+    }
+}
+
+/// `6.2.8.3 CopyDataBlockBytes ( toBlock, toIndex, fromBlock, fromIndex, count )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-copydatablockbytes
+pub(crate) fn copy_data_block_bytes(
+    to_block: &mut SliceRefMut<'_>,
+    to_index: usize,
+    from_block: SliceRef<'_>,
+    from_index: usize,
+    count: usize,
+) {
+    // 1. Assert: fromBlock and toBlock are distinct values.
+    // 2. Let fromSize be the number of bytes in fromBlock.
+    let from_size = from_block.len();
+
+    // 3. Assert: fromIndex + count ≤ fromSize.
+    assert!(from_index + count <= from_size);
+
+    // 4. Let toSize be the number of bytes in toBlock.
+    let to_size = to_block.len();
+
+    // 5. Assert: toIndex + count ≤ toSize.
+    assert!(to_index + count <= to_size);
+
+    // 6. Repeat, while count > 0,
+    //     a. If fromBlock is a Shared Data Block, then
+    //         i-vi. Read fromBlock[fromIndex] with an Unordered ReadSharedMemory event.
+    //         vii. If toBlock is a Shared Data Block, then
+    //             1-3. Write the byte with an Unordered WriteSharedMemory event.
+    //         viii. Else, set toBlock[toIndex] to bytes[0].
+    //     b. Else,
+    //         i. Assert: toBlock is not a Shared Data Block.
+    //         ii. Set toBlock[toIndex] to fromBlock[fromIndex].
+    //     c. Set toIndex to toIndex + 1.
+    //     d. Set fromIndex to fromIndex + 1.
+    //     e. Set count to count - 1.
+    let from_block = from_block.subslice(from_index..from_index + count);
+    match (to_block, from_block) {
+        (SliceRefMut::Slice(to), SliceRef::Slice(from)) => {
+            to[to_index..to_index + count].copy_from_slice(from);
+        }
+        (SliceRefMut::Slice(to), SliceRef::AtomicSlice(from)) => {
+            for (to, from) in to[to_index..to_index + count].iter_mut().zip(from) {
+                *to = from.load(Ordering::Relaxed);
+            }
+        }
+        (SliceRefMut::AtomicSlice(to), SliceRef::Slice(from)) => {
+            for (to, from) in to[to_index..to_index + count].iter().zip(from) {
+                to.store(*from, Ordering::Relaxed);
+            }
+        }
+        (SliceRefMut::AtomicSlice(to), SliceRef::AtomicSlice(from)) => {
+            for (to, from) in to[to_index..to_index + count].iter().zip(from) {
+                to.store(from.load(Ordering::Relaxed), Ordering::Relaxed);
+            }
+        }
+    }
+
+    // 7. Return unused.
+}
+
+/// A reference to either an `ArrayBuffer` or a `SharedArrayBuffer`.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum BufferRef<'a> {
+    Buffer(&'a ArrayBuffer),
+    SharedBuffer(&'a SharedArrayBuffer),
+}
+
+impl<'a> BufferRef<'a> {
+    /// Gets the data block of the buffer, or `None` if the buffer is detached.
+    pub(crate) fn data(&self) -> Option<SliceRef<'a>> {
+        match self {
+            Self::Buffer(buf) => buf.array_buffer_data.as_deref().map(SliceRef::Slice),
+            Self::SharedBuffer(buf) => Some(SliceRef::AtomicSlice(buf.data())),
+        }
+    }
+
+    /// Gets the `[[ArrayBufferByteLength]]` internal slot of the buffer.
+    pub(crate) fn array_buffer_byte_length(&self) -> u64 {
+        match self {
+            Self::Buffer(buf) => buf.array_buffer_byte_length(),
+            Self::SharedBuffer(buf) => buf.array_buffer_byte_length(),
+        }
+    }
+
+    /// `25.1.2.2 IsDetachedBuffer ( arrayBuffer )`
+    ///
+    /// Shared buffers cannot be detached.
+    pub(crate) const fn is_detached_buffer(&self) -> bool {
+        match self {
+            Self::Buffer(buf) => buf.is_detached_buffer(),
+            Self::SharedBuffer(_) => false,
+        }
+    }
+
+    /// `25.1.3.9 IsFixedLengthArrayBuffer ( arrayBuffer )`
+    pub(crate) fn is_fixed_length(&self) -> bool {
+        match self {
+            Self::Buffer(buf) => buf.is_fixed_length(),
+            Self::SharedBuffer(buf) => buf.is_fixed_length(),
+        }
+    }
+
+    /// Returns `true` if both buffers refer to the same data block.
+    pub(crate) fn same_data_block(&self, other: BufferRef<'_>) -> bool {
+        match (self, other) {
+            (Self::Buffer(a), BufferRef::Buffer(b)) => std::ptr::eq(*a, b),
+            (Self::SharedBuffer(a), BufferRef::SharedBuffer(b)) => a.ptr_eq(b),
+            _ => false,
+        }
+    }
+
+    /// `25.1.2.10 GetValueFromBuffer ( arrayBuffer, byteIndex, type, isTypedArray, order [ , isLittleEndian ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getvaluefrombuffer
+    pub(crate) fn get_value_from_buffer(
+        &self,
+        byte_index: u64,
+        t: TypedArrayKind,
+        is_typed_array: bool,
+        order: SharedMemoryOrder,
+        is_little_endian: Option<bool>,
+    ) -> JsValue {
+        // 1. Assert: IsDetachedBuffer(arrayBuffer) is false.
+        // 2. Assert: There are sufficient bytes in arrayBuffer starting at byteIndex to represent a value of type.
+        // 3. Let block be arrayBuffer.[[ArrayBufferData]].
+        let block = self.data().expect("ArrayBuffer cannot be detached here");
+
+        block.get_value(
+            byte_index as usize,
+            t,
+            is_typed_array,
+            order,
+            is_little_endian,
+        )
+    }
+
+    /// `25.1.2.4 CloneArrayBuffer ( srcBuffer, srcByteOffset, srcLength )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-clonearraybuffer
+    pub(crate) fn clone_array_buffer(
+        &self,
+        src_byte_offset: u64,
+        src_length: u64,
+        context: &mut Context<'_>,
+    ) -> JsResult<JsObject> {
+        // 1. Assert: IsDetachedBuffer(srcBuffer) is false.
+        // 2. Let targetBuffer be ? AllocateArrayBuffer(%ArrayBuffer%, srcLength).
+        let target_buffer = ArrayBuffer::allocate(
+            &context
+                .intrinsics()
+                .constructors()
+                .array_buffer()
+                .constructor()
+                .into(),
+            src_length,
+            None,
+            context,
+        )?;
+
+        // 3. Let srcBlock be srcBuffer.[[ArrayBufferData]].
+        let src_block = self.data().ok_or_else(|| {
+            JsNativeError::syntax().with_message("Cannot clone detached array buffer")
+        })?;
+
+        {
+            // 4. Let targetBlock be targetBuffer.[[ArrayBufferData]].
+            let mut target_buffer_mut = target_buffer.borrow_mut();
+            let target_block = target_buffer_mut
+                .as_array_buffer_mut()
+                .expect("This must be an ArrayBuffer");
+
+            // 5. Perform CopyDataBlockBytes(targetBlock, 0, srcBlock, srcByteOffset, srcLength).
+            copy_data_block_bytes(
+                &mut SliceRefMut::Slice(
+                    target_block
+                        .array_buffer_data
+                        .as_mut()
+                        .expect("ArrayBuffer cannot me detached here"),
+                ),
+                0,
+                src_block,
+                src_byte_offset as usize,
+                src_length as usize,
+            );
+        }
+
+        // 6. Return targetBuffer.
+        Ok(target_buffer)
+    }
+}
+
+/// A mutable reference to either an `ArrayBuffer` or a `SharedArrayBuffer`.
+#[derive(Debug)]
+pub(crate) enum BufferRefMut<'a> {
+    Buffer(&'a mut ArrayBuffer),
+    SharedBuffer(&'a mut SharedArrayBuffer),
+}
+
+impl BufferRefMut<'_> {
+    /// Gets the data block of the buffer, or `None` if the buffer is detached.
+    pub(crate) fn data_mut(&mut self) -> Option<SliceRefMut<'_>> {
+        match self {
+            Self::Buffer(buf) => buf.array_buffer_data.as_deref_mut().map(SliceRefMut::Slice),
+            Self::SharedBuffer(buf) => Some(SliceRefMut::AtomicSlice(buf.data())),
+        }
+    }
+
+    /// `25.1.2.10 GetValueFromBuffer ( arrayBuffer, byteIndex, type, isTypedArray, order [ , isLittleEndian ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getvaluefrombuffer
+    pub(crate) fn get_value_from_buffer(
+        &mut self,
+        byte_index: u64,
+        t: TypedArrayKind,
+        is_typed_array: bool,
+        order: SharedMemoryOrder,
+        is_little_endian: Option<bool>,
+    ) -> JsValue {
+        // 1. Assert: IsDetachedBuffer(arrayBuffer) is false.
+        // 2. Assert: There are sufficient bytes in arrayBuffer starting at byteIndex to represent a value of type.
+        // 3. Let block be arrayBuffer.[[ArrayBufferData]].
+        let block = self
+            .data_mut()
+            .expect("ArrayBuffer cannot be detached here");
+
+        block.as_slice_ref().get_value(
+            byte_index as usize,
+            t,
+            is_typed_array,
+            order,
+            is_little_endian,
+        )
+    }
+
+    /// `25.1.2.12 SetValueInBuffer ( arrayBuffer, byteIndex, type, value, isTypedArray, order [ , isLittleEndian ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-setvalueinbuffer
+    pub(crate) fn set_value_in_buffer(
+        &mut self,
+        byte_index: u64,
+        t: TypedArrayKind,
+        value: &JsValue,
+        order: SharedMemoryOrder,
+        is_little_endian: Option<bool>,
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. Assert: IsDetachedBuffer(arrayBuffer) is false.
+        // 2. Assert: There are sufficient bytes in arrayBuffer starting at byteIndex to represent a value of type.
+        // 3. Assert: Type(value) is BigInt if ! IsBigIntElementType(type) is true; otherwise, Type(value) is Number.
+        // 4. Let block be arrayBuffer.[[ArrayBufferData]].
+        let mut block = self
+            .data_mut()
+            .expect("ArrayBuffer cannot be detached here");
+
+        block.set_value(
+            byte_index as usize,
+            t,
+            value,
+            order,
+            is_little_endian,
+            context,
+        )?;
+
+        // 10. Return NormalCompletion(undefined).
+        Ok(JsValue::undefined())
+    }
+}
@@ -0,0 +1,336 @@
+//! The waiter lists used by `Atomics.wait`, `Atomics.waitAsync` and `Atomics.notify`.
+//!
+//! Every agent waiting on a location of a Shared Data Block is registered in a single global list,
+//! guarded by a single global critical section. Waiters are identified by the address of the
+//! location they wait on, which is unique while the `SharedArrayBuffer` of the location is alive,
+//! and are notified in the order they were added to the list.
+//!
+//! Asynchronous waiters are resolved by their own agent: notifying one adds it to the
+//! [`AsyncWaiters`] of its agent and wakes up the agent if it is waiting for its next job. The
+//! job queue of the agent then enqueues the jobs resolving their promises with
+//! [`Context::enqueue_notified_waiter_jobs`].
+//!
+//! [`Context::enqueue_notified_waiter_jobs`]: crate::Context::enqueue_notified_waiter_jobs
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-waiterlist-objects
+
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Condvar, Mutex, MutexGuard, PoisonError,
+    },
+    time::{Duration, Instant},
+};
+
+use crate::{builtins::array_buffer::SharedArrayBuffer, js_string, object::JsFunction, JsString};
+
+/// The global critical section, which guards the waiter lists of every Shared Data Block.
+static CRITICAL_SECTION: Mutex<FutexWaiters> = Mutex::new(FutexWaiters {
+    waiters: Vec::new(),
+});
+
+/// `EnterCriticalSection ( WL )`
+///
+/// A panic while holding the lock cannot leave the waiter list in an inconsistent state, so
+/// poisoning is ignored.
+fn enter_critical_section() -> MutexGuard<'static, FutexWaiters> {
+    CRITICAL_SECTION
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+}
+
+/// The result of waiting on a location of a Shared Data Block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum WaitResult {
+    /// The waiter was notified.
+    Ok,
+    /// The value at the location was not equal to the expected value.
+    NotEqual,
+    /// The waiter was not notified before its timeout.
+    TimedOut,
+}
+
+impl WaitResult {
+    /// Gets the string returned to ECMAScript code for this result.
+    pub(super) fn to_js_string(self) -> JsString {
+        match self {
+            Self::Ok => js_string!("ok"),
+            Self::NotEqual => js_string!("not-equal"),
+            Self::TimedOut => js_string!("timed-out"),
+        }
+    }
+}
+
+/// The asynchronous waiters of an agent that were notified, possibly by another agent.
+#[derive(Debug, Default)]
+struct NotifiedWaiters {
+    waiters: Mutex<Vec<Arc<Waiter>>>,
+    /// Used to wake up an agent waiting for its next job.
+    condvar: Condvar,
+}
+
+impl NotifiedWaiters {
+    fn lock(&self) -> MutexGuard<'_, Vec<Arc<Waiter>>> {
+        self.waiters.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// A Waiter Record.
+#[derive(Debug, Default)]
+struct Waiter {
+    /// Set by `Atomics.notify` when a synchronous waiter is removed from the list.
+    notified: AtomicBool,
+    /// Used to wake up a synchronous waiter.
+    condvar: Condvar,
+    /// The notified waiters of the agent of an asynchronous waiter, to which `Atomics.notify`
+    /// adds the waiter.
+    agent: Option<Arc<NotifiedWaiters>>,
+}
+
+/// An entry of the global waiter list.
+#[derive(Debug)]
+struct FutexWaiter {
+    /// Keeps the Shared Data Block alive, which guarantees that `address` is not reused while
+    /// the waiter is in the list.
+    _buffer: SharedArrayBuffer,
+    address: usize,
+    waiter: Arc<Waiter>,
+}
+
+#[derive(Debug)]
+struct FutexWaiters {
+    waiters: Vec<FutexWaiter>,
+}
+
+impl FutexWaiters {
+    /// `AddWaiter ( WL, waiterRecord )`
+    fn add_waiter(
+        &mut self,
+        buffer: &SharedArrayBuffer,
+        byte_index: usize,
+        agent: Option<Arc<NotifiedWaiters>>,
+    ) -> Arc<Waiter> {
+        let waiter = Arc::new(Waiter {
+            agent,
+            ..Waiter::default()
+        });
+        self.waiters.push(FutexWaiter {
+            _buffer: buffer.clone(),
+            address: address_of(buffer, byte_index),
+            waiter: waiter.clone(),
+        });
+        waiter
+    }
+
+    /// `RemoveWaiter ( WL, waiterRecord )`
+    ///
+    /// Returns `false` if the waiter was not in the list, i.e. if it was already notified.
+    fn remove_waiter(&mut self, waiter: &Arc<Waiter>) -> bool {
+        let len = self.waiters.len();
+        self.waiters.retain(|w| !Arc::ptr_eq(&w.waiter, waiter));
+        self.waiters.len() != len
+    }
+
+    /// `RemoveWaiters ( WL, c )` and `NotifyWaiter ( WL, waiterRecord )`
+    fn notify_waiters(&mut self, address: usize, count: u64) -> u64 {
+        let mut notified = 0;
+        self.waiters.retain(|w| {
+            if notified >= count || w.address != address {
+                return true;
+            }
+            notified += 1;
+            if let Some(agent) = &w.waiter.agent {
+                // `EnqueueResolveInAgentJob ( agentSignifier, promiseCapability, resolution )`
+                agent.lock().push(w.waiter.clone());
+                agent.condvar.notify_all();
+            } else {
+                w.waiter.notified.store(true, Ordering::Relaxed);
+                w.waiter.condvar.notify_one();
+            }
+            false
+        });
+        notified
+    }
+}
+
+/// Gets the address of the byte at `byte_index` of `buffer`.
+fn address_of(buffer: &SharedArrayBuffer, byte_index: usize) -> usize {
+    std::ptr::addr_of!(buffer.data()[byte_index]) as usize
+}
+
+/// Adds `timeout` to the current time, returning `None` if the timeout is infinite or if the
+/// resulting time cannot be represented.
+fn deadline(timeout: Option<Duration>) -> Option<Instant> {
+    timeout.and_then(|timeout| Instant::now().checked_add(timeout))
+}
+
+/// Suspends the current thread until the location at `byte_index` of `buffer` is notified, or
+/// until `timeout` elapses.
+///
+/// `check` is called inside the critical section before suspending the thread, and must return
+/// `true` if the value at the location is equal to the expected value.
+pub(super) fn wait(
+    buffer: &SharedArrayBuffer,
+    byte_index: usize,
+    check: impl FnOnce() -> bool,
+    timeout: Option<Duration>,
+) -> WaitResult {
+    let deadline = deadline(timeout);
+
+    let mut waiters = enter_critical_section();
+
+    if !check() {
+        return WaitResult::NotEqual;
+    }
+
+    let waiter = waiters.add_waiter(buffer, byte_index, None);
+
+    // `SuspendThisAgent ( WL, waiterRecord )`
+    loop {
+        if waiter.notified.load(Ordering::Relaxed) {
+            return WaitResult::Ok;
+        }
+
+        waiters = if let Some(deadline) = deadline {
+            let now = Instant::now();
+            if now >= deadline {
+                waiters.remove_waiter(&waiter);
+                return WaitResult::TimedOut;
+            }
+            waiter
+                .condvar
+                .wait_timeout(waiters, deadline - now)
+                .unwrap_or_else(PoisonError::into_inner)
+                .0
+        } else {
+            waiter
+                .condvar
+                .wait(waiters)
+                .unwrap_or_else(PoisonError::into_inner)
+        };
+    }
+}
+
+/// A waiter registered by `Atomics.waitAsync`.
+#[derive(Debug, Clone)]
+pub(super) struct AsyncWaiter(Arc<Waiter>);
+
+impl AsyncWaiter {
+    /// Removes the waiter from the waiter list once its timeout elapses, returning `false` if it
+    /// was notified before.
+    pub(super) fn time_out(&self) -> bool {
+        enter_critical_section().remove_waiter(&self.0)
+    }
+}
+
+/// The pending `Atomics.waitAsync` calls of an agent.
+#[derive(Debug, Default)]
+pub(crate) struct AsyncWaiters {
+    notified: Arc<NotifiedWaiters>,
+    /// The waiters of the agent, with the functions resolving the promises of their calls.
+    pending: Vec<(Arc<Waiter>, JsFunction)>,
+}
+
+impl AsyncWaiters {
+    /// Registers the function resolving the promise of the call that added `waiter`.
+    pub(super) fn insert(&mut self, waiter: AsyncWaiter, resolve: JsFunction) {
+        self.pending.push((waiter.0, resolve));
+    }
+
+    /// Unregisters `waiter`, returning the function resolving the promise of its call.
+    pub(super) fn remove(&mut self, waiter: &AsyncWaiter) -> Option<JsFunction> {
+        let index = self
+            .pending
+            .iter()
+            .position(|(w, _)| Arc::ptr_eq(w, &waiter.0))?;
+        Some(self.pending.remove(index).1)
+    }
+
+    /// Unregisters the waiters notified since the last call, returning the functions resolving
+    /// the promises of their calls in the order they were notified.
+    pub(super) fn take_notified(&mut self) -> Vec<JsFunction> {
+        let notified = std::mem::take(&mut *self.notified.lock());
+        notified
+            .into_iter()
+            .filter_map(|waiter| self.remove(&AsyncWaiter(waiter)))
+            .collect()
+    }
+
+    /// Blocks the current thread until a waiter of the agent is notified, or until `deadline`.
+    ///
+    /// Returns `true` if a waiter was notified, which includes the waiters notified before the
+    /// call that were not taken yet.
+    pub(crate) fn wait_for_notified(&self, deadline: Instant) -> bool {
+        let mut notified = self.notified.lock();
+        loop {
+            if !notified.is_empty() {
+                return true;
+            }
+            let now = Instant::now();
+            if now >= deadline {
+                return false;
+            }
+            notified = self
+                .notified
+                .condvar
+                .wait_timeout(notified, deadline - now)
+                .unwrap_or_else(PoisonError::into_inner)
+                .0;
+        }
+    }
+}
+
+impl Drop for AsyncWaiters {
+    fn drop(&mut self) {
+        if self.pending.is_empty() {
+            return;
+        }
+
+        // The promises of the waiters can't be resolved anymore, so they shouldn't be counted by
+        // `Atomics.notify` nor keep their buffers alive.
+        let mut waiters = enter_critical_section();
+        for (waiter, _) in &self.pending {
+            waiters.remove_waiter(waiter);
+        }
+    }
+}
+
+/// Registers an asynchronous waiter of the agent of `agent_waiters` on the location at
+/// `byte_index` of `buffer`.
+///
+/// Returns the result of the wait instead if it can be determined synchronously, i.e. if `check`
+/// returns `false` or if `timeout` is zero.
+pub(super) fn wait_async(
+    buffer: &SharedArrayBuffer,
+    byte_index: usize,
+    check: impl FnOnce() -> bool,
+    timeout: Option<Duration>,
+    agent_waiters: &AsyncWaiters,
+) -> Result<AsyncWaiter, WaitResult> {
+    let mut waiters = enter_critical_section();
+
+    if !check() {
+        return Err(WaitResult::NotEqual);
+    }
+
+    if timeout == Some(Duration::ZERO) {
+        return Err(WaitResult::TimedOut);
+    }
+
+    Ok(AsyncWaiter(waiters.add_waiter(
+        buffer,
+        byte_index,
+        Some(agent_waiters.notified.clone()),
+    )))
+}
+
+/// Notifies at most `count` waiters of the location at `byte_index` of `buffer`, returning the
+/// number of notified waiters.
+pub(super) fn notify(buffer: &SharedArrayBuffer, byte_index: usize, count: u64) -> u64 {
+    let mut waiters = enter_critical_section();
+
+    waiters.notify_waiters(address_of(buffer, byte_index), count)
+}
@@ -0,0 +1,849 @@
+//! Boa's implementation of ECMAScript's global `Atomics` object.
+//!
+//! The `Atomics` object contains synchronization methods to orchestrate multithreading
+//! on contexts that live in separate threads.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-atomics-object
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics
+
+mod futex;
+
+#[cfg(test)]
+mod tests;
+
+use std::time::Duration;
+
+use crate::{
+    builtins::{
+        array_buffer::{utils::SliceRef, ArrayBuffer, SharedMemoryOrder},
+        typed_array::{integer_indexed_object::ContentType, TypedArrayKind},
+        BuiltInObject,
+    },
+    context::intrinsics::Intrinsics,
+    error::JsNativeError,
+    job::NativeJob,
+    js_string,
+    object::{builtins::JsPromise, JsObject},
+    property::Attribute,
+    realm::Realm,
+    symbol::JsSymbol,
+    Context, JsArgs, JsResult, JsValue,
+};
+use boa_profiler::Profiler;
+
+use self::futex::{AsyncWaiter, WaitResult};
+
+pub(crate) use self::futex::AsyncWaiters;
+
+use super::{BuiltInBuilder, IntrinsicObject};
+
+/// JavaScript `Atomics` global object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) struct Atomics;
+
+impl IntrinsicObject for Atomics {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        let to_string_tag = JsSymbol::to_string_tag();
+        let attribute = Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE;
+
+        BuiltInBuilder::with_intrinsic::<Self>(realm)
+            .static_method(Self::add, "add", 3)
+            .static_method(Self::bit_and, "and", 3)
+            .static_method(Self::compare_exchange, "compareExchange", 4)
+            .static_method(Self::exchange, "exchange", 3)
+            .static_method(Self::is_lock_free, "isLockFree", 1)
+            .static_method(Self::load, "load", 2)
+            .static_method(Self::bit_or, "or", 3)
+            .static_method(Self::store, "store", 3)
+            .static_method(Self::sub, "sub", 3)
+            .static_method(Self::wait, "wait", 4)
+            .static_method(Self::wait_async, "waitAsync", 4)
+            .static_method(Self::notify, "notify", 3)
+            .static_method(Self::bit_xor, "xor", 3)
+            .static_property(to_string_tag, Self::NAME, attribute)
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        intrinsics.objects().atomics()
+    }
+}
+
+impl BuiltInObject for Atomics {
+    const NAME: &'static str = "Atomics";
+}
+
+/// A validated atomic access to an element of an integer typed array.
+struct AtomicAccess {
+    /// The typed array being accessed.
+    array: JsObject,
+    /// The element type of the typed array.
+    kind: TypedArrayKind,
+    /// The byte index of the accessed element in the buffer of the typed array.
+    byte_index: u64,
+}
+
+impl AtomicAccess {
+    /// `ValidateAtomicAccessOnIntegerTypedArray ( typedArray, requestIndex [ , waitable ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-validateatomicaccessonintegertypedarray
+    fn validate(
+        array: &JsValue,
+        request_index: &JsValue,
+        waitable: bool,
+        context: &mut Context<'_>,
+    ) -> JsResult<Self> {
+        // 1. If waitable is not present, set waitable to false.
+        // 2. Perform ? ValidateIntegerTypedArray(typedArray, waitable).
+        let (array, kind, length, byte_offset) = {
+            let array = array.as_object().ok_or_else(|| {
+                JsNativeError::typ().with_message("`Atomics` operation called with non-object")
+            })?;
+            let array_borrow = array.borrow();
+            let typed_array = array_borrow.as_typed_array().ok_or_else(|| {
+                JsNativeError::typ().with_message("`Atomics` operation called with non-typed array")
+            })?;
+
+            if typed_array.is_out_of_bounds() {
+                return Err(JsNativeError::typ()
+                    .with_message("`Atomics` operation called with out of bounds typed array")
+                    .into());
+            }
+
+            let kind = typed_array.typed_array_name();
+            let valid_type = if waitable {
+                matches!(kind, TypedArrayKind::Int32 | TypedArrayKind::BigInt64)
+            } else {
+                !matches!(
+                    kind,
+                    TypedArrayKind::Uint8Clamped
+                        | TypedArrayKind::Float32
+                        | TypedArrayKind::Float64
+                )
+            };
+            if !valid_type {
+                return Err(JsNativeError::typ()
+                    .with_message(if waitable {
+                        "`Atomics` operation called with a typed array that is not an `Int32Array` or a `BigInt64Array`"
+                    } else {
+                        "`Atomics` operation called with a typed array of an invalid element type"
+                    })
+                    .into());
+            }
+
+            (
+                array.clone(),
+                kind,
+                typed_array.array_length(),
+                typed_array.byte_offset(),
+            )
+        };
+
+        // 3. Let taRecord be MakeTypedArrayWithBufferWitnessRecord(typedArray, unordered).
+        // 4. NOTE: Bounds checking is not a synchronizing operation when typedArray's backing buffer is a growable SharedArrayBuffer.
+        // 5. Let length be TypedArrayLength(taRecord).
+        // 6. Let accessIndex be ? ToIndex(requestIndex).
+        let access_index = request_index.to_index(context)?;
+
+        // 7. Assert: accessIndex ≥ 0.
+        // 8. If accessIndex ≥ length, throw a RangeError exception.
+        if access_index >= length {
+            return Err(JsNativeError::range()
+                .with_message("`Atomics` operation index out of range")
+                .into());
+        }
+
+        // 9. Let typedArray be taRecord.[[Object]].
+        // 10. Let elementSize be TypedArrayElementSize(typedArray).
+        // 11. Let offset be typedArray.[[ByteOffset]].
+        // 12. Return (accessIndex × elementSize) + offset.
+        Ok(Self {
+            array,
+            kind,
+            byte_index: access_index * kind.element_size() + byte_offset,
+        })
+    }
+
+    /// `RevalidateAtomicAccess ( typedArray, byteIndexInBuffer )`
+    ///
+    /// Returns the buffer of the typed array.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-revalidateatomicaccess
+    fn revalidate(&self) -> JsResult<JsObject> {
+        let array = self.array.borrow();
+        let typed_array = array
+            .as_typed_array()
+            .expect("must be a typed array after validation");
+
+        // 1. Let taRecord be MakeTypedArrayWithBufferWitnessRecord(typedArray, unordered).
+        // 2. NOTE: Bounds checking is not a synchronizing operation when typedArray's backing buffer is a growable SharedArrayBuffer.
+        // 3. If IsTypedArrayOutOfBounds(taRecord) is true, throw a TypeError exception.
+        if typed_array.is_out_of_bounds() {
+            return Err(JsNativeError::typ()
+                .with_message("`Atomics` operation called with out of bounds typed array")
+                .into());
+        }
+
+        let buffer = typed_array
+            .viewed_array_buffer()
+            .expect("cannot be detached after the check");
+
+        // 4. Assert: byteIndexInBuffer ≥ typedArray.[[ByteOffset]].
+        // 5. If byteIndexInBuffer ≥ taRecord.[[CachedBufferByteLength]], throw a RangeError exception.
+        let buffer_byte_length = buffer
+            .borrow()
+            .as_buffer()
+            .expect("must be a buffer")
+            .array_buffer_byte_length();
+        if self.byte_index >= buffer_byte_length {
+            return Err(JsNativeError::range()
+                .with_message("`Atomics` operation index out of range")
+                .into());
+        }
+
+        // 6. Return unused.
+        Ok(buffer.clone())
+    }
+
+    /// Converts `value` to the numeric type of the typed array, as done by all the atomic
+    /// read-modify-write operations.
+    ///
+    /// Returns the raw bytes of the converted value in little endian order, zero-extended to
+    /// 64 bits.
+    fn to_raw_operand(&self, value: &JsValue, context: &mut Context<'_>) -> JsResult<u64> {
+        // 3. If typedArray.[[ContentType]] is BigInt, let v be ? ToBigInt(value).
+        // 4. Otherwise, let v be 𝔽(? ToIntegerOrInfinity(value)).
+        let v: JsValue = if self.kind.content_type() == ContentType::BigInt {
+            value.to_bigint(context)?.into()
+        } else {
+            to_integer_or_infinity_number(value, context)?.into()
+        };
+
+        // NumericToRawBytes(elementType, v, isLittleEndian)
+        let raw = ArrayBuffer::numeric_to_raw_bytes(self.kind, &v, true, context)?;
+        let mut bytes = [0; 8];
+        bytes[..raw.len()].copy_from_slice(&raw);
+        Ok(u64::from_le_bytes(bytes))
+    }
+
+    /// Converts the little endian raw bytes `raw` to a value of the element type of the typed
+    /// array.
+    fn raw_to_value(&self, raw: u64) -> JsValue {
+        let size = self.kind.element_size() as usize;
+        ArrayBuffer::raw_bytes_to_numeric(self.kind, &raw.to_le_bytes()[..size], true)
+    }
+
+    /// Atomically applies `op` to the accessed element, returning the previous value of the
+    /// element.
+    fn read_modify_write(&self, buffer: &JsObject, op: impl Fn(u64) -> u64) -> JsValue {
+        let mut buffer = buffer.borrow_mut();
+        let mut buffer = buffer
+            .as_buffer_mut()
+            .expect("viewed array buffer must be a buffer");
+        let mut data = buffer
+            .data_mut()
+            .expect("buffer cannot be detached after revalidation");
+
+        let old = data.atomic_rmw(
+            self.byte_index as usize,
+            self.kind.element_size() as usize,
+            op,
+        );
+
+        self.raw_to_value(old)
+    }
+}
+
+/// Returns `𝔽(? ToIntegerOrInfinity(value))`.
+fn to_integer_or_infinity_number(value: &JsValue, context: &mut Context<'_>) -> JsResult<f64> {
+    let number = value.to_number(context)?;
+    if number.is_nan() {
+        return Ok(0.0);
+    }
+    // Adding `+0` normalizes `-0` to `+0`.
+    Ok(number.trunc() + 0.0)
+}
+
+/// `AtomicReadModifyWrite ( typedArray, index, value, op )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-atomicreadmodifywrite
+fn atomic_read_modify_write(
+    args: &[JsValue],
+    op: impl Fn(u64, u64) -> u64,
+    context: &mut Context<'_>,
+) -> JsResult<JsValue> {
+    // 1. Let byteIndexInBuffer be ? ValidateAtomicAccessOnIntegerTypedArray(typedArray, index).
+    let access = AtomicAccess::validate(
+        args.get_or_undefined(0),
+        args.get_or_undefined(1),
+        false,
+        context,
+    )?;
+
+    // 2. If typedArray.[[ContentType]] is BigInt, let v be ? ToBigInt(value).
+    // 3. Otherwise, let v be 𝔽(? ToIntegerOrInfinity(value)).
+    let v = access.to_raw_operand(args.get_or_undefined(2), context)?;
+
+    // 4. Perform ? RevalidateAtomicAccess(typedArray, byteIndexInBuffer).
+    let buffer = access.revalidate()?;
+
+    // 5. Let buffer be typedArray.[[ViewedArrayBuffer]].
+    // 6. Let elementType be TypedArrayElementType(typedArray).
+    // 7. Return GetModifySetValueInBuffer(buffer, byteIndexInBuffer, elementType, v, op).
+    Ok(access.read_modify_write(&buffer, |old| op(old, v)))
+}
+
+impl Atomics {
+    /// `Atomics.add ( typedArray, index, value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomics.add
+    fn add(_: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Let add denote a semantic function of two List of byte values arguments that applies
+        //    the addition operation to the Number or BigInt values corresponding to the List of
+        //    byte values arguments and returns a List of byte values corresponding to the result
+        //    of that operation.
+        // 2. Return ? AtomicReadModifyWrite(typedArray, index, value, add).
+        atomic_read_modify_write(args, u64::wrapping_add, context)
+    }
+
+    /// `Atomics.and ( typedArray, index, value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomics.and
+    fn bit_and(_: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Let and be a new read-modify-write modification function with parameters
+        //    (xBytes, yBytes) that captures nothing and performs the following steps atomically
+        //    when called:
+        //     a. Return ByteListBitwiseOp(&, xBytes, yBytes).
+        // 2. Return ? AtomicReadModifyWrite(typedArray, index, value, and).
+        atomic_read_modify_write(args, |old, v| old & v, context)
+    }
+
+    /// `Atomics.compareExchange ( typedArray, index, expectedValue, replacementValue )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomics.compareexchange
+    fn compare_exchange(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. Let byteIndexInBuffer be ? ValidateAtomicAccessOnIntegerTypedArray(typedArray, index).
+        let access = AtomicAccess::validate(
+            args.get_or_undefined(0),
+            args.get_or_undefined(1),
+            false,
+            context,
+        )?;
+
+        // 2. Let buffer be typedArray.[[ViewedArrayBuffer]].
+        // 3. Let block be buffer.[[ArrayBufferData]].
+        // 4. If typedArray.[[ContentType]] is BigInt, then
+        //     a. Let expected be ? ToBigInt(expectedValue).
+        //     b. Let replacement be ? ToBigInt(replacementValue).
+        // 5. Else,
+        //     a. Let expected be 𝔽(? ToIntegerOrInfinity(expectedValue)).
+        //     b. Let replacement be 𝔽(? ToIntegerOrInfinity(replacementValue)).
+        // 8. Let expectedBytes be NumericToRawBytes(elementType, expected, isLittleEndian).
+        // 9. Let replacementBytes be NumericToRawBytes(elementType, replacement, isLittleEndian).
+        let expected = access.to_raw_operand(args.get_or_undefined(2), context)?;
+        let replacement = access.to_raw_operand(args.get_or_undefined(3), context)?;
+
+        // 6. Perform ? RevalidateAtomicAccess(typedArray, byteIndexInBuffer).
+        let buffer = access.revalidate()?;
+
+        // 7. Let elementType be TypedArrayElementType(typedArray).
+        // 10-13. Atomically compare the bytes of the element with expectedBytes, replacing them
+        //        with replacementBytes if they are equal, and return the previous value.
+        Ok(access.read_modify_write(
+            &buffer,
+            |old| {
+                if old == expected {
+                    replacement
+                } else {
+                    old
+                }
+            },
+        ))
+    }
+
+    /// `Atomics.exchange ( typedArray, index, value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomics.exchange
+    fn exchange(_: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Let second be a new read-modify-write modification function with parameters
+        //    (oldBytes, newBytes) that captures nothing and performs the following steps
+        //    atomically when called:
+        //     a. Return newBytes.
+        // 2. Return ? AtomicReadModifyWrite(typedArray, index, value, second).
+        atomic_read_modify_write(args, |_, v| v, context)
+    }
+
+    /// `Atomics.isLockFree ( size )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomics.islockfree
+    fn is_lock_free(_: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Let n be ? ToIntegerOrInfinity(size).
+        let n = args.get_or_undefined(0).to_integer_or_infinity(context)?;
+
+        // 2. Let AR be the Agent Record of the surrounding agent.
+        Ok(match n.as_integer() {
+            // 3. If n = 1, return AR.[[IsLockFree1]].
+            Some(1) => cfg!(target_has_atomic = "8"),
+            // 4. If n = 2, return AR.[[IsLockFree2]].
+            Some(2) => cfg!(target_has_atomic = "16"),
+            // 5. If n = 4, return true.
+            Some(4) => true,
+            // 6. If n = 8, return AR.[[IsLockFree8]].
+            Some(8) => cfg!(target_has_atomic = "64"),
+            // 7. Return false.
+            _ => false,
+        }
+        .into())
+    }
+
+    /// `Atomics.load ( typedArray, index )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomics.load
+    fn load(_: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Let byteIndexInBuffer be ? ValidateAtomicAccessOnIntegerTypedArray(typedArray, index).
+        let access = AtomicAccess::validate(
+            args.get_or_undefined(0),
+            args.get_or_undefined(1),
+            false,
+            context,
+        )?;
+
+        // 2. Perform ? RevalidateAtomicAccess(typedArray, byteIndexInBuffer).
+        let buffer = access.revalidate()?;
+
+        // 3. Let buffer be typedArray.[[ViewedArrayBuffer]].
+        // 4. Let elementType be TypedArrayElementType(typedArray).
+        // 5. Return GetValueFromBuffer(buffer, byteIndexInBuffer, elementType, true, seq-cst).
+        let buffer = buffer.borrow();
+        let buffer = buffer
+            .as_buffer()
+            .expect("viewed array buffer must be a buffer");
+        Ok(buffer.get_value_from_buffer(
+            access.byte_index,
+            access.kind,
+            true,
+            SharedMemoryOrder::SeqCst,
+            None,
+        ))
+    }
+
+    /// `Atomics.or ( typedArray, index, value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomics.or
+    fn bit_or(_: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Let or be a new read-modify-write modification function with parameters
+        //    (xBytes, yBytes) that captures nothing and performs the following steps atomically
+        //    when called:
+        //     a. Return ByteListBitwiseOp(|, xBytes, yBytes).
+        // 2. Return ? AtomicReadModifyWrite(typedArray, index, value, or).
+        atomic_read_modify_write(args, |old, v| old | v, context)
+    }
+
+    /// `Atomics.store ( typedArray, index, value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomics.store
+    fn store(_: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Let byteIndexInBuffer be ? ValidateAtomicAccessOnIntegerTypedArray(typedArray, index).
+        let access = AtomicAccess::validate(
+            args.get_or_undefined(0),
+            args.get_or_undefined(1),
+            false,
+            context,
+        )?;
+
+        // 2. If typedArray.[[ContentType]] is BigInt, let v be ? ToBigInt(value).
+        // 3. Otherwise, let v be 𝔽(? ToIntegerOrInfinity(value)).
+        let value = args.get_or_undefined(2);
+        let v: JsValue = if access.kind.content_type() == ContentType::BigInt {
+            value.to_bigint(context)?.into()
+        } else {
+            to_integer_or_infinity_number(value, context)?.into()
+        };
+
+        // 4. Perform ? RevalidateAtomicAccess(typedArray, byteIndexInBuffer).
+        let buffer = access.revalidate()?;
+
+        // 5. Let buffer be typedArray.[[ViewedArrayBuffer]].
+        // 6. Let elementType be TypedArrayElementType(typedArray).
+        // 7. Perform SetValueInBuffer(buffer, byteIndexInBuffer, elementType, v, true, seq-cst).
+        let mut buffer = buffer.borrow_mut();
+        let mut buffer = buffer
+            .as_buffer_mut()
+            .expect("viewed array buffer must be a buffer");
+        buffer.set_value_in_buffer(
+            access.byte_index,
+            access.kind,
+            &v,
+            SharedMemoryOrder::SeqCst,
+            None,
+            context,
+        )?;
+
+        // 8. Return v.
+        Ok(v)
+    }
+
+    /// `Atomics.sub ( typedArray, index, value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomics.sub
+    fn sub(_: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Let subtract denote a semantic function of two List of byte values arguments that
+        //    applies the subtraction operation to the Number or BigInt values corresponding to
+        //    the List of byte values arguments and returns a List of byte values corresponding to
+        //    the result of that operation.
+        // 2. Return ? AtomicReadModifyWrite(typedArray, index, value, subtract).
+        atomic_read_modify_write(args, u64::wrapping_sub, context)
+    }
+
+    /// `Atomics.wait ( typedArray, index, value, timeout )`
+    ///
+    /// Suspends the current thread if the host [can block][can_block], until the element is
+    /// notified by a call to `Atomics.notify` or until the timeout elapses.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomics.wait
+    /// [can_block]: crate::context::HostHooks::can_block
+    fn wait(_: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Return ? DoWait(sync, typedArray, index, value, timeout).
+        do_wait(WaitMode::Sync, args, context)
+    }
+
+    /// `Atomics.waitAsync ( typedArray, index, value, timeout )`
+    ///
+    /// The returned promise is resolved by a job enqueued by [`Context::run_jobs`] after the
+    /// element is notified, or by a job enqueued with [`JobQueue::enqueue_timeout_job`] once the
+    /// timeout elapses.
+    ///
+    /// [`JobQueue::enqueue_timeout_job`]: crate::job::JobQueue::enqueue_timeout_job
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomics.waitasync
+    fn wait_async(_: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Return ? DoWait(async, typedArray, index, value, timeout).
+        do_wait(WaitMode::Async, args, context)
+    }
+
+    /// `Atomics.notify ( typedArray, index, count )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomics.notify
+    fn notify(_: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Let byteIndexInBuffer be ? ValidateAtomicAccessOnIntegerTypedArray(typedArray, index, true).
+        let access = AtomicAccess::validate(
+            args.get_or_undefined(0),
+            args.get_or_undefined(1),
+            true,
+            context,
+        )?;
+
+        // 2. If count is undefined, then
+        let count = args.get_or_undefined(2);
+        let count = if count.is_undefined() {
+            // a. Let c be +∞.
+            u64::MAX
+        } else {
+            // 3. Else,
+            //     a. Let intCount be ? ToIntegerOrInfinity(count).
+            //     b. Let c be max(intCount, 0).
+            count
+                .to_integer_or_infinity(context)?
+                .clamp_finite(0, i64::MAX) as u64
+        };
+
+        // 4. Let buffer be typedArray.[[ViewedArrayBuffer]].
+        // 5. Let block be buffer.[[ArrayBufferData]].
+        let buffer = access.revalidate()?;
+        let buffer = buffer.borrow();
+
+        // 6. If IsSharedArrayBuffer(buffer) is false, return +0𝔽.
+        let Some(buffer) = buffer.as_shared_array_buffer() else {
+            return Ok(0.into());
+        };
+
+        // 7. Let WL be GetWaiterList(block, byteIndexInBuffer).
+        // 8. Perform EnterCriticalSection(WL).
+        // 9. Let S be RemoveWaiters(WL, c).
+        // 10. For each element W of S, do
+        //     a. Perform NotifyWaiter(WL, W).
+        // 11. Perform LeaveCriticalSection(WL).
+        // 12. Let n be the number of elements in S.
+        let n = futex::notify(buffer, access.byte_index as usize, count);
+
+        // 13. Return 𝔽(n).
+        Ok(n.into())
+    }
+
+    /// `Atomics.xor ( typedArray, index, value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomics.xor
+    fn bit_xor(_: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Let xor be a new read-modify-write modification function with parameters
+        //    (xBytes, yBytes) that captures nothing and performs the following steps atomically
+        //    when called:
+        //     a. Return ByteListBitwiseOp(^, xBytes, yBytes).
+        // 2. Return ? AtomicReadModifyWrite(typedArray, index, value, xor).
+        atomic_read_modify_write(args, |old, v| old ^ v, context)
+    }
+
+    /// Enqueues the jobs resolving the promises of the `Atomics.waitAsync` calls of the context
+    /// whose waiters were notified since the last call.
+    pub(crate) fn enqueue_notified_waiter_jobs(context: &mut Context<'_>) {
+        for resolve in context.async_waiters.take_notified() {
+            // `EnqueueResolveInAgentJob ( agentSignifier, promiseCapability, resolution )`
+            // 1. Let resolveJob be a new Job Abstract Closure with no parameters that captures
+            //    agentSignifier, promiseCapability, and resolution and performs the following
+            //    steps when called:
+            //     a. Assert: AgentSignifier() is agentSignifier.
+            //     b. Perform ! Call(promiseCapability.[[Resolve]], undefined, « resolution »).
+            //     c. Return unused.
+            // 2. Let realmInTargetAgent be ! GetFunctionRealm(promiseCapability.[[Resolve]]).
+            // 3. Assert: agentSignifier is realmInTargetAgent.[[AgentSignifier]].
+            // 4. Perform HostEnqueueGenericJob(resolveJob, realmInTargetAgent).
+            let job = NativeJob::new(move |context| {
+                resolve.call(
+                    &JsValue::undefined(),
+                    &[WaitResult::Ok.to_js_string().into()],
+                    context,
+                )
+            });
+            context.job_queue().enqueue_promise_job(job, context);
+        }
+    }
+}
+
+/// The mode of a `DoWait` operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum WaitMode {
+    Sync,
+    Async,
+}
+
+/// `DoWait ( mode, typedArray, index, value, timeout )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-dowait
+fn do_wait(mode: WaitMode, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+    let shared_buffer = |array: &JsValue| {
+        let array = array.as_object()?.borrow();
+        let buffer = array.as_typed_array()?.viewed_array_buffer()?.borrow();
+        buffer.as_shared_array_buffer().cloned()
+    };
+
+    // 1. Let taRecord be ? ValidateIntegerTypedArray(typedArray, true).
+    // 2. Let buffer be taRecord.[[Object]].[[ViewedArrayBuffer]].
+    // 3. If IsSharedArrayBuffer(buffer) is false, throw a TypeError exception.
+    // 4. Let i be ? ValidateAtomicAccess(taRecord, index).
+    // NOTE: Non-typed arrays also throw a TypeError on validation, so checking for a shared
+    //       buffer first is indistinguishable from the order of the spec.
+    let array = args.get_or_undefined(0);
+    let buffer = shared_buffer(array).ok_or_else(|| {
+        JsNativeError::typ()
+            .with_message("`Atomics` wait operation called with a non-shared typed array")
+    })?;
+    let access = AtomicAccess::validate(array, args.get_or_undefined(1), true, context)?;
+
+    // 5. Let arrayTypeName be typedArray.[[TypedArrayName]].
+    // 6. If arrayTypeName is "BigInt64Array", let v be ? ToBigInt64(value).
+    // 7. Else, let v be ? ToInt32(value).
+    let v =
+        ArrayBuffer::numeric_to_raw_bytes(access.kind, args.get_or_undefined(2), true, context)?;
+    let v = ArrayBuffer::raw_bytes_to_numeric(access.kind, &v, true);
+
+    // 8. Let q be ? ToNumber(timeout).
+    let q = args.get_or_undefined(3).to_number(context)?;
+
+    // 9. If q is either NaN or +∞𝔽, let t be +∞; else if q is -∞𝔽, let t be 0; else let t be max(ℝ(q), 0).
+    let t = if q.is_nan() || q == f64::INFINITY {
+        None
+    } else {
+        Some(Duration::try_from_secs_f64(q.max(0.0) / 1000.0).unwrap_or(Duration::MAX))
+    };
+
+    // 10. If mode is sync and AgentCanSuspend() is false, throw a TypeError exception.
+    if mode == WaitMode::Sync && !context.host_hooks().can_block() {
+        return Err(JsNativeError::typ()
+            .with_message("the host does not allow `Atomics.wait` to block")
+            .into());
+    }
+
+    // 11. Let block be buffer.[[ArrayBufferData]].
+    // 12. Let offset be typedArray.[[ByteOffset]].
+    // 13. Let byteIndexInBuffer be (i × elementSize) + offset.
+    let byte_index = access.byte_index as usize;
+
+    // 14. Let WL be GetWaiterList(block, byteIndexInBuffer).
+    // 18. Let elementType be TypedArrayElementType(typedArray).
+    // 19. Let w be GetValueFromBuffer(buffer, byteIndexInBuffer, elementType, true, seq-cst).
+    // 20. If v ≠ w, then
+    //     a. Perform LeaveCriticalSection(WL).
+    let check = || {
+        let w = SliceRef::AtomicSlice(buffer.data()).get_value(
+            byte_index,
+            access.kind,
+            true,
+            SharedMemoryOrder::SeqCst,
+            None,
+        );
+        v.strict_equals(&w)
+    };
+
+    match mode {
+        // 15. If mode is sync, then
+        //     a. Let promiseCapability be blocking.
+        //     b. Let resultObject be undefined.
+        // 17. Perform EnterCriticalSection(WL).
+        // 21-27. Add the waiter and suspend the agent.
+        // 28. If mode is sync, return waiterRecord.[[Result]].
+        WaitMode::Sync => Ok(futex::wait(&buffer, byte_index, check, t)
+            .to_js_string()
+            .into()),
+        // 16. Else,
+        //     a. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+        //     b. Let resultObject be OrdinaryObjectCreate(%Object.prototype%).
+        WaitMode::Async => {
+            let result = JsObject::with_object_proto(context.intrinsics());
+            match futex::wait_async(&buffer, byte_index, check, t, &context.async_waiters) {
+                // 20. If v ≠ w, then
+                //     b. If mode is sync, return "not-equal".
+                //     c. Perform ! CreateDataPropertyOrThrow(resultObject, "async", false).
+                //     d. Perform ! CreateDataPropertyOrThrow(resultObject, "value", "not-equal").
+                //     e. Return resultObject.
+                // 21. If t is 0 and mode is async, then
+                //     b. Perform ! CreateDataPropertyOrThrow(resultObject, "async", false).
+                //     c. Perform ! CreateDataPropertyOrThrow(resultObject, "value", "timed-out").
+                //     d. Return resultObject.
+                Err(value) => {
+                    result
+                        .create_data_property_or_throw(js_string!("async"), false, context)
+                        .expect("cannot fail for a new object");
+                    result
+                        .create_data_property_or_throw(
+                            js_string!("value"),
+                            value.to_js_string(),
+                            context,
+                        )
+                        .expect("cannot fail for a new object");
+                }
+                // 27. Else if t is finite, then
+                //     a. Perform EnqueueAtomicsWaitAsyncTimeoutJob(WL, waiterRecord).
+                // 30. Perform ! CreateDataPropertyOrThrow(resultObject, "async", true).
+                // 31. Perform ! CreateDataPropertyOrThrow(resultObject, "value", promiseCapability.[[Promise]]).
+                Ok(waiter) => {
+                    let (promise, resolvers) = JsPromise::new_pending(context);
+                    context
+                        .async_waiters
+                        .insert(waiter.clone(), resolvers.resolve);
+                    if let Some(timeout) = t {
+                        context.job_queue().enqueue_timeout_job(
+                            wait_async_timeout_job(waiter),
+                            timeout,
+                            context,
+                        );
+                    }
+                    result
+                        .create_data_property_or_throw(js_string!("async"), true, context)
+                        .expect("cannot fail for a new object");
+                    result
+                        .create_data_property_or_throw(js_string!("value"), promise, context)
+                        .expect("cannot fail for a new object");
+                }
+            }
+
+            // 32. Return resultObject.
+            Ok(result.into())
+        }
+    }
+}
+
+/// `EnqueueAtomicsWaitAsyncTimeoutJob ( WL, waiterRecord )`
+///
+/// Creates the job that resolves the promise of an `Atomics.waitAsync` call with `"timed-out"`,
+/// unless `waiter` was notified before.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-enqueueatomicswaitasynctimeoutjob
+fn wait_async_timeout_job(waiter: AsyncWaiter) -> NativeJob {
+    // 1. Let timeoutJob be a new Job Abstract Closure with no parameters that captures WL and
+    //    waiterRecord and performs the following steps when called:
+    NativeJob::new(move |context| {
+        // a. Perform EnterCriticalSection(WL).
+        // b. If WL.[[Waiters]] contains waiterRecord, then
+        //     i. Let timeOfJobExecution be the time value (UTC) of the current time.
+        //     ii. Assert: ℝ(timeOfJobExecution) ≥ waiterRecord.[[TimeoutTime]].
+        //     iii. Set waiterRecord.[[Result]] to "timed-out".
+        //     iv. Perform RemoveWaiter(WL, waiterRecord).
+        //     v. Perform NotifyWaiter(WL, waiterRecord).
+        // c. Perform LeaveCriticalSection(WL).
+        if !waiter.time_out() {
+            return Ok(JsValue::undefined());
+        }
+        let Some(resolve) = context.async_waiters.remove(&waiter) else {
+            return Ok(JsValue::undefined());
+        };
+        resolve.call(
+            &JsValue::undefined(),
+            &[WaitResult::TimedOut.to_js_string().into()],
+            context,
+        )
+        // d. Return unused.
+    })
+}
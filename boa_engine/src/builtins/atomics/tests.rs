@@ -0,0 +1,320 @@
+use std::{sync::mpsc, thread, time::Duration};
+
+use crate::{
+    context::{ContextBuilder, HostHooks},
+    js_string,
+    object::builtins::JsSharedArrayBuffer,
+    property::Attribute,
+    run_test_actions, run_test_actions_with, Context, JsNativeErrorKind, Source, TestAction,
+};
+
+#[test]
+fn read_modify_write() {
+    run_test_actions([
+        TestAction::run("let ta = new Int8Array(new SharedArrayBuffer(8))"),
+        TestAction::assert_eq("Atomics.store(ta, 0, 120)", 120),
+        TestAction::assert_eq("Atomics.add(ta, 0, 10)", 120),
+        TestAction::assert_eq("Atomics.load(ta, 0)", -126),
+        TestAction::assert_eq("Atomics.sub(ta, 0, 2)", -126),
+        TestAction::assert_eq("Atomics.load(ta, 0)", -128),
+        TestAction::assert_eq("Atomics.exchange(ta, 1, -1)", 0),
+        TestAction::assert_eq("Atomics.and(ta, 1, 0x0f)", -1),
+        TestAction::assert_eq("Atomics.or(ta, 1, 0x30)", 0x0f),
+        TestAction::assert_eq("Atomics.xor(ta, 1, 0x3f)", 0x3f),
+        TestAction::assert_eq("Atomics.load(ta, 1)", 0),
+        TestAction::assert_eq("Atomics.store(ta, 2, -0)", 0),
+        TestAction::assert("Object.is(Atomics.store(ta, 2, -0), 0)"),
+        TestAction::assert_eq("Atomics.store(ta, 2, 3.9)", 3),
+    ]);
+}
+
+#[test]
+fn compare_exchange() {
+    run_test_actions([
+        TestAction::run("let ta = new Uint16Array(new SharedArrayBuffer(8), 2)"),
+        TestAction::assert_eq("Atomics.compareExchange(ta, 1, 1, 5)", 0),
+        TestAction::assert_eq("Atomics.load(ta, 1)", 0),
+        TestAction::assert_eq("Atomics.compareExchange(ta, 1, 0, 65537)", 0),
+        TestAction::assert_eq("Atomics.load(ta, 1)", 1),
+        TestAction::assert_eq("Atomics.compareExchange(ta, 1, 65537, 7)", 1),
+        TestAction::assert_eq("Atomics.load(ta, 1)", 7),
+        TestAction::run("let big = new BigInt64Array(new SharedArrayBuffer(16))"),
+        TestAction::assert("Atomics.store(big, 1, -5n) === -5n"),
+        TestAction::assert("Atomics.compareExchange(big, 1, -5n, 2n ** 63n) === -5n"),
+        TestAction::assert("Atomics.load(big, 1) === -(2n ** 63n)"),
+        TestAction::assert("Atomics.add(big, 1, -1n) === -(2n ** 63n)"),
+        TestAction::assert("Atomics.load(big, 1) === 2n ** 63n - 1n"),
+    ]);
+}
+
+#[test]
+fn non_shared_buffers() {
+    run_test_actions([
+        TestAction::run("let ta = new Uint32Array(4)"),
+        TestAction::assert_eq("Atomics.add(ta, 3, 2 ** 32 + 1)", 0),
+        TestAction::assert_eq("ta[3]", 1),
+        TestAction::assert_eq("Atomics.notify(new Int32Array(4), 0)", 0),
+        TestAction::assert_native_error(
+            "Atomics.wait(new Int32Array(4), 0, 0, 0)",
+            JsNativeErrorKind::Type,
+            "`Atomics` wait operation called with a non-shared typed array",
+        ),
+    ]);
+}
+
+#[test]
+fn validation() {
+    run_test_actions([
+        TestAction::assert_native_error(
+            "Atomics.load(new Float64Array(new SharedArrayBuffer(8)), 0)",
+            JsNativeErrorKind::Type,
+            "`Atomics` operation called with a typed array of an invalid element type",
+        ),
+        TestAction::assert_native_error(
+            "Atomics.load(new Uint8ClampedArray(4), 0)",
+            JsNativeErrorKind::Type,
+            "`Atomics` operation called with a typed array of an invalid element type",
+        ),
+        TestAction::assert_native_error(
+            "Atomics.notify(new Uint32Array(new SharedArrayBuffer(8)), 0)",
+            JsNativeErrorKind::Type,
+            "`Atomics` operation called with a typed array that is not an `Int32Array` or a `BigInt64Array`",
+        ),
+        TestAction::assert_native_error(
+            "Atomics.store(new Int32Array(new SharedArrayBuffer(8)), 2, 0)",
+            JsNativeErrorKind::Range,
+            "`Atomics` operation index out of range",
+        ),
+        TestAction::assert_native_error(
+            "Atomics.load([1, 2], 0)",
+            JsNativeErrorKind::Type,
+            "`Atomics` operation called with non-typed array",
+        ),
+        TestAction::assert("Atomics.isLockFree(4)"),
+        TestAction::assert("!Atomics.isLockFree(3)"),
+        TestAction::assert_eq(
+            "Object.prototype.toString.call(Atomics)",
+            js_string!("[object Atomics]"),
+        ),
+    ]);
+}
+
+#[test]
+fn wait_without_notification() {
+    run_test_actions([
+        TestAction::run("let ta = new Int32Array(new SharedArrayBuffer(16))"),
+        TestAction::assert_eq("Atomics.wait(ta, 0, 1)", js_string!("not-equal")),
+        TestAction::assert_eq("Atomics.wait(ta, 0, 0, 0)", js_string!("timed-out")),
+        TestAction::assert_eq("Atomics.wait(ta, 0, 0, 10)", js_string!("timed-out")),
+        TestAction::assert_eq("Atomics.notify(ta, 0)", 0),
+    ]);
+}
+
+#[test]
+fn wait_async() {
+    run_test_actions([
+        TestAction::run("let ta = new Int32Array(new SharedArrayBuffer(16))"),
+        TestAction::run("let result = Atomics.waitAsync(ta, 0, 1)"),
+        TestAction::assert("!result.async"),
+        TestAction::assert_eq("result.value", js_string!("not-equal")),
+        TestAction::run("result = Atomics.waitAsync(ta, 0, 0, 0)"),
+        TestAction::assert("!result.async"),
+        TestAction::assert_eq("result.value", js_string!("timed-out")),
+        TestAction::run(
+            r"
+                let settled = [];
+                let first = Atomics.waitAsync(ta, 1, 0);
+                let second = Atomics.waitAsync(ta, 1, 0);
+                let timeout = Atomics.waitAsync(ta, 2, 0, 5);
+                let forever = Atomics.waitAsync(ta, 3, 0);
+                first.value.then((value) => settled.push(`first: ${value}`));
+                second.value.then((value) => settled.push(`second: ${value}`));
+                timeout.value.then((value) => settled.push(`timeout: ${value}`));
+                forever.value.then((value) => settled.push(`forever: ${value}`));
+            ",
+        ),
+        TestAction::assert("first.async && first.value instanceof Promise"),
+        TestAction::assert_eq("Atomics.notify(ta, 1, 1)", 1),
+        TestAction::assert_eq("Atomics.notify(ta, 1)", 1),
+        TestAction::assert_eq("Atomics.notify(ta, 1)", 0),
+        // Returns even though `forever` is still waiting.
+        TestAction::inspect_context(|context| {
+            context.run_jobs();
+        }),
+        TestAction::assert_eq(
+            "settled.join()",
+            js_string!("first: ok,second: ok,timeout: timed-out"),
+        ),
+        TestAction::assert_eq("Atomics.notify(ta, 3)", 1),
+        TestAction::inspect_context(|context| {
+            context.run_jobs();
+        }),
+        TestAction::assert_eq(
+            "settled.join()",
+            js_string!("first: ok,second: ok,timeout: timed-out,forever: ok"),
+        ),
+    ]);
+}
+
+struct NonBlockingHooks;
+
+impl HostHooks for NonBlockingHooks {
+    fn can_block(&self) -> bool {
+        false
+    }
+}
+
+#[test]
+fn host_cannot_block() {
+    let hooks: &dyn HostHooks = &NonBlockingHooks;
+    let context = &mut ContextBuilder::default().host_hooks(hooks).build().unwrap();
+
+    run_test_actions_with(
+        [
+            TestAction::run("let ta = new Int32Array(new SharedArrayBuffer(16))"),
+            TestAction::assert_native_error(
+                "Atomics.wait(ta, 0, 0)",
+                JsNativeErrorKind::Type,
+                "the host does not allow `Atomics.wait` to block",
+            ),
+            TestAction::assert("Atomics.waitAsync(ta, 0, 0, 0).value === 'timed-out'"),
+        ],
+        context,
+    );
+}
+
+fn context_with_buffer(
+    buffer: crate::builtins::array_buffer::SharedArrayBuffer,
+) -> Context<'static> {
+    let mut context = Context::default();
+    let buffer = JsSharedArrayBuffer::from_buffer(buffer, &mut context);
+    context
+        .register_global_property("buffer", buffer, Attribute::all())
+        .unwrap();
+    context
+}
+
+#[test]
+fn shared_between_threads() {
+    let main = &mut Context::default();
+    let buffer = JsSharedArrayBuffer::new(16, main).unwrap();
+    main.register_global_property("buffer", buffer.clone(), Attribute::all())
+        .unwrap();
+
+    let (sender, receiver) = mpsc::channel();
+    let shared = buffer.inner();
+    let worker = thread::spawn(move || {
+        let context = &mut context_with_buffer(shared);
+        let result = context
+            .eval(Source::from_bytes(
+                r"
+                    const ta = new Int32Array(buffer);
+                    const result = Atomics.wait(ta, 0, 0);
+                    Atomics.store(ta, 1, 1);
+                    result
+                ",
+            ))
+            .unwrap();
+        sender
+            .send(result.to_string(context).unwrap().to_std_string_escaped())
+            .unwrap();
+    });
+
+    run_test_actions_with(
+        [
+            TestAction::run("const ta = new Int32Array(buffer)"),
+            // Keep notifying until the worker is waiting on the location.
+            TestAction::run("while (Atomics.notify(ta, 0) === 0) { Atomics.wait(ta, 2, 0, 1); }"),
+        ],
+        main,
+    );
+
+    worker.join().unwrap();
+    assert_eq!(receiver.recv().unwrap(), "ok");
+    assert_eq!(buffer.inner().to_vec()[4], 1);
+}
+
+#[test]
+fn wait_async_notified_from_another_thread() {
+    let main = &mut Context::default();
+    let buffer = JsSharedArrayBuffer::new(16, main).unwrap();
+    main.register_global_property("buffer", buffer.clone(), Attribute::all())
+        .unwrap();
+
+    run_test_actions_with(
+        [TestAction::run(
+            r"
+                const ta = new Int32Array(buffer);
+                let settled;
+                Atomics.waitAsync(ta, 0, 0).value.then((value) => settled = value);
+            ",
+        )],
+        main,
+    );
+
+    let shared = buffer.inner();
+    let notified = thread::spawn(move || {
+        let context = &mut context_with_buffer(shared);
+        context
+            .eval(Source::from_bytes(
+                "Atomics.notify(new Int32Array(buffer), 0)",
+            ))
+            .unwrap()
+            .as_number()
+    })
+    .join()
+    .unwrap();
+    assert_eq!(notified, Some(1.0));
+
+    run_test_actions_with(
+        [
+            TestAction::inspect_context(|context| {
+                context.run_jobs();
+            }),
+            TestAction::assert_eq("settled", js_string!("ok")),
+        ],
+        main,
+    );
+}
+
+#[test]
+fn wait_async_wakes_up_job_queue() {
+    let main = &mut Context::default();
+    let buffer = JsSharedArrayBuffer::new(16, main).unwrap();
+    main.register_global_property("buffer", buffer.clone(), Attribute::all())
+        .unwrap();
+
+    run_test_actions_with(
+        [TestAction::run(
+            r"
+                const ta = new Int32Array(buffer);
+                let settled;
+                Atomics.waitAsync(ta, 0, 0, 1000).value.then((value) => settled = value);
+            ",
+        )],
+        main,
+    );
+
+    // Notify the waiter while the job queue waits for the timeout of the call.
+    let shared = buffer.inner();
+    let notifier = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(50));
+        let context = &mut context_with_buffer(shared);
+        context
+            .eval(Source::from_bytes(
+                "Atomics.notify(new Int32Array(buffer), 0)",
+            ))
+            .unwrap();
+    });
+
+    run_test_actions_with(
+        [
+            TestAction::inspect_context(|context| {
+                context.run_jobs();
+            }),
+            TestAction::assert_eq("settled", js_string!("ok")),
+        ],
+        main,
+    );
+    notifier.join().unwrap();
+}
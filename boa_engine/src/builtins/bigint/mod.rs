@@ -41,6 +41,7 @@ impl IntrinsicObject for BigInt {
 
         BuiltInBuilder::from_standard_constructor::<Self>(realm)
             .method(Self::to_string, "toString", 0)
+            .method(Self::to_locale_string, "toLocaleString", 0)
             .method(Self::value_of, "valueOf", 0)
             .static_method(Self::as_int_n, "asIntN", 2)
             .static_method(Self::as_uint_n, "asUintN", 2)
@@ -207,6 +208,56 @@ impl BigInt {
         Ok(JsValue::new(x.to_string_radix(radix_mv as u32)))
     }
 
+    /// `BigInt.prototype.toLocaleString( [locales [, options]] )`
+    ///
+    /// The `toLocaleString()` method returns a string with a language-sensitive representation of this `BigInt`.
+    ///
+    /// Without the `intl` feature, this returns the same string as `toString()`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [ECMA-402 reference][spec-402]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-bigint.prototype.tolocalestring
+    /// [spec-402]: https://tc39.es/ecma402/#sup-bigint.prototype.tolocalestring
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/BigInt/toLocaleString
+    #[allow(clippy::wrong_self_convention)]
+    pub(crate) fn to_locale_string(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. Let x be ? thisBigIntValue(this value).
+        let x = Self::this_bigint_value(this)?;
+
+        #[cfg(feature = "intl")]
+        {
+            use crate::builtins::intl::NumberFormat;
+
+            // 2. Let numberFormat be ? Construct(%NumberFormat%, « locales, options »).
+            let number_format =
+                NumberFormat::new(args.get_or_undefined(0), args.get_or_undefined(1), context)?;
+
+            // 3. Return ? FormatNumeric(numberFormat, ℝ(x)).
+            let x = x
+                .to_string()
+                .parse()
+                .expect("the string representation of a BigInt must be a valid decimal");
+            Ok(number_format
+                .format_value(
+                    &crate::builtins::intl::number_format::IntlMathematicalValue::Decimal(x),
+                )
+                .into())
+        }
+
+        #[cfg(not(feature = "intl"))]
+        {
+            let _ = (args, context);
+            Ok(x.to_string().into())
+        }
+    }
+
     /// `BigInt.prototype.valueOf()`
     ///
     /// The `valueOf()` method returns the wrapped primitive value of a Number object.
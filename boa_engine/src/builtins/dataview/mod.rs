@@ -27,7 +27,9 @@ use super::{BuiltInBuilder, BuiltInConstructor, IntrinsicObject};
 #[derive(Debug, Clone, Trace, Finalize)]
 pub struct DataView {
     pub(crate) viewed_array_buffer: JsObject,
-    pub(crate) byte_length: u64,
+    /// The byte length of the view, or `None` if the view tracks the length of a resizable
+    /// buffer.
+    pub(crate) byte_length: Option<u64>,
     pub(crate) byte_offset: u64,
 }
 
@@ -122,94 +124,186 @@ impl BuiltInConstructor for DataView {
     ) -> JsResult<JsValue> {
         let byte_length = args.get_or_undefined(2);
 
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("new target is undefined")
+                .into());
+        }
+
+        // 2. Perform ? RequireInternalSlot(buffer, [[ArrayBufferData]]).
         let buffer_obj = args
             .get_or_undefined(0)
             .as_object()
+            .filter(|obj| obj.borrow().as_buffer().is_some())
             .ok_or_else(|| JsNativeError::typ().with_message("buffer must be an ArrayBuffer"))?;
 
-        // 1. If NewTarget is undefined, throw a TypeError exception.
-        let (offset, view_byte_length) = {
-            if new_target.is_undefined() {
-                return Err(JsNativeError::typ()
-                    .with_message("new target is undefined")
-                    .into());
-            }
-            // 2. Perform ? RequireInternalSlot(buffer, [[ArrayBufferData]]).
+        // 3. Let offset be ? ToIndex(byteOffset).
+        let offset = args.get_or_undefined(1).to_index(context)?;
+
+        let view_byte_length = {
             let buffer_borrow = buffer_obj.borrow();
-            let buffer = buffer_borrow.as_array_buffer().ok_or_else(|| {
-                JsNativeError::typ().with_message("buffer must be an ArrayBuffer")
-            })?;
+            let buffer = buffer_borrow
+                .as_buffer()
+                .expect("already checked that `buffer` is a buffer");
 
-            // 3. Let offset be ? ToIndex(byteOffset).
-            let offset = args.get_or_undefined(1).to_index(context)?;
             // 4. If IsDetachedBuffer(buffer) is true, throw a TypeError exception.
             if buffer.is_detached_buffer() {
                 return Err(JsNativeError::typ()
                     .with_message("ArrayBuffer is detached")
                     .into());
             }
-            // 5. Let bufferByteLength be buffer.[[ArrayBufferByteLength]].
+
+            // 5. Let bufferByteLength be ArrayBufferByteLength(buffer, seq-cst).
             let buffer_byte_length = buffer.array_buffer_byte_length();
+
             // 6. If offset > bufferByteLength, throw a RangeError exception.
             if offset > buffer_byte_length {
                 return Err(JsNativeError::range()
                     .with_message("Start offset is outside the bounds of the buffer")
                     .into());
             }
-            // 7. If byteLength is undefined, then
-            let view_byte_length = if byte_length.is_undefined() {
-                // a. Let viewByteLength be bufferByteLength - offset.
-                buffer_byte_length - offset
+
+            // 7. Let bufferIsFixedLength be IsFixedLengthArrayBuffer(buffer).
+            // 8. If byteLength is undefined, then
+            if byte_length.is_undefined() {
+                // a. If bufferIsFixedLength is true, then
+                //     i. Let viewByteLength be bufferByteLength - offset.
+                // b. Else,
+                //     i. Let viewByteLength be auto.
+                buffer
+                    .is_fixed_length()
+                    .then_some(buffer_byte_length - offset)
             } else {
-                // 8.a. Let viewByteLength be ? ToIndex(byteLength).
-                let view_byte_length = byte_length.to_index(context)?;
-                // 8.b. If offset + viewByteLength > bufferByteLength, throw a RangeError exception.
-                if offset + view_byte_length > buffer_byte_length {
-                    return Err(JsNativeError::range()
-                        .with_message("Invalid data view length")
-                        .into());
-                }
+                None
+            }
+        };
 
-                view_byte_length
-            };
-            (offset, view_byte_length)
+        // 9. Else,
+        let view_byte_length = if byte_length.is_undefined() {
+            view_byte_length
+        } else {
+            // a. Let viewByteLength be ? ToIndex(byteLength).
+            let view_byte_length = byte_length.to_index(context)?;
+
+            // b. If offset + viewByteLength > bufferByteLength, throw a RangeError exception.
+            let buffer_byte_length = buffer_obj
+                .borrow()
+                .as_buffer()
+                .expect("already checked that `buffer` is a buffer")
+                .array_buffer_byte_length();
+            if offset + view_byte_length > buffer_byte_length {
+                return Err(JsNativeError::range()
+                    .with_message("Invalid data view length")
+                    .into());
+            }
+
+            Some(view_byte_length)
         };
 
-        // 9. Let O be ? OrdinaryCreateFromConstructor(NewTarget, "%DataView.prototype%", « [[DataView]], [[ViewedArrayBuffer]], [[ByteLength]], [[ByteOffset]] »).
+        // 10. Let O be ? OrdinaryCreateFromConstructor(NewTarget, "%DataView.prototype%", « [[DataView]], [[ViewedArrayBuffer]], [[ByteLength]], [[ByteOffset]] »).
         let prototype =
             get_prototype_from_constructor(new_target, StandardConstructors::data_view, context)?;
 
-        // 10. If IsDetachedBuffer(buffer) is true, throw a TypeError exception.
-        if buffer_obj
-            .borrow()
-            .as_array_buffer()
-            .ok_or_else(|| JsNativeError::typ().with_message("buffer must be an ArrayBuffer"))?
-            .is_detached_buffer()
         {
-            return Err(JsNativeError::typ()
-                .with_message("ArrayBuffer can't be detached")
-                .into());
+            let buffer_borrow = buffer_obj.borrow();
+            let buffer = buffer_borrow
+                .as_buffer()
+                .expect("already checked that `buffer` is a buffer");
+
+            // 11. If IsDetachedBuffer(buffer) is true, throw a TypeError exception.
+            if buffer.is_detached_buffer() {
+                return Err(JsNativeError::typ()
+                    .with_message("ArrayBuffer can't be detached")
+                    .into());
+            }
+
+            // 12. Set bufferByteLength to ArrayBufferByteLength(buffer, seq-cst).
+            let buffer_byte_length = buffer.array_buffer_byte_length();
+
+            // 13. If offset > bufferByteLength, throw a RangeError exception.
+            if offset > buffer_byte_length {
+                return Err(JsNativeError::range()
+                    .with_message("Start offset is outside the bounds of the buffer")
+                    .into());
+            }
+
+            // 14. If byteLength is not undefined, then
+            //     a. If offset + viewByteLength > bufferByteLength, throw a RangeError exception.
+            if let Some(view_byte_length) = view_byte_length {
+                if offset + view_byte_length > buffer_byte_length {
+                    return Err(JsNativeError::range()
+                        .with_message("Invalid data view length")
+                        .into());
+                }
+            }
         }
 
         let obj = JsObject::from_proto_and_data_with_shared_shape(
             context.root_shape(),
             prototype,
             ObjectData::data_view(Self {
-                // 11. Set O.[[ViewedArrayBuffer]] to buffer.
+                // 15. Set O.[[ViewedArrayBuffer]] to buffer.
                 viewed_array_buffer: buffer_obj.clone(),
-                // 12. Set O.[[ByteLength]] to viewByteLength.
+                // 16. Set O.[[ByteLength]] to viewByteLength.
                 byte_length: view_byte_length,
-                // 13. Set O.[[ByteOffset]] to offset.
+                // 17. Set O.[[ByteOffset]] to offset.
                 byte_offset: offset,
             }),
         );
 
-        // 14. Return O.
+        // 18. Return O.
         Ok(obj.into())
     }
 }
 
 impl DataView {
+    /// Abstract operations `IsViewOutOfBounds ( viewRecord )` and
+    /// `GetViewByteLength ( viewRecord )`.
+    ///
+    /// Returns the byte length of the view, or `None` if the view is out of bounds of its
+    /// buffer.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-isviewoutofbounds
+    pub(crate) fn view_byte_length(&self) -> Option<u64> {
+        let buffer = self.viewed_array_buffer.borrow();
+        let buffer = buffer
+            .as_buffer()
+            .expect("DataView must be constructed with an ArrayBuffer");
+
+        // 1. Let view be viewRecord.[[Object]].
+        // 2. Let bufferByteLength be viewRecord.[[CachedBufferByteLength]].
+        // 3. Assert: IsDetachedBuffer(view.[[ViewedArrayBuffer]]) is true if and only if bufferByteLength is detached.
+        // 4. If bufferByteLength is detached, return true.
+        if buffer.is_detached_buffer() {
+            return None;
+        }
+        let buffer_byte_length = buffer.array_buffer_byte_length();
+
+        // 5. Let byteOffsetStart be view.[[ByteOffset]].
+        let byte_offset_start = self.byte_offset;
+
+        // 6. If view.[[ByteLength]] is auto, then
+        //     a. Let byteOffsetEnd be bufferByteLength.
+        // 7. Else,
+        //     a. Let byteOffsetEnd be byteOffsetStart + view.[[ByteLength]].
+        let byte_offset_end = self.byte_length.map_or(buffer_byte_length, |byte_length| {
+            byte_offset_start + byte_length
+        });
+
+        // 8. If byteOffsetStart > bufferByteLength or byteOffsetEnd > bufferByteLength, return true.
+        if byte_offset_start > buffer_byte_length || byte_offset_end > buffer_byte_length {
+            return None;
+        }
+
+        // 9. NOTE: 0-length DataViews are not considered out-of-bounds.
+        // 10. Return false.
+        Some(byte_offset_end - byte_offset_start)
+    }
+
     /// `25.3.4.1 get DataView.prototype.buffer`
     ///
     /// The buffer accessor property represents the `ArrayBuffer` or `SharedArrayBuffer` referenced
@@ -263,19 +357,12 @@ impl DataView {
             .and_then(|obj| obj.as_data_view())
             .ok_or_else(|| JsNativeError::typ().with_message("`this` is not a DataView"))?;
         // 3. Assert: O has a [[ViewedArrayBuffer]] internal slot.
-        // 4. Let buffer be O.[[ViewedArrayBuffer]].
-        let buffer_borrow = dataview.viewed_array_buffer.borrow();
-        let borrow = buffer_borrow
-            .as_array_buffer()
-            .expect("DataView must be constructed with an ArrayBuffer");
-        // 5. If IsDetachedBuffer(buffer) is true, throw a TypeError exception.
-        if borrow.is_detached_buffer() {
-            return Err(JsNativeError::typ()
-                .with_message("ArrayBuffer is detached")
-                .into());
-        }
-        // 6. Let size be O.[[ByteLength]].
-        let size = dataview.byte_length;
+        // 4. Let viewRecord be MakeDataViewWithBufferWitnessRecord(O, seq-cst).
+        // 5. If IsViewOutOfBounds(viewRecord) is true, throw a TypeError exception.
+        // 6. Let size be GetViewByteLength(viewRecord).
+        let size = dataview.view_byte_length().ok_or_else(|| {
+            JsNativeError::typ().with_message("DataView is outside the bounds of its buffer")
+        })?;
         // 7. Return 𝔽(size).
         Ok(size.into())
     }
@@ -304,15 +391,11 @@ impl DataView {
             .and_then(|obj| obj.as_data_view())
             .ok_or_else(|| JsNativeError::typ().with_message("`this` is not a DataView"))?;
         // 3. Assert: O has a [[ViewedArrayBuffer]] internal slot.
-        // 4. Let buffer be O.[[ViewedArrayBuffer]].
-        let buffer_borrow = dataview.viewed_array_buffer.borrow();
-        let borrow = buffer_borrow
-            .as_array_buffer()
-            .expect("DataView must be constructed with an ArrayBuffer");
-        // 5. If IsDetachedBuffer(buffer) is true, throw a TypeError exception.
-        if borrow.is_detached_buffer() {
+        // 4. Let viewRecord be MakeDataViewWithBufferWitnessRecord(O, seq-cst).
+        // 5. If IsViewOutOfBounds(viewRecord) is true, throw a TypeError exception.
+        if dataview.view_byte_length().is_none() {
             return Err(JsNativeError::typ()
-                .with_message("ArrayBuffer is detached")
+                .with_message("DataView is outside the bounds of its buffer")
                 .into());
         }
         // 6. Let offset be O.[[ByteOffset]].
@@ -340,10 +423,9 @@ impl DataView {
     ) -> JsResult<JsValue> {
         // 1. Perform ? RequireInternalSlot(view, [[DataView]]).
         // 2. Assert: view has a [[ViewedArrayBuffer]] internal slot.
-        let view = view.as_object().map(JsObject::borrow);
         let view = view
-            .as_ref()
-            .and_then(|obj| obj.as_data_view())
+            .as_object()
+            .and_then(|obj| obj.borrow().as_data_view().cloned())
             .ok_or_else(|| JsNativeError::typ().with_message("`this` is not a DataView"))?;
         // 3. Let getIndex be ? ToIndex(requestIndex).
         let get_index = request_index.to_index(context)?;
@@ -351,39 +433,33 @@ impl DataView {
         // 4. Set isLittleEndian to ! ToBoolean(isLittleEndian).
         let is_little_endian = is_little_endian.to_boolean();
 
-        // 5. Let buffer be view.[[ViewedArrayBuffer]].
-        let buffer = &view.viewed_array_buffer;
-        let buffer_borrow = buffer.borrow();
-        let buffer = buffer_borrow
-            .as_array_buffer()
-            .expect("Should be unreachable");
-
-        // 6. If IsDetachedBuffer(buffer) is true, throw a TypeError exception.
-        if buffer.is_detached_buffer() {
-            return Err(JsNativeError::typ()
-                .with_message("ArrayBuffer is detached")
-                .into());
-        }
-        // 7. Let viewOffset be view.[[ByteOffset]].
+        // 5. Let viewOffset be view.[[ByteOffset]].
         let view_offset = view.byte_offset;
 
-        // 8. Let viewSize be view.[[ByteLength]].
-        let view_size = view.byte_length;
+        // 6. Let viewRecord be MakeDataViewWithBufferWitnessRecord(view, unordered).
+        // 7. NOTE: Bounds checking is not a synchronizing operation when view's backing buffer is a growable SharedArrayBuffer.
+        // 8. If IsViewOutOfBounds(viewRecord) is true, throw a TypeError exception.
+        // 9. Let viewSize be GetViewByteLength(viewRecord).
+        let view_size = view.view_byte_length().ok_or_else(|| {
+            JsNativeError::typ().with_message("DataView is outside the bounds of its buffer")
+        })?;
 
-        // 9. Let elementSize be the Element Size value specified in Table 72 for Element Type type.
+        // 10. Let elementSize be the Element Size value specified in Table 71 for Element Type type.
         let element_size = t.element_size();
 
-        // 10. If getIndex + elementSize > viewSize, throw a RangeError exception.
+        // 11. If getIndex + elementSize > viewSize, throw a RangeError exception.
         if get_index + element_size > view_size {
             return Err(JsNativeError::range()
                 .with_message("Offset is outside the bounds of the DataView")
                 .into());
         }
 
-        // 11. Let bufferIndex be getIndex + viewOffset.
+        // 12. Let bufferIndex be getIndex + viewOffset.
         let buffer_index = get_index + view_offset;
 
-        // 12. Return GetValueFromBuffer(buffer, bufferIndex, type, false, Unordered, isLittleEndian).
+        // 13. Return GetValueFromBuffer(view.[[ViewedArrayBuffer]], bufferIndex, type, false, unordered, isLittleEndian).
+        let buffer = view.viewed_array_buffer.borrow();
+        let buffer = buffer.as_buffer().expect("Should be unreachable");
         Ok(buffer.get_value_from_buffer(
             buffer_index,
             t,
@@ -703,10 +779,9 @@ impl DataView {
     ) -> JsResult<JsValue> {
         // 1. Perform ? RequireInternalSlot(view, [[DataView]]).
         // 2. Assert: view has a [[ViewedArrayBuffer]] internal slot.
-        let view = view.as_object().map(JsObject::borrow);
         let view = view
-            .as_ref()
-            .and_then(|obj| obj.as_data_view())
+            .as_object()
+            .and_then(|obj| obj.borrow().as_data_view().cloned())
             .ok_or_else(|| JsNativeError::typ().with_message("`this` is not a DataView"))?;
         // 3. Let getIndex be ? ToIndex(requestIndex).
         let get_index = request_index.to_index(context)?;
@@ -721,40 +796,35 @@ impl DataView {
 
         // 6. Set isLittleEndian to ! ToBoolean(isLittleEndian).
         let is_little_endian = is_little_endian.to_boolean();
-        // 7. Let buffer be view.[[ViewedArrayBuffer]].
-        let buffer = &view.viewed_array_buffer;
-        let mut buffer_borrow = buffer.borrow_mut();
-        let buffer = buffer_borrow
-            .as_array_buffer_mut()
-            .expect("Should be unreachable");
-
-        // 8. If IsDetachedBuffer(buffer) is true, throw a TypeError exception.
-        if buffer.is_detached_buffer() {
-            return Err(JsNativeError::typ()
-                .with_message("ArrayBuffer is detached")
-                .into());
-        }
 
-        // 9. Let viewOffset be view.[[ByteOffset]].
+        // 7. Let viewOffset be view.[[ByteOffset]].
         let view_offset = view.byte_offset;
 
-        // 10. Let viewSize be view.[[ByteLength]].
-        let view_size = view.byte_length;
+        // 8. Let viewRecord be MakeDataViewWithBufferWitnessRecord(view, unordered).
+        // 9. NOTE: Bounds checking is not a synchronizing operation when view's backing buffer is a growable SharedArrayBuffer.
+        // 10. If IsViewOutOfBounds(viewRecord) is true, throw a TypeError exception.
+        // 11. Let viewSize be GetViewByteLength(viewRecord).
+        let view_size = view.view_byte_length().ok_or_else(|| {
+            JsNativeError::typ().with_message("DataView is outside the bounds of its buffer")
+        })?;
 
-        // 11. Let elementSize be the Element Size value specified in Table 72 for Element Type type.
+        // 12. Let elementSize be the Element Size value specified in Table 71 for Element Type type.
         let element_size = t.element_size();
 
-        // 12. If getIndex + elementSize > viewSize, throw a RangeError exception.
+        // 13. If getIndex + elementSize > viewSize, throw a RangeError exception.
         if get_index + element_size > view_size {
             return Err(JsNativeError::range()
                 .with_message("Offset is outside the bounds of DataView")
                 .into());
         }
 
-        // 13. Let bufferIndex be getIndex + viewOffset.
+        // 14. Let bufferIndex be getIndex + viewOffset.
         let buffer_index = get_index + view_offset;
 
-        // 14. Return SetValueInBuffer(buffer, bufferIndex, type, numberValue, false, Unordered, isLittleEndian).
+        // 15. Perform SetValueInBuffer(view.[[ViewedArrayBuffer]], bufferIndex, type, numberValue, false, unordered, isLittleEndian).
+        // 16. Return undefined.
+        let mut buffer = view.viewed_array_buffer.borrow_mut();
+        let mut buffer = buffer.as_buffer_mut().expect("Should be unreachable");
         buffer.set_value_in_buffer(
             buffer_index,
             t,
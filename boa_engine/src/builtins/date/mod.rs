@@ -27,7 +27,7 @@ use crate::{
     string::utf16,
     symbol::JsSymbol,
     value::{IntegerOrNan, JsValue, PreferredType},
-    Context, JsArgs, JsError, JsResult,
+    Context, JsArgs, JsResult,
 };
 use boa_profiler::Profiler;
 use chrono::prelude::*;
@@ -106,7 +106,7 @@ impl IntrinsicObject for Date {
             .length(1)
             .build();
 
-        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+        let builder = BuiltInBuilder::from_standard_constructor::<Self>(realm)
             .static_method(Self::now, "now", 0)
             .static_method(Self::parse, "parse", 1)
             .static_method(Self::utc, "UTC", 7)
@@ -168,8 +168,12 @@ impl IntrinsicObject for Date {
                 JsSymbol::to_primitive(),
                 to_primitive,
                 Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
-            )
-            .build();
+            );
+
+        #[cfg(feature = "temporal")]
+        let builder = builder.method(Self::to_temporal_instant, "toTemporalInstant", 0);
+
+        builder.build();
     }
 
     fn get(intrinsics: &Intrinsics) -> JsObject {
@@ -297,61 +301,71 @@ impl Date {
         context: &mut Context<'_>,
     ) -> JsResult<Option<NaiveDateTime>> {
         // 1. Let y be ? ToNumber(year).
-        let Some(mut year) = values.get_or_undefined(0).to_integer_or_nan(context)?.as_integer() else {
+        let Some(mut year) = values
+            .get_or_undefined(0)
+            .to_integer_or_nan(context)?
+            .as_integer()
+        else {
             return Ok(None);
         };
 
         // 2. If month is present, let m be ? ToNumber(month); else let m be +0𝔽.
         let Some(month) = values.get(1).map_or(Ok(Some(0)), |value| {
             value
-            .to_integer_or_nan(context)
-            .map(IntegerOrNan::as_integer)
-        })? else {
+                .to_integer_or_nan(context)
+                .map(IntegerOrNan::as_integer)
+        })?
+        else {
             return Ok(None);
         };
 
         // 3. If date is present, let dt be ? ToNumber(date); else let dt be 1𝔽.
         let Some(date) = values.get(2).map_or(Ok(Some(1)), |value| {
             value
-            .to_integer_or_nan(context)
-            .map(IntegerOrNan::as_integer)
-        })? else {
+                .to_integer_or_nan(context)
+                .map(IntegerOrNan::as_integer)
+        })?
+        else {
             return Ok(None);
         };
 
         // 4. If hours is present, let h be ? ToNumber(hours); else let h be +0𝔽.
         let Some(hour) = values.get(3).map_or(Ok(Some(0)), |value| {
             value
-            .to_integer_or_nan(context)
-            .map(IntegerOrNan::as_integer)
-        })? else {
+                .to_integer_or_nan(context)
+                .map(IntegerOrNan::as_integer)
+        })?
+        else {
             return Ok(None);
         };
 
         // 5. If minutes is present, let min be ? ToNumber(minutes); else let min be +0𝔽.
         let Some(min) = values.get(4).map_or(Ok(Some(0)), |value| {
             value
-            .to_integer_or_nan(context)
-            .map(IntegerOrNan::as_integer)
-        })? else {
+                .to_integer_or_nan(context)
+                .map(IntegerOrNan::as_integer)
+        })?
+        else {
             return Ok(None);
         };
 
         // 6. If seconds is present, let s be ? ToNumber(seconds); else let s be +0𝔽.
         let Some(sec) = values.get(5).map_or(Ok(Some(0)), |value| {
             value
-            .to_integer_or_nan(context)
-            .map(IntegerOrNan::as_integer)
-        })? else {
+                .to_integer_or_nan(context)
+                .map(IntegerOrNan::as_integer)
+        })?
+        else {
             return Ok(None);
         };
 
         // 7. If ms is present, let milli be ? ToNumber(ms); else let milli be +0𝔽.
         let Some(ms) = values.get(6).map_or(Ok(Some(0)), |value| {
             value
-            .to_integer_or_nan(context)
-            .map(IntegerOrNan::as_integer)
-        })? else {
+                .to_integer_or_nan(context)
+                .map(IntegerOrNan::as_integer)
+        })?
+        else {
             return Ok(None);
         };
 
@@ -1320,11 +1334,32 @@ impl Date {
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.tolocaledatestring
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toLocaleDateString
     pub(crate) fn to_locale_date_string(
-        _this: &JsValue,
-        _args: &[JsValue],
-        _context: &mut Context<'_>,
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
     ) -> JsResult<JsValue> {
-        Err(JsError::from_opaque(JsValue::new("Function Unimplemented")))
+        #[cfg(feature = "intl")]
+        {
+            use crate::builtins::intl::date_time_format::DateTimeReqs;
+
+            // 1. Let x be ? thisTimeValue(this value).
+            // 2. If x is NaN, return "Invalid Date".
+            // 3. Let dateFormat be ? CreateDateTimeFormat(%DateTimeFormat%, locales, options, date, date).
+            // 4. Return ! FormatDateTime(dateFormat, x).
+            Self::format_locale_string(
+                this,
+                args,
+                &DateTimeReqs::Date,
+                &DateTimeReqs::Date,
+                context,
+            )
+        }
+
+        #[cfg(not(feature = "intl"))]
+        {
+            let _ = args;
+            Self::to_date_string(this, &[], context)
+        }
     }
 
     /// [`Date.prototype.toLocaleString()`][spec].
@@ -1337,13 +1372,32 @@ impl Date {
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.tolocalestring
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toLocaleString
     pub(crate) fn to_locale_string(
-        _this: &JsValue,
-        _: &[JsValue],
-        _context: &mut Context<'_>,
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
     ) -> JsResult<JsValue> {
-        Err(JsError::from_opaque(JsValue::new(
-            "Function Unimplemented]",
-        )))
+        #[cfg(feature = "intl")]
+        {
+            use crate::builtins::intl::date_time_format::DateTimeReqs;
+
+            // 1. Let x be ? thisTimeValue(this value).
+            // 2. If x is NaN, return "Invalid Date".
+            // 3. Let dateFormat be ? CreateDateTimeFormat(%DateTimeFormat%, locales, options, any, all).
+            // 4. Return ! FormatDateTime(dateFormat, x).
+            Self::format_locale_string(
+                this,
+                args,
+                &DateTimeReqs::AnyAll,
+                &DateTimeReqs::AnyAll,
+                context,
+            )
+        }
+
+        #[cfg(not(feature = "intl"))]
+        {
+            let _ = args;
+            Self::to_string(this, &[], context)
+        }
     }
 
     /// [`Date.prototype.toLocaleTimeString()`][spec].
@@ -1357,13 +1411,60 @@ impl Date {
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.tolocaletimestring
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toLocaleTimeString
     pub(crate) fn to_locale_time_string(
-        _this: &JsValue,
-        _args: &[JsValue],
-        _context: &mut Context<'_>,
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
     ) -> JsResult<JsValue> {
-        Err(JsError::from_opaque(JsValue::new(
-            "Function Unimplemented]",
-        )))
+        #[cfg(feature = "intl")]
+        {
+            use crate::builtins::intl::date_time_format::DateTimeReqs;
+
+            // 1. Let x be ? thisTimeValue(this value).
+            // 2. If x is NaN, return "Invalid Date".
+            // 3. Let timeFormat be ? CreateDateTimeFormat(%DateTimeFormat%, locales, options, time, time).
+            // 4. Return ! FormatDateTime(timeFormat, x).
+            Self::format_locale_string(
+                this,
+                args,
+                &DateTimeReqs::Time,
+                &DateTimeReqs::Time,
+                context,
+            )
+        }
+
+        #[cfg(not(feature = "intl"))]
+        {
+            let _ = args;
+            Self::to_time_string(this, &[], context)
+        }
+    }
+
+    /// Common steps of the `toLocaleString`, `toLocaleDateString` and `toLocaleTimeString`
+    /// methods, which format the time value of `this` with a new `Intl.DateTimeFormat`.
+    #[cfg(feature = "intl")]
+    fn format_locale_string(
+        this: &JsValue,
+        args: &[JsValue],
+        required: &crate::builtins::intl::date_time_format::DateTimeReqs,
+        defaults: &crate::builtins::intl::date_time_format::DateTimeReqs,
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        use crate::builtins::intl::DateTimeFormat;
+
+        let Some(x) = this_time_value(this)? else {
+            return Ok(js_string!("Invalid Date").into());
+        };
+
+        let date_format = DateTimeFormat::new(
+            args.get_or_undefined(0),
+            args.get_or_undefined(1),
+            required,
+            defaults,
+            context,
+        )?;
+
+        #[allow(clippy::cast_precision_loss)]
+        Ok(date_format.format_value(x as f64, context)?.into())
     }
 
     /// [`Date.prototype.toString()`][spec].
@@ -1442,7 +1543,7 @@ impl Date {
         // 1. Let O be this Date object.
         let Some(t) = this_time_value(this)?.and_then(NaiveDateTime::from_timestamp_millis) else {
             // 3. If tv is NaN, return "Invalid Date".
-            return Ok(js_string!("Invalid Date").into())
+            return Ok(js_string!("Invalid Date").into());
         };
 
         // 2. Let tv be ? thisTimeValue(O).
@@ -1477,6 +1578,40 @@ impl Date {
         Ok(Self::new(this_time_value(this)?).as_value())
     }
 
+    /// [`Date.prototype.toTemporalInstant()`][spec].
+    ///
+    /// The `toTemporalInstant()` method returns a `Temporal.Instant` representing the same point
+    /// in time as the specified Date object.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-date.prototype.totemporalinstant
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toTemporalInstant
+    #[cfg(feature = "temporal")]
+    pub(crate) fn to_temporal_instant(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        use crate::builtins::temporal::Instant;
+
+        // 1. Let dateObject be the this value.
+        // 2. Perform ? RequireInternalSlot(dateObject, [[DateValue]]).
+        // 3. Let t be dateObject.[[DateValue]].
+        // 4. Let ns be ? NumberToBigInt(t) × ℤ(10^6).
+        let Some(t) = this_time_value(this)? else {
+            return Err(JsNativeError::range()
+                .with_message("cannot convert an invalid Date to an instant")
+                .into());
+        };
+
+        // 5. Return ! CreateTemporalInstant(ns).
+        Ok(Instant::new(i128::from(t) * 1_000_000)
+            .create(None, context)?
+            .into())
+    }
+
     /// [`Date.prototype [ @@toPrimitive ] ( hint )`][spec].
     ///
     /// The <code>\[@@toPrimitive\]()</code> method converts a Date object to a primitive value.
@@ -0,0 +1,391 @@
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+
+use crate::{
+    builtins::{BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject},
+    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    object::{
+        builtins::JsPromise, internal_methods::get_prototype_from_constructor, JsObject, ObjectData,
+    },
+    property::Attribute,
+    realm::Realm,
+    string::utf16,
+    symbol::JsSymbol,
+    Context, JsArgs, JsNativeError, JsResult, JsValue,
+};
+
+use super::{DisposableResource, DisposableStack, DisposeCapability, DisposeHint};
+
+/// Boa's implementation of the `AsyncDisposableStack` builtin object.
+///
+/// An `AsyncDisposableStack` aggregates asynchronously disposable resources, disposing all of
+/// them in reverse order, awaiting each one, when the stack itself gets disposed.
+///
+/// More information:
+///  - [Explicit Resource Management proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack-objects
+#[derive(Debug, Default, Trace, Finalize)]
+pub struct AsyncDisposableStack {
+    /// Whether the `[[AsyncDisposableState]]` internal slot is `disposed`.
+    disposed: bool,
+
+    /// The `[[DisposeCapability]]` internal slot.
+    capability: DisposeCapability,
+}
+
+impl IntrinsicObject for AsyncDisposableStack {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        let get_disposed = BuiltInBuilder::callable(realm, Self::get_disposed)
+            .name("get disposed")
+            .build();
+
+        let dispose_async = BuiltInBuilder::callable(realm, Self::dispose_async)
+            .name("disposeAsync")
+            .build();
+
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .accessor(
+                utf16!("disposed"),
+                Some(get_disposed),
+                None,
+                Attribute::CONFIGURABLE,
+            )
+            .property(
+                utf16!("disposeAsync"),
+                dispose_async.clone(),
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .method(Self::r#use, "use", 1)
+            .method(Self::adopt, "adopt", 2)
+            .method(Self::defer, "defer", 1)
+            .method(Self::r#move, "move", 0)
+            .property(
+                JsSymbol::async_dispose(),
+                dispose_async,
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .property(
+                JsSymbol::to_string_tag(),
+                Self::NAME,
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for AsyncDisposableStack {
+    const NAME: &'static str = "AsyncDisposableStack";
+}
+
+impl BuiltInConstructor for AsyncDisposableStack {
+    const LENGTH: usize = 0;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::async_disposable_stack;
+
+    /// Constructor [`AsyncDisposableStack ( )`][spec].
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack
+    fn constructor(
+        new_target: &JsValue,
+        _: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("AsyncDisposableStack: cannot call constructor without `new`")
+                .into());
+        }
+
+        // 2. Let asyncDisposableStack be ? OrdinaryCreateFromConstructor(NewTarget, "%AsyncDisposableStack.prototype%", « [[AsyncDisposableState]], [[DisposeCapability]] »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::async_disposable_stack,
+            context,
+        )?;
+
+        // 3. Set asyncDisposableStack.[[AsyncDisposableState]] to pending.
+        // 4. Set asyncDisposableStack.[[DisposeCapability]] to NewDisposeCapability().
+        // 5. Return asyncDisposableStack.
+        Ok(JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            ObjectData::async_disposable_stack(Self::default()),
+        )
+        .into())
+    }
+}
+
+impl AsyncDisposableStack {
+    /// Gets the `AsyncDisposableStack` object of `this`, throwing a `TypeError` if it isn't one.
+    fn this_stack<'a>(this: &'a JsValue, method: &str) -> JsResult<&'a JsObject> {
+        this.as_object()
+            .filter(|stack| stack.is_async_disposable_stack())
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message(format!(
+                        "AsyncDisposableStack.prototype.{method}: expected `this` to be an `AsyncDisposableStack` object"
+                    ))
+                    .into()
+            })
+    }
+
+    /// Checks that `stack` has not been disposed, throwing a `ReferenceError` otherwise.
+    fn require_not_disposed(stack: &JsObject, method: &str) -> JsResult<()> {
+        let stack = stack.borrow();
+        let stack = stack
+            .as_async_disposable_stack()
+            .expect("must be an `AsyncDisposableStack` object");
+
+        if stack.disposed {
+            return Err(JsNativeError::reference()
+                .with_message(format!(
+                    "AsyncDisposableStack.prototype.{method}: the stack has already been disposed"
+                ))
+                .into());
+        }
+
+        Ok(())
+    }
+
+    /// Appends `resource` to the dispose capability of `stack`.
+    fn push_resource(stack: &JsObject, resource: Option<DisposableResource>) {
+        if let Some(resource) = resource {
+            stack
+                .borrow_mut()
+                .as_async_disposable_stack_mut()
+                .expect("must be an `AsyncDisposableStack` object")
+                .capability
+                .push(resource);
+        }
+    }
+
+    /// Getter [`get AsyncDisposableStack.prototype.disposed`][spec].
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-get-asyncdisposablestack.prototype.disposed
+    pub(crate) fn get_disposed(
+        this: &JsValue,
+        _: &[JsValue],
+        _: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. Let asyncDisposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(asyncDisposableStack, [[AsyncDisposableState]]).
+        let stack = Self::this_stack(this, "disposed")?.borrow();
+        let stack = stack
+            .as_async_disposable_stack()
+            .expect("must be an `AsyncDisposableStack` object");
+
+        // 3. If asyncDisposableStack.[[AsyncDisposableState]] is disposed, return true.
+        // 4. Otherwise, return false.
+        Ok(stack.disposed.into())
+    }
+
+    /// Method [`AsyncDisposableStack.prototype.disposeAsync ( )`][spec].
+    ///
+    /// Disposes all the resources of the stack in reverse order, returning a promise that settles
+    /// after all of them have been disposed. Also installed as
+    /// `AsyncDisposableStack.prototype[@@asyncDispose]`.
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack.prototype.disposeAsync
+    pub(crate) fn dispose_async(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. Let asyncDisposableStack be the this value.
+        // 2. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+        // 3. If asyncDisposableStack does not have an [[AsyncDisposableState]] internal slot, then
+        let stack = match Self::this_stack(this, "disposeAsync") {
+            Ok(stack) => stack,
+            // a. Perform ! Call(promiseCapability.[[Reject]], undefined, « a newly created TypeError object »).
+            // b. Return promiseCapability.[[Promise]].
+            Err(err) => return JsPromise::reject(err, context).map(Into::into),
+        };
+
+        let capability = {
+            let mut stack = stack.borrow_mut();
+            let stack = stack
+                .as_async_disposable_stack_mut()
+                .expect("must be an `AsyncDisposableStack` object");
+
+            // 4. If asyncDisposableStack.[[AsyncDisposableState]] is disposed, then
+            if stack.disposed {
+                // a. Perform ! Call(promiseCapability.[[Resolve]], undefined, « undefined »).
+                // b. Return promiseCapability.[[Promise]].
+                return JsPromise::resolve(JsValue::undefined(), context).map(Into::into);
+            }
+
+            // 5. Set asyncDisposableStack.[[AsyncDisposableState]] to disposed.
+            stack.disposed = true;
+            std::mem::take(&mut stack.capability)
+        };
+
+        // 6. Let result be DisposeResources(asyncDisposableStack.[[DisposeCapability]], NormalCompletion(undefined)).
+        // 7. IfAbruptRejectPromise(result, promiseCapability).
+        // 8. Perform ! Call(promiseCapability.[[Resolve]], undefined, « result »).
+        // 9. Return promiseCapability.[[Promise]].
+        capability
+            .dispose_resources_async(None, context)
+            .map(Into::into)
+    }
+
+    /// Method [`AsyncDisposableStack.prototype.use ( value )`][spec].
+    ///
+    /// Adds `value` to the stack, disposing it with its `@@asyncDispose` or `@@dispose` method.
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack.prototype.use
+    pub(crate) fn r#use(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        let value = args.get_or_undefined(0);
+
+        // 1. Let asyncDisposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(asyncDisposableStack, [[AsyncDisposableState]]).
+        let stack = Self::this_stack(this, "use")?;
+
+        // 3. If asyncDisposableStack.[[AsyncDisposableState]] is disposed, throw a ReferenceError exception.
+        Self::require_not_disposed(stack, "use")?;
+
+        // 4. Perform ? AddDisposableResource(asyncDisposableStack.[[DisposeCapability]], value, async-dispose).
+        let resource =
+            DisposableResource::from_value(value.clone(), DisposeHint::Async, None, context)?;
+        Self::push_resource(stack, resource);
+
+        // 5. Return value.
+        Ok(value.clone())
+    }
+
+    /// Method [`AsyncDisposableStack.prototype.adopt ( value, onDisposeAsync )`][spec].
+    ///
+    /// Adds `value` to the stack, disposing it by calling `onDisposeAsync` with it.
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack.prototype.adopt
+    pub(crate) fn adopt(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        let value = args.get_or_undefined(0);
+
+        // 1. Let asyncDisposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(asyncDisposableStack, [[AsyncDisposableState]]).
+        let stack = Self::this_stack(this, "adopt")?;
+
+        // 3. If asyncDisposableStack.[[AsyncDisposableState]] is disposed, throw a ReferenceError exception.
+        Self::require_not_disposed(stack, "adopt")?;
+
+        // 4. If IsCallable(onDisposeAsync) is false, throw a TypeError exception.
+        let on_dispose =
+            DisposableStack::on_dispose(args.get_or_undefined(1), Self::NAME, "adopt")?;
+
+        // 5-6.
+        let f = DisposableStack::adopt_closure(value.clone(), on_dispose, context);
+
+        // 7. Perform ? AddDisposableResource(asyncDisposableStack.[[DisposeCapability]], undefined, async-dispose, F).
+        let resource = DisposableResource::from_value(
+            JsValue::undefined(),
+            DisposeHint::Async,
+            Some(f),
+            context,
+        )?;
+        Self::push_resource(stack, resource);
+
+        // 8. Return value.
+        Ok(value.clone())
+    }
+
+    /// Method [`AsyncDisposableStack.prototype.defer ( onDisposeAsync )`][spec].
+    ///
+    /// Adds a callback to the stack, which is called and awaited when the stack gets disposed.
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack.prototype.defer
+    pub(crate) fn defer(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. Let asyncDisposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(asyncDisposableStack, [[AsyncDisposableState]]).
+        let stack = Self::this_stack(this, "defer")?;
+
+        // 3. If asyncDisposableStack.[[AsyncDisposableState]] is disposed, throw a ReferenceError exception.
+        Self::require_not_disposed(stack, "defer")?;
+
+        // 4. If IsCallable(onDisposeAsync) is false, throw a TypeError exception.
+        let on_dispose =
+            DisposableStack::on_dispose(args.get_or_undefined(0), Self::NAME, "defer")?;
+
+        // 5. Perform ? AddDisposableResource(asyncDisposableStack.[[DisposeCapability]], undefined, async-dispose, onDisposeAsync).
+        let resource = DisposableResource::from_value(
+            JsValue::undefined(),
+            DisposeHint::Async,
+            Some(on_dispose),
+            context,
+        )?;
+        Self::push_resource(stack, resource);
+
+        // 6. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// Method [`AsyncDisposableStack.prototype.move ( )`][spec].
+    ///
+    /// Moves all the resources of the stack to a new `AsyncDisposableStack`, marking the original
+    /// stack as disposed.
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack.prototype.move
+    pub(crate) fn r#move(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. Let asyncDisposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(asyncDisposableStack, [[AsyncDisposableState]]).
+        let stack = Self::this_stack(this, "move")?;
+
+        // 3. If asyncDisposableStack.[[AsyncDisposableState]] is disposed, throw a ReferenceError exception.
+        Self::require_not_disposed(stack, "move")?;
+
+        let capability = {
+            let mut stack = stack.borrow_mut();
+            let stack = stack
+                .as_async_disposable_stack_mut()
+                .expect("must be an `AsyncDisposableStack` object");
+
+            // 7. Set asyncDisposableStack.[[DisposeCapability]] to NewDisposeCapability().
+            // 8. Set asyncDisposableStack.[[AsyncDisposableState]] to disposed.
+            stack.disposed = true;
+            std::mem::take(&mut stack.capability)
+        };
+
+        // 4. Let newAsyncDisposableStack be ? OrdinaryCreateFromConstructor(%AsyncDisposableStack%, "%AsyncDisposableStack.prototype%", « [[AsyncDisposableState]], [[DisposeCapability]] »).
+        // 5. Set newAsyncDisposableStack.[[AsyncDisposableState]] to pending.
+        // 6. Set newAsyncDisposableStack.[[DisposeCapability]] to asyncDisposableStack.[[DisposeCapability]].
+        let prototype = context
+            .intrinsics()
+            .constructors()
+            .async_disposable_stack()
+            .prototype();
+
+        // 9. Return newAsyncDisposableStack.
+        Ok(JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            ObjectData::async_disposable_stack(Self {
+                disposed: false,
+                capability,
+            }),
+        )
+        .into())
+    }
+}
@@ -0,0 +1,429 @@
+//! Boa's implementation of the `DisposableStack` and `AsyncDisposableStack` objects of the
+//! Explicit Resource Management proposal.
+//!
+//! More information:
+//!  - [Explicit Resource Management proposal][spec]
+//!
+//! [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack-objects
+
+mod async_disposable_stack;
+mod resource;
+
+#[cfg(test)]
+mod tests;
+
+pub(crate) use async_disposable_stack::AsyncDisposableStack;
+pub(crate) use resource::{DisposableResource, DisposeCapability, DisposeHint};
+
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+
+use crate::{
+    builtins::{BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject},
+    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    native_function::NativeFunction,
+    object::{
+        internal_methods::get_prototype_from_constructor, FunctionObjectBuilder, JsObject,
+        ObjectData,
+    },
+    property::Attribute,
+    realm::Realm,
+    string::utf16,
+    symbol::JsSymbol,
+    Context, JsArgs, JsNativeError, JsResult, JsValue,
+};
+
+/// Boa's implementation of the `DisposableStack` builtin object.
+///
+/// A `DisposableStack` aggregates disposable resources, disposing all of them in reverse order
+/// when the stack itself gets disposed.
+///
+/// More information:
+///  - [Explicit Resource Management proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack-objects
+#[derive(Debug, Default, Trace, Finalize)]
+pub struct DisposableStack {
+    /// Whether the `[[DisposableState]]` internal slot is `disposed`.
+    disposed: bool,
+
+    /// The `[[DisposeCapability]]` internal slot.
+    capability: DisposeCapability,
+}
+
+impl IntrinsicObject for DisposableStack {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        let get_disposed = BuiltInBuilder::callable(realm, Self::get_disposed)
+            .name("get disposed")
+            .build();
+
+        let dispose = BuiltInBuilder::callable(realm, Self::dispose)
+            .name("dispose")
+            .build();
+
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .accessor(
+                utf16!("disposed"),
+                Some(get_disposed),
+                None,
+                Attribute::CONFIGURABLE,
+            )
+            .property(
+                utf16!("dispose"),
+                dispose.clone(),
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .method(Self::r#use, "use", 1)
+            .method(Self::adopt, "adopt", 2)
+            .method(Self::defer, "defer", 1)
+            .method(Self::r#move, "move", 0)
+            .property(
+                JsSymbol::dispose(),
+                dispose,
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .property(
+                JsSymbol::to_string_tag(),
+                Self::NAME,
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for DisposableStack {
+    const NAME: &'static str = "DisposableStack";
+}
+
+impl BuiltInConstructor for DisposableStack {
+    const LENGTH: usize = 0;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::disposable_stack;
+
+    /// Constructor [`DisposableStack ( )`][spec].
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack
+    fn constructor(
+        new_target: &JsValue,
+        _: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("DisposableStack: cannot call constructor without `new`")
+                .into());
+        }
+
+        // 2. Let disposableStack be ? OrdinaryCreateFromConstructor(NewTarget, "%DisposableStack.prototype%", « [[DisposableState]], [[DisposeCapability]] »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::disposable_stack,
+            context,
+        )?;
+
+        // 3. Set disposableStack.[[DisposableState]] to pending.
+        // 4. Set disposableStack.[[DisposeCapability]] to NewDisposeCapability().
+        // 5. Return disposableStack.
+        Ok(JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            ObjectData::disposable_stack(Self::default()),
+        )
+        .into())
+    }
+}
+
+impl DisposableStack {
+    /// Gets the `DisposableStack` object of `this`, throwing a `TypeError` if it isn't one.
+    fn this_stack<'a>(this: &'a JsValue, method: &str) -> JsResult<&'a JsObject> {
+        this.as_object()
+            .filter(|stack| stack.is_disposable_stack())
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message(format!(
+                        "DisposableStack.prototype.{method}: expected `this` to be a `DisposableStack` object"
+                    ))
+                    .into()
+            })
+    }
+
+    /// Checks that `stack` has not been disposed, throwing a `ReferenceError` otherwise.
+    fn require_not_disposed(stack: &JsObject, method: &str) -> JsResult<()> {
+        let stack = stack.borrow();
+        let stack = stack
+            .as_disposable_stack()
+            .expect("must be a `DisposableStack` object");
+
+        if stack.disposed {
+            return Err(JsNativeError::reference()
+                .with_message(format!(
+                    "DisposableStack.prototype.{method}: the stack has already been disposed"
+                ))
+                .into());
+        }
+
+        Ok(())
+    }
+
+    /// Appends `resource` to the dispose capability of `stack`.
+    fn push_resource(stack: &JsObject, resource: Option<DisposableResource>) {
+        if let Some(resource) = resource {
+            stack
+                .borrow_mut()
+                .as_disposable_stack_mut()
+                .expect("must be a `DisposableStack` object")
+                .capability
+                .push(resource);
+        }
+    }
+
+    /// Returns a function that calls `on_dispose` with `value`, as in the steps 5-6 of
+    /// [`DisposableStack.prototype.adopt`][spec] and [`AsyncDisposableStack.prototype.adopt`][spec2].
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack.prototype.adopt
+    /// [spec2]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack.prototype.adopt
+    fn adopt_closure(value: JsValue, on_dispose: JsObject, context: &mut Context<'_>) -> JsObject {
+        // 5. Let closure be a new Abstract Closure with no parameters that captures value and onDispose and performs the following steps when called:
+        //     a. Return ? Call(onDispose, undefined, « value »).
+        // 6. Let F be CreateBuiltinFunction(closure, 0, "", « »).
+        FunctionObjectBuilder::new(
+            context,
+            NativeFunction::from_copy_closure_with_captures(
+                |_, _, (value, on_dispose), context| {
+                    on_dispose.call(&JsValue::undefined(), std::slice::from_ref(value), context)
+                },
+                (value, on_dispose),
+            ),
+        )
+        .name("")
+        .length(0)
+        .build()
+        .into()
+    }
+
+    /// Gets the callable `onDispose` argument of `adopt` and `defer`, throwing a `TypeError` if
+    /// it isn't callable.
+    fn on_dispose(on_dispose: &JsValue, name: &str, method: &str) -> JsResult<JsObject> {
+        on_dispose.as_callable().cloned().ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message(format!(
+                    "{name}.prototype.{method}: expected `onDispose` to be callable"
+                ))
+                .into()
+        })
+    }
+
+    /// Getter [`get DisposableStack.prototype.disposed`][spec].
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-get-disposablestack.prototype.disposed
+    pub(crate) fn get_disposed(
+        this: &JsValue,
+        _: &[JsValue],
+        _: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. Let disposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(disposableStack, [[DisposableState]]).
+        let stack = Self::this_stack(this, "disposed")?.borrow();
+        let stack = stack
+            .as_disposable_stack()
+            .expect("must be a `DisposableStack` object");
+
+        // 3. If disposableStack.[[DisposableState]] is disposed, return true.
+        // 4. Otherwise, return false.
+        Ok(stack.disposed.into())
+    }
+
+    /// Method [`DisposableStack.prototype.dispose ( )`][spec].
+    ///
+    /// Disposes all the resources of the stack in reverse order. Also installed as
+    /// `DisposableStack.prototype[@@dispose]`.
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack.prototype.dispose
+    pub(crate) fn dispose(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. Let disposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(disposableStack, [[DisposableState]]).
+        let stack = Self::this_stack(this, "dispose")?;
+
+        let capability = {
+            let mut stack = stack.borrow_mut();
+            let stack = stack
+                .as_disposable_stack_mut()
+                .expect("must be a `DisposableStack` object");
+
+            // 3. If disposableStack.[[DisposableState]] is disposed, return undefined.
+            if stack.disposed {
+                return Ok(JsValue::undefined());
+            }
+
+            // 4. Set disposableStack.[[DisposableState]] to disposed.
+            stack.disposed = true;
+            std::mem::take(&mut stack.capability)
+        };
+
+        // 5. Return DisposeResources(disposableStack.[[DisposeCapability]], NormalCompletion(undefined)).
+        capability.dispose_resources(None, context)?;
+        Ok(JsValue::undefined())
+    }
+
+    /// Method [`DisposableStack.prototype.use ( value )`][spec].
+    ///
+    /// Adds `value` to the stack, disposing it with its `@@dispose` method.
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack.prototype.use
+    pub(crate) fn r#use(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        let value = args.get_or_undefined(0);
+
+        // 1. Let disposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(disposableStack, [[DisposableState]]).
+        let stack = Self::this_stack(this, "use")?;
+
+        // 3. If disposableStack.[[DisposableState]] is disposed, throw a ReferenceError exception.
+        Self::require_not_disposed(stack, "use")?;
+
+        // 4. Perform ? AddDisposableResource(disposableStack.[[DisposeCapability]], value, sync-dispose).
+        let resource =
+            DisposableResource::from_value(value.clone(), DisposeHint::Sync, None, context)?;
+        Self::push_resource(stack, resource);
+
+        // 5. Return value.
+        Ok(value.clone())
+    }
+
+    /// Method [`DisposableStack.prototype.adopt ( value, onDispose )`][spec].
+    ///
+    /// Adds `value` to the stack, disposing it by calling `onDispose` with it.
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack.prototype.adopt
+    pub(crate) fn adopt(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        let value = args.get_or_undefined(0);
+
+        // 1. Let disposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(disposableStack, [[DisposableState]]).
+        let stack = Self::this_stack(this, "adopt")?;
+
+        // 3. If disposableStack.[[DisposableState]] is disposed, throw a ReferenceError exception.
+        Self::require_not_disposed(stack, "adopt")?;
+
+        // 4. If IsCallable(onDispose) is false, throw a TypeError exception.
+        let on_dispose = Self::on_dispose(args.get_or_undefined(1), Self::NAME, "adopt")?;
+
+        // 5-6.
+        let f = Self::adopt_closure(value.clone(), on_dispose, context);
+
+        // 7. Perform ? AddDisposableResource(disposableStack.[[DisposeCapability]], undefined, sync-dispose, F).
+        let resource = DisposableResource::from_value(
+            JsValue::undefined(),
+            DisposeHint::Sync,
+            Some(f),
+            context,
+        )?;
+        Self::push_resource(stack, resource);
+
+        // 8. Return value.
+        Ok(value.clone())
+    }
+
+    /// Method [`DisposableStack.prototype.defer ( onDispose )`][spec].
+    ///
+    /// Adds a callback to the stack, which is called when the stack gets disposed.
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack.prototype.defer
+    pub(crate) fn defer(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. Let disposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(disposableStack, [[DisposableState]]).
+        let stack = Self::this_stack(this, "defer")?;
+
+        // 3. If disposableStack.[[DisposableState]] is disposed, throw a ReferenceError exception.
+        Self::require_not_disposed(stack, "defer")?;
+
+        // 4. If IsCallable(onDispose) is false, throw a TypeError exception.
+        let on_dispose = Self::on_dispose(args.get_or_undefined(0), Self::NAME, "defer")?;
+
+        // 5. Perform ? AddDisposableResource(disposableStack.[[DisposeCapability]], undefined, sync-dispose, onDispose).
+        let resource = DisposableResource::from_value(
+            JsValue::undefined(),
+            DisposeHint::Sync,
+            Some(on_dispose),
+            context,
+        )?;
+        Self::push_resource(stack, resource);
+
+        // 6. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// Method [`DisposableStack.prototype.move ( )`][spec].
+    ///
+    /// Moves all the resources of the stack to a new `DisposableStack`, marking the original
+    /// stack as disposed.
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack.prototype.move
+    pub(crate) fn r#move(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. Let disposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(disposableStack, [[DisposableState]]).
+        let stack = Self::this_stack(this, "move")?;
+
+        // 3. If disposableStack.[[DisposableState]] is disposed, throw a ReferenceError exception.
+        Self::require_not_disposed(stack, "move")?;
+
+        let capability = {
+            let mut stack = stack.borrow_mut();
+            let stack = stack
+                .as_disposable_stack_mut()
+                .expect("must be a `DisposableStack` object");
+
+            // 7. Set disposableStack.[[DisposeCapability]] to NewDisposeCapability().
+            // 8. Set disposableStack.[[DisposableState]] to disposed.
+            stack.disposed = true;
+            std::mem::take(&mut stack.capability)
+        };
+
+        // 4. Let newDisposableStack be ? OrdinaryCreateFromConstructor(%DisposableStack%, "%DisposableStack.prototype%", « [[DisposableState]], [[DisposeCapability]] »).
+        // 5. Set newDisposableStack.[[DisposableState]] to pending.
+        // 6. Set newDisposableStack.[[DisposeCapability]] to disposableStack.[[DisposeCapability]].
+        let prototype = context
+            .intrinsics()
+            .constructors()
+            .disposable_stack()
+            .prototype();
+
+        // 9. Return newDisposableStack.
+        Ok(JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            ObjectData::disposable_stack(Self {
+                disposed: false,
+                capability,
+            }),
+        )
+        .into())
+    }
+}
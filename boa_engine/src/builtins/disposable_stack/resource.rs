@@ -0,0 +1,477 @@
+//! Abstract operations shared by `using` declarations and the disposable stack objects.
+//!
+//! More information:
+//!  - [Explicit Resource Management proposal][spec]
+//!
+//! [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposable-resource-records
+
+use boa_gc::{Finalize, Trace};
+
+use crate::{
+    builtins::promise::{Promise, PromiseCapability},
+    native_function::NativeFunction,
+    object::{FunctionObjectBuilder, JsObject},
+    symbol::JsSymbol,
+    Context, JsError, JsNativeError, JsResult, JsValue,
+};
+
+/// The kind of disposal requested for a resource.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DisposeHint {
+    /// The `sync-dispose` hint, used by `using` declarations and `DisposableStack`.
+    Sync,
+
+    /// The `async-dispose` hint, used by `await using` declarations and `AsyncDisposableStack`.
+    Async,
+}
+
+impl From<u8> for DisposeHint {
+    fn from(value: u8) -> Self {
+        if value == 0 {
+            Self::Sync
+        } else {
+            Self::Async
+        }
+    }
+}
+
+impl From<DisposeHint> for u8 {
+    fn from(value: DisposeHint) -> Self {
+        match value {
+            DisposeHint::Sync => 0,
+            DisposeHint::Async => 1,
+        }
+    }
+}
+
+/// A `DisposableResource` Record.
+///
+/// More information:
+///  - [Explicit Resource Management proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposableresource-records
+#[derive(Debug, Clone, Trace, Finalize)]
+pub(crate) struct DisposableResource {
+    /// The `[[ResourceValue]]` field.
+    value: JsValue,
+
+    /// The `[[Hint]]` field.
+    #[unsafe_ignore_trace]
+    hint: DisposeHint,
+
+    /// The `[[DisposeMethod]]` field.
+    method: Option<JsObject>,
+}
+
+impl DisposableResource {
+    /// Creates the resource to add to a dispose capability, following the first steps of
+    /// [`AddDisposableResource ( disposeCapability, V, hint [ , method ] )`][spec].
+    ///
+    /// Returns `None` if the resource must be ignored.
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-adddisposableresource
+    pub(crate) fn from_value(
+        value: JsValue,
+        hint: DisposeHint,
+        method: Option<JsObject>,
+        context: &mut Context<'_>,
+    ) -> JsResult<Option<Self>> {
+        // 1. If method is not present, then
+        //     a. If V is either null or undefined and hint is sync-dispose, return unused.
+        if method.is_none() && value.is_null_or_undefined() && hint == DisposeHint::Sync {
+            return Ok(None);
+        }
+
+        //     b. NOTE: When V is either null or undefined and hint is async-dispose, we record that
+        //        the resource was evaluated to ensure we will still perform an Await when resources
+        //        are later disposed.
+        //     c. Let resource be ? CreateDisposableResource(V, hint).
+        // 2. Else,
+        //     a. Assert: V is undefined.
+        //     b. Let resource be ? CreateDisposableResource(undefined, hint, method).
+        Self::new(value, hint, method, context).map(Some)
+    }
+
+    /// Abstract operation [`CreateDisposableResource ( V, hint [ , method ] )`][spec].
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-createdisposableresource
+    fn new(
+        value: JsValue,
+        hint: DisposeHint,
+        method: Option<JsObject>,
+        context: &mut Context<'_>,
+    ) -> JsResult<Self> {
+        // 1. If method is not present, then
+        let Some(method) = method else {
+            // a. If V is either null or undefined, then
+            if value.is_null_or_undefined() {
+                // i. Set V to undefined.
+                // ii. Set method to undefined.
+                return Ok(Self {
+                    value: JsValue::undefined(),
+                    hint,
+                    method: None,
+                });
+            }
+
+            // b. Else,
+            // i. If V is not an Object, throw a TypeError exception.
+            let object = value.as_object().ok_or_else(|| {
+                JsNativeError::typ().with_message("disposable resource must be an object")
+            })?;
+
+            // ii. Set method to ? GetDisposeMethod(V, hint).
+            // iii. If method is undefined, throw a TypeError exception.
+            let method = get_dispose_method(object, hint, context)?.ok_or_else(|| {
+                JsNativeError::typ().with_message(match hint {
+                    DisposeHint::Sync => "disposable resource must have a `Symbol.dispose` method",
+                    DisposeHint::Async => {
+                        "disposable resource must have a `Symbol.asyncDispose` or `Symbol.dispose` method"
+                    }
+                })
+            })?;
+
+            return Ok(Self {
+                value,
+                hint,
+                method: Some(method),
+            });
+        };
+
+        // 2. Else,
+        // a. If IsCallable(method) is false, throw a TypeError exception.
+        if !method.is_callable() {
+            return Err(JsNativeError::typ()
+                .with_message("dispose method must be callable")
+                .into());
+        }
+
+        // 3. Return the DisposableResource Record { [[ResourceValue]]: V, [[Hint]]: hint, [[DisposeMethod]]: method }.
+        Ok(Self {
+            value,
+            hint,
+            method: Some(method),
+        })
+    }
+
+    /// Abstract operation [`Dispose ( V, hint, method )`][spec], without the final `Await`.
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-dispose
+    fn dispose(&self, context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. If method is undefined, let result be undefined.
+        // 2. Else, let result be ? Call(method, V).
+        self.method
+            .as_ref()
+            .map_or(Ok(JsValue::undefined()), |method| {
+                method.call(&self.value, &[], context)
+            })
+    }
+}
+
+/// Abstract operation [`GetDisposeMethod ( V, hint )`][spec].
+///
+/// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-getdisposemethod
+fn get_dispose_method(
+    value: &JsObject,
+    hint: DisposeHint,
+    context: &mut Context<'_>,
+) -> JsResult<Option<JsObject>> {
+    // 1. If hint is async-dispose, then
+    if hint == DisposeHint::Async {
+        // a. Let method be ? GetMethod(V, @@asyncDispose).
+        if let Some(method) = value.get_method(JsSymbol::async_dispose(), context)? {
+            return Ok(Some(method));
+        }
+
+        // b. If method is undefined, then
+        //     i. Set method to ? GetMethod(V, @@dispose).
+        let Some(method) = value.get_method(JsSymbol::dispose(), context)? else {
+            return Ok(None);
+        };
+
+        //     ii. If method is not undefined, then
+        //         1. Let closure be a new Abstract Closure with no parameters that captures method and performs the following steps when called:
+        //         2. NOTE: This function is not observable to user code. It is used to ensure that a Promise returned from a synchronous @@dispose method will not be awaited and that any exception thrown will not be thrown synchronously.
+        //         3. Return CreateBuiltinFunction(closure, 0, "", « »).
+        let closure = FunctionObjectBuilder::new(
+            context,
+            NativeFunction::from_copy_closure_with_captures(
+                |this, _, method, context| {
+                    // a. Let O be the this value.
+                    // b. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+                    let capability = PromiseCapability::new(
+                        &context.intrinsics().constructors().promise().constructor(),
+                        context,
+                    )
+                    .expect("cannot fail with the %Promise% constructor");
+
+                    // c. Let result be Completion(Call(method, O)).
+                    match method.call(this, &[], context) {
+                        // e. Perform ? Call(promiseCapability.[[Resolve]], undefined, « undefined »).
+                        Ok(_) => {
+                            capability
+                                .resolve()
+                                .call(&JsValue::undefined(), &[], context)?;
+                        }
+                        // d. IfAbruptRejectPromise(result, promiseCapability).
+                        Err(err) if is_uncatchable(&err) => return Err(err),
+                        Err(err) => {
+                            let err = err.to_opaque(context);
+                            capability
+                                .reject()
+                                .call(&JsValue::undefined(), &[err], context)?;
+                        }
+                    }
+
+                    // f. Return promiseCapability.[[Promise]].
+                    Ok(capability.promise().clone().into())
+                },
+                method,
+            ),
+        )
+        .name("")
+        .length(0)
+        .build();
+
+        return Ok(Some(closure.into()));
+    }
+
+    // 2. Else,
+    //     a. Let method be ? GetMethod(V, @@dispose).
+    // 3. Return method.
+    value.get_method(JsSymbol::dispose(), context)
+}
+
+/// Checks if `error` must bypass disposal and be propagated to the caller untouched.
+fn is_uncatchable(error: &JsError) -> bool {
+    error
+        .as_native()
+        .map_or(false, JsNativeError::is_runtime_limit)
+}
+
+/// Merges the error thrown by a dispose method into the current completion, as in the steps
+/// 3.e.iii of [`DisposeResources`][spec].
+///
+/// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposeresources
+fn suppress(error: JsError, completion: Option<JsError>) -> JsError {
+    match completion {
+        // 1. If completion is a throw completion, then
+        //     a. Set result to result.[[Value]].
+        //     b. Let suppressed be completion.[[Value]].
+        //     c. Let error be a newly created SuppressedError object.
+        //     d. Perform CreateNonEnumerableDataPropertyOrThrow(error, "error", result).
+        //     e. Perform CreateNonEnumerableDataPropertyOrThrow(error, "suppressed", suppressed).
+        //     f. Set completion to ThrowCompletion(error).
+        Some(suppressed) => JsNativeError::suppressed(error, suppressed).into(),
+        // 2. Else,
+        //     a. Set completion to result.
+        None => error,
+    }
+}
+
+/// A `DisposeCapability` Record.
+///
+/// More information:
+///  - [Explicit Resource Management proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposecapability-records
+#[derive(Debug, Clone, Default, Trace, Finalize)]
+pub(crate) struct DisposeCapability {
+    /// The `[[DisposableResourceStack]]` field.
+    stack: Vec<DisposableResource>,
+}
+
+impl DisposeCapability {
+    /// Appends `resource` to the `[[DisposableResourceStack]]` of this capability, as in the
+    /// last steps of [`AddDisposableResource`][spec].
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-adddisposableresource
+    pub(crate) fn push(&mut self, resource: DisposableResource) {
+        // 3. Append resource to disposeCapability.[[DisposableResourceStack]].
+        // 4. Return unused.
+        self.stack.push(resource);
+    }
+
+    /// Abstract operation [`DisposeResources ( disposeCapability, completion )`][spec] for
+    /// capabilities that only hold resources with the `sync-dispose` hint.
+    ///
+    /// `completion` is the error of a pending throw completion, if any.
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposeresources
+    pub(crate) fn dispose_resources(
+        mut self,
+        mut completion: Option<JsError>,
+        context: &mut Context<'_>,
+    ) -> JsResult<()> {
+        // 1. For each resource of disposeCapability.[[DisposableResourceStack]], in reverse list order, do
+        for resource in std::mem::take(&mut self.stack).into_iter().rev() {
+            debug_assert_eq!(resource.hint, DisposeHint::Sync);
+
+            // a. Let result be Dispose(resource.[[ResourceValue]], resource.[[Hint]], resource.[[DisposeMethod]]).
+            // b. If result.[[Type]] is throw, then
+            if let Err(err) = resource.dispose(context) {
+                if is_uncatchable(&err) {
+                    return Err(err);
+                }
+                completion = Some(suppress(err, completion));
+            }
+        }
+
+        // 2. NOTE: After disposeCapability has been disposed, it will never be used again. The contents of
+        //    disposeCapability.[[DisposableResourceStack]] can be discarded in implementations, such as by
+        //    garbage collection, at this point.
+        // 3. Set disposeCapability.[[DisposableResourceStack]] to a new empty List.
+        // 4. Return completion.
+        completion.map_or(Ok(()), Err)
+    }
+
+    /// Abstract operation [`DisposeResources ( disposeCapability, completion )`][spec] for
+    /// capabilities that may hold resources with the `async-dispose` hint.
+    ///
+    /// Since disposing these resources has to await the result of their dispose methods, this
+    /// returns a promise that settles with the final completion of the disposal.
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposeresources
+    pub(crate) fn dispose_resources_async(
+        mut self,
+        completion: Option<JsError>,
+        context: &mut Context<'_>,
+    ) -> JsResult<JsObject> {
+        let capability = PromiseCapability::new(
+            &context.intrinsics().constructors().promise().constructor(),
+            context,
+        )
+        .expect("cannot fail with the %Promise% constructor");
+
+        let promise = capability.promise().clone();
+        continue_async_disposal(
+            std::mem::take(&mut self.stack),
+            completion,
+            capability,
+            context,
+        )?;
+        Ok(promise)
+    }
+}
+
+/// The state of an asynchronous disposal waiting on the result of a dispose method.
+#[derive(Trace, Finalize)]
+struct AsyncDisposal {
+    stack: Vec<DisposableResource>,
+    completion: Option<JsError>,
+    capability: PromiseCapability,
+}
+
+/// Disposes the remaining resources of `stack`, continuing in a promise reaction every time the
+/// result of a dispose method must be awaited, and settles `capability` when done.
+fn continue_async_disposal(
+    mut stack: Vec<DisposableResource>,
+    mut completion: Option<JsError>,
+    capability: PromiseCapability,
+    context: &mut Context<'_>,
+) -> JsResult<()> {
+    // 1. For each resource of disposeCapability.[[DisposableResourceStack]], in reverse list order, do
+    while let Some(resource) = stack.pop() {
+        // a. Let result be Dispose(resource.[[ResourceValue]], resource.[[Hint]], resource.[[DisposeMethod]]).
+        let result = resource.dispose(context);
+
+        // Dispose ( V, hint, method )
+        // 3. If hint is async-dispose, then
+        //     a. Perform ? Await(result).
+        let result = match result {
+            Ok(value) if resource.hint == DisposeHint::Async => Promise::promise_resolve(
+                &context.intrinsics().constructors().promise().constructor(),
+                value,
+                context,
+            ),
+            Ok(_) => continue,
+            Err(err) => Err(err),
+        };
+
+        match result {
+            Ok(promise) => {
+                let on_fulfilled = FunctionObjectBuilder::new(
+                    context,
+                    NativeFunction::from_copy_closure_with_captures(
+                        |_, _, disposal: &AsyncDisposal, context| {
+                            continue_async_disposal(
+                                disposal.stack.clone(),
+                                disposal.completion.clone(),
+                                disposal.capability.clone(),
+                                context,
+                            )?;
+                            Ok(JsValue::undefined())
+                        },
+                        AsyncDisposal {
+                            stack: stack.clone(),
+                            completion: completion.clone(),
+                            capability: capability.clone(),
+                        },
+                    ),
+                )
+                .name("")
+                .length(1)
+                .build();
+
+                let on_rejected = FunctionObjectBuilder::new(
+                    context,
+                    NativeFunction::from_copy_closure_with_captures(
+                        |_, args, disposal: &AsyncDisposal, context| {
+                            let err =
+                                JsError::from_opaque(args.first().cloned().unwrap_or_default());
+                            continue_async_disposal(
+                                disposal.stack.clone(),
+                                Some(suppress(err, disposal.completion.clone())),
+                                disposal.capability.clone(),
+                                context,
+                            )?;
+                            Ok(JsValue::undefined())
+                        },
+                        AsyncDisposal {
+                            stack,
+                            completion,
+                            capability,
+                        },
+                    ),
+                )
+                .name("")
+                .length(1)
+                .build();
+
+                Promise::perform_promise_then(
+                    &promise,
+                    Some(on_fulfilled),
+                    Some(on_rejected),
+                    None,
+                    context,
+                );
+
+                return Ok(());
+            }
+            // b. If result.[[Type]] is throw, then
+            Err(err) => {
+                if is_uncatchable(&err) {
+                    return Err(err);
+                }
+                completion = Some(suppress(err, completion));
+            }
+        }
+    }
+
+    // 4. Return completion.
+    match completion {
+        Some(err) => {
+            let err = err.to_opaque(context);
+            capability
+                .reject()
+                .call(&JsValue::undefined(), &[err], context)?;
+        }
+        None => {
+            capability
+                .resolve()
+                .call(&JsValue::undefined(), &[], context)?;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,159 @@
+use crate::{run_test_actions, JsNativeErrorKind, TestAction};
+use indoc::indoc;
+
+#[test]
+fn using_declaration() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var log = [];
+            function resource(name) {
+                return { [Symbol.dispose]() { log.push(name); } };
+            }
+            {
+                using a = resource("a"), b = resource("b");
+                log.push("body");
+                using c = null;
+                using d = resource("d");
+            }
+        "#}),
+        TestAction::assert_eq("log.join()", "body,d,b,a"),
+    ]);
+}
+
+#[test]
+fn using_declaration_abrupt_completions() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var log = [];
+            function resource(name) {
+                return { [Symbol.dispose]() { log.push(name); } };
+            }
+            function f() {
+                using a = resource("return");
+                return 1;
+            }
+            f();
+            for (const name of ["break", "continue"]) {
+                using b = resource(name);
+                if (name === "break") { continue; }
+                break;
+            }
+            try {
+                using c = resource("throw");
+                throw new Error();
+            } catch {}
+        "#}),
+        TestAction::assert_eq("log.join()", "return,break,continue,throw"),
+    ]);
+}
+
+#[test]
+fn using_declaration_suppressed_error() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var error;
+            try {
+                using a = { [Symbol.dispose]() { throw "a"; } };
+                using b = { [Symbol.dispose]() { throw "b"; } };
+                throw "body";
+            } catch (e) {
+                error = e;
+            }
+        "#}),
+        TestAction::assert("error instanceof SuppressedError"),
+        TestAction::assert_eq("error.error", "a"),
+        TestAction::assert("error.suppressed instanceof SuppressedError"),
+        TestAction::assert_eq("error.suppressed.error", "b"),
+        TestAction::assert_eq("error.suppressed.suppressed", "body"),
+    ]);
+}
+
+#[test]
+fn using_declaration_not_disposable() {
+    run_test_actions([TestAction::assert_native_error(
+        "{ using a = {}; }",
+        JsNativeErrorKind::Type,
+        "disposable resource must have a `Symbol.dispose` method",
+    )]);
+}
+
+#[test]
+fn using_for_of() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var log = [];
+            function resource(name) {
+                return { name, [Symbol.dispose]() { log.push(name); } };
+            }
+            for (using r of [resource("a"), resource("b")]) {
+                log.push(r.name);
+            }
+        "#}),
+        TestAction::assert_eq("log.join()", "a,a,b,b"),
+    ]);
+}
+
+#[test]
+fn await_using_declaration() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var log = [];
+            async function f() {
+                await using a = {
+                    async [Symbol.asyncDispose]() { log.push("a"); }
+                };
+                await using b = { [Symbol.dispose]() { log.push("b"); } };
+                log.push("body");
+            }
+            f().then(() => log.push("done"));
+        "#}),
+        #[allow(clippy::redundant_closure_for_method_calls)]
+        TestAction::inspect_context(|ctx| ctx.run_jobs()),
+        TestAction::assert_eq("log.join()", "body,b,a,done"),
+    ]);
+}
+
+#[test]
+fn disposable_stack() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var log = [];
+            var stack = new DisposableStack();
+            stack.defer(() => log.push("defer"));
+            var value = stack.adopt("adopt", (v) => log.push(v));
+            stack.use({ [Symbol.dispose]() { log.push("use"); } });
+            var moved = stack.move();
+        "#}),
+        TestAction::assert_eq("value", "adopt"),
+        TestAction::assert("stack.disposed"),
+        TestAction::assert("!moved.disposed"),
+        TestAction::assert_native_error(
+            "stack.defer(() => {})",
+            JsNativeErrorKind::Reference,
+            "DisposableStack.prototype.defer: the stack has already been disposed",
+        ),
+        TestAction::run("moved[Symbol.dispose]()"),
+        TestAction::assert_eq("log.join()", "use,adopt,defer"),
+        TestAction::assert("moved.disposed"),
+        TestAction::assert(
+            "DisposableStack.prototype.dispose === DisposableStack.prototype[Symbol.dispose]",
+        ),
+    ]);
+}
+
+#[test]
+fn async_disposable_stack() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var log = [];
+            var stack = new AsyncDisposableStack();
+            stack.defer(async () => log.push("defer"));
+            stack.use({ [Symbol.dispose]() { log.push("use"); } });
+            stack.disposeAsync().then(() => log.push("done"));
+        "#}),
+        #[allow(clippy::redundant_closure_for_method_calls)]
+        TestAction::inspect_context(|ctx| ctx.run_jobs()),
+        TestAction::assert_eq("log.join()", "use,defer,done"),
+        TestAction::assert("stack.disposed"),
+    ]);
+}
@@ -86,7 +86,7 @@ impl BuiltInConstructor for AggregateError {
         let o = JsObject::from_proto_and_data_with_shared_shape(
             context.root_shape(),
             prototype,
-            ObjectData::error(ErrorKind::Aggregate),
+            ObjectData::error(ErrorKind::Aggregate, Error::capture_stack_frames(context)),
         );
 
         // 3. If message is not undefined, then
@@ -85,7 +85,7 @@ impl BuiltInConstructor for EvalError {
         let o = JsObject::from_proto_and_data_with_shared_shape(
             context.root_shape(),
             prototype,
-            ObjectData::error(ErrorKind::Eval),
+            ObjectData::error(ErrorKind::Eval, Error::capture_stack_frames(context)),
         );
 
         // 3. If message is not undefined, then
@@ -15,18 +15,23 @@ use crate::{
     context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
     error::JsNativeError,
     js_string,
-    object::{internal_methods::get_prototype_from_constructor, JsObject, ObjectData},
-    property::Attribute,
+    object::{internal_methods::get_prototype_from_constructor, JsObject, ObjectData, ObjectKind},
+    property::{Attribute, PropertyDescriptor},
     realm::Realm,
     string::utf16,
-    Context, JsArgs, JsResult, JsValue,
+    vm::CodeBlock,
+    Context, JsArgs, JsResult, JsString, JsValue,
 };
+use boa_gc::{Finalize, Gc, Trace};
+use boa_interner::Sym;
 use boa_profiler::Profiler;
+use std::{borrow::Cow, fmt::Write, path::Path};
 
 pub(crate) mod aggregate;
 pub(crate) mod eval;
 pub(crate) mod range;
 pub(crate) mod reference;
+pub(crate) mod suppressed;
 pub(crate) mod syntax;
 pub(crate) mod r#type;
 pub(crate) mod uri;
@@ -39,11 +44,29 @@ pub(crate) use self::eval::EvalError;
 pub(crate) use self::r#type::TypeError;
 pub(crate) use self::range::RangeError;
 pub(crate) use self::reference::ReferenceError;
+pub(crate) use self::suppressed::SuppressedError;
 pub(crate) use self::syntax::SyntaxError;
 pub(crate) use self::uri::UriError;
 
 use super::{BuiltInBuilder, BuiltInConstructor, IntrinsicObject};
 
+/// A frame of the call stack, captured when an error is created or a native error is raised so
+/// that its `stack` property can be rendered when it is read.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct StackFrame {
+    code_block: Gc<CodeBlock>,
+    #[unsafe_ignore_trace]
+    pc: u32,
+}
+
+impl PartialEq for StackFrame {
+    fn eq(&self, other: &Self) -> bool {
+        Gc::ptr_eq(&self.code_block, &other.code_block) && self.pc == other.pc
+    }
+}
+
+impl Eq for StackFrame {}
+
 /// The kind of a `NativeError` object, per the [ECMAScript spec][spec].
 ///
 /// This is used internally to convert between [`JsObject`] and
@@ -121,6 +144,14 @@ pub enum ErrorKind {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-native-error-types-used-in-this-standard-urierror
     Uri,
+
+    /// The `SuppressedError` type.
+    ///
+    /// More information:
+    ///  - [Explicit Resource Management proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-suppressederror-objects
+    Suppressed,
 }
 
 /// Built-in `Error` object.
@@ -132,10 +163,23 @@ impl IntrinsicObject for Error {
         let _timer = Profiler::global().start_event(Self::NAME, "init");
 
         let attribute = Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE;
+        let get_stack = BuiltInBuilder::callable(realm, Self::get_stack)
+            .name("get stack")
+            .build();
+        let set_stack = BuiltInBuilder::callable(realm, Self::set_stack)
+            .name("set stack")
+            .length(1)
+            .build();
         BuiltInBuilder::from_standard_constructor::<Self>(realm)
             .property(utf16!("name"), Self::NAME, attribute)
             .property(utf16!("message"), "", attribute)
             .method(Self::to_string, "toString", 0)
+            .accessor(
+                utf16!("stack"),
+                Some(get_stack),
+                Some(set_stack),
+                Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
             .build();
     }
 
@@ -180,7 +224,7 @@ impl BuiltInConstructor for Error {
         let o = JsObject::from_proto_and_data_with_shared_shape(
             context.root_shape(),
             prototype,
-            ObjectData::error(ErrorKind::Error),
+            ObjectData::error(ErrorKind::Error, Self::capture_stack_frames(context)),
         );
 
         // 3. If message is not undefined, then
@@ -222,6 +266,95 @@ impl Error {
         Ok(())
     }
 
+    /// Captures the frames of the currently executing call stack, to be rendered later with
+    /// [`Self::render_stack_frames`].
+    pub(crate) fn capture_stack_frames(context: &Context<'_>) -> Box<[StackFrame]> {
+        context
+            .stack_trace()
+            .map(|frame| StackFrame {
+                code_block: frame.code_block().clone(),
+                pc: frame.pc,
+            })
+            .collect()
+    }
+
+    /// Renders the captured `stack` frames, one `at` line per frame.
+    pub(crate) fn render_stack_frames(stack: &[StackFrame], context: &Context<'_>) -> String {
+        let mut frames = String::new();
+        for frame in stack {
+            let code_block = &frame.code_block;
+            let path = code_block
+                .source_path()
+                .map_or(Cow::Borrowed("<anonymous>"), Path::to_string_lossy);
+            let location = match code_block.position(frame.pc) {
+                Some(position) => format!("{path}:{position}"),
+                None => path.into_owned(),
+            };
+            let name = code_block.name();
+            if name == Sym::MAIN || name == Sym::EMPTY_STRING {
+                let _ = write!(frames, "\n    at {location}");
+            } else {
+                let name = context.interner().resolve_expect(name);
+                let _ = write!(frames, "\n    at {name} ({location})");
+            }
+        }
+        frames
+    }
+
+    /// `get Error.prototype.stack`
+    ///
+    /// Non-standard accessor that renders the call stack captured when the error was created, in
+    /// the same format as V8: a `name: message` header, built like `Error.prototype.toString`,
+    /// followed by one `at` line per frame.
+    ///
+    /// The stack is rendered every time it is read, so creating an error doesn't run the getters
+    /// of its `name` and `message` properties.
+    pub(crate) fn get_stack(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        let o = this
+            .as_object()
+            .ok_or_else(|| JsNativeError::typ().with_message("'this' is not an Object"))?;
+
+        // Objects without an [[ErrorData]] internal slot don't have a stack.
+        let frames = match o.borrow().kind() {
+            ObjectKind::Error(_, stack) => Self::render_stack_frames(stack, context),
+            _ => return Ok(JsValue::undefined()),
+        };
+
+        let header = Self::to_string(this, &[], context)?.to_string(context)?;
+
+        Ok(js_string!(&header, &JsString::from(frames)).into())
+    }
+
+    /// `set Error.prototype.stack`
+    ///
+    /// Non-standard accessor that shadows the rendered stack with an own `stack` data property of
+    /// the `this` object.
+    pub(crate) fn set_stack(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        let o = this
+            .as_object()
+            .ok_or_else(|| JsNativeError::typ().with_message("'this' is not an Object"))?;
+
+        o.define_property_or_throw(
+            utf16!("stack"),
+            PropertyDescriptor::builder()
+                .value(args.get_or_undefined(0).clone())
+                .writable(true)
+                .enumerable(false)
+                .configurable(true),
+            context,
+        )?;
+
+        Ok(JsValue::undefined())
+    }
+
     /// `Error.prototype.toString()`
     ///
     /// The toString() method returns a string representing the specified Error object.
@@ -83,7 +83,7 @@ impl BuiltInConstructor for RangeError {
         let o = JsObject::from_proto_and_data_with_shared_shape(
             context.root_shape(),
             prototype,
-            ObjectData::error(ErrorKind::Range),
+            ObjectData::error(ErrorKind::Range, Error::capture_stack_frames(context)),
         );
 
         // 3. If message is not undefined, then
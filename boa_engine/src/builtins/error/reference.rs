@@ -85,7 +85,7 @@ impl BuiltInConstructor for ReferenceError {
         let o = JsObject::from_proto_and_data_with_shared_shape(
             context.root_shape(),
             prototype,
-            ObjectData::error(ErrorKind::Reference),
+            ObjectData::error(ErrorKind::Reference, Error::capture_stack_frames(context)),
         );
 
         // 3. If message is not undefined, then
@@ -88,7 +88,7 @@ impl BuiltInConstructor for SyntaxError {
         let o = JsObject::from_proto_and_data_with_shared_shape(
             context.root_shape(),
             prototype,
-            ObjectData::error(ErrorKind::Syntax),
+            ObjectData::error(ErrorKind::Syntax, Error::capture_stack_frames(context)),
         );
 
         // 3. If message is not undefined, then
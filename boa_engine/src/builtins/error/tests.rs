@@ -1,4 +1,4 @@
-use crate::{run_test_actions, TestAction};
+use crate::{run_test_actions, JsValue, TestAction};
 use indoc::indoc;
 
 #[test]
@@ -42,6 +42,19 @@ fn error_names() {
         TestAction::assert_eq("URIError.name", "URIError"),
         TestAction::assert_eq("TypeError.name", "TypeError"),
         TestAction::assert_eq("AggregateError.name", "AggregateError"),
+        TestAction::assert_eq("SuppressedError.name", "SuppressedError"),
+    ]);
+}
+
+#[test]
+fn suppressed_error() {
+    run_test_actions([
+        TestAction::run("var e = new SuppressedError(1, 2, 'message');"),
+        TestAction::assert_eq("e.toString()", "SuppressedError: message"),
+        TestAction::assert_eq("e.error", 1),
+        TestAction::assert_eq("e.suppressed", 2),
+        TestAction::assert("!Object.getOwnPropertyDescriptor(e, 'error').enumerable"),
+        TestAction::assert("Object.getPrototypeOf(SuppressedError) === Error"),
     ]);
 }
 
@@ -56,5 +69,110 @@ fn error_lengths() {
         TestAction::assert_eq("URIError.length", 1),
         TestAction::assert_eq("TypeError.length", 1),
         TestAction::assert_eq("AggregateError.length", 2),
+        TestAction::assert_eq("SuppressedError.length", 3),
+    ]);
+}
+
+#[test]
+fn error_stack() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            function thrower() {
+                throw new TypeError("custom");
+            }
+            function caller() {
+                thrower();
+            }
+            var error;
+            try { caller(); } catch (e) { error = e; }
+        "#}),
+        TestAction::assert_eq(
+            "error.stack",
+            "TypeError: custom\n    at thrower (<anonymous>:2:11)\n    at caller (<anonymous>:5:5)\n    at <anonymous>:8:7",
+        ),
+        TestAction::assert_eq("new Error().stack", "Error\n    at <anonymous>:1:1"),
+    ]);
+}
+
+#[test]
+fn error_stack_accessor() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var calls = 0;
+            class Counted extends Error {
+                get name() {
+                    calls++;
+                    return "Counted";
+                }
+            }
+            var counted = new Counted("lazy");
+            var native;
+            try { null.property; } catch (e) { native = e; }
+        "#}),
+        TestAction::assert_eq("calls", 0),
+        TestAction::assert_eq("counted.stack.split('\\n')[0]", "Counted: lazy"),
+        TestAction::assert_eq("calls", 1),
+        TestAction::assert("!Object.hasOwn(counted, 'stack')"),
+        TestAction::assert("!Object.hasOwn(native, 'stack')"),
+        TestAction::assert_eq("typeof native.stack", "string"),
+        TestAction::assert_eq("Error.prototype.stack", JsValue::undefined()),
+        TestAction::run("counted.stack = 'replaced';"),
+        TestAction::assert_eq("counted.stack", "replaced"),
+        TestAction::assert("!Object.getOwnPropertyDescriptor(counted, 'stack').enumerable"),
+    ]);
+}
+
+#[test]
+fn error_stack_header() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            class ValidationError extends Error {}
+            ValidationError.prototype.name = "ValidationError";
+            var subclassed = new ValidationError("invalid");
+
+            function Renamed() {}
+            Renamed.prototype = Object.create(TypeError.prototype, { name: { value: "Renamed" } });
+            var renamed = Reflect.construct(TypeError, ["oops"], Renamed);
+        "#}),
+        TestAction::assert_eq(
+            "subclassed.stack.split('\\n')[0]",
+            "ValidationError: invalid",
+        ),
+        TestAction::assert_eq("renamed.stack.split('\\n')[0]", "Renamed: oops"),
+    ]);
+}
+
+#[test]
+fn native_error_stack() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            function inner() {
+                return null.property;
+            }
+            function outer() {
+                return [1].map(inner);
+            }
+            var error;
+            try { outer(); } catch (e) { error = e; }
+        "#}),
+        TestAction::assert_eq(
+            "error.stack",
+            "TypeError: cannot convert 'null' or 'undefined' to object\n    at inner (<anonymous>:2:12)\n    at outer (<anonymous>:5:12)\n    at <anonymous>:8:7",
+        ),
+    ]);
+}
+
+#[test]
+fn native_error_stack_operators() {
+    run_test_actions([
+        TestAction::run(indoc! {r"
+            function add(a) {
+                let b = 1;
+                return b + a;
+            }
+            var error;
+            try { add(1n); } catch (e) { error = e; }
+        "}),
+        TestAction::assert("error.stack.split('\\n')[1] === '    at add (<anonymous>:3:12)'"),
     ]);
 }
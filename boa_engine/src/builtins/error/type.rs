@@ -93,7 +93,7 @@ impl BuiltInConstructor for TypeError {
         let o = JsObject::from_proto_and_data_with_shared_shape(
             context.root_shape(),
             prototype,
-            ObjectData::error(ErrorKind::Type),
+            ObjectData::error(ErrorKind::Type, Error::capture_stack_frames(context)),
         );
 
         // 3. If message is not undefined, then
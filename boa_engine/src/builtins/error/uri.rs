@@ -84,7 +84,7 @@ impl BuiltInConstructor for UriError {
         let o = JsObject::from_proto_and_data_with_shared_shape(
             context.root_shape(),
             prototype,
-            ObjectData::error(ErrorKind::Uri),
+            ObjectData::error(ErrorKind::Uri, Error::capture_stack_frames(context)),
         );
 
         // 3. If message is not undefined, then
@@ -0,0 +1,872 @@
+//! Boa's implementation of the runtime semantics of class decorators.
+//!
+//! Decorators are applied while evaluating a class definition, after all of the class elements
+//! have been evaluated but before any static field or static block runs.
+//!
+//! More information:
+//!  - [Decorators proposal][proposal]
+//!
+//! [proposal]: https://tc39.es/proposal-decorators/
+
+use crate::{
+    builtins::function::{set_function_name, ClassStaticElement},
+    error::JsNativeError,
+    js_string,
+    native_function::NativeFunction,
+    object::{FunctionObjectBuilder, JsFunction, JsObject, PrivateElement, PrivateName, PROTOTYPE},
+    property::{PropertyDescriptor, PropertyKey},
+    Context, JsArgs, JsResult, JsString, JsValue,
+};
+use boa_gc::{empty_trace, Finalize, Gc, GcRefCell, Trace};
+use std::{cell::Cell, rc::Rc};
+
+/// The kind of a decorated class element.
+///
+/// This is encoded as the first operand of the `PushClassDecoratedElement` and
+/// `PushClassDecoratedPrivateElement` opcodes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub(crate) enum DecoratedElementKind {
+    /// A method.
+    Method = 0,
+
+    /// A getter.
+    Getter,
+
+    /// A setter.
+    Setter,
+
+    /// A field.
+    Field,
+
+    /// An auto-accessor, declared with the `accessor` keyword.
+    Accessor,
+}
+
+impl DecoratedElementKind {
+    /// Decodes the kind from an opcode operand.
+    pub(crate) fn from_u8(kind: u8) -> Self {
+        match kind {
+            0 => Self::Method,
+            1 => Self::Getter,
+            2 => Self::Setter,
+            3 => Self::Field,
+            4 => Self::Accessor,
+            _ => unreachable!("invalid decorated element kind: {kind}"),
+        }
+    }
+
+    /// Returns the value of the `kind` property of the decorator context object.
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Method => "method",
+            Self::Getter => "getter",
+            Self::Setter => "setter",
+            Self::Field => "field",
+            Self::Accessor => "accessor",
+        }
+    }
+}
+
+/// The name of a decorated class element.
+#[derive(Debug, Clone, Finalize)]
+pub(crate) enum ElementKey {
+    /// A string or symbol name.
+    Public(PropertyKey),
+
+    /// A private name.
+    Private(PrivateName),
+}
+
+// SAFETY: Neither property keys nor private names contain any traceable types.
+unsafe impl Trace for ElementKey {
+    empty_trace!();
+}
+
+/// The value of a decorated class element.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub(crate) enum ElementValue {
+    /// A method, together with its function object.
+    Method(JsObject),
+
+    /// A getter, together with its function object.
+    Getter(JsObject),
+
+    /// A setter, together with its function object.
+    Setter(JsObject),
+
+    /// A field, together with the index of its definition in the `[[Fields]]` of the class, or
+    /// in its static elements if the field is static.
+    Field(usize),
+
+    /// An auto-accessor, together with its getter, its setter and the index of the definition
+    /// of its backing storage field.
+    Accessor {
+        getter: JsObject,
+        setter: JsObject,
+        storage: usize,
+    },
+}
+
+impl ElementValue {
+    /// Returns the kind of this element.
+    const fn kind(&self) -> DecoratedElementKind {
+        match self {
+            Self::Method(_) => DecoratedElementKind::Method,
+            Self::Getter(_) => DecoratedElementKind::Getter,
+            Self::Setter(_) => DecoratedElementKind::Setter,
+            Self::Field(_) => DecoratedElementKind::Field,
+            Self::Accessor { .. } => DecoratedElementKind::Accessor,
+        }
+    }
+}
+
+/// A class element with its decorators, pending to be applied.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub(crate) struct DecoratedElement {
+    is_static: bool,
+    key: ElementKey,
+    value: ElementValue,
+    decorators: Vec<JsValue>,
+}
+
+impl DecoratedElement {
+    /// Creates a new `DecoratedElement`.
+    pub(crate) fn new(
+        is_static: bool,
+        key: ElementKey,
+        value: ElementValue,
+        decorators: Vec<JsValue>,
+    ) -> Self {
+        Self {
+            is_static,
+            key,
+            value,
+            decorators,
+        }
+    }
+}
+
+/// The decorator related state of a class constructor.
+#[derive(Debug, Default, Trace, Finalize)]
+pub(crate) struct ClassDecorators {
+    /// The decorated elements, pending to be applied until the class is initialized.
+    pub(crate) elements: Vec<DecoratedElement>,
+
+    /// The extra initializers added by the decorators of non-static methods and accessors,
+    /// which run when an instance of the class is initialized.
+    pub(crate) instance_initializers: Vec<JsObject>,
+
+    /// The value of the class binding, if a class decorator replaced the class.
+    pub(crate) class_binding: Option<JsObject>,
+}
+
+type Initializers = Gc<GcRefCell<Vec<JsObject>>>;
+
+/// Records a decorated element in the class, which gets applied on `initialize_class`.
+pub(crate) fn push_decorated_element(class: &JsObject, element: DecoratedElement) {
+    class
+        .borrow_mut()
+        .as_function_mut()
+        .expect("class must be function object")
+        .decorators_mut()
+        .expect("class must be an ordinary function")
+        .elements
+        .push(element);
+}
+
+/// Finishes the definition of a class, applying all of its decorators and evaluating its static
+/// elements.
+///
+/// `class_decorators` is either `undefined` or an array with the decorators of the class itself.
+/// Returns the final value of the class binding.
+pub(crate) fn initialize_class(
+    class: &JsObject,
+    class_decorators: &JsValue,
+    context: &mut Context<'_>,
+) -> JsResult<JsObject> {
+    let static_initializers = Initializers::default();
+    let instance_initializers = Initializers::default();
+
+    let elements = {
+        let mut class = class.borrow_mut();
+        let function = class
+            .as_function_mut()
+            .expect("class must be function object");
+        if function.decorators().is_some() {
+            function
+                .decorators_mut()
+                .map(|decorators| std::mem::take(&mut decorators.elements))
+                .unwrap_or_default()
+        } else {
+            Vec::new()
+        }
+    };
+
+    if !elements.is_empty() {
+        let proto = class
+            .get(PROTOTYPE, context)?
+            .as_object()
+            .expect("class prototype must be an object")
+            .clone();
+
+        // Decorators are applied to the static methods and accessors first, then to the
+        // non-static methods and accessors, then to the static fields and finally to the
+        // non-static fields.
+        let mut elements = elements;
+        elements.sort_by_key(|element| {
+            (
+                matches!(element.value, ElementValue::Field(_)),
+                !element.is_static,
+            )
+        });
+
+        for element in elements {
+            let (target, initializers) = if element.is_static {
+                (class, &static_initializers)
+            } else {
+                (&proto, &instance_initializers)
+            };
+            apply_decorators_to_element(class, target, &element, initializers, context)?;
+        }
+    }
+
+    let instance_initializers = instance_initializers.borrow().clone();
+    if !instance_initializers.is_empty() {
+        class
+            .borrow_mut()
+            .as_function_mut()
+            .expect("class must be function object")
+            .decorators_mut()
+            .expect("class must be an ordinary function")
+            .instance_initializers = instance_initializers;
+    }
+
+    let class_initializers = Initializers::default();
+    let new_class =
+        apply_decorators_to_class(class, class_decorators, &class_initializers, context)?;
+    if &new_class != class {
+        class
+            .borrow_mut()
+            .as_function_mut()
+            .expect("class must be function object")
+            .decorators_mut()
+            .expect("class must be an ordinary function")
+            .class_binding = Some(new_class.clone());
+    }
+
+    let static_initializers = static_initializers.borrow().clone();
+    for initializer in static_initializers {
+        initializer.call(&class.clone().into(), &[], context)?;
+    }
+
+    let static_elements = class
+        .borrow_mut()
+        .as_function_mut()
+        .expect("class must be function object")
+        .static_elements_mut()
+        .map(std::mem::take)
+        .unwrap_or_default();
+    for element in &static_elements {
+        match element {
+            ClassStaticElement::Field(field) => class.define_field(field, context)?,
+            ClassStaticElement::Block(block) => {
+                block.call(&class.clone().into(), &[], context)?;
+            }
+        }
+    }
+
+    let class_initializers = class_initializers.borrow().clone();
+    for initializer in class_initializers {
+        initializer.call(&new_class.clone().into(), &[], context)?;
+    }
+
+    Ok(new_class)
+}
+
+/// Abstract operation `ApplyDecoratorsToClassDefinition ( classDef )`
+///
+/// More information:
+///  - [Decorators proposal][proposal]
+///
+/// [proposal]: https://tc39.es/proposal-decorators/#sec-applydecoratorstoclassdefinition
+fn apply_decorators_to_class(
+    class: &JsObject,
+    decorators: &JsValue,
+    initializers: &Initializers,
+    context: &mut Context<'_>,
+) -> JsResult<JsObject> {
+    let Some(decorators) = decorators.as_object() else {
+        return Ok(class.clone());
+    };
+
+    let name = class
+        .borrow()
+        .as_function()
+        .and_then(|function| function.codeblock().map(|code| code.name))
+        .filter(|name| *name != boa_interner::Sym::EMPTY_STRING)
+        .map_or_else(JsValue::undefined, |name| {
+            context
+                .interner()
+                .resolve_expect(name)
+                .into_common::<JsString>(false)
+                .into()
+        });
+
+    let mut class = class.clone();
+
+    // 1. For each element decoratorRecord of classDef.[[Decorators]], in reverse list order, do
+    let length = decorators.length_of_array_like(context)?;
+    for index in (0..length).rev() {
+        let decorator = decorators.get(index, context)?;
+
+        // a. Let decorator be decoratorRecord.[[Decorator]].
+        // b. Let decorationState be the Record { [[Finished]]: false }.
+        let finished = Rc::new(Cell::new(false));
+
+        // c. Let context be CreateDecoratorContextObject(class, className, extraInitializers, decorationState).
+        let decorator_context = create_decorator_context_object(
+            "class",
+            name.clone(),
+            None,
+            None,
+            initializers,
+            &finished,
+            context,
+        );
+
+        // d. Let newDef be ? Call(decorator, undefined, « classDef.[[Class]], context »).
+        let result = decorator.call(
+            &JsValue::undefined(),
+            &[class.clone().into(), decorator_context.into()],
+            context,
+        );
+
+        // e. Set decorationState.[[Finished]] to true.
+        finished.set(true);
+
+        // f. If IsCallable(newDef) is true, then
+        //     i. Set classDef.[[Class]] to newDef.
+        // g. Else if newDef is not undefined, then
+        //     i. Throw a TypeError exception.
+        match result? {
+            JsValue::Undefined => {}
+            value => {
+                class = value.as_callable().cloned().ok_or_else(|| {
+                    JsNativeError::typ().with_message("class decorators must return a function")
+                })?;
+            }
+        }
+    }
+
+    // 2. Return unused.
+    Ok(class)
+}
+
+/// Abstract operation `ApplyDecoratorsToElementDefinition ( homeObject, elementRecord, extraInitializers, isStatic )`
+///
+/// Also defines the resulting element in its home object.
+///
+/// More information:
+///  - [Decorators proposal][proposal]
+///
+/// [proposal]: https://tc39.es/proposal-decorators/#sec-applydecoratorstoelementdefinition
+fn apply_decorators_to_element(
+    class: &JsObject,
+    home_object: &JsObject,
+    element: &DecoratedElement,
+    initializers: &Initializers,
+    context: &mut Context<'_>,
+) -> JsResult<()> {
+    let is_static = element.is_static;
+    let key = element.key.clone();
+    let mut value = element.value.clone();
+
+    let kind = value.kind();
+    let name: JsValue = match &key {
+        ElementKey::Public(key) => key.into(),
+        ElementKey::Private(name) => private_description(*name, context).into(),
+    };
+
+    // 1. For each element decorator of elementRecord.[[Decorators]], in reverse list order, do
+    for decorator in element.decorators.iter().rev() {
+        // a. Let decorationState be the Record { [[Finished]]: false }.
+        let finished = Rc::new(Cell::new(false));
+
+        // b. Let context be CreateDecoratorContextObject(elementRecord.[[Kind]], elementRecord.[[Key]], extraInitializers, decorationState, isStatic).
+        let access = create_decorator_access_object(kind, &key, context);
+        let decorator_context = create_decorator_context_object(
+            kind.name(),
+            name.clone(),
+            Some((is_static, matches!(key, ElementKey::Private(_)))),
+            Some(access),
+            initializers,
+            &finished,
+            context,
+        );
+
+        // c. Let value be ? DecorateElement.
+        let current: JsValue = match &value {
+            ElementValue::Method(function)
+            | ElementValue::Getter(function)
+            | ElementValue::Setter(function) => function.clone().into(),
+            ElementValue::Field(_) => JsValue::undefined(),
+            ElementValue::Accessor { getter, setter, .. } => {
+                let object = JsObject::with_object_proto(context.intrinsics());
+                object
+                    .create_data_property_or_throw(js_string!("get"), getter.clone(), context)
+                    .expect("creating a property on a new object cannot fail");
+                object
+                    .create_data_property_or_throw(js_string!("set"), setter.clone(), context)
+                    .expect("creating a property on a new object cannot fail");
+                object.into()
+            }
+        };
+
+        let result = decorator.call(
+            &JsValue::undefined(),
+            &[current, decorator_context.into()],
+            context,
+        );
+
+        // d. Set decorationState.[[Finished]] to true.
+        finished.set(true);
+        let result = result?;
+
+        match &mut value {
+            // e. If kind is field, then
+            ElementValue::Field(index) => {
+                // i. If IsCallable(newValue) is true, append newValue to elementRecord.[[Initializers]].
+                // ii. Else if newValue is not undefined, throw a TypeError exception.
+                if let Some(initializer) = callable_or_undefined(&result, "field")? {
+                    push_field_initializer(class, is_static, *index, initializer);
+                }
+            }
+            // f. Else if kind is accessor, then
+            ElementValue::Accessor {
+                getter,
+                setter,
+                storage,
+            } => {
+                // i. If newValue is an Object, then
+                if let Some(result) = result.as_object() {
+                    // 1. Let newGetter be ? Get(newValue, "get").
+                    // 2. If IsCallable(newGetter) is true, set elementRecord.[[Get]] to newGetter.
+                    // 3. Else if newGetter is not undefined, throw a TypeError exception.
+                    let new_getter = result.get(js_string!("get"), context)?;
+                    if let Some(new_getter) = callable_or_undefined(&new_getter, "accessor")? {
+                        *getter = new_getter;
+                    }
+
+                    // 4. Let newSetter be ? Get(newValue, "set").
+                    // 5. If IsCallable(newSetter) is true, set elementRecord.[[Set]] to newSetter.
+                    // 6. Else if newSetter is not undefined, throw a TypeError exception.
+                    let new_setter = result.get(js_string!("set"), context)?;
+                    if let Some(new_setter) = callable_or_undefined(&new_setter, "accessor")? {
+                        *setter = new_setter;
+                    }
+
+                    // 7. Let initializer be ? Get(newValue, "init").
+                    // 8. If IsCallable(initializer) is true, append initializer to elementRecord.[[Initializers]].
+                    // 9. Else if initializer is not undefined, throw a TypeError exception.
+                    let initializer = result.get(js_string!("init"), context)?;
+                    if let Some(initializer) = callable_or_undefined(&initializer, "accessor")? {
+                        push_field_initializer(class, is_static, *storage, initializer);
+                    }
+                }
+                // ii. Else if newValue is not undefined, throw a TypeError exception.
+                else if !result.is_undefined() {
+                    return Err(JsNativeError::typ()
+                        .with_message("accessor decorators must return an object or undefined")
+                        .into());
+                }
+            }
+            // g. Else,
+            ElementValue::Method(function)
+            | ElementValue::Getter(function)
+            | ElementValue::Setter(function) => {
+                // i. If IsCallable(newValue) is true, set elementRecord.[[Value]] to newValue.
+                // ii. Else if newValue is not undefined, throw a TypeError exception.
+                if let Some(new_value) = callable_or_undefined(&result, kind.name())? {
+                    *function = new_value;
+                }
+            }
+        }
+    }
+
+    // 2. Perform ? DefineClassElement(homeObject, elementRecord, isStatic).
+    define_element(class, home_object, is_static, &key, &value, context)
+}
+
+/// Checks that the value returned by a decorator is either callable or undefined.
+fn callable_or_undefined(value: &JsValue, kind: &str) -> JsResult<Option<JsObject>> {
+    if value.is_undefined() {
+        return Ok(None);
+    }
+    value.as_callable().cloned().map(Some).ok_or_else(|| {
+        JsNativeError::typ()
+            .with_message(format!(
+                "{kind} decorators must return a function or undefined"
+            ))
+            .into()
+    })
+}
+
+/// Appends an initializer to the `[[Initializers]]` of a field of the class.
+fn push_field_initializer(class: &JsObject, is_static: bool, index: usize, initializer: JsObject) {
+    let initializer = JsFunction::from_object_unchecked(initializer);
+    let mut class = class.borrow_mut();
+    let function = class
+        .as_function_mut()
+        .expect("class must be function object");
+    let field = if is_static {
+        function
+            .static_elements_mut()
+            .and_then(|elements| elements.get_mut(index))
+            .and_then(|element| match element {
+                ClassStaticElement::Field(field) => Some(field),
+                ClassStaticElement::Block(_) => None,
+            })
+    } else {
+        function
+            .fields_mut()
+            .and_then(|fields| fields.get_mut(index))
+    };
+    field
+        .expect("decorated field must be defined in the class")
+        .initializers_mut()
+        .push(initializer);
+}
+
+/// Defines a method, getter, setter or auto-accessor in its home object, once all of its
+/// decorators have been applied.
+fn define_element(
+    class: &JsObject,
+    home_object: &JsObject,
+    is_static: bool,
+    key: &ElementKey,
+    value: &ElementValue,
+    context: &mut Context<'_>,
+) -> JsResult<()> {
+    match key {
+        ElementKey::Public(key) => {
+            let desc = match value {
+                ElementValue::Field(_) => return Ok(()),
+                ElementValue::Method(function) => PropertyDescriptor::builder()
+                    .value(function.clone())
+                    .writable(true),
+                ElementValue::Getter(getter) => PropertyDescriptor::builder().get(getter.clone()),
+                ElementValue::Setter(setter) => PropertyDescriptor::builder().set(setter.clone()),
+                ElementValue::Accessor { getter, setter, .. } => PropertyDescriptor::builder()
+                    .get(getter.clone())
+                    .set(setter.clone()),
+            };
+            home_object.define_property_or_throw(
+                key.clone(),
+                desc.enumerable(false).configurable(true),
+                context,
+            )?;
+        }
+        ElementKey::Private(name) => {
+            let element = match value {
+                ElementValue::Field(_) => return Ok(()),
+                ElementValue::Method(function) => PrivateElement::Method(function.clone()),
+                ElementValue::Getter(getter) => PrivateElement::Accessor {
+                    getter: Some(getter.clone()),
+                    setter: None,
+                },
+                ElementValue::Setter(setter) => PrivateElement::Accessor {
+                    getter: None,
+                    setter: Some(setter.clone()),
+                },
+                ElementValue::Accessor { getter, setter, .. } => PrivateElement::Accessor {
+                    getter: Some(getter.clone()),
+                    setter: Some(setter.clone()),
+                },
+            };
+            if is_static {
+                class.borrow_mut().append_private_element(*name, element);
+            } else {
+                class
+                    .borrow_mut()
+                    .as_function_mut()
+                    .expect("class must be function object")
+                    .push_private_method(*name, element);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the description of a private name, including the leading `#`.
+pub(crate) fn private_description(name: PrivateName, context: &Context<'_>) -> JsString {
+    js_string!(format!(
+        "#{}",
+        context.interner().resolve_expect(name.description())
+    ))
+}
+
+/// The captures of the `addInitializer` function of a decorator context object.
+#[derive(Trace, Finalize)]
+struct AddInitializerCaptures {
+    initializers: Initializers,
+    #[unsafe_ignore_trace]
+    finished: Rc<Cell<bool>>,
+}
+
+/// Abstract operation `CreateDecoratorContextObject ( kind, key, extraInitializers, decorationState [ , isStatic ] )`
+///
+/// `element` contains whether the decorated element is static and whether it is private, and is
+/// `None` for class decorators.
+///
+/// More information:
+///  - [Decorators proposal][proposal]
+///
+/// [proposal]: https://tc39.es/proposal-decorators/#sec-createdecoratorcontextobject
+fn create_decorator_context_object(
+    kind: &str,
+    name: JsValue,
+    element: Option<(bool, bool)>,
+    access: Option<JsObject>,
+    initializers: &Initializers,
+    finished: &Rc<Cell<bool>>,
+    context: &mut Context<'_>,
+) -> JsObject {
+    // 1. Let contextObj be OrdinaryObjectCreate(%Object.prototype%).
+    let object = JsObject::with_object_proto(context.intrinsics());
+
+    // 2. Perform ! CreateDataPropertyOrThrow(contextObj, "kind", kind).
+    object
+        .create_data_property_or_throw(js_string!("kind"), js_string!(kind), context)
+        .expect("creating a property on a new object cannot fail");
+
+    // 3. Perform ! CreateDataPropertyOrThrow(contextObj, "name", name).
+    object
+        .create_data_property_or_throw(js_string!("name"), name, context)
+        .expect("creating a property on a new object cannot fail");
+
+    // 4. If kind is not class, then
+    if let Some((is_static, is_private)) = element {
+        // a. Perform ! CreateDataPropertyOrThrow(contextObj, "access", CreateDecoratorAccessObject(kind, name)).
+        if let Some(access) = access {
+            object
+                .create_data_property_or_throw(js_string!("access"), access, context)
+                .expect("creating a property on a new object cannot fail");
+        }
+
+        // b. Perform ! CreateDataPropertyOrThrow(contextObj, "static", isStatic).
+        object
+            .create_data_property_or_throw(js_string!("static"), is_static, context)
+            .expect("creating a property on a new object cannot fail");
+
+        // c. Perform ! CreateDataPropertyOrThrow(contextObj, "private", key is a Private Name).
+        object
+            .create_data_property_or_throw(js_string!("private"), is_private, context)
+            .expect("creating a property on a new object cannot fail");
+    }
+
+    // 5. Let addInitializer be CreateAddInitializerFunction(extraInitializers, decorationState).
+    let add_initializer = FunctionObjectBuilder::new(
+        context,
+        NativeFunction::from_copy_closure_with_captures(
+            |_, args, captures, _| {
+                // a. If decorationState.[[Finished]] is true, throw a TypeError exception.
+                if captures.finished.get() {
+                    return Err(JsNativeError::typ()
+                        .with_message("cannot add initializers after decoration has finished")
+                        .into());
+                }
+
+                // b. If IsCallable(initializer) is false, throw a TypeError exception.
+                let initializer = args.get_or_undefined(0).as_callable().ok_or_else(|| {
+                    JsNativeError::typ().with_message("initializers must be callable")
+                })?;
+
+                // c. Append initializer to extraInitializers.
+                captures.initializers.borrow_mut().push(initializer.clone());
+
+                // d. Return undefined.
+                Ok(JsValue::undefined())
+            },
+            AddInitializerCaptures {
+                initializers: initializers.clone(),
+                finished: finished.clone(),
+            },
+        ),
+    )
+    .name("addInitializer")
+    .length(1)
+    .build();
+
+    // 6. Perform ! CreateDataPropertyOrThrow(contextObj, "addInitializer", addInitializer).
+    object
+        .create_data_property_or_throw(js_string!("addInitializer"), add_initializer, context)
+        .expect("creating a property on a new object cannot fail");
+
+    // 7. Return contextObj.
+    object
+}
+
+/// Abstract operation `CreateDecoratorAccessObject ( kind, name )`
+///
+/// More information:
+///  - [Decorators proposal][proposal]
+///
+/// [proposal]: https://tc39.es/proposal-decorators/#sec-createdecoratoraccessobject
+fn create_decorator_access_object(
+    kind: DecoratedElementKind,
+    key: &ElementKey,
+    context: &mut Context<'_>,
+) -> JsObject {
+    // 1. Let accessObj be OrdinaryObjectCreate(%Object.prototype%).
+    let object = JsObject::with_object_proto(context.intrinsics());
+
+    // 2. Let hasFunction be a new Built-in Function Object that, when called with argument obj,
+    //    returns whether obj has the element.
+    let has = FunctionObjectBuilder::new(
+        context,
+        NativeFunction::from_copy_closure_with_captures(
+            |_, args, key, context| {
+                let object = access_target(args)?;
+                match key {
+                    ElementKey::Public(key) => {
+                        Ok(object.has_property(key.clone(), context)?.into())
+                    }
+                    ElementKey::Private(name) => Ok(object
+                        .private_element_find(name, true, true)
+                        .is_some()
+                        .into()),
+                }
+            },
+            key.clone(),
+        ),
+    )
+    .name("has")
+    .length(1)
+    .build();
+    object
+        .create_data_property_or_throw(js_string!("has"), has, context)
+        .expect("creating a property on a new object cannot fail");
+
+    // 3. If kind is field, method, accessor or getter, then
+    if kind != DecoratedElementKind::Setter {
+        // a. Let getFunction be a new Built-in Function Object that, when called with argument
+        //    obj, returns the value of the element in obj.
+        let get = FunctionObjectBuilder::new(
+            context,
+            NativeFunction::from_copy_closure_with_captures(
+                |_, args, key, context| {
+                    let object = access_target(args)?;
+                    match key {
+                        ElementKey::Public(key) => object.get(key.clone(), context),
+                        ElementKey::Private(name) => object.private_get(name, context),
+                    }
+                },
+                key.clone(),
+            ),
+        )
+        .name("get")
+        .length(1)
+        .build();
+        object
+            .create_data_property_or_throw(js_string!("get"), get, context)
+            .expect("creating a property on a new object cannot fail");
+    }
+
+    // 4. If kind is field, accessor or setter, then
+    if matches!(
+        kind,
+        DecoratedElementKind::Field | DecoratedElementKind::Accessor | DecoratedElementKind::Setter
+    ) {
+        // a. Let setFunction be a new Built-in Function Object that, when called with arguments
+        //    obj and value, sets the value of the element in obj.
+        let set = FunctionObjectBuilder::new(
+            context,
+            NativeFunction::from_copy_closure_with_captures(
+                |_, args, key, context| {
+                    let object = access_target(args)?;
+                    let value = args.get_or_undefined(1).clone();
+                    match key {
+                        ElementKey::Public(key) => {
+                            object.set(key.clone(), value, true, context)?;
+                        }
+                        ElementKey::Private(name) => object.private_set(name, value, context)?,
+                    }
+                    Ok(JsValue::undefined())
+                },
+                key.clone(),
+            ),
+        )
+        .name("set")
+        .length(2)
+        .build();
+        object
+            .create_data_property_or_throw(js_string!("set"), set, context)
+            .expect("creating a property on a new object cannot fail");
+    }
+
+    // 5. Return accessObj.
+    object
+}
+
+/// Returns the object argument of the functions of a decorator access object.
+fn access_target(args: &[JsValue]) -> JsResult<&JsObject> {
+    args.get_or_undefined(0).as_object().ok_or_else(|| {
+        JsNativeError::typ()
+            .with_message("decorator access functions must be called with an object")
+            .into()
+    })
+}
+
+/// The captures of the getter and setter of an auto-accessor.
+#[derive(Trace, Finalize)]
+struct AccessorCaptures {
+    #[unsafe_ignore_trace]
+    storage: PrivateName,
+}
+
+/// Creates the getter and setter of an auto-accessor, which get and set the value of the
+/// private field `storage` of the object they are called with.
+pub(crate) fn create_auto_accessor(
+    name: &PropertyKey,
+    storage: PrivateName,
+    context: &mut Context<'_>,
+) -> (JsObject, JsObject) {
+    let getter = FunctionObjectBuilder::new(
+        context,
+        NativeFunction::from_copy_closure_with_captures(
+            |this, _, captures, context| {
+                let this = this.as_object().ok_or_else(|| {
+                    JsNativeError::typ().with_message("auto-accessor called on a non-object")
+                })?;
+                this.private_get(&captures.storage, context)
+            },
+            AccessorCaptures { storage },
+        ),
+    )
+    .length(0)
+    .build();
+
+    let setter = FunctionObjectBuilder::new(
+        context,
+        NativeFunction::from_copy_closure_with_captures(
+            |this, args, captures, context| {
+                let this = this.as_object().ok_or_else(|| {
+                    JsNativeError::typ().with_message("auto-accessor called on a non-object")
+                })?;
+                this.private_set(&captures.storage, args.get_or_undefined(0).clone(), context)?;
+                Ok(JsValue::undefined())
+            },
+            AccessorCaptures { storage },
+        ),
+    )
+    .length(1)
+    .build();
+
+    let getter: JsObject = getter.into();
+    let setter: JsObject = setter.into();
+    set_function_name(&getter, name, Some(js_string!("get")), context);
+    set_function_name(&setter, name, Some(js_string!("set")), context);
+    (getter, setter)
+}
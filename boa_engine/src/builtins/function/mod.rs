@@ -44,6 +44,9 @@ use std::{fmt, io::Read};
 use thin_vec::ThinVec;
 
 pub(crate) mod arguments;
+pub(crate) mod decorators;
+
+use self::decorators::ClassDecorators;
 
 #[cfg(test)]
 mod tests;
@@ -118,32 +121,63 @@ impl ConstructorKind {
 
 /// Record containing the field definition of classes.
 ///
+/// The last element of each variant holds the `[[Initializers]]` returned by the decorators
+/// applied to the field, which transform the initial value of the field.
+///
 /// More information:
 ///  - [ECMAScript specification][spec]
+///  - [Decorators proposal][proposal]
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-classfielddefinition-record-specification-type
+/// [proposal]: https://tc39.es/proposal-decorators/
 #[derive(Clone, Debug, Finalize)]
 pub enum ClassFieldDefinition {
     /// A class field definition with a `string` or `symbol` as a name.
-    Public(PropertyKey, JsFunction),
+    Public(PropertyKey, JsFunction, ThinVec<JsFunction>),
 
     /// A class field definition with a private name.
-    Private(PrivateName, JsFunction),
+    Private(PrivateName, JsFunction, ThinVec<JsFunction>),
+}
+
+impl ClassFieldDefinition {
+    /// Returns the `[[Initializers]]` of the field definition.
+    pub(crate) fn initializers_mut(&mut self) -> &mut ThinVec<JsFunction> {
+        match self {
+            Self::Public(_, _, initializers) | Self::Private(_, _, initializers) => initializers,
+        }
+    }
 }
 
 unsafe impl Trace for ClassFieldDefinition {
     custom_trace! {this, {
         match this {
-            Self::Public(_key, func) => {
+            Self::Public(_key, func, initializers) => {
                 mark(func);
+                mark(initializers);
             }
-            Self::Private(_, func) => {
+            Self::Private(_, func, initializers) => {
                 mark(func);
+                mark(initializers);
             }
         }
     }}
 }
 
+/// A static element of a class, which gets evaluated after the class definition.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-runtime-semantics-classdefinitionevaluation
+#[derive(Clone, Debug, Trace, Finalize)]
+pub(crate) enum ClassStaticElement {
+    /// A static field of the class.
+    Field(ClassFieldDefinition),
+
+    /// A `ClassStaticBlockDefinition` Record, containing the body of a static block.
+    Block(JsFunction),
+}
+
 #[derive(Finalize)]
 pub(crate) enum FunctionKind {
     /// A rust function.
@@ -179,6 +213,12 @@ pub(crate) enum FunctionKind {
 
         /// The `[[ScriptOrModule]]` internal slot.
         script_or_module: Option<ActiveRunnable>,
+
+        /// The static fields and blocks of a class, pending evaluation.
+        static_elements: ThinVec<ClassStaticElement>,
+
+        /// The state of the decorators applied to a class and its elements.
+        decorators: Option<Box<ClassDecorators>>,
     },
 
     /// A bytecode async function.
@@ -275,6 +315,8 @@ unsafe impl Trace for FunctionKind {
                 private_methods,
                 class_object,
                 script_or_module,
+                static_elements,
+                decorators,
                 ..
             } => {
                 mark(code);
@@ -288,6 +330,8 @@ unsafe impl Trace for FunctionKind {
                 }
                 mark(class_object);
                 mark(script_or_module);
+                mark(static_elements);
+                mark(decorators);
             }
             Self::Async { code, environments, home_object, class_object, script_or_module }
             | Self::Generator { code, environments, home_object, class_object, script_or_module}
@@ -390,20 +434,69 @@ impl Function {
         }
     }
 
+    /// Returns a mutable reference to the values of the `[[Fields]]` internal slot.
+    pub(crate) fn fields_mut(&mut self) -> Option<&mut ThinVec<ClassFieldDefinition>> {
+        if let FunctionKind::Ordinary { fields, .. } = &mut self.kind {
+            Some(fields)
+        } else {
+            None
+        }
+    }
+
     /// Pushes a value to the `[[Fields]]` internal slot if present.
     pub(crate) fn push_field(&mut self, key: PropertyKey, value: JsFunction) {
         if let FunctionKind::Ordinary { fields, .. } = &mut self.kind {
-            fields.push(ClassFieldDefinition::Public(key, value));
+            fields.push(ClassFieldDefinition::Public(key, value, ThinVec::new()));
         }
     }
 
     /// Pushes a private value to the `[[Fields]]` internal slot if present.
     pub(crate) fn push_field_private(&mut self, name: PrivateName, value: JsFunction) {
         if let FunctionKind::Ordinary { fields, .. } = &mut self.kind {
-            fields.push(ClassFieldDefinition::Private(name, value));
+            fields.push(ClassFieldDefinition::Private(name, value, ThinVec::new()));
         }
     }
 
+    /// Returns a mutable reference to the static elements of the class, pending evaluation.
+    pub(crate) fn static_elements_mut(&mut self) -> Option<&mut ThinVec<ClassStaticElement>> {
+        if let FunctionKind::Ordinary {
+            static_elements, ..
+        } = &mut self.kind
+        {
+            Some(static_elements)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the state of the decorators applied to the class, if any.
+    pub(crate) fn decorators(&self) -> Option<&ClassDecorators> {
+        if let FunctionKind::Ordinary { decorators, .. } = &self.kind {
+            decorators.as_deref()
+        } else {
+            None
+        }
+    }
+
+    /// Returns a mutable reference to the state of the decorators applied to the class,
+    /// initializing it if needed.
+    pub(crate) fn decorators_mut(&mut self) -> Option<&mut ClassDecorators> {
+        if let FunctionKind::Ordinary { decorators, .. } = &mut self.kind {
+            Some(decorators.get_or_insert_with(Box::default))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the value of the class binding of a class constructor.
+    ///
+    /// This is the class itself, unless a class decorator replaced it with another value.
+    pub(crate) fn class_binding(&self, class: &JsObject) -> JsObject {
+        self.decorators()
+            .and_then(|decorators| decorators.class_binding.clone())
+            .unwrap_or_else(|| class.clone())
+    }
+
     /// Returns the values of the `[[PrivateMethods]]` internal slot.
     pub(crate) fn get_private_methods(&self) -> &[(PrivateName, PrivateElement)] {
         if let FunctionKind::Ordinary {
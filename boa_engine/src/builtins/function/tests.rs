@@ -190,3 +190,124 @@ fn function_constructor_early_errors_super() {
         ),
     ]);
 }
+
+#[test]
+fn class_element_decorators() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                var kinds = [];
+                function log(value, context) {
+                    kinds.push(`${context.kind} ${String(context.name)} ${context.static} ${context.private}`);
+                }
+                function double(value, context) {
+                    if (context.kind === "method") {
+                        return function () { return value.call(this) * 2; };
+                    }
+                    return (initial) => initial * 2;
+                }
+                class A {
+                    @log @double method() { return 1; }
+                    @log static staticMethod() {}
+                    @log @double field = 2;
+                    @double #private = 3;
+                    @log get getter() { return 4; }
+                    getPrivate() { return this.#private; }
+                }
+                var a = new A();
+            "#}),
+        TestAction::assert_eq("a.method()", 2),
+        TestAction::assert_eq("a.field", 4),
+        TestAction::assert_eq("a.getPrivate()", 6),
+        TestAction::assert_eq("a.getter", 4),
+        TestAction::assert_eq(
+            "kinds.join()",
+            "method staticMethod true false,method method false false,getter getter false false,field field false false",
+        ),
+        TestAction::assert_native_error(
+            "class B { @(() => 1) method() {} }",
+            JsNativeErrorKind::Type,
+            "method decorators must return a function or undefined",
+        ),
+    ]);
+}
+
+#[test]
+fn class_decorators() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                var order = [];
+                function replace(value, context) {
+                    context.addInitializer(function () { order.push(`init ${typeof this.prototype.replaced}`); });
+                    return class extends value {
+                        replaced() { return true; }
+                    };
+                }
+                @replace
+                class A {
+                    static self() { return A; }
+                    static { order.push("static block"); }
+                }
+            "#}),
+        TestAction::assert("new A().replaced()"),
+        TestAction::assert("A.self() === A"),
+        TestAction::assert_eq("order.join()", "static block,init function"),
+    ]);
+}
+
+#[test]
+fn auto_accessors() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                function tenfold(value, context) {
+                    return {
+                        get() { return value.get.call(this) * 10; },
+                        init(initial) { return initial + 1; },
+                    };
+                }
+                class A {
+                    accessor x = 1;
+                    @tenfold accessor y = 2;
+                    static accessor #z = 3;
+                    static getZ() { return A.#z; }
+                }
+                var a = new A();
+                var desc = Object.getOwnPropertyDescriptor(A.prototype, "x");
+            "#}),
+        TestAction::assert_eq("a.x", 1),
+        TestAction::assert_eq("a.y", 30),
+        TestAction::assert_eq("A.getZ()", 3),
+        TestAction::assert_eq("a.x = 5; a.x", 5),
+        TestAction::assert_eq("desc.get.name", "get x"),
+        TestAction::assert_eq("desc.set.name", "set x"),
+        TestAction::assert("!Object.hasOwn(a, 'x')"),
+    ]);
+}
+
+#[test]
+fn decorator_context() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                var access, context;
+                function grab(value, ctx) {
+                    access = ctx.access;
+                    context = ctx;
+                    ctx.addInitializer(function () { this.initialized = true; });
+                }
+                class A {
+                    @grab #field = 1;
+                }
+                var a = new A();
+            "#}),
+        TestAction::assert_eq("access.get(a)", 1),
+        TestAction::assert_eq("access.set(a, 2); access.get(a)", 2),
+        TestAction::assert("access.has(a)"),
+        TestAction::assert("!access.has({})"),
+        TestAction::assert_eq("context.name", "#field"),
+        TestAction::assert("a.initialized"),
+        TestAction::assert_native_error(
+            "context.addInitializer(() => {})",
+            JsNativeErrorKind::Type,
+            "cannot add initializers after decoration has finished",
+        ),
+    ]);
+}
@@ -137,13 +137,7 @@ impl IntrinsicObject for Generator {
         let _timer = Profiler::global().start_event(Self::NAME, "init");
 
         BuiltInBuilder::with_intrinsic::<Self>(realm)
-            .prototype(
-                realm
-                    .intrinsics()
-                    .objects()
-                    .iterator_prototypes()
-                    .iterator(),
-            )
+            .prototype(realm.intrinsics().constructors().iterator().prototype())
             .static_method(Self::next, "next", 1)
             .static_method(Self::r#return, "return", 1)
             .static_method(Self::throw, "throw", 1)
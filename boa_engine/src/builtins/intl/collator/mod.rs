@@ -6,7 +6,7 @@ use icu_collator::{
 
 use icu_locid::{
     extensions::unicode::Value, extensions_unicode_key as key, extensions_unicode_value as value,
-    Locale,
+    langid, LanguageIdentifier, Locale,
 };
 use icu_provider::DataLocale;
 
@@ -159,6 +159,44 @@ impl Service for Collator {
     }
 }
 
+/// Collation types that can be requested with the `"co"` extension key, each paired with a
+/// language that has data for it.
+const COLLATIONS: [(&str, LanguageIdentifier); 14] = [
+    ("big5han", langid!("zh")),
+    ("compat", langid!("ar")),
+    ("dict", langid!("si")),
+    ("emoji", langid!("und")),
+    ("eor", langid!("und")),
+    ("gb2312", langid!("zh")),
+    ("phonebk", langid!("de")),
+    ("phonetic", langid!("ln")),
+    ("pinyin", langid!("zh")),
+    ("searchjl", langid!("ko")),
+    ("stroke", langid!("zh")),
+    ("trad", langid!("es")),
+    ("unihan", langid!("zh")),
+    ("zhuyin", langid!("zh")),
+];
+
+/// Returns the collation types for which the provider has data.
+pub(in crate::builtins::intl) fn available_collations(
+    provider: BoaProvider<'_>,
+) -> Vec<&'static str> {
+    COLLATIONS
+        .into_iter()
+        .filter(|(co, language)| {
+            let co = Value::try_from_bytes(co.as_bytes()).expect("must be a valid collation");
+            validate_extension::<CollationMetadataV1Marker>(
+                language.clone(),
+                key!("co"),
+                &co,
+                &provider,
+            )
+        })
+        .map(|(co, _)| co)
+        .collect()
+}
+
 impl IntrinsicObject for Collator {
     fn init(realm: &Realm) {
         let _timer = Profiler::global().start_event(Self::NAME, "init");
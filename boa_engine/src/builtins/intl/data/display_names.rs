@@ -0,0 +1,9201 @@
+//! The CLDR data used by `Intl.DisplayNames`.
+//! Version: CLDR 42.0
+//!
+//! This file is generated by `boa_engine/intl_data/generate.py`. Please do not modify it directly.
+
+use super::LocaleData;
+
+pub(in crate::builtins::intl) static DISPLAY_NAMES: LocaleData = LocaleData::new(&[
+    (
+        "de",
+        &[
+            ("calendar/buddhist", "Buddhistischer Kalender"),
+            ("calendar/chinese", "Chinesischer Kalender"),
+            ("calendar/coptic", "Koptischer Kalender"),
+            ("calendar/dangi", "Dangi-Kalender"),
+            ("calendar/ethioaa", "Äthiopischer Amätä-Aläm-Kalender"),
+            ("calendar/ethiopic", "Äthiopischer Kalender"),
+            ("calendar/gregory", "Gregorianischer Kalender"),
+            ("calendar/hebrew", "Hebräischer Kalender"),
+            ("calendar/indian", "Indischer Nationalkalender"),
+            ("calendar/islamic", "Islamischer Kalender"),
+            (
+                "calendar/islamic-civil",
+                "Bürgerlicher islamischer Kalender (tabellarisch)",
+            ),
+            (
+                "calendar/islamic-rgsa",
+                "Islamischer Kalender (Saudi-Arabien, Beobachtung)",
+            ),
+            (
+                "calendar/islamic-tbla",
+                "Islamischer Kalender (tabellarisch, astronomische Epoche)",
+            ),
+            (
+                "calendar/islamic-umalqura",
+                "Islamischer Umm-al-Qura-Kalender",
+            ),
+            ("calendar/iso8601", "ISO-8601-Kalender"),
+            ("calendar/japanese", "Japanischer Kalender"),
+            ("calendar/persian", "Persischer Kalender"),
+            ("calendar/roc", "Kalender der Republik China"),
+            ("currency/AED", "VAE-Dirham"),
+            ("currency/ARS", "Argentinischer Peso"),
+            ("currency/AUD", "Australischer Dollar"),
+            ("currency/BDT", "Bangladesch-Taka"),
+            ("currency/BGN", "Bulgarischer Lew"),
+            ("currency/BHD", "Bahrain-Dinar"),
+            ("currency/BOB", "Bolivianischer Boliviano"),
+            ("currency/BRL", "Brasilianischer Real"),
+            ("currency/CAD", "Kanadischer Dollar"),
+            ("currency/CHF", "Schweizer Franken"),
+            ("currency/CLP", "Chilenischer Peso"),
+            ("currency/CNY", "Renminbi Yuan"),
+            ("currency/COP", "Kolumbianischer Peso"),
+            ("currency/CRC", "Costa-Rica-Colón"),
+            ("currency/CZK", "Tschechische Krone"),
+            ("currency/DKK", "Dänische Krone"),
+            ("currency/DOP", "Dominikanischer Peso"),
+            ("currency/DZD", "Algerischer Dinar"),
+            ("currency/EGP", "Ägyptisches Pfund"),
+            ("currency/ETB", "Äthiopischer Birr"),
+            ("currency/EUR", "Euro"),
+            ("currency/GBP", "Britisches Pfund"),
+            ("currency/GEL", "Georgischer Lari"),
+            ("currency/GHS", "Ghanaischer Cedi"),
+            ("currency/GTQ", "Guatemaltekischer Quetzal"),
+            ("currency/HKD", "Hongkong-Dollar"),
+            ("currency/HUF", "Ungarischer Forint"),
+            ("currency/IDR", "Indonesische Rupiah"),
+            ("currency/ILS", "Israelischer Neuer Schekel"),
+            ("currency/INR", "Indische Rupie"),
+            ("currency/IQD", "Irakischer Dinar"),
+            ("currency/IRR", "Iranischer Rial"),
+            ("currency/ISK", "Isländische Krone"),
+            ("currency/JMD", "Jamaika-Dollar"),
+            ("currency/JOD", "Jordanischer Dinar"),
+            ("currency/JPY", "Japanischer Yen"),
+            ("currency/KES", "Kenia-Schilling"),
+            ("currency/KRW", "Südkoreanischer Won"),
+            ("currency/KWD", "Kuwait-Dinar"),
+            ("currency/KZT", "Kasachischer Tenge"),
+            ("currency/LBP", "Libanesisches Pfund"),
+            ("currency/LKR", "Sri-Lanka-Rupie"),
+            ("currency/MAD", "Marokkanischer Dirham"),
+            ("currency/MDL", "Moldau-Leu"),
+            ("currency/MXN", "Mexikanischer Peso"),
+            ("currency/MYR", "Malaysischer Ringgit"),
+            ("currency/NGN", "Nigerianischer Naira"),
+            ("currency/NOK", "Norwegische Krone"),
+            ("currency/NPR", "Nepalesische Rupie"),
+            ("currency/NZD", "Neuseeland-Dollar"),
+            ("currency/OMR", "Omanischer Rial"),
+            ("currency/PAB", "Panamaischer Balboa"),
+            ("currency/PEN", "Peruanischer Sol"),
+            ("currency/PHP", "Philippinischer Peso"),
+            ("currency/PKR", "Pakistanische Rupie"),
+            ("currency/PLN", "Polnischer Złoty"),
+            ("currency/PYG", "Paraguayischer Guaraní"),
+            ("currency/QAR", "Katar-Riyal"),
+            ("currency/RON", "Rumänischer Leu"),
+            ("currency/RSD", "Serbischer Dinar"),
+            ("currency/RUB", "Russischer Rubel"),
+            ("currency/SAR", "Saudi-Rial"),
+            ("currency/SEK", "Schwedische Krone"),
+            ("currency/SGD", "Singapur-Dollar"),
+            ("currency/THB", "Thailändischer Baht"),
+            ("currency/TND", "Tunesischer Dinar"),
+            ("currency/TRY", "Türkische Lira"),
+            ("currency/TWD", "Neuer Taiwan-Dollar"),
+            ("currency/TZS", "Tansania-Schilling"),
+            ("currency/UAH", "Ukrainische Hrywnja"),
+            ("currency/UGX", "Uganda-Schilling"),
+            ("currency/USD", "US-Dollar"),
+            ("currency/UYU", "Uruguayischer Peso"),
+            ("currency/UZS", "Usbekistan-Sum"),
+            ("currency/VND", "Vietnamesischer Dong"),
+            ("currency/XAF", "CFA-Franc (BEAC)"),
+            ("currency/XOF", "CFA-Franc (BCEAO)"),
+            ("currency/ZAR", "Südafrikanischer Rand"),
+            ("field-narrow/dayPeriod", "Tagesh."),
+            ("field-narrow/era", "E"),
+            ("field-narrow/month", "M"),
+            ("field-narrow/quarter", "Q"),
+            ("field-narrow/timeZoneName", "Zeitz."),
+            ("field-narrow/weekOfYear", "W"),
+            ("field-narrow/weekday", "Wochent."),
+            ("field-narrow/year", "J"),
+            ("field-short/hour", "Std."),
+            ("field-short/minute", "Min."),
+            ("field-short/quarter", "Quart."),
+            ("field-short/second", "Sek."),
+            ("field/day", "Tag"),
+            ("field/dayPeriod", "Tageshälfte"),
+            ("field/era", "Epoche"),
+            ("field/hour", "Stunde"),
+            ("field/minute", "Minute"),
+            ("field/month", "Monat"),
+            ("field/quarter", "Quartal"),
+            ("field/second", "Sekunde"),
+            ("field/timeZoneName", "Zeitzone"),
+            ("field/weekOfYear", "Woche"),
+            ("field/weekday", "Wochentag"),
+            ("field/year", "Jahr"),
+            ("language-short/en-GB", "Englisch (GB)"),
+            ("language/af", "Afrikaans"),
+            ("language/am", "Amharisch"),
+            ("language/ar", "Arabisch"),
+            ("language/ar-001", "Modernes Hocharabisch"),
+            ("language/as", "Assamesisch"),
+            ("language/az", "Aserbaidschanisch"),
+            ("language/be", "Belarussisch"),
+            ("language/bg", "Bulgarisch"),
+            ("language/bn", "Bengalisch"),
+            ("language/bo", "Tibetisch"),
+            ("language/br", "Bretonisch"),
+            ("language/bs", "Bosnisch"),
+            ("language/ca", "Katalanisch"),
+            ("language/chr", "Cherokee"),
+            ("language/cs", "Tschechisch"),
+            ("language/cy", "Walisisch"),
+            ("language/da", "Dänisch"),
+            ("language/de", "Deutsch"),
+            ("language/de-AT", "Österreichisches Deutsch"),
+            ("language/de-CH", "Schweizer Hochdeutsch"),
+            ("language/dz", "Dzongkha"),
+            ("language/el", "Griechisch"),
+            ("language/en", "Englisch"),
+            ("language/eo", "Esperanto"),
+            ("language/es", "Spanisch"),
+            ("language/et", "Estnisch"),
+            ("language/eu", "Baskisch"),
+            ("language/fa", "Persisch"),
+            ("language/fa-AF", "Dari"),
+            ("language/ff", "Ful"),
+            ("language/fi", "Finnisch"),
+            ("language/fil", "Filipino"),
+            ("language/fo", "Färöisch"),
+            ("language/fr", "Französisch"),
+            ("language/fy", "Westfriesisch"),
+            ("language/ga", "Irisch"),
+            ("language/gd", "Gälisch (Schottland)"),
+            ("language/gl", "Galicisch"),
+            ("language/gu", "Gujarati"),
+            ("language/ha", "Haussa"),
+            ("language/haw", "Hawaiisch"),
+            ("language/he", "Hebräisch"),
+            ("language/hi", "Hindi"),
+            ("language/hr", "Kroatisch"),
+            ("language/ht", "Haiti-Kreolisch"),
+            ("language/hu", "Ungarisch"),
+            ("language/hy", "Armenisch"),
+            ("language/id", "Indonesisch"),
+            ("language/ig", "Igbo"),
+            ("language/is", "Isländisch"),
+            ("language/it", "Italienisch"),
+            ("language/ja", "Japanisch"),
+            ("language/jv", "Javanisch"),
+            ("language/ka", "Georgisch"),
+            ("language/kk", "Kasachisch"),
+            ("language/km", "Khmer"),
+            ("language/kn", "Kannada"),
+            ("language/ko", "Koreanisch"),
+            ("language/ku", "Kurdisch"),
+            ("language/ky", "Kirgisisch"),
+            ("language/la", "Latein"),
+            ("language/lb", "Luxemburgisch"),
+            ("language/lo", "Laotisch"),
+            ("language/lt", "Litauisch"),
+            ("language/lv", "Lettisch"),
+            ("language/mg", "Malagasy"),
+            ("language/mi", "Māori"),
+            ("language/mk", "Mazedonisch"),
+            ("language/ml", "Malayalam"),
+            ("language/mn", "Mongolisch"),
+            ("language/mr", "Marathi"),
+            ("language/ms", "Malaiisch"),
+            ("language/mt", "Maltesisch"),
+            ("language/my", "Birmanisch"),
+            ("language/nb", "Norwegisch (Bokmål)"),
+            ("language/ne", "Nepalesisch"),
+            ("language/nl", "Niederländisch"),
+            ("language/nl-BE", "Flämisch"),
+            ("language/nn", "Norwegisch (Nynorsk)"),
+            ("language/no", "Norwegisch"),
+            ("language/or", "Oriya"),
+            ("language/pa", "Punjabi"),
+            ("language/pl", "Polnisch"),
+            ("language/ps", "Paschtu"),
+            ("language/pt", "Portugiesisch"),
+            ("language/ro", "Rumänisch"),
+            ("language/ro-MD", "Moldauisch"),
+            ("language/ru", "Russisch"),
+            ("language/rw", "Kinyarwanda"),
+            ("language/sa", "Sanskrit"),
+            ("language/sd", "Sindhi"),
+            ("language/si", "Singhalesisch"),
+            ("language/sk", "Slowakisch"),
+            ("language/sl", "Slowenisch"),
+            ("language/so", "Somali"),
+            ("language/sq", "Albanisch"),
+            ("language/sr", "Serbisch"),
+            ("language/sv", "Schwedisch"),
+            ("language/sw", "Suaheli"),
+            ("language/sw-CD", "Kongo-Swahili"),
+            ("language/ta", "Tamil"),
+            ("language/te", "Telugu"),
+            ("language/tg", "Tadschikisch"),
+            ("language/th", "Thailändisch"),
+            ("language/ti", "Tigrinya"),
+            ("language/tk", "Turkmenisch"),
+            ("language/tr", "Türkisch"),
+            ("language/uk", "Ukrainisch"),
+            ("language/ur", "Urdu"),
+            ("language/uz", "Usbekisch"),
+            ("language/vi", "Vietnamesisch"),
+            ("language/xh", "Xhosa"),
+            ("language/yi", "Jiddisch"),
+            ("language/yo", "Yoruba"),
+            ("language/zh", "Chinesisch"),
+            ("language/zh-Hans", "Chinesisch (vereinfacht)"),
+            ("language/zh-Hant", "Chinesisch (traditionell)"),
+            ("language/zu", "Zulu"),
+            ("pattern", " (|)"),
+            ("region-short/GB", "UK"),
+            ("region-short/HK", "Hongkong"),
+            ("region-short/MO", "Macau"),
+            ("region-short/PS", "Palästina"),
+            ("region-short/UN", "UN"),
+            ("region-short/US", "USA"),
+            ("region/001", "Welt"),
+            ("region/002", "Afrika"),
+            ("region/003", "Nordamerika"),
+            ("region/005", "Südamerika"),
+            ("region/009", "Ozeanien"),
+            ("region/019", "Amerika"),
+            ("region/142", "Asien"),
+            ("region/150", "Europa"),
+            ("region/419", "Lateinamerika"),
+            ("region/AE", "Vereinigte Arabische Emirate"),
+            ("region/AF", "Afghanistan"),
+            ("region/AL", "Albanien"),
+            ("region/AM", "Armenien"),
+            ("region/AO", "Angola"),
+            ("region/AQ", "Antarktis"),
+            ("region/AR", "Argentinien"),
+            ("region/AT", "Österreich"),
+            ("region/AU", "Australien"),
+            ("region/AZ", "Aserbaidschan"),
+            ("region/BA", "Bosnien und Herzegowina"),
+            ("region/BD", "Bangladesch"),
+            ("region/BE", "Belgien"),
+            ("region/BG", "Bulgarien"),
+            ("region/BO", "Bolivien"),
+            ("region/BR", "Brasilien"),
+            ("region/BY", "Belarus"),
+            ("region/CA", "Kanada"),
+            ("region/CD", "Kongo-Kinshasa"),
+            ("region/CH", "Schweiz"),
+            ("region/CL", "Chile"),
+            ("region/CM", "Kamerun"),
+            ("region/CN", "China"),
+            ("region/CO", "Kolumbien"),
+            ("region/CR", "Costa Rica"),
+            ("region/CU", "Kuba"),
+            ("region/CY", "Zypern"),
+            ("region/CZ", "Tschechien"),
+            ("region/DE", "Deutschland"),
+            ("region/DK", "Dänemark"),
+            ("region/DO", "Dominikanische Republik"),
+            ("region/DZ", "Algerien"),
+            ("region/EC", "Ecuador"),
+            ("region/EE", "Estland"),
+            ("region/EG", "Ägypten"),
+            ("region/ES", "Spanien"),
+            ("region/ET", "Äthiopien"),
+            ("region/EU", "Europäische Union"),
+            ("region/FI", "Finnland"),
+            ("region/FR", "Frankreich"),
+            ("region/GB", "Vereinigtes Königreich"),
+            ("region/GE", "Georgien"),
+            ("region/GH", "Ghana"),
+            ("region/GR", "Griechenland"),
+            ("region/GT", "Guatemala"),
+            ("region/HK", "Sonderverwaltungsregion Hongkong"),
+            ("region/HR", "Kroatien"),
+            ("region/HU", "Ungarn"),
+            ("region/ID", "Indonesien"),
+            ("region/IE", "Irland"),
+            ("region/IL", "Israel"),
+            ("region/IN", "Indien"),
+            ("region/IQ", "Irak"),
+            ("region/IR", "Iran"),
+            ("region/IS", "Island"),
+            ("region/IT", "Italien"),
+            ("region/JM", "Jamaika"),
+            ("region/JO", "Jordanien"),
+            ("region/JP", "Japan"),
+            ("region/KE", "Kenia"),
+            ("region/KH", "Kambodscha"),
+            ("region/KP", "Nordkorea"),
+            ("region/KR", "Südkorea"),
+            ("region/KW", "Kuwait"),
+            ("region/KZ", "Kasachstan"),
+            ("region/LB", "Libanon"),
+            ("region/LK", "Sri Lanka"),
+            ("region/LT", "Litauen"),
+            ("region/LU", "Luxemburg"),
+            ("region/LV", "Lettland"),
+            ("region/MA", "Marokko"),
+            ("region/MD", "Republik Moldau"),
+            ("region/MK", "Nordmazedonien"),
+            ("region/MM", "Myanmar"),
+            ("region/MN", "Mongolei"),
+            ("region/MO", "Sonderverwaltungsregion Macau"),
+            ("region/MT", "Malta"),
+            ("region/MX", "Mexiko"),
+            ("region/MY", "Malaysia"),
+            ("region/NG", "Nigeria"),
+            ("region/NL", "Niederlande"),
+            ("region/NO", "Norwegen"),
+            ("region/NP", "Nepal"),
+            ("region/NZ", "Neuseeland"),
+            ("region/PA", "Panama"),
+            ("region/PE", "Peru"),
+            ("region/PH", "Philippinen"),
+            ("region/PK", "Pakistan"),
+            ("region/PL", "Polen"),
+            ("region/PR", "Puerto Rico"),
+            ("region/PS", "Palästinensische Autonomiegebiete"),
+            ("region/PT", "Portugal"),
+            ("region/PY", "Paraguay"),
+            ("region/QA", "Katar"),
+            ("region/RO", "Rumänien"),
+            ("region/RS", "Serbien"),
+            ("region/RU", "Russland"),
+            ("region/SA", "Saudi-Arabien"),
+            ("region/SE", "Schweden"),
+            ("region/SG", "Singapur"),
+            ("region/SI", "Slowenien"),
+            ("region/SK", "Slowakei"),
+            ("region/SN", "Senegal"),
+            ("region/SY", "Syrien"),
+            ("region/TH", "Thailand"),
+            ("region/TN", "Tunesien"),
+            ("region/TR", "Türkei"),
+            ("region/TW", "Taiwan"),
+            ("region/TZ", "Tansania"),
+            ("region/UA", "Ukraine"),
+            ("region/UG", "Uganda"),
+            ("region/UN", "Vereinte Nationen"),
+            ("region/US", "Vereinigte Staaten"),
+            ("region/UY", "Uruguay"),
+            ("region/UZ", "Usbekistan"),
+            ("region/VE", "Venezuela"),
+            ("region/VN", "Vietnam"),
+            ("region/ZA", "Südafrika"),
+            ("region/ZM", "Sambia"),
+            ("region/ZW", "Simbabwe"),
+            ("script-stand-alone/Hans", "Vereinfachtes Chinesisch"),
+            ("script-stand-alone/Hant", "Traditionelles Chinesisch"),
+            ("script/Arab", "Arabisch"),
+            ("script/Armn", "Armenisch"),
+            ("script/Beng", "Bengalisch"),
+            ("script/Bopo", "Bopomofo"),
+            ("script/Brai", "Braille"),
+            ("script/Cyrl", "Kyrillisch"),
+            ("script/Deva", "Devanagari"),
+            ("script/Ethi", "Äthiopisch"),
+            ("script/Geor", "Georgisch"),
+            ("script/Grek", "Griechisch"),
+            ("script/Gujr", "Gujarati"),
+            ("script/Guru", "Gurmukhi"),
+            ("script/Hang", "Hangul"),
+            ("script/Hani", "Chinesisch"),
+            ("script/Hans", "Vereinfacht"),
+            ("script/Hant", "Traditionell"),
+            ("script/Hebr", "Hebräisch"),
+            ("script/Hira", "Hiragana"),
+            ("script/Jpan", "Japanisch"),
+            ("script/Kana", "Katakana"),
+            ("script/Khmr", "Khmer"),
+            ("script/Knda", "Kannada"),
+            ("script/Kore", "Koreanisch"),
+            ("script/Laoo", "Laotisch"),
+            ("script/Latn", "Lateinisch"),
+            ("script/Mlym", "Malayalam"),
+            ("script/Mong", "Mongolisch"),
+            ("script/Mymr", "Birmanisch"),
+            ("script/Orya", "Oriya"),
+            ("script/Sinh", "Singhalesisch"),
+            ("script/Taml", "Tamilisch"),
+            ("script/Telu", "Telugu"),
+            ("script/Thai", "Thai"),
+            ("separator", ", "),
+        ],
+    ),
+    (
+        "de-AT",
+        &[
+            ("language/ar-001", "modernes Hocharabisch"),
+            ("language/ha", "Hausa"),
+            ("language/haw", "Hawaiianisch"),
+        ],
+    ),
+    ("de-CH", &[("region/ZW", "Zimbabwe")]),
+    (
+        "en",
+        &[
+            ("calendar/buddhist", "Buddhist Calendar"),
+            ("calendar/chinese", "Chinese Calendar"),
+            ("calendar/coptic", "Coptic Calendar"),
+            ("calendar/dangi", "Dangi Calendar"),
+            ("calendar/ethioaa", "Ethiopic Amete Alem Calendar"),
+            ("calendar/ethiopic", "Ethiopic Calendar"),
+            ("calendar/gregory", "Gregorian Calendar"),
+            ("calendar/hebrew", "Hebrew Calendar"),
+            ("calendar/indian", "Indian National Calendar"),
+            ("calendar/islamic", "Islamic Calendar"),
+            (
+                "calendar/islamic-civil",
+                "Islamic Calendar (tabular, civil epoch)",
+            ),
+            (
+                "calendar/islamic-rgsa",
+                "Islamic Calendar (Saudi Arabia, sighting)",
+            ),
+            (
+                "calendar/islamic-tbla",
+                "Islamic Calendar (tabular, astronomical epoch)",
+            ),
+            (
+                "calendar/islamic-umalqura",
+                "Islamic Calendar (Umm al-Qura)",
+            ),
+            ("calendar/iso8601", "ISO-8601 Calendar"),
+            ("calendar/japanese", "Japanese Calendar"),
+            ("calendar/persian", "Persian Calendar"),
+            ("calendar/roc", "Minguo Calendar"),
+            ("currency/AED", "United Arab Emirates Dirham"),
+            ("currency/ARS", "Argentine Peso"),
+            ("currency/AUD", "Australian Dollar"),
+            ("currency/BDT", "Bangladeshi Taka"),
+            ("currency/BGN", "Bulgarian Lev"),
+            ("currency/BHD", "Bahraini Dinar"),
+            ("currency/BOB", "Bolivian Boliviano"),
+            ("currency/BRL", "Brazilian Real"),
+            ("currency/CAD", "Canadian Dollar"),
+            ("currency/CHF", "Swiss Franc"),
+            ("currency/CLP", "Chilean Peso"),
+            ("currency/CNY", "Chinese Yuan"),
+            ("currency/COP", "Colombian Peso"),
+            ("currency/CRC", "Costa Rican Colón"),
+            ("currency/CZK", "Czech Koruna"),
+            ("currency/DKK", "Danish Krone"),
+            ("currency/DOP", "Dominican Peso"),
+            ("currency/DZD", "Algerian Dinar"),
+            ("currency/EGP", "Egyptian Pound"),
+            ("currency/ETB", "Ethiopian Birr"),
+            ("currency/EUR", "Euro"),
+            ("currency/GBP", "British Pound"),
+            ("currency/GEL", "Georgian Lari"),
+            ("currency/GHS", "Ghanaian Cedi"),
+            ("currency/GTQ", "Guatemalan Quetzal"),
+            ("currency/HKD", "Hong Kong Dollar"),
+            ("currency/HUF", "Hungarian Forint"),
+            ("currency/IDR", "Indonesian Rupiah"),
+            ("currency/ILS", "Israeli New Shekel"),
+            ("currency/INR", "Indian Rupee"),
+            ("currency/IQD", "Iraqi Dinar"),
+            ("currency/IRR", "Iranian Rial"),
+            ("currency/ISK", "Icelandic Króna"),
+            ("currency/JMD", "Jamaican Dollar"),
+            ("currency/JOD", "Jordanian Dinar"),
+            ("currency/JPY", "Japanese Yen"),
+            ("currency/KES", "Kenyan Shilling"),
+            ("currency/KRW", "South Korean Won"),
+            ("currency/KWD", "Kuwaiti Dinar"),
+            ("currency/KZT", "Kazakhstani Tenge"),
+            ("currency/LBP", "Lebanese Pound"),
+            ("currency/LKR", "Sri Lankan Rupee"),
+            ("currency/MAD", "Moroccan Dirham"),
+            ("currency/MDL", "Moldovan Leu"),
+            ("currency/MXN", "Mexican Peso"),
+            ("currency/MYR", "Malaysian Ringgit"),
+            ("currency/NGN", "Nigerian Naira"),
+            ("currency/NOK", "Norwegian Krone"),
+            ("currency/NPR", "Nepalese Rupee"),
+            ("currency/NZD", "New Zealand Dollar"),
+            ("currency/OMR", "Omani Rial"),
+            ("currency/PAB", "Panamanian Balboa"),
+            ("currency/PEN", "Peruvian Sol"),
+            ("currency/PHP", "Philippine Peso"),
+            ("currency/PKR", "Pakistani Rupee"),
+            ("currency/PLN", "Polish Zloty"),
+            ("currency/PYG", "Paraguayan Guarani"),
+            ("currency/QAR", "Qatari Riyal"),
+            ("currency/RON", "Romanian Leu"),
+            ("currency/RSD", "Serbian Dinar"),
+            ("currency/RUB", "Russian Ruble"),
+            ("currency/SAR", "Saudi Riyal"),
+            ("currency/SEK", "Swedish Krona"),
+            ("currency/SGD", "Singapore Dollar"),
+            ("currency/THB", "Thai Baht"),
+            ("currency/TND", "Tunisian Dinar"),
+            ("currency/TRY", "Turkish Lira"),
+            ("currency/TWD", "New Taiwan Dollar"),
+            ("currency/TZS", "Tanzanian Shilling"),
+            ("currency/UAH", "Ukrainian Hryvnia"),
+            ("currency/UGX", "Ugandan Shilling"),
+            ("currency/USD", "US Dollar"),
+            ("currency/UYU", "Uruguayan Peso"),
+            ("currency/UZS", "Uzbekistani Som"),
+            ("currency/VND", "Vietnamese Dong"),
+            ("currency/XAF", "Central African CFA Franc"),
+            ("currency/XOF", "West African CFA Franc"),
+            ("currency/ZAR", "South African Rand"),
+            ("field-narrow/hour", "hr"),
+            ("field-narrow/minute", "min"),
+            ("field-narrow/month", "mo"),
+            ("field-narrow/quarter", "qtr"),
+            ("field-narrow/second", "sec"),
+            ("field-narrow/weekOfYear", "wk"),
+            ("field-narrow/year", "yr"),
+            ("field-short/hour", "hr."),
+            ("field-short/minute", "min."),
+            ("field-short/month", "mo."),
+            ("field-short/quarter", "qtr."),
+            ("field-short/second", "sec."),
+            ("field-short/timeZoneName", "zone"),
+            ("field-short/weekOfYear", "wk."),
+            ("field-short/weekday", "day of wk."),
+            ("field-short/year", "yr."),
+            ("field/day", "day"),
+            ("field/dayPeriod", "AM/PM"),
+            ("field/era", "era"),
+            ("field/hour", "hour"),
+            ("field/minute", "minute"),
+            ("field/month", "month"),
+            ("field/quarter", "quarter"),
+            ("field/second", "second"),
+            ("field/timeZoneName", "time zone"),
+            ("field/weekOfYear", "week"),
+            ("field/weekday", "day of the week"),
+            ("field/year", "year"),
+            ("language-short/az", "Azeri"),
+            ("language-short/en-GB", "UK English"),
+            ("language-short/en-US", "US English"),
+            ("language/af", "Afrikaans"),
+            ("language/am", "Amharic"),
+            ("language/ar", "Arabic"),
+            ("language/ar-001", "Modern Standard Arabic"),
+            ("language/as", "Assamese"),
+            ("language/az", "Azerbaijani"),
+            ("language/be", "Belarusian"),
+            ("language/bg", "Bulgarian"),
+            ("language/bn", "Bangla"),
+            ("language/bo", "Tibetan"),
+            ("language/br", "Breton"),
+            ("language/bs", "Bosnian"),
+            ("language/ca", "Catalan"),
+            ("language/chr", "Cherokee"),
+            ("language/cs", "Czech"),
+            ("language/cy", "Welsh"),
+            ("language/da", "Danish"),
+            ("language/de", "German"),
+            ("language/de-AT", "Austrian German"),
+            ("language/de-CH", "Swiss High German"),
+            ("language/dz", "Dzongkha"),
+            ("language/el", "Greek"),
+            ("language/en", "English"),
+            ("language/en-AU", "Australian English"),
+            ("language/en-CA", "Canadian English"),
+            ("language/en-GB", "British English"),
+            ("language/en-US", "American English"),
+            ("language/eo", "Esperanto"),
+            ("language/es", "Spanish"),
+            ("language/es-419", "Latin American Spanish"),
+            ("language/es-ES", "European Spanish"),
+            ("language/es-MX", "Mexican Spanish"),
+            ("language/et", "Estonian"),
+            ("language/eu", "Basque"),
+            ("language/fa", "Persian"),
+            ("language/fa-AF", "Dari"),
+            ("language/ff", "Fula"),
+            ("language/fi", "Finnish"),
+            ("language/fil", "Filipino"),
+            ("language/fo", "Faroese"),
+            ("language/fr", "French"),
+            ("language/fr-CA", "Canadian French"),
+            ("language/fr-CH", "Swiss French"),
+            ("language/fy", "Western Frisian"),
+            ("language/ga", "Irish"),
+            ("language/gd", "Scottish Gaelic"),
+            ("language/gl", "Galician"),
+            ("language/gu", "Gujarati"),
+            ("language/ha", "Hausa"),
+            ("language/haw", "Hawaiian"),
+            ("language/he", "Hebrew"),
+            ("language/hi", "Hindi"),
+            ("language/hr", "Croatian"),
+            ("language/ht", "Haitian Creole"),
+            ("language/hu", "Hungarian"),
+            ("language/hy", "Armenian"),
+            ("language/id", "Indonesian"),
+            ("language/ig", "Igbo"),
+            ("language/is", "Icelandic"),
+            ("language/it", "Italian"),
+            ("language/ja", "Japanese"),
+            ("language/jv", "Javanese"),
+            ("language/ka", "Georgian"),
+            ("language/kk", "Kazakh"),
+            ("language/km", "Khmer"),
+            ("language/kn", "Kannada"),
+            ("language/ko", "Korean"),
+            ("language/ku", "Kurdish"),
+            ("language/ky", "Kyrgyz"),
+            ("language/la", "Latin"),
+            ("language/lb", "Luxembourgish"),
+            ("language/lo", "Lao"),
+            ("language/lt", "Lithuanian"),
+            ("language/lv", "Latvian"),
+            ("language/mg", "Malagasy"),
+            ("language/mi", "Māori"),
+            ("language/mk", "Macedonian"),
+            ("language/ml", "Malayalam"),
+            ("language/mn", "Mongolian"),
+            ("language/mr", "Marathi"),
+            ("language/ms", "Malay"),
+            ("language/mt", "Maltese"),
+            ("language/my", "Burmese"),
+            ("language/nb", "Norwegian Bokmål"),
+            ("language/ne", "Nepali"),
+            ("language/nl", "Dutch"),
+            ("language/nl-BE", "Flemish"),
+            ("language/nn", "Norwegian Nynorsk"),
+            ("language/no", "Norwegian"),
+            ("language/or", "Odia"),
+            ("language/pa", "Punjabi"),
+            ("language/pl", "Polish"),
+            ("language/ps", "Pashto"),
+            ("language/pt", "Portuguese"),
+            ("language/pt-BR", "Brazilian Portuguese"),
+            ("language/pt-PT", "European Portuguese"),
+            ("language/ro", "Romanian"),
+            ("language/ro-MD", "Moldavian"),
+            ("language/ru", "Russian"),
+            ("language/rw", "Kinyarwanda"),
+            ("language/sa", "Sanskrit"),
+            ("language/sd", "Sindhi"),
+            ("language/si", "Sinhala"),
+            ("language/sk", "Slovak"),
+            ("language/sl", "Slovenian"),
+            ("language/so", "Somali"),
+            ("language/sq", "Albanian"),
+            ("language/sr", "Serbian"),
+            ("language/sv", "Swedish"),
+            ("language/sw", "Swahili"),
+            ("language/sw-CD", "Congo Swahili"),
+            ("language/ta", "Tamil"),
+            ("language/te", "Telugu"),
+            ("language/tg", "Tajik"),
+            ("language/th", "Thai"),
+            ("language/ti", "Tigrinya"),
+            ("language/tk", "Turkmen"),
+            ("language/tr", "Turkish"),
+            ("language/uk", "Ukrainian"),
+            ("language/ur", "Urdu"),
+            ("language/uz", "Uzbek"),
+            ("language/vi", "Vietnamese"),
+            ("language/xh", "Xhosa"),
+            ("language/yi", "Yiddish"),
+            ("language/yo", "Yoruba"),
+            ("language/zh", "Chinese"),
+            ("language/zh-Hans", "Simplified Chinese"),
+            ("language/zh-Hant", "Traditional Chinese"),
+            ("language/zu", "Zulu"),
+            ("pattern", " (|)"),
+            ("region-short/BA", "Bosnia"),
+            ("region-short/GB", "UK"),
+            ("region-short/HK", "Hong Kong"),
+            ("region-short/MM", "Myanmar"),
+            ("region-short/MO", "Macao"),
+            ("region-short/PS", "Palestine"),
+            ("region-short/UN", "UN"),
+            ("region-short/US", "US"),
+            ("region/001", "world"),
+            ("region/002", "Africa"),
+            ("region/003", "North America"),
+            ("region/005", "South America"),
+            ("region/009", "Oceania"),
+            ("region/019", "Americas"),
+            ("region/142", "Asia"),
+            ("region/150", "Europe"),
+            ("region/419", "Latin America"),
+            ("region/AE", "United Arab Emirates"),
+            ("region/AF", "Afghanistan"),
+            ("region/AL", "Albania"),
+            ("region/AM", "Armenia"),
+            ("region/AO", "Angola"),
+            ("region/AQ", "Antarctica"),
+            ("region/AR", "Argentina"),
+            ("region/AT", "Austria"),
+            ("region/AU", "Australia"),
+            ("region/AZ", "Azerbaijan"),
+            ("region/BA", "Bosnia & Herzegovina"),
+            ("region/BD", "Bangladesh"),
+            ("region/BE", "Belgium"),
+            ("region/BG", "Bulgaria"),
+            ("region/BO", "Bolivia"),
+            ("region/BR", "Brazil"),
+            ("region/BY", "Belarus"),
+            ("region/CA", "Canada"),
+            ("region/CD", "Congo - Kinshasa"),
+            ("region/CH", "Switzerland"),
+            ("region/CL", "Chile"),
+            ("region/CM", "Cameroon"),
+            ("region/CN", "China"),
+            ("region/CO", "Colombia"),
+            ("region/CR", "Costa Rica"),
+            ("region/CU", "Cuba"),
+            ("region/CY", "Cyprus"),
+            ("region/CZ", "Czechia"),
+            ("region/DE", "Germany"),
+            ("region/DK", "Denmark"),
+            ("region/DO", "Dominican Republic"),
+            ("region/DZ", "Algeria"),
+            ("region/EC", "Ecuador"),
+            ("region/EE", "Estonia"),
+            ("region/EG", "Egypt"),
+            ("region/ES", "Spain"),
+            ("region/ET", "Ethiopia"),
+            ("region/EU", "European Union"),
+            ("region/FI", "Finland"),
+            ("region/FR", "France"),
+            ("region/GB", "United Kingdom"),
+            ("region/GE", "Georgia"),
+            ("region/GH", "Ghana"),
+            ("region/GR", "Greece"),
+            ("region/GT", "Guatemala"),
+            ("region/HK", "Hong Kong SAR China"),
+            ("region/HR", "Croatia"),
+            ("region/HU", "Hungary"),
+            ("region/ID", "Indonesia"),
+            ("region/IE", "Ireland"),
+            ("region/IL", "Israel"),
+            ("region/IN", "India"),
+            ("region/IQ", "Iraq"),
+            ("region/IR", "Iran"),
+            ("region/IS", "Iceland"),
+            ("region/IT", "Italy"),
+            ("region/JM", "Jamaica"),
+            ("region/JO", "Jordan"),
+            ("region/JP", "Japan"),
+            ("region/KE", "Kenya"),
+            ("region/KH", "Cambodia"),
+            ("region/KP", "North Korea"),
+            ("region/KR", "South Korea"),
+            ("region/KW", "Kuwait"),
+            ("region/KZ", "Kazakhstan"),
+            ("region/LB", "Lebanon"),
+            ("region/LK", "Sri Lanka"),
+            ("region/LT", "Lithuania"),
+            ("region/LU", "Luxembourg"),
+            ("region/LV", "Latvia"),
+            ("region/MA", "Morocco"),
+            ("region/MD", "Moldova"),
+            ("region/MK", "North Macedonia"),
+            ("region/MM", "Myanmar (Burma)"),
+            ("region/MN", "Mongolia"),
+            ("region/MO", "Macao SAR China"),
+            ("region/MT", "Malta"),
+            ("region/MX", "Mexico"),
+            ("region/MY", "Malaysia"),
+            ("region/NG", "Nigeria"),
+            ("region/NL", "Netherlands"),
+            ("region/NO", "Norway"),
+            ("region/NP", "Nepal"),
+            ("region/NZ", "New Zealand"),
+            ("region/PA", "Panama"),
+            ("region/PE", "Peru"),
+            ("region/PH", "Philippines"),
+            ("region/PK", "Pakistan"),
+            ("region/PL", "Poland"),
+            ("region/PR", "Puerto Rico"),
+            ("region/PS", "Palestinian Territories"),
+            ("region/PT", "Portugal"),
+            ("region/PY", "Paraguay"),
+            ("region/QA", "Qatar"),
+            ("region/RO", "Romania"),
+            ("region/RS", "Serbia"),
+            ("region/RU", "Russia"),
+            ("region/SA", "Saudi Arabia"),
+            ("region/SE", "Sweden"),
+            ("region/SG", "Singapore"),
+            ("region/SI", "Slovenia"),
+            ("region/SK", "Slovakia"),
+            ("region/SN", "Senegal"),
+            ("region/SY", "Syria"),
+            ("region/TH", "Thailand"),
+            ("region/TN", "Tunisia"),
+            ("region/TR", "Turkey"),
+            ("region/TW", "Taiwan"),
+            ("region/TZ", "Tanzania"),
+            ("region/UA", "Ukraine"),
+            ("region/UG", "Uganda"),
+            ("region/UN", "United Nations"),
+            ("region/US", "United States"),
+            ("region/UY", "Uruguay"),
+            ("region/UZ", "Uzbekistan"),
+            ("region/VE", "Venezuela"),
+            ("region/VN", "Vietnam"),
+            ("region/ZA", "South Africa"),
+            ("region/ZM", "Zambia"),
+            ("region/ZW", "Zimbabwe"),
+            ("script-stand-alone/Hans", "Simplified Han"),
+            ("script-stand-alone/Hant", "Traditional Han"),
+            ("script/Arab", "Arabic"),
+            ("script/Armn", "Armenian"),
+            ("script/Beng", "Bangla"),
+            ("script/Bopo", "Bopomofo"),
+            ("script/Brai", "Braille"),
+            ("script/Cyrl", "Cyrillic"),
+            ("script/Deva", "Devanagari"),
+            ("script/Ethi", "Ethiopic"),
+            ("script/Geor", "Georgian"),
+            ("script/Grek", "Greek"),
+            ("script/Gujr", "Gujarati"),
+            ("script/Guru", "Gurmukhi"),
+            ("script/Hang", "Hangul"),
+            ("script/Hani", "Han"),
+            ("script/Hans", "Simplified"),
+            ("script/Hant", "Traditional"),
+            ("script/Hebr", "Hebrew"),
+            ("script/Hira", "Hiragana"),
+            ("script/Jpan", "Japanese"),
+            ("script/Kana", "Katakana"),
+            ("script/Khmr", "Khmer"),
+            ("script/Knda", "Kannada"),
+            ("script/Kore", "Korean"),
+            ("script/Laoo", "Lao"),
+            ("script/Latn", "Latin"),
+            ("script/Mlym", "Malayalam"),
+            ("script/Mong", "Mongolian"),
+            ("script/Mymr", "Myanmar"),
+            ("script/Orya", "Odia"),
+            ("script/Sinh", "Sinhala"),
+            ("script/Taml", "Tamil"),
+            ("script/Telu", "Telugu"),
+            ("script/Thai", "Thai"),
+            ("separator", ", "),
+        ],
+    ),
+    (
+        "en-001",
+        &[
+            ("currency/RUB", "Russian Rouble"),
+            ("field-short/hour", "hr"),
+            ("field-short/minute", "min"),
+            ("field-short/month", "mo"),
+            ("field-short/quarter", "qtr"),
+            ("field-short/second", "sec"),
+            ("field-short/weekOfYear", "wk"),
+            ("field-short/weekday", "day of wk"),
+            ("field-short/year", "yr"),
+            ("field/dayPeriod", "am/pm"),
+        ],
+    ),
+    (
+        "en-AU",
+        &[
+            ("calendar/dangi", "Korean Calendar"),
+            ("calendar/ethiopic", "Ethiopian Calendar"),
+            ("currency/BOB", "Bolivian boliviano"),
+            ("currency/ILS", "Israeli Shekel"),
+            ("currency/UYU", "Peso Uruguayo"),
+            ("field-short/minute", "min."),
+            ("field-short/month", "mo."),
+            ("field-short/second", "sec."),
+            ("language/bn", "Bengali"),
+            ("language/en-US", "United States English"),
+            ("language/ro-MD", "Moldovan"),
+            ("region/001", "World"),
+            ("script/Beng", "Bengali"),
+        ],
+    ),
+    (
+        "en-CA",
+        &[
+            ("calendar/buddhist", "Buddhist calendar"),
+            ("calendar/chinese", "Chinese calendar"),
+            ("calendar/coptic", "Coptic calendar"),
+            ("calendar/dangi", "Dangi calendar"),
+            ("calendar/ethioaa", "Ethiopic Amete Alem calendar"),
+            ("calendar/ethiopic", "Ethiopic calendar"),
+            ("calendar/gregory", "Gregorian calendar"),
+            ("calendar/hebrew", "Hebrew calendar"),
+            ("calendar/indian", "Indian national calendar"),
+            ("calendar/islamic", "Islamic calendar"),
+            (
+                "calendar/islamic-civil",
+                "Islamic calendar (tabular, civil epoch)",
+            ),
+            (
+                "calendar/islamic-tbla",
+                "Islamic calendar (tabular, astronomical epoch)",
+            ),
+            (
+                "calendar/islamic-umalqura",
+                "Islamic calendar (Umm al-Qura)",
+            ),
+            ("calendar/iso8601", "ISO-8601 calendar"),
+            ("calendar/japanese", "Japanese calendar"),
+            ("calendar/persian", "Persian calendar"),
+            ("calendar/roc", "Minguo calendar"),
+            ("currency/RUB", "Russian Rouble"),
+            ("field-short/hour", "hr"),
+            ("field-short/minute", "min"),
+            ("field-short/month", "mo"),
+            ("field-short/quarter", "qtr"),
+            ("field-short/second", "sec"),
+            ("field-short/weekOfYear", "wk"),
+            ("field-short/weekday", "day of wk"),
+            ("field-short/year", "yr"),
+            ("field/dayPeriod", "a.m./p.m."),
+            ("language/ar-001", "Arabic (Modern Standard)"),
+            ("language/bn", "Bengali"),
+            ("language/ro-MD", "Moldovan"),
+            ("language/zh-Hans", "simplified Chinese"),
+            ("language/zh-Hant", "traditional Chinese"),
+            ("region-short/MO", "Macau"),
+            ("region/BA", "Bosnia and Herzegovina"),
+            ("region/PS", "Palestinian territories"),
+        ],
+    ),
+    ("en-GB", &[("language/ff", "Fulah")]),
+    ("en-GG", &[("currency/GBP", "UK Pound")]),
+    ("en-IM", &[("currency/GBP", "UK Pound")]),
+    (
+        "en-IN",
+        &[
+            ("field-short/dayPeriod", "am/pm"),
+            ("field/dayPeriod", "AM/PM"),
+            ("language/bn", "Bengali"),
+            ("script/Beng", "Bengali"),
+            ("script/Orya", "Oriya"),
+        ],
+    ),
+    ("en-JE", &[("currency/GBP", "UK Pound")]),
+    ("en-SG", &[("field-short/month", "mth")]),
+    (
+        "es",
+        &[
+            ("calendar/buddhist", "calendario budista"),
+            ("calendar/chinese", "calendario chino"),
+            ("calendar/coptic", "calendario cóptico"),
+            ("calendar/dangi", "calendario dangi"),
+            ("calendar/ethioaa", "calendario etíope Amete Alem"),
+            ("calendar/ethiopic", "calendario etíope"),
+            ("calendar/gregory", "calendario gregoriano"),
+            ("calendar/hebrew", "calendario hebreo"),
+            ("calendar/indian", "calendario nacional hindú"),
+            ("calendar/islamic", "calendario islámico"),
+            ("calendar/islamic-civil", "calendario islámico tabular"),
+            (
+                "calendar/islamic-umalqura",
+                "calendario islámico Umm al-Qura",
+            ),
+            ("calendar/iso8601", "calendario ISO-8601"),
+            ("calendar/japanese", "calendario japonés"),
+            ("calendar/persian", "calendario persa"),
+            ("calendar/roc", "calendario de la República de China"),
+            ("currency/AED", "dírham de los Emiratos Árabes Unidos"),
+            ("currency/ARS", "peso argentino"),
+            ("currency/AUD", "dólar australiano"),
+            ("currency/BDT", "taka"),
+            ("currency/BGN", "leva búlgara"),
+            ("currency/BHD", "dinar bareiní"),
+            ("currency/BOB", "boliviano"),
+            ("currency/BRL", "real brasileño"),
+            ("currency/CAD", "dólar canadiense"),
+            ("currency/CHF", "franco suizo"),
+            ("currency/CLP", "peso chileno"),
+            ("currency/CNY", "yuan"),
+            ("currency/COP", "peso colombiano"),
+            ("currency/CRC", "colón costarricense"),
+            ("currency/CZK", "corona checa"),
+            ("currency/DKK", "corona danesa"),
+            ("currency/DOP", "peso dominicano"),
+            ("currency/DZD", "dinar argelino"),
+            ("currency/EGP", "libra egipcia"),
+            ("currency/ETB", "bir"),
+            ("currency/EUR", "euro"),
+            ("currency/GBP", "libra esterlina"),
+            ("currency/GEL", "lari"),
+            ("currency/GHS", "cedi"),
+            ("currency/GTQ", "quetzal guatemalteco"),
+            ("currency/HKD", "dólar hongkonés"),
+            ("currency/HUF", "forinto húngaro"),
+            ("currency/IDR", "rupia indonesia"),
+            ("currency/ILS", "nuevo séquel israelí"),
+            ("currency/INR", "rupia india"),
+            ("currency/IQD", "dinar iraquí"),
+            ("currency/IRR", "rial iraní"),
+            ("currency/ISK", "corona islandesa"),
+            ("currency/JMD", "dólar jamaicano"),
+            ("currency/JOD", "dinar jordano"),
+            ("currency/JPY", "yen"),
+            ("currency/KES", "chelín keniano"),
+            ("currency/KRW", "won surcoreano"),
+            ("currency/KWD", "dinar kuwaití"),
+            ("currency/KZT", "tengue kazajo"),
+            ("currency/LBP", "libra libanesa"),
+            ("currency/LKR", "rupia esrilanquesa"),
+            ("currency/MAD", "dírham marroquí"),
+            ("currency/MDL", "leu moldavo"),
+            ("currency/MXN", "peso mexicano"),
+            ("currency/MYR", "ringit"),
+            ("currency/NGN", "naira"),
+            ("currency/NOK", "corona noruega"),
+            ("currency/NPR", "rupia nepalí"),
+            ("currency/NZD", "dólar neozelandés"),
+            ("currency/OMR", "rial omaní"),
+            ("currency/PAB", "balboa panameño"),
+            ("currency/PEN", "sol peruano"),
+            ("currency/PHP", "peso filipino"),
+            ("currency/PKR", "rupia pakistaní"),
+            ("currency/PLN", "esloti"),
+            ("currency/PYG", "guaraní paraguayo"),
+            ("currency/QAR", "rial catarí"),
+            ("currency/RON", "leu rumano"),
+            ("currency/RSD", "dinar serbio"),
+            ("currency/RUB", "rublo ruso"),
+            ("currency/SAR", "rial saudí"),
+            ("currency/SEK", "corona sueca"),
+            ("currency/SGD", "dólar singapurense"),
+            ("currency/THB", "bat"),
+            ("currency/TND", "dinar tunecino"),
+            ("currency/TRY", "lira turca"),
+            ("currency/TWD", "nuevo dólar taiwanés"),
+            ("currency/TZS", "chelín tanzano"),
+            ("currency/UAH", "grivna"),
+            ("currency/UGX", "chelín ugandés"),
+            ("currency/USD", "dólar estadounidense"),
+            ("currency/UYU", "peso uruguayo"),
+            ("currency/UZS", "sum"),
+            ("currency/VND", "dong"),
+            ("currency/XAF", "franco CFA de África Central"),
+            ("currency/XOF", "franco CFA de África Occidental"),
+            ("currency/ZAR", "rand"),
+            ("field-short/day", "d"),
+            ("field-short/hour", "h"),
+            ("field-short/minute", "min"),
+            ("field-short/month", "m"),
+            ("field-short/quarter", "trim."),
+            ("field-short/second", "s"),
+            ("field-short/timeZoneName", "zona"),
+            ("field-short/weekOfYear", "sem."),
+            ("field-short/weekday", "día de sem."),
+            ("field-short/year", "a"),
+            ("field/day", "día"),
+            ("field/dayPeriod", "a.\u{a0}m./p.\u{a0}m."),
+            ("field/era", "era"),
+            ("field/hour", "hora"),
+            ("field/minute", "minuto"),
+            ("field/month", "mes"),
+            ("field/quarter", "trimestre"),
+            ("field/second", "segundo"),
+            ("field/timeZoneName", "zona horaria"),
+            ("field/weekOfYear", "semana"),
+            ("field/weekday", "día de la semana"),
+            ("field/year", "año"),
+            ("language-short/az", "azerí"),
+            ("language-short/en-GB", "inglés (RU)"),
+            ("language-short/en-US", "inglés (EE. UU.)"),
+            ("language/af", "afrikáans"),
+            ("language/am", "amárico"),
+            ("language/ar", "árabe"),
+            ("language/ar-001", "árabe estándar moderno"),
+            ("language/as", "asamés"),
+            ("language/az", "azerbaiyano"),
+            ("language/be", "bielorruso"),
+            ("language/bg", "búlgaro"),
+            ("language/bn", "bengalí"),
+            ("language/bo", "tibetano"),
+            ("language/br", "bretón"),
+            ("language/bs", "bosnio"),
+            ("language/ca", "catalán"),
+            ("language/chr", "cheroqui"),
+            ("language/cs", "checo"),
+            ("language/cy", "galés"),
+            ("language/da", "danés"),
+            ("language/de", "alemán"),
+            ("language/de-AT", "alemán austríaco"),
+            ("language/de-CH", "alto alemán suizo"),
+            ("language/dz", "dzongkha"),
+            ("language/el", "griego"),
+            ("language/en", "inglés"),
+            ("language/en-AU", "inglés australiano"),
+            ("language/en-CA", "inglés canadiense"),
+            ("language/en-GB", "inglés británico"),
+            ("language/en-US", "inglés estadounidense"),
+            ("language/eo", "esperanto"),
+            ("language/es", "español"),
+            ("language/es-419", "español latinoamericano"),
+            ("language/es-ES", "español de España"),
+            ("language/es-MX", "español de México"),
+            ("language/et", "estonio"),
+            ("language/eu", "euskera"),
+            ("language/fa", "persa"),
+            ("language/fa-AF", "darí"),
+            ("language/ff", "fula"),
+            ("language/fi", "finés"),
+            ("language/fil", "filipino"),
+            ("language/fo", "feroés"),
+            ("language/fr", "francés"),
+            ("language/fr-CA", "francés canadiense"),
+            ("language/fr-CH", "francés suizo"),
+            ("language/fy", "frisón occidental"),
+            ("language/ga", "irlandés"),
+            ("language/gd", "gaélico escocés"),
+            ("language/gl", "gallego"),
+            ("language/gu", "guyaratí"),
+            ("language/ha", "hausa"),
+            ("language/haw", "hawaiano"),
+            ("language/he", "hebreo"),
+            ("language/hi", "hindi"),
+            ("language/hr", "croata"),
+            ("language/ht", "criollo haitiano"),
+            ("language/hu", "húngaro"),
+            ("language/hy", "armenio"),
+            ("language/id", "indonesio"),
+            ("language/ig", "igbo"),
+            ("language/is", "islandés"),
+            ("language/it", "italiano"),
+            ("language/ja", "japonés"),
+            ("language/jv", "javanés"),
+            ("language/ka", "georgiano"),
+            ("language/kk", "kazajo"),
+            ("language/km", "jemer"),
+            ("language/kn", "canarés"),
+            ("language/ko", "coreano"),
+            ("language/ku", "kurdo"),
+            ("language/ky", "kirguís"),
+            ("language/la", "latín"),
+            ("language/lb", "luxemburgués"),
+            ("language/lo", "lao"),
+            ("language/lt", "lituano"),
+            ("language/lv", "letón"),
+            ("language/mg", "malgache"),
+            ("language/mi", "maorí"),
+            ("language/mk", "macedonio"),
+            ("language/ml", "malayálam"),
+            ("language/mn", "mongol"),
+            ("language/mr", "maratí"),
+            ("language/ms", "malayo"),
+            ("language/mt", "maltés"),
+            ("language/my", "birmano"),
+            ("language/nb", "noruego bokmal"),
+            ("language/ne", "nepalí"),
+            ("language/nl", "neerlandés"),
+            ("language/nl-BE", "flamenco"),
+            ("language/nn", "noruego nynorsk"),
+            ("language/no", "noruego"),
+            ("language/or", "oriya"),
+            ("language/pa", "punyabí"),
+            ("language/pl", "polaco"),
+            ("language/ps", "pastún"),
+            ("language/pt", "portugués"),
+            ("language/pt-BR", "portugués de Brasil"),
+            ("language/pt-PT", "portugués de Portugal"),
+            ("language/ro", "rumano"),
+            ("language/ro-MD", "moldavo"),
+            ("language/ru", "ruso"),
+            ("language/rw", "kinyarwanda"),
+            ("language/sa", "sánscrito"),
+            ("language/sd", "sindi"),
+            ("language/si", "cingalés"),
+            ("language/sk", "eslovaco"),
+            ("language/sl", "esloveno"),
+            ("language/so", "somalí"),
+            ("language/sq", "albanés"),
+            ("language/sr", "serbio"),
+            ("language/sv", "sueco"),
+            ("language/sw", "suajili"),
+            ("language/sw-CD", "suajili del Congo"),
+            ("language/ta", "tamil"),
+            ("language/te", "telugu"),
+            ("language/tg", "tayiko"),
+            ("language/th", "tailandés"),
+            ("language/ti", "tigriña"),
+            ("language/tk", "turcomano"),
+            ("language/tr", "turco"),
+            ("language/uk", "ucraniano"),
+            ("language/ur", "urdu"),
+            ("language/uz", "uzbeko"),
+            ("language/vi", "vietnamita"),
+            ("language/xh", "xhosa"),
+            ("language/yi", "yidis"),
+            ("language/yo", "yoruba"),
+            ("language/zh", "chino"),
+            ("language/zh-Hans", "chino simplificado"),
+            ("language/zh-Hant", "chino tradicional"),
+            ("language/zu", "zulú"),
+            ("pattern", " (|)"),
+            ("region-short/GB", "RU"),
+            ("region-short/HK", "Hong Kong"),
+            ("region-short/MO", "Macao"),
+            ("region-short/PS", "Palestina"),
+            ("region-short/US", "EE. UU."),
+            ("region/001", "Mundo"),
+            ("region/002", "África"),
+            ("region/003", "América del Norte"),
+            ("region/005", "Sudamérica"),
+            ("region/009", "Oceanía"),
+            ("region/019", "América"),
+            ("region/142", "Asia"),
+            ("region/150", "Europa"),
+            ("region/419", "Latinoamérica"),
+            ("region/AE", "Emiratos Árabes Unidos"),
+            ("region/AF", "Afganistán"),
+            ("region/AL", "Albania"),
+            ("region/AM", "Armenia"),
+            ("region/AO", "Angola"),
+            ("region/AQ", "Antártida"),
+            ("region/AR", "Argentina"),
+            ("region/AT", "Austria"),
+            ("region/AU", "Australia"),
+            ("region/AZ", "Azerbaiyán"),
+            ("region/BA", "Bosnia y Herzegovina"),
+            ("region/BD", "Bangladés"),
+            ("region/BE", "Bélgica"),
+            ("region/BG", "Bulgaria"),
+            ("region/BO", "Bolivia"),
+            ("region/BR", "Brasil"),
+            ("region/BY", "Bielorrusia"),
+            ("region/CA", "Canadá"),
+            ("region/CD", "República Democrática del Congo"),
+            ("region/CH", "Suiza"),
+            ("region/CL", "Chile"),
+            ("region/CM", "Camerún"),
+            ("region/CN", "China"),
+            ("region/CO", "Colombia"),
+            ("region/CR", "Costa Rica"),
+            ("region/CU", "Cuba"),
+            ("region/CY", "Chipre"),
+            ("region/CZ", "Chequia"),
+            ("region/DE", "Alemania"),
+            ("region/DK", "Dinamarca"),
+            ("region/DO", "República Dominicana"),
+            ("region/DZ", "Argelia"),
+            ("region/EC", "Ecuador"),
+            ("region/EE", "Estonia"),
+            ("region/EG", "Egipto"),
+            ("region/ES", "España"),
+            ("region/ET", "Etiopía"),
+            ("region/EU", "Unión Europea"),
+            ("region/FI", "Finlandia"),
+            ("region/FR", "Francia"),
+            ("region/GB", "Reino Unido"),
+            ("region/GE", "Georgia"),
+            ("region/GH", "Ghana"),
+            ("region/GR", "Grecia"),
+            ("region/GT", "Guatemala"),
+            ("region/HK", "RAE de Hong Kong (China)"),
+            ("region/HR", "Croacia"),
+            ("region/HU", "Hungría"),
+            ("region/ID", "Indonesia"),
+            ("region/IE", "Irlanda"),
+            ("region/IL", "Israel"),
+            ("region/IN", "India"),
+            ("region/IQ", "Irak"),
+            ("region/IR", "Irán"),
+            ("region/IS", "Islandia"),
+            ("region/IT", "Italia"),
+            ("region/JM", "Jamaica"),
+            ("region/JO", "Jordania"),
+            ("region/JP", "Japón"),
+            ("region/KE", "Kenia"),
+            ("region/KH", "Camboya"),
+            ("region/KP", "Corea del Norte"),
+            ("region/KR", "Corea del Sur"),
+            ("region/KW", "Kuwait"),
+            ("region/KZ", "Kazajistán"),
+            ("region/LB", "Líbano"),
+            ("region/LK", "Sri Lanka"),
+            ("region/LT", "Lituania"),
+            ("region/LU", "Luxemburgo"),
+            ("region/LV", "Letonia"),
+            ("region/MA", "Marruecos"),
+            ("region/MD", "Moldavia"),
+            ("region/MK", "Macedonia del Norte"),
+            ("region/MM", "Myanmar (Birmania)"),
+            ("region/MN", "Mongolia"),
+            ("region/MO", "RAE de Macao (China)"),
+            ("region/MT", "Malta"),
+            ("region/MX", "México"),
+            ("region/MY", "Malasia"),
+            ("region/NG", "Nigeria"),
+            ("region/NL", "Países Bajos"),
+            ("region/NO", "Noruega"),
+            ("region/NP", "Nepal"),
+            ("region/NZ", "Nueva Zelanda"),
+            ("region/PA", "Panamá"),
+            ("region/PE", "Perú"),
+            ("region/PH", "Filipinas"),
+            ("region/PK", "Pakistán"),
+            ("region/PL", "Polonia"),
+            ("region/PR", "Puerto Rico"),
+            ("region/PS", "Territorios Palestinos"),
+            ("region/PT", "Portugal"),
+            ("region/PY", "Paraguay"),
+            ("region/QA", "Catar"),
+            ("region/RO", "Rumanía"),
+            ("region/RS", "Serbia"),
+            ("region/RU", "Rusia"),
+            ("region/SA", "Arabia Saudí"),
+            ("region/SE", "Suecia"),
+            ("region/SG", "Singapur"),
+            ("region/SI", "Eslovenia"),
+            ("region/SK", "Eslovaquia"),
+            ("region/SN", "Senegal"),
+            ("region/SY", "Siria"),
+            ("region/TH", "Tailandia"),
+            ("region/TN", "Túnez"),
+            ("region/TR", "Turquía"),
+            ("region/TW", "Taiwán"),
+            ("region/TZ", "Tanzania"),
+            ("region/UA", "Ucrania"),
+            ("region/UG", "Uganda"),
+            ("region/UN", "Naciones Unidas"),
+            ("region/US", "Estados Unidos"),
+            ("region/UY", "Uruguay"),
+            ("region/UZ", "Uzbekistán"),
+            ("region/VE", "Venezuela"),
+            ("region/VN", "Vietnam"),
+            ("region/ZA", "Sudáfrica"),
+            ("region/ZM", "Zambia"),
+            ("region/ZW", "Zimbabue"),
+            ("script-stand-alone/Hans", "han simplificado"),
+            ("script-stand-alone/Hant", "han tradicional"),
+            ("script/Arab", "árabe"),
+            ("script/Armn", "armenio"),
+            ("script/Beng", "bengalí"),
+            ("script/Bopo", "bopomofo"),
+            ("script/Brai", "braille"),
+            ("script/Cyrl", "cirílico"),
+            ("script/Deva", "devanagari"),
+            ("script/Ethi", "etiópico"),
+            ("script/Geor", "georgiano"),
+            ("script/Grek", "griego"),
+            ("script/Gujr", "guyaratí"),
+            ("script/Guru", "gurmuji"),
+            ("script/Hang", "hangul"),
+            ("script/Hani", "han"),
+            ("script/Hans", "simplificado"),
+            ("script/Hant", "tradicional"),
+            ("script/Hebr", "hebreo"),
+            ("script/Hira", "hiragana"),
+            ("script/Jpan", "japonés"),
+            ("script/Kana", "katakana"),
+            ("script/Khmr", "jemer"),
+            ("script/Knda", "canarés"),
+            ("script/Kore", "coreano"),
+            ("script/Laoo", "laosiano"),
+            ("script/Latn", "latino"),
+            ("script/Mlym", "malayálam"),
+            ("script/Mong", "mongol"),
+            ("script/Mymr", "birmano"),
+            ("script/Orya", "oriya"),
+            ("script/Sinh", "cingalés"),
+            ("script/Taml", "tamil"),
+            ("script/Telu", "telugu"),
+            ("script/Thai", "tailandés"),
+            ("separator", ", "),
+        ],
+    ),
+    (
+        "es-419",
+        &[
+            (
+                "calendar/islamic-rgsa",
+                "calendario islámico (Arabia Saudita)",
+            ),
+            ("calendar/islamic-tbla", "calendario islámico tabular"),
+            ("currency/KZT", "tenge kazajo"),
+            ("currency/THB", "baht tailandes"),
+            ("currency/UZS", "som uzbeko"),
+            ("field/dayPeriod", "a.m./p.m."),
+            ("language/eu", "vasco"),
+            ("language/gu", "gujarati"),
+            ("language/ht", "haitiano"),
+            ("language/lo", "laosiano"),
+            ("language/ml", "malabar"),
+            ("language/pa", "panyabí"),
+            ("language/sd", "sindhi"),
+            ("language/sw", "swahili"),
+            ("language/sw-CD", "swahili (Congo)"),
+            ("region-short/GB", "R. U."),
+            ("region/BA", "Bosnia-Herzegovina"),
+            ("script/Gujr", "gujarati"),
+            ("script/Laoo", "lao"),
+            ("script/Latn", "latín"),
+            ("script/Mlym", "malabar"),
+        ],
+    ),
+    (
+        "es-AR",
+        &[
+            ("field-short/dayPeriod", "a.m./p.m."),
+            ("field-short/second", "seg."),
+            ("field/dayPeriod", "a.\u{a0}m./p.\u{a0}m."),
+            ("language/eu", "euskera"),
+            ("language/lo", "lao"),
+            ("language/pa", "punyabí"),
+            ("language/sw", "suajili"),
+            ("language/sw-CD", "suajili del Congo"),
+            ("region-short/GB", "RU"),
+            ("region/BA", "Bosnia y Herzegovina"),
+        ],
+    ),
+    (
+        "es-BO",
+        &[
+            ("field-short/dayPeriod", "a.m./p.m."),
+            ("field/dayPeriod", "a.\u{a0}m./p.\u{a0}m."),
+            ("language/eu", "euskera"),
+            ("language/lo", "lao"),
+            ("language/pa", "punyabí"),
+            ("language/sw", "suajili"),
+            ("language/sw-CD", "suajili del Congo"),
+            ("region-short/GB", "RU"),
+            ("region/BA", "Bosnia y Herzegovina"),
+        ],
+    ),
+    (
+        "es-CL",
+        &[
+            ("currency/CLP", "Peso chileno"),
+            ("field-short/dayPeriod", "a.m./p.m."),
+            ("field/dayPeriod", "a.\u{a0}m./p.\u{a0}m."),
+            ("language/eu", "euskera"),
+            ("language/lo", "lao"),
+            ("language/pa", "punyabí"),
+            ("language/sw", "suajili"),
+            ("language/sw-CD", "suajili del Congo"),
+            ("region-short/GB", "RU"),
+            ("region/BA", "Bosnia y Herzegovina"),
+        ],
+    ),
+    (
+        "es-CO",
+        &[
+            ("field-short/dayPeriod", "a.m./p.m."),
+            ("field/dayPeriod", "a.\u{a0}m./p.\u{a0}m."),
+            ("language/eu", "euskera"),
+            ("language/lo", "lao"),
+            ("language/pa", "punyabí"),
+            ("language/sw", "suajili"),
+            ("language/sw-CD", "suajili del Congo"),
+            ("region-short/GB", "RU"),
+            ("region/BA", "Bosnia y Herzegovina"),
+        ],
+    ),
+    (
+        "es-CR",
+        &[
+            ("field-short/dayPeriod", "a.m./p.m."),
+            ("field/dayPeriod", "a.\u{a0}m./p.\u{a0}m."),
+            ("language/eu", "euskera"),
+            ("language/lo", "lao"),
+            ("language/pa", "punyabí"),
+            ("language/sw", "suajili"),
+            ("language/sw-CD", "suajili del Congo"),
+            ("region-short/GB", "RU"),
+            ("region/BA", "Bosnia y Herzegovina"),
+        ],
+    ),
+    (
+        "es-DO",
+        &[
+            ("field-short/dayPeriod", "a.m./p.m."),
+            ("field-short/era", "era"),
+            ("field/day", "Día"),
+            ("field/dayPeriod", "a.\u{a0}m./p.\u{a0}m."),
+            ("field/era", "Era"),
+            ("field/minute", "Minuto"),
+            ("field/month", "Mes"),
+            ("field/quarter", "Trimestre"),
+            ("field/second", "Segundo"),
+            ("field/weekOfYear", "Semana"),
+            ("field/weekday", "Día de la semana"),
+            ("field/year", "Año"),
+            ("language/eu", "euskera"),
+            ("language/lo", "lao"),
+            ("language/pa", "punyabí"),
+            ("language/sw", "suajili"),
+            ("language/sw-CD", "suajili del Congo"),
+            ("region-short/GB", "RU"),
+            ("region/BA", "Bosnia y Herzegovina"),
+        ],
+    ),
+    (
+        "es-EC",
+        &[
+            ("field-short/dayPeriod", "a.m./p.m."),
+            ("field/dayPeriod", "a.\u{a0}m./p.\u{a0}m."),
+            ("language/eu", "euskera"),
+            ("language/lo", "lao"),
+            ("language/pa", "punyabí"),
+            ("language/sw", "suajili"),
+            ("language/sw-CD", "suajili del Congo"),
+            ("region-short/GB", "RU"),
+            ("region/BA", "Bosnia y Herzegovina"),
+        ],
+    ),
+    (
+        "es-GT",
+        &[
+            ("currency/GTQ", "quetzal"),
+            ("field-short/dayPeriod", "a.m./p.m."),
+            ("field/dayPeriod", "a.\u{a0}m./p.\u{a0}m."),
+            ("language/eu", "euskera"),
+            ("language/lo", "lao"),
+            ("language/pa", "punyabí"),
+            ("language/sw", "suajili"),
+            ("language/sw-CD", "suajili del Congo"),
+            ("region-short/GB", "RU"),
+            ("region/BA", "Bosnia y Herzegovina"),
+        ],
+    ),
+    (
+        "es-HN",
+        &[
+            ("language/eu", "euskera"),
+            ("language/lo", "lao"),
+            ("language/pa", "punyabí"),
+            ("language/sw", "suajili"),
+            ("language/sw-CD", "suajili del Congo"),
+            ("region-short/GB", "RU"),
+            ("region/BA", "Bosnia y Herzegovina"),
+        ],
+    ),
+    (
+        "es-MX",
+        &[
+            ("calendar/roc", "calendario minguo"),
+            ("currency/BDT", "taka bangladesí"),
+            ("currency/THB", "baht tailandés"),
+            ("currency/VND", "dong vietnamita"),
+            ("language-short/en-GB", "inglés (R. U.)"),
+            ("language/eu", "euskera"),
+            ("language/lo", "lao"),
+            ("language/pa", "punyabí"),
+            ("language/sw", "suajili"),
+            ("language/sw-CD", "suajili del Congo"),
+            ("region-short/GB", "RU"),
+            ("region/BA", "Bosnia y Herzegovina"),
+            ("region/RO", "Rumania"),
+            ("region/SA", "Arabia Saudita"),
+        ],
+    ),
+    (
+        "es-NI",
+        &[
+            ("language/eu", "euskera"),
+            ("language/lo", "lao"),
+            ("language/pa", "punyabí"),
+            ("language/sw", "suajili"),
+            ("language/sw-CD", "suajili del Congo"),
+            ("region-short/GB", "RU"),
+            ("region/BA", "Bosnia y Herzegovina"),
+        ],
+    ),
+    (
+        "es-PA",
+        &[
+            ("language/eu", "euskera"),
+            ("language/lo", "lao"),
+            ("language/pa", "punyabí"),
+            ("language/sw", "suajili"),
+            ("language/sw-CD", "suajili del Congo"),
+            ("region-short/GB", "RU"),
+            ("region/BA", "Bosnia y Herzegovina"),
+        ],
+    ),
+    (
+        "es-PE",
+        &[
+            ("language/eu", "euskera"),
+            ("language/lo", "lao"),
+            ("language/pa", "punyabí"),
+            ("language/sw", "suajili"),
+            ("language/sw-CD", "suajili del Congo"),
+            ("region-short/GB", "RU"),
+            ("region/BA", "Bosnia y Herzegovina"),
+        ],
+    ),
+    (
+        "es-PY",
+        &[
+            ("field-narrow/second", "s"),
+            ("field-short/second", "seg."),
+            ("language/eu", "euskera"),
+            ("language/lo", "lao"),
+            ("language/pa", "punyabí"),
+            ("language/sw", "suajili"),
+            ("language/sw-CD", "suajili del Congo"),
+            ("region-short/GB", "RU"),
+            ("region/BA", "Bosnia y Herzegovina"),
+        ],
+    ),
+    (
+        "es-SV",
+        &[
+            ("field-short/dayPeriod", "a.m./p.m."),
+            ("field/dayPeriod", "a.\u{a0}m./p.\u{a0}m."),
+        ],
+    ),
+    (
+        "es-US",
+        &[
+            ("currency/BDT", "taka bangladesí"),
+            ("currency/ETB", "birr"),
+            ("currency/THB", "bat"),
+            ("currency/UZS", "sum"),
+            ("currency/VND", "dong vietnamita"),
+            ("currency/XAF", "franco CFA de África central"),
+            ("field/dayPeriod", "a.\u{a0}m./p.\u{a0}m."),
+            ("language/eu", "euskera"),
+            ("language/gu", "gurayatí"),
+            ("language/ht", "criollo haitiano"),
+            ("language/lo", "lao"),
+            ("language/sw-CD", "swahili del Congo"),
+            ("region-short/GB", "RU"),
+            ("region/BA", "Bosnia y Herzegovina"),
+        ],
+    ),
+    (
+        "es-VE",
+        &[
+            ("language/eu", "euskera"),
+            ("language/lo", "lao"),
+            ("language/pa", "punyabí"),
+            ("language/sw", "suajili"),
+            ("language/sw-CD", "suajili del Congo"),
+            ("region-short/GB", "RU"),
+            ("region/BA", "Bosnia y Herzegovina"),
+        ],
+    ),
+    (
+        "fr",
+        &[
+            ("calendar/buddhist", "calendrier bouddhiste"),
+            ("calendar/chinese", "calendrier chinois"),
+            ("calendar/coptic", "calendrier copte"),
+            ("calendar/dangi", "calendrier dangi"),
+            ("calendar/ethioaa", "calendrier éthiopien Amete Alem"),
+            ("calendar/ethiopic", "calendrier éthiopien"),
+            ("calendar/gregory", "calendrier grégorien"),
+            ("calendar/hebrew", "calendrier hébraïque"),
+            ("calendar/indian", "calendrier indien"),
+            ("calendar/islamic", "calendrier musulman"),
+            (
+                "calendar/islamic-civil",
+                "calendrier musulman (tabulaire, époque civile)",
+            ),
+            (
+                "calendar/islamic-tbla",
+                "calendrier musulman (tabulaire, époque astronomique)",
+            ),
+            (
+                "calendar/islamic-umalqura",
+                "calendrier musulman (Umm al Qura)",
+            ),
+            ("calendar/iso8601", "calendrier ISO 8601"),
+            ("calendar/japanese", "calendrier japonais"),
+            ("calendar/persian", "calendrier persan"),
+            ("calendar/roc", "calendrier républicain chinois"),
+            ("currency/AED", "dirham des Émirats arabes unis"),
+            ("currency/ARS", "peso argentin"),
+            ("currency/AUD", "dollar australien"),
+            ("currency/BDT", "taka bangladeshi"),
+            ("currency/BGN", "lev bulgare"),
+            ("currency/BHD", "dinar bahreïni"),
+            ("currency/BOB", "boliviano bolivien"),
+            ("currency/BRL", "réal brésilien"),
+            ("currency/CAD", "dollar canadien"),
+            ("currency/CHF", "franc suisse"),
+            ("currency/CLP", "peso chilien"),
+            ("currency/CNY", "yuan renminbi chinois"),
+            ("currency/COP", "peso colombien"),
+            ("currency/CRC", "colón costaricain"),
+            ("currency/CZK", "couronne tchèque"),
+            ("currency/DKK", "couronne danoise"),
+            ("currency/DOP", "peso dominicain"),
+            ("currency/DZD", "dinar algérien"),
+            ("currency/EGP", "livre égyptienne"),
+            ("currency/ETB", "birr éthiopien"),
+            ("currency/EUR", "euro"),
+            ("currency/GBP", "livre sterling"),
+            ("currency/GEL", "lari géorgien"),
+            ("currency/GHS", "cédi ghanéen"),
+            ("currency/GTQ", "quetzal guatémaltèque"),
+            ("currency/HKD", "dollar de Hong Kong"),
+            ("currency/HUF", "forint hongrois"),
+            ("currency/IDR", "roupie indonésienne"),
+            ("currency/ILS", "nouveau shekel israélien"),
+            ("currency/INR", "roupie indienne"),
+            ("currency/IQD", "dinar irakien"),
+            ("currency/IRR", "riyal iranien"),
+            ("currency/ISK", "couronne islandaise"),
+            ("currency/JMD", "dollar jamaïcain"),
+            ("currency/JOD", "dinar jordanien"),
+            ("currency/JPY", "yen japonais"),
+            ("currency/KES", "shilling kényan"),
+            ("currency/KRW", "won sud-coréen"),
+            ("currency/KWD", "dinar koweïtien"),
+            ("currency/KZT", "tenge kazakh"),
+            ("currency/LBP", "livre libanaise"),
+            ("currency/LKR", "roupie srilankaise"),
+            ("currency/MAD", "dirham marocain"),
+            ("currency/MDL", "leu moldave"),
+            ("currency/MXN", "peso mexicain"),
+            ("currency/MYR", "ringgit malais"),
+            ("currency/NGN", "naira nigérian"),
+            ("currency/NOK", "couronne norvégienne"),
+            ("currency/NPR", "roupie népalaise"),
+            ("currency/NZD", "dollar néo-zélandais"),
+            ("currency/OMR", "riyal omanais"),
+            ("currency/PAB", "balboa panaméen"),
+            ("currency/PEN", "sol péruvien"),
+            ("currency/PHP", "peso philippin"),
+            ("currency/PKR", "roupie pakistanaise"),
+            ("currency/PLN", "zloty polonais"),
+            ("currency/PYG", "guaraní paraguayen"),
+            ("currency/QAR", "riyal qatari"),
+            ("currency/RON", "leu roumain"),
+            ("currency/RSD", "dinar serbe"),
+            ("currency/RUB", "rouble russe"),
+            ("currency/SAR", "riyal saoudien"),
+            ("currency/SEK", "couronne suédoise"),
+            ("currency/SGD", "dollar de Singapour"),
+            ("currency/THB", "baht thaïlandais"),
+            ("currency/TND", "dinar tunisien"),
+            ("currency/TRY", "livre turque"),
+            ("currency/TWD", "nouveau dollar taïwanais"),
+            ("currency/TZS", "shilling tanzanien"),
+            ("currency/UAH", "hryvnia ukrainienne"),
+            ("currency/UGX", "shilling ougandais"),
+            ("currency/USD", "dollar des États-Unis"),
+            ("currency/UYU", "peso uruguayen"),
+            ("currency/UZS", "sum ouzbek"),
+            ("currency/VND", "dông vietnamien"),
+            ("currency/XAF", "franc CFA (BEAC)"),
+            ("currency/XOF", "franc CFA (BCEAO)"),
+            ("currency/ZAR", "rand sud-africain"),
+            ("field-narrow/year", "a"),
+            ("field-short/day", "j"),
+            ("field-short/hour", "h"),
+            ("field-short/minute", "min"),
+            ("field-short/month", "m."),
+            ("field-short/quarter", "trim."),
+            ("field-short/second", "s"),
+            ("field-short/weekOfYear", "sem."),
+            ("field-short/weekday", "j (sem.)"),
+            ("field-short/year", "an"),
+            ("field/day", "jour"),
+            ("field/dayPeriod", "cadran"),
+            ("field/era", "ère"),
+            ("field/hour", "heure"),
+            ("field/minute", "minute"),
+            ("field/month", "mois"),
+            ("field/quarter", "trimestre"),
+            ("field/second", "seconde"),
+            ("field/timeZoneName", "fuseau horaire"),
+            ("field/weekOfYear", "semaine"),
+            ("field/weekday", "jour de la semaine"),
+            ("field/year", "année"),
+            ("language-short/az", "azéri"),
+            ("language/af", "afrikaans"),
+            ("language/am", "amharique"),
+            ("language/ar", "arabe"),
+            ("language/ar-001", "arabe standard moderne"),
+            ("language/as", "assamais"),
+            ("language/az", "azerbaïdjanais"),
+            ("language/be", "biélorusse"),
+            ("language/bg", "bulgare"),
+            ("language/bn", "bengali"),
+            ("language/bo", "tibétain"),
+            ("language/br", "breton"),
+            ("language/bs", "bosniaque"),
+            ("language/ca", "catalan"),
+            ("language/chr", "cherokee"),
+            ("language/cs", "tchèque"),
+            ("language/cy", "gallois"),
+            ("language/da", "danois"),
+            ("language/de", "allemand"),
+            ("language/de-AT", "allemand autrichien"),
+            ("language/de-CH", "allemand suisse"),
+            ("language/dz", "dzongkha"),
+            ("language/el", "grec"),
+            ("language/en", "anglais"),
+            ("language/en-AU", "anglais australien"),
+            ("language/en-CA", "anglais canadien"),
+            ("language/en-GB", "anglais britannique"),
+            ("language/en-US", "anglais américain"),
+            ("language/eo", "espéranto"),
+            ("language/es", "espagnol"),
+            ("language/es-419", "espagnol d’Amérique latine"),
+            ("language/es-ES", "espagnol d’Espagne"),
+            ("language/es-MX", "espagnol du Mexique"),
+            ("language/et", "estonien"),
+            ("language/eu", "basque"),
+            ("language/fa", "persan"),
+            ("language/fa-AF", "dari"),
+            ("language/ff", "peul"),
+            ("language/fi", "finnois"),
+            ("language/fil", "filipino"),
+            ("language/fo", "féroïen"),
+            ("language/fr", "français"),
+            ("language/fr-CA", "français canadien"),
+            ("language/fr-CH", "français suisse"),
+            ("language/fy", "frison occidental"),
+            ("language/ga", "irlandais"),
+            ("language/gd", "gaélique écossais"),
+            ("language/gl", "galicien"),
+            ("language/gu", "goudjarati"),
+            ("language/ha", "haoussa"),
+            ("language/haw", "hawaïen"),
+            ("language/he", "hébreu"),
+            ("language/hi", "hindi"),
+            ("language/hr", "croate"),
+            ("language/ht", "créole haïtien"),
+            ("language/hu", "hongrois"),
+            ("language/hy", "arménien"),
+            ("language/id", "indonésien"),
+            ("language/ig", "igbo"),
+            ("language/is", "islandais"),
+            ("language/it", "italien"),
+            ("language/ja", "japonais"),
+            ("language/jv", "javanais"),
+            ("language/ka", "géorgien"),
+            ("language/kk", "kazakh"),
+            ("language/km", "khmer"),
+            ("language/kn", "kannada"),
+            ("language/ko", "coréen"),
+            ("language/ku", "kurde"),
+            ("language/ky", "kirghize"),
+            ("language/la", "latin"),
+            ("language/lb", "luxembourgeois"),
+            ("language/lo", "lao"),
+            ("language/lt", "lituanien"),
+            ("language/lv", "letton"),
+            ("language/mg", "malgache"),
+            ("language/mi", "maori"),
+            ("language/mk", "macédonien"),
+            ("language/ml", "malayalam"),
+            ("language/mn", "mongol"),
+            ("language/mr", "marathi"),
+            ("language/ms", "malais"),
+            ("language/mt", "maltais"),
+            ("language/my", "birman"),
+            ("language/nb", "norvégien bokmål"),
+            ("language/ne", "népalais"),
+            ("language/nl", "néerlandais"),
+            ("language/nl-BE", "flamand"),
+            ("language/nn", "norvégien nynorsk"),
+            ("language/no", "norvégien"),
+            ("language/or", "odia"),
+            ("language/pa", "pendjabi"),
+            ("language/pl", "polonais"),
+            ("language/ps", "pachto"),
+            ("language/pt", "portugais"),
+            ("language/pt-BR", "portugais brésilien"),
+            ("language/pt-PT", "portugais européen"),
+            ("language/ro", "roumain"),
+            ("language/ro-MD", "moldave"),
+            ("language/ru", "russe"),
+            ("language/rw", "kinyarwanda"),
+            ("language/sa", "sanskrit"),
+            ("language/sd", "sindhi"),
+            ("language/si", "cingalais"),
+            ("language/sk", "slovaque"),
+            ("language/sl", "slovène"),
+            ("language/so", "somali"),
+            ("language/sq", "albanais"),
+            ("language/sr", "serbe"),
+            ("language/sv", "suédois"),
+            ("language/sw", "swahili"),
+            ("language/sw-CD", "swahili du Congo"),
+            ("language/ta", "tamoul"),
+            ("language/te", "télougou"),
+            ("language/tg", "tadjik"),
+            ("language/th", "thaï"),
+            ("language/ti", "tigrigna"),
+            ("language/tk", "turkmène"),
+            ("language/tr", "turc"),
+            ("language/uk", "ukrainien"),
+            ("language/ur", "ourdou"),
+            ("language/uz", "ouzbek"),
+            ("language/vi", "vietnamien"),
+            ("language/xh", "xhosa"),
+            ("language/yi", "yiddish"),
+            ("language/yo", "yoruba"),
+            ("language/zh", "chinois"),
+            ("language/zh-Hans", "chinois simplifié"),
+            ("language/zh-Hant", "chinois traditionnel"),
+            ("language/zu", "zoulou"),
+            ("pattern", " (|)"),
+            ("region-short/GB", "R.-U."),
+            ("region-short/HK", "Hong Kong"),
+            ("region-short/MO", "Macao"),
+            ("region-short/PS", "Palestine"),
+            ("region-short/UN", "ONU"),
+            ("region-short/US", "É.-U."),
+            ("region/001", "Monde"),
+            ("region/002", "Afrique"),
+            ("region/003", "Amérique du Nord"),
+            ("region/005", "Amérique du Sud"),
+            ("region/009", "Océanie"),
+            ("region/019", "Amériques"),
+            ("region/142", "Asie"),
+            ("region/150", "Europe"),
+            ("region/419", "Amérique latine"),
+            ("region/AE", "Émirats arabes unis"),
+            ("region/AF", "Afghanistan"),
+            ("region/AL", "Albanie"),
+            ("region/AM", "Arménie"),
+            ("region/AO", "Angola"),
+            ("region/AQ", "Antarctique"),
+            ("region/AR", "Argentine"),
+            ("region/AT", "Autriche"),
+            ("region/AU", "Australie"),
+            ("region/AZ", "Azerbaïdjan"),
+            ("region/BA", "Bosnie-Herzégovine"),
+            ("region/BD", "Bangladesh"),
+            ("region/BE", "Belgique"),
+            ("region/BG", "Bulgarie"),
+            ("region/BO", "Bolivie"),
+            ("region/BR", "Brésil"),
+            ("region/BY", "Biélorussie"),
+            ("region/CA", "Canada"),
+            ("region/CD", "Congo-Kinshasa"),
+            ("region/CH", "Suisse"),
+            ("region/CL", "Chili"),
+            ("region/CM", "Cameroun"),
+            ("region/CN", "Chine"),
+            ("region/CO", "Colombie"),
+            ("region/CR", "Costa Rica"),
+            ("region/CU", "Cuba"),
+            ("region/CY", "Chypre"),
+            ("region/CZ", "Tchéquie"),
+            ("region/DE", "Allemagne"),
+            ("region/DK", "Danemark"),
+            ("region/DO", "République dominicaine"),
+            ("region/DZ", "Algérie"),
+            ("region/EC", "Équateur"),
+            ("region/EE", "Estonie"),
+            ("region/EG", "Égypte"),
+            ("region/ES", "Espagne"),
+            ("region/ET", "Éthiopie"),
+            ("region/EU", "Union européenne"),
+            ("region/FI", "Finlande"),
+            ("region/FR", "France"),
+            ("region/GB", "Royaume-Uni"),
+            ("region/GE", "Géorgie"),
+            ("region/GH", "Ghana"),
+            ("region/GR", "Grèce"),
+            ("region/GT", "Guatemala"),
+            ("region/HK", "R.A.S. chinoise de Hong Kong"),
+            ("region/HR", "Croatie"),
+            ("region/HU", "Hongrie"),
+            ("region/ID", "Indonésie"),
+            ("region/IE", "Irlande"),
+            ("region/IL", "Israël"),
+            ("region/IN", "Inde"),
+            ("region/IQ", "Irak"),
+            ("region/IR", "Iran"),
+            ("region/IS", "Islande"),
+            ("region/IT", "Italie"),
+            ("region/JM", "Jamaïque"),
+            ("region/JO", "Jordanie"),
+            ("region/JP", "Japon"),
+            ("region/KE", "Kenya"),
+            ("region/KH", "Cambodge"),
+            ("region/KP", "Corée du Nord"),
+            ("region/KR", "Corée du Sud"),
+            ("region/KW", "Koweït"),
+            ("region/KZ", "Kazakhstan"),
+            ("region/LB", "Liban"),
+            ("region/LK", "Sri Lanka"),
+            ("region/LT", "Lituanie"),
+            ("region/LU", "Luxembourg"),
+            ("region/LV", "Lettonie"),
+            ("region/MA", "Maroc"),
+            ("region/MD", "Moldavie"),
+            ("region/MK", "Macédoine du Nord"),
+            ("region/MM", "Myanmar (Birmanie)"),
+            ("region/MN", "Mongolie"),
+            ("region/MO", "R.A.S. chinoise de Macao"),
+            ("region/MT", "Malte"),
+            ("region/MX", "Mexique"),
+            ("region/MY", "Malaisie"),
+            ("region/NG", "Nigeria"),
+            ("region/NL", "Pays-Bas"),
+            ("region/NO", "Norvège"),
+            ("region/NP", "Népal"),
+            ("region/NZ", "Nouvelle-Zélande"),
+            ("region/PA", "Panama"),
+            ("region/PE", "Pérou"),
+            ("region/PH", "Philippines"),
+            ("region/PK", "Pakistan"),
+            ("region/PL", "Pologne"),
+            ("region/PR", "Porto Rico"),
+            ("region/PS", "Territoires palestiniens"),
+            ("region/PT", "Portugal"),
+            ("region/PY", "Paraguay"),
+            ("region/QA", "Qatar"),
+            ("region/RO", "Roumanie"),
+            ("region/RS", "Serbie"),
+            ("region/RU", "Russie"),
+            ("region/SA", "Arabie saoudite"),
+            ("region/SE", "Suède"),
+            ("region/SG", "Singapour"),
+            ("region/SI", "Slovénie"),
+            ("region/SK", "Slovaquie"),
+            ("region/SN", "Sénégal"),
+            ("region/SY", "Syrie"),
+            ("region/TH", "Thaïlande"),
+            ("region/TN", "Tunisie"),
+            ("region/TR", "Turquie"),
+            ("region/TW", "Taïwan"),
+            ("region/TZ", "Tanzanie"),
+            ("region/UA", "Ukraine"),
+            ("region/UG", "Ouganda"),
+            ("region/UN", "Nations Unies"),
+            ("region/US", "États-Unis"),
+            ("region/UY", "Uruguay"),
+            ("region/UZ", "Ouzbékistan"),
+            ("region/VE", "Venezuela"),
+            ("region/VN", "Viêt Nam"),
+            ("region/ZA", "Afrique du Sud"),
+            ("region/ZM", "Zambie"),
+            ("region/ZW", "Zimbabwe"),
+            ("script-stand-alone/Hans", "sinogrammes simplifiés"),
+            ("script-stand-alone/Hant", "sinogrammes traditionnels"),
+            ("script/Arab", "arabe"),
+            ("script/Armn", "arménien"),
+            ("script/Beng", "bengali"),
+            ("script/Bopo", "bopomofo"),
+            ("script/Brai", "braille"),
+            ("script/Cyrl", "cyrillique"),
+            ("script/Deva", "dévanagari"),
+            ("script/Ethi", "éthiopique"),
+            ("script/Geor", "géorgien"),
+            ("script/Grek", "grec"),
+            ("script/Gujr", "goudjarâtî"),
+            ("script/Guru", "gourmoukhî"),
+            ("script/Hang", "hangûl"),
+            ("script/Hani", "sinogrammes"),
+            ("script/Hans", "simplifié"),
+            ("script/Hant", "traditionnel"),
+            ("script/Hebr", "hébreu"),
+            ("script/Hira", "hiragana"),
+            ("script/Jpan", "japonais"),
+            ("script/Kana", "katakana"),
+            ("script/Khmr", "khmer"),
+            ("script/Knda", "kannara"),
+            ("script/Kore", "coréen"),
+            ("script/Laoo", "lao"),
+            ("script/Latn", "latin"),
+            ("script/Mlym", "malayalam"),
+            ("script/Mong", "mongol"),
+            ("script/Mymr", "birman"),
+            ("script/Orya", "odia"),
+            ("script/Sinh", "cingalais"),
+            ("script/Taml", "tamoul"),
+            ("script/Telu", "télougou"),
+            ("script/Thai", "thaï"),
+            ("separator", ", "),
+        ],
+    ),
+    ("fr-BE", &[("language/gu", "gujarati")]),
+    (
+        "fr-CA",
+        &[
+            (
+                "calendar/islamic-umalqura",
+                "calendrier musulman (calculé, Umm al-Qura)",
+            ),
+            ("currency/IRR", "rial iranien"),
+            ("currency/OMR", "rial omanais"),
+            ("currency/PEN", "nouveau sol péruvien"),
+            ("currency/QAR", "riyal du Qatar"),
+            ("currency/SAR", "rial saoudien"),
+            ("field-narrow/weekday", "j (sem.)"),
+            ("field-short/weekday", "j de la semaine"),
+            ("field-short/year", "a"),
+            ("language-short/en-US", "anglais (É.-U.)"),
+            ("language/gu", "gujarati"),
+            ("language/mr", "marathe"),
+            ("language/sw-CD", "swahili congolais"),
+            ("region/BY", "Bélarus"),
+            ("region/MM", "Myanmar"),
+            ("region/NG", "Nigéria"),
+            ("region/VN", "Vietnam"),
+            ("script-stand-alone/Hans", "caractères chinois simplifiés"),
+            (
+                "script-stand-alone/Hant",
+                "caractères chinois traditionnels",
+            ),
+            ("script/Deva", "devanagari"),
+            ("script/Gujr", "gujarati"),
+            ("script/Hans", "idéogrammes han simplifiés"),
+            ("script/Hant", "idéogrammes han traditionnels"),
+        ],
+    ),
+    ("fr-CH", &[("language/gu", "goudjrati")]),
+    (
+        "fr-HT",
+        &[
+            ("field-narrow/second", "sec."),
+            ("field-short/day", "jr."),
+            ("field-short/hour", "hr"),
+            ("field-short/minute", "min."),
+        ],
+    ),
+    (
+        "hi",
+        &[
+            ("calendar/buddhist", "बौद्ध पंचांग"),
+            ("calendar/chinese", "चीनी पंचांग"),
+            ("calendar/coptic", "कॉप्टिक कैलेंडर"),
+            ("calendar/dangi", "दांगी कैलेंडर"),
+            ("calendar/ethioaa", "इथियोपिक अमेते अलेम कैलेंडर"),
+            ("calendar/ethiopic", "इथियोपिक कैलेंडर"),
+            ("calendar/gregory", "ग्रेगोरियन कैलेंडर"),
+            ("calendar/hebrew", "हिब्रू पंचांग"),
+            ("calendar/indian", "भारतीय राष्ट्रीय कैलेंडर"),
+            ("calendar/islamic", "इस्लामी पंचांग"),
+            ("calendar/islamic-civil", "इस्लामी नागरिक कैलेंडर"),
+            ("calendar/islamic-umalqura", "इस्लामी कैलेंडर (उम्म अल-क़ुरा)"),
+            ("calendar/iso8601", "आईएसओ-8601 कैलेंडर"),
+            ("calendar/japanese", "जापानी पंचांग"),
+            ("calendar/persian", "फ़ारसी कैलेंडर"),
+            ("calendar/roc", "चीनी गणतंत्र पंचांग"),
+            ("currency/AED", "संयुक्त अरब अमीरात दिरहाम"),
+            ("currency/ARS", "अर्जेंटीनी पेसो"),
+            ("currency/AUD", "ऑस्ट्रेलियाई डॉलर"),
+            ("currency/BDT", "बांग्लादेशी टका"),
+            ("currency/BGN", "बुल्गारियाई लेव"),
+            ("currency/BHD", "बहरीनी दिनार"),
+            ("currency/BOB", "बोलिवियाई बोलिवियानो"),
+            ("currency/BRL", "ब्राज़ीली रियाल"),
+            ("currency/CAD", "कनाडाई डॉलर"),
+            ("currency/CHF", "स्विस फ़्रैंक"),
+            ("currency/CLP", "चिली पेसो"),
+            ("currency/CNY", "चीनी युआन"),
+            ("currency/COP", "कोलंबियाई पेसो"),
+            ("currency/CRC", "कोस्टा रिका कोलोन"),
+            ("currency/CZK", "चेक गणराज्य कोरुना"),
+            ("currency/DKK", "डैनिश क्रोन"),
+            ("currency/DOP", "डोमिनिकन पेसो"),
+            ("currency/DZD", "अल्जीरियाई दिनार"),
+            ("currency/EGP", "मिस्र पाउंड"),
+            ("currency/ETB", "इथियोपियन बिर"),
+            ("currency/EUR", "यूरो"),
+            ("currency/GBP", "ब्रिटिश पाउंड स्टर्लिंग"),
+            ("currency/GEL", "जॉर्जियन लारी"),
+            ("currency/GHS", "घानियन सेडी"),
+            ("currency/GTQ", "ग्वाटेमाला क्वेटज़ल"),
+            ("currency/HKD", "हाँगकाँग डॉलर"),
+            ("currency/HUF", "हंगेरियन फ़ोरिंट"),
+            ("currency/IDR", "इंडोनेशियाई रुपिया"),
+            ("currency/ILS", "इज़राइली न्यू शेकेल"),
+            ("currency/INR", "भारतीय रुपया"),
+            ("currency/IQD", "इराकी दिनार"),
+            ("currency/IRR", "ईरानी रियाल"),
+            ("currency/ISK", "आइसलैंडिक क्रोना"),
+            ("currency/JMD", "जमैकन डॉलर"),
+            ("currency/JOD", "जॉर्डनियन दिनार"),
+            ("currency/JPY", "जापानी येन"),
+            ("currency/KES", "केन्याई शिलिंग"),
+            ("currency/KRW", "दक्षिण कोरियाई वॉन"),
+            ("currency/KWD", "कुवैती दिनार"),
+            ("currency/KZT", "कज़ाखिस्तानी टेंज़"),
+            ("currency/LBP", "लेबनानी पाउंड"),
+            ("currency/LKR", "श्रीलंकाई रुपया"),
+            ("currency/MAD", "मोरक्को दिरहम"),
+            ("currency/MDL", "मोल्डोवन लियू"),
+            ("currency/MXN", "मैक्सिकन पेसो"),
+            ("currency/MYR", "मलेशियाई रिंगित"),
+            ("currency/NGN", "नाइजीरियाई नाइरा"),
+            ("currency/NOK", "नॉर्वेजियन क्रोन"),
+            ("currency/NPR", "नेपाली रुपया"),
+            ("currency/NZD", "न्यूज़ीलैंड डॉलर"),
+            ("currency/OMR", "ओमानी रियाल"),
+            ("currency/PAB", "पनामेनियन बैल्बोआ"),
+            ("currency/PEN", "पेरूवियन सोल"),
+            ("currency/PHP", "फ़िलिपीनी पेसो"),
+            ("currency/PKR", "पाकिस्तानी रुपया"),
+            ("currency/PLN", "पोलिश ज़्लॉटी"),
+            ("currency/PYG", "पैराग्वियन गुआरानी"),
+            ("currency/QAR", "क़तरी रियाल"),
+            ("currency/RON", "रोमानियाई ल्यू"),
+            ("currency/RSD", "सर्बियन दिनार"),
+            ("currency/RUB", "रूसी रूबल"),
+            ("currency/SAR", "सउदी रियाल"),
+            ("currency/SEK", "स्वीडीश क्रोना"),
+            ("currency/SGD", "सिंगापुर डॉलर"),
+            ("currency/THB", "थाई बहत"),
+            ("currency/TND", "ट्यूनीशियाई दिनार"),
+            ("currency/TRY", "तुर्की लीरा"),
+            ("currency/TWD", "नया ताईवानी डॉलर"),
+            ("currency/TZS", "तंज़ानियाई शिलिंग"),
+            ("currency/UAH", "यूक्रेनियन रिव्निया"),
+            ("currency/UGX", "युगांडाई शिलिंग"),
+            ("currency/USD", "यूएस डॉलर"),
+            ("currency/UYU", "उरुग्वियन पेसो"),
+            ("currency/UZS", "उज़्बेकिस्तानी सोम"),
+            ("currency/VND", "वियतनामी डोंग"),
+            ("currency/XAF", "केंद्रीय अफ़्रीकी CFA फ़्रैंक"),
+            ("currency/XOF", "पश्चिमी अफ़्रीकी CFA फ़्रैंक"),
+            ("currency/ZAR", "दक्षिण अफ़्रीकी रैंड"),
+            ("field-short/dayPeriod", "पू/अ"),
+            ("field-short/hour", "घं॰"),
+            ("field-short/minute", "मि॰"),
+            ("field-short/second", "से॰"),
+            ("field-short/timeZoneName", "क्षेत्र"),
+            ("field/day", "दिन"),
+            ("field/dayPeriod", "पूर्वाह्न/अपराह्न"),
+            ("field/era", "युग"),
+            ("field/hour", "घंटा"),
+            ("field/minute", "मिनट"),
+            ("field/month", "माह"),
+            ("field/quarter", "तिमाही"),
+            ("field/second", "सेकंड"),
+            ("field/timeZoneName", "समय क्षेत्र"),
+            ("field/weekOfYear", "सप्ताह"),
+            ("field/weekday", "सप्ताह का दिन"),
+            ("field/year", "वर्ष"),
+            ("language-short/az", "अज़ेरी"),
+            ("language-short/en-GB", "यू॰के॰ अंग्रेज़ी"),
+            ("language/af", "अफ़्रीकी"),
+            ("language/am", "अम्हेरी"),
+            ("language/ar", "अरबी"),
+            ("language/ar-001", "आधुनिक मानक अरबी"),
+            ("language/as", "असमिया"),
+            ("language/az", "अज़रबैजानी"),
+            ("language/be", "बेलारूसी"),
+            ("language/bg", "बुल्गारियाई"),
+            ("language/bn", "बंगाली"),
+            ("language/bo", "तिब्बती"),
+            ("language/br", "ब्रेटन"),
+            ("language/bs", "बोस्नियाई"),
+            ("language/ca", "कातालान"),
+            ("language/chr", "चेरोकी"),
+            ("language/cs", "चेक"),
+            ("language/cy", "वेल्श"),
+            ("language/da", "डेनिश"),
+            ("language/de", "जर्मन"),
+            ("language/de-AT", "ऑस्ट्रियाई जर्मन"),
+            ("language/de-CH", "स्विस उच्च जर्मन"),
+            ("language/dz", "ज़ोन्गखा"),
+            ("language/el", "यूनानी"),
+            ("language/en", "अंग्रेज़ी"),
+            ("language/en-AU", "ऑस्ट्रेलियाई अंग्रेज़ी"),
+            ("language/en-CA", "कनाडाई अंग्रेज़ी"),
+            ("language/en-GB", "ब्रिटिश अंग्रेज़ी"),
+            ("language/en-US", "अमेरिकी अंग्रेज़ी"),
+            ("language/eo", "एस्पेरेंतो"),
+            ("language/es", "स्पेनिश"),
+            ("language/es-419", "लैटिन अमेरिकी स्पेनिश"),
+            ("language/es-ES", "यूरोपीय स्पेनिश"),
+            ("language/es-MX", "मैक्सिकन स्पेनिश"),
+            ("language/et", "एस्टोनियाई"),
+            ("language/eu", "बास्क"),
+            ("language/fa", "फ़ारसी"),
+            ("language/fa-AF", "दारी"),
+            ("language/ff", "फुलाह"),
+            ("language/fi", "फ़िनिश"),
+            ("language/fil", "फ़िलिपीनो"),
+            ("language/fo", "फ़ैरोइज़"),
+            ("language/fr", "फ़्रेंच"),
+            ("language/fr-CA", "कनाडाई फ़्रेंच"),
+            ("language/fr-CH", "स्विस फ़्रेंच"),
+            ("language/fy", "पश्चिमी फ़्रिसियाई"),
+            ("language/ga", "आयरिश"),
+            ("language/gd", "स्कॉटिश गाएलिक"),
+            ("language/gl", "गैलिशियन"),
+            ("language/gu", "गुजराती"),
+            ("language/ha", "हौसा"),
+            ("language/haw", "हवाई"),
+            ("language/he", "हिब्रू"),
+            ("language/hi", "हिन्दी"),
+            ("language/hr", "क्रोएशियाई"),
+            ("language/ht", "हैतियाई"),
+            ("language/hu", "हंगेरियाई"),
+            ("language/hy", "आर्मेनियाई"),
+            ("language/id", "इंडोनेशियाई"),
+            ("language/ig", "ईग्बो"),
+            ("language/is", "आइसलैंडिक"),
+            ("language/it", "इतालवी"),
+            ("language/ja", "जापानी"),
+            ("language/jv", "जावानीज़"),
+            ("language/ka", "जॉर्जियाई"),
+            ("language/kk", "कज़ाख़"),
+            ("language/km", "खमेर"),
+            ("language/kn", "कन्नड़"),
+            ("language/ko", "कोरियाई"),
+            ("language/ku", "कुर्दिश"),
+            ("language/ky", "किर्गीज़"),
+            ("language/la", "लैटिन"),
+            ("language/lb", "लग्ज़मबर्गी"),
+            ("language/lo", "लाओ"),
+            ("language/lt", "लिथुआनियाई"),
+            ("language/lv", "लातवियाई"),
+            ("language/mg", "मालागासी"),
+            ("language/mi", "माओरी"),
+            ("language/mk", "मकदूनियाई"),
+            ("language/ml", "मलयालम"),
+            ("language/mn", "मंगोलियाई"),
+            ("language/mr", "मराठी"),
+            ("language/ms", "मलय"),
+            ("language/mt", "माल्टीज़"),
+            ("language/my", "बर्मीज़"),
+            ("language/nb", "नॉर्वेजियाई बोकमाल"),
+            ("language/ne", "नेपाली"),
+            ("language/nl", "डच"),
+            ("language/nl-BE", "फ़्लेमिश"),
+            ("language/nn", "नॉर्वेजियाई नॉयनॉर्स्क"),
+            ("language/no", "नॉर्वेजियाई"),
+            ("language/or", "ओड़िया"),
+            ("language/pa", "पंजाबी"),
+            ("language/pl", "पोलिश"),
+            ("language/ps", "पश्तो"),
+            ("language/pt", "पुर्तगाली"),
+            ("language/pt-BR", "ब्राज़ीली पुर्तगाली"),
+            ("language/pt-PT", "यूरोपीय पुर्तगाली"),
+            ("language/ro", "रोमानियाई"),
+            ("language/ro-MD", "मोलडावियन"),
+            ("language/ru", "रूसी"),
+            ("language/rw", "किन्यारवांडा"),
+            ("language/sa", "संस्कृत"),
+            ("language/sd", "सिंधी"),
+            ("language/si", "सिंहली"),
+            ("language/sk", "स्लोवाक"),
+            ("language/sl", "स्लोवेनियाई"),
+            ("language/so", "सोमाली"),
+            ("language/sq", "अल्बानियाई"),
+            ("language/sr", "सर्बियाई"),
+            ("language/sv", "स्वीडिश"),
+            ("language/sw", "स्वाहिली"),
+            ("language/sw-CD", "कांगो स्वाहिली"),
+            ("language/ta", "तमिल"),
+            ("language/te", "तेलुगू"),
+            ("language/tg", "ताजिक"),
+            ("language/th", "थाई"),
+            ("language/ti", "तिग्रीन्या"),
+            ("language/tk", "तुर्कमेन"),
+            ("language/tr", "तुर्की"),
+            ("language/uk", "यूक्रेनियाई"),
+            ("language/ur", "उर्दू"),
+            ("language/uz", "उज़्बेक"),
+            ("language/vi", "वियतनामी"),
+            ("language/xh", "ख़ोसा"),
+            ("language/yi", "यहूदी"),
+            ("language/yo", "योरूबा"),
+            ("language/zh", "चीनी"),
+            ("language/zh-Hans", "सरलीकृत चीनी"),
+            ("language/zh-Hant", "पारंपरिक चीनी"),
+            ("language/zu", "ज़ुलू"),
+            ("pattern", " (|)"),
+            ("region-short/GB", "यू॰के॰"),
+            ("region-short/HK", "हाँग काँग"),
+            ("region-short/MO", "मकाऊ"),
+            ("region-short/PS", "फ़िलिस्तीन"),
+            ("region-short/US", "अमेरिका"),
+            ("region/001", "विश्व"),
+            ("region/002", "अफ़्रीका"),
+            ("region/003", "उत्तर अमेरिका"),
+            ("region/005", "दक्षिण अमेरिका"),
+            ("region/009", "ओशिआनिया"),
+            ("region/019", "अमेरिकाज़"),
+            ("region/142", "एशिया"),
+            ("region/150", "यूरोप"),
+            ("region/419", "लैटिन अमेरिका"),
+            ("region/AE", "संयुक्त अरब अमीरात"),
+            ("region/AF", "अफ़गानिस्तान"),
+            ("region/AL", "अल्बानिया"),
+            ("region/AM", "आर्मेनिया"),
+            ("region/AO", "अंगोला"),
+            ("region/AQ", "अंटार्कटिका"),
+            ("region/AR", "अर्जेंटीना"),
+            ("region/AT", "ऑस्ट्रिया"),
+            ("region/AU", "ऑस्ट्रेलिया"),
+            ("region/AZ", "अज़रबैजान"),
+            ("region/BA", "बोस्निया और हर्ज़ेगोविना"),
+            ("region/BD", "बांग्लादेश"),
+            ("region/BE", "बेल्जियम"),
+            ("region/BG", "बुल्गारिया"),
+            ("region/BO", "बोलीविया"),
+            ("region/BR", "ब्राज़ील"),
+            ("region/BY", "बेलारूस"),
+            ("region/CA", "कनाडा"),
+            ("region/CD", "कांगो - किंशासा"),
+            ("region/CH", "स्विट्ज़रलैंड"),
+            ("region/CL", "चिली"),
+            ("region/CM", "कैमरून"),
+            ("region/CN", "चीन"),
+            ("region/CO", "कोलंबिया"),
+            ("region/CR", "कोस्टारिका"),
+            ("region/CU", "क्यूबा"),
+            ("region/CY", "साइप्रस"),
+            ("region/CZ", "चेकिया"),
+            ("region/DE", "जर्मनी"),
+            ("region/DK", "डेनमार्क"),
+            ("region/DO", "डोमिनिकन गणराज्य"),
+            ("region/DZ", "अल्जीरिया"),
+            ("region/EC", "इक्वाडोर"),
+            ("region/EE", "एस्टोनिया"),
+            ("region/EG", "मिस्र"),
+            ("region/ES", "स्पेन"),
+            ("region/ET", "इथियोपिया"),
+            ("region/EU", "यूरोपीय संघ"),
+            ("region/FI", "फ़िनलैंड"),
+            ("region/FR", "फ़्रांस"),
+            ("region/GB", "यूनाइटेड किंगडम"),
+            ("region/GE", "जॉर्जिया"),
+            ("region/GH", "घाना"),
+            ("region/GR", "यूनान"),
+            ("region/GT", "ग्वाटेमाला"),
+            ("region/HK", "हाँग काँग (चीन विशेष प्रशासनिक क्षेत्र)"),
+            ("region/HR", "क्रोएशिया"),
+            ("region/HU", "हंगरी"),
+            ("region/ID", "इंडोनेशिया"),
+            ("region/IE", "आयरलैंड"),
+            ("region/IL", "इज़राइल"),
+            ("region/IN", "भारत"),
+            ("region/IQ", "इराक"),
+            ("region/IR", "ईरान"),
+            ("region/IS", "आइसलैंड"),
+            ("region/IT", "इटली"),
+            ("region/JM", "जमैका"),
+            ("region/JO", "जॉर्डन"),
+            ("region/JP", "जापान"),
+            ("region/KE", "केन्या"),
+            ("region/KH", "कंबोडिया"),
+            ("region/KP", "उत्तर कोरिया"),
+            ("region/KR", "दक्षिण कोरिया"),
+            ("region/KW", "कुवैत"),
+            ("region/KZ", "कज़ाखस्तान"),
+            ("region/LB", "लेबनान"),
+            ("region/LK", "श्रीलंका"),
+            ("region/LT", "लिथुआनिया"),
+            ("region/LU", "लग्ज़मबर्ग"),
+            ("region/LV", "लातविया"),
+            ("region/MA", "मोरक्को"),
+            ("region/MD", "मॉल्डोवा"),
+            ("region/MK", "उत्तरी मकदूनिया"),
+            ("region/MM", "म्यांमार (बर्मा)"),
+            ("region/MN", "मंगोलिया"),
+            ("region/MO", "मकाऊ (विशेष प्रशासनिक क्षेत्र चीन)"),
+            ("region/MT", "माल्टा"),
+            ("region/MX", "मैक्सिको"),
+            ("region/MY", "मलेशिया"),
+            ("region/NG", "नाइजीरिया"),
+            ("region/NL", "नीदरलैंड"),
+            ("region/NO", "नॉर्वे"),
+            ("region/NP", "नेपाल"),
+            ("region/NZ", "न्यूज़ीलैंड"),
+            ("region/PA", "पनामा"),
+            ("region/PE", "पेरू"),
+            ("region/PH", "फ़िलिपींस"),
+            ("region/PK", "पाकिस्तान"),
+            ("region/PL", "पोलैंड"),
+            ("region/PR", "पोर्टो रिको"),
+            ("region/PS", "फ़िलिस्तीनी क्षेत्र"),
+            ("region/PT", "पुर्तगाल"),
+            ("region/PY", "पराग्वे"),
+            ("region/QA", "क़तर"),
+            ("region/RO", "रोमानिया"),
+            ("region/RS", "सर्बिया"),
+            ("region/RU", "रूस"),
+            ("region/SA", "सऊदी अरब"),
+            ("region/SE", "स्वीडन"),
+            ("region/SG", "सिंगापुर"),
+            ("region/SI", "स्लोवेनिया"),
+            ("region/SK", "स्लोवाकिया"),
+            ("region/SN", "सेनेगल"),
+            ("region/SY", "सीरिया"),
+            ("region/TH", "थाईलैंड"),
+            ("region/TN", "ट्यूनीशिया"),
+            ("region/TR", "तुर्की"),
+            ("region/TW", "ताइवान"),
+            ("region/TZ", "तंज़ानिया"),
+            ("region/UA", "यूक्रेन"),
+            ("region/UG", "युगांडा"),
+            ("region/UN", "संयुक्त राष्ट्र"),
+            ("region/US", "संयुक्त राज्य"),
+            ("region/UY", "उरूग्वे"),
+            ("region/UZ", "उज़्बेकिस्तान"),
+            ("region/VE", "वेनेज़ुएला"),
+            ("region/VN", "वियतनाम"),
+            ("region/ZA", "दक्षिण अफ़्रीका"),
+            ("region/ZM", "ज़ाम्बिया"),
+            ("region/ZW", "ज़िम्बाब्वे"),
+            ("script-stand-alone/Hans", "सरलीकृत हान"),
+            ("script-stand-alone/Hant", "पारंपरिक हान"),
+            ("script/Arab", "अरबी"),
+            ("script/Armn", "आर्मेनियाई"),
+            ("script/Beng", "बंगाली"),
+            ("script/Bopo", "बोपोमोफ़ो"),
+            ("script/Brai", "ब्रेल"),
+            ("script/Cyrl", "सिरिलिक"),
+            ("script/Deva", "देवनागरी"),
+            ("script/Ethi", "इथियोपियाई"),
+            ("script/Geor", "जॉर्जियन"),
+            ("script/Grek", "यूनानी"),
+            ("script/Gujr", "गुजराती"),
+            ("script/Guru", "गुरमुखी"),
+            ("script/Hang", "हंगुल"),
+            ("script/Hani", "हान"),
+            ("script/Hans", "सरलीकृत"),
+            ("script/Hant", "पारंपरिक"),
+            ("script/Hebr", "हिब्रू"),
+            ("script/Hira", "हिरागाना"),
+            ("script/Jpan", "जापानी"),
+            ("script/Kana", "काताकाना"),
+            ("script/Khmr", "खमेर"),
+            ("script/Knda", "कन्नड़"),
+            ("script/Kore", "कोरियाई"),
+            ("script/Laoo", "लाओ"),
+            ("script/Latn", "लैटिन"),
+            ("script/Mlym", "मलयालम"),
+            ("script/Mong", "मंगोलियाई"),
+            ("script/Mymr", "म्यांमार"),
+            ("script/Orya", "ओड़िया"),
+            ("script/Sinh", "सिंहली"),
+            ("script/Taml", "तमिल"),
+            ("script/Telu", "तेलुगू"),
+            ("script/Thai", "थाई"),
+            ("separator", ", "),
+        ],
+    ),
+    (
+        "hi-Latn",
+        &[
+            (
+                "calendar/islamic-civil",
+                "Islamic Civil Calendar (tabular, civil epoch)",
+            ),
+            ("currency/CRC", "Costa Rican colon"),
+            ("currency/ISK", "Icelandic krona"),
+            ("currency/RUB", "Russian ruble"),
+            ("field-narrow/dayPeriod", "am/pm"),
+            ("field-short/weekday", "wk kaa din"),
+            ("field/day", "din"),
+            ("field/hour", "ghanta"),
+            ("field/month", "mahina"),
+            ("field/weekOfYear", "hafta"),
+            ("field/weekday", "Week kaa din"),
+            ("field/year", "saal"),
+            ("language/af", "Afreeki"),
+            ("language/bn", "Bangla"),
+            ("language/bo", "Tibbati"),
+            ("language/fa", "Faarsi"),
+            ("language/ff", "Fulah"),
+            ("language/nb", "Norwegian Bokmal"),
+            ("region-short/US", "America"),
+            ("script/Beng", "Bangla"),
+            ("script/Mymr", "Burmese"),
+            ("script/Orya", "Odia"),
+        ],
+    ),
+    (
+        "id",
+        &[
+            ("calendar/buddhist", "Kalender Buddha"),
+            ("calendar/chinese", "Kalender Tionghoa"),
+            ("calendar/coptic", "Kalender Koptik"),
+            ("calendar/dangi", "Kalender Dangi"),
+            ("calendar/ethioaa", "Kalender Amete Alem Etiopia"),
+            ("calendar/ethiopic", "Kalender Etiopia"),
+            ("calendar/gregory", "Kalender Gregorian"),
+            ("calendar/hebrew", "Kalender Ibrani"),
+            ("calendar/indian", "Kalender Nasional India"),
+            ("calendar/islamic", "Kalender Islam"),
+            ("calendar/islamic-civil", "Kalender Sipil Islam"),
+            (
+                "calendar/islamic-rgsa",
+                "Kalender Islam (Arab Saudi, penglihatan)",
+            ),
+            ("calendar/islamic-tbla", "Kalender Astronomi Islam"),
+            ("calendar/islamic-umalqura", "Kalender Islam (Umm al-Qura)"),
+            ("calendar/iso8601", "Kalender ISO-8601"),
+            ("calendar/japanese", "Kalender Jepang"),
+            ("calendar/persian", "Kalender Persia"),
+            ("calendar/roc", "Kalender Min-guo"),
+            ("currency/AED", "Dirham Uni Emirat Arab"),
+            ("currency/ARS", "Peso Argentina"),
+            ("currency/AUD", "Dolar Australia"),
+            ("currency/BDT", "Taka Bangladesh"),
+            ("currency/BGN", "Lev Bulgaria"),
+            ("currency/BHD", "Dinar Bahrain"),
+            ("currency/BOB", "Boliviano"),
+            ("currency/BRL", "Real Brasil"),
+            ("currency/CAD", "Dolar Kanada"),
+            ("currency/CHF", "Franc Swiss"),
+            ("currency/CLP", "Peso Cile"),
+            ("currency/CNY", "Yuan Tiongkok"),
+            ("currency/COP", "Peso Kolombia"),
+            ("currency/CRC", "Colon Kosta Rika"),
+            ("currency/CZK", "Koruna Ceko"),
+            ("currency/DKK", "Krone Denmark"),
+            ("currency/DOP", "Peso Dominika"),
+            ("currency/DZD", "Dinar Aljazair"),
+            ("currency/EGP", "Pound Mesir"),
+            ("currency/ETB", "Birr Etiopia"),
+            ("currency/EUR", "Euro"),
+            ("currency/GBP", "Pound Inggris"),
+            ("currency/GEL", "Lari Georgia"),
+            ("currency/GHS", "Cedi Ghana"),
+            ("currency/GTQ", "Quetzal Guatemala"),
+            ("currency/HKD", "Dolar Hong Kong"),
+            ("currency/HUF", "Forint Hungaria"),
+            ("currency/IDR", "Rupiah Indonesia"),
+            ("currency/ILS", "Shekel Baru Israel"),
+            ("currency/INR", "Rupee India"),
+            ("currency/IQD", "Dinar Irak"),
+            ("currency/IRR", "Rial Iran"),
+            ("currency/ISK", "Krona Islandia"),
+            ("currency/JMD", "Dolar Jamaika"),
+            ("currency/JOD", "Dinar Yordania"),
+            ("currency/JPY", "Yen Jepang"),
+            ("currency/KES", "Shilling Kenya"),
+            ("currency/KRW", "Won Korea Selatan"),
+            ("currency/KWD", "Dinar Kuwait"),
+            ("currency/KZT", "Tenge Kazakhstan"),
+            ("currency/LBP", "Pound Lebanon"),
+            ("currency/LKR", "Rupee Sri Lanka"),
+            ("currency/MAD", "Dirham Maroko"),
+            ("currency/MDL", "Leu Moldova"),
+            ("currency/MXN", "Peso Meksiko"),
+            ("currency/MYR", "Ringgit Malaysia"),
+            ("currency/NGN", "Naira Nigeria"),
+            ("currency/NOK", "Krone Norwegia"),
+            ("currency/NPR", "Rupee Nepal"),
+            ("currency/NZD", "Dolar Selandia Baru"),
+            ("currency/OMR", "Rial Oman"),
+            ("currency/PAB", "Balboa Panama"),
+            ("currency/PEN", "Sol Peru"),
+            ("currency/PHP", "Peso Filipina"),
+            ("currency/PKR", "Rupee Pakistan"),
+            ("currency/PLN", "Zloty Polandia"),
+            ("currency/PYG", "Guarani Paraguay"),
+            ("currency/QAR", "Rial Qatar"),
+            ("currency/RON", "Leu Rumania"),
+            ("currency/RSD", "Dinar Serbia"),
+            ("currency/RUB", "Rubel Rusia"),
+            ("currency/SAR", "Riyal Arab Saudi"),
+            ("currency/SEK", "Krona Swedia"),
+            ("currency/SGD", "Dolar Singapura"),
+            ("currency/THB", "Baht Thailand"),
+            ("currency/TND", "Dinar Tunisia"),
+            ("currency/TRY", "Lira Turki"),
+            ("currency/TWD", "Dolar Baru Taiwan"),
+            ("currency/TZS", "Shilling Tanzania"),
+            ("currency/UAH", "Hryvnia Ukraina"),
+            ("currency/UGX", "Shilling Uganda"),
+            ("currency/USD", "Dolar Amerika Serikat"),
+            ("currency/UYU", "Peso Uruguay"),
+            ("currency/UZS", "Som Uzbekistan"),
+            ("currency/VND", "Dong Vietnam"),
+            ("currency/XAF", "Franc CFA Afrika Tengah"),
+            ("currency/XOF", "Franc CFA Afrika Barat"),
+            ("currency/ZAR", "Rand Afrika Selatan"),
+            ("field-narrow/day", "h"),
+            ("field-narrow/hour", "j"),
+            ("field-narrow/minute", "m"),
+            ("field-narrow/second", "d"),
+            ("field-short/day", "hr"),
+            ("field-short/minute", "mnt."),
+            ("field-short/month", "bln."),
+            ("field-short/quarter", "krtl."),
+            ("field-short/second", "dtk."),
+            ("field-short/timeZoneName", "zona wkt"),
+            ("field-short/weekOfYear", "mgg."),
+            ("field-short/weekday", "hari dlm seminggu"),
+            ("field-short/year", "thn."),
+            ("field/day", "hari"),
+            ("field/dayPeriod", "AM/PM"),
+            ("field/era", "era"),
+            ("field/hour", "jam"),
+            ("field/minute", "menit"),
+            ("field/month", "bulan"),
+            ("field/quarter", "kuartal"),
+            ("field/second", "detik"),
+            ("field/timeZoneName", "zona waktu"),
+            ("field/weekOfYear", "minggu"),
+            ("field/weekday", "hari dalam seminggu"),
+            ("field/year", "tahun"),
+            ("language-short/az", "Azeri"),
+            ("language-short/en-GB", "Inggris (UK)"),
+            ("language/af", "Afrikaans"),
+            ("language/am", "Amharik"),
+            ("language/ar", "Arab"),
+            ("language/ar-001", "Arab Standar Modern"),
+            ("language/as", "Assam"),
+            ("language/az", "Azerbaijani"),
+            ("language/be", "Belarusia"),
+            ("language/bg", "Bulgaria"),
+            ("language/bn", "Bengali"),
+            ("language/bo", "Tibet"),
+            ("language/br", "Breton"),
+            ("language/bs", "Bosnia"),
+            ("language/ca", "Katalan"),
+            ("language/chr", "Cherokee"),
+            ("language/cs", "Cheska"),
+            ("language/cy", "Welsh"),
+            ("language/da", "Dansk"),
+            ("language/de", "Jerman"),
+            ("language/de-CH", "Jerman Tinggi (Swiss)"),
+            ("language/dz", "Dzongkha"),
+            ("language/el", "Yunani"),
+            ("language/en", "Inggris"),
+            ("language/en-GB", "Inggris (Britania)"),
+            ("language/eo", "Esperanto"),
+            ("language/es", "Spanyol"),
+            ("language/es-ES", "Spanyol (Eropa)"),
+            ("language/et", "Esti"),
+            ("language/eu", "Basque"),
+            ("language/fa", "Persia"),
+            ("language/fa-AF", "Persia Dari"),
+            ("language/ff", "Fula"),
+            ("language/fi", "Suomi"),
+            ("language/fil", "Filipino"),
+            ("language/fo", "Faroe"),
+            ("language/fr", "Prancis"),
+            ("language/fy", "Frisia Barat"),
+            ("language/ga", "Irlandia"),
+            ("language/gd", "Gaelik Skotlandia"),
+            ("language/gl", "Galisia"),
+            ("language/gu", "Gujarat"),
+            ("language/ha", "Hausa"),
+            ("language/haw", "Hawaii"),
+            ("language/he", "Ibrani"),
+            ("language/hi", "Hindi"),
+            ("language/hr", "Kroasia"),
+            ("language/ht", "Kreol Haiti"),
+            ("language/hu", "Hungaria"),
+            ("language/hy", "Armenia"),
+            ("language/id", "Indonesia"),
+            ("language/ig", "Igbo"),
+            ("language/is", "Islandia"),
+            ("language/it", "Italia"),
+            ("language/ja", "Jepang"),
+            ("language/jv", "Jawa"),
+            ("language/ka", "Georgia"),
+            ("language/kk", "Kazakh"),
+            ("language/km", "Khmer"),
+            ("language/kn", "Kannada"),
+            ("language/ko", "Korea"),
+            ("language/ku", "Kurdi"),
+            ("language/ky", "Kirgiz"),
+            ("language/la", "Latin"),
+            ("language/lb", "Luksemburg"),
+            ("language/lo", "Lao"),
+            ("language/lt", "Lituavi"),
+            ("language/lv", "Latvi"),
+            ("language/mg", "Malagasi"),
+            ("language/mi", "Maori"),
+            ("language/mk", "Makedonia"),
+            ("language/ml", "Malayalam"),
+            ("language/mn", "Mongolia"),
+            ("language/mr", "Marathi"),
+            ("language/ms", "Melayu"),
+            ("language/mt", "Malta"),
+            ("language/my", "Burma"),
+            ("language/nb", "Bokmål Norwegia"),
+            ("language/ne", "Nepali"),
+            ("language/nl", "Belanda"),
+            ("language/nn", "Nynorsk Norwegia"),
+            ("language/no", "Norwegia"),
+            ("language/or", "Oriya"),
+            ("language/pa", "Punjabi"),
+            ("language/pl", "Polski"),
+            ("language/ps", "Pashto"),
+            ("language/pt", "Portugis"),
+            ("language/pt-PT", "Portugis (Eropa)"),
+            ("language/ro", "Rumania"),
+            ("language/ro-MD", "Moldavia"),
+            ("language/ru", "Rusia"),
+            ("language/rw", "Kinyarwanda"),
+            ("language/sa", "Sanskerta"),
+            ("language/sd", "Sindhi"),
+            ("language/si", "Sinhala"),
+            ("language/sk", "Slovak"),
+            ("language/sl", "Sloven"),
+            ("language/so", "Somalia"),
+            ("language/sq", "Albania"),
+            ("language/sr", "Serbia"),
+            ("language/sv", "Swedia"),
+            ("language/sw", "Swahili"),
+            ("language/sw-CD", "Swahili (Kongo)"),
+            ("language/ta", "Tamil"),
+            ("language/te", "Telugu"),
+            ("language/tg", "Tajik"),
+            ("language/th", "Thai"),
+            ("language/ti", "Tigrinya"),
+            ("language/tk", "Turkmen"),
+            ("language/tr", "Turki"),
+            ("language/uk", "Ukraina"),
+            ("language/ur", "Urdu"),
+            ("language/uz", "Uzbek"),
+            ("language/vi", "Vietnam"),
+            ("language/xh", "Xhosa"),
+            ("language/yi", "Yiddish"),
+            ("language/yo", "Yoruba"),
+            ("language/zh", "Tionghoa"),
+            ("language/zu", "Zulu"),
+            ("pattern", " (|)"),
+            ("region-short/GB", "UK"),
+            ("region-short/HK", "Hong Kong"),
+            ("region-short/MO", "Makau"),
+            ("region-short/PS", "Palestina"),
+            ("region-short/UN", "PBB"),
+            ("region-short/US", "AS"),
+            ("region/001", "Dunia"),
+            ("region/002", "Afrika"),
+            ("region/003", "Amerika Utara"),
+            ("region/005", "Amerika Selatan"),
+            ("region/009", "Oseania"),
+            ("region/019", "Amerika"),
+            ("region/142", "Asia"),
+            ("region/150", "Eropa"),
+            ("region/419", "Amerika Latin"),
+            ("region/AE", "Uni Emirat Arab"),
+            ("region/AF", "Afganistan"),
+            ("region/AL", "Albania"),
+            ("region/AM", "Armenia"),
+            ("region/AO", "Angola"),
+            ("region/AQ", "Antarktika"),
+            ("region/AR", "Argentina"),
+            ("region/AT", "Austria"),
+            ("region/AU", "Australia"),
+            ("region/AZ", "Azerbaijan"),
+            ("region/BA", "Bosnia dan Herzegovina"),
+            ("region/BD", "Bangladesh"),
+            ("region/BE", "Belgia"),
+            ("region/BG", "Bulgaria"),
+            ("region/BO", "Bolivia"),
+            ("region/BR", "Brasil"),
+            ("region/BY", "Belarus"),
+            ("region/CA", "Kanada"),
+            ("region/CD", "Kongo - Kinshasa"),
+            ("region/CH", "Swiss"),
+            ("region/CL", "Cile"),
+            ("region/CM", "Kamerun"),
+            ("region/CN", "Tiongkok"),
+            ("region/CO", "Kolombia"),
+            ("region/CR", "Kosta Rika"),
+            ("region/CU", "Kuba"),
+            ("region/CY", "Siprus"),
+            ("region/CZ", "Ceko"),
+            ("region/DE", "Jerman"),
+            ("region/DK", "Denmark"),
+            ("region/DO", "Republik Dominika"),
+            ("region/DZ", "Aljazair"),
+            ("region/EC", "Ekuador"),
+            ("region/EE", "Estonia"),
+            ("region/EG", "Mesir"),
+            ("region/ES", "Spanyol"),
+            ("region/ET", "Etiopia"),
+            ("region/EU", "Uni Eropa"),
+            ("region/FI", "Finlandia"),
+            ("region/FR", "Prancis"),
+            ("region/GB", "Inggris Raya"),
+            ("region/GE", "Georgia"),
+            ("region/GH", "Ghana"),
+            ("region/GR", "Yunani"),
+            ("region/GT", "Guatemala"),
+            ("region/HK", "Hong Kong DAK Tiongkok"),
+            ("region/HR", "Kroasia"),
+            ("region/HU", "Hungaria"),
+            ("region/ID", "Indonesia"),
+            ("region/IE", "Irlandia"),
+            ("region/IL", "Israel"),
+            ("region/IN", "India"),
+            ("region/IQ", "Irak"),
+            ("region/IR", "Iran"),
+            ("region/IS", "Islandia"),
+            ("region/IT", "Italia"),
+            ("region/JM", "Jamaika"),
+            ("region/JO", "Yordania"),
+            ("region/JP", "Jepang"),
+            ("region/KE", "Kenya"),
+            ("region/KH", "Kamboja"),
+            ("region/KP", "Korea Utara"),
+            ("region/KR", "Korea Selatan"),
+            ("region/KW", "Kuwait"),
+            ("region/KZ", "Kazakhstan"),
+            ("region/LB", "Lebanon"),
+            ("region/LK", "Sri Lanka"),
+            ("region/LT", "Lituania"),
+            ("region/LU", "Luksemburg"),
+            ("region/LV", "Latvia"),
+            ("region/MA", "Maroko"),
+            ("region/MD", "Moldova"),
+            ("region/MK", "Makedonia Utara"),
+            ("region/MM", "Myanmar (Burma)"),
+            ("region/MN", "Mongolia"),
+            ("region/MO", "Makau DAK Tiongkok"),
+            ("region/MT", "Malta"),
+            ("region/MX", "Meksiko"),
+            ("region/MY", "Malaysia"),
+            ("region/NG", "Nigeria"),
+            ("region/NL", "Belanda"),
+            ("region/NO", "Norwegia"),
+            ("region/NP", "Nepal"),
+            ("region/NZ", "Selandia Baru"),
+            ("region/PA", "Panama"),
+            ("region/PE", "Peru"),
+            ("region/PH", "Filipina"),
+            ("region/PK", "Pakistan"),
+            ("region/PL", "Polandia"),
+            ("region/PR", "Puerto Riko"),
+            ("region/PS", "Wilayah Palestina"),
+            ("region/PT", "Portugal"),
+            ("region/PY", "Paraguay"),
+            ("region/QA", "Qatar"),
+            ("region/RO", "Rumania"),
+            ("region/RS", "Serbia"),
+            ("region/RU", "Rusia"),
+            ("region/SA", "Arab Saudi"),
+            ("region/SE", "Swedia"),
+            ("region/SG", "Singapura"),
+            ("region/SI", "Slovenia"),
+            ("region/SK", "Slovakia"),
+            ("region/SN", "Senegal"),
+            ("region/SY", "Suriah"),
+            ("region/TH", "Thailand"),
+            ("region/TN", "Tunisia"),
+            ("region/TR", "Turki"),
+            ("region/TW", "Taiwan"),
+            ("region/TZ", "Tanzania"),
+            ("region/UA", "Ukraina"),
+            ("region/UG", "Uganda"),
+            ("region/UN", "Perserikatan Bangsa-Bangsa"),
+            ("region/US", "Amerika Serikat"),
+            ("region/UY", "Uruguay"),
+            ("region/UZ", "Uzbekistan"),
+            ("region/VE", "Venezuela"),
+            ("region/VN", "Vietnam"),
+            ("region/ZA", "Afrika Selatan"),
+            ("region/ZM", "Zambia"),
+            ("region/ZW", "Zimbabwe"),
+            ("script-stand-alone/Hans", "Han Sederhana"),
+            ("script-stand-alone/Hant", "Han Tradisional"),
+            ("script/Arab", "Arab"),
+            ("script/Armn", "Armenia"),
+            ("script/Beng", "Bengali"),
+            ("script/Bopo", "Bopomofo"),
+            ("script/Brai", "Braille"),
+            ("script/Cyrl", "Sirilik"),
+            ("script/Deva", "Dewanagari"),
+            ("script/Ethi", "Etiopia"),
+            ("script/Geor", "Georgia"),
+            ("script/Grek", "Yunani"),
+            ("script/Gujr", "Gujarat"),
+            ("script/Guru", "Gurmukhi"),
+            ("script/Hang", "Hangul"),
+            ("script/Hani", "Han"),
+            ("script/Hans", "Sederhana"),
+            ("script/Hant", "Tradisional"),
+            ("script/Hebr", "Ibrani"),
+            ("script/Hira", "Hiragana"),
+            ("script/Jpan", "Jepang"),
+            ("script/Kana", "Katakana"),
+            ("script/Khmr", "Khmer"),
+            ("script/Knda", "Kannada"),
+            ("script/Kore", "Korea"),
+            ("script/Laoo", "Laos"),
+            ("script/Latn", "Latin"),
+            ("script/Mlym", "Malayalam"),
+            ("script/Mong", "Mongolia"),
+            ("script/Mymr", "Myanmar"),
+            ("script/Orya", "Oriya"),
+            ("script/Sinh", "Sinhala"),
+            ("script/Taml", "Tamil"),
+            ("script/Telu", "Telugu"),
+            ("script/Thai", "Thai"),
+            ("separator", ", "),
+        ],
+    ),
+    (
+        "it",
+        &[
+            ("calendar/buddhist", "Calendario buddista"),
+            ("calendar/chinese", "Calendario cinese"),
+            ("calendar/coptic", "Calendario copto"),
+            ("calendar/dangi", "Calendario dangi"),
+            ("calendar/ethioaa", "Calendario etiope Amete Alem"),
+            ("calendar/ethiopic", "Calendario etiope"),
+            ("calendar/gregory", "Calendario gregoriano"),
+            ("calendar/hebrew", "Calendario ebraico"),
+            ("calendar/indian", "calendario nazionale indiano"),
+            ("calendar/islamic", "Calendario islamico"),
+            ("calendar/islamic-civil", "Calendario islamico tabulare"),
+            (
+                "calendar/islamic-rgsa",
+                "Calendario islamico (Arabia Saudita, osservazione)",
+            ),
+            (
+                "calendar/islamic-tbla",
+                "Calendario islamico (tabulare, era astronomica)",
+            ),
+            (
+                "calendar/islamic-umalqura",
+                "Calendario islamico (Umm al-Qura)",
+            ),
+            ("calendar/iso8601", "Calendario ISO-8601"),
+            ("calendar/japanese", "Calendario giapponese"),
+            ("calendar/persian", "Calendario persiano"),
+            ("calendar/roc", "Calendario minguo"),
+            ("currency/AED", "dirham degli Emirati Arabi Uniti"),
+            ("currency/ARS", "peso argentino"),
+            ("currency/AUD", "dollaro australiano"),
+            ("currency/BDT", "taka bangladese"),
+            ("currency/BGN", "lev bulgaro"),
+            ("currency/BHD", "dinaro del Bahrein"),
+            ("currency/BOB", "boliviano"),
+            ("currency/BRL", "real brasiliano"),
+            ("currency/CAD", "dollaro canadese"),
+            ("currency/CHF", "franco svizzero"),
+            ("currency/CLP", "peso cileno"),
+            ("currency/CNY", "renminbi cinese"),
+            ("currency/COP", "peso colombiano"),
+            ("currency/CRC", "colón costaricano"),
+            ("currency/CZK", "corona ceca"),
+            ("currency/DKK", "corona danese"),
+            ("currency/DOP", "peso dominicano"),
+            ("currency/DZD", "dinaro algerino"),
+            ("currency/EGP", "sterlina egiziana"),
+            ("currency/ETB", "birr etiope"),
+            ("currency/EUR", "euro"),
+            ("currency/GBP", "sterlina britannica"),
+            ("currency/GEL", "lari georgiano"),
+            ("currency/GHS", "cedi ghanese"),
+            ("currency/GTQ", "quetzal guatemalteco"),
+            ("currency/HKD", "dollaro di Hong Kong"),
+            ("currency/HUF", "fiorino ungherese"),
+            ("currency/IDR", "rupia indonesiana"),
+            ("currency/ILS", "nuovo siclo israeliano"),
+            ("currency/INR", "rupia indiana"),
+            ("currency/IQD", "dinaro iracheno"),
+            ("currency/IRR", "rial iraniano"),
+            ("currency/ISK", "corona islandese"),
+            ("currency/JMD", "dollaro giamaicano"),
+            ("currency/JOD", "dinaro giordano"),
+            ("currency/JPY", "yen giapponese"),
+            ("currency/KES", "scellino keniota"),
+            ("currency/KRW", "won sudcoreano"),
+            ("currency/KWD", "dinaro kuwaitiano"),
+            ("currency/KZT", "tenge kazako"),
+            ("currency/LBP", "lira libanese"),
+            ("currency/LKR", "rupia di Sri Lanka"),
+            ("currency/MAD", "dirham marocchino"),
+            ("currency/MDL", "leu moldavo"),
+            ("currency/MXN", "peso messicano"),
+            ("currency/MYR", "ringgit malese"),
+            ("currency/NGN", "naira nigeriana"),
+            ("currency/NOK", "corona norvegese"),
+            ("currency/NPR", "rupia nepalese"),
+            ("currency/NZD", "dollaro neozelandese"),
+            ("currency/OMR", "rial omanita"),
+            ("currency/PAB", "balboa panamense"),
+            ("currency/PEN", "sol peruviano"),
+            ("currency/PHP", "peso filippino"),
+            ("currency/PKR", "rupia pakistana"),
+            ("currency/PLN", "zloty polacco"),
+            ("currency/PYG", "guaraní paraguayano"),
+            ("currency/QAR", "rial qatariano"),
+            ("currency/RON", "leu rumeno"),
+            ("currency/RSD", "dinaro serbo"),
+            ("currency/RUB", "rublo russo"),
+            ("currency/SAR", "riyal saudita"),
+            ("currency/SEK", "corona svedese"),
+            ("currency/SGD", "dollaro di Singapore"),
+            ("currency/THB", "baht thailandese"),
+            ("currency/TND", "dinaro tunisino"),
+            ("currency/TRY", "lira turca"),
+            ("currency/TWD", "nuovo dollaro taiwanese"),
+            ("currency/TZS", "scellino della Tanzania"),
+            ("currency/UAH", "grivnia ucraina"),
+            ("currency/UGX", "scellino ugandese"),
+            ("currency/USD", "dollaro statunitense"),
+            ("currency/UYU", "peso uruguayano"),
+            ("currency/UZS", "sum uzbeco"),
+            ("currency/VND", "dong vietnamita"),
+            ("currency/XAF", "franco CFA BEAC"),
+            ("currency/XOF", "franco CFA BCEAO"),
+            ("currency/ZAR", "rand sudafricano"),
+            ("field-narrow/weekday", "giorno sett."),
+            ("field-short/day", "g."),
+            ("field-short/hour", "h"),
+            ("field-short/minute", "min"),
+            ("field-short/quarter", "trim."),
+            ("field-short/second", "s"),
+            ("field-short/timeZoneName", "fuso"),
+            ("field-short/weekOfYear", "sett."),
+            ("field-short/weekday", "giorno settimana"),
+            ("field/day", "giorno"),
+            ("field/dayPeriod", "AM/PM"),
+            ("field/era", "era"),
+            ("field/hour", "ora"),
+            ("field/minute", "minuto"),
+            ("field/month", "mese"),
+            ("field/quarter", "trimestre"),
+            ("field/second", "secondo"),
+            ("field/timeZoneName", "fuso orario"),
+            ("field/weekOfYear", "settimana"),
+            ("field/weekday", "giorno della settimana"),
+            ("field/year", "anno"),
+            ("language-short/az", "azero"),
+            ("language-short/en-GB", "inglese (GB)"),
+            ("language-short/en-US", "inglese (USA)"),
+            ("language/af", "afrikaans"),
+            ("language/am", "amarico"),
+            ("language/ar", "arabo"),
+            ("language/ar-001", "arabo moderno standard"),
+            ("language/as", "assamese"),
+            ("language/az", "azerbaigiano"),
+            ("language/be", "bielorusso"),
+            ("language/bg", "bulgaro"),
+            ("language/bn", "bengalese"),
+            ("language/bo", "tibetano"),
+            ("language/br", "bretone"),
+            ("language/bs", "bosniaco"),
+            ("language/ca", "catalano"),
+            ("language/chr", "cherokee"),
+            ("language/cs", "ceco"),
+            ("language/cy", "gallese"),
+            ("language/da", "danese"),
+            ("language/de", "tedesco"),
+            ("language/de-AT", "tedesco austriaco"),
+            ("language/de-CH", "alto tedesco svizzero"),
+            ("language/dz", "dzongkha"),
+            ("language/el", "greco"),
+            ("language/en", "inglese"),
+            ("language/en-AU", "inglese australiano"),
+            ("language/en-CA", "inglese canadese"),
+            ("language/en-GB", "inglese britannico"),
+            ("language/en-US", "inglese americano"),
+            ("language/eo", "esperanto"),
+            ("language/es", "spagnolo"),
+            ("language/es-419", "spagnolo latinoamericano"),
+            ("language/es-ES", "spagnolo europeo"),
+            ("language/es-MX", "spagnolo messicano"),
+            ("language/et", "estone"),
+            ("language/eu", "basco"),
+            ("language/fa", "persiano"),
+            ("language/fa-AF", "dari"),
+            ("language/ff", "fulah"),
+            ("language/fi", "finlandese"),
+            ("language/fil", "filippino"),
+            ("language/fo", "faroese"),
+            ("language/fr", "francese"),
+            ("language/fr-CA", "francese canadese"),
+            ("language/fr-CH", "francese svizzero"),
+            ("language/fy", "frisone occidentale"),
+            ("language/ga", "irlandese"),
+            ("language/gd", "gaelico scozzese"),
+            ("language/gl", "galiziano"),
+            ("language/gu", "gujarati"),
+            ("language/ha", "hausa"),
+            ("language/haw", "hawaiano"),
+            ("language/he", "ebraico"),
+            ("language/hi", "hindi"),
+            ("language/hr", "croato"),
+            ("language/ht", "creolo haitiano"),
+            ("language/hu", "ungherese"),
+            ("language/hy", "armeno"),
+            ("language/id", "indonesiano"),
+            ("language/ig", "igbo"),
+            ("language/is", "islandese"),
+            ("language/it", "italiano"),
+            ("language/ja", "giapponese"),
+            ("language/jv", "giavanese"),
+            ("language/ka", "georgiano"),
+            ("language/kk", "kazako"),
+            ("language/km", "khmer"),
+            ("language/kn", "kannada"),
+            ("language/ko", "coreano"),
+            ("language/ku", "curdo"),
+            ("language/ky", "kirghiso"),
+            ("language/la", "latino"),
+            ("language/lb", "lussemburghese"),
+            ("language/lo", "lao"),
+            ("language/lt", "lituano"),
+            ("language/lv", "lettone"),
+            ("language/mg", "malgascio"),
+            ("language/mi", "maori"),
+            ("language/mk", "macedone"),
+            ("language/ml", "malayalam"),
+            ("language/mn", "mongolo"),
+            ("language/mr", "marathi"),
+            ("language/ms", "malese"),
+            ("language/mt", "maltese"),
+            ("language/my", "birmano"),
+            ("language/nb", "norvegese bokmål"),
+            ("language/ne", "nepalese"),
+            ("language/nl", "olandese"),
+            ("language/nl-BE", "fiammingo"),
+            ("language/nn", "norvegese nynorsk"),
+            ("language/no", "norvegese"),
+            ("language/or", "odia"),
+            ("language/pa", "punjabi"),
+            ("language/pl", "polacco"),
+            ("language/ps", "pashto"),
+            ("language/pt", "portoghese"),
+            ("language/pt-BR", "portoghese brasiliano"),
+            ("language/pt-PT", "portoghese europeo"),
+            ("language/ro", "rumeno"),
+            ("language/ro-MD", "moldavo"),
+            ("language/ru", "russo"),
+            ("language/rw", "kinyarwanda"),
+            ("language/sa", "sanscrito"),
+            ("language/sd", "sindhi"),
+            ("language/si", "singalese"),
+            ("language/sk", "slovacco"),
+            ("language/sl", "sloveno"),
+            ("language/so", "somalo"),
+            ("language/sq", "albanese"),
+            ("language/sr", "serbo"),
+            ("language/sv", "svedese"),
+            ("language/sw", "swahili"),
+            ("language/sw-CD", "swahili del Congo"),
+            ("language/ta", "tamil"),
+            ("language/te", "telugu"),
+            ("language/tg", "tagico"),
+            ("language/th", "thailandese"),
+            ("language/ti", "tigrino"),
+            ("language/tk", "turcomanno"),
+            ("language/tr", "turco"),
+            ("language/uk", "ucraino"),
+            ("language/ur", "urdu"),
+            ("language/uz", "uzbeco"),
+            ("language/vi", "vietnamita"),
+            ("language/xh", "xhosa"),
+            ("language/yi", "yiddish"),
+            ("language/yo", "yoruba"),
+            ("language/zh", "cinese"),
+            ("language/zh-Hans", "cinese semplificato"),
+            ("language/zh-Hant", "cinese tradizionale"),
+            ("language/zu", "zulu"),
+            ("pattern", " (|)"),
+            ("region-short/GB", "UK"),
+            ("region-short/HK", "Hong Kong"),
+            ("region-short/MO", "Macao"),
+            ("region-short/PS", "Palestina"),
+            ("region-short/UN", "ONU"),
+            ("region-short/US", "USA"),
+            ("region/001", "Mondo"),
+            ("region/002", "Africa"),
+            ("region/003", "Nord America"),
+            ("region/005", "America del Sud"),
+            ("region/009", "Oceania"),
+            ("region/019", "Americhe"),
+            ("region/142", "Asia"),
+            ("region/150", "Europa"),
+            ("region/419", "America Latina"),
+            ("region/AE", "Emirati Arabi Uniti"),
+            ("region/AF", "Afghanistan"),
+            ("region/AL", "Albania"),
+            ("region/AM", "Armenia"),
+            ("region/AO", "Angola"),
+            ("region/AQ", "Antartide"),
+            ("region/AR", "Argentina"),
+            ("region/AT", "Austria"),
+            ("region/AU", "Australia"),
+            ("region/AZ", "Azerbaigian"),
+            ("region/BA", "Bosnia ed Erzegovina"),
+            ("region/BD", "Bangladesh"),
+            ("region/BE", "Belgio"),
+            ("region/BG", "Bulgaria"),
+            ("region/BO", "Bolivia"),
+            ("region/BR", "Brasile"),
+            ("region/BY", "Bielorussia"),
+            ("region/CA", "Canada"),
+            ("region/CD", "Congo - Kinshasa"),
+            ("region/CH", "Svizzera"),
+            ("region/CL", "Cile"),
+            ("region/CM", "Camerun"),
+            ("region/CN", "Cina"),
+            ("region/CO", "Colombia"),
+            ("region/CR", "Costa Rica"),
+            ("region/CU", "Cuba"),
+            ("region/CY", "Cipro"),
+            ("region/CZ", "Cechia"),
+            ("region/DE", "Germania"),
+            ("region/DK", "Danimarca"),
+            ("region/DO", "Repubblica Dominicana"),
+            ("region/DZ", "Algeria"),
+            ("region/EC", "Ecuador"),
+            ("region/EE", "Estonia"),
+            ("region/EG", "Egitto"),
+            ("region/ES", "Spagna"),
+            ("region/ET", "Etiopia"),
+            ("region/EU", "Unione europea"),
+            ("region/FI", "Finlandia"),
+            ("region/FR", "Francia"),
+            ("region/GB", "Regno Unito"),
+            ("region/GE", "Georgia"),
+            ("region/GH", "Ghana"),
+            ("region/GR", "Grecia"),
+            ("region/GT", "Guatemala"),
+            ("region/HK", "RAS di Hong Kong"),
+            ("region/HR", "Croazia"),
+            ("region/HU", "Ungheria"),
+            ("region/ID", "Indonesia"),
+            ("region/IE", "Irlanda"),
+            ("region/IL", "Israele"),
+            ("region/IN", "India"),
+            ("region/IQ", "Iraq"),
+            ("region/IR", "Iran"),
+            ("region/IS", "Islanda"),
+            ("region/IT", "Italia"),
+            ("region/JM", "Giamaica"),
+            ("region/JO", "Giordania"),
+            ("region/JP", "Giappone"),
+            ("region/KE", "Kenya"),
+            ("region/KH", "Cambogia"),
+            ("region/KP", "Corea del Nord"),
+            ("region/KR", "Corea del Sud"),
+            ("region/KW", "Kuwait"),
+            ("region/KZ", "Kazakistan"),
+            ("region/LB", "Libano"),
+            ("region/LK", "Sri Lanka"),
+            ("region/LT", "Lituania"),
+            ("region/LU", "Lussemburgo"),
+            ("region/LV", "Lettonia"),
+            ("region/MA", "Marocco"),
+            ("region/MD", "Moldavia"),
+            ("region/MK", "Macedonia del Nord"),
+            ("region/MM", "Myanmar (Birmania)"),
+            ("region/MN", "Mongolia"),
+            ("region/MO", "RAS di Macao"),
+            ("region/MT", "Malta"),
+            ("region/MX", "Messico"),
+            ("region/MY", "Malaysia"),
+            ("region/NG", "Nigeria"),
+            ("region/NL", "Paesi Bassi"),
+            ("region/NO", "Norvegia"),
+            ("region/NP", "Nepal"),
+            ("region/NZ", "Nuova Zelanda"),
+            ("region/PA", "Panama"),
+            ("region/PE", "Perù"),
+            ("region/PH", "Filippine"),
+            ("region/PK", "Pakistan"),
+            ("region/PL", "Polonia"),
+            ("region/PR", "Portorico"),
+            ("region/PS", "Territori palestinesi"),
+            ("region/PT", "Portogallo"),
+            ("region/PY", "Paraguay"),
+            ("region/QA", "Qatar"),
+            ("region/RO", "Romania"),
+            ("region/RS", "Serbia"),
+            ("region/RU", "Russia"),
+            ("region/SA", "Arabia Saudita"),
+            ("region/SE", "Svezia"),
+            ("region/SG", "Singapore"),
+            ("region/SI", "Slovenia"),
+            ("region/SK", "Slovacchia"),
+            ("region/SN", "Senegal"),
+            ("region/SY", "Siria"),
+            ("region/TH", "Thailandia"),
+            ("region/TN", "Tunisia"),
+            ("region/TR", "Turchia"),
+            ("region/TW", "Taiwan"),
+            ("region/TZ", "Tanzania"),
+            ("region/UA", "Ucraina"),
+            ("region/UG", "Uganda"),
+            ("region/UN", "Nazioni Unite"),
+            ("region/US", "Stati Uniti"),
+            ("region/UY", "Uruguay"),
+            ("region/UZ", "Uzbekistan"),
+            ("region/VE", "Venezuela"),
+            ("region/VN", "Vietnam"),
+            ("region/ZA", "Sudafrica"),
+            ("region/ZM", "Zambia"),
+            ("region/ZW", "Zimbabwe"),
+            ("script-stand-alone/Hans", "han semplificato"),
+            ("script-stand-alone/Hant", "han tradizionale"),
+            ("script/Arab", "arabo"),
+            ("script/Armn", "armeno"),
+            ("script/Beng", "bengalese"),
+            ("script/Bopo", "bopomofo"),
+            ("script/Brai", "braille"),
+            ("script/Cyrl", "cirillico"),
+            ("script/Deva", "devanagari"),
+            ("script/Ethi", "etiope"),
+            ("script/Geor", "georgiano"),
+            ("script/Grek", "greco"),
+            ("script/Gujr", "gujarati"),
+            ("script/Guru", "gurmukhi"),
+            ("script/Hang", "hangul"),
+            ("script/Hani", "han"),
+            ("script/Hans", "semplificato"),
+            ("script/Hant", "tradizionale"),
+            ("script/Hebr", "ebraico"),
+            ("script/Hira", "hiragana"),
+            ("script/Jpan", "giapponese"),
+            ("script/Kana", "katakana"),
+            ("script/Khmr", "khmer"),
+            ("script/Knda", "kannada"),
+            ("script/Kore", "coreano"),
+            ("script/Laoo", "lao"),
+            ("script/Latn", "latino"),
+            ("script/Mlym", "malayalam"),
+            ("script/Mong", "mongolo"),
+            ("script/Mymr", "birmano"),
+            ("script/Orya", "oriya"),
+            ("script/Sinh", "singalese"),
+            ("script/Taml", "tamil"),
+            ("script/Telu", "telugu"),
+            ("script/Thai", "thailandese"),
+            ("separator", ", "),
+        ],
+    ),
+    (
+        "ja",
+        &[
+            ("calendar/buddhist", "仏暦"),
+            ("calendar/chinese", "中国暦"),
+            ("calendar/coptic", "コプト暦"),
+            ("calendar/dangi", "ダンギ暦"),
+            ("calendar/ethioaa", "エチオピア創世紀元暦"),
+            ("calendar/ethiopic", "エチオピア暦"),
+            ("calendar/gregory", "西暦(グレゴリオ暦)"),
+            ("calendar/hebrew", "ユダヤ暦"),
+            ("calendar/indian", "インド国定暦"),
+            ("calendar/islamic", "イスラム暦"),
+            ("calendar/islamic-civil", "イスラム暦(定周期、公民紀元)"),
+            (
+                "calendar/islamic-rgsa",
+                "イスラム暦(サウジアラビア、月観測)",
+            ),
+            ("calendar/islamic-tbla", "イスラム歴(定周期、天文紀元)"),
+            (
+                "calendar/islamic-umalqura",
+                "イスラム暦(ウンム・アルクラー)",
+            ),
+            ("calendar/iso8601", "ISO-8601"),
+            ("calendar/japanese", "和暦"),
+            ("calendar/persian", "ペルシア暦"),
+            ("calendar/roc", "中華民国暦"),
+            ("currency/AED", "アラブ首長国連邦ディルハム"),
+            ("currency/ARS", "アルゼンチン ペソ"),
+            ("currency/AUD", "オーストラリア ドル"),
+            ("currency/BDT", "バングラデシュ タカ"),
+            ("currency/BGN", "ブルガリア 新レフ"),
+            ("currency/BHD", "バーレーン ディナール"),
+            ("currency/BOB", "ボリビア ボリビアーノ"),
+            ("currency/BRL", "ブラジル レアル"),
+            ("currency/CAD", "カナダ ドル"),
+            ("currency/CHF", "スイス フラン"),
+            ("currency/CLP", "チリ ペソ"),
+            ("currency/CNY", "中国人民元"),
+            ("currency/COP", "コロンビア ペソ"),
+            ("currency/CRC", "コスタリカ コロン"),
+            ("currency/CZK", "チェコ コルナ"),
+            ("currency/DKK", "デンマーク クローネ"),
+            ("currency/DOP", "ドミニカ ペソ"),
+            ("currency/DZD", "アルジェリア ディナール"),
+            ("currency/EGP", "エジプト ポンド"),
+            ("currency/ETB", "エチオピア ブル"),
+            ("currency/EUR", "ユーロ"),
+            ("currency/GBP", "英国ポンド"),
+            ("currency/GEL", "ジョージア ラリ"),
+            ("currency/GHS", "ガーナ セディ"),
+            ("currency/GTQ", "グアテマラ ケツァル"),
+            ("currency/HKD", "香港ドル"),
+            ("currency/HUF", "ハンガリー フォリント"),
+            ("currency/IDR", "インドネシア ルピア"),
+            ("currency/ILS", "イスラエル新シェケル"),
+            ("currency/INR", "インド ルピー"),
+            ("currency/IQD", "イラク ディナール"),
+            ("currency/IRR", "イラン リアル"),
+            ("currency/ISK", "アイスランド クローナ"),
+            ("currency/JMD", "ジャマイカ ドル"),
+            ("currency/JOD", "ヨルダン ディナール"),
+            ("currency/JPY", "日本円"),
+            ("currency/KES", "ケニア シリング"),
+            ("currency/KRW", "韓国ウォン"),
+            ("currency/KWD", "クウェート ディナール"),
+            ("currency/KZT", "カザフスタン テンゲ"),
+            ("currency/LBP", "レバノン ポンド"),
+            ("currency/LKR", "スリランカ ルピー"),
+            ("currency/MAD", "モロッコ ディルハム"),
+            ("currency/MDL", "モルドバ レイ"),
+            ("currency/MXN", "メキシコ ペソ"),
+            ("currency/MYR", "マレーシア リンギット"),
+            ("currency/NGN", "ナイジェリア ナイラ"),
+            ("currency/NOK", "ノルウェー クローネ"),
+            ("currency/NPR", "ネパール ルピー"),
+            ("currency/NZD", "ニュージーランド ドル"),
+            ("currency/OMR", "オマーン リアル"),
+            ("currency/PAB", "パナマ バルボア"),
+            ("currency/PEN", "ペルー ソル"),
+            ("currency/PHP", "フィリピン ペソ"),
+            ("currency/PKR", "パキスタン ルピー"),
+            ("currency/PLN", "ポーランド ズウォティ"),
+            ("currency/PYG", "パラグアイ グアラニ"),
+            ("currency/QAR", "カタール リアル"),
+            ("currency/RON", "ルーマニア レイ"),
+            ("currency/RSD", "ディナール (セルビア)"),
+            ("currency/RUB", "ロシア ルーブル"),
+            ("currency/SAR", "サウジ リヤル"),
+            ("currency/SEK", "スウェーデン クローナ"),
+            ("currency/SGD", "シンガポール ドル"),
+            ("currency/THB", "タイ バーツ"),
+            ("currency/TND", "チュニジア ディナール"),
+            ("currency/TRY", "新トルコリラ"),
+            ("currency/TWD", "新台湾ドル"),
+            ("currency/TZS", "タンザニア シリング"),
+            ("currency/UAH", "ウクライナ グリブナ"),
+            ("currency/UGX", "ウガンダ シリング"),
+            ("currency/USD", "米ドル"),
+            ("currency/UYU", "ウルグアイ ペソ"),
+            ("currency/UZS", "ウズベキスタン スム"),
+            ("currency/VND", "ベトナム ドン"),
+            ("currency/XAF", "中央アフリカ CFA フラン"),
+            ("currency/XOF", "西アフリカ CFA フラン"),
+            ("currency/ZAR", "南アフリカ ランド"),
+            ("field/day", "日"),
+            ("field/dayPeriod", "午前/午後"),
+            ("field/era", "時代"),
+            ("field/hour", "時"),
+            ("field/minute", "分"),
+            ("field/month", "月"),
+            ("field/quarter", "四半期"),
+            ("field/second", "秒"),
+            ("field/timeZoneName", "タイムゾーン"),
+            ("field/weekOfYear", "週"),
+            ("field/weekday", "曜日"),
+            ("field/year", "年"),
+            ("language-short/az", "アゼリー語"),
+            ("language-short/en-GB", "英語(英国)"),
+            ("language-short/en-US", "英語(米国)"),
+            ("language/af", "アフリカーンス語"),
+            ("language/am", "アムハラ語"),
+            ("language/ar", "アラビア語"),
+            ("language/ar-001", "現代標準アラビア語"),
+            ("language/as", "アッサム語"),
+            ("language/az", "アゼルバイジャン語"),
+            ("language/be", "ベラルーシ語"),
+            ("language/bg", "ブルガリア語"),
+            ("language/bn", "ベンガル語"),
+            ("language/bo", "チベット語"),
+            ("language/br", "ブルトン語"),
+            ("language/bs", "ボスニア語"),
+            ("language/ca", "カタロニア語"),
+            ("language/chr", "チェロキー語"),
+            ("language/cs", "チェコ語"),
+            ("language/cy", "ウェールズ語"),
+            ("language/da", "デンマーク語"),
+            ("language/de", "ドイツ語"),
+            ("language/de-CH", "標準ドイツ語 (スイス)"),
+            ("language/dz", "ゾンカ語"),
+            ("language/el", "ギリシャ語"),
+            ("language/en", "英語"),
+            ("language/en-AU", "オーストラリア英語"),
+            ("language/en-CA", "カナダ英語"),
+            ("language/en-GB", "イギリス英語"),
+            ("language/en-US", "アメリカ英語"),
+            ("language/eo", "エスペラント語"),
+            ("language/es", "スペイン語"),
+            ("language/es-ES", "スペイン語 (イベリア半島)"),
+            ("language/et", "エストニア語"),
+            ("language/eu", "バスク語"),
+            ("language/fa", "ペルシア語"),
+            ("language/fa-AF", "ダリー語"),
+            ("language/ff", "フラ語"),
+            ("language/fi", "フィンランド語"),
+            ("language/fil", "フィリピノ語"),
+            ("language/fo", "フェロー語"),
+            ("language/fr", "フランス語"),
+            ("language/fy", "西フリジア語"),
+            ("language/ga", "アイルランド語"),
+            ("language/gd", "スコットランド・ゲール語"),
+            ("language/gl", "ガリシア語"),
+            ("language/gu", "グジャラート語"),
+            ("language/ha", "ハウサ語"),
+            ("language/haw", "ハワイ語"),
+            ("language/he", "ヘブライ語"),
+            ("language/hi", "ヒンディー語"),
+            ("language/hr", "クロアチア語"),
+            ("language/ht", "ハイチ・クレオール語"),
+            ("language/hu", "ハンガリー語"),
+            ("language/hy", "アルメニア語"),
+            ("language/id", "インドネシア語"),
+            ("language/ig", "イボ語"),
+            ("language/is", "アイスランド語"),
+            ("language/it", "イタリア語"),
+            ("language/ja", "日本語"),
+            ("language/jv", "ジャワ語"),
+            ("language/ka", "ジョージア語"),
+            ("language/kk", "カザフ語"),
+            ("language/km", "クメール語"),
+            ("language/kn", "カンナダ語"),
+            ("language/ko", "韓国語"),
+            ("language/ku", "クルド語"),
+            ("language/ky", "キルギス語"),
+            ("language/la", "ラテン語"),
+            ("language/lb", "ルクセンブルク語"),
+            ("language/lo", "ラオ語"),
+            ("language/lt", "リトアニア語"),
+            ("language/lv", "ラトビア語"),
+            ("language/mg", "マダガスカル語"),
+            ("language/mi", "マオリ語"),
+            ("language/mk", "マケドニア語"),
+            ("language/ml", "マラヤーラム語"),
+            ("language/mn", "モンゴル語"),
+            ("language/mr", "マラーティー語"),
+            ("language/ms", "マレー語"),
+            ("language/mt", "マルタ語"),
+            ("language/my", "ミャンマー語"),
+            ("language/nb", "ノルウェー語(ブークモール)"),
+            ("language/ne", "ネパール語"),
+            ("language/nl", "オランダ語"),
+            ("language/nl-BE", "フラマン語"),
+            ("language/nn", "ノルウェー語(ニーノシュク)"),
+            ("language/no", "ノルウェー語"),
+            ("language/or", "オディア語"),
+            ("language/pa", "パンジャブ語"),
+            ("language/pl", "ポーランド語"),
+            ("language/ps", "パシュトゥー語"),
+            ("language/pt", "ポルトガル語"),
+            ("language/pt-PT", "ポルトガル語 (イベリア半島)"),
+            ("language/ro", "ルーマニア語"),
+            ("language/ro-MD", "モルダビア語"),
+            ("language/ru", "ロシア語"),
+            ("language/rw", "キニアルワンダ語"),
+            ("language/sa", "サンスクリット語"),
+            ("language/sd", "シンド語"),
+            ("language/si", "シンハラ語"),
+            ("language/sk", "スロバキア語"),
+            ("language/sl", "スロベニア語"),
+            ("language/so", "ソマリ語"),
+            ("language/sq", "アルバニア語"),
+            ("language/sr", "セルビア語"),
+            ("language/sv", "スウェーデン語"),
+            ("language/sw", "スワヒリ語"),
+            ("language/sw-CD", "コンゴ・スワヒリ語"),
+            ("language/ta", "タミル語"),
+            ("language/te", "テルグ語"),
+            ("language/tg", "タジク語"),
+            ("language/th", "タイ語"),
+            ("language/ti", "ティグリニア語"),
+            ("language/tk", "トルクメン語"),
+            ("language/tr", "トルコ語"),
+            ("language/uk", "ウクライナ語"),
+            ("language/ur", "ウルドゥー語"),
+            ("language/uz", "ウズベク語"),
+            ("language/vi", "ベトナム語"),
+            ("language/xh", "コサ語"),
+            ("language/yi", "イディッシュ語"),
+            ("language/yo", "ヨルバ語"),
+            ("language/zh", "中国語"),
+            ("language/zh-Hans", "簡体中国語"),
+            ("language/zh-Hant", "繁体中国語"),
+            ("language/zu", "ズールー語"),
+            ("pattern", " (|)"),
+            ("region-short/GB", "英国"),
+            ("region-short/HK", "香港"),
+            ("region-short/MO", "マカオ"),
+            ("region-short/PS", "パレスチナ"),
+            ("region-short/UN", "国連"),
+            ("region-short/US", "アメリカ"),
+            ("region/001", "世界"),
+            ("region/002", "アフリカ"),
+            ("region/003", "北アメリカ大陸"),
+            ("region/005", "南アメリカ"),
+            ("region/009", "オセアニア"),
+            ("region/019", "アメリカ大陸"),
+            ("region/142", "アジア"),
+            ("region/150", "ヨーロッパ"),
+            ("region/419", "ラテンアメリカ"),
+            ("region/AE", "アラブ首長国連邦"),
+            ("region/AF", "アフガニスタン"),
+            ("region/AL", "アルバニア"),
+            ("region/AM", "アルメニア"),
+            ("region/AO", "アンゴラ"),
+            ("region/AQ", "南極"),
+            ("region/AR", "アルゼンチン"),
+            ("region/AT", "オーストリア"),
+            ("region/AU", "オーストラリア"),
+            ("region/AZ", "アゼルバイジャン"),
+            ("region/BA", "ボスニア・ヘルツェゴビナ"),
+            ("region/BD", "バングラデシュ"),
+            ("region/BE", "ベルギー"),
+            ("region/BG", "ブルガリア"),
+            ("region/BO", "ボリビア"),
+            ("region/BR", "ブラジル"),
+            ("region/BY", "ベラルーシ"),
+            ("region/CA", "カナダ"),
+            ("region/CD", "コンゴ民主共和国(キンシャサ)"),
+            ("region/CH", "スイス"),
+            ("region/CL", "チリ"),
+            ("region/CM", "カメルーン"),
+            ("region/CN", "中国"),
+            ("region/CO", "コロンビア"),
+            ("region/CR", "コスタリカ"),
+            ("region/CU", "キューバ"),
+            ("region/CY", "キプロス"),
+            ("region/CZ", "チェコ"),
+            ("region/DE", "ドイツ"),
+            ("region/DK", "デンマーク"),
+            ("region/DO", "ドミニカ共和国"),
+            ("region/DZ", "アルジェリア"),
+            ("region/EC", "エクアドル"),
+            ("region/EE", "エストニア"),
+            ("region/EG", "エジプト"),
+            ("region/ES", "スペイン"),
+            ("region/ET", "エチオピア"),
+            ("region/EU", "欧州連合"),
+            ("region/FI", "フィンランド"),
+            ("region/FR", "フランス"),
+            ("region/GB", "イギリス"),
+            ("region/GE", "ジョージア"),
+            ("region/GH", "ガーナ"),
+            ("region/GR", "ギリシャ"),
+            ("region/GT", "グアテマラ"),
+            ("region/HK", "中華人民共和国香港特別行政区"),
+            ("region/HR", "クロアチア"),
+            ("region/HU", "ハンガリー"),
+            ("region/ID", "インドネシア"),
+            ("region/IE", "アイルランド"),
+            ("region/IL", "イスラエル"),
+            ("region/IN", "インド"),
+            ("region/IQ", "イラク"),
+            ("region/IR", "イラン"),
+            ("region/IS", "アイスランド"),
+            ("region/IT", "イタリア"),
+            ("region/JM", "ジャマイカ"),
+            ("region/JO", "ヨルダン"),
+            ("region/JP", "日本"),
+            ("region/KE", "ケニア"),
+            ("region/KH", "カンボジア"),
+            ("region/KP", "北朝鮮"),
+            ("region/KR", "韓国"),
+            ("region/KW", "クウェート"),
+            ("region/KZ", "カザフスタン"),
+            ("region/LB", "レバノン"),
+            ("region/LK", "スリランカ"),
+            ("region/LT", "リトアニア"),
+            ("region/LU", "ルクセンブルク"),
+            ("region/LV", "ラトビア"),
+            ("region/MA", "モロッコ"),
+            ("region/MD", "モルドバ"),
+            ("region/MK", "北マケドニア"),
+            ("region/MM", "ミャンマー (ビルマ)"),
+            ("region/MN", "モンゴル"),
+            ("region/MO", "中華人民共和国マカオ特別行政区"),
+            ("region/MT", "マルタ"),
+            ("region/MX", "メキシコ"),
+            ("region/MY", "マレーシア"),
+            ("region/NG", "ナイジェリア"),
+            ("region/NL", "オランダ"),
+            ("region/NO", "ノルウェー"),
+            ("region/NP", "ネパール"),
+            ("region/NZ", "ニュージーランド"),
+            ("region/PA", "パナマ"),
+            ("region/PE", "ペルー"),
+            ("region/PH", "フィリピン"),
+            ("region/PK", "パキスタン"),
+            ("region/PL", "ポーランド"),
+            ("region/PR", "プエルトリコ"),
+            ("region/PS", "パレスチナ自治区"),
+            ("region/PT", "ポルトガル"),
+            ("region/PY", "パラグアイ"),
+            ("region/QA", "カタール"),
+            ("region/RO", "ルーマニア"),
+            ("region/RS", "セルビア"),
+            ("region/RU", "ロシア"),
+            ("region/SA", "サウジアラビア"),
+            ("region/SE", "スウェーデン"),
+            ("region/SG", "シンガポール"),
+            ("region/SI", "スロベニア"),
+            ("region/SK", "スロバキア"),
+            ("region/SN", "セネガル"),
+            ("region/SY", "シリア"),
+            ("region/TH", "タイ"),
+            ("region/TN", "チュニジア"),
+            ("region/TR", "トルコ"),
+            ("region/TW", "台湾"),
+            ("region/TZ", "タンザニア"),
+            ("region/UA", "ウクライナ"),
+            ("region/UG", "ウガンダ"),
+            ("region/UN", "国際連合"),
+            ("region/US", "アメリカ合衆国"),
+            ("region/UY", "ウルグアイ"),
+            ("region/UZ", "ウズベキスタン"),
+            ("region/VE", "ベネズエラ"),
+            ("region/VN", "ベトナム"),
+            ("region/ZA", "南アフリカ"),
+            ("region/ZM", "ザンビア"),
+            ("region/ZW", "ジンバブエ"),
+            ("script-stand-alone/Hans", "漢字(簡体字)"),
+            ("script-stand-alone/Hant", "漢字(繁体字)"),
+            ("script/Arab", "アラビア文字"),
+            ("script/Armn", "アルメニア文字"),
+            ("script/Beng", "ベンガル文字"),
+            ("script/Bopo", "注音字母"),
+            ("script/Brai", "ブライユ点字"),
+            ("script/Cyrl", "キリル文字"),
+            ("script/Deva", "デーバナーガリー文字"),
+            ("script/Ethi", "エチオピア文字"),
+            ("script/Geor", "ジョージア文字"),
+            ("script/Grek", "ギリシャ文字"),
+            ("script/Gujr", "グジャラート文字"),
+            ("script/Guru", "グルムキー文字"),
+            ("script/Hang", "ハングル"),
+            ("script/Hani", "漢字"),
+            ("script/Hans", "簡体字"),
+            ("script/Hant", "繁体字"),
+            ("script/Hebr", "ヘブライ文字"),
+            ("script/Hira", "ひらがな"),
+            ("script/Jpan", "日本語の文字"),
+            ("script/Kana", "カタカナ"),
+            ("script/Khmr", "クメール文字"),
+            ("script/Knda", "カンナダ文字"),
+            ("script/Kore", "韓国語の文字"),
+            ("script/Laoo", "ラオ文字"),
+            ("script/Latn", "ラテン文字"),
+            ("script/Mlym", "マラヤーラム文字"),
+            ("script/Mong", "モンゴル文字"),
+            ("script/Mymr", "ミャンマー文字"),
+            ("script/Orya", "オディア文字"),
+            ("script/Sinh", "シンハラ文字"),
+            ("script/Taml", "タミル文字"),
+            ("script/Telu", "テルグ文字"),
+            ("script/Thai", "タイ文字"),
+            ("separator", "、"),
+        ],
+    ),
+    (
+        "ko",
+        &[
+            ("calendar/buddhist", "불교력"),
+            ("calendar/chinese", "음력"),
+            ("calendar/coptic", "콥트력"),
+            ("calendar/dangi", "단기력"),
+            ("calendar/ethioaa", "에티오피아 아메테 알렘력"),
+            ("calendar/ethiopic", "에티오피아력"),
+            ("calendar/gregory", "양력"),
+            ("calendar/hebrew", "히브리력"),
+            ("calendar/indian", "인도력"),
+            ("calendar/islamic", "이슬람력"),
+            ("calendar/islamic-civil", "이슬람 상용력"),
+            ("calendar/islamic-umalqura", "이슬람력(움 알 쿠라)"),
+            ("calendar/iso8601", "ISO-8601 달력"),
+            ("calendar/japanese", "일본력"),
+            ("calendar/persian", "페르시안력"),
+            ("calendar/roc", "대만력"),
+            ("currency/AED", "아랍에미리트 디르함"),
+            ("currency/ARS", "아르헨티나 페소"),
+            ("currency/AUD", "호주 달러"),
+            ("currency/BDT", "방글라데시 타카"),
+            ("currency/BGN", "불가리아 레프"),
+            ("currency/BHD", "바레인 디나르"),
+            ("currency/BOB", "볼리비아 볼리비아노"),
+            ("currency/BRL", "브라질 레알"),
+            ("currency/CAD", "캐나다 달러"),
+            ("currency/CHF", "스위스 프랑"),
+            ("currency/CLP", "칠레 페소"),
+            ("currency/CNY", "중국 위안화"),
+            ("currency/COP", "콜롬비아 페소"),
+            ("currency/CRC", "코스타리카 콜론"),
+            ("currency/CZK", "체코 코루나"),
+            ("currency/DKK", "덴마크 크로네"),
+            ("currency/DOP", "도미니카 페소"),
+            ("currency/DZD", "알제리 디나르"),
+            ("currency/EGP", "이집트 파운드"),
+            ("currency/ETB", "에티오피아 비르"),
+            ("currency/EUR", "유로"),
+            ("currency/GBP", "영국 파운드"),
+            ("currency/GEL", "조지아 라리"),
+            ("currency/GHS", "가나 세디"),
+            ("currency/GTQ", "과테말라 케트살"),
+            ("currency/HKD", "홍콩 달러"),
+            ("currency/HUF", "헝가리 포린트"),
+            ("currency/IDR", "인도네시아 루피아"),
+            ("currency/ILS", "이스라엘 신권 세켈"),
+            ("currency/INR", "인도 루피"),
+            ("currency/IQD", "이라크 디나르"),
+            ("currency/IRR", "이란 리얄"),
+            ("currency/ISK", "아이슬란드 크로나"),
+            ("currency/JMD", "자메이카 달러"),
+            ("currency/JOD", "요르단 디나르"),
+            ("currency/JPY", "일본 엔화"),
+            ("currency/KES", "케냐 실링"),
+            ("currency/KRW", "대한민국 원"),
+            ("currency/KWD", "쿠웨이트 디나르"),
+            ("currency/KZT", "카자흐스탄 텡게"),
+            ("currency/LBP", "레바논 파운드"),
+            ("currency/LKR", "스리랑카 루피"),
+            ("currency/MAD", "모로코 디르함"),
+            ("currency/MDL", "몰도바 레이"),
+            ("currency/MXN", "멕시코 페소"),
+            ("currency/MYR", "말레이시아 링깃"),
+            ("currency/NGN", "나이지리아 나이라"),
+            ("currency/NOK", "노르웨이 크로네"),
+            ("currency/NPR", "네팔 루피"),
+            ("currency/NZD", "뉴질랜드 달러"),
+            ("currency/OMR", "오만 리알"),
+            ("currency/PAB", "파나마 발보아"),
+            ("currency/PEN", "페루 솔"),
+            ("currency/PHP", "필리핀 페소"),
+            ("currency/PKR", "파키스탄 루피"),
+            ("currency/PLN", "폴란드 즈워티"),
+            ("currency/PYG", "파라과이 과라니"),
+            ("currency/QAR", "카타르 리얄"),
+            ("currency/RON", "루마니아 레우"),
+            ("currency/RSD", "세르비아 디나르"),
+            ("currency/RUB", "러시아 루블"),
+            ("currency/SAR", "사우디아라비아 리얄"),
+            ("currency/SEK", "스웨덴 크로나"),
+            ("currency/SGD", "싱가포르 달러"),
+            ("currency/THB", "태국 바트"),
+            ("currency/TND", "튀니지 디나르"),
+            ("currency/TRY", "터키 리라"),
+            ("currency/TWD", "신 타이완 달러"),
+            ("currency/TZS", "탄자니아 실링"),
+            ("currency/UAH", "우크라이나 그리브나"),
+            ("currency/UGX", "우간다 실링"),
+            ("currency/USD", "미국 달러"),
+            ("currency/UYU", "우루과이 페소"),
+            ("currency/UZS", "우즈베키스탄 숨"),
+            ("currency/VND", "베트남 동"),
+            ("currency/XAF", "중앙아프리카 CFA 프랑"),
+            ("currency/XOF", "서아프리카 CFA 프랑"),
+            ("currency/ZAR", "남아프리카 랜드"),
+            ("field/day", "일"),
+            ("field/dayPeriod", "오전/오후"),
+            ("field/era", "연호"),
+            ("field/hour", "시"),
+            ("field/minute", "분"),
+            ("field/month", "월"),
+            ("field/quarter", "분기"),
+            ("field/second", "초"),
+            ("field/timeZoneName", "시간대"),
+            ("field/weekOfYear", "주"),
+            ("field/weekday", "요일"),
+            ("field/year", "년"),
+            ("language-short/az", "아제리어"),
+            ("language/af", "아프리칸스어"),
+            ("language/am", "암하라어"),
+            ("language/ar", "아랍어"),
+            ("language/ar-001", "현대 표준 아랍어"),
+            ("language/as", "아삼어"),
+            ("language/az", "아제르바이잔어"),
+            ("language/be", "벨라루스어"),
+            ("language/bg", "불가리아어"),
+            ("language/bn", "벵골어"),
+            ("language/bo", "티베트어"),
+            ("language/br", "브르타뉴어"),
+            ("language/bs", "보스니아어"),
+            ("language/ca", "카탈로니아어"),
+            ("language/chr", "체로키어"),
+            ("language/cs", "체코어"),
+            ("language/cy", "웨일스어"),
+            ("language/da", "덴마크어"),
+            ("language/de", "독일어"),
+            ("language/de-CH", "고지 독일어(스위스)"),
+            ("language/dz", "종카어"),
+            ("language/el", "그리스어"),
+            ("language/en", "영어"),
+            ("language/eo", "에스페란토어"),
+            ("language/es", "스페인어"),
+            ("language/es-ES", "스페인어(유럽)"),
+            ("language/et", "에스토니아어"),
+            ("language/eu", "바스크어"),
+            ("language/fa", "페르시아어"),
+            ("language/fa-AF", "다리어"),
+            ("language/ff", "풀라어"),
+            ("language/fi", "핀란드어"),
+            ("language/fil", "필리핀어"),
+            ("language/fo", "페로어"),
+            ("language/fr", "프랑스어"),
+            ("language/fy", "서부 프리지아어"),
+            ("language/ga", "아일랜드어"),
+            ("language/gd", "스코틀랜드 게일어"),
+            ("language/gl", "갈리시아어"),
+            ("language/gu", "구자라트어"),
+            ("language/ha", "하우사어"),
+            ("language/haw", "하와이어"),
+            ("language/he", "히브리어"),
+            ("language/hi", "힌디어"),
+            ("language/hr", "크로아티아어"),
+            ("language/ht", "아이티어"),
+            ("language/hu", "헝가리어"),
+            ("language/hy", "아르메니아어"),
+            ("language/id", "인도네시아어"),
+            ("language/ig", "이그보어"),
+            ("language/is", "아이슬란드어"),
+            ("language/it", "이탈리아어"),
+            ("language/ja", "일본어"),
+            ("language/jv", "자바어"),
+            ("language/ka", "조지아어"),
+            ("language/kk", "카자흐어"),
+            ("language/km", "크메르어"),
+            ("language/kn", "칸나다어"),
+            ("language/ko", "한국어"),
+            ("language/ku", "쿠르드어"),
+            ("language/ky", "키르기스어"),
+            ("language/la", "라틴어"),
+            ("language/lb", "룩셈부르크어"),
+            ("language/lo", "라오어"),
+            ("language/lt", "리투아니아어"),
+            ("language/lv", "라트비아어"),
+            ("language/mg", "말라가시어"),
+            ("language/mi", "마오리어"),
+            ("language/mk", "마케도니아어"),
+            ("language/ml", "말라얄람어"),
+            ("language/mn", "몽골어"),
+            ("language/mr", "마라티어"),
+            ("language/ms", "말레이어"),
+            ("language/mt", "몰타어"),
+            ("language/my", "버마어"),
+            ("language/nb", "노르웨이어(보크말)"),
+            ("language/ne", "네팔어"),
+            ("language/nl", "네덜란드어"),
+            ("language/nl-BE", "플라망어"),
+            ("language/nn", "노르웨이어(니노르스크)"),
+            ("language/no", "노르웨이어"),
+            ("language/or", "오리야어"),
+            ("language/pa", "펀잡어"),
+            ("language/pl", "폴란드어"),
+            ("language/ps", "파슈토어"),
+            ("language/pt", "포르투갈어"),
+            ("language/pt-PT", "포르투갈어(유럽)"),
+            ("language/ro", "루마니아어"),
+            ("language/ro-MD", "몰도바어"),
+            ("language/ru", "러시아어"),
+            ("language/rw", "르완다어"),
+            ("language/sa", "산스크리트어"),
+            ("language/sd", "신디어"),
+            ("language/si", "싱할라어"),
+            ("language/sk", "슬로바키아어"),
+            ("language/sl", "슬로베니아어"),
+            ("language/so", "소말리아어"),
+            ("language/sq", "알바니아어"),
+            ("language/sr", "세르비아어"),
+            ("language/sv", "스웨덴어"),
+            ("language/sw", "스와힐리어"),
+            ("language/sw-CD", "콩고 스와힐리어"),
+            ("language/ta", "타밀어"),
+            ("language/te", "텔루구어"),
+            ("language/tg", "타지크어"),
+            ("language/th", "태국어"),
+            ("language/ti", "티그리냐어"),
+            ("language/tk", "투르크멘어"),
+            ("language/tr", "터키어"),
+            ("language/uk", "우크라이나어"),
+            ("language/ur", "우르두어"),
+            ("language/uz", "우즈베크어"),
+            ("language/vi", "베트남어"),
+            ("language/xh", "코사어"),
+            ("language/yi", "이디시어"),
+            ("language/yo", "요루바어"),
+            ("language/zh", "중국어"),
+            ("language/zu", "줄루어"),
+            ("pattern", "(|)"),
+            ("region-short/HK", "홍콩"),
+            ("region-short/MO", "마카오"),
+            ("region-short/PS", "팔레스타인"),
+            ("region-short/UN", "유엔"),
+            ("region/001", "세계"),
+            ("region/002", "아프리카"),
+            ("region/003", "북아메리카"),
+            ("region/005", "남아메리카"),
+            ("region/009", "오세아니아"),
+            ("region/019", "아메리카 대륙"),
+            ("region/142", "아시아"),
+            ("region/150", "유럽"),
+            ("region/419", "라틴 아메리카"),
+            ("region/AE", "아랍에미리트"),
+            ("region/AF", "아프가니스탄"),
+            ("region/AL", "알바니아"),
+            ("region/AM", "아르메니아"),
+            ("region/AO", "앙골라"),
+            ("region/AQ", "남극 대륙"),
+            ("region/AR", "아르헨티나"),
+            ("region/AT", "오스트리아"),
+            ("region/AU", "오스트레일리아"),
+            ("region/AZ", "아제르바이잔"),
+            ("region/BA", "보스니아 헤르체고비나"),
+            ("region/BD", "방글라데시"),
+            ("region/BE", "벨기에"),
+            ("region/BG", "불가리아"),
+            ("region/BO", "볼리비아"),
+            ("region/BR", "브라질"),
+            ("region/BY", "벨라루스"),
+            ("region/CA", "캐나다"),
+            ("region/CD", "콩고-킨샤사"),
+            ("region/CH", "스위스"),
+            ("region/CL", "칠레"),
+            ("region/CM", "카메룬"),
+            ("region/CN", "중국"),
+            ("region/CO", "콜롬비아"),
+            ("region/CR", "코스타리카"),
+            ("region/CU", "쿠바"),
+            ("region/CY", "키프로스"),
+            ("region/CZ", "체코"),
+            ("region/DE", "독일"),
+            ("region/DK", "덴마크"),
+            ("region/DO", "도미니카 공화국"),
+            ("region/DZ", "알제리"),
+            ("region/EC", "에콰도르"),
+            ("region/EE", "에스토니아"),
+            ("region/EG", "이집트"),
+            ("region/ES", "스페인"),
+            ("region/ET", "에티오피아"),
+            ("region/EU", "유럽 연합"),
+            ("region/FI", "핀란드"),
+            ("region/FR", "프랑스"),
+            ("region/GB", "영국"),
+            ("region/GE", "조지아"),
+            ("region/GH", "가나"),
+            ("region/GR", "그리스"),
+            ("region/GT", "과테말라"),
+            ("region/HK", "홍콩(중국 특별행정구)"),
+            ("region/HR", "크로아티아"),
+            ("region/HU", "헝가리"),
+            ("region/ID", "인도네시아"),
+            ("region/IE", "아일랜드"),
+            ("region/IL", "이스라엘"),
+            ("region/IN", "인도"),
+            ("region/IQ", "이라크"),
+            ("region/IR", "이란"),
+            ("region/IS", "아이슬란드"),
+            ("region/IT", "이탈리아"),
+            ("region/JM", "자메이카"),
+            ("region/JO", "요르단"),
+            ("region/JP", "일본"),
+            ("region/KE", "케냐"),
+            ("region/KH", "캄보디아"),
+            ("region/KP", "북한"),
+            ("region/KR", "대한민국"),
+            ("region/KW", "쿠웨이트"),
+            ("region/KZ", "카자흐스탄"),
+            ("region/LB", "레바논"),
+            ("region/LK", "스리랑카"),
+            ("region/LT", "리투아니아"),
+            ("region/LU", "룩셈부르크"),
+            ("region/LV", "라트비아"),
+            ("region/MA", "모로코"),
+            ("region/MD", "몰도바"),
+            ("region/MK", "북마케도니아"),
+            ("region/MM", "미얀마"),
+            ("region/MN", "몽골"),
+            ("region/MO", "마카오(중국 특별행정구)"),
+            ("region/MT", "몰타"),
+            ("region/MX", "멕시코"),
+            ("region/MY", "말레이시아"),
+            ("region/NG", "나이지리아"),
+            ("region/NL", "네덜란드"),
+            ("region/NO", "노르웨이"),
+            ("region/NP", "네팔"),
+            ("region/NZ", "뉴질랜드"),
+            ("region/PA", "파나마"),
+            ("region/PE", "페루"),
+            ("region/PH", "필리핀"),
+            ("region/PK", "파키스탄"),
+            ("region/PL", "폴란드"),
+            ("region/PR", "푸에르토리코"),
+            ("region/PS", "팔레스타인 지구"),
+            ("region/PT", "포르투갈"),
+            ("region/PY", "파라과이"),
+            ("region/QA", "카타르"),
+            ("region/RO", "루마니아"),
+            ("region/RS", "세르비아"),
+            ("region/RU", "러시아"),
+            ("region/SA", "사우디아라비아"),
+            ("region/SE", "스웨덴"),
+            ("region/SG", "싱가포르"),
+            ("region/SI", "슬로베니아"),
+            ("region/SK", "슬로바키아"),
+            ("region/SN", "세네갈"),
+            ("region/SY", "시리아"),
+            ("region/TH", "태국"),
+            ("region/TN", "튀니지"),
+            ("region/TR", "터키"),
+            ("region/TW", "대만"),
+            ("region/TZ", "탄자니아"),
+            ("region/UA", "우크라이나"),
+            ("region/UG", "우간다"),
+            ("region/UN", "국제연합"),
+            ("region/US", "미국"),
+            ("region/UY", "우루과이"),
+            ("region/UZ", "우즈베키스탄"),
+            ("region/VE", "베네수엘라"),
+            ("region/VN", "베트남"),
+            ("region/ZA", "남아프리카"),
+            ("region/ZM", "잠비아"),
+            ("region/ZW", "짐바브웨"),
+            ("script-stand-alone/Hans", "한자 간체"),
+            ("script-stand-alone/Hant", "한자 번체"),
+            ("script/Arab", "아랍 문자"),
+            ("script/Armn", "아르메니아 문자"),
+            ("script/Beng", "벵골 문자"),
+            ("script/Bopo", "주음부호"),
+            ("script/Brai", "브라유 점자"),
+            ("script/Cyrl", "키릴 문자"),
+            ("script/Deva", "데바나가리 문자"),
+            ("script/Ethi", "에티오피아 문자"),
+            ("script/Geor", "조지아 문자"),
+            ("script/Grek", "그리스 문자"),
+            ("script/Gujr", "구자라트 문자"),
+            ("script/Guru", "구르무키 문자"),
+            ("script/Hang", "한글"),
+            ("script/Hani", "한자"),
+            ("script/Hans", "간체"),
+            ("script/Hant", "번체"),
+            ("script/Hebr", "히브리 문자"),
+            ("script/Hira", "히라가나"),
+            ("script/Jpan", "일본 문자"),
+            ("script/Kana", "가타카나"),
+            ("script/Khmr", "크메르 문자"),
+            ("script/Knda", "칸나다 문자"),
+            ("script/Kore", "한국 문자"),
+            ("script/Laoo", "라오 문자"),
+            ("script/Latn", "로마자"),
+            ("script/Mlym", "말라얄람 문자"),
+            ("script/Mong", "몽골 문자"),
+            ("script/Mymr", "미얀마 문자"),
+            ("script/Orya", "오리야 문자"),
+            ("script/Sinh", "신할라 문자"),
+            ("script/Taml", "타밀 문자"),
+            ("script/Telu", "텔루구 문자"),
+            ("script/Thai", "타이 문자"),
+            ("separator", ", "),
+        ],
+    ),
+    ("ko-KP", &[("region/KP", "조선민주주의인민공화국")]),
+    (
+        "nl",
+        &[
+            ("calendar/buddhist", "Boeddhistische kalender"),
+            ("calendar/chinese", "Chinese kalender"),
+            ("calendar/coptic", "Koptische kalender"),
+            ("calendar/dangi", "Dangi-kalender"),
+            ("calendar/ethioaa", "Ethiopische Amete Alem-kalender"),
+            ("calendar/ethiopic", "Ethiopische kalender"),
+            ("calendar/gregory", "Gregoriaanse kalender"),
+            ("calendar/hebrew", "Hebreeuwse kalender"),
+            ("calendar/indian", "Indiase nationale kalender"),
+            ("calendar/islamic", "Islamitische kalender"),
+            ("calendar/islamic-civil", "Islamitische kalender (cyclisch)"),
+            (
+                "calendar/islamic-rgsa",
+                "Islamitische kalender (Saudi–Arabië)",
+            ),
+            ("calendar/islamic-tbla", "Islamitische kalender (epoche)"),
+            (
+                "calendar/islamic-umalqura",
+                "Islamitische kalender (Umm al-Qura)",
+            ),
+            ("calendar/iso8601", "ISO-8601-kalender"),
+            ("calendar/japanese", "Japanse kalender"),
+            ("calendar/persian", "Perzische kalender"),
+            ("calendar/roc", "Kalender van de Chinese Republiek"),
+            ("currency/AED", "Verenigde Arabische Emiraten-dirham"),
+            ("currency/ARS", "Argentijnse peso"),
+            ("currency/AUD", "Australische dollar"),
+            ("currency/BDT", "Bengalese taka"),
+            ("currency/BGN", "Bulgaarse lev"),
+            ("currency/BHD", "Bahreinse dinar"),
+            ("currency/BOB", "Boliviaanse boliviano"),
+            ("currency/BRL", "Braziliaanse real"),
+            ("currency/CAD", "Canadese dollar"),
+            ("currency/CHF", "Zwitserse frank"),
+            ("currency/CLP", "Chileense peso"),
+            ("currency/CNY", "Chinese yuan"),
+            ("currency/COP", "Colombiaanse peso"),
+            ("currency/CRC", "Costa Ricaanse colon"),
+            ("currency/CZK", "Tsjechische kroon"),
+            ("currency/DKK", "Deense kroon"),
+            ("currency/DOP", "Dominicaanse peso"),
+            ("currency/DZD", "Algerijnse dinar"),
+            ("currency/EGP", "Egyptisch pond"),
+            ("currency/ETB", "Ethiopische birr"),
+            ("currency/EUR", "Euro"),
+            ("currency/GBP", "Britse pond"),
+            ("currency/GEL", "Georgische lari"),
+            ("currency/GHS", "Ghanese cedi"),
+            ("currency/GTQ", "Guatemalteekse quetzal"),
+            ("currency/HKD", "Hongkongse dollar"),
+            ("currency/HUF", "Hongaarse forint"),
+            ("currency/IDR", "Indonesische roepia"),
+            ("currency/ILS", "Israëlische nieuwe shekel"),
+            ("currency/INR", "Indiase roepie"),
+            ("currency/IQD", "Iraakse dinar"),
+            ("currency/IRR", "Iraanse rial"),
+            ("currency/ISK", "IJslandse kroon"),
+            ("currency/JMD", "Jamaicaanse dollar"),
+            ("currency/JOD", "Jordaanse dinar"),
+            ("currency/JPY", "Japanse yen"),
+            ("currency/KES", "Keniaanse shilling"),
+            ("currency/KRW", "Zuid-Koreaanse won"),
+            ("currency/KWD", "Koeweitse dinar"),
+            ("currency/KZT", "Kazachse tenge"),
+            ("currency/LBP", "Libanees pond"),
+            ("currency/LKR", "Sri Lankaanse roepie"),
+            ("currency/MAD", "Marokkaanse dirham"),
+            ("currency/MDL", "Moldavische leu"),
+            ("currency/MXN", "Mexicaanse peso"),
+            ("currency/MYR", "Maleisische ringgit"),
+            ("currency/NGN", "Nigeriaanse naira"),
+            ("currency/NOK", "Noorse kroon"),
+            ("currency/NPR", "Nepalese roepie"),
+            ("currency/NZD", "Nieuw-Zeelandse dollar"),
+            ("currency/OMR", "Omaanse rial"),
+            ("currency/PAB", "Panamese balboa"),
+            ("currency/PEN", "Peruaanse sol"),
+            ("currency/PHP", "Filipijnse peso"),
+            ("currency/PKR", "Pakistaanse roepie"),
+            ("currency/PLN", "Poolse zloty"),
+            ("currency/PYG", "Paraguayaanse guarani"),
+            ("currency/QAR", "Qatarese rial"),
+            ("currency/RON", "Roemeense leu"),
+            ("currency/RSD", "Servische dinar"),
+            ("currency/RUB", "Russische roebel"),
+            ("currency/SAR", "Saoedi-Arabische riyal"),
+            ("currency/SEK", "Zweedse kroon"),
+            ("currency/SGD", "Singaporese dollar"),
+            ("currency/THB", "Thaise baht"),
+            ("currency/TND", "Tunesische dinar"),
+            ("currency/TRY", "Turkse lira"),
+            ("currency/TWD", "Nieuwe Taiwanese dollar"),
+            ("currency/TZS", "Tanzaniaanse shilling"),
+            ("currency/UAH", "Oekraïense hryvnia"),
+            ("currency/UGX", "Oegandese shilling"),
+            ("currency/USD", "Amerikaanse dollar"),
+            ("currency/UYU", "Uruguayaanse peso"),
+            ("currency/UZS", "Oezbeekse sum"),
+            ("currency/VND", "Vietnamese dong"),
+            ("currency/XAF", "CFA-frank"),
+            ("currency/XOF", "CFA-franc BCEAO"),
+            ("currency/ZAR", "Zuid-Afrikaanse rand"),
+            ("field-narrow/hour", "u"),
+            ("field-narrow/second", "s"),
+            ("field-narrow/weekday", "dag v.d. wk"),
+            ("field-short/minute", "min"),
+            ("field-short/month", "mnd"),
+            ("field-short/second", "sec"),
+            ("field-short/timeZoneName", "zone"),
+            ("field-short/weekOfYear", "wk"),
+            ("field-short/weekday", "dag van de wk"),
+            ("field-short/year", "jr"),
+            ("field/day", "dag"),
+            ("field/dayPeriod", "a.m./p.m."),
+            ("field/era", "tijdperk"),
+            ("field/hour", "uur"),
+            ("field/minute", "minuut"),
+            ("field/month", "maand"),
+            ("field/quarter", "kwartaal"),
+            ("field/second", "seconde"),
+            ("field/timeZoneName", "tijdzone"),
+            ("field/weekOfYear", "week"),
+            ("field/weekday", "dag van de week"),
+            ("field/year", "jaar"),
+            ("language-short/az", "Azeri"),
+            ("language/af", "Afrikaans"),
+            ("language/am", "Amhaars"),
+            ("language/ar", "Arabisch"),
+            ("language/ar-001", "modern standaard Arabisch"),
+            ("language/as", "Assamees"),
+            ("language/az", "Azerbeidzjaans"),
+            ("language/be", "Belarussisch"),
+            ("language/bg", "Bulgaars"),
+            ("language/bn", "Bengaals"),
+            ("language/bo", "Tibetaans"),
+            ("language/br", "Bretons"),
+            ("language/bs", "Bosnisch"),
+            ("language/ca", "Catalaans"),
+            ("language/chr", "Cherokee"),
+            ("language/cs", "Tsjechisch"),
+            ("language/cy", "Welsh"),
+            ("language/da", "Deens"),
+            ("language/de", "Duits"),
+            ("language/dz", "Dzongkha"),
+            ("language/el", "Grieks"),
+            ("language/en", "Engels"),
+            ("language/eo", "Esperanto"),
+            ("language/es", "Spaans"),
+            ("language/et", "Estisch"),
+            ("language/eu", "Baskisch"),
+            ("language/fa", "Perzisch"),
+            ("language/fa-AF", "Dari"),
+            ("language/ff", "Fulah"),
+            ("language/fi", "Fins"),
+            ("language/fil", "Filipijns"),
+            ("language/fo", "Faeröers"),
+            ("language/fr", "Frans"),
+            ("language/fy", "Fries"),
+            ("language/ga", "Iers"),
+            ("language/gd", "Schots-Gaelisch"),
+            ("language/gl", "Galicisch"),
+            ("language/gu", "Gujarati"),
+            ("language/ha", "Hausa"),
+            ("language/haw", "Hawaïaans"),
+            ("language/he", "Hebreeuws"),
+            ("language/hi", "Hindi"),
+            ("language/hr", "Kroatisch"),
+            ("language/ht", "Haïtiaans Creools"),
+            ("language/hu", "Hongaars"),
+            ("language/hy", "Armeens"),
+            ("language/id", "Indonesisch"),
+            ("language/ig", "Igbo"),
+            ("language/is", "IJslands"),
+            ("language/it", "Italiaans"),
+            ("language/ja", "Japans"),
+            ("language/jv", "Javaans"),
+            ("language/ka", "Georgisch"),
+            ("language/kk", "Kazachs"),
+            ("language/km", "Khmer"),
+            ("language/kn", "Kannada"),
+            ("language/ko", "Koreaans"),
+            ("language/ku", "Koerdisch"),
+            ("language/ky", "Kirgizisch"),
+            ("language/la", "Latijn"),
+            ("language/lb", "Luxemburgs"),
+            ("language/lo", "Laotiaans"),
+            ("language/lt", "Litouws"),
+            ("language/lv", "Lets"),
+            ("language/mg", "Malagassisch"),
+            ("language/mi", "Maori"),
+            ("language/mk", "Macedonisch"),
+            ("language/ml", "Malayalam"),
+            ("language/mn", "Mongools"),
+            ("language/mr", "Marathi"),
+            ("language/ms", "Maleis"),
+            ("language/mt", "Maltees"),
+            ("language/my", "Birmaans"),
+            ("language/nb", "Noors - Bokmål"),
+            ("language/ne", "Nepalees"),
+            ("language/nl", "Nederlands"),
+            ("language/nl-BE", "Vlaams"),
+            ("language/nn", "Noors - Nynorsk"),
+            ("language/no", "Noors"),
+            ("language/or", "Odia"),
+            ("language/pa", "Punjabi"),
+            ("language/pl", "Pools"),
+            ("language/ps", "Pasjtoe"),
+            ("language/pt", "Portugees"),
+            ("language/ro", "Roemeens"),
+            ("language/ru", "Russisch"),
+            ("language/rw", "Kinyarwanda"),
+            ("language/sa", "Sanskriet"),
+            ("language/sd", "Sindhi"),
+            ("language/si", "Singalees"),
+            ("language/sk", "Slowaaks"),
+            ("language/sl", "Sloveens"),
+            ("language/so", "Somalisch"),
+            ("language/sq", "Albanees"),
+            ("language/sr", "Servisch"),
+            ("language/sv", "Zweeds"),
+            ("language/sw", "Swahili"),
+            ("language/ta", "Tamil"),
+            ("language/te", "Telugu"),
+            ("language/tg", "Tadzjieks"),
+            ("language/th", "Thai"),
+            ("language/ti", "Tigrinya"),
+            ("language/tk", "Turkmeens"),
+            ("language/tr", "Turks"),
+            ("language/uk", "Oekraïens"),
+            ("language/ur", "Urdu"),
+            ("language/uz", "Oezbeeks"),
+            ("language/vi", "Vietnamees"),
+            ("language/xh", "Xhosa"),
+            ("language/yi", "Jiddisch"),
+            ("language/yo", "Yoruba"),
+            ("language/zh", "Chinees"),
+            ("language/zu", "Zoeloe"),
+            ("pattern", " (|)"),
+            ("region-short/GB", "VK"),
+            ("region-short/HK", "Hongkong"),
+            ("region-short/MO", "Macau"),
+            ("region-short/PS", "Palestina"),
+            ("region-short/UN", "VN"),
+            ("region-short/US", "VS"),
+            ("region/001", "wereld"),
+            ("region/002", "Afrika"),
+            ("region/003", "Noord-Amerika"),
+            ("region/005", "Zuid-Amerika"),
+            ("region/009", "Oceanië"),
+            ("region/019", "Amerika"),
+            ("region/142", "Azië"),
+            ("region/150", "Europa"),
+            ("region/419", "Latijns-Amerika"),
+            ("region/AE", "Verenigde Arabische Emiraten"),
+            ("region/AF", "Afghanistan"),
+            ("region/AL", "Albanië"),
+            ("region/AM", "Armenië"),
+            ("region/AO", "Angola"),
+            ("region/AQ", "Antarctica"),
+            ("region/AR", "Argentinië"),
+            ("region/AT", "Oostenrijk"),
+            ("region/AU", "Australië"),
+            ("region/AZ", "Azerbeidzjan"),
+            ("region/BA", "Bosnië en Herzegovina"),
+            ("region/BD", "Bangladesh"),
+            ("region/BE", "België"),
+            ("region/BG", "Bulgarije"),
+            ("region/BO", "Bolivia"),
+            ("region/BR", "Brazilië"),
+            ("region/BY", "Belarus"),
+            ("region/CA", "Canada"),
+            ("region/CD", "Congo-Kinshasa"),
+            ("region/CH", "Zwitserland"),
+            ("region/CL", "Chili"),
+            ("region/CM", "Kameroen"),
+            ("region/CN", "China"),
+            ("region/CO", "Colombia"),
+            ("region/CR", "Costa Rica"),
+            ("region/CU", "Cuba"),
+            ("region/CY", "Cyprus"),
+            ("region/CZ", "Tsjechië"),
+            ("region/DE", "Duitsland"),
+            ("region/DK", "Denemarken"),
+            ("region/DO", "Dominicaanse Republiek"),
+            ("region/DZ", "Algerije"),
+            ("region/EC", "Ecuador"),
+            ("region/EE", "Estland"),
+            ("region/EG", "Egypte"),
+            ("region/ES", "Spanje"),
+            ("region/ET", "Ethiopië"),
+            ("region/EU", "Europese Unie"),
+            ("region/FI", "Finland"),
+            ("region/FR", "Frankrijk"),
+            ("region/GB", "Verenigd Koninkrijk"),
+            ("region/GE", "Georgië"),
+            ("region/GH", "Ghana"),
+            ("region/GR", "Griekenland"),
+            ("region/GT", "Guatemala"),
+            ("region/HK", "Hongkong SAR van China"),
+            ("region/HR", "Kroatië"),
+            ("region/HU", "Hongarije"),
+            ("region/ID", "Indonesië"),
+            ("region/IE", "Ierland"),
+            ("region/IL", "Israël"),
+            ("region/IN", "India"),
+            ("region/IQ", "Irak"),
+            ("region/IR", "Iran"),
+            ("region/IS", "IJsland"),
+            ("region/IT", "Italië"),
+            ("region/JM", "Jamaica"),
+            ("region/JO", "Jordanië"),
+            ("region/JP", "Japan"),
+            ("region/KE", "Kenia"),
+            ("region/KH", "Cambodja"),
+            ("region/KP", "Noord-Korea"),
+            ("region/KR", "Zuid-Korea"),
+            ("region/KW", "Koeweit"),
+            ("region/KZ", "Kazachstan"),
+            ("region/LB", "Libanon"),
+            ("region/LK", "Sri Lanka"),
+            ("region/LT", "Litouwen"),
+            ("region/LU", "Luxemburg"),
+            ("region/LV", "Letland"),
+            ("region/MA", "Marokko"),
+            ("region/MD", "Moldavië"),
+            ("region/MK", "Noord-Macedonië"),
+            ("region/MM", "Myanmar (Birma)"),
+            ("region/MN", "Mongolië"),
+            ("region/MO", "Macau SAR van China"),
+            ("region/MT", "Malta"),
+            ("region/MX", "Mexico"),
+            ("region/MY", "Maleisië"),
+            ("region/NG", "Nigeria"),
+            ("region/NL", "Nederland"),
+            ("region/NO", "Noorwegen"),
+            ("region/NP", "Nepal"),
+            ("region/NZ", "Nieuw-Zeeland"),
+            ("region/PA", "Panama"),
+            ("region/PE", "Peru"),
+            ("region/PH", "Filipijnen"),
+            ("region/PK", "Pakistan"),
+            ("region/PL", "Polen"),
+            ("region/PR", "Puerto Rico"),
+            ("region/PS", "Palestijnse gebieden"),
+            ("region/PT", "Portugal"),
+            ("region/PY", "Paraguay"),
+            ("region/QA", "Qatar"),
+            ("region/RO", "Roemenië"),
+            ("region/RS", "Servië"),
+            ("region/RU", "Rusland"),
+            ("region/SA", "Saoedi-Arabië"),
+            ("region/SE", "Zweden"),
+            ("region/SG", "Singapore"),
+            ("region/SI", "Slovenië"),
+            ("region/SK", "Slowakije"),
+            ("region/SN", "Senegal"),
+            ("region/SY", "Syrië"),
+            ("region/TH", "Thailand"),
+            ("region/TN", "Tunesië"),
+            ("region/TR", "Turkije"),
+            ("region/TW", "Taiwan"),
+            ("region/TZ", "Tanzania"),
+            ("region/UA", "Oekraïne"),
+            ("region/UG", "Oeganda"),
+            ("region/UN", "Verenigde Naties"),
+            ("region/US", "Verenigde Staten"),
+            ("region/UY", "Uruguay"),
+            ("region/UZ", "Oezbekistan"),
+            ("region/VE", "Venezuela"),
+            ("region/VN", "Vietnam"),
+            ("region/ZA", "Zuid-Afrika"),
+            ("region/ZM", "Zambia"),
+            ("region/ZW", "Zimbabwe"),
+            ("script-stand-alone/Hans", "vereenvoudigd Chinees"),
+            ("script-stand-alone/Hant", "traditioneel Chinees"),
+            ("script/Arab", "Arabisch"),
+            ("script/Armn", "Armeens"),
+            ("script/Beng", "Bengaals"),
+            ("script/Bopo", "Bopomofo"),
+            ("script/Brai", "braille"),
+            ("script/Cyrl", "Cyrillisch"),
+            ("script/Deva", "Devanagari"),
+            ("script/Ethi", "Ethiopisch"),
+            ("script/Geor", "Georgisch"),
+            ("script/Grek", "Grieks"),
+            ("script/Gujr", "Gujarati"),
+            ("script/Guru", "Gurmukhi"),
+            ("script/Hang", "Hangul"),
+            ("script/Hani", "Han"),
+            ("script/Hans", "vereenvoudigd"),
+            ("script/Hant", "traditioneel"),
+            ("script/Hebr", "Hebreeuws"),
+            ("script/Hira", "Hiragana"),
+            ("script/Jpan", "Japans"),
+            ("script/Kana", "Katakana"),
+            ("script/Khmr", "Khmer"),
+            ("script/Knda", "Kannada"),
+            ("script/Kore", "Koreaans"),
+            ("script/Laoo", "Laotiaans"),
+            ("script/Latn", "Latijns"),
+            ("script/Mlym", "Malayalam"),
+            ("script/Mong", "Mongools"),
+            ("script/Mymr", "Birmaans"),
+            ("script/Orya", "Odia"),
+            ("script/Sinh", "Singalees"),
+            ("script/Taml", "Tamil"),
+            ("script/Telu", "Telugu"),
+            ("script/Thai", "Thai"),
+            ("separator", ", "),
+        ],
+    ),
+    (
+        "pl",
+        &[
+            ("calendar/buddhist", "kalendarz buddyjski"),
+            ("calendar/chinese", "kalendarz chiński"),
+            ("calendar/coptic", "kalendarz koptyjski"),
+            ("calendar/dangi", "kalendarz koreański"),
+            ("calendar/ethioaa", "kalendarz etiopski Amete Alem"),
+            ("calendar/ethiopic", "kalendarz etiopski"),
+            ("calendar/gregory", "kalendarz gregoriański"),
+            ("calendar/hebrew", "kalendarz hebrajski"),
+            ("calendar/indian", "narodowy kalendarz hinduski"),
+            ("calendar/islamic", "kalendarz muzułmański"),
+            (
+                "calendar/islamic-civil",
+                "kalendarz islamski (metoda obliczeniowa)",
+            ),
+            (
+                "calendar/islamic-rgsa",
+                "kalendarz islamski (Arabia Saudyjska, metoda wzrokowa)",
+            ),
+            (
+                "calendar/islamic-tbla",
+                "kalendarz islamski (metoda obliczeniowa, epoka astronomiczna)",
+            ),
+            (
+                "calendar/islamic-umalqura",
+                "kalendarz islamski (Umm al-Kura)",
+            ),
+            ("calendar/iso8601", "kalendarz ISO-8601"),
+            ("calendar/japanese", "kalendarz japoński"),
+            ("calendar/persian", "kalendarz perski"),
+            ("calendar/roc", "kalendarz Republiki Chińskiej"),
+            ("currency/AED", "dirham ZEA"),
+            ("currency/ARS", "peso argentyńskie"),
+            ("currency/AUD", "dolar australijski"),
+            ("currency/BDT", "taka bengalska"),
+            ("currency/BGN", "lew bułgarski"),
+            ("currency/BHD", "dinar bahrański"),
+            ("currency/BOB", "boliviano boliwijskie"),
+            ("currency/BRL", "real brazylijski"),
+            ("currency/CAD", "dolar kanadyjski"),
+            ("currency/CHF", "frank szwajcarski"),
+            ("currency/CLP", "peso chilijskie"),
+            ("currency/CNY", "juan chiński"),
+            ("currency/COP", "peso kolumbijskie"),
+            ("currency/CRC", "colon kostarykański"),
+            ("currency/CZK", "korona czeska"),
+            ("currency/DKK", "korona duńska"),
+            ("currency/DOP", "peso dominikańskie"),
+            ("currency/DZD", "dinar algierski"),
+            ("currency/EGP", "funt egipski"),
+            ("currency/ETB", "birr etiopski"),
+            ("currency/EUR", "euro"),
+            ("currency/GBP", "funt szterling"),
+            ("currency/GEL", "lari gruzińskie"),
+            ("currency/GHS", "cedi ghańskie"),
+            ("currency/GTQ", "quetzal gwatemalski"),
+            ("currency/HKD", "dolar hongkoński"),
+            ("currency/HUF", "forint węgierski"),
+            ("currency/IDR", "rupia indonezyjska"),
+            ("currency/ILS", "nowy szekel izraelski"),
+            ("currency/INR", "rupia indyjska"),
+            ("currency/IQD", "dinar iracki"),
+            ("currency/IRR", "rial irański"),
+            ("currency/ISK", "korona islandzka"),
+            ("currency/JMD", "dolar jamajski"),
+            ("currency/JOD", "dinar jordański"),
+            ("currency/JPY", "jen japoński"),
+            ("currency/KES", "szyling kenijski"),
+            ("currency/KRW", "won południowokoreański"),
+            ("currency/KWD", "dinar kuwejcki"),
+            ("currency/KZT", "tenge kazachskie"),
+            ("currency/LBP", "funt libański"),
+            ("currency/LKR", "rupia lankijska"),
+            ("currency/MAD", "dirham marokański"),
+            ("currency/MDL", "lej mołdawski"),
+            ("currency/MXN", "peso meksykańskie"),
+            ("currency/MYR", "ringgit malezyjski"),
+            ("currency/NGN", "naira nigeryjska"),
+            ("currency/NOK", "korona norweska"),
+            ("currency/NPR", "rupia nepalska"),
+            ("currency/NZD", "dolar nowozelandzki"),
+            ("currency/OMR", "rial omański"),
+            ("currency/PAB", "balboa panamski"),
+            ("currency/PEN", "sol peruwiański"),
+            ("currency/PHP", "peso filipińskie"),
+            ("currency/PKR", "rupia pakistańska"),
+            ("currency/PLN", "złoty polski"),
+            ("currency/PYG", "guarani paragwajskie"),
+            ("currency/QAR", "rial katarski"),
+            ("currency/RON", "lej rumuński"),
+            ("currency/RSD", "dinar serbski"),
+            ("currency/RUB", "rubel rosyjski"),
+            ("currency/SAR", "rial saudyjski"),
+            ("currency/SEK", "korona szwedzka"),
+            ("currency/SGD", "dolar singapurski"),
+            ("currency/THB", "baht tajski"),
+            ("currency/TND", "dinar tunezyjski"),
+            ("currency/TRY", "lira turecka"),
+            ("currency/TWD", "nowy dolar tajwański"),
+            ("currency/TZS", "szyling tanzański"),
+            ("currency/UAH", "hrywna ukraińska"),
+            ("currency/UGX", "szyling ugandyjski"),
+            ("currency/USD", "dolar amerykański"),
+            ("currency/UYU", "peso urugwajskie"),
+            ("currency/UZS", "som uzbecki"),
+            ("currency/VND", "dong wietnamski"),
+            ("currency/XAF", "frank CFA BEAC"),
+            ("currency/XOF", "frank CFA"),
+            ("currency/ZAR", "rand południowoafrykański"),
+            ("field-narrow/day", "d."),
+            ("field-narrow/dayPeriod", "rano / po poł. / wiecz."),
+            ("field-narrow/hour", "g."),
+            ("field-narrow/month", "mc"),
+            ("field-narrow/second", "s"),
+            ("field-narrow/timeZoneName", "str. czas."),
+            ("field-narrow/weekday", "dz. tyg."),
+            ("field-short/day", "dz."),
+            ("field-short/hour", "godz."),
+            ("field-short/minute", "min"),
+            ("field-short/month", "mies."),
+            ("field-short/quarter", "kw."),
+            ("field-short/second", "sek."),
+            ("field-short/timeZoneName", "str. czasowa"),
+            ("field-short/weekOfYear", "tydz."),
+            ("field-short/weekday", "dzień tyg."),
+            ("field-short/year", "r."),
+            ("field/day", "dzień"),
+            ("field/dayPeriod", "rano / po południu / wieczorem"),
+            ("field/era", "era"),
+            ("field/hour", "godzina"),
+            ("field/minute", "minuta"),
+            ("field/month", "miesiąc"),
+            ("field/quarter", "kwartał"),
+            ("field/second", "sekunda"),
+            ("field/timeZoneName", "strefa czasowa"),
+            ("field/weekOfYear", "tydzień"),
+            ("field/weekday", "dzień tygodnia"),
+            ("field/year", "rok"),
+            ("language-short/az", "azerski"),
+            ("language-short/en-GB", "angielski (Wlk. Bryt.)"),
+            ("language-short/en-US", "angielski (USA)"),
+            ("language/af", "afrikaans"),
+            ("language/am", "amharski"),
+            ("language/ar", "arabski"),
+            ("language/ar-001", "współczesny arabski"),
+            ("language/as", "asamski"),
+            ("language/az", "azerbejdżański"),
+            ("language/be", "białoruski"),
+            ("language/bg", "bułgarski"),
+            ("language/bn", "bengalski"),
+            ("language/bo", "tybetański"),
+            ("language/br", "bretoński"),
+            ("language/bs", "bośniacki"),
+            ("language/ca", "kataloński"),
+            ("language/chr", "czirokeski"),
+            ("language/cs", "czeski"),
+            ("language/cy", "walijski"),
+            ("language/da", "duński"),
+            ("language/de", "niemiecki"),
+            ("language/de-AT", "niemiecki austriacki"),
+            ("language/de-CH", "wysokoniemiecki szwajcarski"),
+            ("language/dz", "dzongkha"),
+            ("language/el", "grecki"),
+            ("language/en", "angielski"),
+            ("language/en-AU", "angielski australijski"),
+            ("language/en-CA", "angielski kanadyjski"),
+            ("language/en-GB", "angielski brytyjski"),
+            ("language/en-US", "angielski amerykański"),
+            ("language/eo", "esperanto"),
+            ("language/es", "hiszpański"),
+            ("language/es-419", "amerykański hiszpański"),
+            ("language/es-ES", "europejski hiszpański"),
+            ("language/es-MX", "meksykański hiszpański"),
+            ("language/et", "estoński"),
+            ("language/eu", "baskijski"),
+            ("language/fa", "perski"),
+            ("language/fa-AF", "dari"),
+            ("language/ff", "fulani"),
+            ("language/fi", "fiński"),
+            ("language/fil", "filipiński"),
+            ("language/fo", "farerski"),
+            ("language/fr", "francuski"),
+            ("language/fr-CA", "francuski kanadyjski"),
+            ("language/fr-CH", "francuski szwajcarski"),
+            ("language/fy", "zachodniofryzyjski"),
+            ("language/ga", "irlandzki"),
+            ("language/gd", "szkocki gaelicki"),
+            ("language/gl", "galicyjski"),
+            ("language/gu", "gudżarati"),
+            ("language/ha", "hausa"),
+            ("language/haw", "hawajski"),
+            ("language/he", "hebrajski"),
+            ("language/hi", "hindi"),
+            ("language/hr", "chorwacki"),
+            ("language/ht", "kreolski haitański"),
+            ("language/hu", "węgierski"),
+            ("language/hy", "ormiański"),
+            ("language/id", "indonezyjski"),
+            ("language/ig", "igbo"),
+            ("language/is", "islandzki"),
+            ("language/it", "włoski"),
+            ("language/ja", "japoński"),
+            ("language/jv", "jawajski"),
+            ("language/ka", "gruziński"),
+            ("language/kk", "kazachski"),
+            ("language/km", "khmerski"),
+            ("language/kn", "kannada"),
+            ("language/ko", "koreański"),
+            ("language/ku", "kurdyjski"),
+            ("language/ky", "kirgiski"),
+            ("language/la", "łaciński"),
+            ("language/lb", "luksemburski"),
+            ("language/lo", "laotański"),
+            ("language/lt", "litewski"),
+            ("language/lv", "łotewski"),
+            ("language/mg", "malgaski"),
+            ("language/mi", "maoryjski"),
+            ("language/mk", "macedoński"),
+            ("language/ml", "malajalam"),
+            ("language/mn", "mongolski"),
+            ("language/mr", "marathi"),
+            ("language/ms", "malajski"),
+            ("language/mt", "maltański"),
+            ("language/my", "birmański"),
+            ("language/nb", "norweski (bokmål)"),
+            ("language/ne", "nepalski"),
+            ("language/nl", "niderlandzki"),
+            ("language/nl-BE", "flamandzki"),
+            ("language/nn", "norweski (nynorsk)"),
+            ("language/no", "norweski"),
+            ("language/or", "orija"),
+            ("language/pa", "pendżabski"),
+            ("language/pl", "polski"),
+            ("language/ps", "paszto"),
+            ("language/pt", "portugalski"),
+            ("language/pt-BR", "brazylijski portugalski"),
+            ("language/pt-PT", "europejski portugalski"),
+            ("language/ro", "rumuński"),
+            ("language/ro-MD", "mołdawski"),
+            ("language/ru", "rosyjski"),
+            ("language/rw", "kinya-ruanda"),
+            ("language/sa", "sanskryt"),
+            ("language/sd", "sindhi"),
+            ("language/si", "syngaleski"),
+            ("language/sk", "słowacki"),
+            ("language/sl", "słoweński"),
+            ("language/so", "somalijski"),
+            ("language/sq", "albański"),
+            ("language/sr", "serbski"),
+            ("language/sv", "szwedzki"),
+            ("language/sw", "suahili"),
+            ("language/sw-CD", "kongijski suahili"),
+            ("language/ta", "tamilski"),
+            ("language/te", "telugu"),
+            ("language/tg", "tadżycki"),
+            ("language/th", "tajski"),
+            ("language/ti", "tigrinia"),
+            ("language/tk", "turkmeński"),
+            ("language/tr", "turecki"),
+            ("language/uk", "ukraiński"),
+            ("language/ur", "urdu"),
+            ("language/uz", "uzbecki"),
+            ("language/vi", "wietnamski"),
+            ("language/xh", "khosa"),
+            ("language/yi", "jidysz"),
+            ("language/yo", "joruba"),
+            ("language/zh", "chiński"),
+            ("language/zh-Hans", "chiński uproszczony"),
+            ("language/zh-Hant", "chiński tradycyjny"),
+            ("language/zu", "zulu"),
+            ("pattern", " (|)"),
+            ("region-short/GB", "Wlk. Bryt."),
+            ("region-short/HK", "Hongkong"),
+            ("region-short/MO", "Makau"),
+            ("region-short/PS", "Palestyna"),
+            ("region-short/UN", "ONZ"),
+            ("region-short/US", "USA"),
+            ("region/001", "świat"),
+            ("region/002", "Afryka"),
+            ("region/003", "Ameryka Północna"),
+            ("region/005", "Ameryka Południowa"),
+            ("region/009", "Oceania"),
+            ("region/019", "Ameryka"),
+            ("region/142", "Azja"),
+            ("region/150", "Europa"),
+            ("region/419", "Ameryka Łacińska"),
+            ("region/AE", "Zjednoczone Emiraty Arabskie"),
+            ("region/AF", "Afganistan"),
+            ("region/AL", "Albania"),
+            ("region/AM", "Armenia"),
+            ("region/AO", "Angola"),
+            ("region/AQ", "Antarktyda"),
+            ("region/AR", "Argentyna"),
+            ("region/AT", "Austria"),
+            ("region/AU", "Australia"),
+            ("region/AZ", "Azerbejdżan"),
+            ("region/BA", "Bośnia i Hercegowina"),
+            ("region/BD", "Bangladesz"),
+            ("region/BE", "Belgia"),
+            ("region/BG", "Bułgaria"),
+            ("region/BO", "Boliwia"),
+            ("region/BR", "Brazylia"),
+            ("region/BY", "Białoruś"),
+            ("region/CA", "Kanada"),
+            ("region/CD", "Demokratyczna Republika Konga"),
+            ("region/CH", "Szwajcaria"),
+            ("region/CL", "Chile"),
+            ("region/CM", "Kamerun"),
+            ("region/CN", "Chiny"),
+            ("region/CO", "Kolumbia"),
+            ("region/CR", "Kostaryka"),
+            ("region/CU", "Kuba"),
+            ("region/CY", "Cypr"),
+            ("region/CZ", "Czechy"),
+            ("region/DE", "Niemcy"),
+            ("region/DK", "Dania"),
+            ("region/DO", "Dominikana"),
+            ("region/DZ", "Algieria"),
+            ("region/EC", "Ekwador"),
+            ("region/EE", "Estonia"),
+            ("region/EG", "Egipt"),
+            ("region/ES", "Hiszpania"),
+            ("region/ET", "Etiopia"),
+            ("region/EU", "Unia Europejska"),
+            ("region/FI", "Finlandia"),
+            ("region/FR", "Francja"),
+            ("region/GB", "Wielka Brytania"),
+            ("region/GE", "Gruzja"),
+            ("region/GH", "Ghana"),
+            ("region/GR", "Grecja"),
+            ("region/GT", "Gwatemala"),
+            ("region/HK", "SRA Hongkong (Chiny)"),
+            ("region/HR", "Chorwacja"),
+            ("region/HU", "Węgry"),
+            ("region/ID", "Indonezja"),
+            ("region/IE", "Irlandia"),
+            ("region/IL", "Izrael"),
+            ("region/IN", "Indie"),
+            ("region/IQ", "Irak"),
+            ("region/IR", "Iran"),
+            ("region/IS", "Islandia"),
+            ("region/IT", "Włochy"),
+            ("region/JM", "Jamajka"),
+            ("region/JO", "Jordania"),
+            ("region/JP", "Japonia"),
+            ("region/KE", "Kenia"),
+            ("region/KH", "Kambodża"),
+            ("region/KP", "Korea Północna"),
+            ("region/KR", "Korea Południowa"),
+            ("region/KW", "Kuwejt"),
+            ("region/KZ", "Kazachstan"),
+            ("region/LB", "Liban"),
+            ("region/LK", "Sri Lanka"),
+            ("region/LT", "Litwa"),
+            ("region/LU", "Luksemburg"),
+            ("region/LV", "Łotwa"),
+            ("region/MA", "Maroko"),
+            ("region/MD", "Mołdawia"),
+            ("region/MK", "Macedonia Północna"),
+            ("region/MM", "Mjanma (Birma)"),
+            ("region/MN", "Mongolia"),
+            ("region/MO", "SRA Makau (Chiny)"),
+            ("region/MT", "Malta"),
+            ("region/MX", "Meksyk"),
+            ("region/MY", "Malezja"),
+            ("region/NG", "Nigeria"),
+            ("region/NL", "Holandia"),
+            ("region/NO", "Norwegia"),
+            ("region/NP", "Nepal"),
+            ("region/NZ", "Nowa Zelandia"),
+            ("region/PA", "Panama"),
+            ("region/PE", "Peru"),
+            ("region/PH", "Filipiny"),
+            ("region/PK", "Pakistan"),
+            ("region/PL", "Polska"),
+            ("region/PR", "Portoryko"),
+            ("region/PS", "Terytoria Palestyńskie"),
+            ("region/PT", "Portugalia"),
+            ("region/PY", "Paragwaj"),
+            ("region/QA", "Katar"),
+            ("region/RO", "Rumunia"),
+            ("region/RS", "Serbia"),
+            ("region/RU", "Rosja"),
+            ("region/SA", "Arabia Saudyjska"),
+            ("region/SE", "Szwecja"),
+            ("region/SG", "Singapur"),
+            ("region/SI", "Słowenia"),
+            ("region/SK", "Słowacja"),
+            ("region/SN", "Senegal"),
+            ("region/SY", "Syria"),
+            ("region/TH", "Tajlandia"),
+            ("region/TN", "Tunezja"),
+            ("region/TR", "Turcja"),
+            ("region/TW", "Tajwan"),
+            ("region/TZ", "Tanzania"),
+            ("region/UA", "Ukraina"),
+            ("region/UG", "Uganda"),
+            ("region/UN", "Organizacja Narodów Zjednoczonych"),
+            ("region/US", "Stany Zjednoczone"),
+            ("region/UY", "Urugwaj"),
+            ("region/UZ", "Uzbekistan"),
+            ("region/VE", "Wenezuela"),
+            ("region/VN", "Wietnam"),
+            ("region/ZA", "Republika Południowej Afryki"),
+            ("region/ZM", "Zambia"),
+            ("region/ZW", "Zimbabwe"),
+            ("script-stand-alone/Hans", "chińskie uproszczone"),
+            ("script-stand-alone/Hant", "chińskie tradycyjne"),
+            ("script/Arab", "arabskie"),
+            ("script/Armn", "ormiańskie"),
+            ("script/Beng", "bengalskie"),
+            ("script/Bopo", "bopomofo"),
+            ("script/Brai", "Braille’a"),
+            ("script/Cyrl", "cyrylica"),
+            ("script/Deva", "dewanagari"),
+            ("script/Ethi", "etiopskie"),
+            ("script/Geor", "gruzińskie"),
+            ("script/Grek", "greckie"),
+            ("script/Gujr", "gudżarati"),
+            ("script/Guru", "gurmukhi"),
+            ("script/Hang", "hangul"),
+            ("script/Hani", "chińskie"),
+            ("script/Hans", "uproszczone"),
+            ("script/Hant", "tradycyjne"),
+            ("script/Hebr", "hebrajskie"),
+            ("script/Hira", "hiragana"),
+            ("script/Jpan", "japońskie"),
+            ("script/Kana", "katakana"),
+            ("script/Khmr", "khmerskie"),
+            ("script/Knda", "kannada"),
+            ("script/Kore", "koreańskie"),
+            ("script/Laoo", "laotańskie"),
+            ("script/Latn", "łacińskie"),
+            ("script/Mlym", "malajalam"),
+            ("script/Mong", "mongolskie"),
+            ("script/Mymr", "birmańskie"),
+            ("script/Orya", "orija"),
+            ("script/Sinh", "syngaleskie"),
+            ("script/Taml", "tamilskie"),
+            ("script/Telu", "telugu"),
+            ("script/Thai", "tajskie"),
+            ("separator", ", "),
+        ],
+    ),
+    (
+        "pt",
+        &[
+            ("calendar/buddhist", "Calendário Budista"),
+            ("calendar/chinese", "Calendário Chinês"),
+            ("calendar/coptic", "Calendário Copta"),
+            ("calendar/dangi", "Calendário Dangi"),
+            ("calendar/ethioaa", "Calendário Amete Alem da Etiópia"),
+            ("calendar/ethiopic", "Calendário Etíope"),
+            ("calendar/gregory", "Calendário Gregoriano"),
+            ("calendar/hebrew", "Calendário Hebraico"),
+            ("calendar/indian", "Calendário Nacional Indiano"),
+            ("calendar/islamic", "Calendário Islâmico"),
+            ("calendar/islamic-civil", "Calendário Civil Islâmico"),
+            (
+                "calendar/islamic-umalqura",
+                "Calendário Islâmico (Umm al-Qura)",
+            ),
+            ("calendar/iso8601", "Calendário ISO-8601"),
+            ("calendar/japanese", "Calendário Japonês"),
+            ("calendar/persian", "Calendário Persa"),
+            ("calendar/roc", "Calendário da República da China"),
+            ("currency/AED", "Dirham dos Emirados Árabes Unidos"),
+            ("currency/ARS", "Peso argentino"),
+            ("currency/AUD", "Dólar australiano"),
+            ("currency/BDT", "Taka bengali"),
+            ("currency/BGN", "Lev búlgaro"),
+            ("currency/BHD", "Dinar bareinita"),
+            ("currency/BOB", "Boliviano da Bolívia"),
+            ("currency/BRL", "Real brasileiro"),
+            ("currency/CAD", "Dólar canadense"),
+            ("currency/CHF", "Franco suíço"),
+            ("currency/CLP", "Peso chileno"),
+            ("currency/CNY", "Yuan chinês"),
+            ("currency/COP", "Peso colombiano"),
+            ("currency/CRC", "Colón costarriquenho"),
+            ("currency/CZK", "Coroa tcheca"),
+            ("currency/DKK", "Coroa dinamarquesa"),
+            ("currency/DOP", "Peso dominicano"),
+            ("currency/DZD", "Dinar argelino"),
+            ("currency/EGP", "Libra egípcia"),
+            ("currency/ETB", "Birr etíope"),
+            ("currency/EUR", "Euro"),
+            ("currency/GBP", "Libra esterlina"),
+            ("currency/GEL", "Lari georgiano"),
+            ("currency/GHS", "Cedi ganês"),
+            ("currency/GTQ", "Quetzal guatemalteco"),
+            ("currency/HKD", "Dólar de Hong Kong"),
+            ("currency/HUF", "Florim húngaro"),
+            ("currency/IDR", "Rupia indonésia"),
+            ("currency/ILS", "Novo shekel israelense"),
+            ("currency/INR", "Rupia indiana"),
+            ("currency/IQD", "Dinar iraquiano"),
+            ("currency/IRR", "Rial iraniano"),
+            ("currency/ISK", "Coroa islandesa"),
+            ("currency/JMD", "Dólar jamaicano"),
+            ("currency/JOD", "Dinar jordaniano"),
+            ("currency/JPY", "Iene japonês"),
+            ("currency/KES", "Xelim queniano"),
+            ("currency/KRW", "Won sul-coreano"),
+            ("currency/KWD", "Dinar kuwaitiano"),
+            ("currency/KZT", "Tenge cazaque"),
+            ("currency/LBP", "Libra libanesa"),
+            ("currency/LKR", "Rupia cingalesa"),
+            ("currency/MAD", "Dirham marroquino"),
+            ("currency/MDL", "Leu moldávio"),
+            ("currency/MXN", "Peso mexicano"),
+            ("currency/MYR", "Ringgit malaio"),
+            ("currency/NGN", "Naira nigeriana"),
+            ("currency/NOK", "Coroa norueguesa"),
+            ("currency/NPR", "Rupia nepalesa"),
+            ("currency/NZD", "Dólar neozelandês"),
+            ("currency/OMR", "Rial omanense"),
+            ("currency/PAB", "Balboa panamenho"),
+            ("currency/PEN", "Novo sol peruano"),
+            ("currency/PHP", "Peso filipino"),
+            ("currency/PKR", "Rupia paquistanesa"),
+            ("currency/PLN", "Zloty polonês"),
+            ("currency/PYG", "Guarani paraguaio"),
+            ("currency/QAR", "Rial catariano"),
+            ("currency/RON", "Leu romeno"),
+            ("currency/RSD", "Dinar sérvio"),
+            ("currency/RUB", "Rublo russo"),
+            ("currency/SAR", "Riyal saudita"),
+            ("currency/SEK", "Coroa sueca"),
+            ("currency/SGD", "Dólar singapuriano"),
+            ("currency/THB", "Baht tailandês"),
+            ("currency/TND", "Dinar tunisiano"),
+            ("currency/TRY", "Lira turca"),
+            ("currency/TWD", "Novo dólar taiwanês"),
+            ("currency/TZS", "Xelim tanzaniano"),
+            ("currency/UAH", "Hryvnia ucraniano"),
+            ("currency/UGX", "Xelim ugandense"),
+            ("currency/USD", "Dólar americano"),
+            ("currency/UYU", "Peso uruguaio"),
+            ("currency/UZS", "Som uzbeque"),
+            ("currency/VND", "Dong vietnamita"),
+            ("currency/XAF", "Franco CFA de BEAC"),
+            ("currency/XOF", "Franco CFA de BCEAO"),
+            ("currency/ZAR", "Rand sul-africano"),
+            ("field-short/hour", "h"),
+            ("field-short/minute", "min."),
+            ("field-short/quarter", "trim."),
+            ("field-short/second", "seg."),
+            ("field-short/timeZoneName", "fuso"),
+            ("field-short/weekOfYear", "sem."),
+            ("field-short/weekday", "dia da sem."),
+            ("field/day", "dia"),
+            ("field/dayPeriod", "AM/PM"),
+            ("field/era", "era"),
+            ("field/hour", "hora"),
+            ("field/minute", "minuto"),
+            ("field/month", "mês"),
+            ("field/quarter", "trimestre"),
+            ("field/second", "segundo"),
+            ("field/timeZoneName", "fuso horário"),
+            ("field/weekOfYear", "semana"),
+            ("field/weekday", "dia da semana"),
+            ("field/year", "ano"),
+            ("language/af", "africâner"),
+            ("language/am", "amárico"),
+            ("language/ar", "árabe"),
+            ("language/ar-001", "árabe moderno"),
+            ("language/as", "assamês"),
+            ("language/az", "azerbaijano"),
+            ("language/be", "bielorrusso"),
+            ("language/bg", "búlgaro"),
+            ("language/bn", "bengali"),
+            ("language/bo", "tibetano"),
+            ("language/br", "bretão"),
+            ("language/bs", "bósnio"),
+            ("language/ca", "catalão"),
+            ("language/chr", "cheroqui"),
+            ("language/cs", "tcheco"),
+            ("language/cy", "galês"),
+            ("language/da", "dinamarquês"),
+            ("language/de", "alemão"),
+            ("language/de-CH", "alto alemão (Suíça)"),
+            ("language/dz", "dzonga"),
+            ("language/el", "grego"),
+            ("language/en", "inglês"),
+            ("language/eo", "esperanto"),
+            ("language/es", "espanhol"),
+            ("language/et", "estoniano"),
+            ("language/eu", "basco"),
+            ("language/fa", "persa"),
+            ("language/fa-AF", "dari"),
+            ("language/ff", "fula"),
+            ("language/fi", "finlandês"),
+            ("language/fil", "filipino"),
+            ("language/fo", "feroês"),
+            ("language/fr", "francês"),
+            ("language/fy", "frísio ocidental"),
+            ("language/ga", "irlandês"),
+            ("language/gd", "gaélico escocês"),
+            ("language/gl", "galego"),
+            ("language/gu", "guzerate"),
+            ("language/ha", "hauçá"),
+            ("language/haw", "havaiano"),
+            ("language/he", "hebraico"),
+            ("language/hi", "híndi"),
+            ("language/hr", "croata"),
+            ("language/ht", "haitiano"),
+            ("language/hu", "húngaro"),
+            ("language/hy", "armênio"),
+            ("language/id", "indonésio"),
+            ("language/ig", "igbo"),
+            ("language/is", "islandês"),
+            ("language/it", "italiano"),
+            ("language/ja", "japonês"),
+            ("language/jv", "javanês"),
+            ("language/ka", "georgiano"),
+            ("language/kk", "cazaque"),
+            ("language/km", "khmer"),
+            ("language/kn", "canarim"),
+            ("language/ko", "coreano"),
+            ("language/ku", "curdo"),
+            ("language/ky", "quirguiz"),
+            ("language/la", "latim"),
+            ("language/lb", "luxemburguês"),
+            ("language/lo", "laosiano"),
+            ("language/lt", "lituano"),
+            ("language/lv", "letão"),
+            ("language/mg", "malgaxe"),
+            ("language/mi", "maori"),
+            ("language/mk", "macedônio"),
+            ("language/ml", "malaiala"),
+            ("language/mn", "mongol"),
+            ("language/mr", "marati"),
+            ("language/ms", "malaio"),
+            ("language/mt", "maltês"),
+            ("language/my", "birmanês"),
+            ("language/nb", "bokmål norueguês"),
+            ("language/ne", "nepalês"),
+            ("language/nl", "holandês"),
+            ("language/nl-BE", "flamengo"),
+            ("language/nn", "nynorsk norueguês"),
+            ("language/no", "norueguês"),
+            ("language/or", "oriá"),
+            ("language/pa", "panjabi"),
+            ("language/pl", "polonês"),
+            ("language/ps", "pashto"),
+            ("language/pt", "português"),
+            ("language/ro", "romeno"),
+            ("language/ro-MD", "moldávio"),
+            ("language/ru", "russo"),
+            ("language/rw", "quiniaruanda"),
+            ("language/sa", "sânscrito"),
+            ("language/sd", "sindi"),
+            ("language/si", "cingalês"),
+            ("language/sk", "eslovaco"),
+            ("language/sl", "esloveno"),
+            ("language/so", "somali"),
+            ("language/sq", "albanês"),
+            ("language/sr", "sérvio"),
+            ("language/sv", "sueco"),
+            ("language/sw", "suaíli"),
+            ("language/sw-CD", "suaíli do Congo"),
+            ("language/ta", "tâmil"),
+            ("language/te", "télugo"),
+            ("language/tg", "tadjique"),
+            ("language/th", "tailandês"),
+            ("language/ti", "tigrínia"),
+            ("language/tk", "turcomeno"),
+            ("language/tr", "turco"),
+            ("language/uk", "ucraniano"),
+            ("language/ur", "urdu"),
+            ("language/uz", "uzbeque"),
+            ("language/vi", "vietnamita"),
+            ("language/xh", "xhosa"),
+            ("language/yi", "iídiche"),
+            ("language/yo", "iorubá"),
+            ("language/zh", "chinês"),
+            ("language/zh-Hans", "chinês simplificado"),
+            ("language/zh-Hant", "chinês tradicional"),
+            ("language/zu", "zulu"),
+            ("pattern", " (|)"),
+            ("region-short/HK", "Hong Kong"),
+            ("region-short/MO", "Macau"),
+            ("region-short/PS", "Palestina"),
+            ("region-short/UN", "ONU"),
+            ("region-short/US", "EUA"),
+            ("region/001", "Mundo"),
+            ("region/002", "África"),
+            ("region/003", "América do Norte"),
+            ("region/005", "América do Sul"),
+            ("region/009", "Oceania"),
+            ("region/019", "Américas"),
+            ("region/142", "Ásia"),
+            ("region/150", "Europa"),
+            ("region/419", "América Latina"),
+            ("region/AE", "Emirados Árabes Unidos"),
+            ("region/AF", "Afeganistão"),
+            ("region/AL", "Albânia"),
+            ("region/AM", "Armênia"),
+            ("region/AO", "Angola"),
+            ("region/AQ", "Antártida"),
+            ("region/AR", "Argentina"),
+            ("region/AT", "Áustria"),
+            ("region/AU", "Austrália"),
+            ("region/AZ", "Azerbaijão"),
+            ("region/BA", "Bósnia e Herzegovina"),
+            ("region/BD", "Bangladesh"),
+            ("region/BE", "Bélgica"),
+            ("region/BG", "Bulgária"),
+            ("region/BO", "Bolívia"),
+            ("region/BR", "Brasil"),
+            ("region/BY", "Bielorrússia"),
+            ("region/CA", "Canadá"),
+            ("region/CD", "Congo - Kinshasa"),
+            ("region/CH", "Suíça"),
+            ("region/CL", "Chile"),
+            ("region/CM", "Camarões"),
+            ("region/CN", "China"),
+            ("region/CO", "Colômbia"),
+            ("region/CR", "Costa Rica"),
+            ("region/CU", "Cuba"),
+            ("region/CY", "Chipre"),
+            ("region/CZ", "Tchéquia"),
+            ("region/DE", "Alemanha"),
+            ("region/DK", "Dinamarca"),
+            ("region/DO", "República Dominicana"),
+            ("region/DZ", "Argélia"),
+            ("region/EC", "Equador"),
+            ("region/EE", "Estônia"),
+            ("region/EG", "Egito"),
+            ("region/ES", "Espanha"),
+            ("region/ET", "Etiópia"),
+            ("region/EU", "União Europeia"),
+            ("region/FI", "Finlândia"),
+            ("region/FR", "França"),
+            ("region/GB", "Reino Unido"),
+            ("region/GE", "Geórgia"),
+            ("region/GH", "Gana"),
+            ("region/GR", "Grécia"),
+            ("region/GT", "Guatemala"),
+            ("region/HK", "Hong Kong, RAE da China"),
+            ("region/HR", "Croácia"),
+            ("region/HU", "Hungria"),
+            ("region/ID", "Indonésia"),
+            ("region/IE", "Irlanda"),
+            ("region/IL", "Israel"),
+            ("region/IN", "Índia"),
+            ("region/IQ", "Iraque"),
+            ("region/IR", "Irã"),
+            ("region/IS", "Islândia"),
+            ("region/IT", "Itália"),
+            ("region/JM", "Jamaica"),
+            ("region/JO", "Jordânia"),
+            ("region/JP", "Japão"),
+            ("region/KE", "Quênia"),
+            ("region/KH", "Camboja"),
+            ("region/KP", "Coreia do Norte"),
+            ("region/KR", "Coreia do Sul"),
+            ("region/KW", "Kuwait"),
+            ("region/KZ", "Cazaquistão"),
+            ("region/LB", "Líbano"),
+            ("region/LK", "Sri Lanka"),
+            ("region/LT", "Lituânia"),
+            ("region/LU", "Luxemburgo"),
+            ("region/LV", "Letônia"),
+            ("region/MA", "Marrocos"),
+            ("region/MD", "Moldávia"),
+            ("region/MK", "Macedônia do Norte"),
+            ("region/MM", "Mianmar (Birmânia)"),
+            ("region/MN", "Mongólia"),
+            ("region/MO", "Macau, RAE da China"),
+            ("region/MT", "Malta"),
+            ("region/MX", "México"),
+            ("region/MY", "Malásia"),
+            ("region/NG", "Nigéria"),
+            ("region/NL", "Países Baixos"),
+            ("region/NO", "Noruega"),
+            ("region/NP", "Nepal"),
+            ("region/NZ", "Nova Zelândia"),
+            ("region/PA", "Panamá"),
+            ("region/PE", "Peru"),
+            ("region/PH", "Filipinas"),
+            ("region/PK", "Paquistão"),
+            ("region/PL", "Polônia"),
+            ("region/PR", "Porto Rico"),
+            ("region/PS", "Territórios palestinos"),
+            ("region/PT", "Portugal"),
+            ("region/PY", "Paraguai"),
+            ("region/QA", "Catar"),
+            ("region/RO", "Romênia"),
+            ("region/RS", "Sérvia"),
+            ("region/RU", "Rússia"),
+            ("region/SA", "Arábia Saudita"),
+            ("region/SE", "Suécia"),
+            ("region/SG", "Singapura"),
+            ("region/SI", "Eslovênia"),
+            ("region/SK", "Eslováquia"),
+            ("region/SN", "Senegal"),
+            ("region/SY", "Síria"),
+            ("region/TH", "Tailândia"),
+            ("region/TN", "Tunísia"),
+            ("region/TR", "Turquia"),
+            ("region/TW", "Taiwan"),
+            ("region/TZ", "Tanzânia"),
+            ("region/UA", "Ucrânia"),
+            ("region/UG", "Uganda"),
+            ("region/UN", "Nações Unidas"),
+            ("region/US", "Estados Unidos"),
+            ("region/UY", "Uruguai"),
+            ("region/UZ", "Uzbequistão"),
+            ("region/VE", "Venezuela"),
+            ("region/VN", "Vietnã"),
+            ("region/ZA", "África do Sul"),
+            ("region/ZM", "Zâmbia"),
+            ("region/ZW", "Zimbábue"),
+            ("script-stand-alone/Hans", "han simplificado"),
+            ("script-stand-alone/Hant", "han tradicional"),
+            ("script/Arab", "árabe"),
+            ("script/Armn", "armênio"),
+            ("script/Beng", "bengali"),
+            ("script/Bopo", "bopomofo"),
+            ("script/Brai", "braille"),
+            ("script/Cyrl", "cirílico"),
+            ("script/Deva", "devanágari"),
+            ("script/Ethi", "etiópico"),
+            ("script/Geor", "georgiano"),
+            ("script/Grek", "grego"),
+            ("script/Gujr", "guzerate"),
+            ("script/Guru", "gurmuqui"),
+            ("script/Hang", "hangul"),
+            ("script/Hani", "han"),
+            ("script/Hans", "simplificado"),
+            ("script/Hant", "tradicional"),
+            ("script/Hebr", "hebraico"),
+            ("script/Hira", "hiragana"),
+            ("script/Jpan", "japonês"),
+            ("script/Kana", "katakana"),
+            ("script/Khmr", "khmer"),
+            ("script/Knda", "kannada"),
+            ("script/Kore", "coreano"),
+            ("script/Laoo", "lao"),
+            ("script/Latn", "latim"),
+            ("script/Mlym", "malaiala"),
+            ("script/Mong", "mongol"),
+            ("script/Mymr", "birmanês"),
+            ("script/Orya", "oriá"),
+            ("script/Sinh", "cingalês"),
+            ("script/Taml", "tâmil"),
+            ("script/Telu", "télugo"),
+            ("script/Thai", "tailandês"),
+            ("separator", ", "),
+        ],
+    ),
+    (
+        "pt-PT",
+        &[
+            ("calendar/buddhist", "Calendário budista"),
+            ("calendar/chinese", "Calendário chinês"),
+            ("calendar/coptic", "Calendário copta"),
+            ("calendar/dangi", "Calendário dangi"),
+            ("calendar/ethioaa", "Calendário etíope Amete Alem"),
+            ("calendar/ethiopic", "Calendário etíope"),
+            ("calendar/gregory", "Calendário gregoriano"),
+            ("calendar/hebrew", "Calendário hebraico"),
+            ("calendar/indian", "Calendário nacional indiano"),
+            ("calendar/islamic", "Calendário islâmico"),
+            ("calendar/islamic-civil", "Calendário islâmico (civil)"),
+            (
+                "calendar/islamic-umalqura",
+                "Calendário islâmico (Umm al-Qura)",
+            ),
+            ("calendar/japanese", "Calendário japonês"),
+            ("calendar/persian", "Calendário persa"),
+            ("currency/AED", "dirham dos Emirados Árabes Unidos"),
+            ("currency/ARS", "peso argentino"),
+            ("currency/AUD", "dólar australiano"),
+            ("currency/BDT", "taka bengali"),
+            ("currency/BGN", "lev búlgaro"),
+            ("currency/BHD", "dinar baremita"),
+            ("currency/BOB", "boliviano"),
+            ("currency/BRL", "real brasileiro"),
+            ("currency/CAD", "dólar canadiano"),
+            ("currency/CHF", "franco suíço"),
+            ("currency/CLP", "peso chileno"),
+            ("currency/CNY", "yuan"),
+            ("currency/COP", "peso colombiano"),
+            ("currency/CRC", "colon costa-riquenho"),
+            ("currency/CZK", "coroa checa"),
+            ("currency/DKK", "coroa dinamarquesa"),
+            ("currency/DOP", "peso dominicano"),
+            ("currency/DZD", "dinar argelino"),
+            ("currency/EGP", "libra egípcia"),
+            ("currency/ETB", "birr etíope"),
+            ("currency/EUR", "euro"),
+            ("currency/GBP", "libra esterlina britânica"),
+            ("currency/GEL", "lari georgiano"),
+            ("currency/GHS", "cedi ganês"),
+            ("currency/GTQ", "quetzal da Guatemala"),
+            ("currency/HKD", "dólar de Hong Kong"),
+            ("currency/HUF", "forint húngaro"),
+            ("currency/IDR", "rupia indonésia"),
+            ("currency/ILS", "sheqel novo israelita"),
+            ("currency/INR", "rupia indiana"),
+            ("currency/IQD", "dinar iraquiano"),
+            ("currency/IRR", "rial iraniano"),
+            ("currency/ISK", "coroa islandesa"),
+            ("currency/JMD", "dólar jamaicano"),
+            ("currency/JOD", "dinar jordaniano"),
+            ("currency/JPY", "iene japonês"),
+            ("currency/KES", "xelim queniano"),
+            ("currency/KRW", "won sul-coreano"),
+            ("currency/KWD", "dinar kuwaitiano"),
+            ("currency/KZT", "tenge cazaque"),
+            ("currency/LBP", "libra libanesa"),
+            ("currency/LKR", "rupia do Sri Lanka"),
+            ("currency/MAD", "dirham marroquino"),
+            ("currency/MDL", "leu moldavo"),
+            ("currency/MXN", "peso mexicano"),
+            ("currency/MYR", "ringgit malaio"),
+            ("currency/NGN", "naira nigeriana"),
+            ("currency/NOK", "coroa norueguesa"),
+            ("currency/NPR", "rupia nepalesa"),
+            ("currency/NZD", "dólar neozelandês"),
+            ("currency/OMR", "rial omanense"),
+            ("currency/PAB", "balboa do Panamá"),
+            ("currency/PEN", "sol peruano"),
+            ("currency/PHP", "peso filipino"),
+            ("currency/PKR", "rupia paquistanesa"),
+            ("currency/PLN", "zloti polaco"),
+            ("currency/PYG", "guarani paraguaio"),
+            ("currency/QAR", "rial catarense"),
+            ("currency/RON", "leu romeno"),
+            ("currency/RSD", "dinar sérvio"),
+            ("currency/RUB", "rublo russo"),
+            ("currency/SAR", "rial saudita"),
+            ("currency/SEK", "coroa sueca"),
+            ("currency/SGD", "dólar singapuriano"),
+            ("currency/THB", "baht tailandês"),
+            ("currency/TND", "dinar tunisino"),
+            ("currency/TRY", "lira turca"),
+            ("currency/TWD", "novo dólar taiwanês"),
+            ("currency/TZS", "xelim tanzaniano"),
+            ("currency/UAH", "hryvnia ucraniano"),
+            ("currency/UGX", "xelim ugandense"),
+            ("currency/USD", "dólar dos Estados Unidos"),
+            ("currency/UYU", "peso uruguaio"),
+            ("currency/UZS", "som uzbeque"),
+            ("currency/VND", "dong vietnamita"),
+            ("currency/XAF", "franco CFA (BEAC)"),
+            ("currency/XOF", "franco CFA (BCEAO)"),
+            ("currency/ZAR", "rand sul-africano"),
+            ("field-short/minute", "min"),
+            ("field-short/second", "s"),
+            ("field/dayPeriod", "am/pm"),
+            ("language-short/az", "azeri"),
+            ("language-short/en-GB", "inglês (RU)"),
+            ("language/af", "africanês"),
+            ("language/ar-001", "árabe moderno padrão"),
+            ("language/bn", "bengalês"),
+            ("language/chr", "cherokee"),
+            ("language/cs", "checo"),
+            ("language/de-AT", "alemão austríaco"),
+            ("language/de-CH", "alto alemão suíço"),
+            ("language/en-AU", "inglês australiano"),
+            ("language/en-CA", "inglês canadiano"),
+            ("language/en-GB", "inglês britânico"),
+            ("language/en-US", "inglês americano"),
+            ("language/es-419", "espanhol latino-americano"),
+            ("language/es-ES", "espanhol europeu"),
+            ("language/es-MX", "espanhol mexicano"),
+            ("language/et", "estónio"),
+            ("language/fr-CA", "francês canadiano"),
+            ("language/fr-CH", "francês suíço"),
+            ("language/fy", "frísico ocidental"),
+            ("language/ha", "haúça"),
+            ("language/hi", "hindi"),
+            ("language/hy", "arménio"),
+            ("language/mk", "macedónio"),
+            ("language/mr", "marata"),
+            ("language/nb", "norueguês bokmål"),
+            ("language/nl", "neerlandês"),
+            ("language/nn", "norueguês nynorsk"),
+            ("language/pl", "polaco"),
+            ("language/ps", "pastó"),
+            ("language/pt-BR", "português do Brasil"),
+            ("language/pt-PT", "português europeu"),
+            ("language/te", "telugu"),
+            ("language/tg", "tajique"),
+            ("language/tk", "turcomano"),
+            ("language/uz", "usbeque"),
+            ("language/xh", "xosa"),
+            ("language/yo", "ioruba"),
+            ("region-short/GB", "GB"),
+            ("region/009", "Oceânia"),
+            ("region/AM", "Arménia"),
+            ("region/BD", "Bangladeche"),
+            ("region/CD", "Congo-Kinshasa"),
+            ("region/CZ", "Chéquia"),
+            ("region/EE", "Estónia"),
+            ("region/IR", "Irão"),
+            ("region/KE", "Quénia"),
+            ("region/KW", "Koweit"),
+            ("region/LK", "Sri Lanca"),
+            ("region/LV", "Letónia"),
+            ("region/MK", "Macedónia do Norte"),
+            ("region/PL", "Polónia"),
+            ("region/PS", "Territórios palestinianos"),
+            ("region/RO", "Roménia"),
+            ("region/SI", "Eslovénia"),
+            ("region/UZ", "Usbequistão"),
+            ("region/VN", "Vietname"),
+            ("region/ZW", "Zimbabué"),
+            ("script/Armn", "arménio"),
+            ("script/Beng", "bengalês"),
+            ("script/Ethi", "etíope"),
+            ("script/Orya", "odia"),
+            ("script/Telu", "telugu"),
+        ],
+    ),
+    (
+        "ru",
+        &[
+            ("calendar/buddhist", "буддийский календарь"),
+            ("calendar/chinese", "китайский календарь"),
+            ("calendar/coptic", "коптский календарь"),
+            ("calendar/dangi", "календарь данги"),
+            ("calendar/ethioaa", "эфиопский календарь амете-алем"),
+            ("calendar/ethiopic", "эфиопский календарь"),
+            ("calendar/gregory", "григорианский календарь"),
+            ("calendar/hebrew", "еврейский календарь"),
+            ("calendar/indian", "Национальный календарь Индии"),
+            ("calendar/islamic", "исламский календарь"),
+            (
+                "calendar/islamic-civil",
+                "исламский гражданский календарь (табличный)",
+            ),
+            (
+                "calendar/islamic-rgsa",
+                "исламский календарь (Саудовская Аравия)",
+            ),
+            (
+                "calendar/islamic-tbla",
+                "исламский календарь (табличный, астрономическая эпоха)",
+            ),
+            (
+                "calendar/islamic-umalqura",
+                "исламский календарь (Умм аль-Кура)",
+            ),
+            ("calendar/iso8601", "календарь ISO-8601"),
+            ("calendar/japanese", "японский календарь"),
+            ("calendar/persian", "персидский календарь"),
+            ("calendar/roc", "календарь Миньго"),
+            ("currency/AED", "дирхам ОАЭ"),
+            ("currency/ARS", "аргентинский песо"),
+            ("currency/AUD", "австралийский доллар"),
+            ("currency/BDT", "бангладешская така"),
+            ("currency/BGN", "болгарский лев"),
+            ("currency/BHD", "бахрейнский динар"),
+            ("currency/BOB", "боливийский боливиано"),
+            ("currency/BRL", "бразильский реал"),
+            ("currency/CAD", "канадский доллар"),
+            ("currency/CHF", "швейцарский франк"),
+            ("currency/CLP", "чилийский песо"),
+            ("currency/CNY", "китайский юань"),
+            ("currency/COP", "колумбийский песо"),
+            ("currency/CRC", "костариканский колон"),
+            ("currency/CZK", "чешская крона"),
+            ("currency/DKK", "датская крона"),
+            ("currency/DOP", "доминиканский песо"),
+            ("currency/DZD", "алжирский динар"),
+            ("currency/EGP", "египетский фунт"),
+            ("currency/ETB", "эфиопский быр"),
+            ("currency/EUR", "евро"),
+            ("currency/GBP", "британский фунт стерлингов"),
+            ("currency/GEL", "грузинский лари"),
+            ("currency/GHS", "ганский седи"),
+            ("currency/GTQ", "гватемальский кетсаль"),
+            ("currency/HKD", "гонконгский доллар"),
+            ("currency/HUF", "венгерский форинт"),
+            ("currency/IDR", "индонезийская рупия"),
+            ("currency/ILS", "новый израильский шекель"),
+            ("currency/INR", "индийская рупия"),
+            ("currency/IQD", "иракский динар"),
+            ("currency/IRR", "иранский риал"),
+            ("currency/ISK", "исландская крона"),
+            ("currency/JMD", "ямайский доллар"),
+            ("currency/JOD", "иорданский динар"),
+            ("currency/JPY", "японская иена"),
+            ("currency/KES", "кенийский шиллинг"),
+            ("currency/KRW", "южнокорейская вона"),
+            ("currency/KWD", "кувейтский динар"),
+            ("currency/KZT", "казахский тенге"),
+            ("currency/LBP", "ливанский фунт"),
+            ("currency/LKR", "шри-ланкийская рупия"),
+            ("currency/MAD", "марокканский дирхам"),
+            ("currency/MDL", "молдавский лей"),
+            ("currency/MXN", "мексиканский песо"),
+            ("currency/MYR", "малайзийский ринггит"),
+            ("currency/NGN", "нигерийская найра"),
+            ("currency/NOK", "норвежская крона"),
+            ("currency/NPR", "непальская рупия"),
+            ("currency/NZD", "новозеландский доллар"),
+            ("currency/OMR", "оманский риал"),
+            ("currency/PAB", "панамский бальбоа"),
+            ("currency/PEN", "перуанский соль"),
+            ("currency/PHP", "филиппинский песо"),
+            ("currency/PKR", "пакистанская рупия"),
+            ("currency/PLN", "польский злотый"),
+            ("currency/PYG", "парагвайский гуарани"),
+            ("currency/QAR", "катарский риал"),
+            ("currency/RON", "румынский лей"),
+            ("currency/RSD", "сербский динар"),
+            ("currency/RUB", "российский рубль"),
+            ("currency/SAR", "саудовский риял"),
+            ("currency/SEK", "шведская крона"),
+            ("currency/SGD", "сингапурский доллар"),
+            ("currency/THB", "таиландский бат"),
+            ("currency/TND", "тунисский динар"),
+            ("currency/TRY", "турецкая лира"),
+            ("currency/TWD", "новый тайваньский доллар"),
+            ("currency/TZS", "танзанийский шиллинг"),
+            ("currency/UAH", "украинская гривна"),
+            ("currency/UGX", "угандийский шиллинг"),
+            ("currency/USD", "доллар США"),
+            ("currency/UYU", "уругвайский песо"),
+            ("currency/UZS", "узбекский сум"),
+            ("currency/VND", "вьетнамский донг"),
+            ("currency/XAF", "франк КФА BEAC"),
+            ("currency/XOF", "франк КФА ВСЕАО"),
+            ("currency/ZAR", "южноафриканский рэнд"),
+            ("field-narrow/minute", "мин"),
+            ("field-narrow/second", "с"),
+            ("field-narrow/weekday", "дн. нед."),
+            ("field-short/day", "дн."),
+            ("field-short/hour", "ч"),
+            ("field-short/minute", "мин."),
+            ("field-short/month", "мес."),
+            ("field-short/quarter", "кв."),
+            ("field-short/second", "сек."),
+            ("field-short/timeZoneName", "час. пояс"),
+            ("field-short/weekOfYear", "нед."),
+            ("field-short/weekday", "дн. недели"),
+            ("field-short/year", "г."),
+            ("field/day", "день"),
+            ("field/dayPeriod", "AM/PM"),
+            ("field/era", "эра"),
+            ("field/hour", "час"),
+            ("field/minute", "минута"),
+            ("field/month", "месяц"),
+            ("field/quarter", "квартал"),
+            ("field/second", "секунда"),
+            ("field/timeZoneName", "часовой пояс"),
+            ("field/weekOfYear", "неделя"),
+            ("field/weekday", "день недели"),
+            ("field/year", "год"),
+            ("language/af", "африкаанс"),
+            ("language/am", "амхарский"),
+            ("language/ar", "арабский"),
+            ("language/ar-001", "арабский литературный"),
+            ("language/as", "ассамский"),
+            ("language/az", "азербайджанский"),
+            ("language/be", "белорусский"),
+            ("language/bg", "болгарский"),
+            ("language/bn", "бенгальский"),
+            ("language/bo", "тибетский"),
+            ("language/br", "бретонский"),
+            ("language/bs", "боснийский"),
+            ("language/ca", "каталанский"),
+            ("language/chr", "чероки"),
+            ("language/cs", "чешский"),
+            ("language/cy", "валлийский"),
+            ("language/da", "датский"),
+            ("language/de", "немецкий"),
+            ("language/de-AT", "австрийский немецкий"),
+            ("language/de-CH", "литературный швейцарский немецкий"),
+            ("language/dz", "дзонг-кэ"),
+            ("language/el", "греческий"),
+            ("language/en", "английский"),
+            ("language/en-AU", "австралийский английский"),
+            ("language/en-CA", "канадский английский"),
+            ("language/en-GB", "британский английский"),
+            ("language/en-US", "американский английский"),
+            ("language/eo", "эсперанто"),
+            ("language/es", "испанский"),
+            ("language/es-419", "латиноамериканский испанский"),
+            ("language/es-ES", "европейский испанский"),
+            ("language/es-MX", "мексиканский испанский"),
+            ("language/et", "эстонский"),
+            ("language/eu", "баскский"),
+            ("language/fa", "персидский"),
+            ("language/fa-AF", "дари"),
+            ("language/ff", "фулах"),
+            ("language/fi", "финский"),
+            ("language/fil", "филиппинский"),
+            ("language/fo", "фарерский"),
+            ("language/fr", "французский"),
+            ("language/fr-CA", "канадский французский"),
+            ("language/fr-CH", "швейцарский французский"),
+            ("language/fy", "западнофризский"),
+            ("language/ga", "ирландский"),
+            ("language/gd", "гэльский"),
+            ("language/gl", "галисийский"),
+            ("language/gu", "гуджарати"),
+            ("language/ha", "хауса"),
+            ("language/haw", "гавайский"),
+            ("language/he", "иврит"),
+            ("language/hi", "хинди"),
+            ("language/hr", "хорватский"),
+            ("language/ht", "гаитянский"),
+            ("language/hu", "венгерский"),
+            ("language/hy", "армянский"),
+            ("language/id", "индонезийский"),
+            ("language/ig", "игбо"),
+            ("language/is", "исландский"),
+            ("language/it", "итальянский"),
+            ("language/ja", "японский"),
+            ("language/jv", "яванский"),
+            ("language/ka", "грузинский"),
+            ("language/kk", "казахский"),
+            ("language/km", "кхмерский"),
+            ("language/kn", "каннада"),
+            ("language/ko", "корейский"),
+            ("language/ku", "курдский"),
+            ("language/ky", "киргизский"),
+            ("language/la", "латинский"),
+            ("language/lb", "люксембургский"),
+            ("language/lo", "лаосский"),
+            ("language/lt", "литовский"),
+            ("language/lv", "латышский"),
+            ("language/mg", "малагасийский"),
+            ("language/mi", "маори"),
+            ("language/mk", "македонский"),
+            ("language/ml", "малаялам"),
+            ("language/mn", "монгольский"),
+            ("language/mr", "маратхи"),
+            ("language/ms", "малайский"),
+            ("language/mt", "мальтийский"),
+            ("language/my", "бирманский"),
+            ("language/nb", "норвежский букмол"),
+            ("language/ne", "непальский"),
+            ("language/nl", "нидерландский"),
+            ("language/nl-BE", "фламандский"),
+            ("language/nn", "нюнорск"),
+            ("language/no", "норвежский"),
+            ("language/or", "ория"),
+            ("language/pa", "панджаби"),
+            ("language/pl", "польский"),
+            ("language/ps", "пушту"),
+            ("language/pt", "португальский"),
+            ("language/pt-BR", "бразильский португальский"),
+            ("language/pt-PT", "европейский португальский"),
+            ("language/ro", "румынский"),
+            ("language/ro-MD", "молдавский"),
+            ("language/ru", "русский"),
+            ("language/rw", "киньяруанда"),
+            ("language/sa", "санскрит"),
+            ("language/sd", "синдхи"),
+            ("language/si", "сингальский"),
+            ("language/sk", "словацкий"),
+            ("language/sl", "словенский"),
+            ("language/so", "сомали"),
+            ("language/sq", "албанский"),
+            ("language/sr", "сербский"),
+            ("language/sv", "шведский"),
+            ("language/sw", "суахили"),
+            ("language/sw-CD", "конголезский суахили"),
+            ("language/ta", "тамильский"),
+            ("language/te", "телугу"),
+            ("language/tg", "таджикский"),
+            ("language/th", "тайский"),
+            ("language/ti", "тигринья"),
+            ("language/tk", "туркменский"),
+            ("language/tr", "турецкий"),
+            ("language/uk", "украинский"),
+            ("language/ur", "урду"),
+            ("language/uz", "узбекский"),
+            ("language/vi", "вьетнамский"),
+            ("language/xh", "коса"),
+            ("language/yi", "идиш"),
+            ("language/yo", "йоруба"),
+            ("language/zh", "китайский"),
+            ("language/zh-Hans", "китайский, упрощенное письмо"),
+            ("language/zh-Hant", "китайский, традиционное письмо"),
+            ("language/zu", "зулу"),
+            ("pattern", " (|)"),
+            ("region-short/GB", "Британия"),
+            ("region-short/HK", "Гонконг"),
+            ("region-short/MO", "Макао"),
+            ("region-short/PS", "Палестина"),
+            ("region-short/UN", "ООН"),
+            ("region-short/US", "США"),
+            ("region/001", "весь мир"),
+            ("region/002", "Африка"),
+            ("region/003", "Северная Америка"),
+            ("region/005", "Южная Америка"),
+            ("region/009", "Океания"),
+            ("region/019", "Америка"),
+            ("region/142", "Азия"),
+            ("region/150", "Европа"),
+            ("region/419", "Латинская Америка"),
+            ("region/AE", "ОАЭ"),
+            ("region/AF", "Афганистан"),
+            ("region/AL", "Албания"),
+            ("region/AM", "Армения"),
+            ("region/AO", "Ангола"),
+            ("region/AQ", "Антарктида"),
+            ("region/AR", "Аргентина"),
+            ("region/AT", "Австрия"),
+            ("region/AU", "Австралия"),
+            ("region/AZ", "Азербайджан"),
+            ("region/BA", "Босния и Герцеговина"),
+            ("region/BD", "Бангладеш"),
+            ("region/BE", "Бельгия"),
+            ("region/BG", "Болгария"),
+            ("region/BO", "Боливия"),
+            ("region/BR", "Бразилия"),
+            ("region/BY", "Беларусь"),
+            ("region/CA", "Канада"),
+            ("region/CD", "Конго - Киншаса"),
+            ("region/CH", "Швейцария"),
+            ("region/CL", "Чили"),
+            ("region/CM", "Камерун"),
+            ("region/CN", "Китай"),
+            ("region/CO", "Колумбия"),
+            ("region/CR", "Коста-Рика"),
+            ("region/CU", "Куба"),
+            ("region/CY", "Кипр"),
+            ("region/CZ", "Чехия"),
+            ("region/DE", "Германия"),
+            ("region/DK", "Дания"),
+            ("region/DO", "Доминиканская Республика"),
+            ("region/DZ", "Алжир"),
+            ("region/EC", "Эквадор"),
+            ("region/EE", "Эстония"),
+            ("region/EG", "Египет"),
+            ("region/ES", "Испания"),
+            ("region/ET", "Эфиопия"),
+            ("region/EU", "Европейский союз"),
+            ("region/FI", "Финляндия"),
+            ("region/FR", "Франция"),
+            ("region/GB", "Великобритания"),
+            ("region/GE", "Грузия"),
+            ("region/GH", "Гана"),
+            ("region/GR", "Греция"),
+            ("region/GT", "Гватемала"),
+            ("region/HK", "Гонконг (САР)"),
+            ("region/HR", "Хорватия"),
+            ("region/HU", "Венгрия"),
+            ("region/ID", "Индонезия"),
+            ("region/IE", "Ирландия"),
+            ("region/IL", "Израиль"),
+            ("region/IN", "Индия"),
+            ("region/IQ", "Ирак"),
+            ("region/IR", "Иран"),
+            ("region/IS", "Исландия"),
+            ("region/IT", "Италия"),
+            ("region/JM", "Ямайка"),
+            ("region/JO", "Иордания"),
+            ("region/JP", "Япония"),
+            ("region/KE", "Кения"),
+            ("region/KH", "Камбоджа"),
+            ("region/KP", "КНДР"),
+            ("region/KR", "Республика Корея"),
+            ("region/KW", "Кувейт"),
+            ("region/KZ", "Казахстан"),
+            ("region/LB", "Ливан"),
+            ("region/LK", "Шри-Ланка"),
+            ("region/LT", "Литва"),
+            ("region/LU", "Люксембург"),
+            ("region/LV", "Латвия"),
+            ("region/MA", "Марокко"),
+            ("region/MD", "Молдова"),
+            ("region/MK", "Северная Македония"),
+            ("region/MM", "Мьянма (Бирма)"),
+            ("region/MN", "Монголия"),
+            ("region/MO", "Макао (САР)"),
+            ("region/MT", "Мальта"),
+            ("region/MX", "Мексика"),
+            ("region/MY", "Малайзия"),
+            ("region/NG", "Нигерия"),
+            ("region/NL", "Нидерланды"),
+            ("region/NO", "Норвегия"),
+            ("region/NP", "Непал"),
+            ("region/NZ", "Новая Зеландия"),
+            ("region/PA", "Панама"),
+            ("region/PE", "Перу"),
+            ("region/PH", "Филиппины"),
+            ("region/PK", "Пакистан"),
+            ("region/PL", "Польша"),
+            ("region/PR", "Пуэрто-Рико"),
+            ("region/PS", "Палестинские территории"),
+            ("region/PT", "Португалия"),
+            ("region/PY", "Парагвай"),
+            ("region/QA", "Катар"),
+            ("region/RO", "Румыния"),
+            ("region/RS", "Сербия"),
+            ("region/RU", "Россия"),
+            ("region/SA", "Саудовская Аравия"),
+            ("region/SE", "Швеция"),
+            ("region/SG", "Сингапур"),
+            ("region/SI", "Словения"),
+            ("region/SK", "Словакия"),
+            ("region/SN", "Сенегал"),
+            ("region/SY", "Сирия"),
+            ("region/TH", "Таиланд"),
+            ("region/TN", "Тунис"),
+            ("region/TR", "Турция"),
+            ("region/TW", "Тайвань"),
+            ("region/TZ", "Танзания"),
+            ("region/UA", "Украина"),
+            ("region/UG", "Уганда"),
+            ("region/UN", "Организация Объединенных Наций"),
+            ("region/US", "Соединенные Штаты"),
+            ("region/UY", "Уругвай"),
+            ("region/UZ", "Узбекистан"),
+            ("region/VE", "Венесуэла"),
+            ("region/VN", "Вьетнам"),
+            ("region/ZA", "Южно-Африканская Республика"),
+            ("region/ZM", "Замбия"),
+            ("region/ZW", "Зимбабве"),
+            ("script-stand-alone/Hans", "упрощенная китайская"),
+            ("script-stand-alone/Hant", "традиционная китайская"),
+            ("script/Arab", "арабица"),
+            ("script/Armn", "армянская"),
+            ("script/Beng", "бенгальская"),
+            ("script/Bopo", "бопомофо"),
+            ("script/Brai", "шрифт Брайля"),
+            ("script/Cyrl", "кириллица"),
+            ("script/Deva", "деванагари"),
+            ("script/Ethi", "эфиопская"),
+            ("script/Geor", "грузинская"),
+            ("script/Grek", "греческая"),
+            ("script/Gujr", "гуджарати"),
+            ("script/Guru", "гурмукхи"),
+            ("script/Hang", "хангыль"),
+            ("script/Hani", "китайская"),
+            ("script/Hans", "упрощенная"),
+            ("script/Hant", "традиционная"),
+            ("script/Hebr", "еврейская"),
+            ("script/Hira", "хирагана"),
+            ("script/Jpan", "японская"),
+            ("script/Kana", "катакана"),
+            ("script/Khmr", "кхмерская"),
+            ("script/Knda", "каннада"),
+            ("script/Kore", "корейская"),
+            ("script/Laoo", "лаосская"),
+            ("script/Latn", "латиница"),
+            ("script/Mlym", "малаялам"),
+            ("script/Mong", "монгольская"),
+            ("script/Mymr", "мьянманская"),
+            ("script/Orya", "ория"),
+            ("script/Sinh", "сингальская"),
+            ("script/Taml", "тамильская"),
+            ("script/Telu", "телугу"),
+            ("script/Thai", "тайская"),
+            ("separator", ", "),
+        ],
+    ),
+    ("ru-UA", &[("region/AE", "Объединенные Арабские Эмираты")]),
+    (
+        "sv",
+        &[
+            ("calendar/buddhist", "buddistisk kalender"),
+            ("calendar/chinese", "kinesisk kalender"),
+            ("calendar/coptic", "koptisk kalender"),
+            ("calendar/dangi", "koreansk kalender"),
+            ("calendar/ethioaa", "etiopisk amete-alem-kalender"),
+            ("calendar/ethiopic", "etiopisk kalender"),
+            ("calendar/gregory", "gregoriansk kalender"),
+            ("calendar/hebrew", "hebreisk kalender"),
+            ("calendar/indian", "indisk kalender"),
+            ("calendar/islamic", "islamisk kalender"),
+            ("calendar/islamic-civil", "islamisk civil kalender"),
+            ("calendar/islamic-rgsa", "islamisk kalender, Saudi-Arabien"),
+            ("calendar/islamic-tbla", "islamisk kalender, astronomisk"),
+            (
+                "calendar/islamic-umalqura",
+                "islamisk kalender, Umm al-Qura",
+            ),
+            ("calendar/iso8601", "ISO 8601-kalender"),
+            ("calendar/japanese", "japansk kalender"),
+            ("calendar/persian", "persisk kalender"),
+            ("calendar/roc", "kinesiska republikens kalender"),
+            ("currency/AED", "emiratisk dirham"),
+            ("currency/ARS", "argentinsk peso"),
+            ("currency/AUD", "australisk dollar"),
+            ("currency/BDT", "bangladeshisk taka"),
+            ("currency/BGN", "bulgarisk lev"),
+            ("currency/BHD", "bahrainsk dinar"),
+            ("currency/BOB", "boliviansk boliviano"),
+            ("currency/BRL", "brasiliansk real"),
+            ("currency/CAD", "kanadensisk dollar"),
+            ("currency/CHF", "schweizisk franc"),
+            ("currency/CLP", "chilensk peso"),
+            ("currency/CNY", "kinesisk yuan"),
+            ("currency/COP", "colombiansk peso"),
+            ("currency/CRC", "costarikansk colón"),
+            ("currency/CZK", "tjeckisk koruna"),
+            ("currency/DKK", "dansk krona"),
+            ("currency/DOP", "dominikansk peso"),
+            ("currency/DZD", "algerisk dinar"),
+            ("currency/EGP", "egyptiskt pund"),
+            ("currency/ETB", "etiopisk birr"),
+            ("currency/EUR", "euro"),
+            ("currency/GBP", "brittiskt pund"),
+            ("currency/GEL", "georgisk lari"),
+            ("currency/GHS", "ghanansk cedi"),
+            ("currency/GTQ", "guatemalansk quetzal"),
+            ("currency/HKD", "Hongkongdollar"),
+            ("currency/HUF", "ungersk forint"),
+            ("currency/IDR", "indonesisk rupie"),
+            ("currency/ILS", "israelisk ny shekel"),
+            ("currency/INR", "indisk rupie"),
+            ("currency/IQD", "irakisk dinar"),
+            ("currency/IRR", "iransk rial"),
+            ("currency/ISK", "isländsk krona"),
+            ("currency/JMD", "jamaicansk dollar"),
+            ("currency/JOD", "jordansk dinar"),
+            ("currency/JPY", "japansk yen"),
+            ("currency/KES", "kenyansk shilling"),
+            ("currency/KRW", "sydkoreansk won"),
+            ("currency/KWD", "kuwaitisk dinar"),
+            ("currency/KZT", "kazakisk tenge"),
+            ("currency/LBP", "libanesiskt pund"),
+            ("currency/LKR", "srilankesisk rupie"),
+            ("currency/MAD", "marockansk dirham"),
+            ("currency/MDL", "moldavisk leu"),
+            ("currency/MXN", "mexikansk peso"),
+            ("currency/MYR", "malaysisk ringgit"),
+            ("currency/NGN", "nigeriansk naira"),
+            ("currency/NOK", "norsk krona"),
+            ("currency/NPR", "nepalesisk rupie"),
+            ("currency/NZD", "nyzeeländsk dollar"),
+            ("currency/OMR", "omansk rial"),
+            ("currency/PAB", "panamansk balboa"),
+            ("currency/PEN", "peruansk sol"),
+            ("currency/PHP", "filippinsk peso"),
+            ("currency/PKR", "pakistansk rupie"),
+            ("currency/PLN", "polsk zloty"),
+            ("currency/PYG", "paraguayansk guarani"),
+            ("currency/QAR", "qatarisk rial"),
+            ("currency/RON", "rumänsk leu"),
+            ("currency/RSD", "serbisk dinar"),
+            ("currency/RUB", "rysk rubel"),
+            ("currency/SAR", "saudisk riyal"),
+            ("currency/SEK", "svensk krona"),
+            ("currency/SGD", "singaporiansk dollar"),
+            ("currency/THB", "thailändsk baht"),
+            ("currency/TND", "tunisisk dinar"),
+            ("currency/TRY", "turkisk lira"),
+            ("currency/TWD", "taiwanesisk dollar"),
+            ("currency/TZS", "tanzanisk shilling"),
+            ("currency/UAH", "ukrainsk hryvnia"),
+            ("currency/UGX", "ugandisk shilling"),
+            ("currency/USD", "amerikansk dollar"),
+            ("currency/UYU", "uruguayansk peso"),
+            ("currency/UZS", "uzbekisk sum"),
+            ("currency/VND", "vietnamesisk dong"),
+            ("currency/XAF", "centralafrikansk franc"),
+            ("currency/XOF", "västafrikansk franc"),
+            ("currency/ZAR", "sydafrikansk rand"),
+            ("field-narrow/hour", "h"),
+            ("field-narrow/minute", "m"),
+            ("field-narrow/month", "mån"),
+            ("field-narrow/second", "s"),
+            ("field-short/hour", "tim"),
+            ("field-short/minute", "min"),
+            ("field-short/month", "m"),
+            ("field-short/quarter", "kv."),
+            ("field-short/second", "sek"),
+            ("field-short/weekOfYear", "v"),
+            ("field/day", "dag"),
+            ("field/dayPeriod", "fm/em"),
+            ("field/era", "era"),
+            ("field/hour", "timme"),
+            ("field/minute", "minut"),
+            ("field/month", "månad"),
+            ("field/quarter", "kvartal"),
+            ("field/second", "sekund"),
+            ("field/timeZoneName", "tidszon"),
+            ("field/weekOfYear", "vecka"),
+            ("field/weekday", "veckodag"),
+            ("field/year", "år"),
+            ("language-short/az", "azeriska"),
+            ("language/af", "afrikaans"),
+            ("language/am", "amhariska"),
+            ("language/ar", "arabiska"),
+            ("language/ar-001", "modern standardarabiska"),
+            ("language/as", "assamesiska"),
+            ("language/az", "azerbajdzjanska"),
+            ("language/be", "vitryska"),
+            ("language/bg", "bulgariska"),
+            ("language/bn", "bengali"),
+            ("language/bo", "tibetanska"),
+            ("language/br", "bretonska"),
+            ("language/bs", "bosniska"),
+            ("language/ca", "katalanska"),
+            ("language/chr", "cherokesiska"),
+            ("language/cs", "tjeckiska"),
+            ("language/cy", "walesiska"),
+            ("language/da", "danska"),
+            ("language/de", "tyska"),
+            ("language/de-AT", "österrikisk tyska"),
+            ("language/de-CH", "schweizisk högtyska"),
+            ("language/dz", "dzongkha"),
+            ("language/el", "grekiska"),
+            ("language/en", "engelska"),
+            ("language/en-AU", "australisk engelska"),
+            ("language/en-CA", "kanadensisk engelska"),
+            ("language/en-GB", "brittisk engelska"),
+            ("language/en-US", "amerikansk engelska"),
+            ("language/eo", "esperanto"),
+            ("language/es", "spanska"),
+            ("language/es-419", "latinamerikansk spanska"),
+            ("language/es-ES", "europeisk spanska"),
+            ("language/es-MX", "mexikansk spanska"),
+            ("language/et", "estniska"),
+            ("language/eu", "baskiska"),
+            ("language/fa", "persiska"),
+            ("language/fa-AF", "dari"),
+            ("language/ff", "fulani"),
+            ("language/fi", "finska"),
+            ("language/fil", "filippinska"),
+            ("language/fo", "färöiska"),
+            ("language/fr", "franska"),
+            ("language/fr-CA", "kanadensisk franska"),
+            ("language/fr-CH", "schweizisk franska"),
+            ("language/fy", "västfrisiska"),
+            ("language/ga", "iriska"),
+            ("language/gd", "skotsk gäliska"),
+            ("language/gl", "galiciska"),
+            ("language/gu", "gujarati"),
+            ("language/ha", "hausa"),
+            ("language/haw", "hawaiiska"),
+            ("language/he", "hebreiska"),
+            ("language/hi", "hindi"),
+            ("language/hr", "kroatiska"),
+            ("language/ht", "haitiska"),
+            ("language/hu", "ungerska"),
+            ("language/hy", "armeniska"),
+            ("language/id", "indonesiska"),
+            ("language/ig", "igbo"),
+            ("language/is", "isländska"),
+            ("language/it", "italienska"),
+            ("language/ja", "japanska"),
+            ("language/jv", "javanesiska"),
+            ("language/ka", "georgiska"),
+            ("language/kk", "kazakiska"),
+            ("language/km", "kambodjanska"),
+            ("language/kn", "kannada"),
+            ("language/ko", "koreanska"),
+            ("language/ku", "kurdiska"),
+            ("language/ky", "kirgiziska"),
+            ("language/la", "latin"),
+            ("language/lb", "luxemburgiska"),
+            ("language/lo", "laotiska"),
+            ("language/lt", "litauiska"),
+            ("language/lv", "lettiska"),
+            ("language/mg", "malagassiska"),
+            ("language/mi", "maori"),
+            ("language/mk", "makedonska"),
+            ("language/ml", "malayalam"),
+            ("language/mn", "mongoliska"),
+            ("language/mr", "marathi"),
+            ("language/ms", "malajiska"),
+            ("language/mt", "maltesiska"),
+            ("language/my", "burmesiska"),
+            ("language/nb", "norskt bokmål"),
+            ("language/ne", "nepalesiska"),
+            ("language/nl", "nederländska"),
+            ("language/nl-BE", "flamländska"),
+            ("language/nn", "nynorska"),
+            ("language/no", "norska"),
+            ("language/or", "oriya"),
+            ("language/pa", "punjabi"),
+            ("language/pl", "polska"),
+            ("language/ps", "afghanska"),
+            ("language/pt", "portugisiska"),
+            ("language/pt-BR", "brasiliansk portugisiska"),
+            ("language/pt-PT", "europeisk portugisiska"),
+            ("language/ro", "rumänska"),
+            ("language/ro-MD", "moldaviska"),
+            ("language/ru", "ryska"),
+            ("language/rw", "kinjarwanda"),
+            ("language/sa", "sanskrit"),
+            ("language/sd", "sindhi"),
+            ("language/si", "singalesiska"),
+            ("language/sk", "slovakiska"),
+            ("language/sl", "slovenska"),
+            ("language/so", "somaliska"),
+            ("language/sq", "albanska"),
+            ("language/sr", "serbiska"),
+            ("language/sv", "svenska"),
+            ("language/sw", "swahili"),
+            ("language/sw-CD", "Kongo-swahili"),
+            ("language/ta", "tamil"),
+            ("language/te", "telugu"),
+            ("language/tg", "tadzjikiska"),
+            ("language/th", "thailändska"),
+            ("language/ti", "tigrinja"),
+            ("language/tk", "turkmeniska"),
+            ("language/tr", "turkiska"),
+            ("language/uk", "ukrainska"),
+            ("language/ur", "urdu"),
+            ("language/uz", "uzbekiska"),
+            ("language/vi", "vietnamesiska"),
+            ("language/xh", "xhosa"),
+            ("language/yi", "jiddisch"),
+            ("language/yo", "yoruba"),
+            ("language/zh", "kinesiska"),
+            ("language/zh-Hans", "förenklad kinesiska"),
+            ("language/zh-Hant", "traditionell kinesiska"),
+            ("language/zu", "zulu"),
+            ("pattern", " (|)"),
+            ("region-short/HK", "Hongkong"),
+            ("region-short/MO", "Macao"),
+            ("region-short/PS", "Palestina"),
+            ("region-short/UN", "FN"),
+            ("region/001", "världen"),
+            ("region/002", "Afrika"),
+            ("region/003", "Nordamerika"),
+            ("region/005", "Sydamerika"),
+            ("region/009", "Oceanien"),
+            ("region/019", "Nord- och Sydamerika"),
+            ("region/142", "Asien"),
+            ("region/150", "Europa"),
+            ("region/419", "Latinamerika"),
+            ("region/AE", "Förenade Arabemiraten"),
+            ("region/AF", "Afghanistan"),
+            ("region/AL", "Albanien"),
+            ("region/AM", "Armenien"),
+            ("region/AO", "Angola"),
+            ("region/AQ", "Antarktis"),
+            ("region/AR", "Argentina"),
+            ("region/AT", "Österrike"),
+            ("region/AU", "Australien"),
+            ("region/AZ", "Azerbajdzjan"),
+            ("region/BA", "Bosnien och Hercegovina"),
+            ("region/BD", "Bangladesh"),
+            ("region/BE", "Belgien"),
+            ("region/BG", "Bulgarien"),
+            ("region/BO", "Bolivia"),
+            ("region/BR", "Brasilien"),
+            ("region/BY", "Vitryssland"),
+            ("region/CA", "Kanada"),
+            ("region/CD", "Kongo-Kinshasa"),
+            ("region/CH", "Schweiz"),
+            ("region/CL", "Chile"),
+            ("region/CM", "Kamerun"),
+            ("region/CN", "Kina"),
+            ("region/CO", "Colombia"),
+            ("region/CR", "Costa Rica"),
+            ("region/CU", "Kuba"),
+            ("region/CY", "Cypern"),
+            ("region/CZ", "Tjeckien"),
+            ("region/DE", "Tyskland"),
+            ("region/DK", "Danmark"),
+            ("region/DO", "Dominikanska republiken"),
+            ("region/DZ", "Algeriet"),
+            ("region/EC", "Ecuador"),
+            ("region/EE", "Estland"),
+            ("region/EG", "Egypten"),
+            ("region/ES", "Spanien"),
+            ("region/ET", "Etiopien"),
+            ("region/EU", "Europeiska unionen"),
+            ("region/FI", "Finland"),
+            ("region/FR", "Frankrike"),
+            ("region/GB", "Storbritannien"),
+            ("region/GE", "Georgien"),
+            ("region/GH", "Ghana"),
+            ("region/GR", "Grekland"),
+            ("region/GT", "Guatemala"),
+            ("region/HK", "Hongkong SAR"),
+            ("region/HR", "Kroatien"),
+            ("region/HU", "Ungern"),
+            ("region/ID", "Indonesien"),
+            ("region/IE", "Irland"),
+            ("region/IL", "Israel"),
+            ("region/IN", "Indien"),
+            ("region/IQ", "Irak"),
+            ("region/IR", "Iran"),
+            ("region/IS", "Island"),
+            ("region/IT", "Italien"),
+            ("region/JM", "Jamaica"),
+            ("region/JO", "Jordanien"),
+            ("region/JP", "Japan"),
+            ("region/KE", "Kenya"),
+            ("region/KH", "Kambodja"),
+            ("region/KP", "Nordkorea"),
+            ("region/KR", "Sydkorea"),
+            ("region/KW", "Kuwait"),
+            ("region/KZ", "Kazakstan"),
+            ("region/LB", "Libanon"),
+            ("region/LK", "Sri Lanka"),
+            ("region/LT", "Litauen"),
+            ("region/LU", "Luxemburg"),
+            ("region/LV", "Lettland"),
+            ("region/MA", "Marocko"),
+            ("region/MD", "Moldavien"),
+            ("region/MK", "Nordmakedonien"),
+            ("region/MM", "Myanmar (Burma)"),
+            ("region/MN", "Mongoliet"),
+            ("region/MO", "Macao SAR"),
+            ("region/MT", "Malta"),
+            ("region/MX", "Mexiko"),
+            ("region/MY", "Malaysia"),
+            ("region/NG", "Nigeria"),
+            ("region/NL", "Nederländerna"),
+            ("region/NO", "Norge"),
+            ("region/NP", "Nepal"),
+            ("region/NZ", "Nya Zeeland"),
+            ("region/PA", "Panama"),
+            ("region/PE", "Peru"),
+            ("region/PH", "Filippinerna"),
+            ("region/PK", "Pakistan"),
+            ("region/PL", "Polen"),
+            ("region/PR", "Puerto Rico"),
+            ("region/PS", "Palestinska territorierna"),
+            ("region/PT", "Portugal"),
+            ("region/PY", "Paraguay"),
+            ("region/QA", "Qatar"),
+            ("region/RO", "Rumänien"),
+            ("region/RS", "Serbien"),
+            ("region/RU", "Ryssland"),
+            ("region/SA", "Saudiarabien"),
+            ("region/SE", "Sverige"),
+            ("region/SG", "Singapore"),
+            ("region/SI", "Slovenien"),
+            ("region/SK", "Slovakien"),
+            ("region/SN", "Senegal"),
+            ("region/SY", "Syrien"),
+            ("region/TH", "Thailand"),
+            ("region/TN", "Tunisien"),
+            ("region/TR", "Turkiet"),
+            ("region/TW", "Taiwan"),
+            ("region/TZ", "Tanzania"),
+            ("region/UA", "Ukraina"),
+            ("region/UG", "Uganda"),
+            ("region/UN", "Förenta Nationerna"),
+            ("region/US", "USA"),
+            ("region/UY", "Uruguay"),
+            ("region/UZ", "Uzbekistan"),
+            ("region/VE", "Venezuela"),
+            ("region/VN", "Vietnam"),
+            ("region/ZA", "Sydafrika"),
+            ("region/ZM", "Zambia"),
+            ("region/ZW", "Zimbabwe"),
+            ("script-stand-alone/Hans", "förenklade han-tecken"),
+            ("script-stand-alone/Hant", "traditionella han-tecken"),
+            ("script/Arab", "arabiska"),
+            ("script/Armn", "armeniska"),
+            ("script/Beng", "bengaliska"),
+            ("script/Bopo", "bopomofo"),
+            ("script/Brai", "punktskrift"),
+            ("script/Cyrl", "kyrilliska"),
+            ("script/Deva", "devanagari"),
+            ("script/Ethi", "etiopiska"),
+            ("script/Geor", "georgiska"),
+            ("script/Grek", "grekiska"),
+            ("script/Gujr", "gujarati"),
+            ("script/Guru", "gurmukhiska"),
+            ("script/Hang", "hangul"),
+            ("script/Hani", "han"),
+            ("script/Hans", "förenklad"),
+            ("script/Hant", "traditionell"),
+            ("script/Hebr", "hebreiska"),
+            ("script/Hira", "hiragana"),
+            ("script/Jpan", "japanska"),
+            ("script/Kana", "katakana"),
+            ("script/Khmr", "khmeriska"),
+            ("script/Knda", "kanaresiska"),
+            ("script/Kore", "koreanska"),
+            ("script/Laoo", "laotiska"),
+            ("script/Latn", "latinska"),
+            ("script/Mlym", "malayalam"),
+            ("script/Mong", "mongoliska"),
+            ("script/Mymr", "burmesiska"),
+            ("script/Orya", "oriya"),
+            ("script/Sinh", "singalesiska"),
+            ("script/Taml", "tamilska"),
+            ("script/Telu", "telugu"),
+            ("script/Thai", "thailändska"),
+            ("separator", ", "),
+        ],
+    ),
+    (
+        "th",
+        &[
+            ("calendar/buddhist", "ปฏิทินพุทธ"),
+            ("calendar/chinese", "ปฏิทินจีน"),
+            ("calendar/coptic", "ปฏิทินคอปติก"),
+            ("calendar/dangi", "ปฏิทินเกาหลี"),
+            ("calendar/ethioaa", "ปฏิทินปีโลกเอธิโอเปีย"),
+            ("calendar/ethiopic", "ปฏิทินเอธิโอเปีย"),
+            ("calendar/gregory", "ปฏิทินเกรกอเรียน"),
+            ("calendar/hebrew", "ปฏิทินฮิบรู"),
+            ("calendar/indian", "ปฏิทินแห่งชาติอินเดีย"),
+            ("calendar/islamic", "ปฏิทินอิสลาม"),
+            ("calendar/islamic-civil", "ปฏิทินอิสลามซีวิล"),
+            (
+                "calendar/islamic-rgsa",
+                "ปฏิทินอิสลาม (ซาอุดีอาระเบีย แบบพระจันทร์เสี้ยว)",
+            ),
+            ("calendar/islamic-tbla", "ปฏิทินอิสลาม (แบบตาราง สมัยดาราศาสตร์)"),
+            ("calendar/islamic-umalqura", "ปฏิทินอิสลาม (อุมม์อัลกุรา)"),
+            ("calendar/iso8601", "ปฏิทิน ISO-8601"),
+            ("calendar/japanese", "ปฏิทินญี่ปุ่น"),
+            ("calendar/persian", "ปฏิทินเปอร์เชีย"),
+            ("calendar/roc", "ปฏิทินไต้หวัน"),
+            ("currency/AED", "เดอร์แฮมสหรัฐอาหรับเอมิเรตส์"),
+            ("currency/ARS", "เปโซอาร์เจนตินา"),
+            ("currency/AUD", "ดอลลาร์ออสเตรเลีย"),
+            ("currency/BDT", "ตากาบังกลาเทศ"),
+            ("currency/BGN", "เลฟบัลแกเรีย"),
+            ("currency/BHD", "ดีนาร์บาห์เรน"),
+            ("currency/BOB", "โบลิเวียโนโบลิเวีย"),
+            ("currency/BRL", "เรียลบราซิล"),
+            ("currency/CAD", "ดอลลาร์แคนาดา"),
+            ("currency/CHF", "ฟรังก์สวิส"),
+            ("currency/CLP", "เปโซชิลี"),
+            ("currency/CNY", "หยวนจีน"),
+            ("currency/COP", "เปโซโคลอมเบีย"),
+            ("currency/CRC", "โกลองคอสตาริกา"),
+            ("currency/CZK", "โครูนาสาธารณรัฐเช็ก"),
+            ("currency/DKK", "โครนเดนมาร์ก"),
+            ("currency/DOP", "เปโซโดมินิกัน"),
+            ("currency/DZD", "ดีนาร์แอลจีเรีย"),
+            ("currency/EGP", "ปอนด์อียิปต์"),
+            ("currency/ETB", "เบอรร์เอธิโอเปีย"),
+            ("currency/EUR", "ยูโร"),
+            ("currency/GBP", "ปอนด์สเตอร์ลิง (สหราชอาณาจักร)"),
+            ("currency/GEL", "ลารีจอร์เจีย"),
+            ("currency/GHS", "เซดีกานา"),
+            ("currency/GTQ", "เควตซัลกัวเตมาลา"),
+            ("currency/HKD", "ดอลลาร์ฮ่องกง"),
+            ("currency/HUF", "ฟอรินต์ฮังการี"),
+            ("currency/IDR", "รูเปียห์อินโดนีเซีย"),
+            ("currency/ILS", "นิวเชเกลอิสราเอล"),
+            ("currency/INR", "รูปีอินเดีย"),
+            ("currency/IQD", "ดีนาร์อิรัก"),
+            ("currency/IRR", "เรียลอิหร่าน"),
+            ("currency/ISK", "โครนาไอซ์แลนด์"),
+            ("currency/JMD", "ดอลลาร์จาเมกา"),
+            ("currency/JOD", "ดีนาร์จอร์แดน"),
+            ("currency/JPY", "เยนญี่ปุ่น"),
+            ("currency/KES", "ชิลลิงเคนยา"),
+            ("currency/KRW", "วอนเกาหลีใต้"),
+            ("currency/KWD", "ดีนาร์คูเวต"),
+            ("currency/KZT", "เทงเจคาซัคสถาน"),
+            ("currency/LBP", "ปอนด์เลบานอน"),
+            ("currency/LKR", "รูปีศรีลังกา"),
+            ("currency/MAD", "ดีแรห์มโมร็อกโก"),
+            ("currency/MDL", "ลิวมอลโดวา"),
+            ("currency/MXN", "เปโซเม็กซิโก"),
+            ("currency/MYR", "ริงกิตมาเลเซีย"),
+            ("currency/NGN", "ไนราไนจีเรีย"),
+            ("currency/NOK", "โครนนอร์เวย์"),
+            ("currency/NPR", "รูปีเนปาล"),
+            ("currency/NZD", "ดอลลาร์นิวซีแลนด์"),
+            ("currency/OMR", "เรียลโอมาน"),
+            ("currency/PAB", "บัลบัวปานามา"),
+            ("currency/PEN", "ซอลเปรู"),
+            ("currency/PHP", "เปโซฟิลิปปินส์"),
+            ("currency/PKR", "รูปีปากีสถาน"),
+            ("currency/PLN", "ซลอตีโปแลนด์"),
+            ("currency/PYG", "กวารานีปารากวัย"),
+            ("currency/QAR", "เรียลกาตาร์"),
+            ("currency/RON", "ลิวโรมาเนีย"),
+            ("currency/RSD", "ดีนาร์เซอร์เบีย"),
+            ("currency/RUB", "รูเบิลรัสเซีย"),
+            ("currency/SAR", "ริยัลซาอุดีอาระเบีย"),
+            ("currency/SEK", "โครนาสวีเดน"),
+            ("currency/SGD", "ดอลลาร์สิงคโปร์"),
+            ("currency/THB", "บาท"),
+            ("currency/TND", "ดีนาร์ตูนิเซีย"),
+            ("currency/TRY", "ลีราตุรกี"),
+            ("currency/TWD", "ดอลลาร์ไต้หวันใหม่"),
+            ("currency/TZS", "ชิลลิงแทนซาเนีย"),
+            ("currency/UAH", "ฮรีฟเนียยูเครน"),
+            ("currency/UGX", "ชิลลิงยูกันดา"),
+            ("currency/USD", "ดอลลาร์สหรัฐ"),
+            ("currency/UYU", "เปโซอุรุกวัย"),
+            ("currency/UZS", "ซอมอุซเบกิสถาน"),
+            ("currency/VND", "ดองเวียดนาม"),
+            ("currency/XAF", "ฟรังก์เซฟาธนาคารรัฐแอฟริกากลาง"),
+            ("currency/XOF", "ฟรังก์เซฟาธนาคารกลางรัฐแอฟริกาตะวันตก"),
+            ("currency/ZAR", "แรนด์แอฟริกาใต้"),
+            ("field-narrow/timeZoneName", "เขตเวลา"),
+            ("field-short/hour", "ชม."),
+            ("field-short/second", "วิ"),
+            ("field-short/timeZoneName", "โซน"),
+            ("field/day", "วัน"),
+            ("field/dayPeriod", "ช่วงวัน"),
+            ("field/era", "สมัย"),
+            ("field/hour", "ชั่วโมง"),
+            ("field/minute", "นาที"),
+            ("field/month", "เดือน"),
+            ("field/quarter", "ไตรมาส"),
+            ("field/second", "วินาที"),
+            ("field/timeZoneName", "เขตเวลา"),
+            ("field/weekOfYear", "สัปดาห์"),
+            ("field/weekday", "วันของสัปดาห์"),
+            ("field/year", "ปี"),
+            ("language-short/az", "อะเซอรี"),
+            ("language-short/en-GB", "อังกฤษ - อังกฤษ"),
+            ("language-short/en-US", "อังกฤษ - สหรัฐฯ"),
+            ("language/af", "แอฟริกานส์"),
+            ("language/am", "อัมฮารา"),
+            ("language/ar", "อาหรับ"),
+            ("language/ar-001", "อาหรับมาตรฐานสมัยใหม่"),
+            ("language/as", "อัสสัม"),
+            ("language/az", "อาเซอร์ไบจาน"),
+            ("language/be", "เบลารุส"),
+            ("language/bg", "บัลแกเรีย"),
+            ("language/bn", "บังกลา"),
+            ("language/bo", "ทิเบต"),
+            ("language/br", "เบรตัน"),
+            ("language/bs", "บอสเนีย"),
+            ("language/ca", "คาตาลัน"),
+            ("language/chr", "เชอโรกี"),
+            ("language/cs", "เช็ก"),
+            ("language/cy", "เวลส์"),
+            ("language/da", "เดนมาร์ก"),
+            ("language/de", "เยอรมัน"),
+            ("language/de-AT", "เยอรมัน - ออสเตรีย"),
+            ("language/de-CH", "เยอรมันสูง (สวิส)"),
+            ("language/dz", "ซองคา"),
+            ("language/el", "กรีก"),
+            ("language/en", "อังกฤษ"),
+            ("language/en-AU", "อังกฤษ - ออสเตรเลีย"),
+            ("language/en-CA", "อังกฤษ - แคนาดา"),
+            ("language/en-GB", "อังกฤษ - สหราชอาณาจักร"),
+            ("language/en-US", "อังกฤษ - สหรัฐอเมริกา"),
+            ("language/eo", "เอสเปรันโต"),
+            ("language/es", "สเปน"),
+            ("language/es-419", "สเปน - ละตินอเมริกา"),
+            ("language/es-ES", "สเปน - ยุโรป"),
+            ("language/es-MX", "สเปน - เม็กซิโก"),
+            ("language/et", "เอสโตเนีย"),
+            ("language/eu", "บาสก์"),
+            ("language/fa", "เปอร์เซีย"),
+            ("language/fa-AF", "ดารี"),
+            ("language/ff", "ฟูลาห์"),
+            ("language/fi", "ฟินแลนด์"),
+            ("language/fil", "ฟิลิปปินส์"),
+            ("language/fo", "แฟโร"),
+            ("language/fr", "ฝรั่งเศส"),
+            ("language/fr-CA", "ฝรั่งเศส - แคนาดา"),
+            ("language/fr-CH", "ฝรั่งเศส - สวิส"),
+            ("language/fy", "ฟริเซียนตะวันตก"),
+            ("language/ga", "ไอริช"),
+            ("language/gd", "เกลิกสกอต"),
+            ("language/gl", "กาลิเซีย"),
+            ("language/gu", "คุชราต"),
+            ("language/ha", "เฮาซา"),
+            ("language/haw", "ฮาวาย"),
+            ("language/he", "ฮิบรู"),
+            ("language/hi", "ฮินดี"),
+            ("language/hr", "โครเอเชีย"),
+            ("language/ht", "เฮติครีโอล"),
+            ("language/hu", "ฮังการี"),
+            ("language/hy", "อาร์เมเนีย"),
+            ("language/id", "อินโดนีเซีย"),
+            ("language/ig", "อิกโบ"),
+            ("language/is", "ไอซ์แลนด์"),
+            ("language/it", "อิตาลี"),
+            ("language/ja", "ญี่ปุ่น"),
+            ("language/jv", "ชวา"),
+            ("language/ka", "จอร์เจีย"),
+            ("language/kk", "คาซัค"),
+            ("language/km", "เขมร"),
+            ("language/kn", "กันนาดา"),
+            ("language/ko", "เกาหลี"),
+            ("language/ku", "เคิร์ด"),
+            ("language/ky", "คีร์กีซ"),
+            ("language/la", "ละติน"),
+            ("language/lb", "ลักเซมเบิร์ก"),
+            ("language/lo", "ลาว"),
+            ("language/lt", "ลิทัวเนีย"),
+            ("language/lv", "ลัตเวีย"),
+            ("language/mg", "มาลากาซี"),
+            ("language/mi", "เมารี"),
+            ("language/mk", "มาซิโดเนีย"),
+            ("language/ml", "มาลายาลัม"),
+            ("language/mn", "มองโกเลีย"),
+            ("language/mr", "มราฐี"),
+            ("language/ms", "มาเลย์"),
+            ("language/mt", "มอลตา"),
+            ("language/my", "พม่า"),
+            ("language/nb", "นอร์เวย์บุคมอล"),
+            ("language/ne", "เนปาล"),
+            ("language/nl", "ดัตช์"),
+            ("language/nl-BE", "เฟลมิช"),
+            ("language/nn", "นอร์เวย์นีนอสก์"),
+            ("language/no", "นอร์เวย์"),
+            ("language/or", "โอดิยา"),
+            ("language/pa", "ปัญจาบ"),
+            ("language/pl", "โปแลนด์"),
+            ("language/ps", "พัชโต"),
+            ("language/pt", "โปรตุเกส"),
+            ("language/pt-BR", "โปรตุเกส - บราซิล"),
+            ("language/pt-PT", "โปรตุเกส - ยุโรป"),
+            ("language/ro", "โรมาเนีย"),
+            ("language/ro-MD", "มอลโดวา"),
+            ("language/ru", "รัสเซีย"),
+            ("language/rw", "รวันดา"),
+            ("language/sa", "สันสกฤต"),
+            ("language/sd", "สินธิ"),
+            ("language/si", "สิงหล"),
+            ("language/sk", "สโลวัก"),
+            ("language/sl", "สโลวีเนีย"),
+            ("language/so", "โซมาลี"),
+            ("language/sq", "แอลเบเนีย"),
+            ("language/sr", "เซอร์เบีย"),
+            ("language/sv", "สวีเดน"),
+            ("language/sw", "สวาฮีลี"),
+            ("language/sw-CD", "สวาฮีลี - คองโก"),
+            ("language/ta", "ทมิฬ"),
+            ("language/te", "เตลูกู"),
+            ("language/tg", "ทาจิก"),
+            ("language/th", "ไทย"),
+            ("language/ti", "ติกริญญา"),
+            ("language/tk", "เติร์กเมน"),
+            ("language/tr", "ตุรกี"),
+            ("language/uk", "ยูเครน"),
+            ("language/ur", "อูรดู"),
+            ("language/uz", "อุซเบก"),
+            ("language/vi", "เวียดนาม"),
+            ("language/xh", "คะห์โอซา"),
+            ("language/yi", "ยิดดิช"),
+            ("language/yo", "โยรูบา"),
+            ("language/zh", "จีน"),
+            ("language/zh-Hans", "จีนตัวย่อ"),
+            ("language/zh-Hant", "จีนตัวเต็ม"),
+            ("language/zu", "ซูลู"),
+            ("pattern", " (|)"),
+            ("region-short/HK", "ฮ่องกง"),
+            ("region-short/MO", "มาเก๊า"),
+            ("region-short/PS", "ปาเลสไตน์"),
+            ("region-short/US", "สหรัฐฯ"),
+            ("region/001", "โลก"),
+            ("region/002", "แอฟริกา"),
+            ("region/003", "อเมริกาเหนือ"),
+            ("region/005", "อเมริกาใต้"),
+            ("region/009", "โอเชียเนีย"),
+            ("region/019", "อเมริกา"),
+            ("region/142", "เอเชีย"),
+            ("region/150", "ยุโรป"),
+            ("region/419", "ละตินอเมริกา"),
+            ("region/AE", "สหรัฐอาหรับเอมิเรตส์"),
+            ("region/AF", "อัฟกานิสถาน"),
+            ("region/AL", "แอลเบเนีย"),
+            ("region/AM", "อาร์เมเนีย"),
+            ("region/AO", "แองโกลา"),
+            ("region/AQ", "แอนตาร์กติกา"),
+            ("region/AR", "อาร์เจนตินา"),
+            ("region/AT", "ออสเตรีย"),
+            ("region/AU", "ออสเตรเลีย"),
+            ("region/AZ", "อาเซอร์ไบจาน"),
+            ("region/BA", "บอสเนียและเฮอร์เซโกวีนา"),
+            ("region/BD", "บังกลาเทศ"),
+            ("region/BE", "เบลเยียม"),
+            ("region/BG", "บัลแกเรีย"),
+            ("region/BO", "โบลิเวีย"),
+            ("region/BR", "บราซิล"),
+            ("region/BY", "เบลารุส"),
+            ("region/CA", "แคนาดา"),
+            ("region/CD", "คองโก - กินชาซา"),
+            ("region/CH", "สวิตเซอร์แลนด์"),
+            ("region/CL", "ชิลี"),
+            ("region/CM", "แคเมอรูน"),
+            ("region/CN", "จีน"),
+            ("region/CO", "โคลอมเบีย"),
+            ("region/CR", "คอสตาริกา"),
+            ("region/CU", "คิวบา"),
+            ("region/CY", "ไซปรัส"),
+            ("region/CZ", "เช็ก"),
+            ("region/DE", "เยอรมนี"),
+            ("region/DK", "เดนมาร์ก"),
+            ("region/DO", "สาธารณรัฐโดมินิกัน"),
+            ("region/DZ", "แอลจีเรีย"),
+            ("region/EC", "เอกวาดอร์"),
+            ("region/EE", "เอสโตเนีย"),
+            ("region/EG", "อียิปต์"),
+            ("region/ES", "สเปน"),
+            ("region/ET", "เอธิโอเปีย"),
+            ("region/EU", "สหภาพยุโรป"),
+            ("region/FI", "ฟินแลนด์"),
+            ("region/FR", "ฝรั่งเศส"),
+            ("region/GB", "สหราชอาณาจักร"),
+            ("region/GE", "จอร์เจีย"),
+            ("region/GH", "กานา"),
+            ("region/GR", "กรีซ"),
+            ("region/GT", "กัวเตมาลา"),
+            ("region/HK", "เขตปกครองพิเศษฮ่องกงแห่งสาธารณรัฐประชาชนจีน"),
+            ("region/HR", "โครเอเชีย"),
+            ("region/HU", "ฮังการี"),
+            ("region/ID", "อินโดนีเซีย"),
+            ("region/IE", "ไอร์แลนด์"),
+            ("region/IL", "อิสราเอล"),
+            ("region/IN", "อินเดีย"),
+            ("region/IQ", "อิรัก"),
+            ("region/IR", "อิหร่าน"),
+            ("region/IS", "ไอซ์แลนด์"),
+            ("region/IT", "อิตาลี"),
+            ("region/JM", "จาเมกา"),
+            ("region/JO", "จอร์แดน"),
+            ("region/JP", "ญี่ปุ่น"),
+            ("region/KE", "เคนยา"),
+            ("region/KH", "กัมพูชา"),
+            ("region/KP", "เกาหลีเหนือ"),
+            ("region/KR", "เกาหลีใต้"),
+            ("region/KW", "คูเวต"),
+            ("region/KZ", "คาซัคสถาน"),
+            ("region/LB", "เลบานอน"),
+            ("region/LK", "ศรีลังกา"),
+            ("region/LT", "ลิทัวเนีย"),
+            ("region/LU", "ลักเซมเบิร์ก"),
+            ("region/LV", "ลัตเวีย"),
+            ("region/MA", "โมร็อกโก"),
+            ("region/MD", "มอลโดวา"),
+            ("region/MK", "มาซิโดเนียเหนือ"),
+            ("region/MM", "เมียนมา (พม่า)"),
+            ("region/MN", "มองโกเลีย"),
+            ("region/MO", "เขตปกครองพิเศษมาเก๊าแห่งสาธารณรัฐประชาชนจีน"),
+            ("region/MT", "มอลตา"),
+            ("region/MX", "เม็กซิโก"),
+            ("region/MY", "มาเลเซีย"),
+            ("region/NG", "ไนจีเรีย"),
+            ("region/NL", "เนเธอร์แลนด์"),
+            ("region/NO", "นอร์เวย์"),
+            ("region/NP", "เนปาล"),
+            ("region/NZ", "นิวซีแลนด์"),
+            ("region/PA", "ปานามา"),
+            ("region/PE", "เปรู"),
+            ("region/PH", "ฟิลิปปินส์"),
+            ("region/PK", "ปากีสถาน"),
+            ("region/PL", "โปแลนด์"),
+            ("region/PR", "เปอร์โตริโก"),
+            ("region/PS", "ดินแดนปาเลสไตน์"),
+            ("region/PT", "โปรตุเกส"),
+            ("region/PY", "ปารากวัย"),
+            ("region/QA", "กาตาร์"),
+            ("region/RO", "โรมาเนีย"),
+            ("region/RS", "เซอร์เบีย"),
+            ("region/RU", "รัสเซีย"),
+            ("region/SA", "ซาอุดีอาระเบีย"),
+            ("region/SE", "สวีเดน"),
+            ("region/SG", "สิงคโปร์"),
+            ("region/SI", "สโลวีเนีย"),
+            ("region/SK", "สโลวะเกีย"),
+            ("region/SN", "เซเนกัล"),
+            ("region/SY", "ซีเรีย"),
+            ("region/TH", "ไทย"),
+            ("region/TN", "ตูนิเซีย"),
+            ("region/TR", "ตุรกี"),
+            ("region/TW", "ไต้หวัน"),
+            ("region/TZ", "แทนซาเนีย"),
+            ("region/UA", "ยูเครน"),
+            ("region/UG", "ยูกันดา"),
+            ("region/UN", "สหประชาชาติ"),
+            ("region/US", "สหรัฐอเมริกา"),
+            ("region/UY", "อุรุกวัย"),
+            ("region/UZ", "อุซเบกิสถาน"),
+            ("region/VE", "เวเนซุเอลา"),
+            ("region/VN", "เวียดนาม"),
+            ("region/ZA", "แอฟริกาใต้"),
+            ("region/ZM", "แซมเบีย"),
+            ("region/ZW", "ซิมบับเว"),
+            ("script-stand-alone/Hans", "ฮั่นตัวย่อ"),
+            ("script-stand-alone/Hant", "ฮั่นตัวเต็ม"),
+            ("script/Arab", "อาหรับ"),
+            ("script/Armn", "อาร์เมเนีย"),
+            ("script/Beng", "เบงกาลี"),
+            ("script/Bopo", "ปอพอมอฟอ"),
+            ("script/Brai", "เบรลล์"),
+            ("script/Cyrl", "ซีริลลิก"),
+            ("script/Deva", "เทวนาครี"),
+            ("script/Ethi", "เอธิโอปิก"),
+            ("script/Geor", "จอร์เจีย"),
+            ("script/Grek", "กรีก"),
+            ("script/Gujr", "คุชราต"),
+            ("script/Guru", "กูร์มูคี"),
+            ("script/Hang", "ฮันกึล"),
+            ("script/Hani", "ฮั่น"),
+            ("script/Hans", "ตัวย่อ"),
+            ("script/Hant", "ตัวเต็ม"),
+            ("script/Hebr", "ฮีบรู"),
+            ("script/Hira", "ฮิระงะนะ"),
+            ("script/Jpan", "ญี่ปุ่น"),
+            ("script/Kana", "คะตะกะนะ"),
+            ("script/Khmr", "เขมร"),
+            ("script/Knda", "กันนาดา"),
+            ("script/Kore", "เกาหลี"),
+            ("script/Laoo", "ลาว"),
+            ("script/Latn", "ละติน"),
+            ("script/Mlym", "มาลายาลัม"),
+            ("script/Mong", "มองโกเลีย"),
+            ("script/Mymr", "พม่า"),
+            ("script/Orya", "โอริยา"),
+            ("script/Sinh", "สิงหล"),
+            ("script/Taml", "ทมิฬ"),
+            ("script/Telu", "เตลูกู"),
+            ("script/Thai", "ไทย"),
+            ("separator", ", "),
+        ],
+    ),
+    (
+        "tr",
+        &[
+            ("calendar/buddhist", "Budist Takvimi"),
+            ("calendar/chinese", "Çin Takvimi"),
+            ("calendar/coptic", "Kıpti Takvim"),
+            ("calendar/dangi", "Dangi Takvimi"),
+            ("calendar/ethioaa", "Etiyopik Amete Alem Takvimi"),
+            ("calendar/ethiopic", "Etiyopik Takvim"),
+            ("calendar/gregory", "Miladi Takvim"),
+            ("calendar/hebrew", "İbrani Takvimi"),
+            ("calendar/indian", "Ulusal Hint Takvimi"),
+            ("calendar/islamic", "Hicri Takvim"),
+            ("calendar/islamic-civil", "Arap Takvimi"),
+            ("calendar/islamic-rgsa", "Hicri Takvim (Suudi)"),
+            ("calendar/islamic-tbla", "Hicri Takvim (Astronomik Evreler)"),
+            (
+                "calendar/islamic-umalqura",
+                "Hicri Takvim (Ümmü-l Kurra Takvimi)",
+            ),
+            ("calendar/iso8601", "ISO-8601 Takvimi"),
+            ("calendar/japanese", "Japon Takvimi"),
+            ("calendar/persian", "İran Takvimi"),
+            ("calendar/roc", "Çin Cumhuriyeti Takvimi"),
+            ("currency/AED", "Birleşik Arap Emirlikleri Dirhemi"),
+            ("currency/ARS", "Arjantin Pesosu"),
+            ("currency/AUD", "Avustralya Doları"),
+            ("currency/BDT", "Bangladeş Takası"),
+            ("currency/BGN", "Bulgar Levası"),
+            ("currency/BHD", "Bahreyn Dinarı"),
+            ("currency/BOB", "Bolivya Bolivyanosu"),
+            ("currency/BRL", "Brezilya Reali"),
+            ("currency/CAD", "Kanada Doları"),
+            ("currency/CHF", "İsviçre Frangı"),
+            ("currency/CLP", "Şili Pesosu"),
+            ("currency/CNY", "Çin Yuanı"),
+            ("currency/COP", "Kolombiya Pesosu"),
+            ("currency/CRC", "Kosta Rika Kolonu"),
+            ("currency/CZK", "Çek Korunası"),
+            ("currency/DKK", "Danimarka Kronu"),
+            ("currency/DOP", "Dominik Pesosu"),
+            ("currency/DZD", "Cezayir Dinarı"),
+            ("currency/EGP", "Mısır Lirası"),
+            ("currency/ETB", "Etiyopya Birri"),
+            ("currency/EUR", "Euro"),
+            ("currency/GBP", "İngiliz Sterlini"),
+            ("currency/GEL", "Gürcistan Larisi"),
+            ("currency/GHS", "Gana Sedisi"),
+            ("currency/GTQ", "Guatemala Quetzalı"),
+            ("currency/HKD", "Hong Kong Doları"),
+            ("currency/HUF", "Macar Forinti"),
+            ("currency/IDR", "Endonezya Rupisi"),
+            ("currency/ILS", "Yeni İsrail Şekeli"),
+            ("currency/INR", "Hindistan Rupisi"),
+            ("currency/IQD", "Irak Dinarı"),
+            ("currency/IRR", "İran Riyali"),
+            ("currency/ISK", "İzlanda Kronu"),
+            ("currency/JMD", "Jamaika Doları"),
+            ("currency/JOD", "Ürdün Dinarı"),
+            ("currency/JPY", "Japon Yeni"),
+            ("currency/KES", "Kenya Şilini"),
+            ("currency/KRW", "Güney Kore Wonu"),
+            ("currency/KWD", "Kuveyt Dinarı"),
+            ("currency/KZT", "Kazakistan Tengesi"),
+            ("currency/LBP", "Lübnan Lirası"),
+            ("currency/LKR", "Sri Lanka Rupisi"),
+            ("currency/MAD", "Fas Dirhemi"),
+            ("currency/MDL", "Moldova Leyi"),
+            ("currency/MXN", "Meksika Pesosu"),
+            ("currency/MYR", "Malezya Ringgiti"),
+            ("currency/NGN", "Nijerya Nairası"),
+            ("currency/NOK", "Norveç Kronu"),
+            ("currency/NPR", "Nepal Rupisi"),
+            ("currency/NZD", "Yeni Zelanda Doları"),
+            ("currency/OMR", "Umman Riyali"),
+            ("currency/PAB", "Panama Balboası"),
+            ("currency/PEN", "Peru Solü"),
+            ("currency/PHP", "Filipinler Pesosu"),
+            ("currency/PKR", "Pakistan Rupisi"),
+            ("currency/PLN", "Polonya Zlotisi"),
+            ("currency/PYG", "Paraguay Guaranisi"),
+            ("currency/QAR", "Katar Riyali"),
+            ("currency/RON", "Romen Leyi"),
+            ("currency/RSD", "Sırp Dinarı"),
+            ("currency/RUB", "Rus Rublesi"),
+            ("currency/SAR", "Suudi Arabistan Riyali"),
+            ("currency/SEK", "İsveç Kronu"),
+            ("currency/SGD", "Singapur Doları"),
+            ("currency/THB", "Tayland Bahtı"),
+            ("currency/TND", "Tunus Dinarı"),
+            ("currency/TRY", "Türk Lirası"),
+            ("currency/TWD", "Yeni Tayvan Doları"),
+            ("currency/TZS", "Tanzanya Şilini"),
+            ("currency/UAH", "Ukrayna Grivnası"),
+            ("currency/UGX", "Uganda Şilini"),
+            ("currency/USD", "ABD Doları"),
+            ("currency/UYU", "Uruguay Pesosu"),
+            ("currency/UZS", "Özbekistan Somu"),
+            ("currency/VND", "Vietnam Dongu"),
+            ("currency/XAF", "Orta Afrika CFA Frangı"),
+            ("currency/XOF", "Batı Afrika CFA Frangı"),
+            ("currency/ZAR", "Güney Afrika Randı"),
+            ("field-short/hour", "sa."),
+            ("field-short/minute", "dk."),
+            ("field-short/quarter", "çyr."),
+            ("field-short/second", "sn."),
+            ("field-short/timeZoneName", "dilim"),
+            ("field-short/weekOfYear", "hf."),
+            ("field/day", "gün"),
+            ("field/dayPeriod", "ÖÖ/ÖS"),
+            ("field/era", "çağ"),
+            ("field/hour", "saat"),
+            ("field/minute", "dakika"),
+            ("field/month", "ay"),
+            ("field/quarter", "çeyrek"),
+            ("field/second", "saniye"),
+            ("field/timeZoneName", "saat dilimi"),
+            ("field/weekOfYear", "hafta"),
+            ("field/weekday", "haftanın günü"),
+            ("field/year", "yıl"),
+            ("language-short/en-GB", "Birleşik Krallık İngilizcesi"),
+            ("language-short/en-US", "ABD İngilizcesi"),
+            ("language/af", "Afrikaanca"),
+            ("language/am", "Amharca"),
+            ("language/ar", "Arapça"),
+            ("language/ar-001", "Modern Standart Arapça"),
+            ("language/as", "Assamca"),
+            ("language/az", "Azerbaycan dili"),
+            ("language/be", "Belarusça"),
+            ("language/bg", "Bulgarca"),
+            ("language/bn", "Bengalce"),
+            ("language/bo", "Tibetçe"),
+            ("language/br", "Bretonca"),
+            ("language/bs", "Boşnakça"),
+            ("language/ca", "Katalanca"),
+            ("language/chr", "Çerokice"),
+            ("language/cs", "Çekçe"),
+            ("language/cy", "Galce"),
+            ("language/da", "Danca"),
+            ("language/de", "Almanca"),
+            ("language/de-AT", "Avusturya Almancası"),
+            ("language/de-CH", "İsviçre Yüksek Almancası"),
+            ("language/dz", "Dzongkha"),
+            ("language/el", "Yunanca"),
+            ("language/en", "İngilizce"),
+            ("language/en-AU", "Avustralya İngilizcesi"),
+            ("language/en-CA", "Kanada İngilizcesi"),
+            ("language/en-GB", "İngiliz İngilizcesi"),
+            ("language/en-US", "Amerikan İngilizcesi"),
+            ("language/eo", "Esperanto"),
+            ("language/es", "İspanyolca"),
+            ("language/es-419", "Latin Amerika İspanyolcası"),
+            ("language/es-ES", "Avrupa İspanyolcası"),
+            ("language/es-MX", "Meksika İspanyolcası"),
+            ("language/et", "Estonca"),
+            ("language/eu", "Baskça"),
+            ("language/fa", "Farsça"),
+            ("language/fa-AF", "Darice"),
+            ("language/ff", "Fula dili"),
+            ("language/fi", "Fince"),
+            ("language/fil", "Filipince"),
+            ("language/fo", "Faroe dili"),
+            ("language/fr", "Fransızca"),
+            ("language/fr-CA", "Kanada Fransızcası"),
+            ("language/fr-CH", "İsviçre Fransızcası"),
+            ("language/fy", "Batı Frizcesi"),
+            ("language/ga", "İrlandaca"),
+            ("language/gd", "İskoç Gaelcesi"),
+            ("language/gl", "Galiçyaca"),
+            ("language/gu", "Güceratça"),
+            ("language/ha", "Hausa dili"),
+            ("language/haw", "Hawaii dili"),
+            ("language/he", "İbranice"),
+            ("language/hi", "Hintçe"),
+            ("language/hr", "Hırvatça"),
+            ("language/ht", "Haiti Kreyolu"),
+            ("language/hu", "Macarca"),
+            ("language/hy", "Ermenice"),
+            ("language/id", "Endonezce"),
+            ("language/ig", "İbo dili"),
+            ("language/is", "İzlandaca"),
+            ("language/it", "İtalyanca"),
+            ("language/ja", "Japonca"),
+            ("language/jv", "Cava dili"),
+            ("language/ka", "Gürcüce"),
+            ("language/kk", "Kazakça"),
+            ("language/km", "Khmer dili"),
+            ("language/kn", "Kannada dili"),
+            ("language/ko", "Korece"),
+            ("language/ku", "Kürtçe"),
+            ("language/ky", "Kırgızca"),
+            ("language/la", "Latince"),
+            ("language/lb", "Lüksemburgca"),
+            ("language/lo", "Lao dili"),
+            ("language/lt", "Litvanca"),
+            ("language/lv", "Letonca"),
+            ("language/mg", "Malgaşça"),
+            ("language/mi", "Maori dili"),
+            ("language/mk", "Makedonca"),
+            ("language/ml", "Malayalam dili"),
+            ("language/mn", "Moğolca"),
+            ("language/mr", "Marathi dili"),
+            ("language/ms", "Malayca"),
+            ("language/mt", "Maltaca"),
+            ("language/my", "Birman dili"),
+            ("language/nb", "Norveççe Bokmål"),
+            ("language/ne", "Nepalce"),
+            ("language/nl", "Felemenkçe"),
+            ("language/nl-BE", "Flamanca"),
+            ("language/nn", "Norveççe Nynorsk"),
+            ("language/no", "Norveççe"),
+            ("language/or", "Oriya dili"),
+            ("language/pa", "Pencapça"),
+            ("language/pl", "Lehçe"),
+            ("language/ps", "Peştuca"),
+            ("language/pt", "Portekizce"),
+            ("language/pt-BR", "Brezilya Portekizcesi"),
+            ("language/pt-PT", "Avrupa Portekizcesi"),
+            ("language/ro", "Rumence"),
+            ("language/ro-MD", "Moldovaca"),
+            ("language/ru", "Rusça"),
+            ("language/rw", "Kinyarwanda"),
+            ("language/sa", "Sanskrit"),
+            ("language/sd", "Sindhi dili"),
+            ("language/si", "Sinhali dili"),
+            ("language/sk", "Slovakça"),
+            ("language/sl", "Slovence"),
+            ("language/so", "Somalice"),
+            ("language/sq", "Arnavutça"),
+            ("language/sr", "Sırpça"),
+            ("language/sv", "İsveççe"),
+            ("language/sw", "Svahili dili"),
+            ("language/sw-CD", "Kongo Svahili"),
+            ("language/ta", "Tamilce"),
+            ("language/te", "Telugu dili"),
+            ("language/tg", "Tacikçe"),
+            ("language/th", "Tayca"),
+            ("language/ti", "Tigrinya dili"),
+            ("language/tk", "Türkmence"),
+            ("language/tr", "Türkçe"),
+            ("language/uk", "Ukraynaca"),
+            ("language/ur", "Urduca"),
+            ("language/uz", "Özbekçe"),
+            ("language/vi", "Vietnamca"),
+            ("language/xh", "Zosa dili"),
+            ("language/yi", "Yidiş"),
+            ("language/yo", "Yorubaca"),
+            ("language/zh", "Çince"),
+            ("language/zh-Hans", "Basitleştirilmiş Çince"),
+            ("language/zh-Hant", "Geleneksel Çince"),
+            ("language/zu", "Zuluca"),
+            ("pattern", " (|)"),
+            ("region-short/GB", "BK"),
+            ("region-short/HK", "Hong Kong"),
+            ("region-short/MO", "Makao"),
+            ("region-short/PS", "Filistin"),
+            ("region-short/UN", "BM"),
+            ("region-short/US", "ABD"),
+            ("region/001", "Dünya"),
+            ("region/002", "Afrika"),
+            ("region/003", "Kuzey Amerika"),
+            ("region/005", "Güney Amerika"),
+            ("region/009", "Okyanusya"),
+            ("region/019", "Amerika"),
+            ("region/142", "Asya"),
+            ("region/150", "Avrupa"),
+            ("region/419", "Latin Amerika"),
+            ("region/AE", "Birleşik Arap Emirlikleri"),
+            ("region/AF", "Afganistan"),
+            ("region/AL", "Arnavutluk"),
+            ("region/AM", "Ermenistan"),
+            ("region/AO", "Angola"),
+            ("region/AQ", "Antarktika"),
+            ("region/AR", "Arjantin"),
+            ("region/AT", "Avusturya"),
+            ("region/AU", "Avustralya"),
+            ("region/AZ", "Azerbaycan"),
+            ("region/BA", "Bosna-Hersek"),
+            ("region/BD", "Bangladeş"),
+            ("region/BE", "Belçika"),
+            ("region/BG", "Bulgaristan"),
+            ("region/BO", "Bolivya"),
+            ("region/BR", "Brezilya"),
+            ("region/BY", "Belarus"),
+            ("region/CA", "Kanada"),
+            ("region/CD", "Kongo - Kinşasa"),
+            ("region/CH", "İsviçre"),
+            ("region/CL", "Şili"),
+            ("region/CM", "Kamerun"),
+            ("region/CN", "Çin"),
+            ("region/CO", "Kolombiya"),
+            ("region/CR", "Kosta Rika"),
+            ("region/CU", "Küba"),
+            ("region/CY", "Kıbrıs"),
+            ("region/CZ", "Çekya"),
+            ("region/DE", "Almanya"),
+            ("region/DK", "Danimarka"),
+            ("region/DO", "Dominik Cumhuriyeti"),
+            ("region/DZ", "Cezayir"),
+            ("region/EC", "Ekvador"),
+            ("region/EE", "Estonya"),
+            ("region/EG", "Mısır"),
+            ("region/ES", "İspanya"),
+            ("region/ET", "Etiyopya"),
+            ("region/EU", "Avrupa Birliği"),
+            ("region/FI", "Finlandiya"),
+            ("region/FR", "Fransa"),
+            ("region/GB", "Birleşik Krallık"),
+            ("region/GE", "Gürcistan"),
+            ("region/GH", "Gana"),
+            ("region/GR", "Yunanistan"),
+            ("region/GT", "Guatemala"),
+            ("region/HK", "Çin Hong Kong ÖİB"),
+            ("region/HR", "Hırvatistan"),
+            ("region/HU", "Macaristan"),
+            ("region/ID", "Endonezya"),
+            ("region/IE", "İrlanda"),
+            ("region/IL", "İsrail"),
+            ("region/IN", "Hindistan"),
+            ("region/IQ", "Irak"),
+            ("region/IR", "İran"),
+            ("region/IS", "İzlanda"),
+            ("region/IT", "İtalya"),
+            ("region/JM", "Jamaika"),
+            ("region/JO", "Ürdün"),
+            ("region/JP", "Japonya"),
+            ("region/KE", "Kenya"),
+            ("region/KH", "Kamboçya"),
+            ("region/KP", "Kuzey Kore"),
+            ("region/KR", "Güney Kore"),
+            ("region/KW", "Kuveyt"),
+            ("region/KZ", "Kazakistan"),
+            ("region/LB", "Lübnan"),
+            ("region/LK", "Sri Lanka"),
+            ("region/LT", "Litvanya"),
+            ("region/LU", "Lüksemburg"),
+            ("region/LV", "Letonya"),
+            ("region/MA", "Fas"),
+            ("region/MD", "Moldova"),
+            ("region/MK", "Kuzey Makedonya"),
+            ("region/MM", "Myanmar (Burma)"),
+            ("region/MN", "Moğolistan"),
+            ("region/MO", "Çin Makao ÖİB"),
+            ("region/MT", "Malta"),
+            ("region/MX", "Meksika"),
+            ("region/MY", "Malezya"),
+            ("region/NG", "Nijerya"),
+            ("region/NL", "Hollanda"),
+            ("region/NO", "Norveç"),
+            ("region/NP", "Nepal"),
+            ("region/NZ", "Yeni Zelanda"),
+            ("region/PA", "Panama"),
+            ("region/PE", "Peru"),
+            ("region/PH", "Filipinler"),
+            ("region/PK", "Pakistan"),
+            ("region/PL", "Polonya"),
+            ("region/PR", "Porto Riko"),
+            ("region/PS", "Filistin Bölgeleri"),
+            ("region/PT", "Portekiz"),
+            ("region/PY", "Paraguay"),
+            ("region/QA", "Katar"),
+            ("region/RO", "Romanya"),
+            ("region/RS", "Sırbistan"),
+            ("region/RU", "Rusya"),
+            ("region/SA", "Suudi Arabistan"),
+            ("region/SE", "İsveç"),
+            ("region/SG", "Singapur"),
+            ("region/SI", "Slovenya"),
+            ("region/SK", "Slovakya"),
+            ("region/SN", "Senegal"),
+            ("region/SY", "Suriye"),
+            ("region/TH", "Tayland"),
+            ("region/TN", "Tunus"),
+            ("region/TR", "Türkiye"),
+            ("region/TW", "Tayvan"),
+            ("region/TZ", "Tanzanya"),
+            ("region/UA", "Ukrayna"),
+            ("region/UG", "Uganda"),
+            ("region/UN", "Birleşmiş Milletler"),
+            ("region/US", "Amerika Birleşik Devletleri"),
+            ("region/UY", "Uruguay"),
+            ("region/UZ", "Özbekistan"),
+            ("region/VE", "Venezuela"),
+            ("region/VN", "Vietnam"),
+            ("region/ZA", "Güney Afrika"),
+            ("region/ZM", "Zambiya"),
+            ("region/ZW", "Zimbabve"),
+            ("script-stand-alone/Hans", "Basitleştirilmiş Han"),
+            ("script-stand-alone/Hant", "Geleneksel Han"),
+            ("script/Arab", "Arap"),
+            ("script/Armn", "Ermeni"),
+            ("script/Beng", "Bengal"),
+            ("script/Bopo", "Bopomofo"),
+            ("script/Brai", "Braille"),
+            ("script/Cyrl", "Kiril"),
+            ("script/Deva", "Devanagari"),
+            ("script/Ethi", "Etiyopya"),
+            ("script/Geor", "Gürcü"),
+            ("script/Grek", "Yunan"),
+            ("script/Gujr", "Gücerat"),
+            ("script/Guru", "Gurmukhi"),
+            ("script/Hang", "Hangıl"),
+            ("script/Hani", "Han"),
+            ("script/Hans", "Basitleştirilmiş"),
+            ("script/Hant", "Geleneksel"),
+            ("script/Hebr", "İbrani"),
+            ("script/Hira", "Hiragana"),
+            ("script/Jpan", "Japon"),
+            ("script/Kana", "Katakana"),
+            ("script/Khmr", "Kmer"),
+            ("script/Knda", "Kannada"),
+            ("script/Kore", "Korece"),
+            ("script/Laoo", "Lao"),
+            ("script/Latn", "Latin"),
+            ("script/Mlym", "Malayalam"),
+            ("script/Mong", "Moğol"),
+            ("script/Mymr", "Burma"),
+            ("script/Orya", "Oriya"),
+            ("script/Sinh", "Seylan"),
+            ("script/Taml", "Tamil"),
+            ("script/Telu", "Telugu"),
+            ("script/Thai", "Tay"),
+            ("separator", ", "),
+        ],
+    ),
+    (
+        "uk",
+        &[
+            ("calendar/buddhist", "буддійський календар"),
+            ("calendar/chinese", "китайський календар"),
+            ("calendar/coptic", "коптський календар"),
+            ("calendar/dangi", "корейський календар"),
+            ("calendar/ethioaa", "ефіопський амете алем календар"),
+            ("calendar/ethiopic", "ефіопський календар"),
+            ("calendar/gregory", "григоріанський календар"),
+            ("calendar/hebrew", "єврейський календар"),
+            ("calendar/indian", "індійський світський календар"),
+            ("calendar/islamic", "ісламський календар"),
+            (
+                "calendar/islamic-civil",
+                "мусульманський світський календар",
+            ),
+            (
+                "calendar/islamic-rgsa",
+                "ісламський календар Саудівської Аравії",
+            ),
+            ("calendar/islamic-tbla", "ісламський астрономічний календар"),
+            (
+                "calendar/islamic-umalqura",
+                "ісламський календар (Умм аль-Кура)",
+            ),
+            ("calendar/iso8601", "календар ISO-8601"),
+            ("calendar/japanese", "японський календар"),
+            ("calendar/persian", "перський календар"),
+            ("calendar/roc", "календар Китайської Республіки"),
+            ("currency/AED", "дирхам ОАЕ"),
+            ("currency/ARS", "аргентинський песо"),
+            ("currency/AUD", "австралійський долар"),
+            ("currency/BDT", "бангладеська така"),
+            ("currency/BGN", "болгарський лев"),
+            ("currency/BHD", "бахрейнський динар"),
+            ("currency/BOB", "болівійський болівіано"),
+            ("currency/BRL", "бразильський реал"),
+            ("currency/CAD", "канадський долар"),
+            ("currency/CHF", "швейцарський франк"),
+            ("currency/CLP", "чилійський песо"),
+            ("currency/CNY", "китайський юань"),
+            ("currency/COP", "колумбійський песо"),
+            ("currency/CRC", "костариканський колон"),
+            ("currency/CZK", "чеська крона"),
+            ("currency/DKK", "данська крона"),
+            ("currency/DOP", "домініканський песо"),
+            ("currency/DZD", "алжирський динар"),
+            ("currency/EGP", "єгипетський фунт"),
+            ("currency/ETB", "ефіопський бир"),
+            ("currency/EUR", "євро"),
+            ("currency/GBP", "англійський фунт"),
+            ("currency/GEL", "грузинський ларі"),
+            ("currency/GHS", "ганський седі"),
+            ("currency/GTQ", "гватемальський кетсаль"),
+            ("currency/HKD", "гонконгський долар"),
+            ("currency/HUF", "угорський форинт"),
+            ("currency/IDR", "індонезійська рупія"),
+            ("currency/ILS", "ізраїльський новий шекель"),
+            ("currency/INR", "індійська рупія"),
+            ("currency/IQD", "іракський динар"),
+            ("currency/IRR", "іранський ріал"),
+            ("currency/ISK", "ісландська крона"),
+            ("currency/JMD", "ямайський долар"),
+            ("currency/JOD", "йорданський динар"),
+            ("currency/JPY", "японська єна"),
+            ("currency/KES", "кенійський шилінг"),
+            ("currency/KRW", "південнокорейський вон"),
+            ("currency/KWD", "кувейтський динар"),
+            ("currency/KZT", "казахстанський тенге"),
+            ("currency/LBP", "ліванський фунт"),
+            ("currency/LKR", "шрі-ланкійська рупія"),
+            ("currency/MAD", "марокканський дирхам"),
+            ("currency/MDL", "молдовський лей"),
+            ("currency/MXN", "мексиканський песо"),
+            ("currency/MYR", "малайзійський рингіт"),
+            ("currency/NGN", "нігерійська найра"),
+            ("currency/NOK", "норвезька крона"),
+            ("currency/NPR", "непальська рупія"),
+            ("currency/NZD", "новозеландський долар"),
+            ("currency/OMR", "оманський ріал"),
+            ("currency/PAB", "панамське бальбоа"),
+            ("currency/PEN", "перуанський новий сол"),
+            ("currency/PHP", "філіппінський песо"),
+            ("currency/PKR", "пакистанська рупія"),
+            ("currency/PLN", "польський злотий"),
+            ("currency/PYG", "парагвайський гуарані"),
+            ("currency/QAR", "катарський ріал"),
+            ("currency/RON", "румунський лей"),
+            ("currency/RSD", "сербський динар"),
+            ("currency/RUB", "російський рубль"),
+            ("currency/SAR", "саудівський ріал"),
+            ("currency/SEK", "шведська крона"),
+            ("currency/SGD", "сінгапурський долар"),
+            ("currency/THB", "таїландський бат"),
+            ("currency/TND", "туніський динар"),
+            ("currency/TRY", "турецька ліра"),
+            ("currency/TWD", "новий тайванський долар"),
+            ("currency/TZS", "танзанійський шилінг"),
+            ("currency/UAH", "українська гривня"),
+            ("currency/UGX", "угандійський шилінг"),
+            ("currency/USD", "долар США"),
+            ("currency/UYU", "уругвайський песо"),
+            ("currency/UZS", "узбецький сум"),
+            ("currency/VND", "вʼєтнамський донг"),
+            ("currency/XAF", "центральноафриканський франк"),
+            ("currency/XOF", "західноафриканський франк"),
+            ("currency/ZAR", "південноафриканський ранд"),
+            ("field-narrow/day", "д"),
+            ("field-narrow/era", "е"),
+            ("field-narrow/hour", "год"),
+            ("field-narrow/minute", "хв"),
+            ("field-narrow/month", "м"),
+            ("field-narrow/quarter", "кв"),
+            ("field-narrow/timeZoneName", "час. п."),
+            ("field-narrow/weekOfYear", "т"),
+            ("field-narrow/weekday", "д. тиж."),
+            ("field-narrow/year", "р"),
+            ("field-short/day", "д."),
+            ("field-short/era", "е."),
+            ("field-short/hour", "год."),
+            ("field-short/minute", "хв."),
+            ("field-short/month", "міс."),
+            ("field-short/quarter", "кв."),
+            ("field-short/second", "с"),
+            ("field-short/timeZoneName", "час. пояс"),
+            ("field-short/weekOfYear", "тиж."),
+            ("field-short/weekday", "д. тижня"),
+            ("field-short/year", "р."),
+            ("field/day", "день"),
+            ("field/dayPeriod", "дп/пп"),
+            ("field/era", "ера"),
+            ("field/hour", "година"),
+            ("field/minute", "хвилина"),
+            ("field/month", "місяць"),
+            ("field/quarter", "квартал"),
+            ("field/second", "секунда"),
+            ("field/timeZoneName", "часовий пояс"),
+            ("field/weekOfYear", "тиждень"),
+            ("field/weekday", "день тижня"),
+            ("field/year", "рік"),
+            ("language-short/az", "азері"),
+            ("language-short/en-GB", "англійська (Брит.)"),
+            ("language/af", "африкаанс"),
+            ("language/am", "амхарська"),
+            ("language/ar", "арабська"),
+            ("language/ar-001", "сучасна стандартна арабська"),
+            ("language/as", "асамська"),
+            ("language/az", "азербайджанська"),
+            ("language/be", "білоруська"),
+            ("language/bg", "болгарська"),
+            ("language/bn", "бенгальська"),
+            ("language/bo", "тибетська"),
+            ("language/br", "бретонська"),
+            ("language/bs", "боснійська"),
+            ("language/ca", "каталонська"),
+            ("language/chr", "черокі"),
+            ("language/cs", "чеська"),
+            ("language/cy", "валлійська"),
+            ("language/da", "данська"),
+            ("language/de", "німецька"),
+            ("language/dz", "дзонг-ке"),
+            ("language/el", "грецька"),
+            ("language/en", "англійська"),
+            ("language/eo", "есперанто"),
+            ("language/es", "іспанська"),
+            ("language/es-419", "латиноамериканська іспанська"),
+            ("language/es-ES", "європейська іспанська"),
+            ("language/es-MX", "мексиканська іспанська"),
+            ("language/et", "естонська"),
+            ("language/eu", "баскська"),
+            ("language/fa", "перська"),
+            ("language/fa-AF", "дарі"),
+            ("language/ff", "фула"),
+            ("language/fi", "фінська"),
+            ("language/fil", "філіппінська"),
+            ("language/fo", "фарерська"),
+            ("language/fr", "французька"),
+            ("language/fy", "західнофризька"),
+            ("language/ga", "ірландська"),
+            ("language/gd", "шотландська гельська"),
+            ("language/gl", "галісійська"),
+            ("language/gu", "гуджараті"),
+            ("language/ha", "хауса"),
+            ("language/haw", "гавайська"),
+            ("language/he", "іврит"),
+            ("language/hi", "гінді"),
+            ("language/hr", "хорватська"),
+            ("language/ht", "гаїтянська креольська"),
+            ("language/hu", "угорська"),
+            ("language/hy", "вірменська"),
+            ("language/id", "індонезійська"),
+            ("language/ig", "ігбо"),
+            ("language/is", "ісландська"),
+            ("language/it", "італійська"),
+            ("language/ja", "японська"),
+            ("language/jv", "яванська"),
+            ("language/ka", "грузинська"),
+            ("language/kk", "казахська"),
+            ("language/km", "кхмерська"),
+            ("language/kn", "каннада"),
+            ("language/ko", "корейська"),
+            ("language/ku", "курдська"),
+            ("language/ky", "киргизька"),
+            ("language/la", "латинська"),
+            ("language/lb", "люксембурзька"),
+            ("language/lo", "лаоська"),
+            ("language/lt", "литовська"),
+            ("language/lv", "латиська"),
+            ("language/mg", "малагасійська"),
+            ("language/mi", "маорі"),
+            ("language/mk", "македонська"),
+            ("language/ml", "малаялам"),
+            ("language/mn", "монгольська"),
+            ("language/mr", "маратхі"),
+            ("language/ms", "малайська"),
+            ("language/mt", "мальтійська"),
+            ("language/my", "бірманська"),
+            ("language/nb", "норвезька (букмол)"),
+            ("language/ne", "непальська"),
+            ("language/nl", "нідерландська"),
+            ("language/nl-BE", "фламандська"),
+            ("language/nn", "норвезька (нюношк)"),
+            ("language/no", "норвезька"),
+            ("language/or", "одія"),
+            ("language/pa", "панджабі"),
+            ("language/pl", "польська"),
+            ("language/ps", "пушту"),
+            ("language/pt", "португальська"),
+            ("language/pt-BR", "бразильська португальська"),
+            ("language/pt-PT", "європейська португальська"),
+            ("language/ro", "румунська"),
+            ("language/ro-MD", "молдавська"),
+            ("language/ru", "російська"),
+            ("language/rw", "кіньяруанда"),
+            ("language/sa", "санскрит"),
+            ("language/sd", "синдхі"),
+            ("language/si", "сингальська"),
+            ("language/sk", "словацька"),
+            ("language/sl", "словенська"),
+            ("language/so", "сомалі"),
+            ("language/sq", "албанська"),
+            ("language/sr", "сербська"),
+            ("language/sv", "шведська"),
+            ("language/sw", "суахілі"),
+            ("language/sw-CD", "суахілі (Конго)"),
+            ("language/ta", "тамільська"),
+            ("language/te", "телугу"),
+            ("language/tg", "таджицька"),
+            ("language/th", "тайська"),
+            ("language/ti", "тигринья"),
+            ("language/tk", "туркменська"),
+            ("language/tr", "турецька"),
+            ("language/uk", "українська"),
+            ("language/ur", "урду"),
+            ("language/uz", "узбецька"),
+            ("language/vi", "вʼєтнамська"),
+            ("language/xh", "кхоса"),
+            ("language/yi", "їдиш"),
+            ("language/yo", "йоруба"),
+            ("language/zh", "китайська"),
+            ("language/zh-Hans", "китайська (спрощене письмо)"),
+            ("language/zh-Hant", "китайська (традиційне письмо)"),
+            ("language/zu", "зулуська"),
+            ("pattern", " (|)"),
+            ("region-short/HK", "Гонконг"),
+            ("region-short/MO", "Макао"),
+            ("region-short/PS", "Палестина"),
+            ("region-short/UN", "ООН"),
+            ("region-short/US", "США"),
+            ("region/001", "Світ"),
+            ("region/002", "Африка"),
+            ("region/003", "Північна Америка"),
+            ("region/005", "Південна Америка"),
+            ("region/009", "Океанія"),
+            ("region/019", "Американський регіон"),
+            ("region/142", "Азія"),
+            ("region/150", "Європа"),
+            ("region/419", "Латинська Америка"),
+            ("region/AE", "Обʼєднані Арабські Емірати"),
+            ("region/AF", "Афганістан"),
+            ("region/AL", "Албанія"),
+            ("region/AM", "Вірменія"),
+            ("region/AO", "Ангола"),
+            ("region/AQ", "Антарктика"),
+            ("region/AR", "Аргентина"),
+            ("region/AT", "Австрія"),
+            ("region/AU", "Австралія"),
+            ("region/AZ", "Азербайджан"),
+            ("region/BA", "Боснія і Герцеговина"),
+            ("region/BD", "Бангладеш"),
+            ("region/BE", "Бельгія"),
+            ("region/BG", "Болгарія"),
+            ("region/BO", "Болівія"),
+            ("region/BR", "Бразилія"),
+            ("region/BY", "Білорусь"),
+            ("region/CA", "Канада"),
+            ("region/CD", "Конго – Кіншаса"),
+            ("region/CH", "Швейцарія"),
+            ("region/CL", "Чилі"),
+            ("region/CM", "Камерун"),
+            ("region/CN", "Китай"),
+            ("region/CO", "Колумбія"),
+            ("region/CR", "Коста-Рика"),
+            ("region/CU", "Куба"),
+            ("region/CY", "Кіпр"),
+            ("region/CZ", "Чехія"),
+            ("region/DE", "Німеччина"),
+            ("region/DK", "Данія"),
+            ("region/DO", "Домініканська Республіка"),
+            ("region/DZ", "Алжир"),
+            ("region/EC", "Еквадор"),
+            ("region/EE", "Естонія"),
+            ("region/EG", "Єгипет"),
+            ("region/ES", "Іспанія"),
+            ("region/ET", "Ефіопія"),
+            ("region/EU", "Європейський Союз"),
+            ("region/FI", "Фінляндія"),
+            ("region/FR", "Франція"),
+            ("region/GB", "Велика Британія"),
+            ("region/GE", "Грузія"),
+            ("region/GH", "Гана"),
+            ("region/GR", "Греція"),
+            ("region/GT", "Гватемала"),
+            ("region/HK", "Гонконг, ОАР Китаю"),
+            ("region/HR", "Хорватія"),
+            ("region/HU", "Угорщина"),
+            ("region/ID", "Індонезія"),
+            ("region/IE", "Ірландія"),
+            ("region/IL", "Ізраїль"),
+            ("region/IN", "Індія"),
+            ("region/IQ", "Ірак"),
+            ("region/IR", "Іран"),
+            ("region/IS", "Ісландія"),
+            ("region/IT", "Італія"),
+            ("region/JM", "Ямайка"),
+            ("region/JO", "Йорданія"),
+            ("region/JP", "Японія"),
+            ("region/KE", "Кенія"),
+            ("region/KH", "Камбоджа"),
+            ("region/KP", "Північна Корея"),
+            ("region/KR", "Південна Корея"),
+            ("region/KW", "Кувейт"),
+            ("region/KZ", "Казахстан"),
+            ("region/LB", "Ліван"),
+            ("region/LK", "Шрі-Ланка"),
+            ("region/LT", "Литва"),
+            ("region/LU", "Люксембург"),
+            ("region/LV", "Латвія"),
+            ("region/MA", "Марокко"),
+            ("region/MD", "Молдова"),
+            ("region/MK", "Північна Македонія"),
+            ("region/MM", "Мʼянма (Бірма)"),
+            ("region/MN", "Монголія"),
+            ("region/MO", "Макао, ОАР Китаю"),
+            ("region/MT", "Мальта"),
+            ("region/MX", "Мексика"),
+            ("region/MY", "Малайзія"),
+            ("region/NG", "Нігерія"),
+            ("region/NL", "Нідерланди"),
+            ("region/NO", "Норвегія"),
+            ("region/NP", "Непал"),
+            ("region/NZ", "Нова Зеландія"),
+            ("region/PA", "Панама"),
+            ("region/PE", "Перу"),
+            ("region/PH", "Філіппіни"),
+            ("region/PK", "Пакистан"),
+            ("region/PL", "Польща"),
+            ("region/PR", "Пуерто-Рико"),
+            ("region/PS", "Палестинські території"),
+            ("region/PT", "Португалія"),
+            ("region/PY", "Парагвай"),
+            ("region/QA", "Катар"),
+            ("region/RO", "Румунія"),
+            ("region/RS", "Сербія"),
+            ("region/RU", "Росія"),
+            ("region/SA", "Саудівська Аравія"),
+            ("region/SE", "Швеція"),
+            ("region/SG", "Сінгапур"),
+            ("region/SI", "Словенія"),
+            ("region/SK", "Словаччина"),
+            ("region/SN", "Сенегал"),
+            ("region/SY", "Сирія"),
+            ("region/TH", "Таїланд"),
+            ("region/TN", "Туніс"),
+            ("region/TR", "Туреччина"),
+            ("region/TW", "Тайвань"),
+            ("region/TZ", "Танзанія"),
+            ("region/UA", "Україна"),
+            ("region/UG", "Уганда"),
+            ("region/UN", "Організація Об’єднаних Націй"),
+            ("region/US", "Сполучені Штати"),
+            ("region/UY", "Уругвай"),
+            ("region/UZ", "Узбекистан"),
+            ("region/VE", "Венесуела"),
+            ("region/VN", "Вʼєтнам"),
+            ("region/ZA", "Південно-Африканська Республіка"),
+            ("region/ZM", "Замбія"),
+            ("region/ZW", "Зімбабве"),
+            ("script-stand-alone/Hans", "китайська спрощена"),
+            ("script-stand-alone/Hant", "китайська традиційна"),
+            ("script/Arab", "арабиця"),
+            ("script/Armn", "вірменська"),
+            ("script/Beng", "бенгальська"),
+            ("script/Bopo", "бопомофо"),
+            ("script/Brai", "шрифт Брайля"),
+            ("script/Cyrl", "кирилиця"),
+            ("script/Deva", "деванагарі"),
+            ("script/Ethi", "ефіопська"),
+            ("script/Geor", "грузинська"),
+            ("script/Grek", "грецька"),
+            ("script/Gujr", "гуджараті"),
+            ("script/Guru", "гурмухі"),
+            ("script/Hang", "хангиль"),
+            ("script/Hani", "китайська"),
+            ("script/Hans", "спрощена"),
+            ("script/Hant", "традиційна"),
+            ("script/Hebr", "іврит"),
+            ("script/Hira", "хірагана"),
+            ("script/Jpan", "японська"),
+            ("script/Kana", "катакана"),
+            ("script/Khmr", "кхмерська"),
+            ("script/Knda", "каннада"),
+            ("script/Kore", "корейська"),
+            ("script/Laoo", "лаоська"),
+            ("script/Latn", "латиниця"),
+            ("script/Mlym", "малаяламська"),
+            ("script/Mong", "монгольська"),
+            ("script/Mymr", "мʼянмська"),
+            ("script/Orya", "орія"),
+            ("script/Sinh", "сингальська"),
+            ("script/Taml", "тамільська"),
+            ("script/Telu", "телугу"),
+            ("script/Thai", "тайська"),
+            ("separator", ", "),
+        ],
+    ),
+    (
+        "vi",
+        &[
+            ("calendar/buddhist", "Lịch Phật Giáo"),
+            ("calendar/chinese", "Lịch Trung Quốc"),
+            ("calendar/coptic", "Lịch Copts"),
+            ("calendar/dangi", "Lịch Dangi"),
+            ("calendar/ethioaa", "Lịch Ethiopic Amete Alem"),
+            ("calendar/ethiopic", "Lịch Ethiopia"),
+            ("calendar/gregory", "Lịch Gregory"),
+            ("calendar/hebrew", "Lịch Do Thái"),
+            ("calendar/indian", "Lịch Quốc gia Ấn Độ"),
+            ("calendar/islamic", "Lịch Hồi Giáo"),
+            ("calendar/islamic-civil", "Lịch Islamic-Civil"),
+            ("calendar/islamic-rgsa", "Lịch Hồi Giáo - Ả Rập Xê-út"),
+            ("calendar/islamic-tbla", "Lịch Hồi Giáo - Thiên văn"),
+            ("calendar/islamic-umalqura", "Lịch Hồi Giáo - Umm al-Qura"),
+            ("calendar/iso8601", "Lịch ISO-8601"),
+            ("calendar/japanese", "Lịch Nhật Bản"),
+            ("calendar/persian", "Lịch Ba Tư"),
+            ("calendar/roc", "Lịch Trung Hoa Dân Quốc"),
+            ("currency/AED", "Dirham UAE"),
+            ("currency/ARS", "Peso Argentina"),
+            ("currency/AUD", "Đô la Australia"),
+            ("currency/BDT", "Taka Bangladesh"),
+            ("currency/BGN", "Lev Bulgaria"),
+            ("currency/BHD", "Dinar Bahrain"),
+            ("currency/BOB", "Boliviano Bolivia"),
+            ("currency/BRL", "Real Braxin"),
+            ("currency/CAD", "Đô la Canada"),
+            ("currency/CHF", "Franc Thụy sĩ"),
+            ("currency/CLP", "Peso Chile"),
+            ("currency/CNY", "Nhân dân tệ"),
+            ("currency/COP", "Peso Colombia"),
+            ("currency/CRC", "Colón Costa Rica"),
+            ("currency/CZK", "Koruna Cộng hòa Séc"),
+            ("currency/DKK", "Krone Đan Mạch"),
+            ("currency/DOP", "Peso Dominica"),
+            ("currency/DZD", "Dinar Algeria"),
+            ("currency/EGP", "Bảng Ai Cập"),
+            ("currency/ETB", "Birr Ethiopia"),
+            ("currency/EUR", "Euro"),
+            ("currency/GBP", "Bảng Anh"),
+            ("currency/GEL", "Lari Georgia"),
+            ("currency/GHS", "Cedi Ghana"),
+            ("currency/GTQ", "Quetzal Guatemala"),
+            ("currency/HKD", "Đô la Hồng Kông"),
+            ("currency/HUF", "Forint Hungary"),
+            ("currency/IDR", "Rupiah Indonesia"),
+            ("currency/ILS", "Sheqel Israel mới"),
+            ("currency/INR", "Rupee Ấn Độ"),
+            ("currency/IQD", "Dinar Iraq"),
+            ("currency/IRR", "Rial Iran"),
+            ("currency/ISK", "Króna Iceland"),
+            ("currency/JMD", "Đô la Jamaica"),
+            ("currency/JOD", "Dinar Jordan"),
+            ("currency/JPY", "Yên Nhật"),
+            ("currency/KES", "Shilling Kenya"),
+            ("currency/KRW", "Won Hàn Quốc"),
+            ("currency/KWD", "Dinar Kuwait"),
+            ("currency/KZT", "Tenge Kazakhstan"),
+            ("currency/LBP", "Bảng Li-băng"),
+            ("currency/LKR", "Rupee Sri Lanka"),
+            ("currency/MAD", "Dirham Ma-rốc"),
+            ("currency/MDL", "Leu Moldova"),
+            ("currency/MXN", "Peso Mexico"),
+            ("currency/MYR", "Ringgit Malaysia"),
+            ("currency/NGN", "Naira Nigeria"),
+            ("currency/NOK", "Krone Na Uy"),
+            ("currency/NPR", "Rupee Nepal"),
+            ("currency/NZD", "Đô la New Zealand"),
+            ("currency/OMR", "Rial Oman"),
+            ("currency/PAB", "Balboa Panama"),
+            ("currency/PEN", "Sol Peru"),
+            ("currency/PHP", "Peso Philipin"),
+            ("currency/PKR", "Rupee Pakistan"),
+            ("currency/PLN", "Zloty Ba Lan"),
+            ("currency/PYG", "Guarani Paraguay"),
+            ("currency/QAR", "Rial Qatar"),
+            ("currency/RON", "Leu Romania"),
+            ("currency/RSD", "Dinar Serbia"),
+            ("currency/RUB", "Rúp Nga"),
+            ("currency/SAR", "Riyal Ả Rập Xê-út"),
+            ("currency/SEK", "Krona Thụy Điển"),
+            ("currency/SGD", "Đô la Singapore"),
+            ("currency/THB", "Bạt Thái Lan"),
+            ("currency/TND", "Dinar Tunisia"),
+            ("currency/TRY", "Lia Thổ Nhĩ Kỳ"),
+            ("currency/TWD", "Đô la Đài Loan mới"),
+            ("currency/TZS", "Shilling Tanzania"),
+            ("currency/UAH", "Hryvnia Ukraina"),
+            ("currency/UGX", "Shilling Uganda"),
+            ("currency/USD", "Đô la Mỹ"),
+            ("currency/UYU", "Peso Uruguay"),
+            ("currency/UZS", "Som Uzbekistan"),
+            ("currency/VND", "Đồng Việt Nam"),
+            ("currency/XAF", "Franc CFA Trung Phi"),
+            ("currency/XOF", "Franc CFA Tây Phi"),
+            ("currency/ZAR", "Rand Nam Phi"),
+            ("field/day", "Ngày"),
+            ("field/dayPeriod", "SA/CH"),
+            ("field/era", "thời đại"),
+            ("field/hour", "Giờ"),
+            ("field/minute", "Phút"),
+            ("field/month", "Tháng"),
+            ("field/quarter", "Quý"),
+            ("field/second", "Giây"),
+            ("field/timeZoneName", "Múi giờ"),
+            ("field/weekOfYear", "Tuần"),
+            ("field/weekday", "ngày trong tuần"),
+            ("field/year", "Năm"),
+            ("language-short/az", "Tiếng Azeri"),
+            ("language/af", "Tiếng Afrikaans"),
+            ("language/am", "Tiếng Amharic"),
+            ("language/ar", "Tiếng Ả Rập"),
+            ("language/ar-001", "Tiếng Ả Rập Hiện đại"),
+            ("language/as", "Tiếng Assam"),
+            ("language/az", "Tiếng Azerbaijan"),
+            ("language/be", "Tiếng Belarus"),
+            ("language/bg", "Tiếng Bulgaria"),
+            ("language/bn", "Tiếng Bangla"),
+            ("language/bo", "Tiếng Tây Tạng"),
+            ("language/br", "Tiếng Breton"),
+            ("language/bs", "Tiếng Bosnia"),
+            ("language/ca", "Tiếng Catalan"),
+            ("language/chr", "Tiếng Cherokee"),
+            ("language/cs", "Tiếng Séc"),
+            ("language/cy", "Tiếng Wales"),
+            ("language/da", "Tiếng Đan Mạch"),
+            ("language/de", "Tiếng Đức"),
+            ("language/de-CH", "Tiếng Thượng Giéc-man (Thụy Sĩ)"),
+            ("language/dz", "Tiếng Dzongkha"),
+            ("language/el", "Tiếng Hy Lạp"),
+            ("language/en", "Tiếng Anh"),
+            ("language/en-GB", "Tiếng Anh (Anh)"),
+            ("language/en-US", "Tiếng Anh (Mỹ)"),
+            ("language/eo", "Tiếng Quốc Tế Ngữ"),
+            ("language/es", "Tiếng Tây Ban Nha"),
+            ("language/es-419", "Tiếng Tây Ban Nha (Mỹ La tinh)"),
+            ("language/es-ES", "Tiếng Tây Ban Nha (Châu Âu)"),
+            ("language/et", "Tiếng Estonia"),
+            ("language/eu", "Tiếng Basque"),
+            ("language/fa", "Tiếng Ba Tư"),
+            ("language/fa-AF", "Tiếng Dari"),
+            ("language/ff", "Tiếng Fulah"),
+            ("language/fi", "Tiếng Phần Lan"),
+            ("language/fil", "Tiếng Philippines"),
+            ("language/fo", "Tiếng Faroe"),
+            ("language/fr", "Tiếng Pháp"),
+            ("language/fy", "Tiếng Frisia"),
+            ("language/ga", "Tiếng Ireland"),
+            ("language/gd", "Tiếng Gael Scotland"),
+            ("language/gl", "Tiếng Galician"),
+            ("language/gu", "Tiếng Gujarati"),
+            ("language/ha", "Tiếng Hausa"),
+            ("language/haw", "Tiếng Hawaii"),
+            ("language/he", "Tiếng Do Thái"),
+            ("language/hi", "Tiếng Hindi"),
+            ("language/hr", "Tiếng Croatia"),
+            ("language/ht", "Tiếng Haiti"),
+            ("language/hu", "Tiếng Hungary"),
+            ("language/hy", "Tiếng Armenia"),
+            ("language/id", "Tiếng Indonesia"),
+            ("language/ig", "Tiếng Igbo"),
+            ("language/is", "Tiếng Iceland"),
+            ("language/it", "Tiếng Italy"),
+            ("language/ja", "Tiếng Nhật"),
+            ("language/jv", "Tiếng Java"),
+            ("language/ka", "Tiếng Georgia"),
+            ("language/kk", "Tiếng Kazakh"),
+            ("language/km", "Tiếng Khmer"),
+            ("language/kn", "Tiếng Kannada"),
+            ("language/ko", "Tiếng Hàn"),
+            ("language/ku", "Tiếng Kurd"),
+            ("language/ky", "Tiếng Kyrgyz"),
+            ("language/la", "Tiếng La-tinh"),
+            ("language/lb", "Tiếng Luxembourg"),
+            ("language/lo", "Tiếng Lào"),
+            ("language/lt", "Tiếng Litva"),
+            ("language/lv", "Tiếng Latvia"),
+            ("language/mg", "Tiếng Malagasy"),
+            ("language/mi", "Tiếng Māori"),
+            ("language/mk", "Tiếng Macedonia"),
+            ("language/ml", "Tiếng Malayalam"),
+            ("language/mn", "Tiếng Mông Cổ"),
+            ("language/mr", "Tiếng Marathi"),
+            ("language/ms", "Tiếng Mã Lai"),
+            ("language/mt", "Tiếng Malta"),
+            ("language/my", "Tiếng Miến Điện"),
+            ("language/nb", "Tiếng Na Uy (Bokmål)"),
+            ("language/ne", "Tiếng Nepal"),
+            ("language/nl", "Tiếng Hà Lan"),
+            ("language/nl-BE", "Tiếng Flemish"),
+            ("language/nn", "Tiếng Na Uy (Nynorsk)"),
+            ("language/no", "Tiếng Na Uy"),
+            ("language/or", "Tiếng Odia"),
+            ("language/pa", "Tiếng Punjab"),
+            ("language/pl", "Tiếng Ba Lan"),
+            ("language/ps", "Tiếng Pashto"),
+            ("language/pt", "Tiếng Bồ Đào Nha"),
+            ("language/pt-PT", "Tiếng Bồ Đào Nha (Châu Âu)"),
+            ("language/ro", "Tiếng Romania"),
+            ("language/ro-MD", "Tiếng Moldova"),
+            ("language/ru", "Tiếng Nga"),
+            ("language/rw", "Tiếng Kinyarwanda"),
+            ("language/sa", "Tiếng Phạn"),
+            ("language/sd", "Tiếng Sindhi"),
+            ("language/si", "Tiếng Sinhala"),
+            ("language/sk", "Tiếng Slovak"),
+            ("language/sl", "Tiếng Slovenia"),
+            ("language/so", "Tiếng Somali"),
+            ("language/sq", "Tiếng Albania"),
+            ("language/sr", "Tiếng Serbia"),
+            ("language/sv", "Tiếng Thụy Điển"),
+            ("language/sw", "Tiếng Swahili"),
+            ("language/sw-CD", "Tiếng Swahili Congo"),
+            ("language/ta", "Tiếng Tamil"),
+            ("language/te", "Tiếng Telugu"),
+            ("language/tg", "Tiếng Tajik"),
+            ("language/th", "Tiếng Thái"),
+            ("language/ti", "Tiếng Tigrinya"),
+            ("language/tk", "Tiếng Turkmen"),
+            ("language/tr", "Tiếng Thổ Nhĩ Kỳ"),
+            ("language/uk", "Tiếng Ukraina"),
+            ("language/ur", "Tiếng Urdu"),
+            ("language/uz", "Tiếng Uzbek"),
+            ("language/vi", "Tiếng Việt"),
+            ("language/xh", "Tiếng Xhosa"),
+            ("language/yi", "Tiếng Yiddish"),
+            ("language/yo", "Tiếng Yoruba"),
+            ("language/zh", "Tiếng Trung"),
+            ("language/zu", "Tiếng Zulu"),
+            ("pattern", " (|)"),
+            ("region-short/HK", "Hồng Kông"),
+            ("region-short/MO", "Macao"),
+            ("region-short/PS", "Palestine"),
+            ("region/001", "Thế giới"),
+            ("region/002", "Châu Phi"),
+            ("region/003", "Bắc Mỹ"),
+            ("region/005", "Nam Mỹ"),
+            ("region/009", "Châu Đại Dương"),
+            ("region/019", "Châu Mỹ"),
+            ("region/142", "Châu Á"),
+            ("region/150", "Châu Âu"),
+            ("region/419", "Châu Mỹ La-tinh"),
+            ("region/AE", "Các Tiểu Vương quốc Ả Rập Thống nhất"),
+            ("region/AF", "Afghanistan"),
+            ("region/AL", "Albania"),
+            ("region/AM", "Armenia"),
+            ("region/AO", "Angola"),
+            ("region/AQ", "Nam Cực"),
+            ("region/AR", "Argentina"),
+            ("region/AT", "Áo"),
+            ("region/AU", "Australia"),
+            ("region/AZ", "Azerbaijan"),
+            ("region/BA", "Bosnia và Herzegovina"),
+            ("region/BD", "Bangladesh"),
+            ("region/BE", "Bỉ"),
+            ("region/BG", "Bulgaria"),
+            ("region/BO", "Bolivia"),
+            ("region/BR", "Brazil"),
+            ("region/BY", "Belarus"),
+            ("region/CA", "Canada"),
+            ("region/CD", "Congo - Kinshasa"),
+            ("region/CH", "Thụy Sĩ"),
+            ("region/CL", "Chile"),
+            ("region/CM", "Cameroon"),
+            ("region/CN", "Trung Quốc"),
+            ("region/CO", "Colombia"),
+            ("region/CR", "Costa Rica"),
+            ("region/CU", "Cuba"),
+            ("region/CY", "Síp"),
+            ("region/CZ", "Séc"),
+            ("region/DE", "Đức"),
+            ("region/DK", "Đan Mạch"),
+            ("region/DO", "Cộng hòa Dominica"),
+            ("region/DZ", "Algeria"),
+            ("region/EC", "Ecuador"),
+            ("region/EE", "Estonia"),
+            ("region/EG", "Ai Cập"),
+            ("region/ES", "Tây Ban Nha"),
+            ("region/ET", "Ethiopia"),
+            ("region/EU", "Liên Minh Châu Âu"),
+            ("region/FI", "Phần Lan"),
+            ("region/FR", "Pháp"),
+            ("region/GB", "Vương quốc Anh"),
+            ("region/GE", "Georgia"),
+            ("region/GH", "Ghana"),
+            ("region/GR", "Hy Lạp"),
+            ("region/GT", "Guatemala"),
+            ("region/HK", "Đặc khu Hành chính Hồng Kông, Trung Quốc"),
+            ("region/HR", "Croatia"),
+            ("region/HU", "Hungary"),
+            ("region/ID", "Indonesia"),
+            ("region/IE", "Ireland"),
+            ("region/IL", "Israel"),
+            ("region/IN", "Ấn Độ"),
+            ("region/IQ", "Iraq"),
+            ("region/IR", "Iran"),
+            ("region/IS", "Iceland"),
+            ("region/IT", "Italy"),
+            ("region/JM", "Jamaica"),
+            ("region/JO", "Jordan"),
+            ("region/JP", "Nhật Bản"),
+            ("region/KE", "Kenya"),
+            ("region/KH", "Campuchia"),
+            ("region/KP", "Triều Tiên"),
+            ("region/KR", "Hàn Quốc"),
+            ("region/KW", "Kuwait"),
+            ("region/KZ", "Kazakhstan"),
+            ("region/LB", "Li-băng"),
+            ("region/LK", "Sri Lanka"),
+            ("region/LT", "Litva"),
+            ("region/LU", "Luxembourg"),
+            ("region/LV", "Latvia"),
+            ("region/MA", "Ma-rốc"),
+            ("region/MD", "Moldova"),
+            ("region/MK", "Bắc Macedonia"),
+            ("region/MM", "Myanmar (Miến Điện)"),
+            ("region/MN", "Mông Cổ"),
+            ("region/MO", "Đặc khu Hành chính Macao, Trung Quốc"),
+            ("region/MT", "Malta"),
+            ("region/MX", "Mexico"),
+            ("region/MY", "Malaysia"),
+            ("region/NG", "Nigeria"),
+            ("region/NL", "Hà Lan"),
+            ("region/NO", "Na Uy"),
+            ("region/NP", "Nepal"),
+            ("region/NZ", "New Zealand"),
+            ("region/PA", "Panama"),
+            ("region/PE", "Peru"),
+            ("region/PH", "Philippines"),
+            ("region/PK", "Pakistan"),
+            ("region/PL", "Ba Lan"),
+            ("region/PR", "Puerto Rico"),
+            ("region/PS", "Lãnh thổ Palestine"),
+            ("region/PT", "Bồ Đào Nha"),
+            ("region/PY", "Paraguay"),
+            ("region/QA", "Qatar"),
+            ("region/RO", "Romania"),
+            ("region/RS", "Serbia"),
+            ("region/RU", "Nga"),
+            ("region/SA", "Ả Rập Xê-út"),
+            ("region/SE", "Thụy Điển"),
+            ("region/SG", "Singapore"),
+            ("region/SI", "Slovenia"),
+            ("region/SK", "Slovakia"),
+            ("region/SN", "Senegal"),
+            ("region/SY", "Syria"),
+            ("region/TH", "Thái Lan"),
+            ("region/TN", "Tunisia"),
+            ("region/TR", "Thổ Nhĩ Kỳ"),
+            ("region/TW", "Đài Loan"),
+            ("region/TZ", "Tanzania"),
+            ("region/UA", "Ukraina"),
+            ("region/UG", "Uganda"),
+            ("region/UN", "Liên hiệp quốc"),
+            ("region/US", "Hoa Kỳ"),
+            ("region/UY", "Uruguay"),
+            ("region/UZ", "Uzbekistan"),
+            ("region/VE", "Venezuela"),
+            ("region/VN", "Việt Nam"),
+            ("region/ZA", "Nam Phi"),
+            ("region/ZM", "Zambia"),
+            ("region/ZW", "Zimbabwe"),
+            ("script-stand-alone/Hans", "Chữ Hán giản thể"),
+            ("script-stand-alone/Hant", "Chữ Hán phồn thể"),
+            ("script/Arab", "Chữ Ả Rập"),
+            ("script/Armn", "Chữ Armenia"),
+            ("script/Beng", "Chữ Bangla"),
+            ("script/Bopo", "Chữ Bopomofo"),
+            ("script/Brai", "Chữ nổi Braille"),
+            ("script/Cyrl", "Chữ Kirin"),
+            ("script/Deva", "Chữ Devanagari"),
+            ("script/Ethi", "Chữ Ethiopia"),
+            ("script/Geor", "Chữ Georgia"),
+            ("script/Grek", "Chữ Hy Lạp"),
+            ("script/Gujr", "Chữ Gujarati"),
+            ("script/Guru", "Chữ Gurmukhi"),
+            ("script/Hang", "Chữ Hàn"),
+            ("script/Hani", "Chữ Hán"),
+            ("script/Hans", "Giản thể"),
+            ("script/Hant", "Phồn thể"),
+            ("script/Hebr", "Chữ Do Thái"),
+            ("script/Hira", "Chữ Hiragana"),
+            ("script/Jpan", "Chữ Nhật Bản"),
+            ("script/Kana", "Chữ Katakana"),
+            ("script/Khmr", "Chữ Khơ-me"),
+            ("script/Knda", "Chữ Kannada"),
+            ("script/Kore", "Chữ Hàn Quốc"),
+            ("script/Laoo", "Chữ Lào"),
+            ("script/Latn", "Chữ La tinh"),
+            ("script/Mlym", "Chữ Malayalam"),
+            ("script/Mong", "Chữ Mông Cổ"),
+            ("script/Mymr", "Chữ Myanmar"),
+            ("script/Orya", "Chữ Odia"),
+            ("script/Sinh", "Chữ Sinhala"),
+            ("script/Taml", "Chữ Tamil"),
+            ("script/Telu", "Chữ Telugu"),
+            ("script/Thai", "Chữ Thái"),
+            ("separator", ", "),
+        ],
+    ),
+    (
+        "zh",
+        &[
+            ("calendar/buddhist", "佛历"),
+            ("calendar/chinese", "农历"),
+            ("calendar/coptic", "科普特历"),
+            ("calendar/dangi", "檀纪历"),
+            ("calendar/ethioaa", "埃塞俄比亚阿米特阿莱姆日历"),
+            ("calendar/ethiopic", "埃塞俄比亚历"),
+            ("calendar/gregory", "公历"),
+            ("calendar/hebrew", "希伯来历"),
+            ("calendar/indian", "印度国定历"),
+            ("calendar/islamic", "伊斯兰历"),
+            ("calendar/islamic-civil", "伊斯兰希吉来日历"),
+            ("calendar/islamic-rgsa", "沙特阿拉伯伊斯兰历"),
+            ("calendar/islamic-tbla", "伊斯兰天文历"),
+            ("calendar/islamic-umalqura", "伊斯兰历（乌姆库拉）"),
+            ("calendar/iso8601", "国际标准历法"),
+            ("calendar/japanese", "和历"),
+            ("calendar/persian", "波斯历"),
+            ("calendar/roc", "民国纪年"),
+            ("currency/AED", "阿联酋迪拉姆"),
+            ("currency/ARS", "阿根廷比索"),
+            ("currency/AUD", "澳大利亚元"),
+            ("currency/BDT", "孟加拉塔卡"),
+            ("currency/BGN", "保加利亚列弗"),
+            ("currency/BHD", "巴林第纳尔"),
+            ("currency/BOB", "玻利维亚诺"),
+            ("currency/BRL", "巴西雷亚尔"),
+            ("currency/CAD", "加拿大元"),
+            ("currency/CHF", "瑞士法郎"),
+            ("currency/CLP", "智利比索"),
+            ("currency/CNY", "人民币"),
+            ("currency/COP", "哥伦比亚比索"),
+            ("currency/CRC", "哥斯达黎加科朗"),
+            ("currency/CZK", "捷克克朗"),
+            ("currency/DKK", "丹麦克朗"),
+            ("currency/DOP", "多米尼加比索"),
+            ("currency/DZD", "阿尔及利亚第纳尔"),
+            ("currency/EGP", "埃及镑"),
+            ("currency/ETB", "埃塞俄比亚比尔"),
+            ("currency/EUR", "欧元"),
+            ("currency/GBP", "英镑"),
+            ("currency/GEL", "格鲁吉亚拉里"),
+            ("currency/GHS", "加纳塞地"),
+            ("currency/GTQ", "危地马拉格查尔"),
+            ("currency/HKD", "港元"),
+            ("currency/HUF", "匈牙利福林"),
+            ("currency/IDR", "印度尼西亚盾"),
+            ("currency/ILS", "以色列新谢克尔"),
+            ("currency/INR", "印度卢比"),
+            ("currency/IQD", "伊拉克第纳尔"),
+            ("currency/IRR", "伊朗里亚尔"),
+            ("currency/ISK", "冰岛克朗"),
+            ("currency/JMD", "牙买加元"),
+            ("currency/JOD", "约旦第纳尔"),
+            ("currency/JPY", "日元"),
+            ("currency/KES", "肯尼亚先令"),
+            ("currency/KRW", "韩元"),
+            ("currency/KWD", "科威特第纳尔"),
+            ("currency/KZT", "哈萨克斯坦坚戈"),
+            ("currency/LBP", "黎巴嫩镑"),
+            ("currency/LKR", "斯里兰卡卢比"),
+            ("currency/MAD", "摩洛哥迪拉姆"),
+            ("currency/MDL", "摩尔多瓦列伊"),
+            ("currency/MXN", "墨西哥比索"),
+            ("currency/MYR", "马来西亚林吉特"),
+            ("currency/NGN", "尼日利亚奈拉"),
+            ("currency/NOK", "挪威克朗"),
+            ("currency/NPR", "尼泊尔卢比"),
+            ("currency/NZD", "新西兰元"),
+            ("currency/OMR", "阿曼里亚尔"),
+            ("currency/PAB", "巴拿马巴波亚"),
+            ("currency/PEN", "秘鲁索尔"),
+            ("currency/PHP", "菲律宾比索"),
+            ("currency/PKR", "巴基斯坦卢比"),
+            ("currency/PLN", "波兰兹罗提"),
+            ("currency/PYG", "巴拉圭瓜拉尼"),
+            ("currency/QAR", "卡塔尔里亚尔"),
+            ("currency/RON", "罗马尼亚列伊"),
+            ("currency/RSD", "塞尔维亚第纳尔"),
+            ("currency/RUB", "俄罗斯卢布"),
+            ("currency/SAR", "沙特里亚尔"),
+            ("currency/SEK", "瑞典克朗"),
+            ("currency/SGD", "新加坡元"),
+            ("currency/THB", "泰铢"),
+            ("currency/TND", "突尼斯第纳尔"),
+            ("currency/TRY", "土耳其里拉"),
+            ("currency/TWD", "新台币"),
+            ("currency/TZS", "坦桑尼亚先令"),
+            ("currency/UAH", "乌克兰格里夫纳"),
+            ("currency/UGX", "乌干达先令"),
+            ("currency/USD", "美元"),
+            ("currency/UYU", "乌拉圭比索"),
+            ("currency/UZS", "乌兹别克斯坦苏姆"),
+            ("currency/VND", "越南盾"),
+            ("currency/XAF", "中非法郎"),
+            ("currency/XOF", "西非法郎"),
+            ("currency/ZAR", "南非兰特"),
+            ("field-short/minute", "分"),
+            ("field-short/quarter", "季"),
+            ("field/day", "日"),
+            ("field/dayPeriod", "上午/下午"),
+            ("field/era", "纪元"),
+            ("field/hour", "小时"),
+            ("field/minute", "分钟"),
+            ("field/month", "月"),
+            ("field/quarter", "季度"),
+            ("field/second", "秒"),
+            ("field/timeZoneName", "时区"),
+            ("field/weekOfYear", "周"),
+            ("field/weekday", "工作日"),
+            ("field/year", "年"),
+            ("language-short/az", "阿塞语"),
+            ("language-short/en-GB", "英式英语"),
+            ("language-short/en-US", "美式英语"),
+            ("language/af", "南非荷兰语"),
+            ("language/am", "阿姆哈拉语"),
+            ("language/ar", "阿拉伯语"),
+            ("language/ar-001", "现代标准阿拉伯语"),
+            ("language/as", "阿萨姆语"),
+            ("language/az", "阿塞拜疆语"),
+            ("language/be", "白俄罗斯语"),
+            ("language/bg", "保加利亚语"),
+            ("language/bn", "孟加拉语"),
+            ("language/bo", "藏语"),
+            ("language/br", "布列塔尼语"),
+            ("language/bs", "波斯尼亚语"),
+            ("language/ca", "加泰罗尼亚语"),
+            ("language/chr", "切罗基语"),
+            ("language/cs", "捷克语"),
+            ("language/cy", "威尔士语"),
+            ("language/da", "丹麦语"),
+            ("language/de", "德语"),
+            ("language/de-AT", "奥地利德语"),
+            ("language/de-CH", "瑞士高地德语"),
+            ("language/dz", "宗卡语"),
+            ("language/el", "希腊语"),
+            ("language/en", "英语"),
+            ("language/en-AU", "澳大利亚英语"),
+            ("language/en-CA", "加拿大英语"),
+            ("language/en-GB", "英国英语"),
+            ("language/en-US", "美国英语"),
+            ("language/eo", "世界语"),
+            ("language/es", "西班牙语"),
+            ("language/es-419", "拉丁美洲西班牙语"),
+            ("language/es-ES", "欧洲西班牙语"),
+            ("language/es-MX", "墨西哥西班牙语"),
+            ("language/et", "爱沙尼亚语"),
+            ("language/eu", "巴斯克语"),
+            ("language/fa", "波斯语"),
+            ("language/fa-AF", "达里语"),
+            ("language/ff", "富拉语"),
+            ("language/fi", "芬兰语"),
+            ("language/fil", "菲律宾语"),
+            ("language/fo", "法罗语"),
+            ("language/fr", "法语"),
+            ("language/fr-CA", "加拿大法语"),
+            ("language/fr-CH", "瑞士法语"),
+            ("language/fy", "西弗里西亚语"),
+            ("language/ga", "爱尔兰语"),
+            ("language/gd", "苏格兰盖尔语"),
+            ("language/gl", "加利西亚语"),
+            ("language/gu", "古吉拉特语"),
+            ("language/ha", "豪萨语"),
+            ("language/haw", "夏威夷语"),
+            ("language/he", "希伯来语"),
+            ("language/hi", "印地语"),
+            ("language/hr", "克罗地亚语"),
+            ("language/ht", "海地克里奥尔语"),
+            ("language/hu", "匈牙利语"),
+            ("language/hy", "亚美尼亚语"),
+            ("language/id", "印度尼西亚语"),
+            ("language/ig", "伊博语"),
+            ("language/is", "冰岛语"),
+            ("language/it", "意大利语"),
+            ("language/ja", "日语"),
+            ("language/jv", "爪哇语"),
+            ("language/ka", "格鲁吉亚语"),
+            ("language/kk", "哈萨克语"),
+            ("language/km", "高棉语"),
+            ("language/kn", "卡纳达语"),
+            ("language/ko", "韩语"),
+            ("language/ku", "库尔德语"),
+            ("language/ky", "柯尔克孜语"),
+            ("language/la", "拉丁语"),
+            ("language/lb", "卢森堡语"),
+            ("language/lo", "老挝语"),
+            ("language/lt", "立陶宛语"),
+            ("language/lv", "拉脱维亚语"),
+            ("language/mg", "马拉加斯语"),
+            ("language/mi", "毛利语"),
+            ("language/mk", "马其顿语"),
+            ("language/ml", "马拉雅拉姆语"),
+            ("language/mn", "蒙古语"),
+            ("language/mr", "马拉地语"),
+            ("language/ms", "马来语"),
+            ("language/mt", "马耳他语"),
+            ("language/my", "缅甸语"),
+            ("language/nb", "书面挪威语"),
+            ("language/ne", "尼泊尔语"),
+            ("language/nl", "荷兰语"),
+            ("language/nl-BE", "弗拉芒语"),
+            ("language/nn", "挪威尼诺斯克语"),
+            ("language/no", "挪威语"),
+            ("language/or", "奥里亚语"),
+            ("language/pa", "旁遮普语"),
+            ("language/pl", "波兰语"),
+            ("language/ps", "普什图语"),
+            ("language/pt", "葡萄牙语"),
+            ("language/pt-BR", "巴西葡萄牙语"),
+            ("language/pt-PT", "欧洲葡萄牙语"),
+            ("language/ro", "罗马尼亚语"),
+            ("language/ro-MD", "摩尔多瓦语"),
+            ("language/ru", "俄语"),
+            ("language/rw", "卢旺达语"),
+            ("language/sa", "梵语"),
+            ("language/sd", "信德语"),
+            ("language/si", "僧伽罗语"),
+            ("language/sk", "斯洛伐克语"),
+            ("language/sl", "斯洛文尼亚语"),
+            ("language/so", "索马里语"),
+            ("language/sq", "阿尔巴尼亚语"),
+            ("language/sr", "塞尔维亚语"),
+            ("language/sv", "瑞典语"),
+            ("language/sw", "斯瓦希里语"),
+            ("language/sw-CD", "刚果斯瓦希里语"),
+            ("language/ta", "泰米尔语"),
+            ("language/te", "泰卢固语"),
+            ("language/tg", "塔吉克语"),
+            ("language/th", "泰语"),
+            ("language/ti", "提格利尼亚语"),
+            ("language/tk", "土库曼语"),
+            ("language/tr", "土耳其语"),
+            ("language/uk", "乌克兰语"),
+            ("language/ur", "乌尔都语"),
+            ("language/uz", "乌兹别克语"),
+            ("language/vi", "越南语"),
+            ("language/xh", "科萨语"),
+            ("language/yi", "意第绪语"),
+            ("language/yo", "约鲁巴语"),
+            ("language/zh", "中文"),
+            ("language/zh-Hans", "简体中文"),
+            ("language/zh-Hant", "繁体中文"),
+            ("language/zu", "祖鲁语"),
+            ("pattern", "（|）"),
+            ("region-short/HK", "香港"),
+            ("region-short/MO", "澳门"),
+            ("region-short/PS", "巴勒斯坦"),
+            ("region/001", "世界"),
+            ("region/002", "非洲"),
+            ("region/003", "北美洲"),
+            ("region/005", "南美洲"),
+            ("region/009", "大洋洲"),
+            ("region/019", "美洲"),
+            ("region/142", "亚洲"),
+            ("region/150", "欧洲"),
+            ("region/419", "拉丁美洲"),
+            ("region/AE", "阿拉伯联合酋长国"),
+            ("region/AF", "阿富汗"),
+            ("region/AL", "阿尔巴尼亚"),
+            ("region/AM", "亚美尼亚"),
+            ("region/AO", "安哥拉"),
+            ("region/AQ", "南极洲"),
+            ("region/AR", "阿根廷"),
+            ("region/AT", "奥地利"),
+            ("region/AU", "澳大利亚"),
+            ("region/AZ", "阿塞拜疆"),
+            ("region/BA", "波斯尼亚和黑塞哥维那"),
+            ("region/BD", "孟加拉国"),
+            ("region/BE", "比利时"),
+            ("region/BG", "保加利亚"),
+            ("region/BO", "玻利维亚"),
+            ("region/BR", "巴西"),
+            ("region/BY", "白俄罗斯"),
+            ("region/CA", "加拿大"),
+            ("region/CD", "刚果（金）"),
+            ("region/CH", "瑞士"),
+            ("region/CL", "智利"),
+            ("region/CM", "喀麦隆"),
+            ("region/CN", "中国"),
+            ("region/CO", "哥伦比亚"),
+            ("region/CR", "哥斯达黎加"),
+            ("region/CU", "古巴"),
+            ("region/CY", "塞浦路斯"),
+            ("region/CZ", "捷克"),
+            ("region/DE", "德国"),
+            ("region/DK", "丹麦"),
+            ("region/DO", "多米尼加共和国"),
+            ("region/DZ", "阿尔及利亚"),
+            ("region/EC", "厄瓜多尔"),
+            ("region/EE", "爱沙尼亚"),
+            ("region/EG", "埃及"),
+            ("region/ES", "西班牙"),
+            ("region/ET", "埃塞俄比亚"),
+            ("region/EU", "欧盟"),
+            ("region/FI", "芬兰"),
+            ("region/FR", "法国"),
+            ("region/GB", "英国"),
+            ("region/GE", "格鲁吉亚"),
+            ("region/GH", "加纳"),
+            ("region/GR", "希腊"),
+            ("region/GT", "危地马拉"),
+            ("region/HK", "中国香港特别行政区"),
+            ("region/HR", "克罗地亚"),
+            ("region/HU", "匈牙利"),
+            ("region/ID", "印度尼西亚"),
+            ("region/IE", "爱尔兰"),
+            ("region/IL", "以色列"),
+            ("region/IN", "印度"),
+            ("region/IQ", "伊拉克"),
+            ("region/IR", "伊朗"),
+            ("region/IS", "冰岛"),
+            ("region/IT", "意大利"),
+            ("region/JM", "牙买加"),
+            ("region/JO", "约旦"),
+            ("region/JP", "日本"),
+            ("region/KE", "肯尼亚"),
+            ("region/KH", "柬埔寨"),
+            ("region/KP", "朝鲜"),
+            ("region/KR", "韩国"),
+            ("region/KW", "科威特"),
+            ("region/KZ", "哈萨克斯坦"),
+            ("region/LB", "黎巴嫩"),
+            ("region/LK", "斯里兰卡"),
+            ("region/LT", "立陶宛"),
+            ("region/LU", "卢森堡"),
+            ("region/LV", "拉脱维亚"),
+            ("region/MA", "摩洛哥"),
+            ("region/MD", "摩尔多瓦"),
+            ("region/MK", "北马其顿"),
+            ("region/MM", "缅甸"),
+            ("region/MN", "蒙古"),
+            ("region/MO", "中国澳门特别行政区"),
+            ("region/MT", "马耳他"),
+            ("region/MX", "墨西哥"),
+            ("region/MY", "马来西亚"),
+            ("region/NG", "尼日利亚"),
+            ("region/NL", "荷兰"),
+            ("region/NO", "挪威"),
+            ("region/NP", "尼泊尔"),
+            ("region/NZ", "新西兰"),
+            ("region/PA", "巴拿马"),
+            ("region/PE", "秘鲁"),
+            ("region/PH", "菲律宾"),
+            ("region/PK", "巴基斯坦"),
+            ("region/PL", "波兰"),
+            ("region/PR", "波多黎各"),
+            ("region/PS", "巴勒斯坦领土"),
+            ("region/PT", "葡萄牙"),
+            ("region/PY", "巴拉圭"),
+            ("region/QA", "卡塔尔"),
+            ("region/RO", "罗马尼亚"),
+            ("region/RS", "塞尔维亚"),
+            ("region/RU", "俄罗斯"),
+            ("region/SA", "沙特阿拉伯"),
+            ("region/SE", "瑞典"),
+            ("region/SG", "新加坡"),
+            ("region/SI", "斯洛文尼亚"),
+            ("region/SK", "斯洛伐克"),
+            ("region/SN", "塞内加尔"),
+            ("region/SY", "叙利亚"),
+            ("region/TH", "泰国"),
+            ("region/TN", "突尼斯"),
+            ("region/TR", "土耳其"),
+            ("region/TW", "台湾"),
+            ("region/TZ", "坦桑尼亚"),
+            ("region/UA", "乌克兰"),
+            ("region/UG", "乌干达"),
+            ("region/UN", "联合国"),
+            ("region/US", "美国"),
+            ("region/UY", "乌拉圭"),
+            ("region/UZ", "乌兹别克斯坦"),
+            ("region/VE", "委内瑞拉"),
+            ("region/VN", "越南"),
+            ("region/ZA", "南非"),
+            ("region/ZM", "赞比亚"),
+            ("region/ZW", "津巴布韦"),
+            ("script-stand-alone/Hans", "简体中文"),
+            ("script-stand-alone/Hant", "繁体中文"),
+            ("script/Arab", "阿拉伯文"),
+            ("script/Armn", "亚美尼亚文"),
+            ("script/Beng", "孟加拉文"),
+            ("script/Bopo", "汉语拼音"),
+            ("script/Brai", "布莱叶盲文"),
+            ("script/Cyrl", "西里尔文"),
+            ("script/Deva", "天城文"),
+            ("script/Ethi", "埃塞俄比亚文"),
+            ("script/Geor", "格鲁吉亚文"),
+            ("script/Grek", "希腊文"),
+            ("script/Gujr", "古吉拉特文"),
+            ("script/Guru", "果鲁穆奇文"),
+            ("script/Hang", "谚文"),
+            ("script/Hani", "汉字"),
+            ("script/Hans", "简体"),
+            ("script/Hant", "繁体"),
+            ("script/Hebr", "希伯来文"),
+            ("script/Hira", "平假名"),
+            ("script/Jpan", "日文"),
+            ("script/Kana", "片假名"),
+            ("script/Khmr", "高棉文"),
+            ("script/Knda", "卡纳达文"),
+            ("script/Kore", "韩文"),
+            ("script/Laoo", "老挝文"),
+            ("script/Latn", "拉丁文"),
+            ("script/Mlym", "马拉雅拉姆文"),
+            ("script/Mong", "蒙古文"),
+            ("script/Mymr", "缅甸文"),
+            ("script/Orya", "奥里亚文"),
+            ("script/Sinh", "僧伽罗文"),
+            ("script/Taml", "泰米尔文"),
+            ("script/Telu", "泰卢固文"),
+            ("script/Thai", "泰文"),
+            ("separator", "，"),
+        ],
+    ),
+    (
+        "zh-Hant",
+        &[
+            ("calendar/buddhist", "佛曆"),
+            ("calendar/chinese", "農曆"),
+            ("calendar/coptic", "科普特曆"),
+            ("calendar/dangi", "檀紀曆"),
+            ("calendar/ethioaa", "衣索比亞曆 (Amete Alem)"),
+            ("calendar/ethiopic", "衣索比亞曆"),
+            ("calendar/gregory", "公曆"),
+            ("calendar/hebrew", "希伯來曆"),
+            ("calendar/indian", "印度國曆"),
+            ("calendar/islamic", "伊斯蘭曆"),
+            ("calendar/islamic-civil", "伊斯蘭民用曆"),
+            ("calendar/islamic-rgsa", "伊斯蘭新月曆"),
+            ("calendar/islamic-tbla", "伊斯蘭天文曆"),
+            ("calendar/islamic-umalqura", "烏姆庫拉曆"),
+            ("calendar/iso8601", "ISO 8601 國際曆法"),
+            ("calendar/japanese", "日本曆"),
+            ("calendar/persian", "波斯曆"),
+            ("calendar/roc", "國曆"),
+            ("currency/AED", "阿拉伯聯合大公國迪爾汗"),
+            ("currency/ARS", "阿根廷披索"),
+            ("currency/AUD", "澳幣"),
+            ("currency/BDT", "孟加拉塔卡"),
+            ("currency/BGN", "保加利亞新列弗"),
+            ("currency/BHD", "巴林第納爾"),
+            ("currency/BOB", "玻利維亞諾"),
+            ("currency/BRL", "巴西雷亞爾"),
+            ("currency/CAD", "加幣"),
+            ("currency/CHF", "瑞士法郎"),
+            ("currency/CLP", "智利披索"),
+            ("currency/CNY", "人民幣"),
+            ("currency/COP", "哥倫比亞披索"),
+            ("currency/CRC", "哥斯大黎加科朗"),
+            ("currency/CZK", "捷克克朗"),
+            ("currency/DKK", "丹麥克朗"),
+            ("currency/DOP", "多明尼加披索"),
+            ("currency/DZD", "阿爾及利亞第納爾"),
+            ("currency/EGP", "埃及鎊"),
+            ("currency/ETB", "衣索比亞比爾"),
+            ("currency/EUR", "歐元"),
+            ("currency/GBP", "英鎊"),
+            ("currency/GEL", "喬治亞拉里"),
+            ("currency/GHS", "迦納塞地"),
+            ("currency/GTQ", "瓜地馬拉格查爾"),
+            ("currency/HKD", "港幣"),
+            ("currency/HUF", "匈牙利福林"),
+            ("currency/IDR", "印尼盾"),
+            ("currency/ILS", "以色列新謝克爾"),
+            ("currency/INR", "印度盧比"),
+            ("currency/IQD", "伊拉克第納爾"),
+            ("currency/IRR", "伊朗里亞爾"),
+            ("currency/ISK", "冰島克朗"),
+            ("currency/JMD", "牙買加元"),
+            ("currency/JOD", "約旦第納爾"),
+            ("currency/JPY", "日圓"),
+            ("currency/KES", "肯尼亞先令"),
+            ("currency/KRW", "韓元"),
+            ("currency/KWD", "科威特第納爾"),
+            ("currency/KZT", "哈薩克堅戈"),
+            ("currency/LBP", "黎巴嫩鎊"),
+            ("currency/LKR", "斯里蘭卡盧比"),
+            ("currency/MAD", "摩洛哥迪拉姆"),
+            ("currency/MDL", "摩杜雲列伊"),
+            ("currency/MXN", "墨西哥披索"),
+            ("currency/MYR", "馬來西亞令吉"),
+            ("currency/NGN", "奈及利亞奈拉"),
+            ("currency/NOK", "挪威克朗"),
+            ("currency/NPR", "尼泊爾盧比"),
+            ("currency/NZD", "紐西蘭幣"),
+            ("currency/OMR", "阿曼里亞爾"),
+            ("currency/PAB", "巴拿馬巴波亞"),
+            ("currency/PEN", "秘魯太陽幣"),
+            ("currency/PHP", "菲律賓披索"),
+            ("currency/PKR", "巴基斯坦盧比"),
+            ("currency/PLN", "波蘭茲羅提"),
+            ("currency/PYG", "巴拉圭瓜拉尼"),
+            ("currency/QAR", "卡達里亞爾"),
+            ("currency/RON", "羅馬尼亞列伊"),
+            ("currency/RSD", "塞爾維亞戴納"),
+            ("currency/RUB", "俄羅斯盧布"),
+            ("currency/SAR", "沙烏地里亞爾"),
+            ("currency/SEK", "瑞典克朗"),
+            ("currency/SGD", "新加坡幣"),
+            ("currency/THB", "泰銖"),
+            ("currency/TND", "突尼西亞第納爾"),
+            ("currency/TRY", "新土耳其里拉"),
+            ("currency/TWD", "新台幣"),
+            ("currency/TZS", "坦尚尼亞先令"),
+            ("currency/UAH", "烏克蘭格里夫納"),
+            ("currency/UGX", "烏干達先令"),
+            ("currency/USD", "美元"),
+            ("currency/UYU", "烏拉圭披索"),
+            ("currency/UZS", "烏茲別克索姆"),
+            ("currency/VND", "越南盾"),
+            ("currency/XAF", "法郎 (CFA–BEAC)"),
+            ("currency/XOF", "法郎 (CFA–BCEAO)"),
+            ("currency/ZAR", "南非蘭特"),
+            ("field/day", "日"),
+            ("field/dayPeriod", "上午/下午"),
+            ("field/era", "年代"),
+            ("field/hour", "小時"),
+            ("field/minute", "分鐘"),
+            ("field/month", "月"),
+            ("field/quarter", "季"),
+            ("field/second", "秒"),
+            ("field/timeZoneName", "時區"),
+            ("field/weekOfYear", "週"),
+            ("field/weekday", "週天"),
+            ("field/year", "年"),
+            ("language/af", "南非荷蘭文"),
+            ("language/am", "阿姆哈拉文"),
+            ("language/ar", "阿拉伯文"),
+            ("language/ar-001", "現代標準阿拉伯文"),
+            ("language/as", "阿薩姆文"),
+            ("language/az", "亞塞拜然文"),
+            ("language/be", "白俄羅斯文"),
+            ("language/bg", "保加利亞文"),
+            ("language/bn", "孟加拉文"),
+            ("language/bo", "藏文"),
+            ("language/br", "布列塔尼文"),
+            ("language/bs", "波士尼亞文"),
+            ("language/ca", "加泰蘭文"),
+            ("language/chr", "柴羅基文"),
+            ("language/cs", "捷克文"),
+            ("language/cy", "威爾斯文"),
+            ("language/da", "丹麥文"),
+            ("language/de", "德文"),
+            ("language/de-CH", "高地德文（瑞士）"),
+            ("language/dz", "宗卡文"),
+            ("language/el", "希臘文"),
+            ("language/en", "英文"),
+            ("language/eo", "世界文"),
+            ("language/es", "西班牙文"),
+            ("language/et", "愛沙尼亞文"),
+            ("language/eu", "巴斯克文"),
+            ("language/fa", "波斯文"),
+            ("language/ff", "富拉文"),
+            ("language/fi", "芬蘭文"),
+            ("language/fil", "菲律賓文"),
+            ("language/fo", "法羅文"),
+            ("language/fr", "法文"),
+            ("language/fy", "西弗里西亞文"),
+            ("language/ga", "愛爾蘭文"),
+            ("language/gd", "蘇格蘭蓋爾文"),
+            ("language/gl", "加利西亞文"),
+            ("language/gu", "古吉拉特文"),
+            ("language/ha", "豪撒文"),
+            ("language/haw", "夏威夷文"),
+            ("language/he", "希伯來文"),
+            ("language/hi", "印地文"),
+            ("language/hr", "克羅埃西亞文"),
+            ("language/ht", "海地文"),
+            ("language/hu", "匈牙利文"),
+            ("language/hy", "亞美尼亞文"),
+            ("language/id", "印尼文"),
+            ("language/ig", "伊布文"),
+            ("language/is", "冰島文"),
+            ("language/it", "義大利文"),
+            ("language/ja", "日文"),
+            ("language/jv", "爪哇文"),
+            ("language/ka", "喬治亞文"),
+            ("language/kk", "哈薩克文"),
+            ("language/km", "高棉文"),
+            ("language/kn", "坎那達文"),
+            ("language/ko", "韓文"),
+            ("language/ku", "庫德文"),
+            ("language/ky", "吉爾吉斯文"),
+            ("language/la", "拉丁文"),
+            ("language/lb", "盧森堡文"),
+            ("language/lo", "寮文"),
+            ("language/lt", "立陶宛文"),
+            ("language/lv", "拉脫維亞文"),
+            ("language/mg", "馬達加斯加文"),
+            ("language/mi", "毛利文"),
+            ("language/mk", "馬其頓文"),
+            ("language/ml", "馬來亞拉姆文"),
+            ("language/mn", "蒙古文"),
+            ("language/mr", "馬拉地文"),
+            ("language/ms", "馬來文"),
+            ("language/mt", "馬爾他文"),
+            ("language/my", "緬甸文"),
+            ("language/nb", "巴克摩挪威文"),
+            ("language/ne", "尼泊爾文"),
+            ("language/nl", "荷蘭文"),
+            ("language/nl-BE", "佛蘭芒文"),
+            ("language/nn", "耐諾斯克挪威文"),
+            ("language/no", "挪威文"),
+            ("language/or", "歐迪亞文"),
+            ("language/pa", "旁遮普文"),
+            ("language/pl", "波蘭文"),
+            ("language/ps", "普什圖文"),
+            ("language/pt", "葡萄牙文"),
+            ("language/ro", "羅馬尼亞文"),
+            ("language/ro-MD", "摩爾多瓦文"),
+            ("language/ru", "俄文"),
+            ("language/rw", "盧安達文"),
+            ("language/sa", "梵文"),
+            ("language/sd", "信德文"),
+            ("language/si", "僧伽羅文"),
+            ("language/sk", "斯洛伐克文"),
+            ("language/sl", "斯洛維尼亞文"),
+            ("language/so", "索馬利文"),
+            ("language/sq", "阿爾巴尼亞文"),
+            ("language/sr", "塞爾維亞文"),
+            ("language/sv", "瑞典文"),
+            ("language/sw", "史瓦希里文"),
+            ("language/sw-CD", "史瓦希里文（剛果）"),
+            ("language/ta", "坦米爾文"),
+            ("language/te", "泰盧固文"),
+            ("language/tg", "塔吉克文"),
+            ("language/th", "泰文"),
+            ("language/ti", "提格利尼亞文"),
+            ("language/tk", "土庫曼文"),
+            ("language/tr", "土耳其文"),
+            ("language/uk", "烏克蘭文"),
+            ("language/ur", "烏都文"),
+            ("language/uz", "烏茲別克文"),
+            ("language/vi", "越南文"),
+            ("language/xh", "科薩文"),
+            ("language/yi", "意第緒文"),
+            ("language/yo", "約魯巴文"),
+            ("language/zh", "中文"),
+            ("language/zh-Hans", "簡體中文"),
+            ("language/zh-Hant", "繁體中文"),
+            ("language/zu", "祖魯文"),
+            ("pattern", "（|）"),
+            ("region-short/HK", "香港"),
+            ("region-short/MO", "澳門"),
+            ("region-short/PS", "巴勒斯坦"),
+            ("region/001", "世界"),
+            ("region/002", "非洲"),
+            ("region/003", "北美洲"),
+            ("region/005", "南美洲"),
+            ("region/009", "大洋洲"),
+            ("region/019", "美洲"),
+            ("region/142", "亞洲"),
+            ("region/150", "歐洲"),
+            ("region/419", "拉丁美洲"),
+            ("region/AE", "阿拉伯聯合大公國"),
+            ("region/AF", "阿富汗"),
+            ("region/AL", "阿爾巴尼亞"),
+            ("region/AM", "亞美尼亞"),
+            ("region/AO", "安哥拉"),
+            ("region/AQ", "南極洲"),
+            ("region/AR", "阿根廷"),
+            ("region/AT", "奧地利"),
+            ("region/AU", "澳洲"),
+            ("region/AZ", "亞塞拜然"),
+            ("region/BA", "波士尼亞與赫塞哥維納"),
+            ("region/BD", "孟加拉"),
+            ("region/BE", "比利時"),
+            ("region/BG", "保加利亞"),
+            ("region/BO", "玻利維亞"),
+            ("region/BR", "巴西"),
+            ("region/BY", "白俄羅斯"),
+            ("region/CA", "加拿大"),
+            ("region/CD", "剛果（金夏沙）"),
+            ("region/CH", "瑞士"),
+            ("region/CL", "智利"),
+            ("region/CM", "喀麥隆"),
+            ("region/CN", "中國"),
+            ("region/CO", "哥倫比亞"),
+            ("region/CR", "哥斯大黎加"),
+            ("region/CU", "古巴"),
+            ("region/CY", "賽普勒斯"),
+            ("region/CZ", "捷克"),
+            ("region/DE", "德國"),
+            ("region/DK", "丹麥"),
+            ("region/DO", "多明尼加共和國"),
+            ("region/DZ", "阿爾及利亞"),
+            ("region/EC", "厄瓜多"),
+            ("region/EE", "愛沙尼亞"),
+            ("region/EG", "埃及"),
+            ("region/ES", "西班牙"),
+            ("region/ET", "衣索比亞"),
+            ("region/EU", "歐盟"),
+            ("region/FI", "芬蘭"),
+            ("region/FR", "法國"),
+            ("region/GB", "英國"),
+            ("region/GE", "喬治亞"),
+            ("region/GH", "迦納"),
+            ("region/GR", "希臘"),
+            ("region/GT", "瓜地馬拉"),
+            ("region/HK", "中國香港特別行政區"),
+            ("region/HR", "克羅埃西亞"),
+            ("region/HU", "匈牙利"),
+            ("region/ID", "印尼"),
+            ("region/IE", "愛爾蘭"),
+            ("region/IL", "以色列"),
+            ("region/IN", "印度"),
+            ("region/IQ", "伊拉克"),
+            ("region/IR", "伊朗"),
+            ("region/IS", "冰島"),
+            ("region/IT", "義大利"),
+            ("region/JM", "牙買加"),
+            ("region/JO", "約旦"),
+            ("region/JP", "日本"),
+            ("region/KE", "肯亞"),
+            ("region/KH", "柬埔寨"),
+            ("region/KP", "北韓"),
+            ("region/KR", "南韓"),
+            ("region/KW", "科威特"),
+            ("region/KZ", "哈薩克"),
+            ("region/LB", "黎巴嫩"),
+            ("region/LK", "斯里蘭卡"),
+            ("region/LT", "立陶宛"),
+            ("region/LU", "盧森堡"),
+            ("region/LV", "拉脫維亞"),
+            ("region/MA", "摩洛哥"),
+            ("region/MD", "摩爾多瓦"),
+            ("region/MK", "北馬其頓"),
+            ("region/MM", "緬甸"),
+            ("region/MN", "蒙古"),
+            ("region/MO", "中國澳門特別行政區"),
+            ("region/MT", "馬爾他"),
+            ("region/MX", "墨西哥"),
+            ("region/MY", "馬來西亞"),
+            ("region/NG", "奈及利亞"),
+            ("region/NL", "荷蘭"),
+            ("region/NO", "挪威"),
+            ("region/NP", "尼泊爾"),
+            ("region/NZ", "紐西蘭"),
+            ("region/PA", "巴拿馬"),
+            ("region/PE", "秘魯"),
+            ("region/PH", "菲律賓"),
+            ("region/PK", "巴基斯坦"),
+            ("region/PL", "波蘭"),
+            ("region/PR", "波多黎各"),
+            ("region/PS", "巴勒斯坦自治區"),
+            ("region/PT", "葡萄牙"),
+            ("region/PY", "巴拉圭"),
+            ("region/QA", "卡達"),
+            ("region/RO", "羅馬尼亞"),
+            ("region/RS", "塞爾維亞"),
+            ("region/RU", "俄羅斯"),
+            ("region/SA", "沙烏地阿拉伯"),
+            ("region/SE", "瑞典"),
+            ("region/SG", "新加坡"),
+            ("region/SI", "斯洛維尼亞"),
+            ("region/SK", "斯洛伐克"),
+            ("region/SN", "塞內加爾"),
+            ("region/SY", "敘利亞"),
+            ("region/TH", "泰國"),
+            ("region/TN", "突尼西亞"),
+            ("region/TR", "土耳其"),
+            ("region/TW", "台灣"),
+            ("region/TZ", "坦尚尼亞"),
+            ("region/UA", "烏克蘭"),
+            ("region/UG", "烏干達"),
+            ("region/UN", "聯合國"),
+            ("region/US", "美國"),
+            ("region/UY", "烏拉圭"),
+            ("region/UZ", "烏茲別克"),
+            ("region/VE", "委內瑞拉"),
+            ("region/VN", "越南"),
+            ("region/ZA", "南非"),
+            ("region/ZM", "尚比亞"),
+            ("region/ZW", "辛巴威"),
+            ("script-stand-alone/Hans", "簡體中文"),
+            ("script-stand-alone/Hant", "繁體中文"),
+            ("script/Arab", "阿拉伯字母"),
+            ("script/Armn", "亞美尼亞文"),
+            ("script/Beng", "孟加拉文"),
+            ("script/Bopo", "注音符號"),
+            ("script/Brai", "盲人用點字"),
+            ("script/Cyrl", "西里爾文字"),
+            ("script/Deva", "天城文"),
+            ("script/Ethi", "衣索比亞文"),
+            ("script/Geor", "喬治亞文"),
+            ("script/Grek", "希臘字母"),
+            ("script/Gujr", "古吉拉特文"),
+            ("script/Guru", "古魯穆奇文"),
+            ("script/Hang", "韓文字"),
+            ("script/Hani", "漢字"),
+            ("script/Hans", "簡體"),
+            ("script/Hant", "繁體"),
+            ("script/Hebr", "希伯來文"),
+            ("script/Hira", "平假名"),
+            ("script/Jpan", "日文"),
+            ("script/Kana", "片假名"),
+            ("script/Khmr", "高棉文"),
+            ("script/Knda", "坎那達文"),
+            ("script/Kore", "韓文"),
+            ("script/Laoo", "寮國文"),
+            ("script/Latn", "拉丁文"),
+            ("script/Mlym", "馬來亞拉姆文"),
+            ("script/Mong", "蒙古文"),
+            ("script/Mymr", "緬甸文"),
+            ("script/Orya", "歐迪亞文"),
+            ("script/Sinh", "錫蘭文"),
+            ("script/Taml", "坦米爾文"),
+            ("script/Telu", "泰盧固文"),
+            ("script/Thai", "泰文"),
+            ("separator", "，"),
+        ],
+    ),
+    (
+        "zh-Hant-HK",
+        &[
+            ("calendar/ethioaa", "埃塞俄比亞阿美德阿萊姆曆"),
+            ("calendar/ethiopic", "埃塞俄比亞曆"),
+            ("currency/AED", "阿拉伯聯合酋長國迪爾汗"),
+            ("currency/AUD", "澳元"),
+            ("currency/CAD", "加拿大元"),
+            ("currency/CRC", "哥斯達黎加科郎"),
+            ("currency/DOP", "多米尼加披索"),
+            ("currency/ETB", "埃塞俄比亞比爾"),
+            ("currency/GEL", "格魯吉亞拉里"),
+            ("currency/GHS", "加納塞地"),
+            ("currency/GTQ", "危地馬拉格查爾"),
+            ("currency/HKD", "港元"),
+            ("currency/KES", "肯雅先令"),
+            ("currency/KZT", "哈薩克坦吉"),
+            ("currency/MDL", "摩爾多瓦列伊"),
+            ("currency/NGN", "尼日利亞奈拉"),
+            ("currency/NZD", "紐西蘭元"),
+            ("currency/OMR", "阿曼里奧"),
+            ("currency/QAR", "卡塔爾里亞爾"),
+            ("currency/RSD", "塞爾維亞第納爾"),
+            ("currency/SAR", "沙特阿拉伯里亞爾"),
+            ("currency/SGD", "新加坡元"),
+            ("currency/TZS", "坦桑尼亞先令"),
+            ("currency/XAF", "中非法郎"),
+            ("currency/XOF", "多哥非洲共同體法郎"),
+            ("field-narrow/hour", "時"),
+            ("field-narrow/minute", "分"),
+            ("field-narrow/weekOfYear", "週"),
+            ("field/weekOfYear", "星期"),
+            ("field/weekday", "星期幾"),
+            ("language-short/en-GB", "英式英文"),
+            ("language-short/en-US", "美式英文"),
+            ("language/az", "阿塞拜疆文"),
+            ("language/br", "布里多尼文"),
+            ("language/bs", "波斯尼亞文"),
+            ("language/ca", "加泰隆尼亞文"),
+            ("language/de-AT", "奧地利德文"),
+            ("language/de-CH", "瑞士德語"),
+            ("language/en-AU", "澳洲英文"),
+            ("language/en-CA", "加拿大英文"),
+            ("language/en-GB", "英國英文"),
+            ("language/en-US", "美國英文"),
+            ("language/eo", "世界語"),
+            ("language/es-419", "拉丁美洲西班牙文"),
+            ("language/es-ES", "歐洲西班牙文"),
+            ("language/es-MX", "墨西哥西班牙文"),
+            ("language/fa-AF", "達利文"),
+            ("language/fr-CA", "加拿大法文"),
+            ("language/fr-CH", "瑞士法文"),
+            ("language/gl", "加里西亞文"),
+            ("language/hr", "克羅地亞文"),
+            ("language/ig", "伊博文"),
+            ("language/it", "意大利文"),
+            ("language/ka", "格魯吉亞文"),
+            ("language/kn", "坎納達文"),
+            ("language/lo", "老撾文"),
+            ("language/mg", "馬拉加斯文"),
+            ("language/ml", "馬拉雅拉姆文"),
+            ("language/mt", "馬耳他文"),
+            ("language/nl-BE", "比利時荷蘭文"),
+            ("language/or", "奧里雅文"),
+            ("language/pt-BR", "巴西葡萄牙文"),
+            ("language/pt-PT", "歐洲葡萄牙文"),
+            ("language/ro-MD", "摩爾多瓦羅馬尼亞文"),
+            ("language/rw", "盧旺達文"),
+            ("language/sl", "斯洛文尼亞文"),
+            ("language/so", "索馬里文"),
+            ("language/sw-CD", "剛果史瓦希里文"),
+            ("language/ta", "泰米爾文"),
+            ("language/ur", "烏爾都文"),
+            ("region/AE", "阿拉伯聯合酋長國"),
+            ("region/AZ", "亞塞拜疆"),
+            ("region/BA", "波斯尼亞和黑塞哥維那"),
+            ("region/CR", "哥斯達黎加"),
+            ("region/CY", "塞浦路斯"),
+            ("region/DO", "多米尼加共和國"),
+            ("region/EC", "厄瓜多爾"),
+            ("region/ET", "埃塞俄比亞"),
+            ("region/GE", "格魯吉亞"),
+            ("region/GH", "加納"),
+            ("region/GT", "危地馬拉"),
+            ("region/HR", "克羅地亞"),
+            ("region/IT", "意大利"),
+            ("region/KE", "肯尼亞"),
+            ("region/MT", "馬耳他"),
+            ("region/NG", "尼日利亞"),
+            ("region/PS", "巴勒斯坦領土"),
+            ("region/QA", "卡塔爾"),
+            ("region/SA", "沙地阿拉伯"),
+            ("region/SI", "斯洛文尼亞"),
+            ("region/TZ", "坦桑尼亞"),
+            ("region/ZM", "贊比亞"),
+            ("region/ZW", "津巴布韋"),
+            ("script/Cyrl", "西里爾文"),
+            ("script/Ethi", "埃塞俄比亞文"),
+            ("script/Geor", "格魯吉亞文"),
+            ("script/Guru", "古木基文"),
+            ("script/Hans", "簡體字"),
+            ("script/Hant", "繁體字"),
+            ("script/Knda", "坎納達文"),
+            ("script/Laoo", "老撾文"),
+            ("script/Latn", "拉丁字母"),
+            ("script/Mlym", "馬拉雅拉姆文"),
+            ("script/Orya", "奧里雅文"),
+            ("script/Sinh", "僧伽羅文"),
+            ("script/Taml", "泰米爾文"),
+        ],
+    ),
+]);
@@ -0,0 +1,189 @@
+//! Locale data of CLDR that is not provided by the ICU4X data provider.
+//!
+//! The tables of this module are generated by `boa_engine/intl_data/generate.py` from the data of
+//! ICU4C, and only cover a set of widely used languages. Services must fall back to another
+//! locale when [`LocaleData::lookup`] doesn't find any data for their resolved locale.
+//!
+//! Each table maps keys to strings in a small format:
+//!
+//! - Patterns: `{0}` is the number, `{-}` the sign, `{}` a placeholder for a currency or a name,
+//!   and `{text}` is a field of the pattern, like a percent sign or a unit. Any other text is a
+//!   literal.
+//! - Plural values: either one value for all the plural categories or `one=...;other=...`, where
+//!   `other` always comes last.
+//! - Multiple values in a single entry are separated by `|`.
+
+use icu_locid::{subtags::Variants, LanguageIdentifier};
+use icu_locid_transform::LocaleExpander;
+
+mod display_names;
+mod number_format;
+mod parents;
+mod relative_time_format;
+
+pub(in crate::builtins::intl) use self::{
+    display_names::DISPLAY_NAMES, number_format::NUMBER_FORMAT,
+    relative_time_format::RELATIVE_TIME_FORMAT,
+};
+
+/// The sorted key-value pairs of a single locale.
+type Table = &'static [(&'static str, &'static str)];
+
+/// The data of a service for each one of its supported locales.
+#[derive(Debug)]
+pub(in crate::builtins::intl) struct LocaleData {
+    locales: &'static [(&'static str, Table)],
+}
+
+impl LocaleData {
+    const fn new(locales: &'static [(&'static str, Table)]) -> Self {
+        Self { locales }
+    }
+
+    fn contains(&self, locale: &str) -> bool {
+        self.locales
+            .binary_search_by_key(&locale, |(locale, _)| locale)
+            .is_ok()
+            || parents::PARENTS
+                .binary_search_by_key(&locale, |(locale, _)| locale)
+                .is_ok()
+    }
+
+    /// Gets the data of the best match for `locale`, or `None` if there's no data for its
+    /// language.
+    pub(in crate::builtins::intl) fn lookup(
+        &'static self,
+        locale: &LanguageIdentifier,
+        expander: &LocaleExpander,
+    ) -> Option<LocaleChain> {
+        let mut maximized = LanguageIdentifier {
+            language: locale.language,
+            script: locale.script,
+            region: locale.region,
+            variants: Variants::default(),
+        };
+        expander.maximize(&mut maximized);
+        let language = maximized.language;
+
+        let mut likely = LanguageIdentifier::from(language);
+        expander.maximize(&mut likely);
+        let default_script = likely.script == maximized.script;
+
+        let script = maximized
+            .script
+            .map(|script| format!("{language}-{script}"));
+        let region = maximized.region.map(|region| format!("-{region}"));
+        let candidates = [
+            script
+                .as_ref()
+                .zip(region.as_ref())
+                .map(|(s, r)| format!("{s}{r}")),
+            region
+                .as_ref()
+                .filter(|_| default_script)
+                .map(|r| format!("{language}{r}")),
+            script,
+            default_script.then(|| language.to_string()),
+        ];
+
+        candidates
+            .into_iter()
+            .flatten()
+            .find(|candidate| self.contains(candidate))
+            .and_then(|candidate| self.chain(&candidate))
+    }
+
+    /// Gets the data of `locale` and all its parent locales, or `None` if none of them has data.
+    ///
+    /// `locale` must be formatted like the locales of the tables, e.g. `zh-Hant-HK`.
+    pub(in crate::builtins::intl) fn chain(&'static self, locale: &str) -> Option<LocaleChain> {
+        let mut tables = Vec::new();
+        let mut locale = locale.to_string();
+        loop {
+            if let Ok(index) = self
+                .locales
+                .binary_search_by_key(&locale.as_str(), |(locale, _)| locale)
+            {
+                tables.push(self.locales[index].1);
+            }
+
+            locale = match parents::PARENTS.binary_search_by_key(&locale.as_str(), |(l, _)| l) {
+                Ok(index) => parents::PARENTS[index].1.to_string(),
+                Err(_) => match locale.rfind('-') {
+                    Some(index) => locale[..index].to_string(),
+                    None => String::new(),
+                },
+            };
+            if locale.is_empty() {
+                break;
+            }
+        }
+
+        (!tables.is_empty()).then_some(LocaleChain { tables })
+    }
+}
+
+/// The data of a locale, followed by the data of its parent locales.
+#[derive(Debug, Clone)]
+pub(in crate::builtins::intl) struct LocaleChain {
+    tables: Vec<Table>,
+}
+
+impl LocaleChain {
+    /// Gets the value of `key` from the most specific locale that defines it.
+    pub(in crate::builtins::intl) fn get(&self, key: &str) -> Option<&'static str> {
+        self.tables.iter().find_map(|table| {
+            table
+                .binary_search_by_key(&key, |(key, _)| key)
+                .ok()
+                .map(|index| table[index].1)
+        })
+    }
+}
+
+/// Selects the value of a plural `category` from a plural value.
+pub(in crate::builtins::intl) fn plural<'a>(value: &'a str, category: &str) -> &'a str {
+    if !value.contains('=') {
+        return value;
+    }
+    value
+        .split(';')
+        .filter_map(|entry| entry.split_once('='))
+        .find(|(c, _)| *c == category || *c == "other")
+        .map_or(value, |(_, value)| value)
+}
+
+/// A piece of a pattern.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(in crate::builtins::intl) enum PatternPart<'a> {
+    Literal(&'a str),
+    Number,
+    Sign,
+    Placeholder,
+    Field(&'a str),
+}
+
+/// Splits a pattern into its parts.
+pub(in crate::builtins::intl) fn pattern_parts(
+    mut pattern: &str,
+) -> impl Iterator<Item = PatternPart<'_>> {
+    std::iter::from_fn(move || {
+        if pattern.is_empty() {
+            return None;
+        }
+        let Some(rest) = pattern.strip_prefix('{') else {
+            let end = pattern.find('{').unwrap_or(pattern.len());
+            let (literal, rest) = pattern.split_at(end);
+            pattern = rest;
+            return Some(PatternPart::Literal(literal));
+        };
+        let (field, rest) = rest.split_once('}').unwrap_or((rest, ""));
+        pattern = rest;
+        Some(match field {
+            "0" => PatternPart::Number,
+            "-" => PatternPart::Sign,
+            "" => PatternPart::Placeholder,
+            field => PatternPart::Field(field),
+        })
+    })
+}
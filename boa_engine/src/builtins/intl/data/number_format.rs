@@ -0,0 +1,5042 @@
+//! The CLDR data used by `Intl.NumberFormat`.
+//! Version: CLDR 42.0
+//!
+//! This file is generated by `boa_engine/intl_data/generate.py`. Please do not modify it directly.
+
+use super::LocaleData;
+
+pub(in crate::builtins::intl) static NUMBER_FORMAT: LocaleData = LocaleData::new(&[
+    (
+        "de",
+        &[
+            ("accounting", "{-}{0}\u{a0}{}"),
+            ("compact-long/10", "9|{0} {Milliarden}"),
+            ("compact-long/11", "9|{0} {Milliarden}"),
+            ("compact-long/12", "12|one={0} {Billion};other={0} {Billionen}"),
+            ("compact-long/13", "12|{0} {Billionen}"),
+            ("compact-long/14", "12|{0} {Billionen}"),
+            ("compact-long/3", "3|{0} {Tausend}"),
+            ("compact-long/4", "3|{0} {Tausend}"),
+            ("compact-long/5", "3|{0} {Tausend}"),
+            ("compact-long/6", "6|one={0} {Million};other={0} {Millionen}"),
+            ("compact-long/7", "6|{0} {Millionen}"),
+            ("compact-long/8", "6|{0} {Millionen}"),
+            ("compact-long/9", "9|one={0} {Milliarde};other={0} {Milliarden}"),
+            ("compact-short/10", "9|{0}\u{a0}{Mrd.}"),
+            ("compact-short/11", "9|{0}\u{a0}{Mrd.}"),
+            ("compact-short/12", "12|{0}\u{a0}{Bio.}"),
+            ("compact-short/13", "12|{0}\u{a0}{Bio.}"),
+            ("compact-short/14", "12|{0}\u{a0}{Bio.}"),
+            ("compact-short/6", "6|{0}\u{a0}{Mio.}"),
+            ("compact-short/7", "6|{0}\u{a0}{Mio.}"),
+            ("compact-short/8", "6|{0}\u{a0}{Mio.}"),
+            ("compact-short/9", "9|{0}\u{a0}{Mrd.}"),
+            ("currency", "{-}{0}\u{a0}{}"),
+            ("currency-name", "{0} {}"),
+            ("currency/AED", "AED|AED|VAE-Dirham"),
+            ("currency/ARS", "ARS|$|one=Argentinischer Peso;other=Argentinische Pesos"),
+            ("currency/AUD", "AU$|$|one=Australischer Dollar;other=Australische Dollar"),
+            ("currency/BRL", "R$|R$|one=Brasilianischer Real;other=Brasilianische Real"),
+            ("currency/CAD", "CA$|$|one=Kanadischer Dollar;other=Kanadische Dollar"),
+            ("currency/CHF", "CHF|CHF|Schweizer Franken"),
+            ("currency/CLP", "CLP|$|one=Chilenischer Peso;other=Chilenische Pesos"),
+            ("currency/CNY", "CN¥|¥|one=Chinesischer Yuan;other=Renminbi Yuan"),
+            ("currency/COP", "COP|$|one=Kolumbianischer Peso;other=Kolumbianische Pesos"),
+            ("currency/CZK", "CZK|Kč|one=Tschechische Krone;other=Tschechische Kronen"),
+            ("currency/DKK", "DKK|kr|one=Dänische Krone;other=Dänische Kronen"),
+            ("currency/EGP", "EGP|E£|one=Ägyptisches Pfund;other=Ägyptische Pfund"),
+            ("currency/EUR", "€|€|Euro"),
+            ("currency/GBP", "£|£|one=Britisches Pfund;other=Britische Pfund"),
+            ("currency/HKD", "HK$|$|Hongkong-Dollar"),
+            ("currency/HUF", "HUF|Ft|one=Ungarischer Forint;other=Ungarische Forint"),
+            ("currency/IDR", "IDR|Rp|Indonesische Rupiah"),
+            ("currency/ILS", "₪|₪|one=Israelischer Neuer Schekel;other=Israelische Neue Schekel"),
+            ("currency/INR", "₹|₹|one=Indische Rupie;other=Indische Rupien"),
+            ("currency/JPY", "¥|¥|one=Japanischer Yen;other=Japanische Yen"),
+            ("currency/KRW", "₩|₩|one=Südkoreanischer Won;other=Südkoreanische Won"),
+            ("currency/MXN", "MX$|$|one=Mexikanischer Peso;other=Mexikanische Pesos"),
+            ("currency/MYR", "MYR|RM|one=Malaysischer Ringgit;other=Malaysische Ringgit"),
+            ("currency/NOK", "NOK|kr|one=Norwegische Krone;other=Norwegische Kronen"),
+            ("currency/NZD", "NZ$|$|Neuseeland-Dollar"),
+            ("currency/PHP", "PHP|₱|one=Philippinischer Peso;other=Philippinische Pesos"),
+            ("currency/PKR", "PKR|Rs|one=Pakistanische Rupie;other=Pakistanische Rupien"),
+            ("currency/PLN", "PLN|zł|one=Polnischer Złoty;other=Polnische Złoty"),
+            ("currency/RUB", "RUB|₽|one=Russischer Rubel;other=Russische Rubel"),
+            ("currency/SAR", "SAR|SAR|Saudi-Rial"),
+            ("currency/SEK", "SEK|kr|one=Schwedische Krone;other=Schwedische Kronen"),
+            ("currency/SGD", "SGD|$|Singapur-Dollar"),
+            ("currency/THB", "฿|฿|one=Thailändischer Baht;other=Thailändische Baht"),
+            ("currency/TRY", "TRY|₺|Türkische Lira"),
+            ("currency/TWD", "NT$|NT$|one=Neuer Taiwan-Dollar;other=Neue Taiwan-Dollar"),
+            ("currency/UAH", "UAH|₴|one=Ukrainische Hrywnja;other=Ukrainische Hrywen"),
+            ("currency/USD", "$|$|US-Dollar"),
+            ("currency/VND", "₫|₫|one=Vietnamesischer Dong;other=Vietnamesische Dong"),
+            ("currency/ZAR", "ZAR|R|one=Südafrikanischer Rand;other=Südafrikanische Rand"),
+            ("per", "{0}{/}{}|{0}{/}{}|{0}{ pro }{}"),
+            ("per-unit/centimeter", "{0}{/cm}|{0}{/cm}|{0}{ pro Zentimeter}"),
+            ("per-unit/day", "{0}{/T}|{0}{/T}|{0}{ pro Tag}"),
+            ("per-unit/foot", "{0}{/ft}|{0}{/ft}|{0}{ pro Fuß}"),
+            ("per-unit/gallon", "{0}{/gal}|{0}{/gal}|{0}{ pro Gallone}"),
+            ("per-unit/gram", "{0}{/g}|{0}{/g}|{0}{ pro Gramm}"),
+            ("per-unit/hour", "{0}{/h}|{0}{/h}|{0}{ pro Stunde}"),
+            ("per-unit/inch", "{0}{/in}||{0}{ pro Zoll}"),
+            ("per-unit/kilogram", "{0}{/kg}|{0}{/kg}|{0}{ pro Kilogramm}"),
+            ("per-unit/kilometer", "{0}{/km}|{0}{/km}|{0}{ pro Kilometer}"),
+            ("per-unit/liter", "{0}{/l}||{0}{ pro Liter}"),
+            ("per-unit/meter", "{0}{/m}|{0}{/m}|{0}{ pro Meter}"),
+            ("per-unit/minute", "{0}{/min}|{0}{/min}|{0}{ pro Minute}"),
+            ("per-unit/month", "{0}{/M}|{0}{/M}|{0}{ pro Monat}"),
+            ("per-unit/ounce", "{0}{/oz}|{0}{/oz}|{0}{ pro Unze}"),
+            ("per-unit/pound", "{0}{/lb}|{0}{/lb}|{0}{ pro Pfund}"),
+            ("per-unit/second", "{0}{/s}|{0}{/s}|{0}{ pro Sekunde}"),
+            ("per-unit/week", "{0}{/W}|{0}{/W}|{0}{ pro Woche}"),
+            ("per-unit/year", "{0}{/J}|{0}{/J}|{0}{ pro Jahr}"),
+            ("percent", "{-}{0}\u{a0}{%}"),
+            ("unit/acre", "{0} {ac}||one={0} {Acre};other={0} {Acres}"),
+            (
+                "unit/bit",
+                "one={0}\u{a0}{Bit};other={0} {Bit}|{0} {b}|one={0}\u{a0}{Bit};other={0} {Bit}",
+            ),
+            (
+                "unit/byte",
+                "one={0}\u{a0}{Byte};other={0} {Byte}|{0} {B}|one={0}\u{a0}{Byte};other={0} {Byte}",
+            ),
+            ("unit/celsius", "{0} {°C}||{0} {Grad Celsius}"),
+            ("unit/centimeter", "{0} {cm}||{0} {Zentimeter}"),
+            ("unit/day", "{0} {Tg.}|{0} {T}|one={0} {Tag};other={0} {Tage}"),
+            ("unit/degree", "{0}{°}||{0} {Grad}"),
+            ("unit/fahrenheit", "{0} {°F}|{0}{°F}|{0} {Grad Fahrenheit}"),
+            (
+                "unit/fluid-ounce",
+                "{0} {fl oz}||one={0}\u{a0}{Flüssigunze};other={0}\u{a0}{Flüssigunzen}",
+            ),
+            ("unit/foot", "{0} {ft}||{0} {Fuß}"),
+            ("unit/gallon", "{0} {gal}||one={0}\u{a0}{Gallone};other={0}\u{a0}{Gallonen}"),
+            ("unit/gigabit", "{0}\u{a0}{Gb}||one={0}\u{a0}{Gigabit};other={0} {Gigabit}"),
+            ("unit/gigabyte", "{0}\u{a0}{GB}||one={0}\u{a0}{Gigabyte};other={0} {Gigabyte}"),
+            ("unit/gram", "{0} {g}||{0} {Gramm}"),
+            ("unit/hectare", "{0} {ha}||{0} {Hektar}"),
+            ("unit/hour", "{0} {Std.}||one={0} {Stunde};other={0} {Stunden}"),
+            ("unit/inch", "one={0}\u{a0}{in};other={0} {in}||{0} {Zoll}"),
+            ("unit/kilobit", "{0} {kb}||one={0}\u{a0}{Kilobit};other={0} {Kilobit}"),
+            ("unit/kilobyte", "{0} {kB}||one={0}\u{a0}{Kilobyte};other={0} {Kilobyte}"),
+            ("unit/kilogram", "{0} {kg}||{0} {Kilogramm}"),
+            ("unit/kilometer", "{0} {km}||{0} {Kilometer}"),
+            ("unit/kilometer-per-hour", "{0} {km/h}||{0} {Kilometer pro Stunde}"),
+            ("unit/liter", "{0} {l}||{0} {Liter}"),
+            (
+                "unit/liter-per-kilometer",
+                "{0}\u{a0}{l/km}|{0}{l/km}|{0}\u{a0}{Liter pro Kilometer}",
+            ),
+            ("unit/megabit", "{0} {Mb}||one={0}\u{a0}{Megabit};other={0} {Megabit}"),
+            ("unit/megabyte", "{0} {MB}||one={0}\u{a0}{Megabyte};other={0} {Megabyte}"),
+            ("unit/meter", "{0} {m}||{0} {Meter}"),
+            ("unit/meter-per-second", "{0} {m/s}||{0} {Meter pro Sekunde}"),
+            ("unit/microsecond", "{0} {μs}||one={0} {Mikrosekunde};other={0} {Mikrosekunden}"),
+            ("unit/mile", "{0} {mi}||one={0} {Meile};other={0} {Meilen}"),
+            (
+                "unit/mile-per-gallon",
+                "{0} {mpg}|{0}{mpg}|one={0}\u{a0}{Meile pro Gallone};other={0}\u{a0}{Meilen pro Gallone}",
+            ),
+            (
+                "unit/mile-per-hour",
+                "{0} {mi/h}||one={0} {Meile pro Stunde};other={0} {Meilen pro Stunde}",
+            ),
+            (
+                "unit/mile-scandinavian",
+                "{0} {smi}|{0}{smi}|one={0} {skandinavische Meile};other={0} {skandinavische Meilen}",
+            ),
+            ("unit/milliliter", "{0}\u{a0}{ml}||{0}\u{a0}{Milliliter}"),
+            ("unit/millimeter", "{0} {mm}||{0} {Millimeter}"),
+            ("unit/millisecond", "{0} {ms}||one={0} {Millisekunde};other={0} {Millisekunden}"),
+            ("unit/minute", "{0} {Min.}||one={0} {Minute};other={0} {Minuten}"),
+            ("unit/month", "{0} {Mon.}|{0} {M}|one={0} {Monat};other={0} {Monate}"),
+            ("unit/nanosecond", "{0} {ns}||one={0} {Nanosekunde};other={0} {Nanosekunden}"),
+            ("unit/ounce", "{0} {oz}||one={0} {Unze};other={0} {Unzen}"),
+            ("unit/percent", "{0}\u{a0}{%}||{0} {Prozent}"),
+            ("unit/petabyte", "{0} {PB}||{0} {Petabyte}"),
+            ("unit/pound", "{0} {lb}||{0} {Pfund}"),
+            ("unit/second", "{0} {Sek.}||one={0} {Sekunde};other={0} {Sekunden}"),
+            ("unit/stone", "{0} {st}||one={0}\u{a0}{Stone};other={0}\u{a0}{Stones}"),
+            ("unit/terabit", "{0} {Tb}||one={0}\u{a0}{Terabit};other={0} {Terabit}"),
+            ("unit/terabyte", "{0} {TB}||one={0}\u{a0}{Terabyte};other={0} {Terabyte}"),
+            ("unit/week", "{0} {Wo.}|{0} {W}|one={0} {Woche};other={0} {Wochen}"),
+            ("unit/yard", "{0} {yd}||one={0} {Yard};other={0} {Yards}"),
+            ("unit/year", "{0} {J}||one={0} {Jahr};other={0} {Jahre}"),
+        ],
+    ),
+    (
+        "de-AT",
+        &[
+            ("currency", "{-}{}\u{a0}{0}"),
+        ],
+    ),
+    (
+        "de-CH",
+        &[
+            ("currency", "{}{-}{0}"),
+            ("currency/EUR", "EUR|EUR|Euro"),
+            ("per-unit/foot", "{0}{/ft}|{0}{/ft}|{0}{ pro Fuss}"),
+            ("per-unit/gallon", "{0}{/gal}||{0}{ pro Gallone}"),
+            ("percent", "{-}{0}{%}"),
+            ("unit/foot", "{0} {ft}||{0} {Fuss}"),
+            ("unit/percent", "{0}{%}||{0} {Prozent}"),
+        ],
+    ),
+    (
+        "de-LI",
+        &[
+            ("currency", "{-}{}\u{a0}{0}"),
+            ("currency/EUR", "EUR|€|Euro"),
+            ("percent", "{-}{0}{%}"),
+            ("unit/percent", "{0}{%}||{0} {Prozent}"),
+        ],
+    ),
+    (
+        "en",
+        &[
+            ("accounting", "({}{0})"),
+            ("compact-long/10", "9|{0} {billion}"),
+            ("compact-long/11", "9|{0} {billion}"),
+            ("compact-long/12", "12|{0} {trillion}"),
+            ("compact-long/13", "12|{0} {trillion}"),
+            ("compact-long/14", "12|{0} {trillion}"),
+            ("compact-long/3", "3|{0} {thousand}"),
+            ("compact-long/4", "3|{0} {thousand}"),
+            ("compact-long/5", "3|{0} {thousand}"),
+            ("compact-long/6", "6|{0} {million}"),
+            ("compact-long/7", "6|{0} {million}"),
+            ("compact-long/8", "6|{0} {million}"),
+            ("compact-long/9", "9|{0} {billion}"),
+            ("compact-short/10", "9|{0}{B}"),
+            ("compact-short/11", "9|{0}{B}"),
+            ("compact-short/12", "12|{0}{T}"),
+            ("compact-short/13", "12|{0}{T}"),
+            ("compact-short/14", "12|{0}{T}"),
+            ("compact-short/3", "3|{0}{K}"),
+            ("compact-short/4", "3|{0}{K}"),
+            ("compact-short/5", "3|{0}{K}"),
+            ("compact-short/6", "6|{0}{M}"),
+            ("compact-short/7", "6|{0}{M}"),
+            ("compact-short/8", "6|{0}{M}"),
+            ("compact-short/9", "9|{0}{B}"),
+            ("currency", "{-}{}{0}"),
+            ("currency-name", "{0} {}"),
+            ("currency/AED", "AED|AED|one=UAE dirham;other=UAE dirhams"),
+            ("currency/ARS", "ARS|$|one=Argentine peso;other=Argentine pesos"),
+            ("currency/AUD", "A$|$|one=Australian dollar;other=Australian dollars"),
+            ("currency/BRL", "R$|R$|one=Brazilian real;other=Brazilian reals"),
+            ("currency/CAD", "CA$|$|one=Canadian dollar;other=Canadian dollars"),
+            ("currency/CHF", "CHF|CHF|one=Swiss franc;other=Swiss francs"),
+            ("currency/CLP", "CLP|$|one=Chilean peso;other=Chilean pesos"),
+            ("currency/CNY", "CN¥|¥|Chinese yuan"),
+            ("currency/COP", "COP|$|one=Colombian peso;other=Colombian pesos"),
+            ("currency/CZK", "CZK|Kč|one=Czech koruna;other=Czech korunas"),
+            ("currency/DKK", "DKK|kr|one=Danish krone;other=Danish kroner"),
+            ("currency/EGP", "EGP|E£|one=Egyptian pound;other=Egyptian pounds"),
+            ("currency/EUR", "€|€|one=euro;other=euros"),
+            ("currency/GBP", "£|£|one=British pound;other=British pounds"),
+            ("currency/HKD", "HK$|$|one=Hong Kong dollar;other=Hong Kong dollars"),
+            ("currency/HUF", "HUF|Ft|one=Hungarian forint;other=Hungarian forints"),
+            ("currency/IDR", "IDR|Rp|one=Indonesian rupiah;other=Indonesian rupiahs"),
+            ("currency/ILS", "₪|₪|one=Israeli new shekel;other=Israeli new shekels"),
+            ("currency/INR", "₹|₹|one=Indian rupee;other=Indian rupees"),
+            ("currency/JPY", "¥|¥|Japanese yen"),
+            ("currency/KRW", "₩|₩|South Korean won"),
+            ("currency/MXN", "MX$|$|one=Mexican peso;other=Mexican pesos"),
+            ("currency/MYR", "MYR|RM|one=Malaysian ringgit;other=Malaysian ringgits"),
+            ("currency/NOK", "NOK|kr|one=Norwegian krone;other=Norwegian kroner"),
+            ("currency/NZD", "NZ$|$|one=New Zealand dollar;other=New Zealand dollars"),
+            ("currency/PHP", "₱|₱|one=Philippine peso;other=Philippine pesos"),
+            ("currency/PKR", "PKR|Rs|one=Pakistani rupee;other=Pakistani rupees"),
+            ("currency/PLN", "PLN|zł|one=Polish zloty;other=Polish zlotys"),
+            ("currency/RUB", "RUB|₽|one=Russian ruble;other=Russian rubles"),
+            ("currency/SAR", "SAR|SAR|one=Saudi riyal;other=Saudi riyals"),
+            ("currency/SEK", "SEK|kr|one=Swedish krona;other=Swedish kronor"),
+            ("currency/SGD", "SGD|$|one=Singapore dollar;other=Singapore dollars"),
+            ("currency/THB", "THB|฿|Thai baht"),
+            ("currency/TRY", "TRY|₺|one=Turkish lira;other=Turkish Lira"),
+            ("currency/TWD", "NT$|$|one=New Taiwan dollar;other=New Taiwan dollars"),
+            ("currency/UAH", "UAH|₴|one=Ukrainian hryvnia;other=Ukrainian hryvnias"),
+            ("currency/USD", "$|$|one=US dollar;other=US dollars"),
+            ("currency/VND", "₫|₫|Vietnamese dong"),
+            ("currency/ZAR", "ZAR|R|South African rand"),
+            ("per", "{0}{/}{}|{0}{/}{}|{0}{ per }{}"),
+            ("per-unit/centimeter", "{0}{/cm}|{0}{/cm}|{0}{ per centimeter}"),
+            ("per-unit/day", "{0}{/d}|{0}{/d}|{0}{ per day}"),
+            ("per-unit/foot", "{0}{/ft}|{0}{/ft}|{0}{ per foot}"),
+            ("per-unit/gallon", "{0}{/gal US}|{0}{/gal}|{0}{ per gallon}"),
+            ("per-unit/gram", "{0}{/g}|{0}{/g}|{0}{ per gram}"),
+            ("per-unit/hour", "{0}{/h}|{0}{/h}|{0}{ per hour}"),
+            ("per-unit/inch", "{0}{/in}|{0}{/in}|{0}{ per inch}"),
+            ("per-unit/kilogram", "{0}{/kg}|{0}{/kg}|{0}{ per kilogram}"),
+            ("per-unit/kilometer", "{0}{/km}|{0}{/km}|{0}{ per kilometer}"),
+            ("per-unit/liter", "{0}{/L}|{0}{/L}|{0}{ per liter}"),
+            ("per-unit/meter", "{0}{/m}|{0}{/m}|{0}{ per meter}"),
+            ("per-unit/minute", "{0}{/min}|{0}{/min}|{0}{ per minute}"),
+            ("per-unit/month", "{0}{/m}|{0}{/m}|{0}{ per month}"),
+            ("per-unit/ounce", "{0}{/oz}|{0}{/oz}|{0}{ per ounce}"),
+            ("per-unit/pound", "{0}{/lb}|{0}{/lb}|{0}{ per pound}"),
+            ("per-unit/second", "{0}{/s}|{0}{/s}|{0}{ per second}"),
+            ("per-unit/week", "{0}{/w}|{0}{/w}|{0}{ per week}"),
+            ("per-unit/year", "{0}{/y}|{0}{/y}|{0}{ per year}"),
+            ("percent", "{-}{0}{%}"),
+            ("unit/acre", "{0} {ac}|{0}{ac}|one={0} {acre};other={0} {acres}"),
+            ("unit/bit", "{0} {bit}|{0}{bit}|one={0} {bit};other={0} {bits}"),
+            ("unit/byte", "{0} {byte}|{0}{B}|one={0} {byte};other={0} {bytes}"),
+            ("unit/celsius", "{0}{°C}||one={0} {degree Celsius};other={0} {degrees Celsius}"),
+            ("unit/centimeter", "{0} {cm}|{0}{cm}|one={0} {centimeter};other={0} {centimeters}"),
+            ("unit/day", "one={0} {day};other={0} {days}|{0}{d}|one={0} {day};other={0} {days}"),
+            ("unit/degree", "{0} {deg}|{0}{°}|one={0} {degree};other={0} {degrees}"),
+            (
+                "unit/fahrenheit",
+                "{0}{°F}|{0}{°}|one={0} {degree Fahrenheit};other={0} {degrees Fahrenheit}",
+            ),
+            (
+                "unit/fluid-ounce",
+                "{0} {fl oz}|{0}{fl oz}|one={0} {fluid ounce};other={0} {fluid ounces}",
+            ),
+            ("unit/foot", "{0} {ft}|{0}{′}|one={0} {foot};other={0} {feet}"),
+            ("unit/gallon", "{0} {gal}|{0}{gal}|one={0} {gallon};other={0} {gallons}"),
+            ("unit/gigabit", "{0} {Gb}|{0}{Gb}|one={0} {gigabit};other={0} {gigabits}"),
+            ("unit/gigabyte", "{0} {GB}|{0}{GB}|one={0} {gigabyte};other={0} {gigabytes}"),
+            ("unit/gram", "{0} {g}|{0}{g}|one={0} {gram};other={0} {grams}"),
+            ("unit/hectare", "{0} {ha}|{0}{ha}|one={0} {hectare};other={0} {hectares}"),
+            ("unit/hour", "{0} {hr}|{0}{h}|one={0} {hour};other={0} {hours}"),
+            ("unit/inch", "{0} {in}|{0}{″}|one={0} {inch};other={0} {inches}"),
+            ("unit/kilobit", "{0} {kb}|{0}{kb}|one={0} {kilobit};other={0} {kilobits}"),
+            ("unit/kilobyte", "{0} {kB}|{0}{kB}|one={0} {kilobyte};other={0} {kilobytes}"),
+            ("unit/kilogram", "{0} {kg}|{0}{kg}|one={0} {kilogram};other={0} {kilograms}"),
+            ("unit/kilometer", "{0} {km}|{0}{km}|one={0} {kilometer};other={0} {kilometers}"),
+            (
+                "unit/kilometer-per-hour",
+                "{0} {km/h}|{0}{km/h}|one={0} {kilometer per hour};other={0} {kilometers per hour}",
+            ),
+            ("unit/liter", "{0} {L}|{0}{L}|one={0} {liter};other={0} {liters}"),
+            (
+                "unit/liter-per-kilometer",
+                "{0} {L/km}|{0}{L/km}|one={0} {liter per kilometer};other={0} {liters per kilometer}",
+            ),
+            ("unit/megabit", "{0} {Mb}|{0}{Mb}|one={0} {megabit};other={0} {megabits}"),
+            ("unit/megabyte", "{0} {MB}|{0}{MB}|one={0} {megabyte};other={0} {megabytes}"),
+            ("unit/meter", "{0} {m}|{0}{m}|one={0} {meter};other={0} {meters}"),
+            (
+                "unit/meter-per-second",
+                "{0} {m/s}|{0}{m/s}|one={0} {meter per second};other={0} {meters per second}",
+            ),
+            ("unit/microsecond", "{0} {μs}|{0}{μs}|one={0} {microsecond};other={0} {microseconds}"),
+            ("unit/mile", "{0} {mi}|{0}{mi}|one={0} {mile};other={0} {miles}"),
+            (
+                "unit/mile-per-gallon",
+                "{0} {mpg}|{0}{mpg}|one={0} {mile per gallon};other={0} {miles per gallon}",
+            ),
+            (
+                "unit/mile-per-hour",
+                "{0} {mph}|{0}{mph}|one={0} {mile per hour};other={0} {miles per hour}",
+            ),
+            (
+                "unit/mile-scandinavian",
+                "{0} {smi}|{0}{smi}|one={0} {mile-scandinavian};other={0} {miles-scandinavian}",
+            ),
+            ("unit/milliliter", "{0} {mL}|{0}{mL}|one={0} {milliliter};other={0} {milliliters}"),
+            ("unit/millimeter", "{0} {mm}|{0}{mm}|one={0} {millimeter};other={0} {millimeters}"),
+            ("unit/millisecond", "{0} {ms}|{0}{ms}|one={0} {millisecond};other={0} {milliseconds}"),
+            ("unit/minute", "{0} {min}|{0}{m}|one={0} {minute};other={0} {minutes}"),
+            (
+                "unit/month",
+                "one={0} {mth};other={0} {mths}|{0}{m}|one={0} {month};other={0} {months}",
+            ),
+            ("unit/nanosecond", "{0} {ns}|{0}{ns}|one={0} {nanosecond};other={0} {nanoseconds}"),
+            ("unit/ounce", "{0} {oz}|{0}{oz}|one={0} {ounce};other={0} {ounces}"),
+            ("unit/percent", "{0}{%}||{0} {percent}"),
+            ("unit/petabyte", "{0} {PB}|{0}{PB}|one={0} {petabyte};other={0} {petabytes}"),
+            ("unit/pound", "{0} {lb}|{0}{#}|one={0} {pound};other={0} {pounds}"),
+            ("unit/second", "{0} {sec}|{0}{s}|one={0} {second};other={0} {seconds}"),
+            ("unit/stone", "{0} {st}|{0}{st}|one={0} {stone};other={0} {stones}"),
+            ("unit/terabit", "{0} {Tb}|{0}{Tb}|one={0} {terabit};other={0} {terabits}"),
+            ("unit/terabyte", "{0} {TB}|{0}{TB}|one={0} {terabyte};other={0} {terabytes}"),
+            ("unit/week", "one={0} {wk};other={0} {wks}|{0}{w}|one={0} {week};other={0} {weeks}"),
+            ("unit/yard", "{0} {yd}|{0}{yd}|one={0} {yard};other={0} {yards}"),
+            ("unit/year", "one={0} {yr};other={0} {yrs}|{0}{y}|one={0} {year};other={0} {years}"),
+        ],
+    ),
+    (
+        "en-001",
+        &[
+            ("currency/JPY", "JP¥|¥|Japanese yen"),
+            ("currency/RUB", "RUB|₽|one=Russian rouble;other=Russian roubles"),
+            ("currency/USD", "US$|$|one=US dollar;other=US dollars"),
+            ("per-unit/centimeter", "{0}{/cm}||{0}{ per centimetre}"),
+            ("per-unit/day", "{0}{/d}||{0}{ per day}"),
+            ("per-unit/foot", "{0}{/ft}||{0}{ per foot}"),
+            ("per-unit/gallon", "{0}{/US gal}|{0}{/galUS}|{0}{ per US gallon}"),
+            ("per-unit/gram", "{0}{/g}||{0}{ per gram}"),
+            ("per-unit/hour", "{0}{/h}||{0}{ per hour}"),
+            ("per-unit/inch", "{0}{/in}||{0}{ per inch}"),
+            ("per-unit/kilogram", "{0}{/kg}||{0}{ per kilogram}"),
+            ("per-unit/kilometer", "{0}{/km}||{0}{ per kilometre}"),
+            ("per-unit/liter", "{0}{/l}|{0}{/l}|{0}{ per litre}"),
+            ("per-unit/meter", "{0}{/m}||{0}{ per metre}"),
+            ("per-unit/minute", "{0}{/min}||{0}{ per minute}"),
+            ("per-unit/month", "{0}{/m}||{0}{ per month}"),
+            ("per-unit/ounce", "{0}{/oz}||{0}{ per ounce}"),
+            ("per-unit/pound", "{0}{/lb}||{0}{ per pound}"),
+            ("per-unit/second", "{0}{/s}||{0}{ per second}"),
+            ("per-unit/week", "{0}{/w}||{0}{ per week}"),
+            ("per-unit/year", "{0}{/y}||{0}{ per year}"),
+            ("unit/celsius", "{0}{°C}|{0}{°}|one={0} {degree Celsius};other={0} {degrees Celsius}"),
+            ("unit/centimeter", "{0} {cm}|{0}{cm}|one={0} {centimetre};other={0} {centimetres}"),
+            (
+                "unit/fahrenheit",
+                "{0}{°F}||one={0} {degree Fahrenheit};other={0} {degrees Fahrenheit}",
+            ),
+            (
+                "unit/fluid-ounce",
+                "{0} {US fl oz}|{0}{US fl oz}|one={0} {US fluid ounce};other={0} {US fluid ounces}",
+            ),
+            ("unit/gallon", "{0} {US gal}|{0}{galUS}|one={0} {US gallon};other={0} {US gallons}"),
+            ("unit/hour", "one={0} {hr};other={0} {hrs}|{0}{h}|one={0} {hour};other={0} {hours}"),
+            ("unit/kilometer", "{0} {km}|{0}{km}|one={0} {kilometre};other={0} {kilometres}"),
+            (
+                "unit/kilometer-per-hour",
+                "{0} {km/h}|{0}{km/h}|one={0} {kilometre per hour};other={0} {kilometres per hour}",
+            ),
+            ("unit/liter", "{0} {l}|{0}{l}|one={0} {litre};other={0} {litres}"),
+            (
+                "unit/liter-per-kilometer",
+                "{0} {l/km}|{0}{l/km}|one={0} {litre per kilometre};other={0} {litres per kilometre}",
+            ),
+            ("unit/meter", "{0} {m}|{0}{m}|one={0} {metre};other={0} {metres}"),
+            (
+                "unit/meter-per-second",
+                "{0} {m/s}|{0}{m/s}|one={0} {metre per second};other={0} {metres per second}",
+            ),
+            (
+                "unit/mile-per-gallon",
+                "{0} {mpg US}|{0}{mpgUS}|one={0} {mile per US gallon};other={0} {miles per US gallon}",
+            ),
+            (
+                "unit/mile-scandinavian",
+                "{0} {smi}|{0}{smi}|one={0} {Scandinavian mile};other={0} {Scandinavian miles}",
+            ),
+            ("unit/milliliter", "{0} {ml}|{0}{ml}|one={0} {millilitre};other={0} {millilitres}"),
+            ("unit/millimeter", "{0} {mm}|{0}{mm}|one={0} {millimetre};other={0} {millimetres}"),
+            (
+                "unit/minute",
+                "one={0} {min};other={0} {mins}|{0}{m}|one={0} {minute};other={0} {minutes}",
+            ),
+            ("unit/percent", "{0}{%}||{0} {per cent}"),
+            ("unit/pound", "{0} {lb}|{0}{lb}|one={0} {pound};other={0} {pounds}"),
+            (
+                "unit/second",
+                "one={0} {sec};other={0} {secs}|{0}{s}|one={0} {second};other={0} {seconds}",
+            ),
+            ("unit/stone", "{0} {st}|{0}{st}|{0} {stone}"),
+        ],
+    ),
+    (
+        "en-150",
+        &[
+            ("accounting", "{-}{}{0}"),
+            ("currency-name", "€{0} {}"),
+        ],
+    ),
+    (
+        "en-AT",
+        &[
+            ("percent", "{-}{0}\u{a0}{%}"),
+            ("unit/percent", "{0}\u{a0}{%}||{0} {per cent}"),
+        ],
+    ),
+    (
+        "en-AU",
+        &[
+            ("accounting", "({}\u{a0}{0})"),
+            ("currency", "{-}{}\u{a0}{0}"),
+            ("currency/AUD", "$|$|one=Australian dollar;other=Australian dollars"),
+            ("currency/BRL", "BRL|R$|one=Brazilian real;other=Brazilian reals"),
+            ("currency/CAD", "CAD|$|one=Canadian dollar;other=Canadian dollars"),
+            ("currency/CNY", "CNY|¥|Chinese yuan"),
+            ("currency/EGP", "EGP|£|one=Egyptian pound;other=Egyptian pounds"),
+            ("currency/EUR", "EUR|€|euro"),
+            ("currency/GBP", "GBP|£|one=British pound;other=British pounds"),
+            ("currency/HKD", "HKD|$|one=Hong Kong dollar;other=Hong Kong dollars"),
+            ("currency/ILS", "ILS|₪|one=Israeli shekel;other=Israeli sheckles"),
+            ("currency/INR", "INR|₹|one=Indian rupee;other=Indian rupees"),
+            ("currency/JPY", "JPY|¥|Japanese yen"),
+            ("currency/KRW", "KRW|₩|South Korean won"),
+            ("currency/MXN", "MXN|$|one=Mexican peso;other=Mexican pesos"),
+            ("currency/NZD", "NZD|$|one=New Zealand dollar;other=New Zealand dollars"),
+            ("currency/PHP", "PHP|₱|one=Philippine peso;other=Philippine pesos"),
+            ("currency/SEK", "SEK|Kr|one=Swedish krona;other=Swedish kronor"),
+            ("currency/TRY", "TRY|₺|one=Turkish lira;other=Turkish lire"),
+            ("currency/TWD", "TWD|$|one=New Taiwan dollar;other=New Taiwan dollars"),
+            ("currency/USD", "USD|$|one=US dollar;other=US dollars"),
+            ("currency/VND", "VND|₫|one=Vietnamese dong;other=Vietnamese dongs"),
+            ("per-unit/gallon", "{0}{/gal US}|{0}{/galUS}|{0}{ per US gallon}"),
+            ("per-unit/liter", "{0}{/L}|{0}{/L}|{0}{ per litre}"),
+            ("per-unit/minute", "{0}{/min.}|{0}{/min.}|{0}{ per minute}"),
+            ("per-unit/second", "{0}{ ps.}|{0}{ ps.}|{0}{ per second}"),
+            ("unit/celsius", "{0}{°C}||one={0} {degree Celsius};other={0} {degrees Celsius}"),
+            (
+                "unit/fluid-ounce",
+                "{0} {fl oz}|{0}{fl oz}|one={0} {fluid ounce};other={0} {fluid ounces}",
+            ),
+            ("unit/gallon", "{0} {gal US}||one={0} {US gallon};other={0} {US gallons}"),
+            (
+                "unit/kilometer-per-hour",
+                "{0} {km/h}||one={0} {kilometre per hour};other={0} {kilometres per hour}",
+            ),
+            ("unit/liter", "{0} {L}|{0}{L}|one={0} {litre};other={0} {litres}"),
+            (
+                "unit/liter-per-kilometer",
+                "{0} {L/km}|{0}{L/km}|one={0} {litre per kilometre};other={0} {litres per kilometre}",
+            ),
+            (
+                "unit/meter-per-second",
+                "{0} {m/s.}|{0}{m/s}|one={0} {metre per second};other={0} {metres per second}",
+            ),
+            (
+                "unit/mile-per-gallon",
+                "{0} {m.p.g. US}|{0}{mpgUS}|one={0} {mile per US gallon};other={0} {miles per US gallon}",
+            ),
+            ("unit/milliliter", "{0} {mL}|{0}{mL}|one={0} {millilitre};other={0} {millilitres}"),
+            (
+                "unit/minute",
+                "one={0} {min.};other={0} {mins}|{0}{min.}|one={0} {minute};other={0} {minutes}",
+            ),
+            (
+                "unit/second",
+                "one={0} {sec.};other={0} {secs}|{0}{s.}|one={0} {second};other={0} {seconds}",
+            ),
+        ],
+    ),
+    (
+        "en-BS",
+        &[
+            ("unit/celsius", "{0}{°C}||one={0} {degree Celsius};other={0} {degrees Celsius}"),
+            (
+                "unit/fahrenheit",
+                "{0}{°F}|{0}{°}|one={0} {degree Fahrenheit};other={0} {degrees Fahrenheit}",
+            ),
+        ],
+    ),
+    (
+        "en-BZ",
+        &[
+            ("unit/celsius", "{0}{°C}||one={0} {degree Celsius};other={0} {degrees Celsius}"),
+            (
+                "unit/fahrenheit",
+                "{0}{°F}|{0}{°}|one={0} {degree Fahrenheit};other={0} {degrees Fahrenheit}",
+            ),
+        ],
+    ),
+    (
+        "en-CA",
+        &[
+            ("currency/CAD", "$|$|one=Canadian dollar;other=Canadian dollars"),
+            ("currency/JPY", "JP¥|¥|Japanese yen"),
+            ("currency/RUB", "RUB|₽|one=Russian rouble;other=Russian roubles"),
+            ("currency/USD", "US$|US$|one=US dollar;other=US dollars"),
+            ("per-unit/centimeter", "{0}{/cm}||{0}{ per centimetre}"),
+            ("per-unit/day", "{0}{/d}||{0}{ per day}"),
+            ("per-unit/foot", "{0}{/ft}||{0}{ per foot}"),
+            ("per-unit/gallon", "{0}{/US gal}|{0}{/USgal}|{0}{ per US gallon}"),
+            ("per-unit/gram", "{0}{/g}||{0}{ per gram}"),
+            ("per-unit/hour", "{0}{/hr}|{0}{/hr}|{0}{ per hour}"),
+            ("per-unit/inch", "{0}{/in}||{0}{ per inch}"),
+            ("per-unit/kilogram", "{0}{/kg}||{0}{ per kilogram}"),
+            ("per-unit/kilometer", "{0}{/km}||{0}{ per kilometre}"),
+            ("per-unit/liter", "{0}{/L}|{0}{/L}|{0}{ per litre}"),
+            ("per-unit/meter", "{0}{/m}||{0}{ per metre}"),
+            ("per-unit/minute", "{0}{/min}||{0}{ per minute}"),
+            ("per-unit/month", "{0}{/mo}|{0}{/mo}|{0}{ per month}"),
+            ("per-unit/ounce", "{0}{/oz}||{0}{ per ounce}"),
+            ("per-unit/pound", "{0}{/lb}||{0}{ per pound}"),
+            ("per-unit/second", "{0}{/sec}|{0}{/sec}|{0}{ per second}"),
+            ("per-unit/week", "{0}{/wk}|{0}{/wk}|{0}{ per week}"),
+            ("per-unit/year", "{0}{/yr}|{0}{/yr}|{0}{ per year}"),
+            (
+                "unit/bit",
+                "one={0} {bit};other={0} {bits}|one={0}{bit};other={0}{bits}|one={0} {bit};other={0} {bits}",
+            ),
+            (
+                "unit/byte",
+                "one={0} {byte};other={0} {bytes}|{0}{B}|one={0} {byte};other={0} {bytes}",
+            ),
+            (
+                "unit/celsius",
+                "{0} {°C}|{0}{°}|one={0} {degree Celsius};other={0} {degrees Celsius}",
+            ),
+            ("unit/centimeter", "{0} {cm}|{0}{cm}|one={0} {centimetre};other={0} {centimetres}"),
+            (
+                "unit/fahrenheit",
+                "{0} {°F}|{0}{°F}|one={0} {degree Fahrenheit};other={0} {degrees Fahrenheit}",
+            ),
+            (
+                "unit/fluid-ounce",
+                "{0} {US fl oz}|{0}{US fl oz}|one={0} {US fluid ounce};other={0} {US fluid ounces}",
+            ),
+            ("unit/gallon", "{0} {US gal}|{0}{USgal}|one={0} {US gallon};other={0} {US gallons}"),
+            ("unit/hour", "one={0} {hr};other={0} {hrs}|{0}{h}|one={0} {hour};other={0} {hours}"),
+            ("unit/kilometer", "{0} {km}|{0}{km}|one={0} {kilometre};other={0} {kilometres}"),
+            (
+                "unit/kilometer-per-hour",
+                "{0} {km/h}|{0}{km/h}|one={0} {kilometre per hour};other={0} {kilometres per hour}",
+            ),
+            ("unit/liter", "{0} {L}|{0}{L}|one={0} {litre};other={0} {litres}"),
+            (
+                "unit/liter-per-kilometer",
+                "{0} {L/km}|{0}{L/km}|one={0} {litre per kilometre};other={0} {litres per kilometre}",
+            ),
+            ("unit/meter", "{0} {m}|{0}{m}|one={0} {metre};other={0} {metres}"),
+            (
+                "unit/meter-per-second",
+                "{0} {m/s}|{0}{m/s}|one={0} {metre per second};other={0} {metres per second}",
+            ),
+            (
+                "unit/microsecond",
+                "one={0} {μsec};other={0} {μsecs}|{0}{μs}|one={0} {microsecond};other={0} {microseconds}",
+            ),
+            (
+                "unit/mile-per-gallon",
+                "{0} {mpg US}|{0}{mpgUS}|one={0} {mile per US gallon};other={0} {miles per US gallon}",
+            ),
+            (
+                "unit/mile-scandinavian",
+                "{0} {smi}|{0}{smi}|one={0} {Scandinavian mile};other={0} {Scandinavian miles}",
+            ),
+            ("unit/milliliter", "{0} {mL}|{0}{mL}|one={0} {millilitre};other={0} {millilitres}"),
+            ("unit/millimeter", "{0} {mm}|{0}{mm}|one={0} {millimetre};other={0} {millimetres}"),
+            (
+                "unit/millisecond",
+                "one={0} {millisec};other={0} {millisecs}|{0}{ms}|one={0} {millisecond};other={0} {milliseconds}",
+            ),
+            (
+                "unit/minute",
+                "one={0} {min};other={0} {mins}|{0}{min}|one={0} {minute};other={0} {minutes}",
+            ),
+            (
+                "unit/month",
+                "one={0} {mo};other={0} {mos}|{0}{m}|one={0} {month};other={0} {months}",
+            ),
+            (
+                "unit/nanosecond",
+                "one={0} {nanosec};other={0} {nanosecs}|{0}{ns}|one={0} {nanosecond};other={0} {nanoseconds}",
+            ),
+            ("unit/pound", "{0} {lb}|{0}{lb}|one={0} {pound};other={0} {pounds}"),
+            (
+                "unit/second",
+                "one={0} {sec};other={0} {secs}|{0}{s}|one={0} {second};other={0} {seconds}",
+            ),
+            ("unit/stone", "{0} {st}|{0}{st}|{0} {stone}"),
+        ],
+    ),
+    (
+        "en-CC",
+        &[
+            ("currency/AUD", "$|$|one=Australian dollar;other=Australian dollars"),
+        ],
+    ),
+    (
+        "en-CK",
+        &[
+            ("currency/NZD", "$|$|one=New Zealand dollar;other=New Zealand dollars"),
+        ],
+    ),
+    (
+        "en-CX",
+        &[
+            ("currency/AUD", "$|$|one=Australian dollar;other=Australian dollars"),
+        ],
+    ),
+    (
+        "en-DE",
+        &[
+            ("percent", "{-}{0}\u{a0}{%}"),
+            ("unit/percent", "{0}\u{a0}{%}||{0} {per cent}"),
+        ],
+    ),
+    (
+        "en-DK",
+        &[
+            ("currency/DKK", "kr.|kr|one=Danish krone;other=Danish kroner"),
+            ("percent", "{-}{0}\u{a0}{%}"),
+            ("unit/percent", "{0}\u{a0}{%}||{0} {per cent}"),
+        ],
+    ),
+    (
+        "en-FI",
+        &[
+            ("percent", "{-}{0}\u{a0}{%}"),
+            ("unit/percent", "{0}\u{a0}{%}||{0} {per cent}"),
+        ],
+    ),
+    (
+        "en-FK",
+        &[
+            ("currency/GBP", "GB£|£|one=British pound;other=British pounds"),
+        ],
+    ),
+    (
+        "en-GG",
+        &[
+            ("currency/GBP", "£|£|one=UK pound;other=UK pounds"),
+        ],
+    ),
+    (
+        "en-GI",
+        &[
+            ("currency/GBP", "GB£|£|one=British pound;other=British pounds"),
+        ],
+    ),
+    (
+        "en-IM",
+        &[
+            ("currency/GBP", "£|£|one=UK pound;other=UK pounds"),
+        ],
+    ),
+    (
+        "en-IN",
+        &[
+            ("compact-short/10", "10|{0}{TCr}"),
+            ("compact-short/11", "10|{0}{TCr}"),
+            ("compact-short/12", "12|{0}{LCr}"),
+            ("compact-short/13", "12|{0}{LCr}"),
+            ("compact-short/14", "12|{0}{LCr}"),
+            ("compact-short/3", "3|{0}{T}"),
+            ("compact-short/4", "3|{0}{T}"),
+            ("compact-short/5", "5|{0}{L}"),
+            ("compact-short/6", "5|{0}{L}"),
+            ("compact-short/7", "7|{0}{Cr}"),
+            ("compact-short/8", "7|{0}{Cr}"),
+            ("compact-short/9", "7|{0}{Cr}"),
+            ("currency/USD", "$|$|one=US dollar;other=US dollars"),
+            (
+                "unit/kilometer-per-hour",
+                "{0} {kph}|{0}{kph}|one={0} {kilometre per hour};other={0} {kilometres per hour}",
+            ),
+        ],
+    ),
+    (
+        "en-JE",
+        &[
+            ("currency/GBP", "£|£|one=UK pound;other=UK pounds"),
+        ],
+    ),
+    (
+        "en-KI",
+        &[
+            ("currency/AUD", "$|$|one=Australian dollar;other=Australian dollars"),
+        ],
+    ),
+    (
+        "en-KY",
+        &[
+            ("unit/celsius", "{0}{°C}||one={0} {degree Celsius};other={0} {degrees Celsius}"),
+            (
+                "unit/fahrenheit",
+                "{0}{°F}|{0}{°}|one={0} {degree Fahrenheit};other={0} {degrees Fahrenheit}",
+            ),
+        ],
+    ),
+    (
+        "en-LS",
+        &[
+            ("currency/ZAR", "R|R|South African rand"),
+        ],
+    ),
+    (
+        "en-MT",
+        &[
+            ("currency/GBP", "GB£|£|one=British pound;other=British pounds"),
+        ],
+    ),
+    (
+        "en-MV",
+        &[
+            ("accounting", "{-}{}\u{a0}{0}"),
+            ("currency", "{-}{}\u{a0}{0}"),
+        ],
+    ),
+    (
+        "en-MY",
+        &[
+            ("currency/MYR", "RM|RM|one=Malaysian ringgit;other=Malaysian ringgits"),
+        ],
+    ),
+    (
+        "en-NF",
+        &[
+            ("currency/AUD", "$|$|one=Australian dollar;other=Australian dollars"),
+        ],
+    ),
+    (
+        "en-NR",
+        &[
+            ("currency/AUD", "$|$|one=Australian dollar;other=Australian dollars"),
+        ],
+    ),
+    (
+        "en-NU",
+        &[
+            ("currency/NZD", "$|$|one=New Zealand dollar;other=New Zealand dollars"),
+        ],
+    ),
+    (
+        "en-NZ",
+        &[
+            ("currency/NZD", "$|$|one=New Zealand dollar;other=New Zealand dollars"),
+        ],
+    ),
+    (
+        "en-PK",
+        &[
+            ("currency/PKR", "Rs|Rs|one=Pakistani rupee;other=Pakistani rupees"),
+        ],
+    ),
+    (
+        "en-PN",
+        &[
+            ("currency/NZD", "$|$|one=New Zealand dollar;other=New Zealand dollars"),
+        ],
+    ),
+    (
+        "en-PW",
+        &[
+            ("unit/celsius", "{0}{°C}||one={0} {degree Celsius};other={0} {degrees Celsius}"),
+            (
+                "unit/fahrenheit",
+                "{0}{°F}|{0}{°}|one={0} {degree Fahrenheit};other={0} {degrees Fahrenheit}",
+            ),
+        ],
+    ),
+    (
+        "en-SE",
+        &[
+            ("currency/SEK", "kr|kr|one=Swedish krona;other=Swedish kronor"),
+            ("percent", "{-}{0}\u{a0}{%}"),
+            ("unit/percent", "{0}\u{a0}{%}||{0} {per cent}"),
+        ],
+    ),
+    (
+        "en-SG",
+        &[
+            ("currency/SGD", "$|$|one=Singapore dollar;other=Singapore dollars"),
+        ],
+    ),
+    (
+        "en-SH",
+        &[
+            ("currency/GBP", "GB£|£|one=British pound;other=British pounds"),
+        ],
+    ),
+    (
+        "en-SS",
+        &[
+            ("currency/GBP", "GB£|£|one=British pound;other=British pounds"),
+        ],
+    ),
+    (
+        "en-TK",
+        &[
+            ("currency/NZD", "$|$|one=New Zealand dollar;other=New Zealand dollars"),
+        ],
+    ),
+    (
+        "en-TV",
+        &[
+            ("currency/AUD", "$|$|one=Australian dollar;other=Australian dollars"),
+        ],
+    ),
+    (
+        "en-ZA",
+        &[
+            ("currency/ZAR", "R|R|South African rand"),
+        ],
+    ),
+    (
+        "es",
+        &[
+            ("accounting", "{-}{0}\u{a0}{}"),
+            ("compact-long/10", "9|{0} {mil millones}"),
+            ("compact-long/11", "9|{0} {mil millones}"),
+            ("compact-long/12", "12|one={0} {billón};other={0} {billones}"),
+            ("compact-long/13", "12|{0} {billones}"),
+            ("compact-long/14", "12|{0} {billones}"),
+            ("compact-long/3", "3|{0} {mil}"),
+            ("compact-long/4", "3|{0} {mil}"),
+            ("compact-long/5", "3|{0} {mil}"),
+            ("compact-long/6", "6|one={0} {millón};other={0} {millones}"),
+            ("compact-long/7", "6|{0} {millones}"),
+            ("compact-long/8", "6|{0} {millones}"),
+            ("compact-long/9", "9|{0} {mil millones}"),
+            ("compact-short/10", "9|{0}\u{a0}{mil\u{a0}M}"),
+            ("compact-short/11", "9|{0}\u{a0}{mil\u{a0}M}"),
+            ("compact-short/12", "12|{0}\u{a0}{B}"),
+            ("compact-short/13", "12|{0}\u{a0}{B}"),
+            ("compact-short/14", "12|{0}\u{a0}{B}"),
+            ("compact-short/3", "3|{0}\u{a0}{mil}"),
+            ("compact-short/4", "3|{0}\u{a0}{mil}"),
+            ("compact-short/5", "3|{0}\u{a0}{mil}"),
+            ("compact-short/6", "6|{0}\u{a0}{M}"),
+            ("compact-short/7", "6|{0}\u{a0}{M}"),
+            ("compact-short/8", "6|{0}\u{a0}{M}"),
+            ("compact-short/9", "6|{0}\u{a0}{M}"),
+            ("currency", "{-}{0}\u{a0}{}"),
+            ("currency-name", "{0} {}"),
+            (
+                "currency/AED",
+                "AED|AED|one=dírham de los Emiratos Árabes Unidos;other=dírhams de los Emiratos Árabes Unidos",
+            ),
+            ("currency/ARS", "ARS|$|one=peso argentino;other=pesos argentinos"),
+            ("currency/AUD", "AUD|$|one=dólar australiano;other=dólares australianos"),
+            ("currency/BRL", "BRL|R$|one=real brasileño;other=reales brasileños"),
+            ("currency/CAD", "CAD|$|one=dólar canadiense;other=dólares canadienses"),
+            ("currency/CHF", "CHF|CHF|one=franco suizo;other=francos suizos"),
+            ("currency/CLP", "CLP|$|one=peso chileno;other=pesos chilenos"),
+            ("currency/CNY", "CNY|¥|one=yuan;other=yuanes"),
+            ("currency/COP", "COP|$|one=peso colombiano;other=pesos colombianos"),
+            ("currency/CZK", "CZK|Kč|one=corona checa;other=coronas checas"),
+            ("currency/DKK", "DKK|kr|one=corona danesa;other=coronas danesas"),
+            ("currency/EGP", "EGP|EGP|one=libra egipcia;other=libras egipcias"),
+            ("currency/EUR", "€|€|one=euro;other=euros"),
+            ("currency/GBP", "GBP|£|one=libra esterlina;other=libras esterlinas"),
+            ("currency/HKD", "HKD|$|one=dólar hongkonés;other=dólares hongkoneses"),
+            ("currency/HUF", "HUF|Ft|one=forinto húngaro;other=forintos húngaros"),
+            ("currency/IDR", "IDR|Rp|one=rupia indonesia;other=rupias indonesias"),
+            ("currency/ILS", "ILS|₪|one=nuevo séquel israelí;other=nuevos séqueles israelíes"),
+            ("currency/INR", "INR|₹|one=rupia india;other=rupias indias"),
+            ("currency/JPY", "JPY|¥|one=yen;other=yenes"),
+            ("currency/KRW", "KRW|₩|one=won surcoreano;other=wons surcoreanos"),
+            ("currency/MXN", "MXN|$|one=peso mexicano;other=pesos mexicanos"),
+            ("currency/MYR", "MYR|RM|one=ringit;other=ringits"),
+            ("currency/NOK", "NOK|kr|one=corona noruega;other=coronas noruegas"),
+            ("currency/NZD", "NZD|$|one=dólar neozelandés;other=dólares neozelandeses"),
+            ("currency/PHP", "PHP|₱|one=peso filipino;other=pesos filipinos"),
+            ("currency/PKR", "PKR|Rs|one=rupia pakistaní;other=rupias pakistaníes"),
+            ("currency/PLN", "PLN|zł|one=esloti;other=eslotis"),
+            ("currency/RUB", "RUB|₽|one=rublo ruso;other=rublos rusos"),
+            ("currency/SAR", "SAR|SAR|one=rial saudí;other=riales saudíes"),
+            ("currency/SEK", "SEK|kr|one=corona sueca;other=coronas suecas"),
+            ("currency/SGD", "SGD|$|one=dólar singapurense;other=dólares singapurenses"),
+            ("currency/THB", "฿|฿|one=bat;other=bats"),
+            ("currency/TRY", "TRY|₺|one=lira turca;other=liras turcas"),
+            ("currency/TWD", "TWD|NT$|one=nuevo dólar taiwanés;other=nuevos dólares taiwaneses"),
+            ("currency/UAH", "UAH|₴|one=grivna;other=grivnas"),
+            ("currency/USD", "US$|$|one=dólar estadounidense;other=dólares estadounidenses"),
+            ("currency/VND", "₫|₫|one=dong;other=dongs"),
+            ("currency/ZAR", "ZAR|R|one=rand;other=rands"),
+            ("per", "{0}{/}{}|{0}{/}{}|{0}{ por }{}"),
+            ("per-unit/centimeter", "{0}{/cm}|{0}{/cm}|{0}{ por centímetro}"),
+            ("per-unit/day", "{0}{/d}|{0}{/d}|{0}{ por día}"),
+            ("per-unit/foot", "{0}{/ft}|{0}{/ft}|{0}{ por pie}"),
+            ("per-unit/gallon", "{0}{/gal}|{0}{/gal}|{0}{ por galón}"),
+            ("per-unit/gram", "{0}{/g}|{0}{/g}|{0}{ por gramo}"),
+            ("per-unit/hour", "{0}{/h}|{0}{/h}|{0}{ por hora}"),
+            ("per-unit/inch", "{0}{/in}|{0}{/in}|{0}{ por pulgada}"),
+            ("per-unit/kilogram", "{0}{/kg}|{0}{/kg}|{0}{ por kilogramo}"),
+            ("per-unit/kilometer", "{0}{/km}|{0}{/km}|{0}{ por kilómetro}"),
+            ("per-unit/liter", "{0}{/l}|{0}{/l}|{0}{ por litro}"),
+            ("per-unit/meter", "{0}{/m}|{0}{/m}|{0}{ por metro}"),
+            ("per-unit/minute", "{0}{/min}|{0}{/min}|{0}{ por minuto}"),
+            ("per-unit/month", "{0}{/m.}|{0}{/m}|{0}{ por mes}"),
+            ("per-unit/ounce", "{0}{/oz}|{0}{/oz}|{0}{ por onza}"),
+            ("per-unit/pound", "{0}{/lb}|{0}{/lb}|{0}{ por libra}"),
+            ("per-unit/second", "{0}{/s}|{0}{/s}|{0}{ por segundo}"),
+            ("per-unit/week", "{0}{/sem.}|{0}{/sem}|{0}{ por semana}"),
+            ("per-unit/year", "{0}{/a}|{0}{/a}|{0}{ por año}"),
+            ("percent", "{-}{0}\u{a0}{%}"),
+            ("unit/acre", "{0} {ac}|{0}{ac}|one={0} {acre};other={0} {acres}"),
+            ("unit/bit", "{0} {b}|{0}{b}|one={0} {bit};other={0} {bits}"),
+            ("unit/byte", "{0} {B}|{0}{B}|one={0} {byte};other={0} {bytes}"),
+            ("unit/celsius", "{0} {°C}|{0}{°C}|one={0} {grado Celsius};other={0} {grados Celsius}"),
+            ("unit/centimeter", "{0} {cm}|{0}{cm}|one={0} {centímetro};other={0} {centímetros}"),
+            ("unit/day", "{0} {d}|{0}{d}|one={0} {día};other={0} {días}"),
+            ("unit/degree", "{0}{°}||one={0} {grado};other={0} {grados}"),
+            (
+                "unit/fahrenheit",
+                "{0} {°F}|{0}{°F}|one={0} {grado Fahrenheit};other={0} {grados Fahrenheit}",
+            ),
+            (
+                "unit/fluid-ounce",
+                "{0} {fl oz}|{0}{fl oz}|one={0} {onza líquida};other={0} {onzas líquidas}",
+            ),
+            ("unit/foot", "{0} {ft}|{0}{ft}|one={0} {pie};other={0} {pies}"),
+            ("unit/gallon", "{0} {gal}|{0}{gal}|one={0} {galón};other={0} {galones}"),
+            ("unit/gigabit", "{0} {Gb}|{0}{Gb}|one={0} {gigabit};other={0} {gigabits}"),
+            ("unit/gigabyte", "{0} {GB}|{0}{GB}|one={0} {gigabyte};other={0} {gigabytes}"),
+            ("unit/gram", "{0} {g}|{0}{g}|one={0} {gramo};other={0} {gramos}"),
+            ("unit/hectare", "{0} {ha}|{0}{ha}|one={0} {hectárea};other={0} {hectáreas}"),
+            ("unit/hour", "{0} {h}|{0}{h}|one={0} {hora};other={0} {horas}"),
+            ("unit/inch", "{0} {in}|{0}{in}|one={0} {pulgada};other={0} {pulgadas}"),
+            ("unit/kilobit", "{0} {kb}|{0}{kb}|one={0} {kilobit};other={0} {kilobits}"),
+            ("unit/kilobyte", "{0} {kB}|{0}{kB}|one={0} {kilobyte};other={0} {kilobytes}"),
+            ("unit/kilogram", "{0} {kg}|{0}{kg}|one={0} {kilogramo};other={0} {kilogramos}"),
+            ("unit/kilometer", "{0} {km}|{0}{km}|one={0} {kilómetro};other={0} {kilómetros}"),
+            (
+                "unit/kilometer-per-hour",
+                "{0} {km/h}|{0}{km/h}|one={0} {kilómetro por hora};other={0} {kilómetros por hora}",
+            ),
+            ("unit/liter", "{0} {l}|{0}{l}|one={0} {litro};other={0} {litros}"),
+            (
+                "unit/liter-per-kilometer",
+                "{0} {l/km}|{0}{l/km}|one={0} {litro por kilómetro};other={0} {litros por kilómetro}",
+            ),
+            ("unit/megabit", "{0} {Mb}|{0}{Mb}|one={0} {megabit};other={0} {megabits}"),
+            ("unit/megabyte", "{0} {MB}|{0}{MB}|one={0} {megabyte};other={0} {megabytes}"),
+            ("unit/meter", "{0} {m}|{0}{m}|one={0} {metro};other={0} {metros}"),
+            (
+                "unit/meter-per-second",
+                "{0} {m/s}|{0}{m/s}|one={0} {metro por segundo};other={0} {metros por segundo}",
+            ),
+            (
+                "unit/microsecond",
+                "{0} {μs}|{0}{μs}|one={0} {microsegundo};other={0} {microsegundos}",
+            ),
+            ("unit/mile", "{0} {mi}|{0}{mi}|one={0} {milla};other={0} {millas}"),
+            (
+                "unit/mile-per-gallon",
+                "{0} {mi/gal}|{0}{mi/gal}|one={0} {milla por galón};other={0} {millas por galón}",
+            ),
+            (
+                "unit/mile-per-hour",
+                "{0} {mi/h}|{0}{mi/h}|one={0} {milla por hora};other={0} {millas por hora}",
+            ),
+            (
+                "unit/mile-scandinavian",
+                "{0} {mi esc.}|{0}{mi esc}|one={0} {milla escandinava};other={0} {millas escandinavas}",
+            ),
+            ("unit/milliliter", "{0} {ml}||one={0} {mililitro};other={0} {mililitros}"),
+            ("unit/millimeter", "{0} {mm}|{0}{mm}|one={0} {milímetro};other={0} {milímetros}"),
+            ("unit/millisecond", "{0} {ms}|{0}{ms}|one={0} {milisegundo};other={0} {milisegundos}"),
+            ("unit/minute", "{0} {min}|{0}{min}|one={0} {minuto};other={0} {minutos}"),
+            ("unit/month", "{0} {m.}|{0}{m}|one={0} {mes};other={0} {meses}"),
+            ("unit/nanosecond", "{0} {ns}|{0}{ns}|one={0} {nanosegundo};other={0} {nanosegundos}"),
+            ("unit/ounce", "{0} {oz}|{0}{oz}|one={0} {onza};other={0} {onzas}"),
+            ("unit/percent", "{0}\u{a0}{%}||{0} {por ciento}"),
+            ("unit/petabyte", "{0} {PB}|{0}{PB}|one={0} {petabyte};other={0} {petabytes}"),
+            ("unit/pound", "{0} {lb}|{0}{lb}|one={0} {libra};other={0} {libras}"),
+            ("unit/second", "{0} {s}|{0}{s}|one={0} {segundo};other={0} {segundos}"),
+            ("unit/stone", "{0} {st}|{0}{st}|one={0} {stone};other={0} {stones}"),
+            ("unit/terabit", "{0} {Tb}|{0}{Tb}|one={0} {terabit};other={0} {terabits}"),
+            ("unit/terabyte", "{0} {TB}|{0}{TB}|one={0} {terabyte};other={0} {terabytes}"),
+            ("unit/week", "{0} {sem.}|{0}{sem}|one={0} {semana};other={0} {semanas}"),
+            ("unit/yard", "{0} {yd}|{0}{yd}|one={0} {yarda};other={0} {yardas}"),
+            ("unit/year", "{0} {a}|{0}{a}|one={0} {año};other={0} {años}"),
+        ],
+    ),
+    (
+        "es-419",
+        &[
+            ("accounting", "{-}{}\u{a0}{0}"),
+            ("compact-long/12", "12|{0} {billón}"),
+            ("compact-short/3", "3|{0}\u{a0}{K}"),
+            ("compact-short/4", "3|{0}\u{a0}{k}"),
+            ("compact-short/5", "3|{0}\u{a0}{k}"),
+            ("currency", "{-}{}\u{a0}{0}"),
+            ("currency/EGP", "EGP|E£|one=libra egipcia;other=libras egipcias"),
+            ("currency/EUR", "EUR|€|one=euro;other=euros"),
+            ("currency/THB", "THB|฿|one=baht tailandes;other=bahts tailandeses"),
+            ("currency/USD", "USD|$|one=dólar estadounidense;other=dólares estadounidenses"),
+            ("currency/VND", "VND|₫|one=dong;other=dongs"),
+            ("per-unit/day", "{0}{/d.}|{0}{/d.}|{0}{ por día}"),
+            ("per-unit/gallon", "{0}{/gal}||{0}{ por galón}"),
+            ("per-unit/liter", "{0}{/l}||{0}{ por litro}"),
+            ("per-unit/month", "{0}{/m.}|{0}{/m.}|{0}{ por mes}"),
+            ("per-unit/week", "{0}{/sem.}|{0}{/sem.}|{0}{ por semana}"),
+            ("per-unit/year", "{0}{/a.}|{0}{/a}|{0}{ por año}"),
+            (
+                "unit/day",
+                "one={0} {d.};other={0} {dd.}|one={0}{d.};other={0}{dd.}|one={0} {día};other={0} {días}",
+            ),
+            (
+                "unit/fluid-ounce",
+                "{0} {fl oz}|{0}{fl oz}|one={0} {onza fluida};other={0} {onzas fluidas}",
+            ),
+            (
+                "unit/mile-scandinavian",
+                "{0} {mi esc.}|{0}{mi esc.}|one={0} {milla escandinava};other={0} {millas escandinavas}",
+            ),
+            ("unit/milliliter", "{0} {ml}|{0}{ml}|one={0} {mililitro};other={0} {mililitros}"),
+            ("unit/minute", "{0} {min}|{0}{m}|one={0} {minuto};other={0} {minutos}"),
+            (
+                "unit/month",
+                "one={0} {m.};other={0} {mm.}|one={0}{m.};other={0}{mm.}|one={0} {mes};other={0} {meses}",
+            ),
+            (
+                "unit/week",
+                "one={0} {sem.};other={0} {sems.}|one={0}{sem.};other={0}{sems.}|one={0} {semana};other={0} {semanas}",
+            ),
+            (
+                "unit/year",
+                "one={0} {a.};other={0} {aa.}|one={0}{a.};other={0}{aa.}|one={0} {año};other={0} {años}",
+            ),
+        ],
+    ),
+    (
+        "es-AR",
+        &[
+            ("accounting", "({}\u{a0}{0})"),
+            ("currency/ARS", "$|$|one=peso argentino;other=pesos argentinos"),
+            ("currency/USD", "US$|$|one=dólar estadounidense;other=dólares estadounidenses"),
+            ("per-unit/second", "{0}{/seg.}|{0}{/s}|{0}{ por segundo}"),
+            ("per-unit/year", "{0}{/a}|{0}{/a}|{0}{/año}"),
+            ("unit/second", "{0} {seg.}|{0}{seg.}|one={0} {segundo};other={0} {segundos}"),
+            ("unit/year", "one={0} {año};other={0} {años}|{0}{a.}|one={0} {año};other={0} {años}"),
+        ],
+    ),
+    (
+        "es-BR",
+        &[
+            ("currency/BRL", "R$|R$|one=real brasileño;other=reales brasileños"),
+        ],
+    ),
+    (
+        "es-CL",
+        &[
+            ("currency", "{}{-}{0}"),
+            ("currency/CLP", "$|$|one=peso chileno;other=pesos chilenos"),
+            ("currency/USD", "US$|$|one=dólar estadounidense;other=dólares estadounidenses"),
+        ],
+    ),
+    (
+        "es-CO",
+        &[
+            ("currency/COP", "$|$|one=peso colombiano;other=pesos colombianos"),
+            ("currency/USD", "US$|$|one=dólar estadounidense;other=dólares estadounidenses"),
+            ("per-unit/day", "{0}{/día}|{0}{/d.}|{0}{ por día}"),
+            ("per-unit/month", "{0}{/mes}|{0}{/m.}|{0}{/mes}"),
+            ("per-unit/week", "{0}{/sem.}|{0}{/sem.}|{0}{/sem.}"),
+            ("per-unit/year", "{0}{/año}|{0}{/a}|{0}{ por año}"),
+            ("unit/day", "one={0} {día};other={0} {días}||one={0} {día};other={0} {días}"),
+            ("unit/hour", "{0} {h}||one={0} {hora};other={0} {horas}"),
+            ("unit/millisecond", "{0} {ms}||one={0} {milisegundo};other={0} {milisegundos}"),
+            ("unit/minute", "{0} {min}||one={0} {minuto};other={0} {minutos}"),
+            ("unit/month", "one={0} {mes};other={0} {meses}||one={0} {mes};other={0} {meses}"),
+            ("unit/second", "{0} {s}||one={0} {segundo};other={0} {segundos}"),
+            ("unit/week", "one={0} {sem.};other={0} {sems.}||one={0} {semana};other={0} {semanas}"),
+            ("unit/year", "{0} {a.}||one={0} {año};other={0} {años}"),
+        ],
+    ),
+    (
+        "es-CU",
+        &[
+            ("currency/USD", "US$|$|one=dólar estadounidense;other=dólares estadounidenses"),
+        ],
+    ),
+    (
+        "es-DO",
+        &[
+            ("accounting", "({}\u{a0}{0})"),
+            ("currency/USD", "US$|$|one=dólar estadounidense;other=dólares estadounidenses"),
+            ("unit/day", "one={0} {d.};other={0} {dd.}|{0}{d.}|one={0} {día};other={0} {días}"),
+            ("unit/month", "one={0} {m.};other={0} {mm.}|{0}{m.}|one={0} {mes};other={0} {meses}"),
+            ("unit/second", "{0} {seg.}|{0}{s}|one={0} {segundo};other={0} {segundos}"),
+        ],
+    ),
+    (
+        "es-EC",
+        &[
+            ("currency", "{}{-}{0}"),
+            ("currency/USD", "$|$|one=dólar estadounidense;other=dólares estadounidenses"),
+        ],
+    ),
+    (
+        "es-GQ",
+        &[
+            ("currency", "{-}{}{0}"),
+        ],
+    ),
+    (
+        "es-GT",
+        &[
+            ("currency-name", "{} {0}"),
+            ("per-unit/day", "{0}{/d.}|{0}{/d.}|{0}{ al día}"),
+            ("per-unit/month", "{0}{/m.}|{0}{/m.}|{0}{ al mes}"),
+            ("per-unit/year", "{0}{/a.}|{0}{/a}|{0}{ al año}"),
+        ],
+    ),
+    (
+        "es-MX",
+        &[
+            ("compact-long/12", "12|one={0} {billón};other={0} {billones}"),
+            ("compact-short/3", "3|{0}\u{a0}{k}"),
+            ("currency/MXN", "$|$|one=peso mexicano;other=pesos mexicanos"),
+            ("currency/THB", "THB|฿|one=baht tailandés;other=bahts tailandeses"),
+            ("currency/VND", "VND|₫|one=dong vietnamita;other=dongs vietnamitas"),
+            ("per-unit/day", "{0}{/d}|{0}{/d.}|{0}{ por día}"),
+            ("per-unit/year", "{0}{/a}|{0}{/a}|{0}{ por año}"),
+            ("percent", "{-}{0}{%}"),
+            ("unit/day", "one={0} {día};other={0} {días}|{0}{d}|one={0} {día};other={0} {días}"),
+            (
+                "unit/degree",
+                "one={0}{°};other={0} {grados}|{0}{°}|one={0} {grado};other={0} {grados}",
+            ),
+            (
+                "unit/fluid-ounce",
+                "{0} {fl oz}|{0}{fl oz}|one={0} {onza líquida};other={0} {onzas líquidas}",
+            ),
+            ("unit/month", "{0} {m}|{0}{m}|one={0} {mes};other={0} {meses}"),
+            ("unit/percent", "{0}{%}||{0} {por ciento}"),
+            ("unit/week", "{0} {sem}|{0}{sem}|one={0} {semana};other={0} {semanas}"),
+            ("unit/year", "{0} {a}|{0}{a}|one={0} {año};other={0} {años}"),
+        ],
+    ),
+    (
+        "es-PH",
+        &[
+            ("currency/PHP", "₱|₱|one=peso filipino;other=pesos filipinos"),
+        ],
+    ),
+    (
+        "es-PR",
+        &[
+            ("currency/USD", "$|$|one=dólar estadounidense;other=dólares estadounidenses"),
+            (
+                "unit/fahrenheit",
+                "{0} {°F}|{0}{°}|one={0} {grado Fahrenheit};other={0} {grados Fahrenheit}",
+            ),
+        ],
+    ),
+    (
+        "es-PY",
+        &[
+            ("currency", "{}\u{a0}{-}{0}"),
+            ("per-unit/day", "{0}{/día}|{0}{/d.}|{0}{ por día}"),
+            ("per-unit/month", "{0}{/mes}|{0}{/m.}|{0}{ por mes}"),
+            ("per-unit/second", "{0}{/seg.}|{0}{/s}|{0}{ por segundo}"),
+            ("per-unit/year", "{0}{/año}|{0}{/a}|{0}{/año}"),
+            (
+                "unit/day",
+                "one={0} {día};other={0} {días}|one={0}{d.};other={0}{dd.}|one={0} {día};other={0} {días}",
+            ),
+            (
+                "unit/month",
+                "one={0} {mes};other={0} {meses}|one={0}{m.};other={0}{mm.}|one={0} {mes};other={0} {meses}",
+            ),
+            ("unit/second", "{0} {seg.}|{0}{s}|one={0} {segundo};other={0} {segundos}"),
+            (
+                "unit/year",
+                "one={0} {año};other={0} {años}|one={0}{a.};other={0}{aa.}|one={0} {año};other={0} {años}",
+            ),
+        ],
+    ),
+    (
+        "es-SV",
+        &[
+            ("currency/USD", "$|$|one=dólar estadounidense;other=dólares estadounidenses"),
+        ],
+    ),
+    (
+        "es-US",
+        &[
+            ("compact-short/4", "3|{0}\u{a0}{K}"),
+            ("compact-short/5", "3|{0}\u{a0}{K}"),
+            ("currency/JPY", "¥|¥|one=yen;other=yenes"),
+            ("currency/THB", "THB|฿|one=bat;other=bats"),
+            ("currency/USD", "$|$|one=dólar estadounidense;other=dólares estadounidenses"),
+            ("currency/VND", "VND|₫|one=dong vietnamita;other=dongs vietnamitas"),
+            ("per-unit/day", "{0}{/d}|{0}{/d.}|{0}{ por día}"),
+            ("per-unit/year", "{0}{/a}|{0}{/a}|{0}{ por año}"),
+            ("unit/day", "one={0} {día};other={0} {días}|{0}{d}|one={0} {día};other={0} {días}"),
+            (
+                "unit/fahrenheit",
+                "{0} {°F}|{0}{°}|one={0} {grado Fahrenheit};other={0} {grados Fahrenheit}",
+            ),
+            (
+                "unit/mile-scandinavian",
+                "{0} {mil}|{0}{mil}|one={0} {milla escandinava};other={0} {millas escandinavas}",
+            ),
+            ("unit/month", "one={0} {m};other={0} {mm.}|{0}{m}|one={0} {mes};other={0} {meses}"),
+            ("unit/year", "one={0} {a};other={0} {aa.}|{0}{a}|one={0} {año};other={0} {años}"),
+        ],
+    ),
+    (
+        "es-UY",
+        &[
+            ("accounting", "({}\u{a0}{0})"),
+            ("currency/USD", "US$|$|one=dólar estadounidense;other=dólares estadounidenses"),
+        ],
+    ),
+    (
+        "es-VE",
+        &[
+            ("currency", "{}{-}{0}"),
+        ],
+    ),
+    (
+        "fr",
+        &[
+            ("accounting", "({0}\u{a0}{})"),
+            ("compact-long/10", "9|{0} {milliards}"),
+            ("compact-long/11", "9|{0} {milliards}"),
+            ("compact-long/12", "12|one={0} {billion};other={0} {billions}"),
+            ("compact-long/13", "12|{0} {billions}"),
+            ("compact-long/14", "12|{0} {billions}"),
+            ("compact-long/3", "3|one={mille};other={0} {mille}"),
+            ("compact-long/4", "3|{0} {mille}"),
+            ("compact-long/5", "3|{0} {mille}"),
+            ("compact-long/6", "6|one={0} {million};other={0} {millions}"),
+            ("compact-long/7", "6|{0} {millions}"),
+            ("compact-long/8", "6|{0} {millions}"),
+            ("compact-long/9", "9|one={0} {milliard};other={0} {milliards}"),
+            ("compact-short/10", "9|{0}\u{a0}{Md}"),
+            ("compact-short/11", "9|{0}\u{a0}{Md}"),
+            ("compact-short/12", "12|{0}\u{a0}{Bn}"),
+            ("compact-short/13", "12|{0}\u{a0}{Bn}"),
+            ("compact-short/14", "12|{0}\u{a0}{Bn}"),
+            ("compact-short/3", "3|{0}\u{a0}{k}"),
+            ("compact-short/4", "3|{0}\u{a0}{k}"),
+            ("compact-short/5", "3|{0}\u{a0}{k}"),
+            ("compact-short/6", "6|{0}\u{a0}{M}"),
+            ("compact-short/7", "6|{0}\u{a0}{M}"),
+            ("compact-short/8", "6|{0}\u{a0}{M}"),
+            ("compact-short/9", "9|{0}\u{a0}{Md}"),
+            ("currency", "{-}{0}\u{a0}{}"),
+            ("currency-name", "{0} {}"),
+            (
+                "currency/AED",
+                "AED|AED|one=dirham des Émirats arabes unis;other=dirhams des Émirats arabes unis",
+            ),
+            ("currency/ARS", "$AR|$|one=peso argentin;other=pesos argentins"),
+            ("currency/AUD", "$AU|$|one=dollar australien;other=dollars australiens"),
+            ("currency/BRL", "R$|R$|one=réal brésilien;other=réals brésiliens"),
+            ("currency/CAD", "$CA|$|one=dollar canadien;other=dollars canadiens"),
+            ("currency/CHF", "CHF|CHF|one=franc suisse;other=francs suisses"),
+            ("currency/CLP", "$CL|$|one=peso chilien;other=pesos chiliens"),
+            ("currency/CNY", "CNY|¥|one=yuan renminbi chinois;other=yuans renminbi chinois"),
+            ("currency/COP", "$CO|$|one=peso colombien;other=pesos colombiens"),
+            ("currency/CZK", "CZK|Kč|one=couronne tchèque;other=couronnes tchèques"),
+            ("currency/DKK", "DKK|kr|one=couronne danoise;other=couronnes danoises"),
+            ("currency/EGP", "EGP|£E|one=livre égyptienne;other=livres égyptiennes"),
+            ("currency/EUR", "€|€|one=euro;other=euros"),
+            ("currency/GBP", "£GB|£|one=livre sterling;other=livres sterling"),
+            ("currency/HKD", "HKD|$|one=dollar de Hong Kong;other=dollars de Hong Kong"),
+            ("currency/HUF", "HUF|Ft|one=forint hongrois;other=forints hongrois"),
+            ("currency/IDR", "IDR|Rp|one=roupie indonésienne;other=roupies indonésiennes"),
+            ("currency/ILS", "₪|₪|one=nouveau shekel israélien;other=nouveaux shekels israéliens"),
+            ("currency/INR", "₹|₹|one=roupie indienne;other=roupies indiennes"),
+            ("currency/JPY", "JPY|¥|one=yen japonais;other=yens japonais"),
+            ("currency/KRW", "₩|₩|one=won sud-coréen;other=wons sud-coréens"),
+            ("currency/MXN", "$MX|$|one=peso mexicain;other=pesos mexicains"),
+            ("currency/MYR", "MYR|RM|one=ringgit malais;other=ringgits malais"),
+            ("currency/NOK", "NOK|kr|one=couronne norvégienne;other=couronnes norvégiennes"),
+            ("currency/NZD", "$NZ|$|one=dollar néo-zélandais;other=dollars néo-zélandais"),
+            ("currency/PHP", "PHP|₱|one=peso philippin;other=pesos philippins"),
+            ("currency/PKR", "PKR|Rs|one=roupie pakistanaise;other=roupies pakistanaises"),
+            ("currency/PLN", "PLN|zł|one=zloty polonais;other=zlotys polonais"),
+            ("currency/RUB", "RUB|₽|one=rouble russe;other=roubles russes"),
+            ("currency/SAR", "SAR|SAR|one=riyal saoudien;other=riyals saoudiens"),
+            ("currency/SEK", "SEK|kr|one=couronne suédoise;other=couronnes suédoises"),
+            ("currency/SGD", "$SG|$|one=dollar de Singapour;other=dollars de Singapour"),
+            ("currency/THB", "THB|฿|one=baht thaïlandais;other=bahts thaïlandais"),
+            ("currency/TRY", "TRY|₺|one=livre turque;other=livres turques"),
+            (
+                "currency/TWD",
+                "TWD|NT$|one=nouveau dollar taïwanais;other=nouveaux dollars taïwanais",
+            ),
+            ("currency/UAH", "UAH|₴|one=hryvnia ukrainienne;other=hryvnias ukrainiennes"),
+            ("currency/USD", "$US|$|one=dollar des États-Unis;other=dollars des États-Unis"),
+            ("currency/VND", "₫|₫|one=dông vietnamien;other=dôngs vietnamiens"),
+            ("currency/ZAR", "ZAR|R|one=rand sud-africain;other=rands sud-africains"),
+            ("per", "{0}{/}{}|{0}{/}{}|{0}{ par }{}"),
+            ("per-unit/centimeter", "{0}{/cm}|{0}{/cm}|{0}{ par centimètre}"),
+            ("per-unit/day", "{0}{/j}|{0}{/j}|{0}{ par jour}"),
+            ("per-unit/foot", "{0}{/pi}|{0}{/pi}|{0}{ par pied}"),
+            ("per-unit/gallon", "{0}{/gal}|{0}{/gal}|{0}{ par gallon}"),
+            ("per-unit/gram", "{0}{/g}|{0}{/g}|{0}{ par gramme}"),
+            ("per-unit/hour", "{0}{/h}|{0}{/h}|{0}{ par heure}"),
+            ("per-unit/inch", "{0}{/po}|{0}{/po}|{0}{ par pouce}"),
+            ("per-unit/kilogram", "{0}{/kg}|{0}{/kg}|{0}{ par kilogramme}"),
+            ("per-unit/kilometer", "{0}{/km}|{0}{/km}|{0}{ par kilomètre}"),
+            ("per-unit/liter", "{0}{/l}|{0}{/l}|{0}{ par litre}"),
+            ("per-unit/meter", "{0}{/m}|{0}{/m}|{0}{ par mètre}"),
+            ("per-unit/minute", "{0}{/min}|{0}{/min}|{0}{ par minute}"),
+            ("per-unit/month", "{0}{/m.}|{0}{/m.}|{0}{ par mois}"),
+            ("per-unit/ounce", "{0}{/oz}|{0}{/oz}|{0}{ par once}"),
+            ("per-unit/pound", "{0}{/lb}|{0}{/lb}|{0}{ par livre}"),
+            ("per-unit/second", "{0}{/s}|{0}{/s}|{0}{ par seconde}"),
+            ("per-unit/week", "{0}{/sem.}|{0}{/sem.}|{0}{ par semaine}"),
+            ("per-unit/year", "{0}{/an}|{0}{/a}|{0}{ par an}"),
+            ("percent", "{-}{0}\u{a0}{%}"),
+            (
+                "unit/acre",
+                "{0}\u{202f}{ac}|{0}{ac}|one={0}\u{a0}{acre anglo-saxonne};other={0}\u{a0}{acres anglo-saxonnes}",
+            ),
+            ("unit/bit", "{0}\u{202f}{bit}|{0}{bit}|one={0}\u{a0}{bit};other={0}\u{a0}{bits}"),
+            ("unit/byte", "{0}\u{202f}{o}|{0}{o}|one={0}\u{a0}{octet};other={0}\u{a0}{octets}"),
+            (
+                "unit/celsius",
+                "{0}\u{202f}{°C}|{0}{°C}|one={0}\u{a0}{degré Celsius};other={0}\u{a0}{degrés Celsius}",
+            ),
+            (
+                "unit/centimeter",
+                "{0}\u{202f}{cm}|{0}{cm}|one={0}\u{a0}{centimètre};other={0}\u{a0}{centimètres}",
+            ),
+            ("unit/day", "{0}\u{202f}{j}|{0}{j}|one={0}\u{a0}{jour};other={0}\u{a0}{jours}"),
+            ("unit/degree", "{0}{°}||one={0}\u{a0}{degré};other={0}\u{a0}{degrés}"),
+            (
+                "unit/fahrenheit",
+                "{0}\u{202f}{°F}|{0}{°F}|one={0}\u{a0}{degré Fahrenheit};other={0}\u{a0}{degrés Fahrenheit}",
+            ),
+            (
+                "unit/fluid-ounce",
+                "{0} {fl oz}|{0}{fl oz}|one={0} {once liquide};other={0} {onces liquides}",
+            ),
+            ("unit/foot", "{0}\u{202f}{pi}|{0}{′}|one={0}\u{a0}{pied};other={0} {pieds}"),
+            ("unit/gallon", "{0} {gal}|{0}{gal}|one={0} {gallon};other={0} {gallons}"),
+            (
+                "unit/gigabit",
+                "{0}\u{202f}{Gbit}|{0}{Gbit}|one={0}\u{a0}{gigabit};other={0}\u{a0}{gigabits}",
+            ),
+            (
+                "unit/gigabyte",
+                "{0}\u{202f}{Go}|{0}{Go}|one={0}\u{a0}{gigaoctet};other={0}\u{a0}{gigaoctets}",
+            ),
+            ("unit/gram", "{0}\u{202f}{g}|{0}{g}|one={0}\u{a0}{gramme};other={0}\u{a0}{grammes}"),
+            (
+                "unit/hectare",
+                "{0}\u{202f}{ha}|{0}{ha}|one={0}\u{a0}{hectare};other={0}\u{a0}{hectares}",
+            ),
+            ("unit/hour", "{0}\u{202f}{h}|{0}{h}|one={0}\u{a0}{heure};other={0}\u{a0}{heures}"),
+            ("unit/inch", "{0}\u{202f}{po}|{0}{″}|one={0}\u{a0}{pouce};other={0}\u{a0}{pouces}"),
+            (
+                "unit/kilobit",
+                "{0}\u{202f}{kbit}|{0}{kbit}|one={0}\u{a0}{kilobit};other={0}\u{a0}{kilobits}",
+            ),
+            (
+                "unit/kilobyte",
+                "{0}\u{202f}{ko}|{0}{ko}|one={0}\u{a0}{kilooctet};other={0}\u{a0}{kilooctets}",
+            ),
+            (
+                "unit/kilogram",
+                "{0}\u{202f}{kg}|{0}{kg}|one={0}\u{a0}{kilogramme};other={0}\u{a0}{kilogrammes}",
+            ),
+            (
+                "unit/kilometer",
+                "{0}\u{202f}{km}|{0}{km}|one={0}\u{a0}{kilomètre};other={0}\u{a0}{kilomètres}",
+            ),
+            (
+                "unit/kilometer-per-hour",
+                "{0}\u{202f}{km/h}|{0}{km/h}|one={0}\u{a0}{kilomètre par heure};other={0}\u{a0}{kilomètres par heure}",
+            ),
+            ("unit/liter", "{0}\u{202f}{l}|{0}{l}|one={0}\u{a0}{litre};other={0}\u{a0}{litres}"),
+            (
+                "unit/liter-per-kilometer",
+                "{0}\u{202f}{l/km}|{0}{l/km}|one={0} {litre au kilomètre};other={0} {litres au kilomètre}",
+            ),
+            (
+                "unit/megabit",
+                "{0}\u{202f}{Mbit}|{0}{Mbit}|one={0}\u{a0}{mégabit};other={0}\u{a0}{mégabits}",
+            ),
+            (
+                "unit/megabyte",
+                "{0}\u{202f}{Mo}|{0}{Mo}|one={0}\u{a0}{mégaoctet};other={0}\u{a0}{mégaoctets}",
+            ),
+            ("unit/meter", "{0}\u{202f}{m}|{0}{m}|one={0}\u{a0}{mètre};other={0}\u{a0}{mètres}"),
+            (
+                "unit/meter-per-second",
+                "{0}\u{202f}{m/s}|{0}{m/s}|one={0}\u{a0}{mètre par seconde};other={0}\u{a0}{mètres par seconde}",
+            ),
+            (
+                "unit/microsecond",
+                "{0}\u{202f}{μs}|{0}{μs}|one={0}\u{a0}{microseconde};other={0}\u{a0}{microsecondes}",
+            ),
+            ("unit/mile", "{0}\u{202f}{mi}|{0}{mi}|one={0}\u{a0}{mile};other={0}\u{a0}{miles}"),
+            (
+                "unit/mile-per-gallon",
+                "{0}\u{202f}{mi/gal}|{0}{mi/gal}|one={0}\u{a0}{mile par gallon};other={0}\u{a0}{miles par gallon}",
+            ),
+            (
+                "unit/mile-per-hour",
+                "{0}\u{202f}{mi/h}|{0}{mi/h}|one={0}\u{a0}{mile par heure};other={0}\u{a0}{miles par heure}",
+            ),
+            (
+                "unit/mile-scandinavian",
+                "{0}\u{202f}{smi}||one={0}\u{a0}{mille scandinave};other={0}\u{a0}{milles scandinaves}",
+            ),
+            ("unit/milliliter", "{0} {ml}|{0}{ml}|one={0} {millilitre};other={0} {millilitres}"),
+            (
+                "unit/millimeter",
+                "{0}\u{202f}{mm}|{0}{mm}|one={0}\u{a0}{millimètre};other={0}\u{a0}{millimètres}",
+            ),
+            (
+                "unit/millisecond",
+                "{0}\u{202f}{ms}|{0}{ms}|one={0}\u{a0}{milliseconde};other={0}\u{a0}{millisecondes}",
+            ),
+            ("unit/minute", "{0}\u{a0}{min}|{0}{min}|one={0} {minute};other={0} {minutes}"),
+            ("unit/month", "{0}\u{202f}{m.}|{0}{m.}|{0}\u{a0}{mois}"),
+            (
+                "unit/nanosecond",
+                "{0}\u{202f}{ns}|{0}{ns}|one={0}\u{a0}{nanoseconde};other={0}\u{a0}{nanosecondes}",
+            ),
+            ("unit/ounce", "{0}\u{202f}{oz}|{0}{oz}|one={0}\u{a0}{once};other={0}\u{a0}{onces}"),
+            ("unit/percent", "{0}\u{a0}{%}||{0} {pour cent}"),
+            (
+                "unit/petabyte",
+                "{0}\u{202f}{Po}|{0}{Po}|one={0}\u{a0}{pétaoctet};other={0}\u{a0}{pétaoctets}",
+            ),
+            ("unit/pound", "{0}\u{202f}{lb}|{0}{lb}|one={0}\u{a0}{livre};other={0}\u{a0}{livres}"),
+            (
+                "unit/second",
+                "{0}\u{202f}{s}|{0}{s}|one={0}\u{a0}{seconde};other={0}\u{a0}{secondes}",
+            ),
+            ("unit/stone", "{0} {st}||one={0} {stone};other={0} {stones}"),
+            (
+                "unit/terabit",
+                "one={0}\u{202f}{Tbit};other={0} {Tbit}|{0}{Tbit}|one={0}\u{a0}{térabit};other={0}\u{a0}{térabits}",
+            ),
+            (
+                "unit/terabyte",
+                "{0}\u{202f}{To}|{0}{To}|one={0}\u{a0}{téraoctet};other={0}\u{a0}{téraoctets}",
+            ),
+            (
+                "unit/week",
+                "{0}\u{202f}{sem.}|{0}{sem.}|one={0}\u{a0}{semaine};other={0}\u{a0}{semaines}",
+            ),
+            ("unit/yard", "{0}\u{202f}{yd}|{0}{yd}|one={0}\u{a0}{yard};other={0}\u{a0}{yards}"),
+            (
+                "unit/year",
+                "one={0}\u{a0}{an};other={0}\u{a0}{ans}|{0}{a}|one={0}\u{a0}{an};other={0} {ans}",
+            ),
+        ],
+    ),
+    (
+        "fr-CA",
+        &[
+            ("compact-long/3", "3|{0} {mille}"),
+            ("compact-short/10", "9|{0}\u{a0}{G}"),
+            ("compact-short/11", "9|{0}\u{a0}{G}"),
+            ("compact-short/12", "12|{0}\u{a0}{T}"),
+            ("compact-short/13", "12|{0}\u{a0}{T}"),
+            ("compact-short/14", "12|{0}\u{a0}{T}"),
+            ("compact-short/9", "9|{0}\u{a0}{G}"),
+            ("currency/ARS", "ARS|$|one=peso argentin;other=pesos argentins"),
+            ("currency/AUD", "$\u{a0}AU|$|one=dollar australien;other=dollars australiens"),
+            ("currency/CAD", "$|$|one=dollar canadien;other=dollars canadiens"),
+            ("currency/CLP", "CLP|$|one=peso chilien;other=pesos chiliens"),
+            ("currency/CNY", "CN¥|¥|one=yuan renminbi chinois;other=yuans renminbi chinois"),
+            ("currency/COP", "COP|$|one=peso colombien;other=pesos colombiens"),
+            ("currency/GBP", "£|£|one=livre sterling;other=livres sterling"),
+            ("currency/HKD", "$\u{a0}HK|$|one=dollar de Hong Kong;other=dollars de Hong Kong"),
+            (
+                "currency/ILS",
+                "ILS|₪|one=nouveau shekel israélien;other=nouveaux shekels israéliens",
+            ),
+            ("currency/INR", "INR|₹|one=roupie indienne;other=roupies indiennes"),
+            ("currency/JPY", "¥|¥|one=yen japonais;other=yens japonais"),
+            ("currency/KRW", "KRW|₩|one=won sud-coréen;other=wons sud-coréens"),
+            ("currency/MXN", "MXN|$|one=peso mexicain;other=pesos mexicains"),
+            ("currency/NZD", "$\u{a0}NZ|$|one=dollar néo-zélandais;other=dollars néo-zélandais"),
+            ("currency/SAR", "SAR|SAR|one=rial saoudien;other=rials saoudiens"),
+            ("currency/SGD", "$\u{a0}SG|$|one=dollar de Singapour;other=dollars de Singapour"),
+            ("currency/USD", "$\u{a0}US|$|one=dollar des États-Unis;other=dollars des États-Unis"),
+            ("currency/VND", "VND|₫|one=dông vietnamien;other=dôngs vietnamiens"),
+            ("per-unit/minute", "{0}{/min}|{0}{/m}|{0}{ par minute}"),
+            ("per-unit/second", "{0}{/s}|{0}{/s}|{0}{ à la seconde}"),
+            ("per-unit/week", "{0}{/sem.}|{0}{/sem}|{0}{ par semaine}"),
+            ("unit/acre", "{0} {ac}|{0}{ac}|one={0} {acre};other={0} {acres}"),
+            ("unit/bit", "{0} {bit}|{0}{bit}|one={0} {bit};other={0} {bits}"),
+            ("unit/byte", "{0} {octet}|{0}{o}|one={0} {octet};other={0} {octets}"),
+            (
+                "unit/celsius",
+                "{0} {°C}|one={0}\u{a0}{°C};other={0} {°C}|one={0} {degré Celsius};other={0} {degrés Celsius}",
+            ),
+            ("unit/centimeter", "{0} {cm}|{0}{cm}|one={0} {centimètre};other={0} {centimètres}"),
+            ("unit/day", "{0} {j}|{0}{j}|one={0} {jour};other={0} {jours}"),
+            ("unit/degree", "{0}{°}||one={0} {degré};other={0} {degrés}"),
+            (
+                "unit/fahrenheit",
+                "{0} {°F}|{0}{°F}|one={0} {degré Fahrenheit};other={0} {degrés Fahrenheit}",
+            ),
+            (
+                "unit/fluid-ounce",
+                "one={0}\u{a0}{oz liq.};other={0} {oz liq.}|{0}{oz liq.}|one={0} {once liquide};other={0} {onces liquides}",
+            ),
+            (
+                "unit/foot",
+                "one={0}\u{a0}{pi};other={0} {pi}|{0}{pi}|one={0} {pied};other={0} {pieds}",
+            ),
+            ("unit/gigabit", "{0} {Gb}|{0}{Gbit}|one={0} {gigabit};other={0} {gigabits}"),
+            ("unit/gigabyte", "{0} {Go}|{0}{Go}|one={0} {gigaoctet};other={0} {gigaoctets}"),
+            ("unit/gram", "{0} {g}|{0}{g}|one={0} {gramme};other={0} {grammes}"),
+            ("unit/hectare", "{0} {ha}|{0}{ha}|one={0} {hectare};other={0} {hectares}"),
+            ("unit/hour", "{0} {h}|{0}{h}|one={0} {heure};other={0} {heures}"),
+            ("unit/inch", "{0} {po}|{0}{po}|one={0} {pouce};other={0} {pouces}"),
+            ("unit/kilobit", "{0} {kb}|{0}{kbit}|one={0} {kilobit};other={0} {kilobits}"),
+            ("unit/kilobyte", "{0} {ko}|{0}{ko}|one={0} {kilooctet};other={0} {kilooctets}"),
+            ("unit/kilogram", "{0} {kg}|{0}{kg}|one={0} {kilogramme};other={0} {kilogrammes}"),
+            ("unit/kilometer", "{0} {km}|{0}{km}|one={0} {kilomètre};other={0} {kilomètres}"),
+            (
+                "unit/kilometer-per-hour",
+                "{0} {km/h}|{0}{km/h}|one={0} {kilomètre par heure};other={0} {kilomètres par heure}",
+            ),
+            ("unit/liter", "{0}\u{202f}{l}|{0}{l}|one={0} {litre};other={0} {litres}"),
+            ("unit/megabit", "{0} {Mb}|{0}{Mbit}|one={0} {mégabit};other={0} {mégabits}"),
+            ("unit/megabyte", "{0} {Mo}|{0}{Mo}|one={0} {mégaoctet};other={0} {mégaoctets}"),
+            ("unit/meter", "{0} {m}|{0}{m}|one={0} {mètre};other={0} {mètres}"),
+            (
+                "unit/meter-per-second",
+                "{0} {m/s}|{0}{m/s}|one={0} {mètre par seconde};other={0} {mètres par seconde}",
+            ),
+            (
+                "unit/microsecond",
+                "{0} {μs}|{0}{μs}|one={0} {microseconde};other={0} {microsecondes}",
+            ),
+            ("unit/mile", "{0} {mi}|{0}{mi}|one={0} {mille};other={0} {milles}"),
+            (
+                "unit/mile-per-gallon",
+                "{0} {mi/gal}|{0}{mi/gal}|one={0} {mille au gallon};other={0} {milles au gallon}",
+            ),
+            (
+                "unit/mile-per-hour",
+                "{0} {mi/h}|{0}{mi/h}|one={0} {mille à l’heure};other={0} {milles à l’heure}",
+            ),
+            (
+                "unit/mile-scandinavian",
+                "{0}\u{202f}{smi}|{0}{smi}|one={0}\u{a0}{mille scandinave};other={0}\u{a0}{milles scandinaves}",
+            ),
+            ("unit/millimeter", "{0} {mm}|{0}{mm}|one={0} {millimètre};other={0} {millimètres}"),
+            (
+                "unit/millisecond",
+                "{0} {ms}|{0}{ms}|one={0} {milliseconde};other={0} {millisecondes}",
+            ),
+            ("unit/minute", "{0} {min}|{0}{m}|one={0} {minute};other={0} {minutes}"),
+            ("unit/month", "{0} {m.}|{0}{m}|{0} {mois}"),
+            ("unit/nanosecond", "{0} {ns}|{0}{ns}|one={0} {nanoseconde};other={0} {nanosecondes}"),
+            ("unit/ounce", "{0} {oz}|{0}{oz}|one={0} {once};other={0} {onces}"),
+            ("unit/pound", "{0} {lb}|{0}{lb}|one={0} {livre};other={0} {livres}"),
+            ("unit/second", "{0} {s}|{0}{s}|one={0} {seconde};other={0} {secondes}"),
+            ("unit/stone", "{0} {st}|{0}{st}|{0} {stone}"),
+            ("unit/terabit", "{0} {Tb}|{0}{Tbit}|one={0} {térabit};other={0} {térabits}"),
+            ("unit/terabyte", "{0} {To}|{0}{To}|one={0} {téraoctet};other={0} {téraoctets}"),
+            ("unit/week", "{0} {sem.}|{0}{sem}|one={0} {semaine};other={0} {semaines}"),
+            (
+                "unit/yard",
+                "one={0}\u{a0}{vg};other={0} {vg}|{0}{vg}|one={0}\u{a0}{verge};other={0} {verges}",
+            ),
+            ("unit/year", "one={0} {an};other={0} {ans}|{0}{a}|one={0} {an};other={0} {ans}"),
+        ],
+    ),
+    (
+        "fr-CH",
+        &[
+            ("percent", "{-}{0}{%}"),
+            ("unit/percent", "{0}{%}||{0} {pour cent}"),
+        ],
+    ),
+    (
+        "fr-HT",
+        &[
+            ("unit/gram", "{0}{gr}|{0}{g}|one={0}\u{a0}{gramme};other={0}\u{a0}{grammes}"),
+            ("unit/hectare", "{0}\u{202f}{ha}|{0}{ha}|one={0}{carreau};other={0}{carreaux}"),
+        ],
+    ),
+    (
+        "hi",
+        &[
+            ("accounting", "{-}{}{0}"),
+            ("compact-long/10", "9|{0} {अरब}"),
+            ("compact-long/11", "11|{0} {खरब}"),
+            ("compact-long/12", "11|{0} {खरब}"),
+            ("compact-long/13", "11|{0} {खरब}"),
+            ("compact-long/14", "11|{0} {खरब}"),
+            ("compact-long/3", "3|{0} {हज़ार}"),
+            ("compact-long/4", "3|{0} {हज़ार}"),
+            ("compact-long/5", "5|{0} {लाख}"),
+            ("compact-long/6", "5|{0} {लाख}"),
+            ("compact-long/7", "7|{0} {करोड़}"),
+            ("compact-long/8", "7|{0} {करोड़}"),
+            ("compact-long/9", "9|{0} {अरब}"),
+            ("compact-short/10", "9|{0}\u{a0}{अ॰}"),
+            ("compact-short/11", "11|{0}\u{a0}{ख॰}"),
+            ("compact-short/12", "11|{0}\u{a0}{ख॰}"),
+            ("compact-short/13", "13|{0}\u{a0}{नील}"),
+            ("compact-short/14", "13|{0}\u{a0}{नील}"),
+            ("compact-short/3", "3|{0}\u{a0}{हज़ार}"),
+            ("compact-short/4", "3|{0}\u{a0}{हज़ार}"),
+            ("compact-short/5", "5|{0}\u{a0}{लाख}"),
+            ("compact-short/6", "5|{0}\u{a0}{लाख}"),
+            ("compact-short/7", "7|{0}\u{a0}{क॰}"),
+            ("compact-short/8", "7|{0}\u{a0}{क॰}"),
+            ("compact-short/9", "9|{0}\u{a0}{अ॰}"),
+            ("currency", "{-}{}{0}"),
+            ("currency-name", "{0} {}"),
+            ("currency/AED", "AED|AED|संयुक्त अरब अमीरात दिरहाम"),
+            ("currency/ARS", "ARS|$|अर्जेंटीनी पेसो"),
+            ("currency/AUD", "A$|$|ऑस्ट्रेलियाई डॉलर"),
+            ("currency/BRL", "R$|R$|ब्राज़ीली रियाल"),
+            ("currency/CAD", "CA$|$|कनाडाई डॉलर"),
+            ("currency/CHF", "CHF|CHF|स्विस फ़्रैंक"),
+            ("currency/CLP", "CLP|$|चिली पेसो"),
+            ("currency/CNY", "CN¥|¥|चीनी युआन"),
+            ("currency/COP", "COP|$|कोलंबियाई पेसो"),
+            ("currency/CZK", "CZK|Kč|चेक गणराज्य कोरुना"),
+            ("currency/DKK", "DKK|kr|डैनिश क्रोन"),
+            ("currency/EGP", "EGP|E£|मिस्र पाउंड"),
+            ("currency/EUR", "€|€|यूरो"),
+            ("currency/GBP", "£|£|ब्रिटिश पाउंड स्टर्लिंग"),
+            ("currency/HKD", "HK$|$|हाँगकाँग डॉलर"),
+            ("currency/HUF", "HUF|Ft|हंगेरियन फ़ोरिंट"),
+            ("currency/IDR", "IDR|Rp|one=इंडोनेशियाई रुपिया;other=इंडोनेशियाई रुपिये"),
+            ("currency/ILS", "₪|₪|इज़राइली न्यू शेकेल"),
+            ("currency/INR", "₹|₹|one=भारतीय रुपया;other=भारतीय रुपए"),
+            ("currency/JPY", "JP¥|¥|जापानी येन"),
+            ("currency/KRW", "₩|₩|दक्षिण कोरियाई वॉन"),
+            ("currency/MXN", "MX$|$|मैक्सिकन पेसो"),
+            ("currency/MYR", "MYR|RM|मलेशियाई रिंगित"),
+            ("currency/NOK", "NOK|kr|नॉर्वेजियन क्रोन"),
+            ("currency/NZD", "NZ$|$|न्यूज़ीलैंड डॉलर"),
+            ("currency/PHP", "PHP|₱|फ़िलिपीनी पेसो"),
+            ("currency/PKR", "PKR|Rs|one=पाकिस्तानी रुपया;other=पाकिस्तानी रुपए"),
+            ("currency/PLN", "PLN|zł|पोलिश ज़्लॉटी"),
+            ("currency/RUB", "RUB|₽|रूसी रूबल"),
+            ("currency/SAR", "SAR|SAR|सउदी रियाल"),
+            ("currency/SEK", "SEK|kr|स्वीडीश क्रोना"),
+            ("currency/SGD", "SGD|$|सिंगापुर डॉलर"),
+            ("currency/THB", "฿|฿|थाई बहत"),
+            ("currency/TRY", "TRY|₺|तुर्की लीरा"),
+            ("currency/TWD", "NT$|NT$|नया ताईवानी डॉलर"),
+            ("currency/UAH", "UAH|₴|यूक्रेनियन रिव्निया"),
+            ("currency/USD", "$|$|यूएस डॉलर"),
+            ("currency/VND", "₫|₫|वियतनामी डोंग"),
+            ("currency/ZAR", "ZAR|R|दक्षिण अफ़्रीकी रैंड"),
+            ("per", "{0}{/}{}|{0}{/}{}|{0}{ प्रति }{}"),
+            ("per-unit/centimeter", "{0}{/सें॰मी॰}|{0}{/सेंमी}|{0}{/सेंटीमीटर}"),
+            ("per-unit/day", "{0}{/दिन}|{0}{/दि}|{0}{ प्रति दिन}"),
+            ("per-unit/foot", "{0}{/फ़ीट}|{0}{/फ़ीट}|{0}{/फ़ीट}"),
+            ("per-unit/gallon", "{0}{/गैलन}|{0}{/गै}|{0}{/गैलन}"),
+            ("per-unit/gram", "{0}{/ग्रा॰}|{0}{/ग्रा॰}|{0}{/ग्राम}"),
+            ("per-unit/hour", "{0}{/घं॰}|{0}{/घं}|{0}{ प्रति घंटा}"),
+            ("per-unit/inch", "{0}{/इंच}|{0}{/इंच}|{0}{/इंच}"),
+            ("per-unit/kilogram", "{0}{/कि॰ग्रा॰}|{0}{/कि॰ग्रा॰}|{0}{/किलोग्राम}"),
+            ("per-unit/kilometer", "{0}{/कि॰मी॰}|{0}{/किमी}|{0}{/किलोमीटर}"),
+            ("per-unit/liter", "{0}{/ली॰}|{0}{/ली}|{0}{/लीटर}"),
+            ("per-unit/meter", "{0}{/मी}|{0}{/मी}|{0}{/मीटर}"),
+            ("per-unit/minute", "{0}{/मिनट}|{0}{/मि}|{0}{ प्रति मिनट}"),
+            ("per-unit/month", "{0}{/माह}|{0}{/माह}|{0}{ प्रति महीना}"),
+            ("per-unit/ounce", "{0}{/औंस}|{0}{/औंस}|{0}{/औंस}"),
+            ("per-unit/pound", "{0}{/पौंड}|{0}{/पौंड}|{0}{/पौंड}"),
+            ("per-unit/second", "{0}{/से॰}|{0}{/से}|{0}{ प्रति सेकंड}"),
+            ("per-unit/week", "{0}{/सप्ताह}|{0}{/स}|{0}{ प्रति सप्ताह}"),
+            ("per-unit/year", "{0}{/वर्ष}|{0}{/व}|{0}{ प्रति वर्ष}"),
+            ("percent", "{-}{0}{%}"),
+            ("unit/acre", "{0} {एकड़}||{0} {एकड़}"),
+            ("unit/bit", "{0} {बिट}||{0} {बिट}"),
+            ("unit/byte", "{0} {बाइट}||{0} {बाइट}"),
+            ("unit/celsius", "{0}{°से॰}||{0} {डिग्री सेल्सियस}"),
+            ("unit/centimeter", "{0} {सें॰मी॰}||{0} {सेंटीमीटर}"),
+            ("unit/day", "{0} {दिन}|{0} {दि}|{0} {दिन}"),
+            ("unit/degree", "{0} {अंश}|{0}{°}|{0} {अंश}"),
+            ("unit/fahrenheit", "{0}{°फ़ेरन}|{0}{°F}|{0} {डिग्री फ़ेरनहाइट}"),
+            ("unit/fluid-ounce", "{0} {फ़्लूइड आउंस}|{0} {fl oz}|{0} {फ़्लूइड आउंस}"),
+            ("unit/foot", "{0} {फ़ीट}|{0}{′}|one={0} {फ़ुट};other={0} {फ़ीट}"),
+            ("unit/gallon", "{0} {गैलन}|{0} {गै}|{0} {गैलन}"),
+            ("unit/gigabit", "{0} {Gb}||{0} {गीगाबिट}"),
+            ("unit/gigabyte", "{0} {GB}||{0} {गीगाबाइट}"),
+            ("unit/gram", "{0} {ग्रा॰}||{0} {ग्राम}"),
+            ("unit/hectare", "{0} {हे॰}||{0} {हेक्टेयर}"),
+            ("unit/hour", "{0} {घं॰}|one={0}{घं॰};other={0} {घं}|one={0} {घंटा};other={0} {घंटे}"),
+            ("unit/inch", "{0} {इंच}|{0}{″}|{0} {इंच}"),
+            ("unit/kilobit", "{0} {kb}||{0} {किलोबिट}"),
+            ("unit/kilobyte", "{0} {kB}||{0} {किलोबाइट}"),
+            ("unit/kilogram", "{0} {कि॰ग्रा॰}|{0} {किग्रा}|{0} {किलोग्राम}"),
+            ("unit/kilometer", "{0} {कि॰मी॰}|{0} {किमी}|{0} {किलोमीटर}"),
+            ("unit/kilometer-per-hour", "{0} {कि॰मी॰/घं॰}|{0} {किमी/घं}|{0} {किलोमीटर प्रति घंटा}"),
+            ("unit/liter", "{0} {ली॰}|{0} {ली}|{0} {लीटर}"),
+            ("unit/liter-per-kilometer", "{0} {ली/किमी}||{0} {लीटर प्रति किलोमीटर}"),
+            ("unit/megabit", "{0} {Mb}||{0} {मेगाबिट}"),
+            ("unit/megabyte", "{0} {MB}||{0} {मेगाबाइट}"),
+            ("unit/meter", "{0} {मी}||{0} {मीटर}"),
+            (
+                "unit/meter-per-second",
+                "{0} {मी॰/से॰}|one={0} {मी/से};other={0}{मी॰/से॰}|{0} {मीटर प्रति सेकंड}",
+            ),
+            ("unit/microsecond", "{0} {μs}||{0} {माइक्रोसेकंड}"),
+            ("unit/mile", "{0} {मील}||{0} {मील}"),
+            ("unit/mile-per-gallon", "{0} {mpg}||{0} {मील प्रति गैलन}"),
+            ("unit/mile-per-hour", "{0} {मी॰प्र॰घं॰}|{0} {मीप्रघं}|{0} {मील प्रति घंटा}"),
+            ("unit/mile-scandinavian", "{0} {smi}||{0} {मील-स्कैण्डिनेवियन}"),
+            ("unit/milliliter", "{0} {मि॰ ली॰}|{0} {मिली}|{0} {मिलीलीटर}"),
+            ("unit/millimeter", "{0} {मि॰मी॰}|{0} {मिमी}|{0} {मिलीमीटर}"),
+            ("unit/millisecond", "{0} {मि॰से॰}||{0} {मिलीसेकंड}"),
+            ("unit/minute", "{0} {मि॰}|{0} {मि}|{0} {मिनट}"),
+            ("unit/month", "{0} {माह}|{0}{माह}|one={0} {महीना};other={0} {महीने}"),
+            ("unit/nanosecond", "{0} {नैनो से॰}|{0} {नैनो से}|{0} {नैनो सेकंड}"),
+            ("unit/ounce", "{0} {औंस}|{0} {औं॰}|{0} {औंस}"),
+            ("unit/percent", "{0}{%}||{0} {प्रतिशत}"),
+            ("unit/petabyte", "{0} {PB}||{0} {पेटाबाइट}"),
+            ("unit/pound", "{0} {पौंड}|{0}{#}|{0} {पौंड}"),
+            ("unit/second", "{0} {से॰}|{0} {से}|{0} {सेकंड}"),
+            ("unit/stone", "{0} {st}|one={0} {ला};other={0}{st}|{0} {14 पौंड का बट्टा}"),
+            ("unit/terabit", "{0} {Tb}||{0} {टेराबिट}"),
+            ("unit/terabyte", "{0} {TB}||{0} {टेराबाइट}"),
+            ("unit/week", "{0} {सप्ताह}||{0} {सप्ताह}"),
+            ("unit/yard", "{0} {यार्ड}||{0} {यार्ड}"),
+            ("unit/year", "{0} {वर्ष}|{0}{वर्ष}|{0} {वर्ष}"),
+        ],
+    ),
+    (
+        "hi-Latn",
+        &[
+            ("accounting", "{-}{}{0}"),
+            ("compact-long/10", "9|{0} {arab}"),
+            ("compact-long/11", "11|{0} {kharab}"),
+            ("compact-long/12", "11|{0} {kharab}"),
+            ("compact-long/13", "11|{0} {kharab}"),
+            ("compact-long/14", "11|{0} {kharab}"),
+            ("compact-long/3", "3|{0} {hazaar}"),
+            ("compact-long/4", "3|{0} {hazaar}"),
+            ("compact-long/5", "5|{0} {laakh}"),
+            ("compact-long/6", "5|{0} {laakh}"),
+            ("compact-long/7", "7|{0} {karod}"),
+            ("compact-long/8", "7|{0} {karod}"),
+            ("compact-long/9", "9|{0} {arab}"),
+            ("compact-short/10", "9|{0}\u{a0}{a.}"),
+            ("compact-short/11", "11|{0}\u{a0}{kha.}"),
+            ("compact-short/12", "11|{0}\u{a0}{kha.}"),
+            ("compact-short/13", "11|{0}\u{a0}{kha.}"),
+            ("compact-short/14", "12|{0}{kha.}"),
+            ("compact-short/3", "3|{0}\u{a0}{hazaar}"),
+            ("compact-short/4", "3|{0}{Th}"),
+            ("compact-short/5", "5|{0}\u{a0}{laakh}"),
+            ("compact-short/6", "5|{0}\u{a0}{laakh}"),
+            ("compact-short/7", "7|{0}\u{a0}{ka.}"),
+            ("compact-short/8", "7|{0}\u{a0}{ka.}"),
+            ("compact-short/9", "9|{0}\u{a0}{a.}"),
+            ("currency/RUB", "RUB|₽|one=Russian ruble;other=Russian rubles"),
+            ("per-unit/day", "{0}{/d}||{0}{ har din}"),
+            ("per-unit/hour", "{0}{/h}||{0}{ har ghante}"),
+            ("per-unit/minute", "{0}{/min}||{0}{ har minute}"),
+            ("per-unit/month", "{0}{/m}||{0}{ har month}"),
+            ("per-unit/second", "{0}{/s}||{0}{ har second}"),
+            ("per-unit/week", "{0}{/w}||{0}{ har week}"),
+            ("per-unit/year", "{0}{/y}||{0}{ har saal}"),
+            ("unit/day", "{0} {din}|{0}{d}|{0} {din}"),
+            (
+                "unit/fluid-ounce",
+                "{0} {US fl oz}|{0}{fl oz}|one={0} {US fluid ounce};other={0} {US fluid ounces}",
+            ),
+            ("unit/gallon", "{0} {gal US}|{0}{galUS}|one={0} {US gallon};other={0} {US gallons}"),
+            (
+                "unit/hour",
+                "one={0} {hr};other={0} {hrs}|{0}{h}|one={0} {ghanta};other={0} {ghante}",
+            ),
+            ("unit/percent", "{0}{%}||{0} {percent}"),
+            ("unit/pound", "{0} {lb}|{0}{#}|one={0} {pound};other={0} {pounds}"),
+            ("unit/stone", "{0} {st}|{0}{st}|one={0} {stone};other={0} {stones}"),
+            ("unit/year", "one={0} {yr};other={0} {yrs}|{0}{y}|{0} {saal}"),
+        ],
+    ),
+    (
+        "id",
+        &[
+            ("accounting", "{-}{}{0}"),
+            ("compact-long/10", "9|{0} {miliar}"),
+            ("compact-long/11", "9|{0} {miliar}"),
+            ("compact-long/12", "12|{0} {triliun}"),
+            ("compact-long/13", "12|{0} {triliun}"),
+            ("compact-long/14", "12|{0} {triliun}"),
+            ("compact-long/3", "3|{0} {ribu}"),
+            ("compact-long/4", "3|{0} {ribu}"),
+            ("compact-long/5", "3|{0} {ribu}"),
+            ("compact-long/6", "6|{0} {juta}"),
+            ("compact-long/7", "6|{0} {juta}"),
+            ("compact-long/8", "6|{0} {juta}"),
+            ("compact-long/9", "9|{0} {miliar}"),
+            ("compact-short/10", "9|{0}\u{a0}{M}"),
+            ("compact-short/11", "9|{0}\u{a0}{M}"),
+            ("compact-short/12", "12|{0}\u{a0}{T}"),
+            ("compact-short/13", "12|{0}\u{a0}{T}"),
+            ("compact-short/14", "12|{0}\u{a0}{T}"),
+            ("compact-short/3", "3|{0}\u{a0}{rb}"),
+            ("compact-short/4", "3|{0}\u{a0}{rb}"),
+            ("compact-short/5", "3|{0}\u{a0}{rb}"),
+            ("compact-short/6", "6|{0}\u{a0}{jt}"),
+            ("compact-short/7", "6|{0}\u{a0}{jt}"),
+            ("compact-short/8", "6|{0}\u{a0}{jt}"),
+            ("compact-short/9", "9|{0}\u{a0}{M}"),
+            ("currency", "{-}{}{0}"),
+            ("currency-name", "{0} {}"),
+            ("currency/AED", "AED|AED|Dirham Uni Emirat Arab"),
+            ("currency/ARS", "ARS|$|Peso Argentina"),
+            ("currency/AUD", "AU$|$|Dolar Australia"),
+            ("currency/BRL", "R$|R$|Real Brasil"),
+            ("currency/CAD", "CA$|$|Dolar Kanada"),
+            ("currency/CHF", "CHF|CHF|Franc Swiss"),
+            ("currency/CLP", "CLP|$|Peso Cile"),
+            ("currency/CNY", "CN¥|¥|Yuan Tiongkok"),
+            ("currency/COP", "COP|$|Peso Kolombia"),
+            ("currency/CZK", "CZK|Kč|Koruna Ceko"),
+            ("currency/DKK", "DKK|kr|Krone Denmark"),
+            ("currency/EGP", "EGP|E£|Pound Mesir"),
+            ("currency/EUR", "€|€|Euro"),
+            ("currency/GBP", "£|£|Pound Inggris"),
+            ("currency/HKD", "HK$|$|Dolar Hong Kong"),
+            ("currency/HUF", "HUF|Ft|Forint Hungaria"),
+            ("currency/IDR", "Rp|Rp|Rupiah Indonesia"),
+            ("currency/ILS", "₪|₪|Shekel Baru Israel"),
+            ("currency/INR", "Rs|₹|Rupee India"),
+            ("currency/JPY", "JP¥|¥|Yen Jepang"),
+            ("currency/KRW", "₩|₩|Won Korea Selatan"),
+            ("currency/MXN", "MX$|$|Peso Meksiko"),
+            ("currency/MYR", "MYR|RM|Ringgit Malaysia"),
+            ("currency/NOK", "NOK|kr|Krone Norwegia"),
+            ("currency/NZD", "NZ$|$|Dolar Selandia Baru"),
+            ("currency/PHP", "PHP|₱|Peso Filipina"),
+            ("currency/PKR", "PKR|Rs|Rupee Pakistan"),
+            ("currency/PLN", "PLN|zł|Zloty Polandia"),
+            ("currency/RUB", "RUB|₽|Rubel Rusia"),
+            ("currency/SAR", "SAR|SAR|Riyal Arab Saudi"),
+            ("currency/SEK", "SEK|kr|Krona Swedia"),
+            ("currency/SGD", "SGD|$|Dolar Singapura"),
+            ("currency/THB", "฿|฿|Baht Thailand"),
+            ("currency/TRY", "TRY|₺|Lira Turki"),
+            ("currency/TWD", "NT$|NT$|Dolar Baru Taiwan"),
+            ("currency/UAH", "UAH|₴|Hryvnia Ukraina"),
+            ("currency/USD", "US$|$|Dolar Amerika Serikat"),
+            ("currency/VND", "₫|₫|Dong Vietnam"),
+            ("currency/ZAR", "ZAR|R|Rand Afrika Selatan"),
+            ("per", "{0}{/}{}|{0}{/}{}|{0}{ per }{}"),
+            ("per-unit/centimeter", "{0}{/cm}|{0}{/cm}|{0}{ per sentimeter}"),
+            ("per-unit/day", "{0}{/hr}|{0}{/hr}|{0}{ per hari}"),
+            ("per-unit/foot", "{0}{/ft}|{0}{/ft}|{0}{ per kaki}"),
+            ("per-unit/gallon", "{0}{/gal}|{0}{/gal}|{0}{ per galon}"),
+            ("per-unit/gram", "{0}{/g}|{0}{/g}|{0}{ per gram}"),
+            ("per-unit/hour", "{0}{/j}|{0}{/j}|{0}{ per jam}"),
+            ("per-unit/inch", "{0}{/in}|{0}{/in}|{0}{ per inci}"),
+            ("per-unit/kilogram", "{0}{/kg}|{0}{/kg}|{0}{ per kilogram}"),
+            ("per-unit/kilometer", "{0}{/km}|{0}{/km}|{0}{ per kilometer}"),
+            ("per-unit/liter", "{0}{/L}|{0}{/L}|{0}{ per liter}"),
+            ("per-unit/meter", "{0}{/m}|{0}{/m}|{0}{ per meter}"),
+            ("per-unit/minute", "{0}{/mnt}|{0}{/mnt}|{0}{ per menit}"),
+            ("per-unit/month", "{0}{/bln}|{0}{/bln}|{0}{ per bulan}"),
+            ("per-unit/ounce", "{0}{/oz}|{0}{/oz}|{0}{ per ounce}"),
+            ("per-unit/pound", "{0}{/lb}|{0}{/lb}|{0}{ per pound}"),
+            ("per-unit/second", "{0}{/dtk}|{0}{/dtk}|{0}{ per detik}"),
+            ("per-unit/week", "{0}{/mgg}|{0}{/mgg}|{0}{ per minggu}"),
+            ("per-unit/year", "{0}{/thn}|{0}{/thn}|{0}{ per tahun}"),
+            ("percent", "{-}{0}{%}"),
+            ("unit/acre", "{0} {ac}||{0} {ekar}"),
+            ("unit/bit", "{0} {bit}||{0} {bit}"),
+            ("unit/byte", "{0} {byte}|{0} {B}|{0} {byte}"),
+            ("unit/celsius", "{0}{°C}||{0} {derajat Celsius}"),
+            ("unit/centimeter", "{0} {cm}||{0} {sentimeter}"),
+            ("unit/day", "{0} {hr}||{0} {hari}"),
+            ("unit/degree", "{0}{°}||{0} {derajat}"),
+            ("unit/fahrenheit", "{0}{°F}|{0}{°}|{0} {derajat Fahrenheit}"),
+            ("unit/fluid-ounce", "{0} {fl oz}||{0} {fluid ounce}"),
+            ("unit/foot", "{0} {ft}|{0}{′}|{0} {kaki}"),
+            ("unit/gallon", "{0} {gal}||{0} {galon}"),
+            ("unit/gigabit", "{0} {Gb}||{0} {gigabit}"),
+            ("unit/gigabyte", "{0} {GB}||{0} {gigabyte}"),
+            ("unit/gram", "{0} {g}||{0} {gram}"),
+            ("unit/hectare", "{0} {ha}||{0} {hektare}"),
+            ("unit/hour", "{0} {j}||{0} {jam}"),
+            ("unit/inch", "{0} {in}|{0}{″}|{0} {inci}"),
+            ("unit/kilobit", "{0} {kb}||{0} {kilobit}"),
+            ("unit/kilobyte", "{0} {kB}||{0} {kilobyte}"),
+            ("unit/kilogram", "{0} {kg}||{0} {kilogram}"),
+            ("unit/kilometer", "{0} {km}||{0} {kilometer}"),
+            ("unit/kilometer-per-hour", "{0} {km/j}|{0}{km/j}|{0} {kilometer per jam}"),
+            ("unit/liter", "{0} {L}||{0} {liter}"),
+            ("unit/liter-per-kilometer", "{0} {L/km}||{0} {liter per kilometer}"),
+            ("unit/megabit", "{0} {Mb}||{0} {megabit}"),
+            ("unit/megabyte", "{0} {MB}||{0} {megabyte}"),
+            ("unit/meter", "{0} {m}||{0} {meter}"),
+            ("unit/meter-per-second", "{0} {m/dtk}||{0} {meter per detik}"),
+            ("unit/microsecond", "{0} {μd}||{0} {mikrodetik}"),
+            ("unit/mile", "{0} {mi}||{0} {mil}"),
+            ("unit/mile-per-gallon", "{0} {mpg}||{0} {mil per galon}"),
+            ("unit/mile-per-hour", "{0} {mpj}||{0} {mil per jam}"),
+            ("unit/mile-scandinavian", "{0} {smi}||{0} {mil skandinavia}"),
+            ("unit/milliliter", "{0} {mL}||{0} {mililiter}"),
+            ("unit/millimeter", "{0} {mm}||{0} {milimeter}"),
+            ("unit/millisecond", "{0} {md}||{0} {milidetik}"),
+            ("unit/minute", "{0} {mnt}||{0} {menit}"),
+            ("unit/month", "{0} {bln}||{0} {bulan}"),
+            ("unit/nanosecond", "{0} {ndtk}||{0} {nanodetik}"),
+            ("unit/ounce", "{0} {oz}||{0} {ounce}"),
+            ("unit/percent", "{0}{%}||{0} {persen}"),
+            ("unit/petabyte", "{0} {PB}||{0} {petabyte}"),
+            ("unit/pound", "{0} {lb}|{0}{#}|{0} {pound}"),
+            ("unit/second", "{0} {dtk}||{0} {detik}"),
+            ("unit/stone", "{0} {st}||{0} {stone}"),
+            ("unit/terabit", "{0} {Tb}||{0} {terabit}"),
+            ("unit/terabyte", "{0} {TB}||{0} {terabyte}"),
+            ("unit/week", "{0} {mgg}||{0} {minggu}"),
+            ("unit/yard", "{0} {yd}||{0} {yard}"),
+            ("unit/year", "{0} {thn}||{0} {tahun}"),
+        ],
+    ),
+    (
+        "it",
+        &[
+            ("accounting", "{-}{0}\u{a0}{}"),
+            ("compact-long/10", "9|{0} {miliardi}"),
+            ("compact-long/11", "9|{0} {miliardi}"),
+            ("compact-long/12", "12|one={0} {mille miliardi};other={0} {mila miliardi}"),
+            ("compact-long/13", "12|{0} {mila miliardi}"),
+            ("compact-long/14", "12|{0} {mila miliardi}"),
+            ("compact-long/3", "3|one={mille};other={0} {mila}"),
+            ("compact-long/4", "3|{0} {mila}"),
+            ("compact-long/5", "3|{0} {mila}"),
+            ("compact-long/6", "6|one={0} {milione};other={0} {milioni}"),
+            ("compact-long/7", "6|{0} {milioni}"),
+            ("compact-long/8", "6|{0} {milioni}"),
+            ("compact-long/9", "9|one={0} {miliardo};other={0} {miliardi}"),
+            ("compact-short/10", "9|{0}\u{a0}{Mrd}"),
+            ("compact-short/11", "9|{0}\u{a0}{Mrd}"),
+            ("compact-short/12", "12|{0}\u{a0}{Bln}"),
+            ("compact-short/13", "12|{0}\u{a0}{Bln}"),
+            ("compact-short/14", "12|{0}\u{a0}{Bln}"),
+            ("compact-short/6", "6|{0}\u{a0}{Mln}"),
+            ("compact-short/7", "6|{0}\u{a0}{Mln}"),
+            ("compact-short/8", "6|{0}\u{a0}{Mln}"),
+            ("compact-short/9", "9|{0}\u{a0}{Mrd}"),
+            ("currency", "{-}{0}\u{a0}{}"),
+            ("currency-name", "{0} {}"),
+            ("currency/AED", "AED|AED|dirham degli EAU"),
+            ("currency/ARS", "ARS|$|one=peso argentino;other=pesos argentini"),
+            ("currency/AUD", "A$|$|one=dollaro australiano;other=dollari australiani"),
+            ("currency/BRL", "BRL|R$|one=real brasiliano;other=real brasiliani"),
+            ("currency/CAD", "CA$|$|one=dollaro canadese;other=dollari canadesi"),
+            ("currency/CHF", "CHF|CHF|one=franco svizzero;other=franchi svizzeri"),
+            ("currency/CLP", "CLP|$|one=peso cileno;other=pesos cileni"),
+            ("currency/CNY", "CN¥|¥|one=renmimbi cinese;other=renmimbi cinesi"),
+            ("currency/COP", "COP|$|one=peso colombiano;other=pesos colombiani"),
+            ("currency/CZK", "CZK|Kč|one=corona ceca;other=corone ceche"),
+            ("currency/DKK", "DKK|kr|one=corona danese;other=corone danesi"),
+            ("currency/EGP", "EGP|£E|one=sterlina egiziana;other=sterline egiziane"),
+            ("currency/EUR", "€|€|euro"),
+            ("currency/GBP", "£|£|one=sterlina britannica;other=sterline britanniche"),
+            ("currency/HKD", "HKD|$|one=dollaro di Hong Kong;other=dollari di Hong Kong"),
+            ("currency/HUF", "HUF|Ft|one=fiorino ungherese;other=fiorini ungheresi"),
+            ("currency/IDR", "IDR|Rp|one=rupia indonesiana;other=rupie indonesiane"),
+            ("currency/ILS", "₪|₪|one=nuovo siclo israeliano;other=nuovi sicli israeliani"),
+            ("currency/INR", "INR|₹|one=rupia indiana;other=rupie indiane"),
+            ("currency/JPY", "JPY|¥|one=yen giapponese;other=yen giapponesi"),
+            ("currency/KRW", "KRW|₩|one=won sudcoreano;other=won sudcoreani"),
+            ("currency/MXN", "MXN|$|one=peso messicano;other=pesos messicani"),
+            ("currency/MYR", "MYR|RM|one=ringgit malese;other=ringgit malesi"),
+            ("currency/NOK", "NOK|NKr|one=corona norvegese;other=corone norvegesi"),
+            ("currency/NZD", "NZ$|$|one=dollaro neozelandese;other=dollari neozelandesi"),
+            ("currency/PHP", "₱|₱|one=peso filippino;other=pesos filippini"),
+            ("currency/PKR", "PKR|Rs|one=rupia pakistana;other=rupie pakistane"),
+            ("currency/PLN", "PLN|zł|one=zloty polacco;other=zloty polacchi"),
+            ("currency/RUB", "RUB|₽|one=rublo russo;other=rubli russi"),
+            ("currency/SAR", "SAR|SAR|one=riyal saudita;other=riyal sauditi"),
+            ("currency/SEK", "SEK|kr|one=corona svedese;other=corone svedesi"),
+            ("currency/SGD", "SGD|$|one=dollaro di Singapore;other=dollari di Singapore"),
+            ("currency/THB", "฿|฿|one=baht thailandese;other=baht thailandesi"),
+            ("currency/TRY", "TRY|₺|one=lira turca;other=lire turche"),
+            ("currency/TWD", "TWD|NT$|one=nuovo dollaro taiwanese;other=nuovi dollari taiwanesi"),
+            ("currency/UAH", "UAH|₴|one=grivnia ucraina;other=grivnie ucraine"),
+            ("currency/USD", "USD|$|one=dollaro statunitense;other=dollari statunitensi"),
+            ("currency/VND", "VND|₫|one=dong vietnamita;other=dong vietnamiti"),
+            ("currency/ZAR", "ZAR|R|one=rand sudafricano;other=rand sudafricani"),
+            ("per", "{0}{/}{}|{0}{/}{}|{0}{ al }{}"),
+            ("per-unit/centimeter", "{0}{/cm}|{0}{/cm}|{0}{ per centimetro}"),
+            ("per-unit/day", "{0}{/giorno}|{0}{/g}|{0}{ al giorno}"),
+            ("per-unit/foot", "{0}{/ft}|{0}{/ft}|{0}{ per piede}"),
+            ("per-unit/gallon", "{0}{/gal}|{0}{/gal}|{0}{ per gallone}"),
+            ("per-unit/gram", "{0}{/g}|{0}{/g}|{0}{ per grammo}"),
+            ("per-unit/hour", "{0}{/h}|{0}{/h}|{0}{ all’ora}"),
+            ("per-unit/inch", "{0}{/in}|{0}{/in}|{0}{ per pollice}"),
+            ("per-unit/kilogram", "{0}{/kg}|{0}{/kg}|{0}{ per chilogrammo}"),
+            ("per-unit/kilometer", "{0}{/km}|{0}{/km}|{0}{ per chilometro}"),
+            ("per-unit/liter", "{0}{/l}|{0}{/l}|{0}{ per litro}"),
+            ("per-unit/meter", "{0}{/m}|{0}{/m}|{0}{ per metro}"),
+            ("per-unit/minute", "{0}{/min}|{0}{/min}|{0}{ al minuto}"),
+            ("per-unit/month", "{0}{/mese}|{0}{/mese}|{0}{ al mese}"),
+            ("per-unit/ounce", "{0}{/oz}|{0}{/oz}|{0}{ per oncia}"),
+            ("per-unit/pound", "{0}{/lb}|{0}{/lb}|{0}{ per libbra}"),
+            ("per-unit/second", "{0}{/s}|{0}{/s}|{0}{ al secondo}"),
+            ("per-unit/week", "{0}{/settimana}|{0}{/sett.}|{0}{ alla settimana}"),
+            ("per-unit/year", "{0}{/anno}|{0}{/anno}|{0}{ all’anno}"),
+            ("percent", "{-}{0}{%}"),
+            ("unit/acre", "{0} {ac}|{0}{ac}|one={0} {acro};other={0} {acri}"),
+            ("unit/bit", "{0} {bit}|{0}{bit}|{0} {bit}"),
+            ("unit/byte", "{0} {byte}|{0}{B}|{0} {byte}"),
+            ("unit/celsius", "{0} {°C}|{0}{°C}|one={0} {grado Celsius};other={0} {gradi Celsius}"),
+            ("unit/centimeter", "{0} {cm}|{0}{cm}|one={0} {centimetro};other={0} {centimetri}"),
+            (
+                "unit/day",
+                "one={0} {giorno};other={0} {giorni}|one={0}{g};other={0}{gg}|one={0} {giorno};other={0} {giorni}",
+            ),
+            ("unit/degree", "{0}{°}||one={0} {grado};other={0} {gradi}"),
+            (
+                "unit/fahrenheit",
+                "{0} {°F}|{0}{°F}|one={0} {grado Fahrenheit};other={0} {gradi Fahrenheit}",
+            ),
+            (
+                "unit/fluid-ounce",
+                "{0} {fl oz}|{0}{fl oz}|one={0} {oncia liquida};other={0} {once liquide}",
+            ),
+            ("unit/foot", "{0} {ft}|{0}{ft}|one={0} {piede};other={0} {piedi}"),
+            ("unit/gallon", "{0} {gal}|{0}{gal}|one={0} {gallone};other={0} {galloni}"),
+            ("unit/gigabit", "{0} {Gb}|{0}{Gb}|{0} {gigabit}"),
+            ("unit/gigabyte", "{0} {GB}|{0}{GB}|{0} {gigabyte}"),
+            ("unit/gram", "{0} {g}|{0}{g}|one={0} {grammo};other={0} {grammi}"),
+            ("unit/hectare", "{0} {ha}|{0}{ha}|one={0} {ettaro};other={0} {ettari}"),
+            ("unit/hour", "{0} {h}|{0}{h}|one={0} {ora};other={0} {ore}"),
+            ("unit/inch", "{0} {in}|{0}{″}|one={0} {pollice};other={0} {pollici}"),
+            ("unit/kilobit", "{0} {kb}|{0}{kb}|{0} {kilobit}"),
+            ("unit/kilobyte", "{0} {kB}|{0}{kB}|{0} {kilobyte}"),
+            ("unit/kilogram", "{0} {kg}|{0}{kg}|one={0} {chilogrammo};other={0} {chilogrammi}"),
+            ("unit/kilometer", "{0} {km}|{0}{km}|one={0} {chilometro};other={0} {chilometri}"),
+            (
+                "unit/kilometer-per-hour",
+                "{0} {km/h}|{0}{km/h}|one={0} {chilometro orario};other={0} {chilometri orari}",
+            ),
+            ("unit/liter", "{0} {l}|{0}{l}|one={0} {litro};other={0} {litri}"),
+            (
+                "unit/liter-per-kilometer",
+                "{0} {L/km}|{0}{L/km}|one={0} {litro per chilometro};other={0} {litri per chilometro}",
+            ),
+            ("unit/megabit", "{0} {Mb}|{0}{Mb}|{0} {megabit}"),
+            ("unit/megabyte", "{0} {MB}|{0}{MB}|{0} {megabyte}"),
+            ("unit/meter", "{0} {m}|{0}{m}|one={0} {metro};other={0} {metri}"),
+            (
+                "unit/meter-per-second",
+                "{0} {m/s}|{0}{m/s}|one={0} {metro al secondo};other={0} {metri al secondo}",
+            ),
+            (
+                "unit/microsecond",
+                "{0} {μs}|{0}{μs}|one={0} {microsecondo};other={0} {microsecondi}",
+            ),
+            ("unit/mile", "{0} {mi}|{0}{mi}|one={0} {miglio};other={0} {miglia}"),
+            (
+                "unit/mile-per-gallon",
+                "{0} {mpg}|{0}{mpg}|one={0} {miglio per gallone};other={0} {miglia per gallone}",
+            ),
+            (
+                "unit/mile-per-hour",
+                "{0} {mi/h}|{0}{mi/h}|one={0} {miglio all’ora};other={0} {miglia all’ora}",
+            ),
+            (
+                "unit/mile-scandinavian",
+                "{0} {smi}|{0}{smi}|one={0} {miglio scandinavo};other={0} {miglia scandinave}",
+            ),
+            ("unit/milliliter", "{0} {ml}|{0}{ml}|one={0} {millilitro};other={0} {millilitri}"),
+            ("unit/millimeter", "{0} {mm}|{0}{mm}|one={0} {millimetro};other={0} {millimetri}"),
+            (
+                "unit/millisecond",
+                "{0} {ms}|{0}{ms}|one={0} {millisecondo};other={0} {millisecondi}",
+            ),
+            ("unit/minute", "{0} {min}|{0}{min}|one={0} {minuto};other={0} {minuti}"),
+            ("unit/month", "one={0} {mese};other={0} {mesi}||one={0} {mese};other={0} {mesi}"),
+            ("unit/nanosecond", "{0} {ns}|{0}{ns}|one={0} {nanosecondo};other={0} {nanosecondi}"),
+            ("unit/ounce", "{0} {oz}|{0}{oz}|one={0} {oncia};other={0} {once}"),
+            ("unit/percent", "{0}{%}||{0} {percento}"),
+            ("unit/petabyte", "{0} {PB}|{0}{PB}|{0} {petabyte}"),
+            ("unit/pound", "{0} {lb}|{0}{lb}|one={0} {libbra};other={0} {libbre}"),
+            ("unit/second", "{0} {s}|{0}{s}|one={0} {secondo};other={0} {secondi}"),
+            ("unit/stone", "{0} {st}|{0}{st}|{0} {stone}"),
+            ("unit/terabit", "{0} {Tb}|{0}{Tb}|{0} {terabit}"),
+            ("unit/terabyte", "{0} {TB}|{0}{TB}|{0} {terabyte}"),
+            ("unit/week", "{0} {sett.}|{0}{sett.}|one={0} {settimana};other={0} {settimane}"),
+            ("unit/yard", "{0} {yd}|{0}{yd}|one={0} {iarda};other={0} {iarde}"),
+            (
+                "unit/year",
+                "one={0} {anno};other={0} {anni}|one={0}{anno};other={0}{anni}|one={0} {anno};other={0} {anni}",
+            ),
+        ],
+    ),
+    (
+        "it-CH",
+        &[
+            ("currency", "{}{-}{0}"),
+        ],
+    ),
+    (
+        "ja",
+        &[
+            ("accounting", "({}{0})"),
+            ("compact-long/10", "8|{0}{億}"),
+            ("compact-long/11", "8|{0}{億}"),
+            ("compact-long/12", "12|{0}{兆}"),
+            ("compact-long/13", "12|{0}{兆}"),
+            ("compact-long/14", "12|{0}{兆}"),
+            ("compact-long/4", "4|{0}{万}"),
+            ("compact-long/5", "4|{0}{万}"),
+            ("compact-long/6", "4|{0}{万}"),
+            ("compact-long/7", "4|{0}{万}"),
+            ("compact-long/8", "8|{0}{億}"),
+            ("compact-long/9", "8|{0}{億}"),
+            ("compact-short/10", "8|{0}{億}"),
+            ("compact-short/11", "8|{0}{億}"),
+            ("compact-short/12", "12|{0}{兆}"),
+            ("compact-short/13", "12|{0}{兆}"),
+            ("compact-short/14", "12|{0}{兆}"),
+            ("compact-short/4", "4|{0}{万}"),
+            ("compact-short/5", "4|{0}{万}"),
+            ("compact-short/6", "4|{0}{万}"),
+            ("compact-short/7", "4|{0}{万}"),
+            ("compact-short/8", "8|{0}{億}"),
+            ("compact-short/9", "8|{0}{億}"),
+            ("currency", "{-}{}{0}"),
+            ("currency-name", "{0}{}"),
+            ("currency/AED", "AED|AED|UAE ディルハム"),
+            ("currency/ARS", "ARS|$|アルゼンチン ペソ"),
+            ("currency/AUD", "A$|$|オーストラリア ドル"),
+            ("currency/BRL", "R$|R$|ブラジル レアル"),
+            ("currency/CAD", "CA$|$|カナダ ドル"),
+            ("currency/CHF", "CHF|CHF|スイス フラン"),
+            ("currency/CLP", "CLP|$|チリ ペソ"),
+            ("currency/CNY", "元|￥|中国人民元"),
+            ("currency/COP", "COP|$|コロンビア ペソ"),
+            ("currency/CZK", "CZK|Kč|チェコ コルナ"),
+            ("currency/DKK", "DKK|kr|デンマーク クローネ"),
+            ("currency/EGP", "EGP|E£|エジプト ポンド"),
+            ("currency/EUR", "€|€|ユーロ"),
+            ("currency/GBP", "£|£|英国ポンド"),
+            ("currency/HKD", "HK$|$|香港ドル"),
+            ("currency/HUF", "HUF|Ft|ハンガリー フォリント"),
+            ("currency/IDR", "IDR|Rp|インドネシア ルピア"),
+            ("currency/ILS", "₪|₪|イスラエル新シェケル"),
+            ("currency/INR", "₹|₹|インド ルピー"),
+            ("currency/JPY", "￥|￥|円"),
+            ("currency/KRW", "₩|₩|韓国ウォン"),
+            ("currency/MXN", "MX$|$|メキシコ ペソ"),
+            ("currency/MYR", "MYR|RM|マレーシア リンギット"),
+            ("currency/NOK", "NOK|kr|ノルウェー クローネ"),
+            ("currency/NZD", "NZ$|$|ニュージーランド ドル"),
+            ("currency/PHP", "PHP|₱|フィリピン ペソ"),
+            ("currency/PKR", "PKR|Rs|パキスタン ルピー"),
+            ("currency/PLN", "PLN|zł|ポーランド ズウォティ"),
+            ("currency/RUB", "RUB|₽|ロシア ルーブル"),
+            ("currency/SAR", "SAR|SAR|サウジ リヤル"),
+            ("currency/SEK", "SEK|kr|スウェーデン クローナ"),
+            ("currency/SGD", "SGD|$|シンガポール ドル"),
+            ("currency/THB", "THB|฿|タイ バーツ"),
+            ("currency/TRY", "TRY|₺|新トルコリラ"),
+            ("currency/TWD", "NT$|$|新台湾ドル"),
+            ("currency/UAH", "UAH|₴|ウクライナ グリブナ"),
+            ("currency/USD", "$|$|米ドル"),
+            ("currency/VND", "₫|₫|ベトナム ドン"),
+            ("currency/ZAR", "ZAR|R|南アフリカ ランド"),
+            ("per", "{0}{/}{}|{0}{/}{}|{0}{毎}{}"),
+            ("per-unit/centimeter", "{0}{/cm}|{0}{/cm}|{0}{/センチメートル}"),
+            ("per-unit/day", "{0}{/日}|{0}{/日}|{0}{/日}"),
+            ("per-unit/foot", "{0}{/ft}|{0}{/ft}|{0}{/フィート}"),
+            ("per-unit/gallon", "{0}{/gal}|{0}{/gal}|{0}{/ガロン}"),
+            ("per-unit/gram", "{0}{/g}|{0}{/g}|{0}{/グラム}"),
+            ("per-unit/hour", "{0}{/時間}|{0}{/時間}|{0}{/時間}"),
+            ("per-unit/inch", "{0}{/in}|{0}{/in}|{0}{/インチ}"),
+            ("per-unit/kilogram", "{0}{/kg}|{0}{/kg}|{0}{/キログラム}"),
+            ("per-unit/kilometer", "{0}{/km}|{0}{/km}|{0}{/キロメートル}"),
+            ("per-unit/liter", "{0}{/L}|{0}{/L}|{0}{/リットル}"),
+            ("per-unit/meter", "{0}{/m}|{0}{/m}|{0}{/メートル}"),
+            ("per-unit/minute", "{0}{/分}|{0}{/分}|{0}{/分}"),
+            ("per-unit/month", "{0}{/月}|{0}{/月}|{0}{/月}"),
+            ("per-unit/ounce", "{0}{/oz}|{0}{/oz}|{0}{/オンス}"),
+            ("per-unit/pound", "{0}{/lb}|{0}{/lb}|{0}{/ポンド}"),
+            ("per-unit/second", "{0}{/秒}|{0}{/秒}|{0}{/秒}"),
+            ("per-unit/week", "{0}{/週}|{0}{/週}|{0}{/週}"),
+            ("per-unit/year", "{0}{/年}|{0}{/年}|{0}{/年}"),
+            ("percent", "{-}{0}{%}"),
+            ("unit/acre", "{0} {ac}|{0}{ac}|{0} {エーカー}"),
+            ("unit/bit", "{0} {bit}|{0}{b}|{0} {ビット}"),
+            ("unit/byte", "{0} {byte}|{0}{B}|{0} {バイト}"),
+            ("unit/celsius", "{0}{°C}||摂氏 {0} {度}"),
+            ("unit/centimeter", "{0} {cm}|{0}{cm}|{0} {センチメートル}"),
+            ("unit/day", "{0} {日}|{0}{d}|{0} {日}"),
+            ("unit/degree", "{0} {度}|{0}{°}|{0} {度}"),
+            ("unit/fahrenheit", "{0}{°F}||華氏 {0} {度}"),
+            ("unit/fluid-ounce", "{0} {fl oz}|{0}{fl oz}|{0} {液量オンス}"),
+            ("unit/foot", "{0} {ft}|{0}{′}|{0} {フィート}"),
+            ("unit/gallon", "{0} {gal}|{0}{gal}|{0} {ガロン}"),
+            ("unit/gigabit", "{0} {Gb}|{0}{Gb}|{0} {ギガビット}"),
+            ("unit/gigabyte", "{0} {GB}|{0}{GB}|{0} {ギガバイト}"),
+            ("unit/gram", "{0} {g}|{0}{g}|{0} {グラム}"),
+            ("unit/hectare", "{0} {ha}|{0}{ha}|{0} {ヘクタール}"),
+            ("unit/hour", "{0} {時間}|{0}{h}|{0} {時間}"),
+            ("unit/inch", "{0} {in}|{0}{″}|{0} {インチ}"),
+            ("unit/kilobit", "{0} {kb}|{0}{kb}|{0} {キロビット}"),
+            ("unit/kilobyte", "{0} {KB}|{0}{KB}|{0} {キロバイト}"),
+            ("unit/kilogram", "{0} {kg}|{0}{kg}|{0} {キログラム}"),
+            ("unit/kilometer", "{0} {km}|{0}{km}|{0} {キロメートル}"),
+            ("unit/kilometer-per-hour", "{0} {km/h}|{0}{km/h}|時速 {0} {キロメートル}"),
+            ("unit/liter", "{0} {L}|{0}{L}|{0} {リットル}"),
+            ("unit/liter-per-kilometer", "{0} {L/km}|{0}{L/km}|{0} {リットル毎キロメートル}"),
+            ("unit/megabit", "{0} {Mb}|{0}{Mb}|{0} {メガビット}"),
+            ("unit/megabyte", "{0} {MB}|{0}{MB}|{0} {メガバイト}"),
+            ("unit/meter", "{0} {m}|{0}{m}|{0} {メートル}"),
+            ("unit/meter-per-second", "{0} {m/s}|{0}{m/s}|秒速 {0} {メートル}"),
+            ("unit/microsecond", "{0} {μs}|{0}{μs}|{0} {マイクロ秒}"),
+            ("unit/mile", "{0} {mi}|{0}{mi}|{0} {マイル}"),
+            ("unit/mile-per-gallon", "{0} {mpg}|{0}{mpg}|{0} {マイル毎ガロン}"),
+            ("unit/mile-per-hour", "{0} {mph}|{0}{mi/h}|時速 {0} {マイル}"),
+            ("unit/mile-scandinavian", "{0} {smi}|{0}{smi}|{0} {スカンジナビアマイル}"),
+            ("unit/milliliter", "{0} {ml}|{0}{ml}|{0} {ミリリットル}"),
+            ("unit/millimeter", "{0} {mm}|{0}{mm}|{0} {ミリメートル}"),
+            ("unit/millisecond", "{0} {ms}|{0}{ms}|{0} {ミリ秒}"),
+            ("unit/minute", "{0} {分}|{0}{m}|{0} {分}"),
+            ("unit/month", "{0} {か月}|{0}{m}|{0} {か月}"),
+            ("unit/nanosecond", "{0} {ns}|{0}{ns}|{0} {ナノ秒}"),
+            ("unit/ounce", "{0} {oz}|{0}{oz}|{0} {オンス}"),
+            ("unit/percent", "{0}{%}||{0} {パーセント}"),
+            ("unit/petabyte", "{0} {PB}|{0}{PB}|{0} {ペタバイト}"),
+            ("unit/pound", "{0} {lb}|{0}{lb}|{0} {ポンド}"),
+            ("unit/second", "{0} {秒}|{0}{s}|{0} {秒}"),
+            ("unit/stone", "{0} {st}|{0}{st}|{0} {ストーン}"),
+            ("unit/terabit", "{0} {Tb}|{0}{Tb}|{0} {テラビット}"),
+            ("unit/terabyte", "{0} {TB}|{0}{TB}|{0} {テラバイト}"),
+            ("unit/week", "{0} {週間}|{0}{w}|{0} {週間}"),
+            ("unit/yard", "{0} {yd}|{0}{yd}|{0} {ヤード}"),
+            ("unit/year", "{0} {年}|{0}{y}|{0} {年}"),
+        ],
+    ),
+    (
+        "ko",
+        &[
+            ("accounting", "({}{0})"),
+            ("compact-long/10", "8|{0}{억}"),
+            ("compact-long/11", "8|{0}{억}"),
+            ("compact-long/12", "12|{0}{조}"),
+            ("compact-long/13", "12|{0}{조}"),
+            ("compact-long/14", "12|{0}{조}"),
+            ("compact-long/3", "3|{0}{천}"),
+            ("compact-long/4", "4|{0}{만}"),
+            ("compact-long/5", "4|{0}{만}"),
+            ("compact-long/6", "4|{0}{만}"),
+            ("compact-long/7", "4|{0}{만}"),
+            ("compact-long/8", "8|{0}{억}"),
+            ("compact-long/9", "8|{0}{억}"),
+            ("compact-short/10", "8|{0}{억}"),
+            ("compact-short/11", "8|{0}{억}"),
+            ("compact-short/12", "12|{0}{조}"),
+            ("compact-short/13", "12|{0}{조}"),
+            ("compact-short/14", "12|{0}{조}"),
+            ("compact-short/3", "3|{0}{천}"),
+            ("compact-short/4", "4|{0}{만}"),
+            ("compact-short/5", "4|{0}{만}"),
+            ("compact-short/6", "4|{0}{만}"),
+            ("compact-short/7", "4|{0}{만}"),
+            ("compact-short/8", "8|{0}{억}"),
+            ("compact-short/9", "8|{0}{억}"),
+            ("currency", "{-}{}{0}"),
+            ("currency-name", "{0} {}"),
+            ("currency/AED", "AED|AED|아랍에미리트 디르함"),
+            ("currency/ARS", "ARS|$|아르헨티나 페소"),
+            ("currency/AUD", "AU$|$|호주 달러"),
+            ("currency/BRL", "R$|R$|브라질 레알"),
+            ("currency/CAD", "CA$|$|캐나다 달러"),
+            ("currency/CHF", "CHF|CHF|스위스 프랑"),
+            ("currency/CLP", "CLP|$|칠레 페소"),
+            ("currency/CNY", "CN¥|¥|중국 위안화"),
+            ("currency/COP", "COP|$|콜롬비아 페소"),
+            ("currency/CZK", "CZK|Kč|체코 코루나"),
+            ("currency/DKK", "DKK|kr|덴마크 크로네"),
+            ("currency/EGP", "EGP|E£|이집트 파운드"),
+            ("currency/EUR", "€|€|유로"),
+            ("currency/GBP", "£|£|영국 파운드"),
+            ("currency/HKD", "HK$|$|홍콩 달러"),
+            ("currency/HUF", "HUF|Ft|헝가리 포린트"),
+            ("currency/IDR", "IDR|Rp|인도네시아 루피아"),
+            ("currency/ILS", "₪|₪|이스라엘 신권 세켈"),
+            ("currency/INR", "₹|₹|인도 루피"),
+            ("currency/JPY", "JP¥|¥|일본 엔화"),
+            ("currency/KRW", "₩|₩|대한민국 원"),
+            ("currency/MXN", "MX$|$|멕시코 페소"),
+            ("currency/MYR", "MYR|RM|말레이시아 링깃"),
+            ("currency/NOK", "NOK|kr|노르웨이 크로네"),
+            ("currency/NZD", "NZ$|$|뉴질랜드 달러"),
+            ("currency/PHP", "PHP|₱|필리핀 페소"),
+            ("currency/PKR", "PKR|Rs|파키스탄 루피"),
+            ("currency/PLN", "PLN|zł|폴란드 즈워티"),
+            ("currency/RUB", "RUB|₽|러시아 루블"),
+            ("currency/SAR", "SAR|SAR|사우디아라비아 리얄"),
+            ("currency/SEK", "SEK|kr|스웨덴 크로나"),
+            ("currency/SGD", "SGD|$|싱가포르 달러"),
+            ("currency/THB", "THB|฿|태국 바트"),
+            ("currency/TRY", "TRY|₺|터키 리라"),
+            ("currency/TWD", "NT$|NT$|신 타이완 달러"),
+            ("currency/UAH", "UAH|₴|우크라이나 그리브나"),
+            ("currency/USD", "US$|$|미국 달러"),
+            ("currency/VND", "₫|₫|베트남 동"),
+            ("currency/ZAR", "ZAR|R|남아프리카 랜드"),
+            ("per", "{0}{/}{}|{0}{/}{}|{}{당 }{0}"),
+            ("per-unit/centimeter", "{0}{/cm}|{0}{/cm}|{센티미터당 }{0}"),
+            ("per-unit/day", "{0}{/일}|{0}{/일}|{일당 }{0}"),
+            ("per-unit/foot", "{0}{/ft}|{0}{/ft}|{피트당 }{0}"),
+            ("per-unit/gallon", "{0}{/gal}|{0}{/gal}|{갤런당 }{0}"),
+            ("per-unit/gram", "{0}{/g}|{0}{/g}|{그램당 }{0}"),
+            ("per-unit/hour", "{0}{/h}|{0}{/시간}|{시간당 }{0}"),
+            ("per-unit/inch", "{0}{/in}|{0}{/in}|{인치당 }{0}"),
+            ("per-unit/kilogram", "{0}{/kg}|{0}{/kg}|{킬로그램당 }{0}"),
+            ("per-unit/kilometer", "{0}{/km}|{0}{/km}|{킬로미터당 }{0}"),
+            ("per-unit/liter", "{0}{/L}|{0}{/L}|{리터당 }{0}"),
+            ("per-unit/meter", "{0}{/m}|{0}{/m}|{미터당 }{0}"),
+            ("per-unit/minute", "{0}{/min}|{0}{/분}|{분당 }{0}"),
+            ("per-unit/month", "{0}{/월}|{0}{/월}|{월당 }{0}"),
+            ("per-unit/ounce", "{0}{/oz}|{0}{/oz}|{온스당 }{0}"),
+            ("per-unit/pound", "{0}{/lb}|{0}{/lb}|{파운드당 }{0}"),
+            ("per-unit/second", "{0}{/s}|{0}{/초}|{초당 }{0}"),
+            ("per-unit/week", "{0}{/주}|{0}{/주}|{주당 }{0}"),
+            ("per-unit/year", "{0}{/년}|{0}{/년}|{연당 }{0}"),
+            ("percent", "{-}{0}{%}"),
+            ("unit/acre", "{0}{ac}||{0}{에이커}"),
+            ("unit/bit", "{0}{bit}||{0}{비트}"),
+            ("unit/byte", "{0}{byte}||{0}{바이트}"),
+            ("unit/celsius", "{0}{°C}||섭씨 {0}{도}"),
+            ("unit/centimeter", "{0}{cm}||{0}{센티미터}"),
+            ("unit/day", "{0}{일}||{0}{일}"),
+            ("unit/degree", "{0}{°}||{0}{도}"),
+            ("unit/fahrenheit", "{0}{°F}||화씨 {0}{도}"),
+            ("unit/fluid-ounce", "{0}{fl oz}||{0}{액량 온스}"),
+            ("unit/foot", "{0}{ft}|{0}{′}|{0}{피트}"),
+            ("unit/gallon", "{0}{gal}||{0}{갤런}"),
+            ("unit/gigabit", "{0}{Gb}||{0}{기가비트}"),
+            ("unit/gigabyte", "{0}{GB}||{0}{기가바이트}"),
+            ("unit/gram", "{0}{g}||{0}{그램}"),
+            ("unit/hectare", "{0}{ha}||{0}{헥타르}"),
+            ("unit/hour", "{0}{시간}||{0}{시간}"),
+            ("unit/inch", "{0}{in}|{0}{″}|{0}{인치}"),
+            ("unit/kilobit", "{0}{kb}||{0}{킬로비트}"),
+            ("unit/kilobyte", "{0}{kB}||{0}{킬로바이트}"),
+            ("unit/kilogram", "{0}{kg}||{0}{킬로그램}"),
+            ("unit/kilometer", "{0}{km}||{0}{킬로미터}"),
+            ("unit/kilometer-per-hour", "{0}{km/h}||시속 {0}{킬로미터}"),
+            ("unit/liter", "{0}{L}|{0}{ℓ}|{0}{리터}"),
+            ("unit/liter-per-kilometer", "{0}{L/km}||킬로미터당 {0}{리터}"),
+            ("unit/megabit", "{0}{Mb}||{0}{메가비트}"),
+            ("unit/megabyte", "{0}{MB}||{0}{메가바이트}"),
+            ("unit/meter", "{0}{m}||{0}{미터}"),
+            ("unit/meter-per-second", "{0}{m/s}||초속 {0}{미터}"),
+            ("unit/microsecond", "{0}{μs}||{0}{마이크로초}"),
+            ("unit/mile", "{0}{mi}||{0}{마일}"),
+            ("unit/mile-per-gallon", "{0}{mpg}||갤런당 {0}{마일}"),
+            ("unit/mile-per-hour", "{0}{mi/h}|{0}{mph}|시속 {0}{마일}"),
+            ("unit/mile-scandinavian", "{0}{smi}||{0}{스칸디나비아 마일}"),
+            ("unit/milliliter", "{0}{mL}||{0}{밀리리터}"),
+            ("unit/millimeter", "{0}{mm}||{0}{밀리미터}"),
+            ("unit/millisecond", "{0}{ms}||{0}{밀리초}"),
+            ("unit/minute", "{0}{분}||{0}{분}"),
+            ("unit/month", "{0}{개월}||{0}{개월}"),
+            ("unit/nanosecond", "{0}{ns}||{0}{나노초}"),
+            ("unit/ounce", "{0}{oz}||{0}{온스}"),
+            ("unit/percent", "{0}{%}||{0}{%}"),
+            ("unit/petabyte", "{0}{PB}||{0}{페타바이트}"),
+            ("unit/pound", "{0}{lb}||{0}{파운드}"),
+            ("unit/second", "{0}{초}||{0}{초}"),
+            ("unit/stone", "{0}{st}||{0}{스톤}"),
+            ("unit/terabit", "{0}{Tb}||{0}{테라비트}"),
+            ("unit/terabyte", "{0}{TB}||{0}{테라바이트}"),
+            ("unit/week", "{0}{주}||{0}{주}"),
+            ("unit/yard", "{0}{yd}||{0}{야드}"),
+            ("unit/year", "{0}{년}||{0}{년}"),
+        ],
+    ),
+    (
+        "nl",
+        &[
+            ("accounting", "({}\u{a0}{0})"),
+            ("compact-long/10", "9|{0} {miljard}"),
+            ("compact-long/11", "9|{0} {miljard}"),
+            ("compact-long/12", "12|{0} {biljoen}"),
+            ("compact-long/13", "12|{0} {biljoen}"),
+            ("compact-long/14", "12|{0} {biljoen}"),
+            ("compact-long/3", "3|{0} {duizend}"),
+            ("compact-long/4", "3|{0} {duizend}"),
+            ("compact-long/5", "3|{0} {duizend}"),
+            ("compact-long/6", "6|{0} {miljoen}"),
+            ("compact-long/7", "6|{0} {miljoen}"),
+            ("compact-long/8", "6|{0} {miljoen}"),
+            ("compact-long/9", "9|{0} {miljard}"),
+            ("compact-short/10", "9|{0}\u{a0}{mld.}"),
+            ("compact-short/11", "9|{0}\u{a0}{mld.}"),
+            ("compact-short/12", "12|{0}\u{a0}{bln.}"),
+            ("compact-short/13", "12|{0}\u{a0}{bln.}"),
+            ("compact-short/14", "12|{0}\u{a0}{bln.}"),
+            ("compact-short/3", "3|{0}{K}"),
+            ("compact-short/4", "3|{0}{K}"),
+            ("compact-short/5", "3|{0}{K}"),
+            ("compact-short/6", "6|{0}\u{a0}{mln.}"),
+            ("compact-short/7", "6|{0}\u{a0}{mln.}"),
+            ("compact-short/8", "6|{0}\u{a0}{mln.}"),
+            ("compact-short/9", "9|{0}\u{a0}{mld.}"),
+            ("currency", "{}\u{a0}{-}{0}"),
+            ("currency-name", "{0} {}"),
+            ("currency/AED", "AED|AED|VAE-dirham"),
+            ("currency/ARS", "ARS|$|Argentijnse peso"),
+            ("currency/AUD", "AU$|$|Australische dollar"),
+            ("currency/BRL", "R$|R$|Braziliaanse real"),
+            ("currency/CAD", "C$|$|Canadese dollar"),
+            ("currency/CHF", "CHF|CHF|Zwitserse frank"),
+            ("currency/CLP", "CLP|$|Chileense peso"),
+            ("currency/CNY", "CN¥|¥|Chinese yuan"),
+            ("currency/COP", "COP|$|Colombiaanse peso"),
+            ("currency/CZK", "CZK|Kč|one=Tsjechische kroon;other=Tsjechische kronen"),
+            ("currency/DKK", "DKK|kr|one=Deense kroon;other=Deense kronen"),
+            ("currency/EGP", "EGP|E£|Egyptisch pond"),
+            ("currency/EUR", "€|€|euro"),
+            ("currency/GBP", "£|£|Britse pond"),
+            ("currency/HKD", "HK$|$|Hongkongse dollar"),
+            ("currency/HUF", "HUF|Ft|Hongaarse forint"),
+            ("currency/IDR", "IDR|Rp|Indonesische roepia"),
+            ("currency/ILS", "₪|₪|Israëlische nieuwe shekel"),
+            ("currency/INR", "₹|₹|Indiase roepie"),
+            ("currency/JPY", "JP¥|¥|Japanse yen"),
+            ("currency/KRW", "₩|₩|Zuid-Koreaanse won"),
+            ("currency/MXN", "MX$|$|Mexicaanse peso"),
+            ("currency/MYR", "MYR|RM|Maleisische ringgit"),
+            ("currency/NOK", "NOK|kr|one=Noorse kroon;other=Noorse kronen"),
+            ("currency/NZD", "NZ$|$|Nieuw-Zeelandse dollar"),
+            ("currency/PHP", "PHP|₱|Filipijnse peso"),
+            ("currency/PKR", "PKR|Rs|Pakistaanse roepie"),
+            ("currency/PLN", "PLN|zł|Poolse zloty"),
+            ("currency/RUB", "RUB|₽|Russische roebel"),
+            ("currency/SAR", "SAR|SAR|Saoedi-Arabische riyal"),
+            ("currency/SEK", "SEK|kr|one=Zweedse kroon;other=Zweedse kronen"),
+            ("currency/SGD", "SGD|$|Singaporese dollar"),
+            ("currency/THB", "฿|฿|Thaise baht"),
+            ("currency/TRY", "TRY|₺|Turkse lira"),
+            ("currency/TWD", "NT$|NT$|Nieuwe Taiwanese dollar"),
+            ("currency/UAH", "UAH|₴|Oekraïense hryvnia"),
+            ("currency/USD", "US$|$|Amerikaanse dollar"),
+            ("currency/VND", "₫|₫|Vietnamese dong"),
+            ("currency/ZAR", "ZAR|R|Zuid-Afrikaanse rand"),
+            ("per", "{0}{/}{}|{0}{/}{}|{0}{ per }{}"),
+            ("per-unit/centimeter", "{0}{/cm}|{0}{/cm}|{0}{ per centimeter}"),
+            ("per-unit/day", "{0}{/dag}|{0}{/d}|{0}{ per dag}"),
+            ("per-unit/foot", "{0}{/ft}|{0}{/ft}|{0}{ per voet}"),
+            ("per-unit/gallon", "{0}{/gal}|{0}{/gal}|{0}{ per gallon}"),
+            ("per-unit/gram", "{0}{/g}|{0}{/g}|{0}{ per gram}"),
+            ("per-unit/hour", "{0}{/uur}|{0}{/u}|{0}{ per uur}"),
+            ("per-unit/inch", "{0}{/in}|{0}{/in}|{0}{ per inch}"),
+            ("per-unit/kilogram", "{0}{/kg}|{0}{/kg}|{0}{ per kilogram}"),
+            ("per-unit/kilometer", "{0}{/km}|{0}{/km}|{0}{ per kilometer}"),
+            ("per-unit/liter", "{0}{/l}|{0}{/l}|{0}{ per liter}"),
+            ("per-unit/meter", "{0}{/m}|{0}{/m}|{0}{ per meter}"),
+            ("per-unit/minute", "{0}{/min}|{0}{/m}|{0}{ per minuut}"),
+            ("per-unit/month", "{0}{/mnd}|{0}{/m}|{0}{ per maand}"),
+            ("per-unit/ounce", "{0}{/oz}|{0}{/oz}|{0}{ per ounce}"),
+            ("per-unit/pound", "{0}{/lb}|{0}{/lb}|{0}{ per pound}"),
+            ("per-unit/second", "{0}{/sec}|{0}{/s}|{0}{ per seconde}"),
+            ("per-unit/week", "{0}{/wk}|{0}{/w}|{0}{ per week}"),
+            ("per-unit/year", "{0}{/jr}|{0}{/jr}|{0}{ per jaar}"),
+            ("percent", "{-}{0}{%}"),
+            ("unit/acre", "one={0} {acre};other={0} {acres}||one={0} {acre};other={0} {acres}"),
+            ("unit/bit", "one={0} {bit};other={0} {bits}||one={0} {bit};other={0} {bits}"),
+            ("unit/byte", "{0} {byte}||{0} {byte}"),
+            ("unit/celsius", "{0}{°C}|{0}{°}|one={0} {graad Celsius};other={0} {graden Celsius}"),
+            ("unit/centimeter", "{0} {cm}||{0} {centimeter}"),
+            ("unit/day", "one={0} {dag};other={0} {dagen}|{0} {d}|one={0} {dag};other={0} {dagen}"),
+            ("unit/degree", "{0}{°}||one={0} {booggraad};other={0} {booggraden}"),
+            (
+                "unit/fahrenheit",
+                "{0}{°F}||one={0} {graad Fahrenheit};other={0} {graden Fahrenheit}",
+            ),
+            ("unit/fluid-ounce", "{0} {fl oz}||{0} {fluid ounce}"),
+            ("unit/foot", "{0} {ft}||{0} {voet}"),
+            ("unit/gallon", "{0} {gal}||{0} {gallon}"),
+            ("unit/gigabit", "{0} {Gb}||one={0} {gigabit};other={0} {gigabits}"),
+            ("unit/gigabyte", "{0} {GB}||{0} {gigabyte}"),
+            ("unit/gram", "{0} {g}||{0} {gram}"),
+            ("unit/hectare", "{0} {ha}||{0} {hectare}"),
+            ("unit/hour", "{0} {uur}|{0} {u}|{0} {uur}"),
+            ("unit/inch", "{0} {in}|{0}{″}|one={0} {inch};other={0} {inches}"),
+            ("unit/kilobit", "{0} {kb}||one={0} {kilobit};other={0} {kilobits}"),
+            ("unit/kilobyte", "{0} {kB}||{0} {kilobyte}"),
+            ("unit/kilogram", "{0} {kg}||{0} {kilogram}"),
+            ("unit/kilometer", "{0} {km}||{0} {kilometer}"),
+            ("unit/kilometer-per-hour", "{0} {km/u}||{0} {kilometer per uur}"),
+            ("unit/liter", "{0} {l}||{0} {liter}"),
+            ("unit/liter-per-kilometer", "{0} {l/km}||{0} {liter per kilometer}"),
+            ("unit/megabit", "{0} {Mb}||one={0} {megabit};other={0} {megabits}"),
+            ("unit/megabyte", "{0} {MB}||{0} {megabyte}"),
+            ("unit/meter", "{0} {m}||{0} {meter}"),
+            ("unit/meter-per-second", "{0} {m/s}||{0} {meter per seconde}"),
+            ("unit/microsecond", "{0} {μs}||one={0} {microseconde};other={0} {microseconden}"),
+            ("unit/mile", "{0} {mi}||{0} {mijl}"),
+            ("unit/mile-per-gallon", "{0} {mpg}||{0} {mijl per gallon}"),
+            ("unit/mile-per-hour", "{0} {mi/h}||{0} {mijl per uur}"),
+            ("unit/mile-scandinavian", "{0} {smi}||{0} {Scandinavische mijl}"),
+            ("unit/milliliter", "{0} {ml}||{0} {milliliter}"),
+            ("unit/millimeter", "{0} {mm}||{0} {millimeter}"),
+            ("unit/millisecond", "{0} {ms}||one={0} {milliseconde};other={0} {milliseconden}"),
+            ("unit/minute", "{0} {min}|{0} {m}|one={0} {minuut};other={0} {minuten}"),
+            ("unit/month", "{0} {mnd}|{0} {m}|one={0} {maand};other={0} {maanden}"),
+            ("unit/nanosecond", "{0} {ns}||one={0} {nanoseconde};other={0} {nanoseconden}"),
+            ("unit/ounce", "{0} {oz}||{0} {ounce}"),
+            ("unit/percent", "{0}{%}||{0} {procent}"),
+            ("unit/petabyte", "{0} {PB}||{0} {petabyte}"),
+            ("unit/pound", "{0} {lb}||{0} {pound}"),
+            ("unit/second", "{0} {sec}|{0} {s}|one={0} {seconde};other={0} {seconden}"),
+            ("unit/stone", "{0} {st}||{0} {stone}"),
+            ("unit/terabit", "{0} {Tb}||one={0} {terabit};other={0} {terabits}"),
+            ("unit/terabyte", "{0} {TB}||{0} {terabyte}"),
+            ("unit/week", "one={0} {wk};other={0} {wkn}|{0} {w}|one={0} {week};other={0} {weken}"),
+            ("unit/yard", "{0} {yd}||one={0} {yard};other={0} {yards}"),
+            ("unit/year", "{0} {jr}||{0} {jaar}"),
+        ],
+    ),
+    (
+        "nl-BQ",
+        &[
+            ("currency/USD", "$|$|Amerikaanse dollar"),
+        ],
+    ),
+    (
+        "pl",
+        &[
+            ("accounting", "({0}\u{a0}{})"),
+            ("compact-long/10", "9|few={0} {miliardy};other={0} {miliardów}"),
+            ("compact-long/11", "9|few={0} {miliardy};other={0} {miliardów}"),
+            (
+                "compact-long/12",
+                "12|one={0} {bilion};few={0} {biliony};many={0} {bilionów};other={0} {biliona}",
+            ),
+            ("compact-long/13", "12|few={0} {biliony};other={0} {bilionów}"),
+            ("compact-long/14", "12|few={0} {biliony};other={0} {bilionów}"),
+            (
+                "compact-long/3",
+                "3|one={0} {tysiąc};few={0} {tysiące};many={0} {tysięcy};other={0} {tysiąca}",
+            ),
+            ("compact-long/4", "3|few={0} {tysiące};other={0} {tysięcy}"),
+            ("compact-long/5", "3|few={0} {tysiące};other={0} {tysięcy}"),
+            (
+                "compact-long/6",
+                "6|one={0} {milion};few={0} {miliony};many={0} {milionów};other={0} {miliona}",
+            ),
+            ("compact-long/7", "6|few={0} {miliony};other={0} {milionów}"),
+            ("compact-long/8", "6|few={0} {miliony};other={0} {milionów}"),
+            (
+                "compact-long/9",
+                "9|one={0} {miliard};few={0} {miliardy};many={0} {miliardów};other={0} {miliarda}",
+            ),
+            ("compact-short/10", "9|{0}\u{a0}{mld}"),
+            ("compact-short/11", "9|{0}\u{a0}{mld}"),
+            ("compact-short/12", "12|{0}\u{a0}{bln}"),
+            ("compact-short/13", "12|{0}\u{a0}{bln}"),
+            ("compact-short/14", "12|{0}\u{a0}{bln}"),
+            ("compact-short/3", "3|{0}\u{a0}{tys.}"),
+            ("compact-short/4", "3|{0}\u{a0}{tys.}"),
+            ("compact-short/5", "3|{0}\u{a0}{tys.}"),
+            ("compact-short/6", "6|{0}\u{a0}{mln}"),
+            ("compact-short/7", "6|{0}\u{a0}{mln}"),
+            ("compact-short/8", "6|{0}\u{a0}{mln}"),
+            ("compact-short/9", "9|{0}\u{a0}{mld}"),
+            ("currency", "{-}{0}\u{a0}{}"),
+            ("currency-name", "{0} {}"),
+            (
+                "currency/AED",
+                "AED|AED|one=dirham ZEA;few=dirhamy ZEA;many=dirhamów ZEA;other=dirhama ZEA",
+            ),
+            (
+                "currency/ARS",
+                "ARS|$|one=peso argentyńskie;few=pesos argentyńskie;many=pesos argentyńskich;other=peso argentyńskiego",
+            ),
+            (
+                "currency/AUD",
+                "AUD|$|one=dolar australijski;few=dolary australijskie;many=dolarów australijskich;other=dolara australijskiego",
+            ),
+            (
+                "currency/BRL",
+                "R$|R$|one=real brazylijski;few=reale brazylijskie;many=reali brazylijskich;other=reala brazylijskiego",
+            ),
+            (
+                "currency/CAD",
+                "CAD|$|one=dolar kanadyjski;few=dolary kanadyjskie;many=dolarów kanadyjskich;other=dolara kanadyjskiego",
+            ),
+            (
+                "currency/CHF",
+                "CHF|CHF|one=frank szwajcarski;few=franki szwajcarskie;many=franków szwajcarskich;other=franka szwajcarskiego",
+            ),
+            (
+                "currency/CLP",
+                "CLP|$|one=peso chilijskie;few=pesos chilijskie;many=pesos chilijskich;other=peso chilijskiego",
+            ),
+            (
+                "currency/CNY",
+                "CNY|¥|one=juan chiński;few=juany chińskie;many=juanów chińskich;other=juana chińskiego",
+            ),
+            (
+                "currency/COP",
+                "COP|$|one=peso kolumbijskie;few=pesos kolumbijskie;many=pesos kolumbijskich;other=peso kolumbijskiego",
+            ),
+            (
+                "currency/CZK",
+                "CZK|Kč|one=korona czeska;few=korony czeskie;many=koron czeskich;other=korony czeskiej",
+            ),
+            (
+                "currency/DKK",
+                "DKK|kr|one=korona duńska;few=korony duńskie;many=koron duńskich;other=korony duńskiej",
+            ),
+            (
+                "currency/EGP",
+                "EGP|E£|one=funt egipski;few=funty egipskie;many=funtów egipskich;other=funta egipskiego",
+            ),
+            ("currency/EUR", "€|€|euro"),
+            (
+                "currency/GBP",
+                "GBP|£|one=funt szterling;few=funty szterlingi;many=funtów szterlingów;other=funta szterlinga",
+            ),
+            (
+                "currency/HKD",
+                "HKD|$|one=dolar hongkoński;few=dolary hongkońskie;many=dolarów hongkońskich;other=dolara hongkońskiego",
+            ),
+            (
+                "currency/HUF",
+                "HUF|Ft|one=forint węgierski;few=forinty węgierskie;many=forintów węgierskich;other=forinta węgierskiego",
+            ),
+            (
+                "currency/IDR",
+                "IDR|Rp|one=rupia indonezyjska;few=rupie indonezyjskie;many=rupii indonezyjskich;other=rupii indonezyjskiej",
+            ),
+            (
+                "currency/ILS",
+                "ILS|₪|one=nowy szekel izraelski;few=nowe szekle izraelskie;many=nowych szekli izraelskich;other=nowego szekla izraelskiego",
+            ),
+            (
+                "currency/INR",
+                "INR|₹|one=rupia indyjska;few=rupie indyjskie;many=rupii indyjskich;other=rupii indyjskiej",
+            ),
+            (
+                "currency/JPY",
+                "JPY|¥|one=jen japoński;few=jeny japońskie;many=jenów japońskich;other=jena japońskiego",
+            ),
+            (
+                "currency/KRW",
+                "KRW|₩|one=won południowokoreański;few=wony południowokoreańskie;many=wonów południowokoreańskich;other=wona południowokoreańskiego",
+            ),
+            (
+                "currency/MXN",
+                "MXN|$|one=peso meksykańskie;few=pesos meksykańskie;many=pesos meksykańskich;other=peso meksykańskiego",
+            ),
+            (
+                "currency/MYR",
+                "MYR|RM|one=ringgit malezyjski;few=ringgity malezyjskie;many=ringgitów malezyjskich;other=ringgita malezyjskiego",
+            ),
+            (
+                "currency/NOK",
+                "NOK|kr|one=korona norweska;few=korony norweskie;many=koron norweskich;other=korony norweskiej",
+            ),
+            (
+                "currency/NZD",
+                "NZD|$|one=dolar nowozelandzki;few=dolary nowozelandzkie;many=dolarów nowozelandzkich;other=dolara nowozelandzkiego",
+            ),
+            (
+                "currency/PHP",
+                "PHP|₱|one=peso filipińskie;few=pesos filipińskie;many=pesos filipińskich;other=peso filipińskiego",
+            ),
+            (
+                "currency/PKR",
+                "PKR|Rs|one=rupia pakistańska;few=rupie pakistańskie;many=rupii pakistańskich;other=rupii pakistańskiej",
+            ),
+            (
+                "currency/PLN",
+                "zł|zł|one=złoty polski;few=złote polskie;many=złotych polskich;other=złotego polskiego",
+            ),
+            (
+                "currency/RUB",
+                "RUB|₽|one=rubel rosyjski;few=ruble rosyjskie;many=rubli rosyjskich;other=rubla rosyjskiego",
+            ),
+            (
+                "currency/SAR",
+                "SAR|SAR|one=rial saudyjski;few=riale saudyjskie;many=riali saudyjskich;other=riala saudyjskiego",
+            ),
+            (
+                "currency/SEK",
+                "SEK|kr|one=korona szwedzka;few=korony szwedzkie;many=koron szwedzkich;other=korony szwedzkiej",
+            ),
+            (
+                "currency/SGD",
+                "SGD|$|one=dolar singapurski;few=dolary singapurskie;many=dolarów singapurskich;other=dolara singapurskiego",
+            ),
+            (
+                "currency/THB",
+                "THB|฿|one=baht tajski;few=bahty tajskie;many=bahtów tajskich;other=bahta tajskiego",
+            ),
+            (
+                "currency/TRY",
+                "TRY|₺|one=lira turecka;few=liry tureckie;many=lir tureckich;other=liry tureckiej",
+            ),
+            (
+                "currency/TWD",
+                "TWD|NT$|one=nowy dolar tajwański;few=nowe dolary tajwańskie;many=nowych dolarów tajwańskich;other=nowego dolara tajwańskiego",
+            ),
+            (
+                "currency/UAH",
+                "UAH|₴|one=hrywna ukraińska;few=hrywny ukraińskie;many=hrywien ukraińskich;other=hrywny ukraińskiej",
+            ),
+            (
+                "currency/USD",
+                "USD|$|one=dolar amerykański;few=dolary amerykańskie;many=dolarów amerykańskich;other=dolara amerykańskiego",
+            ),
+            (
+                "currency/VND",
+                "VND|₫|one=dong wietnamski;few=dongi wietnamskie;many=dongów wietnamskich;other=donga wietnamskiego",
+            ),
+            (
+                "currency/ZAR",
+                "ZAR|R|one=rand południowoafrykański;few=randy południowoafrykańskie;many=randów południowoafrykańskich;other=randa południowoafrykańskiego",
+            ),
+            ("per", "{0}{/}{}|{0}{/}{}|{0}{ na }{}"),
+            ("per-unit/centimeter", "{0}{/cm}||{0}{ na centymetr}"),
+            ("per-unit/day", "{0}{/dobę}|{0}{/d.}|{0}{ na dobę}"),
+            ("per-unit/foot", "{0}{/ft}||{0}{ na stopę}"),
+            ("per-unit/gallon", "{0}{/gal am.}|{0}{/gal am.}|{0}{ na galon amerykański}"),
+            ("per-unit/gram", "{0}{/g}||{0}{ na gram}"),
+            ("per-unit/hour", "{0}{/godz.}|{0}{/h}|{0}{ na godzinę}"),
+            ("per-unit/inch", "{0}{/cal}|{0}{/cal}|{0}{ na cal}"),
+            ("per-unit/kilogram", "{0}{/kg}||{0}{ na kilogram}"),
+            ("per-unit/kilometer", "{0}{/km}||{0}{ na kilometr}"),
+            ("per-unit/liter", "{0}{/l}||{0}{ na litr}"),
+            ("per-unit/meter", "{0}{/m}||{0}{ na metr}"),
+            ("per-unit/minute", "{0}{/min}||{0}{ na minutę}"),
+            ("per-unit/month", "{0}{/mies.}|{0}{/m-c}|{0}{ na miesiąc}"),
+            ("per-unit/ounce", "{0}{/oz}||{0}{ na uncję}"),
+            ("per-unit/pound", "{0}{/funt}|{0}{/funt}|{0}{ na funt}"),
+            ("per-unit/second", "{0}{/s}||{0}{ na sekundę}"),
+            ("per-unit/week", "{0}{/tydz.}|{0}{/t.}|{0}{ na tydzień}"),
+            ("per-unit/year", "{0}{/rok}|{0}{/rok}|{0}{ na rok}"),
+            ("percent", "{-}{0}{%}"),
+            (
+                "unit/acre",
+                "one={0} {akr};few={0} {akry};many={0} {akrów};other={0} {akra}||one={0} {akr};few={0} {akry};many={0} {akrów};other={0} {akra}",
+            ),
+            ("unit/bit", "{0} {b}||one={0} {bit};few={0} {bity};many={0} {bitów};other={0} {bita}"),
+            (
+                "unit/byte",
+                "{0} {B}||one={0} {bajt};few={0} {bajty};many={0} {bajtów};other={0} {bajta}",
+            ),
+            (
+                "unit/celsius",
+                "{0} {st. C}|{0}{°C}|one={0} {stopień Celsjusza};few={0} {stopnie Celsjusza};many={0} {stopni Celsjusza};other={0} {stopnia Celsjusza}",
+            ),
+            (
+                "unit/centimeter",
+                "{0} {cm}||one={0} {centymetr};few={0} {centymetry};many={0} {centymetrów};other={0} {centymetra}",
+            ),
+            (
+                "unit/day",
+                "one={0} {doba};many={0} {dób};other={0} {doby}|{0} {d.}|one={0} {doba};many={0} {dób};other={0} {doby}",
+            ),
+            (
+                "unit/degree",
+                "{0}{°}||one={0} {stopień};few={0} {stopnie};many={0} {stopni};other={0} {stopnia}",
+            ),
+            (
+                "unit/fahrenheit",
+                "{0}{°F}||one={0} {stopień Fahrenheita};few={0} {stopnie Fahrenheita};many={0} {stopni Fahrenheita};other={0} {stopnia Fahrenheita}",
+            ),
+            (
+                "unit/fluid-ounce",
+                "{0} {fl oz am.}||one={0} {uncja płynu amerykańska};few={0} {uncje płynu amerykańskie};many={0} {uncji płynu amerykańskich};other={0} {uncji płynu amerykańskiej}",
+            ),
+            ("unit/foot", "{0} {ft}||one={0} {stopa};many={0} {stóp};other={0} {stopy}"),
+            (
+                "unit/gallon",
+                "{0} {gal am.}||one={0} {galon amerykański};few={0} {galony amerykańskie};many={0} {galonów amerykańskich};other={0} {galona amerykańskiego}",
+            ),
+            (
+                "unit/gigabit",
+                "{0} {Gb}||one={0} {gigabit};few={0} {gigabity};many={0} {gigabitów};other={0} {gigabita}",
+            ),
+            (
+                "unit/gigabyte",
+                "{0} {GB}||one={0} {gigabajt};few={0} {gigabajty};many={0} {gigabajtów};other={0} {gigabajta}",
+            ),
+            (
+                "unit/gram",
+                "{0} {g}||one={0} {gram};few={0} {gramy};many={0} {gramów};other={0} {grama}",
+            ),
+            (
+                "unit/hectare",
+                "{0} {ha}||one={0} {hektar};few={0} {hektary};many={0} {hektarów};other={0} {hektara}",
+            ),
+            (
+                "unit/hour",
+                "{0} {godz.}|{0} {h}|one={0} {godzina};many={0} {godzin};other={0} {godziny}",
+            ),
+            (
+                "unit/inch",
+                "one={0} {cal};few={0} {cale};many={0} {cali};other={0} {cala}|{0}{″}|one={0} {cal};few={0} {cale};many={0} {cali};other={0} {cala}",
+            ),
+            (
+                "unit/kilobit",
+                "{0} {kb}||one={0} {kilobit};few={0} {kilobity};many={0} {kilobitów};other={0} {kilobita}",
+            ),
+            (
+                "unit/kilobyte",
+                "{0} {kB}||one={0} {kilobajt};few={0} {kilobajty};many={0} {kilobajtów};other={0} {kilobajta}",
+            ),
+            (
+                "unit/kilogram",
+                "{0} {kg}||one={0} {kilogram};few={0} {kilogramy};many={0} {kilogramów};other={0} {kilograma}",
+            ),
+            (
+                "unit/kilometer",
+                "{0} {km}||one={0} {kilometr};few={0} {kilometry};many={0} {kilometrów};other={0} {kilometra}",
+            ),
+            (
+                "unit/kilometer-per-hour",
+                "{0} {km/godz.}||one={0} {kilometr na godzinę};few={0} {kilometry na godzinę};many={0} {kilometrów na godzinę};other={0} {kilometra na godzinę}",
+            ),
+            (
+                "unit/liter",
+                "{0} {l}||one={0} {litr};few={0} {litry};many={0} {litrów};other={0} {litra}",
+            ),
+            (
+                "unit/liter-per-kilometer",
+                "{0} {l/km}||one={0} {litr na kilometr};few={0} {litry na kilometr};many={0} {litrów na kilometr};other={0} {litra na kilometr}",
+            ),
+            (
+                "unit/megabit",
+                "{0} {Mb}||one={0} {megabit};few={0} {megabity};many={0} {megabitów};other={0} {megabita}",
+            ),
+            (
+                "unit/megabyte",
+                "{0} {MB}||one={0} {megabajt};few={0} {megabajty};many={0} {megabajtów};other={0} {megabajta}",
+            ),
+            (
+                "unit/meter",
+                "{0} {m}||one={0} {metr};few={0} {metry};many={0} {metrów};other={0} {metra}",
+            ),
+            (
+                "unit/meter-per-second",
+                "{0} {m/s}||one={0} {metr na sekundę};few={0} {metry na sekundę};many={0} {metrów na sekundę};other={0} {metra na sekundę}",
+            ),
+            (
+                "unit/microsecond",
+                "{0} {μs}||one={0} {mikrosekunda};many={0} {mikrosekund};other={0} {mikrosekundy}",
+            ),
+            (
+                "unit/mile",
+                "one={0} {mila};few={0} {mile};many={0} {mil};other={0} {mili}||one={0} {mila};few={0} {mile};many={0} {mil};other={0} {mili}",
+            ),
+            (
+                "unit/mile-per-gallon",
+                "{0} {mpg}||one={0} {mila na galon};few={0} {mile na galon};many={0} {mil na galon};other={0} {mili na galon}",
+            ),
+            (
+                "unit/mile-per-hour",
+                "one={0} {mila/h};few={0} {mile/h};many={0} {mil/h};other={0} {mili/h}|{0} {mph}|one={0} {mila na godzinę};few={0} {mile na godzinę};many={0} {mil na godzinę};other={0} {mili na godzinę}",
+            ),
+            (
+                "unit/mile-scandinavian",
+                "{0} {smi}||one={0} {mila skandynawska};few={0} {mile skandynawskie};many={0} {mil skandynawskich};other={0} {mili skandynawskiej}",
+            ),
+            (
+                "unit/milliliter",
+                "{0} {ml}||one={0} {mililitr};few={0} {mililitry};many={0} {mililitrów};other={0} {mililitra}",
+            ),
+            (
+                "unit/millimeter",
+                "{0} {mm}||one={0} {milimetr};few={0} {milimetry};many={0} {milimetrów};other={0} {milimetra}",
+            ),
+            (
+                "unit/millisecond",
+                "{0} {ms}||one={0} {milisekunda};many={0} {milisekund};other={0} {milisekundy}",
+            ),
+            ("unit/minute", "{0} {min}||one={0} {minuta};many={0} {minut};other={0} {minuty}"),
+            (
+                "unit/month",
+                "{0} {mies.}|one={0} {m-c};few={0} {m-ce};many={0} {m-cy};other={0} {m-ca}|one={0} {miesiąc};few={0} {miesiące};many={0} {miesięcy};other={0} {miesiąca}",
+            ),
+            (
+                "unit/nanosecond",
+                "{0} {ns}||one={0} {nanosekunda};many={0} {nanosekund};other={0} {nanosekundy}",
+            ),
+            ("unit/ounce", "{0} {oz}||one={0} {uncja};few={0} {uncje};other={0} {uncji}"),
+            ("unit/percent", "{0}{%}||{0} {procent}"),
+            (
+                "unit/petabyte",
+                "{0} {PB}||one={0} {petabajt};few={0} {petabajty};many={0} {petabajtów};other={0} {petabajta}",
+            ),
+            (
+                "unit/pound",
+                "one={0} {funt};few={0} {funty};many={0} {funtów};other={0} {funta}||one={0} {funt};few={0} {funty};many={0} {funtów};other={0} {funta}",
+            ),
+            (
+                "unit/second",
+                "{0} {sek.}|{0} {s}|one={0} {sekunda};many={0} {sekund};other={0} {sekundy}",
+            ),
+            (
+                "unit/stone",
+                "{0} {st}||one={0} {kamień};few={0} {kamienie};many={0} {kamieni};other={0} {kamienia}",
+            ),
+            (
+                "unit/terabit",
+                "{0} {Tb}||one={0} {terabit};few={0} {terabity};many={0} {terabitów};other={0} {terabita}",
+            ),
+            (
+                "unit/terabyte",
+                "{0} {TB}||one={0} {terabajt};few={0} {terabajty};many={0} {terabajtów};other={0} {terabajta}",
+            ),
+            (
+                "unit/week",
+                "one={0} {tydz.};other={0} {tyg.}|one={0} {tydz.};few={0} {t.};other={0} {tyg.}|one={0} {tydzień};few={0} {tygodnie};many={0} {tygodni};other={0} {tygodnia}",
+            ),
+            (
+                "unit/yard",
+                "{0} {yd}||one={0} {jard};few={0} {jardy};many={0} {jardów};other={0} {jarda}",
+            ),
+            (
+                "unit/year",
+                "one={0} {rok};few={0} {lata};many={0} {lat};other={0} {roku}|few={0} {l.};many={0} {l.};other={0} {r.}|one={0} {rok};few={0} {lata};many={0} {lat};other={0} {roku}",
+            ),
+        ],
+    ),
+    (
+        "pt",
+        &[
+            ("accounting", "{-}{}\u{a0}{0}"),
+            ("compact-long/10", "9|{0} {bilhões}"),
+            ("compact-long/11", "9|{0} {bilhões}"),
+            ("compact-long/12", "12|one={0} {trilhão};other={0} {trilhões}"),
+            ("compact-long/13", "12|{0} {trilhões}"),
+            ("compact-long/14", "12|{0} {trilhões}"),
+            ("compact-long/3", "3|{0} {mil}"),
+            ("compact-long/4", "3|{0} {mil}"),
+            ("compact-long/5", "3|{0} {mil}"),
+            ("compact-long/6", "6|one={0} {milhão};other={0} {milhões}"),
+            ("compact-long/7", "6|{0} {milhões}"),
+            ("compact-long/8", "6|{0} {milhões}"),
+            ("compact-long/9", "9|one={0} {bilhão};other={0} {bilhões}"),
+            ("compact-short/10", "9|{0}\u{a0}{bi}"),
+            ("compact-short/11", "9|{0}\u{a0}{bi}"),
+            ("compact-short/12", "12|{0}\u{a0}{tri}"),
+            ("compact-short/13", "12|{0}\u{a0}{tri}"),
+            ("compact-short/14", "12|{0}\u{a0}{tri}"),
+            ("compact-short/3", "3|{0}\u{a0}{mil}"),
+            ("compact-short/4", "3|{0}\u{a0}{mil}"),
+            ("compact-short/5", "3|{0}\u{a0}{mil}"),
+            ("compact-short/6", "6|{0}\u{a0}{mi}"),
+            ("compact-short/7", "6|{0}\u{a0}{mi}"),
+            ("compact-short/8", "6|{0}\u{a0}{mi}"),
+            ("compact-short/9", "9|{0}\u{a0}{bi}"),
+            ("currency", "{-}{}\u{a0}{0}"),
+            ("currency-name", "{0} {}"),
+            ("currency/AED", "AED|AED|one=Dirham dos EAU;other=Dirhams dos EAU"),
+            ("currency/ARS", "ARS|$|one=Peso argentino;other=Pesos argentinos"),
+            ("currency/AUD", "AU$|$|one=Dólar australiano;other=Dólares australianos"),
+            ("currency/BRL", "R$|R$|one=Real brasileiro;other=Reais brasileiros"),
+            ("currency/CAD", "CA$|$|one=Dólar canadense;other=Dólares canadenses"),
+            ("currency/CHF", "CHF|CHF|one=Franco suíço;other=Francos suíços"),
+            ("currency/CLP", "CLP|$|one=Peso chileno;other=Pesos chilenos"),
+            ("currency/CNY", "CN¥|¥|one=Yuan chinês;other=Yuans chineses"),
+            ("currency/COP", "COP|$|one=Peso colombiano;other=Pesos colombianos"),
+            ("currency/CZK", "CZK|Kč|one=Coroa tcheca;other=Coroas tchecas"),
+            ("currency/DKK", "DKK|kr|one=Coroa dinamarquesa;other=Coroas dinamarquesas"),
+            ("currency/EGP", "EGP|E£|one=Libra egípcia;other=Libras egípcias"),
+            ("currency/EUR", "€|€|one=Euro;other=Euros"),
+            ("currency/GBP", "£|£|one=Libra esterlina;other=Libras esterlinas"),
+            ("currency/HKD", "HK$|$|one=Dólar de Hong Kong;other=Dólares de Hong Kong"),
+            ("currency/HUF", "HUF|Ft|one=Florim húngaro;other=Florins húngaros"),
+            ("currency/IDR", "IDR|Rp|one=Rupia indonésia;other=Rupias indonésias"),
+            ("currency/ILS", "₪|₪|one=Novo shekel israelense;other=Novos shekels israelenses"),
+            ("currency/INR", "₹|₹|one=Rupia indiana;other=Rupias indianas"),
+            ("currency/JPY", "JP¥|¥|one=Iene japonês;other=Ienes japoneses"),
+            ("currency/KRW", "₩|₩|one=Won sul-coreano;other=Wons sul-coreanos"),
+            ("currency/MXN", "MX$|$|one=Peso mexicano;other=Pesos mexicanos"),
+            ("currency/MYR", "MYR|RM|one=Ringgit malaio;other=Ringgits malaios"),
+            ("currency/NOK", "NOK|kr|one=Coroa norueguesa;other=Coroas norueguesas"),
+            ("currency/NZD", "NZ$|$|one=Dólar neozelandês;other=Dólares neozelandeses"),
+            ("currency/PHP", "PHP|₱|one=Peso filipino;other=Pesos filipinos"),
+            ("currency/PKR", "PKR|Rs|one=Rupia paquistanesa;other=Rupias paquistanesas"),
+            ("currency/PLN", "PLN|zł|one=Zloty polonês;other=Zlotys poloneses"),
+            ("currency/RUB", "RUB|₽|one=Rublo russo;other=Rublos russos"),
+            ("currency/SAR", "SAR|SAR|one=Riyal saudita;other=Riyales sauditas"),
+            ("currency/SEK", "SEK|kr|one=Coroa sueca;other=Coroas suecas"),
+            ("currency/SGD", "SGD|$|one=Dólar singapuriano;other=Dólares singapurianos"),
+            ("currency/THB", "฿|฿|one=Baht tailandês;other=Bahts tailandeses"),
+            ("currency/TRY", "TRY|₺|one=Lira turca;other=Liras turcas"),
+            ("currency/TWD", "NT$|NT$|one=Novo dólar taiwanês;other=Novos dólares taiwaneses"),
+            ("currency/UAH", "UAH|₴|one=Hryvnia ucraniano;other=Hryvnias ucranianos"),
+            ("currency/USD", "US$|$|one=Dólar americano;other=Dólares americanos"),
+            ("currency/VND", "₫|₫|one=Dong vietnamita;other=Dongs vietnamitas"),
+            ("currency/ZAR", "ZAR|R|one=Rand sul-africano;other=Rands sul-africanos"),
+            ("per", "{0}{/}{}|{0}{/}{}|{0}{ por }{}"),
+            ("per-unit/centimeter", "{0}{/cm}||{0}{ por centímetro}"),
+            ("per-unit/day", "{0}{/dia}|{0}{/dia}|{0}{ por dia}"),
+            ("per-unit/foot", "{0}{/pé}|{0}{/pé}|{0}{ por pé}"),
+            ("per-unit/gallon", "{0}{/gal}|{0}{/gal}|{0}{ por galão}"),
+            ("per-unit/gram", "{0}{/g}||{0}{ por grama}"),
+            ("per-unit/hour", "{0}{/h}||{0}{ por hora}"),
+            ("per-unit/inch", "{0}{/pol.}|{0}{/pol.}|{0}{ por polegada}"),
+            ("per-unit/kilogram", "{0}{/kg}||{0}{ por quilograma}"),
+            ("per-unit/kilometer", "{0}{/km}||{0}{ por quilômetro}"),
+            ("per-unit/liter", "{0}{/l}||{0}{ por litro}"),
+            ("per-unit/meter", "{0}{/m}||{0}{ por metro}"),
+            ("per-unit/minute", "{0}{/min}||{0}{ por minuto}"),
+            ("per-unit/month", "{0}{/mês}|{0}{/mês}|{0}{ por mês}"),
+            ("per-unit/ounce", "{0}{/oz}||{0}{ por onça}"),
+            ("per-unit/pound", "{0}{/lb}||{0}{ por libra}"),
+            ("per-unit/second", "{0}{/s}|{0}{/s}|{0}{ por segundo}"),
+            ("per-unit/week", "{0}{/sem.}|{0}{/sem.}|{0}{ por semana}"),
+            ("per-unit/year", "{0}{/ano}|{0}{/ano}|{0}{ por ano}"),
+            ("percent", "{-}{0}{%}"),
+            (
+                "unit/acre",
+                "{0} {ac}|one={0} {acre};other={0} {acres}|one={0} {acre};other={0} {acres}",
+            ),
+            (
+                "unit/bit",
+                "{0} {bits}|one={0} {bit};other={0} {bits}|one={0} {bit};other={0} {bits}",
+            ),
+            ("unit/byte", "{0} {bytes}|{0} {B}|one={0} {byte};other={0} {bytes}"),
+            ("unit/celsius", "{0} {°C}||one={0} {grau Celsius};other={0} {graus Celsius}"),
+            ("unit/centimeter", "{0} {cm}||one={0} {centímetro};other={0} {centímetros}"),
+            ("unit/day", "one={0} {dia};other={0} {dias}||one={0} {dia};other={0} {dias}"),
+            ("unit/degree", "{0}{°}||one={0} {grau};other={0} {graus}"),
+            ("unit/fahrenheit", "{0} {°F}||one={0} {grau Fahrenheit};other={0} {graus Fahrenheit}"),
+            (
+                "unit/fluid-ounce",
+                "{0} {fl oz}|{0} {fl. oz.}|one={0} {onça fluida};other={0} {onças fluidas}",
+            ),
+            ("unit/foot", "one={0} {pé};other={0} {pés}||one={0} {pé};other={0} {pés}"),
+            ("unit/gallon", "{0} {gal}||one={0} {galão};other={0} {galões}"),
+            ("unit/gigabit", "{0} {Gb}||one={0} {gigabit};other={0} {gigabits}"),
+            ("unit/gigabyte", "{0} {GB}||one={0} {gigabyte};other={0} {gigabytes}"),
+            ("unit/gram", "{0} {g}|{0}{g}|one={0} {grama};other={0} {gramas}"),
+            ("unit/hectare", "{0} {ha}||one={0} {hectare};other={0} {hectares}"),
+            ("unit/hour", "{0} {h}||one={0} {hora};other={0} {horas}"),
+            ("unit/inch", "{0} {pol.}|{0}{″}|one={0} {polegada};other={0} {polegadas}"),
+            ("unit/kilobit", "{0} {kb}||one={0} {kilobit};other={0} {kilobits}"),
+            ("unit/kilobyte", "{0} {kB}||one={0} {kilobyte};other={0} {kilobytes}"),
+            ("unit/kilogram", "{0} {kg}|{0}{kg}|one={0} {quilograma};other={0} {quilogramas}"),
+            ("unit/kilometer", "{0} {km}||one={0} {quilômetro};other={0} {quilômetros}"),
+            (
+                "unit/kilometer-per-hour",
+                "{0} {km/h}|{0}{km/h}|one={0} {quilômetro por hora};other={0} {quilômetros por hora}",
+            ),
+            ("unit/liter", "{0} {l}|{0}{l}|one={0} {litro};other={0} {litros}"),
+            (
+                "unit/liter-per-kilometer",
+                "{0} {l/km}||one={0} {litro por quilômetro};other={0} {litros por quilômetro}",
+            ),
+            ("unit/megabit", "{0} {Mb}||one={0} {megabit};other={0} {megabits}"),
+            ("unit/megabyte", "{0} {MB}||one={0} {megabyte};other={0} {megabytes}"),
+            ("unit/meter", "{0} {m}||one={0} {metro};other={0} {metros}"),
+            (
+                "unit/meter-per-second",
+                "{0} {m/s}||one={0} {metro por segundo};other={0} {metros por segundo}",
+            ),
+            ("unit/microsecond", "{0} {μs}||one={0} {microssegundo};other={0} {microssegundos}"),
+            ("unit/mile", "{0} {mi}||one={0} {milha};other={0} {milhas}"),
+            (
+                "unit/mile-per-gallon",
+                "{0} {mpg}||one={0} {milha por galão};other={0} {milhas por galão}",
+            ),
+            (
+                "unit/mile-per-hour",
+                "{0} {mph}||one={0} {milha por hora};other={0} {milhas por hora}",
+            ),
+            (
+                "unit/mile-scandinavian",
+                "{0} {smi}||one={0} {milha escandinava};other={0} {milhas escandinavas}",
+            ),
+            ("unit/milliliter", "{0} {ml}||one={0} {mililitro};other={0} {mililitros}"),
+            ("unit/millimeter", "{0} {mm}||one={0} {milímetro};other={0} {milímetros}"),
+            ("unit/millisecond", "{0} {ms}||one={0} {milissegundo};other={0} {milissegundos}"),
+            ("unit/minute", "{0} {min}||one={0} {minuto};other={0} {minutos}"),
+            ("unit/month", "one={0} {mês};other={0} {meses}||one={0} {mês};other={0} {meses}"),
+            ("unit/nanosecond", "{0} {ns}||one={0} {nanossegundo};other={0} {nanossegundos}"),
+            ("unit/ounce", "{0} {oz}||one={0} {onça};other={0} {onças}"),
+            ("unit/percent", "{0}{%}||{0} {por cento}"),
+            ("unit/petabyte", "{0} {PB}||one={0} {petabyte};other={0} {petabytes}"),
+            ("unit/pound", "{0} {lb}||one={0} {libra};other={0} {libras}"),
+            ("unit/second", "{0} {s}||one={0} {segundo};other={0} {segundos}"),
+            ("unit/stone", "{0} {st}||one={0} {stone};other={0} {stones}"),
+            ("unit/terabit", "{0} {Tb}||one={0} {terabit};other={0} {terabits}"),
+            ("unit/terabyte", "{0} {TB}||one={0} {terabyte};other={0} {terabytes}"),
+            ("unit/week", "{0} {sem.}||one={0} {semana};other={0} {semanas}"),
+            ("unit/yard", "{0} {yd}||one={0} {jarda};other={0} {jardas}"),
+            ("unit/year", "one={0} {ano};other={0} {anos}||one={0} {ano};other={0} {anos}"),
+        ],
+    ),
+    (
+        "pt-CV",
+        &[
+            ("accounting", "{-}{0}\u{a0}{}"),
+            ("currency-name", "{0}\u{a0}{}"),
+        ],
+    ),
+    (
+        "pt-PT",
+        &[
+            ("accounting", "({0}\u{a0}{})"),
+            ("compact-long/10", "9|{0} {mil milhões}"),
+            ("compact-long/11", "9|{0} {mil milhões}"),
+            ("compact-long/12", "12|one={0} {bilião};other={0} {biliões}"),
+            ("compact-long/13", "12|{0} {biliões}"),
+            ("compact-long/14", "12|{0} {biliões}"),
+            ("compact-long/9", "9|{0} {mil milhões}"),
+            ("compact-short/10", "9|{0}\u{a0}{mM}"),
+            ("compact-short/11", "9|{0}\u{a0}{mM}"),
+            ("compact-short/12", "12|{0}\u{a0}{Bi}"),
+            ("compact-short/13", "12|{0}\u{a0}{Bi}"),
+            ("compact-short/14", "12|{0}\u{a0}{Bi}"),
+            ("compact-short/6", "6|{0}\u{a0}{M}"),
+            ("compact-short/7", "6|{0}\u{a0}{M}"),
+            ("compact-short/8", "6|{0}\u{a0}{M}"),
+            ("compact-short/9", "9|{0}\u{a0}{mM}"),
+            ("currency", "{-}{0}\u{a0}{}"),
+            (
+                "currency/AED",
+                "AED|AED|one=dirham dos Emirados Árabes Unidos;other=sdirham dos Emirados Árabes Unidos",
+            ),
+            ("currency/ARS", "ARS|$|one=peso argentino;other=pesos argentinos"),
+            ("currency/AUD", "AU$|$|one=dólar australiano;other=dólares australianos"),
+            ("currency/BRL", "R$|R$|one=real brasileiro;other=reais brasileiros"),
+            ("currency/CAD", "CA$|$|one=dólar canadiano;other=dólares canadianos"),
+            ("currency/CHF", "CHF|CHF|one=franco suíço;other=francos suíços"),
+            ("currency/CLP", "CLP|$|one=peso chileno;other=pesos chilenos"),
+            ("currency/CNY", "CN¥|¥|one=yuan;other=yuans"),
+            ("currency/COP", "COP|$|one=peso colombiano;other=pesos colombianos"),
+            ("currency/CZK", "CZK|Kč|one=coroa checa;other=coroas checas"),
+            ("currency/DKK", "DKK|kr|one=coroa dinamarquesa;other=coroas dinamarquesas"),
+            ("currency/EGP", "EGP|E£|one=libra egípcia;other=libras egípcias"),
+            ("currency/EUR", "€|€|one=euro;other=euros"),
+            (
+                "currency/GBP",
+                "£|£|one=libra esterlina britânica;other=libras esterlinas britânicas",
+            ),
+            ("currency/HKD", "HK$|$|one=dólar de Hong Kong;other=dólares de Hong Kong"),
+            ("currency/HUF", "HUF|Ft|one=forint húngaro;other=forints húngaros"),
+            ("currency/IDR", "IDR|Rp|one=rupia indonésia;other=rupias indonésias"),
+            ("currency/ILS", "₪|₪|one=sheqel novo israelita;other=sheqels novos israelitas"),
+            ("currency/INR", "₹|₹|one=rupia indiana;other=rupias indianas"),
+            ("currency/JPY", "JP¥|¥|one=iene japonês;other=ienes japoneses"),
+            ("currency/KRW", "₩|₩|one=won sul-coreano;other=wons sul-coreano"),
+            ("currency/MXN", "MX$|$|one=peso mexicano;other=pesos mexicanos"),
+            ("currency/MYR", "MYR|RM|one=ringgit malaio;other=ringgits malaios"),
+            ("currency/NOK", "NOK|kr|one=coroa norueguesa;other=coroas norueguesas"),
+            ("currency/NZD", "NZ$|$|one=dólar neozelandês;other=dólares neozelandeses"),
+            ("currency/PHP", "PHP|₱|one=peso filipino;other=pesos filipinos"),
+            ("currency/PKR", "PKR|Rs|one=rupia paquistanesa;other=rupias paquistanesas"),
+            ("currency/PLN", "PLN|zł|one=zloti polaco;other=zlotis polacos"),
+            ("currency/RUB", "RUB|₽|one=rublo russo;other=rublos russos"),
+            ("currency/SAR", "SAR|SAR|one=rial saudita;other=riais sauditas"),
+            ("currency/SEK", "SEK|kr|one=coroa sueca;other=coroas suecas"),
+            ("currency/SGD", "SGD|$|one=dólar singapuriano;other=dólares singapurianos"),
+            ("currency/THB", "฿|฿|one=baht tailandês;other=bahts tailandeses"),
+            ("currency/TRY", "TRY|₺|one=lira turca;other=liras turcas"),
+            ("currency/TWD", "NT$|NT$|one=novo dólar taiwanês;other=novos dólares taiwaneses"),
+            ("currency/UAH", "UAH|₴|one=hryvnia ucraniano;other=hryvnias ucranianos"),
+            ("currency/USD", "US$|$|one=dólar dos Estados Unidos;other=dólares dos Estados Unidos"),
+            ("currency/VND", "₫|₫|one=dong vietnamita;other=dongs vietnamitas"),
+            ("currency/ZAR", "ZAR|R|one=rand sul-africano;other=rands sul-africanos"),
+            ("per-unit/day", "{0}{/dia}|{0}{/d}|{0}{ por dia}"),
+            ("per-unit/gallon", "{0}{/gal}||{0}{ por galão}"),
+            ("per-unit/hour", "{0}{/h}|{0}{/h}|{0}{/h}"),
+            ("per-unit/kilometer", "{0}{/km}||{0}{ por quilómetro}"),
+            ("per-unit/minute", "{0}{/min}|{0}{/min}|{0}{ por minuto}"),
+            ("per-unit/second", "{0}{/s}|{0}{/s}|{0}{/s}"),
+            ("unit/acre", "one={0} {acre};other={0} {acres}||one={0} {acre};other={0} {acres}"),
+            ("unit/bit", "{0} {bit}|one={0} {bit};other={0} {bits}|one={0} {bit};other={0} {bits}"),
+            ("unit/byte", "{0} {byte}|{0} {B}|one={0} {byte};other={0} {bytes}"),
+            ("unit/celsius", "{0} {°C}|{0}{°C}|one={0} {grau Celsius};other={0} {graus Celsius}"),
+            ("unit/fluid-ounce", "{0} {fl oz}||one={0} {onça fluida};other={0} {onças fluidas}"),
+            ("unit/foot", "one={0} {pé};other={0} {pés}|{0}{′}|one={0} {pé};other={0} {pés}"),
+            ("unit/gram", "{0} {g}||one={0} {grama};other={0} {gramas}"),
+            ("unit/kilogram", "{0} {kg}||one={0} {quilograma};other={0} {quilogramas}"),
+            ("unit/kilometer", "{0} {km}||one={0} {quilómetro};other={0} {quilómetros}"),
+            (
+                "unit/kilometer-per-hour",
+                "{0} {km/h}||one={0} {quilómetro por hora};other={0} {quilómetros por hora}",
+            ),
+            ("unit/liter", "{0} {l}||one={0} {litro};other={0} {litros}"),
+            (
+                "unit/liter-per-kilometer",
+                "{0} {l/km}||one={0} {litro por quilómetro};other={0} {litros por quilómetro}",
+            ),
+            ("unit/mile", "one={0} {milha};other={0} {milhas}||one={0} {milha};other={0} {milhas}"),
+            (
+                "unit/mile-per-hour",
+                "{0} {mi/h}||one={0} {milha por hora};other={0} {milhas por hora}",
+            ),
+        ],
+    ),
+    (
+        "ru",
+        &[
+            ("accounting", "{-}{0}\u{a0}{}"),
+            ("compact-long/10", "9|one={0} {миллиард};few={0} {миллиарда};other={0} {миллиардов}"),
+            ("compact-long/11", "9|one={0} {миллиард};few={0} {миллиарда};other={0} {миллиардов}"),
+            (
+                "compact-long/12",
+                "12|one={0} {триллион};many={0} {триллионов};other={0} {триллиона}",
+            ),
+            ("compact-long/13", "12|one={0} {триллион};few={0} {триллиона};other={0} {триллионов}"),
+            ("compact-long/14", "12|one={0} {триллион};few={0} {триллиона};other={0} {триллионов}"),
+            ("compact-long/3", "3|one={0} {тысяча};many={0} {тысяч};other={0} {тысячи}"),
+            ("compact-long/4", "3|one={0} {тысяча};few={0} {тысячи};other={0} {тысяч}"),
+            ("compact-long/5", "3|one={0} {тысяча};few={0} {тысячи};other={0} {тысяч}"),
+            ("compact-long/6", "6|one={0} {миллион};many={0} {миллионов};other={0} {миллиона}"),
+            ("compact-long/7", "6|one={0} {миллион};few={0} {миллиона};other={0} {миллионов}"),
+            ("compact-long/8", "6|one={0} {миллион};few={0} {миллиона};other={0} {миллионов}"),
+            ("compact-long/9", "9|one={0} {миллиард};many={0} {миллиардов};other={0} {миллиарда}"),
+            ("compact-short/10", "9|{0}\u{a0}{млрд}"),
+            ("compact-short/11", "9|{0}\u{a0}{млрд}"),
+            ("compact-short/12", "12|{0}\u{a0}{трлн}"),
+            ("compact-short/13", "12|{0}\u{a0}{трлн}"),
+            ("compact-short/14", "12|{0}\u{a0}{трлн}"),
+            ("compact-short/3", "3|{0}\u{a0}{тыс.}"),
+            ("compact-short/4", "3|{0}\u{a0}{тыс.}"),
+            ("compact-short/5", "3|{0}\u{a0}{тыс.}"),
+            ("compact-short/6", "6|{0}\u{a0}{млн}"),
+            ("compact-short/7", "6|{0}\u{a0}{млн}"),
+            ("compact-short/8", "6|{0}\u{a0}{млн}"),
+            ("compact-short/9", "9|{0}\u{a0}{млрд}"),
+            ("currency", "{-}{0}\u{a0}{}"),
+            ("currency-name", "{0} {}"),
+            ("currency/AED", "AED|AED|one=дирхам ОАЭ;many=дирхамов ОАЭ;other=дирхама ОАЭ"),
+            (
+                "currency/ARS",
+                "ARS|$|one=аргентинский песо;few=аргентинских песо;many=аргентинских песо;other=аргентинского песо",
+            ),
+            (
+                "currency/AUD",
+                "A$|$|one=австралийский доллар;few=австралийских доллара;many=австралийских долларов;other=австралийского доллара",
+            ),
+            (
+                "currency/BRL",
+                "R$|R$|one=бразильский реал;few=бразильских реала;many=бразильских реалов;other=бразильского реала",
+            ),
+            (
+                "currency/CAD",
+                "CA$|$|one=канадский доллар;few=канадских доллара;many=канадских долларов;other=канадского доллара",
+            ),
+            (
+                "currency/CHF",
+                "CHF|CHF|one=швейцарский франк;few=швейцарских франка;many=швейцарских франков;other=швейцарского франка",
+            ),
+            (
+                "currency/CLP",
+                "CLP|$|one=чилийский песо;few=чилийских песо;many=чилийских песо;other=чилийского песо",
+            ),
+            (
+                "currency/CNY",
+                "CN¥|¥|one=китайский юань;few=китайских юаня;many=китайских юаней;other=китайского юаня",
+            ),
+            (
+                "currency/COP",
+                "COP|$|one=колумбийский песо;few=колумбийских песо;many=колумбийских песо;other=колумбийского песо",
+            ),
+            (
+                "currency/CZK",
+                "CZK|Kč|one=чешская крона;few=чешские кроны;many=чешских крон;other=чешской кроны",
+            ),
+            (
+                "currency/DKK",
+                "DKK|kr|one=датская крона;few=датские кроны;many=датских крон;other=датской кроны",
+            ),
+            (
+                "currency/EGP",
+                "EGP|E£|one=египетский фунт;few=египетских фунта;many=египетских фунтов;other=египетского фунта",
+            ),
+            ("currency/EUR", "€|€|евро"),
+            (
+                "currency/GBP",
+                "£|£|one=британский фунт стерлингов;few=британских фунта стерлингов;many=британских фунтов стерлингов;other=британского фунта стерлингов",
+            ),
+            (
+                "currency/HKD",
+                "HK$|$|one=гонконгский доллар;few=гонконгских доллара;many=гонконгских долларов;other=гонконгского доллара",
+            ),
+            (
+                "currency/HUF",
+                "HUF|Ft|one=венгерский форинт;few=венгерских форинта;many=венгерских форинтов;other=венгерского форинта",
+            ),
+            (
+                "currency/IDR",
+                "IDR|Rp|one=индонезийская рупия;few=индонезийские рупии;many=индонезийских рупий;other=индонезийской рупии",
+            ),
+            (
+                "currency/ILS",
+                "₪|₪|one=новый израильский шекель;few=новых израильских шекеля;many=новых израильских шекелей;other=нового израильского шекеля",
+            ),
+            (
+                "currency/INR",
+                "₹|₹|one=индийская рупия;few=индийские рупии;many=индийских рупий;other=индийской рупии",
+            ),
+            (
+                "currency/JPY",
+                "¥|¥|one=японская иена;few=японские иены;many=японских иен;other=японской иены",
+            ),
+            (
+                "currency/KRW",
+                "₩|₩|one=южнокорейская вона;few=южнокорейские воны;many=южнокорейских вон;other=южнокорейской воны",
+            ),
+            (
+                "currency/MXN",
+                "MX$|$|one=мексиканский песо;few=мексиканских песо;many=мексиканских песо;other=мексиканского песо",
+            ),
+            (
+                "currency/MYR",
+                "MYR|RM|one=малайзийский ринггит;few=малайзийских ринггита;many=малайзийских ринггитов;other=малайзийского ринггита",
+            ),
+            (
+                "currency/NOK",
+                "NOK|kr|one=норвежская крона;few=норвежские кроны;many=норвежских крон;other=норвежской кроны",
+            ),
+            (
+                "currency/NZD",
+                "NZ$|$|one=новозеландский доллар;few=новозеландских доллара;many=новозеландских долларов;other=новозеландского доллара",
+            ),
+            (
+                "currency/PHP",
+                "PHP|₱|one=филиппинский песо;few=филиппинских песо;many=филиппинских песо;other=филиппинского песо",
+            ),
+            (
+                "currency/PKR",
+                "PKR|Rs|one=пакистанская рупия;few=пакистанские рупии;many=пакистанских рупий;other=пакистанской рупии",
+            ),
+            (
+                "currency/PLN",
+                "PLN|zł|one=польский злотый;few=польских злотых;many=польских злотых;other=польского злотого",
+            ),
+            (
+                "currency/RUB",
+                "₽|₽|one=российский рубль;few=российских рубля;many=российских рублей;other=российского рубля",
+            ),
+            (
+                "currency/SAR",
+                "SAR|SAR|one=саудовский риял;few=саудовских рияла;many=саудовских риялов;other=саудовского рияла",
+            ),
+            (
+                "currency/SEK",
+                "SEK|kr|one=шведская крона;few=шведские кроны;many=шведских крон;other=шведской кроны",
+            ),
+            (
+                "currency/SGD",
+                "SGD|$|one=сингапурский доллар;few=сингапурских доллара;many=сингапурских долларов;other=сингапурского доллара",
+            ),
+            (
+                "currency/THB",
+                "฿|฿|one=таиландский бат;few=таиландских бата;many=таиландских батов;other=таиландского бата",
+            ),
+            (
+                "currency/TRY",
+                "TRY|₺|one=турецкая лира;few=турецкие лиры;many=турецких лир;other=турецкой лиры",
+            ),
+            (
+                "currency/TWD",
+                "NT$|NT$|one=новый тайваньский доллар;few=новых тайваньских доллара;many=новых тайваньских долларов;other=нового тайваньского доллара",
+            ),
+            (
+                "currency/UAH",
+                "₴|₴|one=украинская гривна;few=украинские гривны;many=украинских гривен;other=украинской гривны",
+            ),
+            ("currency/USD", "$|$|one=доллар США;many=долларов США;other=доллара США"),
+            (
+                "currency/VND",
+                "₫|₫|one=вьетнамский донг;few=вьетнамских донга;many=вьетнамских донгов;other=вьетнамского донга",
+            ),
+            (
+                "currency/ZAR",
+                "ZAR|R|one=южноафриканский рэнд;few=южноафриканских рэнда;many=южноафриканских рэндов;other=южноафриканского рэнда",
+            ),
+            ("per", "{0}{/}{}|{0}{/}{}|{0}{/}{}"),
+            ("per-unit/centimeter", "{0}{/см}|{0}{/см}|{0}{ на сантиметр}"),
+            ("per-unit/day", "{0}{/д}|{0}{/д.}|{0}{ в день}"),
+            ("per-unit/foot", "{0}{/фт}|{0}{/фт}|{0}{ на фут}"),
+            ("per-unit/gallon", "{0}{/ам. гал}|{0}{/ам. гал}|{0}{ на амер. галлон}"),
+            ("per-unit/gram", "{0}{/г}|{0}{/г}|{0}{ на грамм}"),
+            ("per-unit/hour", "{0}{/ч}|{0}{/ч}|{0}{ в час}"),
+            ("per-unit/inch", "{0}{/дюйм}|{0}{/дюйм}|{0}{ на дюйм}"),
+            ("per-unit/kilogram", "{0}{/кг}|{0}{/кг}|{0}{ на килограмм}"),
+            ("per-unit/kilometer", "{0}{/км}|{0}{/км}|{0}{ на километр}"),
+            ("per-unit/liter", "{0}{/л}|{0}{/л}|{0}{ на литр}"),
+            ("per-unit/meter", "{0}{/м}|{0}{/м}|{0}{ на метр}"),
+            ("per-unit/minute", "{0}{/мин}|{0}{/мин}|{0}{ в минуту}"),
+            ("per-unit/month", "{0}{/мес}|{0}{/м.}|{0}{ в месяц}"),
+            ("per-unit/ounce", "{0}{/унц}|{0}{/унц}|{0}{ на унцию}"),
+            ("per-unit/pound", "{0}{/фнт}|{0}{/фнт}|{0}{ на фунт}"),
+            ("per-unit/second", "{0}{/c}|{0}{/c}|{0}{ в секунду}"),
+            ("per-unit/week", "{0}{/нед}|{0}{/н.}|{0}{ в неделю}"),
+            ("per-unit/year", "{0}{/г}|{0}{/г.}|{0}{ в год}"),
+            ("percent", "{-}{0}\u{a0}{%}"),
+            (
+                "unit/acre",
+                "one={0} {акр};other={0} {акр.}||one={0} {акр};many={0} {акров};other={0} {акра}",
+            ),
+            (
+                "unit/bit",
+                "one={0} {бит};many={0} {бит};other={0} {бита}||one={0} {бит};many={0} {бит};other={0} {бита}",
+            ),
+            ("unit/byte", "{0} {Б}||one={0} {байт};many={0} {байт};other={0} {байта}"),
+            (
+                "unit/celsius",
+                "{0} {°C}||one={0} {градус Цельсия};many={0} {градусов Цельсия};other={0} {градуса Цельсия}",
+            ),
+            (
+                "unit/centimeter",
+                "{0} {см}||one={0} {сантиметр};many={0} {сантиметров};other={0} {сантиметра}",
+            ),
+            ("unit/day", "{0} {дн.}|{0} {д.}|one={0} {день};many={0} {дней};other={0} {дня}"),
+            ("unit/degree", "{0}{°}||one={0} {градус};many={0} {градусов};other={0} {градуса}"),
+            (
+                "unit/fahrenheit",
+                "{0} {°F}|few={0} {°F};other={0}{°F}|one={0} {градус Фаренгейта};many={0} {градусов Фаренгейта};other={0} {градуса Фаренгейта}",
+            ),
+            (
+                "unit/fluid-ounce",
+                "{0} {ам. жидк. унц.}||one={0} {амер. жидкая унция};few={0} {амер. жидкие унции};many={0} {амер. жидких унций};other={0} {амер. жидкой унции}",
+            ),
+            ("unit/foot", "{0} {фт}||one={0} {фут};many={0} {футов};other={0} {фута}"),
+            (
+                "unit/gallon",
+                "{0} {ам. гал.}||one={0} {амер. галлон};many={0} {амер. галлонов};other={0} {амер. галлона}",
+            ),
+            (
+                "unit/gigabit",
+                "{0} {Гбит}||one={0} {гигабит};many={0} {гигабит};other={0} {гигабита}",
+            ),
+            (
+                "unit/gigabyte",
+                "{0} {ГБ}||one={0} {гигабайт};many={0} {гигабайт};other={0} {гигабайта}",
+            ),
+            ("unit/gram", "{0} {г}||one={0} {грамм};many={0} {грамм};other={0} {грамма}"),
+            ("unit/hectare", "{0} {га}||one={0} {гектар};many={0} {гектаров};other={0} {гектара}"),
+            ("unit/hour", "{0} {ч}||one={0} {час};many={0} {часов};other={0} {часа}"),
+            (
+                "unit/inch",
+                "one={0} {дюйм};other={0} {дюйм.}|{0} {дюйм.}|one={0} {дюйм};many={0} {дюймов};other={0} {дюйма}",
+            ),
+            (
+                "unit/kilobit",
+                "{0} {кбит}||one={0} {килобит};many={0} {килобит};other={0} {килобита}",
+            ),
+            (
+                "unit/kilobyte",
+                "{0} {кБ}||one={0} {килобайт};many={0} {килобайт};other={0} {килобайта}",
+            ),
+            (
+                "unit/kilogram",
+                "{0} {кг}||one={0} {килограмм};many={0} {килограмм};other={0} {килограмма}",
+            ),
+            (
+                "unit/kilometer",
+                "{0} {км}||one={0} {километр};many={0} {километров};other={0} {километра}",
+            ),
+            (
+                "unit/kilometer-per-hour",
+                "{0} {км/ч}||one={0} {километр в час};many={0} {километров в час};other={0} {километра в час}",
+            ),
+            ("unit/liter", "{0} {л}||one={0} {литр};many={0} {литров};other={0} {литра}"),
+            (
+                "unit/liter-per-kilometer",
+                "{0} {л/км}||one={0} {литр на километр};many={0} {литров на километр};other={0} {литра на километр}",
+            ),
+            (
+                "unit/megabit",
+                "{0} {Мбит}||one={0} {мегабит};many={0} {мегабит};other={0} {мегабита}",
+            ),
+            (
+                "unit/megabyte",
+                "{0} {МБ}||one={0} {мегабайт};many={0} {мегабайт};other={0} {мегабайта}",
+            ),
+            ("unit/meter", "{0} {м}||one={0} {метр};many={0} {метров};other={0} {метра}"),
+            (
+                "unit/meter-per-second",
+                "{0} {м/с}||one={0} {метр в секунду};many={0} {метров в секунду};other={0} {метра в секунду}",
+            ),
+            (
+                "unit/microsecond",
+                "{0} {мкс}||one={0} {микросекунда};many={0} {микросекунд};other={0} {микросекунды}",
+            ),
+            ("unit/mile", "{0} {ми}||one={0} {миля};many={0} {миль};other={0} {мили}"),
+            (
+                "unit/mile-per-gallon",
+                "{0} {ми/ам. гал}||one={0} {миля на амер. галлон};many={0} {миль на амер. галлон};other={0} {мили на амер. галлон}",
+            ),
+            (
+                "unit/mile-per-hour",
+                "{0} {ми/ч}||one={0} {миля в час};many={0} {миль в час};other={0} {мили в час}",
+            ),
+            (
+                "unit/mile-scandinavian",
+                "{0} {ск. ми}||one={0} {скандинавская миля};few={0} {скандинавские мили};many={0} {скандинавских миль};other={0} {скандинавской мили}",
+            ),
+            (
+                "unit/milliliter",
+                "{0} {мл}||one={0} {миллилитр};many={0} {миллилитров};other={0} {миллилитра}",
+            ),
+            (
+                "unit/millimeter",
+                "{0} {мм}||one={0} {миллиметр};many={0} {миллиметров};other={0} {миллиметра}",
+            ),
+            (
+                "unit/millisecond",
+                "{0} {мс}||one={0} {миллисекунда};many={0} {миллисекунд};other={0} {миллисекунды}",
+            ),
+            ("unit/minute", "{0} {мин}||one={0} {минута};many={0} {минут};other={0} {минуты}"),
+            (
+                "unit/month",
+                "{0} {мес.}|{0} {м.}|one={0} {месяц};many={0} {месяцев};other={0} {месяца}",
+            ),
+            (
+                "unit/nanosecond",
+                "{0} {нс}||one={0} {наносекунда};many={0} {наносекунд};other={0} {наносекунды}",
+            ),
+            ("unit/ounce", "{0} {унц.}||one={0} {унция};many={0} {унций};other={0} {унции}"),
+            (
+                "unit/percent",
+                "{0}\u{a0}{%}||one={0} {процент};many={0} {процентов};other={0} {процента}",
+            ),
+            (
+                "unit/petabyte",
+                "{0} {ПБ}||one={0} {петабайт};many={0} {петабайт};other={0} {петабайта}",
+            ),
+            ("unit/pound", "{0} {фнт}||one={0} {фунт};many={0} {фунтов};other={0} {фунта}"),
+            ("unit/second", "{0} {с}||one={0} {секунда};many={0} {секунд};other={0} {секунды}"),
+            (
+                "unit/stone",
+                "one={0}\u{a0}{стоун};many={0} {стоунов};other={0} {стоуна}|{0} {стн}|one={0}\u{a0}{стоун};many={0} {стоунов};other={0} {стоуна}",
+            ),
+            (
+                "unit/terabit",
+                "{0} {Тбит}||one={0} {терабит};many={0} {терабит};other={0} {терабита}",
+            ),
+            (
+                "unit/terabyte",
+                "{0} {ТБ}||one={0} {терабайт};many={0} {терабайт};other={0} {терабайта}",
+            ),
+            (
+                "unit/week",
+                "{0} {нед.}|{0} {н.}|one={0} {неделя};many={0} {недель};other={0} {недели}",
+            ),
+            (
+                "unit/yard",
+                "one={0} {ярд};other={0} {ярд.}||one={0} {ярд};many={0} {ярдов};other={0} {ярда}",
+            ),
+            (
+                "unit/year",
+                "many={0} {л.};other={0} {г.}||one={0} {год};many={0} {лет};other={0} {года}",
+            ),
+        ],
+    ),
+    (
+        "sv",
+        &[
+            ("accounting", "{-}{0}\u{a0}{}"),
+            ("compact-long/10", "9|{0} {miljarder}"),
+            ("compact-long/11", "9|{0} {miljarder}"),
+            ("compact-long/12", "12|one={0} {biljon};other={0} {biljoner}"),
+            ("compact-long/13", "12|{0} {biljoner}"),
+            ("compact-long/14", "12|{0} {biljoner}"),
+            ("compact-long/3", "3|{0} {tusen}"),
+            ("compact-long/4", "3|{0} {tusen}"),
+            ("compact-long/5", "3|{0} {tusen}"),
+            ("compact-long/6", "6|one={0} {miljon};other={0} {miljoner}"),
+            ("compact-long/7", "6|{0} {miljoner}"),
+            ("compact-long/8", "6|{0} {miljoner}"),
+            ("compact-long/9", "9|one={0} {miljard};other={0} {miljarder}"),
+            ("compact-short/10", "9|{0}\u{a0}{md}"),
+            ("compact-short/11", "9|{0}\u{a0}{md}"),
+            ("compact-short/12", "12|{0}\u{a0}{bn}"),
+            ("compact-short/13", "12|{0}\u{a0}{bn}"),
+            ("compact-short/14", "12|{0}\u{a0}{bn}"),
+            ("compact-short/3", "3|{0}\u{a0}{tn}"),
+            ("compact-short/4", "3|{0}\u{a0}{tn}"),
+            ("compact-short/5", "3|{0}\u{a0}{tn}"),
+            ("compact-short/6", "6|{0}\u{a0}{mn}"),
+            ("compact-short/7", "6|{0}\u{a0}{mn}"),
+            ("compact-short/8", "6|{0}\u{a0}{mn}"),
+            ("compact-short/9", "9|{0}\u{a0}{md}"),
+            ("currency", "{-}{0}\u{a0}{}"),
+            ("currency-name", "{0} {}"),
+            ("currency/AED", "AED|AED|emiratisk dirham"),
+            ("currency/ARS", "ARS|$|one=argentinsk peso;other=argentinska pesos"),
+            ("currency/AUD", "AUD|$|one=australisk dollar;other=australiska dollar"),
+            ("currency/BRL", "BR$|R$|one=brasiliansk real;other=brasilianska real"),
+            ("currency/CAD", "CA$|$|one=kanadensisk dollar;other=kanadensiska dollar"),
+            ("currency/CHF", "CHF|CHF|one=schweizisk franc;other=schweiziska franc"),
+            ("currency/CLP", "CLP|$|one=chilensk peso;other=chilenska pesos"),
+            ("currency/CNY", "CNY|¥|one=kinesisk yuan;other=kinesiska yuan"),
+            ("currency/COP", "COP|$|one=colombiansk peso;other=colombianska pesos"),
+            ("currency/CZK", "CZK|Kč|one=tjeckisk koruna;other=tjeckiska koruna"),
+            ("currency/DKK", "Dkr|kr|one=dansk krona;other=danska kronor"),
+            ("currency/EGP", "EG£|E£|one=egyptiskt pund;other=egyptiska pund"),
+            ("currency/EUR", "€|€|euro"),
+            ("currency/GBP", "GBP|£|one=brittiskt pund;other=brittiska pund"),
+            ("currency/HKD", "HKD|$|Hongkongdollar"),
+            ("currency/HUF", "HUF|Ft|one=ungersk forint;other=ungerska forinter"),
+            ("currency/IDR", "IDR|Rp|one=indonesisk rupie;other=indonesiska rupier"),
+            ("currency/ILS", "₪|₪|one=israelisk ny shekel;other=israeliska nya shekel"),
+            ("currency/INR", "INR|₹|one=indisk rupie;other=indiska rupier"),
+            ("currency/JPY", "JPY|¥|one=japansk yen;other=japanska yen"),
+            ("currency/KRW", "KRW|₩|one=sydkoreansk won;other=sydkoreanska won"),
+            ("currency/MXN", "MX$|$|one=mexikansk peso;other=mexikanska pesos"),
+            ("currency/MYR", "MYR|RM|one=malaysisk ringgit;other=malaysiska ringgiter"),
+            ("currency/NOK", "Nkr|kr|one=norsk krona;other=norska kronor"),
+            ("currency/NZD", "NZD|$|one=nyzeeländsk dollar;other=nyzeeländska dollar"),
+            ("currency/PHP", "PHP|₱|one=filippinsk peso;other=filippinska pesos"),
+            ("currency/PKR", "PKR|Rs|one=pakistansk rupie;other=pakistanska rupier"),
+            ("currency/PLN", "PLN|zł|one=polsk zloty;other=polska zloty"),
+            ("currency/RUB", "RUB|₽|one=rysk rubel;other=ryska rubel"),
+            ("currency/SAR", "SAR|SAR|one=saudisk riyal;other=saudiska riyal"),
+            ("currency/SEK", "kr|kr|one=svensk krona;other=svenska kronor"),
+            ("currency/SGD", "SGD|$|one=singaporiansk dollar;other=singaporianska dollar"),
+            ("currency/THB", "THB|฿|one=thailändsk baht;other=thailändska baht"),
+            ("currency/TRY", "TRY|₺|one=turkisk lira;other=turkiska lira"),
+            ("currency/TWD", "TWD|NT$|one=taiwanesisk dollar;other=taiwanesiska dollar"),
+            ("currency/UAH", "UAH|₴|one=ukrainsk hryvnia;other=ukrainska hryvnia"),
+            ("currency/USD", "US$|$|one=amerikansk dollar;other=amerikanska dollar"),
+            ("currency/VND", "VND|₫|one=vietnamesisk dong;other=vietnamesiska dong"),
+            ("currency/ZAR", "ZAR|R|one=sydafrikansk rand;other=sydafrikanska rand"),
+            ("per", "{0}{/}{}|{0}{/}{}|{0}{ per }{}"),
+            ("per-unit/centimeter", "{0}{/cm}|{0}{/cm}|{0}{ per centimeter}"),
+            ("per-unit/day", "{0}{/d}|{0}{/d}|{0}{ per dygn}"),
+            ("per-unit/foot", "{0}{/fot}|{0}{/fot}|{0}{ per fot}"),
+            ("per-unit/gallon", "{0}{/gal US}||{0}{ per gallon}"),
+            ("per-unit/gram", "{0}{/g}|{0}{/g}|{0}{ per gram}"),
+            ("per-unit/hour", "{0}{/h}|{0}{/h}|{0}{ per timme}"),
+            ("per-unit/inch", "{0}{/tum}|{0}{/tum}|{0}{ per tum}"),
+            ("per-unit/kilogram", "{0}{/kg}|{0}{/kg}|{0}{ per kilogram}"),
+            ("per-unit/kilometer", "{0}{/km}|{0}{/km}|{0}{ per kilometer}"),
+            ("per-unit/liter", "{0}{/l}|{0}{/l}|{0}{ per liter}"),
+            ("per-unit/meter", "{0}{/m}|{0}{/m}|{0}{ per meter}"),
+            ("per-unit/minute", "{0}{/min}|{0}{/m}|{0}{ per minut}"),
+            ("per-unit/month", "{0}{/mån}|{0}{/mån}|{0}{ per månad}"),
+            ("per-unit/ounce", "{0}{/uns}|{0}{/uns}|{0}{ per uns}"),
+            ("per-unit/pound", "{0}{/pund}|{0}{/pund}|{0}{ per pund}"),
+            ("per-unit/second", "{0}{/s}|{0}{/s}|{0}{ per sekund}"),
+            ("per-unit/week", "{0}{/v}|{0}{/v}|{0}{ per vecka}"),
+            ("per-unit/year", "{0}{/år}|{0}{/år}|{0}{ per år}"),
+            ("percent", "{-}{0}\u{a0}{%}"),
+            (
+                "unit/acre",
+                "{0} {ac}|{0}{ac}|one={0} {engelskt tunnland};other={0} {engelska tunnland}",
+            ),
+            ("unit/bit", "{0} {b}|{0}{b}|{0} {bit}"),
+            ("unit/byte", "{0} {B}|{0}{B}|{0} {byte}"),
+            ("unit/celsius", "{0} {°C}|{0}{°C}|one={0} {grad Celsius};other={0} {grader Celsius}"),
+            ("unit/centimeter", "{0} {cm}|{0}{cm}|{0} {centimeter}"),
+            ("unit/day", "{0} {d}|{0}{d}|{0} {dygn}"),
+            ("unit/degree", "{0}{°}||one={0} {grad};other={0} {grader}"),
+            (
+                "unit/fahrenheit",
+                "{0} {°F}|{0}{°F}|one={0} {grad Fahrenheit};other={0} {grader Fahrenheit}",
+            ),
+            (
+                "unit/fluid-ounce",
+                "{0} {fl oz}|{0}{fl oz}|one={0} {flytande uns};other={0} {fluid ounces}",
+            ),
+            ("unit/foot", "{0} {fot}|{0}{fot}|{0} {fot}"),
+            ("unit/gallon", "{0} {gal}|{0}{gal}|{0} {gallon}"),
+            ("unit/gigabit", "{0} {Gb}|{0}{Gb}|{0} {gigabit}"),
+            ("unit/gigabyte", "{0} {GB}|{0}{GB}|{0} {gigabyte}"),
+            ("unit/gram", "{0} {g}|{0}{g}|{0} {gram}"),
+            ("unit/hectare", "{0} {ha}|{0}{ha}|{0} {hektar}"),
+            ("unit/hour", "{0} {tim}|{0}{h}|one={0} {timme};other={0} {timmar}"),
+            ("unit/inch", "{0} {tum}|one={0} {tum};other={0}{\"}|{0} {tum}"),
+            ("unit/kilobit", "{0} {kb}|{0}{kb}|{0} {kilobit}"),
+            ("unit/kilobyte", "{0} {kB}|{0}{kB}|{0} {kilobyte}"),
+            ("unit/kilogram", "{0} {kg}|{0}{kg}|{0} {kilogram}"),
+            ("unit/kilometer", "{0} {km}|{0}{km}|{0} {kilometer}"),
+            ("unit/kilometer-per-hour", "{0} {km/h}|{0}{km/h}|{0} {kilometer per timme}"),
+            ("unit/liter", "{0} {l}|{0}{l}|{0} {liter}"),
+            ("unit/liter-per-kilometer", "{0} {l/km}|{0}{l/km}|{0} {liter per kilometer}"),
+            ("unit/megabit", "{0} {Mb}|{0}{Mb}|{0} {megabit}"),
+            ("unit/megabyte", "{0} {MB}|{0}{MB}|{0} {megabyte}"),
+            ("unit/meter", "{0} {m}|{0}{m}|{0} {meter}"),
+            ("unit/meter-per-second", "{0} {m/s}|{0}{m/s}|{0} {meter per sekund}"),
+            (
+                "unit/microsecond",
+                "{0} {μs}|{0}{μs}|one={0} {mikrosekund};other={0} {mikrosekunder}",
+            ),
+            ("unit/mile", "{0} {mi}|{0}{mi}|{0} {mile}"),
+            (
+                "unit/mile-per-gallon",
+                "{0} {mpg}|{0}{mpg}|one={0} {mile per gallon};other={0} {miles per gallon}",
+            ),
+            ("unit/mile-per-hour", "{0} {mi/h}|{0}{mi/h}|{0} {mile per timme}"),
+            ("unit/mile-scandinavian", "{0} {mil}|{0}{mil}|{0} {mil}"),
+            ("unit/milliliter", "{0} {ml}|{0}{ml}|{0} {milliliter}"),
+            ("unit/millimeter", "{0} {mm}|{0}{mm}|{0} {millimeter}"),
+            (
+                "unit/millisecond",
+                "{0} {ms}|{0}{ms}|one={0} {millisekund};other={0} {millisekunder}",
+            ),
+            ("unit/minute", "{0} {min}|{0}{m}|one={0} {minut};other={0} {minuter}"),
+            ("unit/month", "{0} {mån}|{0}{m}|one={0} {månad};other={0} {månader}"),
+            ("unit/nanosecond", "{0} {ns}|{0}{ns}|one={0} {nanosekund};other={0} {nanosekunder}"),
+            ("unit/ounce", "{0} {uns}|{0}{uns}|{0} {uns}"),
+            ("unit/percent", "{0}\u{a0}{%}||{0} {procent}"),
+            ("unit/petabyte", "{0} {PB}|{0}{PB}|{0} {petabyte}"),
+            ("unit/pound", "{0} {pund}|{0}{lb}|{0} {pund}"),
+            ("unit/second", "{0} {s}|{0}{s}|one={0} {sekund};other={0} {sekunder}"),
+            (
+                "unit/stone",
+                "{0} {eng. sten}|{0} {eng. s:n}|one={0} {engelsk sten};other={0} {engelska stenar}",
+            ),
+            ("unit/terabit", "{0} {Tb}|{0}{Tb}|{0} {terabit}"),
+            ("unit/terabyte", "{0} {TB}|{0}{TB}|{0} {terabyte}"),
+            ("unit/week", "{0} {v}|{0}{v}|one={0} {vecka};other={0} {veckor}"),
+            ("unit/yard", "{0} {yd}|one={0} {yd};other={0}{yd}|{0} {yard}"),
+            ("unit/year", "{0} {år}|{0}{å}|{0} {år}"),
+        ],
+    ),
+    (
+        "sv-FI",
+        &[
+            ("unit/kilometer-per-hour", "{0} {km/h}||{0} {kilometer per timme}"),
+        ],
+    ),
+    (
+        "th",
+        &[
+            ("accounting", "({}{0})"),
+            ("compact-long/10", "10|{0} {หมื่นล้าน}"),
+            ("compact-long/11", "11|{0} {แสนล้าน}"),
+            ("compact-long/12", "12|{0} {ล้านล้าน}"),
+            ("compact-long/13", "12|{0} {ล้านล้าน}"),
+            ("compact-long/14", "12|{0} {ล้านล้าน}"),
+            ("compact-long/3", "3|{0} {พัน}"),
+            ("compact-long/4", "4|{0} {หมื่น}"),
+            ("compact-long/5", "5|{0} {แสน}"),
+            ("compact-long/6", "6|{0} {ล้าน}"),
+            ("compact-long/7", "6|{0} {ล้าน}"),
+            ("compact-long/8", "6|{0} {ล้าน}"),
+            ("compact-long/9", "9|{0} {พันล้าน}"),
+            ("compact-short/10", "9|{0}{B}"),
+            ("compact-short/11", "9|{0}{B}"),
+            ("compact-short/12", "12|{0}{T}"),
+            ("compact-short/13", "12|{0}{T}"),
+            ("compact-short/14", "12|{0}{T}"),
+            ("compact-short/3", "3|{0}{K}"),
+            ("compact-short/4", "3|{0}{K}"),
+            ("compact-short/5", "3|{0}{K}"),
+            ("compact-short/6", "6|{0}{M}"),
+            ("compact-short/7", "6|{0}{M}"),
+            ("compact-short/8", "6|{0}{M}"),
+            ("compact-short/9", "9|{0}{B}"),
+            ("currency", "{-}{}{0}"),
+            ("currency-name", "{0} {}"),
+            ("currency/AED", "AED|AED|เดอร์แฮมสหรัฐอาหรับเอมิเรตส์"),
+            ("currency/ARS", "ARS|$|เปโซอาร์เจนตินา"),
+            ("currency/AUD", "AU$|$|ดอลลาร์ออสเตรเลีย"),
+            ("currency/BRL", "R$|R$|เรียลบราซิล"),
+            ("currency/CAD", "CA$|$|ดอลลาร์แคนาดา"),
+            ("currency/CHF", "CHF|CHF|ฟรังก์สวิส"),
+            ("currency/CLP", "CLP|$|เปโซชิลี"),
+            ("currency/CNY", "CN¥|¥|หยวนจีน"),
+            ("currency/COP", "COP|$|เปโซโคลอมเบีย"),
+            ("currency/CZK", "CZK|Kč|โครูนาสาธารณรัฐเช็ก"),
+            ("currency/DKK", "DKK|kr|โครนเดนมาร์ก"),
+            ("currency/EGP", "EGP|E£|ปอนด์อียิปต์"),
+            ("currency/EUR", "€|€|ยูโร"),
+            ("currency/GBP", "£|£|ปอนด์สเตอร์ลิง (สหราชอาณาจักร)"),
+            ("currency/HKD", "HK$|$|ดอลลาร์ฮ่องกง"),
+            ("currency/HUF", "HUF|Ft|ฟอรินต์ฮังการี"),
+            ("currency/IDR", "IDR|Rp|รูเปียห์อินโดนีเซีย"),
+            ("currency/ILS", "₪|₪|นิวเชเกลอิสราเอล"),
+            ("currency/INR", "₹|₹|รูปีอินเดีย"),
+            ("currency/JPY", "¥|¥|เยนญี่ปุ่น"),
+            ("currency/KRW", "₩|₩|วอนเกาหลีใต้"),
+            ("currency/MXN", "MX$|$|เปโซเม็กซิโก"),
+            ("currency/MYR", "MYR|RM|ริงกิตมาเลเซีย"),
+            ("currency/NOK", "NOK|kr|โครนนอร์เวย์"),
+            ("currency/NZD", "NZ$|$|ดอลลาร์นิวซีแลนด์"),
+            ("currency/PHP", "PHP|₱|เปโซฟิลิปปินส์"),
+            ("currency/PKR", "PKR|Rs|รูปีปากีสถาน"),
+            ("currency/PLN", "PLN|zł|ซลอตีโปแลนด์"),
+            ("currency/RUB", "RUB|₽|รูเบิลรัสเซีย"),
+            ("currency/SAR", "SAR|SAR|ริยัลซาอุดีอาระเบีย"),
+            ("currency/SEK", "SEK|kr|โครนาสวีเดน"),
+            ("currency/SGD", "SGD|$|ดอลลาร์สิงคโปร์"),
+            ("currency/THB", "฿|฿|บาทไทย"),
+            ("currency/TRY", "TRY|₺|ลีราตุรกี"),
+            ("currency/TWD", "NT$|NT$|ดอลลาร์ไต้หวันใหม่"),
+            ("currency/UAH", "UAH|₴|ฮรีฟเนียยูเครน"),
+            ("currency/USD", "US$|$|ดอลลาร์สหรัฐ"),
+            ("currency/VND", "₫|₫|ดองเวียดนาม"),
+            ("currency/ZAR", "ZAR|R|แรนด์แอฟริกาใต้"),
+            ("per", "{0}{/}{}|{0}{/}{}|{0}{ต่อ}{}"),
+            ("per-unit/centimeter", "{0}{/ซม.}|{0}{/ซม.}|{0}{ ต่อเซนติเมตร}"),
+            ("per-unit/day", "{0}{/วัน}|{0}{/วัน}|{0}{ ต่อวัน}"),
+            ("per-unit/foot", "{0}{/ฟุต}|{0}{/ฟุต}|{0}{ ต่อฟุต}"),
+            ("per-unit/gallon", "{0}{/แกลลอน}|{0}{/แกลลอน}|{0}{ ต่อแกลลอน}"),
+            ("per-unit/gram", "{0}{/ก.}|{0}{/ก.}|{0}{ ต่อกรัม}"),
+            ("per-unit/hour", "{0}{/ชม.}|{0}{/ชม.}|{0}{ ต่อชั่วโมง}"),
+            ("per-unit/inch", "{0}{/นิ้ว}|{0}{/นิ้ว}|{0}{ ต่อนิ้ว}"),
+            ("per-unit/kilogram", "{0}{/กก.}|{0}{/กก.}|{0}{ ต่อกิโลกรัม}"),
+            ("per-unit/kilometer", "{0}{/กม.}|{0}{/กม.}|{0}{ ต่อกิโลเมตร}"),
+            ("per-unit/liter", "{0}{/ล.}|{0}{/ล.}|{0}{ ต่อลิตร}"),
+            ("per-unit/meter", "{0}{/ม.}|{0}{/ม.}|{0}{ ต่อเมตร}"),
+            ("per-unit/minute", "{0}{/นาที}|{0}{/นาที}|{0}{ ต่อนาที}"),
+            ("per-unit/month", "{0}{/เดือน}|{0}{/เดือน}|{0}{ ต่อเดือน}"),
+            ("per-unit/ounce", "{0}{/ออนซ์}|{0}{/ออนซ์}|{0}{ ต่อออนซ์}"),
+            ("per-unit/pound", "{0}{/ปอนด์}|{0}{/ปอนด์}|{0}{ ต่อปอนด์}"),
+            ("per-unit/second", "{0}{/วิ}|{0}{/วิ}|{0}{ ต่อวินาที}"),
+            ("per-unit/week", "{0}{/สัปดาห์}|{0}{/สัปดาห์}|{0}{ ต่อสัปดาห์}"),
+            ("per-unit/year", "{0}{/ปี}|{0}{/ปี}|{0}{ ต่อปี}"),
+            ("percent", "{-}{0}{%}"),
+            ("unit/acre", "{0} {เอเคอร์}|{0}{เอเคอร์}|{0} {เอเคอร์}"),
+            ("unit/bit", "{0} {บิต}|{0}{บิต}|{0} {บิต}"),
+            ("unit/byte", "{0} {ไบต์}|{0}{ไบต์}|{0} {ไบต์}"),
+            ("unit/celsius", "{0}{°C}||{0} {องศาเซลเซียส}"),
+            ("unit/centimeter", "{0} {ซม.}|{0}{ซม.}|{0} {เซนติเมตร}"),
+            ("unit/day", "{0} {วัน}|{0}{วัน}|{0} {วัน}"),
+            ("unit/degree", "{0}{°}||{0} {องศา}"),
+            ("unit/fahrenheit", "{0}{°F}||{0} {องศาฟาเรนไฮต์}"),
+            ("unit/fluid-ounce", "{0} {ฟลูอิดออนซ์}|{0}{fl oz}|{0} {ฟลูอิดออนซ์}"),
+            ("unit/foot", "{0} {ฟุต}|{0}{′}|{0} {ฟุต}"),
+            ("unit/gallon", "{0} {แกลลอน}|{0}{แกลลอน}|{0} {แกลลอน}"),
+            ("unit/gigabit", "{0} {Gb}|{0}{Gb}|{0} {กิกะบิต}"),
+            ("unit/gigabyte", "{0} {GB}|{0}{GB}|{0} {กิกะไบต์}"),
+            ("unit/gram", "{0} {ก.}|{0}{ก.}|{0} {กรัม}"),
+            ("unit/hectare", "{0} {เฮกตาร์}|{0}{เฮกตาร์}|{0} {เฮกตาร์}"),
+            ("unit/hour", "{0} {ชม.}|{0}{ชม.}|{0} {ชั่วโมง}"),
+            ("unit/inch", "{0} {นิ้ว}|{0}{″}|{0} {นิ้ว}"),
+            ("unit/kilobit", "{0} {kb}|{0}{kb}|{0} {กิโลบิต}"),
+            ("unit/kilobyte", "{0} {kB}|{0}{kB}|{0} {กิโลไบต์}"),
+            ("unit/kilogram", "{0} {กก.}|{0}{กก.}|{0} {กิโลกรัม}"),
+            ("unit/kilometer", "{0} {กม.}|{0}{กม.}|{0} {กิโลเมตร}"),
+            ("unit/kilometer-per-hour", "{0} {กม./ชม.}|{0}{กม./ชม.}|{0} {กิโลเมตรต่อชั่วโมง}"),
+            ("unit/liter", "{0} {ล.}|{0}{ล.}|{0} {ลิตร}"),
+            ("unit/liter-per-kilometer", "{0} {ล./กม.}|{0}{ล./กม.}|{0} {ลิตรต่อกิโลเมตร}"),
+            ("unit/megabit", "{0} {Mb}|{0}{Mb}|{0} {เมกะบิต}"),
+            ("unit/megabyte", "{0} {MB}|{0}{MB}|{0} {เมกะไบต์}"),
+            ("unit/meter", "{0} {ม.}|{0}{ม.}|{0} {เมตร}"),
+            ("unit/meter-per-second", "{0} {ม./วิ}|{0}{ม./วิ}|{0} {เมตรต่อวินาที}"),
+            ("unit/microsecond", "{0} {ไมโครวิ}|{0}{ไมโครวิ}|{0} {ไมโครวินาที}"),
+            ("unit/mile", "{0} {ไมล์}|{0}{ไมล์}|{0} {ไมล์}"),
+            ("unit/mile-per-gallon", "{0} {ไมล์/แกลลอน}|{0}{mpg}|{0} {ไมล์ต่อแกลลอน}"),
+            ("unit/mile-per-hour", "{0} {ไมล์/ชม.}|{0}{ไมล์/ชม.}|{0} {ไมล์ต่อชั่วโมง}"),
+            ("unit/mile-scandinavian", "{0} {smi}|{0}{smi}|{0} {ไมล์สแกนดิเนเวีย}"),
+            ("unit/milliliter", "{0} {มล.}|{0}{มล.}|{0} {มิลลิลิตร}"),
+            ("unit/millimeter", "{0} {มม.}|{0}{มม.}|{0} {มิลลิเมตร}"),
+            ("unit/millisecond", "{0} {มิลลิวินาที}|{0} {มิลลิวิ}|{0} {มิลลิวินาที}"),
+            ("unit/minute", "{0} {นาที}|{0}{นาที}|{0} {นาที}"),
+            ("unit/month", "{0} {เดือน}|{0}{เดือน}|{0} {เดือน}"),
+            ("unit/nanosecond", "{0} {นาโนวิ}|{0}{นาโนวิ}|{0} {นาโนวินาที}"),
+            ("unit/ounce", "{0} {ออนซ์}|{0}{ออนซ์}|{0} {ออนซ์}"),
+            ("unit/percent", "{0}{%}||{0}\u{a0}{เปอร์เซ็นต์}"),
+            ("unit/petabyte", "{0} {PB}|{0}{PB}|{0} {เพตะไบต์}"),
+            ("unit/pound", "{0} {ปอนด์}|{0}{#}|{0} {ปอนด์}"),
+            ("unit/second", "{0} {วิ}|{0}{วิ}|{0} {วินาที}"),
+            ("unit/stone", "{0} {st}|{0}{st}|{0} {สโตน}"),
+            ("unit/terabit", "{0} {Tb}|{0}{Tb}|{0} {เทราบิต}"),
+            ("unit/terabyte", "{0} {TB}|{0}{TB}|{0} {เทราไบต์}"),
+            ("unit/week", "{0} {สัปดาห์}|{0}{สัปดาห์}|{0} {สัปดาห์}"),
+            ("unit/yard", "{0} {หลา}|{0}{หลา}|{0} {หลา}"),
+            ("unit/year", "{0} {ปี}|{0}{ปี}|{0} {ปี}"),
+        ],
+    ),
+    (
+        "tr",
+        &[
+            ("accounting", "({}{0})"),
+            ("compact-long/10", "9|{0} {milyar}"),
+            ("compact-long/11", "9|{0} {milyar}"),
+            ("compact-long/12", "12|{0} {trilyon}"),
+            ("compact-long/13", "12|{0} {trilyon}"),
+            ("compact-long/14", "12|{0} {trilyon}"),
+            ("compact-long/3", "3|{0} {bin}"),
+            ("compact-long/4", "3|{0} {bin}"),
+            ("compact-long/5", "3|{0} {bin}"),
+            ("compact-long/6", "6|{0} {milyon}"),
+            ("compact-long/7", "6|{0} {milyon}"),
+            ("compact-long/8", "6|{0} {milyon}"),
+            ("compact-long/9", "9|{0} {milyar}"),
+            ("compact-short/10", "9|{0}\u{a0}{Mr}"),
+            ("compact-short/11", "9|{0}\u{a0}{Mr}"),
+            ("compact-short/12", "12|{0}\u{a0}{Tn}"),
+            ("compact-short/13", "12|{0}\u{a0}{Tn}"),
+            ("compact-short/14", "12|{0}\u{a0}{Tn}"),
+            ("compact-short/3", "3|{0}\u{a0}{B}"),
+            ("compact-short/4", "3|{0}\u{a0}{B}"),
+            ("compact-short/5", "3|{0}\u{a0}{B}"),
+            ("compact-short/6", "6|{0}\u{a0}{Mn}"),
+            ("compact-short/7", "6|{0}\u{a0}{Mn}"),
+            ("compact-short/8", "6|{0}\u{a0}{Mn}"),
+            ("compact-short/9", "9|{0}\u{a0}{Mr}"),
+            ("currency", "{-}{}{0}"),
+            ("currency-name", "{0} {}"),
+            ("currency/AED", "AED|AED|BAE dirhemi"),
+            ("currency/ARS", "ARS|$|Arjantin pesosu"),
+            ("currency/AUD", "AU$|$|Avustralya doları"),
+            ("currency/BRL", "R$|R$|Brezilya reali"),
+            ("currency/CAD", "CA$|$|Kanada doları"),
+            ("currency/CHF", "CHF|CHF|İsviçre frangı"),
+            ("currency/CLP", "CLP|$|Şili pesosu"),
+            ("currency/CNY", "CN¥|¥|Çin yuanı"),
+            ("currency/COP", "COP|$|Kolombiya pesosu"),
+            ("currency/CZK", "CZK|Kč|Çek korunası"),
+            ("currency/DKK", "DKK|kr|Danimarka kronu"),
+            ("currency/EGP", "EGP|E£|Mısır lirası"),
+            ("currency/EUR", "€|€|Euro"),
+            ("currency/GBP", "£|£|İngiliz sterlini"),
+            ("currency/HKD", "HK$|$|Hong Kong doları"),
+            ("currency/HUF", "HUF|Ft|Macar forinti"),
+            ("currency/IDR", "IDR|Rp|Endonezya rupisi"),
+            ("currency/ILS", "₪|₪|Yeni İsrail şekeli"),
+            ("currency/INR", "₹|₹|Hindistan rupisi"),
+            ("currency/JPY", "¥|¥|Japon yeni"),
+            ("currency/KRW", "₩|₩|Güney Kore wonu"),
+            ("currency/MXN", "MX$|$|Meksika pesosu"),
+            ("currency/MYR", "MYR|RM|Malezya ringgiti"),
+            ("currency/NOK", "NOK|kr|Norveç kronu"),
+            ("currency/NZD", "NZ$|$|Yeni Zelanda doları"),
+            ("currency/PHP", "PHP|₱|Filipinler pesosu"),
+            ("currency/PKR", "PKR|Rs|Pakistan rupisi"),
+            ("currency/PLN", "PLN|zł|Polonya zlotisi"),
+            ("currency/RUB", "RUB|₽|Rus rublesi"),
+            ("currency/SAR", "SAR|SAR|Suudi Arabistan riyali"),
+            ("currency/SEK", "SEK|kr|İsveç kronu"),
+            ("currency/SGD", "SGD|$|Singapur doları"),
+            ("currency/THB", "฿|฿|Tayland bahtı"),
+            ("currency/TRY", "₺|₺|Türk lirası"),
+            ("currency/TWD", "NT$|NT$|Yeni Tayvan doları"),
+            ("currency/UAH", "UAH|₴|Ukrayna grivnası"),
+            ("currency/USD", "$|$|ABD doları"),
+            ("currency/VND", "₫|₫|Vietnam dongu"),
+            ("currency/ZAR", "ZAR|R|Güney Afrika randı"),
+            ("per", "{0}{/}{}|{0}{/}{}|{0}{/}{}"),
+            ("per-unit/centimeter", "{0}{/cm}|{0}{/cm}|{0}{/santimetre}"),
+            ("per-unit/day", "{0}{/gün}|{0}{/g}|{0}{/gün}"),
+            ("per-unit/foot", "{0}{/ft}|{0}{/ft}|{0}{/fit}"),
+            ("per-unit/gallon", "{0}{/gal}|{0}{/gal}|{0}{/galon}"),
+            ("per-unit/gram", "{0}{/g}|{0}{/g}|{0}{/gram}"),
+            ("per-unit/hour", "{0}{/sa}|{0}{/sa}|{0}{/saat}"),
+            ("per-unit/inch", "{0}{/in}|{0}{/in}|{0}{/inç}"),
+            ("per-unit/kilogram", "{0}{/kg}|{0}{/kg}|{0}{/kg}"),
+            ("per-unit/kilometer", "{0}{/km}|{0}{/km}|{0}{/kilometre}"),
+            ("per-unit/liter", "{0}{/l}|{0}{/l}|{0}{/litre}"),
+            ("per-unit/meter", "{0}{/m}|{0}{/m}|{0}{/metre}"),
+            ("per-unit/minute", "{0}{/dk.}|{0}{/dk.}|{0}{/dakika}"),
+            ("per-unit/month", "{0}{/ay}|{0}{/ay}|{0}{/ay}"),
+            ("per-unit/ounce", "{0}{/oz}|{0}{/oz}|{0}{/oz}"),
+            ("per-unit/pound", "{0}{/lb}|{0}{/lb}|{0}{/libre}"),
+            ("per-unit/second", "{0}{/sn}|{0}{/sn}|{0}{/saniye}"),
+            ("per-unit/week", "{0}{/hf.}|{0}{/hf.}|{0}{/hafta}"),
+            ("per-unit/year", "{0}{/y}|{0}{/y}|{0}{/yıl}"),
+            ("percent", "{-}{%}{0}"),
+            ("unit/acre", "{0} {ac}||{0} {akre}"),
+            ("unit/bit", "{0} {bit}||{0} {bit}"),
+            ("unit/byte", "{0} {bayt}||{0} {bayt}"),
+            (
+                "unit/celsius",
+                "one={0} {°C};other={0}{°C}|one={0}{°C};other={0} {°C}|{0} {santigrat derece}",
+            ),
+            ("unit/centimeter", "{0} {cm}||{0} {santimetre}"),
+            ("unit/day", "{0} {gün}|{0}{g}|{0} {gün}"),
+            ("unit/degree", "{0}{°}||{0} {derece}"),
+            ("unit/fahrenheit", "one={0} {°F};other={0}{°F}|{0} {°F}|{0} {fahrenhayt derece}"),
+            ("unit/fluid-ounce", "{0} {sıvı ons}||{0} {sıvı ons}"),
+            ("unit/foot", "{0} {fit}|{0}{′}|{0} {fit}"),
+            ("unit/gallon", "{0} {gal}|{0} {galon}|{0} {galon}"),
+            ("unit/gigabit", "{0} {Gb}||{0} {gigabit}"),
+            ("unit/gigabyte", "{0} {GB}||{0} {gigabayt}"),
+            ("unit/gram", "{0} {g}||{0} {gram}"),
+            ("unit/hectare", "{0} {ha}||{0} {hektar}"),
+            ("unit/hour", "{0} {sa.}|one={0} {sa};other={0}{s}|{0} {saat}"),
+            ("unit/inch", "{0} {inç}|{0}{″}|{0} {inç}"),
+            ("unit/kilobit", "{0} {kb}||{0} {kilobit}"),
+            ("unit/kilobyte", "{0} {kB}||{0} {kilobayt}"),
+            ("unit/kilogram", "{0} {kg}||{0} {kilogram}"),
+            ("unit/kilometer", "{0} {km}||{0} {kilometre}"),
+            ("unit/kilometer-per-hour", "{0} {km/sa}||{0} {kilometre/saat}"),
+            ("unit/liter", "{0} {l}||{0} {litre}"),
+            ("unit/liter-per-kilometer", "{0} {l/km}||{0} {litre/kilometre}"),
+            ("unit/megabit", "{0} {Mb}||{0} {megabit}"),
+            ("unit/megabyte", "{0} {MB}||{0} {megabayt}"),
+            ("unit/meter", "{0} {m}||{0} {metre}"),
+            ("unit/meter-per-second", "{0} {m/sn}||{0} {metre/saniye}"),
+            ("unit/microsecond", "{0} {μsn}||{0} {mikrosaniye}"),
+            ("unit/mile", "{0} {mil}||{0} {mil}"),
+            ("unit/mile-per-gallon", "{0} {mpg}||{0} {mil/galon}"),
+            ("unit/mile-per-hour", "{0} {mil/sa}||{0} {mil/saat}"),
+            ("unit/mile-scandinavian", "{0} {smi}||{0} {smi}"),
+            ("unit/milliliter", "{0} {ml}||{0} {mililitre}"),
+            ("unit/millimeter", "{0} {mm}||{0} {milimetre}"),
+            ("unit/millisecond", "{0} {msn}|{0}{msn}|{0} {milisaniye}"),
+            ("unit/minute", "{0} {dk.}|{0}{d}|{0} {dakika}"),
+            ("unit/month", "{0} {ay}|{0}{a}|{0} {ay}"),
+            ("unit/nanosecond", "{0} {nsn}||{0} {nanosaniye}"),
+            ("unit/ounce", "{0} {oz}||{0} {ons}"),
+            ("unit/percent", "{%}{0}||{yüzde} {0}"),
+            ("unit/petabyte", "{0} {PB}||{0} {petabayt}"),
+            ("unit/pound", "{0} {lb}||{0} {libre}"),
+            ("unit/second", "{0} {sn.}|{0}{sn}|{0} {saniye}"),
+            ("unit/stone", "{0} {st}||{0} {stone}"),
+            ("unit/terabit", "{0} {Tb}||{0} {terabit}"),
+            ("unit/terabyte", "{0} {TB}||{0} {terabayt}"),
+            ("unit/week", "{0} {hf.}|{0}{h}|{0} {hafta}"),
+            ("unit/yard", "{0} {yd}||{0} {yarda}"),
+            ("unit/year", "{0} {yıl}|{0}{y}|{0} {yıl}"),
+        ],
+    ),
+    (
+        "tr-TR",
+        &[
+            ("accounting", "{-}{}{0}"),
+            ("currency-name", "€{0} {}"),
+        ],
+    ),
+    (
+        "uk",
+        &[
+            ("accounting", "{-}{0}\u{a0}{}"),
+            ("compact-long/10", "9|one={0} {мільярд};few={0} {мільярди};other={0} {мільярдів}"),
+            ("compact-long/11", "9|one={0} {мільярд};few={0} {мільярди};other={0} {мільярдів}"),
+            (
+                "compact-long/12",
+                "12|one={0} {трильйон};few={0} {трильйони};many={0} {трильйонів};other={0} {трильйона}",
+            ),
+            ("compact-long/13", "12|one={0} {трильйон};few={0} {трильйони};other={0} {трильйонів}"),
+            ("compact-long/14", "12|one={0} {трильйон};few={0} {трильйони};other={0} {трильйонів}"),
+            ("compact-long/3", "3|one={0} {тисяча};many={0} {тисяч};other={0} {тисячі}"),
+            ("compact-long/4", "3|one={0} {тисяча};few={0} {тисячі};other={0} {тисяч}"),
+            ("compact-long/5", "3|one={0} {тисяча};few={0} {тисячі};other={0} {тисяч}"),
+            (
+                "compact-long/6",
+                "6|one={0} {мільйон};few={0} {мільйони};many={0} {мільйонів};other={0} {мільйона}",
+            ),
+            ("compact-long/7", "6|one={0} {мільйон};few={0} {мільйони};other={0} {мільйонів}"),
+            ("compact-long/8", "6|one={0} {мільйон};few={0} {мільйони};other={0} {мільйонів}"),
+            (
+                "compact-long/9",
+                "9|one={0} {мільярд};few={0} {мільярди};many={0} {мільярдів};other={0} {мільярда}",
+            ),
+            ("compact-short/10", "9|{0}\u{a0}{млрд}"),
+            ("compact-short/11", "9|{0}\u{a0}{млрд}"),
+            ("compact-short/12", "12|{0}\u{a0}{трлн}"),
+            ("compact-short/13", "12|{0}\u{a0}{трлн}"),
+            ("compact-short/14", "12|{0}\u{a0}{трлн}"),
+            ("compact-short/3", "3|{0}\u{a0}{тис.}"),
+            ("compact-short/4", "3|{0}\u{a0}{тис.}"),
+            ("compact-short/5", "3|{0}\u{a0}{тис.}"),
+            ("compact-short/6", "6|{0}\u{a0}{млн}"),
+            ("compact-short/7", "6|{0}\u{a0}{млн}"),
+            ("compact-short/8", "6|{0}\u{a0}{млн}"),
+            ("compact-short/9", "9|{0}\u{a0}{млрд}"),
+            ("currency", "{-}{0}\u{a0}{}"),
+            ("currency-name", "{0} {}"),
+            (
+                "currency/AED",
+                "AED|AED|one=дирхам ОАЕ;few=дирхами ОАЕ;many=дирхамів ОАЕ;other=дирхама ОАЕ",
+            ),
+            (
+                "currency/ARS",
+                "ARS|$|one=аргентинський песо;few=аргентинські песо;many=аргентинських песо;other=аргентинського песо",
+            ),
+            (
+                "currency/AUD",
+                "AUD|$|one=австралійський долар;few=австралійські долари;many=австралійських доларів;other=австралійського долара",
+            ),
+            (
+                "currency/BRL",
+                "BRL|R$|one=бразильський реал;few=бразильські реали;many=бразильських реалів;other=бразильського реала",
+            ),
+            (
+                "currency/CAD",
+                "CAD|$|one=канадський долар;few=канадські долари;many=канадських доларів;other=канадського долара",
+            ),
+            (
+                "currency/CHF",
+                "CHF|CHF|one=швейцарський франк;few=швейцарські франки;many=швейцарських франків;other=швейцарського франка",
+            ),
+            (
+                "currency/CLP",
+                "CLP|$|one=чилійський песо;few=чилійські песо;many=чилійських песо;other=чилійського песо",
+            ),
+            (
+                "currency/CNY",
+                "CNY|¥|one=китайський юань;few=китайські юані;many=китайських юанів;other=китайського юаня",
+            ),
+            (
+                "currency/COP",
+                "COP|$|one=колумбійський песо;few=колумбійські песо;many=колумбійських песо;other=колумбійського песо",
+            ),
+            (
+                "currency/CZK",
+                "CZK|Kč|one=чеська крона;few=чеські крони;many=чеських крон;other=чеської крони",
+            ),
+            (
+                "currency/DKK",
+                "DKK|kr|one=данська крона;few=данські крони;many=данських крон;other=данської крони",
+            ),
+            (
+                "currency/EGP",
+                "EGP|E£|one=єгипетський фунт;few=єгипетські фунти;many=єгипетських фунтів;other=єгипетського фунта",
+            ),
+            ("currency/EUR", "EUR|€|євро"),
+            (
+                "currency/GBP",
+                "GBP|£|one=англійський фунт;few=англійські фунти;many=англійських фунтів;other=англійського фунта",
+            ),
+            (
+                "currency/HKD",
+                "HKD|$|one=гонконгський долар;few=гонконгські долари;many=гонконгських доларів;other=гонконгського долара",
+            ),
+            (
+                "currency/HUF",
+                "HUF|Ft|one=угорський форинт;few=угорські форинти;many=угорських форинтів;other=угорського форинта",
+            ),
+            (
+                "currency/IDR",
+                "IDR|Rp|one=індонезійська рупія;many=індонезійських рупій;other=індонезійські рупії",
+            ),
+            (
+                "currency/ILS",
+                "ILS|₪|one=ізраїльський новий шекель;few=ізраїльські нові шекелі;many=ізраїльських нових шекелів;other=ізраїльського нового шекеля",
+            ),
+            (
+                "currency/INR",
+                "INR|₹|one=індійська рупія;few=індійські рупії;many=індійських рупій;other=індійської рупії",
+            ),
+            (
+                "currency/JPY",
+                "¥|¥|one=японська єна;few=японські єни;many=японських єн;other=японської єни",
+            ),
+            (
+                "currency/KRW",
+                "KRW|₩|one=південнокорейський вон;few=південнокорейські вони;many=південнокорейських вонів;other=південнокорейського вона",
+            ),
+            (
+                "currency/MXN",
+                "MXN|$|one=мексиканський песо;few=мексиканські песо;many=мексиканських песо;other=мексиканського песо",
+            ),
+            (
+                "currency/MYR",
+                "MYR|RM|one=малайзійський рингіт;few=малайзійські рингіти;many=малайзійських рингітів;other=малайзійського рингіта",
+            ),
+            (
+                "currency/NOK",
+                "NOK|kr|one=норвезька крона;few=норвезькі крони;many=норвезьких крон;other=норвезької крони",
+            ),
+            (
+                "currency/NZD",
+                "NZD|$|one=новозеландський долар;few=новозеландські долари;many=новозеландських доларів;other=новозеландського долара",
+            ),
+            (
+                "currency/PHP",
+                "PHP|₱|one=філіппінський песо;few=філіппінські песо;many=філіппінських песо;other=філіппінського песо",
+            ),
+            (
+                "currency/PKR",
+                "PKR|Rs|one=пакистанська рупія;few=пакистанські рупії;many=пакистанських рупій;other=пакистанської рупії",
+            ),
+            (
+                "currency/PLN",
+                "PLN|zł|one=польський злотий;few=польські злоті;many=польських злотих;other=польського злотого",
+            ),
+            (
+                "currency/RUB",
+                "RUB|₽|one=російський рубль;few=російські рублі;many=російських рублів;other=російського рубля",
+            ),
+            (
+                "currency/SAR",
+                "SAR|SAR|one=саудівський ріал;few=саудівські ріали;many=саудівських ріалів;other=саудівського ріала",
+            ),
+            (
+                "currency/SEK",
+                "SEK|kr|one=шведська крона;few=шведські крони;many=шведських крон;other=шведської крони",
+            ),
+            (
+                "currency/SGD",
+                "SGD|$|one=сінгапурський долар;few=сінгапурські долари;many=сінгапурських доларів;other=сінгапурського долара",
+            ),
+            (
+                "currency/THB",
+                "THB|฿|one=таїландський бат;few=таїландські бати;many=таїландських батів;other=таїландського бата",
+            ),
+            (
+                "currency/TRY",
+                "TRY|₺|one=турецька ліра;few=турецькі ліри;many=турецьких лір;other=турецької ліри",
+            ),
+            (
+                "currency/TWD",
+                "TWD|$|one=новий тайванський долар;few=нові тайванські долари;many=нових тайванських доларів;other=нового тайванського долара",
+            ),
+            (
+                "currency/UAH",
+                "₴|₴|one=українська гривня;few=українські гривні;many=українських гривень;other=української гривні",
+            ),
+            (
+                "currency/USD",
+                "USD|$|one=долар США;few=долари США;many=доларів США;other=долара США",
+            ),
+            (
+                "currency/VND",
+                "VND|₫|one=вʼєтнамський донг;few=вʼєтнамські донги;many=вʼєтнамських донгів;other=вʼєтнамського донга",
+            ),
+            (
+                "currency/ZAR",
+                "ZAR|R|one=південноафриканський ранд;few=південноафриканські ранди;many=південноафриканських рандів;other=південноафриканського ранда",
+            ),
+            ("per", "{0}{/}{}|{0}{/}{}|{0}{ на }{}"),
+            ("per-unit/centimeter", "{0}{/см}|{0}{/см}|{0}{ на сантиметр}"),
+            ("per-unit/day", "{0}{/дн.}|{0}{/д}|{0}{ на день}"),
+            ("per-unit/foot", "{0}{/фт}|{0}{/фт}|{0}{ на фут}"),
+            ("per-unit/gallon", "{0}{/гал.}|{0}{/гал.}|{0}{ на галон}"),
+            ("per-unit/gram", "{0}{/г}|{0}{/г}|{0}{ на грам}"),
+            ("per-unit/hour", "{0}{/год}|{0}{/г}|{0}{ на годину}"),
+            ("per-unit/inch", "{0}{/дюйм}|{0}{/дм}|{0}{ на дюйм}"),
+            ("per-unit/kilogram", "{0}{/кг}|{0}{/кг}|{0}{ на кілограм}"),
+            ("per-unit/kilometer", "{0}{/км}|{0}{/км}|{0}{ на кілометр}"),
+            ("per-unit/liter", "{0}{/л}|{0}{/л}|{0}{ на літр}"),
+            ("per-unit/meter", "{0}{/м}|{0}{/м}|{0}{ на метр}"),
+            ("per-unit/minute", "{0}{/хв}|{0}{/х}|{0}{ на хвилину}"),
+            ("per-unit/month", "{0}{/міс.}|{0}{/м}|{0}{ на місяць}"),
+            ("per-unit/ounce", "{0}{/унц.}|{0}{/ун}|{0}{ на унцію}"),
+            ("per-unit/pound", "{0}{/фунт}|{0}{/фнт}|{0}{ на фунт}"),
+            ("per-unit/second", "{0}{/с}|{0}{/с}|{0}{ на секунду}"),
+            ("per-unit/week", "{0}{/тиж.}|{0}{/т}|{0}{ на тиждень}"),
+            ("per-unit/year", "{0}{/р.}|{0}{/р}|{0}{ на рік}"),
+            ("percent", "{-}{0}{%}"),
+            (
+                "unit/acre",
+                "one={0} {акр};few={0} {акри};many={0} {акрів};other={0} {акра}|{0} {акр}|one={0} {акр};few={0} {акри};many={0} {акрів};other={0} {акра}",
+            ),
+            ("unit/bit", "{0} {б}||one={0} {біт};few={0} {біти};many={0} {бітів};other={0} {біта}"),
+            (
+                "unit/byte",
+                "{0} {Б}||one={0} {байт};few={0} {байти};many={0} {байтів};other={0} {байта}",
+            ),
+            (
+                "unit/celsius",
+                "{0}\u{a0}{°C}|{0}{°C}|one={0} {градус Цельсія};few={0} {градуси Цельсія};many={0} {градусів Цельсія};other={0} {градуса Цельсія}",
+            ),
+            (
+                "unit/centimeter",
+                "{0} {см}||one={0} {сантиметр};few={0} {сантиметри};many={0} {сантиметрів};other={0} {сантиметра}",
+            ),
+            (
+                "unit/day",
+                "{0} {дн.}|{0}{д}|one={0} {день};few={0} {дні};many={0} {днів};other={0} {дня}",
+            ),
+            (
+                "unit/degree",
+                "{0}{°}||one={0} {градус};few={0} {градуси};many={0} {градусів};other={0} {градуса}",
+            ),
+            (
+                "unit/fahrenheit",
+                "{0}\u{a0}{°F}|{0}{°F}|one={0} {градус Фаренгейта};few={0} {градуси Фаренгейта};many={0} {градусів Фаренгейта};other={0} {градуса Фаренгейта}",
+            ),
+            (
+                "unit/fluid-ounce",
+                "one={0} {рід. унція};many={0} {рід. унцій};other={0} {рід. унції}||one={0} {рідка унція};few={0} {рідкі унції};many={0} {рідких унцій};other={0} {рідкої унції}",
+            ),
+            (
+                "unit/foot",
+                "{0} {фт}||one={0} {фут};few={0} {фути};many={0} {футів};other={0} {фута}",
+            ),
+            (
+                "unit/gallon",
+                "{0} {гал.}||one={0} {галон};few={0} {галони};many={0} {галонів};other={0} {галона}",
+            ),
+            (
+                "unit/gigabit",
+                "{0} {Гб}||one={0} {гігабіт};few={0} {гігабіти};many={0} {гігабітів};other={0} {гігабіта}",
+            ),
+            (
+                "unit/gigabyte",
+                "{0} {ГБ}||one={0} {гігабайт};few={0} {гігабайти};many={0} {гігабайтів};other={0} {гігабайта}",
+            ),
+            (
+                "unit/gram",
+                "{0} {г}|{0}{г}|one={0} {грам};few={0} {грами};many={0} {грамів};other={0} {грама}",
+            ),
+            (
+                "unit/hectare",
+                "{0} {га}|{0}{га}|one={0} {гектар};few={0} {гектари};many={0} {гектарів};other={0} {гектара}",
+            ),
+            (
+                "unit/hour",
+                "{0} {год}|{0} {год.}|one={0} {година};many={0} {годин};other={0} {години}",
+            ),
+            (
+                "unit/inch",
+                "one={0} {дюйм};few={0} {дюйми};many={0} {дюймів};other={0} {дюйма}|{0} {дм}|one={0} {дюйм};few={0} {дюйми};many={0} {дюймів};other={0} {дюйма}",
+            ),
+            (
+                "unit/kilobit",
+                "{0} {кб}||one={0} {кілобіт};few={0} {кілобіти};many={0} {кілобітів};other={0} {кілобіта}",
+            ),
+            (
+                "unit/kilobyte",
+                "{0} {кБ}||one={0} {кілобайт};few={0} {кілобайти};many={0} {кілобайтів};other={0} {кілобайта}",
+            ),
+            (
+                "unit/kilogram",
+                "{0} {кг}|{0}{кг}|one={0} {кілограм};few={0} {кілограми};many={0} {кілограмів};other={0} {кілограма}",
+            ),
+            (
+                "unit/kilometer",
+                "{0} {км}||one={0} {кілометр};few={0} {кілометри};many={0} {кілометрів};other={0} {кілометра}",
+            ),
+            (
+                "unit/kilometer-per-hour",
+                "{0} {км/год}||one={0} {кілометр на годину};few={0} {кілометри на годину};many={0} {кілометрів на годину};other={0} {кілометра на годину}",
+            ),
+            (
+                "unit/liter",
+                "{0} {л}|{0}{л}|one={0} {літр};few={0} {літри};many={0} {літрів};other={0} {літра}",
+            ),
+            (
+                "unit/liter-per-kilometer",
+                "{0} {л/км}||one={0} {літр на кілометр};few={0} {літри на кілометр};many={0} {літрів на кілометр};other={0} {літра на кілометр}",
+            ),
+            (
+                "unit/megabit",
+                "{0} {Мб}||one={0} {мегабіт};few={0} {мегабіти};many={0} {мегабітів};other={0} {мегабіта}",
+            ),
+            (
+                "unit/megabyte",
+                "{0} {МБ}||one={0} {мегабайт};few={0} {мегабайти};many={0} {мегабайтів};other={0} {мегабайта}",
+            ),
+            (
+                "unit/meter",
+                "{0} {м}|{0}{м}|one={0} {метр};few={0} {метри};many={0} {метрів};other={0} {метра}",
+            ),
+            (
+                "unit/meter-per-second",
+                "{0} {м/с}||one={0} {метр на секунду};few={0} {метри на секунду};many={0} {метрів на секунду};other={0} {метра на секунду}",
+            ),
+            (
+                "unit/microsecond",
+                "{0} {мкс}|{0}{мкс}|one={0} {мікросекунда};many={0} {мікросекунд};other={0} {мікросекунди}",
+            ),
+            (
+                "unit/mile",
+                "one={0} {миля};many={0} {миль};other={0} {милі}|{0} {мл}|one={0} {миля};many={0} {миль};other={0} {милі}",
+            ),
+            (
+                "unit/mile-per-gallon",
+                "one={0} {миля/гал.};many={0} {миль/гал.};other={0} {милі/гал.}|{0} {мл/гал}|one={0} {миля на галон};many={0} {миль на галон};other={0} {милі на галон}",
+            ),
+            (
+                "unit/mile-per-hour",
+                "one={0} {миля/год};many={0} {миль/год};other={0} {милі/год}|{0} {мл/год}|one={0} {миля на годину};many={0} {миль на годину};other={0} {милі на годину}",
+            ),
+            (
+                "unit/mile-scandinavian",
+                "one={0} {сканд. миля};many={0} {сканд. миль};other={0} {сканд. милі}|{0} {сканд. мл}|one={0} {скандинавська миля};few={0} {скандинавські милі};many={0} {скандинавських миль};other={0} {скандинавської милі}",
+            ),
+            (
+                "unit/milliliter",
+                "{0} {мл}||one={0} {мілілітр};few={0} {мілілітри};many={0} {мілілітрів};other={0} {мілілітра}",
+            ),
+            (
+                "unit/millimeter",
+                "{0} {мм}||one={0} {міліметр};few={0} {міліметри};many={0} {міліметрів};other={0} {міліметра}",
+            ),
+            (
+                "unit/millisecond",
+                "{0} {мс}|{0}{мс}|one={0} {мілісекунда};many={0} {мілісекунд};other={0} {мілісекунди}",
+            ),
+            (
+                "unit/minute",
+                "{0} {хв}|{0}{х}|one={0} {хвилина};many={0} {хвилин};other={0} {хвилини}",
+            ),
+            (
+                "unit/month",
+                "{0} {міс.}|{0}{м}|one={0} {місяць};few={0} {місяці};many={0} {місяців};other={0} {місяця}",
+            ),
+            (
+                "unit/nanosecond",
+                "{0} {нс}|{0}{нс}|one={0} {наносекунда};many={0} {наносекунд};other={0} {наносекунди}",
+            ),
+            (
+                "unit/ounce",
+                "{0} {унц.}|{0} {ун.}|one={0} {унція};many={0} {унцій};other={0} {унції}",
+            ),
+            (
+                "unit/percent",
+                "{0}{%}||one={0} {відсоток};few={0} {відсотки};many={0} {відсотків};other={0} {відсотка}",
+            ),
+            (
+                "unit/petabyte",
+                "{0} {ПБ}||one={0} {петабайт};few={0} {петабайти};many={0} {петабайтів};other={0} {петабайта}",
+            ),
+            (
+                "unit/pound",
+                "one={0} {фунт};few={0} {фунти};many={0} {фунтів};other={0} {фунта}|{0} {фнт}|one={0} {фунт};few={0} {фунти};many={0} {фунтів};other={0} {фунта}",
+            ),
+            (
+                "unit/second",
+                "{0} {с}|{0}{с}|one={0} {секунда};many={0} {секунд};other={0} {секунди}",
+            ),
+            (
+                "unit/stone",
+                "{0} {стн}||one={0} {стоун};few={0} {стоуни};many={0} {стоунів};other={0} {стоуна}",
+            ),
+            (
+                "unit/terabit",
+                "{0} {Тб}||one={0} {терабіт};few={0} {терабіти};many={0} {терабітів};other={0} {терабіта}",
+            ),
+            (
+                "unit/terabyte",
+                "{0} {ТБ}||one={0} {терабайт};few={0} {терабайти};many={0} {терабайтів};other={0} {терабайта}",
+            ),
+            (
+                "unit/week",
+                "{0} {тиж.}|{0}{т}|one={0} {тиждень};few={0} {тижні};many={0} {тижнів};other={0} {тижня}",
+            ),
+            (
+                "unit/yard",
+                "one={0} {ярд};few={0} {ярди};many={0} {ярдів};other={0} {ярда}||one={0} {ярд};few={0} {ярди};many={0} {ярдів};other={0} {ярда}",
+            ),
+            (
+                "unit/year",
+                "{0} {р.}|{0}{р}|one={0} {рік};few={0} {роки};many={0} {років};other={0} {року}",
+            ),
+        ],
+    ),
+    (
+        "vi",
+        &[
+            ("accounting", "{-}{0}\u{a0}{}"),
+            ("compact-long/10", "9|{0} {tỷ}"),
+            ("compact-long/11", "9|{0} {tỷ}"),
+            ("compact-long/12", "12|{0} {nghìn tỷ}"),
+            ("compact-long/13", "12|{0} {nghìn tỷ}"),
+            ("compact-long/14", "12|{0} {nghìn tỷ}"),
+            ("compact-long/3", "3|{0} {nghìn}"),
+            ("compact-long/4", "3|{0} {nghìn}"),
+            ("compact-long/5", "3|{0} {nghìn}"),
+            ("compact-long/6", "6|{0} {triệu}"),
+            ("compact-long/7", "6|{0} {triệu}"),
+            ("compact-long/8", "6|{0} {triệu}"),
+            ("compact-long/9", "9|{0} {tỷ}"),
+            ("compact-short/10", "9|{0}\u{a0}{T}"),
+            ("compact-short/11", "9|{0}\u{a0}{T}"),
+            ("compact-short/12", "12|{0}\u{a0}{NT}"),
+            ("compact-short/13", "12|{0}\u{a0}{NT}"),
+            ("compact-short/14", "12|{0}\u{a0}{NT}"),
+            ("compact-short/3", "3|{0}\u{a0}{N}"),
+            ("compact-short/4", "3|{0}\u{a0}{N}"),
+            ("compact-short/5", "3|{0}\u{a0}{N}"),
+            ("compact-short/6", "6|{0}\u{a0}{Tr}"),
+            ("compact-short/7", "6|{0}\u{a0}{Tr}"),
+            ("compact-short/8", "6|{0}\u{a0}{Tr}"),
+            ("compact-short/9", "9|{0}\u{a0}{T}"),
+            ("currency", "{-}{0}\u{a0}{}"),
+            ("currency-name", "{0} {}"),
+            ("currency/AED", "AED|AED|Dirham UAE"),
+            ("currency/ARS", "ARS|$|Peso Argentina"),
+            ("currency/AUD", "AU$|$|Đô la Australia"),
+            ("currency/BRL", "R$|R$|Real Braxin"),
+            ("currency/CAD", "CA$|$|Đô la Canada"),
+            ("currency/CHF", "CHF|CHF|Franc Thụy sĩ"),
+            ("currency/CLP", "CLP|$|Peso Chile"),
+            ("currency/CNY", "CN¥|¥|Nhân dân tệ"),
+            ("currency/COP", "COP|$|Peso Colombia"),
+            ("currency/CZK", "CZK|Kč|Koruna Cộng hòa Séc"),
+            ("currency/DKK", "DKK|kr|Krone Đan Mạch"),
+            ("currency/EGP", "EGP|E£|Bảng Ai Cập"),
+            ("currency/EUR", "€|€|euro"),
+            ("currency/GBP", "£|£|Bảng Anh"),
+            ("currency/HKD", "HK$|$|Đô la Hồng Kông"),
+            ("currency/HUF", "HUF|Ft|forint Hungary"),
+            ("currency/IDR", "IDR|Rp|Rupiah Indonesia"),
+            ("currency/ILS", "₪|₪|Sheqel Israel mới"),
+            ("currency/INR", "₹|₹|Rupee Ấn Độ"),
+            ("currency/JPY", "¥|¥|Yên Nhật"),
+            ("currency/KRW", "₩|₩|Won Hàn Quốc"),
+            ("currency/MXN", "MX$|$|Peso Mexico"),
+            ("currency/MYR", "MYR|RM|Ringgit Malaysia"),
+            ("currency/NOK", "NOK|kr|Krone Na Uy"),
+            ("currency/NZD", "NZ$|$|Đô la New Zealand"),
+            ("currency/PHP", "PHP|₱|Peso Philipin"),
+            ("currency/PKR", "PKR|Rs|Rupee Pakistan"),
+            ("currency/PLN", "PLN|zł|Zloty Ba Lan"),
+            ("currency/RUB", "RUB|₽|Rúp Nga"),
+            ("currency/SAR", "SAR|SAR|Riyal Ả Rập Xê-út"),
+            ("currency/SEK", "SEK|kr|Krona Thụy Điển"),
+            ("currency/SGD", "SGD|$|Đô la Singapore"),
+            ("currency/THB", "฿|฿|Bạt Thái Lan"),
+            ("currency/TRY", "TRY|₺|Lia Thổ Nhĩ Kỳ"),
+            ("currency/TWD", "NT$|NT$|Đô la Đài Loan mới"),
+            ("currency/UAH", "UAH|₴|Hryvnia Ucraina"),
+            ("currency/USD", "US$|$|Đô la Mỹ"),
+            ("currency/VND", "₫|₫|Đồng Việt Nam"),
+            ("currency/ZAR", "ZAR|R|Rand Nam Phi"),
+            ("per", "{0}{/}{}|{0}{/}{}|{0}{/}{}"),
+            ("per-unit/centimeter", "{0}{/cm}||"),
+            ("per-unit/day", "{0}{/ngày}|{0}{/ngày}|{0}{/ngày}"),
+            ("per-unit/foot", "{0}{/ft}||"),
+            ("per-unit/gallon", "{0}{/gal Mỹ}|{0}{/gal}|{0}{/gal}"),
+            ("per-unit/gram", "{0}{/g}||{0}{/gam}"),
+            ("per-unit/hour", "{0}{/giờ}|{0}{/giờ}|{0}{/giờ}"),
+            ("per-unit/inch", "{0}{/in}||{0}{/inch}"),
+            ("per-unit/kilogram", "{0}{/kg}||"),
+            ("per-unit/kilometer", "{0}{/km}||"),
+            ("per-unit/liter", "{0}{/l}|{0}{/l}|{0}{/l}"),
+            ("per-unit/meter", "{0}{/m}||"),
+            ("per-unit/minute", "{0}{/phút}|{0}{/phút}|{0}{/phút}"),
+            ("per-unit/month", "{0}{/tháng}|{0}{/tháng}|{0}{/tháng}"),
+            ("per-unit/ounce", "{0}{/oz}||{0}{/aoxơ}"),
+            ("per-unit/pound", "{0}{/lb}||"),
+            ("per-unit/second", "{0}{/giây}|{0}{/giây}|{0}{/giây}"),
+            ("per-unit/week", "{0}{/tuần}|{0}{/tuần}|{0}{/tuần}"),
+            ("per-unit/year", "{0}{/năm}|{0}{/năm}|{0}{/năm}"),
+            ("percent", "{-}{0}{%}"),
+            ("unit/acre", "{0} {mẫu}||{0} {mẫu}"),
+            ("unit/bit", "{0} {bit}||{0} {bit}"),
+            ("unit/byte", "{0} {byte}|{0} {B}|{0} {byte}"),
+            ("unit/celsius", "{0}{°C}||{0} {độ C}"),
+            ("unit/centimeter", "{0} {cm}|{0}{cm}|{0} {xentimét}"),
+            ("unit/day", "{0} {ngày}||{0} {ngày}"),
+            ("unit/degree", "{0}{°}||{0} {độ}"),
+            ("unit/fahrenheit", "{0}{°F}||{0} {độ F}"),
+            ("unit/fluid-ounce", "{0} {fl oz}||{0} {aoxơ chất lỏng}"),
+            ("unit/foot", "{0} {ft}|{0}{'}|{0} {feet}"),
+            ("unit/gallon", "{0} {gal}||{0} {gallon}"),
+            ("unit/gigabit", "{0} {Gb}||{0} {gigabit}"),
+            ("unit/gigabyte", "{0} {GB}||{0} {gigabyte}"),
+            ("unit/gram", "{0} {g}|{0}{g}|{0} {gam}"),
+            ("unit/hectare", "{0} {ha}|{0}{ha}|{0} {héc-ta}"),
+            ("unit/hour", "{0} {giờ}||{0} {giờ}"),
+            ("unit/inch", "{0} {inch}|{0}{\"}|{0} {inch}"),
+            ("unit/kilobit", "{0} {kb}||{0} {kilobit}"),
+            ("unit/kilobyte", "{0} {kB}||{0} {kilobyte}"),
+            ("unit/kilogram", "{0} {kg}|{0}{kg}|{0} {kilôgam}"),
+            ("unit/kilometer", "{0} {km}|{0}{km}|{0} {kilômét}"),
+            ("unit/kilometer-per-hour", "{0} {km/h}|{0}{km/h}|{0} {kilômét/giờ}"),
+            ("unit/liter", "{0} {L}|{0}{L}|{0} {lít}"),
+            ("unit/liter-per-kilometer", "{0} {l/km}||{0} {lít/km}"),
+            ("unit/megabit", "{0} {Mb}||{0} {megabit}"),
+            ("unit/megabyte", "{0} {MB}||{0} {megabyte}"),
+            ("unit/meter", "{0} {m}|{0}{m}|{0} {mét}"),
+            ("unit/meter-per-second", "{0} {m/s}|{0}{m/s}|{0} {mét/giây}"),
+            ("unit/microsecond", "{0} {μs}||{0} {micrô giây}"),
+            ("unit/mile", "{0} {dặm}|{0}{mi}|{0} {dặm}"),
+            ("unit/mile-per-gallon", "{0} {mpg}||{0} {dặm/gallon}"),
+            ("unit/mile-per-hour", "{0} {mi/h}|{0}{mph}|{0} {dặm/giờ}"),
+            ("unit/mile-scandinavian", "{0} {smi}||{0} {dặm scandinavia}"),
+            ("unit/milliliter", "{0} {mL}||{0} {mililít}"),
+            ("unit/millimeter", "{0} {mm}|{0}{mm}|{0} {milimét}"),
+            ("unit/millisecond", "{0} {mili giây}|{0}{miligiây}|{0} {mili giây}"),
+            ("unit/minute", "{0} {phút}||{0} {phút}"),
+            ("unit/month", "{0} {tháng}||{0} {tháng}"),
+            ("unit/nanosecond", "{0} {nano giây}||{0} {nano giây}"),
+            ("unit/ounce", "{0} {oz}|{0}{oz}|{0} {aoxơ}"),
+            ("unit/percent", "{0}{%}||{0}{%}"),
+            ("unit/petabyte", "{0} {PB}||{0} {PB}"),
+            ("unit/pound", "{0} {lb}|{0}{lb}|{0} {pao}"),
+            ("unit/second", "{0} {giây}||{0} {giây}"),
+            ("unit/stone", "{0} {st}||{0} {st}"),
+            ("unit/terabit", "{0} {Tb}||{0} {terabit}"),
+            ("unit/terabyte", "{0} {TB}||{0} {terabyte}"),
+            ("unit/week", "{0} {tuần}||{0} {tuần}"),
+            ("unit/yard", "{0} {yd}|{0}{yd}|{0} {yard}"),
+            ("unit/year", "{0} {năm}||{0} {năm}"),
+        ],
+    ),
+    (
+        "zh",
+        &[
+            ("accounting", "({}{0})"),
+            ("compact-long/10", "8|{0}{亿}"),
+            ("compact-long/11", "8|{0}{亿}"),
+            ("compact-long/12", "12|{0}{万亿}"),
+            ("compact-long/13", "12|{0}{万亿}"),
+            ("compact-long/14", "12|{0}{万亿}"),
+            ("compact-long/4", "4|{0}{万}"),
+            ("compact-long/5", "4|{0}{万}"),
+            ("compact-long/6", "4|{0}{万}"),
+            ("compact-long/7", "4|{0}{万}"),
+            ("compact-long/8", "8|{0}{亿}"),
+            ("compact-long/9", "8|{0}{亿}"),
+            ("compact-short/10", "8|{0}{亿}"),
+            ("compact-short/11", "8|{0}{亿}"),
+            ("compact-short/12", "12|{0}{万亿}"),
+            ("compact-short/13", "12|{0}{万亿}"),
+            ("compact-short/14", "12|{0}{万亿}"),
+            ("compact-short/4", "4|{0}{万}"),
+            ("compact-short/5", "4|{0}{万}"),
+            ("compact-short/6", "4|{0}{万}"),
+            ("compact-short/7", "4|{0}{万}"),
+            ("compact-short/8", "8|{0}{亿}"),
+            ("compact-short/9", "8|{0}{亿}"),
+            ("currency", "{-}{}{0}"),
+            ("currency-name", "{0}{}"),
+            ("currency/AED", "AED|AED|阿联酋迪拉姆"),
+            ("currency/ARS", "ARS|$|阿根廷比索"),
+            ("currency/AUD", "AU$|$|澳大利亚元"),
+            ("currency/BRL", "R$|R$|巴西雷亚尔"),
+            ("currency/CAD", "CA$|$|加拿大元"),
+            ("currency/CHF", "CHF|CHF|瑞士法郎"),
+            ("currency/CLP", "CLP|$|智利比索"),
+            ("currency/CNY", "¥|¥|人民币"),
+            ("currency/COP", "COP|$|哥伦比亚比索"),
+            ("currency/CZK", "CZK|Kč|捷克克朗"),
+            ("currency/DKK", "DKK|kr|丹麦克朗"),
+            ("currency/EGP", "EGP|E£|埃及镑"),
+            ("currency/EUR", "€|€|欧元"),
+            ("currency/GBP", "£|£|英镑"),
+            ("currency/HKD", "HK$|$|港元"),
+            ("currency/HUF", "HUF|Ft|匈牙利福林"),
+            ("currency/IDR", "IDR|Rp|印度尼西亚盾"),
+            ("currency/ILS", "₪|₪|以色列新谢克尔"),
+            ("currency/INR", "₹|₹|印度卢比"),
+            ("currency/JPY", "JP¥|¥|日元"),
+            ("currency/KRW", "￦|₩|韩元"),
+            ("currency/MXN", "MX$|$|墨西哥比索"),
+            ("currency/MYR", "MYR|RM|马来西亚林吉特"),
+            ("currency/NOK", "NOK|kr|挪威克朗"),
+            ("currency/NZD", "NZ$|$|新西兰元"),
+            ("currency/PHP", "PHP|₱|菲律宾比索"),
+            ("currency/PKR", "PKR|Rs|巴基斯坦卢比"),
+            ("currency/PLN", "PLN|zł|波兰兹罗提"),
+            ("currency/RUB", "RUB|₽|俄罗斯卢布"),
+            ("currency/SAR", "SAR|SAR|沙特里亚尔"),
+            ("currency/SEK", "SEK|kr|瑞典克朗"),
+            ("currency/SGD", "SGD|$|新加坡元"),
+            ("currency/THB", "THB|฿|泰铢"),
+            ("currency/TRY", "TRY|₺|土耳其里拉"),
+            ("currency/TWD", "NT$|NT$|新台币"),
+            ("currency/UAH", "UAH|₴|乌克兰格里夫纳"),
+            ("currency/USD", "US$|$|美元"),
+            ("currency/VND", "₫|₫|越南盾"),
+            ("currency/ZAR", "ZAR|R|南非兰特"),
+            ("per", "{0}{/}{}|{0}{/}{}|{每}{}{0}"),
+            ("per-unit/centimeter", "{0}{/厘米}|{0}{/cm}|{0}{/厘米}"),
+            ("per-unit/day", "{0}{/天}|{0}{/天}|{0}{/天}"),
+            ("per-unit/foot", "{0}{/英尺}|{0}{/ft}|{0}{/英尺}"),
+            ("per-unit/gallon", "{0}{/加仑}|{0}{/gal}|{0}{/加仑}"),
+            ("per-unit/gram", "{0}{/g}||"),
+            ("per-unit/hour", "{0}{/小时}|{0}{/小时}|{0}{/小时}"),
+            ("per-unit/inch", "{0}{/英寸}|{0}{/in}|{0}{/英寸}"),
+            ("per-unit/kilogram", "{0}{/kg}||{每千克}{0}"),
+            ("per-unit/kilometer", "{0}{/公里}|{0}{/km}|{0}{/公里}"),
+            ("per-unit/liter", "{0}{/升}|{0}{/L}|{0}{/升}"),
+            ("per-unit/meter", "{0}{/米}|{0}{/m}|{0}{/米}"),
+            ("per-unit/minute", "{0}{/分钟}|{0}{/分钟}|{0}{/分钟}"),
+            ("per-unit/month", "{0}{/月}|{0}{/月}|{0}{/月}"),
+            ("per-unit/ounce", "{0}{/oz}||{每盎司}{0}"),
+            ("per-unit/pound", "{0}{/lb}||{每磅}{0}"),
+            ("per-unit/second", "{0}{/秒}|{0}{/秒}|{0}{/秒}"),
+            ("per-unit/week", "{0}{/周}|{0}{/周}|{0}{/周}"),
+            ("per-unit/year", "{0}{/年}|{0}{/年}|{0}{/年}"),
+            ("percent", "{-}{0}{%}"),
+            ("unit/acre", "{0}{英亩}|{0}{ac}|{0}{英亩}"),
+            ("unit/bit", "{0} {b}||{0}{比特}"),
+            ("unit/byte", "{0} {B}||{0}{字节}"),
+            ("unit/celsius", "{0}{°C}||{0}{摄氏度}"),
+            ("unit/centimeter", "{0}{厘米}||{0}{厘米}"),
+            ("unit/day", "{0}{天}||{0}{天}"),
+            ("unit/degree", "{0}{°}||{0}{度}"),
+            ("unit/fahrenheit", "{0}{°F}||{0}{华氏度}"),
+            ("unit/fluid-ounce", "{0}{液盎司}|{0}{fl oz}|{0}{液盎司}"),
+            ("unit/foot", "{0}{英尺}|{0}{′}|{0}{英尺}"),
+            ("unit/gallon", "{0}{加仑}|{0}{gal}|{0}{加仑}"),
+            ("unit/gigabit", "{0} {Gb}||{0}{吉比特}"),
+            ("unit/gigabyte", "{0} {GB}||{0}{吉字节}"),
+            ("unit/gram", "{0} {g}|{0}{g}|{0}{克}"),
+            ("unit/hectare", "{0}{公顷}|{0}{ha}|{0}{公顷}"),
+            ("unit/hour", "{0}{小时}||{0}{小时}"),
+            ("unit/inch", "{0}{英寸}|{0}{″}|{0}{英寸}"),
+            ("unit/kilobit", "{0} {kb}||{0}{千比特}"),
+            ("unit/kilobyte", "{0} {kB}||{0}{千字节}"),
+            ("unit/kilogram", "{0} {kg}|{0}{kg}|{0}{千克}"),
+            ("unit/kilometer", "{0}{公里}||{0}{公里}"),
+            ("unit/kilometer-per-hour", "{0} {km/h}|{0}{km/h}|每小时{0}{公里}"),
+            ("unit/liter", "{0}{升}||{0}{升}"),
+            ("unit/liter-per-kilometer", "{0} {L/km}|{0}{L/km}|每公里{0}{升}"),
+            ("unit/megabit", "{0} {Mb}||{0}{兆比特}"),
+            ("unit/megabyte", "{0} {MB}||{0}{兆字节}"),
+            ("unit/meter", "{0}{米}||{0}{米}"),
+            ("unit/meter-per-second", "{0} {m/s}|{0}{m/s}|每秒{0}{米}"),
+            ("unit/microsecond", "{0}{微秒}|{0}{μs}|{0}{微秒}"),
+            ("unit/mile", "{0}{英里}|{0}{mi}|{0}{英里}"),
+            ("unit/mile-per-gallon", "{0} {mpg US}|{0}{mpg}|每加仑{0}{英里}"),
+            ("unit/mile-per-hour", "{0} {mi/h}|{0}{mph}|每小时{0}{英里}"),
+            ("unit/mile-scandinavian", "{0}{斯堪的纳维亚英里}|{0}{smi}|{0}{斯堪的纳维亚英里}"),
+            ("unit/milliliter", "{0}{毫升}|{0}{mL}|{0}{毫升}"),
+            ("unit/millimeter", "{0}{毫米}||{0}{毫米}"),
+            ("unit/millisecond", "{0}{毫秒}|{0}{ms}|{0}{毫秒}"),
+            ("unit/minute", "{0}{分钟}||{0}{分钟}"),
+            ("unit/month", "{0}{个月}||{0}{个月}"),
+            ("unit/nanosecond", "{0}{纳秒}|{0}{ns}|{0}{纳秒}"),
+            ("unit/ounce", "{0} {oz}|{0}{oz}|{0}{盎司}"),
+            ("unit/percent", "{0}{%}||{0}{%}"),
+            ("unit/petabyte", "{0} {PB}||{0}{拍字节}"),
+            ("unit/pound", "{0} {lb}|{0}{#}|{0}{磅}"),
+            ("unit/second", "{0}{秒}||{0}{秒钟}"),
+            ("unit/stone", "{0} {st}|{0}{st}|{0}{英石}"),
+            ("unit/terabit", "{0} {Tb}||{0}{太比特}"),
+            ("unit/terabyte", "{0} {TB}||{0}{太字节}"),
+            ("unit/week", "{0}{周}||{0}{周}"),
+            ("unit/yard", "{0}{码}|{0}{yd}|{0}{码}"),
+            ("unit/year", "{0}{年}||{0}{年}"),
+        ],
+    ),
+    (
+        "zh-Hans-HK",
+        &[
+            ("currency-name", "{0} {}"),
+            ("currency/CNY", "CN¥|¥|人民币"),
+            ("per", "{0}{/}{}|{0}{/}{}|{0}{/}{}"),
+            ("per-unit/centimeter", "{0}{/厘米}|{0}{/cm}|"),
+            ("per-unit/day", "{0}{/天}|{0}{/天}|"),
+            ("per-unit/foot", "{0}{/英尺}|{0}{/ft}|"),
+            ("per-unit/gallon", "{0}{/加仑}|{0}{/gal}|"),
+            ("per-unit/hour", "{0}{/小时}|{0}{/小时}|"),
+            ("per-unit/inch", "{0}{/英寸}|{0}{/in}|"),
+            ("per-unit/kilometer", "{0}{/公里}|{0}{/km}|"),
+            ("per-unit/liter", "{0}{/升}|{0}{/L}|"),
+            ("per-unit/meter", "{0}{/米}|{0}{/m}|"),
+            ("per-unit/minute", "{0}{/分钟}|{0}{/分钟}|"),
+            ("per-unit/month", "{0}{/月}|{0}{/月}|"),
+            ("per-unit/week", "{0}{/周}|{0}{/周}|"),
+            ("per-unit/year", "{0}{/年}|{0}{/年}|"),
+            ("unit/second", "{0}{秒}||{0}{秒}"),
+        ],
+    ),
+    (
+        "zh-Hans-MO",
+        &[
+            ("currency-name", "{0} {}"),
+            ("currency/CNY", "CN¥|¥|人民币"),
+            ("per", "{0}{/}{}|{0}{/}{}|{0}{/}{}"),
+            ("per-unit/centimeter", "{0}{/厘米}|{0}{/cm}|"),
+            ("per-unit/day", "{0}{/天}|{0}{/天}|"),
+            ("per-unit/foot", "{0}{/英尺}|{0}{/ft}|"),
+            ("per-unit/gallon", "{0}{/加仑}|{0}{/gal}|"),
+            ("per-unit/hour", "{0}{/小时}|{0}{/小时}|"),
+            ("per-unit/inch", "{0}{/英寸}|{0}{/in}|"),
+            ("per-unit/kilometer", "{0}{/公里}|{0}{/km}|"),
+            ("per-unit/liter", "{0}{/升}|{0}{/L}|"),
+            ("per-unit/meter", "{0}{/米}|{0}{/m}|"),
+            ("per-unit/minute", "{0}{/分钟}|{0}{/分钟}|"),
+            ("per-unit/month", "{0}{/月}|{0}{/月}|"),
+            ("per-unit/week", "{0}{/周}|{0}{/周}|"),
+            ("per-unit/year", "{0}{/年}|{0}{/年}|"),
+            ("unit/second", "{0}{秒}||{0}{秒}"),
+        ],
+    ),
+    (
+        "zh-Hans-SG",
+        &[
+            ("currency-name", "{0} {}"),
+            ("currency/CNY", "CN¥|¥|人民币"),
+            ("currency/SGD", "$|$|新加坡元"),
+            ("per", "{0}{/}{}|{0}{/}{}|{0}{/}{}"),
+            ("per-unit/centimeter", "{0}{/厘米}|{0}{/cm}|"),
+            ("per-unit/day", "{0}{/天}|{0}{/天}|"),
+            ("per-unit/foot", "{0}{/英尺}|{0}{/ft}|"),
+            ("per-unit/gallon", "{0}{/加仑}|{0}{/gal}|"),
+            ("per-unit/hour", "{0}{/小时}|{0}{/小时}|"),
+            ("per-unit/inch", "{0}{/英寸}|{0}{/in}|"),
+            ("per-unit/kilometer", "{0}{/公里}|{0}{/km}|"),
+            ("per-unit/liter", "{0}{/升}|{0}{/L}|"),
+            ("per-unit/meter", "{0}{/米}|{0}{/m}|"),
+            ("per-unit/minute", "{0}{/分钟}|{0}{/分钟}|"),
+            ("per-unit/month", "{0}{/月}|{0}{/月}|"),
+            ("per-unit/week", "{0}{/周}|{0}{/周}|"),
+            ("per-unit/year", "{0}{/年}|{0}{/年}|"),
+            ("unit/second", "{0}{秒}||{0}{秒}"),
+        ],
+    ),
+    (
+        "zh-Hant",
+        &[
+            ("accounting", "({}{0})"),
+            ("compact-long/10", "8|{0}{億}"),
+            ("compact-long/11", "8|{0}{億}"),
+            ("compact-long/12", "12|{0}{兆}"),
+            ("compact-long/13", "12|{0}{兆}"),
+            ("compact-long/14", "12|{0}{兆}"),
+            ("compact-long/4", "4|{0}{萬}"),
+            ("compact-long/5", "4|{0}{萬}"),
+            ("compact-long/6", "4|{0}{萬}"),
+            ("compact-long/7", "4|{0}{萬}"),
+            ("compact-long/8", "8|{0}{億}"),
+            ("compact-long/9", "8|{0}{億}"),
+            ("compact-short/10", "8|{0}{億}"),
+            ("compact-short/11", "8|{0}{億}"),
+            ("compact-short/12", "12|{0}{兆}"),
+            ("compact-short/13", "12|{0}{兆}"),
+            ("compact-short/14", "12|{0}{兆}"),
+            ("compact-short/4", "4|{0}{萬}"),
+            ("compact-short/5", "4|{0}{萬}"),
+            ("compact-short/6", "4|{0}{萬}"),
+            ("compact-short/7", "4|{0}{萬}"),
+            ("compact-short/8", "8|{0}{億}"),
+            ("compact-short/9", "8|{0}{億}"),
+            ("currency", "{-}{}{0}"),
+            ("currency-name", "{0} {}"),
+            ("currency/AED", "AED|AED|阿拉伯聯合大公國迪爾汗"),
+            ("currency/ARS", "ARS|$|阿根廷披索"),
+            ("currency/AUD", "AU$|$|澳幣"),
+            ("currency/BRL", "R$|R$|巴西雷亞爾"),
+            ("currency/CAD", "CA$|$|加幣"),
+            ("currency/CHF", "CHF|CHF|瑞士法郎"),
+            ("currency/CLP", "CLP|$|智利披索"),
+            ("currency/CNY", "CN¥|¥|人民幣"),
+            ("currency/COP", "COP|$|哥倫比亞披索"),
+            ("currency/CZK", "CZK|Kč|捷克克朗"),
+            ("currency/DKK", "DKK|kr|丹麥克朗"),
+            ("currency/EGP", "EGP|E£|埃及鎊"),
+            ("currency/EUR", "€|€|歐元"),
+            ("currency/GBP", "£|£|英鎊"),
+            ("currency/HKD", "HK$|$|港幣"),
+            ("currency/HUF", "HUF|Ft|匈牙利福林"),
+            ("currency/IDR", "IDR|Rp|印尼盾"),
+            ("currency/ILS", "₪|₪|以色列新謝克爾"),
+            ("currency/INR", "₹|₹|印度盧比"),
+            ("currency/JPY", "¥|¥|日圓"),
+            ("currency/KRW", "￦|₩|韓元"),
+            ("currency/MXN", "MX$|$|墨西哥披索"),
+            ("currency/MYR", "MYR|RM|馬來西亞令吉"),
+            ("currency/NOK", "NOK|kr|挪威克朗"),
+            ("currency/NZD", "NZ$|$|紐西蘭幣"),
+            ("currency/PHP", "PHP|₱|菲律賓披索"),
+            ("currency/PKR", "PKR|Rs|巴基斯坦盧比"),
+            ("currency/PLN", "PLN|zł|波蘭茲羅提"),
+            ("currency/RUB", "RUB|₽|俄羅斯盧布"),
+            ("currency/SAR", "SAR|SAR|沙烏地里亞爾"),
+            ("currency/SEK", "SEK|kr|瑞典克朗"),
+            ("currency/SGD", "SGD|$|新加坡幣"),
+            ("currency/THB", "THB|฿|泰銖"),
+            ("currency/TRY", "TRY|₺|新土耳其里拉"),
+            ("currency/TWD", "$|$|新台幣"),
+            ("currency/UAH", "UAH|₴|烏克蘭格里夫納"),
+            ("currency/USD", "US$|$|美元"),
+            ("currency/VND", "₫|₫|越南盾"),
+            ("currency/ZAR", "ZAR|R|南非蘭特"),
+            ("per", "{0}{/}{}|{0}{/}{}|{每}{}{ }{0}"),
+            ("per-unit/centimeter", "{0}{/公分}|{0}{/公分}|{每公分 }{0}"),
+            ("per-unit/day", "{0}{/天}|{0}{/天}|{每天 }{0}"),
+            ("per-unit/foot", "{0}{/呎}|{0}{/呎}|{每英尺 }{0}"),
+            ("per-unit/gallon", "{0}{/加侖}|{0}{/gal}|{每加侖 }{0}"),
+            ("per-unit/gram", "{0}{/克}|{0}{/克}|{每克 }{0}"),
+            ("per-unit/hour", "{0}{/小時}|{0}{/小時}|{每小時 }{0}"),
+            ("per-unit/inch", "{0}{/吋}|{0}{/吋}|{每英寸 }{0}"),
+            ("per-unit/kilogram", "{0}{/公斤}|{0}{/公斤}|{每公斤 }{0}"),
+            ("per-unit/kilometer", "{0}{/公里}|{0}{/公里}|{每公里 }{0}"),
+            ("per-unit/liter", "{0}{/升}|{0}{/L}|{每公升 }{0}"),
+            ("per-unit/meter", "{0}{/公尺}|{0}{/公尺}|{每公尺 }{0}"),
+            ("per-unit/minute", "{0}{/分鐘}|{0}{/分鐘}|{每分鐘 }{0}"),
+            ("per-unit/month", "{0}{/月}|{0}{/月}|{每月 }{0}"),
+            ("per-unit/ounce", "{0}{/盎司}|{0}{/盎司}|{每盎司 }{0}"),
+            ("per-unit/pound", "{0}{/磅}|{0}{/磅}|{每磅 }{0}"),
+            ("per-unit/second", "{0}{/秒}|{0}{/秒}|{每秒 }{0}"),
+            ("per-unit/week", "{0}{/週}|{0}{/週}|{每週 }{0}"),
+            ("per-unit/year", "{0}{/年}|{0}{/年}|{每年 }{0}"),
+            ("percent", "{-}{0}{%}"),
+            ("unit/acre", "{0} {英畝}|{0}{英畝}|{0} {英畝}"),
+            ("unit/bit", "{0} {bit}|{0}{bit}|{0} {bit}"),
+            ("unit/byte", "{0} {byte}|{0}{byte}|{0} {byte}"),
+            ("unit/celsius", "{0}{°C}||攝氏 {0} {度}"),
+            ("unit/centimeter", "{0} {公分}|{0}{公分}|{0} {公分}"),
+            ("unit/day", "{0} {天}||{0} {天}"),
+            ("unit/degree", "{0} {度}|{0}{°}|{0} {度}"),
+            ("unit/fahrenheit", "{0}{°F}||華氏 {0} {度}"),
+            ("unit/fluid-ounce", "{0} {液盎司}|{0}{fl-oz}|{0} {液盎司}"),
+            ("unit/foot", "{0} {呎}|{0}{呎}|{0} {英尺}"),
+            ("unit/gallon", "{0} {加侖}|{0}{gal}|{0} {加侖}"),
+            ("unit/gigabit", "{0} {Gb}|{0}{Gb}|{0} {Gb}"),
+            ("unit/gigabyte", "{0} {GB}|{0}{GB}|{0} {GB}"),
+            ("unit/gram", "{0} {克}|{0}{克}|{0} {克}"),
+            ("unit/hectare", "{0} {公頃}|{0}{公頃}|{0} {公頃}"),
+            ("unit/hour", "{0} {小時}||{0} {小時}"),
+            ("unit/inch", "{0} {吋}|{0}{吋}|{0} {英寸}"),
+            ("unit/kilobit", "{0} {kb}|{0}{kb}|{0} {kb}"),
+            ("unit/kilobyte", "{0} {kB}|{0}{kB}|{0} {kB}"),
+            ("unit/kilogram", "{0} {公斤}||{0} {公斤}"),
+            ("unit/kilometer", "{0} {公里}|{0}{公里}|{0} {公里}"),
+            ("unit/kilometer-per-hour", "{0} {公里/小時}|{0}{公里/小時}|每小時 {0} {公里}"),
+            ("unit/liter", "{0} {升}|{0}{升}|{0} {公升}"),
+            ("unit/liter-per-kilometer", "{0} {升/公里}|{0}{L/km}|每公里 {0} {公升}"),
+            ("unit/megabit", "{0} {Mb}|{0}{Mb}|{0} {Mb}"),
+            ("unit/megabyte", "{0} {MB}|{0}{MB}|{0} {MB}"),
+            ("unit/meter", "{0} {公尺}|{0}{公尺}|{0} {公尺}"),
+            ("unit/meter-per-second", "{0} {公尺/秒}|{0}{公尺/秒}|每秒 {0} {公尺}"),
+            ("unit/microsecond", "{0} {微秒}|{0}{μs}|{0} {微秒}"),
+            ("unit/mile", "{0} {英里}|{0}{英里}|{0} {英里}"),
+            ("unit/mile-per-gallon", "{0} {英里/加侖}|{0}{mpg}|每加侖 {0} {英里}"),
+            ("unit/mile-per-hour", "{0} {英里/小時}|{0}{英里/小時}|每小時 {0} {英里}"),
+            ("unit/mile-scandinavian", "{0} {斯堪地那維亞英里}|{0}{smi}|{0} {斯堪地那維亞英里}"),
+            ("unit/milliliter", "{0} {毫升}|{0}{mL}|{0} {毫升}"),
+            ("unit/millimeter", "{0} {公釐}|{0}{公釐}|{0} {公釐}"),
+            ("unit/millisecond", "{0} {毫秒}||{0} {毫秒}"),
+            ("unit/minute", "{0} {分鐘}||{0} {分鐘}"),
+            ("unit/month", "{0} {個月}||{0} {個月}"),
+            ("unit/nanosecond", "{0} {奈秒}|{0}{ns}|{0} {奈秒}"),
+            ("unit/ounce", "{0} {盎司}|{0}{盎司}|{0} {盎司}"),
+            ("unit/percent", "{0}{%}||{0}{%}"),
+            ("unit/petabyte", "{0} {PB}|{0}{PB}|{0} {PB}"),
+            ("unit/pound", "{0} {磅}|{0}{磅}|{0} {磅}"),
+            ("unit/second", "{0} {秒}||{0} {秒}"),
+            ("unit/stone", "{0} {英石}|{0}{st}|{0} {英石}"),
+            ("unit/terabit", "{0} {Tb}|{0}{Tb}|{0} {Tb}"),
+            ("unit/terabyte", "{0} {TB}|{0}{TB}|{0} {TB}"),
+            ("unit/week", "{0} {週}||{0} {週}"),
+            ("unit/yard", "{0} {碼}|{0}{碼}|{0} {碼}"),
+            ("unit/year", "{0} {年}||{0} {年}"),
+        ],
+    ),
+    (
+        "zh-Hant-HK",
+        &[
+            ("compact-short/10", "9|{0}{B}"),
+            ("compact-short/11", "9|{0}{B}"),
+            ("compact-short/12", "12|{0}{T}"),
+            ("compact-short/13", "12|{0}{T}"),
+            ("compact-short/14", "12|{0}{T}"),
+            ("compact-short/3", "3|{0}{K}"),
+            ("compact-short/4", "3|{0}{K}"),
+            ("compact-short/5", "3|{0}{K}"),
+            ("compact-short/6", "6|{0}{M}"),
+            ("compact-short/7", "6|{0}{M}"),
+            ("compact-short/8", "6|{0}{M}"),
+            ("compact-short/9", "9|{0}{B}"),
+            ("currency/AED", "AED|AED|阿拉伯聯合酋長國迪爾汗"),
+            ("currency/AUD", "AU$|$|澳元"),
+            ("currency/CAD", "CA$|$|加拿大元"),
+            ("currency/HKD", "HK$|$|港元"),
+            ("currency/KRW", "₩|₩|韓元"),
+            ("currency/NZD", "NZ$|$|紐西蘭元"),
+            ("currency/SAR", "SAR|SAR|沙特阿拉伯里亞爾"),
+            ("currency/SGD", "SGD|$|新加坡元"),
+            ("currency/TWD", "NT$|$|新台幣"),
+            ("per", "{0}{/}{}|{0}{/}{}|{0}{每}{}"),
+            ("per-unit/centimeter", "{0}{ 每厘米}|{0}{ 每厘米}|{0}{ 每厘米}"),
+            ("per-unit/day", "{0}{ 每日}|{0}{ 每日}|{0}{ 每日}"),
+            ("per-unit/foot", "{0}{ 每呎}|{0}{ 每呎}|{0}{ 每呎}"),
+            ("per-unit/gallon", "{0}{ 每加侖}|{0}{ 每加侖}|{0}{ 每加侖}"),
+            ("per-unit/gram", "{0}{ 每克}|{0}{ 每克}|{0}{ 每克}"),
+            ("per-unit/hour", "{0}{ 每小時}|{0}{ 每小時}|{0}{ 每小時}"),
+            ("per-unit/inch", "{0}{ 每吋}|{0}{ 每吋}|{0}{ 每吋}"),
+            ("per-unit/kilogram", "{0}{ 每公斤}|{0}{ 每公斤}|{0}{ 每公斤}"),
+            ("per-unit/kilometer", "{0}{ 每公里}|{0}{ 每公里}|{0}{ 每公里}"),
+            ("per-unit/liter", "{0}{ 每升}|{0}{ 每升}|{0}{ 每公升}"),
+            ("per-unit/meter", "{0}{ 每米}|{0}{ 每米}|{0}{ 每米}"),
+            ("per-unit/minute", "{0}{ 每分鐘}|{0}{ 每分鐘}|{0}{ 每分鐘}"),
+            ("per-unit/month", "{0}{ 每月}|{0}{ 每月}|{0}{ 每個月}"),
+            ("per-unit/ounce", "{0}{ 每安士}|{0}{ 每安士}|{0}{ 每安士}"),
+            ("per-unit/pound", "{0}{ 每磅}|{0}{ 每磅}|{0}{ 每磅}"),
+            ("per-unit/second", "{0}{每秒}|{0}{/秒}|{0}{每秒}"),
+            ("per-unit/week", "{0}{ 每週}|{0}{ 每星期}|{0}{ 每星期}"),
+            ("per-unit/year", "{0}{ 每年}|{0}{ 每年}|{0}{ 每年}"),
+            ("unit/bit", "{0} {bit}|{0}{bit}|{0} {個位元}"),
+            ("unit/byte", "{0} {byte}|{0}{byte}|{0} {位元組}"),
+            ("unit/centimeter", "{0} {厘米}|{0}{厘米}|{0} {厘米}"),
+            ("unit/day", "{0} {日}|{0}{日}|{0} {日}"),
+            ("unit/fluid-ounce", "{0} {液安士}|{0}{fl-oz}|{0} {液安士}"),
+            ("unit/foot", "{0} {呎}|{0}{呎}|{0} {呎}"),
+            ("unit/hour", "{0} {小時}|{0}{小時}|{0} {小時}"),
+            ("unit/inch", "{0} {吋}|{0}{吋}|{0} {吋}"),
+            ("unit/kilometer-per-hour", "{0} {公里每小時}|{0}{kph}|每小時 {0} {公里}"),
+            ("unit/liter-per-kilometer", "{0} {升每公里}|{0}{L/km}|{0} {公升/公里}"),
+            ("unit/meter", "{0} {米}|{0}{米}|{0} {米}"),
+            ("unit/meter-per-second", "{0} {米/秒}|{0}{m/s}|{0} {米/秒}"),
+            ("unit/mile", "{0} {哩}|{0}{哩}|{0} {英里}"),
+            ("unit/mile-per-gallon", "{0} {英里每加侖}|{0}{mpg}|{0} {英里/加侖}"),
+            ("unit/mile-per-hour", "{0} {英里每小時}|{0}{mph}|每小時 {0} {英里}"),
+            ("unit/millimeter", "{0} {毫米}|{0}{毫米}|{0} {毫米}"),
+            ("unit/millisecond", "{0} {毫秒}|{0}{毫秒}|{0} {毫秒}"),
+            ("unit/minute", "{0} {分鐘}|{0}{分}|{0} {分鐘}"),
+            ("unit/month", "{0} {個月}|{0}{個月}|{0} {個月}"),
+            ("unit/nanosecond", "{0} {納秒}|{0}{ns}|{0} {納秒}"),
+            ("unit/ounce", "{0} {安士}||{0} {安士}"),
+            ("unit/second", "{0} {秒}|{0}{秒}|{0} {秒}"),
+            ("unit/week", "{0} {星期}|{0}{週}|{0} {星期}"),
+            ("unit/year", "{0} {年}|{0}{年}|{0} {年}"),
+        ],
+    ),
+]);
@@ -0,0 +1,1738 @@
+//! The CLDR data used by `Intl.RelativeTimeFormat`.
+//! Version: CLDR 42.0
+//!
+//! This file is generated by `boa_engine/intl_data/generate.py`. Please do not modify it directly.
+
+use super::LocaleData;
+
+pub(in crate::builtins::intl) static RELATIVE_TIME_FORMAT: LocaleData = LocaleData::new(&[
+    (
+        "de",
+        &[
+            ("day", "one=in {0} Tag;other=in {0} Tagen|one=vor {0} Tag;other=vor {0} Tagen"),
+            ("day-narrow", "one=in {0} Tag;other=in {0} Tagen|one=vor {0} Tag;other=vor {0} Tagen"),
+            ("day-narrow/relative", "-2=vorgestern;-1=gestern;0=heute;1=morgen;2=übermorgen"),
+            ("day-short", "one=in {0} Tag;other=in {0} Tagen|one=vor {0} Tag;other=vor {0} Tagen"),
+            ("day-short/relative", "-2=vorgestern;-1=gestern;0=heute;1=morgen;2=übermorgen"),
+            ("day/relative", "-2=vorgestern;-1=gestern;0=heute;1=morgen;2=übermorgen"),
+            (
+                "hour",
+                "one=in {0} Stunde;other=in {0} Stunden|one=vor {0} Stunde;other=vor {0} Stunden",
+            ),
+            ("hour-narrow", "in {0} Std.|vor {0} Std."),
+            ("hour-narrow/relative", "0=in dieser Stunde"),
+            ("hour-short", "in {0} Std.|vor {0} Std."),
+            ("hour-short/relative", "0=in dieser Stunde"),
+            ("hour/relative", "0=in dieser Stunde"),
+            (
+                "minute",
+                "one=in {0} Minute;other=in {0} Minuten|one=vor {0} Minute;other=vor {0} Minuten",
+            ),
+            ("minute-narrow", "in {0} m|vor {0} m"),
+            ("minute-narrow/relative", "0=in dieser Minute"),
+            ("minute-short", "in {0} Min.|vor {0} Min."),
+            ("minute-short/relative", "0=in dieser Minute"),
+            ("minute/relative", "0=in dieser Minute"),
+            (
+                "month",
+                "one=in {0} Monat;other=in {0} Monaten|one=vor {0} Monat;other=vor {0} Monaten",
+            ),
+            (
+                "month-narrow",
+                "one=in {0} Monat;other=in {0} Monaten|one=vor {0}\u{a0}Monat;other=vor {0} Monaten",
+            ),
+            ("month-narrow/relative", "-1=letzten Monat;0=diesen Monat;1=nächsten Monat"),
+            (
+                "month-short",
+                "one=in {0} Monat;other=in {0} Monaten|one=vor {0} Monat;other=vor {0}\u{a0}Monaten",
+            ),
+            ("month-short/relative", "-1=letzten Monat;0=diesen Monat;1=nächsten Monat"),
+            ("month/relative", "-1=letzten Monat;0=diesen Monat;1=nächsten Monat"),
+            (
+                "quarter",
+                "one=in {0} Quartal;other=in {0} Quartalen|one=vor {0} Quartal;other=vor {0} Quartalen",
+            ),
+            ("quarter-narrow", "in {0} Q|vor {0} Q"),
+            ("quarter-narrow/relative", "-1=letztes Quartal;0=dieses Quartal;1=nächstes Quartal"),
+            ("quarter-short", "in {0} Quart.|vor {0} Quart."),
+            ("quarter-short/relative", "-1=letztes Quartal;0=dieses Quartal;1=nächstes Quartal"),
+            ("quarter/relative", "-1=letztes Quartal;0=dieses Quartal;1=nächstes Quartal"),
+            (
+                "second",
+                "one=in {0} Sekunde;other=in {0} Sekunden|one=vor {0} Sekunde;other=vor {0} Sekunden",
+            ),
+            ("second-narrow", "in {0} s|vor {0} s"),
+            ("second-narrow/relative", "0=jetzt"),
+            ("second-short", "in {0} Sek.|vor {0} Sek."),
+            ("second-short/relative", "0=jetzt"),
+            ("second/relative", "0=jetzt"),
+            ("week", "one=in {0} Woche;other=in {0} Wochen|one=vor {0} Woche;other=vor {0} Wochen"),
+            ("week-narrow", "in {0} Wo.|vor {0} Wo."),
+            ("week-narrow/relative", "-1=letzte Woche;0=diese Woche;1=nächste Woche"),
+            (
+                "week-short",
+                "one=in {0} Woche;other=in {0} Wochen|one=vor {0} Woche;other=vor {0} Wochen",
+            ),
+            ("week-short/relative", "-1=letzte Woche;0=diese Woche;1=nächste Woche"),
+            ("week/relative", "-1=letzte Woche;0=diese Woche;1=nächste Woche"),
+            ("year", "one=in {0} Jahr;other=in {0} Jahren|one=vor {0} Jahr;other=vor {0} Jahren"),
+            (
+                "year-narrow",
+                "one=in {0} Jahr;other=in {0} Jahren|one=vor {0} Jahr;other=vor {0} Jahren",
+            ),
+            ("year-narrow/relative", "-1=letztes Jahr;0=dieses Jahr;1=nächstes Jahr"),
+            (
+                "year-short",
+                "one=in {0} Jahr;other=in {0} Jahren|one=vor {0} Jahr;other=vor {0} Jahren",
+            ),
+            ("year-short/relative", "-1=letztes Jahr;0=dieses Jahr;1=nächstes Jahr"),
+            ("year/relative", "-1=letztes Jahr;0=dieses Jahr;1=nächstes Jahr"),
+        ],
+    ),
+    (
+        "en",
+        &[
+            ("day", "one=in {0} day;other=in {0} days|one={0} day ago;other={0} days ago"),
+            ("day-narrow", "in {0}d|{0}d ago"),
+            ("day-narrow/relative", "-1=yesterday;0=today;1=tomorrow"),
+            ("day-short", "one=in {0} day;other=in {0} days|one={0} day ago;other={0} days ago"),
+            ("day-short/relative", "-1=yesterday;0=today;1=tomorrow"),
+            ("day/relative", "-1=yesterday;0=today;1=tomorrow"),
+            ("hour", "one=in {0} hour;other=in {0} hours|one={0} hour ago;other={0} hours ago"),
+            ("hour-narrow", "in {0}h|{0}h ago"),
+            ("hour-narrow/relative", "0=this hour"),
+            ("hour-short", "in {0} hr.|{0} hr. ago"),
+            ("hour-short/relative", "0=this hour"),
+            ("hour/relative", "0=this hour"),
+            (
+                "minute",
+                "one=in {0} minute;other=in {0} minutes|one={0} minute ago;other={0} minutes ago",
+            ),
+            ("minute-narrow", "in {0}m|{0}m ago"),
+            ("minute-narrow/relative", "0=this minute"),
+            ("minute-short", "in {0} min.|{0} min. ago"),
+            ("minute-short/relative", "0=this minute"),
+            ("minute/relative", "0=this minute"),
+            (
+                "month",
+                "one=in {0} month;other=in {0} months|one={0} month ago;other={0} months ago",
+            ),
+            ("month-narrow", "in {0}mo|{0}mo ago"),
+            ("month-narrow/relative", "-1=last mo.;0=this mo.;1=next mo."),
+            ("month-short", "in {0} mo.|{0} mo. ago"),
+            ("month-short/relative", "-1=last mo.;0=this mo.;1=next mo."),
+            ("month/relative", "-1=last month;0=this month;1=next month"),
+            (
+                "quarter",
+                "one=in {0} quarter;other=in {0} quarters|one={0} quarter ago;other={0} quarters ago",
+            ),
+            ("quarter-narrow", "in {0}q|{0}q ago"),
+            ("quarter-narrow/relative", "-1=last qtr.;0=this qtr.;1=next qtr."),
+            (
+                "quarter-short",
+                "one=in {0} qtr.;other=in {0} qtrs.|one={0} qtr. ago;other={0} qtrs. ago",
+            ),
+            ("quarter-short/relative", "-1=last qtr.;0=this qtr.;1=next qtr."),
+            ("quarter/relative", "-1=last quarter;0=this quarter;1=next quarter"),
+            (
+                "second",
+                "one=in {0} second;other=in {0} seconds|one={0} second ago;other={0} seconds ago",
+            ),
+            ("second-narrow", "in {0}s|{0}s ago"),
+            ("second-narrow/relative", "0=now"),
+            ("second-short", "in {0} sec.|{0} sec. ago"),
+            ("second-short/relative", "0=now"),
+            ("second/relative", "0=now"),
+            ("week", "one=in {0} week;other=in {0} weeks|one={0} week ago;other={0} weeks ago"),
+            ("week-narrow", "in {0}w|{0}w ago"),
+            ("week-narrow/relative", "-1=last wk.;0=this wk.;1=next wk."),
+            ("week-short", "in {0} wk.|{0} wk. ago"),
+            ("week-short/relative", "-1=last wk.;0=this wk.;1=next wk."),
+            ("week/relative", "-1=last week;0=this week;1=next week"),
+            ("year", "one=in {0} year;other=in {0} years|one={0} year ago;other={0} years ago"),
+            ("year-narrow", "in {0}y|{0}y ago"),
+            ("year-narrow/relative", "-1=last yr.;0=this yr.;1=next yr."),
+            ("year-short", "in {0} yr.|{0} yr. ago"),
+            ("year-short/relative", "-1=last yr.;0=this yr.;1=next yr."),
+            ("year/relative", "-1=last year;0=this year;1=next year"),
+        ],
+    ),
+    (
+        "en-001",
+        &[
+            ("day-narrow", "one=in {0} day;other=in {0} days|one={0} day ago;other={0} days ago"),
+            ("hour-narrow", "in {0} hr|{0} hr ago"),
+            ("hour-short", "in {0} hr|{0} hr ago"),
+            ("minute-narrow", "in {0} min|{0} min ago"),
+            ("minute-short", "in {0} min|{0} min ago"),
+            ("month-narrow", "in {0} mo|{0} mo ago"),
+            ("month-narrow/relative", "-1=last mo;0=this mo;1=next mo"),
+            ("month-short", "in {0} mo|{0} mo ago"),
+            ("month-short/relative", "-1=last mo;0=this mo;1=next mo"),
+            ("quarter-narrow", "in {0} qtr|{0} qtr ago"),
+            ("quarter-short", "in {0} qtr|{0} qtr ago"),
+            ("second-narrow", "in {0} sec|{0} sec ago"),
+            ("second-short", "in {0} sec|{0} sec ago"),
+            ("week-narrow", "in {0} wk|{0} wk ago"),
+            ("week-narrow/relative", "-1=last wk;0=this wk;1=next wk"),
+            ("week-short", "in {0} wk|{0} wk ago"),
+            ("week-short/relative", "-1=last wk;0=this wk;1=next wk"),
+            ("year-narrow", "in {0} yr|{0} yr ago"),
+            ("year-narrow/relative", "-1=last yr;0=this yr;1=next yr"),
+            ("year-short", "in {0} yr|{0} yr ago"),
+            ("year-short/relative", "-1=last yr;0=this yr;1=next yr"),
+        ],
+    ),
+    (
+        "en-AU",
+        &[
+            ("hour-narrow", "one=in {0} hr;other=in {0} hrs|one={0} hr ago;other={0} hrs ago"),
+            ("hour-short", "one=in {0} hr;other=in {0} hrs|one={0} hr ago;other={0} hrs ago"),
+            (
+                "minute-narrow",
+                "one=in {0} min.;other=in {0} mins|one={0} min. ago;other={0} mins ago",
+            ),
+            (
+                "minute-short",
+                "one=in {0} min.;other=in {0} mins|one={0} min. ago;other={0} mins ago",
+            ),
+            ("month-narrow", "in {0} mo.|{0} mo. ago"),
+            ("month-short", "in {0} mo.|{0} mo. ago"),
+            (
+                "quarter-narrow",
+                "one=in {0} qtr;other=in {0} qtrs|one={0} qtr ago;other={0} qtrs ago",
+            ),
+            (
+                "quarter-short",
+                "one=in {0} qtr;other=in {0} qtrs|one={0} qtr ago;other={0} qtrs ago",
+            ),
+            (
+                "second-narrow",
+                "one=in {0} sec.;other=in {0} secs|one={0} sec. ago;other={0} secs ago",
+            ),
+            (
+                "second-short",
+                "one=in {0} sec.;other=in {0} secs|one={0} sec. ago;other={0} secs ago",
+            ),
+            ("week-narrow", "one=in {0} wk;other=in {0} wks|one={0} wk ago;other={0} wks ago"),
+            ("week-short", "one=in {0} wk;other=in {0} wks|one={0} wk ago;other={0} wks ago"),
+            ("year-narrow", "one=in {0} yr;other=in {0} yrs|one={0} yr ago;other={0} yrs ago"),
+            ("year-short", "one=in {0} yr;other=in {0} yrs|one={0} yr ago;other={0} yrs ago"),
+        ],
+    ),
+    (
+        "en-CA",
+        &[
+            ("hour-narrow", "one=in {0} hr;other=in {0} hrs|one={0} hr ago;other={0} hrs ago"),
+            ("hour-short", "one=in {0} hr;other=in {0} hrs|one={0} hr ago;other={0} hrs ago"),
+            (
+                "minute-narrow",
+                "one=in {0} min;other=in {0} mins|one={0} min ago;other={0} mins ago",
+            ),
+            ("minute-short", "one=in {0} min;other=in {0} mins|one={0} min ago;other={0} mins ago"),
+            ("month-narrow", "one=in {0} mo;other=in {0} mos|one={0} mo ago;other={0} mos ago"),
+            ("month-narrow/relative", "-1=last mo;0=this mo;1=next mo"),
+            ("month-short", "one=in {0} mo;other=in {0} mos|one={0} mo ago;other={0} mos ago"),
+            ("month-short/relative", "-1=last mo;0=this mo;1=next mo"),
+            (
+                "quarter-narrow",
+                "one=in {0} qtr;other=in {0} qtrs|one={0} qtr ago;other={0} qtrs ago",
+            ),
+            (
+                "quarter-short",
+                "one=in {0} qtr;other=in {0} qtrs|one={0} qtr ago;other={0} qtrs ago",
+            ),
+            (
+                "second-narrow",
+                "one=in {0} sec;other=in {0} secs|one={0} sec ago;other={0} secs ago",
+            ),
+            ("second-short", "one=in {0} sec;other=in {0} secs|one={0} sec ago;other={0} secs ago"),
+            ("week-narrow", "one=in {0} wk;other=in {0} wks|one={0} wk ago;other={0} wks ago"),
+            ("week-narrow/relative", "-1=last wk;0=this wk;1=next wk"),
+            ("week-short", "one=in {0} wk;other=in {0} wks|one={0} wk ago;other={0} wks ago"),
+            ("week-short/relative", "-1=last wk;0=this wk;1=next wk"),
+            ("year-narrow", "one=in {0} yr;other=in {0} yrs|one={0} yr ago;other={0} yrs ago"),
+            ("year-narrow/relative", "-1=last yr;0=this yr;1=next yr"),
+            ("year-short", "one=in {0} yr;other=in {0} yrs|one={0} yr ago;other={0} yrs ago"),
+            ("year-short/relative", "-1=last yr;0=this yr;1=next yr"),
+        ],
+    ),
+    (
+        "en-SG",
+        &[
+            ("month-short", "in {0} mth|{0} mth ago"),
+            ("month-short/relative", "-1=last mth;0=this mth;1=next mth"),
+            ("quarter-narrow/relative", "-1=last qtr;0=this qtr;1=next qtr"),
+            (
+                "quarter-short",
+                "one=in {0} qtr;other=in {0} qtrs|one={0} qtr ago;other={0} qtrs ago",
+            ),
+            ("quarter-short/relative", "-1=last qtr;0=this qtr;1=next qtr"),
+        ],
+    ),
+    (
+        "es",
+        &[
+            (
+                "day",
+                "one=dentro de {0} día;other=dentro de {0} días|one=hace {0} día;other=hace {0} días",
+            ),
+            ("day-narrow", "dentro de {0} d|hace {0} d"),
+            ("day-narrow/relative", "-2=anteayer;-1=ayer;0=hoy;1=mañana;2=pasado mañana"),
+            ("day-short", "dentro de {0} d|hace {0} d"),
+            ("day-short/relative", "-2=anteayer;-1=ayer;0=hoy;1=mañana;2=pasado mañana"),
+            ("day/relative", "-2=anteayer;-1=ayer;0=hoy;1=mañana;2=pasado mañana"),
+            (
+                "hour",
+                "one=dentro de {0} hora;other=dentro de {0} horas|one=hace {0} hora;other=hace {0} horas",
+            ),
+            ("hour-narrow", "dentro de {0} h|hace {0} h"),
+            ("hour-narrow/relative", "0=esta hora"),
+            ("hour-short", "dentro de {0} h|hace {0} h"),
+            ("hour-short/relative", "0=esta hora"),
+            ("hour/relative", "0=esta hora"),
+            (
+                "minute",
+                "one=dentro de {0} minuto;other=dentro de {0} minutos|one=hace {0} minuto;other=hace {0} minutos",
+            ),
+            ("minute-narrow", "dentro de {0} min|hace {0} min"),
+            ("minute-narrow/relative", "0=este minuto"),
+            ("minute-short", "dentro de {0} min|hace {0} min"),
+            ("minute-short/relative", "0=este minuto"),
+            ("minute/relative", "0=este minuto"),
+            (
+                "month",
+                "one=dentro de {0} mes;other=dentro de {0} meses|one=hace {0} mes;other=hace {0} meses",
+            ),
+            ("month-narrow", "dentro de {0} m|hace {0} m"),
+            ("month-narrow/relative", "-1=el mes pasado;0=este mes;1=el próximo mes"),
+            ("month-short", "dentro de {0} m|hace {0} m"),
+            ("month-short/relative", "-1=el mes pasado;0=este mes;1=el próximo mes"),
+            ("month/relative", "-1=el mes pasado;0=este mes;1=el próximo mes"),
+            (
+                "quarter",
+                "one=dentro de {0} trimestre;other=dentro de {0} trimestres|one=hace {0} trimestre;other=hace {0} trimestres",
+            ),
+            ("quarter-narrow", "dentro de {0} trim.|hace {0} trim."),
+            (
+                "quarter-narrow/relative",
+                "-1=el trimestre pasado;0=este trimestre;1=el próximo trimestre",
+            ),
+            ("quarter-short", "dentro de {0} trim.|hace {0} trim."),
+            (
+                "quarter-short/relative",
+                "-1=el trimestre pasado;0=este trimestre;1=el próximo trimestre",
+            ),
+            ("quarter/relative", "-1=el trimestre pasado;0=este trimestre;1=el próximo trimestre"),
+            (
+                "second",
+                "one=dentro de {0} segundo;other=dentro de {0} segundos|one=hace {0} segundo;other=hace {0} segundos",
+            ),
+            ("second-narrow", "dentro de {0} s|hace {0} s"),
+            ("second-narrow/relative", "0=ahora"),
+            ("second-short", "dentro de {0} s|hace {0} s"),
+            ("second-short/relative", "0=ahora"),
+            ("second/relative", "0=ahora"),
+            (
+                "week",
+                "one=dentro de {0} semana;other=dentro de {0} semanas|one=hace {0} semana;other=hace {0} semanas",
+            ),
+            ("week-narrow", "dentro de {0} sem.|hace {0} sem."),
+            ("week-narrow/relative", "-1=sem. ant.;0=esta sem.;1=próx. sem."),
+            ("week-short", "dentro de {0} sem.|hace {0} sem."),
+            ("week-short/relative", "-1=sem. ant.;0=esta sem.;1=próx. sem."),
+            ("week/relative", "-1=la semana pasada;0=esta semana;1=la próxima semana"),
+            (
+                "year",
+                "one=dentro de {0} año;other=dentro de {0} años|one=hace {0} año;other=hace {0} años",
+            ),
+            ("year-narrow", "dentro de {0} a|hace {0} a"),
+            ("year-narrow/relative", "-1=el año pasado;0=este año;1=el próximo año"),
+            ("year-short", "dentro de {0} a|hace {0} a"),
+            ("year-short/relative", "-1=el año pasado;0=este año;1=el próximo año"),
+            ("year/relative", "-1=el año pasado;0=este año;1=el próximo año"),
+        ],
+    ),
+    (
+        "es-419",
+        &[
+            (
+                "day-narrow",
+                "one=dentro de {0} día;other=dentro de {0} días|one=hace {0} día;other=hace {0} días",
+            ),
+            (
+                "day-short",
+                "one=dentro de {0} día;other=dentro de {0} días|one=hace {0} día;other=hace {0} días",
+            ),
+            ("week-narrow/relative", "-1=sem. pas.;0=esta sem.;1=próx. sem."),
+            ("week-short/relative", "-1=sem. pas.;0=esta sem.;1=próx. sem."),
+        ],
+    ),
+    (
+        "es-AR",
+        &[
+            ("day-narrow", "dentro de {0} días|hace {0} días"),
+            ("day-short", "dentro de {0} días|hace {0} días"),
+            ("second-narrow", "dentro de {0} seg.|hace {0} seg."),
+            ("second-short", "dentro de {0} seg.|hace {0} seg."),
+        ],
+    ),
+    (
+        "es-MX",
+        &[
+            (
+                "day-narrow",
+                "one=dentro de {0} día;other=en {0} días|one=hace {0} día;other=hace {0} días",
+            ),
+            ("day-short", "one=en {0} día;other=en {0} días|one=hace {0} día;other=hace {0} días"),
+            ("hour-short", "one=en {0} h;other=en {0} n|hace {0} h"),
+            ("month", "one=en {0} mes;other=en {0} meses|one=hace {0} mes;other=hace {0} meses"),
+            ("month-short", "en {0} m|hace {0} m"),
+            ("month/relative", "-1=el mes pasado;0=este mes;1=el mes próximo"),
+            (
+                "quarter",
+                "one=en {0} trimestre;other=dentro de {0} trimetres|one=hace {0} trimestre;other=hace {0} trimestres",
+            ),
+            ("quarter-narrow", "en {0} trim.|hace {0} trim."),
+            ("quarter-short", "one=en {0} trim.;other=en {0} trim|hace {0} trim."),
+            ("second-short", "en {0} s|hace {0} s"),
+            ("week-short", "en {0} sem.|hace {0} sem."),
+            ("week/relative", "-1=la semana pasada;0=esta semana;1=la semana próxima"),
+            ("year-narrow", "en {0} a|hace {0} a"),
+            ("year-short", "en {0} a|hace {0} a"),
+            ("year/relative", "-1=el año pasado;0=este año;1=el año próximo"),
+        ],
+    ),
+    (
+        "es-PY",
+        &[
+            ("second-narrow", "dentro de {0} seg.|hace {0} seg."),
+            ("second-short", "dentro de {0} seg.|hace {0} seg."),
+        ],
+    ),
+    (
+        "es-SV",
+        &[
+            ("day/relative", "-2=antier;-1=ayer;0=hoy;1=mañana;2=pasado mañana"),
+        ],
+    ),
+    (
+        "es-US",
+        &[
+            ("week-narrow/relative", "-1=sem. pasada;0=esta sem.;1=próxima sem."),
+            ("week-short/relative", "-1=sem. pasada;0=esta sem.;1=próxima sem."),
+        ],
+    ),
+    (
+        "fr",
+        &[
+            (
+                "day",
+                "one=dans {0} jour;other=dans {0} jours|one=il y a {0} jour;other=il y a {0} jours",
+            ),
+            ("day-narrow", "+{0} j|-{0} j"),
+            ("day-narrow/relative", "-2=avant-hier;-1=hier;0=aujourd’hui;1=demain;2=après-demain"),
+            ("day-short", "dans {0}\u{a0}j|il y a {0}\u{a0}j"),
+            ("day-short/relative", "-2=avant-hier;-1=hier;0=aujourd’hui;1=demain;2=après-demain"),
+            ("day/relative", "-2=avant-hier;-1=hier;0=aujourd’hui;1=demain;2=après-demain"),
+            (
+                "hour",
+                "one=dans {0} heure;other=dans {0} heures|one=il y a {0} heure;other=il y a {0} heures",
+            ),
+            ("hour-narrow", "+{0} h|-{0} h"),
+            ("hour-narrow/relative", "0=cette heure-ci"),
+            ("hour-short", "dans {0}\u{a0}h|il y a {0}\u{a0}h"),
+            ("hour-short/relative", "0=cette heure-ci"),
+            ("hour/relative", "0=cette heure-ci"),
+            (
+                "minute",
+                "one=dans {0} minute;other=dans {0} minutes|one=il y a {0} minute;other=il y a {0} minutes",
+            ),
+            ("minute-narrow", "+{0} min|-{0} min"),
+            ("minute-narrow/relative", "0=cette minute-ci"),
+            ("minute-short", "dans {0}\u{a0}min|il y a {0}\u{a0}min"),
+            ("minute-short/relative", "0=cette minute-ci"),
+            ("minute/relative", "0=cette minute-ci"),
+            ("month", "dans {0} mois|il y a {0} mois"),
+            ("month-narrow", "+{0} m.|-{0} m."),
+            ("month-narrow/relative", "-1=le mois dernier;0=ce mois-ci;1=le mois prochain"),
+            ("month-short", "dans {0} m.|il y a {0} m."),
+            ("month-short/relative", "-1=le mois dernier;0=ce mois-ci;1=le mois prochain"),
+            ("month/relative", "-1=le mois dernier;0=ce mois-ci;1=le mois prochain"),
+            (
+                "quarter",
+                "one=dans {0} trimestre;other=dans {0} trimestres|one=il y a {0} trimestre;other=il y a {0} trimestres",
+            ),
+            ("quarter-narrow", "+{0} trim.|-{0} trim."),
+            (
+                "quarter-narrow/relative",
+                "-1=le trimestre dernier;0=ce trimestre;1=le trimestre prochain",
+            ),
+            ("quarter-short", "dans {0} trim.|il y a {0} trim."),
+            (
+                "quarter-short/relative",
+                "-1=le trimestre dernier;0=ce trimestre;1=le trimestre prochain",
+            ),
+            ("quarter/relative", "-1=le trimestre dernier;0=ce trimestre;1=le trimestre prochain"),
+            (
+                "second",
+                "one=dans {0} seconde;other=dans {0} secondes|one=il y a {0} seconde;other=il y a {0} secondes",
+            ),
+            ("second-narrow", "+{0} s|-{0} s"),
+            ("second-narrow/relative", "0=maintenant"),
+            ("second-short", "dans {0}\u{a0}s|il y a {0}\u{a0}s"),
+            ("second-short/relative", "0=maintenant"),
+            ("second/relative", "0=maintenant"),
+            (
+                "week",
+                "one=dans {0} semaine;other=dans {0} semaines|one=il y a {0} semaine;other=il y a {0} semaines",
+            ),
+            ("week-narrow", "+{0} sem.|-{0} sem."),
+            (
+                "week-narrow/relative",
+                "-1=la semaine dernière;0=cette semaine;1=la semaine prochaine",
+            ),
+            ("week-short", "dans {0} sem.|il y a {0} sem."),
+            (
+                "week-short/relative",
+                "-1=la semaine dernière;0=cette semaine;1=la semaine prochaine",
+            ),
+            ("week/relative", "-1=la semaine dernière;0=cette semaine;1=la semaine prochaine"),
+            ("year", "one=dans {0} an;other=dans {0} ans|one=il y a {0} an;other=il y a {0} ans"),
+            ("year-narrow", "+{0} a|-{0} a"),
+            ("year-narrow/relative", "-1=l’année dernière;0=cette année;1=l’année prochaine"),
+            ("year-short", "dans {0} a|il y a {0} a"),
+            ("year-short/relative", "-1=l’année dernière;0=cette année;1=l’année prochaine"),
+            ("year/relative", "-1=l’année dernière;0=cette année;1=l’année prochaine"),
+        ],
+    ),
+    (
+        "fr-CA",
+        &[
+            ("quarter-narrow/relative", "-1=trim. dernier;0=ce trim.;1=trim.prochain"),
+            ("quarter-short/relative", "-1=trim. dernier;0=ce trim.;1=trim. prochain"),
+            (
+                "quarter/relative",
+                "-1=le trimestre dernier;0=ce trimestre-ci;1=le trimestre prochain",
+            ),
+            ("second-narrow", "one=+ {0} s;other=+{0} s|-{0} s"),
+        ],
+    ),
+    (
+        "hi",
+        &[
+            ("day", "{0} दिन में|{0} दिन पहले"),
+            ("day-narrow", "{0} दिन में|{0} दिन पहले"),
+            ("day-narrow/relative", "-1=बीता कल;0=आज;1=आने वाला कल"),
+            ("day-short", "{0} दिन में|{0} दिन पहले"),
+            ("day-short/relative", "-2=परसों;-1=बीता कल;0=आज;1=आने वाला कल;2=परसों"),
+            ("day/relative", "-2=परसों;-1=कल;0=आज;1=कल;2=परसों"),
+            ("hour", "{0} घंटे में|{0} घंटे पहले"),
+            ("hour-narrow", "{0} घं॰ में|{0} घं॰ पहले"),
+            ("hour-narrow/relative", "0=यह घंटा"),
+            ("hour-short", "{0} घं॰ में|{0} घं॰ पहले"),
+            ("hour-short/relative", "0=यह घंटा"),
+            ("hour/relative", "0=यह घंटा"),
+            ("minute", "{0} मिनट में|{0} मिनट पहले"),
+            ("minute-narrow", "{0} मि॰ में|{0} मि॰ पहले"),
+            ("minute-narrow/relative", "0=यह मिनट"),
+            ("minute-short", "{0} मि॰ में|{0} मि॰ पहले"),
+            ("minute-short/relative", "0=यह मिनट"),
+            ("minute/relative", "0=यह मिनट"),
+            ("month", "{0} माह में|{0} माह पहले"),
+            ("month-narrow", "{0} माह में|{0} माह पहले"),
+            ("month-narrow/relative", "-1=पिछला माह;0=इस माह;1=अगला माह"),
+            ("month-short", "{0} माह में|{0} माह पहले"),
+            ("month-short/relative", "-1=पिछला माह;0=इस माह;1=अगला माह"),
+            ("month/relative", "-1=पिछला माह;0=इस माह;1=अगला माह"),
+            ("quarter", "one={0} तिमाही में;other={0} तिमाहियों में|{0} तिमाही पहले"),
+            ("quarter-narrow", "{0} ति॰ में|{0} ति॰ पहले"),
+            ("quarter-narrow/relative", "-1=अंतिम तिमाही;0=इस तिमाही;1=अगली तिमाही"),
+            (
+                "quarter-short",
+                "one={0} तिमाही में;other={0} तिमाहियों में|one={0} तिमाही पहले;other={0} तिमाहियों पहले",
+            ),
+            ("quarter-short/relative", "-1=अंतिम तिमाही;0=इस तिमाही;1=अगली तिमाही"),
+            ("quarter/relative", "-1=अंतिम तिमाही;0=इस तिमाही;1=अगली तिमाही"),
+            ("second", "{0} सेकंड में|{0} सेकंड पहले"),
+            ("second-narrow", "{0} से॰ में|{0} से॰ पहले"),
+            ("second-narrow/relative", "0=अब"),
+            ("second-short", "{0} से॰ में|{0} से॰ पहले"),
+            ("second-short/relative", "0=अब"),
+            ("second/relative", "0=अब"),
+            ("week", "{0} सप्ताह में|{0} सप्ताह पहले"),
+            ("week-narrow", "{0} सप्ताह में|{0} सप्ताह पहले"),
+            ("week-narrow/relative", "-1=पिछला सप्ताह;0=इस सप्ताह;1=अगला सप्ताह"),
+            ("week-short", "{0} सप्ताह में|{0} सप्ताह पहले"),
+            ("week-short/relative", "-1=पिछला सप्ताह;0=इस सप्ताह;1=अगला सप्ताह"),
+            ("week/relative", "-1=पिछला सप्ताह;0=इस सप्ताह;1=अगला सप्ताह"),
+            ("year", "{0} वर्ष में|{0} वर्ष पहले"),
+            ("year-narrow", "{0} वर्ष में|{0} वर्ष पहले"),
+            ("year-narrow/relative", "-1=पिछले साल;0=इस साल;1=अगले साल"),
+            ("year-short", "{0} वर्ष में|{0} वर्ष पहले"),
+            ("year-short/relative", "-1=पिछला वर्ष;0=इस वर्ष;1=अगला वर्ष"),
+            ("year/relative", "-1=पिछला वर्ष;0=इस वर्ष;1=अगला वर्ष"),
+        ],
+    ),
+    (
+        "hi-Latn",
+        &[
+            ("day", "{0} din mein|{0} din pehle"),
+            ("day-narrow", "{0} din mein|{0} din pahle"),
+            ("day-narrow/relative", "-2=parson;-1=kal;0=aaj;1=aane wala kal;2=aane wala parson"),
+            ("day-short", "{0} din mein|{0} din pahle"),
+            ("day-short/relative", "-2=parson;-1=kal;0=aaj;1=aane wala kal;2=aane wala parson"),
+            ("day/relative", "-2=parson;-1=kal;0=aaj;1=aane wala kal;2=aane wala parson"),
+            ("hour", "{0} ghante mein|{0} ghante pahle"),
+            ("hour-narrow", "{0}hr mein|{0}hr pahle"),
+            ("hour-narrow/relative", "0=yah ghanta"),
+            ("hour-short", "{0} hr mein|one={0} hr pahle;other={0} hr mein"),
+            ("hour-short/relative", "0=yah ghanta"),
+            ("hour/relative", "0=yah ghanta"),
+            ("minute", "{0} minute mein|{0} minute pahle"),
+            ("minute-narrow", "{0} min. mein|{0} min. pahle"),
+            ("minute-narrow/relative", "0=yah minute"),
+            ("minute-short", "{0} min. mein|{0} min. pahle"),
+            ("minute-short/relative", "0=yah minute"),
+            ("minute/relative", "0=yah minute"),
+            ("month", "{0} maheene mein|{0} maheene pahle"),
+            ("month-narrow", "{0}mo mein|{0}mo pahle"),
+            ("month-narrow/relative", "-1=last mo.;0=is mo.;1=next mo."),
+            ("month-short", "{0} mo. mein|{0} mo. pahle"),
+            ("month-short/relative", "-1=last mo;0=is mo;1=next mo"),
+            ("month/relative", "-1=pichhla maheena;0=yah maheena;1=agla maheena"),
+            (
+                "quarter",
+                "one={0} quarter mein;other={0} quarters mein|one={0} quarter pahle;other={0} quarters pahle",
+            ),
+            ("quarter-narrow", "{0}q mein|{0}q pahle"),
+            (
+                "quarter-short",
+                "one={0} qtr. mein;other={0} qtrs. mein|one={0} qtr. pahle;other={0} qtrs. pahle",
+            ),
+            ("quarter/relative", "-1=last quarter;0=is quarter;1=next quarter"),
+            ("second", "{0} second mein|{0} second pahle"),
+            ("second-narrow", "{0}s mein|{0}s pahle"),
+            ("second-short", "{0} sec. mein|{0} sec. pahle"),
+            ("second/relative", "0=abhi"),
+            ("week", "{0} hafte mein|{0} hafte pehle"),
+            ("week-narrow", "{0}w mein|{0}w pahle"),
+            ("week-narrow/relative", "-1=last wk;0=is wk;1=next wk"),
+            ("week-short", "{0} wk. mein|{0} wk. pahle"),
+            ("week-short/relative", "-1=last wk.;0=is wk.;1=next wk."),
+            ("week/relative", "-1=pichhla hafta;0=is hafte;1=agla hafta"),
+            ("year", "{0} saal mein|{0} saal pahle"),
+            ("year-narrow", "{0}y mein|{0}y pahle"),
+            ("year-narrow/relative", "-1=last yr.;0=is yr.;1=next yr."),
+            ("year-short", "{0} yr. mein|{0} yr. pahle"),
+            ("year-short/relative", "-1=last yr.;0=is yr.;1=next yr."),
+            ("year/relative", "-1=pichhla saal;0=is saal;1=agla saal"),
+        ],
+    ),
+    (
+        "id",
+        &[
+            ("day", "dalam {0} hari|{0} hari yang lalu"),
+            ("day-narrow", "dalam {0} h|{0} h lalu"),
+            ("day-narrow/relative", "-2=selumbari;2=lusa"),
+            ("day-short", "dalam {0} h|{0} h lalu"),
+            ("day-short/relative", "-2=selumbari;-1=kemarin;0=hari ini;1=besok;2=lusa"),
+            ("day/relative", "-2=kemarin dulu;-1=kemarin;0=hari ini;1=besok;2=lusa"),
+            ("hour", "dalam {0} jam|{0} jam yang lalu"),
+            ("hour-narrow", "dalam {0} jam|{0} jam lalu"),
+            ("hour-narrow/relative", "0=jam ini"),
+            ("hour-short", "dalam {0} jam|{0} jam lalu"),
+            ("hour-short/relative", "0=jam ini"),
+            ("hour/relative", "0=jam ini"),
+            ("minute", "dalam {0} menit|{0} menit yang lalu"),
+            ("minute-narrow", "dlm {0} mnt|{0} mnt lalu"),
+            ("minute-narrow/relative", "0=mnt ini"),
+            ("minute-short", "dlm {0} mnt|{0} mnt lalu"),
+            ("minute-short/relative", "0=mnt ini"),
+            ("minute/relative", "0=menit ini"),
+            ("month", "dalam {0} bulan|{0} bulan yang lalu"),
+            ("month-narrow", "dlm {0} bln|{0} bln lalu"),
+            ("month-narrow/relative", "-1=bln lalu;0=bln ini;1=bln berikutnya"),
+            ("month-short", "dlm {0} bln|{0} bln lalu"),
+            ("month-short/relative", "-1=bln lalu;0=bln ini;1=bln berikutnya"),
+            ("month/relative", "-1=bulan lalu;0=bulan ini;1=bulan depan"),
+            ("quarter", "dalam {0} kuartal|{0} kuartal yang lalu"),
+            ("quarter-narrow", "dlm {0} krtl.|{0} krtl. lalu"),
+            ("quarter-narrow/relative", "-1=krtl lalu;0=krtl ini;1=krtl berikutnya"),
+            ("quarter-short", "dlm {0} krtl.|{0} krtl. lalu"),
+            ("quarter-short/relative", "-1=krtl lalu;0=krtl ini;1=krtl berikutnya"),
+            ("quarter/relative", "-1=Kuartal lalu;0=kuartal ini;1=kuartal berikutnya"),
+            ("second", "dalam {0} detik|{0} detik yang lalu"),
+            ("second-narrow", "dlm {0} dtk|{0} dtk lalu"),
+            ("second-narrow/relative", "0=sekarang"),
+            ("second-short", "dlm {0} dtk|{0} dtk lalu"),
+            ("second-short/relative", "0=sekarang"),
+            ("second/relative", "0=sekarang"),
+            ("week", "dalam {0} minggu|{0} minggu yang lalu"),
+            ("week-narrow", "dlm {0} mgg|{0} mgg lalu"),
+            ("week-narrow/relative", "-1=mgg lalu;0=mgg ini;1=mgg depan"),
+            ("week-short", "dlm {0} mgg|{0} mgg lalu"),
+            ("week-short/relative", "-1=mgg lalu;0=mgg ini;1=mgg depan"),
+            ("week/relative", "-1=minggu lalu;0=minggu ini;1=minggu depan"),
+            ("year", "dalam {0} tahun|{0} tahun yang lalu"),
+            ("year-narrow", "dlm {0} thn|{0} thn lalu"),
+            ("year-narrow/relative", "-1=thn lalu;0=thn ini;1=thn depan"),
+            ("year-short", "dlm {0} thn|{0} thn lalu"),
+            ("year-short/relative", "-1=thn lalu;0=thn ini;1=thn depan"),
+            ("year/relative", "-1=tahun lalu;0=tahun ini;1=tahun depan"),
+        ],
+    ),
+    (
+        "it",
+        &[
+            (
+                "day",
+                "one=tra {0} giorno;other=tra {0} giorni|one={0} giorno fa;other={0} giorni fa",
+            ),
+            ("day-narrow", "one=tra {0} g;other=tra {0} gg|one={0} g fa;other={0} gg fa"),
+            ("day-narrow/relative", "-2=l’altro ieri;-1=ieri;0=oggi;1=domani;2=dopodomani"),
+            ("day-short", "one=tra {0} g;other=tra {0} gg|one={0} g fa;other={0} gg fa"),
+            ("day-short/relative", "-2=l’altro ieri;-1=ieri;0=oggi;1=domani;2=dopodomani"),
+            ("day/relative", "-2=l’altro ieri;-1=ieri;0=oggi;1=domani;2=dopodomani"),
+            ("hour", "one=tra {0} ora;other=tra {0} ore|one={0} ora fa;other={0} ore fa"),
+            ("hour-narrow", "tra {0} h|{0} h fa"),
+            ("hour-narrow/relative", "0=quest’ora"),
+            ("hour-short", "tra {0} h|{0} h fa"),
+            ("hour-short/relative", "0=quest’ora"),
+            ("hour/relative", "0=quest’ora"),
+            (
+                "minute",
+                "one=tra {0} minuto;other=tra {0} minuti|one={0} minuto fa;other={0} minuti fa",
+            ),
+            ("minute-narrow", "tra {0} min|{0} min fa"),
+            ("minute-narrow/relative", "0=questo minuto"),
+            ("minute-short", "tra {0} min|{0} min fa"),
+            ("minute-short/relative", "0=questo minuto"),
+            ("minute/relative", "0=questo minuto"),
+            ("month", "one=tra {0} mese;other=tra {0} mesi|one={0} mese fa;other={0} mesi fa"),
+            (
+                "month-narrow",
+                "one=tra {0} mese;other=tra {0} mesi|one={0} mese fa;other={0} mesi fa",
+            ),
+            ("month-narrow/relative", "-1=mese scorso;0=questo mese;1=mese prossimo"),
+            (
+                "month-short",
+                "one=tra {0} mese;other=tra {0} mesi|one={0} mese fa;other={0} mesi fa",
+            ),
+            ("month-short/relative", "-1=mese scorso;0=questo mese;1=mese prossimo"),
+            ("month/relative", "-1=mese scorso;0=questo mese;1=mese prossimo"),
+            (
+                "quarter",
+                "one=tra {0} trimestre;other=tra {0} trimestri|one={0} trimestre fa;other={0} trimestri fa",
+            ),
+            ("quarter-narrow", "tra {0} trim.|{0} trim. fa"),
+            ("quarter-narrow/relative", "-1=trim. scorso;0=questo trim.;1=trim. prossimo"),
+            ("quarter-short", "tra {0} trim.|{0} trim. fa"),
+            ("quarter-short/relative", "-1=trim. scorso;0=questo trim.;1=trim. prossimo"),
+            ("quarter/relative", "-1=trimestre scorso;0=questo trimestre;1=trimestre prossimo"),
+            (
+                "second",
+                "one=tra {0} secondo;other=tra {0} secondi|one={0} secondo fa;other={0} secondi fa",
+            ),
+            ("second-narrow", "tra {0} s|{0} s fa"),
+            ("second-narrow/relative", "0=ora"),
+            ("second-short", "tra {0} sec.|{0} sec. fa"),
+            ("second-short/relative", "0=ora"),
+            ("second/relative", "0=ora"),
+            (
+                "week",
+                "one=tra {0} settimana;other=tra {0} settimane|one={0} settimana fa;other={0} settimane fa",
+            ),
+            ("week-narrow", "tra {0} sett.|{0} sett. fa"),
+            ("week-narrow/relative", "-1=sett. scorsa;0=questa sett.;1=sett. prossima"),
+            ("week-short", "tra {0} sett.|{0} sett. fa"),
+            ("week-short/relative", "-1=sett. scorsa;0=questa sett.;1=sett. prossima"),
+            ("week/relative", "-1=settimana scorsa;0=questa settimana;1=settimana prossima"),
+            ("year", "one=tra {0} anno;other=tra {0} anni|one={0} anno fa;other={0} anni fa"),
+            (
+                "year-narrow",
+                "one=tra {0} anno;other=tra {0} anni|one={0} anno fa;other={0} anni fa",
+            ),
+            ("year-narrow/relative", "-1=anno scorso;0=quest’anno;1=anno prossimo"),
+            ("year-short", "one=tra {0} anno;other=tra {0} anni|one={0} anno fa;other={0} anni fa"),
+            ("year-short/relative", "-1=anno scorso;0=quest’anno;1=anno prossimo"),
+            ("year/relative", "-1=anno scorso;0=quest’anno;1=anno prossimo"),
+        ],
+    ),
+    (
+        "ja",
+        &[
+            ("day", "{0} 日後|{0} 日前"),
+            ("day-narrow", "{0}日後|{0}日前"),
+            ("day-narrow/relative", "-2=一昨日;-1=昨日;0=今日;1=明日;2=明後日"),
+            ("day-short", "{0} 日後|{0} 日前"),
+            ("day-short/relative", "-2=一昨日;-1=昨日;0=今日;1=明日;2=明後日"),
+            ("day/relative", "-2=一昨日;-1=昨日;0=今日;1=明日;2=明後日"),
+            ("hour", "{0} 時間後|{0} 時間前"),
+            ("hour-narrow", "{0}時間後|{0}時間前"),
+            ("hour-narrow/relative", "0=1 時間以内"),
+            ("hour-short", "{0} 時間後|{0} 時間前"),
+            ("hour-short/relative", "0=1 時間以内"),
+            ("hour/relative", "0=1 時間以内"),
+            ("minute", "{0} 分後|{0} 分前"),
+            ("minute-narrow", "{0}分後|{0}分前"),
+            ("minute-narrow/relative", "0=1 分以内"),
+            ("minute-short", "{0} 分後|{0} 分前"),
+            ("minute-short/relative", "0=1 分以内"),
+            ("minute/relative", "0=1 分以内"),
+            ("month", "{0} か月後|{0} か月前"),
+            ("month-narrow", "{0}か月後|{0}か月前"),
+            ("month-narrow/relative", "-1=先月;0=今月;1=来月"),
+            ("month-short", "{0} か月後|{0} か月前"),
+            ("month-short/relative", "-1=先月;0=今月;1=来月"),
+            ("month/relative", "-1=先月;0=今月;1=来月"),
+            ("quarter", "{0} 四半期後|{0} 四半期前"),
+            ("quarter-narrow", "{0}四半期後|{0}四半期前"),
+            ("quarter-narrow/relative", "-1=前四半期;0=今四半期;1=翌四半期"),
+            ("quarter-short", "{0} 四半期後|{0} 四半期前"),
+            ("quarter-short/relative", "-1=前四半期;0=今四半期;1=翌四半期"),
+            ("quarter/relative", "-1=前四半期;0=今四半期;1=翌四半期"),
+            ("second", "{0} 秒後|{0} 秒前"),
+            ("second-narrow", "{0}秒後|{0}秒前"),
+            ("second-narrow/relative", "0=今"),
+            ("second-short", "{0} 秒後|{0} 秒前"),
+            ("second-short/relative", "0=今"),
+            ("second/relative", "0=今"),
+            ("week", "{0} 週間後|{0} 週間前"),
+            ("week-narrow", "{0}週間後|{0}週間前"),
+            ("week-narrow/relative", "-1=先週;0=今週;1=来週"),
+            ("week-short", "{0} 週間後|{0} 週間前"),
+            ("week-short/relative", "-1=先週;0=今週;1=来週"),
+            ("week/relative", "-1=先週;0=今週;1=来週"),
+            ("year", "{0} 年後|{0} 年前"),
+            ("year-narrow", "{0}年後|{0}年前"),
+            ("year-narrow/relative", "-1=昨年;0=今年;1=来年"),
+            ("year-short", "{0} 年後|{0} 年前"),
+            ("year-short/relative", "-1=昨年;0=今年;1=来年"),
+            ("year/relative", "-1=昨年;0=今年;1=来年"),
+        ],
+    ),
+    (
+        "ko",
+        &[
+            ("day", "{0}일 후|{0}일 전"),
+            ("day-narrow", "{0}일 후|{0}일 전"),
+            ("day-narrow/relative", "-2=그저께;-1=어제;0=오늘;1=내일;2=모레"),
+            ("day-short", "{0}일 후|{0}일 전"),
+            ("day-short/relative", "-2=그저께;-1=어제;0=오늘;1=내일;2=모레"),
+            ("day/relative", "-2=그저께;-1=어제;0=오늘;1=내일;2=모레"),
+            ("hour", "{0}시간 후|{0}시간 전"),
+            ("hour-narrow", "{0}시간 후|{0}시간 전"),
+            ("hour-narrow/relative", "0=현재 시간"),
+            ("hour-short", "{0}시간 후|{0}시간 전"),
+            ("hour-short/relative", "0=현재 시간"),
+            ("hour/relative", "0=현재 시간"),
+            ("minute", "{0}분 후|{0}분 전"),
+            ("minute-narrow", "{0}분 후|{0}분 전"),
+            ("minute-narrow/relative", "0=현재 분"),
+            ("minute-short", "{0}분 후|{0}분 전"),
+            ("minute-short/relative", "0=현재 분"),
+            ("minute/relative", "0=현재 분"),
+            ("month", "{0}개월 후|{0}개월 전"),
+            ("month-narrow", "{0}개월 후|{0}개월 전"),
+            ("month-narrow/relative", "-1=지난달;0=이번 달;1=다음 달"),
+            ("month-short", "{0}개월 후|{0}개월 전"),
+            ("month-short/relative", "-1=지난달;0=이번 달;1=다음 달"),
+            ("month/relative", "-1=지난달;0=이번 달;1=다음 달"),
+            ("quarter", "{0}분기 후|{0}분기 전"),
+            ("quarter-narrow", "{0}분기 후|{0}분기 전"),
+            ("quarter-narrow/relative", "-1=지난 분기;0=이번 분기;1=다음 분기"),
+            ("quarter-short", "{0}분기 후|{0}분기 전"),
+            ("quarter-short/relative", "-1=지난 분기;0=이번 분기;1=다음 분기"),
+            ("quarter/relative", "-1=지난 분기;0=이번 분기;1=다음 분기"),
+            ("second", "{0}초 후|{0}초 전"),
+            ("second-narrow", "{0}초 후|{0}초 전"),
+            ("second-narrow/relative", "0=지금"),
+            ("second-short", "{0}초 후|{0}초 전"),
+            ("second-short/relative", "0=지금"),
+            ("second/relative", "0=지금"),
+            ("week", "{0}주 후|{0}주 전"),
+            ("week-narrow", "{0}주 후|{0}주 전"),
+            ("week-narrow/relative", "-1=지난주;0=이번 주;1=다음 주"),
+            ("week-short", "{0}주 후|{0}주 전"),
+            ("week-short/relative", "-1=지난주;0=이번 주;1=다음 주"),
+            ("week/relative", "-1=지난주;0=이번 주;1=다음 주"),
+            ("year", "{0}년 후|{0}년 전"),
+            ("year-narrow", "{0}년 후|{0}년 전"),
+            ("year-narrow/relative", "-1=작년;0=올해;1=내년"),
+            ("year-short", "{0}년 후|{0}년 전"),
+            ("year-short/relative", "-1=작년;0=올해;1=내년"),
+            ("year/relative", "-1=작년;0=올해;1=내년"),
+        ],
+    ),
+    (
+        "nl",
+        &[
+            (
+                "day",
+                "one=over {0} dag;other=over {0} dagen|one={0} dag geleden;other={0} dagen geleden",
+            ),
+            (
+                "day-narrow",
+                "one=over {0} dag;other=over {0} dgn|one={0} dag geleden;other={0} dgn geleden",
+            ),
+            ("day-narrow/relative", "-2=eergisteren;-1=gisteren;0=vandaag;1=morgen;2=overmorgen"),
+            (
+                "day-short",
+                "one=over {0} dag;other=over {0} dgn|one={0} dag geleden;other={0} dgn geleden",
+            ),
+            ("day-short/relative", "-2=eergisteren;-1=gisteren;0=vandaag;1=morgen;2=overmorgen"),
+            ("day/relative", "-2=eergisteren;-1=gisteren;0=vandaag;1=morgen;2=overmorgen"),
+            ("hour", "over {0} uur|{0} uur geleden"),
+            ("hour-narrow", "over {0} uur|{0} uur geleden"),
+            ("hour-narrow/relative", "0=binnen een uur"),
+            ("hour-short", "over {0} uur|{0} uur geleden"),
+            ("hour-short/relative", "0=binnen een uur"),
+            ("hour/relative", "0=binnen een uur"),
+            (
+                "minute",
+                "one=over {0} minuut;other=over {0} minuten|one={0} minuut geleden;other={0} minuten geleden",
+            ),
+            ("minute-narrow", "over {0} min.|{0} min. geleden"),
+            ("minute-narrow/relative", "0=binnen een minuut"),
+            ("minute-short", "over {0} min.|{0} min. geleden"),
+            ("minute-short/relative", "0=binnen een minuut"),
+            ("minute/relative", "0=binnen een minuut"),
+            (
+                "month",
+                "one=over {0} maand;other=over {0} maanden|one={0} maand geleden;other={0} maanden geleden",
+            ),
+            (
+                "month-narrow",
+                "one=over {0} maand;other=over {0} maanden|one={0} maand geleden;other={0} maanden geleden",
+            ),
+            ("month-narrow/relative", "-1=vorige maand;0=deze maand;1=volgende maand"),
+            (
+                "month-short",
+                "one=over {0} maand;other=over {0} maanden|one={0} maand geleden;other={0} maanden geleden",
+            ),
+            ("month-short/relative", "-1=vorige maand;0=deze maand;1=volgende maand"),
+            ("month/relative", "-1=vorige maand;0=deze maand;1=volgende maand"),
+            (
+                "quarter",
+                "one=over {0} kwartaal;other=over {0} kwartalen|one={0} kwartaal geleden;other={0} kwartalen geleden",
+            ),
+            ("quarter-narrow", "over {0} kw.|{0} kw. geleden"),
+            ("quarter-narrow/relative", "-1=vorig kwartaal;0=dit kwartaal;1=volgend kwartaal"),
+            ("quarter-short", "over {0} kwart.|{0} kwart. geleden"),
+            ("quarter-short/relative", "-1=vorig kwartaal;0=dit kwartaal;1=volgend kwartaal"),
+            ("quarter/relative", "-1=vorig kwartaal;0=dit kwartaal;1=volgend kwartaal"),
+            (
+                "second",
+                "one=over {0} seconde;other=over {0} seconden|one={0} seconde geleden;other={0} seconden geleden",
+            ),
+            ("second-narrow", "over {0} sec.|{0} sec. geleden"),
+            ("second-narrow/relative", "0=nu"),
+            ("second-short", "over {0} sec.|{0} sec. geleden"),
+            ("second-short/relative", "0=nu"),
+            ("second/relative", "0=nu"),
+            (
+                "week",
+                "one=over {0} week;other=over {0} weken|one={0} week geleden;other={0} weken geleden",
+            ),
+            (
+                "week-narrow",
+                "one=over {0} week;other=over {0} weken|one={0} week geleden;other={0} weken geleden",
+            ),
+            ("week-narrow/relative", "-1=vorige week;0=deze week;1=volgende week"),
+            (
+                "week-short",
+                "one=over {0} week;other=over {0} weken|one={0} week geleden;other={0} weken geleden",
+            ),
+            ("week-short/relative", "-1=vorige week;0=deze week;1=volgende week"),
+            ("week/relative", "-1=vorige week;0=deze week;1=volgende week"),
+            ("year", "over {0} jaar|{0} jaar geleden"),
+            ("year-narrow", "over {0} jaar|{0} jaar geleden"),
+            ("year-narrow/relative", "-1=vorig jaar;0=dit jaar;1=volgend jaar"),
+            ("year-short", "over {0} jaar|{0} jaar geleden"),
+            ("year-short/relative", "-1=vorig jaar;0=dit jaar;1=volgend jaar"),
+            ("year/relative", "-1=vorig jaar;0=dit jaar;1=volgend jaar"),
+        ],
+    ),
+    (
+        "pl",
+        &[
+            (
+                "day",
+                "one=za {0} dzień;few=za {0} dni;many=za {0} dni;other=za {0} dnia|one={0} dzień temu;few={0} dni temu;many={0} dni temu;other={0} dnia temu",
+            ),
+            (
+                "day-narrow",
+                "one=za {0} dzień;few=za {0} dni;many=za {0} dni;other=za {0} dnia|one={0} dzień temu;few={0} dni temu;many={0} dni temu;other={0} dnia temu",
+            ),
+            ("day-narrow/relative", "-2=przedwczoraj;-1=wcz.;0=dziś;1=jutro;2=pojutrze"),
+            (
+                "day-short",
+                "one=za {0} dzień;few=za {0} dni;many=za {0} dni;other=za {0} dnia|one={0} dzień temu;few={0} dni temu;many={0} dni temu;other={0} dnia temu",
+            ),
+            ("day-short/relative", "-2=przedwczoraj;-1=wczoraj;0=dzisiaj;1=jutro;2=pojutrze"),
+            ("day/relative", "-2=przedwczoraj;-1=wczoraj;0=dzisiaj;1=jutro;2=pojutrze"),
+            (
+                "hour",
+                "one=za {0} godzinę;many=za {0} godzin;other=za {0} godziny|one={0} godzinę temu;many={0} godzin temu;other={0} godziny temu",
+            ),
+            ("hour-narrow", "za {0} g.|{0} g. temu"),
+            ("hour-narrow/relative", "0=ta godzina"),
+            ("hour-short", "za {0} godz.|{0} godz. temu"),
+            ("hour-short/relative", "0=ta godzina"),
+            ("hour/relative", "0=ta godzina"),
+            (
+                "minute",
+                "one=za {0} minutę;many=za {0} minut;other=za {0} minuty|one={0} minutę temu;many={0} minut temu;other={0} minuty temu",
+            ),
+            ("minute-narrow", "za {0} min|{0} min temu"),
+            ("minute-narrow/relative", "0=ta minuta"),
+            ("minute-short", "za {0} min|{0} min temu"),
+            ("minute-short/relative", "0=ta minuta"),
+            ("minute/relative", "0=ta minuta"),
+            (
+                "month",
+                "one=za {0} miesiąc;few=za {0} miesiące;many=za {0} miesięcy;other=za {0} miesiąca|one={0} miesiąc temu;few={0} miesiące temu;many={0} miesięcy temu;other={0} miesiąca temu",
+            ),
+            ("month-narrow", "za {0} mies.|{0} mies. temu"),
+            ("month-narrow/relative", "-1=w zeszłym mies.;0=w tym mies.;1=w przyszłym mies."),
+            ("month-short", "za {0} mies.|{0} mies. temu"),
+            ("month-short/relative", "-1=w zeszłym mies.;0=w tym mies.;1=w przyszłym mies."),
+            ("month/relative", "-1=w zeszłym miesiącu;0=w tym miesiącu;1=w przyszłym miesiącu"),
+            (
+                "quarter",
+                "one=za {0} kwartał;few=za {0} kwartały;many=za {0} kwartałów;other=za {0} kwartału|one={0} kwartał temu;few={0} kwartały temu;many={0} kwartałów temu;other={0} kwartału temu",
+            ),
+            ("quarter-narrow", "za {0} kw.|{0} kw. temu"),
+            (
+                "quarter-narrow/relative",
+                "-1=w zeszłym kwartale;0=w tym kwartale;1=w przyszłym kwartale",
+            ),
+            ("quarter-short", "za {0} kw.|{0} kw. temu"),
+            (
+                "quarter-short/relative",
+                "-1=w zeszłym kwartale;0=w tym kwartale;1=w przyszłym kwartale",
+            ),
+            ("quarter/relative", "-1=w zeszłym kwartale;0=w tym kwartale;1=w przyszłym kwartale"),
+            (
+                "second",
+                "one=za {0} sekundę;many=za {0} sekund;other=za {0} sekundy|one={0} sekundę temu;many={0} sekund temu;other={0} sekundy temu",
+            ),
+            ("second-narrow", "za {0} s|{0} s temu"),
+            ("second-narrow/relative", "0=teraz"),
+            ("second-short", "za {0} sek.|{0} sek. temu"),
+            ("second-short/relative", "0=teraz"),
+            ("second/relative", "0=teraz"),
+            (
+                "week",
+                "one=za {0} tydzień;few=za {0} tygodnie;many=za {0} tygodni;other=za {0} tygodnia|one={0} tydzień temu;few={0} tygodnie temu;many={0} tygodni temu;other={0} tygodnia temu",
+            ),
+            (
+                "week-narrow",
+                "one=za {0} tydz.;other=za {0} tyg.|one={0} tydz. temu;other={0} tyg. temu",
+            ),
+            ("week-narrow/relative", "-1=w zeszłym tyg.;0=w tym tyg.;1=w przyszłym tyg."),
+            (
+                "week-short",
+                "one=za {0} tydz.;other=za {0} tyg.|one={0} tydz. temu;other={0} tyg. temu",
+            ),
+            ("week-short/relative", "-1=w zeszłym tyg.;0=w tym tyg.;1=w przyszłym tyg."),
+            ("week/relative", "-1=w zeszłym tygodniu;0=w tym tygodniu;1=w przyszłym tygodniu"),
+            (
+                "year",
+                "one=za {0} rok;few=za {0} lata;many=za {0} lat;other=za {0} roku|one={0} rok temu;few={0} lata temu;many={0} lat temu;other={0} roku temu",
+            ),
+            (
+                "year-narrow",
+                "one=za {0} rok;few=za {0} lata;many=za {0} lat;other=za {0} roku|one={0} rok temu;few={0} lata temu;many={0} lat temu;other={0} roku temu",
+            ),
+            ("year-narrow/relative", "-1=w zeszłym roku;0=w tym roku;1=w przyszłym roku"),
+            (
+                "year-short",
+                "one=za {0} rok;few=za {0} lata;many=za {0} lat;other=za {0} roku|one={0} rok temu;few={0} lata temu;many={0} lat temu;other={0} roku temu",
+            ),
+            ("year-short/relative", "-1=w zeszłym roku;0=w tym roku;1=w przyszłym roku"),
+            ("year/relative", "-1=w zeszłym roku;0=w tym roku;1=w przyszłym roku"),
+        ],
+    ),
+    (
+        "pt",
+        &[
+            ("day", "one=em {0} dia;other=em {0} dias|one=há {0} dia;other=há {0} dias"),
+            ("day-narrow", "one=em {0} dia;other=em {0} dias|one=há {0} dia;other=há {0} dias"),
+            ("day-narrow/relative", "-2=anteontem;2=depois de amanhã"),
+            ("day-short", "one=em {0} dia;other=em {0} dias|one=há {0} dia;other=há {0} dias"),
+            ("day-short/relative", "-2=anteontem;-1=ontem;0=hoje;1=amanhã;2=depois de amanhã"),
+            ("day/relative", "-2=anteontem;-1=ontem;0=hoje;1=amanhã;2=depois de amanhã"),
+            ("hour", "one=em {0} hora;other=em {0} horas|one=há {0} hora;other=há {0} horas"),
+            ("hour-narrow", "em {0} h|há {0} h"),
+            ("hour-narrow/relative", "0=esta hora"),
+            ("hour-short", "em {0} h|há {0} h"),
+            ("hour-short/relative", "0=esta hora"),
+            ("hour/relative", "0=esta hora"),
+            (
+                "minute",
+                "one=em {0} minuto;other=em {0} minutos|one=há {0} minuto;other=há {0} minutos",
+            ),
+            ("minute-narrow", "em {0} min.|há {0} min."),
+            ("minute-narrow/relative", "0=este minuto"),
+            ("minute-short", "em {0} min.|há {0} min."),
+            ("minute-short/relative", "0=este minuto"),
+            ("minute/relative", "0=este minuto"),
+            ("month", "one=em {0} mês;other=em {0} meses|one=há {0} mês;other=há {0} meses"),
+            ("month-narrow", "one=em {0} mês;other=em {0} meses|one=há {0} mês;other=há {0} meses"),
+            ("month-narrow/relative", "-1=mês passado;0=este mês;1=próximo mês"),
+            ("month-short", "one=em {0} mês;other=em {0} meses|one=há {0} mês;other=há {0} meses"),
+            ("month-short/relative", "-1=mês passado;0=este mês;1=próximo mês"),
+            ("month/relative", "-1=mês passado;0=este mês;1=próximo mês"),
+            (
+                "quarter",
+                "one=em {0} trimestre;other=em {0} trimestres|one=há {0} trimestre;other=há {0} trimestres",
+            ),
+            ("quarter-narrow", "em {0} trim.|há {0} trim."),
+            ("quarter-narrow/relative", "-1=último trimestre;0=este trimestre;1=próximo trimestre"),
+            ("quarter-short", "em {0} trim.|há {0} trim."),
+            ("quarter-short/relative", "-1=último trimestre;0=este trimestre;1=próximo trimestre"),
+            ("quarter/relative", "-1=último trimestre;0=este trimestre;1=próximo trimestre"),
+            (
+                "second",
+                "one=em {0} segundo;other=em {0} segundos|one=há {0} segundo;other=há {0} segundos",
+            ),
+            ("second-narrow", "em {0} seg.|há {0} seg."),
+            ("second-narrow/relative", "0=agora"),
+            ("second-short", "em {0} seg.|há {0} seg."),
+            ("second-short/relative", "0=agora"),
+            ("second/relative", "0=agora"),
+            (
+                "week",
+                "one=em {0} semana;other=em {0} semanas|one=há {0} semana;other=há {0} semanas",
+            ),
+            ("week-narrow", "em {0} sem.|há {0} sem."),
+            ("week-narrow/relative", "-1=semana passada;0=esta semana;1=próxima semana"),
+            ("week-short", "em {0} sem.|há {0} sem."),
+            ("week-short/relative", "-1=semana passada;0=esta semana;1=próxima semana"),
+            ("week/relative", "-1=semana passada;0=esta semana;1=próxima semana"),
+            ("year", "one=em {0} ano;other=em {0} anos|one=há {0} ano;other=há {0} anos"),
+            ("year-narrow", "one=em {0} ano;other=em {0} anos|one=há {0} ano;other=há {0} anos"),
+            ("year-narrow/relative", "-1=ano passado;0=este ano;1=próximo ano"),
+            ("year-short", "one=em {0} ano;other=em {0} anos|one=há {0} ano;other=há {0} anos"),
+            ("year-short/relative", "-1=ano passado;0=este ano;1=próximo ano"),
+            ("year/relative", "-1=ano passado;0=este ano;1=próximo ano"),
+        ],
+    ),
+    (
+        "pt-PT",
+        &[
+            (
+                "day",
+                "one=dentro de {0} dia;other=dentro de {0} dias|one=há {0} dia;other=há {0} dias",
+            ),
+            ("day-narrow", "one=+{0} dia;other=+{0} dias|one=-{0} dia;other=-{0} dias"),
+            ("day-narrow/relative", "-2=anteontem;-1=ontem;0=hoje;1=amanhã;2=depois de amanhã"),
+            (
+                "day-short",
+                "one=dentro de {0} dia;other=dentro de {0} dias|one=há {0} dia;other=há {0} dias",
+            ),
+            (
+                "hour",
+                "one=dentro de {0} hora;other=dentro de {0} horas|one=há {0} hora;other=há {0} horas",
+            ),
+            ("hour-narrow", "+{0} h|-{0} h"),
+            ("hour-short", "dentro de {0} h|há {0} h"),
+            (
+                "minute",
+                "one=dentro de {0} minuto;other=dentro de {0} minutos|one=há {0} minuto;other=há {0} minutos",
+            ),
+            ("minute-narrow", "+{0} min|-{0} min"),
+            ("minute-short", "dentro de {0} min|há {0} min"),
+            (
+                "month",
+                "one=dentro de {0} mês;other=dentro de {0} meses|one=há {0} mês;other=há {0} meses",
+            ),
+            ("month-narrow", "one=+{0} mês;other=+{0} meses|one=-{0} mês;other=-{0} meses"),
+            (
+                "month-short",
+                "one=dentro de {0} mês;other=dentro de {0} meses|one=há {0} mês;other=há {0} meses",
+            ),
+            (
+                "quarter",
+                "one=dentro de {0} trimestre;other=dentro de {0} trimestres|one=há {0} trimestre;other=há {0} trimestres",
+            ),
+            ("quarter-narrow", "+{0} trim.|-{0} trim."),
+            ("quarter-narrow/relative", "-1=trim. passado;0=este trim.;1=próximo trim."),
+            ("quarter-short", "dentro de {0} trim.|há {0} trim."),
+            ("quarter-short/relative", "-1=trim. passado;0=este trim.;1=próximo trim."),
+            ("quarter/relative", "-1=trimestre passado;0=este trimestre;1=próximo trimestre"),
+            (
+                "second",
+                "one=dentro de {0} segundo;other=dentro de {0} segundos|one=há {0} segundo;other=há {0} segundos",
+            ),
+            ("second-narrow", "+{0} s|-{0} s"),
+            ("second-short", "dentro de {0} s|há {0} s"),
+            (
+                "week",
+                "one=dentro de {0} semana;other=dentro de {0} semanas|one=há {0} semana;other=há {0} semanas",
+            ),
+            ("week-narrow", "+{0} sem.|-{0} sem."),
+            ("week-short", "dentro de {0} sem.|há {0} sem."),
+            (
+                "year",
+                "one=dentro de {0} ano;other=dentro de {0} anos|one=há {0} ano;other=há {0} anos",
+            ),
+            ("year-narrow", "one=+{0} ano;other=+{0} anos|one=-{0} ano;other=-{0} anos"),
+            (
+                "year-short",
+                "one=dentro de {0} ano;other=dentro de {0} anos|one=há {0} ano;other=há {0} anos",
+            ),
+        ],
+    ),
+    (
+        "ru",
+        &[
+            (
+                "day",
+                "one=через {0} день;many=через {0} дней;other=через {0} дня|one={0} день назад;many={0} дней назад;other={0} дня назад",
+            ),
+            ("day-narrow", "+{0} дн.|-{0} дн."),
+            ("day-narrow/relative", "-2=позавчера;-1=вчера;0=сегодня;1=завтра;2=послезавтра"),
+            ("day-short", "через {0} дн.|{0} дн. назад"),
+            ("day-short/relative", "-2=позавчера;-1=вчера;0=сегодня;1=завтра;2=послезавтра"),
+            ("day/relative", "-2=позавчера;-1=вчера;0=сегодня;1=завтра;2=послезавтра"),
+            (
+                "hour",
+                "one=через {0} час;many=через {0} часов;other=через {0} часа|one={0} час назад;many={0} часов назад;other={0} часа назад",
+            ),
+            ("hour-narrow", "+{0} ч|-{0} ч"),
+            ("hour-narrow/relative", "0=в этот час"),
+            ("hour-short", "через {0} ч|{0} ч назад"),
+            ("hour-short/relative", "0=в этот час"),
+            ("hour/relative", "0=в этот час"),
+            (
+                "minute",
+                "one=через {0} минуту;many=через {0} минут;other=через {0} минуты|one={0} минуту назад;many={0} минут назад;other={0} минуты назад",
+            ),
+            ("minute-narrow", "+{0} мин|-{0} мин"),
+            ("minute-narrow/relative", "0=в эту минуту"),
+            ("minute-short", "через {0} мин.|{0} мин. назад"),
+            ("minute-short/relative", "0=в эту минуту"),
+            ("minute/relative", "0=в эту минуту"),
+            (
+                "month",
+                "one=через {0} месяц;many=через {0} месяцев;other=через {0} месяца|one={0} месяц назад;many={0} месяцев назад;other={0} месяца назад",
+            ),
+            ("month-narrow", "+{0} мес.|-{0} мес."),
+            ("month-narrow/relative", "-1=в пр. мес.;0=в эт. мес.;1=в след. мес."),
+            ("month-short", "через {0} мес.|{0} мес. назад"),
+            ("month-short/relative", "-1=в прошлом мес.;0=в этом мес.;1=в следующем мес."),
+            ("month/relative", "-1=в прошлом месяце;0=в этом месяце;1=в следующем месяце"),
+            (
+                "quarter",
+                "one=через {0} квартал;many=через {0} кварталов;other=через {0} квартала|one={0} квартал назад;many={0} кварталов назад;other={0} квартала назад",
+            ),
+            ("quarter-narrow", "+{0} кв.|-{0} кв."),
+            ("quarter-narrow/relative", "-1=посл. кв.;0=тек. кв.;1=след. кв."),
+            ("quarter-short", "через {0} кв.|{0} кв. назад"),
+            ("quarter-short/relative", "-1=последний кв.;0=текущий кв.;1=следующий кв."),
+            (
+                "quarter/relative",
+                "-1=в прошлом квартале;0=в текущем квартале;1=в следующем квартале",
+            ),
+            (
+                "second",
+                "one=через {0} секунду;many=через {0} секунд;other=через {0} секунды|one={0} секунду назад;many={0} секунд назад;other={0} секунды назад",
+            ),
+            ("second-narrow", "+{0} с|-{0} с"),
+            ("second-narrow/relative", "0=сейчас"),
+            ("second-short", "через {0}\u{a0}сек.|{0} сек. назад"),
+            ("second-short/relative", "0=сейчас"),
+            ("second/relative", "0=сейчас"),
+            (
+                "week",
+                "one=через {0} неделю;many=через {0} недель;other=через {0} недели|one={0} неделю назад;many={0} недель назад;other={0} недели назад",
+            ),
+            ("week-narrow", "+{0} нед.|-{0} нед."),
+            ("week-narrow/relative", "-1=на пр. нед.;0=на эт. нед.;1=на след. нед."),
+            ("week-short", "через {0} нед.|{0} нед. назад"),
+            ("week-short/relative", "-1=на прошлой нед.;0=на этой нед.;1=на следующей нед."),
+            ("week/relative", "-1=на прошлой неделе;0=на этой неделе;1=на следующей неделе"),
+            (
+                "year",
+                "one=через {0} год;many=через {0} лет;other=через {0} года|one={0} год назад;many={0} лет назад;other={0} года назад",
+            ),
+            ("year-narrow", "many=+{0} л.;other=+{0} г.|many=-{0} л.;other=-{0} г."),
+            ("year-narrow/relative", "-1=в пр. г.;0=в эт. г.;1=в сл. г."),
+            (
+                "year-short",
+                "many=через {0} л.;other=через {0} г.|many={0} л. назад;other={0} г. назад",
+            ),
+            ("year-short/relative", "-1=в прошлом г.;0=в этом г.;1=в след. г."),
+            ("year/relative", "-1=в прошлом году;0=в этом году;1=в следующем году"),
+        ],
+    ),
+    (
+        "sv",
+        &[
+            (
+                "day",
+                "one=om {0} dag;other=om {0} dagar|one=för {0} dag sedan;other=för {0} dagar sedan",
+            ),
+            ("day-narrow", "+{0} d|−{0} d"),
+            ("day-narrow/relative", "-2=i förrgår;-1=igår;0=idag;1=imorgon;2=i övermorgon"),
+            ("day-short", "om {0} d|one=för {0} d sedan;other=för\u{a0}{0}\u{a0}d sedan"),
+            ("day-short/relative", "-2=i förrgår;-1=i går;0=i dag;1=i morgon;2=i övermorgon"),
+            ("day/relative", "-2=i förrgår;-1=i går;0=i dag;1=i morgon;2=i övermorgon"),
+            (
+                "hour",
+                "one=om {0} timme;other=om {0} timmar|one=för {0} timme sedan;other=för {0} timmar sedan",
+            ),
+            ("hour-narrow", "+{0} h|−{0} h"),
+            ("hour-narrow/relative", "0=denna timme"),
+            ("hour-short", "om {0} tim|för {0} tim sedan"),
+            ("hour-short/relative", "0=denna timme"),
+            ("hour/relative", "0=denna timme"),
+            (
+                "minute",
+                "one=om {0} minut;other=om {0} minuter|one=för {0} minut sedan;other=för {0} minuter sedan",
+            ),
+            ("minute-narrow", "+{0} min|−{0} min"),
+            ("minute-narrow/relative", "0=denna minut"),
+            ("minute-short", "om\u{a0}{0} min|för {0} min sen"),
+            ("minute-short/relative", "0=denna minut"),
+            ("minute/relative", "0=denna minut"),
+            (
+                "month",
+                "one=om {0} månad;other=om {0} månader|one=för {0} månad sedan;other=för {0} månader sedan",
+            ),
+            ("month-narrow", "+{0} mån.|−{0} mån"),
+            ("month-narrow/relative", "-1=förra mån.;0=denna mån.;1=nästa mån."),
+            ("month-short", "om {0} mån.|för {0} mån. sen"),
+            ("month-short/relative", "-1=förra mån.;0=denna mån.;1=nästa mån."),
+            ("month/relative", "-1=förra månaden;0=denna månad;1=nästa månad"),
+            ("quarter", "om {0} kvartal|för {0} kvartal sedan"),
+            ("quarter-narrow", "+{0} kv.|−{0} kv"),
+            ("quarter-narrow/relative", "-1=förra kv.;0=detta kv.;1=nästa kv."),
+            ("quarter-short", "om {0} kv.|för {0} kv. sen"),
+            ("quarter-short/relative", "-1=förra kv.;0=detta kv.;1=nästa kv."),
+            ("quarter/relative", "-1=förra kvartalet;0=detta kvartal;1=nästa kvartal"),
+            (
+                "second",
+                "one=om {0} sekund;other=om {0} sekunder|one=för {0} sekund sedan;other=för {0} sekunder sedan",
+            ),
+            ("second-narrow", "+{0} s|−{0} s"),
+            ("second-narrow/relative", "0=nu"),
+            ("second-short", "one=om\u{a0}{0} sek;other=om {0} sek|för {0} s sen"),
+            ("second-short/relative", "0=nu"),
+            ("second/relative", "0=nu"),
+            (
+                "week",
+                "one=om {0} vecka;other=om {0} veckor|one=för {0} vecka sedan;other=för {0} veckor sedan",
+            ),
+            ("week-narrow", "+{0} v.|−{0} v"),
+            ("week-narrow/relative", "-1=förra v.;0=denna v.;1=nästa v."),
+            ("week-short", "om {0} v.|för {0} v. sedan"),
+            ("week-short/relative", "-1=förra v.;0=denna v.;1=nästa v."),
+            ("week/relative", "-1=förra veckan;0=denna vecka;1=nästa vecka"),
+            ("year", "om {0} år|för {0} år sedan"),
+            ("year-narrow", "+{0} år|−{0} år"),
+            ("year-narrow/relative", "-1=i fjol;0=i år;1=nästa år"),
+            ("year-short", "om {0} år|för {0} år sen"),
+            ("year-short/relative", "-1=i fjol;0=i år;1=nästa år"),
+            ("year/relative", "-1=i fjol;0=i år;1=nästa år"),
+        ],
+    ),
+    (
+        "th",
+        &[
+            ("day", "ในอีก {0} วัน|{0} วันที่ผ่านมา"),
+            ("day-narrow", "ใน {0} วัน|{0} วันที่แล้ว"),
+            ("day-narrow/relative", "-2=เมื่อวานซืน;-1=เมื่อวาน;0=วันนี้;1=พรุ่งนี้;2=มะรืนนี้"),
+            ("day-short", "ใน {0} วัน|{0} วันที่แล้ว"),
+            ("day-short/relative", "-2=เมื่อวานซืน;-1=เมื่อวาน;0=วันนี้;1=พรุ่งนี้;2=มะรืนนี้"),
+            ("day/relative", "-2=เมื่อวานซืน;-1=เมื่อวาน;0=วันนี้;1=พรุ่งนี้;2=มะรืนนี้"),
+            ("hour", "ในอีก {0} ชั่วโมง|{0} ชั่วโมงที่ผ่านมา"),
+            ("hour-narrow", "ใน {0} ชม.|{0} ชม. ที่แล้ว"),
+            ("hour-narrow/relative", "0=ชั่วโมงนี้"),
+            ("hour-short", "ใน {0} ชม.|{0} ชม. ที่แล้ว"),
+            ("hour-short/relative", "0=ชั่วโมงนี้"),
+            ("hour/relative", "0=ชั่วโมงนี้"),
+            ("minute", "ในอีก {0} นาที|{0} นาทีที่ผ่านมา"),
+            ("minute-narrow", "ใน {0} นาที|{0} นาทีที่แล้ว"),
+            ("minute-narrow/relative", "0=นาทีนี้"),
+            ("minute-short", "ใน {0} นาที|{0} นาทีที่แล้ว"),
+            ("minute-short/relative", "0=นาทีนี้"),
+            ("minute/relative", "0=นาทีนี้"),
+            ("month", "ในอีก {0} เดือน|{0} เดือนที่ผ่านมา"),
+            ("month-narrow", "ใน {0} เดือน|{0} เดือนที่แล้ว"),
+            ("month-narrow/relative", "-1=เดือนที่แล้ว;0=เดือนนี้;1=เดือนหน้า"),
+            ("month-short", "ใน {0} เดือน|{0} เดือนที่แล้ว"),
+            ("month-short/relative", "-1=เดือนที่แล้ว;0=เดือนนี้;1=เดือนหน้า"),
+            ("month/relative", "-1=เดือนที่แล้ว;0=เดือนนี้;1=เดือนหน้า"),
+            ("quarter", "ในอีก {0} ไตรมาส|{0} ไตรมาสที่แล้ว"),
+            ("quarter-narrow", "ใน {0} ไตรมาส|{0} ไตรมาสที่แล้ว"),
+            ("quarter-narrow/relative", "-1=ไตรมาสที่แล้ว;0=ไตรมาสนี้;1=ไตรมาสหน้า"),
+            ("quarter-short", "ใน {0} ไตรมาส|{0} ไตรมาสที่แล้ว"),
+            ("quarter-short/relative", "-1=ไตรมาสที่แล้ว;0=ไตรมาสนี้;1=ไตรมาสหน้า"),
+            ("quarter/relative", "-1=ไตรมาสที่แล้ว;0=ไตรมาสนี้;1=ไตรมาสหน้า"),
+            ("second", "ในอีก {0} วินาที|{0} วินาทีที่ผ่านมา"),
+            ("second-narrow", "ใน {0} วินาที|{0} วินาทีที่แล้ว"),
+            ("second-narrow/relative", "0=ขณะนี้"),
+            ("second-short", "ใน {0} วินาที|{0} วินาทีที่แล้ว"),
+            ("second-short/relative", "0=ขณะนี้"),
+            ("second/relative", "0=ขณะนี้"),
+            ("week", "ในอีก {0} สัปดาห์|{0} สัปดาห์ที่ผ่านมา"),
+            ("week-narrow", "ใน {0} สัปดาห์|{0} สัปดาห์ที่แล้ว"),
+            ("week-narrow/relative", "-1=สัปดาห์ที่แล้ว;0=สัปดาห์นี้;1=สัปดาห์หน้า"),
+            ("week-short", "ใน {0} สัปดาห์|{0} สัปดาห์ที่แล้ว"),
+            ("week-short/relative", "-1=สัปดาห์ที่แล้ว;0=สัปดาห์นี้;1=สัปดาห์หน้า"),
+            ("week/relative", "-1=สัปดาห์ที่แล้ว;0=สัปดาห์นี้;1=สัปดาห์หน้า"),
+            ("year", "ในอีก {0} ปี|{0} ปีที่แล้ว"),
+            ("year-narrow", "ใน {0} ปี|{0} ปีที่แล้ว"),
+            ("year-narrow/relative", "-1=ปีที่แล้ว;0=ปีนี้;1=ปีหน้า"),
+            ("year-short", "ใน {0} ปี|{0} ปีที่แล้ว"),
+            ("year-short/relative", "-1=ปีที่แล้ว;0=ปีนี้;1=ปีหน้า"),
+            ("year/relative", "-1=ปีที่แล้ว;0=ปีนี้;1=ปีหน้า"),
+        ],
+    ),
+    (
+        "tr",
+        &[
+            ("day", "{0} gün sonra|{0} gün önce"),
+            ("day-narrow", "{0} gün sonra|{0} gün önce"),
+            ("day-narrow/relative", "-2=evvelsi gün;-1=dün;0=bugün;1=yarın;2=öbür gün"),
+            ("day-short", "{0} gün sonra|{0} gün önce"),
+            ("day-short/relative", "-2=evvelsi gün;-1=dün;0=bugün;1=yarın;2=öbür gün"),
+            ("day/relative", "-2=evvelsi gün;-1=dün;0=bugün;1=yarın;2=öbür gün"),
+            ("hour", "{0} saat sonra|{0} saat önce"),
+            ("hour-narrow", "{0} sa. sonra|{0} sa. önce"),
+            ("hour-narrow/relative", "0=bu saat"),
+            ("hour-short", "{0} sa. sonra|{0} sa. önce"),
+            ("hour-short/relative", "0=bu saat"),
+            ("hour/relative", "0=bu saat"),
+            ("minute", "{0} dakika sonra|{0} dakika önce"),
+            ("minute-narrow", "{0} dk. sonra|{0} dk. önce"),
+            ("minute-narrow/relative", "0=bu dakika"),
+            ("minute-short", "{0} dk. sonra|{0} dk. önce"),
+            ("minute-short/relative", "0=bu dakika"),
+            ("minute/relative", "0=bu dakika"),
+            ("month", "{0} ay sonra|{0} ay önce"),
+            ("month-narrow", "{0} ay sonra|{0} ay önce"),
+            ("month-narrow/relative", "-1=geçen ay;0=bu ay;1=gelecek ay"),
+            ("month-short", "{0} ay sonra|{0} ay önce"),
+            ("month-short/relative", "-1=geçen ay;0=bu ay;1=gelecek ay"),
+            ("month/relative", "-1=geçen ay;0=bu ay;1=gelecek ay"),
+            ("quarter", "{0} çeyrek sonra|{0} çeyrek önce"),
+            ("quarter-narrow", "{0} çyr. sonra|{0} çyr. önce"),
+            ("quarter-narrow/relative", "-1=geçen çyr.;0=bu çyr.;1=gelecek çyr."),
+            ("quarter-short", "{0} çyr. sonra|{0} çyr. önce"),
+            ("quarter-short/relative", "-1=geçen çyr.;0=bu çyr.;1=gelecek çyr."),
+            ("quarter/relative", "-1=geçen çeyrek;0=bu çeyrek;1=gelecek çeyrek"),
+            ("second", "{0} saniye sonra|{0} saniye önce"),
+            ("second-narrow", "{0} sn. sonra|{0} sn. önce"),
+            ("second-narrow/relative", "0=şimdi"),
+            ("second-short", "{0} sn. sonra|{0} sn. önce"),
+            ("second-short/relative", "0=şimdi"),
+            ("second/relative", "0=şimdi"),
+            ("week", "{0} hafta sonra|{0} hafta önce"),
+            ("week-narrow", "{0} hf. sonra|{0} hf. önce"),
+            ("week-narrow/relative", "-1=geçen hf.;0=bu hf.;1=gelecek hf."),
+            ("week-short", "{0} hf. sonra|{0} hf. önce"),
+            ("week-short/relative", "-1=geçen hf.;0=bu hf.;1=gelecek hf."),
+            ("week/relative", "-1=geçen hafta;0=bu hafta;1=gelecek hafta"),
+            ("year", "{0} yıl sonra|{0} yıl önce"),
+            ("year-narrow", "{0} yıl sonra|{0} yıl önce"),
+            ("year-narrow/relative", "-1=geçen yıl;0=bu yıl;1=gelecek yıl"),
+            ("year-short", "{0} yıl sonra|{0} yıl önce"),
+            ("year-short/relative", "-1=geçen yıl;0=bu yıl;1=gelecek yıl"),
+            ("year/relative", "-1=geçen yıl;0=bu yıl;1=gelecek yıl"),
+        ],
+    ),
+    (
+        "uk",
+        &[
+            (
+                "day",
+                "one=через {0} день;few=через {0} дні;many=через {0} днів;other=через {0} дня|one={0} день тому;few={0} дні тому;many={0} днів тому;other={0} дня тому",
+            ),
+            ("day-narrow", "за {0} д.|{0} дн. тому"),
+            ("day-narrow/relative", "-2=позавчора;-1=учора;0=сьогодні;1=завтра;2=післязавтра"),
+            ("day-short", "через {0} дн.|{0} дн. тому"),
+            ("day-short/relative", "-2=позавчора;-1=учора;0=сьогодні;1=завтра;2=післязавтра"),
+            ("day/relative", "-2=позавчора;-1=учора;0=сьогодні;1=завтра;2=післязавтра"),
+            (
+                "hour",
+                "one=через {0} годину;many=через {0} годин;other=через {0} години|one={0} годину тому;many={0} годин тому;other={0} години тому",
+            ),
+            ("hour-narrow", "за {0} год|{0} год тому"),
+            ("hour-narrow/relative", "0=цієї години"),
+            ("hour-short", "через {0} год|{0} год тому"),
+            ("hour-short/relative", "0=цієї години"),
+            ("hour/relative", "0=цієї години"),
+            (
+                "minute",
+                "one=через {0} хвилину;many=через {0} хвилин;other=через {0} хвилини|one={0} хвилину тому;many={0} хвилин тому;other={0} хвилини тому",
+            ),
+            ("minute-narrow", "за {0} хв|{0} хв тому"),
+            ("minute-narrow/relative", "0=цієї хвилини"),
+            ("minute-short", "через {0} хв|{0} хв тому"),
+            ("minute-short/relative", "0=цієї хвилини"),
+            ("minute/relative", "0=цієї хвилини"),
+            (
+                "month",
+                "one=через {0} місяць;few=через {0} місяці;many=через {0} місяців;other=через {0} місяця|one={0} місяць тому;few={0} місяці тому;many={0} місяців тому;other={0} місяця тому",
+            ),
+            ("month-narrow", "за {0} міс.|{0} міс. тому"),
+            ("month-narrow/relative", "-1=мин. міс.;0=цього міс.;1=наст. міс."),
+            ("month-short", "через {0} міс.|{0} міс. тому"),
+            ("month-short/relative", "-1=минулого місяця;0=цього місяця;1=наступного місяця"),
+            ("month/relative", "-1=минулого місяця;0=цього місяця;1=наступного місяця"),
+            (
+                "quarter",
+                "one=через {0} квартал;few=через {0} квартали;many=через {0} кварталів;other=через {0} кварталу|one={0} квартал тому;few={0} квартали тому;many={0} кварталів тому;other={0} кварталу тому",
+            ),
+            ("quarter-narrow", "за {0} кв.|{0} кв. тому"),
+            ("quarter-narrow/relative", "-1=минулого кв.;0=цього кв.;1=наступного кв."),
+            ("quarter-short", "через {0} кв.|{0} кв. тому"),
+            ("quarter-short/relative", "-1=минулого кв.;0=цього кв.;1=наступного кв."),
+            ("quarter/relative", "-1=минулого кварталу;0=цього кварталу;1=наступного кварталу"),
+            (
+                "second",
+                "one=через {0} секунду;many=через {0} секунд;other=через {0} секунди|one={0} секунду тому;many={0} секунд тому;other={0} секунди тому",
+            ),
+            ("second-narrow", "за {0} с|{0} с тому"),
+            ("second-narrow/relative", "0=зараз"),
+            ("second-short", "через {0} с|{0} с тому"),
+            ("second-short/relative", "0=зараз"),
+            ("second/relative", "0=зараз"),
+            (
+                "week",
+                "one=через {0} тиждень;few=через {0} тижні;many=через {0} тижнів;other=через {0} тижня|one={0} тиждень тому;few={0} тижні тому;many={0} тижнів тому;other={0} тижня тому",
+            ),
+            ("week-narrow", "за {0} тиж.|{0} тиж. тому"),
+            ("week-narrow/relative", "-1=минулого тижня;0=цього тижня;1=наступного тижня"),
+            ("week-short", "через {0} тиж.|{0} тиж. тому"),
+            ("week-short/relative", "-1=мин. тижня;0=цього тижня;1=наст. тижня"),
+            ("week/relative", "-1=минулого тижня;0=цього тижня;1=наступного тижня"),
+            (
+                "year",
+                "one=через {0} рік;few=через {0} роки;many=через {0} років;other=через {0} року|one={0} рік тому;few={0} роки тому;many={0} років тому;other={0} року тому",
+            ),
+            ("year-narrow", "за {0} р.|{0} р. тому"),
+            ("year-narrow/relative", "-1=торік;0=цього року;1=наст. р."),
+            ("year-short", "через {0} р.|{0} р. тому"),
+            ("year-short/relative", "-1=торік;0=цьогоріч;1=наст. року"),
+            ("year/relative", "-1=минулого року;0=цього року;1=наступного року"),
+        ],
+    ),
+    (
+        "vi",
+        &[
+            ("day", "sau {0} ngày nữa|{0} ngày trước"),
+            ("day-narrow", "sau {0} ngày nữa|{0} ngày trước"),
+            ("day-narrow/relative", "-2=Hôm kia;-1=hôm qua;0=hôm nay;1=ngày mai;2=Ngày kia"),
+            ("day-short", "sau {0} ngày nữa|{0} ngày trước"),
+            ("day-short/relative", "-2=Hôm kia;-1=hôm qua;0=hôm nay;1=ngày mai;2=Ngày kia"),
+            ("day/relative", "-2=Hôm kia;-1=Hôm qua;0=Hôm nay;1=Ngày mai;2=Ngày kia"),
+            ("hour", "sau {0} giờ nữa|{0} giờ trước"),
+            ("hour-narrow", "sau {0} giờ nữa|{0} giờ trước"),
+            ("hour-narrow/relative", "0=giờ này"),
+            ("hour-short", "sau {0} giờ nữa|{0} giờ trước"),
+            ("hour-short/relative", "0=giờ này"),
+            ("hour/relative", "0=giờ này"),
+            ("minute", "sau {0} phút nữa|{0} phút trước"),
+            ("minute-narrow", "sau {0} phút nữa|{0} phút trước"),
+            ("minute-narrow/relative", "0=phút này"),
+            ("minute-short", "sau {0} phút nữa|{0} phút trước"),
+            ("minute-short/relative", "0=phút này"),
+            ("minute/relative", "0=phút này"),
+            ("month", "sau {0} tháng nữa|{0} tháng trước"),
+            ("month-narrow", "sau {0} tháng nữa|{0} tháng trước"),
+            ("month-narrow/relative", "-1=tháng trước;0=tháng này;1=tháng sau"),
+            ("month-short", "sau {0} tháng nữa|{0} tháng trước"),
+            ("month-short/relative", "-1=tháng trước;0=tháng này;1=tháng sau"),
+            ("month/relative", "-1=tháng trước;0=tháng này;1=tháng sau"),
+            ("quarter", "sau {0} quý nữa|{0} quý trước"),
+            ("quarter-narrow", "sau {0} quý nữa|{0} quý trước"),
+            ("quarter-narrow/relative", "-1=quý trước;0=quý này;1=quý sau"),
+            ("quarter-short", "sau {0} quý nữa|{0} quý trước"),
+            ("quarter-short/relative", "-1=quý trước;0=quý này;1=quý sau"),
+            ("quarter/relative", "-1=quý trước;0=quý này;1=quý sau"),
+            ("second", "sau {0} giây nữa|{0} giây trước"),
+            ("second-narrow", "sau {0} giây nữa|{0} giây trước"),
+            ("second-narrow/relative", "0=bây giờ"),
+            ("second-short", "sau {0} giây nữa|{0} giây trước"),
+            ("second-short/relative", "0=bây giờ"),
+            ("second/relative", "0=bây giờ"),
+            ("week", "sau {0} tuần nữa|{0} tuần trước"),
+            ("week-narrow", "sau {0} tuần nữa|{0} tuần trước"),
+            ("week-narrow/relative", "-1=tuần trước;0=tuần này;1=tuần sau"),
+            ("week-short", "sau {0} tuần nữa|{0} tuần trước"),
+            ("week-short/relative", "-1=tuần trước;0=tuần này;1=tuần sau"),
+            ("week/relative", "-1=tuần trước;0=tuần này;1=tuần sau"),
+            ("year", "sau {0} năm nữa|{0} năm trước"),
+            ("year-narrow", "sau {0} năm nữa|{0} năm trước"),
+            ("year-narrow/relative", "-1=năm ngoái;0=năm nay;1=năm sau"),
+            ("year-short", "sau {0} năm nữa|{0} năm trước"),
+            ("year-short/relative", "-1=năm ngoái;0=năm nay;1=năm sau"),
+            ("year/relative", "-1=năm ngoái;0=năm nay;1=năm sau"),
+        ],
+    ),
+    (
+        "zh",
+        &[
+            ("day", "{0}天后|{0}天前"),
+            ("day-narrow", "{0}天后|{0}天前"),
+            ("day-narrow/relative", "-2=前天;-1=昨天;0=今天;1=明天;2=后天"),
+            ("day-short", "{0}天后|{0}天前"),
+            ("day-short/relative", "-2=前天;-1=昨天;0=今天;1=明天;2=后天"),
+            ("day/relative", "-2=前天;-1=昨天;0=今天;1=明天;2=后天"),
+            ("hour", "{0}小时后|{0}小时前"),
+            ("hour-narrow", "{0}小时后|{0}小时前"),
+            ("hour-narrow/relative", "0=这一时间 / 此时"),
+            ("hour-short", "{0}小时后|{0}小时前"),
+            ("hour-short/relative", "0=这一时间 / 此时"),
+            ("hour/relative", "0=这一时间 / 此时"),
+            ("minute", "{0}分钟后|{0}分钟前"),
+            ("minute-narrow", "{0}分钟后|{0}分钟前"),
+            ("minute-narrow/relative", "0=此刻"),
+            ("minute-short", "{0}分钟后|{0}分钟前"),
+            ("minute-short/relative", "0=此刻"),
+            ("minute/relative", "0=此刻"),
+            ("month", "{0}个月后|{0}个月前"),
+            ("month-narrow", "{0}个月后|{0}个月前"),
+            ("month-narrow/relative", "-1=上个月;0=本月;1=下个月"),
+            ("month-short", "{0}个月后|{0}个月前"),
+            ("month-short/relative", "-1=上个月;0=本月;1=下个月"),
+            ("month/relative", "-1=上个月;0=本月;1=下个月"),
+            ("quarter", "{0}个季度后|{0}个季度前"),
+            ("quarter-narrow", "{0}个季度后|{0}个季度前"),
+            ("quarter-narrow/relative", "-1=上季度;0=本季度;1=下季度"),
+            ("quarter-short", "{0}个季度后|{0}个季度前"),
+            ("quarter-short/relative", "-1=上季度;0=本季度;1=下季度"),
+            ("quarter/relative", "-1=上季度;0=本季度;1=下季度"),
+            ("second", "{0}秒钟后|{0}秒钟前"),
+            ("second-narrow", "{0}秒后|{0}秒前"),
+            ("second-narrow/relative", "0=现在"),
+            ("second-short", "{0}秒后|{0}秒前"),
+            ("second-short/relative", "0=现在"),
+            ("second/relative", "0=现在"),
+            ("week", "{0}周后|{0}周前"),
+            ("week-narrow", "{0}周后|{0}周前"),
+            ("week-narrow/relative", "-1=上周;0=本周;1=下周"),
+            ("week-short", "{0}周后|{0}周前"),
+            ("week-short/relative", "-1=上周;0=本周;1=下周"),
+            ("week/relative", "-1=上周;0=本周;1=下周"),
+            ("year", "{0}年后|{0}年前"),
+            ("year-narrow", "{0}年后|{0}年前"),
+            ("year-narrow/relative", "-1=去年;0=今年;1=明年"),
+            ("year-short", "{0}年后|{0}年前"),
+            ("year-short/relative", "-1=去年;0=今年;1=明年"),
+            ("year/relative", "-1=去年;0=今年;1=明年"),
+        ],
+    ),
+    (
+        "zh-Hans-HK",
+        &[
+            ("second", "{0}秒后|{0}秒前"),
+        ],
+    ),
+    (
+        "zh-Hans-MO",
+        &[
+            ("second", "{0}秒后|{0}秒前"),
+        ],
+    ),
+    (
+        "zh-Hans-SG",
+        &[
+            ("second", "{0}秒后|{0}秒前"),
+        ],
+    ),
+    (
+        "zh-Hant",
+        &[
+            ("day", "{0} 天後|{0} 天前"),
+            ("day-narrow", "{0} 天後|{0} 天前"),
+            ("day-narrow/relative", "-2=前天;-1=昨天;0=今天;1=明天;2=後天"),
+            ("day-short", "{0} 天後|{0} 天前"),
+            ("day-short/relative", "-2=前天;-1=昨天;0=今天;1=明天;2=後天"),
+            ("day/relative", "-2=前天;-1=昨天;0=今天;1=明天;2=後天"),
+            ("hour", "{0} 小時後|{0} 小時前"),
+            ("hour-narrow", "{0} 小時後|{0} 小時前"),
+            ("hour-narrow/relative", "0=這一小時"),
+            ("hour-short", "{0} 小時後|{0} 小時前"),
+            ("hour-short/relative", "0=這一小時"),
+            ("hour/relative", "0=這一小時"),
+            ("minute", "{0} 分鐘後|{0} 分鐘前"),
+            ("minute-narrow", "{0} 分鐘後|{0} 分鐘前"),
+            ("minute-narrow/relative", "0=這一分鐘"),
+            ("minute-short", "{0} 分鐘後|{0} 分鐘前"),
+            ("minute-short/relative", "0=這一分鐘"),
+            ("minute/relative", "0=這一分鐘"),
+            ("month", "{0} 個月後|{0} 個月前"),
+            ("month-narrow", "{0} 個月後|{0} 個月前"),
+            ("month-narrow/relative", "-1=上個月;0=本月;1=下個月"),
+            ("month-short", "{0} 個月後|{0} 個月前"),
+            ("month-short/relative", "-1=上個月;0=本月;1=下個月"),
+            ("month/relative", "-1=上個月;0=本月;1=下個月"),
+            ("quarter", "{0} 季後|{0} 季前"),
+            ("quarter-narrow", "{0} 季後|{0} 季前"),
+            ("quarter-narrow/relative", "-1=上一季;0=這一季;1=下一季"),
+            ("quarter-short", "{0} 季後|{0} 季前"),
+            ("quarter-short/relative", "-1=上一季;0=這一季;1=下一季"),
+            ("quarter/relative", "-1=上一季;0=這一季;1=下一季"),
+            ("second", "{0} 秒後|{0} 秒前"),
+            ("second-narrow", "{0} 秒後|{0} 秒前"),
+            ("second-narrow/relative", "0=現在"),
+            ("second-short", "{0} 秒後|{0} 秒前"),
+            ("second-short/relative", "0=現在"),
+            ("second/relative", "0=現在"),
+            ("week", "{0} 週後|{0} 週前"),
+            ("week-narrow", "{0} 週後|{0} 週前"),
+            ("week-narrow/relative", "-1=上週;0=本週;1=下週"),
+            ("week-short", "{0} 週後|{0} 週前"),
+            ("week-short/relative", "-1=上週;0=本週;1=下週"),
+            ("week/relative", "-1=上週;0=本週;1=下週"),
+            ("year", "{0} 年後|{0} 年前"),
+            ("year-narrow", "{0} 年後|{0} 年前"),
+            ("year-narrow/relative", "-1=去年;0=今年;1=明年"),
+            ("year-short", "{0} 年後|{0} 年前"),
+            ("year-short/relative", "-1=去年;0=今年;1=明年"),
+            ("year/relative", "-1=去年;0=今年;1=明年"),
+        ],
+    ),
+    (
+        "zh-Hant-HK",
+        &[
+            ("day", "{0} 日後|{0} 日前"),
+            ("day-narrow", "{0}日後|{0}日前"),
+            ("day-narrow/relative", "-2=前日;-1=昨日;0=今日;1=明日;2=後日"),
+            ("day-short", "{0} 日後|{0} 日前"),
+            ("day-short/relative", "-2=前日;-1=昨日;0=今日;1=明日;2=後日"),
+            ("day/relative", "-2=前日;-1=昨日;0=今日;1=明日;2=後日"),
+            ("hour-narrow", "{0}小時後|{0}小時前"),
+            ("hour-narrow/relative", "0=這個小時"),
+            ("hour-short/relative", "0=這個小時"),
+            ("hour/relative", "0=這個小時"),
+            ("minute-narrow", "{0}分後|{0}分前"),
+            ("minute-narrow/relative", "0=這分鐘"),
+            ("minute-short/relative", "0=這分鐘"),
+            ("minute/relative", "0=這分鐘"),
+            ("month-narrow", "{0}個月後|{0}個月前"),
+            ("quarter-narrow", "+{0}Q|-{0}Q"),
+            ("quarter-narrow/relative", "-1=上季;0=今季;1=下季"),
+            ("quarter-short/relative", "-1=上季;0=今季;1=下季"),
+            ("quarter/relative", "-1=上一季;0=今季;1=下一季"),
+            ("second-narrow", "{0}秒後|{0}秒前"),
+            ("week", "{0} 星期後|{0} 星期前"),
+            ("week-narrow", "{0}星期後|{0}星期前"),
+            ("week-narrow/relative", "-1=上星期;0=本星期;1=下星期"),
+            ("week-short", "{0} 星期後|{0} 星期前"),
+            ("week-short/relative", "-1=上星期;0=本星期;1=下星期"),
+            ("week/relative", "-1=上星期;0=本星期;1=下星期"),
+            ("year-narrow", "{0}年後|{0}年前"),
+            ("year-narrow/relative", "-1=上年;0=今年;1=下年"),
+            ("year-short/relative", "-1=上年;0=今年;1=下年"),
+            ("year/relative", "-1=上年;0=今年;1=下年"),
+        ],
+    ),
+]);
@@ -0,0 +1,382 @@
+//! Formatting of date time patterns.
+//!
+//! `icu_datetime` 1.2 only writes formatted dates to plain strings, without annotating which
+//! field produced each character. To support `formatToParts`, the patterns and symbols are
+//! taken from the `icu_datetime` locale data and written here, one part per pattern field.
+//!
+//! The engine doesn't have time zone names data, so time zones are always written in the
+//! localized GMT format (e.g. "GMT+5:30").
+
+use chrono::{NaiveDateTime, Timelike};
+use icu_calendar::{
+    types::{Era, IsoWeekday, MonthCode},
+    AsCalendar, Date,
+};
+use icu_datetime::{
+    fields::{self, Field, FieldLength, FieldSymbol},
+    pattern::{runtime::Pattern, PatternItem},
+    provider::{
+        calendar::{DateSymbolsV1, TimeSymbolsV1},
+        time_zones::TimeZoneFormatsV1,
+    },
+};
+use icu_decimal::provider::DecimalSymbolsV1;
+
+use crate::builtins::intl::number_format::Part;
+
+/// The fields of a time value, in the calendar and time zone of a `DateTimeFormat`.
+#[derive(Debug, Clone)]
+pub(crate) struct LocalTime {
+    era: Era,
+    year: i32,
+    month: MonthCode,
+    ordinal_month: u32,
+    day: u32,
+    weekday: IsoWeekday,
+    hour: u32,
+    minute: u32,
+    second: u32,
+    millisecond: u32,
+    /// The offset from UTC of the time zone, in seconds.
+    offset: i32,
+}
+
+impl LocalTime {
+    /// Creates a new `LocalTime` from a calendar date and the local time of day of `time`,
+    /// which is a time `offset` seconds away from UTC.
+    pub(crate) fn new<A: AsCalendar>(date: &Date<A>, time: NaiveDateTime, offset: i32) -> Self {
+        let year = date.year();
+        let month = date.month();
+        Self {
+            era: year.era,
+            year: year.number,
+            month: month.code,
+            ordinal_month: month.ordinal,
+            day: date.day_of_month().0,
+            weekday: date.day_of_week(),
+            hour: time.hour(),
+            minute: time.minute(),
+            second: time.second(),
+            millisecond: time.timestamp_subsec_millis(),
+            offset,
+        }
+    }
+}
+
+/// The locale data needed to format a pattern.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Symbols<'a> {
+    pub(crate) date: &'a DateSymbolsV1<'a>,
+    pub(crate) time: &'a TimeSymbolsV1<'a>,
+    pub(crate) time_zone: &'a TimeZoneFormatsV1<'a>,
+    pub(crate) decimal: &'a DecimalSymbolsV1<'a>,
+}
+
+/// Abstract operation [`FormatDateTimePattern ( dateTimeFormat, patternParts, x, rangeFormatOptions )`][spec].
+///
+/// Writes `time` using `pattern`, returning the list of parts of the formatted string.
+///
+/// [spec]: https://tc39.es/ecma402/#sec-formatdatetimepattern
+pub(crate) fn partition(
+    pattern: &Pattern<'_>,
+    time: &LocalTime,
+    symbols: Symbols<'_>,
+) -> Vec<Part> {
+    let mut parts: Vec<Part> = Vec::new();
+
+    for item in pattern.items.iter() {
+        match item {
+            PatternItem::Literal(c) => push_literal(&mut parts, c.encode_utf8(&mut [0; 4])),
+            PatternItem::Field(Field {
+                symbol: FieldSymbol::Second(fields::Second::FractionalSecond),
+                length,
+            }) => {
+                let digits = match length {
+                    FieldLength::Fixed(digits) => usize::from(digits.clamp(1, 3)),
+                    _ => 3,
+                };
+                push_literal(&mut parts, &symbols.decimal.decimal_separator);
+                let fraction = format!("{:03}", time.millisecond);
+                parts.push(Part::new(
+                    "fractionalSecond",
+                    localize_digits(&fraction[..digits], symbols.decimal),
+                ));
+            }
+            PatternItem::Field(field) => {
+                if let Some(part) = format_field(pattern, field, time, symbols) {
+                    parts.push(part);
+                }
+            }
+        }
+    }
+
+    parts
+}
+
+/// Appends a literal to the list of parts, merging it with the last part if that part is also
+/// a literal.
+fn push_literal(parts: &mut Vec<Part>, literal: &str) {
+    match parts.last_mut() {
+        Some(part) if part.typ == "literal" => part.value.push_str(literal),
+        _ => parts.push(Part::new("literal", literal)),
+    }
+}
+
+/// Formats a single field of a pattern.
+///
+/// Returns `None` if the field is not supported.
+fn format_field(
+    pattern: &Pattern<'_>,
+    field: Field,
+    time: &LocalTime,
+    symbols: Symbols<'_>,
+) -> Option<Part> {
+    let (typ, value) = match field.symbol {
+        FieldSymbol::Era => {
+            let eras = &symbols.date.eras;
+            let names = match field.length {
+                FieldLength::Wide => &eras.names,
+                FieldLength::Narrow => &eras.narrow,
+                _ => &eras.abbr,
+            };
+            let era = names.get(&*time.era.0).unwrap_or(&*time.era.0);
+            ("era", era.to_owned())
+        }
+        FieldSymbol::Year(fields::Year::Calendar) => (
+            "year",
+            format_number(i64::from(time.year), field.length, symbols.decimal),
+        ),
+        FieldSymbol::Month(month) => {
+            let value = match field.length {
+                FieldLength::One | FieldLength::TwoDigit => None,
+                length => month_symbol(symbols.date, month, length, time.month),
+            };
+            let value = value.map_or_else(
+                || format_number(i64::from(time.ordinal_month), field.length, symbols.decimal),
+                ToOwned::to_owned,
+            );
+            ("month", value)
+        }
+        FieldSymbol::Day(fields::Day::DayOfMonth) => (
+            "day",
+            format_number(i64::from(time.day), field.length, symbols.decimal),
+        ),
+        FieldSymbol::Day(fields::Day::DayOfWeekInMonth) => (
+            "day",
+            format_number(
+                i64::from((time.day - 1) / 7 + 1),
+                field.length,
+                symbols.decimal,
+            ),
+        ),
+        FieldSymbol::Weekday(weekday) => (
+            "weekday",
+            weekday_symbol(symbols.date, weekday, field.length, time.weekday).to_owned(),
+        ),
+        FieldSymbol::DayPeriod(period) => {
+            let widths = &symbols.time.day_periods.format;
+            let names = match field.length {
+                FieldLength::Wide => &widths.wide,
+                FieldLength::Narrow => &widths.narrow,
+                _ => &widths.abbreviated,
+            };
+            let is_top_of_hour = pattern.time_granularity.is_top_of_hour(
+                time.minute as u8,
+                time.second as u8,
+                time.millisecond * 1_000_000,
+            );
+            let name = match (period, time.hour, is_top_of_hour) {
+                (fields::DayPeriod::NoonMidnight, 0, true) => {
+                    names.midnight.as_ref().unwrap_or(&names.am)
+                }
+                (fields::DayPeriod::NoonMidnight, 12, true) => {
+                    names.noon.as_ref().unwrap_or(&names.pm)
+                }
+                (_, hour, _) if hour < 12 => &names.am,
+                _ => &names.pm,
+            };
+            ("dayPeriod", name.to_string())
+        }
+        FieldSymbol::Hour(hour) => {
+            let value = match hour {
+                fields::Hour::H11 => time.hour % 12,
+                fields::Hour::H12 => (time.hour + 11) % 12 + 1,
+                fields::Hour::H24 if time.hour == 0 => 24,
+                _ => time.hour,
+            };
+            (
+                "hour",
+                format_number(i64::from(value), field.length, symbols.decimal),
+            )
+        }
+        FieldSymbol::Minute => (
+            "minute",
+            format_number(i64::from(time.minute), field.length, symbols.decimal),
+        ),
+        FieldSymbol::Second(fields::Second::Second) => (
+            "second",
+            format_number(i64::from(time.second), field.length, symbols.decimal),
+        ),
+        FieldSymbol::TimeZone(_) => (
+            "timeZoneName",
+            format_offset(
+                time.offset,
+                field.length == FieldLength::Wide,
+                symbols.time_zone,
+                symbols.decimal,
+            ),
+        ),
+        _ => return None,
+    };
+
+    Some(Part::new(typ, value))
+}
+
+/// Formats a numeric field, padding it to the minimum number of digits of `length`.
+fn format_number(value: i64, length: FieldLength, decimal: &DecimalSymbolsV1<'_>) -> String {
+    let min_digits = match length {
+        FieldLength::One => 1,
+        FieldLength::TwoDigit => 2,
+        FieldLength::Abbreviated => 3,
+        FieldLength::Wide => 4,
+        FieldLength::Narrow => 5,
+        FieldLength::Six => 6,
+        FieldLength::Fixed(digits) => usize::from(digits),
+    };
+
+    let mut digits = format!("{:0min_digits$}", value.unsigned_abs());
+
+    // Two digit fields only keep the last two digits of the value (e.g. 2023 => "23").
+    if length == FieldLength::TwoDigit {
+        digits.drain(..digits.len() - 2);
+    }
+
+    let digits = localize_digits(&digits, decimal);
+    if value < 0 {
+        format!("-{digits}")
+    } else {
+        digits
+    }
+}
+
+/// Replaces the ASCII digits of `digits` with the digits of the locale's numbering system.
+fn localize_digits(digits: &str, decimal: &DecimalSymbolsV1<'_>) -> String {
+    digits
+        .bytes()
+        .map(|digit| decimal.digits[usize::from(digit - b'0')])
+        .collect()
+}
+
+/// Gets the name of a month.
+fn month_symbol<'a>(
+    symbols: &'a DateSymbolsV1<'_>,
+    month: fields::Month,
+    length: FieldLength,
+    code: MonthCode,
+) -> Option<&'a str> {
+    let format = &symbols.months.format;
+    let stand_alone = symbols
+        .months
+        .stand_alone
+        .as_ref()
+        .filter(|_| month == fields::Month::StandAlone);
+
+    let names = match length {
+        FieldLength::Wide => stand_alone
+            .and_then(|widths| widths.wide.as_ref())
+            .unwrap_or(&format.wide),
+        FieldLength::Narrow => stand_alone
+            .and_then(|widths| widths.narrow.as_ref())
+            .unwrap_or(&format.narrow),
+        _ => stand_alone
+            .and_then(|widths| widths.abbreviated.as_ref())
+            .unwrap_or(&format.abbreviated),
+    };
+
+    names.get(code)
+}
+
+/// Gets the name of a day of the week.
+fn weekday_symbol<'a>(
+    symbols: &'a DateSymbolsV1<'_>,
+    weekday: fields::Weekday,
+    length: FieldLength,
+    day: IsoWeekday,
+) -> &'a str {
+    let format = &symbols.weekdays.format;
+    let stand_alone = symbols
+        .weekdays
+        .stand_alone
+        .as_ref()
+        .filter(|_| weekday == fields::Weekday::StandAlone);
+
+    let names = match length {
+        FieldLength::Wide => stand_alone
+            .and_then(|widths| widths.wide.as_ref())
+            .unwrap_or(&format.wide),
+        FieldLength::Narrow => stand_alone
+            .and_then(|widths| widths.narrow.as_ref())
+            .unwrap_or(&format.narrow),
+        FieldLength::Six => stand_alone
+            .and_then(|widths| widths.short.as_ref())
+            .or(format.short.as_ref())
+            .unwrap_or(&format.abbreviated),
+        _ => stand_alone
+            .and_then(|widths| widths.abbreviated.as_ref())
+            .unwrap_or(&format.abbreviated),
+    };
+
+    // The symbols start on Sunday.
+    &names.0[day as usize % 7]
+}
+
+/// Formats a UTC offset in the localized GMT format.
+///
+/// The short format omits the zero padding of the hours and the minutes if they are zero
+/// (e.g. "GMT-8"), while the long format always shows both (e.g. "GMT-08:00").
+fn format_offset(
+    offset: i32,
+    long: bool,
+    time_zone: &TimeZoneFormatsV1<'_>,
+    decimal: &DecimalSymbolsV1<'_>,
+) -> String {
+    if offset / 60 == 0 {
+        return time_zone.gmt_zero_format.to_string();
+    }
+
+    let hour_format = if offset > 0 {
+        &time_zone.hour_format.0
+    } else {
+        &time_zone.hour_format.1
+    };
+    let minutes = offset.unsigned_abs() / 60;
+    let (hours, minutes) = (minutes / 60, minutes % 60);
+
+    let mut result = String::new();
+    let mut hours_end = 0;
+    let mut chars = hour_format.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            'H' => {
+                let mut len = 1;
+                while chars.next_if_eq(&'H').is_some() {
+                    len += 1;
+                }
+                let len = if long { len } else { 1 };
+                result.push_str(&localize_digits(&format!("{hours:0len$}"), decimal));
+                hours_end = result.len();
+            }
+            'm' => {
+                while chars.next_if_eq(&'m').is_some() {}
+                if !long && minutes == 0 {
+                    result.truncate(hours_end);
+                } else {
+                    result.push_str(&localize_digits(&format!("{minutes:02}"), decimal));
+                }
+            }
+            c => result.push(c),
+        }
+    }
+
+    time_zone.gmt_format.replace("{0}", &result)
+}
@@ -0,0 +1,1461 @@
+//! This module implements the global `Intl.DateTimeFormat` object.
+//!
+//! `Intl.DateTimeFormat` is a built-in object that has properties and methods for date and time i18n.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma402/#datetimeformat-objects
+
+use boa_gc::{custom_trace, Finalize, Trace};
+use boa_profiler::Profiler;
+use chrono::{Datelike, Duration, NaiveDateTime};
+use icu_calendar::{
+    buddhist::Buddhist,
+    coptic::Coptic,
+    ethiopian::Ethiopian,
+    gregorian::Gregorian,
+    indian::Indian,
+    japanese::{Japanese, JapaneseExtended},
+    AnyCalendar, AnyCalendarKind, Date, Ref,
+};
+use icu_datetime::{
+    fields::{self, Field, FieldLength, FieldSymbol},
+    options::preferences::HourCycle,
+    pattern::{runtime::Pattern, CoarseHourCycle, PatternItem},
+    provider::{
+        calendar::{
+            DateLengthsV1, DateSkeletonPatternsV1Marker, DateSymbolsV1, TimeLengthsV1,
+            TimeLengthsV1Marker, TimeSymbolsV1Marker,
+        },
+        time_zones::TimeZoneFormatsV1Marker,
+    },
+    skeleton::{create_best_pattern_for_fields, BestSkeleton},
+    CldrCalendar,
+};
+use icu_decimal::provider::DecimalSymbolsV1Marker;
+use icu_locid::{
+    extensions::unicode::Value, extensions_unicode_key as key, extensions_unicode_value as value,
+    Locale,
+};
+use icu_provider::{
+    DataError, DataLocale, DataMarker, DataPayload, DataProvider, DataRequest, DataRequestMetadata,
+    DataResponse, KeyedDataMarker,
+};
+
+use crate::{
+    builtins::{BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject},
+    context::{
+        intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+        BoaProvider,
+    },
+    native_function::NativeFunction,
+    object::{
+        internal_methods::get_prototype_from_constructor, FunctionObjectBuilder, JsFunction,
+        JsObject, ObjectData,
+    },
+    property::Attribute,
+    realm::Realm,
+    string::utf16,
+    symbol::JsSymbol,
+    Context, JsArgs, JsNativeError, JsResult, JsString, JsValue,
+};
+
+use super::{
+    locale::{canonicalize_locale_list, resolve_locale, supported_locales, validate_extension},
+    number_format::{parts_to_array, Part},
+    options::{get_option, IntlOptions, LocaleMatcher},
+    Service,
+};
+
+mod format;
+mod options;
+pub(crate) use options::*;
+
+use format::{partition, LocalTime, Symbols};
+
+#[cfg(test)]
+mod tests;
+
+/// Data marker for the date symbols of any calendar.
+struct ErasedDateSymbolsV1Marker;
+
+impl DataMarker for ErasedDateSymbolsV1Marker {
+    type Yokeable = DateSymbolsV1<'static>;
+}
+
+/// Data marker for the date length patterns of any calendar.
+struct ErasedDateLengthsV1Marker;
+
+impl DataMarker for ErasedDateLengthsV1Marker {
+    type Yokeable = DateLengthsV1<'static>;
+}
+
+/// JavaScript `Intl.DateTimeFormat` object.
+pub struct DateTimeFormat {
+    locale: Locale,
+    calendar: AnyCalendar,
+    numbering_system: Option<Value>,
+    time_zone: TimeZone,
+    hour_cycle: Option<HourCycle>,
+    components: Components,
+    date_style: Option<DateTimeStyle>,
+    time_style: Option<DateTimeStyle>,
+    pattern: Pattern<'static>,
+    date_symbols: DataPayload<ErasedDateSymbolsV1Marker>,
+    time_symbols: DataPayload<TimeSymbolsV1Marker>,
+    time_zone_formats: DataPayload<TimeZoneFormatsV1Marker>,
+    decimal_symbols: DataPayload<DecimalSymbolsV1Marker>,
+    bound_format: Option<JsFunction>,
+}
+
+impl Finalize for DateTimeFormat {}
+
+// SAFETY: only `bound_format` is a traceable object.
+unsafe impl Trace for DateTimeFormat {
+    custom_trace!(this, mark(&this.bound_format));
+}
+
+impl std::fmt::Debug for DateTimeFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DateTimeFormat")
+            .field("locale", &self.locale)
+            .field("calendar", &self.calendar.kind())
+            .field("numbering_system", &self.numbering_system)
+            .field("time_zone", &self.time_zone)
+            .field("hour_cycle", &self.hour_cycle)
+            .field("components", &self.components)
+            .field("date_style", &self.date_style)
+            .field("time_style", &self.time_style)
+            .field("pattern", &self.pattern)
+            .field("date_symbols", &"DateSymbolsV1")
+            .field("time_symbols", &"TimeSymbolsV1")
+            .field("time_zone_formats", &"TimeZoneFormatsV1")
+            .field("decimal_symbols", &"DecimalSymbolsV1")
+            .field("bound_format", &self.bound_format)
+            .finish()
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub(in crate::builtins::intl) struct DateTimeFormatLocaleOptions {
+    pub(in crate::builtins::intl) calendar: Option<Value>,
+    pub(in crate::builtins::intl) numbering_system: Option<Value>,
+    pub(in crate::builtins::intl) hour_cycle: Option<HourCycle>,
+    pub(in crate::builtins::intl) hour12: Option<bool>,
+}
+
+impl Service for DateTimeFormat {
+    type LangMarker = TimeLengthsV1Marker;
+
+    type LocaleOptions = DateTimeFormatLocaleOptions;
+
+    fn resolve(locale: &mut Locale, options: &mut Self::LocaleOptions, provider: BoaProvider<'_>) {
+        let calendar = options
+            .calendar
+            .take()
+            .filter(is_supported_calendar)
+            .or_else(|| {
+                locale
+                    .extensions
+                    .unicode
+                    .keywords
+                    .get(&key!("ca"))
+                    .cloned()
+                    .filter(is_supported_calendar)
+            });
+
+        let numbering_system = options
+            .numbering_system
+            .take()
+            .filter(|nu| {
+                validate_extension::<DecimalSymbolsV1Marker>(
+                    locale.id.clone(),
+                    key!("nu"),
+                    nu,
+                    &provider,
+                )
+            })
+            .or_else(|| {
+                locale
+                    .extensions
+                    .unicode
+                    .keywords
+                    .get(&key!("nu"))
+                    .cloned()
+                    .filter(|nu| {
+                        validate_extension::<DecimalSymbolsV1Marker>(
+                            locale.id.clone(),
+                            key!("nu"),
+                            nu,
+                            &provider,
+                        )
+                    })
+            });
+
+        // The `hour12` option overrides both the `hourCycle` option and the `hc` extension key.
+        let hour_cycle = if options.hour12.is_some() {
+            None
+        } else {
+            options.hour_cycle.take().or_else(|| {
+                locale
+                    .extensions
+                    .unicode
+                    .keywords
+                    .get(&key!("hc"))
+                    .and_then(|hc| hour_cycle_from_str(&hc.to_string()))
+            })
+        };
+
+        locale.extensions.unicode.clear();
+
+        if let Some(ca) = calendar.clone() {
+            locale.extensions.unicode.keywords.set(key!("ca"), ca);
+        }
+        if let Some(hc) = hour_cycle {
+            let hc = match hc {
+                HourCycle::H11 => value!("h11"),
+                HourCycle::H12 => value!("h12"),
+                HourCycle::H23 => value!("h23"),
+                HourCycle::H24 => value!("h24"),
+            };
+            locale.extensions.unicode.keywords.set(key!("hc"), hc);
+        }
+        if let Some(nu) = numbering_system.clone() {
+            locale.extensions.unicode.keywords.set(key!("nu"), nu);
+        }
+
+        options.calendar = calendar;
+        options.numbering_system = numbering_system;
+        options.hour_cycle = hour_cycle;
+    }
+}
+
+/// The identifiers of the calendars supported by `Intl.DateTimeFormat`.
+pub(in crate::builtins::intl) const CALENDARS: [&str; 7] = [
+    "buddhist", "coptic", "ethioaa", "ethiopic", "gregory", "indian", "japanese",
+];
+
+/// Checks if `ca` is the identifier of a calendar supported by `Intl.DateTimeFormat`.
+fn is_supported_calendar(ca: &Value) -> bool {
+    !matches!(
+        AnyCalendarKind::get_for_bcp47_value(ca),
+        None | Some(AnyCalendarKind::Iso | AnyCalendarKind::JapaneseExtended)
+    )
+}
+
+impl IntrinsicObject for DateTimeFormat {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        let format = BuiltInBuilder::callable(realm, Self::format)
+            .name("get format")
+            .build();
+
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .static_method(Self::supported_locales_of, "supportedLocalesOf", 1)
+            .property(
+                JsSymbol::to_string_tag(),
+                "Intl.DateTimeFormat",
+                Attribute::CONFIGURABLE,
+            )
+            .accessor(
+                utf16!("format"),
+                Some(format),
+                None,
+                Attribute::CONFIGURABLE,
+            )
+            .method(Self::format_to_parts, "formatToParts", 1)
+            .method(Self::format_range, "formatRange", 2)
+            .method(Self::format_range_to_parts, "formatRangeToParts", 2)
+            .method(Self::resolved_options, "resolvedOptions", 0)
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for DateTimeFormat {
+    const NAME: &'static str = "DateTimeFormat";
+}
+
+impl BuiltInConstructor for DateTimeFormat {
+    const LENGTH: usize = 0;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::date_time_format;
+
+    /// The `Intl.DateTimeFormat` constructor is the `%DateTimeFormat%` intrinsic object and a standard built-in property of the `Intl` object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#datetimeformat-objects
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, let newTarget be the active function object, else let newTarget be NewTarget.
+        let new_target = &if new_target.is_undefined() {
+            context
+                .vm
+                .active_function
+                .clone()
+                .unwrap_or_else(|| {
+                    context
+                        .intrinsics()
+                        .constructors()
+                        .date_time_format()
+                        .constructor()
+                })
+                .into()
+        } else {
+            new_target.clone()
+        };
+
+        let locales = args.get_or_undefined(0);
+        let options = args.get_or_undefined(1);
+
+        // 2. Let dateTimeFormat be ? CreateDateTimeFormat(newTarget, locales, options, any, date).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::date_time_format,
+            context,
+        )?;
+        let date_time_format = Self::new(
+            locales,
+            options,
+            &DateTimeReqs::AnyAll,
+            &DateTimeReqs::Date,
+            context,
+        )?;
+
+        // 3. If the implementation supports the normative optional constructor mode of 4.3 Note 1, then
+        //     a. Let this be the this value.
+        //     b. Return ? ChainDateTimeFormat(dateTimeFormat, NewTarget, this).
+        // 4. Return dateTimeFormat.
+        Ok(JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            ObjectData::date_time_format(Box::new(date_time_format)),
+        )
+        .into())
+    }
+}
+
+impl DateTimeFormat {
+    /// Abstract operation [`CreateDateTimeFormat ( newTarget, locales, options, required, defaults )`][spec].
+    ///
+    /// Creates a new `DateTimeFormat` from the provided locales and options, which is used by the
+    /// `Intl.DateTimeFormat` constructor and by the `toLocaleString` methods of dates.
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-createdatetimeformat
+    pub(crate) fn new(
+        locales: &JsValue,
+        options: &JsValue,
+        required: &DateTimeReqs,
+        defaults: &DateTimeReqs,
+        context: &mut Context<'_>,
+    ) -> JsResult<Self> {
+        // 2. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(locales, context)?;
+
+        // 3. Set options to ? ToDateTimeOptions(options, required, defaults).
+        let options = to_date_time_options(options, required, defaults, context)?;
+
+        // 4. Let opt be a new Record.
+        // 5. Let matcher be ? GetOption(options, "localeMatcher", string, « "lookup", "best fit" », "best fit").
+        // 6. Set opt.[[localeMatcher]] to matcher.
+        let matcher =
+            get_option::<LocaleMatcher>(&options, utf16!("localeMatcher"), false, context)?
+                .unwrap_or_default();
+
+        // 7. Let calendar be ? GetOption(options, "calendar", string, empty, undefined).
+        // 8. If calendar is not undefined, then
+        //     a. If calendar cannot be matched by the type Unicode locale nonterminal, throw a RangeError exception.
+        // 9. Set opt.[[ca]] to calendar.
+        let calendar = get_option::<Value>(&options, utf16!("calendar"), false, context)?;
+
+        // 10. Let numberingSystem be ? GetOption(options, "numberingSystem", string, empty, undefined).
+        // 11. If numberingSystem is not undefined, then
+        //     a. If numberingSystem cannot be matched by the type Unicode locale nonterminal, throw a RangeError exception.
+        // 12. Set opt.[[nu]] to numberingSystem.
+        let numbering_system =
+            get_option::<Value>(&options, utf16!("numberingSystem"), false, context)?;
+
+        // 13. Let hour12 be ? GetOption(options, "hour12", boolean, empty, undefined).
+        let hour12 = get_option::<bool>(&options, utf16!("hour12"), false, context)?;
+
+        // 14. Let hourCycle be ? GetOption(options, "hourCycle", string, « "h11", "h12", "h23", "h24" », undefined).
+        // 15. If hour12 is not undefined, then
+        //     a. Set hourCycle to null.
+        // 16. Set opt.[[hc]] to hourCycle.
+        let hour_cycle = get_option::<HourCycle>(&options, utf16!("hourCycle"), false, context)?;
+
+        let mut intl_options = IntlOptions {
+            matcher,
+            service_options: DateTimeFormatLocaleOptions {
+                calendar,
+                numbering_system,
+                hour_cycle,
+                hour12,
+            },
+        };
+
+        // 17. Let localeData be %DateTimeFormat%.[[LocaleData]].
+        // 18. Let r be ResolveLocale(%DateTimeFormat%.[[AvailableLocales]], requestedLocales, opt, %DateTimeFormat%.[[RelevantExtensionKeys]], localeData).
+        // 19. Set dateTimeFormat.[[Locale]] to r.[[locale]].
+        // 20. Let resolvedCalendar be r.[[ca]].
+        // 21. Set dateTimeFormat.[[Calendar]] to resolvedCalendar.
+        // 22. Set dateTimeFormat.[[NumberingSystem]] to r.[[nu]].
+        let locale = resolve_locale::<Self>(&requested_locales, &mut intl_options, context.icu());
+        let DateTimeFormatLocaleOptions {
+            calendar,
+            numbering_system,
+            hour_cycle,
+            hour12,
+        } = intl_options.service_options;
+
+        let calendar_kind = calendar
+            .as_ref()
+            .and_then(AnyCalendarKind::get_for_bcp47_value)
+            .unwrap_or(AnyCalendarKind::Gregorian);
+        let provider = context.icu().provider();
+        let calendar = AnyCalendar::try_new_unstable(&provider, calendar_kind)
+            .map_err(|e| JsNativeError::typ().with_message(e.to_string()))?;
+
+        // 23. Let dataLocale be r.[[dataLocale]].
+        let data_locale = DataLocale::from(&locale.id);
+        let (date_symbols, date_lengths) =
+            load_calendar_data(calendar_kind, &data_locale, provider)
+                .map_err(|e| JsNativeError::typ().with_message(e.to_string()))?;
+        let time_lengths = load::<TimeLengthsV1Marker>(&data_locale, provider)?;
+        let time_symbols = load::<TimeSymbolsV1Marker>(&data_locale, provider)?;
+        let time_zone_formats = load::<TimeZoneFormatsV1Marker>(&data_locale, provider)?;
+        let decimal_symbols = {
+            let mut locale = data_locale.clone();
+            if let Some(nu) = numbering_system.clone() {
+                locale.set_unicode_ext(key!("nu"), nu);
+            }
+            load::<DecimalSymbolsV1Marker>(&locale, provider)?
+        };
+
+        // 24. Let dataLocaleData be localeData.[[<dataLocale>]].
+        // 25. Let hcDefault be dataLocaleData.[[hourCycle]].
+        let hc_default = match time_lengths.get().preferred_hour_cycle {
+            CoarseHourCycle::H11H12 => HourCycle::H12,
+            CoarseHourCycle::H23H24 => HourCycle::H23,
+        };
+
+        // 26. If hour12 is true, then
+        //     a. Let hc be dataLocaleData.[[hourCycle12]].
+        // 27. Else if hour12 is false, then
+        //     a. Let hc be dataLocaleData.[[hourCycle24]].
+        // 28. Else,
+        //     a. Assert: hour12 is undefined.
+        //     b. Let hc be r.[[hc]].
+        //     c. If hc is null, set hc to hcDefault.
+        let hour_cycle = match hour12 {
+            Some(true) => HourCycle::H12,
+            Some(false) => HourCycle::H23,
+            None => hour_cycle.unwrap_or(hc_default),
+        };
+
+        // 29. Let timeZone be ? Get(options, "timeZone").
+        let time_zone = options.get(utf16!("timeZone"), context)?;
+
+        // 30. If timeZone is undefined, then
+        //     a. Set timeZone to DefaultTimeZone().
+        // 31. Else,
+        //     a. Set timeZone to ? ToString(timeZone).
+        //     b. If the result of IsValidTimeZoneName(timeZone) is false, then
+        //         i. Throw a RangeError exception.
+        //     c. Set timeZone to CanonicalizeTimeZoneName(timeZone).
+        // 32. Set dateTimeFormat.[[TimeZone]] to timeZone.
+        let time_zone = if time_zone.is_undefined() {
+            TimeZone::host(&*context.time_zone_provider())
+        } else {
+            let time_zone = time_zone.to_string(context)?.to_std_string_escaped();
+            TimeZone::from_name(&time_zone, &*context.time_zone_provider()).ok_or_else(|| {
+                JsNativeError::range().with_message(format!("unsupported time zone `{time_zone}`"))
+            })?
+        };
+
+        // 33. Let formatOptions be a new Record.
+        // 34. Set formatOptions.[[hourCycle]] to hc.
+        // 35. Let hasExplicitFormatComponents be false.
+        // 36. For each row of Table 6, except the header row, in table order, do
+        //     a. Let prop be the name given in the Property column of the row.
+        //     b. If prop is "fractionalSecondDigits", then
+        //         i. Let value be ? GetNumberOption(options, "fractionalSecondDigits", 1, 3, undefined).
+        //     c. Else,
+        //         i. Let values be a List whose elements are the strings given in the Values column of the row.
+        //         ii. Let value be ? GetOption(options, prop, string, values, undefined).
+        //     d. Set formatOptions.[[<prop>]] to value.
+        //     e. If value is not undefined, then
+        //         i. Set hasExplicitFormatComponents to true.
+        let components = Components::from_options(&options, context)?;
+
+        // 37. Let matcher be ? GetOption(options, "formatMatcher", string, « "basic", "best fit" », "best fit").
+        // The best fit matcher of `icu_datetime` is used for both values.
+        get_option::<FormatMatcher>(&options, utf16!("formatMatcher"), false, context)?;
+
+        // 38. Let dateStyle be ? GetOption(options, "dateStyle", string, « "full", "long", "medium", "short" », undefined).
+        // 39. Set dateTimeFormat.[[DateStyle]] to dateStyle.
+        let date_style =
+            get_option::<DateTimeStyle>(&options, utf16!("dateStyle"), false, context)?;
+
+        // 40. Let timeStyle be ? GetOption(options, "timeStyle", string, « "full", "long", "medium", "short" », undefined).
+        // 41. Set dateTimeFormat.[[TimeStyle]] to timeStyle.
+        let time_style =
+            get_option::<DateTimeStyle>(&options, utf16!("timeStyle"), false, context)?;
+
+        let date_lengths = date_lengths.get();
+        let time_lengths = time_lengths.get();
+
+        // 42. If dateStyle is not undefined or timeStyle is not undefined, then
+        let pattern = if date_style.is_some() || time_style.is_some() {
+            // a. If hasExplicitFormatComponents is true, then
+            if !components.is_empty() {
+                // i. Throw a TypeError exception.
+                return Err(JsNativeError::typ()
+                    .with_message(
+                        "`dateStyle` and `timeStyle` cannot be used with explicit date time components",
+                    )
+                    .into());
+            }
+
+            // b. Let styles be dataLocaleData.[[styles]].[[<resolvedCalendar>]].
+            // c. Let bestFormat be DateTimeStyleFormat(dateStyle, timeStyle, styles).
+            style_pattern(
+                date_style,
+                time_style,
+                hour_cycle,
+                date_lengths,
+                time_lengths,
+            )
+            .map_err(|e| JsNativeError::typ().with_message(e.to_string()))?
+        }
+        // 43. Else,
+        else {
+            // a. Let formats be dataLocaleData.[[formats]].[[<resolvedCalendar>]].
+            // b. If matcher is "basic", then
+            //     i. Let bestFormat be BasicFormatMatcher(formatOptions, formats).
+            // c. Else,
+            //     i. Let bestFormat be BestFitFormatMatcher(formatOptions, formats).
+            let mut skeleton_locale = data_locale;
+            skeleton_locale.set_unicode_ext(
+                key!("ca"),
+                if calendar_kind == AnyCalendarKind::EthiopianAmeteAlem {
+                    value!("ethiopic")
+                } else {
+                    calendar_kind.as_bcp47_value()
+                },
+            );
+            let skeletons = load::<DateSkeletonPatternsV1Marker>(&skeleton_locale, provider)?;
+
+            match create_best_pattern_for_fields(
+                skeletons.get(),
+                &date_lengths.length_combinations,
+                &components.to_fields(hour_cycle),
+                &components.to_bag(hour_cycle),
+                false,
+            ) {
+                BestSkeleton::AllFieldsMatch(patterns)
+                | BestSkeleton::MissingOrExtraFields(patterns) => {
+                    patterns.patterns_iter().next().map_or_else(
+                        || date_lengths.date.medium.clone(),
+                        |pattern| pattern.clone().into_owned(),
+                    )
+                }
+                BestSkeleton::NoMatch => date_lengths.date.medium.clone(),
+            }
+        };
+
+        // 44. For each row in Table 6, except the header row, in table order, do
+        //     a. Let prop be the name given in the Property column of the row.
+        //     b. If bestFormat has a field [[<prop>]], then
+        //         i. Let p be bestFormat.[[<prop>]].
+        //         ii. Set dateTimeFormat's internal slot whose name is the Internal Slot column of the row to p.
+        // 45. If dateTimeFormat.[[Hour]] is undefined, then
+        //     a. Set dateTimeFormat.[[HourCycle]] to undefined.
+        // 46. Else,
+        //     a. Set dateTimeFormat.[[HourCycle]] to hc.
+        let pattern = adjust_pattern(&pattern, hour_cycle, &components);
+        let has_hour = pattern.items.iter().any(|item| {
+            matches!(
+                item,
+                PatternItem::Field(Field {
+                    symbol: FieldSymbol::Hour(_),
+                    ..
+                })
+            )
+        });
+        let components = if date_style.is_some() || time_style.is_some() {
+            Components::default()
+        } else {
+            Components::from_pattern(&pattern, &components)
+        };
+
+        // 47. Set dateTimeFormat.[[Pattern]] to pattern.
+        // 48. Set dateTimeFormat.[[RangePatterns]] to rangePatterns.
+        // 49. Return dateTimeFormat.
+        Ok(Self {
+            locale,
+            calendar,
+            numbering_system,
+            time_zone,
+            hour_cycle: has_hour.then_some(hour_cycle),
+            components,
+            date_style,
+            time_style,
+            pattern,
+            date_symbols,
+            time_symbols,
+            time_zone_formats,
+            decimal_symbols,
+            bound_format: None,
+        })
+    }
+
+    /// Gets the locale data used to format a pattern.
+    fn symbols(&self) -> Symbols<'_> {
+        Symbols {
+            date: self.date_symbols.get(),
+            time: self.time_symbols.get(),
+            time_zone: self.time_zone_formats.get(),
+            decimal: self.decimal_symbols.get(),
+        }
+    }
+
+    /// Abstract operation [`PartitionDateTimePattern ( dateTimeFormat, x )`][spec].
+    ///
+    /// Formats the time value `x` into a list of parts, throwing a `RangeError` if `x` is not a
+    /// valid time value.
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-partitiondatetimepattern
+    fn partition(&self, x: f64, context: &Context<'_>) -> JsResult<Vec<Part>> {
+        // 1. Let x be TimeClip(x).
+        // 2. If x is NaN, throw a RangeError exception.
+        #[allow(clippy::cast_possible_truncation)]
+        let utc = (x.is_finite() && x.abs() <= 8.64e15)
+            .then_some(x as i64)
+            .and_then(NaiveDateTime::from_timestamp_millis)
+            .ok_or_else(|| JsNativeError::range().with_message("invalid time value"))?;
+
+        // 3. Let epochNanoseconds be ℤ(ℝ(x) × 10^6).
+        // 4. Let timeZone be dateTimeFormat.[[TimeZone]].
+        // 5. Let offsetNs be GetNamedTimeZoneOffsetNanoseconds(timeZone, epochNanoseconds).
+        // 6. Let tm be ToLocalTime(epochNanoseconds + offsetNs, dateTimeFormat.[[Calendar]]).
+        let offset = self.time_zone.offset_at(utc, context);
+        let local = utc + Duration::seconds(i64::from(offset));
+        #[allow(clippy::cast_possible_truncation)]
+        let date = Date::try_new_iso_date(local.year(), local.month() as u8, local.day() as u8)
+            .map_err(|e| JsNativeError::range().with_message(e.to_string()))?
+            .to_calendar(Ref(&self.calendar));
+        let time = LocalTime::new(&date, local, offset);
+
+        // 7. Let result be FormatDateTimePattern(dateTimeFormat, patternParts, x, undefined).
+        // 8. Return result.
+        Ok(partition(&self.pattern, &time, self.symbols()))
+    }
+
+    /// Abstract operation [`FormatDateTime ( dateTimeFormat, x )`][spec].
+    ///
+    /// Formats the time value `x` according to this date time format.
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-formatdatetime
+    pub(crate) fn format_value(&self, x: f64, context: &Context<'_>) -> JsResult<JsString> {
+        // 1. Let parts be ? PartitionDateTimePattern(dateTimeFormat, x).
+        // 2. Let result be the empty String.
+        // 3. For each Record { [[Type]], [[Value]] } part in parts, do
+        //     a. Set result to the string-concatenation of result and part.[[Value]].
+        // 4. Return result.
+        Ok(self
+            .partition(x, context)?
+            .into_iter()
+            .map(|part| part.value)
+            .collect::<String>()
+            .into())
+    }
+
+    /// Abstract operation [`PartitionDateTimeRangePattern ( dateTimeFormat, x, y )`][spec].
+    ///
+    /// The locale data doesn't contain interval formats, so different dates are formatted by
+    /// joining the formatted start and end dates with an en dash.
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-partitiondatetimerangepattern
+    fn partition_range(
+        &self,
+        x: f64,
+        y: f64,
+        context: &Context<'_>,
+    ) -> JsResult<Vec<(Part, &'static str)>> {
+        // 1. Let x be TimeClip(x).
+        // 2. If x is NaN, throw a RangeError exception.
+        // 3. Let y be TimeClip(y).
+        // 4. If y is NaN, throw a RangeError exception.
+        let start = self.partition(x, context)?;
+        let end = self.partition(y, context)?;
+
+        // 13. If dateFieldsPracticallyEqual is true, then
+        //     a. Let pattern be dateTimeFormat.[[Pattern]].
+        //     b. Let patternParts be PartitionPattern(pattern).
+        //     c. Let result be FormatDateTimePattern(dateTimeFormat, patternParts, x, undefined).
+        //     d. For each Record { [[Type]], [[Value]] } r in result, do
+        //         i. Set r.[[Source]] to "shared".
+        //     e. Return result.
+        if start == end {
+            return Ok(start.into_iter().map(|part| (part, "shared")).collect());
+        }
+
+        let mut result: Vec<_> = start.into_iter().map(|part| (part, "startRange")).collect();
+        result.push((Part::new("literal", " – "), "shared"));
+        result.extend(end.into_iter().map(|part| (part, "endRange")));
+
+        Ok(result)
+    }
+
+    /// [`Intl.DateTimeFormat.supportedLocalesOf ( locales [ , options ] )`][spec].
+    ///
+    /// Returns an array containing those of the provided locales that are supported in date and time
+    /// formatting without having to fall back to the runtime's default locale.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.datetimeformat.supportedlocalesof
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat/supportedLocalesOf
+    fn supported_locales_of(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        let locales = args.get_or_undefined(0);
+        let options = args.get_or_undefined(1);
+
+        // 1. Let availableLocales be %DateTimeFormat%.[[AvailableLocales]].
+        // 2. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(locales, context)?;
+
+        // 3. Return ? SupportedLocales(availableLocales, requestedLocales, options).
+        supported_locales::<<Self as Service>::LangMarker>(&requested_locales, options, context)
+            .map(JsValue::from)
+    }
+
+    /// [`get Intl.DateTimeFormat.prototype.format`][spec].
+    ///
+    /// Formats a date according to the locale and formatting options of this
+    /// `Intl.DateTimeFormat` object.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.datetimeformat.prototype.format
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat/format
+    fn format(this: &JsValue, _: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Let dtf be the this value.
+        // 2. If the implementation supports the normative optional constructor mode of 4.3 Note 1, then
+        //     a. Set dtf to ? UnwrapDateTimeFormat(dtf).
+        // 3. Perform ? RequireInternalSlot(dtf, [[InitializedDateTimeFormat]]).
+        let this = this.as_object().ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("`format` can only be called on a `DateTimeFormat` object")
+        })?;
+        let dtf_obj = this.clone();
+        let mut dtf = this.borrow_mut();
+        let dtf = dtf.as_date_time_format_mut().ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("`format` can only be called on a `DateTimeFormat` object")
+        })?;
+
+        // 4. If dtf.[[BoundFormat]] is undefined, then
+        //     a. Let F be a new built-in function object as defined in DateTime Format Functions (11.5.4).
+        //     b. Set F.[[DateTimeFormat]] to dtf.
+        //     c. Set dtf.[[BoundFormat]] to F.
+        let bound_format = if let Some(f) = dtf.bound_format.clone() {
+            f
+        } else {
+            let bound_format = FunctionObjectBuilder::new(
+                context,
+                // 11.5.4 DateTime Format Functions
+                // https://tc39.es/ecma402/#sec-datetime-format-functions
+                NativeFunction::from_copy_closure_with_captures(
+                    |_, args, dtf, context| {
+                        // 1. Let dtf be F.[[DateTimeFormat]].
+                        // 2. Assert: Type(dtf) is Object and dtf has an [[InitializedDateTimeFormat]] internal slot.
+                        // 3. If date is not provided or is undefined, then
+                        //     a. Let x be ! Call(%Date.now%, undefined).
+                        // 4. Else,
+                        //     a. Let x be ? ToNumber(date).
+                        let x = to_time_value(args.get_or_undefined(0), context)?;
+
+                        // 5. Return ? FormatDateTime(dtf, x).
+                        let dtf = dtf.borrow();
+                        let dtf = dtf
+                            .as_date_time_format()
+                            .expect("checked above that the object was a date time format object");
+                        Ok(dtf.format_value(x, context)?.into())
+                    },
+                    dtf_obj,
+                ),
+            )
+            .length(1)
+            .build();
+
+            dtf.bound_format = Some(bound_format.clone());
+            bound_format
+        };
+
+        // 5. Return dtf.[[BoundFormat]].
+        Ok(bound_format.into())
+    }
+
+    /// [`Intl.DateTimeFormat.prototype.formatToParts ( date )`][spec].
+    ///
+    /// Returns an array of objects representing the locale-specific parts of a formatted date.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DateTimeFormat.prototype.formatToParts
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat/formatToParts
+    fn format_to_parts(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. Let dtf be the this value.
+        // 2. Perform ? RequireInternalSlot(dtf, [[InitializedDateTimeFormat]]).
+        let dtf = this.as_object().ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("`formatToParts` can only be called on a `DateTimeFormat` object")
+        })?;
+        if dtf.borrow().as_date_time_format().is_none() {
+            return Err(JsNativeError::typ()
+                .with_message("`formatToParts` can only be called on a `DateTimeFormat` object")
+                .into());
+        }
+
+        // 3. If date is undefined, then
+        //     a. Let x be ! Call(%Date.now%, undefined).
+        // 4. Else,
+        //     a. Let x be ? ToNumber(date).
+        let x = to_time_value(args.get_or_undefined(0), context)?;
+
+        let dtf = dtf.borrow();
+        let dtf = dtf
+            .as_date_time_format()
+            .expect("checked above that the object was a date time format object");
+
+        // 5. Return ? FormatDateTimeToParts(dtf, x).
+
+        // Abstract operation `FormatDateTimeToParts ( dateTimeFormat, x )`
+        // https://tc39.es/ecma402/#sec-formatdatetimetoparts
+
+        // 1. Let parts be ? PartitionDateTimePattern(dateTimeFormat, x).
+        let parts = dtf.partition(x, context)?;
+
+        // 2. Let result be ! ArrayCreate(0).
+        // 3. Let n be 0.
+        // 4. For each Record { [[Type]], [[Value]] } part in parts, do
+        //     a. Let O be OrdinaryObjectCreate(%Object.prototype%).
+        //     b. Perform ! CreateDataPropertyOrThrow(O, "type", part.[[Type]]).
+        //     c. Perform ! CreateDataPropertyOrThrow(O, "value", part.[[Value]]).
+        //     d. Perform ! CreateDataPropertyOrThrow(result, ! ToString(n), O).
+        //     e. Increment n by 1.
+        // 5. Return result.
+        Ok(parts_to_array(parts.into_iter().map(|part| (part, None)), context).into())
+    }
+
+    /// [`Intl.DateTimeFormat.prototype.formatRange ( startDate, endDate )`][spec].
+    ///
+    /// Returns a string representing the range between two dates, formatted according to the
+    /// locale and formatting options of this `Intl.DateTimeFormat` object.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.datetimeformat.prototype.formatRange
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat/formatRange
+    fn format_range(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. Let dtf be this value.
+        // 2. Perform ? RequireInternalSlot(dtf, [[InitializedDateTimeFormat]]).
+        // 3. If startDate is undefined or endDate is undefined, throw a TypeError exception.
+        // 4. Let x be ? ToNumber(startDate).
+        // 5. Let y be ? ToNumber(endDate).
+        // 6. Return ? FormatDateTimeRange(dtf, x, y).
+
+        // Abstract operation `FormatDateTimeRange ( dateTimeFormat, x, y )`
+        // https://tc39.es/ecma402/#sec-formatdatetimerange
+
+        // 1. Let parts be ? PartitionDateTimeRangePattern(dateTimeFormat, x, y).
+        // 2. Let result be the empty String.
+        // 3. For each Record { [[Type]], [[Value]], [[Source]] } part in parts, do
+        //     a. Set result to the string-concatenation of result and part.[[Value]].
+        // 4. Return result.
+        let parts = Self::range_parts(this, args, "formatRange", context)?;
+        Ok(JsString::from(
+            parts
+                .into_iter()
+                .map(|(part, _)| part.value)
+                .collect::<String>(),
+        )
+        .into())
+    }
+
+    /// [`Intl.DateTimeFormat.prototype.formatRangeToParts ( startDate, endDate )`][spec].
+    ///
+    /// Returns an array of objects representing the locale-specific parts of a formatted range
+    /// of dates.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DateTimeFormat.prototype.formatRangeToParts
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat/formatRangeToParts
+    fn format_range_to_parts(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. Let dtf be this value.
+        // 2. Perform ? RequireInternalSlot(dtf, [[InitializedDateTimeFormat]]).
+        // 3. If startDate is undefined or endDate is undefined, throw a TypeError exception.
+        // 4. Let x be ? ToNumber(startDate).
+        // 5. Let y be ? ToNumber(endDate).
+        // 6. Return ? FormatDateTimeRangeToParts(dtf, x, y).
+
+        // Abstract operation `FormatDateTimeRangeToParts ( dateTimeFormat, x, y )`
+        // https://tc39.es/ecma402/#sec-formatdatetimerangetoparts
+
+        // 1. Let parts be ? PartitionDateTimeRangePattern(dateTimeFormat, x, y).
+        // 2. Let result be ! ArrayCreate(0).
+        // 3. Let n be 0.
+        // 4. For each Record { [[Type]], [[Value]], [[Source]] } part in parts, do
+        //     a. Let O be OrdinaryObjectCreate(%Object.prototype%).
+        //     b. Perform ! CreateDataPropertyOrThrow(O, "type", part.[[Type]]).
+        //     c. Perform ! CreateDataPropertyOrThrow(O, "value", part.[[Value]]).
+        //     d. Perform ! CreateDataPropertyOrThrow(O, "source", part.[[Source]]).
+        //     e. Perform ! CreateDataPropertyOrThrow(result, ! ToString(n), O).
+        //     f. Increment n by 1.
+        // 5. Return result.
+        let parts = Self::range_parts(this, args, "formatRangeToParts", context)?;
+        Ok(parts_to_array(
+            parts.into_iter().map(|(part, source)| (part, Some(source))),
+            context,
+        )
+        .into())
+    }
+
+    /// Common steps of `formatRange` and `formatRangeToParts`, which partition the range given
+    /// by the `startDate` and `endDate` arguments.
+    fn range_parts(
+        this: &JsValue,
+        args: &[JsValue],
+        method: &str,
+        context: &mut Context<'_>,
+    ) -> JsResult<Vec<(Part, &'static str)>> {
+        // 1. Let dtf be this value.
+        // 2. Perform ? RequireInternalSlot(dtf, [[InitializedDateTimeFormat]]).
+        let dtf = this
+            .as_object()
+            .filter(|dtf| dtf.borrow().as_date_time_format().is_some())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(format!(
+                    "`{method}` can only be called on a `DateTimeFormat` object"
+                ))
+            })?;
+
+        // 3. If startDate is undefined or endDate is undefined, throw a TypeError exception.
+        let start = args.get_or_undefined(0);
+        let end = args.get_or_undefined(1);
+        if start.is_undefined() || end.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message(format!("`{method}` requires a start and an end date"))
+                .into());
+        }
+
+        // 4. Let x be ? ToNumber(startDate).
+        let x = start.to_number(context)?;
+
+        // 5. Let y be ? ToNumber(endDate).
+        let y = end.to_number(context)?;
+
+        let dtf = dtf.borrow();
+        let dtf = dtf
+            .as_date_time_format()
+            .expect("checked above that the object was a date time format object");
+        dtf.partition_range(x, y, context)
+    }
+
+    /// [`Intl.DateTimeFormat.prototype.resolvedOptions ( )`][spec].
+    ///
+    /// Returns a new object with properties reflecting the locale and date and time formatting
+    /// options computed during the construction of the current `Intl.DateTimeFormat` object.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.datetimeformat.prototype.resolvedoptions
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat/resolvedOptions
+    fn resolved_options(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. Let dtf be the this value.
+        // 2. If the implementation supports the normative optional constructor mode of 4.3 Note 1, then
+        //     a. Set dtf to ? UnwrapDateTimeFormat(dtf).
+        // 3. Perform ? RequireInternalSlot(dtf, [[InitializedDateTimeFormat]]).
+        let dtf = this.as_object().map(JsObject::borrow).ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("`resolvedOptions` can only be called on a `DateTimeFormat` object")
+        })?;
+        let dtf = dtf.as_date_time_format().ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("`resolvedOptions` can only be called on a `DateTimeFormat` object")
+        })?;
+
+        // 4. Let options be OrdinaryObjectCreate(%Object.prototype%).
+        let options = context
+            .intrinsics()
+            .templates()
+            .ordinary_object()
+            .create(ObjectData::ordinary(), vec![]);
+
+        // 5. For each row of Table 7, except the header row, in table order, do
+        //     a. Let p be the Property value of the current row.
+        //     b. If there is an Internal Slot value in the current row, then
+        //         i. Let v be the value of dtf's internal slot whose name is the Internal Slot value of the current row.
+        //     c. Else,
+        //         i. Let hc be dtf.[[HourCycle]].
+        //         ii. If hc is "h11" or "h12", let v be true.
+        //         iii. Else if, hc is "h23" or "h24", let v be false.
+        //         iv. Else, let v be undefined.
+        //     d. If v is not undefined, then
+        //         i. Perform ! CreateDataPropertyOrThrow(options, p, v).
+        let mut properties: Vec<(&[u16], JsValue)> = vec![
+            (utf16!("locale"), dtf.locale.to_string().into()),
+            (
+                utf16!("calendar"),
+                dtf.calendar.kind().as_bcp47_string().into(),
+            ),
+            (
+                utf16!("numberingSystem"),
+                dtf.numbering_system
+                    .as_ref()
+                    .map_or_else(|| String::from("latn"), ToString::to_string)
+                    .into(),
+            ),
+            (utf16!("timeZone"), dtf.time_zone.name(context).into()),
+        ];
+
+        if let Some(hc) = dtf.hour_cycle {
+            properties.push((utf16!("hourCycle"), hour_cycle_to_str(hc).into()));
+            properties.push((
+                utf16!("hour12"),
+                matches!(hc, HourCycle::H11 | HourCycle::H12).into(),
+            ));
+        }
+
+        let components = dtf.components;
+        let text_properties = [
+            (utf16!("weekday"), components.weekday),
+            (utf16!("era"), components.era),
+        ];
+        for (property, value) in text_properties {
+            if let Some(value) = value {
+                properties.push((property, value.to_string().into()));
+            }
+        }
+        if let Some(year) = components.year {
+            properties.push((utf16!("year"), year.to_string().into()));
+        }
+        if let Some(month) = components.month {
+            properties.push((utf16!("month"), month.to_string().into()));
+        }
+        if let Some(day) = components.day {
+            properties.push((utf16!("day"), day.to_string().into()));
+        }
+        if let Some(day_period) = components.day_period {
+            properties.push((utf16!("dayPeriod"), day_period.to_string().into()));
+        }
+        let numeric_properties = [
+            (utf16!("hour"), components.hour),
+            (utf16!("minute"), components.minute),
+            (utf16!("second"), components.second),
+        ];
+        for (property, value) in numeric_properties {
+            if let Some(value) = value {
+                properties.push((property, value.to_string().into()));
+            }
+        }
+        if let Some(digits) = components.fractional_second_digits {
+            properties.push((utf16!("fractionalSecondDigits"), digits.into()));
+        }
+        if let Some(time_zone_name) = components.time_zone_name {
+            properties.push((utf16!("timeZoneName"), time_zone_name.to_string().into()));
+        }
+        if let Some(date_style) = dtf.date_style {
+            properties.push((utf16!("dateStyle"), date_style.to_string().into()));
+        }
+        if let Some(time_style) = dtf.time_style {
+            properties.push((utf16!("timeStyle"), time_style.to_string().into()));
+        }
+
+        for (property, value) in properties {
+            options
+                .create_data_property_or_throw(property, value, context)
+                .expect("operation must not fail per the spec");
+        }
+
+        // 6. Return options.
+        Ok(options.into())
+    }
+}
+
+/// Converts the `date` argument of the format methods to a time value, using the current time
+/// if the argument is undefined.
+fn to_time_value(date: &JsValue, context: &mut Context<'_>) -> JsResult<f64> {
+    if date.is_undefined() {
+        #[allow(clippy::cast_precision_loss)]
+        Ok(context.host_hooks().utc_now().timestamp_millis() as f64)
+    } else {
+        date.to_number(context)
+    }
+}
+
+/// Loads the locale data of `M` for `locale`.
+fn load<M>(locale: &DataLocale, provider: BoaProvider<'_>) -> JsResult<DataPayload<M>>
+where
+    M: KeyedDataMarker,
+    for<'a> BoaProvider<'a>: DataProvider<M>,
+{
+    DataProvider::<M>::load(
+        &provider,
+        DataRequest {
+            locale,
+            metadata: DataRequestMetadata::default(),
+        },
+    )
+    .and_then(DataResponse::take_payload)
+    .map_err(|e| JsNativeError::typ().with_message(e.to_string()).into())
+}
+
+/// Loads the date symbols and date length patterns of the calendar `kind` for `locale`.
+fn load_calendar_data(
+    kind: AnyCalendarKind,
+    locale: &DataLocale,
+    provider: BoaProvider<'_>,
+) -> Result<
+    (
+        DataPayload<ErasedDateSymbolsV1Marker>,
+        DataPayload<ErasedDateLengthsV1Marker>,
+    ),
+    DataError,
+> {
+    fn load_for<'a, C: CldrCalendar>(
+        locale: &DataLocale,
+        provider: BoaProvider<'a>,
+    ) -> Result<
+        (
+            DataPayload<ErasedDateSymbolsV1Marker>,
+            DataPayload<ErasedDateLengthsV1Marker>,
+        ),
+        DataError,
+    >
+    where
+        BoaProvider<'a>:
+            DataProvider<C::DateSymbolsV1Marker> + DataProvider<C::DateLengthsV1Marker>,
+    {
+        let symbols = DataProvider::<C::DateSymbolsV1Marker>::load(
+            &provider,
+            DataRequest {
+                locale,
+                metadata: DataRequestMetadata::default(),
+            },
+        )?
+        .take_payload()?
+        .cast();
+        let lengths = DataProvider::<C::DateLengthsV1Marker>::load(
+            &provider,
+            DataRequest {
+                locale,
+                metadata: DataRequestMetadata::default(),
+            },
+        )?
+        .take_payload()?
+        .cast();
+        Ok((symbols, lengths))
+    }
+
+    match kind {
+        AnyCalendarKind::Buddhist => load_for::<Buddhist>(locale, provider),
+        AnyCalendarKind::Japanese => load_for::<Japanese>(locale, provider),
+        AnyCalendarKind::JapaneseExtended => load_for::<JapaneseExtended>(locale, provider),
+        AnyCalendarKind::Coptic => load_for::<Coptic>(locale, provider),
+        AnyCalendarKind::Indian => load_for::<Indian>(locale, provider),
+        AnyCalendarKind::Ethiopian | AnyCalendarKind::EthiopianAmeteAlem => {
+            load_for::<Ethiopian>(locale, provider)
+        }
+        _ => load_for::<Gregorian>(locale, provider),
+    }
+}
+
+/// Abstract operation [`DateTimeStyleFormat ( dateStyle, timeStyle, styles )`][spec].
+///
+/// Gets the pattern of the locale data for the provided date and time styles.
+///
+/// [spec]: https://tc39.es/ecma402/#sec-date-time-style-format
+fn style_pattern(
+    date_style: Option<DateTimeStyle>,
+    time_style: Option<DateTimeStyle>,
+    hour_cycle: HourCycle,
+    date_lengths: &DateLengthsV1<'_>,
+    time_lengths: &TimeLengthsV1<'_>,
+) -> Result<Pattern<'static>, icu_datetime::pattern::PatternError> {
+    let date = date_style.map(|style| {
+        let date = &date_lengths.date;
+        match style {
+            DateTimeStyle::Full => date.full.clone(),
+            DateTimeStyle::Long => date.long.clone(),
+            DateTimeStyle::Medium => date.medium.clone(),
+            DateTimeStyle::Short => date.short.clone(),
+        }
+    });
+    let time = time_style.map(|style| {
+        let time = match hour_cycle {
+            HourCycle::H11 | HourCycle::H12 => &time_lengths.time_h11_h12,
+            _ => &time_lengths.time_h23_h24,
+        };
+        match style {
+            DateTimeStyle::Full => time.full.clone(),
+            DateTimeStyle::Long => time.long.clone(),
+            DateTimeStyle::Medium => time.medium.clone(),
+            DateTimeStyle::Short => time.short.clone(),
+        }
+    });
+
+    let pattern = match (date, time) {
+        (Some(date), Some(time)) => {
+            let combinations = &date_lengths.length_combinations;
+            let glue = match date_style {
+                Some(DateTimeStyle::Full) => &combinations.full,
+                Some(DateTimeStyle::Long) => &combinations.long,
+                Some(DateTimeStyle::Medium) => &combinations.medium,
+                _ => &combinations.short,
+            };
+            glue.clone().combined(date, time)?
+        }
+        (Some(pattern), None) | (None, Some(pattern)) => pattern,
+        (None, None) => date_lengths.date.medium.clone(),
+    };
+
+    Ok(pattern.into_owned())
+}
+
+/// Adjusts the fields of a pattern of the locale data to the resolved options of a
+/// `DateTimeFormat`.
+///
+/// This uses the exact hour cycle `hour_cycle` for the hour fields, the requested width for the
+/// day period fields and the localized GMT format for the time zone fields, which is the only
+/// time zone format supported.
+fn adjust_pattern(
+    pattern: &Pattern<'_>,
+    hour_cycle: HourCycle,
+    components: &Components,
+) -> Pattern<'static> {
+    let items: Vec<_> = pattern
+        .items
+        .iter()
+        .map(|item| match item {
+            PatternItem::Field(Field {
+                symbol: FieldSymbol::Hour(_),
+                length,
+            }) => {
+                let hour = match hour_cycle {
+                    HourCycle::H11 => fields::Hour::H11,
+                    HourCycle::H12 => fields::Hour::H12,
+                    HourCycle::H23 => fields::Hour::H23,
+                    HourCycle::H24 => fields::Hour::H24,
+                };
+                PatternItem::Field(Field {
+                    symbol: FieldSymbol::Hour(hour),
+                    length,
+                })
+            }
+            PatternItem::Field(Field {
+                symbol: symbol @ FieldSymbol::DayPeriod(_),
+                length,
+            }) => {
+                let length = match components.day_period {
+                    Some(TextWidth::Narrow) => FieldLength::Narrow,
+                    Some(TextWidth::Short) => FieldLength::Abbreviated,
+                    Some(TextWidth::Long) => FieldLength::Wide,
+                    None => length,
+                };
+                PatternItem::Field(Field { symbol, length })
+            }
+            PatternItem::Field(Field {
+                symbol: FieldSymbol::TimeZone(_),
+                length,
+            }) => {
+                let long = components
+                    .time_zone_name
+                    .map_or(length == FieldLength::Wide, TimeZoneNameStyle::is_long);
+                PatternItem::Field(Field {
+                    symbol: FieldSymbol::TimeZone(fields::TimeZone::UpperO),
+                    length: if long {
+                        FieldLength::Wide
+                    } else {
+                        FieldLength::One
+                    },
+                })
+            }
+            item => item,
+        })
+        .collect();
+
+    Pattern::from(items)
+}
+
+/// Represents the `required` and `defaults` arguments in the abstract operation
+/// `toDateTimeOptions`.
+///
+/// Since `required` and `defaults` differ only in the `any` and `all` variants,
+/// we combine both in a single variant `AnyAll`.
+#[derive(Debug, PartialEq)]
+pub(crate) enum DateTimeReqs {
+    Date,
+    Time,
+    AnyAll,
+}
+
+/// The abstract operation `toDateTimeOptions` is called with arguments `options`, `required` and
+/// `defaults`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-todatetimeoptions
+pub(crate) fn to_date_time_options(
+    options: &JsValue,
+    required: &DateTimeReqs,
+    defaults: &DateTimeReqs,
+    context: &mut Context<'_>,
+) -> JsResult<JsObject> {
+    // 1. If options is undefined, let options be null;
+    // otherwise let options be ? ToObject(options).
+    // 2. Let options be ! OrdinaryObjectCreate(options).
+    let options = if options.is_undefined() {
+        None
+    } else {
+        Some(options.to_object(context)?)
+    };
+    let options = JsObject::from_proto_and_data_with_shared_shape(
+        context.root_shape(),
+        options,
+        ObjectData::ordinary(),
+    );
+
+    // 3. Let needDefaults be true.
+    let mut need_defaults = true;
+
+    // 4. If required is "date" or "any", then
+    if [DateTimeReqs::Date, DateTimeReqs::AnyAll].contains(required) {
+        // a. For each property name prop of « "weekday", "year", "month", "day" », do
+        for property in [
+            utf16!("weekday"),
+            utf16!("year"),
+            utf16!("month"),
+            utf16!("day"),
+        ] {
+            // i. Let value be ? Get(options, prop).
+            let value = options.get(property, context)?;
+
+            // ii. If value is not undefined, let needDefaults be false.
+            if !value.is_undefined() {
+                need_defaults = false;
+            }
+        }
+    }
+
+    // 5. If required is "time" or "any", then
+    if [DateTimeReqs::Time, DateTimeReqs::AnyAll].contains(required) {
+        // a. For each property name prop of « "dayPeriod", "hour", "minute", "second",
+        // "fractionalSecondDigits" », do
+        for property in [
+            utf16!("dayPeriod"),
+            utf16!("hour"),
+            utf16!("minute"),
+            utf16!("second"),
+            utf16!("fractionalSecondDigits"),
+        ] {
+            // i. Let value be ? Get(options, prop).
+            let value = options.get(property, context)?;
+
+            // ii. If value is not undefined, let needDefaults be false.
+            if !value.is_undefined() {
+                need_defaults = false;
+            }
+        }
+    }
+
+    // 6. Let dateStyle be ? Get(options, "dateStyle").
+    let date_style = options.get(utf16!("dateStyle"), context)?;
+
+    // 7. Let timeStyle be ? Get(options, "timeStyle").
+    let time_style = options.get(utf16!("timeStyle"), context)?;
+
+    // 8. If dateStyle is not undefined or timeStyle is not undefined, let needDefaults be false.
+    if !date_style.is_undefined() || !time_style.is_undefined() {
+        need_defaults = false;
+    }
+
+    // 9. If required is "date" and timeStyle is not undefined, then
+    if required == &DateTimeReqs::Date && !time_style.is_undefined() {
+        // a. Throw a TypeError exception.
+        return Err(JsNativeError::typ()
+            .with_message("'date' is required, but timeStyle was defined")
+            .into());
+    }
+
+    // 10. If required is "time" and dateStyle is not undefined, then
+    if required == &DateTimeReqs::Time && !date_style.is_undefined() {
+        // a. Throw a TypeError exception.
+        return Err(JsNativeError::typ()
+            .with_message("'time' is required, but dateStyle was defined")
+            .into());
+    }
+
+    // 11. If needDefaults is true and defaults is either "date" or "all", then
+    if need_defaults && [DateTimeReqs::Date, DateTimeReqs::AnyAll].contains(defaults) {
+        // a. For each property name prop of « "year", "month", "day" », do
+        for property in [utf16!("year"), utf16!("month"), utf16!("day")] {
+            // i. Perform ? CreateDataPropertyOrThrow(options, prop, "numeric").
+            options.create_data_property_or_throw(property, "numeric", context)?;
+        }
+    }
+
+    // 12. If needDefaults is true and defaults is either "time" or "all", then
+    if need_defaults && [DateTimeReqs::Time, DateTimeReqs::AnyAll].contains(defaults) {
+        // a. For each property name prop of « "hour", "minute", "second" », do
+        for property in [utf16!("hour"), utf16!("minute"), utf16!("second")] {
+            // i. Perform ? CreateDataPropertyOrThrow(options, prop, "numeric").
+            options.create_data_property_or_throw(property, "numeric", context)?;
+        }
+    }
+
+    // 13. Return options.
+    Ok(options)
+}
@@ -0,0 +1,723 @@
+use std::{fmt::Display, str::FromStr};
+
+use chrono::NaiveDateTime;
+use icu_datetime::{
+    fields::{self, Field, FieldLength, FieldSymbol},
+    options::{components, preferences::HourCycle},
+    pattern::{runtime::Pattern, PatternItem},
+};
+
+use crate::{
+    builtins::intl::options::{get_number_option, get_option, OptionType, OptionTypeParsable},
+    context::TimeZoneProvider,
+    object::JsObject,
+    string::utf16,
+    Context, JsNativeError, JsResult, JsValue,
+};
+
+impl OptionType for HourCycle {
+    fn from_value(value: JsValue, context: &mut Context<'_>) -> JsResult<Self> {
+        match value.to_string(context)?.to_std_string_escaped().as_str() {
+            "h11" => Ok(Self::H11),
+            "h12" => Ok(Self::H12),
+            "h23" => Ok(Self::H23),
+            "h24" => Ok(Self::H24),
+            _ => Err(JsNativeError::range()
+                .with_message("provided string was not `h11`, `h12`, `h23` or `h24`")
+                .into()),
+        }
+    }
+}
+
+/// Returns the string representation of an hour cycle, as used by the `hourCycle` option and
+/// the `hc` Unicode extension key.
+pub(crate) const fn hour_cycle_to_str(hc: HourCycle) -> &'static str {
+    match hc {
+        HourCycle::H11 => "h11",
+        HourCycle::H12 => "h12",
+        HourCycle::H23 => "h23",
+        HourCycle::H24 => "h24",
+    }
+}
+
+/// Parses the value of an `hc` Unicode extension key.
+pub(crate) fn hour_cycle_from_str(hc: &str) -> Option<HourCycle> {
+    match hc {
+        "h11" => Some(HourCycle::H11),
+        "h12" => Some(HourCycle::H12),
+        "h23" => Some(HourCycle::H23),
+        "h24" => Some(HourCycle::H24),
+        _ => None,
+    }
+}
+
+/// The value of the `dateStyle` and `timeStyle` options.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DateTimeStyle {
+    Full,
+    Long,
+    Medium,
+    Short,
+}
+
+impl Display for DateTimeStyle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Full => "full",
+            Self::Long => "long",
+            Self::Medium => "medium",
+            Self::Short => "short",
+        }
+        .fmt(f)
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseDateTimeStyleError;
+
+impl Display for ParseDateTimeStyleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("provided string was not `full`, `long`, `medium` or `short`")
+    }
+}
+
+impl FromStr for DateTimeStyle {
+    type Err = ParseDateTimeStyleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "full" => Ok(Self::Full),
+            "long" => Ok(Self::Long),
+            "medium" => Ok(Self::Medium),
+            "short" => Ok(Self::Short),
+            _ => Err(ParseDateTimeStyleError),
+        }
+    }
+}
+
+impl OptionTypeParsable for DateTimeStyle {}
+
+/// The value of the `formatMatcher` option.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum FormatMatcher {
+    Basic,
+    #[default]
+    BestFit,
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseFormatMatcherError;
+
+impl Display for ParseFormatMatcherError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("provided string was not `basic` or `best fit`")
+    }
+}
+
+impl FromStr for FormatMatcher {
+    type Err = ParseFormatMatcherError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "basic" => Ok(Self::Basic),
+            "best fit" => Ok(Self::BestFit),
+            _ => Err(ParseFormatMatcherError),
+        }
+    }
+}
+
+impl OptionTypeParsable for FormatMatcher {}
+
+/// The value of the textual `weekday`, `era` and `dayPeriod` options.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TextWidth {
+    Narrow,
+    Short,
+    Long,
+}
+
+impl Display for TextWidth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Narrow => "narrow",
+            Self::Short => "short",
+            Self::Long => "long",
+        }
+        .fmt(f)
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseTextWidthError;
+
+impl Display for ParseTextWidthError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("provided string was not `narrow`, `short` or `long`")
+    }
+}
+
+impl FromStr for TextWidth {
+    type Err = ParseTextWidthError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "narrow" => Ok(Self::Narrow),
+            "short" => Ok(Self::Short),
+            "long" => Ok(Self::Long),
+            _ => Err(ParseTextWidthError),
+        }
+    }
+}
+
+impl OptionTypeParsable for TextWidth {}
+
+impl From<TextWidth> for components::Text {
+    fn from(width: TextWidth) -> Self {
+        match width {
+            TextWidth::Narrow => Self::Narrow,
+            TextWidth::Short => Self::Short,
+            TextWidth::Long => Self::Long,
+        }
+    }
+}
+
+/// The value of the numeric `year`, `day`, `hour`, `minute` and `second` options.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum NumericWidth {
+    Numeric,
+    TwoDigit,
+}
+
+impl Display for NumericWidth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Numeric => "numeric",
+            Self::TwoDigit => "2-digit",
+        }
+        .fmt(f)
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseNumericWidthError;
+
+impl Display for ParseNumericWidthError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("provided string was not `numeric` or `2-digit`")
+    }
+}
+
+impl FromStr for NumericWidth {
+    type Err = ParseNumericWidthError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "numeric" => Ok(Self::Numeric),
+            "2-digit" => Ok(Self::TwoDigit),
+            _ => Err(ParseNumericWidthError),
+        }
+    }
+}
+
+impl OptionTypeParsable for NumericWidth {}
+
+impl From<NumericWidth> for components::Numeric {
+    fn from(width: NumericWidth) -> Self {
+        match width {
+            NumericWidth::Numeric => Self::Numeric,
+            NumericWidth::TwoDigit => Self::TwoDigit,
+        }
+    }
+}
+
+/// The value of the `month` option.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum MonthWidth {
+    Numeric,
+    TwoDigit,
+    Narrow,
+    Short,
+    Long,
+}
+
+impl Display for MonthWidth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Numeric => "numeric",
+            Self::TwoDigit => "2-digit",
+            Self::Narrow => "narrow",
+            Self::Short => "short",
+            Self::Long => "long",
+        }
+        .fmt(f)
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseMonthWidthError;
+
+impl Display for ParseMonthWidthError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("provided string was not `numeric`, `2-digit`, `narrow`, `short` or `long`")
+    }
+}
+
+impl FromStr for MonthWidth {
+    type Err = ParseMonthWidthError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "numeric" => Ok(Self::Numeric),
+            "2-digit" => Ok(Self::TwoDigit),
+            "narrow" => Ok(Self::Narrow),
+            "short" => Ok(Self::Short),
+            "long" => Ok(Self::Long),
+            _ => Err(ParseMonthWidthError),
+        }
+    }
+}
+
+impl OptionTypeParsable for MonthWidth {}
+
+impl From<MonthWidth> for components::Month {
+    fn from(width: MonthWidth) -> Self {
+        match width {
+            MonthWidth::Numeric => Self::Numeric,
+            MonthWidth::TwoDigit => Self::TwoDigit,
+            MonthWidth::Narrow => Self::Narrow,
+            MonthWidth::Short => Self::Short,
+            MonthWidth::Long => Self::Long,
+        }
+    }
+}
+
+/// The value of the `timeZoneName` option.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TimeZoneNameStyle {
+    Short,
+    Long,
+    ShortOffset,
+    LongOffset,
+    ShortGeneric,
+    LongGeneric,
+}
+
+impl TimeZoneNameStyle {
+    /// Returns `true` if this style uses the long form of the time zone name.
+    pub(crate) const fn is_long(self) -> bool {
+        matches!(self, Self::Long | Self::LongOffset | Self::LongGeneric)
+    }
+}
+
+impl Display for TimeZoneNameStyle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Short => "short",
+            Self::Long => "long",
+            Self::ShortOffset => "shortOffset",
+            Self::LongOffset => "longOffset",
+            Self::ShortGeneric => "shortGeneric",
+            Self::LongGeneric => "longGeneric",
+        }
+        .fmt(f)
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseTimeZoneNameStyleError;
+
+impl Display for ParseTimeZoneNameStyleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(
+            "provided string was not `short`, `long`, `shortOffset`, `longOffset`, \
+             `shortGeneric` or `longGeneric`",
+        )
+    }
+}
+
+impl FromStr for TimeZoneNameStyle {
+    type Err = ParseTimeZoneNameStyleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "short" => Ok(Self::Short),
+            "long" => Ok(Self::Long),
+            "shortOffset" => Ok(Self::ShortOffset),
+            "longOffset" => Ok(Self::LongOffset),
+            "shortGeneric" => Ok(Self::ShortGeneric),
+            "longGeneric" => Ok(Self::LongGeneric),
+            _ => Err(ParseTimeZoneNameStyleError),
+        }
+    }
+}
+
+impl OptionTypeParsable for TimeZoneNameStyle {}
+
+impl From<TimeZoneNameStyle> for components::TimeZoneName {
+    fn from(style: TimeZoneNameStyle) -> Self {
+        match style {
+            TimeZoneNameStyle::Short => Self::ShortSpecific,
+            TimeZoneNameStyle::Long => Self::LongSpecific,
+            TimeZoneNameStyle::ShortOffset | TimeZoneNameStyle::LongOffset => Self::GmtOffset,
+            TimeZoneNameStyle::ShortGeneric => Self::ShortGeneric,
+            TimeZoneNameStyle::LongGeneric => Self::LongGeneric,
+        }
+    }
+}
+
+/// The date and time components requested by the options of a `DateTimeFormat`, as listed in
+/// [Table 6][spec] of the specification.
+///
+/// [spec]: https://tc39.es/ecma402/#table-datetimeformat-components
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct Components {
+    pub(crate) weekday: Option<TextWidth>,
+    pub(crate) era: Option<TextWidth>,
+    pub(crate) year: Option<NumericWidth>,
+    pub(crate) month: Option<MonthWidth>,
+    pub(crate) day: Option<NumericWidth>,
+    pub(crate) day_period: Option<TextWidth>,
+    pub(crate) hour: Option<NumericWidth>,
+    pub(crate) minute: Option<NumericWidth>,
+    pub(crate) second: Option<NumericWidth>,
+    pub(crate) fractional_second_digits: Option<u8>,
+    pub(crate) time_zone_name: Option<TimeZoneNameStyle>,
+}
+
+impl Components {
+    /// Gets the value of every component from the `options` object, in table order.
+    pub(crate) fn from_options(options: &JsObject, context: &mut Context<'_>) -> JsResult<Self> {
+        let weekday = get_option::<TextWidth>(options, utf16!("weekday"), false, context)?;
+        let era = get_option::<TextWidth>(options, utf16!("era"), false, context)?;
+        let year = get_option::<NumericWidth>(options, utf16!("year"), false, context)?;
+        let month = get_option::<MonthWidth>(options, utf16!("month"), false, context)?;
+        let day = get_option::<NumericWidth>(options, utf16!("day"), false, context)?;
+        let day_period = get_option::<TextWidth>(options, utf16!("dayPeriod"), false, context)?;
+        let hour = get_option::<NumericWidth>(options, utf16!("hour"), false, context)?;
+        let minute = get_option::<NumericWidth>(options, utf16!("minute"), false, context)?;
+        let second = get_option::<NumericWidth>(options, utf16!("second"), false, context)?;
+
+        // i. Let value be ? GetNumberOption(options, "fractionalSecondDigits", 1, 3, undefined).
+        let fractional_second_digits = get_number_option(
+            options,
+            utf16!("fractionalSecondDigits"),
+            1.0,
+            3.0,
+            None,
+            context,
+        )?
+        .map(|digits| digits as u8);
+
+        let time_zone_name =
+            get_option::<TimeZoneNameStyle>(options, utf16!("timeZoneName"), false, context)?;
+
+        Ok(Self {
+            weekday,
+            era,
+            year,
+            month,
+            day,
+            day_period,
+            hour,
+            minute,
+            second,
+            fractional_second_digits,
+            time_zone_name,
+        })
+    }
+
+    /// Checks if no component was requested.
+    pub(crate) fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Converts these components into an `icu_datetime` components bag, which is used to
+    /// match the requested components against the skeletons of the locale data.
+    pub(crate) fn to_bag(self, hour_cycle: HourCycle) -> components::Bag {
+        let mut bag = components::Bag::empty();
+        bag.era = self.era.map(Into::into);
+        bag.year = self.year.map(|year| match year {
+            NumericWidth::Numeric => components::Year::Numeric,
+            NumericWidth::TwoDigit => components::Year::TwoDigit,
+        });
+        bag.month = self.month.map(Into::into);
+        bag.day = self.day.map(|day| match day {
+            NumericWidth::Numeric => components::Day::NumericDayOfMonth,
+            NumericWidth::TwoDigit => components::Day::TwoDigitDayOfMonth,
+        });
+        bag.weekday = self.weekday.map(Into::into);
+        bag.hour = self.hour.map(Into::into);
+        bag.minute = self.minute.map(Into::into);
+        bag.second = self.second.map(Into::into);
+        bag.fractional_second = self.fractional_second_digits;
+        bag.time_zone_name = self.time_zone_name.map(Into::into);
+        if self.hour.is_some() {
+            bag.preferences = Some(icu_datetime::options::preferences::Bag::from_hour_cycle(
+                hour_cycle,
+            ));
+        }
+        bag
+    }
+
+    /// Gets the list of skeleton fields of these components, which must be sorted in the same
+    /// order as the fields of the skeletons in the locale data.
+    pub(crate) fn to_fields(self, hour_cycle: HourCycle) -> Vec<Field> {
+        const fn numeric(width: NumericWidth) -> FieldLength {
+            match width {
+                NumericWidth::Numeric => FieldLength::One,
+                NumericWidth::TwoDigit => FieldLength::TwoDigit,
+            }
+        }
+
+        let mut fields = Vec::new();
+        let mut push = |symbol, length| fields.push(Field { symbol, length });
+
+        if let Some(era) = self.era {
+            let length = match era {
+                TextWidth::Narrow => FieldLength::Narrow,
+                TextWidth::Short => FieldLength::Abbreviated,
+                TextWidth::Long => FieldLength::Wide,
+            };
+            push(FieldSymbol::Era, length);
+        }
+        if let Some(year) = self.year {
+            push(FieldSymbol::Year(fields::Year::Calendar), numeric(year));
+        }
+        if let Some(month) = self.month {
+            let length = match month {
+                MonthWidth::Numeric => FieldLength::One,
+                MonthWidth::TwoDigit => FieldLength::TwoDigit,
+                MonthWidth::Narrow => FieldLength::Narrow,
+                MonthWidth::Short => FieldLength::Abbreviated,
+                MonthWidth::Long => FieldLength::Wide,
+            };
+            push(FieldSymbol::Month(fields::Month::Format), length);
+        }
+        if let Some(day) = self.day {
+            push(FieldSymbol::Day(fields::Day::DayOfMonth), numeric(day));
+        }
+        if let Some(weekday) = self.weekday {
+            let length = match weekday {
+                TextWidth::Narrow => FieldLength::Narrow,
+                TextWidth::Short => FieldLength::One,
+                TextWidth::Long => FieldLength::Wide,
+            };
+            push(FieldSymbol::Weekday(fields::Weekday::Format), length);
+        }
+        if let Some(hour) = self.hour {
+            // Skeletons only use the `h` and `H` hour symbols.
+            let symbol = match hour_cycle {
+                HourCycle::H11 | HourCycle::H12 => fields::Hour::H12,
+                _ => fields::Hour::H23,
+            };
+            push(FieldSymbol::Hour(symbol), numeric(hour));
+        }
+        if let Some(minute) = self.minute {
+            push(FieldSymbol::Minute, numeric(minute));
+        }
+        if let Some(second) = self.second {
+            push(FieldSymbol::Second(fields::Second::Second), numeric(second));
+        }
+        if let Some(digits) = self.fractional_second_digits {
+            push(
+                FieldSymbol::Second(fields::Second::FractionalSecond),
+                FieldLength::Fixed(digits),
+            );
+        }
+        if self.time_zone_name.is_some() {
+            // Skeletons only use the `v` time zone symbol.
+            push(
+                FieldSymbol::TimeZone(fields::TimeZone::LowerV),
+                FieldLength::One,
+            );
+        }
+
+        fields
+    }
+
+    /// Gets the components that are displayed by `pattern`.
+    ///
+    /// The `dayPeriod` and `timeZoneName` components are taken from `requested` instead, since
+    /// their field in the pattern doesn't reflect the width that was requested.
+    pub(crate) fn from_pattern(pattern: &Pattern<'_>, requested: &Self) -> Self {
+        const fn numeric(length: FieldLength) -> NumericWidth {
+            match length {
+                FieldLength::TwoDigit => NumericWidth::TwoDigit,
+                _ => NumericWidth::Numeric,
+            }
+        }
+
+        let mut components = Self::default();
+        for item in pattern.items.iter() {
+            let PatternItem::Field(Field { symbol, length }) = item else {
+                continue;
+            };
+            match symbol {
+                FieldSymbol::Era => {
+                    components.era = Some(match length {
+                        FieldLength::Wide => TextWidth::Long,
+                        FieldLength::Narrow => TextWidth::Narrow,
+                        _ => TextWidth::Short,
+                    });
+                }
+                FieldSymbol::Year(_) => components.year = Some(numeric(length)),
+                FieldSymbol::Month(_) => {
+                    components.month = Some(match length {
+                        FieldLength::One => MonthWidth::Numeric,
+                        FieldLength::TwoDigit => MonthWidth::TwoDigit,
+                        FieldLength::Wide => MonthWidth::Long,
+                        FieldLength::Narrow => MonthWidth::Narrow,
+                        _ => MonthWidth::Short,
+                    });
+                }
+                FieldSymbol::Day(_) => components.day = Some(numeric(length)),
+                FieldSymbol::Weekday(_) => {
+                    components.weekday = Some(match length {
+                        FieldLength::Wide => TextWidth::Long,
+                        FieldLength::Narrow => TextWidth::Narrow,
+                        _ => TextWidth::Short,
+                    });
+                }
+                FieldSymbol::DayPeriod(_) => components.day_period = requested.day_period,
+                FieldSymbol::Hour(_) => components.hour = Some(numeric(length)),
+                FieldSymbol::Minute => components.minute = Some(numeric(length)),
+                FieldSymbol::Second(fields::Second::FractionalSecond) => {
+                    if let FieldLength::Fixed(digits) = length {
+                        components.fractional_second_digits = Some(digits);
+                    }
+                }
+                FieldSymbol::Second(_) => components.second = Some(numeric(length)),
+                FieldSymbol::TimeZone(_) => components.time_zone_name = requested.time_zone_name,
+                FieldSymbol::Week(_) => {}
+            }
+        }
+
+        components
+    }
+}
+
+/// A time zone supported by `Intl.DateTimeFormat`.
+///
+/// Named time zones other than UTC are the ones available in the [`TimeZoneProvider`] of the
+/// context.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum TimeZone {
+    /// The time zone of the host, as given by [`HostHooks::local_from_utc`], if the
+    /// [`TimeZoneProvider`] doesn't know its identifier.
+    Host,
+    /// The `"UTC"` time zone.
+    Utc,
+    /// A fixed offset from UTC, in minutes.
+    Offset(i16),
+    /// The primary identifier of a named time zone of the [`TimeZoneProvider`].
+    Named(String),
+}
+
+impl TimeZone {
+    /// Abstract operations [`IsValidTimeZoneName ( timeZone )`][valid] and
+    /// [`CanonicalizeTimeZoneName ( timeZone )`][canon].
+    ///
+    /// Returns `None` if `time_zone` is not the name of a supported time zone.
+    ///
+    /// [valid]: https://tc39.es/ecma402/#sec-isvalidtimezonename
+    /// [canon]: https://tc39.es/ecma402/#sec-canonicalizetimezonename
+    pub(crate) fn from_name(time_zone: &str, provider: &dyn TimeZoneProvider) -> Option<Self> {
+        const UTC_ALIASES: [&str; 12] = [
+            "utc",
+            "etc/utc",
+            "etc/uct",
+            "uct",
+            "etc/gmt",
+            "gmt",
+            "etc/universal",
+            "universal",
+            "etc/zulu",
+            "zulu",
+            "etc/greenwich",
+            "greenwich",
+        ];
+
+        if UTC_ALIASES.contains(&time_zone.to_ascii_lowercase().as_str()) {
+            return Some(Self::Utc);
+        }
+
+        // Offset time zones have the form `±HH`, `±HHMM` or `±HH:MM`.
+        let (sign, offset) = match time_zone.as_bytes().first()? {
+            b'+' => (1, &time_zone[1..]),
+            b'-' => (-1, &time_zone[1..]),
+            _ => {
+                let identifier = provider.normalize(time_zone)?;
+                return Some(match provider.primary_identifier(&identifier) {
+                    primary if UTC_ALIASES.contains(&primary.to_ascii_lowercase().as_str()) => {
+                        Self::Utc
+                    }
+                    primary => Self::Named(primary),
+                });
+            }
+        };
+        let (hours, minutes) = match offset.len() {
+            2 => (offset, "00"),
+            4 => offset.split_at(2),
+            5 if offset.as_bytes()[2] == b':' => (&offset[..2], &offset[3..]),
+            _ => return None,
+        };
+        if !hours
+            .bytes()
+            .chain(minutes.bytes())
+            .all(|b| b.is_ascii_digit())
+        {
+            return None;
+        }
+        let hours: i16 = hours.parse().ok()?;
+        let minutes: i16 = minutes.parse().ok()?;
+        if hours > 23 || minutes > 59 {
+            return None;
+        }
+
+        Some(Self::Offset(sign * (hours * 60 + minutes)))
+    }
+
+    /// Abstract operation [`DefaultTimeZone ( )`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-defaulttimezone
+    pub(crate) fn host(provider: &dyn TimeZoneProvider) -> Self {
+        provider
+            .host_time_zone()
+            .and_then(|time_zone| Self::from_name(&time_zone, provider))
+            .unwrap_or(Self::Host)
+    }
+
+    /// Gets the offset from UTC of this time zone at the UTC time `utc`, in seconds.
+    pub(crate) fn offset_at(&self, utc: NaiveDateTime, context: &Context<'_>) -> i32 {
+        match self {
+            Self::Host => context
+                .host_hooks()
+                .local_from_utc(utc)
+                .offset()
+                .local_minus_utc(),
+            Self::Utc => 0,
+            Self::Offset(minutes) => i32::from(*minutes) * 60,
+            Self::Named(identifier) => context
+                .time_zone_provider()
+                .offset(identifier, utc.timestamp()),
+        }
+    }
+
+    /// Gets the canonical name of this time zone.
+    ///
+    /// The host time zone doesn't have an identifier, so it is named after its current offset.
+    pub(crate) fn name(&self, context: &Context<'_>) -> String {
+        let minutes = match self {
+            Self::Host => match self.offset_at(context.host_hooks().utc_now(), context) / 60 {
+                0 => return "UTC".to_owned(),
+                minutes => minutes,
+            },
+            Self::Utc => return "UTC".to_owned(),
+            Self::Offset(minutes) => i32::from(*minutes),
+            Self::Named(identifier) => return identifier.clone(),
+        };
+        let sign = if minutes < 0 { '-' } else { '+' };
+        let minutes = minutes.abs();
+        format!("{sign}{:02}:{:02}", minutes / 60, minutes % 60)
+    }
+}
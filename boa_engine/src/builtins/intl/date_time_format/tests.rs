@@ -0,0 +1,104 @@
+use icu_datetime::{
+    fields::{self, Field, FieldLength, FieldSymbol},
+    options::preferences::HourCycle,
+    pattern::{runtime::Pattern, PatternItem},
+};
+
+use crate::{
+    builtins::intl::date_time_format::{
+        adjust_pattern, Components, MonthWidth, NumericWidth, TextWidth, TimeZone,
+        TimeZoneNameStyle,
+    },
+    context::DefaultTimeZoneProvider,
+};
+
+fn fields(pattern: &Pattern<'_>) -> Vec<Field> {
+    pattern
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            PatternItem::Field(field) => Some(field),
+            PatternItem::Literal(_) => None,
+        })
+        .collect()
+}
+
+#[test]
+fn time_zone_names() {
+    let provider = &DefaultTimeZoneProvider;
+    let from_name = |name| TimeZone::from_name(name, provider);
+
+    assert_eq!(from_name("UTC"), Some(TimeZone::Utc));
+    assert_eq!(from_name("etc/gmt"), Some(TimeZone::Utc));
+    assert_eq!(from_name("Etc/GMT+0"), Some(TimeZone::Utc));
+    assert_eq!(from_name("+05:30"), Some(TimeZone::Offset(330)));
+    assert_eq!(from_name("-0800"), Some(TimeZone::Offset(-480)));
+    assert_eq!(from_name("+01"), Some(TimeZone::Offset(60)));
+    assert_eq!(from_name("+24:00"), None);
+    assert_eq!(from_name("+5:30"), None);
+    assert_eq!(
+        from_name("etc/gmt-5"),
+        Some(TimeZone::Named("Etc/GMT-5".to_owned()))
+    );
+    assert_eq!(from_name("Mars/Olympus_Mons"), None);
+}
+
+#[test]
+fn adjusted_pattern_fields() {
+    let pattern: Pattern<'_> = "h:mm a zzzz".parse().unwrap();
+
+    let adjusted = adjust_pattern(&pattern, HourCycle::H23, &Components::default());
+    assert_eq!(
+        fields(&adjusted),
+        [
+            Field {
+                symbol: FieldSymbol::Hour(fields::Hour::H23),
+                length: FieldLength::One,
+            },
+            Field {
+                symbol: FieldSymbol::Minute,
+                length: FieldLength::TwoDigit,
+            },
+            Field {
+                symbol: FieldSymbol::DayPeriod(fields::DayPeriod::AmPm),
+                length: FieldLength::One,
+            },
+            Field {
+                symbol: FieldSymbol::TimeZone(fields::TimeZone::UpperO),
+                length: FieldLength::Wide,
+            },
+        ]
+    );
+
+    let components = Components {
+        day_period: Some(TextWidth::Long),
+        time_zone_name: Some(TimeZoneNameStyle::ShortOffset),
+        ..Components::default()
+    };
+    let adjusted = adjust_pattern(&pattern, HourCycle::H11, &components);
+    assert_eq!(
+        fields(&adjusted)[0].symbol,
+        FieldSymbol::Hour(fields::Hour::H11)
+    );
+    assert_eq!(fields(&adjusted)[2].length, FieldLength::Wide);
+    assert_eq!(fields(&adjusted)[3].length, FieldLength::One);
+}
+
+#[test]
+fn components_of_pattern() {
+    let pattern: Pattern<'_> = "EEEE, MMMM d, y 'at' HH:mm".parse().unwrap();
+
+    let components = Components::from_pattern(&pattern, &Components::default());
+    assert_eq!(
+        components,
+        Components {
+            weekday: Some(TextWidth::Long),
+            year: Some(NumericWidth::Numeric),
+            month: Some(MonthWidth::Long),
+            day: Some(NumericWidth::Numeric),
+            hour: Some(NumericWidth::TwoDigit),
+            minute: Some(NumericWidth::TwoDigit),
+            ..Components::default()
+        }
+    );
+}
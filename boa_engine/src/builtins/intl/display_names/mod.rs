@@ -0,0 +1,543 @@
+use boa_gc::{empty_trace, Finalize, Trace};
+use boa_profiler::Profiler;
+use icu_decimal::provider::DecimalSymbolsV1Marker;
+use icu_locid::{
+    langid,
+    subtags::{Region, Script, Variant},
+    LanguageIdentifier, Locale,
+};
+
+use crate::{
+    builtins::{BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject},
+    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    object::{internal_methods::get_prototype_from_constructor, JsObject, ObjectData},
+    property::Attribute,
+    realm::Realm,
+    string::utf16,
+    symbol::JsSymbol,
+    Context, JsArgs, JsNativeError, JsResult, JsValue,
+};
+
+use super::{
+    data::{LocaleChain, DISPLAY_NAMES},
+    locale::{canonicalize_locale_list, resolve_locale, supported_locales},
+    options::{get_option, get_options_object, IntlOptions, LocaleMatcher},
+    Service,
+};
+
+mod names;
+mod options;
+pub(crate) use options::*;
+
+#[cfg(test)]
+mod tests;
+
+pub struct DisplayNames {
+    locale: Locale,
+    style: Style,
+    typ: DisplayType,
+    fallback: Fallback,
+    language_display: LanguageDisplay,
+    data: LocaleChain,
+}
+
+impl Finalize for DisplayNames {}
+
+// SAFETY: `DisplayNames` doesn't contain any traceable data.
+unsafe impl Trace for DisplayNames {
+    empty_trace!();
+}
+
+impl std::fmt::Debug for DisplayNames {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DisplayNames")
+            .field("locale", &self.locale)
+            .field("style", &self.style)
+            .field("typ", &self.typ)
+            .field("fallback", &self.fallback)
+            .field("language_display", &self.language_display)
+            .field("data", &self.data)
+            .finish()
+    }
+}
+
+impl Service for DisplayNames {
+    type LangMarker = DecimalSymbolsV1Marker;
+
+    type LocaleOptions = ();
+}
+
+impl IntrinsicObject for DisplayNames {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .static_method(Self::supported_locales_of, "supportedLocalesOf", 1)
+            .property(
+                JsSymbol::to_string_tag(),
+                "Intl.DisplayNames",
+                Attribute::CONFIGURABLE,
+            )
+            .method(Self::of, "of", 1)
+            .method(Self::resolved_options, "resolvedOptions", 0)
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for DisplayNames {
+    const NAME: &'static str = "DisplayNames";
+}
+
+impl BuiltInConstructor for DisplayNames {
+    const LENGTH: usize = 2;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::display_names;
+
+    /// Constructor [`Intl.DisplayNames ( locales, options )`][spec].
+    ///
+    /// Constructor for `DisplayNames` objects.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DisplayNames
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DisplayNames/DisplayNames
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("cannot call `Intl.DisplayNames` constructor without `new`")
+                .into());
+        }
+
+        let locales = args.get_or_undefined(0);
+        let options = args.get_or_undefined(1);
+
+        // 2. Let displayNames be ? OrdinaryCreateFromConstructor(NewTarget, "%DisplayNames.prototype%", « [[InitializedDisplayNames]], [[Locale]], [[Style]], [[Type]], [[Fallback]], [[LanguageDisplay]], [[Fields]] »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::display_names,
+            context,
+        )?;
+
+        // 3. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(locales, context)?;
+
+        // 4. If options is undefined, throw a TypeError exception.
+        if options.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("`Intl.DisplayNames` requires an options object")
+                .into());
+        }
+
+        // 5. Set options to ? GetOptionsObject(options).
+        let options = get_options_object(options)?;
+
+        // 6. Let opt be a new Record.
+        // 7. Let localeData be %DisplayNames%.[[LocaleData]].
+        // 8. Let matcher be ? GetOption(options, "localeMatcher", string, « "lookup", "best fit" », "best fit").
+        // 9. Set opt.[[localeMatcher]] to matcher.
+        let matcher =
+            get_option::<LocaleMatcher>(&options, utf16!("localeMatcher"), false, context)?
+                .unwrap_or_default();
+
+        // 10. Let r be ResolveLocale(%DisplayNames%.[[AvailableLocales]], requestedLocales, opt, %DisplayNames%.[[RelevantExtensionKeys]], localeData).
+        let mut locale = resolve_locale::<Self>(
+            &requested_locales,
+            &mut IntlOptions {
+                matcher,
+                ..Default::default()
+            },
+            context.icu(),
+        );
+
+        // Only a subset of the available locales has CLDR data for display names. The others fall
+        // back to English.
+        let data = DISPLAY_NAMES
+            .lookup(&locale.id, context.icu().locale_expander())
+            .unwrap_or_else(|| {
+                locale.id = langid!("en");
+                DISPLAY_NAMES
+                    .chain("en")
+                    .expect("the locale data must have English")
+            });
+
+        // 11. Let style be ? GetOption(options, "style", string, « "narrow", "short", "long" », "long").
+        // 12. Set displayNames.[[Style]] to style.
+        let style =
+            get_option::<Style>(&options, utf16!("style"), false, context)?.unwrap_or_default();
+
+        // 13. Let type be ? GetOption(options, "type", string, « "language", "region", "script", "currency", "calendar", "dateTimeField" », undefined).
+        // 14. If type is undefined, throw a TypeError exception.
+        // 15. Set displayNames.[[Type]] to type.
+        let typ = get_option::<DisplayType>(&options, utf16!("type"), false, context)?.ok_or_else(
+            || JsNativeError::typ().with_message("`Intl.DisplayNames` requires a `type` option"),
+        )?;
+
+        // 16. Let fallback be ? GetOption(options, "fallback", string, « "code", "none" », "code").
+        // 17. Set displayNames.[[Fallback]] to fallback.
+        let fallback = get_option::<Fallback>(&options, utf16!("fallback"), false, context)?
+            .unwrap_or_default();
+
+        // 18. Set displayNames.[[Locale]] to r.[[locale]].
+        // 19. Let dataLocale be r.[[dataLocale]].
+        // 20. Let dataLocaleData be localeData.[[<dataLocale>]].
+        // 21. Let types be dataLocaleData.[[types]].
+        // 22. Assert: types is a Record (see 12.2.3).
+        // 23. Let languageDisplay be ? GetOption(options, "languageDisplay", string, « "dialect", "standard" », "dialect").
+        let language_display =
+            get_option::<LanguageDisplay>(&options, utf16!("languageDisplay"), false, context)?
+                .unwrap_or_default();
+
+        // 24. Let typeFields be types.[[<type>]].
+        // 25. Assert: typeFields is a Record (see 12.2.3).
+        // 26. If type is "language", then
+        //     a. Set displayNames.[[LanguageDisplay]] to languageDisplay.
+        //     b. Let typeFields be typeFields.[[<languageDisplay>]].
+        //     c. Assert: typeFields is a Record (see 12.2.3).
+        // 27. Let styleFields be typeFields.[[<style>]].
+        // 28. Assert: styleFields is a Record (see 12.2.3).
+        // 29. Set displayNames.[[Fields]] to styleFields.
+        // 30. Return displayNames.
+        Ok(JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            ObjectData::display_names(Self {
+                locale,
+                style,
+                typ,
+                fallback,
+                language_display,
+                data,
+            }),
+        )
+        .into())
+    }
+}
+
+impl DisplayNames {
+    /// [`Intl.DisplayNames.supportedLocalesOf ( locales [ , options ] )`][spec].
+    ///
+    /// Returns an array containing those of the provided locales that are supported in display
+    /// names without having to fall back to the runtime's default locale.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DisplayNames.supportedLocalesOf
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DisplayNames/supportedLocalesOf
+    fn supported_locales_of(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        let locales = args.get_or_undefined(0);
+        let options = args.get_or_undefined(1);
+
+        // 1. Let availableLocales be %DisplayNames%.[[AvailableLocales]].
+        // 2. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(locales, context)?;
+
+        // 3. Return ? SupportedLocales(availableLocales, requestedLocales, options).
+        supported_locales::<<Self as Service>::LangMarker>(&requested_locales, options, context)
+            .map(JsValue::from)
+    }
+
+    /// [`Intl.DisplayNames.prototype.of ( code )`][spec].
+    ///
+    /// Returns the display name of `code`, which is interpreted according to the `type` of this
+    /// `Intl.DisplayNames` object.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DisplayNames.prototype.of
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DisplayNames/of
+    fn of(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Let displayNames be this value.
+        // 2. Perform ? RequireInternalSlot(displayNames, [[InitializedDisplayNames]]).
+        let display_names = this.as_object().map(JsObject::borrow).ok_or_else(|| {
+            JsNativeError::typ().with_message("`of` can only be called on a `DisplayNames` object")
+        })?;
+        let display_names = display_names.as_display_names().ok_or_else(|| {
+            JsNativeError::typ().with_message("`of` can only be called on a `DisplayNames` object")
+        })?;
+
+        // 3. Let code be ? ToString(code).
+        let code = args
+            .get_or_undefined(0)
+            .to_string(context)?
+            .to_std_string_escaped();
+
+        // 4. Set code to ? CanonicalCodeForDisplayNames(displayNames.[[Type]], code).
+        let mut code = canonical_code(display_names.typ, &code)?;
+        if display_names.typ == DisplayType::Language {
+            let mut locale = code
+                .parse::<Locale>()
+                .expect("canonical codes must be valid language identifiers");
+            context
+                .icu()
+                .locale_canonicalizer()
+                .canonicalize(&mut locale);
+            code = locale.id.to_string();
+        }
+
+        // 5. Let fields be displayNames.[[Fields]].
+        // 6. If fields has a field [[<code>]], return fields.[[<code>]].
+        if let Some(name) = display_names.name_of(&code) {
+            return Ok(name.into());
+        }
+
+        // 7. If displayNames.[[Fallback]] is "code", return code.
+        // 8. Return undefined.
+        match display_names.fallback {
+            Fallback::Code => Ok(code.into()),
+            Fallback::None => Ok(JsValue::undefined()),
+        }
+    }
+
+    /// Gets the display name of a canonicalized `code`, if it has one.
+    fn name_of(&self, code: &str) -> Option<String> {
+        match self.typ {
+            DisplayType::Language => {
+                let id = code
+                    .parse::<LanguageIdentifier>()
+                    .expect("canonicalized codes must be valid language identifiers");
+                language_name(&self.data, &id, self.language_display, self.style)
+            }
+            DisplayType::Region => names::region(&self.data, code, self.style).map(String::from),
+            DisplayType::Script => names::script(&self.data, code, self.style).map(String::from),
+            DisplayType::Currency => names::currency(&self.data, code).map(String::from),
+            DisplayType::Calendar => names::calendar(&self.data, code).map(String::from),
+            DisplayType::DateTimeField => {
+                names::date_time_field(&self.data, code, self.style).map(String::from)
+            }
+        }
+    }
+
+    /// [`Intl.DisplayNames.prototype.resolvedOptions ( )`][spec].
+    ///
+    /// Returns a new object with properties reflecting the locale and options computed during the
+    /// construction of the current `Intl.DisplayNames` object.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DisplayNames.prototype.resolvedOptions
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DisplayNames/resolvedOptions
+    fn resolved_options(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. Let displayNames be this value.
+        // 2. Perform ? RequireInternalSlot(displayNames, [[InitializedDisplayNames]]).
+        let display_names = this.as_object().map(JsObject::borrow).ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("`resolvedOptions` can only be called on a `DisplayNames` object")
+        })?;
+        let display_names = display_names.as_display_names().ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("`resolvedOptions` can only be called on a `DisplayNames` object")
+        })?;
+
+        // 3. Let options be OrdinaryObjectCreate(%Object.prototype%).
+        let options = context
+            .intrinsics()
+            .templates()
+            .ordinary_object()
+            .create(ObjectData::ordinary(), vec![]);
+
+        // 4. For each row of Table 9, except the header row, in table order, do
+        //     a. Let p be the Property value of the current row.
+        //     b. Let v be the value of displayNames's internal slot whose name is the Internal Slot value of the current row.
+        //     c. Assert: v is not undefined.
+        //     d. Perform ! CreateDataPropertyOrThrow(options, p, v).
+        let mut fields: Vec<(&[u16], JsValue)> = vec![
+            (utf16!("locale"), display_names.locale.to_string().into()),
+            (utf16!("style"), display_names.style.to_string().into()),
+            (utf16!("type"), display_names.typ.to_string().into()),
+            (
+                utf16!("fallback"),
+                display_names.fallback.to_string().into(),
+            ),
+        ];
+        if display_names.typ == DisplayType::Language {
+            fields.push((
+                utf16!("languageDisplay"),
+                display_names.language_display.to_string().into(),
+            ));
+        }
+        for (key, value) in fields {
+            options
+                .create_data_property_or_throw(key, value, context)
+                .expect("operation must not fail per the spec");
+        }
+
+        // 5. Return options.
+        Ok(options.into())
+    }
+}
+
+/// Returns the codes of the currencies that have a display name.
+pub(in crate::builtins::intl) fn available_currencies() -> impl Iterator<Item = &'static str> {
+    names::CURRENCIES.iter().copied()
+}
+
+/// Abstract operation [`CanonicalCodeForDisplayNames ( type, code )`][spec].
+///
+/// Validates `code` according to `typ` and returns it in its canonical form, except for language
+/// identifiers, which still have to be canonicalized with the locale canonicalizer of the context.
+///
+/// [spec]: https://tc39.es/ecma402/#sec-canonicalcodefordisplaynames
+fn canonical_code(typ: DisplayType, code: &str) -> JsResult<String> {
+    let invalid = || -> JsResult<String> {
+        Err(JsNativeError::range()
+            .with_message(format!("invalid code `{code}` for the `{typ}` type"))
+            .into())
+    };
+
+    let all_alpha = |c: &str| c.bytes().all(|b| b.is_ascii_alphabetic());
+
+    match typ {
+        // 1. If type is "language", then
+        DisplayType::Language => {
+            // a. If code does not match the unicode_language_id production, throw a RangeError exception.
+            // b. If IsStructurallyValidLanguageTag(code) is false, throw a RangeError exception.
+            if code.contains('_') {
+                return invalid();
+            }
+            let Ok(id) = code.parse::<LanguageIdentifier>() else {
+                return invalid();
+            };
+            // The parser silently drops repeated variants, which are not structurally valid.
+            let subtags = 1
+                + usize::from(id.script.is_some())
+                + usize::from(id.region.is_some())
+                + id.variants.len();
+            if code.split('-').count() != subtags {
+                return invalid();
+            }
+
+            // c. Return CanonicalizeUnicodeLocaleId(code).
+            // The canonicalization is done by the caller, since it requires locale data.
+            Ok(id.to_string())
+        }
+        // 2. If type is "region", then
+        DisplayType::Region => {
+            // a. If code does not match the unicode_region_subtag production, throw a RangeError exception.
+            let valid = match code.len() {
+                2 => all_alpha(code),
+                3 => code.bytes().all(|b| b.is_ascii_digit()),
+                _ => false,
+            };
+            if !valid {
+                return invalid();
+            }
+            // b. Return the ASCII-uppercase of code.
+            Ok(code.to_ascii_uppercase())
+        }
+        // 3. If type is "script", then
+        DisplayType::Script => {
+            // a. If code does not match the unicode_script_subtag production, throw a RangeError exception.
+            if code.len() != 4 || !all_alpha(code) {
+                return invalid();
+            }
+            // b. Assert: The length of code is 4, and every code unit of code represents an ASCII letter.
+            // c. Let first be the ASCII-uppercase of the substring of code from 0 to 1.
+            // d. Let rest be the ASCII-lowercase of the substring of code from 1.
+            // e. Return the string-concatenation of first and rest.
+            Ok(code[..1].to_ascii_uppercase() + &code[1..].to_ascii_lowercase())
+        }
+        // 4. If type is "calendar", then
+        DisplayType::Calendar => {
+            // a. If code does not match the Unicode Locale Identifier type nonterminal, throw a RangeError exception.
+            // b. If code uses any of the backwards compatibility syntax described in Unicode Technical Standard #35 LDML § 3.3 BCP 47 Conformance, throw a RangeError exception.
+            let valid = !code.is_empty()
+                && code.split('-').all(|subtag| {
+                    (3..=8).contains(&subtag.len())
+                        && subtag.bytes().all(|b| b.is_ascii_alphanumeric())
+                });
+            if !valid {
+                return invalid();
+            }
+            // c. Return the ASCII-lowercase of code.
+            Ok(code.to_ascii_lowercase())
+        }
+        // 5. If type is "dateTimeField", then
+        DisplayType::DateTimeField => {
+            // a. If the result of IsValidDateTimeFieldCode(code) is false, throw a RangeError exception.
+            if !names::is_valid_date_time_field(code) {
+                return invalid();
+            }
+            // b. Return code.
+            Ok(code.to_string())
+        }
+        // 6. Assert: type is "currency".
+        DisplayType::Currency => {
+            // 7. If IsWellFormedCurrencyCode(code) is false, throw a RangeError exception.
+            if code.len() != 3 || !all_alpha(code) {
+                return invalid();
+            }
+            // 8. Return the ASCII-uppercase of code.
+            Ok(code.to_ascii_uppercase())
+        }
+    }
+}
+
+/// Gets the display name of a language identifier in the locale of `data`.
+///
+/// The name is composed of the name of its language, followed by the names of its script,
+/// region and variants in parentheses (e.g. "Chinese (Traditional, Taiwan)"). If
+/// `language_display` is `"dialect"`, the language and region or script are displayed with
+/// their dialect name when available (e.g. "British English").
+fn language_name(
+    data: &LocaleChain,
+    id: &LanguageIdentifier,
+    language_display: LanguageDisplay,
+    style: Style,
+) -> Option<String> {
+    let language = id.language.as_str();
+    let mut script = id.script.as_ref().map(Script::as_str);
+    let mut region = id.region.as_ref().map(Region::as_str);
+
+    let mut dialect = None;
+    if language_display == LanguageDisplay::Dialect {
+        if let Some(name) =
+            region.and_then(|r| names::language(data, &format!("{language}-{r}"), style))
+        {
+            dialect = Some(name);
+            region = None;
+        } else if let Some(name) =
+            script.and_then(|s| names::language(data, &format!("{language}-{s}"), style))
+        {
+            dialect = Some(name);
+            script = None;
+        }
+    }
+
+    let name = dialect.or_else(|| names::language(data, language, style))?;
+
+    let qualifiers = script
+        .map(|s| names::script_qualifier(data, s).unwrap_or(s))
+        .into_iter()
+        .chain(region.map(|r| names::region(data, r, style).unwrap_or(r)))
+        .chain(id.variants.iter().map(Variant::as_str))
+        .collect::<Vec<_>>();
+
+    if qualifiers.is_empty() {
+        Some(name.to_string())
+    } else {
+        let (open, close, separator) = names::qualifier_pattern(data);
+        Some(format!("{name}{open}{}{close}", qualifiers.join(separator)))
+    }
+}
@@ -0,0 +1,115 @@
+//! Display names of languages, regions, scripts, currencies, calendars and date time fields.
+//!
+//! ICU4X doesn't provide display name data yet, so the names are read from the CLDR data of the
+//! `DISPLAY_NAMES` table, which only has names for the codes of a set of widely used languages,
+//! regions, scripts, currencies and calendars.
+
+use super::{super::data::LocaleChain, options::Style};
+
+/// Codes of the currencies that have a display name, sorted.
+pub(super) static CURRENCIES: [&str; 78] = [
+    "AED", "ARS", "AUD", "BDT", "BGN", "BHD", "BOB", "BRL", "CAD", "CHF", "CLP", "CNY", "COP",
+    "CRC", "CZK", "DKK", "DOP", "DZD", "EGP", "ETB", "EUR", "GBP", "GEL", "GHS", "GTQ", "HKD",
+    "HUF", "IDR", "ILS", "INR", "IQD", "IRR", "ISK", "JMD", "JOD", "JPY", "KES", "KRW", "KWD",
+    "KZT", "LBP", "LKR", "MAD", "MDL", "MXN", "MYR", "NGN", "NOK", "NPR", "NZD", "OMR", "PAB",
+    "PEN", "PHP", "PKR", "PLN", "PYG", "QAR", "RON", "RSD", "RUB", "SAR", "SEK", "SGD", "THB",
+    "TND", "TRY", "TWD", "TZS", "UAH", "UGX", "USD", "UYU", "UZS", "VND", "XAF", "XOF", "ZAR",
+];
+
+/// Looks up the name of `code` of the given `kind`, preferring the alternative names of `alts`.
+fn name(data: &LocaleChain, kind: &str, alts: &[&str], code: &str) -> Option<&'static str> {
+    alts.iter()
+        .find_map(|alt| data.get(&format!("{kind}-{alt}/{code}")))
+        .or_else(|| data.get(&format!("{kind}/{code}")))
+}
+
+/// Gets the alternative names to use for `style`.
+const fn alts(style: Style) -> &'static [&'static str] {
+    match style {
+        Style::Long => &[],
+        Style::Short | Style::Narrow => &["short"],
+    }
+}
+
+/// Gets the display name of a canonicalized language tag of the form `language[-Script][-REGION]`,
+/// which is only found if the whole tag has a name (e.g. "en-GB" or "fr").
+pub(super) fn language(data: &LocaleChain, code: &str, style: Style) -> Option<&'static str> {
+    name(data, "language", alts(style), code)
+}
+
+/// Gets the display name of a canonicalized region code.
+pub(super) fn region(data: &LocaleChain, code: &str, style: Style) -> Option<&'static str> {
+    name(data, "region", alts(style), code)
+}
+
+/// Gets the display name of a canonicalized script code.
+///
+/// Scripts have a different name when they are not displayed as part of a language name (e.g.
+/// "Simplified Han" instead of "Simplified"), which is only used in the long style.
+pub(super) fn script(data: &LocaleChain, code: &str, style: Style) -> Option<&'static str> {
+    let alts: &[&str] = match style {
+        Style::Long => &["stand-alone"],
+        Style::Short | Style::Narrow => &[],
+    };
+    name(data, "script", alts, code)
+}
+
+/// Gets the name of a canonicalized script code when it qualifies a language name.
+pub(super) fn script_qualifier(data: &LocaleChain, code: &str) -> Option<&'static str> {
+    name(data, "script", &[], code)
+}
+
+/// Gets the display name of a canonicalized currency code.
+pub(super) fn currency(data: &LocaleChain, code: &str) -> Option<&'static str> {
+    name(data, "currency", &[], code)
+}
+
+/// Gets the display name of a canonicalized calendar identifier.
+pub(super) fn calendar(data: &LocaleChain, code: &str) -> Option<&'static str> {
+    name(data, "calendar", &[], code)
+}
+
+/// Gets the display name of a date time field, which must be valid.
+pub(super) fn date_time_field(
+    data: &LocaleChain,
+    code: &str,
+    style: Style,
+) -> Option<&'static str> {
+    let alts: &[&str] = match style {
+        Style::Long => &[],
+        Style::Short => &["short"],
+        Style::Narrow => &["narrow", "short"],
+    };
+    name(data, "field", alts, code)
+}
+
+/// Abstract operation [`IsValidDateTimeFieldCode ( field )`][spec].
+///
+/// [spec]: https://tc39.es/ecma402/#sec-isvaliddatetimefieldcode
+pub(super) fn is_valid_date_time_field(code: &str) -> bool {
+    matches!(
+        code,
+        "era"
+            | "year"
+            | "quarter"
+            | "month"
+            | "weekOfYear"
+            | "weekday"
+            | "day"
+            | "dayPeriod"
+            | "hour"
+            | "minute"
+            | "second"
+            | "timeZoneName"
+    )
+}
+
+/// Gets the pattern of a name with qualifiers, as the text before and after the qualifiers, and
+/// the separator between qualifiers.
+pub(super) fn qualifier_pattern(data: &LocaleChain) -> (&'static str, &'static str, &'static str) {
+    let (open, close) = data
+        .get("pattern")
+        .and_then(|pattern| pattern.split_once('|'))
+        .unwrap_or((" (", ")"));
+    (open, close, data.get("separator").unwrap_or(", "))
+}
@@ -0,0 +1,181 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::builtins::intl::options::OptionTypeParsable;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum Style {
+    Narrow,
+    Short,
+    #[default]
+    Long,
+}
+
+impl Display for Style {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Narrow => "narrow",
+            Self::Short => "short",
+            Self::Long => "long",
+        }
+        .fmt(f)
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseStyleError;
+
+impl Display for ParseStyleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("provided string was not `narrow`, `short` or `long`")
+    }
+}
+
+impl FromStr for Style {
+    type Err = ParseStyleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "narrow" => Ok(Self::Narrow),
+            "short" => Ok(Self::Short),
+            "long" => Ok(Self::Long),
+            _ => Err(ParseStyleError),
+        }
+    }
+}
+
+impl OptionTypeParsable for Style {}
+
+/// The kind of code whose display name is requested, given by the `type` option.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DisplayType {
+    Language,
+    Region,
+    Script,
+    Currency,
+    Calendar,
+    DateTimeField,
+}
+
+impl Display for DisplayType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Language => "language",
+            Self::Region => "region",
+            Self::Script => "script",
+            Self::Currency => "currency",
+            Self::Calendar => "calendar",
+            Self::DateTimeField => "dateTimeField",
+        }
+        .fmt(f)
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseDisplayTypeError;
+
+impl Display for ParseDisplayTypeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(
+            "provided string was not `language`, `region`, `script`, `currency`, `calendar` \
+             or `dateTimeField`",
+        )
+    }
+}
+
+impl FromStr for DisplayType {
+    type Err = ParseDisplayTypeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "language" => Ok(Self::Language),
+            "region" => Ok(Self::Region),
+            "script" => Ok(Self::Script),
+            "currency" => Ok(Self::Currency),
+            "calendar" => Ok(Self::Calendar),
+            "dateTimeField" => Ok(Self::DateTimeField),
+            _ => Err(ParseDisplayTypeError),
+        }
+    }
+}
+
+impl OptionTypeParsable for DisplayType {}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum Fallback {
+    #[default]
+    Code,
+    None,
+}
+
+impl Display for Fallback {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Code => "code",
+            Self::None => "none",
+        }
+        .fmt(f)
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseFallbackError;
+
+impl Display for ParseFallbackError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("provided string was not `code` or `none`")
+    }
+}
+
+impl FromStr for Fallback {
+    type Err = ParseFallbackError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "code" => Ok(Self::Code),
+            "none" => Ok(Self::None),
+            _ => Err(ParseFallbackError),
+        }
+    }
+}
+
+impl OptionTypeParsable for Fallback {}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum LanguageDisplay {
+    #[default]
+    Dialect,
+    Standard,
+}
+
+impl Display for LanguageDisplay {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Dialect => "dialect",
+            Self::Standard => "standard",
+        }
+        .fmt(f)
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseLanguageDisplayError;
+
+impl Display for ParseLanguageDisplayError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("provided string was not `dialect` or `standard`")
+    }
+}
+
+impl FromStr for LanguageDisplay {
+    type Err = ParseLanguageDisplayError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "dialect" => Ok(Self::Dialect),
+            "standard" => Ok(Self::Standard),
+            _ => Err(ParseLanguageDisplayError),
+        }
+    }
+}
+
+impl OptionTypeParsable for LanguageDisplay {}
@@ -0,0 +1,148 @@
+use crate::builtins::intl::{
+    data::DISPLAY_NAMES,
+    display_names::{canonical_code, language_name, names, DisplayType, LanguageDisplay, Style},
+};
+
+#[test]
+fn sorted_currencies() {
+    assert!(names::CURRENCIES.windows(2).all(|w| w[0] < w[1]));
+}
+
+#[test]
+fn canonical_codes() {
+    let code = |typ, code| canonical_code(typ, code).ok();
+
+    assert_eq!(code(DisplayType::Region, "gb"), Some("GB".into()));
+    assert_eq!(code(DisplayType::Region, "419"), Some("419".into()));
+    assert_eq!(code(DisplayType::Region, "G1"), None);
+    assert_eq!(code(DisplayType::Script, "LATN"), Some("Latn".into()));
+    assert_eq!(code(DisplayType::Script, "Lat"), None);
+    assert_eq!(code(DisplayType::Currency, "usd"), Some("USD".into()));
+    assert_eq!(code(DisplayType::Currency, "US$"), None);
+    assert_eq!(
+        code(DisplayType::Calendar, "Gregory"),
+        Some("gregory".into())
+    );
+    assert_eq!(
+        code(DisplayType::Calendar, "islamic-civil"),
+        Some("islamic-civil".into())
+    );
+    assert_eq!(code(DisplayType::Calendar, "gregory_"), None);
+    assert_eq!(
+        code(DisplayType::DateTimeField, "weekOfYear"),
+        Some("weekOfYear".into())
+    );
+    assert_eq!(code(DisplayType::DateTimeField, "week"), None);
+    assert_eq!(code(DisplayType::Language, "en-gb"), Some("en-GB".into()));
+    assert_eq!(code(DisplayType::Language, "en_GB"), None);
+    assert_eq!(code(DisplayType::Language, "en-u-ca-gregory"), None);
+    assert_eq!(code(DisplayType::Language, "de-1996-1996"), None);
+    assert_eq!(code(DisplayType::Language, "root"), None);
+}
+
+#[test]
+fn language_names() {
+    let en = DISPLAY_NAMES.chain("en").unwrap();
+    let name =
+        |code: &str, display, style| language_name(&en, &code.parse().unwrap(), display, style);
+
+    assert_eq!(
+        name("en-GB", LanguageDisplay::Dialect, Style::Long).as_deref(),
+        Some("British English")
+    );
+    assert_eq!(
+        name("en-GB", LanguageDisplay::Dialect, Style::Short).as_deref(),
+        Some("UK English")
+    );
+    assert_eq!(
+        name("en-GB", LanguageDisplay::Standard, Style::Long).as_deref(),
+        Some("English (United Kingdom)")
+    );
+    assert_eq!(
+        name("zh-Hant-TW", LanguageDisplay::Dialect, Style::Long).as_deref(),
+        Some("Traditional Chinese (Taiwan)")
+    );
+    assert_eq!(
+        name("zh-Hant-TW", LanguageDisplay::Standard, Style::Long).as_deref(),
+        Some("Chinese (Traditional, Taiwan)")
+    );
+    assert_eq!(
+        name("es-Latn-419", LanguageDisplay::Dialect, Style::Long).as_deref(),
+        Some("Latin American Spanish (Latin)")
+    );
+    assert_eq!(
+        name("en-XY", LanguageDisplay::Dialect, Style::Long).as_deref(),
+        Some("English (XY)")
+    );
+    assert_eq!(name("xyz", LanguageDisplay::Dialect, Style::Long), None);
+}
+
+#[test]
+fn localized_names() {
+    let de = DISPLAY_NAMES.chain("de").unwrap();
+    let ja = DISPLAY_NAMES.chain("ja").unwrap();
+
+    assert_eq!(
+        language_name(
+            &de,
+            &"de-AT".parse().unwrap(),
+            LanguageDisplay::Dialect,
+            Style::Long
+        )
+        .as_deref(),
+        Some("Österreichisches Deutsch")
+    );
+    assert_eq!(
+        language_name(
+            &de,
+            &"zh-Hant-TW".parse().unwrap(),
+            LanguageDisplay::Standard,
+            Style::Long
+        )
+        .as_deref(),
+        Some("Chinesisch (Traditionell, Taiwan)")
+    );
+    assert_eq!(
+        language_name(
+            &ja,
+            &"zh-Hant-TW".parse().unwrap(),
+            LanguageDisplay::Standard,
+            Style::Long
+        )
+        .as_deref(),
+        Some("中国語 (繁体字、台湾)")
+    );
+    assert_eq!(
+        names::region(&de, "GB", Style::Long),
+        Some("Vereinigtes Königreich")
+    );
+    assert_eq!(names::region(&de, "GB", Style::Short), Some("UK"));
+    assert_eq!(
+        names::script(&de, "Hans", Style::Long),
+        Some("Vereinfachtes Chinesisch")
+    );
+    assert_eq!(names::currency(&ja, "EUR"), Some("ユーロ"));
+    assert_eq!(
+        names::calendar(&de, "gregory"),
+        Some("Gregorianischer Kalender")
+    );
+    assert_eq!(
+        names::date_time_field(&de, "weekday", Style::Long),
+        Some("Wochentag")
+    );
+    assert_eq!(
+        names::date_time_field(&de, "weekday", Style::Narrow),
+        Some("Wochent.")
+    );
+    assert_eq!(
+        names::date_time_field(&de, "hour", Style::Short),
+        Some("Std.")
+    );
+
+    // Regional locales inherit the names of their parent locales.
+    let de_at = DISPLAY_NAMES.chain("de-AT").unwrap();
+    assert_eq!(
+        names::region(&de_at, "GB", Style::Long),
+        Some("Vereinigtes Königreich")
+    );
+}
@@ -16,7 +16,7 @@ use crate::{
     property::Attribute,
     realm::Realm,
     symbol::JsSymbol,
-    Context, JsArgs, JsResult, JsValue,
+    Context, JsArgs, JsNativeError, JsResult, JsValue,
 };
 
 use boa_profiler::Profiler;
@@ -24,15 +24,21 @@ use icu_provider::KeyedDataMarker;
 
 pub(crate) mod collator;
 pub(crate) mod date_time_format;
+pub(crate) mod display_names;
 pub(crate) mod list_format;
 pub(crate) mod locale;
+pub(crate) mod number_format;
+pub(crate) mod plural_rules;
+pub(crate) mod relative_time_format;
 pub(crate) mod segmenter;
 
 pub(crate) use self::{
-    collator::Collator, date_time_format::DateTimeFormat, list_format::ListFormat, locale::Locale,
-    segmenter::Segmenter,
+    collator::Collator, date_time_format::DateTimeFormat, display_names::DisplayNames,
+    list_format::ListFormat, locale::Locale, number_format::NumberFormat,
+    plural_rules::PluralRules, relative_time_format::RelativeTimeFormat, segmenter::Segmenter,
 };
 
+mod data;
 mod options;
 
 /// JavaScript `Intl` object.
@@ -54,6 +60,15 @@ impl IntrinsicObject for Intl {
                 realm.intrinsics().constructors().collator().constructor(),
                 Collator::ATTRIBUTE,
             )
+            .static_property(
+                DisplayNames::NAME,
+                realm
+                    .intrinsics()
+                    .constructors()
+                    .display_names()
+                    .constructor(),
+                DisplayNames::ATTRIBUTE,
+            )
             .static_property(
                 ListFormat::NAME,
                 realm
@@ -68,6 +83,33 @@ impl IntrinsicObject for Intl {
                 realm.intrinsics().constructors().locale().constructor(),
                 Locale::ATTRIBUTE,
             )
+            .static_property(
+                NumberFormat::NAME,
+                realm
+                    .intrinsics()
+                    .constructors()
+                    .number_format()
+                    .constructor(),
+                NumberFormat::ATTRIBUTE,
+            )
+            .static_property(
+                PluralRules::NAME,
+                realm
+                    .intrinsics()
+                    .constructors()
+                    .plural_rules()
+                    .constructor(),
+                PluralRules::ATTRIBUTE,
+            )
+            .static_property(
+                RelativeTimeFormat::NAME,
+                realm
+                    .intrinsics()
+                    .constructors()
+                    .relative_time_format()
+                    .constructor(),
+                RelativeTimeFormat::ATTRIBUTE,
+            )
             .static_property(
                 Segmenter::NAME,
                 realm.intrinsics().constructors().segmenter().constructor(),
@@ -83,6 +125,7 @@ impl IntrinsicObject for Intl {
                 DateTimeFormat::ATTRIBUTE,
             )
             .static_method(Self::get_canonical_locales, "getCanonicalLocales", 1)
+            .static_method(Self::supported_values_of, "supportedValuesOf", 1)
             .build();
     }
 
@@ -122,6 +165,67 @@ impl Intl {
             context,
         )))
     }
+
+    /// `Intl.supportedValuesOf ( key )`
+    ///
+    /// Returns a sorted array containing the supported calendar, collation, currency, numbering
+    /// system, time zone or unit identifiers.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN docs][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.supportedvaluesof
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/supportedValuesOf
+    pub(crate) fn supported_values_of(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. Let key be ? ToString(key).
+        let key = args
+            .get_or_undefined(0)
+            .to_string(context)?
+            .to_std_string_escaped();
+
+        let provider = context.icu().provider();
+        let mut list: Vec<&str> = match key.as_str() {
+            // 2. If key is "calendar", then
+            //     a. Let list be AvailableCanonicalCalendars( ).
+            "calendar" => date_time_format::CALENDARS.to_vec(),
+            // 3. Else if key is "collation", then
+            //     a. Let list be AvailableCanonicalCollations( ).
+            "collation" => collator::available_collations(provider),
+            // 4. Else if key is "currency", then
+            //     a. Let list be AvailableCanonicalCurrencies( ).
+            "currency" => display_names::available_currencies().collect(),
+            // 5. Else if key is "numberingSystem", then
+            //     a. Let list be AvailableCanonicalNumberingSystems( ).
+            "numberingSystem" => number_format::available_numbering_systems(provider),
+            // 6. Else if key is "timeZone", then
+            //     a. Let list be AvailableCanonicalTimeZones( ).
+            // Only UTC and offset time zones are supported, and the latter are not identifiers
+            // of the IANA Time Zone Database.
+            "timeZone" => vec!["UTC"],
+            // 7. Else if key is "unit", then
+            //     a. Let list be AvailableCanonicalUnits( ).
+            "unit" => number_format::simple_unit_ids().collect(),
+            // 8. Else,
+            //     a. Throw a RangeError exception.
+            _ => {
+                return Err(JsNativeError::range()
+                    .with_message(format!("`{key}` is not a supported key"))
+                    .into())
+            }
+        };
+        list.sort_unstable();
+
+        // 9. Return CreateArrayFromList( list ).
+        Ok(JsValue::Object(Array::create_array_from_list(
+            list.into_iter().map(JsValue::from),
+            context,
+        )))
+    }
 }
 
 /// A service component that is part of the `Intl` API.
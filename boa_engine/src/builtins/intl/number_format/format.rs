@@ -0,0 +1,589 @@
+//! Formatting of numbers into a list of parts.
+//!
+//! The digits, separators and signs are taken from the ICU4X decimal symbols of the resolved
+//! locale. The patterns of percentages, currencies, units and compact notations, along with the
+//! names of currencies and units, come from the [CLDR locale data](crate::builtins::intl::data)
+//! of the resolved locale.
+
+use std::borrow::Cow;
+
+use fixed_decimal::{FixedDecimal, Sign};
+use icu_decimal::provider::{AffixesV1, DecimalSymbolsV1};
+use icu_plurals::PluralCategory;
+
+use crate::builtins::intl::{
+    data::{pattern_parts, plural, PatternPart},
+    plural_rules::plural_category_to_str,
+};
+
+use super::{
+    options::{
+        CompactDisplay, Currency, CurrencyDisplay, CurrencySign, Notation, SignDisplay, Style,
+        Unit, UnitDisplay, UnitFormatOptions,
+    },
+    IntlMathematicalValue, NumberFormat,
+};
+
+/// A part of a formatted number, as returned by `Intl.NumberFormat.prototype.formatToParts`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Part {
+    pub(crate) typ: &'static str,
+    pub(crate) value: String,
+}
+
+impl Part {
+    pub(crate) fn new(typ: &'static str, value: impl Into<String>) -> Self {
+        Self {
+            typ,
+            value: value.into(),
+        }
+    }
+
+    /// Checks if this part is a piece of the numeric value itself, instead of an affix.
+    pub(crate) fn is_numeric(&self) -> bool {
+        matches!(
+            self.typ,
+            "integer" | "group" | "decimal" | "fraction" | "nan" | "infinity"
+        )
+    }
+}
+
+/// The table of [sanctioned single unit identifiers][spec].
+///
+/// [spec]: https://tc39.es/ecma402/#table-sanctioned-single-unit-identifiers
+static SIMPLE_UNITS: [&str; 45] = [
+    "acre",
+    "bit",
+    "byte",
+    "celsius",
+    "centimeter",
+    "day",
+    "degree",
+    "fahrenheit",
+    "fluid-ounce",
+    "foot",
+    "gallon",
+    "gigabit",
+    "gigabyte",
+    "gram",
+    "hectare",
+    "hour",
+    "inch",
+    "kilobit",
+    "kilobyte",
+    "kilogram",
+    "kilometer",
+    "liter",
+    "megabit",
+    "megabyte",
+    "meter",
+    "microsecond",
+    "mile",
+    "mile-scandinavian",
+    "milliliter",
+    "millimeter",
+    "millisecond",
+    "minute",
+    "month",
+    "nanosecond",
+    "ounce",
+    "percent",
+    "petabyte",
+    "pound",
+    "second",
+    "stone",
+    "terabit",
+    "terabyte",
+    "week",
+    "yard",
+    "year",
+];
+
+/// Abstract operation [`IsSanctionedSingleUnitIdentifier ( unitIdentifier )`][spec].
+///
+/// Returns the sanctioned unit identifier equal to `id`, if there is one.
+///
+/// [spec]: https://tc39.es/ecma402/#sec-issanctionedsingleunitidentifier
+pub(crate) fn simple_unit(id: &str) -> Option<&'static str> {
+    SIMPLE_UNITS.iter().copied().find(|unit| *unit == id)
+}
+
+/// Returns the identifiers of all the sanctioned single units.
+pub(crate) fn simple_unit_ids() -> impl Iterator<Item = &'static str> {
+    SIMPLE_UNITS.iter().copied()
+}
+
+/// Selects the value of the unit `display` from a `short|narrow|long` value, where an empty
+/// narrow value is the same as the short one.
+fn unit_width(value: &'static str, display: UnitDisplay) -> Option<&'static str> {
+    let mut widths = value.split('|');
+    let short = widths.next()?;
+    let narrow = widths.next()?;
+    let long = widths.next()?;
+    let width = match display {
+        UnitDisplay::Short => short,
+        UnitDisplay::Narrow if narrow.is_empty() => short,
+        UnitDisplay::Narrow => narrow,
+        UnitDisplay::Long => long,
+    };
+    (!width.is_empty()).then_some(width)
+}
+
+/// Appends the parts of a [locale data](crate::builtins::intl::data) `pattern` to `parts`.
+///
+/// The fields of the pattern become parts of type `field`, and the number, placeholder and sign
+/// are replaced by the provided parts. Returns `true` if the pattern has a position for the sign.
+fn push_pattern(
+    parts: &mut Vec<Part>,
+    pattern: &str,
+    field: &'static str,
+    number: &mut Vec<Part>,
+    placeholder: Option<&Part>,
+    sign: Option<&Part>,
+) -> bool {
+    let mut signed = false;
+    for part in pattern_parts(pattern) {
+        match part {
+            PatternPart::Literal(literal) => parts.push(Part::new("literal", literal)),
+            PatternPart::Number => parts.append(number),
+            PatternPart::Sign => {
+                signed = true;
+                parts.extend(sign.cloned());
+            }
+            PatternPart::Placeholder => parts.extend(placeholder.cloned()),
+            PatternPart::Field(value) => parts.push(Part::new(field, value)),
+        }
+    }
+    signed
+}
+
+/// Merges the adjacent unit parts of a compound unit, like the `km` and `/h` of `km/h`.
+fn merge_units(parts: &mut Vec<Part>) {
+    parts.dedup_by(|next, previous| {
+        let merge = previous.typ == "unit" && next.typ == "unit";
+        if merge {
+            previous.value.push_str(&next.value);
+        }
+        merge
+    });
+}
+
+/// Separates the currency from the number of `pattern` with a no-break space if the side of the
+/// currency `symbol` next to the number is a letter, as in `USD\u{a0}1.00`.
+fn currency_spacing(pattern: &'static str, symbol: &str) -> Cow<'static, str> {
+    if symbol.ends_with(char::is_alphabetic) && pattern.contains("{}{0}") {
+        return pattern.replace("{}{0}", "{}\u{a0}{0}").into();
+    }
+    if symbol.starts_with(char::is_alphabetic) && pattern.contains("{0}{}") {
+        return pattern.replace("{0}{}", "{0}\u{a0}{}").into();
+    }
+    pattern.into()
+}
+
+impl NumberFormat {
+    /// Abstract operation [`PartitionNumberPattern ( numberFormat, x )`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-partitionnumberpattern
+    pub(crate) fn partition(&self, x: &IntlMathematicalValue) -> Vec<Part> {
+        let symbols = self.symbols.get();
+
+        // 1. If x is not-a-number, then
+        //     a. Let n be an implementation- and locale-dependent (ILD) String value indicating the NaN value.
+        // 2. Else if x is positive-infinity, then
+        //     a. Let n be an ILD String value indicating positive infinity.
+        // 3. Else if x is negative-infinity, then
+        //     a. Let n be an ILD String value indicating negative infinity.
+        // 4. Else,
+        //     a. If x is not negative-zero, then
+        //         i. Assert: x is a mathematical value.
+        //         ii. If numberFormat.[[Style]] is "percent", let x be 100 × x.
+        //         iii. Let exponent be ComputeExponent(numberFormat, x).
+        //         iv. Let x be x × 10^-exponent.
+        //     b. Let formatNumberResult be FormatNumericToString(numberFormat, x).
+        //     c. Let n be formatNumberResult.[[FormattedString]].
+        //     d. Let x be formatNumberResult.[[RoundedNumber]].
+        let mut number = Vec::new();
+        let (negative, zero, category) = match x {
+            IntlMathematicalValue::NaN => {
+                number.push(Part::new("nan", "NaN"));
+                (false, true, PluralCategory::Other)
+            }
+            IntlMathematicalValue::Infinity { negative } => {
+                number.push(Part::new("infinity", "∞"));
+                (*negative, false, PluralCategory::Other)
+            }
+            IntlMathematicalValue::Decimal(x) => {
+                let mut x = x.clone();
+                if self.unit_options.style() == Style::Percent {
+                    x.multiply_pow10(2);
+                }
+                let exponent = self.compute_exponent(&x);
+                x.multiply_pow10(-exponent);
+                self.digit_options.format_fixed_decimal(&mut x);
+                let category = self.plural_rules.category_for(&x);
+
+                // Abstract operation `PartitionNotationSubPattern ( numberFormat, x, n, exponent )`
+                // https://tc39.es/ecma402/#sec-partitionnotationsubpattern
+                let mut digits = Vec::new();
+                self.push_digits(&mut digits, &x, symbols);
+                match self.notation {
+                    Notation::Scientific | Notation::Engineering => {
+                        number.append(&mut digits);
+                        number.push(Part::new("exponentSeparator", "E"));
+                        if exponent < 0 {
+                            number.push(Part::new(
+                                "exponentMinusSign",
+                                symbols.minus_sign_affixes.prefix.to_string(),
+                            ));
+                        }
+                        let exponent = exponent
+                            .unsigned_abs()
+                            .to_string()
+                            .bytes()
+                            .map(|digit| symbols.digits[usize::from(digit - b'0')])
+                            .collect::<String>();
+                        number.push(Part::new("exponentInteger", exponent));
+                    }
+                    Notation::Compact if exponent != 0 => {
+                        let magnitude = if x.is_zero() {
+                            exponent
+                        } else {
+                            x.nonzero_magnitude_start() + exponent
+                        };
+                        let pattern = self
+                            .compact_data(magnitude)
+                            .and_then(|value| value.split_once('|'))
+                            .map_or("{0}", |(_, patterns)| {
+                                plural(patterns, plural_category_to_str(category))
+                            });
+                        push_pattern(&mut number, pattern, "compact", &mut digits, None, None);
+                    }
+                    _ => number.append(&mut digits),
+                }
+
+                (x.sign() == Sign::Negative, x.is_zero(), category)
+            }
+        };
+        let category = plural_category_to_str(category);
+
+        // 5. Let pattern be GetNumberFormatPattern(numberFormat, x).
+        // 6. Let result be a new empty List.
+        // 7. Let patternParts be PartitionPattern(pattern).
+        // 8. For each Record { [[Type]], [[Value]] } patternPart of patternParts, do
+        //     a. Let p be patternPart.[[Type]].
+        //     b. If p is "literal", then
+        //         i. Append the Record { [[Type]]: "literal", [[Value]]: patternPart.[[Value]] } to result.
+        //     c. Else if p is equal to "number", then
+        //         i. Let notationSubParts be PartitionNotationSubPattern(numberFormat, x, n, exponent).
+        //         ii. Append all elements of notationSubParts to result.
+        //     d. Else if p is equal to "plusSign", then
+        //         i. Let plusSignSymbol be the ILND String representing the plus sign.
+        //         ii. Append the Record { [[Type]]: "plusSign", [[Value]]: plusSignSymbol } to result.
+        //     e. Else if p is equal to "minusSign", then
+        //         i. Let minusSignSymbol be the ILND String representing the minus sign.
+        //         ii. Append the Record { [[Type]]: "minusSign", [[Value]]: minusSignSymbol } to result.
+        //     f. Else if p is equal to "percentSign" and numberFormat.[[Style]] is "percent", then
+        //         i. Let percentSignSymbol be the ILND String representing the percent sign.
+        //         ii. Append the Record { [[Type]]: "percentSign", [[Value]]: percentSignSymbol } to result.
+        //     g. Else if p is equal to "unitPrefix" and numberFormat.[[Style]] is "unit", then
+        //         ...
+        //     h. Else if p is equal to "unitSuffix" and numberFormat.[[Style]] is "unit", then
+        //         ...
+        //     i. Else if p is equal to "currencyCode" and numberFormat.[[Style]] is "currency", then
+        //         ...
+        //     j. Else if p is equal to "currencyPrefix" and numberFormat.[[Style]] is "currency", then
+        //         ...
+        //     k. Else if p is equal to "currencySuffix" and numberFormat.[[Style]] is "currency", then
+        //         ...
+        // 9. Return result.
+        let sign = match self.sign_display {
+            SignDisplay::Never => None,
+            SignDisplay::Auto => negative.then_some(true),
+            SignDisplay::Always => Some(negative),
+            SignDisplay::ExceptZero => (!zero).then_some(negative),
+            SignDisplay::Negative => (negative && !zero).then_some(true),
+        };
+        let accounting = sign == Some(true)
+            && matches!(
+                self.unit_options,
+                UnitFormatOptions::Currency {
+                    sign: CurrencySign::Accounting,
+                    ..
+                }
+            );
+        let sign = sign.map(|negative| {
+            if negative {
+                ("minusSign", &symbols.minus_sign_affixes)
+            } else {
+                ("plusSign", &symbols.plus_sign_affixes)
+            }
+        });
+        let sign_part = sign
+            .filter(|(_, AffixesV1 { prefix, .. })| !prefix.is_empty())
+            .map(|(typ, AffixesV1 { prefix, .. })| Part::new(typ, prefix.to_string()));
+        let sign_part = sign_part.as_ref();
+
+        let mut result = Vec::with_capacity(number.len() + 4);
+        let signed = match &self.unit_options {
+            UnitFormatOptions::Decimal => {
+                push_pattern(&mut result, "{-}{0}", "", &mut number, None, sign_part)
+            }
+            UnitFormatOptions::Percent => push_pattern(
+                &mut result,
+                self.data("percent"),
+                "percentSign",
+                &mut number,
+                None,
+                sign_part,
+            ),
+            UnitFormatOptions::Currency {
+                currency, display, ..
+            } => {
+                let [symbol, narrow, names] = self.currency_names(currency);
+                let (pattern, placeholder) = match display {
+                    CurrencyDisplay::Name => (
+                        Cow::Borrowed(plural(self.data("currency-name"), category)),
+                        plural(names, category),
+                    ),
+                    display => {
+                        let symbol = match display {
+                            CurrencyDisplay::Code => currency.as_str(),
+                            CurrencyDisplay::NarrowSymbol => narrow,
+                            _ => symbol,
+                        };
+                        let pattern = self.data(if accounting { "accounting" } else { "currency" });
+                        (currency_spacing(pattern, symbol), symbol)
+                    }
+                };
+                push_pattern(
+                    &mut result,
+                    &pattern,
+                    "",
+                    &mut number,
+                    Some(&Part::new("currency", placeholder)),
+                    sign_part,
+                )
+            }
+            UnitFormatOptions::Unit { unit, display } => self.push_unit(
+                &mut result,
+                *unit,
+                *display,
+                category,
+                &mut number,
+                sign_part,
+            ),
+        };
+
+        if !signed && !accounting {
+            result.splice(0..0, sign_part.cloned());
+        }
+        if let Some((typ, AffixesV1 { suffix, .. })) = sign {
+            if !accounting && !suffix.is_empty() {
+                result.push(Part::new(typ, suffix.to_string()));
+            }
+        }
+
+        result
+    }
+
+    /// Gets the value of `key` from the locale data of this number format.
+    fn data(&self, key: &str) -> &'static str {
+        self.data
+            .get(key)
+            .expect("the locale data of every language must have all the keys")
+    }
+
+    /// Gets the exponent and the patterns of the compact notation for numbers of `magnitude`.
+    ///
+    /// Numbers with greater magnitudes than the ones that have compact names are scaled by the
+    /// largest exponent.
+    fn compact_data(&self, magnitude: i16) -> Option<&'static str> {
+        let width = if self.compact_display == Some(CompactDisplay::Long) {
+            "long"
+        } else {
+            "short"
+        };
+        self.data
+            .get(&format!("compact-{width}/{}", magnitude.min(14)))
+    }
+
+    /// Gets the symbol, narrow symbol and plural names of a currency.
+    ///
+    /// Currencies without display names are displayed using their currency code.
+    fn currency_names<'a>(&self, currency: &'a Currency) -> [&'a str; 3] {
+        self.data
+            .get(&format!("currency/{}", currency.as_str()))
+            .and_then(|value| {
+                let mut names = value.split('|');
+                Some([names.next()?, names.next()?, names.next()?])
+            })
+            .unwrap_or([currency.as_str(); 3])
+    }
+
+    /// Appends the parts of a number formatted with `unit`.
+    ///
+    /// Compound units without their own names are formatted with the per-unit pattern of the
+    /// denominator or, if it doesn't have one, with the generic per pattern.
+    fn push_unit(
+        &self,
+        parts: &mut Vec<Part>,
+        unit: Unit,
+        display: UnitDisplay,
+        category: &str,
+        number: &mut Vec<Part>,
+        sign: Option<&Part>,
+    ) -> bool {
+        let names = |id: &str| {
+            self.data
+                .get(&format!("unit/{id}"))
+                .and_then(|value| unit_width(value, display))
+        };
+        let compound = unit.denominator.and_then(|_| names(&unit.to_string()));
+        let numerator = compound
+            .or_else(|| names(unit.numerator))
+            .expect("the locale data of every language must have all the units");
+
+        let Some(denominator) = unit.denominator.filter(|_| compound.is_none()) else {
+            let signed = push_pattern(
+                parts,
+                plural(numerator, category),
+                "unit",
+                number,
+                None,
+                sign,
+            );
+            merge_units(parts);
+            return signed;
+        };
+
+        let mut numerator_parts = Vec::new();
+        let signed = push_pattern(
+            &mut numerator_parts,
+            plural(numerator, category),
+            "unit",
+            number,
+            None,
+            sign,
+        );
+        if let Some(per_unit) = self
+            .data
+            .get(&format!("per-unit/{denominator}"))
+            .and_then(|value| unit_width(value, display))
+        {
+            push_pattern(parts, per_unit, "unit", &mut numerator_parts, None, None);
+        } else {
+            let name = names(denominator)
+                .and_then(|names| {
+                    pattern_parts(plural(names, "one")).find_map(|part| match part {
+                        PatternPart::Field(name) => Some(name),
+                        _ => None,
+                    })
+                })
+                .unwrap_or(denominator);
+            let per = unit_width(self.data("per"), display)
+                .expect("the locale data of every language must have all the per patterns");
+            push_pattern(
+                parts,
+                per,
+                "unit",
+                &mut numerator_parts,
+                Some(&Part::new("unit", name)),
+                None,
+            );
+        }
+        merge_units(parts);
+        signed
+    }
+
+    /// Writes the integer, group, decimal and fraction parts of an already rounded number.
+    fn push_digits(&self, parts: &mut Vec<Part>, x: &FixedDecimal, symbols: &DecimalSymbolsV1<'_>) {
+        let digit = |magnitude| symbols.digits[usize::from(x.digit_at(magnitude))];
+        let lower_magnitude = *x.magnitude_range().start();
+        let upper_magnitude = *x.magnitude_range().end();
+
+        let mut integer = String::new();
+        for magnitude in (0..=upper_magnitude).rev() {
+            integer.push(digit(magnitude));
+            if self
+                .use_grouping
+                .check(upper_magnitude, magnitude, symbols.grouping_sizes)
+            {
+                parts.push(Part::new("integer", std::mem::take(&mut integer)));
+                parts.push(Part::new("group", symbols.grouping_separator.to_string()));
+            }
+        }
+        parts.push(Part::new("integer", integer));
+
+        if lower_magnitude < 0 {
+            parts.push(Part::new("decimal", symbols.decimal_separator.to_string()));
+            parts.push(Part::new(
+                "fraction",
+                (lower_magnitude..0).rev().map(digit).collect::<String>(),
+            ));
+        }
+    }
+
+    /// Abstract operation [`ComputeExponent ( numberFormat, x )`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-computeexponent
+    fn compute_exponent(&self, x: &FixedDecimal) -> i16 {
+        // 1. If x = 0, then
+        //     a. Return 0.
+        if x.is_zero() {
+            return 0;
+        }
+
+        // 2. If x < 0, then
+        //     a. Let x = -x.
+        // 3. Let magnitude be the base 10 logarithm of x rounded down to the nearest integer.
+        let magnitude = x.nonzero_magnitude_start();
+
+        // 4. Let exponent be ComputeExponentForMagnitude(numberFormat, magnitude).
+        let exponent = self.exponent_for_magnitude(magnitude);
+
+        // 5. Let x be x × 10^(-exponent).
+        // 6. Let formatNumberResult be FormatNumericToString(numberFormat, x).
+        let mut rounded = x.clone();
+        rounded.multiply_pow10(-exponent);
+        self.digit_options.format_fixed_decimal(&mut rounded);
+
+        // 7. If formatNumberResult.[[RoundedNumber]] = 0, then
+        //     a. Return exponent.
+        // 8. Let newMagnitude be the base 10 logarithm of formatNumberResult.[[RoundedNumber]] rounded down to the nearest integer.
+        // 9. If newMagnitude is magnitude - exponent, then
+        //     a. Return exponent.
+        if rounded.is_zero() || rounded.nonzero_magnitude_start() == magnitude - exponent {
+            return exponent;
+        }
+
+        // 10. Return ComputeExponentForMagnitude(numberFormat, magnitude + 1).
+        self.exponent_for_magnitude(magnitude + 1)
+    }
+
+    /// Abstract operation [`ComputeExponentForMagnitude ( numberFormat, magnitude )`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-computeexponentformagnitude
+    fn exponent_for_magnitude(&self, magnitude: i16) -> i16 {
+        match self.notation {
+            // 2. If notation is "scientific", then
+            //     a. Return magnitude.
+            Notation::Scientific => magnitude,
+            // 3. Else if notation is "engineering", then
+            //     a. Let thousands be the greatest integer that is not greater than magnitude / 3.
+            //     b. Return thousands × 3.
+            Notation::Engineering => magnitude.div_euclid(3) * 3,
+            // 4. Else if notation is "compact", then
+            //     a. Let exponent be an ILD integer by which to scale a number of the given magnitude in compact notation for the current locale.
+            //     b. Return exponent.
+            Notation::Compact => self
+                .compact_data(magnitude)
+                .and_then(|value| value.split_once('|'))
+                .and_then(|(exponent, _)| exponent.parse().ok())
+                .unwrap_or(0),
+            // 5. Else,
+            //     a. Assert: notation is "standard".
+            //     b. Return 0.
+            Notation::Standard => 0,
+        }
+    }
+}
@@ -0,0 +1,1114 @@
+use std::str::FromStr;
+
+use boa_gc::{custom_trace, Finalize, Trace};
+use boa_profiler::Profiler;
+use fixed_decimal::{DoublePrecision, FixedDecimal, Sign};
+use icu_decimal::provider::DecimalSymbolsV1Marker;
+use icu_locid::{
+    extensions::unicode::Value, extensions_unicode_key as key, langid, LanguageIdentifier, Locale,
+};
+use icu_plurals::{PluralRuleType, PluralRules as NativePluralRules};
+use icu_provider::{
+    DataLocale, DataPayload, DataProvider, DataRequest, DataRequestMetadata, DataResponse,
+};
+
+use crate::{
+    builtins::{
+        string::is_trimmable_whitespace, Array, BuiltInBuilder, BuiltInConstructor, BuiltInObject,
+        IntrinsicObject,
+    },
+    context::{
+        intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+        BoaProvider,
+    },
+    native_function::NativeFunction,
+    object::{
+        internal_methods::get_prototype_from_constructor, FunctionObjectBuilder, JsFunction,
+        JsObject, ObjectData,
+    },
+    property::Attribute,
+    realm::Realm,
+    string::utf16,
+    symbol::JsSymbol,
+    value::PreferredType,
+    Context, JsArgs, JsNativeError, JsResult, JsString, JsValue,
+};
+
+use super::{
+    data::{LocaleChain, NUMBER_FORMAT},
+    locale::{canonicalize_locale_list, resolve_locale, supported_locales, validate_extension},
+    options::{coerce_options_to_object, get_option, IntlOptions, LocaleMatcher},
+    Service,
+};
+
+mod format;
+mod options;
+pub(crate) use format::{simple_unit_ids, Part};
+pub(crate) use options::*;
+
+#[cfg(test)]
+mod tests;
+
+pub struct NumberFormat {
+    locale: Locale,
+    numbering_system: Option<Value>,
+    unit_options: UnitFormatOptions,
+    digit_options: DigitFormatOptions,
+    notation: Notation,
+    compact_display: Option<CompactDisplay>,
+    use_grouping: UseGrouping,
+    sign_display: SignDisplay,
+    symbols: DataPayload<DecimalSymbolsV1Marker>,
+    plural_rules: NativePluralRules,
+    data: LocaleChain,
+    bound_format: Option<JsFunction>,
+}
+
+impl Finalize for NumberFormat {}
+
+// SAFETY: only `bound_format` is a traceable object.
+unsafe impl Trace for NumberFormat {
+    custom_trace!(this, mark(&this.bound_format));
+}
+
+impl std::fmt::Debug for NumberFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("NumberFormat")
+            .field("locale", &self.locale)
+            .field("numbering_system", &self.numbering_system)
+            .field("unit_options", &self.unit_options)
+            .field("digit_options", &self.digit_options)
+            .field("notation", &self.notation)
+            .field("compact_display", &self.compact_display)
+            .field("use_grouping", &self.use_grouping)
+            .field("sign_display", &self.sign_display)
+            .field("symbols", &"DecimalSymbolsV1")
+            .field("plural_rules", &self.plural_rules)
+            .field("data", &self.data)
+            .field("bound_format", &self.bound_format)
+            .finish()
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub(in crate::builtins::intl) struct NumberFormatLocaleOptions {
+    pub(in crate::builtins::intl) numbering_system: Option<Value>,
+}
+
+impl Service for NumberFormat {
+    type LangMarker = DecimalSymbolsV1Marker;
+
+    type LocaleOptions = NumberFormatLocaleOptions;
+
+    fn resolve(locale: &mut Locale, options: &mut Self::LocaleOptions, provider: BoaProvider<'_>) {
+        let numbering_system = options
+            .numbering_system
+            .take()
+            .filter(|nu| {
+                validate_extension::<Self::LangMarker>(locale.id.clone(), key!("nu"), nu, &provider)
+            })
+            .or_else(|| {
+                locale
+                    .extensions
+                    .unicode
+                    .keywords
+                    .get(&key!("nu"))
+                    .cloned()
+                    .filter(|nu| {
+                        validate_extension::<Self::LangMarker>(
+                            locale.id.clone(),
+                            key!("nu"),
+                            nu,
+                            &provider,
+                        )
+                    })
+            });
+
+        locale.extensions.unicode.clear();
+
+        if let Some(nu) = numbering_system.clone() {
+            locale.extensions.unicode.keywords.set(key!("nu"), nu);
+        }
+
+        options.numbering_system = numbering_system;
+    }
+}
+
+/// Numbering systems with simple digit mappings that can be requested with the `"nu"` extension
+/// key, each paired with a language that has data for it.
+const NUMBERING_SYSTEMS: [(&str, LanguageIdentifier); 24] = [
+    ("adlm", langid!("ff")),
+    ("arab", langid!("ar")),
+    ("arabext", langid!("fa")),
+    ("beng", langid!("bn")),
+    ("cakm", langid!("ccp")),
+    ("deva", langid!("hi")),
+    ("gujr", langid!("gu")),
+    ("guru", langid!("pa")),
+    ("hanidec", langid!("zh")),
+    ("java", langid!("jv")),
+    ("khmr", langid!("km")),
+    ("knda", langid!("kn")),
+    ("laoo", langid!("lo")),
+    ("mlym", langid!("ml")),
+    ("mong", langid!("mn")),
+    ("mtei", langid!("mni")),
+    ("mymr", langid!("my")),
+    ("olck", langid!("sat")),
+    ("orya", langid!("or")),
+    ("tamldec", langid!("ta")),
+    ("telu", langid!("te")),
+    ("thai", langid!("th")),
+    ("tibt", langid!("dz")),
+    ("vaii", langid!("vai")),
+];
+
+/// Returns the numbering systems for which the provider has data.
+///
+/// `"latn"` is always included, since it is the default numbering system of every locale.
+pub(in crate::builtins::intl) fn available_numbering_systems(
+    provider: BoaProvider<'_>,
+) -> Vec<&'static str> {
+    NUMBERING_SYSTEMS
+        .into_iter()
+        .filter(|(nu, language)| {
+            let nu =
+                Value::try_from_bytes(nu.as_bytes()).expect("must be a valid numbering system");
+            validate_extension::<DecimalSymbolsV1Marker>(
+                language.clone(),
+                key!("nu"),
+                &nu,
+                &provider,
+            )
+        })
+        .map(|(nu, _)| nu)
+        .chain(std::iter::once("latn"))
+        .collect()
+}
+
+impl IntrinsicObject for NumberFormat {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        let format = BuiltInBuilder::callable(realm, Self::format)
+            .name("get format")
+            .build();
+
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .static_method(Self::supported_locales_of, "supportedLocalesOf", 1)
+            .property(
+                JsSymbol::to_string_tag(),
+                "Intl.NumberFormat",
+                Attribute::CONFIGURABLE,
+            )
+            .accessor(
+                utf16!("format"),
+                Some(format),
+                None,
+                Attribute::CONFIGURABLE,
+            )
+            .method(Self::format_to_parts, "formatToParts", 1)
+            .method(Self::format_range, "formatRange", 2)
+            .method(Self::format_range_to_parts, "formatRangeToParts", 2)
+            .method(Self::resolved_options, "resolvedOptions", 0)
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for NumberFormat {
+    const NAME: &'static str = "NumberFormat";
+}
+
+impl BuiltInConstructor for NumberFormat {
+    const LENGTH: usize = 0;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::number_format;
+
+    /// Constructor [`Intl.NumberFormat ( [ locales [ , options ] ] )`][spec].
+    ///
+    /// Constructor for `NumberFormat` objects.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.numberformat
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/NumberFormat/NumberFormat
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, let newTarget be the active function object, else let newTarget be NewTarget.
+        let new_target = &if new_target.is_undefined() {
+            context
+                .vm
+                .active_function
+                .clone()
+                .unwrap_or_else(|| {
+                    context
+                        .intrinsics()
+                        .constructors()
+                        .number_format()
+                        .constructor()
+                })
+                .into()
+        } else {
+            new_target.clone()
+        };
+
+        let locales = args.get_or_undefined(0);
+        let options = args.get_or_undefined(1);
+
+        // 2. Let numberFormat be ? OrdinaryCreateFromConstructor(newTarget, "%NumberFormat.prototype%", « [[InitializedNumberFormat]], [[Locale]], [[DataLocale]], [[NumberingSystem]], [[Style]], [[Unit]], [[UnitDisplay]], [[Currency]], [[CurrencyDisplay]], [[CurrencySign]], [[MinimumIntegerDigits]], [[MinimumFractionDigits]], [[MaximumFractionDigits]], [[MinimumSignificantDigits]], [[MaximumSignificantDigits]], [[RoundingType]], [[Notation]], [[CompactDisplay]], [[UseGrouping]], [[SignDisplay]], [[RoundingIncrement]], [[RoundingMode]], [[ComputedRoundingPriority]], [[TrailingZeroDisplay]], [[BoundFormat]] »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::number_format,
+            context,
+        )?;
+
+        // 3. Perform ? InitializeNumberFormat(numberFormat, locales, options).
+        let number_format = Self::new(locales, options, context)?;
+
+        // 4. If the implementation supports the normative optional constructor mode of 4.3 Note 1, then
+        //     a. Let this be the this value.
+        //     b. Return ? ChainNumberFormat(numberFormat, NewTarget, this).
+        // 5. Return numberFormat.
+        Ok(JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            ObjectData::number_format(number_format),
+        )
+        .into())
+    }
+}
+
+impl NumberFormat {
+    /// Abstract operation [`InitializeNumberFormat ( numberFormat, locales, options )`][spec].
+    ///
+    /// Creates a new `NumberFormat` from the provided locales and options, which is used by the
+    /// `Intl.NumberFormat` constructor and by the `toLocaleString` methods of numbers.
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-initializenumberformat
+    pub(crate) fn new(
+        locales: &JsValue,
+        options: &JsValue,
+        context: &mut Context<'_>,
+    ) -> JsResult<Self> {
+        // 1. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(locales, context)?;
+
+        // 2. Set options to ? CoerceOptionsToObject(options).
+        let options = coerce_options_to_object(options, context)?;
+
+        // 3. Let opt be a new Record.
+        // 4. Let matcher be ? GetOption(options, "localeMatcher", string, « "lookup", "best fit" », "best fit").
+        // 5. Set opt.[[localeMatcher]] to matcher.
+        let matcher =
+            get_option::<LocaleMatcher>(&options, utf16!("localeMatcher"), false, context)?
+                .unwrap_or_default();
+
+        // 6. Let numberingSystem be ? GetOption(options, "numberingSystem", string, empty, undefined).
+        // 7. If numberingSystem is not undefined, then
+        //     a. If numberingSystem does not match the Unicode Locale Identifier type nonterminal, throw a RangeError exception.
+        // 8. Set opt.[[nu]] to numberingSystem.
+        let numbering_system =
+            get_option::<Value>(&options, utf16!("numberingSystem"), false, context)?;
+
+        let mut intl_options = IntlOptions {
+            matcher,
+            service_options: NumberFormatLocaleOptions { numbering_system },
+        };
+
+        // 9. Let localeData be %NumberFormat%.[[LocaleData]].
+        // 10. Let r be ResolveLocale(%NumberFormat%.[[AvailableLocales]], requestedLocales, opt, %NumberFormat%.[[RelevantExtensionKeys]], localeData).
+        // 11. Set numberFormat.[[Locale]] to r.[[locale]].
+        // 12. Set numberFormat.[[DataLocale]] to r.[[dataLocale]].
+        // 13. Set numberFormat.[[NumberingSystem]] to r.[[nu]].
+        let mut locale =
+            resolve_locale::<Self>(&requested_locales, &mut intl_options, context.icu());
+
+        // 14. Perform ? SetNumberFormatUnitOptions(numberFormat, options).
+        let unit_options = UnitFormatOptions::from_options(&options, context)?;
+
+        // 15. Let style be numberFormat.[[Style]].
+        // 16. If style is "currency", then
+        //     a. Let currency be numberFormat.[[Currency]].
+        //     b. Let cDigits be CurrencyDigits(currency).
+        //     c. Let mnfdDefault be cDigits.
+        //     d. Let mxfdDefault be cDigits.
+        // 17. Else,
+        //     a. Let mnfdDefault be 0.
+        //     b. If style is "percent", then
+        //         i. Let mxfdDefault be 0.
+        //     c. Else,
+        //         i. Let mxfdDefault be 3.
+        let (min_float_digits_default, max_float_digits_default) = match unit_options {
+            UnitFormatOptions::Currency { currency, .. } => (currency.digits(), currency.digits()),
+            UnitFormatOptions::Percent => (0, 0),
+            _ => (0, 3),
+        };
+
+        // 18. Let notation be ? GetOption(options, "notation", string, « "standard", "scientific", "engineering", "compact" », "standard").
+        // 19. Set numberFormat.[[Notation]] to notation.
+        let notation = get_option::<Notation>(&options, utf16!("notation"), false, context)?
+            .unwrap_or_default();
+
+        // 20. Perform ? SetNumberFormatDigitOptions(numberFormat, options, mnfdDefault, mxfdDefault, notation).
+        let digit_options = DigitFormatOptions::from_options(
+            &options,
+            min_float_digits_default,
+            max_float_digits_default,
+            notation,
+            context,
+        )?;
+
+        // 21. Let compactDisplay be ? GetOption(options, "compactDisplay", string, « "short", "long" », "short").
+        let compact_display =
+            get_option::<CompactDisplay>(&options, utf16!("compactDisplay"), false, context)?
+                .unwrap_or_default();
+
+        // 22. Let defaultUseGrouping be "auto".
+        // 23. If notation is "compact", then
+        //     a. Set numberFormat.[[CompactDisplay]] to compactDisplay.
+        //     b. Set defaultUseGrouping to "min2".
+        let (compact_display, default_use_grouping) = if notation == Notation::Compact {
+            (Some(compact_display), UseGrouping::Min2)
+        } else {
+            (None, UseGrouping::Auto)
+        };
+
+        // 24. Let useGrouping be ? GetBooleanOrStringNumberFormatOption(options, "useGrouping", « "min2", "auto", "always", "true", "false" », defaultUseGrouping).
+        // 25. If useGrouping is "true" or useGrouping is "false", set useGrouping to defaultUseGrouping.
+        // 26. If useGrouping is true, set useGrouping to "always".
+        // 27. Set numberFormat.[[UseGrouping]] to useGrouping.
+        let use_grouping = get_use_grouping_option(&options, default_use_grouping, context)?;
+
+        // 28. Let signDisplay be ? GetOption(options, "signDisplay", string, « "auto", "never", "always", "exceptZero", "negative" », "auto").
+        // 29. Set numberFormat.[[SignDisplay]] to signDisplay.
+        let sign_display =
+            get_option::<SignDisplay>(&options, utf16!("signDisplay"), false, context)?
+                .unwrap_or_default();
+
+        // Only a subset of the available locales has CLDR data for the patterns and names used by
+        // the styles and notations other than plain decimals. Those fall back to English instead
+        // of mixing the symbols of the locale with the English patterns.
+        let data = NUMBER_FORMAT
+            .lookup(&locale.id, context.icu().locale_expander())
+            .unwrap_or_else(|| {
+                if unit_options != UnitFormatOptions::Decimal || notation == Notation::Compact {
+                    locale.id = langid!("en");
+                }
+                NUMBER_FORMAT
+                    .chain("en")
+                    .expect("the locale data must have English")
+            });
+
+        let symbols = DataProvider::<DecimalSymbolsV1Marker>::load(
+            &context.icu().provider(),
+            DataRequest {
+                locale: &DataLocale::from(&locale),
+                metadata: DataRequestMetadata::default(),
+            },
+        )
+        .and_then(DataResponse::take_payload)
+        .map_err(|e| JsNativeError::typ().with_message(e.to_string()))?;
+
+        let plural_rules = context
+            .icu()
+            .provider()
+            .try_new_plural_rules(&DataLocale::from(&locale), PluralRuleType::Cardinal)
+            .map_err(|e| JsNativeError::typ().with_message(e.to_string()))?;
+
+        // 30. Return numberFormat.
+        Ok(Self {
+            locale,
+            numbering_system: intl_options.service_options.numbering_system,
+            unit_options,
+            digit_options,
+            notation,
+            compact_display,
+            use_grouping,
+            sign_display,
+            symbols,
+            plural_rules,
+            data,
+            bound_format: None,
+        })
+    }
+
+    /// Abstract operation [`FormatNumeric ( numberFormat, x )`][spec].
+    ///
+    /// Formats the mathematical value `x` according to this number format.
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-formatnumber
+    pub(crate) fn format_value(&self, x: &IntlMathematicalValue) -> JsString {
+        // 1. Let parts be ? PartitionNumberPattern(numberFormat, x).
+        // 2. Let result be the empty String.
+        // 3. For each Record { [[Type]], [[Value]] } part in parts, do
+        //     a. Set result to the string-concatenation of result and part.[[Value]].
+        // 4. Return result.
+        self.partition(x)
+            .into_iter()
+            .map(|part| part.value)
+            .collect::<String>()
+            .into()
+    }
+
+    /// Abstract operation [`PartitionNumberRangePattern ( numberFormat, x, y )`][spec].
+    ///
+    /// Returns the parts of the formatted range, each one along with its source.
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-partitionnumberrangepattern
+    fn partition_range(
+        &self,
+        x: &IntlMathematicalValue,
+        y: &IntlMathematicalValue,
+    ) -> JsResult<Vec<(Part, &'static str)>> {
+        // 1. If x is NaN or y is NaN, throw a RangeError exception.
+        if matches!(x, IntlMathematicalValue::NaN) || matches!(y, IntlMathematicalValue::NaN) {
+            return Err(JsNativeError::range()
+                .with_message("cannot format a range with a NaN bound")
+                .into());
+        }
+
+        // 2. Let result be a new empty List.
+        // 3. Let xResult be ? PartitionNumberPattern(numberFormat, x).
+        // 4. Let yResult be ? PartitionNumberPattern(numberFormat, y).
+        let x = self.partition(x);
+        let y = self.partition(y);
+
+        // 5. If ! FormatNumeric(numberFormat, x) is equal to ! FormatNumeric(numberFormat, y), then
+        if x == y {
+            // a. Let appxResult be ? FormatApproximately(numberFormat, xResult).
+            // b. For each r in appxResult, do
+            //     i. Set r.[[Source]] to "shared".
+            // c. Return appxResult.
+            let mut result = Vec::with_capacity(x.len() + 1);
+            let mut approximately = Some(Part {
+                typ: "approximatelySign",
+                value: String::from("~"),
+            });
+            for part in x {
+                if part.is_numeric() {
+                    if let Some(sign) = approximately.take() {
+                        result.push((sign, "shared"));
+                    }
+                }
+                result.push((part, "shared"));
+            }
+            return Ok(result);
+        }
+
+        // 6. For each element r in xResult, do
+        //     a. Append the Record { [[Type]]: r.[[Type]], [[Value]]: r.[[Value]], [[Source]]: "startRange" } to result.
+        // 7. Let rangeSeparator be an ILND String value used to separate two numbers.
+        // 8. Append the Record { [[Type]]: "literal", [[Value]]: rangeSeparator, [[Source]]: "shared" } to result.
+        // 9. For each element r in yResult, do
+        //     a. Append the Record { [[Type]]: r.[[Type]], [[Value]]: r.[[Value]], [[Source]]: "endRange" } to result.
+        // 10. Return ! CollapseNumberRange(result).
+        let separator = if x.iter().chain(&y).all(Part::is_numeric) {
+            "–"
+        } else {
+            " – "
+        };
+        let mut result = Vec::with_capacity(x.len() + y.len() + 1);
+        result.extend(x.into_iter().map(|part| (part, "startRange")));
+        result.push((
+            Part {
+                typ: "literal",
+                value: String::from(separator),
+            },
+            "shared",
+        ));
+        result.extend(y.into_iter().map(|part| (part, "endRange")));
+        Ok(result)
+    }
+
+    /// [`Intl.NumberFormat.supportedLocalesOf ( locales [ , options ] )`][spec].
+    ///
+    /// Returns an array containing those of the provided locales that are supported in number
+    /// formatting without having to fall back to the runtime's default locale.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.numberformat.supportedlocalesof
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/NumberFormat/supportedLocalesOf
+    fn supported_locales_of(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        let locales = args.get_or_undefined(0);
+        let options = args.get_or_undefined(1);
+
+        // 1. Let availableLocales be %NumberFormat%.[[AvailableLocales]].
+        // 2. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(locales, context)?;
+
+        // 3. Return ? SupportedLocales(availableLocales, requestedLocales, options).
+        supported_locales::<<Self as Service>::LangMarker>(&requested_locales, options, context)
+            .map(JsValue::from)
+    }
+
+    /// [`get Intl.NumberFormat.prototype.format`][spec].
+    ///
+    /// Formats a number according to the locale and formatting options of this
+    /// `Intl.NumberFormat` object.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.numberformat.prototype.format
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/NumberFormat/format
+    fn format(this: &JsValue, _: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Let nf be the this value.
+        // 2. If the implementation supports the normative optional constructor mode of 4.3 Note 1, then
+        //     a. Set nf to ? UnwrapNumberFormat(nf).
+        // 3. Perform ? RequireInternalSlot(nf, [[InitializedNumberFormat]]).
+        let this = this.as_object().ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("`format` can only be called on a `NumberFormat` object")
+        })?;
+        let nf_obj = this.clone();
+        let mut nf = this.borrow_mut();
+        let nf = nf.as_number_format_mut().ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("`format` can only be called on a `NumberFormat` object")
+        })?;
+
+        // 4. If nf.[[BoundFormat]] is undefined, then
+        //     a. Let F be a new built-in function object as defined in Number Format Functions (15.5.2).
+        //     b. Set F.[[NumberFormat]] to nf.
+        //     c. Set nf.[[BoundFormat]] to F.
+        let bound_format = if let Some(f) = nf.bound_format.clone() {
+            f
+        } else {
+            let bound_format = FunctionObjectBuilder::new(
+                context,
+                // 15.5.2 Number Format Functions
+                // https://tc39.es/ecma402/#sec-number-format-functions
+                NativeFunction::from_copy_closure_with_captures(
+                    |_, args, nf, context| {
+                        // 1. Let nf be F.[[NumberFormat]].
+                        // 2. Assert: Type(nf) is Object and nf has an [[InitializedNumberFormat]] internal slot.
+                        // 3. If value is not provided, let value be undefined.
+                        // 4. Let x be ? ToIntlMathematicalValue(value).
+                        let x = to_intl_mathematical_value(args.get_or_undefined(0), context)?;
+
+                        // 5. Return ? FormatNumeric(nf, x).
+                        let nf = nf.borrow();
+                        let nf = nf
+                            .as_number_format()
+                            .expect("checked above that the object was a number format object");
+                        Ok(nf.format_value(&x).into())
+                    },
+                    nf_obj,
+                ),
+            )
+            .length(1)
+            .build();
+
+            nf.bound_format = Some(bound_format.clone());
+            bound_format
+        };
+
+        // 5. Return nf.[[BoundFormat]].
+        Ok(bound_format.into())
+    }
+
+    /// [`Intl.NumberFormat.prototype.formatToParts ( x )`][spec].
+    ///
+    /// Returns an array of objects representing the locale-specific parts of a formatted number.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.numberformat.prototype.formattoparts
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/NumberFormat/formatToParts
+    fn format_to_parts(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. Let nf be the this value.
+        // 2. Perform ? RequireInternalSlot(nf, [[InitializedNumberFormat]]).
+        let nf = this.as_object().ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("`formatToParts` can only be called on a `NumberFormat` object")
+        })?;
+
+        // 3. Let x be ? ToIntlMathematicalValue(value).
+        let x = to_intl_mathematical_value(args.get_or_undefined(0), context)?;
+
+        let nf = nf.borrow();
+        let nf = nf.as_number_format().ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("`formatToParts` can only be called on a `NumberFormat` object")
+        })?;
+
+        // 4. Return ? FormatNumericToParts(nf, x).
+
+        // Abstract operation `FormatNumericToParts ( numberFormat, x )`
+        // https://tc39.es/ecma402/#sec-formatnumbertoparts
+
+        // 1. Let parts be ? PartitionNumberPattern(numberFormat, x).
+        let parts = nf.partition(&x);
+
+        // 2. Let result be ! ArrayCreate(0).
+        // 3. Let n be 0.
+        // 4. For each Record { [[Type]], [[Value]] } part in parts, do
+        //     a. Let O be OrdinaryObjectCreate(%Object.prototype%).
+        //     b. Perform ! CreateDataPropertyOrThrow(O, "type", part.[[Type]]).
+        //     c. Perform ! CreateDataPropertyOrThrow(O, "value", part.[[Value]]).
+        //     d. Perform ! CreateDataPropertyOrThrow(result, ! ToString(n), O).
+        //     e. Increment n by 1.
+        // 5. Return result.
+        Ok(parts_to_array(parts.into_iter().map(|part| (part, None)), context).into())
+    }
+
+    /// [`Intl.NumberFormat.prototype.formatRange ( start, end )`][spec].
+    ///
+    /// Returns a string representing the range between two numbers, formatted according to the
+    /// locale and formatting options of this `Intl.NumberFormat` object.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.numberformat.prototype.formatrange
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/NumberFormat/formatRange
+    fn format_range(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. Let nf be the this value.
+        // 2. Perform ? RequireInternalSlot(nf, [[InitializedNumberFormat]]).
+        // 3. If start is undefined or end is undefined, throw a TypeError exception.
+        // 4. Let x be ? ToIntlMathematicalValue(start).
+        // 5. Let y be ? ToIntlMathematicalValue(end).
+        // 6. Return ? FormatNumericRange(nf, x, y).
+
+        // Abstract operation `FormatNumericRange ( numberFormat, x, y )`
+        // https://tc39.es/ecma402/#sec-formatnumericrange
+
+        // 1. Let parts be ? PartitionNumberRangePattern(numberFormat, x, y).
+        // 2. Let result be the empty String.
+        // 3. For each part in parts, do
+        //     a. Set result to the string-concatenation of result and part.[[Value]].
+        // 4. Return result.
+        let parts = Self::range_parts(this, args, "formatRange", context)?;
+        Ok(JsString::from(
+            parts
+                .into_iter()
+                .map(|(part, _)| part.value)
+                .collect::<String>(),
+        )
+        .into())
+    }
+
+    /// [`Intl.NumberFormat.prototype.formatRangeToParts ( start, end )`][spec].
+    ///
+    /// Returns an array of objects representing the locale-specific parts of a formatted range
+    /// of numbers.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.numberformat.prototype.formatrangetoparts
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/NumberFormat/formatRangeToParts
+    fn format_range_to_parts(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. Let nf be the this value.
+        // 2. Perform ? RequireInternalSlot(nf, [[InitializedNumberFormat]]).
+        // 3. If start is undefined or end is undefined, throw a TypeError exception.
+        // 4. Let x be ? ToIntlMathematicalValue(start).
+        // 5. Let y be ? ToIntlMathematicalValue(end).
+        // 6. Return ? FormatNumericRangeToParts(nf, x, y).
+
+        // Abstract operation `FormatNumericRangeToParts ( numberFormat, x, y )`
+        // https://tc39.es/ecma402/#sec-formatnumericrangetoparts
+
+        // 1. Let parts be ? PartitionNumberRangePattern(numberFormat, x, y).
+        // 2. Let result be ! ArrayCreate(0).
+        // 3. Let n be 0.
+        // 4. For each part in parts, do
+        //     a. Let O be OrdinaryObjectCreate(%Object.prototype%).
+        //     b. Perform ! CreateDataPropertyOrThrow(O, "type", part.[[Type]]).
+        //     c. Perform ! CreateDataPropertyOrThrow(O, "value", part.[[Value]]).
+        //     d. Perform ! CreateDataPropertyOrThrow(O, "source", part.[[Source]]).
+        //     e. Perform ! CreateDataPropertyOrThrow(result, ! ToString(n), O).
+        //     f. Increment n by 1.
+        // 5. Return result.
+        let parts = Self::range_parts(this, args, "formatRangeToParts", context)?;
+        Ok(parts_to_array(
+            parts.into_iter().map(|(part, source)| (part, Some(source))),
+            context,
+        )
+        .into())
+    }
+
+    /// Common steps of `formatRange` and `formatRangeToParts`, which partition the range given
+    /// by the `start` and `end` arguments.
+    fn range_parts(
+        this: &JsValue,
+        args: &[JsValue],
+        method: &str,
+        context: &mut Context<'_>,
+    ) -> JsResult<Vec<(Part, &'static str)>> {
+        // 1. Let nf be the this value.
+        // 2. Perform ? RequireInternalSlot(nf, [[InitializedNumberFormat]]).
+        let nf = this
+            .as_object()
+            .filter(|nf| nf.borrow().as_number_format().is_some())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(format!(
+                    "`{method}` can only be called on a `NumberFormat` object"
+                ))
+            })?;
+
+        // 3. If start is undefined or end is undefined, throw a TypeError exception.
+        let start = args.get_or_undefined(0);
+        let end = args.get_or_undefined(1);
+        if start.is_undefined() || end.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message(format!("`{method}` requires a start and an end value"))
+                .into());
+        }
+
+        // 4. Let x be ? ToIntlMathematicalValue(start).
+        let x = to_intl_mathematical_value(start, context)?;
+
+        // 5. Let y be ? ToIntlMathematicalValue(end).
+        let y = to_intl_mathematical_value(end, context)?;
+
+        let nf = nf.borrow();
+        let nf = nf
+            .as_number_format()
+            .expect("checked above that the object was a number format object");
+        nf.partition_range(&x, &y)
+    }
+
+    /// [`Intl.NumberFormat.prototype.resolvedOptions ( )`][spec].
+    ///
+    /// Returns a new object with properties reflecting the locale and number formatting options
+    /// computed during the construction of the current `Intl.NumberFormat` object.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.numberformat.prototype.resolvedoptions
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/NumberFormat/resolvedOptions
+    fn resolved_options(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. Let nf be the this value.
+        // 2. If the implementation supports the normative optional constructor mode of 4.3 Note 1, then
+        //     a. Set nf to ? UnwrapNumberFormat(nf).
+        // 3. Perform ? RequireInternalSlot(nf, [[InitializedNumberFormat]]).
+        let nf = this.as_object().map(JsObject::borrow).ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("`resolvedOptions` can only be called on a `NumberFormat` object")
+        })?;
+        let nf = nf.as_number_format().ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("`resolvedOptions` can only be called on a `NumberFormat` object")
+        })?;
+
+        // 4. Let options be OrdinaryObjectCreate(%Object.prototype%).
+        let options = context
+            .intrinsics()
+            .templates()
+            .ordinary_object()
+            .create(ObjectData::ordinary(), vec![]);
+
+        // 5. For each row of Table 13, except the header row, in table order, do
+        //     a. Let p be the Property value of the current row.
+        //     b. Let v be the value of nf's internal slot whose name is the Internal Slot value of the current row.
+        //     c. If v is not undefined, then
+        //         i. If there is a Conversion value in the current row, then
+        //             1. Assert: The Conversion value of the current row is number.
+        //             2. Set v to 𝔽(v).
+        //         ii. Perform ! CreateDataPropertyOrThrow(options, p, v).
+        let mut properties: Vec<(&[u16], JsValue)> = vec![
+            (utf16!("locale"), nf.locale.to_string().into()),
+            (
+                utf16!("numberingSystem"),
+                nf.numbering_system
+                    .as_ref()
+                    .map_or_else(|| String::from("latn"), ToString::to_string)
+                    .into(),
+            ),
+            (utf16!("style"), nf.unit_options.style().to_string().into()),
+        ];
+
+        match nf.unit_options {
+            UnitFormatOptions::Currency {
+                currency,
+                display,
+                sign,
+            } => {
+                properties.push((utf16!("currency"), currency.as_str().into()));
+                properties.push((utf16!("currencyDisplay"), display.to_string().into()));
+                properties.push((utf16!("currencySign"), sign.to_string().into()));
+            }
+            UnitFormatOptions::Unit { unit, display } => {
+                properties.push((utf16!("unit"), unit.to_string().into()));
+                properties.push((utf16!("unitDisplay"), display.to_string().into()));
+            }
+            UnitFormatOptions::Decimal | UnitFormatOptions::Percent => {}
+        }
+
+        let digits = nf.digit_options;
+        properties.push((
+            utf16!("minimumIntegerDigits"),
+            digits.minimum_integer_digits.into(),
+        ));
+        if let Some(Extrema { minimum, maximum }) = digits.rounding_type.fraction_digits() {
+            properties.push((utf16!("minimumFractionDigits"), minimum.into()));
+            properties.push((utf16!("maximumFractionDigits"), maximum.into()));
+        }
+        if let Some(Extrema { minimum, maximum }) = digits.rounding_type.significant_digits() {
+            properties.push((utf16!("minimumSignificantDigits"), minimum.into()));
+            properties.push((utf16!("maximumSignificantDigits"), maximum.into()));
+        }
+
+        properties.push((
+            utf16!("useGrouping"),
+            match nf.use_grouping {
+                UseGrouping::Always => "always".into(),
+                UseGrouping::Auto => "auto".into(),
+                UseGrouping::Min2 => "min2".into(),
+                UseGrouping::Never => false.into(),
+            },
+        ));
+        properties.push((utf16!("notation"), nf.notation.to_string().into()));
+        if let Some(compact_display) = nf.compact_display {
+            properties.push((utf16!("compactDisplay"), compact_display.to_string().into()));
+        }
+        properties.push((utf16!("signDisplay"), nf.sign_display.to_string().into()));
+        properties.push((
+            utf16!("roundingIncrement"),
+            digits.rounding_increment.into(),
+        ));
+        properties.push((
+            utf16!("roundingMode"),
+            digits.rounding_mode.to_string().into(),
+        ));
+        properties.push((
+            utf16!("roundingPriority"),
+            digits.rounding_priority.to_string().into(),
+        ));
+        properties.push((
+            utf16!("trailingZeroDisplay"),
+            digits.trailing_zero_display.to_string().into(),
+        ));
+
+        for (property, value) in properties {
+            options
+                .create_data_property_or_throw(property, value, context)
+                .expect("operation must not fail per the spec");
+        }
+
+        // 6. Return options.
+        Ok(options.into())
+    }
+}
+
+/// Creates an array of part objects from a list of parts, adding a `source` property to each
+/// part object if the part has one.
+pub(in crate::builtins::intl) fn parts_to_array(
+    parts: impl IntoIterator<Item = (Part, Option<&'static str>)>,
+    context: &mut Context<'_>,
+) -> JsObject {
+    let result = Array::array_create(0, None, context)
+        .expect("creating an empty array with default proto must not fail");
+
+    for (n, (part, source)) in parts.into_iter().enumerate() {
+        let o = context
+            .intrinsics()
+            .templates()
+            .ordinary_object()
+            .create(ObjectData::ordinary(), vec![]);
+        o.create_data_property_or_throw(utf16!("type"), part.typ, context)
+            .expect("operation must not fail per the spec");
+        o.create_data_property_or_throw(utf16!("value"), part.value, context)
+            .expect("operation must not fail per the spec");
+        if let Some(source) = source {
+            o.create_data_property_or_throw(utf16!("source"), source, context)
+                .expect("operation must not fail per the spec");
+        }
+        result
+            .create_data_property_or_throw(n, o, context)
+            .expect("operation must not fail per the spec");
+    }
+
+    result
+}
+
+/// Abstract operation [`GetBooleanOrStringNumberFormatOption ( options, property, stringValues, fallback )`][spec]
+/// for the `useGrouping` option.
+///
+/// [spec]: https://tc39.es/ecma402/#sec-getbooleanorstringnumberformatoption
+fn get_use_grouping_option(
+    options: &JsObject,
+    fallback: UseGrouping,
+    context: &mut Context<'_>,
+) -> JsResult<UseGrouping> {
+    // 1. Let value be ? Get(options, property).
+    let value = options.get(utf16!("useGrouping"), context)?;
+
+    // 2. If value is undefined, return fallback.
+    if value.is_undefined() {
+        return Ok(fallback);
+    }
+
+    // 3. If value is true, return true.
+    if value.as_boolean() == Some(true) {
+        return Ok(UseGrouping::Always);
+    }
+
+    // 4. If ToBoolean(value) is false, return false.
+    if !value.to_boolean() {
+        return Ok(UseGrouping::Never);
+    }
+
+    // 5. Let value be ? ToString(value).
+    // 6. If stringValues does not contain value, throw a RangeError exception.
+    // 7. Return value.
+    match value.to_string(context)?.to_std_string_escaped().as_str() {
+        "always" => Ok(UseGrouping::Always),
+        "auto" => Ok(UseGrouping::Auto),
+        "min2" => Ok(UseGrouping::Min2),
+        "true" | "false" => Ok(fallback),
+        _ => Err(JsNativeError::range()
+            .with_message("provided string was not `always`, `auto` or `min2`")
+            .into()),
+    }
+}
+
+/// An Intl mathematical value, which is a mathematical value along with the special values of a
+/// Number.
+///
+/// The sign of a [`FixedDecimal`] is used to represent the negative zero.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum IntlMathematicalValue {
+    NaN,
+    Infinity { negative: bool },
+    Decimal(FixedDecimal),
+}
+
+impl From<f64> for IntlMathematicalValue {
+    fn from(x: f64) -> Self {
+        if x.is_nan() {
+            Self::NaN
+        } else if x.is_infinite() {
+            Self::Infinity {
+                negative: x.is_sign_negative(),
+            }
+        } else {
+            Self::Decimal(
+                FixedDecimal::try_from_f64(x, DoublePrecision::Floating)
+                    .expect("finite numbers must be representable as a decimal"),
+            )
+        }
+    }
+}
+
+/// Abstract operation [`ToIntlMathematicalValue ( value )`][spec].
+///
+/// Converts `value` to a mathematical value. Unlike `ToNumber`, this converts `BigInt` values and
+/// numeric strings without losing precision.
+///
+/// [spec]: https://tc39.es/ecma402/#sec-tointlmathematicalvalue
+pub(crate) fn to_intl_mathematical_value(
+    value: &JsValue,
+    context: &mut Context<'_>,
+) -> JsResult<IntlMathematicalValue> {
+    // 1. Let primValue be ? ToPrimitive(value, number).
+    let prim_value = value.to_primitive(context, PreferredType::Number)?;
+
+    // 2. If Type(primValue) is BigInt, return ℝ(primValue).
+    if let Some(bigint) = prim_value.as_bigint() {
+        return Ok(IntlMathematicalValue::Decimal(
+            FixedDecimal::from_str(&bigint.to_string())
+                .expect("the string representation of a BigInt must be a valid decimal"),
+        ));
+    }
+
+    // 3. If Type(primValue) is String, then
+    //     a. Let str be primValue.
+    // 4. Else,
+    //     a. Let x be ? ToNumber(primValue).
+    //     b. If x is -0𝔽, return negative-zero.
+    //     c. Let str be Number::toString(x, 10).
+    // 5. Let text be StringToCodePoints(str).
+    // 6. Let literal be ParseText(text, StringNumericLiteral).
+    // 7. If literal is a List of errors, return not-a-number.
+    // 8. Let intlMV be the StringIntlMV of literal.
+    // 9. If intlMV is a mathematical value, then
+    //     a. Let rounded be RoundMVResult(abs(intlMV)).
+    //     b. If rounded is +∞𝔽 and intlMV < 0, return negative-infinity.
+    //     c. If rounded is +∞𝔽, return positive-infinity.
+    //     d. If rounded is +0𝔽 and intlMV < 0, return negative-zero.
+    //     e. If rounded is +0𝔽, return 0.
+    // 10. Return intlMV.
+    if let Some(string) = prim_value.as_string() {
+        if let Some(decimal) = parse_decimal(
+            string
+                .to_std_string_escaped()
+                .trim_matches(is_trimmable_whitespace),
+        ) {
+            return Ok(IntlMathematicalValue::Decimal(decimal));
+        }
+    }
+
+    // Any other numeric literal (such as hexadecimal integers or infinities) can be represented
+    // exactly by a Number.
+    Ok(prim_value.to_number(context)?.into())
+}
+
+/// Parses a decimal `StrUnsignedDecimalLiteral`, along with its sign, as an exact decimal number.
+fn parse_decimal(string: &str) -> Option<FixedDecimal> {
+    let (sign, string) = if let Some(string) = string.strip_prefix('-') {
+        (Sign::Negative, string)
+    } else {
+        (Sign::None, string.strip_prefix('+').unwrap_or(string))
+    };
+
+    let (mantissa, exponent) = match string.find(['e', 'E']) {
+        Some(index) => (&string[..index], string[index + 1..].parse::<i16>().ok()?),
+        None => (string, 0),
+    };
+
+    let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    if integer.is_empty() && fraction.is_empty()
+        || !integer
+            .bytes()
+            .chain(fraction.bytes())
+            .all(|c| c.is_ascii_digit())
+    {
+        return None;
+    }
+
+    let integer = if integer.is_empty() { "0" } else { integer };
+    let mut decimal = if fraction.is_empty() {
+        FixedDecimal::from_str(integer)
+    } else {
+        FixedDecimal::from_str(&format!("{integer}.{fraction}"))
+    }
+    .ok()?;
+    decimal.multiply_pow10(exponent);
+    decimal.set_sign(sign);
+    Some(decimal)
+}
@@ -0,0 +1,1398 @@
+use std::{cmp::Ordering, fmt::Display, str::FromStr};
+
+use fixed_decimal::{FixedDecimal, Sign};
+use icu_decimal::provider::GroupingSizesV1;
+use num_bigint::BigUint;
+
+use crate::{
+    builtins::intl::options::{
+        default_number_option, get_number_option, get_option, OptionTypeParsable,
+    },
+    object::JsObject,
+    string::utf16,
+    Context, JsNativeError, JsResult,
+};
+
+use super::format::simple_unit;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum Style {
+    #[default]
+    Decimal,
+    Percent,
+    Currency,
+    Unit,
+}
+
+impl Display for Style {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Decimal => "decimal",
+            Self::Percent => "percent",
+            Self::Currency => "currency",
+            Self::Unit => "unit",
+        }
+        .fmt(f)
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseStyleError;
+
+impl Display for ParseStyleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("provided string was not `decimal`, `percent`, `currency` or `unit`")
+    }
+}
+
+impl FromStr for Style {
+    type Err = ParseStyleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "decimal" => Ok(Self::Decimal),
+            "percent" => Ok(Self::Percent),
+            "currency" => Ok(Self::Currency),
+            "unit" => Ok(Self::Unit),
+            _ => Err(ParseStyleError),
+        }
+    }
+}
+
+impl OptionTypeParsable for Style {}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum CurrencyDisplay {
+    Code,
+    #[default]
+    Symbol,
+    NarrowSymbol,
+    Name,
+}
+
+impl Display for CurrencyDisplay {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Code => "code",
+            Self::Symbol => "symbol",
+            Self::NarrowSymbol => "narrowSymbol",
+            Self::Name => "name",
+        }
+        .fmt(f)
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseCurrencyDisplayError;
+
+impl Display for ParseCurrencyDisplayError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("provided string was not `code`, `symbol`, `narrowSymbol` or `name`")
+    }
+}
+
+impl FromStr for CurrencyDisplay {
+    type Err = ParseCurrencyDisplayError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "code" => Ok(Self::Code),
+            "symbol" => Ok(Self::Symbol),
+            "narrowSymbol" => Ok(Self::NarrowSymbol),
+            "name" => Ok(Self::Name),
+            _ => Err(ParseCurrencyDisplayError),
+        }
+    }
+}
+
+impl OptionTypeParsable for CurrencyDisplay {}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum CurrencySign {
+    #[default]
+    Standard,
+    Accounting,
+}
+
+impl Display for CurrencySign {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Standard => "standard",
+            Self::Accounting => "accounting",
+        }
+        .fmt(f)
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseCurrencySignError;
+
+impl Display for ParseCurrencySignError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("provided string was not `standard` or `accounting`")
+    }
+}
+
+impl FromStr for CurrencySign {
+    type Err = ParseCurrencySignError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "standard" => Ok(Self::Standard),
+            "accounting" => Ok(Self::Accounting),
+            _ => Err(ParseCurrencySignError),
+        }
+    }
+}
+
+impl OptionTypeParsable for CurrencySign {}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum UnitDisplay {
+    #[default]
+    Short,
+    Narrow,
+    Long,
+}
+
+impl Display for UnitDisplay {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Short => "short",
+            Self::Narrow => "narrow",
+            Self::Long => "long",
+        }
+        .fmt(f)
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseUnitDisplayError;
+
+impl Display for ParseUnitDisplayError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("provided string was not `short`, `narrow` or `long`")
+    }
+}
+
+impl FromStr for UnitDisplay {
+    type Err = ParseUnitDisplayError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "short" => Ok(Self::Short),
+            "narrow" => Ok(Self::Narrow),
+            "long" => Ok(Self::Long),
+            _ => Err(ParseUnitDisplayError),
+        }
+    }
+}
+
+impl OptionTypeParsable for UnitDisplay {}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum Notation {
+    #[default]
+    Standard,
+    Scientific,
+    Engineering,
+    Compact,
+}
+
+impl Display for Notation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Standard => "standard",
+            Self::Scientific => "scientific",
+            Self::Engineering => "engineering",
+            Self::Compact => "compact",
+        }
+        .fmt(f)
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseNotationError;
+
+impl Display for ParseNotationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("provided string was not `standard`, `scientific`, `engineering` or `compact`")
+    }
+}
+
+impl FromStr for Notation {
+    type Err = ParseNotationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "standard" => Ok(Self::Standard),
+            "scientific" => Ok(Self::Scientific),
+            "engineering" => Ok(Self::Engineering),
+            "compact" => Ok(Self::Compact),
+            _ => Err(ParseNotationError),
+        }
+    }
+}
+
+impl OptionTypeParsable for Notation {}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum CompactDisplay {
+    #[default]
+    Short,
+    Long,
+}
+
+impl Display for CompactDisplay {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Short => "short",
+            Self::Long => "long",
+        }
+        .fmt(f)
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseCompactDisplayError;
+
+impl Display for ParseCompactDisplayError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("provided string was not `short` or `long`")
+    }
+}
+
+impl FromStr for CompactDisplay {
+    type Err = ParseCompactDisplayError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "short" => Ok(Self::Short),
+            "long" => Ok(Self::Long),
+            _ => Err(ParseCompactDisplayError),
+        }
+    }
+}
+
+impl OptionTypeParsable for CompactDisplay {}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum SignDisplay {
+    #[default]
+    Auto,
+    Never,
+    Always,
+    ExceptZero,
+    Negative,
+}
+
+impl Display for SignDisplay {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Auto => "auto",
+            Self::Never => "never",
+            Self::Always => "always",
+            Self::ExceptZero => "exceptZero",
+            Self::Negative => "negative",
+        }
+        .fmt(f)
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseSignDisplayError;
+
+impl Display for ParseSignDisplayError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("provided string was not `auto`, `never`, `always`, `exceptZero` or `negative`")
+    }
+}
+
+impl FromStr for SignDisplay {
+    type Err = ParseSignDisplayError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "auto" => Ok(Self::Auto),
+            "never" => Ok(Self::Never),
+            "always" => Ok(Self::Always),
+            "exceptZero" => Ok(Self::ExceptZero),
+            "negative" => Ok(Self::Negative),
+            _ => Err(ParseSignDisplayError),
+        }
+    }
+}
+
+impl OptionTypeParsable for SignDisplay {}
+
+/// The grouping strategy of a number format, as defined by the `useGrouping` option.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum UseGrouping {
+    Always,
+    Auto,
+    Min2,
+    Never,
+}
+
+impl UseGrouping {
+    /// Checks if a grouping separator must be written after the digit at `magnitude` of a number
+    /// whose most significant digit is at `upper_magnitude`.
+    pub(crate) fn check(
+        self,
+        upper_magnitude: i16,
+        magnitude: i16,
+        sizes: GroupingSizesV1,
+    ) -> bool {
+        let primary = i16::from(sizes.primary);
+        if primary == 0 || magnitude < primary {
+            return false;
+        }
+        let min_grouping = match self {
+            Self::Never => return false,
+            Self::Always => 1,
+            Self::Auto => i16::from(sizes.min_grouping).max(1),
+            Self::Min2 => i16::from(sizes.min_grouping).max(2),
+        };
+        if upper_magnitude < primary + min_grouping - 1 {
+            return false;
+        }
+        let secondary = if sizes.secondary == 0 {
+            primary
+        } else {
+            i16::from(sizes.secondary)
+        };
+        (magnitude - primary) % secondary == 0
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum RoundingMode {
+    Ceil,
+    Floor,
+    Expand,
+    Trunc,
+    HalfCeil,
+    HalfFloor,
+    #[default]
+    HalfExpand,
+    HalfTrunc,
+    HalfEven,
+}
+
+impl RoundingMode {
+    /// Rounds `number` to the digit at `position`, using this rounding mode.
+    ///
+    /// This doesn't use the rounding methods of [`FixedDecimal`], since those can carry into the
+    /// wrong digit when there are zeroes between `position` and the rest of the digits.
+    pub(crate) fn round(self, number: &mut FixedDecimal, position: i16) {
+        let sign = number.sign();
+
+        // The number is already exactly representable at this position.
+        if number.is_zero() || number.nonzero_magnitude_end() >= position {
+            number.trunc(position);
+            return;
+        }
+
+        // Abstract operation `GetUnsignedRoundingMode ( roundingMode, isNegative )`
+        // https://tc39.es/ecma402/#sec-getunsignedroundingmode
+        let mode = match (self, sign == Sign::Negative) {
+            (Self::Ceil, true) | (Self::Floor, false) => Self::Trunc,
+            (Self::Ceil, false) | (Self::Floor, true) => Self::Expand,
+            (Self::HalfCeil, true) | (Self::HalfFloor, false) => Self::HalfTrunc,
+            (Self::HalfCeil, false) | (Self::HalfFloor, true) => Self::HalfExpand,
+            (mode, _) => mode,
+        };
+
+        // Abstract operation `ApplyUnsignedRoundingMode ( x, r1, r2, unsignedRoundingMode )`
+        // https://tc39.es/ecma402/#sec-applyunsignedroundingmode
+        let half = number.digit_at(position - 1).cmp(&5).then_with(|| {
+            if number.nonzero_magnitude_end() < position - 1 {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }
+        });
+        let round_up = match mode {
+            Self::Expand => true,
+            Self::Trunc => false,
+            Self::HalfExpand => half != Ordering::Less,
+            Self::HalfTrunc => half == Ordering::Greater,
+            _ => {
+                half == Ordering::Greater
+                    || half == Ordering::Equal && number.digit_at(position) % 2 == 1
+            }
+        };
+
+        number.trunc(position);
+        if round_up {
+            let (digits, lower_magnitude) = to_digits(number);
+            let unit = BigUint::from(10u8).pow(u32::from(position.abs_diff(lower_magnitude)));
+            *number = from_digits(&(digits + unit), lower_magnitude);
+            number.pad_end(position);
+        }
+        number.set_sign(sign);
+    }
+}
+
+impl Display for RoundingMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Ceil => "ceil",
+            Self::Floor => "floor",
+            Self::Expand => "expand",
+            Self::Trunc => "trunc",
+            Self::HalfCeil => "halfCeil",
+            Self::HalfFloor => "halfFloor",
+            Self::HalfExpand => "halfExpand",
+            Self::HalfTrunc => "halfTrunc",
+            Self::HalfEven => "halfEven",
+        }
+        .fmt(f)
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseRoundingModeError;
+
+impl Display for ParseRoundingModeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("provided string was not a valid rounding mode")
+    }
+}
+
+impl FromStr for RoundingMode {
+    type Err = ParseRoundingModeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ceil" => Ok(Self::Ceil),
+            "floor" => Ok(Self::Floor),
+            "expand" => Ok(Self::Expand),
+            "trunc" => Ok(Self::Trunc),
+            "halfCeil" => Ok(Self::HalfCeil),
+            "halfFloor" => Ok(Self::HalfFloor),
+            "halfExpand" => Ok(Self::HalfExpand),
+            "halfTrunc" => Ok(Self::HalfTrunc),
+            "halfEven" => Ok(Self::HalfEven),
+            _ => Err(ParseRoundingModeError),
+        }
+    }
+}
+
+impl OptionTypeParsable for RoundingMode {}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum RoundingPriority {
+    #[default]
+    Auto,
+    MorePrecision,
+    LessPrecision,
+}
+
+impl Display for RoundingPriority {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Auto => "auto",
+            Self::MorePrecision => "morePrecision",
+            Self::LessPrecision => "lessPrecision",
+        }
+        .fmt(f)
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseRoundingPriorityError;
+
+impl Display for ParseRoundingPriorityError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("provided string was not `auto`, `morePrecision` or `lessPrecision`")
+    }
+}
+
+impl FromStr for RoundingPriority {
+    type Err = ParseRoundingPriorityError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "auto" => Ok(Self::Auto),
+            "morePrecision" => Ok(Self::MorePrecision),
+            "lessPrecision" => Ok(Self::LessPrecision),
+            _ => Err(ParseRoundingPriorityError),
+        }
+    }
+}
+
+impl OptionTypeParsable for RoundingPriority {}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum TrailingZeroDisplay {
+    #[default]
+    Auto,
+    StripIfInteger,
+}
+
+impl Display for TrailingZeroDisplay {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Auto => "auto",
+            Self::StripIfInteger => "stripIfInteger",
+        }
+        .fmt(f)
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseTrailingZeroDisplayError;
+
+impl Display for ParseTrailingZeroDisplayError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("provided string was not `auto` or `stripIfInteger`")
+    }
+}
+
+impl FromStr for TrailingZeroDisplay {
+    type Err = ParseTrailingZeroDisplayError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "auto" => Ok(Self::Auto),
+            "stripIfInteger" => Ok(Self::StripIfInteger),
+            _ => Err(ParseTrailingZeroDisplayError),
+        }
+    }
+}
+
+impl OptionTypeParsable for TrailingZeroDisplay {}
+
+/// A well-formed ISO 4217 currency code, normalized to upper case.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Currency([u8; 3]);
+
+impl Currency {
+    /// Gets the currency code as a string.
+    pub(crate) fn as_str(&self) -> &str {
+        std::str::from_utf8(&self.0).expect("currency codes are always ASCII")
+    }
+
+    /// Abstract operation [`CurrencyDigits ( currency )`][spec].
+    ///
+    /// Returns the number of minor units of the currency, as defined by the ISO 4217 standard.
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-currencydigits
+    pub(crate) fn digits(self) -> u8 {
+        match self.as_str() {
+            "BIF" | "CLP" | "DJF" | "GNF" | "ISK" | "JPY" | "KMF" | "KRW" | "PYG" | "RWF"
+            | "UGX" | "UYI" | "VND" | "VUV" | "XAF" | "XOF" | "XPF" => 0,
+            "BHD" | "IQD" | "JOD" | "KWD" | "LYD" | "OMR" | "TND" => 3,
+            "CLF" | "UYW" => 4,
+            _ => 2,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseCurrencyError;
+
+impl Display for ParseCurrencyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("provided string was not a well-formed currency code")
+    }
+}
+
+impl FromStr for Currency {
+    type Err = ParseCurrencyError;
+
+    /// Abstract operation [`IsWellFormedCurrencyCode ( currency )`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-iswellformedcurrencycode
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match *s.as_bytes() {
+            [a, b, c] if [a, b, c].iter().all(u8::is_ascii_alphabetic) => Ok(Self([
+                a.to_ascii_uppercase(),
+                b.to_ascii_uppercase(),
+                c.to_ascii_uppercase(),
+            ])),
+            _ => Err(ParseCurrencyError),
+        }
+    }
+}
+
+impl OptionTypeParsable for Currency {}
+
+/// A well-formed unit identifier, which is either a sanctioned single unit or the division of two
+/// of them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Unit {
+    pub(crate) numerator: &'static str,
+    pub(crate) denominator: Option<&'static str>,
+}
+
+impl Display for Unit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.numerator)?;
+        if let Some(denominator) = self.denominator {
+            write!(f, "-per-{denominator}")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseUnitError;
+
+impl Display for ParseUnitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("provided string was not a well-formed unit identifier")
+    }
+}
+
+impl FromStr for Unit {
+    type Err = ParseUnitError;
+
+    /// Abstract operation [`IsWellFormedUnitIdentifier ( unitIdentifier )`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-iswellformedunitidentifier
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // 1. If ! IsSanctionedSingleUnitIdentifier(unitIdentifier) is true, then
+        //     a. Return true.
+        if let Some(numerator) = simple_unit(s) {
+            return Ok(Self {
+                numerator,
+                denominator: None,
+            });
+        }
+
+        // 2. Let i be StringIndexOf(unitIdentifier, "-per-", 0).
+        // 3. If i is -1 or StringIndexOf(unitIdentifier, "-per-", i + 1) is not -1, then
+        //     a. Return false.
+        // 4. Assert: The five-character substring "-per-" occurs exactly once in unitIdentifier, at index i.
+        // 5. Let numerator be the substring of unitIdentifier from 0 to i.
+        // 6. Let denominator be the substring of unitIdentifier from i + 5.
+        // 7. If ! IsSanctionedSingleUnitIdentifier(numerator) and
+        //    ! IsSanctionedSingleUnitIdentifier(denominator) are both true, then
+        //     a. Return true.
+        // 8. Return false.
+        let (numerator, denominator) = s.split_once("-per-").ok_or(ParseUnitError)?;
+        Ok(Self {
+            numerator: simple_unit(numerator).ok_or(ParseUnitError)?,
+            denominator: Some(simple_unit(denominator).ok_or(ParseUnitError)?),
+        })
+    }
+}
+
+impl OptionTypeParsable for Unit {}
+
+/// The style of a number format, along with its currency or unit options.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(variant_size_differences)]
+pub(crate) enum UnitFormatOptions {
+    Decimal,
+    Percent,
+    Currency {
+        currency: Currency,
+        display: CurrencyDisplay,
+        sign: CurrencySign,
+    },
+    Unit {
+        unit: Unit,
+        display: UnitDisplay,
+    },
+}
+
+impl UnitFormatOptions {
+    /// Abstract operation [`SetNumberFormatUnitOptions ( intlObj, options )`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-setnumberformatunitoptions
+    pub(crate) fn from_options(options: &JsObject, context: &mut Context<'_>) -> JsResult<Self> {
+        // 1. Let style be ? GetOption(options, "style", string, « "decimal", "percent", "currency", "unit" », "decimal").
+        // 2. Set intlObj.[[Style]] to style.
+        let style =
+            get_option::<Style>(options, utf16!("style"), false, context)?.unwrap_or_default();
+
+        // 3. Let currency be ? GetOption(options, "currency", string, empty, undefined).
+        // 5. Else,
+        //     a. If IsWellFormedCurrencyCode(currency) is false, throw a RangeError exception.
+        let currency = get_option::<Currency>(options, utf16!("currency"), false, context)?;
+
+        // 4. If currency is undefined, then
+        //     a. If style is "currency", throw a TypeError exception.
+        if style == Style::Currency && currency.is_none() {
+            return Err(JsNativeError::typ()
+                .with_message("cannot format a currency without a `currency` option")
+                .into());
+        }
+
+        // 6. Let currencyDisplay be ? GetOption(options, "currencyDisplay", string, « "code", "symbol", "narrowSymbol", "name" », "symbol").
+        let currency_display =
+            get_option::<CurrencyDisplay>(options, utf16!("currencyDisplay"), false, context)?
+                .unwrap_or_default();
+
+        // 7. Let currencySign be ? GetOption(options, "currencySign", string, « "standard", "accounting" », "standard").
+        let currency_sign =
+            get_option::<CurrencySign>(options, utf16!("currencySign"), false, context)?
+                .unwrap_or_default();
+
+        // 8. Let unit be ? GetOption(options, "unit", string, empty, undefined).
+        // 10. Else,
+        //     a. If IsWellFormedUnitIdentifier(unit) is false, throw a RangeError exception.
+        let unit = get_option::<Unit>(options, utf16!("unit"), false, context)?;
+
+        // 9. If unit is undefined, then
+        //     a. If style is "unit", throw a TypeError exception.
+        if style == Style::Unit && unit.is_none() {
+            return Err(JsNativeError::typ()
+                .with_message("cannot format a unit without a `unit` option")
+                .into());
+        }
+
+        // 11. Let unitDisplay be ? GetOption(options, "unitDisplay", string, « "short", "narrow", "long" », "short").
+        let unit_display =
+            get_option::<UnitDisplay>(options, utf16!("unitDisplay"), false, context)?
+                .unwrap_or_default();
+
+        Ok(match (style, currency, unit) {
+            // 12. If style is "currency", then
+            //     a. Set intlObj.[[Currency]] to the ASCII-uppercase of currency.
+            //     b. Set intlObj.[[CurrencyDisplay]] to currencyDisplay.
+            //     c. Set intlObj.[[CurrencySign]] to currencySign.
+            (Style::Currency, Some(currency), _) => Self::Currency {
+                currency,
+                display: currency_display,
+                sign: currency_sign,
+            },
+            // 13. If style is "unit", then
+            //     a. Set intlObj.[[Unit]] to unit.
+            //     b. Set intlObj.[[UnitDisplay]] to unitDisplay.
+            (Style::Unit, _, Some(unit)) => Self::Unit {
+                unit,
+                display: unit_display,
+            },
+            (Style::Percent, _, _) => Self::Percent,
+            _ => Self::Decimal,
+        })
+    }
+
+    /// Gets the style of the number format.
+    pub(crate) const fn style(&self) -> Style {
+        match self {
+            Self::Decimal => Style::Decimal,
+            Self::Percent => Style::Percent,
+            Self::Currency { .. } => Style::Currency,
+            Self::Unit { .. } => Style::Unit,
+        }
+    }
+}
+
+/// The minimum and maximum number of digits of some kind.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Extrema {
+    pub(crate) minimum: u8,
+    pub(crate) maximum: u8,
+}
+
+/// The kind of rounding used by a number format, along with its digit limits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum RoundingType {
+    FractionDigits(Extrema),
+    SignificantDigits(Extrema),
+    MorePrecision {
+        significant_digits: Extrema,
+        fraction_digits: Extrema,
+    },
+    LessPrecision {
+        significant_digits: Extrema,
+        fraction_digits: Extrema,
+    },
+}
+
+impl RoundingType {
+    /// Gets the fraction digits limits, if this rounding type uses them.
+    pub(crate) const fn fraction_digits(self) -> Option<Extrema> {
+        match self {
+            Self::FractionDigits(digits)
+            | Self::MorePrecision {
+                fraction_digits: digits,
+                ..
+            }
+            | Self::LessPrecision {
+                fraction_digits: digits,
+                ..
+            } => Some(digits),
+            Self::SignificantDigits(_) => None,
+        }
+    }
+
+    /// Gets the significant digits limits, if this rounding type uses them.
+    pub(crate) const fn significant_digits(self) -> Option<Extrema> {
+        match self {
+            Self::SignificantDigits(digits)
+            | Self::MorePrecision {
+                significant_digits: digits,
+                ..
+            }
+            | Self::LessPrecision {
+                significant_digits: digits,
+                ..
+            } => Some(digits),
+            Self::FractionDigits(_) => None,
+        }
+    }
+}
+
+/// The digit options of a number format, which define how numbers are rounded and padded.
+///
+/// These are shared by `Intl.NumberFormat` and `Intl.PluralRules`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct DigitFormatOptions {
+    pub(crate) minimum_integer_digits: u8,
+    pub(crate) rounding_increment: u16,
+    pub(crate) rounding_mode: RoundingMode,
+    pub(crate) trailing_zero_display: TrailingZeroDisplay,
+    pub(crate) rounding_type: RoundingType,
+    pub(crate) rounding_priority: RoundingPriority,
+}
+
+impl DigitFormatOptions {
+    /// Abstract operation [`SetNumberFormatDigitOptions ( intlObj, options, mnfdDefault, mxfdDefault, notation )`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-setnfdigitoptions
+    pub(crate) fn from_options(
+        options: &JsObject,
+        min_float_digits_default: u8,
+        mut max_float_digits_default: u8,
+        notation: Notation,
+        context: &mut Context<'_>,
+    ) -> JsResult<Self> {
+        // 1. Let mnid be ? GetNumberOption(options, "minimumIntegerDigits,", 1, 21, 1).
+        let minimum_integer_digits = get_number_option(
+            options,
+            utf16!("minimumIntegerDigits"),
+            1.0,
+            21.0,
+            Some(1.0),
+            context,
+        )?
+        .unwrap_or(1.0) as u8;
+
+        // 2. Let mnfd be ? Get(options, "minimumFractionDigits").
+        let min_float_digits = options.get(utf16!("minimumFractionDigits"), context)?;
+        // 3. Let mxfd be ? Get(options, "maximumFractionDigits").
+        let max_float_digits = options.get(utf16!("maximumFractionDigits"), context)?;
+        // 4. Let mnsd be ? Get(options, "minimumSignificantDigits").
+        let min_sig_digits = options.get(utf16!("minimumSignificantDigits"), context)?;
+        // 5. Let mxsd be ? Get(options, "maximumSignificantDigits").
+        let max_sig_digits = options.get(utf16!("maximumSignificantDigits"), context)?;
+
+        // 6. Set intlObj.[[MinimumIntegerDigits]] to mnid.
+        // 7. Let roundingIncrement be ? GetNumberOption(options, "roundingIncrement", 1, 5000, 1).
+        let rounding_increment = get_number_option(
+            options,
+            utf16!("roundingIncrement"),
+            1.0,
+            5000.0,
+            Some(1.0),
+            context,
+        )?
+        .unwrap_or(1.0) as u16;
+
+        // 8. If roundingIncrement is not in « 1, 2, 5, 10, 20, 25, 50, 100, 200, 250, 500, 1000, 2000, 2500, 5000 », throw a RangeError exception.
+        if ![
+            1, 2, 5, 10, 20, 25, 50, 100, 200, 250, 500, 1000, 2000, 2500, 5000,
+        ]
+        .contains(&rounding_increment)
+        {
+            return Err(JsNativeError::range()
+                .with_message("invalid value for the `roundingIncrement` option")
+                .into());
+        }
+
+        // 9. Let roundingMode be ? GetOption(options, "roundingMode", string, « "ceil", "floor", "expand", "trunc", "halfCeil", "halfFloor", "halfExpand", "halfTrunc", "halfEven" », "halfExpand").
+        let rounding_mode =
+            get_option::<RoundingMode>(options, utf16!("roundingMode"), false, context)?
+                .unwrap_or_default();
+
+        // 10. Let roundingPriority be ? GetOption(options, "roundingPriority", string, « "auto", "morePrecision", "lessPrecision" », "auto").
+        let rounding_priority =
+            get_option::<RoundingPriority>(options, utf16!("roundingPriority"), false, context)?
+                .unwrap_or_default();
+
+        // 11. Let trailingZeroDisplay be ? GetOption(options, "trailingZeroDisplay", string, « "auto", "stripIfInteger" », "auto").
+        let trailing_zero_display = get_option::<TrailingZeroDisplay>(
+            options,
+            utf16!("trailingZeroDisplay"),
+            false,
+            context,
+        )?
+        .unwrap_or_default();
+
+        // 12. NOTE: All fields required by SetNumberFormatDigitOptions have now been read from options. The remainder of this AO interprets the options and may throw exceptions.
+
+        // 13. If roundingIncrement is not 1, set mxfdDefault to mnfdDefault.
+        if rounding_increment != 1 {
+            max_float_digits_default = min_float_digits_default;
+        }
+
+        // 14. Set intlObj.[[RoundingIncrement]] to roundingIncrement.
+        // 15. Set intlObj.[[RoundingMode]] to roundingMode.
+        // 16. Set intlObj.[[TrailingZeroDisplay]] to trailingZeroDisplay.
+
+        // 17. If mnsd is not undefined or mxsd is not undefined, then
+        //     a. Let hasSd be true.
+        // 18. Else,
+        //     a. Let hasSd be false.
+        let has_sig_digits = !min_sig_digits.is_undefined() || !max_sig_digits.is_undefined();
+
+        // 19. If mnfd is not undefined or mxfd is not undefined, then
+        //     a. Let hasFd be true.
+        // 20. Else,
+        //     a. Let hasFd be false.
+        let has_float_digits = !min_float_digits.is_undefined() || !max_float_digits.is_undefined();
+
+        // 21. Let needSd be true.
+        // 22. Let needFd be true.
+        // 23. If roundingPriority is "auto", then
+        //     a. Set needSd to hasSd.
+        //     b. If needSd is true, or hasFd is false and notation is "compact", then
+        //         i. Set needFd to false.
+        let (need_sig_digits, need_float_digits) = if rounding_priority == RoundingPriority::Auto {
+            (
+                has_sig_digits,
+                !(has_sig_digits || (!has_float_digits && notation == Notation::Compact)),
+            )
+        } else {
+            (true, true)
+        };
+
+        // 24. If needSd is true, then
+        let significant_digits = if need_sig_digits {
+            // a. If hasSd is true, then
+            if has_sig_digits {
+                // i. Set intlObj.[[MinimumSignificantDigits]] to ? DefaultNumberOption(mnsd, 1, 21, 1).
+                let minimum =
+                    default_number_option(&min_sig_digits, 1.0, 21.0, Some(1.0), context)?
+                        .unwrap_or(1.0);
+                // ii. Set intlObj.[[MaximumSignificantDigits]] to ? DefaultNumberOption(mxsd, intlObj.[[MinimumSignificantDigits]], 21, 21).
+                let maximum =
+                    default_number_option(&max_sig_digits, minimum, 21.0, Some(21.0), context)?
+                        .unwrap_or(21.0);
+                Some(Extrema {
+                    minimum: minimum as u8,
+                    maximum: maximum as u8,
+                })
+            } else {
+                // b. Else,
+                //     i. Set intlObj.[[MinimumSignificantDigits]] to 1.
+                //     ii. Set intlObj.[[MaximumSignificantDigits]] to 21.
+                Some(Extrema {
+                    minimum: 1,
+                    maximum: 21,
+                })
+            }
+        } else {
+            None
+        };
+
+        // 25. If needFd is true, then
+        let fraction_digits = if need_float_digits {
+            // a. If hasFd is true, then
+            if has_float_digits {
+                // i. Let mnfd be ? DefaultNumberOption(mnfd, 0, 100, undefined).
+                let min_float_digits =
+                    default_number_option(&min_float_digits, 0.0, 100.0, None, context)?;
+                // ii. Let mxfd be ? DefaultNumberOption(mxfd, 0, 100, undefined).
+                let max_float_digits =
+                    default_number_option(&max_float_digits, 0.0, 100.0, None, context)?;
+
+                let (minimum, maximum) = match (min_float_digits, max_float_digits) {
+                    // iii. If mnfd is undefined, set mnfd to min(mnfdDefault, mxfd).
+                    (None, Some(max)) => (f64::from(min_float_digits_default).min(max), max),
+                    // iv. Else if mxfd is undefined, set mxfd to max(mxfdDefault, mnfd).
+                    (Some(min), None) => (min, f64::from(max_float_digits_default).max(min)),
+                    // v. Else if mnfd is greater than mxfd, throw a RangeError exception.
+                    (Some(min), Some(max)) if min > max => return Err(JsNativeError::range()
+                        .with_message(
+                            "`minimumFractionDigits` cannot be bigger than `maximumFractionDigits`",
+                        )
+                        .into()),
+                    (Some(min), Some(max)) => (min, max),
+                    (None, None) => unreachable!("hasFd must be true"),
+                };
+
+                // vi. Set intlObj.[[MinimumFractionDigits]] to mnfd.
+                // vii. Set intlObj.[[MaximumFractionDigits]] to mxfd.
+                Some(Extrema {
+                    minimum: minimum as u8,
+                    maximum: maximum as u8,
+                })
+            } else {
+                // b. Else,
+                //     i. Set intlObj.[[MinimumFractionDigits]] to mnfdDefault.
+                //     ii. Set intlObj.[[MaximumFractionDigits]] to mxfdDefault.
+                Some(Extrema {
+                    minimum: min_float_digits_default,
+                    maximum: max_float_digits_default,
+                })
+            }
+        } else {
+            None
+        };
+
+        let (rounding_type, rounding_priority) = match (significant_digits, fraction_digits) {
+            // 26. If needSd is false and needFd is false, then
+            //     a. Set intlObj.[[MinimumFractionDigits]] to 0.
+            //     b. Set intlObj.[[MaximumFractionDigits]] to 0.
+            //     c. Set intlObj.[[MinimumSignificantDigits]] to 1.
+            //     d. Set intlObj.[[MaximumSignificantDigits]] to 2.
+            //     e. Set intlObj.[[RoundingType]] to morePrecision.
+            //     f. Set intlObj.[[ComputedRoundingPriority]] to "morePrecision".
+            (None, None) => (
+                RoundingType::MorePrecision {
+                    significant_digits: Extrema {
+                        minimum: 1,
+                        maximum: 2,
+                    },
+                    fraction_digits: Extrema {
+                        minimum: 0,
+                        maximum: 0,
+                    },
+                },
+                RoundingPriority::MorePrecision,
+            ),
+            // 27. Else if roundingPriority is "auto", then
+            //     a. If needSd is true, then
+            //         i. Set intlObj.[[RoundingType]] to significantDigits.
+            //     b. Else,
+            //         i. Set intlObj.[[RoundingType]] to fractionDigits.
+            //     c. Set intlObj.[[ComputedRoundingPriority]] to "auto".
+            (Some(digits), None) => (
+                RoundingType::SignificantDigits(digits),
+                RoundingPriority::Auto,
+            ),
+            (None, Some(digits)) => (RoundingType::FractionDigits(digits), RoundingPriority::Auto),
+            // 28. Else,
+            //     a. If roundingPriority is "morePrecision", then
+            //         i. Set intlObj.[[RoundingType]] to morePrecision.
+            //         ii. Set intlObj.[[ComputedRoundingPriority]] to "morePrecision".
+            //     b. Else,
+            //         i. Set intlObj.[[RoundingType]] to lessPrecision.
+            //         ii. Set intlObj.[[ComputedRoundingPriority]] to "lessPrecision".
+            (Some(significant_digits), Some(fraction_digits)) => {
+                if rounding_priority == RoundingPriority::LessPrecision {
+                    (
+                        RoundingType::LessPrecision {
+                            significant_digits,
+                            fraction_digits,
+                        },
+                        RoundingPriority::LessPrecision,
+                    )
+                } else {
+                    (
+                        RoundingType::MorePrecision {
+                            significant_digits,
+                            fraction_digits,
+                        },
+                        RoundingPriority::MorePrecision,
+                    )
+                }
+            }
+        };
+
+        // 29. If roundingIncrement is not 1, then
+        if rounding_increment != 1 {
+            // a. If intlObj.[[RoundingType]] is not fractionDigits, throw a TypeError exception.
+            let RoundingType::FractionDigits(digits) = rounding_type else {
+                return Err(JsNativeError::typ()
+                    .with_message("`roundingIncrement` can only be used with fraction digits")
+                    .into());
+            };
+
+            // b. If intlObj.[[MaximumFractionDigits]] is not equal to intlObj.[[MinimumFractionDigits]], throw a RangeError exception.
+            if digits.minimum != digits.maximum {
+                return Err(JsNativeError::range()
+                    .with_message(
+                        "`roundingIncrement` requires equal minimum and maximum fraction digits",
+                    )
+                    .into());
+            }
+        }
+
+        Ok(Self {
+            minimum_integer_digits,
+            rounding_increment,
+            rounding_mode,
+            trailing_zero_display,
+            rounding_type,
+            rounding_priority,
+        })
+    }
+
+    /// Abstract operation [`FormatNumericToString ( intlObject, x )`][spec].
+    ///
+    /// Rounds and pads `number` in place, according to these options. The sign of `number` is
+    /// preserved even if it is rounded to zero, which represents the negative zero of the spec.
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-formatnumberstring
+    pub(crate) fn format_fixed_decimal(&self, number: &mut FixedDecimal) {
+        // 1. If x is negative-zero, then
+        //     a. Let isNegative be true.
+        //     b. Set x to 0.
+        // 2. Else,
+        //     a. Assert: x is a mathematical value.
+        //     b. If x < 0, let isNegative be true; else let isNegative be false.
+        //     c. If isNegative is true, then
+        //         i. Set x to -x.
+        let sign = number.sign();
+
+        // 3. Let unsignedRoundingMode be GetUnsignedRoundingMode(intlObject.[[RoundingMode]], isNegative).
+        // 4. If intlObject.[[RoundingType]] is significantDigits, then
+        //     a. Let result be ToRawPrecision(x, intlObject.[[MinimumSignificantDigits]], intlObject.[[MaximumSignificantDigits]], unsignedRoundingMode).
+        // 5. Else if intlObject.[[RoundingType]] is fractionDigits, then
+        //     a. Let result be ToRawFixed(x, intlObject.[[MinimumFractionDigits]], intlObject.[[MaximumFractionDigits]], intlObject.[[RoundingIncrement]], unsignedRoundingMode).
+        // 6. Else,
+        //     a. Let sResult be ToRawPrecision(x, intlObject.[[MinimumSignificantDigits]], intlObject.[[MaximumSignificantDigits]], unsignedRoundingMode).
+        //     b. Let fResult be ToRawFixed(x, intlObject.[[MinimumFractionDigits]], intlObject.[[MaximumFractionDigits]], intlObject.[[RoundingIncrement]], unsignedRoundingMode).
+        //     c. If intlObj.[[RoundingType]] is morePrecision, then
+        //         i. If sResult.[[RoundingMagnitude]] ≤ fResult.[[RoundingMagnitude]], then
+        //             1. Let fixedIsMorePrecise be false.
+        //         ii. Else,
+        //             1. Let fixedIsMorePrecise be true.
+        //     d. Else,
+        //         i. Assert: intlObj.[[RoundingType]] is lessPrecision.
+        //         ii. If sResult.[[RoundingMagnitude]] ≤ fResult.[[RoundingMagnitude]], then
+        //             1. Let fixedIsMorePrecise be true.
+        //         iii. Else,
+        //             1. Let fixedIsMorePrecise be false.
+        //     e. If fixedIsMorePrecise is true, then
+        //         i. Let result be fResult.
+        //     f. Else,
+        //         i. Let result be sResult.
+        match self.rounding_type {
+            RoundingType::SignificantDigits(digits) => {
+                self.round_to_precision(number, digits);
+            }
+            RoundingType::FractionDigits(digits) => {
+                self.round_to_fixed(number, digits);
+            }
+            RoundingType::MorePrecision {
+                significant_digits,
+                fraction_digits,
+            }
+            | RoundingType::LessPrecision {
+                significant_digits,
+                fraction_digits,
+            } => {
+                let mut fixed = number.clone();
+                let significant_magnitude = self.round_to_precision(number, significant_digits);
+                let fixed_magnitude = self.round_to_fixed(&mut fixed, fraction_digits);
+                let fixed_is_more_precise =
+                    if matches!(self.rounding_type, RoundingType::MorePrecision { .. }) {
+                        significant_magnitude > fixed_magnitude
+                    } else {
+                        significant_magnitude <= fixed_magnitude
+                    };
+                if fixed_is_more_precise {
+                    *number = fixed;
+                }
+            }
+        }
+
+        // 7. Set x to result.[[RoundedNumber]].
+        // 8. Let string be result.[[FormattedString]].
+        // 9. If intlObject.[[TrailingZeroDisplay]] is "stripIfInteger" and x modulo 1 = 0, then
+        //     a. Let i be StringIndexOf(string, ".", 0).
+        //     b. If i ≠ -1, set string to the substring of string from 0 to i.
+        if self.trailing_zero_display == TrailingZeroDisplay::StripIfInteger
+            && number.nonzero_magnitude_end() >= 0
+        {
+            number.trim_end();
+        }
+
+        // 10. Let int be result.[[IntegerDigitsCount]].
+        // 11. Let minInteger be intlObject.[[MinimumIntegerDigits]].
+        // 12. If int < minInteger, then
+        //     a. Let forwardZeros be the String consisting of minInteger - int occurrences of the code unit 0x0030 (DIGIT ZERO).
+        //     b. Set string to the string-concatenation of forwardZeros and string.
+        number.pad_start(i16::from(self.minimum_integer_digits));
+
+        // 13. If isNegative is true, then
+        //     a. If x is 0, set x to negative-zero. Otherwise, set x to -x.
+        // 14. Return the Record { [[RoundedNumber]]: x, [[FormattedString]]: string }.
+        number.set_sign(sign);
+    }
+
+    /// Abstract operation [`ToRawPrecision ( x, minPrecision, maxPrecision, unsignedRoundingMode )`][spec].
+    ///
+    /// Returns the rounding magnitude of the result.
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-torawprecision
+    fn round_to_precision(&self, number: &mut FixedDecimal, digits: Extrema) -> i16 {
+        let minimum = i16::from(digits.minimum);
+        let maximum = i16::from(digits.maximum);
+
+        // 1. Let p be maxPrecision.
+        // 2. If x = 0, then
+        //     a. Let m be the String consisting of p occurrences of the code unit 0x0030 (DIGIT ZERO).
+        //     b. Let e be 0.
+        //     c. Let xFinal be 0.
+        // 3. Else,
+        //     a. Let n1 and e1 be each an integer and r1 a mathematical value, with r1 = ToRawPrecisionFn(n1, e1, p), such that r1 ≤ x and r1 is maximized.
+        //     b. Let n2 and e2 be each an integer and r2 a mathematical value, with r2 = ToRawPrecisionFn(n2, e2, p), such that r2 ≥ x and r2 is minimized.
+        //     c. Let r be ApplyUnsignedRoundingMode(x, r1, r2, unsignedRoundingMode).
+        //     d. If r is r1, then
+        //         i. Let n be n1.
+        //         ii. Let e be e1.
+        //         iii. Let xFinal be r1.
+        //     e. Else,
+        //         i. Let n be n2.
+        //         ii. Let e be e2.
+        //         iii. Let xFinal be r2.
+        //     f. Let m be the String consisting of the digits of the decimal representation of n (in order, with no leading zeroes).
+        let magnitude = number.nonzero_magnitude_start();
+        self.rounding_mode.round(number, magnitude - maximum + 1);
+
+        // The rounding could have carried a digit over to a new magnitude (e.g. 9.99 to 10.0).
+        let magnitude = number.nonzero_magnitude_start();
+
+        // 4. If e ≥ (p – 1), then
+        //     a. Set m to the string-concatenation of m and e - p + 1 occurrences of the code unit 0x0030 (DIGIT ZERO).
+        //     b. Let int be e + 1.
+        // 5. Else if e ≥ 0, then
+        //     a. Set m to the string-concatenation of the first e + 1 code units of m, the code unit 0x002E (FULL STOP), and the remaining p - (e + 1) code units of m.
+        //     b. Let int be e + 1.
+        // 6. Else,
+        //     a. Assert: e < 0.
+        //     b. Set m to the string-concatenation of "0.", -(e + 1) occurrences of the code unit 0x0030 (DIGIT ZERO), and m.
+        //     c. Let int be 1.
+        // 7. If m contains the code unit 0x002E (FULL STOP) and maxPrecision > minPrecision, then
+        //     a. Let cut be maxPrecision - minPrecision.
+        //     b. Repeat, while cut > 0 and the last code unit of m is 0x0030 (DIGIT ZERO),
+        //         i. Remove the last code unit from m.
+        //         ii. Decrease cut by 1.
+        //     c. If the last code unit of m is 0x002E (FULL STOP), then
+        //         i. Remove the last code unit from m.
+        number.trim_end();
+        number.pad_end(magnitude - minimum + 1);
+
+        // 8. Return the Record { [[FormattedString]]: m, [[RoundedNumber]]: xFinal, [[IntegerDigitsCount]]: int, [[RoundingMagnitude]]: e–p+1 }.
+        magnitude - maximum + 1
+    }
+
+    /// Abstract operation [`ToRawFixed ( x, minFraction, maxFraction, roundingIncrement, unsignedRoundingMode )`][spec].
+    ///
+    /// Returns the rounding magnitude of the result.
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-torawfixed
+    fn round_to_fixed(&self, number: &mut FixedDecimal, digits: Extrema) -> i16 {
+        // 1. Let f be maxFraction.
+        // 2. Let n1 be an integer and r1 a mathematical value, with r1 = ToRawFixedFn(n1, f), such that n1 modulo roundingIncrement = 0, r1 ≤ x, and r1 is maximized.
+        // 3. Let n2 be an integer and r2 a mathematical value, with r2 = ToRawFixedFn(n2, f), such that n2 modulo roundingIncrement = 0, r2 ≥ x, and r2 is minimized.
+        // 4. Let r be ApplyUnsignedRoundingMode(x, r1, r2, unsignedRoundingMode).
+        let position = -i16::from(digits.maximum);
+        round_to_increment(
+            number,
+            position,
+            self.rounding_increment,
+            self.rounding_mode,
+        );
+
+        // 5. If r is r1, then
+        //     a. Let n be n1.
+        //     b. Let xFinal be r1.
+        // 6. Else,
+        //     a. Let n be n2.
+        //     b. Let xFinal be r2.
+        // 7. If n = 0, let m be "0". Otherwise, let m be the String consisting of the digits of the decimal representation of n (in order, with no leading zeroes).
+        // 8. If f ≠ 0, then
+        //     a. Let k be the length of m.
+        //     b. If k ≤ f, then
+        //         i. Let z be the String value consisting of f + 1 - k occurrences of the code unit 0x0030 (DIGIT ZERO).
+        //         ii. Let m be the string-concatenation of z and m.
+        //         iii. Let k be f + 1.
+        //     c. Let a be the first k - f code units of m, and let b be the remaining f code units of m.
+        //     d. Let m be the string-concatenation of a, ".", and b.
+        //     e. Let int be the length of a.
+        // 9. Else, let int be the length of m.
+        // 10. Let cut be maxFraction - minFraction.
+        // 11. Repeat, while cut > 0 and the last code unit of m is 0x0030 (DIGIT ZERO),
+        //     a. Remove the last code unit from m.
+        //     b. Decrease cut by 1.
+        // 12. If the last code unit of m is 0x002E (FULL STOP), then
+        //     a. Remove the last code unit from m.
+        number.trim_end();
+        number.pad_end(-i16::from(digits.minimum));
+
+        // 13. Return the Record { [[FormattedString]]: m, [[RoundedNumber]]: xFinal, [[IntegerDigitsCount]]: int, [[RoundingMagnitude]]: –f }.
+        position
+    }
+}
+
+/// Rounds `number` to a multiple of `increment` units of the digit at `position`.
+///
+/// Every valid rounding increment is either 1, 2, 5 or 25 times a power of ten. Rounding to
+/// multiples of 2, 5 or 25 is done by dividing the number by that factor, rounding the quotient
+/// to the digit at `position` and multiplying it back.
+fn round_to_increment(
+    number: &mut FixedDecimal,
+    mut position: i16,
+    mut increment: u16,
+    mode: RoundingMode,
+) {
+    while increment % 10 == 0 {
+        increment /= 10;
+        position += 1;
+    }
+
+    // Dividing by the increment is equivalent to multiplying by its inverse, which is an exact
+    // decimal number.
+    let (inverse, shift) = match increment {
+        1 => {
+            mode.round(number, position);
+            return;
+        }
+        2 => (5, 1),
+        5 => (2, 1),
+        25 => (4, 2),
+        _ => unreachable!("the rounding increment was validated on construction"),
+    };
+
+    let sign = number.sign();
+    let mut quotient = multiply(number, inverse);
+    quotient.multiply_pow10(-shift);
+    mode.round(&mut quotient, position);
+    *number = multiply(&quotient, u32::from(increment));
+    number.set_sign(sign);
+}
+
+/// Multiplies a decimal number by a small integer.
+fn multiply(number: &FixedDecimal, factor: u32) -> FixedDecimal {
+    let (digits, lower_magnitude) = to_digits(number);
+    let mut result = from_digits(&(digits * factor), lower_magnitude);
+    result.set_sign(if number.sign() == Sign::Negative {
+        Sign::Negative
+    } else {
+        Sign::None
+    });
+    result
+}
+
+/// Returns the digits of the absolute value of a decimal number as an integer, along with the
+/// magnitude of its last digit.
+fn to_digits(number: &FixedDecimal) -> (BigUint, i16) {
+    let lower_magnitude = *number.magnitude_range().start();
+    let digits = number
+        .magnitude_range()
+        .rev()
+        .map(|m| char::from(b'0' + number.digit_at(m)))
+        .collect::<String>()
+        .parse::<BigUint>()
+        .expect("must be a valid sequence of digits");
+    (digits, lower_magnitude)
+}
+
+/// Creates an unsigned decimal number from its digits and the magnitude of its last digit.
+fn from_digits(digits: &BigUint, lower_magnitude: i16) -> FixedDecimal {
+    let mut result = FixedDecimal::from_str(&digits.to_string())
+        .expect("the digits of an integer must be a valid decimal");
+    result.multiply_pow10(lower_magnitude);
+    result
+}
@@ -0,0 +1,421 @@
+use std::{borrow::Cow, str::FromStr};
+
+use fixed_decimal::FixedDecimal;
+use icu_decimal::provider::{AffixesV1, DecimalSymbolsV1, GroupingSizesV1};
+use icu_locid::{locale, Locale};
+use icu_plurals::{provider::CardinalV1Marker, provider::PluralRulesV1, PluralRules};
+use icu_provider::{
+    DataError, DataLocale, DataPayload, DataProvider, DataRequest, DataResponse,
+    DataResponseMetadata,
+};
+
+use crate::builtins::intl::{
+    data::NUMBER_FORMAT,
+    number_format::{
+        parse_decimal, CompactDisplay, Currency, CurrencyDisplay, CurrencySign, DigitFormatOptions,
+        Extrema, IntlMathematicalValue, Notation, NumberFormat, RoundingMode, RoundingPriority,
+        RoundingType, SignDisplay, TrailingZeroDisplay, Unit, UnitDisplay, UnitFormatOptions,
+        UseGrouping,
+    },
+};
+
+fn format(options: &DigitFormatOptions, number: &str) -> String {
+    let mut number = FixedDecimal::from_str(number).unwrap();
+    options.format_fixed_decimal(&mut number);
+    number.to_string()
+}
+
+fn fraction_digits(minimum: u8, maximum: u8) -> DigitFormatOptions {
+    DigitFormatOptions {
+        minimum_integer_digits: 1,
+        rounding_increment: 1,
+        rounding_mode: RoundingMode::HalfExpand,
+        trailing_zero_display: TrailingZeroDisplay::Auto,
+        rounding_type: RoundingType::FractionDigits(Extrema { minimum, maximum }),
+        rounding_priority: RoundingPriority::Auto,
+    }
+}
+
+#[test]
+fn round_fraction_digits() {
+    let options = fraction_digits(0, 3);
+    assert_eq!(format(&options, "1.23456"), "1.235");
+    assert_eq!(format(&options, "-1.0005"), "-1.001");
+    assert_eq!(format(&options, "0.9995"), "1");
+    assert_eq!(format(&options, "12"), "12");
+    assert_eq!(format(&options, "-0.0001"), "-0");
+
+    let options = fraction_digits(2, 2);
+    assert_eq!(format(&options, "1"), "1.00");
+    assert_eq!(format(&options, "0.995"), "1.00");
+}
+
+#[test]
+fn round_significant_digits() {
+    let options = DigitFormatOptions {
+        rounding_type: RoundingType::SignificantDigits(Extrema {
+            minimum: 1,
+            maximum: 3,
+        }),
+        ..fraction_digits(0, 3)
+    };
+    assert_eq!(format(&options, "123456"), "123000");
+    assert_eq!(format(&options, "0.0012345"), "0.00123");
+    assert_eq!(format(&options, "9.999"), "10");
+
+    let options = DigitFormatOptions {
+        rounding_type: RoundingType::SignificantDigits(Extrema {
+            minimum: 4,
+            maximum: 4,
+        }),
+        ..options
+    };
+    assert_eq!(format(&options, "1.5"), "1.500");
+}
+
+#[test]
+fn round_increment_and_modes() {
+    let options = DigitFormatOptions {
+        rounding_increment: 5,
+        ..fraction_digits(2, 2)
+    };
+    assert_eq!(format(&options, "1.23"), "1.25");
+    assert_eq!(format(&options, "1.22"), "1.20");
+
+    let options = DigitFormatOptions {
+        rounding_mode: RoundingMode::Floor,
+        ..fraction_digits(0, 0)
+    };
+    assert_eq!(format(&options, "1.9"), "1");
+    assert_eq!(format(&options, "-1.1"), "-2");
+
+    let options = DigitFormatOptions {
+        rounding_mode: RoundingMode::HalfEven,
+        ..fraction_digits(0, 0)
+    };
+    assert_eq!(format(&options, "2.5"), "2");
+    assert_eq!(format(&options, "3.5"), "4");
+}
+
+#[test]
+fn pad_and_strip() {
+    let options = DigitFormatOptions {
+        minimum_integer_digits: 3,
+        ..fraction_digits(0, 3)
+    };
+    assert_eq!(format(&options, "7.5"), "007.5");
+
+    let options = DigitFormatOptions {
+        trailing_zero_display: TrailingZeroDisplay::StripIfInteger,
+        ..fraction_digits(2, 2)
+    };
+    assert_eq!(format(&options, "5"), "5");
+    assert_eq!(format(&options, "5.1"), "5.10");
+}
+
+#[test]
+fn parse_decimal_strings() {
+    let parse = |s| parse_decimal(s).map(|d| d.to_string());
+    assert_eq!(parse("123.4500").as_deref(), Some("123.4500"));
+    assert_eq!(parse("-.5").as_deref(), Some("-0.5"));
+    assert_eq!(parse("+1e3").as_deref(), Some("1000"));
+    assert_eq!(
+        parse("12345678901234567890.5").as_deref(),
+        Some("12345678901234567890.5")
+    );
+    assert_eq!(parse("0x10"), None);
+    assert_eq!(parse("."), None);
+    assert_eq!(parse("Infinity"), None);
+}
+
+#[test]
+fn currency_and_unit_identifiers() {
+    let currency = Currency::from_str("eur").unwrap();
+    assert_eq!(currency.as_str(), "EUR");
+    assert_eq!(currency.digits(), 2);
+    assert_eq!(Currency::from_str("JPY").unwrap().digits(), 0);
+    assert!(Currency::from_str("EU").is_err());
+    assert!(Currency::from_str("E1R").is_err());
+
+    assert_eq!(
+        Unit::from_str("kilometer-per-hour").unwrap().to_string(),
+        "kilometer-per-hour"
+    );
+    assert!(Unit::from_str("meter").is_ok());
+    assert!(Unit::from_str("furlong").is_err());
+    assert!(Unit::from_str("meter-per-furlong").is_err());
+}
+
+/// Provides the cardinal plural rules shared by English and German.
+struct PluralRulesProvider;
+
+impl DataProvider<CardinalV1Marker> for PluralRulesProvider {
+    fn load(&self, _: DataRequest<'_>) -> Result<DataResponse<CardinalV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: DataResponseMetadata::default(),
+            payload: Some(DataPayload::from_owned(PluralRulesV1 {
+                zero: None,
+                one: Some("i = 1 and v = 0".parse().unwrap()),
+                two: None,
+                few: None,
+                many: None,
+            })),
+        })
+    }
+}
+
+/// Creates a number format for `locale` with the provided options and separators, without
+/// loading any data from the data provider.
+fn localized_number_format(
+    locale: Locale,
+    [decimal_separator, grouping_separator]: [&'static str; 2],
+    unit_options: UnitFormatOptions,
+    digit_options: DigitFormatOptions,
+    notation: Notation,
+    sign_display: SignDisplay,
+) -> NumberFormat {
+    let symbols = DecimalSymbolsV1 {
+        minus_sign_affixes: AffixesV1 {
+            prefix: Cow::Borrowed("-"),
+            suffix: Cow::Borrowed(""),
+        },
+        plus_sign_affixes: AffixesV1 {
+            prefix: Cow::Borrowed("+"),
+            suffix: Cow::Borrowed(""),
+        },
+        decimal_separator: Cow::Borrowed(decimal_separator),
+        grouping_separator: Cow::Borrowed(grouping_separator),
+        grouping_sizes: GroupingSizesV1 {
+            primary: 3,
+            secondary: 3,
+            min_grouping: 1,
+        },
+        digits: ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'],
+    };
+    let plural_rules =
+        PluralRules::try_new_cardinal_unstable(&PluralRulesProvider, &DataLocale::from(&locale))
+            .unwrap();
+    let data = NUMBER_FORMAT.chain(&locale.to_string()).unwrap();
+
+    NumberFormat {
+        locale,
+        numbering_system: None,
+        unit_options,
+        digit_options,
+        notation,
+        compact_display: (notation == Notation::Compact).then_some(CompactDisplay::Short),
+        use_grouping: if notation == Notation::Compact {
+            UseGrouping::Min2
+        } else {
+            UseGrouping::Auto
+        },
+        sign_display,
+        symbols: DataPayload::from_owned(symbols),
+        plural_rules,
+        data,
+        bound_format: None,
+    }
+}
+
+/// Creates an `en` number format with the provided options.
+fn number_format(
+    unit_options: UnitFormatOptions,
+    digit_options: DigitFormatOptions,
+    notation: Notation,
+    sign_display: SignDisplay,
+) -> NumberFormat {
+    localized_number_format(
+        locale!("en"),
+        [".", ","],
+        unit_options,
+        digit_options,
+        notation,
+        sign_display,
+    )
+}
+
+fn format_number(nf: &NumberFormat, number: &str) -> String {
+    let x = match number {
+        "NaN" => IntlMathematicalValue::NaN,
+        "Infinity" => IntlMathematicalValue::Infinity { negative: false },
+        "-Infinity" => IntlMathematicalValue::Infinity { negative: true },
+        number => IntlMathematicalValue::Decimal(FixedDecimal::from_str(number).unwrap()),
+    };
+    nf.format_value(&x).to_std_string_escaped()
+}
+
+#[test]
+fn format_decimal() {
+    let nf = number_format(
+        UnitFormatOptions::Decimal,
+        fraction_digits(0, 3),
+        Notation::Standard,
+        SignDisplay::Auto,
+    );
+    assert_eq!(format_number(&nf, "1234567.891"), "1,234,567.891");
+    assert_eq!(format_number(&nf, "-1234.5"), "-1,234.5");
+    assert_eq!(format_number(&nf, "-0"), "-0");
+    assert_eq!(format_number(&nf, "NaN"), "NaN");
+    assert_eq!(format_number(&nf, "-Infinity"), "-∞");
+
+    let nf = number_format(
+        UnitFormatOptions::Decimal,
+        fraction_digits(0, 3),
+        Notation::Standard,
+        SignDisplay::ExceptZero,
+    );
+    assert_eq!(format_number(&nf, "5"), "+5");
+    assert_eq!(format_number(&nf, "0"), "0");
+    assert_eq!(format_number(&nf, "-0.0001"), "0");
+}
+
+#[test]
+fn format_styles() {
+    let nf = number_format(
+        UnitFormatOptions::Percent,
+        fraction_digits(0, 0),
+        Notation::Standard,
+        SignDisplay::Auto,
+    );
+    assert_eq!(format_number(&nf, "0.256"), "26%");
+
+    let nf = number_format(
+        UnitFormatOptions::Currency {
+            currency: Currency::from_str("USD").unwrap(),
+            display: CurrencyDisplay::Symbol,
+            sign: CurrencySign::Accounting,
+        },
+        fraction_digits(2, 2),
+        Notation::Standard,
+        SignDisplay::Auto,
+    );
+    assert_eq!(format_number(&nf, "1234.5"), "$1,234.50");
+    assert_eq!(format_number(&nf, "-3"), "($3.00)");
+
+    let nf = number_format(
+        UnitFormatOptions::Unit {
+            unit: Unit::from_str("liter-per-kilometer").unwrap(),
+            display: UnitDisplay::Short,
+        },
+        fraction_digits(0, 3),
+        Notation::Standard,
+        SignDisplay::Auto,
+    );
+    assert_eq!(format_number(&nf, "50"), "50 L/km");
+}
+
+#[test]
+fn format_notations() {
+    let nf = number_format(
+        UnitFormatOptions::Decimal,
+        fraction_digits(0, 3),
+        Notation::Scientific,
+        SignDisplay::Auto,
+    );
+    assert_eq!(format_number(&nf, "123456"), "1.235E5");
+    assert_eq!(format_number(&nf, "0.00012"), "1.2E-4");
+
+    let nf = number_format(
+        UnitFormatOptions::Decimal,
+        fraction_digits(0, 3),
+        Notation::Engineering,
+        SignDisplay::Auto,
+    );
+    assert_eq!(format_number(&nf, "123456"), "123.456E3");
+
+    let nf = number_format(
+        UnitFormatOptions::Decimal,
+        DigitFormatOptions {
+            rounding_type: RoundingType::MorePrecision {
+                significant_digits: Extrema {
+                    minimum: 1,
+                    maximum: 2,
+                },
+                fraction_digits: Extrema {
+                    minimum: 0,
+                    maximum: 0,
+                },
+            },
+            rounding_priority: RoundingPriority::MorePrecision,
+            ..fraction_digits(0, 0)
+        },
+        Notation::Compact,
+        SignDisplay::Auto,
+    );
+    assert_eq!(format_number(&nf, "1234"), "1.2K");
+    assert_eq!(format_number(&nf, "987654321"), "988M");
+    assert_eq!(format_number(&nf, "12345"), "12K");
+}
+
+#[test]
+fn format_with_locale_data() {
+    let de = |unit_options, notation, sign_display| {
+        localized_number_format(
+            locale!("de"),
+            [",", "."],
+            unit_options,
+            fraction_digits(0, 2),
+            notation,
+            sign_display,
+        )
+    };
+    let currency = |code, display, sign| UnitFormatOptions::Currency {
+        currency: Currency::from_str(code).unwrap(),
+        display,
+        sign,
+    };
+    let unit = |id, display| UnitFormatOptions::Unit {
+        unit: Unit::from_str(id).unwrap(),
+        display,
+    };
+
+    let nf = de(
+        UnitFormatOptions::Percent,
+        Notation::Standard,
+        SignDisplay::Always,
+    );
+    assert_eq!(format_number(&nf, "0.256"), "+25,6\u{a0}%");
+
+    let nf = de(
+        currency("EUR", CurrencyDisplay::Symbol, CurrencySign::Accounting),
+        Notation::Standard,
+        SignDisplay::Auto,
+    );
+    assert_eq!(format_number(&nf, "1234.5"), "1.234,5\u{a0}€");
+    assert_eq!(format_number(&nf, "-3"), "-3\u{a0}€");
+
+    let nf = de(
+        currency("ARS", CurrencyDisplay::Name, CurrencySign::Standard),
+        Notation::Standard,
+        SignDisplay::Auto,
+    );
+    assert_eq!(format_number(&nf, "1"), "1 Argentinischer Peso");
+    assert_eq!(format_number(&nf, "1.5"), "1,5 Argentinische Pesos");
+
+    let nf = de(
+        unit("kilometer-per-hour", UnitDisplay::Long),
+        Notation::Standard,
+        SignDisplay::Auto,
+    );
+    assert_eq!(format_number(&nf, "50"), "50 Kilometer pro Stunde");
+    let nf = de(
+        unit("kilometer-per-day", UnitDisplay::Short),
+        Notation::Standard,
+        SignDisplay::Auto,
+    );
+    assert_eq!(format_number(&nf, "5"), "5 km/T");
+    let nf = de(
+        unit("byte-per-acre", UnitDisplay::Long),
+        Notation::Standard,
+        SignDisplay::Auto,
+    );
+    assert_eq!(format_number(&nf, "5"), "5 Byte pro Acre");
+
+    let nf = de(
+        UnitFormatOptions::Decimal,
+        Notation::Compact,
+        SignDisplay::Auto,
+    );
+    assert_eq!(format_number(&nf, "1234"), "1234");
+    assert_eq!(format_number(&nf, "1500000"), "1,5\u{a0}Mio.");
+}
@@ -0,0 +1,480 @@
+use boa_gc::{empty_trace, Finalize, Trace};
+use boa_profiler::Profiler;
+use fixed_decimal::{DoublePrecision, FixedDecimal};
+use icu_locid::Locale;
+use icu_plurals::{
+    provider::CardinalV1Marker, PluralCategory, PluralRuleType, PluralRules as NativePluralRules,
+};
+use icu_provider::DataLocale;
+
+use crate::{
+    builtins::{Array, BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject},
+    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    object::{internal_methods::get_prototype_from_constructor, JsObject, ObjectData},
+    property::Attribute,
+    realm::Realm,
+    string::utf16,
+    symbol::JsSymbol,
+    Context, JsArgs, JsNativeError, JsResult, JsValue,
+};
+
+use super::{
+    locale::{canonicalize_locale_list, resolve_locale, supported_locales},
+    number_format::{DigitFormatOptions, Extrema, Notation},
+    options::{coerce_options_to_object, get_option, IntlOptions, LocaleMatcher},
+    Service,
+};
+
+mod options;
+
+#[cfg(test)]
+mod tests;
+
+pub struct PluralRules {
+    locale: Locale,
+    native: NativePluralRules,
+    rule_type: PluralRuleType,
+    format_options: DigitFormatOptions,
+}
+
+impl Finalize for PluralRules {}
+
+// SAFETY: `PluralRules` doesn't contain any traceable data.
+unsafe impl Trace for PluralRules {
+    empty_trace!();
+}
+
+impl std::fmt::Debug for PluralRules {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PluralRules")
+            .field("locale", &self.locale)
+            .field("native", &self.native)
+            .field("rule_type", &self.rule_type)
+            .field("format_options", &self.format_options)
+            .finish()
+    }
+}
+
+impl Service for PluralRules {
+    type LangMarker = CardinalV1Marker;
+
+    type LocaleOptions = ();
+}
+
+impl IntrinsicObject for PluralRules {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .static_method(Self::supported_locales_of, "supportedLocalesOf", 1)
+            .property(
+                JsSymbol::to_string_tag(),
+                "Intl.PluralRules",
+                Attribute::CONFIGURABLE,
+            )
+            .method(Self::select, "select", 1)
+            .method(Self::select_range, "selectRange", 2)
+            .method(Self::resolved_options, "resolvedOptions", 0)
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for PluralRules {
+    const NAME: &'static str = "PluralRules";
+}
+
+impl BuiltInConstructor for PluralRules {
+    const LENGTH: usize = 0;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::plural_rules;
+
+    /// Constructor [`Intl.PluralRules ( [ locales [ , options ] ] )`][spec].
+    ///
+    /// Constructor for `PluralRules` objects.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.pluralrules
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/PluralRules/PluralRules
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("cannot call `Intl.PluralRules` constructor without `new`")
+                .into());
+        }
+
+        // 2. Let pluralRules be ? OrdinaryCreateFromConstructor(NewTarget, "%PluralRules.prototype%", « [[InitializedPluralRules]], [[Locale]], [[Type]], [[MinimumIntegerDigits]], [[MinimumFractionDigits]], [[MaximumFractionDigits]], [[MinimumSignificantDigits]], [[MaximumSignificantDigits]], [[RoundingType]], [[RoundingIncrement]], [[RoundingMode]], [[ComputedRoundingPriority]], [[TrailingZeroDisplay]] »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::plural_rules,
+            context,
+        )?;
+
+        // 3. Return ? InitializePluralRules(pluralRules, locales, options).
+        let plural_rules = Self::new(args.get_or_undefined(0), args.get_or_undefined(1), context)?;
+
+        Ok(JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            ObjectData::plural_rules(plural_rules),
+        )
+        .into())
+    }
+}
+
+impl PluralRules {
+    /// Abstract operation [`InitializePluralRules ( pluralRules, locales, options )`][spec].
+    ///
+    /// Creates a new `PluralRules` from the provided locales and options.
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-initializepluralrules
+    pub(crate) fn new(
+        locales: &JsValue,
+        options: &JsValue,
+        context: &mut Context<'_>,
+    ) -> JsResult<Self> {
+        // 1. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(locales, context)?;
+
+        // 2. Set options to ? CoerceOptionsToObject(options).
+        let options = coerce_options_to_object(options, context)?;
+
+        // 3. Let opt be a new Record.
+        // 4. Let matcher be ? GetOption(options, "localeMatcher", string, « "lookup", "best fit" », "best fit").
+        // 5. Set opt.[[localeMatcher]] to matcher.
+        let matcher =
+            get_option::<LocaleMatcher>(&options, utf16!("localeMatcher"), false, context)?
+                .unwrap_or_default();
+
+        // 6. Let t be ? GetOption(options, "type", string, « "cardinal", "ordinal" », "cardinal").
+        // 7. Set pluralRules.[[Type]] to t.
+        let rule_type = get_option::<PluralRuleType>(&options, utf16!("type"), false, context)?
+            .unwrap_or(PluralRuleType::Cardinal);
+
+        // 8. Perform ? SetNumberFormatDigitOptions(pluralRules, options, +0𝔽, 3𝔽, "standard").
+        let format_options =
+            DigitFormatOptions::from_options(&options, 0, 3, Notation::Standard, context)?;
+
+        // 9. Let localeData be %PluralRules%.[[LocaleData]].
+        // 10. Let r be ResolveLocale(%PluralRules%.[[AvailableLocales]], requestedLocales, opt, %PluralRules%.[[RelevantExtensionKeys]], localeData).
+        // 11. Set pluralRules.[[Locale]] to r.[[locale]].
+        let locale = resolve_locale::<Self>(
+            &requested_locales,
+            &mut IntlOptions {
+                matcher,
+                ..Default::default()
+            },
+            context.icu(),
+        );
+
+        let native = context
+            .icu()
+            .provider()
+            .try_new_plural_rules(&DataLocale::from(&locale), rule_type)
+            .map_err(|e| JsNativeError::typ().with_message(e.to_string()))?;
+
+        // 12. Return pluralRules.
+        Ok(Self {
+            locale,
+            native,
+            rule_type,
+            format_options,
+        })
+    }
+
+    /// Abstract operation [`ResolvePlural ( pluralRules, n )`][spec].
+    ///
+    /// Returns the plural category of `n`, along with the rounded decimal used to compute it.
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-resolveplural
+    pub(crate) fn resolve_plural(&self, n: f64) -> (PluralCategory, Option<FixedDecimal>) {
+        // 1. Assert: Type(pluralRules) is Object.
+        // 2. Assert: pluralRules has an [[InitializedPluralRules]] internal slot.
+        // 3. Assert: Type(n) is Number.
+        // 4. If n is not a finite Number, then
+        //     a. Return "other".
+        if !n.is_finite() {
+            return (PluralCategory::Other, None);
+        }
+
+        // 5. Let locale be pluralRules.[[Locale]].
+        // 6. Let type be pluralRules.[[Type]].
+        // 7. Let res be ! FormatNumericToString(pluralRules, ℝ(n)).
+        let mut n = FixedDecimal::try_from_f64(n, DoublePrecision::Floating)
+            .expect("finite numbers must be representable as a decimal");
+        self.format_options.format_fixed_decimal(&mut n);
+
+        // 8. Let s be res.[[FormattedString]].
+        // 9. Let operands be ! GetOperands(s).
+        // 10. Let p be ! PluralRuleSelect(locale, type, n, operands).
+        // 11. Return p.
+        (self.native.category_for(&n), Some(n))
+    }
+
+    /// [`Intl.PluralRules.supportedLocalesOf ( locales [ , options ] )`][spec].
+    ///
+    /// Returns an array containing those of the provided locales that are supported in plural
+    /// rules without having to fall back to the runtime's default locale.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.pluralrules.supportedlocalesof
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/PluralRules/supportedLocalesOf
+    fn supported_locales_of(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        let locales = args.get_or_undefined(0);
+        let options = args.get_or_undefined(1);
+
+        // 1. Let availableLocales be %PluralRules%.[[AvailableLocales]].
+        // 2. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(locales, context)?;
+
+        // 3. Return ? SupportedLocales(availableLocales, requestedLocales, options).
+        supported_locales::<<Self as Service>::LangMarker>(&requested_locales, options, context)
+            .map(JsValue::from)
+    }
+
+    /// [`Intl.PluralRules.prototype.select ( value )`][spec].
+    ///
+    /// Returns a string indicating which plural rule to use for locale-aware formatting of a
+    /// number.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.pluralrules.prototype.select
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/PluralRules/select
+    fn select(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Let pr be the this value.
+        // 2. Perform ? RequireInternalSlot(pr, [[InitializedPluralRules]]).
+        let pr = this.as_object().ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("`select` can only be called on a `PluralRules` object")
+        })?;
+
+        // 3. Let n be ? ToNumber(value).
+        let n = args.get_or_undefined(0).to_number(context)?;
+
+        let pr = pr.borrow();
+        let pr = pr.as_plural_rules().ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("`select` can only be called on a `PluralRules` object")
+        })?;
+
+        // 4. Return ! ResolvePlural(pr, n).[[PluralCategory]].
+        Ok(plural_category_to_str(pr.resolve_plural(n).0).into())
+    }
+
+    /// [`Intl.PluralRules.prototype.selectRange ( start, end )`][spec].
+    ///
+    /// Returns a string indicating which plural rule to use for locale-aware formatting of a
+    /// range of numbers.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.pluralrules.prototype.selectrange
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/PluralRules/selectRange
+    fn select_range(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. Let pr be the this value.
+        // 2. Perform ? RequireInternalSlot(pr, [[InitializedPluralRules]]).
+        let pr = this
+            .as_object()
+            .filter(|pr| pr.borrow().as_plural_rules().is_some())
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("`selectRange` can only be called on a `PluralRules` object")
+            })?;
+
+        // 3. If start is undefined or end is undefined, throw a TypeError exception.
+        let start = args.get_or_undefined(0);
+        let end = args.get_or_undefined(1);
+        if start.is_undefined() || end.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("`selectRange` requires a start and an end value")
+                .into());
+        }
+
+        // 4. Let x be ? ToNumber(start).
+        let x = start.to_number(context)?;
+
+        // 5. Let y be ? ToNumber(end).
+        let y = end.to_number(context)?;
+
+        // 6. Return ? ResolvePluralRange(pr, x, y).
+
+        // Abstract operation `ResolvePluralRange ( pluralRules, x, y )`
+        // https://tc39.es/ecma402/#sec-resolveplural
+
+        // 1. If x is NaN or y is NaN, throw a RangeError exception.
+        if x.is_nan() || y.is_nan() {
+            return Err(JsNativeError::range()
+                .with_message("cannot select the plural category of a range with a NaN bound")
+                .into());
+        }
+
+        let pr = pr.borrow();
+        let pr = pr
+            .as_plural_rules()
+            .expect("checked above that the object was a plural rules object");
+
+        // 2. Let xp be ! ResolvePlural(pluralRules, x).
+        let (xp, x) = pr.resolve_plural(x);
+
+        // 3. Let yp be ! ResolvePlural(pluralRules, y).
+        let (yp, y) = pr.resolve_plural(y);
+
+        // 4. If xp.[[FormattedString]] is yp.[[FormattedString]], then
+        //     a. Return xp.[[PluralCategory]].
+        if x.is_some() && x == y {
+            return Ok(plural_category_to_str(xp).into());
+        }
+
+        // 5. Let locale be pluralRules.[[Locale]].
+        // 6. Let type be pluralRules.[[Type]].
+        // 7. Return ! PluralRuleSelectRange(locale, type, xp.[[PluralCategory]], yp.[[PluralCategory]]).
+
+        // ICU4X doesn't provide the plural ranges data of CLDR yet, so this uses the category of the
+        // end of the range, which is the resolution of most ranges in most locales.
+        Ok(plural_category_to_str(yp).into())
+    }
+
+    /// [`Intl.PluralRules.prototype.resolvedOptions ( )`][spec].
+    ///
+    /// Returns a new object with properties reflecting the locale and options computed during the
+    /// construction of the current `Intl.PluralRules` object.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.pluralrules.prototype.resolvedoptions
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/PluralRules/resolvedOptions
+    fn resolved_options(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. Let pr be the this value.
+        // 2. Perform ? RequireInternalSlot(pr, [[InitializedPluralRules]]).
+        let pr = this.as_object().map(JsObject::borrow).ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("`resolvedOptions` can only be called on a `PluralRules` object")
+        })?;
+        let pr = pr.as_plural_rules().ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("`resolvedOptions` can only be called on a `PluralRules` object")
+        })?;
+
+        // 3. Let options be OrdinaryObjectCreate(%Object.prototype%).
+        let options = context
+            .intrinsics()
+            .templates()
+            .ordinary_object()
+            .create(ObjectData::ordinary(), vec![]);
+
+        // 4. For each row of Table 16, except the header row, in table order, do
+        //     a. Let p be the Property value of the current row.
+        //     b. Let v be the value of pr's internal slot whose name is the Internal Slot value of the current row.
+        //     c. If v is not undefined, then
+        //         i. Perform ! CreateDataPropertyOrThrow(options, p, v).
+        let digits = pr.format_options;
+        let mut properties: Vec<(&[u16], JsValue)> = vec![
+            (utf16!("locale"), pr.locale.to_string().into()),
+            (
+                utf16!("type"),
+                match pr.rule_type {
+                    PluralRuleType::Ordinal => "ordinal",
+                    _ => "cardinal",
+                }
+                .into(),
+            ),
+            (
+                utf16!("minimumIntegerDigits"),
+                digits.minimum_integer_digits.into(),
+            ),
+        ];
+        if let Some(Extrema { minimum, maximum }) = digits.rounding_type.fraction_digits() {
+            properties.push((utf16!("minimumFractionDigits"), minimum.into()));
+            properties.push((utf16!("maximumFractionDigits"), maximum.into()));
+        }
+        if let Some(Extrema { minimum, maximum }) = digits.rounding_type.significant_digits() {
+            properties.push((utf16!("minimumSignificantDigits"), minimum.into()));
+            properties.push((utf16!("maximumSignificantDigits"), maximum.into()));
+        }
+        for (property, value) in properties {
+            options
+                .create_data_property_or_throw(property, value, context)
+                .expect("operation must not fail per the spec");
+        }
+
+        // 5. Let pluralCategories be a List of Strings containing all possible results of PluralRuleSelect for the selected locale pr.[[Locale]].
+        let plural_categories = Array::create_array_from_list(
+            pr.native
+                .categories()
+                .map(|category| plural_category_to_str(category).into()),
+            context,
+        );
+
+        // 6. Perform ! CreateDataProperty(options, "pluralCategories", CreateArrayFromList(pluralCategories)).
+        options
+            .create_data_property_or_throw(utf16!("pluralCategories"), plural_categories, context)
+            .expect("operation must not fail per the spec");
+
+        for (property, value) in [
+            (
+                utf16!("roundingIncrement"),
+                JsValue::from(digits.rounding_increment),
+            ),
+            (
+                utf16!("roundingMode"),
+                digits.rounding_mode.to_string().into(),
+            ),
+            (
+                utf16!("roundingPriority"),
+                digits.rounding_priority.to_string().into(),
+            ),
+            (
+                utf16!("trailingZeroDisplay"),
+                digits.trailing_zero_display.to_string().into(),
+            ),
+        ] {
+            options
+                .create_data_property_or_throw(property, value, context)
+                .expect("operation must not fail per the spec");
+        }
+
+        // 7. Return options.
+        Ok(options.into())
+    }
+}
+
+/// Returns the string representation of a plural category.
+pub(crate) const fn plural_category_to_str(category: PluralCategory) -> &'static str {
+    match category {
+        PluralCategory::Zero => "zero",
+        PluralCategory::One => "one",
+        PluralCategory::Two => "two",
+        PluralCategory::Few => "few",
+        PluralCategory::Many => "many",
+        PluralCategory::Other => "other",
+    }
+}
@@ -0,0 +1,25 @@
+use crate::{run_test_actions, TestAction};
+
+#[test]
+fn select_across_locales() {
+    run_test_actions([
+        TestAction::assert_eq("new Intl.PluralRules('en').select(1)", "one"),
+        TestAction::assert_eq("new Intl.PluralRules('en').select(1.5)", "other"),
+        TestAction::assert_eq("new Intl.PluralRules('fr').select(1.5)", "one"),
+        TestAction::assert_eq("new Intl.PluralRules('fr').select(0)", "one"),
+        TestAction::assert_eq("new Intl.PluralRules('ru').select(3)", "few"),
+        TestAction::assert_eq("new Intl.PluralRules('ru').select(5)", "many"),
+        TestAction::assert_eq("new Intl.PluralRules('ar').select(0)", "zero"),
+        TestAction::assert_eq("new Intl.PluralRules('ar').select(2)", "two"),
+        TestAction::assert_eq("new Intl.PluralRules('ja').select(1)", "other"),
+        TestAction::assert_eq(
+            "new Intl.PluralRules('en', { type: 'ordinal' }).select(22)",
+            "two",
+        ),
+        TestAction::assert_eq(
+            "new Intl.PluralRules('en', { minimumFractionDigits: 1 }).select(1)",
+            "other",
+        ),
+        TestAction::assert_eq("new Intl.PluralRules('ru').resolvedOptions().locale", "ru"),
+    ]);
+}
@@ -0,0 +1,47 @@
+//! Patterns used to format relative times.
+//!
+//! The patterns and the names of relative times come from the
+//! [CLDR locale data](crate::builtins::intl::data) of the resolved locale, while the number of a
+//! relative time is formatted with the digits, separators and plural rules of the locale.
+
+use crate::builtins::intl::data::LocaleChain;
+
+use super::options::{Style, Unit};
+
+/// Returns the key of the locale data of `unit` in the provided style.
+fn entry(unit: Unit, style: Style) -> String {
+    match style {
+        Style::Long => unit.to_string(),
+        Style::Short => format!("{unit}-short"),
+        Style::Narrow => format!("{unit}-narrow"),
+    }
+}
+
+/// Returns the future and past plural patterns of `unit` in the provided style.
+pub(super) fn patterns(data: &LocaleChain, unit: Unit, style: Style) -> [&'static str; 2] {
+    data.get(&entry(unit, style))
+        .or_else(|| data.get(&unit.to_string()))
+        .and_then(|patterns| patterns.split_once('|'))
+        .map(|(future, past)| [future, past])
+        .expect("the locale data of every language must have all the units")
+}
+
+/// Returns the string used for `value` units of relative time if the `numeric` option is
+/// `"auto"` and the locale has a special string for that value (e.g. "yesterday").
+pub(super) fn relative_name(
+    data: &LocaleChain,
+    unit: Unit,
+    style: Style,
+    value: f64,
+) -> Option<&'static str> {
+    if value.fract() != 0.0 || value.abs() > 2.0 {
+        return None;
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    let value = (value as i8).to_string();
+    data.get(&format!("{}/relative", entry(unit, style)))?
+        .split(';')
+        .filter_map(|name| name.split_once('='))
+        .find_map(|(offset, name)| (offset == value).then_some(name))
+}
@@ -0,0 +1,534 @@
+use boa_gc::{custom_trace, Finalize, Trace};
+use boa_profiler::Profiler;
+use icu_decimal::provider::DecimalSymbolsV1Marker;
+use icu_locid::{extensions::unicode::Value, langid, Locale};
+
+use crate::{
+    builtins::{Array, BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject},
+    context::{
+        intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+        BoaProvider,
+    },
+    object::{internal_methods::get_prototype_from_constructor, JsObject, ObjectData},
+    property::Attribute,
+    realm::Realm,
+    string::utf16,
+    symbol::JsSymbol,
+    Context, JsArgs, JsNativeError, JsResult, JsString, JsValue,
+};
+
+use super::{
+    data::{pattern_parts, plural, LocaleChain, PatternPart, RELATIVE_TIME_FORMAT},
+    locale::{canonicalize_locale_list, resolve_locale, supported_locales},
+    number_format::{NumberFormatLocaleOptions, Part},
+    options::{coerce_options_to_object, get_option, IntlOptions, LocaleMatcher},
+    plural_rules::plural_category_to_str,
+    NumberFormat, PluralRules, Service,
+};
+
+mod format;
+mod options;
+pub(crate) use options::*;
+
+#[cfg(test)]
+mod tests;
+
+pub struct RelativeTimeFormat {
+    locale: Locale,
+    numbering_system: Option<Value>,
+    style: Style,
+    numeric: Numeric,
+    number_format: NumberFormat,
+    plural_rules: PluralRules,
+    data: LocaleChain,
+}
+
+impl Finalize for RelativeTimeFormat {}
+
+// SAFETY: only `number_format` contains traceable data.
+unsafe impl Trace for RelativeTimeFormat {
+    custom_trace!(this, mark(&this.number_format));
+}
+
+impl std::fmt::Debug for RelativeTimeFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RelativeTimeFormat")
+            .field("locale", &self.locale)
+            .field("numbering_system", &self.numbering_system)
+            .field("style", &self.style)
+            .field("numeric", &self.numeric)
+            .field("number_format", &self.number_format)
+            .field("plural_rules", &self.plural_rules)
+            .field("data", &self.data)
+            .finish()
+    }
+}
+
+impl Service for RelativeTimeFormat {
+    type LangMarker = DecimalSymbolsV1Marker;
+
+    type LocaleOptions = NumberFormatLocaleOptions;
+
+    fn resolve(locale: &mut Locale, options: &mut Self::LocaleOptions, provider: BoaProvider<'_>) {
+        // The only relevant extension key is "nu", which is resolved in the same way as in
+        // `Intl.NumberFormat`.
+        <NumberFormat as Service>::resolve(locale, options, provider);
+    }
+}
+
+impl IntrinsicObject for RelativeTimeFormat {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .static_method(Self::supported_locales_of, "supportedLocalesOf", 1)
+            .property(
+                JsSymbol::to_string_tag(),
+                "Intl.RelativeTimeFormat",
+                Attribute::CONFIGURABLE,
+            )
+            .method(Self::format, "format", 2)
+            .method(Self::format_to_parts, "formatToParts", 2)
+            .method(Self::resolved_options, "resolvedOptions", 0)
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for RelativeTimeFormat {
+    const NAME: &'static str = "RelativeTimeFormat";
+}
+
+impl BuiltInConstructor for RelativeTimeFormat {
+    const LENGTH: usize = 0;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::relative_time_format;
+
+    /// Constructor [`Intl.RelativeTimeFormat ( [ locales [ , options ] ] )`][spec].
+    ///
+    /// Constructor for `RelativeTimeFormat` objects.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.relativetimeformat
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/RelativeTimeFormat/RelativeTimeFormat
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("cannot call `Intl.RelativeTimeFormat` constructor without `new`")
+                .into());
+        }
+
+        let locales = args.get_or_undefined(0);
+        let options = args.get_or_undefined(1);
+
+        // 2. Let relativeTimeFormat be ? OrdinaryCreateFromConstructor(NewTarget, "%RelativeTimeFormat.prototype%", « [[InitializedRelativeTimeFormat]], [[Locale]], [[DataLocale]], [[Style]], [[Numeric]], [[NumberFormat]], [[NumberingSystem]], [[PluralRules]] »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::relative_time_format,
+            context,
+        )?;
+
+        // 3. Return ? InitializeRelativeTimeFormat(relativeTimeFormat, locales, options).
+
+        // Abstract operation `InitializeRelativeTimeFormat ( relativeTimeFormat, locales, options )`
+        // https://tc39.es/ecma402/#sec-InitializeRelativeTimeFormat
+
+        // 1. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(locales, context)?;
+
+        // 2. Set options to ? CoerceOptionsToObject(options).
+        let options = coerce_options_to_object(options, context)?;
+
+        // 3. Let opt be a new Record.
+        // 4. Let matcher be ? GetOption(options, "localeMatcher", string, « "lookup", "best fit" », "best fit").
+        // 5. Set opt.[[LocaleMatcher]] to matcher.
+        let matcher =
+            get_option::<LocaleMatcher>(&options, utf16!("localeMatcher"), false, context)?
+                .unwrap_or_default();
+
+        // 6. Let numberingSystem be ? GetOption(options, "numberingSystem", string, empty, undefined).
+        // 7. If numberingSystem is not undefined, then
+        //     a. If numberingSystem does not match the Unicode Locale Identifier type nonterminal, throw a RangeError exception.
+        // 8. Set opt.[[nu]] to numberingSystem.
+        let numbering_system =
+            get_option::<Value>(&options, utf16!("numberingSystem"), false, context)?;
+
+        let mut intl_options = IntlOptions {
+            matcher,
+            service_options: NumberFormatLocaleOptions { numbering_system },
+        };
+
+        // 9. Let localeData be %RelativeTimeFormat%.[[LocaleData]].
+        // 10. Let r be ResolveLocale(%RelativeTimeFormat%.[[AvailableLocales]], requestedLocales, opt, %RelativeTimeFormat%.[[RelevantExtensionKeys]], localeData).
+        // 11. Let locale be r.[[locale]].
+        // 12. Set relativeTimeFormat.[[Locale]] to locale.
+        // 13. Set relativeTimeFormat.[[DataLocale]] to r.[[dataLocale]].
+        // 14. Set relativeTimeFormat.[[NumberingSystem]] to r.[[nu]].
+        let mut locale =
+            resolve_locale::<Self>(&requested_locales, &mut intl_options, context.icu());
+
+        // Only a subset of the available locales has CLDR data for relative times. The others fall
+        // back to English, so that the number and its unit are in the same language.
+        let data = RELATIVE_TIME_FORMAT
+            .lookup(&locale.id, context.icu().locale_expander())
+            .unwrap_or_else(|| {
+                locale.id = langid!("en");
+                RELATIVE_TIME_FORMAT
+                    .chain("en")
+                    .expect("the locale data must have English")
+            });
+
+        // 15. Let style be ? GetOption(options, "style", string, « "long", "short", "narrow" », "long").
+        // 16. Set relativeTimeFormat.[[Style]] to style.
+        let style =
+            get_option::<Style>(&options, utf16!("style"), false, context)?.unwrap_or_default();
+
+        // 17. Let numeric be ? GetOption(options, "numeric", string, « "always", "auto" », "always").
+        // 18. Set relativeTimeFormat.[[Numeric]] to numeric.
+        let numeric =
+            get_option::<Numeric>(&options, utf16!("numeric"), false, context)?.unwrap_or_default();
+
+        // 19. Let relativeTimeFormat.[[NumberFormat]] be ! Construct(%NumberFormat%, « locale »).
+        let locale_string = JsValue::from(locale.to_string());
+        let number_format = NumberFormat::new(&locale_string, &JsValue::undefined(), context)?;
+
+        // 20. Let relativeTimeFormat.[[PluralRules]] be ! Construct(%PluralRules%, « locale »).
+        let plural_rules = PluralRules::new(&locale_string, &JsValue::undefined(), context)?;
+
+        // 21. Return relativeTimeFormat.
+        Ok(JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            ObjectData::relative_time_format(Self {
+                locale,
+                numbering_system: intl_options.service_options.numbering_system,
+                style,
+                numeric,
+                number_format,
+                plural_rules,
+                data,
+            }),
+        )
+        .into())
+    }
+}
+
+impl RelativeTimeFormat {
+    /// Abstract operation [`PartitionRelativeTimePattern ( relativeTimeFormat, value, unit )`][spec].
+    ///
+    /// Returns the parts of the formatted relative time, along with the unit of each numeric
+    /// part.
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-PartitionRelativeTimePattern
+    fn partition(&self, value: f64, unit: &JsString) -> JsResult<Vec<(Part, Option<Unit>)>> {
+        // 1. Assert: relativeTimeFormat has an [[InitializedRelativeTimeFormat]] internal slot.
+        // 2. Assert: Type(value) is Number.
+        // 3. Assert: Type(unit) is String.
+        // 4. If value is NaN, +∞𝔽, or -∞𝔽, throw a RangeError exception.
+        if !value.is_finite() {
+            return Err(JsNativeError::range()
+                .with_message("cannot format a relative time that is not finite")
+                .into());
+        }
+
+        // 5. Let unit be ? SingularRelativeTimeUnit(unit).
+        let unit = unit
+            .to_std_string_escaped()
+            .parse::<Unit>()
+            .map_err(|e| JsNativeError::range().with_message(e.to_string()))?;
+
+        // 6. Let localeData be %RelativeTimeFormat%.[[LocaleData]].
+        // 7. Let dataLocale be relativeTimeFormat.[[DataLocale]].
+        // 8. Let fields be localeData.[[<dataLocale>]].
+        // 9. Let style be relativeTimeFormat.[[Style]].
+        // 10. If style is equal to "short", then
+        //     a. Let entry be the string-concatenation of unit and "-short".
+        // 11. Else if style is equal to "narrow", then
+        //     a. Let entry be the string-concatenation of unit and "-narrow".
+        // 12. Else,
+        //     a. Let entry be unit.
+        // 13. If fields doesn't have a field [[<entry>]], then
+        //     a. Let entry be unit.
+        // 14. Let patterns be fields.[[<entry>]].
+        // 15. Let numeric be relativeTimeFormat.[[Numeric]].
+        // 16. If numeric is equal to "auto", then
+        //     a. Let valueString be ToString(value).
+        //     b. If patterns has a field [[<valueString>]], then
+        //         i. Let result be patterns.[[<valueString>]].
+        //         ii. Return a List containing the Record { [[Type]]: "literal", [[Value]]: result }.
+        if self.numeric == Numeric::Auto {
+            if let Some(name) = format::relative_name(&self.data, unit, self.style, value) {
+                return Ok(vec![(Part::new("literal", name), None)]);
+            }
+        }
+
+        // 17. If value is -0𝔽 or if value is less than 0, then
+        //     a. Let tl be "past".
+        //     b. Set value to -value.
+        // 18. Else,
+        //     a. Let tl be "future".
+        let past = value.is_sign_negative();
+        let value = value.abs();
+
+        // 19. Let po be patterns.[[<tl>]].
+        // 20. Let fv be ! PartitionNumberPattern(relativeTimeFormat.[[NumberFormat]], value).
+        let fv = self.number_format.partition(&value.into());
+
+        // 21. Let pr be ! ResolvePlural(relativeTimeFormat.[[PluralRules]], value).[[PluralCategory]].
+        // 22. Let pattern be po.[[<pr>]].
+        let [future_patterns, past_patterns] = format::patterns(&self.data, unit, self.style);
+        let category = plural_category_to_str(self.plural_rules.resolve_plural(value).0);
+        let pattern = plural(if past { past_patterns } else { future_patterns }, category);
+
+        // 23. Return ! MakePartsList(pattern, unit, fv).
+
+        // Abstract operation `MakePartsList ( pattern, unit, parts )`
+        // https://tc39.es/ecma402/#sec-makepartslist
+        let mut result = Vec::with_capacity(fv.len() + 2);
+        let mut fv = fv.into_iter().map(|part| (part, Some(unit)));
+        for part in pattern_parts(pattern) {
+            match part {
+                PatternPart::Number => result.extend(&mut fv),
+                PatternPart::Literal(literal) => result.push((Part::new("literal", literal), None)),
+                _ => {}
+            }
+        }
+        Ok(result)
+    }
+
+    /// [`Intl.RelativeTimeFormat.supportedLocalesOf ( locales [ , options ] )`][spec].
+    ///
+    /// Returns an array containing those of the provided locales that are supported in relative
+    /// time formatting without having to fall back to the runtime's default locale.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.relativetimeformat.supportedLocalesOf
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/RelativeTimeFormat/supportedLocalesOf
+    fn supported_locales_of(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        let locales = args.get_or_undefined(0);
+        let options = args.get_or_undefined(1);
+
+        // 1. Let availableLocales be %RelativeTimeFormat%.[[AvailableLocales]].
+        // 2. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(locales, context)?;
+
+        // 3. Return ? SupportedLocales(availableLocales, requestedLocales, options).
+        supported_locales::<<Self as Service>::LangMarker>(&requested_locales, options, context)
+            .map(JsValue::from)
+    }
+
+    /// [`Intl.RelativeTimeFormat.prototype.format ( value, unit )`][spec].
+    ///
+    /// Formats a value and a unit according to the locale and formatting options of this
+    /// `Intl.RelativeTimeFormat` object.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.RelativeTimeFormat.prototype.format
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/RelativeTimeFormat/format
+    fn format(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Let relativeTimeFormat be the this value.
+        // 2. Perform ? RequireInternalSlot(relativeTimeFormat, [[InitializedRelativeTimeFormat]]).
+        // 3. Let value be ? ToNumber(value).
+        // 4. Let unit be ? ToString(unit).
+        // 5. Return ? FormatRelativeTime(relativeTimeFormat, value, unit).
+
+        // Abstract operation `FormatRelativeTime ( relativeTimeFormat, value, unit )`
+        // https://tc39.es/ecma402/#sec-FormatRelativeTime
+
+        // 1. Let parts be ? PartitionRelativeTimePattern(relativeTimeFormat, value, unit).
+        // 2. Let result be an empty String.
+        // 3. For each Record { [[Type]], [[Value]], [[Unit]] } part in parts, do
+        //     a. Set result to the string-concatenation of result and part.[[Value]].
+        // 4. Return result.
+        let parts = Self::parts(this, args, "format", context)?;
+        Ok(JsString::from(
+            parts
+                .into_iter()
+                .map(|(part, _)| part.value)
+                .collect::<String>(),
+        )
+        .into())
+    }
+
+    /// [`Intl.RelativeTimeFormat.prototype.formatToParts ( value, unit )`][spec].
+    ///
+    /// Returns an array of objects representing the locale-specific parts of a formatted
+    /// relative time.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.RelativeTimeFormat.prototype.formatToParts
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/RelativeTimeFormat/formatToParts
+    fn format_to_parts(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. Let relativeTimeFormat be the this value.
+        // 2. Perform ? RequireInternalSlot(relativeTimeFormat, [[InitializedRelativeTimeFormat]]).
+        // 3. Let value be ? ToNumber(value).
+        // 4. Let unit be ? ToString(unit).
+        // 5. Return ? FormatRelativeTimeToParts(relativeTimeFormat, value, unit).
+
+        // Abstract operation `FormatRelativeTimeToParts ( relativeTimeFormat, value, unit )`
+        // https://tc39.es/ecma402/#sec-FormatRelativeTimeToParts
+
+        // 1. Let parts be ? PartitionRelativeTimePattern(relativeTimeFormat, value, unit).
+        let parts = Self::parts(this, args, "formatToParts", context)?;
+
+        // 2. Let result be ! ArrayCreate(0).
+        let result = Array::array_create(0, None, context)
+            .expect("creating an empty array with default proto must not fail");
+
+        // 3. Let n be 0.
+        // 4. For each Record { [[Type]], [[Value]], [[Unit]] } part in parts, do
+        for (n, (part, unit)) in parts.into_iter().enumerate() {
+            // a. Let O be OrdinaryObjectCreate(%Object.prototype%).
+            let o = context
+                .intrinsics()
+                .templates()
+                .ordinary_object()
+                .create(ObjectData::ordinary(), vec![]);
+
+            // b. Perform ! CreateDataPropertyOrThrow(O, "type", part.[[Type]]).
+            o.create_data_property_or_throw(utf16!("type"), part.typ, context)
+                .expect("operation must not fail per the spec");
+
+            // c. Perform ! CreateDataPropertyOrThrow(O, "value", part.[[Value]]).
+            o.create_data_property_or_throw(utf16!("value"), part.value, context)
+                .expect("operation must not fail per the spec");
+
+            // d. If part.[[Unit]] is not empty, then
+            //     i. Perform ! CreateDataPropertyOrThrow(O, "unit", part.[[Unit]]).
+            if let Some(unit) = unit {
+                o.create_data_property_or_throw(utf16!("unit"), unit.to_string(), context)
+                    .expect("operation must not fail per the spec");
+            }
+
+            // e. Perform ! CreateDataPropertyOrThrow(result, ! ToString(𝔽(n)), O).
+            result
+                .create_data_property_or_throw(n, o, context)
+                .expect("operation must not fail per the spec");
+
+            // f. Increment n by 1.
+        }
+
+        // 5. Return result.
+        Ok(result.into())
+    }
+
+    /// Common steps of `format` and `formatToParts`, which partition the relative time given by
+    /// the `value` and `unit` arguments.
+    fn parts(
+        this: &JsValue,
+        args: &[JsValue],
+        method: &str,
+        context: &mut Context<'_>,
+    ) -> JsResult<Vec<(Part, Option<Unit>)>> {
+        // 1. Let relativeTimeFormat be the this value.
+        // 2. Perform ? RequireInternalSlot(relativeTimeFormat, [[InitializedRelativeTimeFormat]]).
+        let rtf = this
+            .as_object()
+            .filter(|rtf| rtf.borrow().as_relative_time_format().is_some())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(format!(
+                    "`{method}` can only be called on a `RelativeTimeFormat` object"
+                ))
+            })?;
+
+        // 3. Let value be ? ToNumber(value).
+        let value = args.get_or_undefined(0).to_number(context)?;
+
+        // 4. Let unit be ? ToString(unit).
+        let unit = args.get_or_undefined(1).to_string(context)?;
+
+        let rtf = rtf.borrow();
+        let rtf = rtf
+            .as_relative_time_format()
+            .expect("checked above that the object was a relative time format object");
+        rtf.partition(value, &unit)
+    }
+
+    /// [`Intl.RelativeTimeFormat.prototype.resolvedOptions ( )`][spec].
+    ///
+    /// Returns a new object with properties reflecting the locale and formatting options
+    /// computed during the construction of the current `Intl.RelativeTimeFormat` object.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.relativetimeformat.prototype.resolvedoptions
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/RelativeTimeFormat/resolvedOptions
+    fn resolved_options(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. Let relativeTimeFormat be the this value.
+        // 2. Perform ? RequireInternalSlot(relativeTimeFormat, [[InitializedRelativeTimeFormat]]).
+        let rtf = this.as_object().map(JsObject::borrow).ok_or_else(|| {
+            JsNativeError::typ().with_message(
+                "`resolvedOptions` can only be called on a `RelativeTimeFormat` object",
+            )
+        })?;
+        let rtf = rtf.as_relative_time_format().ok_or_else(|| {
+            JsNativeError::typ().with_message(
+                "`resolvedOptions` can only be called on a `RelativeTimeFormat` object",
+            )
+        })?;
+
+        // 3. Let options be OrdinaryObjectCreate(%Object.prototype%).
+        let options = context
+            .intrinsics()
+            .templates()
+            .ordinary_object()
+            .create(ObjectData::ordinary(), vec![]);
+
+        // 4. For each row of Table 17, except the header row, in table order, do
+        //     a. Let p be the Property value of the current row.
+        //     b. Let v be the value of relativeTimeFormat's internal slot whose name is the Internal Slot value of the current row.
+        //     c. Assert: v is not undefined.
+        //     d. Perform ! CreateDataPropertyOrThrow(options, p, v).
+        options
+            .create_data_property_or_throw(utf16!("locale"), rtf.locale.to_string(), context)
+            .expect("operation must not fail per the spec");
+        options
+            .create_data_property_or_throw(utf16!("style"), rtf.style.to_string(), context)
+            .expect("operation must not fail per the spec");
+        options
+            .create_data_property_or_throw(utf16!("numeric"), rtf.numeric.to_string(), context)
+            .expect("operation must not fail per the spec");
+        options
+            .create_data_property_or_throw(
+                utf16!("numberingSystem"),
+                rtf.numbering_system
+                    .as_ref()
+                    .map_or_else(|| String::from("latn"), ToString::to_string),
+                context,
+            )
+            .expect("operation must not fail per the spec");
+
+        // 5. Return options.
+        Ok(options.into())
+    }
+}
@@ -0,0 +1,155 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::builtins::intl::options::OptionTypeParsable;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum Style {
+    #[default]
+    Long,
+    Short,
+    Narrow,
+}
+
+impl Display for Style {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Long => "long",
+            Self::Short => "short",
+            Self::Narrow => "narrow",
+        }
+        .fmt(f)
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseStyleError;
+
+impl Display for ParseStyleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("provided string was not `long`, `short` or `narrow`")
+    }
+}
+
+impl FromStr for Style {
+    type Err = ParseStyleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "long" => Ok(Self::Long),
+            "short" => Ok(Self::Short),
+            "narrow" => Ok(Self::Narrow),
+            _ => Err(ParseStyleError),
+        }
+    }
+}
+
+impl OptionTypeParsable for Style {}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum Numeric {
+    #[default]
+    Always,
+    Auto,
+}
+
+impl Display for Numeric {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Always => "always",
+            Self::Auto => "auto",
+        }
+        .fmt(f)
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseNumericError;
+
+impl Display for ParseNumericError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("provided string was not `always` or `auto`")
+    }
+}
+
+impl FromStr for Numeric {
+    type Err = ParseNumericError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "always" => Ok(Self::Always),
+            "auto" => Ok(Self::Auto),
+            _ => Err(ParseNumericError),
+        }
+    }
+}
+
+impl OptionTypeParsable for Numeric {}
+
+/// A unit of a relative time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Unit {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Quarter,
+    Year,
+}
+
+impl Display for Unit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Second => "second",
+            Self::Minute => "minute",
+            Self::Hour => "hour",
+            Self::Day => "day",
+            Self::Week => "week",
+            Self::Month => "month",
+            Self::Quarter => "quarter",
+            Self::Year => "year",
+        }
+        .fmt(f)
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseUnitError;
+
+impl Display for ParseUnitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("provided string was not a valid relative time unit")
+    }
+}
+
+impl FromStr for Unit {
+    type Err = ParseUnitError;
+
+    /// Abstract operation [`SingularRelativeTimeUnit ( unit )`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-singularrelativetimeunit
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // 1. If unit is "seconds", return "second".
+        // 2. If unit is "minutes", return "minute".
+        // 3. If unit is "hours", return "hour".
+        // 4. If unit is "days", return "day".
+        // 5. If unit is "weeks", return "week".
+        // 6. If unit is "months", return "month".
+        // 7. If unit is "quarters", return "quarter".
+        // 8. If unit is "years", return "year".
+        // 9. If unit is not one of "second", "minute", "hour", "day", "week", "month", "quarter", or "year", throw a RangeError exception.
+        // 10. Return unit.
+        match s {
+            "second" | "seconds" => Ok(Self::Second),
+            "minute" | "minutes" => Ok(Self::Minute),
+            "hour" | "hours" => Ok(Self::Hour),
+            "day" | "days" => Ok(Self::Day),
+            "week" | "weeks" => Ok(Self::Week),
+            "month" | "months" => Ok(Self::Month),
+            "quarter" | "quarters" => Ok(Self::Quarter),
+            "year" | "years" => Ok(Self::Year),
+            _ => Err(ParseUnitError),
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use crate::{
+    builtins::intl::{
+        data::RELATIVE_TIME_FORMAT,
+        relative_time_format::{
+            format::{patterns, relative_name},
+            Style, Unit,
+        },
+    },
+    run_test_actions, TestAction,
+};
+
+#[test]
+fn singular_relative_time_unit() {
+    assert_eq!("days".parse::<Unit>().ok(), Some(Unit::Day));
+    assert_eq!("quarter".parse::<Unit>().ok(), Some(Unit::Quarter));
+    assert!("millisecond".parse::<Unit>().is_err());
+    assert!("Days".parse::<Unit>().is_err());
+}
+
+#[test]
+fn relative_names() {
+    let en = RELATIVE_TIME_FORMAT.chain("en").unwrap();
+    assert_eq!(
+        relative_name(&en, Unit::Day, Style::Long, -1.0),
+        Some("yesterday")
+    );
+    assert_eq!(
+        relative_name(&en, Unit::Day, Style::Long, -0.0),
+        Some("today")
+    );
+    assert_eq!(
+        relative_name(&en, Unit::Year, Style::Short, 1.0),
+        Some("next yr.")
+    );
+    assert_eq!(relative_name(&en, Unit::Second, Style::Narrow, 1.0), None);
+    assert_eq!(relative_name(&en, Unit::Week, Style::Long, 2.0), None);
+
+    let de = RELATIVE_TIME_FORMAT.chain("de-AT").unwrap();
+    assert_eq!(
+        relative_name(&de, Unit::Day, Style::Long, 2.0),
+        Some("übermorgen")
+    );
+    assert_eq!(
+        patterns(&de, Unit::Day, Style::Short),
+        [
+            "one=in {0} Tag;other=in {0} Tagen",
+            "one=vor {0} Tag;other=vor {0} Tagen"
+        ]
+    );
+}
+
+#[test]
+fn format_across_locales() {
+    run_test_actions([
+        TestAction::assert_eq(
+            "new Intl.RelativeTimeFormat('en').format(-1, 'day')",
+            "1 day ago",
+        ),
+        TestAction::assert_eq(
+            "new Intl.RelativeTimeFormat('en').format(1000, 'days')",
+            "in 1,000 days",
+        ),
+        TestAction::assert_eq(
+            "new Intl.RelativeTimeFormat('en', { numeric: 'auto' }).format(-1, 'day')",
+            "yesterday",
+        ),
+        TestAction::assert_eq(
+            "new Intl.RelativeTimeFormat('de').format(-1, 'day')",
+            "vor 1 Tag",
+        ),
+        TestAction::assert_eq(
+            "new Intl.RelativeTimeFormat('de').format(1000, 'days')",
+            "in 1.000 Tagen",
+        ),
+        TestAction::assert_eq(
+            "new Intl.RelativeTimeFormat('de', { numeric: 'auto' }).format(2, 'day')",
+            "übermorgen",
+        ),
+        TestAction::assert_eq(
+            "new Intl.RelativeTimeFormat('ja', { style: 'short' }).format(3, 'month')",
+            "3 か月後",
+        ),
+        TestAction::assert_eq(
+            "new Intl.RelativeTimeFormat('de').resolvedOptions().locale",
+            "de",
+        ),
+        TestAction::assert_eq(
+            "new Intl.RelativeTimeFormat('ar').resolvedOptions().locale",
+            "en",
+        ),
+    ]);
+}
@@ -34,9 +34,9 @@ impl IntrinsicObject for AsyncFromSyncIterator {
             .prototype(
                 realm
                     .intrinsics()
-                    .objects()
-                    .iterator_prototypes()
-                    .async_iterator(),
+                    .constructors()
+                    .async_iterator()
+                    .prototype(),
             )
             .static_method(Self::next, "next", 1)
             .static_method(Self::r#return, "return", 1)
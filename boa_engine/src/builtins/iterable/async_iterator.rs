@@ -0,0 +1,989 @@
+//! Boa's implementation of the `AsyncIterator` constructor and the async iterator helpers of
+//! `%AsyncIterator.prototype%`.
+//!
+//! More information:
+//!  - [ECMA reference][spec]
+//!  - [Async Iterator Helpers proposal][proposal]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-asynciteratorprototype
+//! [proposal]: https://tc39.es/proposal-async-iterator-helpers/
+
+use crate::{
+    builtins::{
+        iterable::{
+            async_iterator_helper::{
+                async_iterator_close, await_then, if_abrupt_close_async_iterator, reject,
+                AsyncHelperClosure, AsyncIteratorHelper,
+            },
+            create_iter_result_object, get_iterator_direct, AsyncFromSyncIterator, IteratorRecord,
+            IteratorResult, PrimitiveHandling,
+        },
+        promise::{if_abrupt_reject_promise, PromiseCapability},
+        Array, BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject,
+    },
+    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    js_string,
+    object::{internal_methods::get_prototype_from_constructor, JsObject, ObjectData},
+    property::Attribute,
+    realm::Realm,
+    symbol::JsSymbol,
+    value::IntegerOrInfinity,
+    Context, JsArgs, JsNativeError, JsResult, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+
+/// The `AsyncIterator` constructor and `%AsyncIterator.prototype%` object.
+///
+/// More information:
+///  - [ECMA reference][spec]
+///  - [Async Iterator Helpers proposal][proposal]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-asynciteratorprototype
+/// [proposal]: https://tc39.es/proposal-async-iterator-helpers/#sec-asynciterator-constructor
+pub(crate) struct AsyncIterator;
+
+impl IntrinsicObject for AsyncIterator {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .static_method(Self::from, "from", 1)
+            .method(Self::map, "map", 1)
+            .method(Self::filter, "filter", 1)
+            .method(Self::take, "take", 1)
+            .method(Self::drop, "drop", 1)
+            .method(Self::flat_map, "flatMap", 1)
+            .method(Self::reduce, "reduce", 1)
+            .method(Self::to_array, "toArray", 0)
+            .method(Self::for_each, "forEach", 1)
+            .method(Self::some, "some", 1)
+            .method(Self::every, "every", 1)
+            .method(Self::find, "find", 1)
+            .method(
+                |v, _, _| Ok(v.clone()),
+                (
+                    JsSymbol::async_iterator(),
+                    js_string!("[Symbol.asyncIterator]"),
+                ),
+                0,
+            )
+            .property(
+                JsSymbol::to_string_tag(),
+                js_string!("AsyncIterator"),
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for AsyncIterator {
+    const NAME: &'static str = "AsyncIterator";
+}
+
+impl BuiltInConstructor for AsyncIterator {
+    const LENGTH: usize = 0;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::async_iterator;
+
+    /// Constructor [`AsyncIterator ( )`][spec].
+    ///
+    /// [spec]: https://tc39.es/proposal-async-iterator-helpers/#sec-asynciterator
+    fn constructor(
+        new_target: &JsValue,
+        _: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined or the active function object, throw a TypeError exception.
+        let active_function = context
+            .intrinsics()
+            .constructors()
+            .async_iterator()
+            .constructor();
+        if new_target
+            .as_object()
+            .map_or(true, |new_target| *new_target == active_function)
+        {
+            return Err(JsNativeError::typ()
+                .with_message(
+                    "AsyncIterator: the constructor is abstract and cannot be called directly",
+                )
+                .into());
+        }
+
+        // 2. Return ? OrdinaryCreateFromConstructor(NewTarget, "%AsyncIterator.prototype%").
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::async_iterator,
+            context,
+        )?;
+        Ok(JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            ObjectData::ordinary(),
+        )
+        .into())
+    }
+}
+
+impl AsyncIterator {
+    /// `AsyncIterator.from ( O )`
+    ///
+    /// More information:
+    ///  - [Async Iterator Helpers proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-async-iterator-helpers/#sec-asynciterator.from
+    fn from(_: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Let iteratorRecord be ? GetIteratorFlattenable(O, iterate-strings, async).
+        let iterator_record = get_async_iterator_flattenable(
+            args.get_or_undefined(0),
+            PrimitiveHandling::IterateStrings,
+            context,
+        )?;
+
+        // 2. Let hasInstance be ? OrdinaryHasInstance(%AsyncIterator%, iteratorRecord.[[Iterator]]).
+        let has_instance = JsValue::ordinary_has_instance(
+            &context
+                .intrinsics()
+                .constructors()
+                .async_iterator()
+                .constructor()
+                .into(),
+            &iterator_record.iterator().clone().into(),
+            context,
+        )?;
+
+        // 3. If hasInstance is true, then
+        if has_instance {
+            // a. Return iteratorRecord.[[Iterator]].
+            return Ok(iterator_record.iterator().clone().into());
+        }
+
+        // 4. Let wrapper be OrdinaryObjectCreate(%WrapForValidAsyncIteratorPrototype%, « [[AsyncIterated]] »).
+        // 5. Set wrapper.[[AsyncIterated]] to iteratorRecord.
+        // 6. Return wrapper.
+        Ok(JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            context
+                .intrinsics()
+                .objects()
+                .iterator_prototypes()
+                .wrap_for_valid_async_iterator(),
+            ObjectData::wrap_for_valid_async_iterator(WrapForValidAsyncIterator {
+                async_iterated: iterator_record,
+            }),
+        )
+        .into())
+    }
+
+    /// `AsyncIterator.prototype.map ( mapper )`
+    ///
+    /// More information:
+    ///  - [Async Iterator Helpers proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-async-iterator-helpers/#sec-asynciteratorprototype.map
+    fn map(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let o = this_async_iterator(this, "map")?;
+
+        // 3. If IsCallable(mapper) is false, throw a TypeError exception.
+        let mapper = require_callable(args.get_or_undefined(0), "map", "mapper")?;
+
+        // 4. Let iterated be ? GetIteratorDirect(O).
+        let iterated = get_iterator_direct(o, context)?;
+
+        // 5. Let closure be a new Abstract Closure with no parameters that captures iterated and mapper and performs the following steps when called:
+        // 6. Let result be CreateAsyncIteratorFromClosure(closure, "Async Iterator Helper", %AsyncIteratorHelperPrototype%, « [[UnderlyingIterator]] »).
+        // 7. Set result.[[UnderlyingIterator]] to iterated.
+        // 8. Return result.
+        Ok(
+            AsyncIteratorHelper::create(iterated, AsyncHelperClosure::Map { mapper }, context)
+                .into(),
+        )
+    }
+
+    /// `AsyncIterator.prototype.filter ( predicate )`
+    ///
+    /// More information:
+    ///  - [Async Iterator Helpers proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-async-iterator-helpers/#sec-asynciteratorprototype.filter
+    fn filter(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let o = this_async_iterator(this, "filter")?;
+
+        // 3. If IsCallable(predicate) is false, throw a TypeError exception.
+        let predicate = require_callable(args.get_or_undefined(0), "filter", "predicate")?;
+
+        // 4. Let iterated be ? GetIteratorDirect(O).
+        let iterated = get_iterator_direct(o, context)?;
+
+        // 5. Let closure be a new Abstract Closure with no parameters that captures iterated and predicate and performs the following steps when called:
+        // 6. Let result be CreateAsyncIteratorFromClosure(closure, "Async Iterator Helper", %AsyncIteratorHelperPrototype%, « [[UnderlyingIterator]] »).
+        // 7. Set result.[[UnderlyingIterator]] to iterated.
+        // 8. Return result.
+        Ok(
+            AsyncIteratorHelper::create(
+                iterated,
+                AsyncHelperClosure::Filter { predicate },
+                context,
+            )
+            .into(),
+        )
+    }
+
+    /// `AsyncIterator.prototype.take ( limit )`
+    ///
+    /// More information:
+    ///  - [Async Iterator Helpers proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-async-iterator-helpers/#sec-asynciteratorprototype.take
+    fn take(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let o = this_async_iterator(this, "take")?;
+
+        // 3. Let numLimit be ? ToNumber(limit).
+        // 4. If numLimit is NaN, throw a RangeError exception.
+        // 5. Let integerLimit be ! ToIntegerOrInfinity(numLimit).
+        // 6. If integerLimit < 0, throw a RangeError exception.
+        let remaining = to_limit(args.get_or_undefined(0), "take", context)?;
+
+        // 7. Let iterated be ? GetIteratorDirect(O).
+        let iterated = get_iterator_direct(o, context)?;
+
+        // 8. Let closure be a new Abstract Closure with no parameters that captures iterated and integerLimit and performs the following steps when called:
+        // 9. Let result be CreateAsyncIteratorFromClosure(closure, "Async Iterator Helper", %AsyncIteratorHelperPrototype%, « [[UnderlyingIterator]] »).
+        // 10. Set result.[[UnderlyingIterator]] to iterated.
+        // 11. Return result.
+        Ok(
+            AsyncIteratorHelper::create(iterated, AsyncHelperClosure::Take { remaining }, context)
+                .into(),
+        )
+    }
+
+    /// `AsyncIterator.prototype.drop ( limit )`
+    ///
+    /// More information:
+    ///  - [Async Iterator Helpers proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-async-iterator-helpers/#sec-asynciteratorprototype.drop
+    fn drop(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let o = this_async_iterator(this, "drop")?;
+
+        // 3. Let numLimit be ? ToNumber(limit).
+        // 4. If numLimit is NaN, throw a RangeError exception.
+        // 5. Let integerLimit be ! ToIntegerOrInfinity(numLimit).
+        // 6. If integerLimit < 0, throw a RangeError exception.
+        let remaining = to_limit(args.get_or_undefined(0), "drop", context)?;
+
+        // 7. Let iterated be ? GetIteratorDirect(O).
+        let iterated = get_iterator_direct(o, context)?;
+
+        // 8. Let closure be a new Abstract Closure with no parameters that captures iterated and integerLimit and performs the following steps when called:
+        // 9. Let result be CreateAsyncIteratorFromClosure(closure, "Async Iterator Helper", %AsyncIteratorHelperPrototype%, « [[UnderlyingIterator]] »).
+        // 10. Set result.[[UnderlyingIterator]] to iterated.
+        // 11. Return result.
+        Ok(
+            AsyncIteratorHelper::create(iterated, AsyncHelperClosure::Drop { remaining }, context)
+                .into(),
+        )
+    }
+
+    /// `AsyncIterator.prototype.flatMap ( mapper )`
+    ///
+    /// More information:
+    ///  - [Async Iterator Helpers proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-async-iterator-helpers/#sec-asynciteratorprototype.flatmap
+    fn flat_map(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let o = this_async_iterator(this, "flatMap")?;
+
+        // 3. If IsCallable(mapper) is false, throw a TypeError exception.
+        let mapper = require_callable(args.get_or_undefined(0), "flatMap", "mapper")?;
+
+        // 4. Let iterated be ? GetIteratorDirect(O).
+        let iterated = get_iterator_direct(o, context)?;
+
+        // 5. Let closure be a new Abstract Closure with no parameters that captures iterated and mapper and performs the following steps when called:
+        // 6. Let result be CreateAsyncIteratorFromClosure(closure, "Async Iterator Helper", %AsyncIteratorHelperPrototype%, « [[UnderlyingIterator]] »).
+        // 7. Set result.[[UnderlyingIterator]] to iterated.
+        // 8. Return result.
+        Ok(AsyncIteratorHelper::create(
+            iterated,
+            AsyncHelperClosure::FlatMap {
+                mapper,
+                inner: None,
+            },
+            context,
+        )
+        .into())
+    }
+
+    /// `AsyncIterator.prototype.reduce ( reducer [ , initialValue ] )`
+    ///
+    /// More information:
+    ///  - [Async Iterator Helpers proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-async-iterator-helpers/#sec-asynciteratorprototype.reduce
+    fn reduce(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let o = this_async_iterator(this, "reduce")?;
+
+        // 3. If IsCallable(reducer) is false, throw a TypeError exception.
+        let reducer = require_callable(args.get_or_undefined(0), "reduce", "reducer")?;
+
+        // 4. Let iterated be ? GetIteratorDirect(O).
+        let iterated = get_iterator_direct(o, context)?;
+
+        // 5. Let closure be a new Abstract Closure with no parameters that captures iterated, reducer, and initialValue and performs the following steps when called:
+        //     a. If initialValue is not present, then
+        //         i. Let next be ? Await(? IteratorNext(iterated)).
+        //         ii. If ? IteratorComplete(next) is true, throw a TypeError exception.
+        //         iii. Let accumulator be ? IteratorValue(next).
+        //         iv. Let counter be 1.
+        //     b. Else,
+        //         i. Let accumulator be initialValue.
+        //         ii. Let counter be 0.
+        // 6. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+        // 7. Perform AsyncFunctionStart(promiseCapability, closure).
+        // 8. Return promiseCapability.[[Promise]].
+        let (accumulator, counter) = match args.get(1) {
+            Some(initial_value) => (Some(initial_value.clone()), 0),
+            None => (None, 1),
+        };
+        Ok(Consumer::start(
+            iterated,
+            ConsumerKind::Reduce {
+                reducer,
+                accumulator,
+            },
+            counter,
+            context,
+        ))
+    }
+
+    /// `AsyncIterator.prototype.toArray ( )`
+    ///
+    /// More information:
+    ///  - [Async Iterator Helpers proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-async-iterator-helpers/#sec-asynciteratorprototype.toarray
+    fn to_array(this: &JsValue, _: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let o = this_async_iterator(this, "toArray")?;
+
+        // 3. Let iterated be ? GetIteratorDirect(O).
+        let iterated = get_iterator_direct(o, context)?;
+
+        // 4. Let closure be a new Abstract Closure with no parameters that captures iterated and performs the following steps when called:
+        //     a. Let items be a new empty List.
+        // 5. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+        // 6. Perform AsyncFunctionStart(promiseCapability, closure).
+        // 7. Return promiseCapability.[[Promise]].
+        let items = Array::array_create(0, None, context)
+            .expect("creating an empty array with the default prototype must not fail");
+        Ok(Consumer::start(
+            iterated,
+            ConsumerKind::ToArray { items },
+            0,
+            context,
+        ))
+    }
+
+    /// `AsyncIterator.prototype.forEach ( fn )`
+    ///
+    /// More information:
+    ///  - [Async Iterator Helpers proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-async-iterator-helpers/#sec-asynciteratorprototype.foreach
+    fn for_each(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let o = this_async_iterator(this, "forEach")?;
+
+        // 3. If IsCallable(fn) is false, throw a TypeError exception.
+        let procedure = require_callable(args.get_or_undefined(0), "forEach", "fn")?;
+
+        // 4. Let iterated be ? GetIteratorDirect(O).
+        let iterated = get_iterator_direct(o, context)?;
+
+        // 5. Let closure be a new Abstract Closure with no parameters that captures iterated and fn and performs the following steps when called:
+        // 6. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+        // 7. Perform AsyncFunctionStart(promiseCapability, closure).
+        // 8. Return promiseCapability.[[Promise]].
+        Ok(Consumer::start(
+            iterated,
+            ConsumerKind::ForEach { procedure },
+            0,
+            context,
+        ))
+    }
+
+    /// `AsyncIterator.prototype.some ( predicate )`
+    ///
+    /// More information:
+    ///  - [Async Iterator Helpers proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-async-iterator-helpers/#sec-asynciteratorprototype.some
+    fn some(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let o = this_async_iterator(this, "some")?;
+
+        // 3. If IsCallable(predicate) is false, throw a TypeError exception.
+        let predicate = require_callable(args.get_or_undefined(0), "some", "predicate")?;
+
+        // 4. Let iterated be ? GetIteratorDirect(O).
+        let iterated = get_iterator_direct(o, context)?;
+
+        // 5. Let closure be a new Abstract Closure with no parameters that captures iterated and predicate and performs the following steps when called:
+        // 6. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+        // 7. Perform AsyncFunctionStart(promiseCapability, closure).
+        // 8. Return promiseCapability.[[Promise]].
+        Ok(Consumer::start(
+            iterated,
+            ConsumerKind::Some { predicate },
+            0,
+            context,
+        ))
+    }
+
+    /// `AsyncIterator.prototype.every ( predicate )`
+    ///
+    /// More information:
+    ///  - [Async Iterator Helpers proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-async-iterator-helpers/#sec-asynciteratorprototype.every
+    fn every(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let o = this_async_iterator(this, "every")?;
+
+        // 3. If IsCallable(predicate) is false, throw a TypeError exception.
+        let predicate = require_callable(args.get_or_undefined(0), "every", "predicate")?;
+
+        // 4. Let iterated be ? GetIteratorDirect(O).
+        let iterated = get_iterator_direct(o, context)?;
+
+        // 5. Let closure be a new Abstract Closure with no parameters that captures iterated and predicate and performs the following steps when called:
+        // 6. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+        // 7. Perform AsyncFunctionStart(promiseCapability, closure).
+        // 8. Return promiseCapability.[[Promise]].
+        Ok(Consumer::start(
+            iterated,
+            ConsumerKind::Every { predicate },
+            0,
+            context,
+        ))
+    }
+
+    /// `AsyncIterator.prototype.find ( predicate )`
+    ///
+    /// More information:
+    ///  - [Async Iterator Helpers proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-async-iterator-helpers/#sec-asynciteratorprototype.find
+    fn find(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let o = this_async_iterator(this, "find")?;
+
+        // 3. If IsCallable(predicate) is false, throw a TypeError exception.
+        let predicate = require_callable(args.get_or_undefined(0), "find", "predicate")?;
+
+        // 4. Let iterated be ? GetIteratorDirect(O).
+        let iterated = get_iterator_direct(o, context)?;
+
+        // 5. Let closure be a new Abstract Closure with no parameters that captures iterated and predicate and performs the following steps when called:
+        // 6. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+        // 7. Perform AsyncFunctionStart(promiseCapability, closure).
+        // 8. Return promiseCapability.[[Promise]].
+        Ok(Consumer::start(
+            iterated,
+            ConsumerKind::Find { predicate },
+            0,
+            context,
+        ))
+    }
+}
+
+/// Returns the `this` value of an async iterator helper method, throwing a `TypeError` if it's
+/// not an object.
+fn this_async_iterator<'a>(this: &'a JsValue, method: &str) -> JsResult<&'a JsObject> {
+    this.as_object().ok_or_else(|| {
+        JsNativeError::typ()
+            .with_message(format!(
+                "AsyncIterator.prototype.{method}: `this` is not an async iterator object"
+            ))
+            .into()
+    })
+}
+
+/// Checks that the `argument` of an async iterator helper method is callable.
+fn require_callable(value: &JsValue, method: &str, argument: &str) -> JsResult<JsObject> {
+    value.as_callable().cloned().ok_or_else(|| {
+        JsNativeError::typ()
+            .with_message(format!(
+                "AsyncIterator.prototype.{method}: `{argument}` is not callable"
+            ))
+            .into()
+    })
+}
+
+/// Converts the `limit` argument of `take` and `drop` to an integer.
+///
+/// Returns `None` if the limit is `+∞`.
+fn to_limit(limit: &JsValue, method: &str, context: &mut Context<'_>) -> JsResult<Option<u64>> {
+    let num_limit = limit.to_number(context)?;
+    let error = match IntegerOrInfinity::from(num_limit) {
+        _ if num_limit.is_nan() => "must not be NaN",
+        IntegerOrInfinity::PositiveInfinity => return Ok(None),
+        IntegerOrInfinity::Integer(limit) if limit >= 0 => return Ok(Some(limit as u64)),
+        _ => "must be positive",
+    };
+    Err(JsNativeError::range()
+        .with_message(format!("AsyncIterator.prototype.{method}: `limit` {error}"))
+        .into())
+}
+
+/// `GetIteratorFlattenable ( obj, primitiveHandling, async )`
+///
+/// More information:
+///  - [Async Iterator Helpers proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-async-iterator-helpers/#sec-getiteratorflattenable
+pub(super) fn get_async_iterator_flattenable(
+    value: &JsValue,
+    primitive_handling: PrimitiveHandling,
+    context: &mut Context<'_>,
+) -> JsResult<IteratorRecord> {
+    // 1. If obj is not an Object, then
+    //     a. If primitiveHandling is reject-primitives, throw a TypeError exception.
+    //     b. Assert: primitiveHandling is iterate-string-primitives.
+    //     c. If obj is not a String, throw a TypeError exception.
+    if !value.is_object()
+        && (primitive_handling == PrimitiveHandling::RejectPrimitives || !value.is_string())
+    {
+        return Err(JsNativeError::typ()
+            .with_message(format!(
+                "value with type `{}` is not an async iterator nor an iterable object",
+                value.type_of()
+            ))
+            .into());
+    }
+
+    // 2. Let alreadyAsync be false.
+    // 3. Let method be undefined.
+    // 4. If hint is async, then
+    //     a. Set method to ? Get(obj, @@asyncIterator).
+    //     b. Set alreadyAsync to true.
+    let mut already_async = true;
+    let mut method = value.get_v(JsSymbol::async_iterator(), context)?;
+
+    // 5. If IsCallable(method) is false, then
+    if !method.is_callable() {
+        // a. Set method to ? Get(obj, @@iterator).
+        // b. Set alreadyAsync to false.
+        method = value.get_v(JsSymbol::iterator(), context)?;
+        already_async = false;
+    }
+
+    // 6. If IsCallable(method) is false, then
+    //     a. Let iterator be obj.
+    //     b. Set alreadyAsync to true.
+    // 7. Else,
+    //     a. Let iterator be ? Call(method, obj).
+    let iterator = if method.is_callable() {
+        method.call(value, &[], context)?
+    } else {
+        already_async = true;
+        value.clone()
+    };
+
+    // 8. If iterator is not an Object, throw a TypeError exception.
+    let Some(iterator) = iterator.as_object() else {
+        return Err(JsNativeError::typ()
+            .with_message("returned iterator is not an object")
+            .into());
+    };
+
+    // 9. Let iteratorRecord be ? GetIteratorDirect(iterator).
+    let iterator_record = get_iterator_direct(iterator, context)?;
+
+    // 10. If hint is async and alreadyAsync is false, then
+    //     a. Return CreateAsyncFromSyncIterator(iteratorRecord).
+    if !already_async {
+        return Ok(AsyncFromSyncIterator::create(iterator_record, context));
+    }
+
+    // 11. Return iteratorRecord.
+    Ok(iterator_record)
+}
+
+/// The operation of an async iterator method that consumes the iterator.
+#[derive(Debug, Clone, Trace, Finalize)]
+enum ConsumerKind {
+    /// `AsyncIterator.prototype.reduce`, with no `accumulator` until the first value of the
+    /// iterator is read if there's no initial value.
+    Reduce {
+        reducer: JsObject,
+        accumulator: Option<JsValue>,
+    },
+    /// `AsyncIterator.prototype.toArray`.
+    ToArray { items: JsObject },
+    /// `AsyncIterator.prototype.forEach`.
+    ForEach { procedure: JsObject },
+    /// `AsyncIterator.prototype.some`.
+    Some { predicate: JsObject },
+    /// `AsyncIterator.prototype.every`.
+    Every { predicate: JsObject },
+    /// `AsyncIterator.prototype.find`.
+    Find { predicate: JsObject },
+}
+
+/// The state of the async closure of an async iterator method that consumes the iterator.
+#[derive(Debug, Clone, Trace, Finalize)]
+struct Consumer {
+    iterated: IteratorRecord,
+    kind: ConsumerKind,
+    counter: u64,
+}
+
+impl Consumer {
+    /// Starts consuming `iterated`, returning the promise for the result of the closure, as in
+    /// `AsyncFunctionStart(promiseCapability, closure)`.
+    fn start(
+        iterated: IteratorRecord,
+        kind: ConsumerKind,
+        counter: u64,
+        context: &mut Context<'_>,
+    ) -> JsValue {
+        let capability = PromiseCapability::new(
+            &context.intrinsics().constructors().promise().constructor(),
+            context,
+        )
+        .expect("cannot fail with the %Promise% constructor");
+
+        let result = Self {
+            iterated,
+            kind,
+            counter,
+        }
+        .step(context);
+
+        let result = match result {
+            Ok(result) => capability
+                .resolve()
+                .call(&JsValue::undefined(), &[result], context),
+            Err(err) => {
+                let err = err.to_opaque(context);
+                capability
+                    .reject()
+                    .call(&JsValue::undefined(), &[err], context)
+            }
+        };
+        result.expect("calling the resolving functions of %Promise% cannot fail");
+
+        capability.promise().clone().into()
+    }
+
+    /// Requests the next value of the iterator, continuing in [`Consumer::on_next`] after it has
+    /// been awaited.
+    fn step(self, context: &mut Context<'_>) -> JsResult<JsValue> {
+        // Let next be ? Await(? IteratorNext(iterated)).
+        let next = self.iterated.next_method().call(
+            &self.iterated.iterator().clone().into(),
+            &[],
+            context,
+        )?;
+        await_then(next, self, Self::on_next, reject, context)
+    }
+
+    /// Continues the closure with the awaited result of the `next` method of the iterator.
+    #[allow(clippy::needless_pass_by_value)]
+    fn on_next(next: JsValue, consumer: &Self, context: &mut Context<'_>) -> JsResult<JsValue> {
+        let Some(next) = next.as_object() else {
+            return Err(JsNativeError::typ()
+                .with_message("next value should be an object")
+                .into());
+        };
+        let next = IteratorResult::new(next.clone());
+
+        // If ? IteratorComplete(next) is true, then
+        if next.complete(context)? {
+            return match &consumer.kind {
+                // reduce: Return accumulator.
+                ConsumerKind::Reduce {
+                    accumulator: Some(accumulator),
+                    ..
+                } => Ok(accumulator.clone()),
+                // reduce: If ? IteratorComplete(next) is true, throw a TypeError exception.
+                ConsumerKind::Reduce {
+                    accumulator: None, ..
+                } => Err(JsNativeError::typ()
+                    .with_message(
+                        "AsyncIterator.prototype.reduce: cannot reduce an empty iterator without an initial value",
+                    )
+                    .into()),
+                // toArray: Return CreateArrayFromList(items).
+                ConsumerKind::ToArray { items } => Ok(items.clone().into()),
+                // forEach, find: Return undefined.
+                ConsumerKind::ForEach { .. } | ConsumerKind::Find { .. } => {
+                    Ok(JsValue::undefined())
+                }
+                // some: Return false.
+                ConsumerKind::Some { .. } => Ok(false.into()),
+                // every: Return true.
+                ConsumerKind::Every { .. } => Ok(true.into()),
+            };
+        }
+
+        // Let value be ? IteratorValue(next).
+        let value = next.value(context)?;
+
+        let mut consumer = consumer.clone();
+        let counter = consumer.counter;
+        consumer.counter += 1;
+
+        let result = match &mut consumer.kind {
+            ConsumerKind::Reduce {
+                accumulator: accumulator @ None,
+                ..
+            } => {
+                // iii. Let accumulator be ? IteratorValue(next).
+                *accumulator = Some(value);
+                return consumer.step(context);
+            }
+            ConsumerKind::ToArray { items } => {
+                // Append value to items.
+                items
+                    .create_data_property_or_throw(counter, value, context)
+                    .expect("defining an index of a fresh array cannot fail");
+                return consumer.step(context);
+            }
+            // Let result be Completion(Call(reducer, undefined, « accumulator, value, 𝔽(counter) »)).
+            ConsumerKind::Reduce {
+                reducer,
+                accumulator: Some(accumulator),
+            } => reducer.call(
+                &JsValue::undefined(),
+                &[accumulator.clone(), value.clone(), counter.into()],
+                context,
+            ),
+            // Let r be Completion(Call(fn, undefined, « value, 𝔽(counter) »)).
+            ConsumerKind::ForEach { procedure } => procedure.call(
+                &JsValue::undefined(),
+                &[value.clone(), counter.into()],
+                context,
+            ),
+            // Let result be Completion(Call(predicate, undefined, « value, 𝔽(counter) »)).
+            ConsumerKind::Some { predicate }
+            | ConsumerKind::Every { predicate }
+            | ConsumerKind::Find { predicate } => predicate.call(
+                &JsValue::undefined(),
+                &[value.clone(), counter.into()],
+                context,
+            ),
+        };
+
+        // IfAbruptCloseAsyncIterator(result, iterated).
+        let result = if_abrupt_close_async_iterator!(result, consumer.iterated, context);
+
+        // Set result to Completion(Await(result)).
+        // IfAbruptCloseAsyncIterator(result, iterated).
+        await_then(
+            result,
+            (consumer, value),
+            |result, (consumer, value), context| {
+                let mut consumer = consumer.clone();
+                let completion = match &mut consumer.kind {
+                    // Set accumulator to result.
+                    ConsumerKind::Reduce { accumulator, .. } => {
+                        *accumulator = Some(result);
+                        None
+                    }
+                    // If ToBoolean(result) is true, return ? AsyncIteratorClose(iterated, NormalCompletion(true)).
+                    ConsumerKind::Some { .. } if result.to_boolean() => Some(true.into()),
+                    // If ToBoolean(result) is false, return ? AsyncIteratorClose(iterated, NormalCompletion(false)).
+                    ConsumerKind::Every { .. } if !result.to_boolean() => Some(false.into()),
+                    // If ToBoolean(result) is true, return ? AsyncIteratorClose(iterated, NormalCompletion(value)).
+                    ConsumerKind::Find { .. } if result.to_boolean() => Some(value.clone()),
+                    _ => None,
+                };
+
+                match completion {
+                    Some(completion) => {
+                        async_iterator_close(consumer.iterated.iterator(), Ok(completion), context)
+                    }
+                    None => consumer.step(context),
+                }
+            },
+            |error, (consumer, _), context| {
+                async_iterator_close(consumer.iterated.iterator(), Err(error), context)
+            },
+            context,
+        )
+    }
+}
+
+/// The internal representation of the `%WrapForValidAsyncIteratorPrototype%` objects returned
+/// by `AsyncIterator.from`.
+///
+/// More information:
+///  - [Async Iterator Helpers proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-async-iterator-helpers/#sec-wrapforvalidasynciteratorprototype-object
+#[derive(Debug, Clone, Finalize, Trace)]
+pub struct WrapForValidAsyncIterator {
+    /// The `[[AsyncIterated]]` internal slot.
+    async_iterated: IteratorRecord,
+}
+
+impl IntrinsicObject for WrapForValidAsyncIterator {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event("WrapForValidAsyncIteratorPrototype", "init");
+
+        BuiltInBuilder::with_intrinsic::<Self>(realm)
+            .prototype(
+                realm
+                    .intrinsics()
+                    .constructors()
+                    .async_iterator()
+                    .prototype(),
+            )
+            .static_method(Self::next, "next", 0)
+            .static_method(Self::r#return, "return", 0)
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        intrinsics
+            .objects()
+            .iterator_prototypes()
+            .wrap_for_valid_async_iterator()
+    }
+}
+
+impl WrapForValidAsyncIterator {
+    /// Returns the `[[AsyncIterated]]` record of the `this` value.
+    fn async_iterated(this: &JsValue, method: &str) -> JsResult<IteratorRecord> {
+        this.as_object()
+            .and_then(|o| {
+                o.borrow()
+                    .as_wrap_for_valid_async_iterator()
+                    .map(|wrapper| wrapper.async_iterated.clone())
+            })
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message(format!(
+                        "%WrapForValidAsyncIteratorPrototype%.{method}: `this` is not a wrapped async iterator"
+                    ))
+                    .into()
+            })
+    }
+
+    /// `%WrapForValidAsyncIteratorPrototype%.next ( )`
+    ///
+    /// More information:
+    ///  - [Async Iterator Helpers proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-async-iterator-helpers/#sec-wrapforvalidasynciteratorprototype.next
+    fn next(this: &JsValue, _: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Let O be this value.
+        // 2. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+        let promise_capability = PromiseCapability::new(
+            &context.intrinsics().constructors().promise().constructor(),
+            context,
+        )
+        .expect("cannot fail with the %Promise% constructor");
+
+        // 3. Let result be Completion(RequireInternalSlot(O, [[AsyncIterated]])).
+        // 4. IfAbruptRejectPromise(result, promiseCapability).
+        let iterator_record = Self::async_iterated(this, "next");
+        if_abrupt_reject_promise!(iterator_record, promise_capability, context);
+
+        // 5. Let iteratorRecord be O.[[AsyncIterated]].
+        // 6. Let result be Completion(Call(iteratorRecord.[[NextMethod]], iteratorRecord.[[Iterator]])).
+        // 7. IfAbruptRejectPromise(result, promiseCapability).
+        let result = iterator_record.next_method().call(
+            &iterator_record.iterator().clone().into(),
+            &[],
+            context,
+        );
+        if_abrupt_reject_promise!(result, promise_capability, context);
+
+        // 8. Perform ! Call(promiseCapability.[[Resolve]], undefined, « result »).
+        promise_capability
+            .resolve()
+            .call(&JsValue::undefined(), &[result], context)?;
+
+        // 9. Return promiseCapability.[[Promise]].
+        Ok(promise_capability.promise().clone().into())
+    }
+
+    /// `%WrapForValidAsyncIteratorPrototype%.return ( )`
+    ///
+    /// More information:
+    ///  - [Async Iterator Helpers proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-async-iterator-helpers/#sec-wrapforvalidasynciteratorprototype.return
+    fn r#return(this: &JsValue, _: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Let O be this value.
+        // 2. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+        let promise_capability = PromiseCapability::new(
+            &context.intrinsics().constructors().promise().constructor(),
+            context,
+        )
+        .expect("cannot fail with the %Promise% constructor");
+
+        // 3. Let result be Completion(RequireInternalSlot(O, [[AsyncIterated]])).
+        // 4. IfAbruptRejectPromise(result, promiseCapability).
+        let iterator_record = Self::async_iterated(this, "return");
+        if_abrupt_reject_promise!(iterator_record, promise_capability, context);
+
+        // 5. Let iterator be O.[[AsyncIterated]].[[Iterator]].
+        let iterator = iterator_record.iterator().clone();
+
+        // 6. Let returnMethod be Completion(GetMethod(iterator, "return")).
+        // 7. IfAbruptRejectPromise(returnMethod, promiseCapability).
+        let return_method = iterator.get_method(js_string!("return"), context);
+        if_abrupt_reject_promise!(return_method, promise_capability, context);
+
+        let result = match return_method {
+            // 8. If returnMethod is undefined, then
+            //     a. Let iterResult be CreateIterResultObject(undefined, true).
+            //     b. Perform ! Call(promiseCapability.[[Resolve]], undefined, « iterResult »).
+            //     c. Return promiseCapability.[[Promise]].
+            None => create_iter_result_object(JsValue::undefined(), true, context),
+            // 9. Let result be Completion(Call(returnMethod, iterator)).
+            // 10. IfAbruptRejectPromise(result, promiseCapability).
+            Some(return_method) => {
+                let result = return_method.call(&iterator.into(), &[], context);
+                if_abrupt_reject_promise!(result, promise_capability, context);
+                result
+            }
+        };
+
+        // 11. Perform ! Call(promiseCapability.[[Resolve]], undefined, « result »).
+        promise_capability
+            .resolve()
+            .call(&JsValue::undefined(), &[result], context)?;
+
+        // 12. Return promiseCapability.[[Promise]].
+        Ok(promise_capability.promise().clone().into())
+    }
+}
@@ -0,0 +1,863 @@
+//! Boa's implementation of the `%AsyncIteratorHelperPrototype%` object and the async iterators
+//! it produces.
+//!
+//! More information:
+//!  - [Async Iterator Helpers proposal][spec]
+//!
+//! [spec]: https://tc39.es/proposal-async-iterator-helpers/#sec-asynciteratorhelperprototype
+
+use crate::{
+    builtins::{
+        iterable::{
+            async_iterator::get_async_iterator_flattenable, create_iter_result_object,
+            IteratorRecord, IteratorResult, PrimitiveHandling,
+        },
+        promise::PromiseCapability,
+        BuiltInBuilder, IntrinsicObject, Promise,
+    },
+    context::intrinsics::Intrinsics,
+    js_string,
+    native_function::NativeFunction,
+    object::{FunctionObjectBuilder, JsObject, ObjectData},
+    property::Attribute,
+    realm::Realm,
+    symbol::JsSymbol,
+    Context, JsArgs, JsError, JsNativeError, JsResult, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+
+/// `IfAbruptCloseAsyncIterator ( value, iteratorRecord )`
+///
+/// Returns the promise for closing the iterator with the error of `value` if `value` is an
+/// abrupt completion, so that it rejects with the error once the iterator has been closed.
+///
+/// More information:
+///  - [Async Iterator Helpers proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-async-iterator-helpers/#sec-ifabruptcloseasynciterator
+macro_rules! if_abrupt_close_async_iterator {
+    ($value:expr, $iterator_record:expr, $context:expr) => {
+        match $value {
+            // 1. If value is an abrupt completion, return ? AsyncIteratorClose(iteratorRecord, value).
+            Err(err) => {
+                return $crate::builtins::iterable::async_iterator_close(
+                    $iterator_record.iterator(),
+                    Err(err),
+                    $context,
+                )
+            }
+            // 2. Else if value is a Completion Record, set value to value.
+            Ok(value) => value,
+        }
+    };
+}
+
+pub(crate) use if_abrupt_close_async_iterator;
+
+/// The `[[AsyncGeneratorState]]` of an async iterator helper.
+///
+/// Since the requests to a helper are processed one at a time, the helper doesn't need to
+/// distinguish between `executing` and `awaiting-return`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HelperState {
+    SuspendedStart,
+    SuspendedYield,
+    Executing,
+    Completed,
+}
+
+/// The abstract closure of an async iterator helper, along with the state it keeps between
+/// yields.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub(crate) enum AsyncHelperClosure {
+    /// The closure of `AsyncIterator.prototype.map`.
+    Map { mapper: JsObject },
+
+    /// The closure of `AsyncIterator.prototype.filter`.
+    Filter { predicate: JsObject },
+
+    /// The closure of `AsyncIterator.prototype.take`.
+    ///
+    /// A `remaining` of `None` represents `+∞`.
+    Take { remaining: Option<u64> },
+
+    /// The closure of `AsyncIterator.prototype.drop`.
+    ///
+    /// A `remaining` of `None` represents `+∞`.
+    Drop { remaining: Option<u64> },
+
+    /// The closure of `AsyncIterator.prototype.flatMap`.
+    FlatMap {
+        mapper: JsObject,
+        inner: Option<IteratorRecord>,
+    },
+}
+
+/// A request made to an async iterator helper, waiting for the previous requests to finish.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Request {
+    Next,
+    Return,
+}
+
+/// The internal representation of the async iterator objects returned by the async iterator
+/// helpers.
+///
+/// More information:
+///  - [Async Iterator Helpers proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-async-iterator-helpers/#sec-asynciteratorhelperprototype
+#[derive(Debug, Finalize, Trace)]
+pub struct AsyncIteratorHelper {
+    /// The `[[UnderlyingIterator]]` internal slot.
+    underlying: IteratorRecord,
+
+    /// The closure of the helper.
+    closure: AsyncHelperClosure,
+
+    /// The number of values the closure has processed so far.
+    counter: u64,
+
+    /// The `[[AsyncGeneratorState]]` internal slot.
+    #[unsafe_ignore_trace]
+    state: HelperState,
+
+    /// The promise of the last request made to the helper, which must settle before the next
+    /// request starts, like the `[[AsyncGeneratorQueue]]` of async generators.
+    last_request: Option<JsObject>,
+}
+
+impl IntrinsicObject for AsyncIteratorHelper {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event("AsyncIteratorHelperPrototype", "init");
+
+        BuiltInBuilder::with_intrinsic::<Self>(realm)
+            .prototype(
+                realm
+                    .intrinsics()
+                    .constructors()
+                    .async_iterator()
+                    .prototype(),
+            )
+            .static_method(Self::next, "next", 0)
+            .static_method(Self::r#return, "return", 0)
+            .static_property(
+                JsSymbol::to_string_tag(),
+                js_string!("Async Iterator Helper"),
+                Attribute::CONFIGURABLE,
+            )
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        intrinsics
+            .objects()
+            .iterator_prototypes()
+            .async_iterator_helper()
+    }
+}
+
+impl AsyncIteratorHelper {
+    /// Creates a new async iterator helper object, as in the last steps of the async iterator
+    /// helper methods.
+    pub(crate) fn create(
+        underlying: IteratorRecord,
+        closure: AsyncHelperClosure,
+        context: &Context<'_>,
+    ) -> JsObject {
+        JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            context
+                .intrinsics()
+                .objects()
+                .iterator_prototypes()
+                .async_iterator_helper(),
+            ObjectData::async_iterator_helper(Self {
+                underlying,
+                closure,
+                counter: 0,
+                state: HelperState::SuspendedStart,
+                last_request: None,
+            }),
+        )
+    }
+
+    /// `%AsyncIteratorHelperPrototype%.next ( )`
+    ///
+    /// More information:
+    ///  - [Async Iterator Helpers proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-async-iterator-helpers/#sec-asynciteratorhelperprototype.next
+    fn next(this: &JsValue, _: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Return AsyncGeneratorEnqueue(this value, NormalCompletion(undefined), "Async Iterator Helper").
+        Self::enqueue(this, Request::Next, context)
+    }
+
+    /// `%AsyncIteratorHelperPrototype%.return ( )`
+    ///
+    /// More information:
+    ///  - [Async Iterator Helpers proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-async-iterator-helpers/#sec-asynciteratorhelperprototype.return
+    fn r#return(this: &JsValue, _: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Let O be this value.
+        // 2. Let C be Completion { [[Type]]: return, [[Value]]: undefined, [[Target]]: empty }.
+        // 3. Return AsyncGeneratorEnqueue(O, C, "Async Iterator Helper").
+        Self::enqueue(this, Request::Return, context)
+    }
+
+    /// Queues `request` on the helper, running it once all the previous requests have settled.
+    fn enqueue(this: &JsValue, request: Request, context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+        let capability = PromiseCapability::new(
+            &context.intrinsics().constructors().promise().constructor(),
+            context,
+        )
+        .expect("cannot fail with the %Promise% constructor");
+        let promise = capability.promise().clone();
+
+        // 2. Let check be Completion(AsyncGeneratorValidate(generator, generatorBrand)).
+        // 3. If check is an abrupt completion, then
+        //     a. Let badGeneratorError be a newly created TypeError object.
+        //     b. Perform ! Call(promiseCapability.[[Reject]], undefined, « badGeneratorError »).
+        //     c. Return promiseCapability.[[Promise]].
+        let Some(object) = this
+            .as_object()
+            .filter(|object| object.borrow().as_async_iterator_helper().is_some())
+        else {
+            let error = JsNativeError::typ()
+                .with_message("`this` is not an Async Iterator Helper object")
+                .to_opaque(context);
+            capability
+                .reject()
+                .call(&JsValue::undefined(), &[error.into()], context)?;
+            return Ok(promise.into());
+        };
+
+        // 4. Let queue be generator.[[AsyncGeneratorQueue]].
+        // 5. Let request be AsyncGeneratorRequest { [[Completion]]: completion, [[Capability]]: promiseCapability }.
+        // 6. Append request to queue.
+        let previous = object
+            .borrow_mut()
+            .as_async_iterator_helper_mut()
+            .expect("must be an async iterator helper")
+            .last_request
+            .replace(promise.clone());
+
+        // 7. Let state be generator.[[AsyncGeneratorState]].
+        // 8. If state is not executing, then
+        //     a. Perform AsyncGeneratorResumeNext(generator).
+        match previous {
+            None => Self::run(object, request, &capability, context)?,
+            Some(previous) => {
+                let run = FunctionObjectBuilder::new(
+                    context,
+                    NativeFunction::from_copy_closure_with_captures(
+                        move |_, _, (object, capability), context| {
+                            Self::run(object, request, capability, context)?;
+                            Ok(JsValue::undefined())
+                        },
+                        (object.clone(), capability),
+                    ),
+                )
+                .build();
+                Promise::perform_promise_then(
+                    &previous,
+                    Some(run.clone()),
+                    Some(run),
+                    None,
+                    context,
+                );
+            }
+        }
+
+        // 9. Return promiseCapability.[[Promise]].
+        Ok(promise.into())
+    }
+
+    /// Runs `request` on the helper, settling `capability` with its result.
+    fn run(
+        object: &JsObject,
+        request: Request,
+        capability: &PromiseCapability,
+        context: &mut Context<'_>,
+    ) -> JsResult<()> {
+        let (state, underlying, closure) = {
+            let mut object = object.borrow_mut();
+            let helper = object
+                .as_async_iterator_helper_mut()
+                .expect("must be an async iterator helper");
+            let state = helper.state;
+            helper.state = match (state, request) {
+                (HelperState::Completed, _) | (HelperState::SuspendedStart, Request::Return) => {
+                    HelperState::Completed
+                }
+                _ => HelperState::Executing,
+            };
+            (state, helper.underlying.clone(), helper.closure.clone())
+        };
+
+        let result = match (state, request) {
+            // AsyncGeneratorAwaitReturn ( generator )
+            // AsyncGeneratorCompleteStep ( generator, completion, true )
+            (HelperState::Completed, _) => Ok(create_iter_result_object(
+                JsValue::undefined(),
+                true,
+                context,
+            )),
+            (HelperState::Executing, _) => Err(JsNativeError::typ()
+                .with_message("the Async Iterator Helper is already running")
+                .into()),
+            // %AsyncIteratorHelperPrototype%.return ( )
+            // 4. If O.[[AsyncGeneratorState]] is suspended-start, then
+            //     a. Set O.[[AsyncGeneratorState]] to completed.
+            //     b. Let result be Completion(AsyncIteratorClose(O.[[UnderlyingIterator]], NormalCompletion(unused))).
+            //     c. IfAbruptRejectPromise(result, promiseCapability).
+            //     d. Perform ! Call(promiseCapability.[[Resolve]], undefined, « CreateIterResultObject(undefined, true) »).
+            (HelperState::SuspendedStart, Request::Return) => {
+                async_iterator_close(underlying.iterator(), Ok(JsValue::undefined()), context)
+                    .and_then(|result| {
+                        await_then(
+                            result,
+                            (),
+                            |_, (), context| Ok(done(context)),
+                            reject,
+                            context,
+                        )
+                    })
+            }
+            (HelperState::SuspendedYield, Request::Return) => {
+                closure.resume_return(&underlying, context)
+            }
+            (_, Request::Next) => Self::resume(object, context),
+        };
+
+        let result = result.and_then(|result| {
+            await_then(
+                result,
+                (object.clone(), capability.clone()),
+                |result, (object, capability), context| {
+                    let done = result
+                        .as_object()
+                        .map(|result| IteratorResult::new(result.clone()).complete(context))
+                        .transpose()?
+                        .unwrap_or_default();
+                    Self::finish(
+                        object,
+                        if done {
+                            HelperState::Completed
+                        } else {
+                            HelperState::SuspendedYield
+                        },
+                        capability,
+                    );
+                    capability
+                        .resolve()
+                        .call(&JsValue::undefined(), &[result], context)
+                },
+                |error, (object, capability), context| {
+                    Self::finish(object, HelperState::Completed, capability);
+                    let error = error.to_opaque(context);
+                    capability
+                        .reject()
+                        .call(&JsValue::undefined(), &[error], context)
+                },
+                context,
+            )
+        });
+
+        if let Err(error) = result {
+            Self::finish(object, HelperState::Completed, capability);
+            let error = error.to_opaque(context);
+            capability
+                .reject()
+                .call(&JsValue::undefined(), &[error], context)?;
+        }
+
+        Ok(())
+    }
+
+    /// Updates the state of the helper after a request settles.
+    fn finish(object: &JsObject, state: HelperState, capability: &PromiseCapability) {
+        let mut object = object.borrow_mut();
+        let helper = object
+            .as_async_iterator_helper_mut()
+            .expect("must be an async iterator helper");
+        if helper.state == HelperState::Executing {
+            helper.state = state;
+        }
+        if helper.last_request.as_ref() == Some(capability.promise()) {
+            helper.last_request = None;
+        }
+    }
+
+    /// Resumes the closure of the helper until its next `Yield`, returning the promise for the
+    /// iterator result of the request.
+    fn resume(object: &JsObject, context: &mut Context<'_>) -> JsResult<JsValue> {
+        let (underlying, closure) = {
+            let mut object = object.borrow_mut();
+            let helper = object
+                .as_async_iterator_helper_mut()
+                .expect("must be an async iterator helper");
+
+            if let AsyncHelperClosure::Take { remaining } = &mut helper.closure {
+                match remaining {
+                    // i. If remaining = 0, then
+                    //     1. Return ? AsyncIteratorClose(iterated, NormalCompletion(undefined)).
+                    Some(0) => {
+                        let iterator = helper.underlying.iterator().clone();
+                        drop(object);
+                        let result =
+                            async_iterator_close(&iterator, Ok(JsValue::undefined()), context)?;
+                        return await_then(
+                            result,
+                            (),
+                            |_, (), context| Ok(done(context)),
+                            reject,
+                            context,
+                        );
+                    }
+                    // ii. If remaining ≠ +∞, then
+                    //     1. Set remaining to remaining - 1.
+                    Some(remaining) => *remaining -= 1,
+                    None => {}
+                }
+            }
+
+            (helper.underlying.clone(), helper.closure.clone())
+        };
+
+        // viii. Repeat, while innerAlive is true,
+        //     1. Let innerNext be Completion(IteratorNext(innerIterator)).
+        //     2. IfAbruptCloseAsyncIterator(innerNext, iterated).
+        //     3. Set innerNext to Completion(Await(innerNext)).
+        //     4. IfAbruptCloseAsyncIterator(innerNext, iterated).
+        if let AsyncHelperClosure::FlatMap {
+            inner: Some(inner), ..
+        } = &closure
+        {
+            let inner_next =
+                inner
+                    .next_method()
+                    .call(&inner.iterator().clone().into(), &[], context);
+            let inner_next = if_abrupt_close_async_iterator!(inner_next, &underlying, context);
+            return await_then(
+                inner_next,
+                object.clone(),
+                Self::on_inner_next,
+                |error, object, context| {
+                    let underlying = Self::underlying(object);
+                    async_iterator_close(underlying.iterator(), Err(error), context)
+                },
+                context,
+            );
+        }
+
+        // Let next be ? Await(? IteratorNext(iterated)).
+        let next =
+            underlying
+                .next_method()
+                .call(&underlying.iterator().clone().into(), &[], context)?;
+        await_then(next, object.clone(), Self::on_next, reject, context)
+    }
+
+    /// Returns the `[[UnderlyingIterator]]` of the helper.
+    fn underlying(object: &JsObject) -> IteratorRecord {
+        object
+            .borrow()
+            .as_async_iterator_helper()
+            .expect("must be an async iterator helper")
+            .underlying
+            .clone()
+    }
+
+    /// Increments the counter of the helper, returning its previous value.
+    fn increment_counter(object: &JsObject) -> u64 {
+        let mut object = object.borrow_mut();
+        let helper = object
+            .as_async_iterator_helper_mut()
+            .expect("must be an async iterator helper");
+        helper.counter += 1;
+        helper.counter - 1
+    }
+
+    /// Continues the closure of the helper with the awaited result of the `next` method of the
+    /// underlying iterator.
+    #[allow(clippy::needless_pass_by_value)]
+    fn on_next(next: JsValue, object: &JsObject, context: &mut Context<'_>) -> JsResult<JsValue> {
+        // If next is not an Object, throw a TypeError exception.
+        let Some(next) = next.as_object() else {
+            return Err(JsNativeError::typ()
+                .with_message("next value should be an object")
+                .into());
+        };
+        let next = IteratorResult::new(next.clone());
+
+        // If ? IteratorComplete(next) is true, return undefined.
+        if next.complete(context)? {
+            let mut object = object.borrow_mut();
+            let helper = object
+                .as_async_iterator_helper_mut()
+                .expect("must be an async iterator helper");
+            helper.underlying.set_done(true);
+            return Ok(done(context));
+        }
+
+        let (underlying, closure) = {
+            let object = object.borrow();
+            let helper = object
+                .as_async_iterator_helper()
+                .expect("must be an async iterator helper");
+            (helper.underlying.clone(), helper.closure.clone())
+        };
+
+        // `drop` must not read the values it skips.
+        let skip = {
+            let mut object = object.borrow_mut();
+            let helper = object
+                .as_async_iterator_helper_mut()
+                .expect("must be an async iterator helper");
+            match &mut helper.closure {
+                // a. Repeat, while remaining > 0,
+                //     i. If remaining ≠ +∞, then
+                //         1. Set remaining to remaining - 1.
+                AsyncHelperClosure::Drop {
+                    remaining: Some(remaining),
+                } if *remaining > 0 => {
+                    *remaining -= 1;
+                    true
+                }
+                AsyncHelperClosure::Drop { remaining: None } => true,
+                _ => false,
+            }
+        };
+        if skip {
+            return Self::resume(object, context);
+        }
+
+        // Let value be ? IteratorValue(next).
+        let value = next.value(context)?;
+
+        match &closure {
+            AsyncHelperClosure::Map { mapper } => {
+                // iii. Let mapped be Completion(Call(mapper, undefined, « value, 𝔽(counter) »)).
+                let counter = Self::increment_counter(object);
+                let mapped = mapper.call(&JsValue::undefined(), &[value, counter.into()], context);
+
+                // iv. IfAbruptCloseAsyncIterator(mapped, iterated).
+                let mapped = if_abrupt_close_async_iterator!(mapped, &underlying, context);
+
+                // v. Set mapped to Completion(Await(mapped)).
+                // vi. IfAbruptCloseAsyncIterator(mapped, iterated).
+                // vii. Let completion be Completion(Yield(mapped)).
+                await_then(
+                    mapped,
+                    underlying,
+                    |mapped, _, context| Ok(create_iter_result_object(mapped, false, context)),
+                    |error, underlying, context| {
+                        async_iterator_close(underlying.iterator(), Err(error), context)
+                    },
+                    context,
+                )
+            }
+            AsyncHelperClosure::Filter { predicate } => {
+                // iii. Let selected be Completion(Call(predicate, undefined, « value, 𝔽(counter) »)).
+                let counter = Self::increment_counter(object);
+                let selected = predicate.call(
+                    &JsValue::undefined(),
+                    &[value.clone(), counter.into()],
+                    context,
+                );
+
+                // iv. IfAbruptCloseAsyncIterator(selected, iterated).
+                let selected = if_abrupt_close_async_iterator!(selected, &underlying, context);
+
+                // v. Set selected to Completion(Await(selected)).
+                // vi. IfAbruptCloseAsyncIterator(selected, iterated).
+                // vii. If ToBoolean(selected) is true, then
+                //     1. Let completion be Completion(Yield(value)).
+                await_then(
+                    selected,
+                    (object.clone(), value),
+                    |selected, (object, value), context| {
+                        if selected.to_boolean() {
+                            Ok(create_iter_result_object(value.clone(), false, context))
+                        } else {
+                            Self::resume(object, context)
+                        }
+                    },
+                    |error, (object, _), context| {
+                        let underlying = Self::underlying(object);
+                        async_iterator_close(underlying.iterator(), Err(error), context)
+                    },
+                    context,
+                )
+            }
+            // v. Let completion be Completion(Yield(value)).
+            AsyncHelperClosure::Take { .. } | AsyncHelperClosure::Drop { .. } => {
+                Ok(create_iter_result_object(value, false, context))
+            }
+            AsyncHelperClosure::FlatMap { mapper, .. } => {
+                // iii. Let mapped be Completion(Call(mapper, undefined, « value, 𝔽(counter) »)).
+                let counter = Self::increment_counter(object);
+                let mapped = mapper.call(&JsValue::undefined(), &[value, counter.into()], context);
+
+                // iv. IfAbruptCloseAsyncIterator(mapped, iterated).
+                let mapped = if_abrupt_close_async_iterator!(mapped, &underlying, context);
+
+                // v. Set mapped to Completion(Await(mapped)).
+                // vi. IfAbruptCloseAsyncIterator(mapped, iterated).
+                await_then(
+                    mapped,
+                    object.clone(),
+                    |mapped, object, context| {
+                        let underlying = Self::underlying(object);
+
+                        // vii. Let innerIterator be Completion(GetIteratorFlattenable(mapped, reject-primitives, async)).
+                        let inner = get_async_iterator_flattenable(
+                            &mapped,
+                            PrimitiveHandling::RejectPrimitives,
+                            context,
+                        );
+
+                        // viii. IfAbruptCloseAsyncIterator(innerIterator, iterated).
+                        let inner = if_abrupt_close_async_iterator!(inner, &underlying, context);
+
+                        // ix. Let innerAlive be true.
+                        if let AsyncHelperClosure::FlatMap { inner: slot, .. } = &mut object
+                            .borrow_mut()
+                            .as_async_iterator_helper_mut()
+                            .expect("must be an async iterator helper")
+                            .closure
+                        {
+                            *slot = Some(inner);
+                        }
+                        Self::resume(object, context)
+                    },
+                    |error, object, context| {
+                        let underlying = Self::underlying(object);
+                        async_iterator_close(underlying.iterator(), Err(error), context)
+                    },
+                    context,
+                )
+            }
+        }
+    }
+
+    /// Continues the closure of a `flatMap` helper with the awaited result of the `next` method
+    /// of the inner iterator.
+    #[allow(clippy::needless_pass_by_value)]
+    fn on_inner_next(
+        inner_next: JsValue,
+        object: &JsObject,
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        let underlying = Self::underlying(object);
+
+        // 5. Let innerComplete be Completion(IteratorComplete(innerNext)).
+        // 6. IfAbruptCloseAsyncIterator(innerComplete, iterated).
+        let inner_next = inner_next.as_object().cloned().ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("next value should be an object")
+                .into()
+        });
+        let inner_next = if_abrupt_close_async_iterator!(inner_next, &underlying, context);
+        let inner_next = IteratorResult::new(inner_next);
+        let inner_complete = inner_next.complete(context);
+        let inner_complete = if_abrupt_close_async_iterator!(inner_complete, &underlying, context);
+
+        // 7. If innerComplete is true, set innerAlive to false.
+        if inner_complete {
+            if let AsyncHelperClosure::FlatMap { inner, .. } = &mut object
+                .borrow_mut()
+                .as_async_iterator_helper_mut()
+                .expect("must be an async iterator helper")
+                .closure
+            {
+                *inner = None;
+            }
+            return Self::resume(object, context);
+        }
+
+        // 8. Else,
+        //     a. Let innerValue be Completion(IteratorValue(innerNext)).
+        //     b. IfAbruptCloseAsyncIterator(innerValue, iterated).
+        //     c. Let completion be Completion(Yield(innerValue)).
+        let inner_value = inner_next.value(context);
+        let inner_value = if_abrupt_close_async_iterator!(inner_value, &underlying, context);
+        Ok(create_iter_result_object(inner_value, false, context))
+    }
+}
+
+impl AsyncHelperClosure {
+    /// Resumes the closure from its last `Yield` with a return completion, closing the
+    /// iterators it holds, and returns the promise for the iterator result of the request.
+    fn resume_return(
+        &self,
+        iterated: &IteratorRecord,
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        let result = if let Self::FlatMap {
+            inner: Some(inner), ..
+        } = self
+        {
+            // i. Let backupCompletion be Completion(AsyncIteratorClose(innerIterator, completion)).
+            // ii. IfAbruptCloseAsyncIterator(backupCompletion, iterated).
+            // iii. Return ? AsyncIteratorClose(iterated, completion).
+            let backup_completion =
+                async_iterator_close(inner.iterator(), Ok(JsValue::undefined()), context);
+            let backup_completion =
+                if_abrupt_close_async_iterator!(backup_completion, iterated, context);
+            await_then(
+                backup_completion,
+                iterated.clone(),
+                |_, iterated, context| {
+                    async_iterator_close(iterated.iterator(), Ok(JsValue::undefined()), context)
+                },
+                |error, iterated, context| {
+                    async_iterator_close(iterated.iterator(), Err(error), context)
+                },
+                context,
+            )?
+        } else {
+            // IfAbruptCloseAsyncIterator(completion, iterated).
+            async_iterator_close(iterated.iterator(), Ok(JsValue::undefined()), context)?
+        };
+
+        await_then(
+            result,
+            (),
+            |_, (), context| Ok(done(context)),
+            reject,
+            context,
+        )
+    }
+}
+
+/// Creates the `{ value: undefined, done: true }` iterator result of a completed helper.
+fn done(context: &mut Context<'_>) -> JsValue {
+    create_iter_result_object(JsValue::undefined(), true, context)
+}
+
+/// Rejection handler for [`await_then`] that rethrows the rejection reason.
+#[allow(clippy::unnecessary_wraps)]
+pub(crate) fn reject<T>(error: JsError, _: &T, _: &mut Context<'_>) -> JsResult<JsValue> {
+    Err(error)
+}
+
+/// Awaits `value` inside a promise reaction, continuing with `on_fulfilled` or `on_rejected`
+/// depending on how it settles.
+///
+/// Returns the promise for the result of the continuation, which can itself return a promise to
+/// await further values.
+pub(crate) fn await_then<T>(
+    value: JsValue,
+    captures: T,
+    on_fulfilled: fn(JsValue, &T, &mut Context<'_>) -> JsResult<JsValue>,
+    on_rejected: fn(JsError, &T, &mut Context<'_>) -> JsResult<JsValue>,
+    context: &mut Context<'_>,
+) -> JsResult<JsValue>
+where
+    T: Trace + Clone + 'static,
+{
+    let constructor = context.intrinsics().constructors().promise().constructor();
+    let promise = Promise::promise_resolve(&constructor, value, context)?;
+    let capability = PromiseCapability::new(&constructor, context)
+        .expect("cannot fail with the %Promise% constructor");
+
+    let on_fulfilled = FunctionObjectBuilder::new(
+        context,
+        NativeFunction::from_copy_closure_with_captures(
+            move |_, args, captures, context| {
+                on_fulfilled(args.get_or_undefined(0).clone(), captures, context)
+            },
+            captures.clone(),
+        ),
+    )
+    .length(1)
+    .build();
+    let on_rejected = FunctionObjectBuilder::new(
+        context,
+        NativeFunction::from_copy_closure_with_captures(
+            move |_, args, captures, context| {
+                on_rejected(
+                    JsError::from_opaque(args.get_or_undefined(0).clone()),
+                    captures,
+                    context,
+                )
+            },
+            captures,
+        ),
+    )
+    .length(1)
+    .build();
+
+    Promise::perform_promise_then(
+        &promise,
+        Some(on_fulfilled),
+        Some(on_rejected),
+        Some(capability.clone()),
+        context,
+    );
+    Ok(capability.promise().clone().into())
+}
+
+/// `AsyncIteratorClose ( iteratorRecord, completion )`
+///
+/// Returns a promise that settles with `completion` once the `return` method of `iterator` has
+/// been awaited, or `completion` itself if there's nothing to await.
+///
+/// More information:
+///  - [ECMA reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-asynciteratorclose
+pub(crate) fn async_iterator_close(
+    iterator: &JsObject,
+    completion: JsResult<JsValue>,
+    context: &mut Context<'_>,
+) -> JsResult<JsValue> {
+    // 3. Let innerResult be Completion(GetMethod(iterator, "return")).
+    // 4. If innerResult.[[Type]] is normal, then
+    //     a. Let return be innerResult.[[Value]].
+    //     b. If return is undefined, return ? completion.
+    //     c. Set innerResult to Completion(Call(return, iterator)).
+    //     d. If innerResult.[[Type]] is normal, set innerResult to Completion(Await(innerResult.[[Value]])).
+    // 5. If completion.[[Type]] is throw, return ? completion.
+    // 6. If innerResult.[[Type]] is throw, return ? innerResult.
+    let inner_result = match iterator.get_method(js_string!("return"), context) {
+        Ok(Some(r#return)) => r#return.call(&iterator.clone().into(), &[], context),
+        Ok(None) => return completion,
+        Err(err) => Err(err),
+    };
+    let inner_result = match inner_result {
+        Ok(inner_result) => inner_result,
+        Err(err) => {
+            completion?;
+            return Err(err);
+        }
+    };
+
+    await_then(
+        inner_result,
+        completion,
+        |inner_result, completion, _| {
+            let completion = completion.clone()?;
+
+            // 7. If innerResult.[[Value]] is not an Object, throw a TypeError exception.
+            if !inner_result.is_object() {
+                return Err(JsNativeError::typ()
+                    .with_message("inner result was not an object")
+                    .into());
+            }
+
+            // 8. Return ? completion.
+            Ok(completion)
+        },
+        |error, completion, _| {
+            completion.clone()?;
+            Err(error)
+        },
+        context,
+    )
+}
@@ -0,0 +1,925 @@
+//! Boa's implementation of the `Iterator` constructor and the iterator helpers of
+//! `%Iterator.prototype%`.
+//!
+//! More information:
+//!  - [ECMA reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-iterator-objects
+
+use crate::{
+    builtins::{
+        iterable::{
+            create_iter_result_object, get_iterator_direct, get_iterator_flattenable,
+            if_abrupt_close_iterator,
+            iterator_helper::{HelperClosure, IteratorHelper},
+            IteratorRecord, PrimitiveHandling,
+        },
+        Array, BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject,
+    },
+    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    js_string,
+    object::{internal_methods::get_prototype_from_constructor, JsObject, ObjectData, CONSTRUCTOR},
+    property::{Attribute, PropertyDescriptor, PropertyKey},
+    realm::Realm,
+    symbol::JsSymbol,
+    value::IntegerOrInfinity,
+    Context, JsArgs, JsError, JsNativeError, JsResult, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+
+/// The `Iterator` constructor and `%Iterator.prototype%` object.
+///
+/// More information:
+///  - [ECMA reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-%iteratorprototype%-object
+pub(crate) struct Iterator;
+
+impl IntrinsicObject for Iterator {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        let get_to_string_tag = BuiltInBuilder::callable(realm, Self::get_to_string_tag)
+            .name("get [Symbol.toStringTag]")
+            .build();
+        let set_to_string_tag = BuiltInBuilder::callable(realm, Self::set_to_string_tag)
+            .name("set [Symbol.toStringTag]")
+            .length(1)
+            .build();
+
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .static_method(Self::from, "from", 1)
+            .method(Self::map, "map", 1)
+            .method(Self::filter, "filter", 1)
+            .method(Self::take, "take", 1)
+            .method(Self::drop, "drop", 1)
+            .method(Self::flat_map, "flatMap", 1)
+            .method(Self::reduce, "reduce", 1)
+            .method(Self::to_array, "toArray", 0)
+            .method(Self::for_each, "forEach", 1)
+            .method(Self::some, "some", 1)
+            .method(Self::every, "every", 1)
+            .method(Self::find, "find", 1)
+            .method(
+                |v, _, _| Ok(v.clone()),
+                (JsSymbol::iterator(), js_string!("[Symbol.iterator]")),
+                0,
+            )
+            .accessor(
+                JsSymbol::to_string_tag(),
+                Some(get_to_string_tag),
+                Some(set_to_string_tag),
+                Attribute::CONFIGURABLE,
+            )
+            .build();
+
+        // `Iterator.prototype.constructor` is an accessor property instead of the usual data
+        // property, for web compatibility.
+        let get_constructor = BuiltInBuilder::callable(realm, Self::get_constructor)
+            .name("get constructor")
+            .build();
+        let set_constructor = BuiltInBuilder::callable(realm, Self::set_constructor)
+            .name("set constructor")
+            .length(1)
+            .build();
+        realm
+            .intrinsics()
+            .constructors()
+            .iterator()
+            .prototype()
+            .insert_property(
+                CONSTRUCTOR,
+                PropertyDescriptor::builder()
+                    .get(get_constructor)
+                    .set(set_constructor)
+                    .enumerable(false)
+                    .configurable(true),
+            );
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for Iterator {
+    const NAME: &'static str = "Iterator";
+}
+
+impl BuiltInConstructor for Iterator {
+    const LENGTH: usize = 0;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::iterator;
+
+    /// Constructor [`Iterator ( )`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator-constructor
+    fn constructor(
+        new_target: &JsValue,
+        _: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is either undefined or the active function object, throw a TypeError exception.
+        let active_function = context.intrinsics().constructors().iterator().constructor();
+        if new_target
+            .as_object()
+            .map_or(true, |new_target| *new_target == active_function)
+        {
+            return Err(JsNativeError::typ()
+                .with_message("Iterator: the constructor is abstract and cannot be called directly")
+                .into());
+        }
+
+        // 2. Return ? OrdinaryCreateFromConstructor(NewTarget, "%Iterator.prototype%").
+        let prototype =
+            get_prototype_from_constructor(new_target, StandardConstructors::iterator, context)?;
+        Ok(JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            ObjectData::ordinary(),
+        )
+        .into())
+    }
+}
+
+impl Iterator {
+    /// `Iterator.from ( O )`
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.from
+    fn from(_: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Let iteratorRecord be ? GetIteratorFlattenable(O, iterate-string-primitives).
+        let iterator_record = get_iterator_flattenable(
+            args.get_or_undefined(0),
+            PrimitiveHandling::IterateStrings,
+            context,
+        )?;
+
+        // 2. Let hasInstance be ? OrdinaryHasInstance(%Iterator%, iteratorRecord.[[Iterator]]).
+        let has_instance = JsValue::ordinary_has_instance(
+            &context
+                .intrinsics()
+                .constructors()
+                .iterator()
+                .constructor()
+                .into(),
+            &iterator_record.iterator().clone().into(),
+            context,
+        )?;
+
+        // 3. If hasInstance is true, then
+        if has_instance {
+            // a. Return iteratorRecord.[[Iterator]].
+            return Ok(iterator_record.iterator().clone().into());
+        }
+
+        // 4. Let wrapper be OrdinaryObjectCreate(%WrapForValidIteratorPrototype%, « [[Iterated]] »).
+        // 5. Set wrapper.[[Iterated]] to iteratorRecord.
+        // 6. Return wrapper.
+        Ok(JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            context
+                .intrinsics()
+                .objects()
+                .iterator_prototypes()
+                .wrap_for_valid_iterator(),
+            ObjectData::wrap_for_valid_iterator(WrapForValidIterator {
+                iterated: iterator_record,
+            }),
+        )
+        .into())
+    }
+
+    /// `get Iterator.prototype.constructor`
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-iterator.prototype.constructor
+    #[allow(clippy::unnecessary_wraps)]
+    fn get_constructor(_: &JsValue, _: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Return %Iterator%.
+        Ok(context
+            .intrinsics()
+            .constructors()
+            .iterator()
+            .constructor()
+            .into())
+    }
+
+    /// `set Iterator.prototype.constructor`
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-set-iterator.prototype.constructor
+    fn set_constructor(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. Perform ? SetterThatIgnoresPrototypeProperties(this value, %Iterator.prototype%, "constructor", v).
+        // 2. Return undefined.
+        setter_that_ignores_prototype_properties(
+            this,
+            CONSTRUCTOR.into(),
+            args.get_or_undefined(0).clone(),
+            context,
+        )?;
+        Ok(JsValue::undefined())
+    }
+
+    /// `get Iterator.prototype [ @@toStringTag ]`
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-iterator.prototype-@@tostringtag
+    #[allow(clippy::unnecessary_wraps)]
+    fn get_to_string_tag(_: &JsValue, _: &[JsValue], _: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Return "Iterator".
+        Ok(js_string!("Iterator").into())
+    }
+
+    /// `set Iterator.prototype [ @@toStringTag ]`
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-set-iterator.prototype-@@tostringtag
+    fn set_to_string_tag(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. Perform ? SetterThatIgnoresPrototypeProperties(this value, %Iterator.prototype%, @@toStringTag, v).
+        // 2. Return undefined.
+        setter_that_ignores_prototype_properties(
+            this,
+            JsSymbol::to_string_tag().into(),
+            args.get_or_undefined(0).clone(),
+            context,
+        )?;
+        Ok(JsValue::undefined())
+    }
+
+    /// `Iterator.prototype.map ( mapper )`
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.map
+    fn map(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let o = this_iterator(this, "map")?;
+
+        // 3. Let iterated be the Iterator Record { [[Iterator]]: O, [[NextMethod]]: undefined, [[Done]]: false }.
+        // 4. If IsCallable(mapper) is false, then
+        //     a. Let error be ThrowCompletion(a newly created TypeError object).
+        //     b. Return ? IteratorClose(iterated, error).
+        let mapper = require_callable(args.get_or_undefined(0), o, "map", "mapper", context)?;
+
+        // 5. Set iterated to ? GetIteratorDirect(O).
+        let iterated = get_iterator_direct(o, context)?;
+
+        // 6. Let closure be a new Abstract Closure with no parameters that captures iterated and mapper and performs the following steps when called:
+        // 7. Let result be CreateIteratorFromClosure(closure, "Iterator Helper", %IteratorHelperPrototype%, « [[UnderlyingIterator]] »).
+        // 8. Set result.[[UnderlyingIterator]] to iterated.
+        // 9. Return result.
+        Ok(IteratorHelper::create(iterated, HelperClosure::Map { mapper }, context).into())
+    }
+
+    /// `Iterator.prototype.filter ( predicate )`
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.filter
+    fn filter(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let o = this_iterator(this, "filter")?;
+
+        // 3. Let iterated be the Iterator Record { [[Iterator]]: O, [[NextMethod]]: undefined, [[Done]]: false }.
+        // 4. If IsCallable(predicate) is false, then
+        //     a. Let error be ThrowCompletion(a newly created TypeError object).
+        //     b. Return ? IteratorClose(iterated, error).
+        let predicate =
+            require_callable(args.get_or_undefined(0), o, "filter", "predicate", context)?;
+
+        // 5. Set iterated to ? GetIteratorDirect(O).
+        let iterated = get_iterator_direct(o, context)?;
+
+        // 6. Let closure be a new Abstract Closure with no parameters that captures iterated and predicate and performs the following steps when called:
+        // 7. Let result be CreateIteratorFromClosure(closure, "Iterator Helper", %IteratorHelperPrototype%, « [[UnderlyingIterator]] »).
+        // 8. Set result.[[UnderlyingIterator]] to iterated.
+        // 9. Return result.
+        Ok(IteratorHelper::create(iterated, HelperClosure::Filter { predicate }, context).into())
+    }
+
+    /// `Iterator.prototype.take ( limit )`
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.take
+    fn take(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let o = this_iterator(this, "take")?;
+
+        // 3. Let iterated be the Iterator Record { [[Iterator]]: O, [[NextMethod]]: undefined, [[Done]]: false }.
+        // 4. Let numLimit be Completion(ToNumber(limit)).
+        // 5. IfAbruptCloseIterator(numLimit, iterated).
+        // 6. If numLimit is NaN, then
+        //     a. Let error be ThrowCompletion(a newly created RangeError object).
+        //     b. Return ? IteratorClose(iterated, error).
+        // 7. Let integerLimit be ! ToIntegerOrInfinity(numLimit).
+        // 8. If integerLimit < 0, then
+        //     a. Let error be ThrowCompletion(a newly created RangeError object).
+        //     b. Return ? IteratorClose(iterated, error).
+        let remaining = to_limit(args.get_or_undefined(0), o, "take", context)?;
+
+        // 9. Set iterated to ? GetIteratorDirect(O).
+        let iterated = get_iterator_direct(o, context)?;
+
+        // 10. Let closure be a new Abstract Closure with no parameters that captures iterated and integerLimit and performs the following steps when called:
+        // 11. Let result be CreateIteratorFromClosure(closure, "Iterator Helper", %IteratorHelperPrototype%, « [[UnderlyingIterator]] »).
+        // 12. Set result.[[UnderlyingIterator]] to iterated.
+        // 13. Return result.
+        Ok(IteratorHelper::create(iterated, HelperClosure::Take { remaining }, context).into())
+    }
+
+    /// `Iterator.prototype.drop ( limit )`
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.drop
+    fn drop(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let o = this_iterator(this, "drop")?;
+
+        // 3. Let iterated be the Iterator Record { [[Iterator]]: O, [[NextMethod]]: undefined, [[Done]]: false }.
+        // 4. Let numLimit be Completion(ToNumber(limit)).
+        // 5. IfAbruptCloseIterator(numLimit, iterated).
+        // 6. If numLimit is NaN, then
+        //     a. Let error be ThrowCompletion(a newly created RangeError object).
+        //     b. Return ? IteratorClose(iterated, error).
+        // 7. Let integerLimit be ! ToIntegerOrInfinity(numLimit).
+        // 8. If integerLimit < 0, then
+        //     a. Let error be ThrowCompletion(a newly created RangeError object).
+        //     b. Return ? IteratorClose(iterated, error).
+        let remaining = to_limit(args.get_or_undefined(0), o, "drop", context)?;
+
+        // 9. Set iterated to ? GetIteratorDirect(O).
+        let iterated = get_iterator_direct(o, context)?;
+
+        // 10. Let closure be a new Abstract Closure with no parameters that captures iterated and integerLimit and performs the following steps when called:
+        // 11. Let result be CreateIteratorFromClosure(closure, "Iterator Helper", %IteratorHelperPrototype%, « [[UnderlyingIterator]] »).
+        // 12. Set result.[[UnderlyingIterator]] to iterated.
+        // 13. Return result.
+        Ok(IteratorHelper::create(iterated, HelperClosure::Drop { remaining }, context).into())
+    }
+
+    /// `Iterator.prototype.flatMap ( mapper )`
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.flatmap
+    fn flat_map(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let o = this_iterator(this, "flatMap")?;
+
+        // 3. Let iterated be the Iterator Record { [[Iterator]]: O, [[NextMethod]]: undefined, [[Done]]: false }.
+        // 4. If IsCallable(mapper) is false, then
+        //     a. Let error be ThrowCompletion(a newly created TypeError object).
+        //     b. Return ? IteratorClose(iterated, error).
+        let mapper = require_callable(args.get_or_undefined(0), o, "flatMap", "mapper", context)?;
+
+        // 5. Set iterated to ? GetIteratorDirect(O).
+        let iterated = get_iterator_direct(o, context)?;
+
+        // 6. Let closure be a new Abstract Closure with no parameters that captures iterated and mapper and performs the following steps when called:
+        // 7. Let result be CreateIteratorFromClosure(closure, "Iterator Helper", %IteratorHelperPrototype%, « [[UnderlyingIterator]] »).
+        // 8. Set result.[[UnderlyingIterator]] to iterated.
+        // 9. Return result.
+        Ok(IteratorHelper::create(
+            iterated,
+            HelperClosure::FlatMap {
+                mapper,
+                inner: None,
+            },
+            context,
+        )
+        .into())
+    }
+
+    /// `Iterator.prototype.reduce ( reducer [ , initialValue ] )`
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.reduce
+    fn reduce(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let o = this_iterator(this, "reduce")?;
+
+        // 3. Let iterated be the Iterator Record { [[Iterator]]: O, [[NextMethod]]: undefined, [[Done]]: false }.
+        // 4. If IsCallable(reducer) is false, then
+        //     a. Let error be ThrowCompletion(a newly created TypeError object).
+        //     b. Return ? IteratorClose(iterated, error).
+        let reducer = require_callable(args.get_or_undefined(0), o, "reduce", "reducer", context)?;
+
+        // 5. Set iterated to ? GetIteratorDirect(O).
+        let mut iterated = get_iterator_direct(o, context)?;
+
+        let (mut accumulator, mut counter) = if let Some(initial_value) = args.get(1) {
+            // 7. Else,
+            //     a. Let accumulator be initialValue.
+            //     b. Let counter be 0.
+            (initial_value.clone(), 0)
+        } else {
+            // 6. If initialValue is not present, then
+            //     a. Let accumulator be ? IteratorStepValue(iterated).
+            //     b. If accumulator is done, throw a TypeError exception.
+            //     c. Let counter be 1.
+            let Some(accumulator) = iterated.step_value(context)? else {
+                return Err(JsNativeError::typ()
+                    .with_message(
+                        "Iterator.prototype.reduce: cannot reduce an empty iterator without an initial value",
+                    )
+                    .into());
+            };
+            (accumulator, 1)
+        };
+
+        // 8. Repeat,
+        loop {
+            // a. Let value be ? IteratorStepValue(iterated).
+            // b. If value is done, return accumulator.
+            let Some(value) = iterated.step_value(context)? else {
+                return Ok(accumulator);
+            };
+
+            // c. Let result be Completion(Call(reducer, undefined, « accumulator, value, 𝔽(counter) »)).
+            let result = reducer.call(
+                &JsValue::undefined(),
+                &[accumulator, value, counter.into()],
+                context,
+            );
+
+            // d. IfAbruptCloseIterator(result, iterated).
+            // e. Set accumulator to result.[[Value]].
+            accumulator = if_abrupt_close_iterator!(result, iterated, context);
+
+            // f. Set counter to counter + 1.
+            counter += 1;
+        }
+    }
+
+    /// `Iterator.prototype.toArray ( )`
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.toarray
+    fn to_array(this: &JsValue, _: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let o = this_iterator(this, "toArray")?;
+
+        // 3. Let iterated be ? GetIteratorDirect(O).
+        let mut iterated = get_iterator_direct(o, context)?;
+
+        // 4. Let items be a new empty List.
+        let mut items = Vec::new();
+
+        // 5. Repeat,
+        //     a. Let value be ? IteratorStepValue(iterated).
+        //     b. If value is done, return CreateArrayFromList(items).
+        //     c. Append value to items.
+        while let Some(value) = iterated.step_value(context)? {
+            items.push(value);
+        }
+        Ok(Array::create_array_from_list(items, context).into())
+    }
+
+    /// `Iterator.prototype.forEach ( procedure )`
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.foreach
+    fn for_each(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let o = this_iterator(this, "forEach")?;
+
+        // 3. Let iterated be the Iterator Record { [[Iterator]]: O, [[NextMethod]]: undefined, [[Done]]: false }.
+        // 4. If IsCallable(procedure) is false, then
+        //     a. Let error be ThrowCompletion(a newly created TypeError object).
+        //     b. Return ? IteratorClose(iterated, error).
+        let procedure =
+            require_callable(args.get_or_undefined(0), o, "forEach", "procedure", context)?;
+
+        // 5. Set iterated to ? GetIteratorDirect(O).
+        let mut iterated = get_iterator_direct(o, context)?;
+
+        // 6. Let counter be 0.
+        // 7. Repeat,
+        for counter in 0u64.. {
+            // a. Let value be ? IteratorStepValue(iterated).
+            // b. If value is done, return undefined.
+            let Some(value) = iterated.step_value(context)? else {
+                break;
+            };
+
+            // c. Let result be Completion(Call(procedure, undefined, « value, 𝔽(counter) »)).
+            let result = procedure.call(&JsValue::undefined(), &[value, counter.into()], context);
+
+            // d. IfAbruptCloseIterator(result, iterated).
+            if_abrupt_close_iterator!(result, iterated, context);
+
+            // e. Set counter to counter + 1.
+        }
+
+        Ok(JsValue::undefined())
+    }
+
+    /// `Iterator.prototype.some ( predicate )`
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.some
+    fn some(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let o = this_iterator(this, "some")?;
+
+        // 3. Let iterated be the Iterator Record { [[Iterator]]: O, [[NextMethod]]: undefined, [[Done]]: false }.
+        // 4. If IsCallable(predicate) is false, then
+        //     a. Let error be ThrowCompletion(a newly created TypeError object).
+        //     b. Return ? IteratorClose(iterated, error).
+        let predicate =
+            require_callable(args.get_or_undefined(0), o, "some", "predicate", context)?;
+
+        // 5. Set iterated to ? GetIteratorDirect(O).
+        let mut iterated = get_iterator_direct(o, context)?;
+
+        // 6. Let counter be 0.
+        // 7. Repeat,
+        for counter in 0u64.. {
+            // a. Let value be ? IteratorStepValue(iterated).
+            // b. If value is done, return false.
+            let Some(value) = iterated.step_value(context)? else {
+                break;
+            };
+
+            // c. Let result be Completion(Call(predicate, undefined, « value, 𝔽(counter) »)).
+            let result = predicate.call(&JsValue::undefined(), &[value, counter.into()], context);
+
+            // d. IfAbruptCloseIterator(result, iterated).
+            let result = if_abrupt_close_iterator!(result, iterated, context);
+
+            // e. If ToBoolean(result) is true, return ? IteratorClose(iterated, NormalCompletion(true)).
+            if result.to_boolean() {
+                return iterated.close(Ok(true.into()), context);
+            }
+
+            // f. Set counter to counter + 1.
+        }
+
+        Ok(false.into())
+    }
+
+    /// `Iterator.prototype.every ( predicate )`
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.every
+    fn every(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let o = this_iterator(this, "every")?;
+
+        // 3. Let iterated be the Iterator Record { [[Iterator]]: O, [[NextMethod]]: undefined, [[Done]]: false }.
+        // 4. If IsCallable(predicate) is false, then
+        //     a. Let error be ThrowCompletion(a newly created TypeError object).
+        //     b. Return ? IteratorClose(iterated, error).
+        let predicate =
+            require_callable(args.get_or_undefined(0), o, "every", "predicate", context)?;
+
+        // 5. Set iterated to ? GetIteratorDirect(O).
+        let mut iterated = get_iterator_direct(o, context)?;
+
+        // 6. Let counter be 0.
+        // 7. Repeat,
+        for counter in 0u64.. {
+            // a. Let value be ? IteratorStepValue(iterated).
+            // b. If value is done, return true.
+            let Some(value) = iterated.step_value(context)? else {
+                break;
+            };
+
+            // c. Let result be Completion(Call(predicate, undefined, « value, 𝔽(counter) »)).
+            let result = predicate.call(&JsValue::undefined(), &[value, counter.into()], context);
+
+            // d. IfAbruptCloseIterator(result, iterated).
+            let result = if_abrupt_close_iterator!(result, iterated, context);
+
+            // e. If ToBoolean(result) is false, return ? IteratorClose(iterated, NormalCompletion(false)).
+            if !result.to_boolean() {
+                return iterated.close(Ok(false.into()), context);
+            }
+
+            // f. Set counter to counter + 1.
+        }
+
+        Ok(true.into())
+    }
+
+    /// `Iterator.prototype.find ( predicate )`
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.find
+    fn find(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let o = this_iterator(this, "find")?;
+
+        // 3. Let iterated be the Iterator Record { [[Iterator]]: O, [[NextMethod]]: undefined, [[Done]]: false }.
+        // 4. If IsCallable(predicate) is false, then
+        //     a. Let error be ThrowCompletion(a newly created TypeError object).
+        //     b. Return ? IteratorClose(iterated, error).
+        let predicate =
+            require_callable(args.get_or_undefined(0), o, "find", "predicate", context)?;
+
+        // 5. Set iterated to ? GetIteratorDirect(O).
+        let mut iterated = get_iterator_direct(o, context)?;
+
+        // 6. Let counter be 0.
+        // 7. Repeat,
+        for counter in 0u64.. {
+            // a. Let value be ? IteratorStepValue(iterated).
+            // b. If value is done, return undefined.
+            let Some(value) = iterated.step_value(context)? else {
+                break;
+            };
+
+            // c. Let result be Completion(Call(predicate, undefined, « value, 𝔽(counter) »)).
+            let result = predicate.call(
+                &JsValue::undefined(),
+                &[value.clone(), counter.into()],
+                context,
+            );
+
+            // d. IfAbruptCloseIterator(result, iterated).
+            let result = if_abrupt_close_iterator!(result, iterated, context);
+
+            // e. If ToBoolean(result) is true, return ? IteratorClose(iterated, NormalCompletion(value)).
+            if result.to_boolean() {
+                return iterated.close(Ok(value), context);
+            }
+
+            // f. Set counter to counter + 1.
+        }
+
+        Ok(JsValue::undefined())
+    }
+}
+
+/// Returns the `this` value of an iterator helper method, throwing a `TypeError` if it's not an
+/// object.
+fn this_iterator<'a>(this: &'a JsValue, method: &str) -> JsResult<&'a JsObject> {
+    this.as_object().ok_or_else(|| {
+        JsNativeError::typ()
+            .with_message(format!(
+                "Iterator.prototype.{method}: `this` is not an iterator object"
+            ))
+            .into()
+    })
+}
+
+/// Closes `iterator` with a throw completion of `error`, returning the resulting error.
+///
+/// This is the `Return ? IteratorClose(iterated, error)` step used by the argument validation of
+/// the iterator helpers, where the `[[NextMethod]]` of `iterated` has not been read yet.
+fn close_with_error(iterator: &JsObject, error: JsError, context: &mut Context<'_>) -> JsError {
+    IteratorRecord::new(iterator.clone(), JsValue::undefined(), false)
+        .close(Err(error), context)
+        .expect_err("closing an iterator with a throw completion must return an error")
+}
+
+/// Checks that the `argument` of an iterator helper method is callable, closing `iterator`
+/// otherwise.
+fn require_callable(
+    value: &JsValue,
+    iterator: &JsObject,
+    method: &str,
+    argument: &str,
+    context: &mut Context<'_>,
+) -> JsResult<JsObject> {
+    if let Some(callable) = value.as_callable() {
+        return Ok(callable.clone());
+    }
+
+    let error = JsNativeError::typ()
+        .with_message(format!(
+            "Iterator.prototype.{method}: `{argument}` is not callable"
+        ))
+        .into();
+    Err(close_with_error(iterator, error, context))
+}
+
+/// Converts the `limit` argument of `take` and `drop` to an integer, closing `iterator` if the
+/// conversion fails or the limit is out of range.
+///
+/// Returns `None` if the limit is `+∞`.
+fn to_limit(
+    limit: &JsValue,
+    iterator: &JsObject,
+    method: &str,
+    context: &mut Context<'_>,
+) -> JsResult<Option<u64>> {
+    // 4. Let numLimit be Completion(ToNumber(limit)).
+    // 5. IfAbruptCloseIterator(numLimit, iterated).
+    let num_limit = match limit.to_number(context) {
+        Ok(num_limit) => num_limit,
+        Err(err) => return Err(close_with_error(iterator, err, context)),
+    };
+
+    // 6. If numLimit is NaN, then
+    //     a. Let error be ThrowCompletion(a newly created RangeError object).
+    //     b. Return ? IteratorClose(iterated, error).
+    // 7. Let integerLimit be ! ToIntegerOrInfinity(numLimit).
+    // 8. If integerLimit < 0, then
+    //     a. Let error be ThrowCompletion(a newly created RangeError object).
+    //     b. Return ? IteratorClose(iterated, error).
+    let error = match IntegerOrInfinity::from(num_limit) {
+        _ if num_limit.is_nan() => "must not be NaN",
+        IntegerOrInfinity::PositiveInfinity => return Ok(None),
+        IntegerOrInfinity::Integer(limit) if limit >= 0 => return Ok(Some(limit as u64)),
+        _ => "must be positive",
+    };
+    let error = JsNativeError::range()
+        .with_message(format!("Iterator.prototype.{method}: `limit` {error}"))
+        .into();
+    Err(close_with_error(iterator, error, context))
+}
+
+/// Abstract operation `SetterThatIgnoresPrototypeProperties ( thisValue, home, p, v )`, with
+/// `%Iterator.prototype%` as `home`.
+///
+/// More information:
+///  - [ECMA reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-SetterThatIgnoresPrototypeProperties
+fn setter_that_ignores_prototype_properties(
+    this: &JsValue,
+    key: PropertyKey,
+    value: JsValue,
+    context: &mut Context<'_>,
+) -> JsResult<()> {
+    // 1. If thisValue is not an Object, then
+    //     a. Throw a TypeError exception.
+    let Some(this) = this.as_object() else {
+        return Err(JsNativeError::typ()
+            .with_message("Iterator.prototype: cannot set a property of a non-object")
+            .into());
+    };
+
+    // 2. If SameValue(thisValue, home) is true, then
+    //     a. NOTE: Throwing here emulates assignment to a non-writable data property on the home object in strict mode code.
+    //     b. Throw a TypeError exception.
+    if *this == context.intrinsics().constructors().iterator().prototype() {
+        return Err(JsNativeError::typ()
+            .with_message("Iterator.prototype: cannot set a property of the prototype itself")
+            .into());
+    }
+
+    // 3. Let desc be ? thisValue.[[GetOwnProperty]](p).
+    let desc = this.__get_own_property__(&key, context)?;
+
+    // 4. If desc is undefined, then
+    if desc.is_none() {
+        // a. Perform ? CreateDataPropertyOrThrow(thisValue, p, v).
+        this.create_data_property_or_throw(key, value, context)?;
+    } else {
+        // 5. Else,
+        //     a. Perform ? Set(thisValue, p, v, true).
+        this.set(key, value, true, context)?;
+    }
+
+    // 6. Return unused.
+    Ok(())
+}
+
+/// The internal representation of the `%WrapForValidIteratorPrototype%` objects returned by
+/// `Iterator.from`.
+///
+/// More information:
+///  - [ECMA reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-%wrapforvaliditeratorprototype%-object
+#[derive(Debug, Clone, Finalize, Trace)]
+pub struct WrapForValidIterator {
+    /// The `[[Iterated]]` internal slot.
+    iterated: IteratorRecord,
+}
+
+impl IntrinsicObject for WrapForValidIterator {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event("WrapForValidIteratorPrototype", "init");
+
+        BuiltInBuilder::with_intrinsic::<Self>(realm)
+            .prototype(realm.intrinsics().constructors().iterator().prototype())
+            .static_method(Self::next, "next", 0)
+            .static_method(Self::r#return, "return", 0)
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        intrinsics
+            .objects()
+            .iterator_prototypes()
+            .wrap_for_valid_iterator()
+    }
+}
+
+impl WrapForValidIterator {
+    /// Returns the `[[Iterated]]` record of the `this` value.
+    fn iterated(this: &JsValue, method: &str) -> JsResult<IteratorRecord> {
+        // 1. Let O be this value.
+        // 2. Perform ? RequireInternalSlot(O, [[Iterated]]).
+        this.as_object()
+            .and_then(|o| {
+                o.borrow()
+                    .as_wrap_for_valid_iterator()
+                    .map(|wrapper| wrapper.iterated.clone())
+            })
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message(format!(
+                        "%WrapForValidIteratorPrototype%.{method}: `this` is not a wrapped iterator"
+                    ))
+                    .into()
+            })
+    }
+
+    /// `%WrapForValidIteratorPrototype%.next ( )`
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%wrapforvaliditeratorprototype%.next
+    fn next(this: &JsValue, _: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 3. Let iteratorRecord be O.[[Iterated]].
+        let iterator_record = Self::iterated(this, "next")?;
+
+        // 4. Return ? Call(iteratorRecord.[[NextMethod]], iteratorRecord.[[Iterator]]).
+        iterator_record
+            .next_method()
+            .call(&iterator_record.iterator().clone().into(), &[], context)
+    }
+
+    /// `%WrapForValidIteratorPrototype%.return ( )`
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%wrapforvaliditeratorprototype%.return
+    fn r#return(this: &JsValue, _: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 3. Let iterator be O.[[Iterated]].[[Iterator]].
+        // 4. Assert: iterator is an Object.
+        let iterator = Self::iterated(this, "return")?.iterator().clone();
+
+        // 5. Let returnMethod be ? GetMethod(iterator, "return").
+        let return_method = iterator.get_method(js_string!("return"), context)?;
+
+        // 6. If returnMethod is undefined, then
+        //     a. Return CreateIterResultObject(undefined, true).
+        // 7. Return ? Call(returnMethod, iterator).
+        match return_method {
+            Some(return_method) => return_method.call(&iterator.into(), &[], context),
+            None => Ok(create_iter_result_object(
+                JsValue::undefined(),
+                true,
+                context,
+            )),
+        }
+    }
+}
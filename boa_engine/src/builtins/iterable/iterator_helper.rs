@@ -0,0 +1,456 @@
+//! Boa's implementation of the `%IteratorHelperPrototype%` object and the iterators it produces.
+//!
+//! More information:
+//!  - [ECMA reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-%iteratorhelperprototype%-object
+
+use crate::{
+    builtins::{
+        iterable::{
+            create_iter_result_object, get_iterator_flattenable, IteratorRecord, PrimitiveHandling,
+        },
+        BuiltInBuilder, IntrinsicObject,
+    },
+    context::intrinsics::Intrinsics,
+    js_string,
+    object::{JsObject, ObjectData},
+    property::Attribute,
+    realm::Realm,
+    symbol::JsSymbol,
+    Context, JsNativeError, JsResult, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+
+/// The `[[GeneratorState]]` of an iterator helper.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HelperState {
+    SuspendedStart,
+    SuspendedYield,
+    Executing,
+    Completed,
+}
+
+/// The abstract closure of an iterator helper, along with the state it keeps between yields.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub(crate) enum HelperClosure {
+    /// The closure of `Iterator.prototype.map`.
+    Map { mapper: JsObject },
+
+    /// The closure of `Iterator.prototype.filter`.
+    Filter { predicate: JsObject },
+
+    /// The closure of `Iterator.prototype.take`.
+    ///
+    /// A `remaining` of `None` represents `+∞`.
+    Take { remaining: Option<u64> },
+
+    /// The closure of `Iterator.prototype.drop`.
+    ///
+    /// A `remaining` of `None` represents `+∞`.
+    Drop { remaining: Option<u64> },
+
+    /// The closure of `Iterator.prototype.flatMap`.
+    FlatMap {
+        mapper: JsObject,
+        inner: Option<IteratorRecord>,
+    },
+}
+
+impl HelperClosure {
+    /// Runs the closure until its next `Yield`, returning the yielded value, or `None` if the
+    /// closure returned.
+    fn resume(
+        &mut self,
+        iterated: &mut IteratorRecord,
+        counter: &mut u64,
+        context: &mut Context<'_>,
+    ) -> JsResult<Option<JsValue>> {
+        match self {
+            Self::Map { mapper } => {
+                // i. Let value be ? IteratorStepValue(iterated).
+                // ii. If value is done, return ReturnCompletion(undefined).
+                let Some(value) = iterated.step_value(context)? else {
+                    return Ok(None);
+                };
+
+                // iii. Let mapped be Completion(Call(mapper, undefined, « value, 𝔽(counter) »)).
+                let mapped =
+                    mapper.call(&JsValue::undefined(), &[value, (*counter).into()], context);
+
+                // iv. IfAbruptCloseIterator(mapped, iterated).
+                let mapped = if_abrupt_close(mapped, iterated, context)?;
+
+                // v. Let completion be Completion(Yield(mapped)).
+                // vi. IfAbruptCloseIterator(completion, iterated).
+                // vii. Set counter to counter + 1.
+                *counter += 1;
+                Ok(Some(mapped))
+            }
+            Self::Filter { predicate } => loop {
+                // i. Let value be ? IteratorStepValue(iterated).
+                // ii. If value is done, return ReturnCompletion(undefined).
+                let Some(value) = iterated.step_value(context)? else {
+                    return Ok(None);
+                };
+
+                // iii. Let selected be Completion(Call(predicate, undefined, « value, 𝔽(counter) »)).
+                let selected = predicate.call(
+                    &JsValue::undefined(),
+                    &[value.clone(), (*counter).into()],
+                    context,
+                );
+
+                // iv. IfAbruptCloseIterator(selected, iterated).
+                let selected = if_abrupt_close(selected, iterated, context)?;
+
+                // vi. Set counter to counter + 1.
+                *counter += 1;
+
+                // v. If ToBoolean(selected) is true, then
+                if selected.to_boolean() {
+                    // 1. Let completion be Completion(Yield(value)).
+                    // 2. IfAbruptCloseIterator(completion, iterated).
+                    return Ok(Some(value));
+                }
+            },
+            Self::Take { remaining } => {
+                // i. If remaining = 0, then
+                if *remaining == Some(0) {
+                    // 1. Return ? IteratorClose(iterated, ReturnCompletion(undefined)).
+                    iterated.close(Ok(JsValue::undefined()), context)?;
+                    return Ok(None);
+                }
+
+                // ii. If remaining ≠ +∞, then
+                if let Some(remaining) = remaining {
+                    // 1. Set remaining to remaining - 1.
+                    *remaining -= 1;
+                }
+
+                // iii. Let value be ? IteratorStepValue(iterated).
+                // iv. If value is done, return ReturnCompletion(undefined).
+                // v. Let completion be Completion(Yield(value)).
+                // vi. IfAbruptCloseIterator(completion, iterated).
+                iterated.step_value(context)
+            }
+            Self::Drop { remaining } => {
+                // b. Repeat, while remaining > 0,
+                while *remaining != Some(0) {
+                    // i. If remaining ≠ +∞, then
+                    if let Some(remaining) = remaining {
+                        // 1. Set remaining to remaining - 1.
+                        *remaining -= 1;
+                    }
+
+                    // ii. Let next be ? IteratorStep(iterated).
+                    // iii. If next is done, return ReturnCompletion(undefined).
+                    if iterated.step(context)?.is_none() {
+                        iterated.set_done(true);
+                        return Ok(None);
+                    }
+                }
+
+                // c. Repeat,
+                //     i. Let value be ? IteratorStepValue(iterated).
+                //     ii. If value is done, return ReturnCompletion(undefined).
+                //     iii. Let completion be Completion(Yield(value)).
+                //     iv. IfAbruptCloseIterator(completion, iterated).
+                iterated.step_value(context)
+            }
+            Self::FlatMap { mapper, inner } => loop {
+                // viii. Repeat, while innerAlive is true,
+                if let Some(inner_iterator) = inner {
+                    // 1. Let innerValue be Completion(IteratorStepValue(innerIterator)).
+                    let inner_value = inner_iterator.step_value(context);
+
+                    // 2. IfAbruptCloseIterator(innerValue, iterated).
+                    // 3. If innerValue is done, then
+                    //     a. Set innerAlive to false.
+                    // 4. Else,
+                    //     a. Let completion be Completion(Yield(innerValue)).
+                    //     b. If completion is an abrupt completion, then
+                    //         i. Let backupCompletion be Completion(IteratorClose(innerIterator, completion)).
+                    //         ii. IfAbruptCloseIterator(backupCompletion, iterated).
+                    //         iii. Return ? IteratorClose(iterated, completion).
+                    if let Some(inner_value) = if_abrupt_close(inner_value, iterated, context)? {
+                        return Ok(Some(inner_value));
+                    }
+                    *inner = None;
+                }
+
+                // i. Let value be ? IteratorStepValue(iterated).
+                // ii. If value is done, return ReturnCompletion(undefined).
+                let Some(value) = iterated.step_value(context)? else {
+                    return Ok(None);
+                };
+
+                // iii. Let mapped be Completion(Call(mapper, undefined, « value, 𝔽(counter) »)).
+                let mapped =
+                    mapper.call(&JsValue::undefined(), &[value, (*counter).into()], context);
+
+                // iv. IfAbruptCloseIterator(mapped, iterated).
+                let mapped = if_abrupt_close(mapped, iterated, context)?;
+
+                // v. Let innerIterator be Completion(GetIteratorFlattenable(mapped, reject-primitives)).
+                let inner_iterator =
+                    get_iterator_flattenable(&mapped, PrimitiveHandling::RejectPrimitives, context);
+
+                // vi. IfAbruptCloseIterator(innerIterator, iterated).
+                // vii. Let innerAlive be true.
+                *inner = Some(if_abrupt_close(inner_iterator, iterated, context)?);
+
+                // ix. Set counter to counter + 1.
+                *counter += 1;
+            },
+        }
+    }
+
+    /// Resumes the closure from its last `Yield` with a return completion, closing the
+    /// iterators it holds.
+    fn resume_return(&self, iterated: &IteratorRecord, context: &mut Context<'_>) -> JsResult<()> {
+        if let Self::FlatMap {
+            inner: Some(inner), ..
+        } = self
+        {
+            // i. Let backupCompletion be Completion(IteratorClose(innerIterator, completion)).
+            let backup_completion = inner.close(Ok(JsValue::undefined()), context);
+
+            // ii. IfAbruptCloseIterator(backupCompletion, iterated).
+            if_abrupt_close(backup_completion, iterated, context)?;
+        }
+
+        // IfAbruptCloseIterator(completion, iterated).
+        iterated.close(Ok(JsValue::undefined()), context)?;
+        Ok(())
+    }
+}
+
+/// `IfAbruptCloseIterator ( value, iteratorRecord )`, for the closures of the iterator helpers.
+fn if_abrupt_close<T>(
+    value: JsResult<T>,
+    iterated: &IteratorRecord,
+    context: &mut Context<'_>,
+) -> JsResult<T> {
+    value.map_err(|err| {
+        iterated
+            .close(Err(err), context)
+            .expect_err("closing an iterator with a throw completion must return an error")
+    })
+}
+
+/// The internal representation of the iterator objects returned by the iterator helpers.
+///
+/// More information:
+///  - [ECMA reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-%iteratorhelperprototype%-object
+#[derive(Debug, Finalize, Trace)]
+pub struct IteratorHelper {
+    /// The `[[UnderlyingIterator]]` internal slot.
+    underlying: IteratorRecord,
+
+    /// The closure of the helper.
+    closure: HelperClosure,
+
+    /// The number of values the closure has processed so far.
+    counter: u64,
+
+    /// The `[[GeneratorState]]` internal slot.
+    #[unsafe_ignore_trace]
+    state: HelperState,
+}
+
+impl IntrinsicObject for IteratorHelper {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event("IteratorHelperPrototype", "init");
+
+        BuiltInBuilder::with_intrinsic::<Self>(realm)
+            .prototype(realm.intrinsics().constructors().iterator().prototype())
+            .static_method(Self::next, "next", 0)
+            .static_method(Self::r#return, "return", 0)
+            .static_property(
+                JsSymbol::to_string_tag(),
+                js_string!("Iterator Helper"),
+                Attribute::CONFIGURABLE,
+            )
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        intrinsics.objects().iterator_prototypes().iterator_helper()
+    }
+}
+
+impl IteratorHelper {
+    /// Creates a new iterator helper object, as in the last steps of the iterator helper methods.
+    ///
+    /// This corresponds to `CreateIteratorFromClosure(closure, "Iterator Helper",
+    /// %IteratorHelperPrototype%, « [[UnderlyingIterator]] »)`, followed by setting
+    /// `[[UnderlyingIterator]]` to `underlying`.
+    pub(crate) fn create(
+        underlying: IteratorRecord,
+        closure: HelperClosure,
+        context: &Context<'_>,
+    ) -> JsObject {
+        JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            context
+                .intrinsics()
+                .objects()
+                .iterator_prototypes()
+                .iterator_helper(),
+            ObjectData::iterator_helper(Self {
+                underlying,
+                closure,
+                counter: 0,
+                state: HelperState::SuspendedStart,
+            }),
+        )
+    }
+
+    /// Returns the `this` value as an iterator helper object, throwing a `TypeError` otherwise.
+    fn this_helper<'a>(this: &'a JsValue, method: &str) -> JsResult<&'a JsObject> {
+        this.as_object()
+            .filter(|object| object.borrow().as_iterator_helper().is_some())
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message(format!(
+                        "%IteratorHelperPrototype%.{method}: `this` is not an Iterator Helper object"
+                    ))
+                    .into()
+            })
+    }
+
+    /// `%IteratorHelperPrototype%.next ( )`
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%iteratorhelperprototype%.next
+    fn next(this: &JsValue, _: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Return ? GeneratorResume(this value, undefined, "Iterator Helper").
+        let object = Self::this_helper(this, "next")?;
+
+        let (mut underlying, mut closure, mut counter) = {
+            let mut object = object.borrow_mut();
+            let helper = object
+                .as_iterator_helper_mut()
+                .expect("must be an iterator helper");
+            match helper.state {
+                HelperState::Executing => {
+                    return Err(JsNativeError::typ()
+                        .with_message(
+                            "%IteratorHelperPrototype%.next: the Iterator Helper is already running",
+                        )
+                        .into());
+                }
+                HelperState::Completed => {
+                    return Ok(create_iter_result_object(
+                        JsValue::undefined(),
+                        true,
+                        context,
+                    ));
+                }
+                HelperState::SuspendedStart | HelperState::SuspendedYield => {}
+            }
+            helper.state = HelperState::Executing;
+            (
+                helper.underlying.clone(),
+                helper.closure.clone(),
+                helper.counter,
+            )
+        };
+
+        let result = closure.resume(&mut underlying, &mut counter, context);
+
+        {
+            let mut object = object.borrow_mut();
+            let helper = object
+                .as_iterator_helper_mut()
+                .expect("must be an iterator helper");
+            helper.underlying = underlying;
+            helper.closure = closure;
+            helper.counter = counter;
+            helper.state = if matches!(result, Ok(Some(_))) {
+                HelperState::SuspendedYield
+            } else {
+                HelperState::Completed
+            };
+        }
+
+        Ok(match result? {
+            Some(value) => create_iter_result_object(value, false, context),
+            None => create_iter_result_object(JsValue::undefined(), true, context),
+        })
+    }
+
+    /// `%IteratorHelperPrototype%.return ( )`
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%iteratorhelperprototype%.return
+    fn r#return(this: &JsValue, _: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Let O be this value.
+        // 2. Perform ? RequireInternalSlot(O, [[UnderlyingIterator]]).
+        // 3. Assert: O has a [[GeneratorState]] slot.
+        let object = Self::this_helper(this, "return")?;
+
+        let (underlying, closure, state) = {
+            let mut object = object.borrow_mut();
+            let helper = object
+                .as_iterator_helper_mut()
+                .expect("must be an iterator helper");
+            let state = helper.state;
+            match state {
+                HelperState::Executing => {
+                    return Err(JsNativeError::typ()
+                        .with_message(
+                            "%IteratorHelperPrototype%.return: the Iterator Helper is already running",
+                        )
+                        .into());
+                }
+                HelperState::Completed => {
+                    return Ok(create_iter_result_object(
+                        JsValue::undefined(),
+                        true,
+                        context,
+                    ));
+                }
+                // 4. If O.[[GeneratorState]] is suspended-start, then
+                //     a. Set O.[[GeneratorState]] to completed.
+                HelperState::SuspendedStart => helper.state = HelperState::Completed,
+                HelperState::SuspendedYield => helper.state = HelperState::Executing,
+            }
+            (helper.underlying.clone(), helper.closure.clone(), state)
+        };
+
+        let result = if state == HelperState::SuspendedStart {
+            // b. NOTE: Once a generator enters the completed state it never leaves it and its associated execution context is never resumed. Any execution state associated with O can be discarded at this point.
+            // c. Perform ? IteratorClose(O.[[UnderlyingIterator]], NormalCompletion(unused)).
+            underlying
+                .close(Ok(JsValue::undefined()), context)
+                .map(|_| ())
+        } else {
+            // 5. Let C be Completion { [[Type]]: return, [[Value]]: undefined, [[Target]]: empty }.
+            // 6. Return ? GeneratorResumeAbrupt(O, C, "Iterator Helper").
+            let result = closure.resume_return(&underlying, context);
+            object
+                .borrow_mut()
+                .as_iterator_helper_mut()
+                .expect("must be an iterator helper")
+                .state = HelperState::Completed;
+            result
+        };
+        result?;
+
+        // d. Return CreateIterResultObject(undefined, true).
+        Ok(create_iter_result_object(
+            JsValue::undefined(),
+            true,
+            context,
+        ))
+    }
+}
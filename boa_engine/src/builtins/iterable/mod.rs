@@ -1,20 +1,34 @@
 //! Boa's implementation of ECMAScript's `IteratorRecord` and iterator prototype objects.
 
 use crate::{
-    builtins::{BuiltInBuilder, IntrinsicObject},
-    context::intrinsics::Intrinsics,
+    builtins::Number,
     error::JsNativeError,
     js_string,
     object::{JsObject, ObjectData},
-    realm::Realm,
     symbol::JsSymbol,
     Context, JsResult, JsValue,
 };
 use boa_gc::{Finalize, Trace};
 use boa_profiler::Profiler;
+use indexmap::IndexMap;
+use std::hash::Hash;
 
 mod async_from_sync_iterator;
+mod async_iterator;
+mod async_iterator_helper;
+mod iterator;
+mod iterator_helper;
+
+#[cfg(test)]
+mod tests;
+
 pub(crate) use async_from_sync_iterator::AsyncFromSyncIterator;
+pub(crate) use async_iterator::{AsyncIterator, WrapForValidAsyncIterator};
+pub(crate) use async_iterator_helper::{
+    async_iterator_close, await_then, if_abrupt_close_async_iterator, reject, AsyncIteratorHelper,
+};
+pub(crate) use iterator::{Iterator, WrapForValidIterator};
+pub(crate) use iterator_helper::IteratorHelper;
 
 /// `IfAbruptCloseIterator ( value, iteratorRecord )`
 ///
@@ -42,15 +56,21 @@ pub(crate) use if_abrupt_close_iterator;
 /// The built-in iterator prototypes.
 #[derive(Debug, Default, Trace, Finalize)]
 pub struct IteratorPrototypes {
-    /// The `IteratorPrototype` object.
-    iterator: JsObject,
-
-    /// The `AsyncIteratorPrototype` object.
-    async_iterator: JsObject,
-
     /// The `AsyncFromSyncIteratorPrototype` prototype object.
     async_from_sync_iterator: JsObject,
 
+    /// The `%IteratorHelperPrototype%` prototype object.
+    iterator_helper: JsObject,
+
+    /// The `%WrapForValidIteratorPrototype%` prototype object.
+    wrap_for_valid_iterator: JsObject,
+
+    /// The `%AsyncIteratorHelperPrototype%` prototype object.
+    async_iterator_helper: JsObject,
+
+    /// The `%WrapForValidAsyncIteratorPrototype%` prototype object.
+    wrap_for_valid_async_iterator: JsObject,
+
     /// The `ArrayIteratorPrototype` prototype object.
     array: JsObject,
 
@@ -81,22 +101,34 @@ impl IteratorPrototypes {
         self.array.clone()
     }
 
-    /// Returns the `IteratorPrototype` object.
+    /// Returns the `AsyncFromSyncIteratorPrototype` object.
     #[inline]
-    pub fn iterator(&self) -> JsObject {
-        self.iterator.clone()
+    pub fn async_from_sync_iterator(&self) -> JsObject {
+        self.async_from_sync_iterator.clone()
     }
 
-    /// Returns the `AsyncIteratorPrototype` object.
+    /// Returns the `%IteratorHelperPrototype%` object.
     #[inline]
-    pub fn async_iterator(&self) -> JsObject {
-        self.async_iterator.clone()
+    pub fn iterator_helper(&self) -> JsObject {
+        self.iterator_helper.clone()
     }
 
-    /// Returns the `AsyncFromSyncIteratorPrototype` object.
+    /// Returns the `%WrapForValidIteratorPrototype%` object.
     #[inline]
-    pub fn async_from_sync_iterator(&self) -> JsObject {
-        self.async_from_sync_iterator.clone()
+    pub fn wrap_for_valid_iterator(&self) -> JsObject {
+        self.wrap_for_valid_iterator.clone()
+    }
+
+    /// Returns the `%AsyncIteratorHelperPrototype%` object.
+    #[inline]
+    pub fn async_iterator_helper(&self) -> JsObject {
+        self.async_iterator_helper.clone()
+    }
+
+    /// Returns the `%WrapForValidAsyncIteratorPrototype%` object.
+    #[inline]
+    pub fn wrap_for_valid_async_iterator(&self) -> JsObject {
+        self.wrap_for_valid_async_iterator.clone()
     }
 
     /// Returns the `SetIteratorPrototype` object.
@@ -137,62 +169,6 @@ impl IteratorPrototypes {
     }
 }
 
-/// `%IteratorPrototype%` object
-///
-/// More information:
-///  - [ECMA reference][spec]
-///
-/// [spec]: https://tc39.es/ecma262/#sec-%iteratorprototype%-object
-
-pub(crate) struct Iterator;
-
-impl IntrinsicObject for Iterator {
-    fn init(realm: &Realm) {
-        let _timer = Profiler::global().start_event("Iterator Prototype", "init");
-
-        BuiltInBuilder::with_intrinsic::<Self>(realm)
-            .static_method(
-                |v, _, _| Ok(v.clone()),
-                (JsSymbol::iterator(), js_string!("[Symbol.iterator]")),
-                0,
-            )
-            .build();
-    }
-
-    fn get(intrinsics: &Intrinsics) -> JsObject {
-        intrinsics.objects().iterator_prototypes().iterator()
-    }
-}
-
-/// `%AsyncIteratorPrototype%` object
-///
-/// More information:
-///  - [ECMA reference][spec]
-///
-/// [spec]: https://tc39.es/ecma262/#sec-asynciteratorprototype
-pub(crate) struct AsyncIterator;
-
-impl IntrinsicObject for AsyncIterator {
-    fn init(realm: &Realm) {
-        let _timer = Profiler::global().start_event("AsyncIteratorPrototype", "init");
-
-        BuiltInBuilder::with_intrinsic::<Self>(realm)
-            .static_method(
-                |v, _, _| Ok(v.clone()),
-                (
-                    JsSymbol::async_iterator(),
-                    js_string!("[Symbol.asyncIterator]"),
-                ),
-                0,
-            )
-            .build();
-    }
-
-    fn get(intrinsics: &Intrinsics) -> JsObject {
-        intrinsics.objects().iterator_prototypes().async_iterator()
-    }
-}
-
 /// `CreateIterResultObject( value, done )`
 ///
 /// Generates an object supporting the `IteratorResult` interface.
@@ -293,6 +269,83 @@ impl JsValue {
     }
 }
 
+/// `GetIteratorDirect ( obj )`
+///
+/// More information:
+///  - [ECMA reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-getiteratordirect
+pub(crate) fn get_iterator_direct(
+    object: &JsObject,
+    context: &mut Context<'_>,
+) -> JsResult<IteratorRecord> {
+    // 1. Let nextMethod be ? Get(obj, "next").
+    let next_method = object.get(js_string!("next"), context)?;
+
+    // 2. Let iteratorRecord be the Iterator Record { [[Iterator]]: obj, [[NextMethod]]: nextMethod, [[Done]]: false }.
+    // 3. Return iteratorRecord.
+    Ok(IteratorRecord::new(object.clone(), next_method, false))
+}
+
+/// How `GetIteratorFlattenable` handles primitive values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum PrimitiveHandling {
+    /// Strings are iterated, any other primitive throws a `TypeError`.
+    IterateStrings,
+
+    /// All primitives throw a `TypeError`.
+    RejectPrimitives,
+}
+
+/// `GetIteratorFlattenable ( obj, primitiveHandling )`
+///
+/// More information:
+///  - [ECMA reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-getiteratorflattenable
+pub(crate) fn get_iterator_flattenable(
+    value: &JsValue,
+    primitive_handling: PrimitiveHandling,
+    context: &mut Context<'_>,
+) -> JsResult<IteratorRecord> {
+    // 1. If obj is not an Object, then
+    //     a. If primitiveHandling is reject-primitives, throw a TypeError exception.
+    //     b. Assert: primitiveHandling is iterate-string-primitives.
+    //     c. If obj is not a String, throw a TypeError exception.
+    if !value.is_object()
+        && (primitive_handling == PrimitiveHandling::RejectPrimitives || !value.is_string())
+    {
+        return Err(JsNativeError::typ()
+            .with_message(format!(
+                "value with type `{}` is not an iterator nor an iterable object",
+                value.type_of()
+            ))
+            .into());
+    }
+
+    // 2. Let method be ? GetMethod(obj, @@iterator).
+    let method = value.get_method(JsSymbol::iterator(), context)?;
+
+    // 3. If method is undefined, then
+    //     a. Let iterator be obj.
+    // 4. Else,
+    //     a. Let iterator be ? Call(method, obj).
+    let iterator = match method {
+        Some(method) => method.call(value, &[], context)?,
+        None => value.clone(),
+    };
+
+    // 5. If iterator is not an Object, throw a TypeError exception.
+    let Some(iterator) = iterator.as_object() else {
+        return Err(JsNativeError::typ()
+            .with_message("returned iterator is not an object")
+            .into());
+    };
+
+    // 6. Return ? GetIteratorDirect(iterator).
+    get_iterator_direct(iterator, context)
+}
+
 /// The result of the iteration process.
 #[derive(Debug)]
 pub struct IteratorResult {
@@ -464,6 +517,46 @@ impl IteratorRecord {
         Ok(Some(result))
     }
 
+    /// `IteratorStepValue ( iteratorRecord )`
+    ///
+    /// Requests the next value from the iterator, returning `None` once the iterator has reached
+    /// its end. Unlike [`IteratorRecord::step`], this sets the `[[Done]]` field of the record if
+    /// the iterator finishes or throws.
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iteratorstepvalue
+    pub(crate) fn step_value(&mut self, context: &mut Context<'_>) -> JsResult<Option<JsValue>> {
+        let _timer = Profiler::global().start_event("IteratorRecord::step_value", "iterator");
+
+        // 1. Let result be Completion(IteratorNext(iteratorRecord)).
+        // 2. If result is a throw completion, then
+        //     a. Set iteratorRecord.[[Done]] to true.
+        // 3. Set result to ! result.
+        // 4. Let done be Completion(IteratorComplete(result)).
+        // 5. If done is a throw completion, then
+        //     a. Set iteratorRecord.[[Done]] to true.
+        // 6. Set done to ! done.
+        // 7. If done is true, then
+        //     a. Set iteratorRecord.[[Done]] to true.
+        //     b. Return done.
+        // 8. Let value be Completion(IteratorValue(result)).
+        // 9. If value is a throw completion, then
+        //     a. Set iteratorRecord.[[Done]] to true.
+        // 10. Return ? value.
+        let value = self.next(None, context).and_then(|result| {
+            if result.complete(context)? {
+                return Ok(None);
+            }
+            result.value(context).map(Some)
+        });
+        if !matches!(value, Ok(Some(_))) {
+            self.done = true;
+        }
+        value
+    }
+
     /// `IteratorClose ( iteratorRecord, completion )`
     ///
     /// The abstract operation `IteratorClose` takes arguments `iteratorRecord` (an
@@ -567,3 +660,90 @@ pub(crate) fn iterable_to_list(
     // 6. Return values.
     Ok(values)
 }
+
+/// `GroupBy ( items, callbackfn, keyCoercion )`
+///
+/// Groups the values of the iterable `items` by the keys returned by `callback`, coercing each
+/// key with `key_coercion`. The groups are returned in the order their keys were first seen.
+///
+/// More information:
+///  - [ECMA reference][spec]
+///
+///  [spec]: https://tc39.es/ecma262/#sec-groupby
+pub(crate) fn group_by<K, F>(
+    items: &JsValue,
+    callback: &JsValue,
+    key_coercion: F,
+    context: &mut Context<'_>,
+) -> JsResult<IndexMap<K, Vec<JsValue>>>
+where
+    K: Hash + Eq,
+    F: Fn(JsValue, &mut Context<'_>) -> JsResult<K>,
+{
+    // 1. Perform ? RequireObjectCoercible(items).
+    items.require_object_coercible()?;
+
+    // 2. If IsCallable(callbackfn) is false, throw a TypeError exception.
+    let callback = callback.as_callable().ok_or_else(|| {
+        JsNativeError::typ().with_message("GroupBy: callback function is not callable")
+    })?;
+
+    // 3. Let groups be a new empty List.
+    let mut groups: IndexMap<K, Vec<JsValue>> = IndexMap::new();
+
+    // 4. Let iteratorRecord be ? GetIterator(items, sync).
+    let mut iterator_record = items.get_iterator(context, Some(IteratorHint::Sync), None)?;
+
+    // 5. Let k be 0.
+    let mut k = 0u64;
+
+    // 6. Repeat,
+    loop {
+        // a. If k ≥ 2^53 - 1, then
+        if k >= Number::MAX_SAFE_INTEGER as u64 {
+            // i. Let error be ThrowCompletion(a newly created TypeError object).
+            let error = JsNativeError::typ()
+                .with_message("GroupBy: iterator produced too many values")
+                .into();
+
+            // ii. Return ? IteratorClose(iteratorRecord, error).
+            return Err(iterator_record
+                .close(Err(error), context)
+                .expect_err("closing an iterator with an error must return an error"));
+        }
+
+        // b. Let next be ? IteratorStepValue(iteratorRecord).
+        // c. If next is done, then
+        //     i. Return groups.
+        // d. Let value be next.
+        let Some(value) = iterator_record.step_value(context)? else {
+            return Ok(groups);
+        };
+
+        // e. Let key be Completion(Call(callbackfn, undefined, « value, 𝔽(k) »)).
+        // f. IfAbruptCloseIterator(key, iteratorRecord).
+        // g. If keyCoercion is property, then
+        //     i. Set key to Completion(ToPropertyKey(key)).
+        //     ii. IfAbruptCloseIterator(key, iteratorRecord).
+        // h. Else,
+        //     i. Assert: keyCoercion is zero.
+        //     ii. If key is -0𝔽, set key to +0𝔽.
+        let key = callback
+            .call(&JsValue::undefined(), &[value.clone(), k.into()], context)
+            .and_then(|key| key_coercion(key, context));
+        let key = match key {
+            Ok(key) => key,
+            Err(err) => {
+                return Err(iterator_record
+                    .close(Err(err), context)
+                    .expect_err("closing an iterator with an error must return an error"))
+            }
+        };
+
+        // i. Perform AddValueToKeyedGroup(groups, key, value).
+        groups.entry(key).or_default().push(value);
+
+        // j. Set k to k + 1.
+        k += 1;
+    }
+}
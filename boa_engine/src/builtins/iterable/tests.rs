@@ -0,0 +1,211 @@
+use crate::{run_test_actions, JsNativeErrorKind, TestAction};
+use indoc::indoc;
+
+#[test]
+fn iterator_constructor() {
+    run_test_actions([
+        TestAction::assert_eq("typeof Iterator", "function"),
+        TestAction::assert("Object.getPrototypeOf([].values()) instanceof Iterator"),
+        TestAction::assert("(function* () {})() instanceof Iterator"),
+        TestAction::assert_native_error(
+            "new Iterator()",
+            JsNativeErrorKind::Type,
+            "Iterator: the constructor is abstract and cannot be called directly",
+        ),
+        TestAction::assert_native_error(
+            "Iterator()",
+            JsNativeErrorKind::Type,
+            "Iterator: the constructor is abstract and cannot be called directly",
+        ),
+        TestAction::assert("new (class extends Iterator {})() instanceof Iterator"),
+        TestAction::assert_eq("Iterator.prototype[Symbol.toStringTag]", "Iterator"),
+        TestAction::assert_eq(
+            "[].values().map(x => x)[Symbol.toStringTag]",
+            "Iterator Helper",
+        ),
+    ]);
+}
+
+#[test]
+fn lazy_helpers() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::run(indoc! {r"
+                function* naturals() {
+                    let i = 0;
+                    while (true) {
+                        yield i++;
+                    }
+                }
+            "}),
+        TestAction::assert(
+            "arrayEquals(naturals().map(x => x * 2).filter(x => x % 3 === 0).take(4).toArray(), [0, 6, 12, 18])",
+        ),
+        TestAction::assert("arrayEquals(naturals().drop(3).take(2).toArray(), [3, 4])"),
+        TestAction::assert(
+            "arrayEquals(naturals().flatMap(x => [x, -x]).take(5).toArray(), [0, -0, 1, -1, 2])",
+        ),
+        TestAction::assert(
+            "arrayEquals(naturals().map((_, i) => i).take(3).toArray(), [0, 1, 2])",
+        ),
+        TestAction::assert_native_error(
+            "naturals().flatMap(x => x).next()",
+            JsNativeErrorKind::Type,
+            "value with type `number` is not an iterator nor an iterable object",
+        ),
+        TestAction::assert_native_error(
+            "naturals().take(-1)",
+            JsNativeErrorKind::Range,
+            "Iterator.prototype.take: `limit` must be positive",
+        ),
+        TestAction::assert_native_error(
+            "naturals().drop(NaN)",
+            JsNativeErrorKind::Range,
+            "Iterator.prototype.drop: `limit` must not be NaN",
+        ),
+    ]);
+}
+
+#[test]
+fn eager_helpers() {
+    run_test_actions([
+        TestAction::run(indoc! {r"
+                function* upTo(n) {
+                    for (let i = 1; i <= n; i++) {
+                        yield i;
+                    }
+                }
+                let sum = 0;
+                upTo(4).forEach(x => sum += x);
+            "}),
+        TestAction::assert_eq("sum", 10),
+        TestAction::assert_eq("upTo(4).reduce((a, b) => a + b)", 10),
+        TestAction::assert_eq("upTo(4).reduce((a, b) => a + b, 10)", 20),
+        TestAction::assert("upTo(4).some(x => x > 3)"),
+        TestAction::assert("!upTo(4).every(x => x > 3)"),
+        TestAction::assert_eq("upTo(4).find(x => x > 2)", 3),
+        TestAction::assert_eq("upTo(4).find(x => x > 4)", crate::JsValue::undefined()),
+        TestAction::assert_native_error(
+            "upTo(0).reduce((a, b) => a + b)",
+            JsNativeErrorKind::Type,
+            "Iterator.prototype.reduce: cannot reduce an empty iterator without an initial value",
+        ),
+    ]);
+}
+
+#[test]
+fn closing() {
+    run_test_actions([
+        TestAction::run(indoc! {r"
+                let closed = 0;
+                function* gen() {
+                    try {
+                        yield 1;
+                        yield 2;
+                        yield 3;
+                    } finally {
+                        closed++;
+                    }
+                }
+            "}),
+        TestAction::run("gen().take(1).toArray()"),
+        TestAction::assert_eq("closed", 1),
+        TestAction::run("gen().some(x => x === 2)"),
+        TestAction::assert_eq("closed", 2),
+        TestAction::run("gen().find(x => x === 1)"),
+        TestAction::assert_eq("closed", 3),
+        TestAction::run(indoc! {r"
+                const helper = gen().map(x => x);
+                helper.next();
+                helper.return();
+            "}),
+        TestAction::assert_eq("closed", 4),
+        TestAction::assert("helper.next().done"),
+        TestAction::assert_opaque_error("gen().map(x => { throw 'mapper'; }).next()", "mapper"),
+        TestAction::assert_eq("closed", 5),
+        TestAction::run(indoc! {r"
+                const spy = Iterator.from({
+                    next() { return { value: 1, done: false }; },
+                    return() { closed++; return {}; },
+                });
+                try { spy.map(42); } catch {}
+            "}),
+        TestAction::assert_eq("closed", 6),
+    ]);
+}
+
+#[test]
+fn iterator_from() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::run(indoc! {r"
+                let i = 0;
+                const plain = { next() { return { value: i++, done: i > 3 }; } };
+                const wrapped = Iterator.from(plain);
+            "}),
+        TestAction::assert("wrapped instanceof Iterator"),
+        TestAction::assert("wrapped !== plain"),
+        TestAction::assert("arrayEquals(wrapped.map(x => x + 1).toArray(), [1, 2, 3])"),
+        TestAction::assert("arrayEquals(Iterator.from('ab').toArray(), ['a', 'b'])"),
+        TestAction::run("const values = [1].values();"),
+        TestAction::assert("Iterator.from(values) === values"),
+        TestAction::assert_native_error(
+            "Iterator.from(1)",
+            JsNativeErrorKind::Type,
+            "value with type `number` is not an iterator nor an iterable object",
+        ),
+    ]);
+}
+
+#[test]
+fn async_helpers() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::run(indoc! {r"
+                let closed = 0;
+                async function* naturals() {
+                    let i = 0;
+                    try {
+                        while (true) {
+                            yield i++;
+                        }
+                    } finally {
+                        closed++;
+                    }
+                }
+                let mapped, dropped, flattened, reduced, found, fromSync, rejected;
+                naturals()
+                    .map(async x => x * 2)
+                    .filter(x => x > 2)
+                    .take(3)
+                    .toArray()
+                    .then(v => mapped = v);
+                naturals().drop(2).take(2).toArray().then(v => dropped = v);
+                naturals().flatMap(x => [x, x + 10]).take(4).toArray().then(v => flattened = v);
+                naturals().take(4).reduce((a, b) => a + b).then(v => reduced = v);
+                naturals().find(x => x === 3).then(v => found = v);
+                AsyncIterator.from([1, 2, 3]).map(x => x + 1).toArray().then(v => fromSync = v);
+                naturals().forEach(x => { throw x; }).catch(e => rejected = e);
+            "}),
+        #[allow(clippy::redundant_closure_for_method_calls)]
+        TestAction::inspect_context(|ctx| ctx.run_jobs()),
+        TestAction::assert("arrayEquals(mapped, [4, 6, 8])"),
+        TestAction::assert("arrayEquals(dropped, [2, 3])"),
+        TestAction::assert("arrayEquals(flattened, [0, 10, 1, 11])"),
+        TestAction::assert_eq("reduced", 6),
+        TestAction::assert_eq("found", 3),
+        TestAction::assert("arrayEquals(fromSync, [2, 3, 4])"),
+        TestAction::assert_eq("rejected", 0),
+        TestAction::assert_eq("closed", 6),
+        TestAction::assert_native_error(
+            "naturals().take(-1)",
+            JsNativeErrorKind::Range,
+            "AsyncIterator.prototype.take: `limit` must be positive",
+        ),
+        TestAction::assert_native_error(
+            "new AsyncIterator()",
+            JsNativeErrorKind::Type,
+            "AsyncIterator: the constructor is abstract and cannot be called directly",
+        ),
+    ]);
+}
@@ -11,7 +11,7 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map
 
 use crate::{
-    builtins::BuiltInObject,
+    builtins::{iterable::group_by, Array, BuiltInObject},
     context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
     error::JsNativeError,
     object::{internal_methods::get_prototype_from_constructor, JsObject, ObjectData},
@@ -60,6 +60,7 @@ impl IntrinsicObject for Map {
                 None,
                 Attribute::CONFIGURABLE,
             )
+            .static_method(Self::group_by, "groupBy", 2)
             .property(
                 utf16!("entries"),
                 entries_function.clone(),
@@ -170,6 +171,61 @@ impl Map {
         Ok(this.clone())
     }
 
+    /// `Map.groupBy ( items, callbackfn )`
+    ///
+    /// Groups the values of an iterable into a new `Map`, using the keys returned by
+    /// `callbackfn`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-map.groupby
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/groupBy
+    pub(crate) fn group_by(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        let items = args.get_or_undefined(0);
+        let callback = args.get_or_undefined(1);
+
+        // 1. Let groups be ? GroupBy(items, callbackfn, zero).
+        let groups = group_by(
+            items,
+            callback,
+            |key, _| {
+                // ii. If key is -0𝔽, set key to +0𝔽.
+                Ok(match key.as_number() {
+                    Some(n) if n.is_zero() => JsValue::Integer(0),
+                    _ => key,
+                })
+            },
+            context,
+        )?;
+
+        // 2. Let map be ! Construct(%Map%).
+        let mut map_data = OrderedMap::with_capacity(groups.len());
+
+        // 3. For each Record { [[Key]], [[Elements]] } g of groups, do
+        for (key, elements) in groups {
+            // a. Let elements be CreateArrayFromList(g.[[Elements]]).
+            let elements = Array::create_array_from_list(elements, context);
+
+            // b. Let entry be the Record { [[Key]]: g.[[Key]], [[Value]]: elements }.
+            // c. Append entry to map.[[MapData]].
+            map_data.insert(key, elements.into());
+        }
+
+        // 4. Return map.
+        let map = JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            context.intrinsics().constructors().map().prototype(),
+            ObjectData::map(map_data),
+        );
+        Ok(map.into())
+    }
+
     /// `Map.prototype.entries()`
     ///
     /// Returns a new Iterator object that contains the [key, value] pairs for each element in the Map object in insertion order.
@@ -304,3 +304,23 @@ fn for_of_delete() {
             "#}),
     ]);
 }
+
+#[test]
+fn group_by() {
+    run_test_actions([
+        TestAction::run(indoc! {r"
+            const grouped = Map.groupBy([1, 2, 3, 4, -0], (x) => x === -0 ? 0 : x % 2);
+        "}),
+        TestAction::assert("grouped instanceof Map"),
+        TestAction::assert_eq("grouped.size", 2),
+        TestAction::assert_eq("grouped.get(1).join()", "1,3"),
+        TestAction::assert_eq("grouped.get(0).join()", "2,4,0"),
+        TestAction::assert_eq("[...grouped.keys()].join()", "1,0"),
+        TestAction::assert("Object.is(Map.groupBy([0], () => -0).keys().next().value, 0)"),
+        TestAction::assert_native_error(
+            "Map.groupBy([], 1)",
+            JsNativeErrorKind::Type,
+            "GroupBy: callback function is not callable",
+        ),
+    ]);
+}
@@ -5,10 +5,12 @@ pub mod array_buffer;
 pub mod async_function;
 pub mod async_generator;
 pub mod async_generator_function;
+pub mod atomics;
 pub mod bigint;
 pub mod boolean;
 pub mod dataview;
 pub mod date;
+pub mod disposable_stack;
 pub mod error;
 pub mod eval;
 pub mod function;
@@ -25,6 +27,7 @@ pub mod proxy;
 pub mod reflect;
 pub mod regexp;
 pub mod set;
+pub mod shadow_realm;
 pub mod string;
 pub mod symbol;
 pub mod typed_array;
@@ -33,22 +36,30 @@ pub mod weak;
 pub mod weak_map;
 pub mod weak_set;
 
+#[cfg(any(feature = "intl", feature = "temporal"))]
+mod options;
+
 #[cfg(feature = "annex-b")]
 pub mod escape;
 
 #[cfg(feature = "intl")]
 pub mod intl;
 
+#[cfg(feature = "temporal")]
+pub mod temporal;
+
 pub(crate) use self::{
     array::Array,
     async_function::AsyncFunction,
+    atomics::Atomics,
     bigint::BigInt,
     boolean::Boolean,
     dataview::DataView,
     date::Date,
+    disposable_stack::{AsyncDisposableStack, DisposableStack},
     error::{
-        AggregateError, Error, EvalError, RangeError, ReferenceError, SyntaxError, TypeError,
-        UriError,
+        AggregateError, Error, EvalError, RangeError, ReferenceError, SuppressedError, SyntaxError,
+        TypeError, UriError,
     },
     eval::Eval,
     function::BuiltInFunctionObject,
@@ -62,6 +73,7 @@ pub(crate) use self::{
     reflect::Reflect,
     regexp::RegExp,
     set::Set,
+    shadow_realm::ShadowRealm,
     string::String,
     symbol::Symbol,
     typed_array::{
@@ -73,13 +85,16 @@ pub(crate) use self::{
 use crate::{
     builtins::{
         array::ArrayIterator,
-        array_buffer::ArrayBuffer,
+        array_buffer::{ArrayBuffer, SharedArrayBuffer},
         async_generator::AsyncGenerator,
         async_generator_function::AsyncGeneratorFunction,
         error::r#type::ThrowTypeError,
         generator::Generator,
         generator_function::GeneratorFunction,
-        iterable::{AsyncFromSyncIterator, AsyncIterator, Iterator},
+        iterable::{
+            AsyncFromSyncIterator, AsyncIterator, AsyncIteratorHelper, Iterator, IteratorHelper,
+            WrapForValidAsyncIterator, WrapForValidIterator,
+        },
         map::MapIterator,
         object::for_in_iterator::ForInIterator,
         regexp::RegExpStringIterator,
@@ -87,7 +102,7 @@ use crate::{
         string::StringIterator,
         typed_array::TypedArray,
         uri::{DecodeUri, DecodeUriComponent, EncodeUri, EncodeUriComponent},
-        weak::WeakRef,
+        weak::{FinalizationRegistry, WeakRef},
         weak_map::WeakMap,
         weak_set::WeakSet,
     },
@@ -191,13 +206,19 @@ impl Realm {
         Iterator::init(self);
         AsyncIterator::init(self);
         AsyncFromSyncIterator::init(self);
+        IteratorHelper::init(self);
+        WrapForValidIterator::init(self);
+        AsyncIteratorHelper::init(self);
+        WrapForValidAsyncIterator::init(self);
         ForInIterator::init(self);
         Math::init(self);
         Json::init(self);
+        Atomics::init(self);
         Array::init(self);
         ArrayIterator::init(self);
         Proxy::init(self);
         ArrayBuffer::init(self);
+        SharedArrayBuffer::init(self);
         BigInt::init(self);
         Boolean::init(self);
         Date::init(self);
@@ -238,6 +259,7 @@ impl Realm {
         EvalError::init(self);
         UriError::init(self);
         AggregateError::init(self);
+        SuppressedError::init(self);
         Reflect::init(self);
         Generator::init(self);
         GeneratorFunction::init(self);
@@ -250,6 +272,10 @@ impl Realm {
         DecodeUri::init(self);
         DecodeUriComponent::init(self);
         WeakRef::init(self);
+        FinalizationRegistry::init(self);
+        DisposableStack::init(self);
+        AsyncDisposableStack::init(self);
+        ShadowRealm::init(self);
         WeakMap::init(self);
         WeakSet::init(self);
 
@@ -263,13 +289,31 @@ impl Realm {
         {
             intl::Intl::init(self);
             intl::Collator::init(self);
+            intl::DisplayNames::init(self);
             intl::ListFormat::init(self);
             intl::Locale::init(self);
+            intl::NumberFormat::init(self);
+            intl::PluralRules::init(self);
+            intl::RelativeTimeFormat::init(self);
             intl::DateTimeFormat::init(self);
             intl::Segmenter::init(self);
             intl::segmenter::Segments::init(self);
             intl::segmenter::SegmentIterator::init(self);
         }
+
+        #[cfg(feature = "temporal")]
+        {
+            temporal::Temporal::init(self);
+            temporal::Now::init(self);
+            temporal::Calendar::init(self);
+            temporal::Duration::init(self);
+            temporal::Instant::init(self);
+            temporal::PlainDate::init(self);
+            temporal::PlainDateTime::init(self);
+            temporal::PlainTime::init(self);
+            temporal::TimeZone::init(self);
+            temporal::ZonedDateTime::init(self);
+        }
     }
 }
 
@@ -310,11 +354,15 @@ pub(crate) fn set_default_global_bindings(context: &mut Context<'_>) -> JsResult
 
     global_binding::<BuiltInFunctionObject>(context)?;
     global_binding::<BuiltInObjectObject>(context)?;
+    global_binding::<Iterator>(context)?;
+    global_binding::<AsyncIterator>(context)?;
     global_binding::<Math>(context)?;
     global_binding::<Json>(context)?;
+    global_binding::<Atomics>(context)?;
     global_binding::<Array>(context)?;
     global_binding::<Proxy>(context)?;
     global_binding::<ArrayBuffer>(context)?;
+    global_binding::<SharedArrayBuffer>(context)?;
     global_binding::<BigInt>(context)?;
     global_binding::<Boolean>(context)?;
     global_binding::<Date>(context)?;
@@ -350,6 +398,7 @@ pub(crate) fn set_default_global_bindings(context: &mut Context<'_>) -> JsResult
     global_binding::<EvalError>(context)?;
     global_binding::<UriError>(context)?;
     global_binding::<AggregateError>(context)?;
+    global_binding::<SuppressedError>(context)?;
     global_binding::<Reflect>(context)?;
     global_binding::<Promise>(context)?;
     global_binding::<EncodeUri>(context)?;
@@ -357,6 +406,10 @@ pub(crate) fn set_default_global_bindings(context: &mut Context<'_>) -> JsResult
     global_binding::<DecodeUri>(context)?;
     global_binding::<DecodeUriComponent>(context)?;
     global_binding::<WeakRef>(context)?;
+    global_binding::<FinalizationRegistry>(context)?;
+    global_binding::<DisposableStack>(context)?;
+    global_binding::<AsyncDisposableStack>(context)?;
+    global_binding::<ShadowRealm>(context)?;
     global_binding::<WeakMap>(context)?;
     global_binding::<WeakSet>(context)?;
 
@@ -369,6 +422,9 @@ pub(crate) fn set_default_global_bindings(context: &mut Context<'_>) -> JsResult
     #[cfg(feature = "intl")]
     global_binding::<intl::Intl>(context)?;
 
+    #[cfg(feature = "temporal")]
+    global_binding::<temporal::Temporal>(context)?;
+
     Ok(())
 }
 
@@ -44,6 +44,7 @@ fn to_fixed() {
     ]);
 }
 
+#[cfg(not(feature = "intl"))]
 #[test]
 fn to_locale_string() {
     // TODO: We don't actually do any locale checking here
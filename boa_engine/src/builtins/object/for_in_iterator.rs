@@ -42,13 +42,7 @@ impl IntrinsicObject for ForInIterator {
         let _timer = Profiler::global().start_event("ForInIterator", "init");
 
         BuiltInBuilder::with_intrinsic::<Self>(realm)
-            .prototype(
-                realm
-                    .intrinsics()
-                    .objects()
-                    .iterator_prototypes()
-                    .iterator(),
-            )
+            .prototype(realm.intrinsics().constructors().iterator().prototype())
             .static_method(Self::next, "next", 0)
             .build();
     }
@@ -17,7 +17,7 @@ use std::ops::Deref;
 
 use super::{Array, BuiltInBuilder, BuiltInConstructor, IntrinsicObject};
 use crate::{
-    builtins::{map, BuiltInObject},
+    builtins::{iterable::group_by, map, BuiltInObject},
     context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
     error::JsNativeError,
     js_string,
@@ -104,6 +104,7 @@ impl IntrinsicObject for Object {
             .static_method(Self::get_own_property_symbols, "getOwnPropertySymbols", 1)
             .static_method(Self::has_own, "hasOwn", 2)
             .static_method(Self::from_entries, "fromEntries", 1)
+            .static_method(Self::group_by, "groupBy", 2)
             .build();
     }
 
@@ -527,7 +528,7 @@ impl Object {
         context: &mut Context<'_>,
     ) -> JsValue {
         // 1. If Desc is undefined, return undefined.
-        let Some(desc)= desc else {
+        let Some(desc) = desc else {
             return JsValue::undefined();
         };
 
@@ -819,7 +820,7 @@ impl Object {
             match o.borrow().kind() {
                 ObjectKind::Arguments(_) => utf16!("Arguments"),
                 _ if o.is_callable() => utf16!("Function"),
-                ObjectKind::Error(_) => utf16!("Error"),
+                ObjectKind::Error(..) => utf16!("Error"),
                 ObjectKind::Boolean(_) => utf16!("Boolean"),
                 ObjectKind::Number(_) => utf16!("Number"),
                 ObjectKind::String(_) => utf16!("String"),
@@ -1308,6 +1309,50 @@ impl Object {
         // 6. Return ? AddEntriesFromIterable(obj, iterable, adder).
         map::add_entries_from_iterable(&obj, iterable, &adder.into(), context)
     }
+
+    /// `Object.groupBy ( items, callbackfn )`
+    ///
+    /// Groups the values of an iterable into a null-prototype object, using the property keys
+    /// returned by `callbackfn`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.groupby
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/groupBy
+    pub(crate) fn group_by(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        let items = args.get_or_undefined(0);
+        let callback = args.get_or_undefined(1);
+
+        // 1. Let groups be ? GroupBy(items, callbackfn, property).
+        let groups = group_by(
+            items,
+            callback,
+            |key, context| key.to_property_key(context),
+            context,
+        )?;
+
+        // 2. Let obj be OrdinaryObjectCreate(null).
+        let obj = JsObject::with_null_proto();
+
+        // 3. For each Record { [[Key]], [[Elements]] } g of groups, do
+        for (key, elements) in groups {
+            // a. Let elements be CreateArrayFromList(g.[[Elements]]).
+            let elements = Array::create_array_from_list(elements, context);
+
+            // b. Perform ! CreateDataPropertyOrThrow(obj, g.[[Key]], elements).
+            obj.create_data_property_or_throw(key, elements, context)
+                .expect("cannot fail for a fresh null-prototype object");
+        }
+
+        // 4. Return obj.
+        Ok(obj.into())
+    }
 }
 
 /// The abstract operation `ObjectDefineProperties`
@@ -422,3 +422,25 @@ fn object_from_entries() {
         TestAction::assert_eq("map[5]", 4),
     ]);
 }
+
+#[test]
+fn object_group_by() {
+    run_test_actions([
+        TestAction::run(indoc! {r"
+            const grouped = Object.groupBy([1, 2, 3, 4, 5], (x, i) => x % 2 ? 'odd' : 'even');
+        "}),
+        TestAction::assert("Object.getPrototypeOf(grouped) === null"),
+        TestAction::assert_eq("Object.keys(grouped).join()", "odd,even"),
+        TestAction::assert_eq("grouped.odd.join()", "1,3,5"),
+        TestAction::assert_eq("grouped.even.join()", "2,4"),
+        TestAction::assert_eq(
+            "Object.keys(Object.groupBy('abca', (c) => c)).join()",
+            "a,b,c",
+        ),
+        TestAction::assert_native_error(
+            "Object.groupBy(null, () => 0)",
+            JsNativeErrorKind::Type,
+            "cannot convert null or undefined to Object",
+        ),
+    ]);
+}
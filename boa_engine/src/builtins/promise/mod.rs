@@ -331,6 +331,7 @@ impl IntrinsicObject for Promise {
             .static_method(Self::race, "race", 1)
             .static_method(Self::reject, "reject", 1)
             .static_method(Self::resolve, "resolve", 1)
+            .static_method(Self::with_resolvers, "withResolvers", 0)
             .static_accessor(
                 JsSymbol::species(),
                 Some(get_species),
@@ -1488,6 +1489,60 @@ impl Promise {
         Self::promise_resolve(c, x.clone(), context).map(JsValue::from)
     }
 
+    /// `Promise.withResolvers ( )`
+    ///
+    /// Creates a new promise and returns it together with its resolving functions.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.withResolvers
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/withResolvers
+    pub(crate) fn with_resolvers(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. Let C be the this value.
+        let c = this.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("Promise.withResolvers() called on a non-object")
+        })?;
+
+        // 2. Let promiseCapability be ? NewPromiseCapability(C).
+        let promise_capability = PromiseCapability::new(c, context)?;
+
+        // 3. Let obj be OrdinaryObjectCreate(%Object.prototype%).
+        let obj = JsObject::with_object_proto(context.intrinsics());
+
+        // 4. Perform ! CreateDataPropertyOrThrow(obj, "promise", promiseCapability.[[Promise]]).
+        obj.create_data_property_or_throw(
+            utf16!("promise"),
+            promise_capability.promise.clone(),
+            context,
+        )
+        .expect("cannot fail per spec");
+
+        // 5. Perform ! CreateDataPropertyOrThrow(obj, "resolve", promiseCapability.[[Resolve]]).
+        obj.create_data_property_or_throw(
+            utf16!("resolve"),
+            promise_capability.resolve.clone(),
+            context,
+        )
+        .expect("cannot fail per spec");
+
+        // 6. Perform ! CreateDataPropertyOrThrow(obj, "reject", promiseCapability.[[Reject]]).
+        obj.create_data_property_or_throw(
+            utf16!("reject"),
+            promise_capability.reject.clone(),
+            context,
+        )
+        .expect("cannot fail per spec");
+
+        // 7. Return obj.
+        Ok(obj.into())
+    }
+
     /// `PromiseResolve ( C, x )`
     ///
     /// The abstract operation `PromiseResolve` takes arguments `C` (a constructor) and `x` (an
@@ -1600,7 +1655,11 @@ impl Promise {
 
         let on_finally = args.get_or_undefined(0);
 
-        let Some(on_finally) = on_finally.as_object().cloned().and_then(JsFunction::from_object) else {
+        let Some(on_finally) = on_finally
+            .as_object()
+            .cloned()
+            .and_then(JsFunction::from_object)
+        else {
             // 5. If IsCallable(onFinally) is false, then
             //    a. Let thenFinally be onFinally.
             //    b. Let catchFinally be onFinally.
@@ -2158,7 +2217,11 @@ impl Promise {
                     };
 
                     // 12. If IsCallable(thenAction) is false, then
-                    let Some(then_action) = then_action.as_object().cloned().and_then(JsFunction::from_object) else {
+                    let Some(then_action) = then_action
+                        .as_object()
+                        .cloned()
+                        .and_then(JsFunction::from_object)
+                    else {
                         // a. Perform FulfillPromise(promise, resolution).
                         fulfill_promise(promise, resolution.clone(), context);
 
@@ -2167,14 +2230,15 @@ impl Promise {
                     };
 
                     // 13. Let thenJobCallback be HostMakeJobCallback(thenAction).
-                    let then_job_callback = context.host_hooks().make_job_callback(then_action, context);
+                    let then_job_callback =
+                        context.host_hooks().make_job_callback(then_action, context);
 
                     // 14. Let job be NewPromiseResolveThenableJob(promise, resolution, thenJobCallback).
                     let job = new_promise_resolve_thenable_job(
                         promise.clone(),
                         resolution.clone(),
                         then_job_callback,
-                        context
+                        context,
                     );
 
                     // 15. Perform HostEnqueuePromiseJob(job.[[Job]], job.[[Realm]]).
@@ -2289,10 +2353,16 @@ fn new_promise_reaction_job(
                 }
             },
             //   e. Else, let handlerResult be Completion(HostCallJobCallback(handler, undefined, « argument »)).
-            Some(handler) => context
-                .host_hooks()
-                .call_job_callback(handler, &JsValue::Undefined, &[argument.clone()], context)
-                .map_err(|e| e.to_opaque(context)),
+            Some(handler) => match context.host_hooks().call_job_callback(
+                handler,
+                &JsValue::Undefined,
+                &[argument.clone()],
+                context,
+            ) {
+                // Runtime limit errors cannot be caught, so they are propagated to the job runner.
+                Err(e) if e.is_runtime_limit() => return Err(e),
+                result => result.map_err(|e| e.to_opaque(context)),
+            },
         };
 
         match promise_capability {
@@ -18,3 +18,28 @@ fn promise() {
         TestAction::assert_eq("count", 3),
     ]);
 }
+
+#[test]
+fn with_resolvers() {
+    run_test_actions([
+        TestAction::run(indoc! {r"
+            const { promise, resolve, reject } = Promise.withResolvers();
+            let value;
+            promise.then((v) => { value = v; });
+            resolve('resolved');
+        "}),
+        TestAction::assert("promise instanceof Promise"),
+        TestAction::assert_eq("typeof reject", "function"),
+        #[allow(clippy::redundant_closure_for_method_calls)]
+        TestAction::inspect_context(|ctx| ctx.run_jobs()),
+        TestAction::assert_eq("value", "resolved"),
+        TestAction::assert_eq(
+            "Object.keys(Promise.withResolvers()).join()",
+            "promise,resolve,reject",
+        ),
+        TestAction::assert(indoc! {r"
+            class MyPromise extends Promise {}
+            MyPromise.withResolvers().promise instanceof MyPromise
+        "}),
+    ]);
+}
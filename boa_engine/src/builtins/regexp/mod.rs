@@ -25,9 +25,9 @@ use crate::{
     value::JsValue,
     Context, JsArgs, JsResult, JsString,
 };
-use boa_parser::lexer::regex::RegExpFlags;
+use boa_parser::lexer::regex::{create_matcher, RegExpFlags};
 use boa_profiler::Profiler;
-use regress::{Flags, Regex};
+use regress::Regex;
 use std::str::FromStr;
 
 use super::{BuiltInBuilder, BuiltInConstructor, IntrinsicObject};
@@ -75,6 +75,9 @@ impl IntrinsicObject for RegExp {
         let get_unicode = BuiltInBuilder::callable(realm, Self::get_unicode)
             .name("get unicode")
             .build();
+        let get_unicode_sets = BuiltInBuilder::callable(realm, Self::get_unicode_sets)
+            .name("get unicodeSets")
+            .build();
         let get_sticky = BuiltInBuilder::callable(realm, Self::get_sticky)
             .name("get sticky")
             .build();
@@ -125,6 +128,12 @@ impl IntrinsicObject for RegExp {
             )
             .accessor(utf16!("dotAll"), Some(get_dot_all), None, flag_attributes)
             .accessor(utf16!("unicode"), Some(get_unicode), None, flag_attributes)
+            .accessor(
+                utf16!("unicodeSets"),
+                Some(get_unicode_sets),
+                None,
+                flag_attributes,
+            )
             .accessor(utf16!("sticky"), Some(get_sticky), None, flag_attributes)
             .accessor(utf16!("flags"), Some(get_flags), None, flag_attributes)
             .accessor(utf16!("source"), Some(get_source), None, flag_attributes);
@@ -320,7 +329,7 @@ impl RegExp {
             flags.to_string(context)?
         };
 
-        // 5. If F contains any code unit other than "g", "i", "m", "s", "u", or "y"
+        // 5. If F contains any code unit other than "d", "g", "i", "m", "s", "u", "v", or "y"
         //    or if it contains the same code unit more than once, throw a SyntaxError exception.
         // TODO: Should directly parse the JsString instead of converting to String
         let flags = match RegExpFlags::from_str(&f.to_std_string_escaped()) {
@@ -341,15 +350,14 @@ impl RegExp {
         // 18. Let rer be the RegExp Record { [[IgnoreCase]]: i, [[Multiline]]: m, [[DotAll]]: s, [[Unicode]]: u, [[CapturingGroupsCount]]: capturingGroupsCount }.
         // 19. Set obj.[[RegExpRecord]] to rer.
         // 20. Set obj.[[RegExpMatcher]] to CompilePattern of parseResult with argument rer.
-        let matcher =
-            match Regex::from_unicode(p.code_points().map(CodePoint::as_u32), Flags::from(flags)) {
-                Err(error) => {
-                    return Err(JsNativeError::syntax()
-                        .with_message(format!("failed to create matcher: {}", error.text))
-                        .into());
-                }
-                Ok(val) => val,
-            };
+        let matcher = match create_matcher(p.code_points().map(CodePoint::as_u32), flags) {
+            Err(error) => {
+                return Err(JsNativeError::syntax()
+                    .with_message(format!("failed to create matcher: {}", error.text))
+                    .into());
+            }
+            Ok(val) => val,
+        };
 
         let regexp = Self {
             matcher,
@@ -408,6 +416,7 @@ impl RegExp {
                     b's' => regexp.flags.contains(RegExpFlags::DOT_ALL),
                     b'i' => regexp.flags.contains(RegExpFlags::IGNORE_CASE),
                     b'u' => regexp.flags.contains(RegExpFlags::UNICODE),
+                    b'v' => regexp.flags.contains(RegExpFlags::UNICODE_SETS),
                     b'y' => regexp.flags.contains(RegExpFlags::STICKY),
                     _ => unreachable!(),
                 }));
@@ -428,6 +437,7 @@ impl RegExp {
             b's' => "dotAll",
             b'i' => "ignoreCase",
             b'u' => "unicode",
+            b'v' => "unicodeSets",
             b'y' => "sticky",
             _ => unreachable!(),
         };
@@ -546,6 +556,25 @@ impl RegExp {
         Self::regexp_has_flag(this, b'u', context)
     }
 
+    /// `get RegExp.prototype.unicodeSets`
+    ///
+    /// The unicodeSets property indicates whether or not the "`v`" flag is used with a regular expression.
+    /// unicodeSets is a read-only property of an individual regular expression instance.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-regexp.prototype.unicodesets
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/unicodeSets
+    pub(crate) fn get_unicode_sets(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        Self::regexp_has_flag(this, b'v', context)
+    }
+
     /// `get RegExp.prototype.sticky`
     ///
     /// This flag indicates that it matches only from the index indicated by the `lastIndex` property
@@ -621,13 +650,19 @@ impl RegExp {
                 result.push('u');
             }
 
-            // 16. Let sticky be ! ToBoolean(? Get(R, "sticky")).
-            // 17. If sticky is true, append the code unit 0x0079 (LATIN SMALL LETTER Y) as the last code unit of result.
+            // 16. Let unicodeSets be ToBoolean(? Get(R, "unicodeSets")).
+            // 17. If unicodeSets is true, append the code unit 0x0076 (LATIN SMALL LETTER V) as the last code unit of result.
+            if object.get(utf16!("unicodeSets"), context)?.to_boolean() {
+                result.push('v');
+            }
+
+            // 18. Let sticky be ! ToBoolean(? Get(R, "sticky")).
+            // 19. If sticky is true, append the code unit 0x0079 (LATIN SMALL LETTER Y) as the last code unit of result.
             if object.get(utf16!("sticky"), context)?.to_boolean() {
                 result.push('y');
             }
 
-            // 18. Return result.
+            // 20. Return result.
             return Ok(result.into());
         }
 
@@ -885,8 +920,8 @@ impl RegExp {
         // 9. Let matcher be R.[[RegExpMatcher]].
         let matcher = &rx.matcher;
 
-        // 10. If flags contains "u", let fullUnicode be true; else let fullUnicode be false.
-        let unicode = flags.contains(&('u' as u16));
+        // 10. If flags contains "u" or flags contains "v", let fullUnicode be true; else let fullUnicode be false.
+        let unicode = flags.contains(&('u' as u16)) || flags.contains(&('v' as u16));
 
         // 11. Let matchSucceeded be false.
         // 12. Repeat, while matchSucceeded is false,
@@ -1084,23 +1119,21 @@ impl RegExp {
         // 3. Let S be ? ToString(string).
         let arg_str = args.get_or_undefined(0).to_string(context)?;
 
-        // 4. Let global be ! ToBoolean(? Get(rx, "global")).
-        let global = rx.get(utf16!("global"), context)?.to_boolean();
+        // 4. Let flags be ? ToString(? Get(rx, "flags")).
+        let flags = rx.get(utf16!("flags"), context)?.to_string(context)?;
 
-        // 5. If global is false, then
+        // 5. If flags does not contain "g", then
         #[allow(clippy::if_not_else)]
-        if !global {
+        if !flags.contains(&u16::from(b'g')) {
             // a. Return ? RegExpExec(rx, S).
             (Self::abstract_exec(rx, arg_str, context)?)
                 .map_or_else(|| Ok(JsValue::null()), |v| Ok(v.into()))
         // 6. Else,
         } else {
-            // a. Assert: global is true.
-
-            // b. Let fullUnicode be ! ToBoolean(? Get(rx, "unicode")).
-            let unicode = rx.get(utf16!("unicode"), context)?.to_boolean();
+            // a. If flags contains "u" or flags contains "v", let fullUnicode be true. Otherwise, let fullUnicode be false.
+            let unicode = flags.contains(&u16::from(b'u')) || flags.contains(&u16::from(b'v'));
 
-            // c. Perform ? Set(rx, "lastIndex", +0𝔽, true).
+            // b. Perform ? Set(rx, "lastIndex", +0𝔽, true).
             rx.set(utf16!("lastIndex"), 0, true, context)?;
 
             // d. Let A be ! ArrayCreate(0).
@@ -1234,9 +1267,9 @@ impl RegExp {
         // 10. Else, let global be false.
         let global = flags.contains(&('g' as u16));
 
-        // 11. If flags contains "u", let fullUnicode be true.
+        // 11. If flags contains "u" or flags contains "v", let fullUnicode be true.
         // 12. Else, let fullUnicode be false.
-        let unicode = flags.contains(&('u' as u16));
+        let unicode = flags.contains(&('u' as u16)) || flags.contains(&('v' as u16));
 
         // 13. Return ! CreateRegExpStringIterator(matcher, S, global, fullUnicode).
         Ok(RegExpStringIterator::create_regexp_string_iterator(
@@ -1306,8 +1339,8 @@ impl RegExp {
 
         // 9. If global is true, then
         let full_unicode = if global {
-            // a. If flags contains "u", let fullUnicode be true. Otherwise, let fullUnicode be false.
-            let full_unicode = flags.contains(&u16::from(b'u'));
+            // a. If flags contains "u" or flags contains "v", let fullUnicode be true. Otherwise, let fullUnicode be false.
+            let full_unicode = flags.contains(&u16::from(b'u')) || flags.contains(&u16::from(b'v'));
 
             // b. Perform ? Set(rx, "lastIndex", +0𝔽, true).
             rx.set(utf16!("lastIndex"), 0, true, context)?;
@@ -1574,9 +1607,9 @@ impl RegExp {
         // 5. Let flags be ? ToString(? Get(rx, "flags")).
         let flags = rx.get(utf16!("flags"), context)?.to_string(context)?;
 
-        // 6. If flags contains "u", let unicodeMatching be true.
+        // 6. If flags contains "u" or flags contains "v", let unicodeMatching be true.
         // 7. Else, let unicodeMatching be false.
-        let unicode = flags.contains(&('u' as u16));
+        let unicode = flags.contains(&('u' as u16)) || flags.contains(&('v' as u16));
 
         // 8. If flags contains "y", let newFlags be flags.
         // 9. Else, let newFlags be the string-concatenation of flags and "y".
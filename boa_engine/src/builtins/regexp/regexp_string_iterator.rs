@@ -45,13 +45,7 @@ impl IntrinsicObject for RegExpStringIterator {
         let _timer = Profiler::global().start_event("RegExpStringIterator", "init");
 
         BuiltInBuilder::with_intrinsic::<Self>(realm)
-            .prototype(
-                realm
-                    .intrinsics()
-                    .objects()
-                    .iterator_prototypes()
-                    .iterator(),
-            )
+            .prototype(realm.intrinsics().constructors().iterator().prototype())
             .static_method(Self::next, "next", 0)
             .static_property(
                 JsSymbol::to_string_tag(),
@@ -210,3 +210,89 @@ fn search() {
         TestAction::assert_eq("/d/[Symbol.search](undefined)", 2),
     ]);
 }
+
+#[test]
+fn unicode_sets() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::run(indoc! {r"
+                var re_v = /[\p{L}--[a-z]]/gv;
+            "}),
+        TestAction::assert("re_v.unicodeSets"),
+        TestAction::assert("!re_v.unicode"),
+        TestAction::assert_eq("re_v.flags", "gv"),
+        TestAction::assert_eq("new RegExp('a', 'vdy').flags", "dvy"),
+        TestAction::assert_eq("RegExp.prototype.unicodeSets", JsValue::undefined()),
+        TestAction::assert("arrayEquals('aBcDé'.match(re_v), ['B', 'D', 'é'])"),
+        // set operations
+        TestAction::assert(r"/^[\w&&\d]+$/v.test('0123')"),
+        TestAction::assert(r"!/^[\w&&\d]+$/v.test('a1')"),
+        TestAction::assert(r"/^[[a-z]--[aeiou]]+$/v.test('bcd')"),
+        TestAction::assert(r"!/[[a-z]--[aeiou]]/v.test('a')"),
+        TestAction::assert(r"/^[^[a-z]&&[^aeiou]]$/v.test('e')"),
+        TestAction::assert(r"!/^[^[a-z]&&[^aeiou]]$/v.test('b')"),
+        TestAction::assert(r"/^[\d--5]+$/v.test('0123') && !/[\d--5]/v.test('5')"),
+        TestAction::assert(r"/^[\-\&\u{1F600}]+$/v.test('-&\u{1F600}')"),
+        TestAction::assert(r"!/[]/v.test('a') && /^[^]$/v.test('\u{1F600}')"),
+        // class string disjunctions
+        TestAction::assert(r"arrayEquals('xabcx'.match(/[\q{abc|ab|a}]/v), ['abc'])"),
+        TestAction::assert(r"/^[\q{abc|d}--\q{abc}]$/v.test('d')"),
+        TestAction::assert(r"!/^[\q{abc|d}--\q{abc}]$/v.test('abc')"),
+        TestAction::assert(r"/^[\q{ABC}&&\q{abc}]$/vi.test('abc')"),
+        // properties of strings
+        TestAction::assert(r"/^\p{Basic_Emoji}$/v.test('\u{1F44D}')"),
+        TestAction::assert(r"/^[\p{Basic_Emoji}--\q{x}]$/v.test('\u{2764}\u{FE0F}')"),
+        TestAction::assert(r"!/^\p{Basic_Emoji}$/v.test('\u{2764}')"),
+        TestAction::assert(r"/^\p{Emoji_Keycap_Sequence}$/v.test('1️⃣')"),
+        TestAction::assert(r"/^\p{RGI_Emoji_Flag_Sequence}$/v.test('\u{1F1EB}\u{1F1F7}')"),
+        TestAction::assert(r"!/^\p{RGI_Emoji_Flag_Sequence}$/v.test('\u{1F1E6}\u{1F1E6}')"),
+        TestAction::assert(r"/^\p{RGI_Emoji_Modifier_Sequence}$/v.test('\u{1F44D}\u{1F3FD}')"),
+        TestAction::assert(
+            r"/^\p{RGI_Emoji_Tag_Sequence}$/v.test('\u{1F3F4}\u{E0067}\u{E0062}\u{E0073}\u{E0063}\u{E0074}\u{E007F}')",
+        ),
+        TestAction::assert(
+            r"/^\p{RGI_Emoji_ZWJ_Sequence}$/v.test('\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}')",
+        ),
+        TestAction::assert(
+            r"/^\p{RGI_Emoji}$/v.test('\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}')",
+        ),
+        TestAction::assert(r"/^\p{RGI_Emoji}$/v.test('\u{1F1EB}\u{1F1F7}')"),
+        TestAction::assert(r"/^[\p{RGI_Emoji}--\q{x}]$/v.test('\u{1F44D}\u{1F3FD}')"),
+        TestAction::assert(
+            r"/^[\p{RGI_Emoji}&&\p{RGI_Emoji_Flag_Sequence}]$/v.test('\u{1F1EB}\u{1F1F7}')",
+        ),
+        TestAction::assert(
+            r"!/^[\p{RGI_Emoji}--\p{RGI_Emoji_Flag_Sequence}]$/v.test('\u{1F1EB}\u{1F1F7}')",
+        ),
+        TestAction::assert(r"!/^\p{RGI_Emoji}$/v.test('a')"),
+        TestAction::assert_eq(
+            r"'\u{1F44D}\u{1F3FD}!'.replace(/\p{RGI_Emoji}/gv, 'X')",
+            "X!",
+        ),
+        TestAction::assert_eq(
+            r"'\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}'.match(/\p{RGI_Emoji}/v)[0].length",
+            8,
+        ),
+        // errors
+        TestAction::assert_native_error(
+            "new RegExp('a', 'uv')",
+            JsNativeErrorKind::Syntax,
+            "regular expression flags u and v cannot be used together",
+        ),
+        TestAction::assert_native_error(
+            r"new RegExp('[^\\q{ab}]', 'v')",
+            JsNativeErrorKind::Syntax,
+            "failed to create matcher: negated character class may contain strings",
+        ),
+        TestAction::assert_native_error(
+            r"new RegExp('[a|b]', 'v')",
+            JsNativeErrorKind::Syntax,
+            "failed to create matcher: invalid character '|' in character class",
+        ),
+        TestAction::assert_native_error(
+            r"new RegExp('[a&&bc]', 'v')",
+            JsNativeErrorKind::Syntax,
+            "failed to create matcher: invalid set operation in character class intersection",
+        ),
+    ]);
+}
@@ -19,7 +19,10 @@ pub mod ordered_set;
 
 use self::ordered_set::OrderedSet;
 use crate::{
-    builtins::{BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject},
+    builtins::{
+        iterable::IteratorRecord, BuiltInBuilder, BuiltInConstructor, BuiltInObject,
+        IntrinsicObject,
+    },
     context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
     error::JsNativeError,
     object::{internal_methods::get_prototype_from_constructor, JsObject, ObjectData},
@@ -27,6 +30,7 @@ use crate::{
     realm::Realm,
     string::utf16,
     symbol::JsSymbol,
+    value::IntegerOrInfinity,
     Context, JsArgs, JsResult, JsValue,
 };
 use boa_profiler::Profiler;
@@ -69,6 +73,13 @@ impl IntrinsicObject for Set {
             .method(Self::entries, "entries", 0)
             .method(Self::for_each, "forEach", 1)
             .method(Self::has, "has", 1)
+            .method(Self::union, "union", 1)
+            .method(Self::intersection, "intersection", 1)
+            .method(Self::difference, "difference", 1)
+            .method(Self::symmetric_difference, "symmetricDifference", 1)
+            .method(Self::is_subset_of, "isSubsetOf", 1)
+            .method(Self::is_superset_of, "isSupersetOf", 1)
+            .method(Self::is_disjoint_from, "isDisjointFrom", 1)
             .property(
                 utf16!("keys"),
                 values_function.clone(),
@@ -341,7 +352,10 @@ impl Set {
         _: &[JsValue],
         context: &mut Context<'_>,
     ) -> JsResult<JsValue> {
-        let Some(lock) = this.as_object().and_then(|o| o.borrow_mut().as_set_mut().map(|set| set.lock(o.clone()))) else {
+        let Some(lock) = this
+            .as_object()
+            .and_then(|o| o.borrow_mut().as_set_mut().map(|set| set.lock(o.clone())))
+        else {
             return Err(JsNativeError::typ()
                 .with_message("Method Set.prototype.entries called on incompatible receiver")
                 .into());
@@ -372,7 +386,10 @@ impl Set {
     ) -> JsResult<JsValue> {
         // 1. Let S be the this value.
         // 2. Perform ? RequireInternalSlot(S, [[SetData]]).
-        let Some(lock) = this.as_object().and_then(|o| o.borrow_mut().as_set_mut().map(|set| set.lock(o.clone()))) else {
+        let Some(lock) = this
+            .as_object()
+            .and_then(|o| o.borrow_mut().as_set_mut().map(|set| set.lock(o.clone())))
+        else {
             return Err(JsNativeError::typ()
                 .with_message("Method Set.prototype.forEach called on incompatible receiver")
                 .into());
@@ -381,7 +398,9 @@ impl Set {
         // 3. If IsCallable(callbackfn) is false, throw a TypeError exception.
         let Some(callback_fn) = args.get_or_undefined(0).as_callable() else {
             return Err(JsNativeError::typ()
-                .with_message("Method Set.prototype.forEach called with non-callable callback function")
+                .with_message(
+                    "Method Set.prototype.forEach called with non-callable callback function",
+                )
                 .into());
         };
 
@@ -393,7 +412,10 @@ impl Set {
         // 7. Repeat, while index < numEntries,
         while index < Self::get_size_full(this)? {
             // a. Let e be entries[index].
-            let Some(e) = this.as_object().and_then(|o| o.borrow().as_set().map(|s| s.get_index(index).cloned())) else {
+            let Some(e) = this
+                .as_object()
+                .and_then(|o| o.borrow().as_set().map(|s| s.get_index(index).cloned()))
+            else {
                 return Err(JsNativeError::typ()
                     .with_message("Method Set.prototype.forEach called on incompatible receiver")
                     .into());
@@ -459,6 +481,408 @@ impl Set {
         Ok(s.contains(value).into())
     }
 
+    /// `Set.prototype.union ( other )`
+    ///
+    /// This method returns a new set containing the elements of this set and of `other`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-set-methods/#sec-set.prototype.union
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/union
+    pub(crate) fn union(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[SetData]]).
+        let o = Self::this_set_object(this, "union")?;
+
+        // 3. Let otherRec be ? GetSetRecord(other).
+        let other_rec = SetRecord::get(args.get_or_undefined(0), context)?;
+
+        // 4. Let keysIter be ? GetKeysIterator(otherRec).
+        let mut keys_iter = other_rec.keys_iterator(context)?;
+
+        // 5. Let resultSetData be a copy of O.[[SetData]].
+        let mut result_set_data = Self::copy_set_data(&o);
+
+        // 6. Let next be true.
+        // 7. Repeat, while next is not false,
+        //     a. Set next to ? IteratorStepValue(keysIter).
+        //     b. If next is not done, then
+        while let Some(next) = keys_iter.step_value(context)? {
+            // i. Set next to CanonicalizeKeyedCollectionKey(next).
+            // ii. If SetDataHas(resultSetData, next) is false, then
+            //     1. Append next to resultSetData.
+            result_set_data.add(canonicalize_keyed_collection_key(next));
+        }
+
+        // 8. Let result be OrdinaryObjectCreateFromConstructor(%Set%, "%Set.prototype%", « [[SetData]] »).
+        // 9. Set result.[[SetData]] to resultSetData.
+        // 10. Return result.
+        Ok(Self::create_from_set_data(result_set_data, context).into())
+    }
+
+    /// `Set.prototype.intersection ( other )`
+    ///
+    /// This method returns a new set containing the elements that are in both this set and
+    /// `other`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-set-methods/#sec-set.prototype.intersection
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/intersection
+    pub(crate) fn intersection(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[SetData]]).
+        let o = Self::this_set_object(this, "intersection")?;
+
+        // 3. Let otherRec be ? GetSetRecord(other).
+        let other_rec = SetRecord::get(args.get_or_undefined(0), context)?;
+
+        // 4. Let resultSetData be a new empty List.
+        let mut result_set_data = OrderedSet::new();
+
+        // 5. If SetDataSize(O.[[SetData]]) ≤ otherRec.[[Size]], then
+        if Self::set_data_size(&o) <= other_rec.size {
+            // a. Let thisSize be the number of elements in O.[[SetData]].
+            // b. Let index be 0.
+            // c. Repeat, while index < thisSize,
+            Self::for_each_live(&o, context, |e, context| {
+                // i. Let e be O.[[SetData]][index].
+                // ii. Set index to index + 1.
+                // iii. If e is not empty, then
+                //     1. Let inOther be ToBoolean(? Call(otherRec.[[Has]], otherRec.[[Set]], « e »)).
+                //     2. If inOther is true, then
+                //         a. NOTE: It is possible for earlier calls to otherRec.[[Has]] to remove and re-add an element of O.[[SetData]], which can cause elements to be visited more than once during this iteration.
+                //         b. If SetDataHas(resultSetData, e) is false, then
+                //             i. Append e to resultSetData.
+                //     3. NOTE: The number of elements in O.[[SetData]] may have increased during execution of otherRec.[[Has]].
+                //     4. Set thisSize to the number of elements in O.[[SetData]].
+                if other_rec.has(&e, context)? {
+                    result_set_data.add(e);
+                }
+                Ok(true)
+            })?;
+        } else {
+            // 6. Else,
+            //     a. Let keysIter be ? GetKeysIterator(otherRec).
+            let mut keys_iter = other_rec.keys_iterator(context)?;
+
+            //     b. Let next be true.
+            //     c. Repeat, while next is not false,
+            //         i. Set next to ? IteratorStepValue(keysIter).
+            //         ii. If next is not done, then
+            while let Some(next) = keys_iter.step_value(context)? {
+                // 1. Set next to CanonicalizeKeyedCollectionKey(next).
+                let next = canonicalize_keyed_collection_key(next);
+
+                // 2. Let inThis be SetDataHas(O.[[SetData]], next).
+                // 3. If inThis is true, then
+                //     a. NOTE: Because other is an arbitrary object, it is possible for its "keys" iterator to produce the same value more than once.
+                //     b. If SetDataHas(resultSetData, next) is false, then
+                //         i. Append next to resultSetData.
+                if Self::set_data_has(&o, &next) {
+                    result_set_data.add(next);
+                }
+            }
+        }
+
+        // 7. Let result be OrdinaryObjectCreateFromConstructor(%Set%, "%Set.prototype%", « [[SetData]] »).
+        // 8. Set result.[[SetData]] to resultSetData.
+        // 9. Return result.
+        Ok(Self::create_from_set_data(result_set_data, context).into())
+    }
+
+    /// `Set.prototype.difference ( other )`
+    ///
+    /// This method returns a new set containing the elements of this set that are not in
+    /// `other`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-set-methods/#sec-set.prototype.difference
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/difference
+    pub(crate) fn difference(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[SetData]]).
+        let o = Self::this_set_object(this, "difference")?;
+
+        // 3. Let otherRec be ? GetSetRecord(other).
+        let other_rec = SetRecord::get(args.get_or_undefined(0), context)?;
+
+        // 4. Let resultSetData be a copy of O.[[SetData]].
+        let mut result_set_data = Self::copy_set_data(&o);
+
+        // 5. If SetDataSize(O.[[SetData]]) ≤ otherRec.[[Size]], then
+        if Self::set_data_size(&o) <= other_rec.size {
+            // a. Let thisSize be the number of elements in O.[[SetData]].
+            // b. Let index be 0.
+            // c. Repeat, while index < thisSize,
+            //     i. Let e be resultSetData[index].
+            //     ii. If e is not empty, then
+            //         1. Let inOther be ToBoolean(? Call(otherRec.[[Has]], otherRec.[[Set]], « e »)).
+            //         2. If inOther is true, then
+            //             a. Set resultSetData[index] to empty.
+            //     iii. Set index to index + 1.
+            let elements = result_set_data.iter().cloned().collect::<Vec<_>>();
+            for e in elements {
+                if other_rec.has(&e, context)? {
+                    result_set_data.delete(&e);
+                }
+            }
+        } else {
+            // 6. Else,
+            //     a. Let keysIter be ? GetKeysIterator(otherRec).
+            let mut keys_iter = other_rec.keys_iterator(context)?;
+
+            //     b. Let next be true.
+            //     c. Repeat, while next is not false,
+            //         i. Set next to ? IteratorStepValue(keysIter).
+            //         ii. If next is not done, then
+            while let Some(next) = keys_iter.step_value(context)? {
+                // 1. Set next to CanonicalizeKeyedCollectionKey(next).
+                // 2. Let valueIndex be SetDataIndex(resultSetData, next).
+                // 3. If valueIndex is not not-found, then
+                //     a. Set resultSetData[valueIndex] to empty.
+                result_set_data.delete(&canonicalize_keyed_collection_key(next));
+            }
+        }
+
+        // 7. Let result be OrdinaryObjectCreateFromConstructor(%Set%, "%Set.prototype%", « [[SetData]] »).
+        // 8. Set result.[[SetData]] to resultSetData.
+        // 9. Return result.
+        Ok(Self::create_from_set_data(result_set_data, context).into())
+    }
+
+    /// `Set.prototype.symmetricDifference ( other )`
+    ///
+    /// This method returns a new set containing the elements that are in either this set or
+    /// `other`, but not in both.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-set-methods/#sec-set.prototype.symmetricdifference
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/symmetricDifference
+    pub(crate) fn symmetric_difference(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[SetData]]).
+        let o = Self::this_set_object(this, "symmetricDifference")?;
+
+        // 3. Let otherRec be ? GetSetRecord(other).
+        let other_rec = SetRecord::get(args.get_or_undefined(0), context)?;
+
+        // 4. Let keysIter be ? GetKeysIterator(otherRec).
+        let mut keys_iter = other_rec.keys_iterator(context)?;
+
+        // 5. Let resultSetData be a copy of O.[[SetData]].
+        let mut result_set_data = Self::copy_set_data(&o);
+
+        // 6. Let next be true.
+        // 7. Repeat, while next is not false,
+        //     a. Set next to ? IteratorStepValue(keysIter).
+        //     b. If next is not done, then
+        while let Some(next) = keys_iter.step_value(context)? {
+            // i. Set next to CanonicalizeKeyedCollectionKey(next).
+            let next = canonicalize_keyed_collection_key(next);
+
+            // ii. Let resultIndex be SetDataIndex(resultSetData, next).
+            // iii. If resultIndex is not-found, let alreadyInResult be false. Otherwise let alreadyInResult be true.
+            let already_in_result = result_set_data.contains(&next);
+
+            // iv. If SetDataHas(O.[[SetData]], next) is true, then
+            if Self::set_data_has(&o, &next) {
+                // 1. If alreadyInResult is true, set resultSetData[resultIndex] to empty.
+                if already_in_result {
+                    result_set_data.delete(&next);
+                }
+            } else if !already_in_result {
+                // v. Else,
+                //     1. If alreadyInResult is false, append next to resultSetData.
+                result_set_data.add(next);
+            }
+        }
+
+        // 8. Let result be OrdinaryObjectCreateFromConstructor(%Set%, "%Set.prototype%", « [[SetData]] »).
+        // 9. Set result.[[SetData]] to resultSetData.
+        // 10. Return result.
+        Ok(Self::create_from_set_data(result_set_data, context).into())
+    }
+
+    /// `Set.prototype.isSubsetOf ( other )`
+    ///
+    /// This method returns `true` if all the elements of this set are in `other`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-set-methods/#sec-set.prototype.issubsetof
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/isSubsetOf
+    pub(crate) fn is_subset_of(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[SetData]]).
+        let o = Self::this_set_object(this, "isSubsetOf")?;
+
+        // 3. Let otherRec be ? GetSetRecord(other).
+        let other_rec = SetRecord::get(args.get_or_undefined(0), context)?;
+
+        // 4. If SetDataSize(O.[[SetData]]) > otherRec.[[Size]], return false.
+        if Self::set_data_size(&o) > other_rec.size {
+            return Ok(false.into());
+        }
+
+        // 5. Let thisSize be the number of elements in O.[[SetData]].
+        // 6. Let index be 0.
+        // 7. Repeat, while index < thisSize,
+        //     a. Let e be O.[[SetData]][index].
+        //     b. Set index to index + 1.
+        //     c. If e is not empty, then
+        //         i. Let inOther be ToBoolean(? Call(otherRec.[[Has]], otherRec.[[Set]], « e »)).
+        //         ii. If inOther is false, return false.
+        //         iii. NOTE: The number of elements in O.[[SetData]] may have increased during execution of otherRec.[[Has]].
+        //         iv. Set thisSize to the number of elements in O.[[SetData]].
+        // 8. Return true.
+        Self::for_each_live(&o, context, |e, context| other_rec.has(&e, context)).map(JsValue::from)
+    }
+
+    /// `Set.prototype.isSupersetOf ( other )`
+    ///
+    /// This method returns `true` if all the elements of `other` are in this set.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-set-methods/#sec-set.prototype.issupersetof
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/isSupersetOf
+    pub(crate) fn is_superset_of(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[SetData]]).
+        let o = Self::this_set_object(this, "isSupersetOf")?;
+
+        // 3. Let otherRec be ? GetSetRecord(other).
+        let other_rec = SetRecord::get(args.get_or_undefined(0), context)?;
+
+        // 4. If SetDataSize(O.[[SetData]]) < otherRec.[[Size]], return false.
+        if Self::set_data_size(&o) < other_rec.size {
+            return Ok(false.into());
+        }
+
+        // 5. Let keysIter be ? GetKeysIterator(otherRec).
+        let mut keys_iter = other_rec.keys_iterator(context)?;
+
+        // 6. Let next be true.
+        // 7. Repeat, while next is not false,
+        //     a. Set next to ? IteratorStepValue(keysIter).
+        //     b. If next is not done, then
+        while let Some(next) = keys_iter.step_value(context)? {
+            // i. If SetDataHas(O.[[SetData]], next) is false, then
+            if !Self::set_data_has(&o, &canonicalize_keyed_collection_key(next)) {
+                // 1. Perform ? IteratorClose(keysIter, NormalCompletion(unused)).
+                keys_iter.close(Ok(JsValue::undefined()), context)?;
+
+                // 2. Return false.
+                return Ok(false.into());
+            }
+        }
+
+        // 8. Return true.
+        Ok(true.into())
+    }
+
+    /// `Set.prototype.isDisjointFrom ( other )`
+    ///
+    /// This method returns `true` if this set has no elements in common with `other`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-set-methods/#sec-set.prototype.isdisjointfrom
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/isDisjointFrom
+    pub(crate) fn is_disjoint_from(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[SetData]]).
+        let o = Self::this_set_object(this, "isDisjointFrom")?;
+
+        // 3. Let otherRec be ? GetSetRecord(other).
+        let other_rec = SetRecord::get(args.get_or_undefined(0), context)?;
+
+        // 4. If SetDataSize(O.[[SetData]]) ≤ otherRec.[[Size]], then
+        if Self::set_data_size(&o) <= other_rec.size {
+            // a. Let thisSize be the number of elements in O.[[SetData]].
+            // b. Let index be 0.
+            // c. Repeat, while index < thisSize,
+            //     i. Let e be O.[[SetData]][index].
+            //     ii. Set index to index + 1.
+            //     iii. If e is not empty, then
+            //         1. Let inOther be ToBoolean(? Call(otherRec.[[Has]], otherRec.[[Set]], « e »)).
+            //         2. If inOther is true, return false.
+            //         3. NOTE: The number of elements in O.[[SetData]] may have increased during execution of otherRec.[[Has]].
+            //         4. Set thisSize to the number of elements in O.[[SetData]].
+            return Self::for_each_live(&o, context, |e, context| {
+                other_rec.has(&e, context).map(|in_other| !in_other)
+            })
+            .map(JsValue::from);
+        }
+
+        // 5. Else,
+        //     a. Let keysIter be ? GetKeysIterator(otherRec).
+        let mut keys_iter = other_rec.keys_iterator(context)?;
+
+        //     b. Let next be true.
+        //     c. Repeat, while next is not false,
+        //         i. Set next to ? IteratorStepValue(keysIter).
+        //         ii. If next is not done, then
+        while let Some(next) = keys_iter.step_value(context)? {
+            // 1. If SetDataHas(O.[[SetData]], next) is true, then
+            if Self::set_data_has(&o, &canonicalize_keyed_collection_key(next)) {
+                // a. Perform ? IteratorClose(keysIter, NormalCompletion(unused)).
+                keys_iter.close(Ok(JsValue::undefined()), context)?;
+
+                // b. Return false.
+                return Ok(false.into());
+            }
+        }
+
+        // 6. Return true.
+        Ok(true.into())
+    }
+
     /// `Set.prototype.values( )`
     ///
     /// This method returns an iterator over the values of the set
@@ -474,7 +898,10 @@ impl Set {
         _: &[JsValue],
         context: &mut Context<'_>,
     ) -> JsResult<JsValue> {
-        let Some(lock) = this.as_object().and_then(|o| o.borrow_mut().as_set_mut().map(|set| set.lock(o.clone()))) else {
+        let Some(lock) = this
+            .as_object()
+            .and_then(|o| o.borrow_mut().as_set_mut().map(|set| set.lock(o.clone())))
+        else {
             return Err(JsNativeError::typ()
                 .with_message("Method Set.prototype.values called on incompatible receiver")
                 .into());
@@ -503,6 +930,92 @@ impl Set {
             })
     }
 
+    /// Helper function to get the `Set` object that a `Set.prototype` method was called on.
+    fn this_set_object(this: &JsValue, method: &str) -> JsResult<JsObject> {
+        this.as_object()
+            .filter(|obj| obj.is_set())
+            .cloned()
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message(format!(
+                        "Method Set.prototype.{method} called on incompatible receiver"
+                    ))
+                    .into()
+            })
+    }
+
+    /// Helper function to get the number of elements of a `Set` object.
+    fn set_data_size(set: &JsObject) -> i64 {
+        set.borrow().as_set().map_or(0, OrderedSet::len) as i64
+    }
+
+    /// Helper function to check if a `Set` object contains a canonicalized value.
+    fn set_data_has(set: &JsObject, value: &JsValue) -> bool {
+        set.borrow()
+            .as_set()
+            .map_or(false, |set| set.contains(value))
+    }
+
+    /// Helper function to copy the elements of a `Set` object into a new `OrderedSet`.
+    fn copy_set_data(set: &JsObject) -> OrderedSet {
+        let set = set.borrow();
+        let set = set.as_set().expect("must be a set");
+        let mut result = OrderedSet::with_capacity(set.len());
+        for value in set.iter() {
+            result.add(value.clone());
+        }
+        result
+    }
+
+    /// Helper function to create a new `Set` object with `%Set.prototype%` from its elements.
+    fn create_from_set_data(set_data: OrderedSet, context: &Context<'_>) -> JsObject {
+        JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            context.intrinsics().constructors().set().prototype(),
+            ObjectData::set(set_data),
+        )
+    }
+
+    /// Helper function to iterate over the live elements of a `Set` object, calling `f` on
+    /// each one until it returns `false`.
+    ///
+    /// Elements added while iterating are also visited, and elements removed while iterating
+    /// are skipped. Returns `false` if the iteration was stopped by `f`.
+    fn for_each_live<F>(set: &JsObject, context: &mut Context<'_>, mut f: F) -> JsResult<bool>
+    where
+        F: FnMut(JsValue, &mut Context<'_>) -> JsResult<bool>,
+    {
+        let lock = set
+            .borrow_mut()
+            .as_set_mut()
+            .expect("must be a set")
+            .lock(set.clone());
+
+        let mut index = 0;
+        loop {
+            let e = {
+                let set = set.borrow();
+                let set = set.as_set().expect("must be a set");
+                if index >= set.full_len() {
+                    break;
+                }
+                set.get_index(index).cloned()
+            };
+            index += 1;
+
+            if let Some(e) = e {
+                if !f(e, context)? {
+                    drop(lock);
+                    return Ok(false);
+                }
+            }
+        }
+
+        drop(lock);
+
+        Ok(true)
+    }
+
     /// Helper function to get the full size of the `Set` object.
     pub(crate) fn get_size_full(set: &JsValue) -> JsResult<usize> {
         set.as_object()
@@ -514,3 +1027,144 @@ impl Set {
             })
     }
 }
+
+/// `CanonicalizeKeyedCollectionKey ( key )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-canonicalizekeyedcollectionkey
+fn canonicalize_keyed_collection_key(key: JsValue) -> JsValue {
+    // 1. If key is -0𝔽, return +0𝔽.
+    match key.as_number() {
+        Some(n) if n.is_zero() => JsValue::Integer(0),
+        // 2. Return key.
+        _ => key,
+    }
+}
+
+/// The `Set Record` specification type, which encapsulates the methods of a set-like object
+/// used by the set methods.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-set-methods/#sec-set-records
+struct SetRecord {
+    set: JsObject,
+    size: IntegerOrInfinity,
+    has: JsObject,
+    keys: JsObject,
+}
+
+impl SetRecord {
+    /// `GetSetRecord ( obj )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-set-methods/#sec-getsetrecord
+    fn get(obj: &JsValue, context: &mut Context<'_>) -> JsResult<Self> {
+        // 1. If obj is not an Object, throw a TypeError exception.
+        let Some(set) = obj.as_object() else {
+            return Err(JsNativeError::typ()
+                .with_message("GetSetRecord: argument must be an object")
+                .into());
+        };
+
+        // 2. Let rawSize be ? Get(obj, "size").
+        let raw_size = set.get(utf16!("size"), context)?;
+
+        // 3. Let numSize be ? ToNumber(rawSize).
+        // 4. NOTE: If rawSize is undefined, then numSize will be NaN.
+        let num_size = raw_size.to_number(context)?;
+
+        // 5. If numSize is NaN, throw a TypeError exception.
+        if num_size.is_nan() {
+            return Err(JsNativeError::typ()
+                .with_message("GetSetRecord: `size` must be a number")
+                .into());
+        }
+
+        // 6. Let intSize be ! ToIntegerOrInfinity(numSize).
+        let size = IntegerOrInfinity::from(num_size);
+
+        // 7. If intSize < 0, throw a RangeError exception.
+        if size < 0 {
+            return Err(JsNativeError::range()
+                .with_message("GetSetRecord: `size` must not be negative")
+                .into());
+        }
+
+        // 8. Let has be ? Get(obj, "has").
+        // 9. If IsCallable(has) is false, throw a TypeError exception.
+        let has = set
+            .get(utf16!("has"), context)?
+            .as_callable()
+            .cloned()
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message("GetSetRecord: `has` must be callable")
+            })?;
+
+        // 10. Let keys be ? Get(obj, "keys").
+        // 11. If IsCallable(keys) is false, throw a TypeError exception.
+        let keys = set
+            .get(utf16!("keys"), context)?
+            .as_callable()
+            .cloned()
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message("GetSetRecord: `keys` must be callable")
+            })?;
+
+        // 12. Return a new Set Record { [[Set]]: obj, [[Size]]: intSize, [[Has]]: has, [[Keys]]: keys }.
+        Ok(Self {
+            set: set.clone(),
+            size,
+            has,
+            keys,
+        })
+    }
+
+    /// Calls the `has` method of the set-like object with `value`, converting the result to a
+    /// boolean.
+    fn has(&self, value: &JsValue, context: &mut Context<'_>) -> JsResult<bool> {
+        self.has
+            .call(
+                &self.set.clone().into(),
+                std::slice::from_ref(value),
+                context,
+            )
+            .map(|v| v.to_boolean())
+    }
+
+    /// `GetKeysIterator ( setRec )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-set-methods/#sec-getkeysiterator
+    fn keys_iterator(&self, context: &mut Context<'_>) -> JsResult<IteratorRecord> {
+        // 1. Let keysIter be ? Call(setRec.[[Keys]], setRec.[[Set]]).
+        let keys_iter = self.keys.call(&self.set.clone().into(), &[], context)?;
+
+        // 2. If keysIter is not an Object, throw a TypeError exception.
+        let Some(keys_iter) = keys_iter.as_object() else {
+            return Err(JsNativeError::typ()
+                .with_message("GetKeysIterator: `keys` must return an object")
+                .into());
+        };
+
+        // 3. Let nextMethod be ? Get(keysIter, "next").
+        let next_method = keys_iter.get(utf16!("next"), context)?;
+
+        // 4. If IsCallable(nextMethod) is false, throw a TypeError exception.
+        if !next_method.is_callable() {
+            return Err(JsNativeError::typ()
+                .with_message("GetKeysIterator: `next` must be callable")
+                .into());
+        }
+
+        // 5. Return a new Iterator Record { [[Iterator]]: keysIter, [[NextMethod]]: nextMethod, [[Done]]: false }.
+        Ok(IteratorRecord::new(keys_iter.clone(), next_method, false))
+    }
+}
@@ -174,3 +174,74 @@ fn not_a_function() {
         "calling a builtin Set constructor without new is forbidden",
     )]);
 }
+
+#[test]
+fn set_methods() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::run(indoc! {r"
+            const a = new Set([1, 2, 3]);
+            const b = new Set([3, 4]);
+            const setLike = {
+                size: 2,
+                has(x) { return x === 2 || x === -0; },
+                keys() { return [2, -0].values(); },
+            };
+        "}),
+        TestAction::assert("arrayEquals([...a.union(b)], [1, 2, 3, 4])"),
+        TestAction::assert("arrayEquals([...a.intersection(b)], [3])"),
+        TestAction::assert("arrayEquals([...b.intersection(a)], [3])"),
+        TestAction::assert("arrayEquals([...a.difference(b)], [1, 2])"),
+        TestAction::assert("arrayEquals([...a.symmetricDifference(b)], [1, 2, 4])"),
+        TestAction::assert("new Set([3]).isSubsetOf(a)"),
+        TestAction::assert("!a.isSubsetOf(b)"),
+        TestAction::assert("a.isSupersetOf(new Set([1, 2]))"),
+        TestAction::assert("!a.isSupersetOf(b)"),
+        TestAction::assert("a.isDisjointFrom(new Set([5]))"),
+        TestAction::assert("!a.isDisjointFrom(b)"),
+        TestAction::assert("arrayEquals([...a], [1, 2, 3])"),
+        TestAction::assert("a.union(b) instanceof Set"),
+        TestAction::assert("arrayEquals([...a.union(setLike)], [1, 2, 3, 0])"),
+        TestAction::assert("Object.is([...new Set([1]).union(setLike)][2], 0)"),
+        TestAction::assert("arrayEquals([...new Set([0, 2, 5]).intersection(setLike)], [2, 0])"),
+        TestAction::assert("arrayEquals([...new Set([0, 5]).intersection(setLike)], [0])"),
+        TestAction::assert_native_error(
+            "a.union([1])",
+            JsNativeErrorKind::Type,
+            "GetSetRecord: `size` must be a number",
+        ),
+        TestAction::assert_native_error(
+            "a.union({ size: -1, has() {}, keys() {} })",
+            JsNativeErrorKind::Range,
+            "GetSetRecord: `size` must not be negative",
+        ),
+        TestAction::assert_native_error(
+            "a.isSubsetOf({ size: 1, has: 1, keys() {} })",
+            JsNativeErrorKind::Type,
+            "GetSetRecord: `has` must be callable",
+        ),
+        TestAction::assert_native_error(
+            "Set.prototype.union.call({}, b)",
+            JsNativeErrorKind::Type,
+            "Method Set.prototype.union called on incompatible receiver",
+        ),
+    ]);
+}
+
+#[test]
+fn set_methods_close_iterator() {
+    run_test_actions([TestAction::assert(indoc! {r"
+            let closed = false;
+            const setLike = {
+                size: 1,
+                has() { return false; },
+                keys() {
+                    return {
+                        next() { return { value: 10, done: false }; },
+                        return() { closed = true; return {}; },
+                    };
+                },
+            };
+            !new Set([1, 2]).isSupersetOf(setLike) && closed
+        "})]);
+}
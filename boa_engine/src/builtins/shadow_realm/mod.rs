@@ -0,0 +1,628 @@
+//! Boa's implementation of the `ShadowRealm` object of the `ShadowRealm` proposal.
+//!
+//! A `ShadowRealm` is a distinct global environment, with its own global object and set of
+//! intrinsics. Code evaluated inside of it can only communicate with its creator through
+//! primitive values and wrapped functions, which makes it useful to sandbox untrusted code.
+//!
+//! More information:
+//!  - [ShadowRealm proposal][spec]
+//!
+//! [spec]: https://tc39.es/proposal-shadowrealm/
+
+#[cfg(test)]
+mod tests;
+
+use boa_ast::operations::{contains, ContainsSymbol};
+use boa_gc::{Finalize, Gc, Trace};
+use boa_interner::Sym;
+use boa_parser::{Parser, Source};
+use boa_profiler::Profiler;
+
+use crate::{
+    builtins::{
+        function::set_function_name, promise::PromiseCapability, set_default_global_bindings,
+        BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject, Promise,
+    },
+    bytecompiler::ByteCompiler,
+    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    error::JsNativeError,
+    module::{ModuleRequest, Referrer},
+    native_function::NativeFunction,
+    object::{
+        internal_methods::get_prototype_from_constructor, FunctionObjectBuilder, JsObject,
+        ObjectData,
+    },
+    property::{Attribute, PropertyDescriptor},
+    realm::Realm,
+    string::utf16,
+    symbol::JsSymbol,
+    value::IntegerOrInfinity,
+    vm::{load_dynamic_import, CallFrame, Opcode},
+    Context, JsArgs, JsError, JsResult, JsString, JsValue,
+};
+
+/// Boa's implementation of the `ShadowRealm` builtin object.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ShadowRealm;
+
+impl IntrinsicObject for ShadowRealm {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .method(Self::evaluate, "evaluate", 1)
+            .method(Self::import_value, "importValue", 2)
+            .property(
+                JsSymbol::to_string_tag(),
+                Self::NAME,
+                Attribute::CONFIGURABLE,
+            )
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for ShadowRealm {
+    const NAME: &'static str = "ShadowRealm";
+}
+
+impl BuiltInConstructor for ShadowRealm {
+    const LENGTH: usize = 0;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::shadow_realm;
+
+    /// `ShadowRealm ( )`
+    ///
+    /// More information:
+    ///  - [ShadowRealm proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-shadowrealm
+    fn constructor(
+        new_target: &JsValue,
+        _: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("ShadowRealm: cannot call constructor without `new`")
+                .into());
+        }
+
+        // 2. Let O be ? OrdinaryCreateFromConstructor(NewTarget, "%ShadowRealm.prototype%", « [[ShadowRealm]] »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::shadow_realm,
+            context,
+        )?;
+
+        // 3. Let callerContext be the running execution context.
+        // 4. Perform ? InitializeHostDefinedRealm().
+        // 5. Let innerContext be the running execution context.
+        // 6. Remove innerContext from the execution context stack and restore callerContext as the running execution context.
+        // 7. Let realmRec be the Realm of innerContext.
+        // 8. Set O.[[ShadowRealm]] to realmRec.
+        // 9. Perform ? HostInitializeShadowRealm(realmRec).
+        let realm = Realm::create(&*context.host_hooks(), context.root_shape());
+        {
+            let old_realm = context.enter_realm(realm.clone());
+            let context = &mut context.guard(move |ctx| {
+                ctx.enter_realm(old_realm);
+            });
+            set_default_global_bindings(context)?;
+        }
+
+        let shadow_realm = JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            ObjectData::shadow_realm(realm),
+        );
+
+        // 10. Return O.
+        Ok(shadow_realm.into())
+    }
+}
+
+impl ShadowRealm {
+    /// Abstract operation `ValidateShadowRealmObject ( O )`, which returns the realm of `O`.
+    ///
+    /// More information:
+    ///  - [ShadowRealm proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-validateshadowrealmobject
+    fn validate(this: &JsValue, method: &str) -> JsResult<Realm> {
+        // 1. Perform ? RequireInternalSlot(O, [[ShadowRealm]]).
+        this.as_object()
+            .and_then(|o| o.borrow().as_shadow_realm().cloned())
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message(format!(
+                        "ShadowRealm.prototype.{method}: `this` is not a ShadowRealm"
+                    ))
+                    .into()
+            })
+    }
+
+    /// `ShadowRealm.prototype.evaluate ( sourceText )`
+    ///
+    /// Evaluates `sourceText` as a script inside the shadow realm, wrapping the result.
+    ///
+    /// More information:
+    ///  - [ShadowRealm proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-shadowrealm.prototype.evaluate
+    fn evaluate(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Let O be this value.
+        // 2. Perform ? ValidateShadowRealmObject(O).
+        let eval_realm = Self::validate(this, "evaluate")?;
+
+        // 3. If sourceText is not a String, throw a TypeError exception.
+        let Some(source_text) = args.get_or_undefined(0).as_string() else {
+            return Err(JsNativeError::typ()
+                .with_message("ShadowRealm.prototype.evaluate: `sourceText` must be a string")
+                .into());
+        };
+
+        // 4. Let callerRealm be the current Realm Record.
+        let caller_realm = context.realm().clone();
+
+        // 5. Let evalRealm be O.[[ShadowRealm]].
+        // 6. Return ? PerformShadowRealmEval(sourceText, callerRealm, evalRealm).
+        perform_shadow_realm_eval(source_text, &caller_realm, eval_realm, context)
+    }
+
+    /// `ShadowRealm.prototype.importValue ( specifier, exportName )`
+    ///
+    /// Imports the module `specifier` inside the shadow realm, resolving to the wrapped value of
+    /// its `exportName` export.
+    ///
+    /// More information:
+    ///  - [ShadowRealm proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-shadowrealm.prototype.importvalue
+    fn import_value(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be this value.
+        // 2. Perform ? ValidateShadowRealmObject(O).
+        let eval_realm = Self::validate(this, "importValue")?;
+
+        // 3. Let specifierString be ? ToString(specifier).
+        let specifier = args.get_or_undefined(0).to_string(context)?;
+
+        // 4. If exportName is not a String, throw a TypeError exception.
+        let Some(export_name) = args.get_or_undefined(1).as_string().cloned() else {
+            return Err(JsNativeError::typ()
+                .with_message("ShadowRealm.prototype.importValue: `exportName` must be a string")
+                .into());
+        };
+
+        // 5. Let callerRealm be the current Realm Record.
+        let caller_realm = context.realm().clone();
+
+        // 6. Let evalRealm be O.[[ShadowRealm]].
+        // 7. Return ShadowRealmImportValue(specifierString, exportName, callerRealm, evalRealm).
+        Ok(
+            shadow_realm_import_value(specifier, export_name, caller_realm, eval_realm, context)
+                .into(),
+        )
+    }
+}
+
+/// Abstract operation `PerformShadowRealmEval ( sourceText, callerRealm, evalRealm )`
+///
+/// More information:
+///  - [ShadowRealm proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-shadowrealm/#sec-performshadowrealmeval
+fn perform_shadow_realm_eval(
+    source_text: &JsString,
+    caller_realm: &Realm,
+    eval_realm: Realm,
+    context: &mut Context<'_>,
+) -> JsResult<JsValue> {
+    // 1. Perform ? HostEnsureCanCompileStrings(evalRealm).
+    context
+        .host_hooks()
+        .ensure_can_compile_strings(eval_realm.clone(), context)?;
+
+    // 2. Perform the following substeps in an implementation-defined order, possibly interleaving parsing and error detection:
+    //     a. Let script be ParseText(StringToCodePoints(sourceText), Script).
+    //     b. If script is a List of errors, throw a SyntaxError exception.
+    //     c. If script Contains ScriptBody is false, return undefined.
+    //     d. Let body be the ScriptBody of script.
+    let source = source_text.to_std_string_escaped();
+    let mut parser = Parser::new(Source::from_bytes(&source));
+    parser.set_identifier(context.next_parser_identifier());
+    let body = parser.parse_eval(false, context.interner_mut())?;
+
+    //     e. If body Contains NewTarget, throw a SyntaxError exception.
+    if contains(&body, ContainsSymbol::NewTarget) {
+        return Err(JsNativeError::syntax()
+            .with_message("invalid `new.target` expression inside ShadowRealm code")
+            .into());
+    }
+
+    //     f. If body Contains SuperProperty, throw a SyntaxError exception.
+    if contains(&body, ContainsSymbol::SuperProperty) {
+        return Err(JsNativeError::syntax()
+            .with_message("invalid `super` reference inside ShadowRealm code")
+            .into());
+    }
+
+    //     g. If body Contains SuperCall, throw a SyntaxError exception.
+    if contains(&body, ContainsSymbol::SuperCall) {
+        return Err(JsNativeError::syntax()
+            .with_message("invalid `super` call inside ShadowRealm code")
+            .into());
+    }
+
+    // 3. Let strictEval be IsStrict of script.
+    let strict = body.strict();
+
+    // 4. Let runningContext be the running execution context.
+    // 5. If runningContext is not already suspended, suspend runningContext.
+    // 6. Let evalContext be GetShadowRealmContext(evalRealm, strictEval).
+    // 7. Let lexEnv be evalContext's LexicalEnvironment.
+    // 8. Let varEnv be evalContext's VariableEnvironment.
+    // 9. Push evalContext onto the execution context stack; evalContext is now the running execution context.
+    let result = {
+        let old_realm = context.enter_realm(eval_realm);
+        let environments = context.vm.environments.pop_to_global();
+        let active_runnable = context.vm.active_runnable.take();
+        let context = &mut context.guard(move |ctx| {
+            ctx.vm.environments.truncate(1);
+            ctx.enter_realm(old_realm);
+            ctx.vm.environments.extend(environments);
+            ctx.vm.active_runnable = active_runnable;
+        });
+
+        // 10. Let result be Completion(EvalDeclarationInstantiation(body, varEnv, lexEnv, null, strictEval)).
+        // 11. If result.[[Type]] is normal, then
+        //     a. Set result to Completion(Evaluation of body).
+        // 12. If result.[[Type]] is normal and result.[[Value]] is empty, then
+        //     a. Set result to NormalCompletion(undefined).
+        (|| {
+            let mut compiler = ByteCompiler::new(
+                Sym::MAIN,
+                strict,
+                false,
+                context.vm.environments.current_compile_environment(),
+                context,
+            );
+
+            compiler.push_compile_environment(strict);
+
+            let push_env = compiler.emit_opcode_with_operand(Opcode::PushDeclarativeEnvironment);
+
+            compiler.eval_declaration_instantiation(&body, strict)?;
+            compiler.compile_statement_list(body.statements(), true, false);
+
+            let env_index = compiler.pop_compile_environment();
+            compiler.patch_jump_with_target(push_env, env_index);
+
+            compiler.emit_opcode(Opcode::PopEnvironment);
+
+            let code_block = Gc::new(compiler.finish());
+
+            context.vm.push_frame(CallFrame::new(code_block));
+            context.realm().resize_global_env();
+            let record = context.run();
+            context.vm.pop_frame();
+
+            record.consume()
+        })()
+
+        // 13. Suspend evalContext and remove it from the execution context stack.
+        // 14. Resume the context that is now on the top of the execution context stack as the running execution context.
+    };
+
+    // 15. If result.[[Type]] is not normal, throw a TypeError exception.
+    // 16. Return ? GetWrappedValue(callerRealm, result.[[Value]]).
+    let result = result.map_err(boundary_error)?;
+    get_wrapped_value(caller_realm, &result, context)
+}
+
+/// Abstract operation `ShadowRealmImportValue ( specifierString, exportNameString, callerRealm, evalRealm )`
+///
+/// More information:
+///  - [ShadowRealm proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-shadowrealm/#sec-shadowrealmimportvalue
+fn shadow_realm_import_value(
+    specifier: JsString,
+    export_name: JsString,
+    caller_realm: Realm,
+    eval_realm: Realm,
+    context: &mut Context<'_>,
+) -> JsObject {
+    // 1. Let innerCapability be ! NewPromiseCapability(%Promise%).
+    let inner_capability = PromiseCapability::new(
+        &context.intrinsics().constructors().promise().constructor(),
+        context,
+    )
+    .expect("operation cannot fail for the %Promise% intrinsic");
+
+    // 2. Let runningContext be the running execution context.
+    // 3. If runningContext is not already suspended, suspend runningContext.
+    // 4. Let evalContext be GetShadowRealmContext(evalRealm, true).
+    // 5. Push evalContext onto the execution context stack; evalContext is now the running execution context.
+    let old_realm = context.enter_realm(eval_realm.clone());
+    let active_runnable = context.vm.active_runnable.take();
+
+    // 6. Let referrer be evalRealm.
+    // 7. Perform HostLoadImportedModule(referrer, specifierString, empty, innerCapability).
+    load_dynamic_import(
+        Referrer::Realm(eval_realm),
+        ModuleRequest::new(specifier, Vec::new()),
+        inner_capability.clone(),
+        context,
+    );
+
+    // 8. Suspend evalContext and remove it from the execution context stack.
+    // 9. Resume the context that is now on the top of the execution context stack as the running execution context.
+    context.vm.active_runnable = active_runnable;
+    context.enter_realm(old_realm);
+
+    // 10. Let steps be the steps of an ExportGetter function as described below.
+    // 11. Let onFulfilled be CreateBuiltinFunction(steps, 1, "", « [[ExportNameString]] », callerRealm).
+    // 12. Set onFulfilled.[[ExportNameString]] to exportNameString.
+    let on_fulfilled = FunctionObjectBuilder::new(
+        context,
+        NativeFunction::from_copy_closure_with_captures(
+            |_, args, (export_name, caller_realm), context| {
+                // 1. Assert: exports is a module namespace exotic object.
+                let exports = args
+                    .get_or_undefined(0)
+                    .as_object()
+                    .expect("the import promise must resolve to a module namespace");
+
+                // 2. Let f be the active function object.
+                // 3. Let string be f.[[ExportNameString]].
+                // 4. Assert: string is a String.
+                // 5. Let hasOwn be ? HasOwnProperty(exports, string).
+                // 6. If hasOwn is false, throw a TypeError exception.
+                if !exports.has_own_property(export_name.clone(), context)? {
+                    return Err(JsNativeError::typ()
+                        .with_message(format!(
+                            "ShadowRealm.prototype.importValue: module has no export named `{}`",
+                            export_name.to_std_string_escaped()
+                        ))
+                        .into());
+                }
+
+                // 7. Let value be ? Get(exports, string).
+                let value = exports.get(export_name.clone(), context)?;
+
+                // 8. Let realm be f.[[Realm]].
+                // 9. Return ? GetWrappedValue(realm, value).
+                get_wrapped_value(caller_realm, &value, context)
+            },
+            (export_name, caller_realm),
+        ),
+    )
+    .length(1)
+    .build();
+
+    // 13. Let errorSteps be the steps of an ImportValueError function, which throw a TypeError.
+    // 14. Let onRejected be CreateBuiltinFunction(errorSteps, 1, "", « », callerRealm).
+    let on_rejected = FunctionObjectBuilder::new(
+        context,
+        NativeFunction::from_fn_ptr(|_, args, _| {
+            Err(boundary_error(JsError::from_opaque(
+                args.get_or_undefined(0).clone(),
+            )))
+        }),
+    )
+    .length(1)
+    .build();
+
+    // 15. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+    let promise_capability = PromiseCapability::new(
+        &context.intrinsics().constructors().promise().constructor(),
+        context,
+    )
+    .expect("operation cannot fail for the %Promise% intrinsic");
+    let promise = promise_capability.promise().clone();
+
+    // 16. Return PerformPromiseThen(innerCapability.[[Promise]], onFulfilled, onRejected, promiseCapability).
+    Promise::perform_promise_then(
+        inner_capability.promise(),
+        Some(on_fulfilled),
+        Some(on_rejected),
+        Some(promise_capability),
+        context,
+    );
+
+    promise
+}
+
+/// Abstract operation `GetWrappedValue ( callerRealm, value )`
+///
+/// Wraps `value` for its use inside `caller_realm`. Primitives are passed as is, callables are
+/// wrapped inside a new wrapped function, and any other object throws a `TypeError`.
+///
+/// More information:
+///  - [ShadowRealm proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-shadowrealm/#sec-getwrappedvalue
+pub(crate) fn get_wrapped_value(
+    caller_realm: &Realm,
+    value: &JsValue,
+    context: &mut Context<'_>,
+) -> JsResult<JsValue> {
+    // 1. If value is an Object, then
+    if let Some(object) = value.as_object() {
+        // a. If IsCallable(value) is false, throw a TypeError exception.
+        if !object.is_callable() {
+            return Err(JsNativeError::typ()
+                .with_message("cannot pass a non-callable object across a ShadowRealm boundary")
+                .into());
+        }
+
+        // b. Return ? WrappedFunctionCreate(callerRealm, value).
+        return WrappedFunction::create(caller_realm.clone(), object, context).map(Into::into);
+    }
+
+    // 2. Return value.
+    Ok(value.clone())
+}
+
+/// Converts an error that crossed a `ShadowRealm` boundary into a `TypeError` of the caller realm.
+///
+/// Errors caused by exceeding the runtime limits are not catchable, so they are passed as is.
+pub(crate) fn boundary_error(error: JsError) -> JsError {
+    if error
+        .as_native()
+        .map_or(false, JsNativeError::is_runtime_limit)
+    {
+        return error;
+    }
+
+    JsNativeError::typ()
+        .with_message(format!(
+            "error thrown across a ShadowRealm boundary: {error}"
+        ))
+        .into()
+}
+
+/// Wraps a callable object from a different realm.
+///
+/// More information:
+///  - [ShadowRealm proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-shadowrealm/#sec-wrapped-function-exotic-objects
+#[derive(Debug, Trace, Finalize)]
+pub struct WrappedFunction {
+    target: JsObject,
+    realm: Realm,
+}
+
+impl WrappedFunction {
+    /// Abstract operation `WrappedFunctionCreate ( callerRealm, Target )`
+    ///
+    /// More information:
+    ///  - [ShadowRealm proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-wrappedfunctioncreate
+    pub(crate) fn create(
+        caller_realm: Realm,
+        target: &JsObject,
+        context: &mut Context<'_>,
+    ) -> JsResult<JsObject> {
+        // 1. Let internalSlotsList be the internal slots listed in Table 2, plus [[Prototype]] and [[Extensible]].
+        // 2. Let wrapped be MakeBasicObject(internalSlotsList).
+        // 3. Set wrapped.[[Prototype]] to callerRealm.[[Intrinsics]].[[%Function.prototype%]].
+        // 4. Set wrapped.[[Call]] as described in 2.1.
+        // 5. Set wrapped.[[WrappedTargetFunction]] to Target.
+        // 6. Set wrapped.[[Realm]] to callerRealm.
+        let wrapped = JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            caller_realm
+                .intrinsics()
+                .constructors()
+                .function()
+                .prototype(),
+            ObjectData::wrapped_function(Self {
+                target: target.clone(),
+                realm: caller_realm,
+            }),
+        );
+
+        // 7. Let result be Completion(CopyNameAndLength(wrapped, Target)).
+        // 8. If result is an Abrupt Completion, throw a TypeError exception.
+        copy_name_and_length(&wrapped, target, context).map_err(|_| {
+            JsNativeError::typ()
+                .with_message("could not copy the name and length of a wrapped function")
+        })?;
+
+        // 9. Return wrapped.
+        Ok(wrapped)
+    }
+
+    /// Gets the wrapped target function.
+    #[must_use]
+    pub const fn target(&self) -> &JsObject {
+        &self.target
+    }
+
+    /// Gets the realm in which the function was wrapped.
+    #[must_use]
+    pub const fn realm(&self) -> &Realm {
+        &self.realm
+    }
+}
+
+/// Abstract operation `CopyNameAndLength ( F, Target )`
+///
+/// More information:
+///  - [ShadowRealm proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-shadowrealm/#sec-copynameandlength
+fn copy_name_and_length(
+    function: &JsObject,
+    target: &JsObject,
+    context: &mut Context<'_>,
+) -> JsResult<()> {
+    // 1. If prefix is not present, then
+    //     a. Set prefix to "".
+    // 2. If argCount is not present, then
+    //     a. Set argCount to 0.
+    // 3. Let L be 0.
+    let mut length = JsValue::new(0);
+
+    // 4. Let targetHasLength be ? HasOwnProperty(Target, "length").
+    // 5. If targetHasLength is true, then
+    if target.has_own_property(utf16!("length"), context)? {
+        // a. Let targetLen be ? Get(Target, "length").
+        let target_len = target.get(utf16!("length"), context)?;
+
+        // b. If targetLen is a Number, then
+        if target_len.is_number() {
+            match target_len
+                .to_integer_or_infinity(context)
+                .expect("to_integer_or_infinity cannot fail for a number")
+            {
+                // i. If targetLen is +∞𝔽, set L to +∞.
+                IntegerOrInfinity::PositiveInfinity => length = f64::INFINITY.into(),
+                // ii. Else if targetLen is -∞𝔽, set L to 0.
+                IntegerOrInfinity::NegativeInfinity => {}
+                // iii. Else,
+                //     1. Let targetLenAsInt be ! ToIntegerOrInfinity(targetLen).
+                //     2. Assert: targetLenAsInt is finite.
+                //     3. Set L to max(targetLenAsInt - argCount, 0).
+                IntegerOrInfinity::Integer(target_len) => length = target_len.max(0).into(),
+            }
+        }
+    }
+
+    // 6. Perform SetFunctionLength(F, L).
+    function.define_property_or_throw(
+        utf16!("length"),
+        PropertyDescriptor::builder()
+            .value(length)
+            .writable(false)
+            .enumerable(false)
+            .configurable(true),
+        context,
+    )?;
+
+    // 7. Let targetName be ? Get(Target, "name").
+    let target_name = target.get(utf16!("name"), context)?;
+
+    // 8. If targetName is not a String, set targetName to the empty String.
+    let target_name = target_name
+        .as_string()
+        .map_or_else(JsString::default, Clone::clone);
+
+    // 9. Perform SetFunctionName(F, targetName, prefix).
+    set_function_name(function, &target_name.into(), None, context);
+
+    Ok(())
+}
@@ -0,0 +1,183 @@
+use crate::{
+    module::{ModuleLoader, ModuleRequest, Referrer},
+    run_test_actions, run_test_actions_with, Context, JsNativeError, JsNativeErrorKind, JsResult,
+    Module, Source, TestAction,
+};
+use indoc::indoc;
+use std::{cell::RefCell, collections::HashMap};
+
+#[test]
+fn evaluate() {
+    run_test_actions([
+        TestAction::run("const realm = new ShadowRealm();"),
+        TestAction::assert_eq("realm.evaluate('1 + 2')", 3),
+        TestAction::assert_eq("realm.evaluate('var x = 10; x')", 10),
+        TestAction::assert_eq("realm.evaluate('x * 2')", 20),
+        TestAction::assert_eq("typeof x", "undefined"),
+        TestAction::assert_eq("realm.evaluate('Object.prototype.y = 1; ({}).y')", 1),
+        TestAction::assert_eq("({}).y", crate::JsValue::undefined()),
+        TestAction::assert_eq("realm.evaluate('typeof globalThis')", "object"),
+        TestAction::assert_eq("typeof realm.evaluate('Symbol.iterator')", "symbol"),
+        TestAction::assert("realm.evaluate('Symbol.iterator') === Symbol.iterator"),
+        TestAction::assert_eq(
+            "Object.prototype.toString.call(realm)",
+            "[object ShadowRealm]",
+        ),
+    ]);
+}
+
+#[test]
+fn wrapped_functions() {
+    run_test_actions([
+        TestAction::run("const realm = new ShadowRealm();"),
+        TestAction::assert_eq("realm.evaluate('(x) => x * 2')(21)", 42),
+        TestAction::run("const foo = realm.evaluate('function foo(a, b) {}; foo');"),
+        TestAction::assert_eq("foo.name", "foo"),
+        TestAction::assert_eq("foo.length", 2),
+        TestAction::assert("Object.getPrototypeOf(foo) === Function.prototype"),
+        TestAction::assert("!('prototype' in foo)"),
+        TestAction::assert_eq(
+            indoc! {r"
+                const apply = realm.evaluate('(f, x) => f(x) + 1');
+                apply((x) => x * 10, 4)
+            "},
+            41,
+        ),
+        TestAction::assert_eq("realm.evaluate('(f) => typeof f')(() => {})", "function"),
+    ]);
+}
+
+#[test]
+fn errors() {
+    run_test_actions([
+        TestAction::run("const realm = new ShadowRealm();"),
+        TestAction::assert_native_error(
+            "ShadowRealm()",
+            JsNativeErrorKind::Type,
+            "ShadowRealm: cannot call constructor without `new`",
+        ),
+        TestAction::assert_native_error(
+            "realm.evaluate(1)",
+            JsNativeErrorKind::Type,
+            "ShadowRealm.prototype.evaluate: `sourceText` must be a string",
+        ),
+        TestAction::assert_native_error(
+            "ShadowRealm.prototype.evaluate.call({}, '1')",
+            JsNativeErrorKind::Type,
+            "ShadowRealm.prototype.evaluate: `this` is not a ShadowRealm",
+        ),
+        TestAction::assert_native_error(
+            "realm.evaluate('({})')",
+            JsNativeErrorKind::Type,
+            "cannot pass a non-callable object across a ShadowRealm boundary",
+        ),
+        TestAction::assert_native_error(
+            "realm.evaluate('(x) => x')({})",
+            JsNativeErrorKind::Type,
+            "error thrown across a ShadowRealm boundary: TypeError: cannot pass a non-callable \
+             object across a ShadowRealm boundary",
+        ),
+        TestAction::assert_native_error(
+            "realm.evaluate('throw 1')",
+            JsNativeErrorKind::Type,
+            "error thrown across a ShadowRealm boundary: 1",
+        ),
+        TestAction::assert("realm.evaluate('let a = 1; a') === 1"),
+        TestAction::assert_native_error(
+            "realm.evaluate('let let = 1')",
+            JsNativeErrorKind::Syntax,
+            "'let' is disallowed as a lexically bound name at line 1, col 5",
+        ),
+        TestAction::assert_native_error(
+            "realm.evaluate('new.target')",
+            JsNativeErrorKind::Syntax,
+            "invalid new.target usage at line 1, col 1",
+        ),
+    ]);
+}
+
+/// A module loader serving a fixed set of in-memory modules.
+#[derive(Default)]
+struct MemoryModuleLoader {
+    modules: RefCell<HashMap<String, Module>>,
+}
+
+impl ModuleLoader for MemoryModuleLoader {
+    fn load_imported_module(
+        &self,
+        referrer: Referrer,
+        request: ModuleRequest,
+        finish_load: Box<dyn FnOnce(JsResult<Module>, &mut Context<'_>)>,
+        context: &mut Context<'_>,
+    ) {
+        let Referrer::Realm(realm) = referrer else {
+            unreachable!("modules are only imported from realms in these tests");
+        };
+        let specifier = request.specifier().to_std_string_escaped();
+        if let Some(module) = self.modules.borrow().get(&specifier) {
+            finish_load(Ok(module.clone()), context);
+            return;
+        }
+        let source = match specifier.as_str() {
+            "counter" => "export let count = 0; export function inc() { return ++count; }",
+            "object" => "export const obj = {};",
+            _ => {
+                finish_load(
+                    Err(JsNativeError::typ().with_message("module not found").into()),
+                    context,
+                );
+                return;
+            }
+        };
+        let module = Module::parse(Source::from_bytes(source), Some(realm), context);
+        if let Ok(module) = &module {
+            self.modules.borrow_mut().insert(specifier, module.clone());
+        }
+        finish_load(module, context);
+    }
+}
+
+#[test]
+fn import_value() {
+    let loader = &MemoryModuleLoader::default();
+    let dyn_loader: &dyn ModuleLoader = loader;
+    let context = &mut Context::builder()
+        .module_loader(dyn_loader)
+        .build()
+        .unwrap();
+
+    run_test_actions_with(
+        [
+            TestAction::run(indoc! {r#"
+                const realm = new ShadowRealm();
+                let inc, missing, object, notFound;
+                realm.importValue("counter", "inc").then((f) => { inc = f; });
+                realm.importValue("counter", "missing").catch((e) => { missing = e; });
+                realm.importValue("object", "obj").catch((e) => { object = e; });
+                realm.importValue("none", "x").catch((e) => { notFound = e; });
+            "#}),
+            #[allow(clippy::redundant_closure_for_method_calls)]
+            TestAction::inspect_context(|ctx| ctx.run_jobs()),
+            TestAction::assert_eq("inc()", 1),
+            TestAction::assert_eq("inc()", 2),
+            TestAction::assert_eq("realm.evaluate('typeof count')", "undefined"),
+            TestAction::assert("missing instanceof TypeError"),
+            TestAction::assert_eq(
+                "missing.message",
+                "ShadowRealm.prototype.importValue: module has no export named `missing`",
+            ),
+            TestAction::assert("object instanceof TypeError"),
+            TestAction::assert_eq(
+                "object.message",
+                "cannot pass a non-callable object across a ShadowRealm boundary",
+            ),
+            TestAction::assert("notFound instanceof TypeError"),
+            TestAction::assert_native_error(
+                "realm.importValue('counter', 1)",
+                JsNativeErrorKind::Type,
+                "ShadowRealm.prototype.importValue: `exportName` must be a string",
+            ),
+        ],
+        context,
+    );
+}
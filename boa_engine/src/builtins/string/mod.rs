@@ -142,7 +142,9 @@ impl IntrinsicObject for String {
             .method(Self::replace_all, "replaceAll", 2)
             .method(Self::iterator, (symbol_iterator, "[Symbol.iterator]"), 0)
             .method(Self::search, "search", 1)
-            .method(Self::at, "at", 1);
+            .method(Self::at, "at", 1)
+            .method(Self::is_well_formed, "isWellFormed", 0)
+            .method(Self::to_well_formed, "toWellFormed", 0);
 
         #[cfg(feature = "annex-b")]
         {
@@ -562,6 +564,81 @@ impl String {
         Ok(js_string!(&s[k..=k]).into())
     }
 
+    /// `String.prototype.isWellFormed ( )`
+    ///
+    /// The `isWellFormed()` method returns a boolean indicating whether the string contains
+    /// any lone surrogates.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.iswellformed
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/isWellFormed
+    pub(crate) fn is_well_formed(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be ? RequireObjectCoercible(this value).
+        let this = this.require_object_coercible()?;
+
+        // 2. Let S be ? ToString(O).
+        let s = this.to_string(context)?;
+
+        // 3. Return IsStringWellFormedUnicode(S).
+        let is_well_formed = s
+            .code_points()
+            .all(|cp| matches!(cp, CodePoint::Unicode(_)));
+
+        Ok(is_well_formed.into())
+    }
+
+    /// `String.prototype.toWellFormed ( )`
+    ///
+    /// The `toWellFormed()` method returns a string where all lone surrogates of this string
+    /// are replaced with the Unicode replacement character U+FFFD.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.towellformed
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/toWellFormed
+    pub(crate) fn to_well_formed(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be ? RequireObjectCoercible(this value).
+        let this = this.require_object_coercible()?;
+
+        // 2. Let S be ? ToString(O).
+        let s = this.to_string(context)?;
+
+        // 3. Let strLen be the length of S.
+        // 4. Let k be 0.
+        // 5. Let result be the empty String.
+        let mut result = Vec::with_capacity(s.len());
+
+        // 6. Repeat, while k < strLen,
+        //     a. Let cp be CodePointAt(S, k).
+        for cp in s.code_points() {
+            match cp {
+                // b. If cp.[[IsUnpairedSurrogate]] is true, then
+                //     i. Set result to the string-concatenation of result and 0xFFFD (REPLACEMENT CHARACTER).
+                CodePoint::UnpairedSurrogate(_) => result.push(0xFFFD),
+                // c. Else,
+                //     i. Set result to the string-concatenation of result and UTF16EncodeCodePoint(cp.[[CodePoint]]).
+                CodePoint::Unicode(c) => result.extend_from_slice(c.encode_utf16(&mut [0; 2])),
+            }
+            // d. Set k to k + cp.[[CodeUnitCount]].
+        }
+
+        // 7. Return result.
+        Ok(js_string!(result).into())
+    }
+
     /// `String.prototype.codePointAt( index )`
     ///
     /// The `codePointAt()` method returns an integer between `0` to `1114111` (`0x10FFFF`) representing the UTF-16 code unit at the given index.
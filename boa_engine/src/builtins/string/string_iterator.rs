@@ -36,13 +36,7 @@ impl IntrinsicObject for StringIterator {
         let _timer = Profiler::global().start_event("StringIterator", "init");
 
         BuiltInBuilder::with_intrinsic::<Self>(realm)
-            .prototype(
-                realm
-                    .intrinsics()
-                    .objects()
-                    .iterator_prototypes()
-                    .iterator(),
-            )
+            .prototype(realm.intrinsics().constructors().iterator().prototype())
             .static_method(Self::next, "next", 0)
             .static_property(
                 JsSymbol::to_string_tag(),
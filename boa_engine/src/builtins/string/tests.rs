@@ -909,3 +909,34 @@ fn from_code_point() {
         ),
     ]);
 }
+
+#[test]
+fn is_well_formed() {
+    run_test_actions([
+        TestAction::assert("''.isWellFormed()"),
+        TestAction::assert("'abc'.isWellFormed()"),
+        TestAction::assert(r"'😀'.isWellFormed()"),
+        TestAction::assert(r"!'a\uD83Db'.isWellFormed()"),
+        TestAction::assert(r"!'\uDE00\uD83D'.isWellFormed()"),
+        TestAction::assert_native_error(
+            "String.prototype.isWellFormed.call(null)",
+            JsNativeErrorKind::Type,
+            "cannot convert null or undefined to Object",
+        ),
+    ]);
+}
+
+#[test]
+fn to_well_formed() {
+    run_test_actions([
+        TestAction::assert_eq("'abc'.toWellFormed()", js_string!("abc")),
+        TestAction::assert(r"'😀'.toWellFormed() === '😀'"),
+        TestAction::assert(r"'a\uD83Db'.toWellFormed() === 'a�b'"),
+        TestAction::assert(r"'\uDE00\uD83D'.toWellFormed() === '��'"),
+        TestAction::assert_native_error(
+            "String.prototype.toWellFormed.call(undefined)",
+            JsNativeErrorKind::Type,
+            "cannot convert null or undefined to Object",
+        ),
+    ]);
+}
@@ -95,7 +95,9 @@ impl IntrinsicObject for Symbol {
     fn init(realm: &Realm) {
         let _timer = Profiler::global().start_event(Self::NAME, "init");
 
+        let symbol_async_dispose = JsSymbol::async_dispose();
         let symbol_async_iterator = JsSymbol::async_iterator();
+        let symbol_dispose = JsSymbol::dispose();
         let symbol_has_instance = JsSymbol::has_instance();
         let symbol_is_concat_spreadable = JsSymbol::is_concat_spreadable();
         let symbol_iterator = JsSymbol::iterator();
@@ -123,7 +125,9 @@ impl IntrinsicObject for Symbol {
         BuiltInBuilder::from_standard_constructor::<Self>(realm)
             .static_method(Self::for_, "for", 1)
             .static_method(Self::key_for, "keyFor", 1)
+            .static_property(utf16!("asyncDispose"), symbol_async_dispose, attribute)
             .static_property(utf16!("asyncIterator"), symbol_async_iterator, attribute)
+            .static_property(utf16!("dispose"), symbol_dispose, attribute)
             .static_property(utf16!("hasInstance"), symbol_has_instance, attribute)
             .static_property(
                 utf16!("isConcatSpreadable"),
@@ -225,6 +229,12 @@ impl BuiltInConstructor for Symbol {
 }
 
 impl Symbol {
+    /// Returns `true` if the symbol was created by `Symbol.for`, i.e. if it is in the
+    /// `GlobalSymbolRegistry`.
+    pub(crate) fn is_registered(sym: &JsSymbol) -> bool {
+        GLOBAL_SYMBOL_REGISTRY.symbols.contains_key(sym)
+    }
+
     fn this_symbol_value(value: &JsValue) -> JsResult<JsSymbol> {
         value
             .as_symbol()
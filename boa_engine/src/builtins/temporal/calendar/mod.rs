@@ -0,0 +1,565 @@
+//! Boa's implementation of the `Temporal.Calendar` builtin object.
+//!
+//! Only the ISO 8601 calendar is supported, so every `Temporal.Calendar` object has the
+//! `iso8601` identifier.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/proposal-temporal/#sec-temporal-calendar-objects
+
+use boa_gc::{empty_trace, Finalize, Trace};
+use boa_profiler::Profiler;
+
+use crate::{
+    builtins::{
+        iterable::IteratorHint,
+        options::{get_option, get_options_object},
+        Array, BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject,
+    },
+    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    js_string,
+    object::{JsObject, ObjectData},
+    property::{Attribute, PropertyNameKind},
+    realm::Realm,
+    string::utf16,
+    symbol::JsSymbol,
+    Context, JsArgs, JsNativeError, JsResult, JsString, JsValue,
+};
+
+use super::{
+    constructor_without_new, create_temporal_object,
+    duration::{to_temporal_duration, Duration},
+    fields::{FieldNames, Fields, RequiredFields},
+    iso::{days_in_month, days_in_year, is_leap_year, IsoDate, NS_PER_DAY},
+    options::{get_overflow, get_temporal_unit, TemporalUnit, UnitGroup, UnitOption},
+    parser::{parse_date_time, parse_time},
+    plain_date::{to_temporal_date, PlainDate},
+    require_string, with_getters,
+};
+
+/// The identifier of the only supported calendar.
+pub(crate) const ISO8601: &str = "iso8601";
+
+/// Abstract operation [`CanonicalizeCalendar ( id )`][spec]
+///
+/// Checks that `id` identifies the ISO 8601 calendar.
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-canonicalizecalendar
+pub(crate) fn canonicalize_calendar(id: &str) -> JsResult<()> {
+    if id.eq_ignore_ascii_case(ISO8601) {
+        Ok(())
+    } else {
+        Err(JsNativeError::range()
+            .with_message(format!("unsupported calendar `{id}`"))
+            .into())
+    }
+}
+
+/// Abstract operation [`ToTemporalCalendarIdentifier ( temporalCalendarLike )`][spec]
+///
+/// Custom calendar objects are not supported, so the only objects accepted are the Temporal
+/// objects that have a calendar.
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-totemporalcalendaridentifier
+pub(crate) fn to_calendar_identifier(value: &JsValue, _context: &mut Context<'_>) -> JsResult<()> {
+    // 1. If temporalCalendarLike is an Object, then
+    if let Some(object) = value.as_object() {
+        // a. If temporalCalendarLike has an [[InitializedTemporalDate]], [[InitializedTemporalDateTime]],
+        //    [[InitializedTemporalMonthDay]], [[InitializedTemporalYearMonth]], or
+        //    [[InitializedTemporalZonedDateTime]] internal slot, then
+        //     i. Return temporalCalendarLike.[[Calendar]].
+        if has_calendar(object) {
+            return Ok(());
+        }
+        return Err(JsNativeError::typ()
+            .with_message("custom calendar objects are not supported")
+            .into());
+    }
+
+    // 2. If temporalCalendarLike is not a String, throw a TypeError exception.
+    let id = require_string(value, "a calendar")?;
+
+    // 3. Let identifier be ? ParseTemporalCalendarString(temporalCalendarLike).
+    // 4. Return ? CanonicalizeCalendar(identifier).
+    parse_calendar_string(&id)
+}
+
+/// Abstract operation [`ParseTemporalCalendarString ( string )`][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-parsetemporalcalendarstring
+fn parse_calendar_string(string: &str) -> JsResult<()> {
+    let annotation = if let Some(parsed) = parse_date_time(string) {
+        Some(parsed.calendar)
+    } else {
+        parse_time(string).map(|(_, calendar)| calendar)
+    };
+
+    match annotation {
+        Some(Some(calendar)) => canonicalize_calendar(&calendar),
+        Some(None) => Ok(()),
+        None => canonicalize_calendar(string),
+    }
+}
+
+/// Returns `true` if `object` is a Temporal object with a calendar.
+fn has_calendar(object: &JsObject) -> bool {
+    let object = object.borrow();
+    object.as_calendar().is_some()
+        || object.as_plain_date().is_some()
+        || object.as_plain_date_time().is_some()
+        || object.as_zoned_date_time().is_some()
+}
+
+/// Abstract operation [`GetTemporalCalendarIdentifierWithISODefault ( item )`][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-gettemporalcalendarslotvaluewithisodefault
+pub(crate) fn get_calendar_with_iso_default(
+    item: &JsObject,
+    context: &mut Context<'_>,
+) -> JsResult<()> {
+    // 1. If item has an [[InitializedTemporalDate]], [[InitializedTemporalDateTime]],
+    //    [[InitializedTemporalMonthDay]], [[InitializedTemporalYearMonth]], or
+    //    [[InitializedTemporalZonedDateTime]] internal slot, then
+    //     a. Return item.[[Calendar]].
+    if has_calendar(item) {
+        return Ok(());
+    }
+
+    // 2. Let calendarLike be ? Get(item, "calendar").
+    let calendar = item.get(utf16!("calendar"), context)?;
+
+    // 3. If calendarLike is undefined, then
+    //     a. Return "iso8601".
+    if calendar.is_undefined() {
+        return Ok(());
+    }
+
+    // 4. Return ? ToTemporalCalendarIdentifier(calendarLike).
+    to_calendar_identifier(&calendar, context)
+}
+
+/// Checks the calendar annotation of a parsed string.
+pub(crate) fn check_calendar_annotation(calendar: Option<&str>) -> JsResult<()> {
+    calendar.map_or(Ok(()), canonicalize_calendar)
+}
+
+/// The `Temporal.Calendar` builtin object.
+#[derive(Debug, Clone, Copy)]
+pub struct Calendar;
+
+impl Finalize for Calendar {}
+
+// SAFETY: `Calendar` doesn't contain any traceable data.
+unsafe impl Trace for Calendar {
+    empty_trace!();
+}
+
+impl IntrinsicObject for Calendar {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        let builder = BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .static_method(Self::from, "from", 1)
+            .property(
+                JsSymbol::to_string_tag(),
+                "Temporal.Calendar",
+                Attribute::CONFIGURABLE,
+            )
+            .method(Self::date_from_fields, "dateFromFields", 1)
+            .method(Self::date_add, "dateAdd", 2)
+            .method(Self::date_until, "dateUntil", 2)
+            .method(Self::year, "year", 1)
+            .method(Self::month, "month", 1)
+            .method(Self::month_code, "monthCode", 1)
+            .method(Self::day, "day", 1)
+            .method(Self::day_of_week, "dayOfWeek", 1)
+            .method(Self::day_of_year, "dayOfYear", 1)
+            .method(Self::week_of_year, "weekOfYear", 1)
+            .method(Self::year_of_week, "yearOfWeek", 1)
+            .method(Self::days_in_week, "daysInWeek", 1)
+            .method(Self::days_in_month, "daysInMonth", 1)
+            .method(Self::days_in_year, "daysInYear", 1)
+            .method(Self::months_in_year, "monthsInYear", 1)
+            .method(Self::in_leap_year, "inLeapYear", 1)
+            .method(Self::fields, "fields", 1)
+            .method(Self::merge_fields, "mergeFields", 2)
+            .method(Self::to_string, "toString", 0)
+            .method(Self::to_string, "toJSON", 0);
+
+        with_getters(builder, realm, &[("id", Self::to_string)]).build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for Calendar {
+    const NAME: &'static str = "Calendar";
+}
+
+impl BuiltInConstructor for Calendar {
+    const LENGTH: usize = 1;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::calendar;
+
+    /// Constructor [`Temporal.Calendar ( id )`][spec].
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.calendar
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, then
+        if new_target.is_undefined() {
+            // a. Throw a TypeError exception.
+            return Err(constructor_without_new(Self::NAME).into());
+        }
+
+        // 2. If id is not a String, throw a TypeError exception.
+        let id = require_string(args.get_or_undefined(0), "the calendar identifier")?;
+
+        // 3. If IsBuiltinCalendar(id) is false, then
+        //     a. Throw a RangeError exception.
+        canonicalize_calendar(&id)?;
+
+        // 4. Return ? CreateTemporalCalendar(id, NewTarget).
+        Ok(Self.create(Some(new_target), context)?.into())
+    }
+}
+
+impl Calendar {
+    /// Creates a new `Temporal.Calendar` object.
+    pub(crate) fn create(
+        self,
+        new_target: Option<&JsValue>,
+        context: &mut Context<'_>,
+    ) -> JsResult<JsObject> {
+        create_temporal_object(
+            ObjectData::calendar(self),
+            new_target,
+            StandardConstructors::calendar,
+            context,
+        )
+    }
+
+    /// Checks that the `this` value is a `Temporal.Calendar` object.
+    fn this_calendar(this: &JsValue) -> JsResult<()> {
+        if this
+            .as_object()
+            .map_or(false, |object| object.borrow().as_calendar().is_some())
+        {
+            Ok(())
+        } else {
+            Err(JsNativeError::typ()
+                .with_message("'this' is not a Temporal.Calendar object")
+                .into())
+        }
+    }
+
+    /// Checks the `this` value, and converts the first argument to a date.
+    fn date_arg(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<IsoDate> {
+        Self::this_calendar(this)?;
+        to_temporal_date(args.get_or_undefined(0), &JsValue::undefined(), context)
+    }
+
+    /// `Temporal.Calendar.from ( calendarLike )`
+    fn from(_: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        let calendar = args.get_or_undefined(0);
+        if let Some(object) = calendar.as_object() {
+            if object.borrow().as_calendar().is_some() {
+                return Ok(calendar.clone());
+            }
+        }
+        to_calendar_identifier(calendar, context)?;
+        Ok(Self.create(None, context)?.into())
+    }
+
+    /// `Temporal.Calendar.prototype.dateFromFields ( fields [ , options ] )`
+    fn date_from_fields(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        Self::this_calendar(this)?;
+        let Some(fields) = args.get_or_undefined(0).as_object() else {
+            return Err(JsNativeError::typ()
+                .with_message("the fields must be an object")
+                .into());
+        };
+        let fields = Fields::prepare(fields, FieldNames::DATE, RequiredFields::None, context)?;
+        let options = get_options_object(args.get_or_undefined(1))?;
+        let overflow = get_overflow(&options, context)?;
+        let date = fields.to_date(overflow)?;
+        Ok(PlainDate::new(date).create(None, context)?.into())
+    }
+
+    /// `Temporal.Calendar.prototype.dateAdd ( date, duration [ , options ] )`
+    fn date_add(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        let date = Self::date_arg(this, args, context)?;
+        let duration = to_temporal_duration(args.get_or_undefined(1), context)?;
+        let options = get_options_object(args.get_or_undefined(2))?;
+        let overflow = get_overflow(&options, context)?;
+
+        let internal = duration.to_internal();
+        let mut date_duration = internal.date;
+        date_duration.days += (internal.time / NS_PER_DAY) as i64;
+        let date = date.add(date_duration, overflow)?;
+        Ok(PlainDate::new(date).create(None, context)?.into())
+    }
+
+    /// `Temporal.Calendar.prototype.dateUntil ( one, two [ , options ] )`
+    fn date_until(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        let one = Self::date_arg(this, args, context)?;
+        let two = to_temporal_date(args.get_or_undefined(1), &JsValue::undefined(), context)?;
+        let options = get_options_object(args.get_or_undefined(2))?;
+        let largest_unit = match get_temporal_unit(
+            &options,
+            utf16!("largestUnit"),
+            UnitGroup::Date,
+            None,
+            true,
+            context,
+        )? {
+            Some(UnitOption::Unit(unit)) => unit,
+            _ => TemporalUnit::Day,
+        };
+        let duration = one.until(two, largest_unit);
+        Ok(Duration::from_date_duration(duration)?
+            .create(None, context)?
+            .into())
+    }
+
+    /// `Temporal.Calendar.prototype.year ( temporalDateLike )`
+    fn year(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        Ok(Self::date_arg(this, args, context)?.year.into())
+    }
+
+    /// `Temporal.Calendar.prototype.month ( temporalDateLike )`
+    fn month(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        Ok(Self::date_arg(this, args, context)?.month.into())
+    }
+
+    /// `Temporal.Calendar.prototype.monthCode ( temporalDateLike )`
+    fn month_code(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        let date = Self::date_arg(this, args, context)?;
+        Ok(js_string!(format!("M{:02}", date.month)).into())
+    }
+
+    /// `Temporal.Calendar.prototype.day ( temporalDateLike )`
+    fn day(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        Ok(Self::date_arg(this, args, context)?.day.into())
+    }
+
+    /// `Temporal.Calendar.prototype.dayOfWeek ( temporalDateLike )`
+    fn day_of_week(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        Ok(Self::date_arg(this, args, context)?.day_of_week().into())
+    }
+
+    /// `Temporal.Calendar.prototype.dayOfYear ( temporalDateLike )`
+    fn day_of_year(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        Ok(Self::date_arg(this, args, context)?.day_of_year().into())
+    }
+
+    /// `Temporal.Calendar.prototype.weekOfYear ( temporalDateLike )`
+    fn week_of_year(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        Ok(Self::date_arg(this, args, context)?.week_of_year().1.into())
+    }
+
+    /// `Temporal.Calendar.prototype.yearOfWeek ( temporalDateLike )`
+    fn year_of_week(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        Ok(Self::date_arg(this, args, context)?.week_of_year().0.into())
+    }
+
+    /// `Temporal.Calendar.prototype.daysInWeek ( temporalDateLike )`
+    fn days_in_week(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        Self::date_arg(this, args, context)?;
+        Ok(7.into())
+    }
+
+    /// `Temporal.Calendar.prototype.daysInMonth ( temporalDateLike )`
+    fn days_in_month(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        let date = Self::date_arg(this, args, context)?;
+        Ok(days_in_month(date.year, date.month).into())
+    }
+
+    /// `Temporal.Calendar.prototype.daysInYear ( temporalDateLike )`
+    fn days_in_year(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        Ok(days_in_year(Self::date_arg(this, args, context)?.year).into())
+    }
+
+    /// `Temporal.Calendar.prototype.monthsInYear ( temporalDateLike )`
+    fn months_in_year(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        Self::date_arg(this, args, context)?;
+        Ok(12.into())
+    }
+
+    /// `Temporal.Calendar.prototype.inLeapYear ( temporalDateLike )`
+    fn in_leap_year(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        Ok(is_leap_year(Self::date_arg(this, args, context)?.year).into())
+    }
+
+    /// `Temporal.Calendar.prototype.fields ( fields )`
+    fn fields(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        const FIELD_NAMES: [&str; 10] = [
+            "year",
+            "month",
+            "monthCode",
+            "day",
+            "hour",
+            "minute",
+            "second",
+            "millisecond",
+            "microsecond",
+            "nanosecond",
+        ];
+
+        Self::this_calendar(this)?;
+
+        let iterator =
+            args.get_or_undefined(0)
+                .get_iterator(context, Some(IteratorHint::Sync), None)?;
+        let mut names: Vec<JsString> = Vec::new();
+        while let Some(next) = iterator.step(context)? {
+            let value = next.value(context)?;
+            let error = match value.as_string() {
+                None => JsNativeError::typ().with_message("field names must be strings"),
+                Some(name) if names.contains(name) => JsNativeError::range().with_message(format!(
+                    "duplicate field `{}`",
+                    name.to_std_string_escaped()
+                )),
+                Some(name) if !FIELD_NAMES.iter().any(|field| name == *field) => {
+                    JsNativeError::range()
+                        .with_message(format!("invalid field `{}`", name.to_std_string_escaped()))
+                }
+                Some(name) => {
+                    names.push(name.clone());
+                    continue;
+                }
+            };
+            return iterator.close(Err(error.into()), context);
+        }
+
+        Ok(Array::create_array_from_list(names.into_iter().map(Into::into), context).into())
+    }
+
+    /// `Temporal.Calendar.prototype.mergeFields ( fields, additionalFields )`
+    fn merge_fields(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        Self::this_calendar(this)?;
+        let fields = args.get_or_undefined(0).to_object(context)?;
+        let additional = args.get_or_undefined(1).to_object(context)?;
+
+        let merged = JsObject::with_object_proto(context.intrinsics());
+        let is_month = |key: &JsValue| {
+            key.as_string().map_or(false, |key| {
+                key == utf16!("month") || key == utf16!("monthCode")
+            })
+        };
+
+        let new_keys = additional.enumerable_own_property_names(PropertyNameKind::Key, context)?;
+        let has_month = new_keys.iter().any(is_month);
+
+        for key in fields.enumerable_own_property_names(PropertyNameKind::Key, context)? {
+            if has_month && is_month(&key) {
+                continue;
+            }
+            let key = key.to_property_key(context)?;
+            let value = fields.get(key.clone(), context)?;
+            if !value.is_undefined() {
+                merged.create_data_property_or_throw(key, value, context)?;
+            }
+        }
+
+        for key in new_keys {
+            let key = key.to_property_key(context)?;
+            let value = additional.get(key.clone(), context)?;
+            if !value.is_undefined() {
+                merged.create_data_property_or_throw(key, value, context)?;
+            }
+        }
+
+        Ok(merged.into())
+    }
+
+    /// `Temporal.Calendar.prototype.toString ( )`, `Temporal.Calendar.prototype.toJSON ( )` and
+    /// `get Temporal.Calendar.prototype.id`
+    fn to_string(this: &JsValue, _: &[JsValue], _: &mut Context<'_>) -> JsResult<JsValue> {
+        Self::this_calendar(this)?;
+        Ok(js_string!(ISO8601).into())
+    }
+}
+
+/// Creates the result of the `getCalendar` methods.
+pub(crate) fn get_calendar(context: &mut Context<'_>) -> JsResult<JsValue> {
+    Ok(Calendar.create(None, context)?.into())
+}
+
+/// Returns the calendar annotation of a `toString` method, according to its `calendarName`
+/// option.
+pub(crate) const fn format_calendar_annotation(show: super::options::ShowCalendar) -> &'static str {
+    use super::options::ShowCalendar;
+    match show {
+        ShowCalendar::Auto | ShowCalendar::Never => "",
+        ShowCalendar::Always => "[u-ca=iso8601]",
+        ShowCalendar::Critical => "[!u-ca=iso8601]",
+    }
+}
+
+/// Gets the `calendarName` option of a `toString` method.
+pub(crate) fn get_show_calendar(
+    options: &JsObject,
+    context: &mut Context<'_>,
+) -> JsResult<super::options::ShowCalendar> {
+    Ok(get_option(options, utf16!("calendarName"), false, context)?.unwrap_or_default())
+}
@@ -0,0 +1,1307 @@
+//! Boa's implementation of the `Temporal.Duration` builtin object.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/proposal-temporal/#sec-temporal-duration-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/Duration
+
+use std::{cmp::Ordering, fmt::Write};
+
+use boa_gc::{empty_trace, Finalize, Trace};
+use boa_profiler::Profiler;
+
+use crate::{
+    builtins::{
+        options::{get_option, get_options_object},
+        BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject,
+    },
+    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    js_string,
+    object::{JsObject, ObjectData},
+    property::Attribute,
+    realm::Realm,
+    string::utf16,
+    symbol::JsSymbol,
+    Context, JsArgs, JsNativeError, JsResult, JsValue,
+};
+
+use super::{
+    calendar::{check_calendar_annotation, get_calendar_with_iso_default},
+    constructor_without_new, create_temporal_object,
+    fields::{FieldNames, Fields, RequiredFields},
+    format_fractional_seconds,
+    iso::{DateDuration, IsoDate, IsoDateTime, IsoTime},
+    options::{
+        get_required_temporal_unit, get_rounding_increment, get_string_precision_options,
+        get_temporal_unit, validate_rounding_increment, DifferenceSettings, Overflow, Precision,
+        RoundingMode, TemporalUnit, UnitGroup, UnitOption,
+    },
+    parser::{parse_date_time, parse_duration, ParsedOffset},
+    plain_date::PlainDate,
+    require_string,
+    rounding::{
+        add_zoned_date_time, check_time_duration, difference_date_time_with_rounding,
+        difference_zoned_date_time, difference_zoned_date_time_with_rounding, round_time_duration,
+        total_relative_duration, total_time_duration, InternalDuration, RelativeTo,
+    },
+    time_zone::TimeZoneSlot,
+    to_integer_if_integral, value_of, with_getters,
+    zoned_date_time::{interpret_iso_date_time_offset, OffsetBehaviour, ZonedDateTime},
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The units of the fields of a duration, in the order of [`Duration::fields`].
+const UNITS: [TemporalUnit; 10] = [
+    TemporalUnit::Year,
+    TemporalUnit::Month,
+    TemporalUnit::Week,
+    TemporalUnit::Day,
+    TemporalUnit::Hour,
+    TemporalUnit::Minute,
+    TemporalUnit::Second,
+    TemporalUnit::Millisecond,
+    TemporalUnit::Microsecond,
+    TemporalUnit::Nanosecond,
+];
+
+/// The `Temporal.Duration` builtin object.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Duration {
+    /// The years, months, weeks, days, hours, minutes, seconds, milliseconds, microseconds and
+    /// nanoseconds of the duration, in this order.
+    pub(crate) fields: [f64; 10],
+}
+
+impl Finalize for Duration {}
+
+// SAFETY: `Duration` doesn't contain any traceable data.
+unsafe impl Trace for Duration {
+    empty_trace!();
+}
+
+impl IntrinsicObject for Duration {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        let builder = BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .static_method(Self::from, "from", 1)
+            .static_method(Self::compare, "compare", 2)
+            .property(
+                JsSymbol::to_string_tag(),
+                "Temporal.Duration",
+                Attribute::CONFIGURABLE,
+            )
+            .method(Self::with, "with", 1)
+            .method(Self::negate, "negated", 0)
+            .method(Self::abs, "abs", 0)
+            .method(Self::add, "add", 1)
+            .method(Self::subtract, "subtract", 1)
+            .method(Self::round, "round", 1)
+            .method(Self::total, "total", 1)
+            .method(Self::to_string, "toString", 0)
+            .method(Self::to_json, "toJSON", 0)
+            .method(Self::to_json, "toLocaleString", 0)
+            .method(value_of, "valueOf", 0);
+
+        with_getters(
+            builder,
+            realm,
+            &[
+                ("years", Self::get_years),
+                ("months", Self::get_months),
+                ("weeks", Self::get_weeks),
+                ("days", Self::get_days),
+                ("hours", Self::get_hours),
+                ("minutes", Self::get_minutes),
+                ("seconds", Self::get_seconds),
+                ("milliseconds", Self::get_milliseconds),
+                ("microseconds", Self::get_microseconds),
+                ("nanoseconds", Self::get_nanoseconds),
+                ("sign", Self::get_sign),
+                ("blank", Self::get_blank),
+            ],
+        )
+        .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for Duration {
+    const NAME: &'static str = "Duration";
+}
+
+impl BuiltInConstructor for Duration {
+    const LENGTH: usize = 0;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::duration;
+
+    /// Constructor [`Temporal.Duration ( [ years [ , months [ , weeks [ , days [ , hours [ , minutes [ , seconds [ , milliseconds [ , microseconds [ , nanoseconds ] ] ] ] ] ] ] ] ] ] )`][spec].
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.duration
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, then
+        if new_target.is_undefined() {
+            // a. Throw a TypeError exception.
+            return Err(constructor_without_new(Self::NAME).into());
+        }
+
+        // 2-11. Let each field be ? ToIntegerIfIntegral(field), or 0 if it is undefined.
+        let mut fields = [0.0; 10];
+        for (i, field) in fields.iter_mut().enumerate() {
+            let value = args.get_or_undefined(i);
+            if !value.is_undefined() {
+                *field = to_integer_if_integral(value, context)?;
+            }
+        }
+
+        // 12. Return ? CreateTemporalDuration(y, mo, w, d, h, m, s, ms, mis, ns, NewTarget).
+        Ok(Self::new(fields)?.create(Some(new_target), context)?.into())
+    }
+}
+
+impl Duration {
+    /// Creates a new duration, checking that it is valid.
+    ///
+    /// This follows the abstract operation [`IsValidDuration ( years, months, weeks, days, hours, minutes, seconds, milliseconds, microseconds, nanoseconds )`][spec].
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-isvalidduration
+    pub(crate) fn new(fields: [f64; 10]) -> JsResult<Self> {
+        const MAX_CALENDAR_FIELD: f64 = 4_294_967_296.0;
+        const NANOSECONDS: [i128; 6] = [
+            86_400_000_000_000,
+            3_600_000_000_000,
+            60_000_000_000,
+            1_000_000_000,
+            1_000_000,
+            1000,
+        ];
+
+        let invalid = || -> JsResult<Self> {
+            Err(JsNativeError::range()
+                .with_message("invalid duration")
+                .into())
+        };
+
+        // 1. Let sign be DurationSign(years, months, weeks, days, hours, minutes, seconds, milliseconds, microseconds, nanoseconds).
+        // 2. For each value v of « years, months, weeks, days, hours, minutes, seconds, milliseconds, microseconds, nanoseconds », do
+        //     a. If 𝔽(v) is not finite, return false.
+        //     b. If v < 0 and sign > 0, return false.
+        //     c. If v > 0 and sign < 0, return false.
+        let mut sign = 0.0;
+        for field in fields {
+            if !field.is_finite() {
+                return invalid();
+            }
+            if field != 0.0 {
+                if sign != 0.0 && (field < 0.0) != (sign < 0.0) {
+                    return invalid();
+                }
+                sign = field.signum();
+            }
+        }
+
+        // 3. If abs(years) ≥ 2**32, return false.
+        // 4. If abs(months) ≥ 2**32, return false.
+        // 5. If abs(weeks) ≥ 2**32, return false.
+        if fields[..3]
+            .iter()
+            .any(|field| field.abs() >= MAX_CALENDAR_FIELD)
+        {
+            return invalid();
+        }
+
+        // 6. Let normalizedSeconds be days × 86,400 + hours × 3600 + minutes × 60 + seconds + ℝ(𝔽(milliseconds)) × 10**-3 + ℝ(𝔽(microseconds)) × 10**-6 + ℝ(𝔽(nanoseconds)) × 10**-9.
+        // 7. NOTE: The above step cannot be implemented directly using floating-point arithmetic.
+        // 8. If abs(normalizedSeconds) ≥ 2**53, return false.
+        let mut total: i128 = fields[9] as i128;
+        for (&field, length) in fields[3..9].iter().zip(NANOSECONDS) {
+            if field.abs() >= 1e30 {
+                return invalid();
+            }
+            let Some(sum) = (field as i128)
+                .checked_mul(length)
+                .and_then(|value| value.checked_add(total))
+            else {
+                return invalid();
+            };
+            total = sum;
+        }
+        if fields[9].abs() >= 1e30 || check_time_duration(total).is_err() {
+            return invalid();
+        }
+
+        // 9. Return true.
+        Ok(Self {
+            // Avoid negative zeroes.
+            fields: fields.map(|field| field + 0.0),
+        })
+    }
+
+    /// Creates a duration from a date duration.
+    pub(crate) fn from_date_duration(date: DateDuration) -> JsResult<Self> {
+        Self::new([
+            date.years as f64,
+            date.months as f64,
+            date.weeks as f64,
+            date.days as f64,
+            0.0,
+            0.0,
+            0.0,
+            0.0,
+            0.0,
+            0.0,
+        ])
+    }
+
+    /// Abstract operation [`TemporalDurationFromInternal ( internalDuration, largestUnit )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-temporaldurationfrominternal
+    pub(crate) fn from_internal(
+        internal: InternalDuration,
+        largest_unit: TemporalUnit,
+    ) -> JsResult<Self> {
+        // 1-11. Balance the time duration into the units smaller than or equal to largestUnit.
+        let sign = internal.time.signum();
+        let mut remainder = internal.time.abs();
+        let mut time = [0.0; 7];
+        let units = [
+            TemporalUnit::Day,
+            TemporalUnit::Hour,
+            TemporalUnit::Minute,
+            TemporalUnit::Second,
+            TemporalUnit::Millisecond,
+            TemporalUnit::Microsecond,
+            TemporalUnit::Nanosecond,
+        ];
+        for (field, unit) in time.iter_mut().zip(units) {
+            if unit > largest_unit {
+                continue;
+            }
+            let length = unit.nanoseconds().expect("time units have a fixed length");
+            *field = ((remainder / length) * sign) as f64;
+            remainder %= length;
+        }
+
+        // 12. Return ? CreateTemporalDuration(internalDuration.[[Date]].[[Years]], internalDuration.[[Date]].[[Months]], internalDuration.[[Date]].[[Weeks]], internalDuration.[[Date]].[[Days]] + days × sign, hours × sign, minutes × sign, seconds × sign, milliseconds × sign, microseconds × sign, nanoseconds × sign).
+        let date = internal.date;
+        Self::new([
+            date.years as f64,
+            date.months as f64,
+            date.weeks as f64,
+            date.days as f64 + time[0],
+            time[1],
+            time[2],
+            time[3],
+            time[4],
+            time[5],
+            time[6],
+        ])
+    }
+
+    /// Abstract operation [`ToInternalDurationRecord ( duration )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-tointernaldurationrecord
+    pub(crate) fn to_internal(self) -> InternalDuration {
+        let [years, months, weeks, days, hours, minutes, seconds, milliseconds, microseconds, nanoseconds] =
+            self.fields;
+        let time = ((((hours as i128 * 60 + minutes as i128) * 60 + seconds as i128) * 1000
+            + milliseconds as i128)
+            * 1000
+            + microseconds as i128)
+            * 1000
+            + nanoseconds as i128;
+        InternalDuration::new(
+            DateDuration {
+                years: years as i64,
+                months: months as i64,
+                weeks: weeks as i64,
+                days: days as i64,
+            },
+            time,
+        )
+    }
+
+    /// Abstract operation [`ToInternalDurationRecordWith24HourDays ( duration )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-tointernaldurationrecordwith24hourdays
+    pub(crate) fn to_internal_24h(self) -> InternalDuration {
+        let mut internal = self.to_internal();
+        internal.time += i128::from(internal.date.days) * super::iso::NS_PER_DAY;
+        internal.date.days = 0;
+        internal
+    }
+
+    /// Abstract operation [`DurationSign ( duration )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-durationsign
+    pub(crate) fn sign(&self) -> i32 {
+        self.fields
+            .iter()
+            .find(|&&field| field != 0.0)
+            .map_or(0, |&field| field.signum() as i32)
+    }
+
+    /// Abstract operation [`CreateNegatedTemporalDuration ( duration )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-createnegatedtemporalduration
+    pub(crate) fn negated(&self) -> Self {
+        Self {
+            fields: self.fields.map(|field| -field + 0.0),
+        }
+    }
+
+    /// Abstract operation [`DefaultTemporalLargestUnit ( duration )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-defaulttemporallargestunit
+    pub(crate) fn default_largest_unit(&self) -> TemporalUnit {
+        self.fields
+            .iter()
+            .zip(UNITS)
+            .find(|(&field, _)| field != 0.0)
+            .map_or(TemporalUnit::Nanosecond, |(_, unit)| unit)
+    }
+
+    /// Creates a new `Temporal.Duration` object with this data.
+    pub(crate) fn create(
+        self,
+        new_target: Option<&JsValue>,
+        context: &mut Context<'_>,
+    ) -> JsResult<JsObject> {
+        create_temporal_object(
+            ObjectData::duration(self),
+            new_target,
+            StandardConstructors::duration,
+            context,
+        )
+    }
+
+    /// Gets the duration of the `this` value.
+    fn this_duration(this: &JsValue) -> JsResult<Self> {
+        this.as_object()
+            .and_then(|object| object.borrow().as_duration().copied())
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("'this' is not a Temporal.Duration object")
+                    .into()
+            })
+    }
+
+    /// `Temporal.Duration.from ( item )`
+    fn from(_: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        let duration = to_temporal_duration(args.get_or_undefined(0), context)?;
+        Ok(duration.create(None, context)?.into())
+    }
+
+    /// `Temporal.Duration.compare ( one, two [ , options ] )`
+    fn compare(_: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Set one to ? ToTemporalDuration(one).
+        let one = to_temporal_duration(args.get_or_undefined(0), context)?;
+
+        // 2. Set two to ? ToTemporalDuration(two).
+        let two = to_temporal_duration(args.get_or_undefined(1), context)?;
+
+        // 3. Let resolvedOptions be ? GetOptionsObject(options).
+        let options = get_options_object(args.get_or_undefined(2))?;
+
+        // 4. Let relativeToRecord be ? GetTemporalRelativeToOption(resolvedOptions).
+        let relative_to = get_relative_to(&options, context)?;
+
+        // 5. If one.[[Years]] = two.[[Years]], and ... one.[[Nanoseconds]] = two.[[Nanoseconds]], then
+        if one == two {
+            // a. Return +0𝔽.
+            return Ok(0.into());
+        }
+
+        // 8. Let largestUnit1 be DefaultTemporalLargestUnit(one).
+        // 9. Let largestUnit2 be DefaultTemporalLargestUnit(two).
+        let largest_unit_1 = one.default_largest_unit();
+        let largest_unit_2 = two.default_largest_unit();
+
+        // 10. Let duration1 be ToInternalDurationRecord(one).
+        // 11. Let duration2 be ToInternalDurationRecord(two).
+        let duration_1 = one.to_internal();
+        let duration_2 = two.to_internal();
+
+        // 12. If zonedRelativeTo is not undefined, and either TemporalUnitCategory(largestUnit1) or TemporalUnitCategory(largestUnit2) is date, then
+        if let RelativeToOption::Zoned(zoned) = &relative_to {
+            if largest_unit_1.is_date_unit() || largest_unit_2.is_date_unit() {
+                // a-d. Add both durations to the starting point and compare the results.
+                let after_1 = add_zoned_date_time(
+                    zoned.epoch_ns,
+                    &zoned.time_zone,
+                    duration_1,
+                    Overflow::Constrain,
+                    context,
+                )?;
+                let after_2 = add_zoned_date_time(
+                    zoned.epoch_ns,
+                    &zoned.time_zone,
+                    duration_2,
+                    Overflow::Constrain,
+                    context,
+                )?;
+                // e. If after1 > after2, return 1𝔽.
+                // f. If after1 < after2, return -1𝔽.
+                // g. Return +0𝔽.
+                return Ok(ordering_to_value(after_1.cmp(&after_2)));
+            }
+        }
+
+        // 13. If IsCalendarUnit(largestUnit1) is true or IsCalendarUnit(largestUnit2) is true, then
+        let (days_1, days_2) =
+            if largest_unit_1.is_calendar_unit() || largest_unit_2.is_calendar_unit() {
+                // a. If plainRelativeTo is undefined, throw a RangeError exception.
+                let RelativeToOption::Plain(date) = relative_to else {
+                    return Err(JsNativeError::range()
+                        .with_message("a starting date is required to compare calendar units")
+                        .into());
+                };
+                // b. Let days1 be ? DateDurationDays(duration1.[[Date]], plainRelativeTo).
+                // c. Let days2 be ? DateDurationDays(duration2.[[Date]], plainRelativeTo).
+                (
+                    date_duration_days(duration_1.date, date)?,
+                    date_duration_days(duration_2.date, date)?,
+                )
+            } else {
+                // 14. Else,
+                //     a. Let days1 be one.[[Days]].
+                //     b. Let days2 be two.[[Days]].
+                (duration_1.date.days, duration_2.date.days)
+            };
+
+        // 15. Let timeDuration1 be ? Add24HourDaysToTimeDuration(duration1.[[Time]], days1).
+        // 16. Let timeDuration2 be ? Add24HourDaysToTimeDuration(duration2.[[Time]], days2).
+        let time_1 = duration_1.time + i128::from(days_1) * super::iso::NS_PER_DAY;
+        let time_2 = duration_2.time + i128::from(days_2) * super::iso::NS_PER_DAY;
+
+        // 17. Return 𝔽(CompareTimeDuration(timeDuration1, timeDuration2)).
+        Ok(ordering_to_value(time_1.cmp(&time_2)))
+    }
+
+    /// `get Temporal.Duration.prototype.years`
+    fn get_years(this: &JsValue, _: &[JsValue], _: &mut Context<'_>) -> JsResult<JsValue> {
+        Ok(Self::this_duration(this)?.fields[0].into())
+    }
+
+    /// `get Temporal.Duration.prototype.months`
+    fn get_months(this: &JsValue, _: &[JsValue], _: &mut Context<'_>) -> JsResult<JsValue> {
+        Ok(Self::this_duration(this)?.fields[1].into())
+    }
+
+    /// `get Temporal.Duration.prototype.weeks`
+    fn get_weeks(this: &JsValue, _: &[JsValue], _: &mut Context<'_>) -> JsResult<JsValue> {
+        Ok(Self::this_duration(this)?.fields[2].into())
+    }
+
+    /// `get Temporal.Duration.prototype.days`
+    fn get_days(this: &JsValue, _: &[JsValue], _: &mut Context<'_>) -> JsResult<JsValue> {
+        Ok(Self::this_duration(this)?.fields[3].into())
+    }
+
+    /// `get Temporal.Duration.prototype.hours`
+    fn get_hours(this: &JsValue, _: &[JsValue], _: &mut Context<'_>) -> JsResult<JsValue> {
+        Ok(Self::this_duration(this)?.fields[4].into())
+    }
+
+    /// `get Temporal.Duration.prototype.minutes`
+    fn get_minutes(this: &JsValue, _: &[JsValue], _: &mut Context<'_>) -> JsResult<JsValue> {
+        Ok(Self::this_duration(this)?.fields[5].into())
+    }
+
+    /// `get Temporal.Duration.prototype.seconds`
+    fn get_seconds(this: &JsValue, _: &[JsValue], _: &mut Context<'_>) -> JsResult<JsValue> {
+        Ok(Self::this_duration(this)?.fields[6].into())
+    }
+
+    /// `get Temporal.Duration.prototype.milliseconds`
+    fn get_milliseconds(this: &JsValue, _: &[JsValue], _: &mut Context<'_>) -> JsResult<JsValue> {
+        Ok(Self::this_duration(this)?.fields[7].into())
+    }
+
+    /// `get Temporal.Duration.prototype.microseconds`
+    fn get_microseconds(this: &JsValue, _: &[JsValue], _: &mut Context<'_>) -> JsResult<JsValue> {
+        Ok(Self::this_duration(this)?.fields[8].into())
+    }
+
+    /// `get Temporal.Duration.prototype.nanoseconds`
+    fn get_nanoseconds(this: &JsValue, _: &[JsValue], _: &mut Context<'_>) -> JsResult<JsValue> {
+        Ok(Self::this_duration(this)?.fields[9].into())
+    }
+
+    /// `get Temporal.Duration.prototype.sign`
+    fn get_sign(this: &JsValue, _: &[JsValue], _: &mut Context<'_>) -> JsResult<JsValue> {
+        Ok(Self::this_duration(this)?.sign().into())
+    }
+
+    /// `get Temporal.Duration.prototype.blank`
+    fn get_blank(this: &JsValue, _: &[JsValue], _: &mut Context<'_>) -> JsResult<JsValue> {
+        Ok((Self::this_duration(this)?.sign() == 0).into())
+    }
+
+    /// `Temporal.Duration.prototype.with ( temporalDurationLike )`
+    fn with(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Let duration be the this value.
+        // 2. Perform ? RequireInternalSlot(duration, [[InitializedTemporalDuration]]).
+        let duration = Self::this_duration(this)?;
+
+        // 3. Let temporalDurationLike be ? ToTemporalPartialDurationRecord(temporalDurationLike).
+        let partial = to_partial_duration(args.get_or_undefined(0), context)?;
+
+        // 4-23. Use the fields of the partial duration, or the fields of duration if they are undefined.
+        let mut fields = duration.fields;
+        for (field, value) in fields.iter_mut().zip(partial) {
+            if let Some(value) = value {
+                *field = value;
+            }
+        }
+
+        // 24. Return ? CreateTemporalDuration(years, months, weeks, days, hours, minutes, seconds, milliseconds, microseconds, nanoseconds).
+        Ok(Self::new(fields)?.create(None, context)?.into())
+    }
+
+    /// `Temporal.Duration.prototype.negated ( )`
+    fn negate(this: &JsValue, _: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        let duration = Self::this_duration(this)?;
+        Ok(duration.negated().create(None, context)?.into())
+    }
+
+    /// `Temporal.Duration.prototype.abs ( )`
+    fn abs(this: &JsValue, _: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        let duration = Self::this_duration(this)?;
+        Ok(Self {
+            fields: duration.fields.map(f64::abs),
+        }
+        .create(None, context)?
+        .into())
+    }
+
+    /// `Temporal.Duration.prototype.add ( other )`
+    fn add(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        let duration = Self::this_duration(this)?;
+        let other = to_temporal_duration(args.get_or_undefined(0), context)?;
+        Ok(duration.add_durations(other)?.create(None, context)?.into())
+    }
+
+    /// `Temporal.Duration.prototype.subtract ( other )`
+    fn subtract(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        let duration = Self::this_duration(this)?;
+        let other = to_temporal_duration(args.get_or_undefined(0), context)?;
+        Ok(duration
+            .add_durations(other.negated())?
+            .create(None, context)?
+            .into())
+    }
+
+    /// Abstract operation [`AddDurations ( operation, duration, other )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-adddurations
+    fn add_durations(self, other: Self) -> JsResult<Self> {
+        // 3. Let largestUnit1 be DefaultTemporalLargestUnit(duration).
+        // 4. Let largestUnit2 be DefaultTemporalLargestUnit(other).
+        // 5. Let largestUnit be LargerOfTwoTemporalUnits(largestUnit1, largestUnit2).
+        let largest_unit = self
+            .default_largest_unit()
+            .max(other.default_largest_unit());
+
+        // 6. If IsCalendarUnit(largestUnit) is true, throw a RangeError exception.
+        if largest_unit.is_calendar_unit() {
+            return Err(JsNativeError::range()
+                .with_message("cannot add durations with years, months or weeks")
+                .into());
+        }
+
+        // 7. Let d1 be ToInternalDurationRecordWith24HourDays(duration).
+        // 8. Let d2 be ToInternalDurationRecordWith24HourDays(other).
+        // 9. Let timeResult be ? AddTimeDuration(d1.[[Time]], d2.[[Time]]).
+        let time = check_time_duration(self.to_internal_24h().time + other.to_internal_24h().time)?;
+
+        // 10. Let result be CombineDateAndTimeDuration(ZeroDateDuration(), timeResult).
+        // 11. Return ? TemporalDurationFromInternal(result, largestUnit).
+        Self::from_internal(InternalDuration::from_time(time), largest_unit)
+    }
+
+    /// `Temporal.Duration.prototype.round ( roundTo )`
+    fn round(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Let duration be the this value.
+        // 2. Perform ? RequireInternalSlot(duration, [[InitializedTemporalDuration]]).
+        let duration = Self::this_duration(this)?;
+
+        // 3-5. Get the options object, or an object with a `smallestUnit` if roundTo is a string.
+        let round_to = options_or_unit(args.get_or_undefined(0), utf16!("smallestUnit"), context)?;
+
+        // 6. Let smallestUnitPresent be true.
+        // 7. Let largestUnitPresent be true.
+        // 8. NOTE: The following steps read options and perform independent validation in alphabetical order.
+        // 9. Let largestUnit be ? GetTemporalUnitValuedOption(roundTo, "largestUnit", datetime, unset, « auto »).
+        let largest_unit = get_temporal_unit(
+            &round_to,
+            utf16!("largestUnit"),
+            UnitGroup::DateTime,
+            None,
+            true,
+            context,
+        )?;
+
+        // 10. Let relativeToRecord be ? GetTemporalRelativeToOption(roundTo).
+        let relative_to = get_relative_to(&round_to, context)?;
+
+        // 13. Let roundingIncrement be ? GetRoundingIncrementOption(roundTo).
+        let rounding_increment = get_rounding_increment(&round_to, context)?;
+
+        // 14. Let roundingMode be ? GetRoundingModeOption(roundTo, half-expand).
+        let rounding_mode = get_option(&round_to, utf16!("roundingMode"), false, context)?
+            .unwrap_or(RoundingMode::HalfExpand);
+
+        // 15. Let smallestUnit be ? GetTemporalUnitValuedOption(roundTo, "smallestUnit", datetime, unset).
+        let smallest_unit = get_temporal_unit(
+            &round_to,
+            utf16!("smallestUnit"),
+            UnitGroup::DateTime,
+            None,
+            false,
+            context,
+        )?;
+
+        // 17. If smallestUnit is unset, then
+        //     a. Set smallestUnitPresent to false.
+        //     b. Set smallestUnit to nanosecond.
+        let smallest_unit_present = smallest_unit.is_some();
+        let smallest_unit = match smallest_unit {
+            Some(UnitOption::Unit(unit)) => unit,
+            _ => TemporalUnit::Nanosecond,
+        };
+
+        // 18. Let existingLargestUnit be DefaultTemporalLargestUnit(duration).
+        let existing_largest_unit = duration.default_largest_unit();
+
+        // 19. Let defaultLargestUnit be LargerOfTwoTemporalUnits(existingLargestUnit, smallestUnit).
+        let default_largest_unit = existing_largest_unit.max(smallest_unit);
+
+        // 20-21. If largestUnit is unset or auto, set largestUnit to defaultLargestUnit.
+        let largest_unit_present = largest_unit.is_some();
+        let largest_unit = match largest_unit {
+            Some(UnitOption::Unit(unit)) => unit,
+            _ => default_largest_unit,
+        };
+
+        // 22. If smallestUnitPresent is false and largestUnitPresent is false, then
+        if !smallest_unit_present && !largest_unit_present {
+            // a. Throw a RangeError exception.
+            return Err(JsNativeError::range()
+                .with_message("either `smallestUnit` or `largestUnit` is required")
+                .into());
+        }
+
+        // 23. If LargerOfTwoTemporalUnits(largestUnit, smallestUnit) is not largestUnit, throw a RangeError exception.
+        if smallest_unit > largest_unit {
+            return Err(JsNativeError::range()
+                .with_message("`smallestUnit` cannot be larger than `largestUnit`")
+                .into());
+        }
+
+        // 24. Let maximum be MaximumTemporalDurationRoundingIncrement(smallestUnit).
+        // 25. If maximum is not unset, perform ? ValidateTemporalRoundingIncrement(roundingIncrement, maximum, false).
+        if let Some(maximum) = smallest_unit.maximum_rounding_increment() {
+            validate_rounding_increment(rounding_increment, maximum, false)?;
+        }
+
+        // 26. If roundingIncrement > 1, and largestUnit is not smallestUnit, and TemporalUnitCategory(smallestUnit) is date, throw a RangeError exception.
+        if rounding_increment > 1 && largest_unit != smallest_unit && smallest_unit.is_date_unit() {
+            return Err(JsNativeError::range()
+                .with_message("`roundingIncrement` must be 1 when rounding to a date unit")
+                .into());
+        }
+
+        let settings = DifferenceSettings {
+            smallest_unit,
+            largest_unit,
+            rounding_mode,
+            rounding_increment,
+        };
+
+        match relative_to {
+            // 27. If zonedRelativeTo is not undefined, then
+            RelativeToOption::Zoned(zoned) => {
+                // a. Let internalDuration be ToInternalDurationRecord(duration).
+                let internal = duration.to_internal();
+                // b-d. Let targetEpochNs be ? AddZonedDateTime(relativeEpochNs, timeZone, calendar, internalDuration, constrain).
+                let target = add_zoned_date_time(
+                    zoned.epoch_ns,
+                    &zoned.time_zone,
+                    internal,
+                    Overflow::Constrain,
+                    context,
+                )?;
+                // e. Set internalDuration to ? DifferenceZonedDateTimeWithRounding(relativeEpochNs, targetEpochNs, timeZone, calendar, largestUnit, roundingIncrement, smallestUnit, roundingMode).
+                let internal = difference_zoned_date_time_with_rounding(
+                    zoned.epoch_ns,
+                    target,
+                    &zoned.time_zone,
+                    settings,
+                    context,
+                )?;
+                // f. If TemporalUnitCategory(largestUnit) is date, set largestUnit to hour.
+                let largest_unit = if largest_unit.is_date_unit() {
+                    TemporalUnit::Hour
+                } else {
+                    largest_unit
+                };
+                // g. Return ? TemporalDurationFromInternal(internalDuration, largestUnit).
+                Ok(Self::from_internal(internal, largest_unit)?
+                    .create(None, context)?
+                    .into())
+            }
+            // 28. If plainRelativeTo is not undefined, then
+            RelativeToOption::Plain(date) => {
+                let (start, target) = plain_relative_target(duration, date)?;
+                // h. Set internalDuration to ? DifferencePlainDateTimeWithRounding(isoDateTime, targetDateTime, calendar, largestUnit, roundingIncrement, smallestUnit, roundingMode).
+                let internal =
+                    difference_date_time_with_rounding(start, target, settings, context)?;
+                // i. Return ? TemporalDurationFromInternal(internalDuration, largestUnit).
+                Ok(Self::from_internal(internal, largest_unit)?
+                    .create(None, context)?
+                    .into())
+            }
+            RelativeToOption::None => {
+                // 29. If IsCalendarUnit(existingLargestUnit) is true, or IsCalendarUnit(largestUnit) is true, throw a RangeError exception.
+                if existing_largest_unit.is_calendar_unit() || largest_unit.is_calendar_unit() {
+                    return Err(JsNativeError::range()
+                        .with_message("a starting date is required to round calendar units")
+                        .into());
+                }
+
+                // 30. Assert: IsCalendarUnit(smallestUnit) is false.
+                // 31. Let internalDuration be ToInternalDurationRecordWith24HourDays(duration).
+                let internal = duration.to_internal_24h();
+
+                // 32-33. Round the time duration to the smallest unit, including days.
+                let time = round_time_duration(
+                    internal.time,
+                    rounding_increment,
+                    smallest_unit,
+                    rounding_mode,
+                )?;
+
+                // 34. Return ? TemporalDurationFromInternal(internalDuration, largestUnit).
+                Ok(
+                    Self::from_internal(InternalDuration::from_time(time), largest_unit)?
+                        .create(None, context)?
+                        .into(),
+                )
+            }
+        }
+    }
+
+    /// `Temporal.Duration.prototype.total ( totalOf )`
+    fn total(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Let duration be the this value.
+        // 2. Perform ? RequireInternalSlot(duration, [[InitializedTemporalDuration]]).
+        let duration = Self::this_duration(this)?;
+
+        // 3-5. Get the options object, or an object with a `unit` if totalOf is a string.
+        let total_of = options_or_unit(args.get_or_undefined(0), utf16!("unit"), context)?;
+
+        // 6. NOTE: The following steps read options and perform independent validation in alphabetical order.
+        // 7. Let relativeToRecord be ? GetTemporalRelativeToOption(totalOf).
+        let relative_to = get_relative_to(&total_of, context)?;
+
+        // 10. Let unit be ? GetTemporalUnitValuedOption(totalOf, "unit", datetime, required).
+        let unit = get_required_temporal_unit(
+            &total_of,
+            utf16!("unit"),
+            UnitGroup::DateTime,
+            None,
+            context,
+        )?;
+
+        let total = match relative_to {
+            // 11. If zonedRelativeTo is not undefined, then
+            RelativeToOption::Zoned(zoned) => {
+                // a. Let internalDuration be ToInternalDurationRecord(duration).
+                // b-d. Let targetEpochNs be ? AddZonedDateTime(relativeEpochNs, timeZone, calendar, internalDuration, constrain).
+                let target = add_zoned_date_time(
+                    zoned.epoch_ns,
+                    &zoned.time_zone,
+                    duration.to_internal(),
+                    Overflow::Constrain,
+                    context,
+                )?;
+                // e. Let total be ? DifferenceZonedDateTimeWithTotal(relativeEpochNs, targetEpochNs, timeZone, calendar, unit).
+                if unit.is_date_unit() {
+                    let difference = difference_zoned_date_time(
+                        zoned.epoch_ns,
+                        target,
+                        &zoned.time_zone,
+                        unit,
+                        context,
+                    )?;
+                    let relative = RelativeTo {
+                        date_time: zoned.time_zone.iso_date_time_for(zoned.epoch_ns, context),
+                        time_zone: Some(&zoned.time_zone),
+                    };
+                    total_relative_duration(difference, target, relative, unit, context)?
+                } else {
+                    total_time_duration(target - zoned.epoch_ns, unit)
+                }
+            }
+            // 12. Else if plainRelativeTo is not undefined, then
+            RelativeToOption::Plain(date) => {
+                let (start, target) = plain_relative_target(duration, date)?;
+                // h. Let total be ? DifferencePlainDateTimeWithTotal(isoDateTime, targetDateTime, calendar, unit).
+                if start == target {
+                    0.0
+                } else {
+                    start.check_limits()?;
+                    target.check_limits()?;
+                    let (date, time) = start.until(target, unit);
+                    let relative = RelativeTo {
+                        date_time: start,
+                        time_zone: None,
+                    };
+                    total_relative_duration(
+                        InternalDuration::new(date, time),
+                        target.to_epoch_nanoseconds(),
+                        relative,
+                        unit,
+                        context,
+                    )?
+                }
+            }
+            // 13. Else,
+            RelativeToOption::None => {
+                // a. Let largestUnit be DefaultTemporalLargestUnit(duration).
+                // b. If IsCalendarUnit(largestUnit) is true, or IsCalendarUnit(unit) is true, throw a RangeError exception.
+                if duration.default_largest_unit().is_calendar_unit() || unit.is_calendar_unit() {
+                    return Err(JsNativeError::range()
+                        .with_message("a starting date is required to total calendar units")
+                        .into());
+                }
+                // c. Let internalDuration be ToInternalDurationRecordWith24HourDays(duration).
+                // d. Let total be TotalTimeDuration(internalDuration.[[Time]], unit).
+                total_time_duration(duration.to_internal_24h().time, unit)
+            }
+        };
+
+        // 14. Return 𝔽(total).
+        Ok(total.into())
+    }
+
+    /// `Temporal.Duration.prototype.toString ( [ options ] )`
+    fn to_string(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Let duration be the this value.
+        // 2. Perform ? RequireInternalSlot(duration, [[InitializedTemporalDuration]]).
+        let duration = Self::this_duration(this)?;
+
+        // 3. Let resolvedOptions be ? GetOptionsObject(options).
+        let options = get_options_object(args.get_or_undefined(0))?;
+
+        // 4-9. Read the precision options, which cannot be hours or minutes.
+        let (precision, rounding_mode) = get_string_precision_options(&options, context)?;
+        if precision.precision == Precision::Minute {
+            return Err(JsNativeError::range()
+                .with_message("`smallestUnit` cannot be `minute`")
+                .into());
+        }
+
+        // 10. If precision.[[Unit]] is nanosecond and precision.[[Increment]] = 1, then
+        if precision.unit == TemporalUnit::Nanosecond && precision.increment == 1 {
+            // a. Return TemporalDurationToString(duration, precision.[[Precision]]).
+            return Ok(js_string!(duration.to_iso_string(precision.precision)).into());
+        }
+
+        // 11. Let largestUnit be DefaultTemporalLargestUnit(duration).
+        let largest_unit = duration.default_largest_unit();
+
+        // 12. Let internalDuration be ToInternalDurationRecord(duration).
+        let internal = duration.to_internal();
+
+        // 13. Let timeDuration be ? RoundTimeDuration(internalDuration.[[Time]], precision.[[Increment]], precision.[[Unit]], roundingMode).
+        let time = round_time_duration(
+            internal.time,
+            precision.increment,
+            precision.unit,
+            rounding_mode,
+        )?;
+
+        // 14. Set internalDuration to CombineDateAndTimeDuration(internalDuration.[[Date]], timeDuration).
+        // 15. Let roundedLargestUnit be LargerOfTwoTemporalUnits(largestUnit, second).
+        // 16. Let roundedDuration be ? TemporalDurationFromInternal(internalDuration, roundedLargestUnit).
+        let rounded = Self::from_internal(
+            InternalDuration::new(internal.date, time),
+            largest_unit.max(TemporalUnit::Second),
+        )?;
+
+        // 17. Return TemporalDurationToString(roundedDuration, precision.[[Precision]]).
+        Ok(js_string!(rounded.to_iso_string(precision.precision)).into())
+    }
+
+    /// `Temporal.Duration.prototype.toJSON ( )` and
+    /// `Temporal.Duration.prototype.toLocaleString ( [ locales [ , options ] ] )`
+    fn to_json(this: &JsValue, _: &[JsValue], _: &mut Context<'_>) -> JsResult<JsValue> {
+        let duration = Self::this_duration(this)?;
+        Ok(js_string!(duration.to_iso_string(Precision::Auto)).into())
+    }
+
+    /// Abstract operation [`TemporalDurationToString ( duration, precision )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-temporaldurationtostring
+    pub(crate) fn to_iso_string(self, precision: Precision) -> String {
+        let fields = self.fields.map(f64::abs);
+        let mut result = String::from(if self.sign() < 0 { "-P" } else { "P" });
+
+        for (field, designator) in fields[..4].iter().zip(['Y', 'M', 'W', 'D']) {
+            if *field != 0.0 {
+                let _ = write!(result, "{field}{designator}");
+            }
+        }
+
+        let mut time = String::new();
+        for (field, designator) in fields[4..6].iter().zip(['H', 'M']) {
+            if *field != 0.0 {
+                let _ = write!(time, "{field}{designator}");
+            }
+        }
+
+        let zero_minutes_and_higher = fields[..6].iter().all(|&field| field == 0.0);
+        let seconds = fields[6] as i128 * 1_000_000_000
+            + fields[7] as i128 * 1_000_000
+            + fields[8] as i128 * 1000
+            + fields[9] as i128;
+        if seconds != 0 || zero_minutes_and_higher || precision != Precision::Auto {
+            let _ = write!(
+                time,
+                "{}{}S",
+                seconds / 1_000_000_000,
+                format_fractional_seconds((seconds % 1_000_000_000) as u32, precision)
+            );
+        }
+
+        if !time.is_empty() {
+            result.push('T');
+            result.push_str(&time);
+        }
+        result
+    }
+}
+
+/// Converts an ordering to the result of a `compare` function.
+pub(crate) fn ordering_to_value(ordering: Ordering) -> JsValue {
+    match ordering {
+        Ordering::Less => (-1).into(),
+        Ordering::Equal => 0.into(),
+        Ordering::Greater => 1.into(),
+    }
+}
+
+/// Returns the options object of `round` and `total`, which can also be the string of the unit
+/// option `key`.
+pub(crate) fn options_or_unit(
+    value: &JsValue,
+    key: &[u16],
+    context: &mut Context<'_>,
+) -> JsResult<JsObject> {
+    // 1. If roundTo is undefined, then
+    if value.is_undefined() {
+        // a. Throw a TypeError exception.
+        return Err(JsNativeError::typ()
+            .with_message("an options object or a unit is required")
+            .into());
+    }
+
+    // 2. If roundTo is a String, then
+    if value.is_string() {
+        // a. Let paramString be roundTo.
+        // b. Set roundTo to OrdinaryObjectCreate(null).
+        // c. Perform ! CreateDataPropertyOrThrow(roundTo, "smallestUnit", paramString).
+        let options = get_options_object(&JsValue::undefined())?;
+        options.create_data_property_or_throw(key, value.clone(), context)?;
+        return Ok(options);
+    }
+
+    // 3. Else,
+    //     a. Set roundTo to ? GetOptionsObject(roundTo).
+    get_options_object(value)
+}
+
+/// Abstract operation [`DateDurationDays ( dateDuration, plainRelativeTo )`][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-datedurationdays
+fn date_duration_days(duration: DateDuration, relative_to: IsoDate) -> JsResult<i64> {
+    // 1. Let yearsMonthsWeeksDuration be ! AdjustDateDurationRecord(dateDuration, 0).
+    let years_months_weeks = DateDuration {
+        days: 0,
+        ..duration
+    };
+
+    // 2. If DateDurationSign(yearsMonthsWeeksDuration) = 0, return dateDuration.[[Days]].
+    if years_months_weeks.sign() == 0 {
+        return Ok(duration.days);
+    }
+
+    // 3. Let later be ? CalendarDateAdd(plainRelativeTo.[[Calendar]], plainRelativeTo.[[ISODate]], yearsMonthsWeeksDuration, constrain).
+    let later = relative_to.add(years_months_weeks, Overflow::Constrain)?;
+
+    // 4-7. Return dateDuration.[[Days]] + the days between plainRelativeTo and later.
+    Ok(duration.days + later.to_epoch_days() - relative_to.to_epoch_days())
+}
+
+/// Returns the start and end date-times of a duration relative to a plain date, as used by
+/// `round` and `total`.
+fn plain_relative_target(
+    duration: Duration,
+    date: IsoDate,
+) -> JsResult<(IsoDateTime, IsoDateTime)> {
+    // a. Let internalDuration be ToInternalDurationRecordWith24HourDays(duration).
+    let internal = duration.to_internal_24h();
+
+    // b. Let targetTime be AddTime(MidnightTimeRecord(), internalDuration.[[Time]]).
+    let (days, target_time) = IsoTime::balance(internal.time);
+
+    // c. Let calendar be plainRelativeTo.[[Calendar]].
+    // d. Let dateDuration be ! AdjustDateDurationRecord(internalDuration.[[Date]], targetTime.[[Days]]).
+    let date_duration = DateDuration {
+        days,
+        ..internal.date
+    };
+
+    // e. Let targetDate be ? CalendarDateAdd(calendar, plainRelativeTo.[[ISODate]], dateDuration, constrain).
+    let target_date = date.add(date_duration, Overflow::Constrain)?;
+
+    // f. Let isoDateTime be CombineISODateAndTimeRecord(plainRelativeTo.[[ISODate]], MidnightTimeRecord()).
+    // g. Let targetDateTime be CombineISODateAndTimeRecord(targetDate, targetTime).
+    Ok((
+        IsoDateTime::new(date, IsoTime::default()),
+        IsoDateTime::new(target_date, target_time),
+    ))
+}
+
+/// The `relativeTo` option of the methods of `Temporal.Duration`.
+#[derive(Debug)]
+enum RelativeToOption {
+    None,
+    Plain(IsoDate),
+    Zoned(ZonedDateTime),
+}
+
+/// Abstract operation [`GetTemporalRelativeToOption ( options )`][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-gettemporalrelativetooption
+fn get_relative_to(options: &JsObject, context: &mut Context<'_>) -> JsResult<RelativeToOption> {
+    // 1. Let value be ? Get(options, "relativeTo").
+    let value = options.get(utf16!("relativeTo"), context)?;
+
+    // 2. If value is undefined, return the Record { [[PlainRelativeTo]]: undefined, [[ZonedRelativeTo]]: undefined }.
+    if value.is_undefined() {
+        return Ok(RelativeToOption::None);
+    }
+
+    // 3. Let offsetBehaviour be option.
+    // 4. Let matchBehaviour be match-exactly.
+    let mut match_minutes = false;
+
+    // 5. If value is an Object, then
+    let (date, time, offset_behaviour, time_zone) = if let Some(object) = value.as_object() {
+        {
+            let borrowed = object.borrow();
+            // a. If value has an [[InitializedTemporalZonedDateTime]] internal slot, then
+            if let Some(zoned) = borrowed.as_zoned_date_time() {
+                // i. Return the Record { [[PlainRelativeTo]]: undefined, [[ZonedRelativeTo]]: value }.
+                return Ok(RelativeToOption::Zoned(zoned.clone()));
+            }
+            // b. If value has an [[InitializedTemporalDate]] internal slot, then
+            if let Some(date) = borrowed.as_plain_date() {
+                // i. Return the Record { [[PlainRelativeTo]]: value, [[ZonedRelativeTo]]: undefined }.
+                return Ok(RelativeToOption::Plain(date.date));
+            }
+            // c. If value has an [[InitializedTemporalDateTime]] internal slot, then
+            if let Some(date_time) = borrowed.as_plain_date_time() {
+                // i. Let plainDate be ! CreateTemporalDate(value.[[ISODateTime]].[[ISODate]], value.[[Calendar]]).
+                // ii. Return the Record { [[PlainRelativeTo]]: plainDate, [[ZonedRelativeTo]]: undefined }.
+                return Ok(RelativeToOption::Plain(date_time.date_time.date));
+            }
+        }
+
+        // d. Let calendar be ? GetTemporalCalendarIdentifierWithISODefault(value).
+        get_calendar_with_iso_default(object, context)?;
+
+        // e. Let fields be ? PrepareCalendarFields(calendar, value, « year, month, month-code, day », « hour, minute, second, millisecond, microsecond, nanosecond, offset, time-zone », «»).
+        let fields = Fields::prepare(object, FieldNames::ZONED, RequiredFields::None, context)?;
+
+        // f. Let result be ? InterpretTemporalDateTimeFields(calendar, fields, constrain).
+        let date = fields.to_date(Overflow::Constrain)?;
+        let time = fields.to_time(Overflow::Constrain)?;
+
+        // g-j. If offsetString is unset, set offsetBehaviour to wall.
+        let offset_behaviour = match fields.offset {
+            Some(offset) => OffsetBehaviour::Option(offset),
+            None => OffsetBehaviour::Wall,
+        };
+        (date, Some(time), offset_behaviour, fields.time_zone)
+    } else {
+        // 6. Else,
+        //     a. If value is not a String, throw a TypeError exception.
+        let string = require_string(&value, "`relativeTo`")?;
+
+        // b. Let result be ? ParseISODateTime(value, « TemporalDateTimeString[+Zoned], TemporalDateTimeString[~Zoned] »).
+        let parsed =
+            parse_date_time(&string).ok_or_else(|| super::invalid_string("date-time", &string))?;
+
+        // h. Let calendar be result.[[Calendar]]. If it is empty, set it to "iso8601".
+        // i. Set calendar to ? CanonicalizeCalendar(calendar).
+        check_calendar_annotation(parsed.calendar.as_deref())?;
+
+        // c-g. Let timeZone be the time zone annotation, if any.
+        let (time_zone, offset_behaviour) = match parsed.time_zone {
+            None => {
+                if parsed.offset == Some(ParsedOffset::Z) {
+                    return Err(super::invalid_string("date-time", &string).into());
+                }
+                (None, OffsetBehaviour::Wall)
+            }
+            Some(annotation) => {
+                let time_zone = TimeZoneSlot::from_identifier(&annotation, context)?;
+                let behaviour = match parsed.offset {
+                    Some(ParsedOffset::Z) => OffsetBehaviour::Exact,
+                    None => OffsetBehaviour::Wall,
+                    Some(ParsedOffset::Offset { nanoseconds, exact }) => {
+                        match_minutes = !exact;
+                        OffsetBehaviour::Option(nanoseconds)
+                    }
+                };
+                (Some(time_zone), behaviour)
+            }
+        };
+        (parsed.date, parsed.time, offset_behaviour, time_zone)
+    };
+
+    // 7. If timeZone is unset, then
+    let Some(time_zone) = time_zone else {
+        // a. Let plainDate be ? CreateTemporalDate(isoDate, calendar).
+        // b. Return the Record { [[PlainRelativeTo]]: plainDate, [[ZonedRelativeTo]]: undefined }.
+        return Ok(RelativeToOption::Plain(
+            PlainDate::new(date.check_limits()?).date,
+        ));
+    };
+
+    // 8-10. Let epochNanoseconds be ? InterpretISODateTimeOffset(isoDate, time, offsetBehaviour, offsetNs, timeZone, compatible, reject, matchBehaviour).
+    let epoch_ns = interpret_iso_date_time_offset(
+        date,
+        time,
+        offset_behaviour,
+        &time_zone,
+        super::options::Disambiguation::Compatible,
+        super::options::OffsetOption::Reject,
+        match_minutes,
+        context,
+    )?;
+
+    // 11. Let zonedRelativeTo be ! CreateTemporalZonedDateTime(epochNanoseconds, timeZone, calendar).
+    // 12. Return the Record { [[PlainRelativeTo]]: undefined, [[ZonedRelativeTo]]: zonedRelativeTo }.
+    Ok(RelativeToOption::Zoned(ZonedDateTime::new(
+        epoch_ns, time_zone,
+    )))
+}
+
+/// Abstract operation [`ToTemporalPartialDurationRecord ( temporalDurationLike )`][spec]
+///
+/// Returns the fields of the duration in the order of [`Duration::fields`].
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-totemporalpartialdurationrecord
+fn to_partial_duration(value: &JsValue, context: &mut Context<'_>) -> JsResult<[Option<f64>; 10]> {
+    /// The indices of the duration fields, sorted by their property names.
+    const ALPHABETICAL: [usize; 10] = [3, 4, 8, 7, 5, 1, 9, 6, 2, 0];
+
+    // 1. If temporalDurationLike is not an Object, then
+    let Some(object) = value.as_object() else {
+        // a. Throw a TypeError exception.
+        return Err(JsNativeError::typ()
+            .with_message("expected an object with duration properties")
+            .into());
+    };
+
+    // 2-22. Read the fields in alphabetical order, converting them with ToIntegerIfIntegral.
+    let mut fields = [None; 10];
+    for index in ALPHABETICAL {
+        let value = object.get(js_string!(UNITS[index].plural()), context)?;
+        if !value.is_undefined() {
+            fields[index] = Some(to_integer_if_integral(&value, context)?);
+        }
+    }
+
+    // 23. If years, months, weeks, days, hours, minutes, seconds, milliseconds, microseconds, and nanoseconds are all undefined, throw a TypeError exception.
+    if fields.iter().all(Option::is_none) {
+        return Err(JsNativeError::typ()
+            .with_message("the object must have at least one duration property")
+            .into());
+    }
+
+    // 24. Return result.
+    Ok(fields)
+}
+
+/// Abstract operation [`ToTemporalDuration ( item )`][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-totemporalduration
+pub(crate) fn to_temporal_duration(
+    item: &JsValue,
+    context: &mut Context<'_>,
+) -> JsResult<Duration> {
+    // 1. If item is an Object and item has an [[InitializedTemporalDuration]] internal slot, then
+    if let Some(object) = item.as_object() {
+        if let Some(duration) = object.borrow().as_duration() {
+            // a. Return ! CreateTemporalDuration(item.[[Years]], ..., item.[[Nanoseconds]]).
+            return Ok(*duration);
+        }
+    }
+
+    // 2. If item is not an Object, then
+    if !item.is_object() {
+        // a. If item is not a String, throw a TypeError exception.
+        let string = require_string(item, "a duration")?;
+
+        // b. Return ? ParseTemporalDurationString(item).
+        let fields =
+            parse_duration(&string).ok_or_else(|| super::invalid_string("duration", &string))?;
+        return Duration::new(fields);
+    }
+
+    // 3-14. Let partial be ? ToTemporalPartialDurationRecord(item), and set the missing fields to 0.
+    let fields = to_partial_duration(item, context)?.map(Option::unwrap_or_default);
+
+    // 15. Return ? CreateTemporalDuration(result.[[Years]], ..., result.[[Nanoseconds]]).
+    Duration::new(fields)
+}
+
+/// Converts a time duration to a `Duration` with the given largest unit, as returned by the
+/// `until` and `since` methods.
+pub(crate) fn duration_from_difference(
+    internal: InternalDuration,
+    largest_unit: TemporalUnit,
+    negate: bool,
+) -> JsResult<Duration> {
+    let duration = Duration::from_internal(internal, largest_unit)?;
+    Ok(if negate { duration.negated() } else { duration })
+}
+
+/// Converts a duration to the internal representation used to add it to an exact time, checking
+/// that it doesn't contain calendar units.
+pub(crate) fn to_time_duration_only(duration: &Duration, what: &str) -> JsResult<i128> {
+    if duration.fields[..4].iter().any(|&field| field != 0.0) {
+        return Err(JsNativeError::range()
+            .with_message(format!("cannot add a duration with date units to {what}"))
+            .into());
+    }
+    Ok(duration.to_internal().time)
+}
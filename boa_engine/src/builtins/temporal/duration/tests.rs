@@ -0,0 +1,126 @@
+use crate::{js_string, run_test_actions, JsNativeErrorKind, TestAction};
+use indoc::indoc;
+
+#[test]
+fn constructor() {
+    run_test_actions([
+        TestAction::run("let d = new Temporal.Duration(1, 2, 3, 4, 5, 6, 7, 8, 9, 10)"),
+        TestAction::assert_eq("d.years", 1),
+        TestAction::assert_eq("d.nanoseconds", 10),
+        TestAction::assert_eq("d.sign", 1),
+        TestAction::assert_eq("new Temporal.Duration().sign", 0),
+        TestAction::assert("new Temporal.Duration().blank"),
+        TestAction::assert_native_error(
+            "new Temporal.Duration(1, -1)",
+            JsNativeErrorKind::Range,
+            "invalid duration",
+        ),
+        TestAction::assert_native_error(
+            "Temporal.Duration()",
+            JsNativeErrorKind::Type,
+            "cannot call `Temporal.Duration` constructor without `new`",
+        ),
+    ]);
+}
+
+#[test]
+fn from_string() {
+    run_test_actions([
+        TestAction::assert_eq(
+            "Temporal.Duration.from('P1Y2M3W4DT5H6M7.008009010S').toString()",
+            js_string!("P1Y2M3W4DT5H6M7.00800901S"),
+        ),
+        TestAction::assert_eq(
+            "Temporal.Duration.from('-PT1.5H').toString()",
+            js_string!("-PT1H30M"),
+        ),
+        TestAction::assert_eq(
+            "Temporal.Duration.from('PT0S').toString()",
+            js_string!("PT0S"),
+        ),
+        TestAction::assert_native_error(
+            "Temporal.Duration.from('P1H')",
+            JsNativeErrorKind::Range,
+            "`P1H` is not a valid duration string",
+        ),
+    ]);
+}
+
+#[test]
+fn negated_and_abs() {
+    run_test_actions([
+        TestAction::run("let d = Temporal.Duration.from({ hours: -2, minutes: -30 })"),
+        TestAction::assert_eq("d.negated().toString()", js_string!("PT2H30M")),
+        TestAction::assert_eq("d.abs().toString()", js_string!("PT2H30M")),
+        TestAction::assert_eq("d.with({ hours: -1 }).toString()", js_string!("-PT1H30M")),
+    ]);
+}
+
+#[test]
+fn add_and_subtract() {
+    run_test_actions([
+        TestAction::assert_eq(
+            "Temporal.Duration.from('PT1H50M').add('PT20M').toString()",
+            js_string!("PT2H10M"),
+        ),
+        TestAction::assert_eq(
+            "Temporal.Duration.from('PT1H').subtract('PT90M').toString()",
+            js_string!("-PT30M"),
+        ),
+        TestAction::assert_native_error(
+            "Temporal.Duration.from('P1M').add('P1D')",
+            JsNativeErrorKind::Range,
+            "cannot add durations with years, months or weeks",
+        ),
+    ]);
+}
+
+#[test]
+fn round() {
+    run_test_actions([
+        TestAction::assert_eq(
+            "Temporal.Duration.from('PT130M').round({ largestUnit: 'hour' }).toString()",
+            js_string!("PT2H10M"),
+        ),
+        TestAction::assert_eq(
+            "Temporal.Duration.from('PT1H29M').round('hour').toString()",
+            js_string!("PT1H"),
+        ),
+        TestAction::assert_eq(
+            indoc! {r"
+                Temporal.Duration.from('P1M16D')
+                    .round({ smallestUnit: 'month', relativeTo: '2023-02-01' })
+                    .toString()
+            "},
+            js_string!("P2M"),
+        ),
+        TestAction::assert_native_error(
+            "Temporal.Duration.from('P1M').round('day')",
+            JsNativeErrorKind::Range,
+            "a starting date is required to round calendar units",
+        ),
+    ]);
+}
+
+#[test]
+fn total() {
+    run_test_actions([
+        TestAction::assert_eq("Temporal.Duration.from('PT90M').total('hour')", 1.5),
+        TestAction::assert_eq(
+            "Temporal.Duration.from('P1M').total({ unit: 'day', relativeTo: '2024-02-01' })",
+            29,
+        ),
+    ]);
+}
+
+#[test]
+fn compare() {
+    run_test_actions([
+        TestAction::assert_eq("Temporal.Duration.compare('PT1H', 'PT60M')", 0),
+        TestAction::assert_eq("Temporal.Duration.compare('PT1H', 'PT59M')", 1),
+        TestAction::assert_eq(
+            "Temporal.Duration.compare('P1M', 'P30D', { relativeTo: '2023-02-01' })",
+            -1,
+        ),
+    ]);
+}
@@ -0,0 +1,365 @@
+//! The property bags used to create and modify the Temporal date and time types.
+
+use crate::{
+    object::JsObject, string::utf16, value::PreferredType, Context, JsNativeError, JsResult,
+    JsValue,
+};
+
+use super::{
+    iso::{IsoDate, IsoTime},
+    options::Overflow,
+    parser::parse_offset,
+    time_zone::{to_time_zone_slot, TimeZoneSlot},
+    to_integer_with_truncation, to_positive_integer_with_truncation,
+};
+
+/// The groups of fields read from a property bag.
+#[derive(Debug, Clone, Copy, Default)]
+#[allow(clippy::struct_excessive_bools)]
+pub(crate) struct FieldNames {
+    /// The `year`, `month`, `monthCode` and `day` fields.
+    pub(crate) date: bool,
+    /// The `hour`, `minute`, `second`, `millisecond`, `microsecond` and `nanosecond` fields.
+    pub(crate) time: bool,
+    /// The `offset` field.
+    pub(crate) offset: bool,
+    /// The `timeZone` field.
+    pub(crate) time_zone: bool,
+}
+
+impl FieldNames {
+    /// The fields of a `PlainDate`.
+    pub(crate) const DATE: Self = Self {
+        date: true,
+        time: false,
+        offset: false,
+        time_zone: false,
+    };
+
+    /// The fields of a `PlainTime`.
+    pub(crate) const TIME: Self = Self {
+        date: false,
+        time: true,
+        offset: false,
+        time_zone: false,
+    };
+
+    /// The fields of a `PlainDateTime`.
+    pub(crate) const DATE_TIME: Self = Self {
+        date: true,
+        time: true,
+        offset: false,
+        time_zone: false,
+    };
+
+    /// The fields of a `ZonedDateTime`.
+    pub(crate) const ZONED: Self = Self {
+        date: true,
+        time: true,
+        offset: true,
+        time_zone: true,
+    };
+}
+
+/// Which fields must be present in a property bag.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum RequiredFields {
+    /// No field is required by itself.
+    None,
+    /// The `timeZone` field is required.
+    TimeZone,
+    /// At least one of the fields must be present.
+    Partial,
+}
+
+/// The fields of a property bag, after converting them to the expected types.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Fields {
+    pub(crate) year: Option<f64>,
+    pub(crate) month: Option<f64>,
+    /// The month number and leap flag of the month code.
+    pub(crate) month_code: Option<(u8, bool)>,
+    pub(crate) day: Option<f64>,
+    pub(crate) hour: Option<f64>,
+    pub(crate) minute: Option<f64>,
+    pub(crate) second: Option<f64>,
+    pub(crate) millisecond: Option<f64>,
+    pub(crate) microsecond: Option<f64>,
+    pub(crate) nanosecond: Option<f64>,
+    /// The UTC offset, in nanoseconds.
+    pub(crate) offset: Option<i64>,
+    pub(crate) time_zone: Option<TimeZoneSlot>,
+}
+
+impl Fields {
+    /// Abstract operation [`PrepareCalendarFields ( calendar, fields, calendarFieldNames, nonCalendarFieldNames, requiredFieldNames )`][spec]
+    ///
+    /// Reads the fields in `names` from `object`, in alphabetical order.
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-preparecalendarfields
+    pub(crate) fn prepare(
+        object: &JsObject,
+        names: FieldNames,
+        required: RequiredFields,
+        context: &mut Context<'_>,
+    ) -> JsResult<Self> {
+        let mut fields = Self::default();
+        let mut any = false;
+
+        // Reads a field, returning `None` if it is undefined.
+        let mut read = |key: &[u16], enabled: bool, context: &mut Context<'_>| {
+            if !enabled {
+                return Ok(None);
+            }
+            let value = object.get(key, context)?;
+            if value.is_undefined() {
+                return Ok(None);
+            }
+            any = true;
+            Ok::<_, crate::JsError>(Some(value))
+        };
+
+        if let Some(day) = read(utf16!("day"), names.date, context)? {
+            fields.day = Some(to_positive_integer_with_truncation(&day, context)?);
+        }
+        if let Some(hour) = read(utf16!("hour"), names.time, context)? {
+            fields.hour = Some(to_integer_with_truncation(&hour, context)?);
+        }
+        if let Some(microsecond) = read(utf16!("microsecond"), names.time, context)? {
+            fields.microsecond = Some(to_integer_with_truncation(&microsecond, context)?);
+        }
+        if let Some(millisecond) = read(utf16!("millisecond"), names.time, context)? {
+            fields.millisecond = Some(to_integer_with_truncation(&millisecond, context)?);
+        }
+        if let Some(minute) = read(utf16!("minute"), names.time, context)? {
+            fields.minute = Some(to_integer_with_truncation(&minute, context)?);
+        }
+        if let Some(month) = read(utf16!("month"), names.date, context)? {
+            fields.month = Some(to_positive_integer_with_truncation(&month, context)?);
+        }
+        if let Some(month_code) = read(utf16!("monthCode"), names.date, context)? {
+            let month_code = to_primitive_and_require_string(&month_code, "monthCode", context)?;
+            fields.month_code = Some(parse_month_code(&month_code)?);
+        }
+        if let Some(nanosecond) = read(utf16!("nanosecond"), names.time, context)? {
+            fields.nanosecond = Some(to_integer_with_truncation(&nanosecond, context)?);
+        }
+        if let Some(offset) = read(utf16!("offset"), names.offset, context)? {
+            let offset = to_primitive_and_require_string(&offset, "offset", context)?;
+            fields.offset = Some(parse_offset(&offset).ok_or_else(|| {
+                JsNativeError::range().with_message(format!("`{offset}` is not a valid offset"))
+            })?);
+        }
+        if let Some(second) = read(utf16!("second"), names.time, context)? {
+            fields.second = Some(to_integer_with_truncation(&second, context)?);
+        }
+        if let Some(time_zone) = read(utf16!("timeZone"), names.time_zone, context)? {
+            fields.time_zone = Some(to_time_zone_slot(&time_zone, context)?);
+        }
+        if let Some(year) = read(utf16!("year"), names.date, context)? {
+            fields.year = Some(to_integer_with_truncation(&year, context)?);
+        }
+
+        match required {
+            RequiredFields::TimeZone if fields.time_zone.is_none() => Err(JsNativeError::typ()
+                .with_message("the `timeZone` property is required")
+                .into()),
+            RequiredFields::Partial if !any => Err(JsNativeError::typ()
+                .with_message("the object must have at least one of the expected properties")
+                .into()),
+            _ => Ok(fields),
+        }
+    }
+
+    /// Creates the fields of a date.
+    pub(crate) fn from_date(date: IsoDate) -> Self {
+        Self {
+            year: Some(date.year.into()),
+            month: Some(date.month.into()),
+            month_code: Some((date.month, false)),
+            day: Some(date.day.into()),
+            ..Self::default()
+        }
+    }
+
+    /// Sets the time fields to the fields of `time`.
+    pub(crate) fn with_time(mut self, time: IsoTime) -> Self {
+        self.hour = Some(time.hour.into());
+        self.minute = Some(time.minute.into());
+        self.second = Some(time.second.into());
+        self.millisecond = Some(time.millisecond.into());
+        self.microsecond = Some(time.microsecond.into());
+        self.nanosecond = Some(time.nanosecond.into());
+        self
+    }
+
+    /// Abstract operation [`CalendarMergeFields ( calendar, fields, additionalFields )`][spec]
+    /// for the ISO 8601 calendar.
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-calendarmergefields
+    pub(crate) fn merge(self, additional: Self) -> Self {
+        let (month, month_code) = if additional.month.is_some() || additional.month_code.is_some() {
+            (additional.month, additional.month_code)
+        } else {
+            (self.month, self.month_code)
+        };
+        Self {
+            year: additional.year.or(self.year),
+            month,
+            month_code,
+            day: additional.day.or(self.day),
+            hour: additional.hour.or(self.hour),
+            minute: additional.minute.or(self.minute),
+            second: additional.second.or(self.second),
+            millisecond: additional.millisecond.or(self.millisecond),
+            microsecond: additional.microsecond.or(self.microsecond),
+            nanosecond: additional.nanosecond.or(self.nanosecond),
+            offset: additional.offset.or(self.offset),
+            time_zone: additional.time_zone.or(self.time_zone),
+        }
+    }
+
+    /// Abstract operation [`CalendarDateFromFields ( calendar, fields, overflow )`][spec] for
+    /// the ISO 8601 calendar.
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-calendardatefromfields
+    #[allow(clippy::float_cmp)]
+    pub(crate) fn to_date(&self, overflow: Overflow) -> JsResult<IsoDate> {
+        // Abstract operation `CalendarResolveFields ( calendar, fields, type )`
+        let (Some(year), Some(day)) = (self.year, self.day) else {
+            return Err(JsNativeError::typ()
+                .with_message("the `year` and `day` properties are required")
+                .into());
+        };
+
+        let month = match (self.month, self.month_code) {
+            (None, None) => {
+                return Err(JsNativeError::typ()
+                    .with_message("either the `month` or `monthCode` property is required")
+                    .into())
+            }
+            (_, Some((code, leap))) if leap || !(1..=12).contains(&code) => {
+                return Err(JsNativeError::range()
+                    .with_message("invalid month code for the ISO 8601 calendar")
+                    .into())
+            }
+            (Some(month), Some((code, _))) if month != f64::from(code) => {
+                return Err(JsNativeError::range()
+                    .with_message("`month` and `monthCode` don't match")
+                    .into())
+            }
+            (_, Some((code, _))) => f64::from(code),
+            (Some(month), None) => month,
+        };
+
+        IsoDate::regulate(year, month, day, overflow)
+    }
+
+    /// Abstract operation [`RegulateTime ( hour, minute, second, millisecond, microsecond, nanosecond, overflow )`][spec]
+    /// for the time fields, which default to zero.
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-regulatetime
+    pub(crate) fn to_time(&self, overflow: Overflow) -> JsResult<IsoTime> {
+        IsoTime::regulate(
+            [
+                self.hour,
+                self.minute,
+                self.second,
+                self.millisecond,
+                self.microsecond,
+                self.nanosecond,
+            ]
+            .map(Option::unwrap_or_default),
+            overflow,
+        )
+    }
+}
+
+/// Abstract operation [`ToPrimitiveAndRequireString ( argument )`][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-toprimitiveandrequirestring
+fn to_primitive_and_require_string(
+    value: &JsValue,
+    field: &str,
+    context: &mut Context<'_>,
+) -> JsResult<String> {
+    let primitive = value.to_primitive(context, PreferredType::String)?;
+    primitive
+        .as_string()
+        .map(crate::JsString::to_std_string_escaped)
+        .ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message(format!("the `{field}` property must be a string"))
+                .into()
+        })
+}
+
+/// Abstract operation [`ParseMonthCode ( argument )`][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-parsemonthcode
+fn parse_month_code(month_code: &str) -> JsResult<(u8, bool)> {
+    let (digits, leap) = match month_code.strip_suffix('L') {
+        Some(digits) => (digits, true),
+        None => (month_code, false),
+    };
+    digits
+        .strip_prefix('M')
+        .filter(|digits| digits.len() == 2 && digits.bytes().all(|c| c.is_ascii_digit()))
+        .and_then(|digits| digits.parse::<u8>().ok())
+        .filter(|&month| month != 0 || leap)
+        .map(|month| (month, leap))
+        .ok_or_else(|| {
+            JsNativeError::range()
+                .with_message(format!("`{month_code}` is not a valid month code"))
+                .into()
+        })
+}
+
+/// Abstract operation [`IsPartialTemporalObject ( value )`][spec]
+///
+/// Returns the object of a valid partial Temporal object, throwing a `TypeError` otherwise.
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-ispartialtemporalobject
+pub(crate) fn to_partial_object(value: &JsValue, context: &mut Context<'_>) -> JsResult<JsObject> {
+    // 1. If value is not an Object, return false.
+    let Some(object) = value.as_object() else {
+        return Err(JsNativeError::typ()
+            .with_message("expected an object with the properties to change")
+            .into());
+    };
+
+    // 2. If value has an [[InitializedTemporalDate]], [[InitializedTemporalDateTime]],
+    //    [[InitializedTemporalMonthDay]], [[InitializedTemporalTime]],
+    //    [[InitializedTemporalYearMonth]], or [[InitializedTemporalZonedDateTime]] internal slot,
+    //    return false.
+    {
+        let object = object.borrow();
+        if object.as_plain_date().is_some()
+            || object.as_plain_date_time().is_some()
+            || object.as_plain_time().is_some()
+            || object.as_zoned_date_time().is_some()
+        {
+            return Err(JsNativeError::typ()
+                .with_message("expected a property bag instead of a Temporal object")
+                .into());
+        }
+    }
+
+    // 3. Let calendarProperty be ? Get(value, "calendar").
+    // 4. If calendarProperty is not undefined, return false.
+    // 5. Let timeZoneProperty be ? Get(value, "timeZone").
+    // 6. If timeZoneProperty is not undefined, return false.
+    for key in [utf16!("calendar"), utf16!("timeZone")] {
+        if !object.get(key, context)?.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message(format!(
+                    "the `{}` property cannot be changed",
+                    String::from_utf16_lossy(key)
+                ))
+                .into());
+        }
+    }
+
+    // 7. Return true.
+    Ok(object.clone())
+}
@@ -0,0 +1,555 @@
+//! Boa's implementation of the `Temporal.Instant` builtin object.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/proposal-temporal/#sec-temporal-instant-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/Instant
+
+use boa_gc::{empty_trace, Finalize, Trace};
+use boa_profiler::Profiler;
+
+use crate::{
+    builtins::{
+        options::{get_option, get_options_object},
+        BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject,
+    },
+    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    js_string,
+    object::{JsObject, ObjectData},
+    property::Attribute,
+    realm::Realm,
+    string::utf16,
+    symbol::JsSymbol,
+    value::PreferredType,
+    Context, JsArgs, JsBigInt, JsNativeError, JsResult, JsValue,
+};
+
+use super::{
+    constructor_without_new, create_temporal_object,
+    duration::{
+        duration_from_difference, options_or_unit, ordering_to_value, to_temporal_duration,
+        to_time_duration_only, Duration,
+    },
+    format_offset_minutes, invalid_string,
+    iso::IsoDateTime,
+    options::{
+        get_difference_settings, get_required_temporal_unit, get_rounding_increment,
+        get_string_precision_options, validate_rounding_increment, Precision, RoundingMode,
+        TemporalUnit, UnitGroup,
+    },
+    parser::{parse_date_time, ParsedOffset},
+    plain_date_time::format_date_time,
+    require_string,
+    rounding::{round_time_duration, InternalDuration},
+    time_zone::{
+        bigint_to_epoch_nanoseconds, check_epoch_nanoseconds, to_time_zone_slot, TimeZoneSlot,
+    },
+    value_of, with_getters,
+    zoned_date_time::ZonedDateTime,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The `Temporal.Instant` builtin object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Instant {
+    /// The number of nanoseconds since the epoch.
+    pub(crate) epoch_ns: i128,
+}
+
+impl Finalize for Instant {}
+
+// SAFETY: `Instant` doesn't contain any traceable data.
+unsafe impl Trace for Instant {
+    empty_trace!();
+}
+
+impl IntrinsicObject for Instant {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        let builder = BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .static_method(Self::from, "from", 1)
+            .static_method(Self::from_epoch_milliseconds, "fromEpochMilliseconds", 1)
+            .static_method(Self::from_epoch_nanoseconds, "fromEpochNanoseconds", 1)
+            .static_method(Self::compare, "compare", 2)
+            .property(
+                JsSymbol::to_string_tag(),
+                "Temporal.Instant",
+                Attribute::CONFIGURABLE,
+            )
+            .method(Self::add, "add", 1)
+            .method(Self::subtract, "subtract", 1)
+            .method(Self::until, "until", 1)
+            .method(Self::since, "since", 1)
+            .method(Self::round, "round", 1)
+            .method(Self::equals, "equals", 1)
+            .method(Self::to_string, "toString", 0)
+            .method(Self::to_json, "toJSON", 0)
+            .method(Self::to_json, "toLocaleString", 0)
+            .method(value_of, "valueOf", 0)
+            .method(Self::to_zoned_date_time_iso, "toZonedDateTimeISO", 1);
+
+        with_getters(
+            builder,
+            realm,
+            &[
+                ("epochMilliseconds", Self::get_epoch_milliseconds),
+                ("epochNanoseconds", Self::get_epoch_nanoseconds),
+            ],
+        )
+        .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for Instant {
+    const NAME: &'static str = "Instant";
+}
+
+impl BuiltInConstructor for Instant {
+    const LENGTH: usize = 1;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::instant;
+
+    /// Constructor [`Temporal.Instant ( epochNanoseconds )`][spec].
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.instant
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, then
+        if new_target.is_undefined() {
+            // a. Throw a TypeError exception.
+            return Err(constructor_without_new(Self::NAME).into());
+        }
+
+        // 2. Let epochNanoseconds be ? ToBigInt(epochNanoseconds).
+        let epoch_ns = args.get_or_undefined(0).to_bigint(context)?;
+
+        // 3. If IsValidEpochNanoseconds(epochNanoseconds) is false, throw a RangeError exception.
+        let epoch_ns = bigint_to_epoch_nanoseconds(&epoch_ns)?;
+
+        // 4. Return ? CreateTemporalInstant(epochNanoseconds, NewTarget).
+        Ok(Self::new(epoch_ns)
+            .create(Some(new_target), context)?
+            .into())
+    }
+}
+
+impl Instant {
+    /// Creates a new instant from a valid number of nanoseconds since the epoch.
+    pub(crate) const fn new(epoch_ns: i128) -> Self {
+        Self { epoch_ns }
+    }
+
+    /// Creates a new `Temporal.Instant` object with this data.
+    pub(crate) fn create(
+        self,
+        new_target: Option<&JsValue>,
+        context: &mut Context<'_>,
+    ) -> JsResult<JsObject> {
+        create_temporal_object(
+            ObjectData::instant(self),
+            new_target,
+            StandardConstructors::instant,
+            context,
+        )
+    }
+
+    /// Gets the epoch nanoseconds of the `this` value.
+    fn this_epoch_ns(this: &JsValue) -> JsResult<i128> {
+        this.as_object()
+            .and_then(|object| object.borrow().as_instant().map(|instant| instant.epoch_ns))
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("'this' is not a Temporal.Instant object")
+                    .into()
+            })
+    }
+
+    /// `Temporal.Instant.from ( item )`
+    fn from(_: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        let epoch_ns = to_temporal_instant(args.get_or_undefined(0), context)?;
+        Ok(Self::new(epoch_ns).create(None, context)?.into())
+    }
+
+    /// `Temporal.Instant.fromEpochMilliseconds ( epochMilliseconds )`
+    fn from_epoch_milliseconds(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. Set epochMilliseconds to ? ToNumber(epochMilliseconds).
+        let epoch_ms = args.get_or_undefined(0).to_number(context)?;
+
+        // 2. Set epochMilliseconds to ? NumberToBigInt(epochMilliseconds).
+        if !epoch_ms.is_finite() || epoch_ms.fract() != 0.0 {
+            return Err(JsNativeError::range()
+                .with_message("epoch milliseconds must be an integer")
+                .into());
+        }
+
+        // 3. Let epochNanoseconds be epochMilliseconds × ℤ(10**6).
+        // 4. If IsValidEpochNanoseconds(epochNanoseconds) is false, throw a RangeError exception.
+        let nanoseconds = check_epoch_nanoseconds(epoch_ms as i128 * 1_000_000)?;
+
+        // 5. Return ! CreateTemporalInstant(epochNanoseconds).
+        Ok(Self::new(nanoseconds).create(None, context)?.into())
+    }
+
+    /// `Temporal.Instant.fromEpochNanoseconds ( epochNanoseconds )`
+    fn from_epoch_nanoseconds(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. Set epochNanoseconds to ? ToBigInt(epochNanoseconds).
+        let epoch_ns = args.get_or_undefined(0).to_bigint(context)?;
+
+        // 2. If IsValidEpochNanoseconds(epochNanoseconds) is false, throw a RangeError exception.
+        let epoch_ns = bigint_to_epoch_nanoseconds(&epoch_ns)?;
+
+        // 3. Return ! CreateTemporalInstant(epochNanoseconds).
+        Ok(Self::new(epoch_ns).create(None, context)?.into())
+    }
+
+    /// `Temporal.Instant.compare ( one, two )`
+    fn compare(_: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        let one = to_temporal_instant(args.get_or_undefined(0), context)?;
+        let two = to_temporal_instant(args.get_or_undefined(1), context)?;
+        Ok(ordering_to_value(one.cmp(&two)))
+    }
+
+    /// `get Temporal.Instant.prototype.epochMilliseconds`
+    fn get_epoch_milliseconds(
+        this: &JsValue,
+        _: &[JsValue],
+        _: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        let epoch_ns = Self::this_epoch_ns(this)?;
+        Ok((epoch_ns.div_euclid(1_000_000) as f64).into())
+    }
+
+    /// `get Temporal.Instant.prototype.epochNanoseconds`
+    fn get_epoch_nanoseconds(
+        this: &JsValue,
+        _: &[JsValue],
+        _: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        let epoch_ns = Self::this_epoch_ns(this)?;
+        Ok(JsBigInt::from(epoch_ns).into())
+    }
+
+    /// `Temporal.Instant.prototype.add ( temporalDurationLike )`
+    fn add(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        let epoch_ns = Self::this_epoch_ns(this)?;
+        let duration = to_temporal_duration(args.get_or_undefined(0), context)?;
+        Self::add_duration(epoch_ns, &duration, context)
+    }
+
+    /// `Temporal.Instant.prototype.subtract ( temporalDurationLike )`
+    fn subtract(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        let epoch_ns = Self::this_epoch_ns(this)?;
+        let duration = to_temporal_duration(args.get_or_undefined(0), context)?;
+        Self::add_duration(epoch_ns, &duration.negated(), context)
+    }
+
+    /// Abstract operation [`AddDurationToInstant ( operation, instant, temporalDurationLike )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-adddurationtoinstant
+    fn add_duration(
+        epoch_ns: i128,
+        duration: &Duration,
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 3. Let largestUnit be DefaultTemporalLargestUnit(duration).
+        // 4. If TemporalUnitCategory(largestUnit) is date, throw a RangeError exception.
+        // 5. Let internalDuration be ToInternalDurationRecordWith24HourDays(duration).
+        let time = to_time_duration_only(duration, "an instant")?;
+
+        // 6. Let ns be ? AddInstant(instant.[[EpochNanoseconds]], internalDuration.[[Time]]).
+        let epoch_ns = check_epoch_nanoseconds(epoch_ns + time)?;
+
+        // 7. Return ! CreateTemporalInstant(ns).
+        Ok(Self::new(epoch_ns).create(None, context)?.into())
+    }
+
+    /// `Temporal.Instant.prototype.until ( other [ , options ] )`
+    fn until(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        Self::difference(false, this, args, context)
+    }
+
+    /// `Temporal.Instant.prototype.since ( other [ , options ] )`
+    fn since(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        Self::difference(true, this, args, context)
+    }
+
+    /// Abstract operation [`DifferenceTemporalInstant ( operation, instant, other, options )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-differencetemporalinstant
+    fn difference(
+        since: bool,
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        let epoch_ns = Self::this_epoch_ns(this)?;
+
+        // 1. Set other to ? ToTemporalInstant(other).
+        let other = to_temporal_instant(args.get_or_undefined(0), context)?;
+
+        // 2. Let resolvedOptions be ? GetOptionsObject(options).
+        // 3. Let settings be ? GetDifferenceSettings(operation, resolvedOptions, time, « », nanosecond, second).
+        let settings = get_difference_settings(
+            since,
+            args.get_or_undefined(1),
+            UnitGroup::Time,
+            TemporalUnit::Nanosecond,
+            TemporalUnit::Second,
+            context,
+        )?;
+
+        // 4. Let internalDuration be DifferenceInstant(instant.[[EpochNanoseconds]], other.[[EpochNanoseconds]], settings.[[RoundingIncrement]], settings.[[SmallestUnit]], settings.[[RoundingMode]]).
+        let time = round_time_duration(
+            other - epoch_ns,
+            settings.rounding_increment,
+            settings.smallest_unit,
+            settings.rounding_mode,
+        )?;
+
+        // 5. Let result be ! TemporalDurationFromInternal(internalDuration, settings.[[LargestUnit]]).
+        // 6. If operation is since, set result to CreateNegatedTemporalDuration(result).
+        // 7. Return result.
+        Ok(duration_from_difference(
+            InternalDuration::from_time(time),
+            settings.largest_unit,
+            since,
+        )?
+        .create(None, context)?
+        .into())
+    }
+
+    /// `Temporal.Instant.prototype.round ( roundTo )`
+    fn round(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Let instant be the this value.
+        // 2. Perform ? RequireInternalSlot(instant, [[InitializedTemporalInstant]]).
+        let epoch_ns = Self::this_epoch_ns(this)?;
+
+        // 3-5. Get the options object, or an object with a `smallestUnit` if roundTo is a string.
+        let round_to = options_or_unit(args.get_or_undefined(0), utf16!("smallestUnit"), context)?;
+
+        // 6. NOTE: The following steps read options and perform independent validation in alphabetical order.
+        // 7. Let roundingIncrement be ? GetRoundingIncrementOption(roundTo).
+        let rounding_increment = get_rounding_increment(&round_to, context)?;
+
+        // 8. Let roundingMode be ? GetRoundingModeOption(roundTo, half-expand).
+        let rounding_mode = get_option(&round_to, utf16!("roundingMode"), false, context)?
+            .unwrap_or(RoundingMode::HalfExpand);
+
+        // 9. Let smallestUnit be ? GetTemporalUnitValuedOption(roundTo, "smallestUnit", time, required).
+        let smallest_unit = get_required_temporal_unit(
+            &round_to,
+            utf16!("smallestUnit"),
+            UnitGroup::Time,
+            None,
+            context,
+        )?;
+
+        // 10-16. Let maximum be the number of smallestUnit in a day.
+        let maximum = (TemporalUnit::Day.nanoseconds().unwrap_or_default()
+            / smallest_unit.nanoseconds().unwrap_or(1)) as u64;
+
+        // 17. Perform ? ValidateTemporalRoundingIncrement(roundingIncrement, maximum, true).
+        validate_rounding_increment(rounding_increment, maximum, true)?;
+
+        // 18. Let roundedNs be RoundTemporalInstant(instant.[[EpochNanoseconds]], roundingIncrement, smallestUnit, roundingMode).
+        let epoch_ns = round_instant(epoch_ns, rounding_increment, smallest_unit, rounding_mode)?;
+
+        // 19. Return ! CreateTemporalInstant(roundedNs).
+        Ok(Self::new(epoch_ns).create(None, context)?.into())
+    }
+
+    /// `Temporal.Instant.prototype.equals ( other )`
+    fn equals(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        let epoch_ns = Self::this_epoch_ns(this)?;
+        let other = to_temporal_instant(args.get_or_undefined(0), context)?;
+        Ok((epoch_ns == other).into())
+    }
+
+    /// `Temporal.Instant.prototype.toString ( [ options ] )`
+    fn to_string(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Let instant be the this value.
+        // 2. Perform ? RequireInternalSlot(instant, [[InitializedTemporalInstant]]).
+        let epoch_ns = Self::this_epoch_ns(this)?;
+
+        // 3. Let resolvedOptions be ? GetOptionsObject(options).
+        let options = get_options_object(args.get_or_undefined(0))?;
+
+        // 4-9. Read the precision options.
+        let (precision, rounding_mode) = get_string_precision_options(&options, context)?;
+
+        // 10. Let timeZone be ? Get(resolvedOptions, "timeZone").
+        // 11. If timeZone is not undefined, then
+        //     a. Set timeZone to ? ToTemporalTimeZoneIdentifier(timeZone).
+        let time_zone = options.get(utf16!("timeZone"), context)?;
+        let time_zone = if time_zone.is_undefined() {
+            None
+        } else {
+            Some(to_time_zone_slot(&time_zone, context)?)
+        };
+
+        // 13. Let roundedNs be RoundTemporalInstant(instant.[[EpochNanoseconds]], precision.[[Increment]], precision.[[Unit]], roundingMode).
+        let epoch_ns = round_instant(epoch_ns, precision.increment, precision.unit, rounding_mode)?;
+
+        // 14. Return TemporalInstantToString(roundedInstant, timeZone, precision.[[Precision]]).
+        Ok(js_string!(instant_to_string(
+            epoch_ns,
+            time_zone.as_ref(),
+            precision.precision,
+            context
+        ))
+        .into())
+    }
+
+    /// `Temporal.Instant.prototype.toJSON ( )` and
+    /// `Temporal.Instant.prototype.toLocaleString ( [ locales [ , options ] ] )`
+    fn to_json(this: &JsValue, _: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        let epoch_ns = Self::this_epoch_ns(this)?;
+        Ok(js_string!(instant_to_string(epoch_ns, None, Precision::Auto, context)).into())
+    }
+
+    /// `Temporal.Instant.prototype.toZonedDateTimeISO ( timeZone )`
+    fn to_zoned_date_time_iso(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        let epoch_ns = Self::this_epoch_ns(this)?;
+        let time_zone = to_time_zone_slot(args.get_or_undefined(0), context)?;
+        Ok(ZonedDateTime::new(epoch_ns, time_zone)
+            .create(None, context)?
+            .into())
+    }
+}
+
+/// Abstract operation [`RoundTemporalInstant ( ns, increment, unit, roundingMode )`][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-roundtemporalinstant
+pub(crate) fn round_instant(
+    epoch_ns: i128,
+    increment: u64,
+    unit: TemporalUnit,
+    mode: RoundingMode,
+) -> JsResult<i128> {
+    let length = unit.nanoseconds().expect("unit must be a time unit");
+    check_epoch_nanoseconds(mode.round_as_if_positive(epoch_ns, length * i128::from(increment)))
+}
+
+/// Abstract operation [`TemporalInstantToString ( instant, timeZone, precision )`][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-temporalinstanttostring
+pub(crate) fn instant_to_string(
+    epoch_ns: i128,
+    time_zone: Option<&TimeZoneSlot>,
+    precision: Precision,
+    context: &Context<'_>,
+) -> String {
+    // 1. Let outputTimeZone be timeZone.
+    // 2. If outputTimeZone is undefined, set outputTimeZone to "UTC".
+    let utc = TimeZoneSlot::utc();
+    let output_time_zone = time_zone.unwrap_or(&utc);
+
+    // 3. Let epochNs be instant.[[EpochNanoseconds]].
+    // 4. Let isoDateTime be GetISODateTimeFor(outputTimeZone, epochNs).
+    let offset = output_time_zone.offset_nanoseconds_for(epoch_ns, context);
+    let date_time = IsoDateTime::from_epoch_nanoseconds(epoch_ns + i128::from(offset));
+
+    // 5. Let dateTimeString be ISODateTimeToString(isoDateTime, "iso8601", precision, never).
+    let date_time = format_date_time(date_time, precision);
+
+    // 6. If timeZone is undefined, then
+    //     a. Let timeZoneString be "Z".
+    // 7. Else,
+    //     a. Let offsetNanoseconds be GetOffsetNanosecondsFor(outputTimeZone, epochNs).
+    //     b. Let timeZoneString be FormatDateTimeUTCOffsetRounded(offsetNanoseconds).
+    // 8. Return the string-concatenation of dateTimeString and timeZoneString.
+    match time_zone {
+        None => format!("{date_time}Z"),
+        Some(_) => format!("{date_time}{}", format_offset_rounded(offset)),
+    }
+}
+
+/// Abstract operation [`FormatDateTimeUTCOffsetRounded ( offsetNanoseconds )`][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-formatdatetimeutcoffsetrounded
+pub(crate) fn format_offset_rounded(offset: i64) -> String {
+    // 1. Set offsetNanoseconds to RoundNumberToIncrement(offsetNanoseconds, 60 × 10**9, half-expand).
+    let minutes =
+        RoundingMode::HalfExpand.round(i128::from(offset), 60_000_000_000) / 60_000_000_000;
+
+    // 2. Let offsetMinutes be offsetNanoseconds / (60 × 10**9).
+    // 3. Return FormatOffsetTimeZoneIdentifier(offsetMinutes).
+    format_offset_minutes(minutes as i16)
+}
+
+/// Abstract operation [`ToTemporalInstant ( item )`][spec]
+///
+/// Returns the epoch nanoseconds of the instant.
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-totemporalinstant
+pub(crate) fn to_temporal_instant(item: &JsValue, context: &mut Context<'_>) -> JsResult<i128> {
+    // 1. If item is an Object, then
+    let item = if let Some(object) = item.as_object() {
+        {
+            let object = object.borrow();
+            // a. If item has an [[InitializedTemporalInstant]] or [[InitializedTemporalZonedDateTime]] internal slot, then
+            //     i. Return ! CreateTemporalInstant(item.[[EpochNanoseconds]]).
+            if let Some(instant) = object.as_instant() {
+                return Ok(instant.epoch_ns);
+            }
+            if let Some(zoned) = object.as_zoned_date_time() {
+                return Ok(zoned.epoch_ns);
+            }
+        }
+        // b. NOTE: This use of ToPrimitive allows Instant-like objects to be converted.
+        // c. Set item to ? ToPrimitive(item, string).
+        item.to_primitive(context, PreferredType::String)?
+    } else {
+        item.clone()
+    };
+
+    // 2. If item is not a String, throw a TypeError exception.
+    let string = require_string(&item, "an instant")?;
+
+    // 3. Let parsed be ? ParseTemporalInstantString(item).
+    let parsed = parse_date_time(&string).ok_or_else(|| invalid_string("instant", &string))?;
+
+    // 4. If parsed.[[Z]] is true, let offsetNanoseconds be 0.
+    // 5. Otherwise, let offsetNanoseconds be ! ParseDateTimeUTCOffset(parsed.[[OffsetString]]).
+    let offset = match parsed.offset {
+        Some(ParsedOffset::Z) => 0,
+        Some(ParsedOffset::Offset { nanoseconds, .. }) => nanoseconds,
+        None => return Err(invalid_string("instant", &string).into()),
+    };
+
+    // 6. If parsed.[[Time]] is start-of-day, let time be MidnightTimeRecord(). Else, let time be parsed.[[Time]].
+    // 7. Let balanced be BalanceISODateTime(parsed.[[Year]], parsed.[[Month]], parsed.[[Day]], time.[[Hour]], time.[[Minute]], time.[[Second]], time.[[Millisecond]], time.[[Microsecond]], time.[[Nanosecond]] - offsetNanoseconds).
+    // 8. Perform ? CheckISODaysRange(balanced.[[ISODate]]).
+    // 9. Let epochNanoseconds be GetUTCEpochNanoseconds(balanced).
+    let date_time = IsoDateTime::new(parsed.date, parsed.time.unwrap_or_default());
+    let epoch_ns = date_time.to_epoch_nanoseconds() - i128::from(offset);
+
+    // 10. If IsValidEpochNanoseconds(epochNanoseconds) is false, throw a RangeError exception.
+    // 11. Return ! CreateTemporalInstant(epochNanoseconds).
+    check_epoch_nanoseconds(epoch_ns)
+}
@@ -0,0 +1,116 @@
+use crate::{js_string, run_test_actions, JsNativeErrorKind, TestAction};
+
+#[test]
+fn constructor() {
+    run_test_actions([
+        TestAction::run("let i = new Temporal.Instant(1_000_000_000_000_000_123n)"),
+        TestAction::assert_eq("i.epochMilliseconds", 1_000_000_000_000_i64),
+        TestAction::assert_eq("i.toString()", js_string!("2001-09-09T01:46:40.000000123Z")),
+        TestAction::assert_native_error(
+            "new Temporal.Instant(8_640_000_000_000_000_000_001n)",
+            JsNativeErrorKind::Range,
+            "date-time is outside the supported range",
+        ),
+    ]);
+}
+
+#[test]
+fn from() {
+    run_test_actions([
+        TestAction::assert_eq(
+            "Temporal.Instant.from('2020-01-01T00:00+01:00').epochMilliseconds",
+            1_577_833_200_000_i64,
+        ),
+        TestAction::assert_eq(
+            "Temporal.Instant.fromEpochMilliseconds(-1).toString()",
+            js_string!("1969-12-31T23:59:59.999Z"),
+        ),
+        TestAction::assert_native_error(
+            "Temporal.Instant.from('2020-01-01T00:00')",
+            JsNativeErrorKind::Range,
+            "`2020-01-01T00:00` is not a valid instant string",
+        ),
+    ]);
+}
+
+#[test]
+fn arithmetic() {
+    run_test_actions([
+        TestAction::run("let i = Temporal.Instant.from('2020-01-01T00:00Z')"),
+        TestAction::assert_eq(
+            "i.add({ hours: 25 }).toString()",
+            js_string!("2020-01-02T01:00:00Z"),
+        ),
+        TestAction::assert_eq(
+            "i.subtract('PT1S').toString()",
+            js_string!("2019-12-31T23:59:59Z"),
+        ),
+        TestAction::assert_eq(
+            "i.until('2020-01-02T01:30Z').toString()",
+            js_string!("PT91800S"),
+        ),
+        TestAction::assert_eq(
+            "i.since('2019-12-31T00:00Z', { largestUnit: 'hour' }).toString()",
+            js_string!("PT24H"),
+        ),
+        TestAction::assert_native_error(
+            "i.add({ days: 1 })",
+            JsNativeErrorKind::Range,
+            "cannot add a duration with date units to an instant",
+        ),
+    ]);
+}
+
+#[test]
+fn round() {
+    run_test_actions([
+        TestAction::run("let i = Temporal.Instant.from('2020-01-01T10:29:30.5Z')"),
+        TestAction::assert_eq(
+            "i.round('hour').toString()",
+            js_string!("2020-01-01T10:00:00Z"),
+        ),
+        TestAction::assert_eq(
+            "i.round({ smallestUnit: 'minute', roundingIncrement: 15 }).toString()",
+            js_string!("2020-01-01T10:30:00Z"),
+        ),
+        TestAction::assert_eq(
+            "i.toString({ fractionalSecondDigits: 0 })",
+            js_string!("2020-01-01T10:29:30Z"),
+        ),
+        TestAction::assert_eq(
+            "i.toString({ timeZone: '+05:30', smallestUnit: 'minute' })",
+            js_string!("2020-01-01T15:59+05:30"),
+        ),
+    ]);
+}
+
+#[test]
+fn comparisons() {
+    run_test_actions([
+        TestAction::assert_eq(
+            "Temporal.Instant.compare('2020-01-01T00:00Z', '2020-01-01T01:00+01:00')",
+            0,
+        ),
+        TestAction::assert("Temporal.Instant.from('2020-01-01T00:00Z').equals('2020-01-01T00:00Z')"),
+        TestAction::assert_native_error(
+            "Temporal.Instant.from('2020-01-01T00:00Z') < 0",
+            JsNativeErrorKind::Type,
+            "cannot convert a Temporal object to a primitive value; use `compare` or `equals` instead",
+        ),
+    ]);
+}
+
+#[test]
+fn date_to_temporal_instant() {
+    run_test_actions([
+        TestAction::assert_eq(
+            "new Date(Date.UTC(2020, 0, 1, 12)).toTemporalInstant().toString()",
+            js_string!("2020-01-01T12:00:00Z"),
+        ),
+        TestAction::assert_native_error(
+            "new Date(NaN).toTemporalInstant()",
+            JsNativeErrorKind::Range,
+            "cannot convert an invalid Date to an instant",
+        ),
+    ]);
+}
@@ -0,0 +1,489 @@
+//! Date and time arithmetic of the proleptic Gregorian calendar, as used by the ISO 8601
+//! calendar of Temporal.
+//!
+//! All the records in this module are always valid: the constructors check that every field is
+//! in range, and the arithmetic operations balance their results.
+
+use std::cmp::Ordering;
+
+use crate::{JsNativeError, JsResult};
+
+use super::options::{Overflow, TemporalUnit};
+
+/// The number of nanoseconds in a 24-hour day.
+pub(crate) const NS_PER_DAY: i128 = 86_400_000_000_000;
+
+/// The maximum absolute number of nanoseconds since the epoch of an `Instant`.
+pub(crate) const NS_MAX_INSTANT: i128 = 8_640_000_000_000_000_000_000;
+
+/// The maximum absolute value of the days of an epoch-days date within the Temporal limits.
+///
+/// This is one day more than the limit of an `Instant`, to support any date-time whose UTC
+/// offset moves it into the valid range of instants.
+const MAX_EPOCH_DAYS: i64 = 100_000_001;
+
+/// Returns `true` if `year` is a leap year of the proleptic Gregorian calendar.
+pub(crate) const fn is_leap_year(year: i32) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+/// Returns the number of days of the month `month` (1-based) of `year`.
+pub(crate) const fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Returns the number of days of `year`.
+pub(crate) const fn days_in_year(year: i32) -> u16 {
+    if is_leap_year(year) {
+        366
+    } else {
+        365
+    }
+}
+
+/// Returns an error for a date or time outside the range supported by Temporal.
+pub(crate) fn out_of_range() -> JsNativeError {
+    JsNativeError::range().with_message("date-time is outside the supported range")
+}
+
+/// A calendar date of the ISO 8601 calendar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) struct IsoDate {
+    pub(crate) year: i32,
+    pub(crate) month: u8,
+    pub(crate) day: u8,
+}
+
+impl IsoDate {
+    /// Creates a new date, returning `None` if the month or day are out of range.
+    pub(crate) fn new(year: i32, month: u8, day: u8) -> Option<Self> {
+        ((1..=12).contains(&month) && (1..=days_in_month(year, month)).contains(&day))
+            .then_some(Self { year, month, day })
+    }
+
+    /// Abstract operation [`RegulateISODate ( year, month, day, overflow )`][spec]
+    ///
+    /// Creates a new date from the integer fields, constraining or rejecting the values that are
+    /// out of range depending on `overflow`.
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-regulateisodate
+    pub(crate) fn regulate(year: f64, month: f64, day: f64, overflow: Overflow) -> JsResult<Self> {
+        if !(-1_000_000.0..=1_000_000.0).contains(&year) {
+            return Err(out_of_range().into());
+        }
+        let (month, day) = match overflow {
+            Overflow::Constrain => {
+                if month < 1.0 || day < 1.0 {
+                    return Err(JsNativeError::range()
+                        .with_message("month and day must be positive")
+                        .into());
+                }
+                let month = month.min(12.0) as u8;
+                let day = day.min(f64::from(days_in_month(year as i32, month))) as u8;
+                (month, day)
+            }
+            Overflow::Reject => {
+                if !(1.0..=12.0).contains(&month) || !(1.0..=31.0).contains(&day) {
+                    return Err(JsNativeError::range()
+                        .with_message("month or day out of range")
+                        .into());
+                }
+                (month as u8, day as u8)
+            }
+        };
+
+        Self::new(year as i32, month, day)
+            .ok_or_else(|| {
+                JsNativeError::range()
+                    .with_message("day out of range for the month")
+                    .into()
+            })
+            .and_then(Self::check_limits)
+    }
+
+    /// Checks that the date is within the range of dates supported by Temporal.
+    pub(crate) fn check_limits(self) -> JsResult<Self> {
+        if self.to_epoch_days().abs() > MAX_EPOCH_DAYS {
+            return Err(out_of_range().into());
+        }
+        Ok(self)
+    }
+
+    /// Returns the number of days since the Unix epoch of this date.
+    pub(crate) fn to_epoch_days(self) -> i64 {
+        // Algorithm `days_from_civil` from <https://howardhinnant.github.io/date_algorithms.html>.
+        let year = i64::from(self.year) - i64::from(self.month <= 2);
+        let era = year.div_euclid(400);
+        let yoe = year.rem_euclid(400);
+        let month = i64::from(self.month);
+        let doy =
+            (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + i64::from(self.day) - 1;
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+        era * 146_097 + doe - 719_468
+    }
+
+    /// Creates a date from the number of days since the Unix epoch.
+    ///
+    /// `days` must be within the range of dates supported by Temporal.
+    pub(crate) fn from_epoch_days(days: i64) -> Self {
+        // Algorithm `civil_from_days` from <https://howardhinnant.github.io/date_algorithms.html>.
+        let days = days + 719_468;
+        let era = days.div_euclid(146_097);
+        let doe = days.rem_euclid(146_097);
+        let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = doy - (153 * mp + 2) / 5 + 1;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 };
+        let year = yoe + era * 400 + i64::from(month <= 2);
+        Self {
+            year: year as i32,
+            month: month as u8,
+            day: day as u8,
+        }
+    }
+
+    /// Adds a number of days to this date, checking that the result is within the limits.
+    pub(crate) fn add_days(self, days: i64) -> JsResult<Self> {
+        let days = self
+            .to_epoch_days()
+            .checked_add(days)
+            .filter(|days| days.abs() <= MAX_EPOCH_DAYS)
+            .ok_or_else(out_of_range)?;
+        Ok(Self::from_epoch_days(days))
+    }
+
+    /// Returns the ISO day of the week of this date, from 1 (Monday) to 7 (Sunday).
+    pub(crate) fn day_of_week(self) -> u8 {
+        // The Unix epoch was a Thursday.
+        ((self.to_epoch_days() + 3).rem_euclid(7) + 1) as u8
+    }
+
+    /// Returns the ordinal day of the year of this date, starting from 1.
+    pub(crate) fn day_of_year(self) -> u16 {
+        (self.to_epoch_days() - Self::start_of_year(self.year)) as u16 + 1
+    }
+
+    /// Returns the ISO week-numbering year and week of the year of this date.
+    pub(crate) fn week_of_year(self) -> (i32, u8) {
+        let weeks_in_year = |year: i32| {
+            let jan_1 = ((Self::start_of_year(year) + 3).rem_euclid(7) + 1) as u8;
+            if jan_1 == 4 || (jan_1 == 3 && is_leap_year(year)) {
+                53
+            } else {
+                52
+            }
+        };
+
+        let week = (i32::from(self.day_of_year()) - i32::from(self.day_of_week()) + 10) / 7;
+        if week < 1 {
+            (self.year - 1, weeks_in_year(self.year - 1))
+        } else if week > i32::from(weeks_in_year(self.year)) {
+            (self.year + 1, 1)
+        } else {
+            (self.year, week as u8)
+        }
+    }
+
+    /// Returns the epoch days of the first day of `year`.
+    fn start_of_year(year: i32) -> i64 {
+        Self {
+            year,
+            month: 1,
+            day: 1,
+        }
+        .to_epoch_days()
+    }
+
+    /// Abstract operation [`AddISODate ( year, month, day, years, months, weeks, days, overflow )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-addisodate
+    pub(crate) fn add(self, duration: DateDuration, overflow: Overflow) -> JsResult<Self> {
+        let months = (i64::from(self.year) + duration.years)
+            .checked_mul(12)
+            .and_then(|months| months.checked_add(i64::from(self.month) - 1 + duration.months))
+            .ok_or_else(out_of_range)?;
+        let year = months.div_euclid(12);
+        if year.abs() > 1_000_000 {
+            return Err(out_of_range().into());
+        }
+        let intermediate = Self::regulate(
+            year as f64,
+            (months.rem_euclid(12) + 1) as f64,
+            f64::from(self.day),
+            overflow,
+        )?;
+        let days = duration
+            .weeks
+            .checked_mul(7)
+            .and_then(|days| days.checked_add(duration.days))
+            .ok_or_else(out_of_range)?;
+        intermediate.add_days(days)
+    }
+
+    /// Abstract operation [`CalendarDateUntil ( calendar, one, two, largestUnit )`][spec] for the
+    /// ISO 8601 calendar.
+    ///
+    /// Returns the duration between `self` and `other`, using `largest_unit` as the largest date
+    /// unit of the result.
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-calendardateuntil
+    pub(crate) fn until(self, other: Self, largest_unit: TemporalUnit) -> DateDuration {
+        let sign = match other.cmp(&self) {
+            Ordering::Equal => return DateDuration::default(),
+            Ordering::Greater => 1,
+            Ordering::Less => -1,
+        };
+        // Returns `true` if the (possibly non-existent) date `year-month-day` goes past `other`.
+        let surpasses = |year: i64, month: i64, day: u8| {
+            let cmp =
+                (year, month, day).cmp(&(i64::from(other.year), i64::from(other.month), other.day));
+            match cmp {
+                Ordering::Greater => sign == 1,
+                Ordering::Less => sign == -1,
+                Ordering::Equal => false,
+            }
+        };
+
+        let mut years = 0;
+        if largest_unit == TemporalUnit::Year {
+            years = i64::from(other.year) - i64::from(self.year);
+            while years != 0 && surpasses(i64::from(self.year) + years, self.month.into(), self.day)
+            {
+                years -= sign;
+            }
+        }
+
+        let start = (i64::from(self.year) + years) * 12 + i64::from(self.month) - 1;
+        let mut months = 0;
+        if matches!(largest_unit, TemporalUnit::Year | TemporalUnit::Month) {
+            months = i64::from(other.year) * 12 + i64::from(other.month) - 1 - start;
+            while months != 0
+                && surpasses(
+                    (start + months).div_euclid(12),
+                    (start + months).rem_euclid(12) + 1,
+                    self.day,
+                )
+            {
+                months -= sign;
+            }
+        }
+
+        let year = (start + months).div_euclid(12) as i32;
+        let month = ((start + months).rem_euclid(12) + 1) as u8;
+        let constrained = Self {
+            year,
+            month,
+            day: self.day.min(days_in_month(year, month)),
+        };
+
+        let mut days = other.to_epoch_days() - constrained.to_epoch_days();
+        let mut weeks = 0;
+        if largest_unit == TemporalUnit::Week {
+            weeks = days / 7;
+            days %= 7;
+        }
+
+        DateDuration {
+            years,
+            months,
+            weeks,
+            days,
+        }
+    }
+}
+
+/// The date part of a duration.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct DateDuration {
+    pub(crate) years: i64,
+    pub(crate) months: i64,
+    pub(crate) weeks: i64,
+    pub(crate) days: i64,
+}
+
+impl DateDuration {
+    /// Returns the sign of the date duration.
+    pub(crate) fn sign(&self) -> i64 {
+        [self.years, self.months, self.weeks, self.days]
+            .into_iter()
+            .find(|&field| field != 0)
+            .map_or(0, i64::signum)
+    }
+}
+
+/// A wall-clock time.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) struct IsoTime {
+    pub(crate) hour: u8,
+    pub(crate) minute: u8,
+    pub(crate) second: u8,
+    pub(crate) millisecond: u16,
+    pub(crate) microsecond: u16,
+    pub(crate) nanosecond: u16,
+}
+
+impl IsoTime {
+    /// Abstract operation [`RegulateTime ( hour, minute, second, millisecond, microsecond, nanosecond, overflow )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-regulatetime
+    pub(crate) fn regulate(fields: [f64; 6], overflow: Overflow) -> JsResult<Self> {
+        const MAXIMUMS: [f64; 6] = [23.0, 59.0, 59.0, 999.0, 999.0, 999.0];
+
+        let mut values = [0.0; 6];
+        for ((value, field), max) in values.iter_mut().zip(fields).zip(MAXIMUMS) {
+            *value = match overflow {
+                Overflow::Constrain => field.clamp(0.0, max),
+                Overflow::Reject if (0.0..=max).contains(&field) => field,
+                Overflow::Reject => {
+                    return Err(JsNativeError::range()
+                        .with_message("time field out of range")
+                        .into())
+                }
+            };
+        }
+
+        Ok(Self {
+            hour: values[0] as u8,
+            minute: values[1] as u8,
+            second: values[2] as u8,
+            millisecond: values[3] as u16,
+            microsecond: values[4] as u16,
+            nanosecond: values[5] as u16,
+        })
+    }
+
+    /// Returns the number of nanoseconds since midnight of this time.
+    pub(crate) fn to_nanoseconds(self) -> i128 {
+        ((((i128::from(self.hour) * 60 + i128::from(self.minute)) * 60 + i128::from(self.second))
+            * 1000
+            + i128::from(self.millisecond))
+            * 1000
+            + i128::from(self.microsecond))
+            * 1000
+            + i128::from(self.nanosecond)
+    }
+
+    /// Abstract operation [`BalanceTime ( hour, minute, second, millisecond, microsecond, nanosecond )`][spec]
+    ///
+    /// Creates a time from a number of nanoseconds since midnight, returning the number of whole
+    /// days contained in `nanoseconds`.
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-balancetime
+    pub(crate) const fn balance(nanoseconds: i128) -> (i64, Self) {
+        let days = nanoseconds.div_euclid(NS_PER_DAY);
+        let ns = nanoseconds.rem_euclid(NS_PER_DAY);
+        let time = Self {
+            hour: (ns / 3_600_000_000_000) as u8,
+            minute: (ns / 60_000_000_000 % 60) as u8,
+            second: (ns / 1_000_000_000 % 60) as u8,
+            millisecond: (ns / 1_000_000 % 1000) as u16,
+            microsecond: (ns / 1000 % 1000) as u16,
+            nanosecond: (ns % 1000) as u16,
+        };
+        (days as i64, time)
+    }
+
+    /// Abstract operation [`RoundTime ( time, increment, unit, roundingMode )`][spec]
+    ///
+    /// Returns the rounded time, and the number of days the rounding carried over.
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-roundtime
+    pub(crate) fn round(
+        self,
+        increment: u64,
+        unit: TemporalUnit,
+        mode: super::options::RoundingMode,
+    ) -> (i64, Self) {
+        let length = unit.nanoseconds().expect("unit must be a time unit or day");
+        let rounded = mode.round(self.to_nanoseconds(), i128::from(increment) * length);
+        Self::balance(rounded)
+    }
+}
+
+/// A wall-clock date and time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) struct IsoDateTime {
+    pub(crate) date: IsoDate,
+    pub(crate) time: IsoTime,
+}
+
+impl IsoDateTime {
+    /// Creates a new date-time.
+    pub(crate) const fn new(date: IsoDate, time: IsoTime) -> Self {
+        Self { date, time }
+    }
+
+    /// Returns the number of nanoseconds since the epoch of this date-time, interpreted as UTC.
+    pub(crate) fn to_epoch_nanoseconds(self) -> i128 {
+        i128::from(self.date.to_epoch_days()) * NS_PER_DAY + self.time.to_nanoseconds()
+    }
+
+    /// Creates the UTC date-time of a number of nanoseconds since the epoch.
+    ///
+    /// `nanoseconds` must be within the limits of Temporal date-times.
+    pub(crate) fn from_epoch_nanoseconds(nanoseconds: i128) -> Self {
+        let (days, time) = IsoTime::balance(nanoseconds);
+        Self {
+            date: IsoDate::from_epoch_days(days),
+            time,
+        }
+    }
+
+    /// Abstract operation [`ISODateTimeWithinLimits ( isoDateTime )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-isodatetimewithinlimits
+    pub(crate) fn is_within_limits(self) -> bool {
+        self.to_epoch_nanoseconds().abs() < NS_MAX_INSTANT + NS_PER_DAY
+    }
+
+    /// Checks that the date-time is within the limits supported by Temporal.
+    pub(crate) fn check_limits(self) -> JsResult<Self> {
+        if self.is_within_limits() {
+            Ok(self)
+        } else {
+            Err(out_of_range().into())
+        }
+    }
+
+    /// Adds a number of nanoseconds to this date-time, balancing the result.
+    pub(crate) fn add_nanoseconds(self, nanoseconds: i128) -> JsResult<Self> {
+        let (days, time) = IsoTime::balance(self.time.to_nanoseconds() + nanoseconds);
+        Ok(Self {
+            date: self.date.add_days(days)?,
+            time,
+        })
+    }
+
+    /// Abstract operation [`DifferenceISODateTime ( isoDateTime1, isoDateTime2, calendar, largestUnit )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-differenceisodatetime
+    pub(crate) fn until(self, other: Self, largest_unit: TemporalUnit) -> (DateDuration, i128) {
+        let mut time = other.time.to_nanoseconds() - self.time.to_nanoseconds();
+        let date_sign: i128 = match other.date.cmp(&self.date) {
+            Ordering::Greater => 1,
+            Ordering::Less => -1,
+            Ordering::Equal => 0,
+        };
+
+        let mut adjusted = other.date;
+        if time.signum() == -date_sign {
+            adjusted = IsoDate::from_epoch_days(adjusted.to_epoch_days() - date_sign as i64);
+            time += date_sign * NS_PER_DAY;
+        }
+
+        let mut date = self
+            .date
+            .until(adjusted, largest_unit.max(TemporalUnit::Day));
+        if largest_unit < TemporalUnit::Day {
+            time += i128::from(date.days) * NS_PER_DAY;
+            date.days = 0;
+        }
+        (date, time)
+    }
+}
@@ -0,0 +1,335 @@
+//! Boa's implementation of the ECMAScript `Temporal` global object.
+//!
+//! `Temporal` is a namespace object containing the classes of the Temporal date and time API,
+//! which fixes the many shortcomings of `Date`.
+//!
+//! Only the ISO 8601 calendar is supported, and the available named time zones depend on the
+//! [`TimeZoneProvider`][provider] of the context.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/proposal-temporal/#sec-temporal-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal
+//! [provider]: crate::context::TimeZoneProvider
+
+use boa_profiler::Profiler;
+
+use crate::{
+    builtins::{BuiltInBuilder, BuiltInConstructorWithPrototype, BuiltInObject, IntrinsicObject},
+    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    native_function::NativeFunctionPointer,
+    object::{internal_methods::get_prototype_from_constructor, JsObject, ObjectData},
+    property::Attribute,
+    realm::Realm,
+    symbol::JsSymbol,
+    Context, JsNativeError, JsResult, JsValue,
+};
+
+pub(crate) mod calendar;
+pub(crate) mod duration;
+pub(crate) mod instant;
+pub(crate) mod now;
+pub(crate) mod plain_date;
+pub(crate) mod plain_date_time;
+pub(crate) mod plain_time;
+pub(crate) mod time_zone;
+pub(crate) mod zoned_date_time;
+
+mod fields;
+mod iso;
+mod options;
+mod parser;
+mod rounding;
+
+#[cfg(test)]
+mod tests;
+
+pub(crate) use self::{
+    calendar::Calendar, duration::Duration, instant::Instant, now::Now, plain_date::PlainDate,
+    plain_date_time::PlainDateTime, plain_time::PlainTime, time_zone::TimeZone,
+    zoned_date_time::ZonedDateTime,
+};
+
+use self::{iso::IsoTime, options::Precision};
+
+/// The `Temporal` namespace object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) struct Temporal;
+
+impl IntrinsicObject for Temporal {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        let constructors = realm.intrinsics().constructors();
+
+        BuiltInBuilder::with_intrinsic::<Self>(realm)
+            .static_property(
+                JsSymbol::to_string_tag(),
+                Self::NAME,
+                Attribute::CONFIGURABLE,
+            )
+            .static_property(
+                Now::NAME,
+                realm.intrinsics().objects().temporal_now(),
+                Now::ATTRIBUTE,
+            )
+            .static_property(
+                Calendar::NAME,
+                constructors.calendar().constructor(),
+                Calendar::ATTRIBUTE,
+            )
+            .static_property(
+                Duration::NAME,
+                constructors.duration().constructor(),
+                Duration::ATTRIBUTE,
+            )
+            .static_property(
+                Instant::NAME,
+                constructors.instant().constructor(),
+                Instant::ATTRIBUTE,
+            )
+            .static_property(
+                PlainDate::NAME,
+                constructors.plain_date().constructor(),
+                PlainDate::ATTRIBUTE,
+            )
+            .static_property(
+                PlainDateTime::NAME,
+                constructors.plain_date_time().constructor(),
+                PlainDateTime::ATTRIBUTE,
+            )
+            .static_property(
+                PlainTime::NAME,
+                constructors.plain_time().constructor(),
+                PlainTime::ATTRIBUTE,
+            )
+            .static_property(
+                TimeZone::NAME,
+                constructors.time_zone().constructor(),
+                TimeZone::ATTRIBUTE,
+            )
+            .static_property(
+                ZonedDateTime::NAME,
+                constructors.zoned_date_time().constructor(),
+                ZonedDateTime::ATTRIBUTE,
+            )
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        intrinsics.objects().temporal()
+    }
+}
+
+impl BuiltInObject for Temporal {
+    const NAME: &'static str = "Temporal";
+}
+
+/// Defines a getter for each of the `(name, getter)` pairs on the prototype of a Temporal class.
+fn with_getters<'ctx>(
+    mut builder: BuiltInConstructorWithPrototype<'ctx>,
+    realm: &Realm,
+    getters: &[(&'static str, NativeFunctionPointer)],
+) -> BuiltInConstructorWithPrototype<'ctx> {
+    for &(name, getter) in getters {
+        let getter = BuiltInBuilder::callable(realm, getter)
+            .name(format!("get {name}"))
+            .build();
+        builder = builder.accessor(name, Some(getter), None, Attribute::CONFIGURABLE);
+    }
+    builder
+}
+
+/// Creates a new object with the data of a Temporal class.
+///
+/// The prototype of the object is taken from `new_target` if it is provided, or from the
+/// standard constructor otherwise.
+fn create_temporal_object(
+    data: ObjectData,
+    new_target: Option<&JsValue>,
+    default: fn(&StandardConstructors) -> &StandardConstructor,
+    context: &mut Context<'_>,
+) -> JsResult<JsObject> {
+    let prototype = match new_target {
+        Some(new_target) => get_prototype_from_constructor(new_target, default, context)?,
+        None => default(context.intrinsics().constructors()).prototype(),
+    };
+    Ok(JsObject::from_proto_and_data_with_shared_shape(
+        context.root_shape(),
+        prototype,
+        data,
+    ))
+}
+
+/// Returns the error thrown when a Temporal constructor is called without `new`.
+fn constructor_without_new(name: &str) -> JsNativeError {
+    JsNativeError::typ().with_message(format!(
+        "cannot call `Temporal.{name}` constructor without `new`"
+    ))
+}
+
+/// `Temporal.*.prototype.valueOf ( )`
+///
+/// Temporal objects cannot be converted to primitives, since comparing them with relational
+/// operators would give wrong results.
+fn value_of(_: &JsValue, _: &[JsValue], _: &mut Context<'_>) -> JsResult<JsValue> {
+    Err(JsNativeError::typ()
+        .with_message("cannot convert a Temporal object to a primitive value; use `compare` or `equals` instead")
+        .into())
+}
+
+/// Abstract operation [`ToIntegerWithTruncation ( argument )`][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-tointegerwithtruncation
+pub(crate) fn to_integer_with_truncation(
+    value: &JsValue,
+    context: &mut Context<'_>,
+) -> JsResult<f64> {
+    // 1. Let number be ? ToNumber(argument).
+    let number = value.to_number(context)?;
+
+    // 2. If number is NaN, +∞𝔽 or -∞𝔽, throw a RangeError exception.
+    if !number.is_finite() {
+        return Err(JsNativeError::range()
+            .with_message("expected a finite number")
+            .into());
+    }
+
+    // 3. Return truncate(ℝ(number)).
+    Ok(number.trunc() + 0.0)
+}
+
+/// Abstract operation [`ToPositiveIntegerWithTruncation ( argument )`][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-topositiveintegerwithtruncation
+fn to_positive_integer_with_truncation(
+    value: &JsValue,
+    context: &mut Context<'_>,
+) -> JsResult<f64> {
+    // 1. Let integer be ? ToIntegerWithTruncation(argument).
+    let integer = to_integer_with_truncation(value, context)?;
+
+    // 2. If integer ≤ 0, throw a RangeError exception.
+    if integer <= 0.0 {
+        return Err(JsNativeError::range()
+            .with_message("expected a positive integer")
+            .into());
+    }
+
+    // 3. Return integer.
+    Ok(integer)
+}
+
+/// Abstract operation [`ToIntegerIfIntegral ( argument )`][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-tointegerifintegral
+fn to_integer_if_integral(value: &JsValue, context: &mut Context<'_>) -> JsResult<f64> {
+    // 1. Let number be ? ToNumber(argument).
+    let number = value.to_number(context)?;
+
+    // 2. If number is not an integral Number, throw a RangeError exception.
+    if !number.is_finite() || number.fract() != 0.0 {
+        return Err(JsNativeError::range()
+            .with_message("expected an integer")
+            .into());
+    }
+
+    // 3. Return ℝ(number).
+    Ok(number + 0.0)
+}
+
+/// Converts a value to a string, throwing a `TypeError` if it is not already a string.
+fn require_string(value: &JsValue, what: &str) -> JsResult<String> {
+    value
+        .as_string()
+        .map(crate::JsString::to_std_string_escaped)
+        .ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message(format!("{what} must be a string or an object"))
+                .into()
+        })
+}
+
+/// Returns the `RangeError` thrown when a string cannot be parsed as a Temporal value.
+fn invalid_string(what: &str, string: &str) -> JsNativeError {
+    JsNativeError::range().with_message(format!("`{string}` is not a valid {what} string"))
+}
+
+/// Abstract operation [`PadISOYear ( y )`][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-padisoyear
+fn pad_iso_year(year: i32) -> String {
+    if (0..=9999).contains(&year) {
+        format!("{year:04}")
+    } else {
+        let sign = if year < 0 { '-' } else { '+' };
+        format!("{sign}{:06}", year.unsigned_abs())
+    }
+}
+
+/// Abstract operation [`FormatFractionalSeconds ( subSecondNanoseconds, precision )`][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-formatfractionalseconds
+fn format_fractional_seconds(nanoseconds: u32, precision: Precision) -> String {
+    let digits = format!("{nanoseconds:09}");
+    let digits = match precision {
+        Precision::Auto => digits.trim_end_matches('0'),
+        Precision::Digits(n) => &digits[..usize::from(n)],
+        Precision::Minute => "",
+    };
+    if digits.is_empty() {
+        String::new()
+    } else {
+        format!(".{digits}")
+    }
+}
+
+/// Abstract operation [`FormatTimeString ( hour, minute, second, subSecondNanoseconds, precision [ , style ] )`][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-formattimestring
+fn format_time_string(time: IsoTime, precision: Precision) -> String {
+    if precision == Precision::Minute {
+        return format!("{:02}:{:02}", time.hour, time.minute);
+    }
+    let nanoseconds = u32::from(time.millisecond) * 1_000_000
+        + u32::from(time.microsecond) * 1000
+        + u32::from(time.nanosecond);
+    format!(
+        "{:02}:{:02}:{:02}{}",
+        time.hour,
+        time.minute,
+        time.second,
+        format_fractional_seconds(nanoseconds, precision)
+    )
+}
+
+/// Abstract operation [`FormatUTCOffsetNanoseconds ( offsetNanoseconds )`][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-formatutcoffsetnanoseconds
+fn format_offset_nanoseconds(offset: i64) -> String {
+    let sign = if offset < 0 { '-' } else { '+' };
+    let offset = offset.unsigned_abs();
+    let hours = offset / 3_600_000_000_000;
+    let minutes = offset / 60_000_000_000 % 60;
+    let seconds = offset / 1_000_000_000 % 60;
+    let subseconds = (offset % 1_000_000_000) as u32;
+    if seconds == 0 && subseconds == 0 {
+        format!("{sign}{hours:02}:{minutes:02}")
+    } else {
+        format!(
+            "{sign}{hours:02}:{minutes:02}:{seconds:02}{}",
+            format_fractional_seconds(subseconds, Precision::Auto)
+        )
+    }
+}
+
+/// Abstract operation [`FormatOffsetTimeZoneIdentifier ( offsetMinutes [ , style ] )`][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-formatoffsettimezoneidentifier
+fn format_offset_minutes(offset: i16) -> String {
+    let sign = if offset < 0 { '-' } else { '+' };
+    let offset = offset.unsigned_abs();
+    format!("{sign}{:02}:{:02}", offset / 60, offset % 60)
+}
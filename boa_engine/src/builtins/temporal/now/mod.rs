@@ -0,0 +1,194 @@
+//! Boa's implementation of the `Temporal.Now` object.
+//!
+//! The current time is taken from [`HostHooks::utc_now`], so embedders can virtualize it, and the
+//! current time zone from the [`TimeZoneProvider`] of the context, falling back to the UTC
+//! offset of the host given by [`HostHooks::local_from_utc`].
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/proposal-temporal/#sec-temporal-now-object
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/Now
+//! [`HostHooks::utc_now`]: crate::context::HostHooks::utc_now
+//! [`HostHooks::local_from_utc`]: crate::context::HostHooks::local_from_utc
+//! [`TimeZoneProvider`]: crate::context::TimeZoneProvider
+
+use boa_profiler::Profiler;
+
+use crate::{
+    builtins::{BuiltInBuilder, BuiltInObject, IntrinsicObject},
+    context::intrinsics::Intrinsics,
+    js_string,
+    object::JsObject,
+    property::Attribute,
+    realm::Realm,
+    symbol::JsSymbol,
+    Context, JsArgs, JsResult, JsValue,
+};
+
+use super::{
+    instant::Instant,
+    iso::IsoDateTime,
+    plain_date::PlainDate,
+    plain_date_time::PlainDateTime,
+    plain_time::PlainTime,
+    time_zone::{to_time_zone_slot, TimeZoneSlot},
+    zoned_date_time::ZonedDateTime,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The `Temporal.Now` object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) struct Now;
+
+impl IntrinsicObject for Now {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        BuiltInBuilder::with_intrinsic::<Self>(realm)
+            .static_property(
+                JsSymbol::to_string_tag(),
+                "Temporal.Now",
+                Attribute::CONFIGURABLE,
+            )
+            .static_method(Self::time_zone_id, "timeZoneId", 0)
+            .static_method(Self::instant, "instant", 0)
+            .static_method(Self::plain_date_time_iso, "plainDateTimeISO", 0)
+            .static_method(Self::zoned_date_time_iso, "zonedDateTimeISO", 0)
+            .static_method(Self::plain_date_iso, "plainDateISO", 0)
+            .static_method(Self::plain_time_iso, "plainTimeISO", 0)
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        intrinsics.objects().temporal_now()
+    }
+}
+
+impl BuiltInObject for Now {
+    const NAME: &'static str = "Now";
+}
+
+impl Now {
+    /// `Temporal.Now.timeZoneId ( )`
+    #[allow(clippy::unnecessary_wraps)]
+    fn time_zone_id(_: &JsValue, _: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        Ok(js_string!(system_time_zone(context).identifier()).into())
+    }
+
+    /// `Temporal.Now.instant ( )`
+    fn instant(_: &JsValue, _: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        let epoch_ns = system_epoch_nanoseconds(context);
+        Ok(Instant::new(epoch_ns).create(None, context)?.into())
+    }
+
+    /// `Temporal.Now.plainDateTimeISO ( [ temporalTimeZoneLike ] )`
+    fn plain_date_time_iso(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        let date_time = system_date_time(args.get_or_undefined(0), context)?;
+        Ok(PlainDateTime::new(date_time).create(None, context)?.into())
+    }
+
+    /// `Temporal.Now.zonedDateTimeISO ( [ temporalTimeZoneLike ] )`
+    fn zoned_date_time_iso(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. If temporalTimeZoneLike is undefined, then
+        //     a. Let timeZone be SystemTimeZoneIdentifier().
+        // 2. Else,
+        //     a. Let timeZone be ? ToTemporalTimeZoneIdentifier(temporalTimeZoneLike).
+        let time_zone = to_time_zone_or_system(args.get_or_undefined(0), context)?;
+
+        // 3. Let ns be SystemUTCEpochNanoseconds().
+        let epoch_ns = system_epoch_nanoseconds(context);
+
+        // 4. Return ! CreateTemporalZonedDateTime(ns, timeZone, "iso8601").
+        Ok(ZonedDateTime::new(epoch_ns, time_zone)
+            .create(None, context)?
+            .into())
+    }
+
+    /// `Temporal.Now.plainDateISO ( [ temporalTimeZoneLike ] )`
+    fn plain_date_iso(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        let date_time = system_date_time(args.get_or_undefined(0), context)?;
+        Ok(PlainDate::new(date_time.date).create(None, context)?.into())
+    }
+
+    /// `Temporal.Now.plainTimeISO ( [ temporalTimeZoneLike ] )`
+    fn plain_time_iso(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        let date_time = system_date_time(args.get_or_undefined(0), context)?;
+        Ok(PlainTime::new(date_time.time).create(None, context)?.into())
+    }
+}
+
+/// Abstract operation [`SystemTimeZoneIdentifier ( )`][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-systemtimezoneidentifier
+fn system_time_zone(context: &Context<'_>) -> TimeZoneSlot {
+    if let Some(time_zone) = context
+        .time_zone_provider()
+        .host_time_zone()
+        .and_then(|identifier| TimeZoneSlot::from_identifier(&identifier, context).ok())
+    {
+        return time_zone;
+    }
+
+    let hooks = context.host_hooks();
+    let offset = hooks
+        .local_from_utc(hooks.utc_now())
+        .offset()
+        .local_minus_utc()
+        / 60;
+    if offset == 0 {
+        TimeZoneSlot::utc()
+    } else {
+        TimeZoneSlot::Offset(offset as i16)
+    }
+}
+
+/// Abstract operation [`SystemUTCEpochNanoseconds ( )`][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-systemutcepochnanoseconds
+fn system_epoch_nanoseconds(context: &Context<'_>) -> i128 {
+    let now = context.host_hooks().utc_now();
+    i128::from(now.timestamp()) * 1_000_000_000 + i128::from(now.timestamp_subsec_nanos())
+}
+
+/// Returns the time zone `value`, or the time zone of the host if it is undefined.
+fn to_time_zone_or_system(value: &JsValue, context: &Context<'_>) -> JsResult<TimeZoneSlot> {
+    if value.is_undefined() {
+        Ok(system_time_zone(context))
+    } else {
+        to_time_zone_slot(value, context)
+    }
+}
+
+/// Abstract operation [`SystemDateTime ( temporalTimeZoneLike )`][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-systemdatetime
+fn system_date_time(value: &JsValue, context: &Context<'_>) -> JsResult<IsoDateTime> {
+    // 1-2. Let timeZone be the time zone of temporalTimeZoneLike, or SystemTimeZoneIdentifier().
+    let time_zone = to_time_zone_or_system(value, context)?;
+
+    // 3. Let epochNs be SystemUTCEpochNanoseconds().
+    let epoch_ns = system_epoch_nanoseconds(context);
+
+    // 4. Return GetISODateTimeFor(timeZone, epochNs).
+    Ok(time_zone.iso_date_time_for(epoch_ns, context))
+}
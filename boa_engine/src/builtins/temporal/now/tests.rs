@@ -0,0 +1,72 @@
+use chrono::{DateTime, FixedOffset, NaiveDateTime};
+
+use crate::{
+    context::{ContextBuilder, HostHooks},
+    js_string, run_test_actions, run_test_actions_with, TestAction,
+};
+
+/// Host hooks of a host whose clock is stopped at 2023-04-05T06:07:08.009Z, in the `+05:30` time
+/// zone.
+struct StoppedClockHooks;
+
+impl HostHooks for StoppedClockHooks {
+    fn utc_now(&self) -> NaiveDateTime {
+        NaiveDateTime::from_timestamp_opt(1_680_674_828, 9_000_000).unwrap()
+    }
+
+    fn local_from_utc(&self, utc: NaiveDateTime) -> DateTime<FixedOffset> {
+        DateTime::from_utc(utc, FixedOffset::east_opt(5 * 3600 + 30 * 60).unwrap())
+    }
+}
+
+#[test]
+fn namespace() {
+    run_test_actions([
+        TestAction::assert_eq(
+            "Object.prototype.toString.call(Temporal.Now)",
+            js_string!("[object Temporal.Now]"),
+        ),
+        TestAction::assert_eq(
+            "typeof Temporal.Now.instant().epochNanoseconds",
+            js_string!("bigint"),
+        ),
+        TestAction::assert("Temporal.Now.plainDateISO('UTC') instanceof Temporal.PlainDate"),
+    ]);
+}
+
+#[test]
+fn host_clock() {
+    let hooks: &dyn HostHooks = &StoppedClockHooks;
+    let context = &mut ContextBuilder::default().host_hooks(hooks).build().unwrap();
+
+    run_test_actions_with(
+        [
+            TestAction::assert_eq("Temporal.Now.timeZoneId()", js_string!("+05:30")),
+            TestAction::assert_eq(
+                "Temporal.Now.instant().toString()",
+                js_string!("2023-04-05T06:07:08.009Z"),
+            ),
+            TestAction::assert_eq(
+                "Temporal.Now.plainDateTimeISO().toString()",
+                js_string!("2023-04-05T11:37:08.009"),
+            ),
+            TestAction::assert_eq(
+                "Temporal.Now.plainDateTimeISO('-01:00').toString()",
+                js_string!("2023-04-05T05:07:08.009"),
+            ),
+            TestAction::assert_eq(
+                "Temporal.Now.zonedDateTimeISO('UTC').toString()",
+                js_string!("2023-04-05T06:07:08.009+00:00[UTC]"),
+            ),
+            TestAction::assert_eq(
+                "Temporal.Now.plainDateISO('Etc/GMT-14').toString()",
+                js_string!("2023-04-05"),
+            ),
+            TestAction::assert_eq(
+                "Temporal.Now.plainTimeISO().toString()",
+                js_string!("11:37:08.009"),
+            ),
+        ],
+        context,
+    );
+}
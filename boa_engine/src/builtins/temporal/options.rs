@@ -0,0 +1,906 @@
+//! Parsing and validation of the options of the `Temporal` builtins.
+
+use std::{cmp::Ordering, fmt::Display, str::FromStr};
+
+use crate::{
+    builtins::options::{get_option, OptionType, OptionTypeParsable},
+    object::JsObject,
+    string::utf16,
+    Context, JsNativeError, JsResult, JsValue,
+};
+
+/// The units of time of Temporal, ordered from smallest to largest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) enum TemporalUnit {
+    Nanosecond,
+    Microsecond,
+    Millisecond,
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+impl TemporalUnit {
+    /// Returns the length in nanoseconds of this unit, or `None` if it is a calendar unit,
+    /// which has no fixed length.
+    pub(crate) const fn nanoseconds(self) -> Option<i128> {
+        Some(match self {
+            Self::Nanosecond => 1,
+            Self::Microsecond => 1000,
+            Self::Millisecond => 1_000_000,
+            Self::Second => 1_000_000_000,
+            Self::Minute => 60_000_000_000,
+            Self::Hour => 3_600_000_000_000,
+            Self::Day => 86_400_000_000_000,
+            Self::Week | Self::Month | Self::Year => return None,
+        })
+    }
+
+    /// Abstract operation [`IsCalendarUnit ( unit )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-iscalendarunit
+    pub(crate) fn is_calendar_unit(self) -> bool {
+        self > Self::Day
+    }
+
+    /// Returns `true` if this unit is a date unit, including days.
+    pub(crate) fn is_date_unit(self) -> bool {
+        self >= Self::Day
+    }
+
+    /// Abstract operation [`MaximumTemporalDurationRoundingIncrement ( unit )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-maximumtemporaldurationroundingincrement
+    pub(crate) const fn maximum_rounding_increment(self) -> Option<u64> {
+        match self {
+            Self::Year | Self::Month | Self::Week | Self::Day => None,
+            Self::Hour => Some(24),
+            Self::Minute | Self::Second => Some(60),
+            Self::Millisecond | Self::Microsecond | Self::Nanosecond => Some(1000),
+        }
+    }
+
+    /// Returns the plural name of this unit, as used by the fields of a `Duration`.
+    pub(crate) const fn plural(self) -> &'static str {
+        match self {
+            Self::Nanosecond => "nanoseconds",
+            Self::Microsecond => "microseconds",
+            Self::Millisecond => "milliseconds",
+            Self::Second => "seconds",
+            Self::Minute => "minutes",
+            Self::Hour => "hours",
+            Self::Day => "days",
+            Self::Week => "weeks",
+            Self::Month => "months",
+            Self::Year => "years",
+        }
+    }
+}
+
+impl Display for TemporalUnit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Nanosecond => "nanosecond",
+            Self::Microsecond => "microsecond",
+            Self::Millisecond => "millisecond",
+            Self::Second => "second",
+            Self::Minute => "minute",
+            Self::Hour => "hour",
+            Self::Day => "day",
+            Self::Week => "week",
+            Self::Month => "month",
+            Self::Year => "year",
+        }
+        .fmt(f)
+    }
+}
+
+/// The value of an option accepting a unit of time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum UnitOption {
+    Auto,
+    Unit(TemporalUnit),
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseUnitError;
+
+impl Display for ParseUnitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("provided string was not a valid unit of time")
+    }
+}
+
+impl FromStr for UnitOption {
+    type Err = ParseUnitError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let unit = match s {
+            "auto" => return Ok(Self::Auto),
+            "year" | "years" => TemporalUnit::Year,
+            "month" | "months" => TemporalUnit::Month,
+            "week" | "weeks" => TemporalUnit::Week,
+            "day" | "days" => TemporalUnit::Day,
+            "hour" | "hours" => TemporalUnit::Hour,
+            "minute" | "minutes" => TemporalUnit::Minute,
+            "second" | "seconds" => TemporalUnit::Second,
+            "millisecond" | "milliseconds" => TemporalUnit::Millisecond,
+            "microsecond" | "microseconds" => TemporalUnit::Microsecond,
+            "nanosecond" | "nanoseconds" => TemporalUnit::Nanosecond,
+            _ => return Err(ParseUnitError),
+        };
+        Ok(Self::Unit(unit))
+    }
+}
+
+impl OptionTypeParsable for UnitOption {}
+
+/// The group of units accepted by an option.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum UnitGroup {
+    Date,
+    Time,
+    DateTime,
+}
+
+/// Abstract operation [`GetTemporalUnitValuedOption ( options, key, unitGroup, default [ , extraValues ] )`][spec]
+///
+/// Gets the unit of the `key` option, checking that it belongs to `group` or is one of `extra`.
+/// The `"auto"` value must be explicitly allowed by passing `allow_auto`.
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-gettemporalunitvaluedoption
+pub(crate) fn get_temporal_unit(
+    options: &JsObject,
+    key: &[u16],
+    group: UnitGroup,
+    extra: Option<TemporalUnit>,
+    allow_auto: bool,
+    context: &mut Context<'_>,
+) -> JsResult<Option<UnitOption>> {
+    let Some(unit) = get_option::<UnitOption>(options, key, false, context)? else {
+        return Ok(None);
+    };
+
+    let allowed = match unit {
+        UnitOption::Auto => allow_auto,
+        UnitOption::Unit(unit) => {
+            Some(unit) == extra
+                || match group {
+                    UnitGroup::Date => unit.is_date_unit(),
+                    UnitGroup::Time => !unit.is_date_unit(),
+                    UnitGroup::DateTime => true,
+                }
+        }
+    };
+
+    if !allowed {
+        return Err(JsNativeError::range()
+            .with_message(format!(
+                "invalid unit for the `{}` option",
+                String::from_utf16_lossy(key)
+            ))
+            .into());
+    }
+
+    Ok(Some(unit))
+}
+
+/// Gets a unit option that must be present and cannot be `"auto"`.
+pub(crate) fn get_required_temporal_unit(
+    options: &JsObject,
+    key: &[u16],
+    group: UnitGroup,
+    extra: Option<TemporalUnit>,
+    context: &mut Context<'_>,
+) -> JsResult<TemporalUnit> {
+    match get_temporal_unit(options, key, group, extra, false, context)? {
+        Some(UnitOption::Unit(unit)) => Ok(unit),
+        _ => Err(JsNativeError::range()
+            .with_message(format!(
+                "the `{}` option is required",
+                String::from_utf16_lossy(key)
+            ))
+            .into()),
+    }
+}
+
+/// The rounding modes of Temporal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum RoundingMode {
+    Ceil,
+    Floor,
+    Expand,
+    Trunc,
+    HalfCeil,
+    HalfFloor,
+    HalfExpand,
+    HalfTrunc,
+    HalfEven,
+}
+
+/// The rounding modes of Temporal, after resolving the direction of the number to round.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum UnsignedRoundingMode {
+    Zero,
+    Infinity,
+    HalfZero,
+    HalfInfinity,
+    HalfEven,
+}
+
+impl UnsignedRoundingMode {
+    /// Abstract operation [`ApplyUnsignedRoundingMode ( x, r1, r2, unsignedRoundingMode )`][spec]
+    ///
+    /// Returns `true` if a number strictly between `r1` and `r2` must be rounded to `r2`, given
+    /// how the number compares against the midpoint between both, and whether `r1` is an even
+    /// multiple of the increment.
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-applyunsignedroundingmode
+    #[allow(clippy::match_same_arms)]
+    const fn rounds_up(self, half: Ordering, r1_is_even: bool) -> bool {
+        match (self, half) {
+            (Self::Zero, _) => false,
+            (Self::Infinity, _) => true,
+            (_, Ordering::Less) => false,
+            (_, Ordering::Greater) => true,
+            (Self::HalfZero, Ordering::Equal) => false,
+            (Self::HalfInfinity, Ordering::Equal) => true,
+            (Self::HalfEven, Ordering::Equal) => !r1_is_even,
+        }
+    }
+}
+
+impl RoundingMode {
+    /// Abstract operation [`NegateRoundingMode ( roundingMode )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-negateroundingmode
+    pub(crate) const fn negate(self) -> Self {
+        match self {
+            Self::Ceil => Self::Floor,
+            Self::Floor => Self::Ceil,
+            Self::HalfCeil => Self::HalfFloor,
+            Self::HalfFloor => Self::HalfCeil,
+            mode => mode,
+        }
+    }
+
+    /// Abstract operation [`GetUnsignedRoundingMode ( roundingMode, sign )`][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-getunsignedroundingmode
+    const fn unsigned(self, negative: bool) -> UnsignedRoundingMode {
+        match (self, negative) {
+            (Self::Ceil, true) | (Self::Floor, false) | (Self::Trunc, _) => {
+                UnsignedRoundingMode::Zero
+            }
+            (Self::Ceil, false) | (Self::Floor, true) | (Self::Expand, _) => {
+                UnsignedRoundingMode::Infinity
+            }
+            (Self::HalfCeil, true) | (Self::HalfFloor, false) | (Self::HalfTrunc, _) => {
+                UnsignedRoundingMode::HalfZero
+            }
+            (Self::HalfCeil, false) | (Self::HalfFloor, true) | (Self::HalfExpand, _) => {
+                UnsignedRoundingMode::HalfInfinity
+            }
+            (Self::HalfEven, _) => UnsignedRoundingMode::HalfEven,
+        }
+    }
+
+    /// Abstract operation [`RoundNumberToIncrement ( x, increment, roundingMode )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-roundnumbertoincrement
+    pub(crate) fn round(self, x: i128, increment: i128) -> i128 {
+        let quotient = x.abs() / increment;
+        let remainder = x.abs() % increment;
+        if remainder == 0 {
+            return x;
+        }
+        let half = (remainder * 2).cmp(&increment);
+        let rounded = if self.unsigned(x < 0).rounds_up(half, quotient % 2 == 0) {
+            quotient + 1
+        } else {
+            quotient
+        };
+        rounded * increment * x.signum()
+    }
+
+    /// Abstract operation [`RoundNumberToIncrementAsIfPositive ( x, increment, roundingMode )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-roundnumbertoincrementasifpositive
+    pub(crate) fn round_as_if_positive(self, x: i128, increment: i128) -> i128 {
+        let quotient = x.div_euclid(increment);
+        let remainder = x.rem_euclid(increment);
+        if remainder == 0 {
+            return x;
+        }
+        let half = (remainder * 2).cmp(&increment);
+        let rounded = if self.unsigned(false).rounds_up(half, quotient % 2 == 0) {
+            quotient + 1
+        } else {
+            quotient
+        };
+        rounded * increment
+    }
+
+    /// Rounds a value that progressed `numerator / denominator` of the way from `r1` to `r2`,
+    /// returning `true` if the value must be rounded to `r2`.
+    ///
+    /// `r1` and `r2` must have the same sign as `sign`, and the progress must be in `[0, 1]`.
+    pub(crate) fn rounds_to_end(
+        self,
+        r1: i64,
+        increment: u64,
+        numerator: i128,
+        denominator: i128,
+        sign: i64,
+    ) -> bool {
+        if numerator == denominator {
+            return true;
+        }
+        if numerator == 0 {
+            return false;
+        }
+        let half = (numerator * 2).cmp(&denominator);
+        let r1_is_even = (r1.unsigned_abs() / increment) % 2 == 0;
+        self.unsigned(sign < 0).rounds_up(half, r1_is_even)
+    }
+}
+
+impl Display for RoundingMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Ceil => "ceil",
+            Self::Floor => "floor",
+            Self::Expand => "expand",
+            Self::Trunc => "trunc",
+            Self::HalfCeil => "halfCeil",
+            Self::HalfFloor => "halfFloor",
+            Self::HalfExpand => "halfExpand",
+            Self::HalfTrunc => "halfTrunc",
+            Self::HalfEven => "halfEven",
+        }
+        .fmt(f)
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseRoundingModeError;
+
+impl Display for ParseRoundingModeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("provided string was not a valid rounding mode")
+    }
+}
+
+impl FromStr for RoundingMode {
+    type Err = ParseRoundingModeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ceil" => Ok(Self::Ceil),
+            "floor" => Ok(Self::Floor),
+            "expand" => Ok(Self::Expand),
+            "trunc" => Ok(Self::Trunc),
+            "halfCeil" => Ok(Self::HalfCeil),
+            "halfFloor" => Ok(Self::HalfFloor),
+            "halfExpand" => Ok(Self::HalfExpand),
+            "halfTrunc" => Ok(Self::HalfTrunc),
+            "halfEven" => Ok(Self::HalfEven),
+            _ => Err(ParseRoundingModeError),
+        }
+    }
+}
+
+impl OptionTypeParsable for RoundingMode {}
+
+/// How to handle date and time fields that are out of range.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum Overflow {
+    #[default]
+    Constrain,
+    Reject,
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseOverflowError;
+
+impl Display for ParseOverflowError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("provided string was not `constrain` or `reject`")
+    }
+}
+
+impl FromStr for Overflow {
+    type Err = ParseOverflowError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "constrain" => Ok(Self::Constrain),
+            "reject" => Ok(Self::Reject),
+            _ => Err(ParseOverflowError),
+        }
+    }
+}
+
+impl OptionTypeParsable for Overflow {}
+
+/// How to pick an instant for a wall-clock time that is repeated or skipped in a time zone.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum Disambiguation {
+    #[default]
+    Compatible,
+    Earlier,
+    Later,
+    Reject,
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseDisambiguationError;
+
+impl Display for ParseDisambiguationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("provided string was not `compatible`, `earlier`, `later` or `reject`")
+    }
+}
+
+impl FromStr for Disambiguation {
+    type Err = ParseDisambiguationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "compatible" => Ok(Self::Compatible),
+            "earlier" => Ok(Self::Earlier),
+            "later" => Ok(Self::Later),
+            "reject" => Ok(Self::Reject),
+            _ => Err(ParseDisambiguationError),
+        }
+    }
+}
+
+impl OptionTypeParsable for Disambiguation {}
+
+/// How to handle a UTC offset that doesn't match the time zone of a `ZonedDateTime`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum OffsetOption {
+    Prefer,
+    Use,
+    Ignore,
+    Reject,
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseOffsetOptionError;
+
+impl Display for ParseOffsetOptionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("provided string was not `prefer`, `use`, `ignore` or `reject`")
+    }
+}
+
+impl FromStr for OffsetOption {
+    type Err = ParseOffsetOptionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "prefer" => Ok(Self::Prefer),
+            "use" => Ok(Self::Use),
+            "ignore" => Ok(Self::Ignore),
+            "reject" => Ok(Self::Reject),
+            _ => Err(ParseOffsetOptionError),
+        }
+    }
+}
+
+impl OptionTypeParsable for OffsetOption {}
+
+/// Whether to show the calendar annotation when converting to a string.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum ShowCalendar {
+    #[default]
+    Auto,
+    Always,
+    Never,
+    Critical,
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseShowCalendarError;
+
+impl Display for ParseShowCalendarError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("provided string was not `auto`, `always`, `never` or `critical`")
+    }
+}
+
+impl FromStr for ShowCalendar {
+    type Err = ParseShowCalendarError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "auto" => Ok(Self::Auto),
+            "always" => Ok(Self::Always),
+            "never" => Ok(Self::Never),
+            "critical" => Ok(Self::Critical),
+            _ => Err(ParseShowCalendarError),
+        }
+    }
+}
+
+impl OptionTypeParsable for ShowCalendar {}
+
+/// Whether to show the time zone annotation when converting a `ZonedDateTime` to a string.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum ShowTimeZone {
+    #[default]
+    Auto,
+    Never,
+    Critical,
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseShowTimeZoneError;
+
+impl Display for ParseShowTimeZoneError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("provided string was not `auto`, `never` or `critical`")
+    }
+}
+
+impl FromStr for ShowTimeZone {
+    type Err = ParseShowTimeZoneError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "auto" => Ok(Self::Auto),
+            "never" => Ok(Self::Never),
+            "critical" => Ok(Self::Critical),
+            _ => Err(ParseShowTimeZoneError),
+        }
+    }
+}
+
+impl OptionTypeParsable for ShowTimeZone {}
+
+/// Whether to show the UTC offset when converting a `ZonedDateTime` to a string.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum ShowOffset {
+    #[default]
+    Auto,
+    Never,
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseShowOffsetError;
+
+impl Display for ParseShowOffsetError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("provided string was not `auto` or `never`")
+    }
+}
+
+impl FromStr for ShowOffset {
+    type Err = ParseShowOffsetError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "auto" => Ok(Self::Auto),
+            "never" => Ok(Self::Never),
+            _ => Err(ParseShowOffsetError),
+        }
+    }
+}
+
+impl OptionTypeParsable for ShowOffset {}
+
+/// The direction in which to search for a time zone transition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Direction {
+    Next,
+    Previous,
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseDirectionError;
+
+impl Display for ParseDirectionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("provided string was not `next` or `previous`")
+    }
+}
+
+impl FromStr for Direction {
+    type Err = ParseDirectionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "next" => Ok(Self::Next),
+            "previous" => Ok(Self::Previous),
+            _ => Err(ParseDirectionError),
+        }
+    }
+}
+
+impl OptionTypeParsable for Direction {}
+
+/// The value of the `fractionalSecondDigits` option.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum FractionalDigits {
+    #[default]
+    Auto,
+    Fixed(u8),
+}
+
+impl OptionType for FractionalDigits {
+    fn from_value(value: JsValue, context: &mut Context<'_>) -> JsResult<Self> {
+        // Abstract operation `GetTemporalFractionalSecondDigitsOption ( options )`
+        // https://tc39.es/proposal-temporal/#sec-temporal-gettemporalfractionalseconddigitsoption
+
+        // 3. If digitsValue is not a Number, then
+        if !value.is_number() {
+            // a. If ? ToString(digitsValue) is not "auto", throw a RangeError exception.
+            if value.to_string(context)?.to_std_string_escaped() != "auto" {
+                return Err(JsNativeError::range()
+                    .with_message("`fractionalSecondDigits` must be `auto` or a number")
+                    .into());
+            }
+            // b. Return auto.
+            return Ok(Self::Auto);
+        }
+
+        // 4. If digitsValue is NaN, +∞𝔽, or -∞𝔽, throw a RangeError exception.
+        // 5. Let digitCount be floor(ℝ(digitsValue)).
+        // 6. If digitCount < 0 or digitCount > 9, throw a RangeError exception.
+        let digits = value.as_number().unwrap_or_default().floor();
+        if !(0.0..=9.0).contains(&digits) {
+            return Err(JsNativeError::range()
+                .with_message("`fractionalSecondDigits` must be between 0 and 9")
+                .into());
+        }
+
+        // 7. Return digitCount.
+        Ok(Self::Fixed(digits as u8))
+    }
+}
+
+/// The precision to use when converting a time to a string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Precision {
+    /// Only show hours and minutes.
+    Minute,
+    /// Show as many fractional digits as needed.
+    Auto,
+    /// Show a fixed number of fractional digits.
+    Digits(u8),
+}
+
+/// The result of [`to_seconds_string_precision`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct PrecisionRecord {
+    pub(crate) precision: Precision,
+    pub(crate) unit: TemporalUnit,
+    pub(crate) increment: u64,
+}
+
+/// Gets the options of the `toString` methods that control the precision of the time, in the
+/// order required by the specification.
+pub(crate) fn get_string_precision_options(
+    options: &JsObject,
+    context: &mut Context<'_>,
+) -> JsResult<(PrecisionRecord, RoundingMode)> {
+    let digits =
+        get_option::<FractionalDigits>(options, utf16!("fractionalSecondDigits"), false, context)?
+            .unwrap_or_default();
+    let rounding_mode =
+        get_option::<RoundingMode>(options, utf16!("roundingMode"), false, context)?
+            .unwrap_or(RoundingMode::Trunc);
+    let smallest_unit = get_temporal_unit(
+        options,
+        utf16!("smallestUnit"),
+        UnitGroup::Time,
+        None,
+        false,
+        context,
+    )?;
+    if smallest_unit == Some(UnitOption::Unit(TemporalUnit::Hour)) {
+        return Err(JsNativeError::range()
+            .with_message("`smallestUnit` cannot be `hour`")
+            .into());
+    }
+    let smallest_unit = match smallest_unit {
+        Some(UnitOption::Unit(unit)) => Some(unit),
+        _ => None,
+    };
+    Ok((
+        to_seconds_string_precision(smallest_unit, digits),
+        rounding_mode,
+    ))
+}
+
+/// Abstract operation [`ToSecondsStringPrecisionRecord ( smallestUnit, fractionalDigitCount )`][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-tosecondsstringprecisionrecord
+pub(crate) fn to_seconds_string_precision(
+    smallest_unit: Option<TemporalUnit>,
+    digits: FractionalDigits,
+) -> PrecisionRecord {
+    let (precision, unit, increment) = match (smallest_unit, digits) {
+        (Some(TemporalUnit::Minute), _) => (Precision::Minute, TemporalUnit::Minute, 1),
+        (Some(TemporalUnit::Second), _) | (None, FractionalDigits::Fixed(0)) => {
+            (Precision::Digits(0), TemporalUnit::Second, 1)
+        }
+        (Some(TemporalUnit::Millisecond), _) => {
+            (Precision::Digits(3), TemporalUnit::Millisecond, 1)
+        }
+        (Some(TemporalUnit::Microsecond), _) => {
+            (Precision::Digits(6), TemporalUnit::Microsecond, 1)
+        }
+        (Some(_), _) => (Precision::Digits(9), TemporalUnit::Nanosecond, 1),
+        (None, FractionalDigits::Auto) => (Precision::Auto, TemporalUnit::Nanosecond, 1),
+        (None, FractionalDigits::Fixed(digits @ 1..=3)) => (
+            Precision::Digits(digits),
+            TemporalUnit::Millisecond,
+            10u64.pow(3 - u32::from(digits)),
+        ),
+        (None, FractionalDigits::Fixed(digits @ 4..=6)) => (
+            Precision::Digits(digits),
+            TemporalUnit::Microsecond,
+            10u64.pow(6 - u32::from(digits)),
+        ),
+        (None, FractionalDigits::Fixed(digits)) => (
+            Precision::Digits(digits),
+            TemporalUnit::Nanosecond,
+            10u64.pow(9 - u32::from(digits)),
+        ),
+    };
+    PrecisionRecord {
+        precision,
+        unit,
+        increment,
+    }
+}
+
+/// Abstract operation [`GetRoundingIncrementOption ( options )`][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-getroundingincrementoption
+pub(crate) fn get_rounding_increment(
+    options: &JsObject,
+    context: &mut Context<'_>,
+) -> JsResult<u64> {
+    // 1. Let value be ? Get(options, "roundingIncrement").
+    let value = options.get(utf16!("roundingIncrement"), context)?;
+
+    // 2. If value is undefined, return 1𝔽.
+    if value.is_undefined() {
+        return Ok(1);
+    }
+
+    // 3. Let integerIncrement be ? ToIntegerWithTruncation(value).
+    let increment = super::to_integer_with_truncation(&value, context)?;
+
+    // 4. If integerIncrement < 1 or integerIncrement > 10**9, throw a RangeError exception.
+    if !(1.0..=1e9).contains(&increment) {
+        return Err(JsNativeError::range()
+            .with_message("`roundingIncrement` must be between 1 and 10^9")
+            .into());
+    }
+
+    // 5. Return integerIncrement.
+    Ok(increment as u64)
+}
+
+/// Abstract operation [`ValidateTemporalRoundingIncrement ( increment, dividend, inclusive )`][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-validatetemporalroundingincrement
+pub(crate) fn validate_rounding_increment(
+    increment: u64,
+    dividend: u64,
+    inclusive: bool,
+) -> JsResult<()> {
+    let maximum = if inclusive { dividend } else { dividend - 1 };
+    if increment > maximum || dividend % increment != 0 {
+        return Err(JsNativeError::range()
+            .with_message("`roundingIncrement` does not divide evenly into the unit")
+            .into());
+    }
+    Ok(())
+}
+
+/// Gets the `overflow` option of an options object.
+pub(crate) fn get_overflow(options: &JsObject, context: &mut Context<'_>) -> JsResult<Overflow> {
+    Ok(get_option(options, utf16!("overflow"), false, context)?.unwrap_or_default())
+}
+
+/// The settings of the `until` and `since` methods.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DifferenceSettings {
+    pub(crate) smallest_unit: TemporalUnit,
+    pub(crate) largest_unit: TemporalUnit,
+    pub(crate) rounding_mode: RoundingMode,
+    pub(crate) rounding_increment: u64,
+}
+
+impl DifferenceSettings {
+    /// Returns `true` if rounding with these settings doesn't change a duration.
+    pub(crate) fn is_noop(&self) -> bool {
+        self.smallest_unit == TemporalUnit::Nanosecond && self.rounding_increment == 1
+    }
+}
+
+/// Abstract operation [`GetDifferenceSettings ( operation, options, unitGroup, disallowedUnits, fallbackSmallestUnit, smallestLargestDefaultUnit )`][spec]
+///
+/// `since` is `true` for the `since` operation, which negates the rounding mode. Disallowed units
+/// are already excluded by `group`, since no Temporal type disallows units of its own group.
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-getdifferencesettings
+pub(crate) fn get_difference_settings(
+    since: bool,
+    options: &JsValue,
+    group: UnitGroup,
+    fallback_smallest_unit: TemporalUnit,
+    default_largest_unit: TemporalUnit,
+    context: &mut Context<'_>,
+) -> JsResult<DifferenceSettings> {
+    let options = crate::builtins::options::get_options_object(options)?;
+    // 1. NOTE: The following steps read options and perform independent validation in alphabetical order.
+    // 2. Let largestUnit be ? GetTemporalUnitValuedOption(options, "largestUnit", unitGroup, auto).
+    let largest_unit =
+        get_temporal_unit(&options, utf16!("largestUnit"), group, None, true, context)?;
+
+    // 4. Let roundingIncrement be ? GetRoundingIncrementOption(options).
+    let rounding_increment = get_rounding_increment(&options, context)?;
+
+    // 5. Let roundingMode be ? GetRoundingModeOption(options, trunc).
+    let mut rounding_mode =
+        get_option::<RoundingMode>(&options, utf16!("roundingMode"), false, context)?
+            .unwrap_or(RoundingMode::Trunc);
+
+    // 6. If operation is since, then
+    if since {
+        // a. Set roundingMode to NegateRoundingMode(roundingMode).
+        rounding_mode = rounding_mode.negate();
+    }
+
+    // 7. Let smallestUnit be ? GetTemporalUnitValuedOption(options, "smallestUnit", unitGroup, fallbackSmallestUnit).
+    let smallest_unit = match get_temporal_unit(
+        &options,
+        utf16!("smallestUnit"),
+        group,
+        None,
+        false,
+        context,
+    )? {
+        Some(UnitOption::Unit(unit)) => unit,
+        _ => fallback_smallest_unit,
+    };
+
+    // 9. Let defaultLargestUnit be LargerOfTwoTemporalUnits(smallestLargestDefaultUnit, smallestUnit).
+    // 10. If largestUnit is auto, set largestUnit to defaultLargestUnit.
+    let largest_unit = match largest_unit {
+        Some(UnitOption::Unit(unit)) => unit,
+        _ => default_largest_unit.max(smallest_unit),
+    };
+
+    // 11. If LargerOfTwoTemporalUnits(largestUnit, smallestUnit) is not largestUnit, throw a RangeError exception.
+    if smallest_unit > largest_unit {
+        return Err(JsNativeError::range()
+            .with_message("`smallestUnit` cannot be larger than `largestUnit`")
+            .into());
+    }
+
+    // 12. Let maximum be MaximumTemporalDurationRoundingIncrement(smallestUnit).
+    // 13. If maximum is not unset, perform ? ValidateTemporalRoundingIncrement(roundingIncrement, maximum, false).
+    if let Some(maximum) = smallest_unit.maximum_rounding_increment() {
+        validate_rounding_increment(rounding_increment, maximum, false)?;
+    }
+
+    // 14. Return the Record { [[SmallestUnit]]: smallestUnit, [[LargestUnit]]: largestUnit, [[RoundingMode]]: roundingMode, [[RoundingIncrement]]: roundingIncrement,  }.
+    Ok(DifferenceSettings {
+        smallest_unit,
+        largest_unit,
+        rounding_mode,
+        rounding_increment,
+    })
+}
@@ -0,0 +1,506 @@
+//! Parser of the ISO 8601 strings accepted by Temporal, extended with the time zone and
+//! calendar annotations of [RFC 9557][rfc].
+//!
+//! [rfc]: https://www.rfc-editor.org/rfc/rfc9557
+
+use super::iso::{IsoDate, IsoTime};
+
+/// A UTC offset found in a date-time string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ParsedOffset {
+    /// The `Z` designator, marking an exact time without a local offset.
+    Z,
+    /// A numeric offset. `exact` is `false` if the offset only had minute precision.
+    Offset { nanoseconds: i64, exact: bool },
+}
+
+/// The result of parsing a date-time string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ParsedDateTime {
+    pub(crate) date: IsoDate,
+    pub(crate) time: Option<IsoTime>,
+    pub(crate) offset: Option<ParsedOffset>,
+    pub(crate) time_zone: Option<String>,
+    pub(crate) calendar: Option<String>,
+}
+
+/// The result of parsing a duration string, with one signed value per field of a duration.
+pub(crate) type ParsedDuration = [f64; 10];
+
+/// A simple cursor over the bytes of an ASCII string.
+struct Cursor<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    const fn new(text: &'a str) -> Self {
+        Self {
+            bytes: text.as_bytes(),
+            pos: 0,
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    fn peek_nth(&self, n: usize) -> Option<u8> {
+        self.bytes.get(self.pos + n).copied()
+    }
+
+    const fn is_done(&self) -> bool {
+        self.pos >= self.bytes.len()
+    }
+
+    /// Consumes the next byte if it is any of `chars`, returning it.
+    fn eat(&mut self, chars: &[u8]) -> Option<u8> {
+        let next = self.peek().filter(|c| chars.contains(c))?;
+        self.pos += 1;
+        Some(next)
+    }
+
+    /// Consumes exactly `n` ASCII digits, returning their value.
+    fn digits(&mut self, n: usize) -> Option<u32> {
+        let digits = self.bytes.get(self.pos..self.pos + n)?;
+        if !digits.iter().all(u8::is_ascii_digit) {
+            return None;
+        }
+        self.pos += n;
+        Some(
+            digits
+                .iter()
+                .fold(0, |acc, digit| acc * 10 + u32::from(digit - b'0')),
+        )
+    }
+
+    /// Consumes exactly `n` digits whose value must be at most `max`.
+    fn digits_max(&mut self, n: usize, max: u32) -> Option<u32> {
+        self.digits(n).filter(|&value| value <= max)
+    }
+
+    fn peek_digit(&self) -> bool {
+        self.peek().map_or(false, |c| c.is_ascii_digit())
+    }
+
+    /// Consumes a fractional part of one to nine digits after a `.` or `,` separator, returning
+    /// its value in nanoseconds.
+    #[allow(clippy::option_option)]
+    fn fraction(&mut self) -> Option<Option<u32>> {
+        if self.eat(b".,").is_none() {
+            return Some(None);
+        }
+        let start = self.pos;
+        while self.peek_digit() {
+            self.pos += 1;
+        }
+        let len = self.pos - start;
+        if !(1..=9).contains(&len) {
+            return None;
+        }
+        let value = self.bytes[start..self.pos]
+            .iter()
+            .fold(0, |acc, digit| acc * 10 + u32::from(digit - b'0'));
+        Some(Some(value * 10u32.pow((9 - len) as u32)))
+    }
+
+    /// Parses a `DateYear`.
+    fn year(&mut self) -> Option<i32> {
+        match self.eat(b"+-") {
+            Some(sign) => {
+                let year = self.digits(6)? as i32;
+                if sign == b'-' {
+                    // -000000 is not a valid year.
+                    (year != 0).then_some(-year)
+                } else {
+                    Some(year)
+                }
+            }
+            None => self.digits(4).map(|year| year as i32),
+        }
+    }
+
+    /// Parses a `Date`, in the basic or the extended format.
+    fn date(&mut self) -> Option<IsoDate> {
+        let year = self.year()?;
+        let extended = self.eat(b"-").is_some();
+        let month = self.digits(2)?;
+        if extended && self.eat(b"-").is_none() {
+            return None;
+        }
+        let day = self.digits(2)?;
+        IsoDate::new(year, month as u8, day as u8)
+    }
+
+    /// Parses a `Time`, in the basic or the extended format.
+    fn time(&mut self) -> Option<IsoTime> {
+        let hour = self.digits_max(2, 23)?;
+        let mut time = IsoTime {
+            hour: hour as u8,
+            ..IsoTime::default()
+        };
+
+        let extended = self.peek() == Some(b':');
+        if extended && self.peek_nth(1).map_or(false, |c| c.is_ascii_digit()) {
+            self.pos += 1;
+        } else if extended || !self.peek_digit() {
+            return Some(time);
+        }
+        time.minute = self.digits_max(2, 59)? as u8;
+
+        if extended {
+            if self.peek() != Some(b':') {
+                return Some(time);
+            }
+            self.pos += 1;
+        } else if !self.peek_digit() {
+            return Some(time);
+        }
+        // A leap second is parsed as the previous second.
+        time.second = self.digits_max(2, 60)?.min(59) as u8;
+
+        if let Some(fraction) = self.fraction()? {
+            time.millisecond = (fraction / 1_000_000) as u16;
+            time.microsecond = (fraction / 1000 % 1000) as u16;
+            time.nanosecond = (fraction % 1000) as u16;
+        }
+
+        Some(time)
+    }
+
+    /// Parses a `UTCOffset`, with or without sub-minute precision, returning the offset in
+    /// nanoseconds and whether it had sub-minute precision.
+    fn utc_offset(&mut self, sub_minute: bool) -> Option<(i64, bool)> {
+        let sign = if self.eat(b"+-")? == b'-' { -1 } else { 1 };
+        let hours = i64::from(self.digits_max(2, 23)?);
+        let mut nanoseconds = hours * 3_600_000_000_000;
+
+        let extended = self.eat(b":").is_some();
+        if !extended && !self.peek_digit() {
+            return Some((sign * nanoseconds, false));
+        }
+        nanoseconds += i64::from(self.digits_max(2, 59)?) * 60_000_000_000;
+
+        let has_seconds = if extended {
+            self.peek() == Some(b':')
+        } else {
+            self.peek_digit()
+        };
+        if !has_seconds || !sub_minute {
+            return Some((sign * nanoseconds, false));
+        }
+        if extended {
+            self.pos += 1;
+        }
+        nanoseconds += i64::from(self.digits_max(2, 59)?) * 1_000_000_000;
+        nanoseconds += i64::from(self.fraction()?.unwrap_or_default());
+
+        Some((sign * nanoseconds, true))
+    }
+
+    /// Parses a `DateTimeUTCOffset`, if there is one.
+    #[allow(clippy::option_option)]
+    fn date_time_offset(&mut self) -> Option<Option<ParsedOffset>> {
+        match self.peek() {
+            Some(b'Z' | b'z') => {
+                self.pos += 1;
+                Some(Some(ParsedOffset::Z))
+            }
+            Some(b'+' | b'-') => {
+                let (nanoseconds, exact) = self.utc_offset(true)?;
+                Some(Some(ParsedOffset::Offset { nanoseconds, exact }))
+            }
+            _ => Some(None),
+        }
+    }
+
+    /// Parses the optional `TimeZoneAnnotation` and `Annotations` at the end of a string,
+    /// returning the time zone and the calendar.
+    fn annotations(&mut self) -> Option<(Option<String>, Option<String>)> {
+        let mut time_zone = None;
+        let mut calendar = None;
+        let mut calendar_critical = false;
+        let mut calendar_count = 0;
+
+        while self.eat(b"[").is_some() {
+            let critical = self.eat(b"!").is_some();
+            let end = self.pos + self.bytes[self.pos..].iter().position(|&c| c == b']')?;
+            let content = std::str::from_utf8(&self.bytes[self.pos..end]).ok()?;
+            self.pos = end + 1;
+
+            match content.split_once('=') {
+                None => {
+                    // The time zone annotation must be the first one.
+                    if time_zone.is_some()
+                        || calendar_count > 0
+                        || !is_time_zone_identifier(content)
+                    {
+                        return None;
+                    }
+                    time_zone = Some(content.to_owned());
+                }
+                Some((key, value)) => {
+                    if !is_annotation_key(key) || !is_annotation_value(value) {
+                        return None;
+                    }
+                    if key == "u-ca" {
+                        calendar_count += 1;
+                        calendar_critical |= critical;
+                        if calendar.is_none() {
+                            calendar = Some(value.to_owned());
+                        }
+                    } else if critical {
+                        // Unknown critical annotations must be rejected.
+                        return None;
+                    }
+                }
+            }
+        }
+
+        if calendar_count > 1 && calendar_critical {
+            return None;
+        }
+
+        Some((time_zone, calendar))
+    }
+}
+
+/// Returns `true` if `key` is a valid `AnnotationKey`.
+fn is_annotation_key(key: &str) -> bool {
+    let mut chars = key.bytes();
+    chars
+        .next()
+        .map_or(false, |c| c.is_ascii_lowercase() || c == b'_')
+        && chars.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == b'_' || c == b'-')
+}
+
+/// Returns `true` if `value` is a valid `AnnotationValue`.
+fn is_annotation_value(value: &str) -> bool {
+    value.split('-').all(|component| {
+        !component.is_empty() && component.bytes().all(|c| c.is_ascii_alphanumeric())
+    })
+}
+
+/// Returns `true` if `name` is a valid `TimeZoneIANAName`.
+fn is_iana_name(name: &str) -> bool {
+    name.split('/').all(|component| {
+        let mut chars = component.bytes();
+        component != "."
+            && component != ".."
+            && chars
+                .next()
+                .map_or(false, |c| c.is_ascii_alphabetic() || c == b'.' || c == b'_')
+            && chars.all(|c| c.is_ascii_alphanumeric() || b"._+-".contains(&c))
+    })
+}
+
+/// Returns `true` if `text` is a valid `TimeZoneIdentifier`.
+pub(crate) fn is_time_zone_identifier(text: &str) -> bool {
+    parse_offset_identifier(text).is_some() || is_iana_name(text)
+}
+
+/// Parses a `UTCOffset` without sub-minute precision, as used by offset time zone identifiers,
+/// returning the offset in minutes.
+pub(crate) fn parse_offset_identifier(text: &str) -> Option<i16> {
+    let mut cursor = Cursor::new(text);
+    let (nanoseconds, _) = cursor.utc_offset(false)?;
+    cursor
+        .is_done()
+        .then_some((nanoseconds / 60_000_000_000) as i16)
+}
+
+/// Parses a `UTCOffset` with optional sub-minute precision, returning the offset in
+/// nanoseconds.
+pub(crate) fn parse_offset(text: &str) -> Option<i64> {
+    let mut cursor = Cursor::new(text);
+    let (nanoseconds, _) = cursor.utc_offset(true)?;
+    cursor.is_done().then_some(nanoseconds)
+}
+
+/// Parses a date-time string, as accepted by `TemporalDateTimeString`, `TemporalInstantString`
+/// and `TemporalZonedDateTimeString`.
+pub(crate) fn parse_date_time(text: &str) -> Option<ParsedDateTime> {
+    let mut cursor = Cursor::new(text);
+    let date = cursor.date()?;
+
+    let time = if cursor.eat(b"Tt ").is_some() {
+        Some(cursor.time()?)
+    } else {
+        None
+    };
+
+    let offset = if time.is_some() {
+        cursor.date_time_offset()?
+    } else {
+        None
+    };
+    let (time_zone, calendar) = cursor.annotations()?;
+
+    cursor.is_done().then_some(ParsedDateTime {
+        date,
+        time,
+        offset,
+        time_zone,
+        calendar,
+    })
+}
+
+/// Parses a time string, as accepted by `TemporalTimeString`.
+///
+/// Returns the time and the calendar annotation, or `None` if the string is not valid or
+/// contains the `Z` designator.
+pub(crate) fn parse_time(text: &str) -> Option<(IsoTime, Option<String>)> {
+    if let Some(parsed) = parse_date_time(text) {
+        return match (parsed.time, parsed.offset) {
+            (Some(time), None | Some(ParsedOffset::Offset { .. })) => Some((time, parsed.calendar)),
+            _ => None,
+        };
+    }
+
+    let mut cursor = Cursor::new(text);
+    let designator = cursor.eat(b"Tt").is_some();
+    let time = cursor.time()?;
+    let offset = cursor.date_time_offset()?;
+    if offset == Some(ParsedOffset::Z) {
+        return None;
+    }
+
+    // Without the time designator, the time must not be ambiguous with a month-day or a
+    // year-month.
+    if !designator && is_ambiguous_time(&text[..cursor.pos]) {
+        return None;
+    }
+
+    let (_, calendar) = cursor.annotations()?;
+    cursor.is_done().then_some((time, calendar))
+}
+
+/// Returns `true` if `text` is also a valid `DateSpecMonthDay` or `DateSpecYearMonth`.
+fn is_ambiguous_time(text: &str) -> bool {
+    let month_day = || {
+        let mut cursor = Cursor::new(text);
+        if cursor.bytes.starts_with(b"--") {
+            cursor.pos += 2;
+        }
+        let month = cursor.digits(2)?;
+        cursor.eat(b"-");
+        let day = cursor.digits(2)?;
+        (cursor.is_done() && (1..=12).contains(&month))
+            .then(|| day >= 1 && day <= u32::from(super::iso::days_in_month(1972, month as u8)))
+    };
+    let year_month = || {
+        let mut cursor = Cursor::new(text);
+        cursor.year()?;
+        cursor.eat(b"-");
+        let month = cursor.digits(2)?;
+        Some(cursor.is_done() && (1..=12).contains(&month))
+    };
+
+    month_day() == Some(true) || year_month() == Some(true)
+}
+
+/// Parses a duration string, as accepted by `TemporalDurationString`.
+pub(crate) fn parse_duration(text: &str) -> Option<ParsedDuration> {
+    /// Parses an unbounded decimal integer, followed by an optional fraction.
+    fn number(cursor: &mut Cursor<'_>) -> Option<(f64, Option<u32>)> {
+        let start = cursor.pos;
+        while cursor.peek_digit() {
+            cursor.pos += 1;
+        }
+        if cursor.pos == start {
+            return None;
+        }
+        let whole = std::str::from_utf8(&cursor.bytes[start..cursor.pos])
+            .ok()?
+            .parse::<f64>()
+            .ok()?;
+        Some((whole, cursor.fraction()?))
+    }
+
+    let mut cursor = Cursor::new(text);
+    let sign = if cursor.eat(b"+-") == Some(b'-') {
+        -1.0
+    } else {
+        1.0
+    };
+    cursor.eat(b"Pp")?;
+
+    let mut fields = [0.0; 10];
+    let mut any = false;
+
+    // The date designators, in order: years, months, weeks and days.
+    let mut next_date = 0;
+    while cursor.peek_digit() {
+        let (value, fraction) = number(&mut cursor)?;
+        if fraction.is_some() {
+            return None;
+        }
+        let index = match cursor.eat(b"YyMmWwDd")?.to_ascii_uppercase() {
+            b'Y' => 0,
+            b'M' => 1,
+            b'W' => 2,
+            _ => 3,
+        };
+        if index < next_date {
+            return None;
+        }
+        next_date = index + 1;
+        fields[index] = value;
+        any = true;
+    }
+
+    if cursor.eat(b"Tt").is_some() {
+        // The time designators, in order: hours, minutes and seconds.
+        let mut next_time = 4;
+        let mut any_time = false;
+        while cursor.peek_digit() {
+            let (value, fraction) = number(&mut cursor)?;
+            let index = match cursor.eat(b"HhMmSs")?.to_ascii_uppercase() {
+                b'H' => 4,
+                b'M' => 5,
+                _ => 6,
+            };
+            if index < next_time {
+                return None;
+            }
+            next_time = index + 1;
+            fields[index] = value;
+            any_time = true;
+
+            if let Some(fraction) = fraction {
+                // The fraction must be in the last component, and is balanced into the
+                // smaller units.
+                let mut rest = u64::from(fraction) * [3_600, 60, 1][index - 4];
+                if index == 4 {
+                    fields[5] = (rest / 60_000_000_000) as f64;
+                    rest %= 60_000_000_000;
+                }
+                if index <= 5 {
+                    fields[6] = (rest / 1_000_000_000) as f64;
+                    rest %= 1_000_000_000;
+                }
+                fields[7] = (rest / 1_000_000) as f64;
+                fields[8] = (rest / 1000 % 1000) as f64;
+                fields[9] = (rest % 1000) as f64;
+                if !cursor.is_done() {
+                    return None;
+                }
+            }
+        }
+        if !any_time {
+            return None;
+        }
+        any = true;
+    }
+
+    if !any || !cursor.is_done() {
+        return None;
+    }
+
+    for field in &mut fields {
+        *field *= sign;
+        // Avoid negative zeroes.
+        *field += 0.0;
+    }
+    Some(fields)
+}
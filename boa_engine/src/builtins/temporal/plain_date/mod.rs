@@ -0,0 +1,715 @@
+//! Boa's implementation of the `Temporal.PlainDate` builtin object.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/proposal-temporal/#sec-temporal-plaindate-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/PlainDate
+
+use boa_gc::{empty_trace, Finalize, Trace};
+use boa_profiler::Profiler;
+
+use crate::{
+    builtins::{
+        options::get_options_object, BuiltInBuilder, BuiltInConstructor, BuiltInObject,
+        IntrinsicObject,
+    },
+    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    js_string,
+    native_function::NativeFunctionPointer,
+    object::{JsObject, ObjectData},
+    property::Attribute,
+    realm::Realm,
+    string::utf16,
+    symbol::JsSymbol,
+    Context, JsArgs, JsNativeError, JsResult, JsValue,
+};
+
+use super::{
+    calendar::{
+        canonicalize_calendar, check_calendar_annotation, format_calendar_annotation, get_calendar,
+        get_calendar_with_iso_default, get_show_calendar, to_calendar_identifier, ISO8601,
+    },
+    constructor_without_new, create_temporal_object,
+    duration::{duration_from_difference, ordering_to_value, to_temporal_duration, Duration},
+    fields::{to_partial_object, FieldNames, Fields, RequiredFields},
+    invalid_string,
+    iso::{days_in_month, days_in_year, is_leap_year, DateDuration, IsoDate, IsoDateTime, IsoTime},
+    options::{get_difference_settings, get_overflow, Disambiguation, TemporalUnit, UnitGroup},
+    pad_iso_year,
+    parser::{parse_date_time, ParsedOffset},
+    plain_date_time::PlainDateTime,
+    plain_time::to_temporal_time,
+    require_string,
+    rounding::{round_relative_duration, InternalDuration, RelativeTo},
+    time_zone::to_time_zone_slot,
+    to_integer_with_truncation, value_of, with_getters,
+    zoned_date_time::ZonedDateTime,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The `Temporal.PlainDate` builtin object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PlainDate {
+    pub(crate) date: IsoDate,
+}
+
+impl Finalize for PlainDate {}
+
+// SAFETY: `PlainDate` doesn't contain any traceable data.
+unsafe impl Trace for PlainDate {
+    empty_trace!();
+}
+
+impl IntrinsicObject for PlainDate {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        let builder = BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .static_method(Self::from, "from", 1)
+            .static_method(Self::compare, "compare", 2)
+            .property(
+                JsSymbol::to_string_tag(),
+                "Temporal.PlainDate",
+                Attribute::CONFIGURABLE,
+            )
+            .method(Self::get_calendar, "getCalendar", 0)
+            .method(Self::add, "add", 1)
+            .method(Self::subtract, "subtract", 1)
+            .method(Self::with, "with", 1)
+            .method(Self::with_calendar, "withCalendar", 1)
+            .method(Self::until, "until", 1)
+            .method(Self::since, "since", 1)
+            .method(Self::equals, "equals", 1)
+            .method(Self::to_plain_date_time, "toPlainDateTime", 0)
+            .method(Self::to_zoned_date_time, "toZonedDateTime", 1)
+            .method(Self::to_string, "toString", 0)
+            .method(Self::to_json, "toJSON", 0)
+            .method(Self::to_json, "toLocaleString", 0)
+            .method(value_of, "valueOf", 0);
+
+        with_getters(builder, realm, &date_getters::<Self>()).build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for PlainDate {
+    const NAME: &'static str = "PlainDate";
+}
+
+impl BuiltInConstructor for PlainDate {
+    const LENGTH: usize = 3;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::plain_date;
+
+    /// Constructor [`Temporal.PlainDate ( isoYear, isoMonth, isoDay [ , calendar ] )`][spec].
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindate
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(constructor_without_new(Self::NAME).into());
+        }
+
+        // 2. Let y be ? ToIntegerWithTruncation(isoYear).
+        let year = to_integer_with_truncation(args.get_or_undefined(0), context)?;
+        // 3. Let m be ? ToIntegerWithTruncation(isoMonth).
+        let month = to_integer_with_truncation(args.get_or_undefined(1), context)?;
+        // 4. Let d be ? ToIntegerWithTruncation(isoDay).
+        let day = to_integer_with_truncation(args.get_or_undefined(2), context)?;
+
+        // 5. If calendar is undefined, set calendar to "iso8601".
+        // 6. If calendar is not a String, throw a TypeError exception.
+        // 7. Set calendar to ? CanonicalizeCalendar(calendar).
+        check_calendar_argument(args.get_or_undefined(3))?;
+
+        // 8. If IsValidISODate(y, m, d) is false, throw a RangeError exception.
+        // 9. Let isoDate be CreateISODateRecord(y, m, d).
+        // 10. Return ? CreateTemporalDate(isoDate, calendar, NewTarget).
+        let date = IsoDate::regulate(year, month, day, super::options::Overflow::Reject)?;
+        Ok(Self::new(date).create(Some(new_target), context)?.into())
+    }
+}
+
+impl PlainDate {
+    /// Creates a new plain date.
+    pub(crate) const fn new(date: IsoDate) -> Self {
+        Self { date }
+    }
+
+    /// Creates a new `Temporal.PlainDate` object with this data.
+    pub(crate) fn create(
+        self,
+        new_target: Option<&JsValue>,
+        context: &mut Context<'_>,
+    ) -> JsResult<JsObject> {
+        create_temporal_object(
+            ObjectData::plain_date(self),
+            new_target,
+            StandardConstructors::plain_date,
+            context,
+        )
+    }
+
+    /// `Temporal.PlainDate.from ( item [ , options ] )`
+    fn from(_: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        let date = to_temporal_date(args.get_or_undefined(0), args.get_or_undefined(1), context)?;
+        Ok(Self::new(date).create(None, context)?.into())
+    }
+
+    /// `Temporal.PlainDate.compare ( one, two )`
+    fn compare(_: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        let undefined = JsValue::undefined();
+        let one = to_temporal_date(args.get_or_undefined(0), &undefined, context)?;
+        let two = to_temporal_date(args.get_or_undefined(1), &undefined, context)?;
+        Ok(ordering_to_value(one.cmp(&two)))
+    }
+
+    /// `Temporal.PlainDate.prototype.getCalendar ( )`
+    fn get_calendar(this: &JsValue, _: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        Self::this_date(this, context)?;
+        get_calendar(context)
+    }
+
+    /// `Temporal.PlainDate.prototype.add ( temporalDurationLike [ , options ] )`
+    fn add(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        let date = Self::this_date(this, context)?;
+        let duration = to_temporal_duration(args.get_or_undefined(0), context)?;
+        Self::add_duration(date, &duration, args.get_or_undefined(1), context)
+    }
+
+    /// `Temporal.PlainDate.prototype.subtract ( temporalDurationLike [ , options ] )`
+    fn subtract(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        let date = Self::this_date(this, context)?;
+        let duration = to_temporal_duration(args.get_or_undefined(0), context)?;
+        Self::add_duration(date, &duration.negated(), args.get_or_undefined(1), context)
+    }
+
+    /// Abstract operation [`AddDurationToDate ( operation, temporalDate, temporalDurationLike, options )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-adddurationtodate
+    fn add_duration(
+        date: IsoDate,
+        duration: &Duration,
+        options: &JsValue,
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 4. Let dateDuration be ToDateDurationRecordWithoutTime(duration).
+        let duration = date_duration_without_time(duration);
+
+        // 5. Let resolvedOptions be ? GetOptionsObject(options).
+        // 6. Let overflow be ? GetTemporalOverflowOption(resolvedOptions).
+        let overflow = get_overflow(&get_options_object(options)?, context)?;
+
+        // 7. Let result be ? CalendarDateAdd(calendar, temporalDate.[[ISODate]], dateDuration, overflow).
+        let date = date.add(duration, overflow)?;
+
+        // 8. Return ! CreateTemporalDate(result, calendar).
+        Ok(Self::new(date).create(None, context)?.into())
+    }
+
+    /// `Temporal.PlainDate.prototype.with ( temporalDateLike [ , options ] )`
+    fn with(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Let temporalDate be the this value.
+        // 2. Perform ? RequireInternalSlot(temporalDate, [[InitializedTemporalDate]]).
+        let date = Self::this_date(this, context)?;
+
+        // 3. If ? IsPartialTemporalObject(temporalDateLike) is false, throw a TypeError exception.
+        let partial = to_partial_object(args.get_or_undefined(0), context)?;
+
+        // 4. Let calendar be temporalDate.[[Calendar]].
+        // 5. Let fields be ISODateToFields(calendar, temporalDate.[[ISODate]], date).
+        let fields = Fields::from_date(date);
+
+        // 6. Let partialDate be ? PrepareCalendarFields(calendar, temporalDateLike, « year, month, month-code, day », « », partial).
+        let partial =
+            Fields::prepare(&partial, FieldNames::DATE, RequiredFields::Partial, context)?;
+
+        // 7. Set fields to CalendarMergeFields(calendar, fields, partialDate).
+        let fields = fields.merge(partial);
+
+        // 8. Let resolvedOptions be ? GetOptionsObject(options).
+        // 9. Let overflow be ? GetTemporalOverflowOption(resolvedOptions).
+        let overflow = get_overflow(&get_options_object(args.get_or_undefined(1))?, context)?;
+
+        // 10. Let isoDate be ? CalendarDateFromFields(calendar, fields, overflow).
+        let date = fields.to_date(overflow)?;
+
+        // 11. Return ! CreateTemporalDate(isoDate, calendar).
+        Ok(Self::new(date).create(None, context)?.into())
+    }
+
+    /// `Temporal.PlainDate.prototype.withCalendar ( calendarLike )`
+    fn with_calendar(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        let date = Self::this_date(this, context)?;
+        to_calendar_identifier(args.get_or_undefined(0), context)?;
+        Ok(Self::new(date).create(None, context)?.into())
+    }
+
+    /// `Temporal.PlainDate.prototype.until ( other [ , options ] )`
+    fn until(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        Self::difference(false, this, args, context)
+    }
+
+    /// `Temporal.PlainDate.prototype.since ( other [ , options ] )`
+    fn since(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        Self::difference(true, this, args, context)
+    }
+
+    /// Abstract operation [`DifferenceTemporalPlainDate ( operation, temporalDate, other, options )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-differencetemporalplaindate
+    fn difference(
+        since: bool,
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        let date = Self::this_date(this, context)?;
+
+        // 1. Set other to ? ToTemporalDate(other).
+        let other = to_temporal_date(args.get_or_undefined(0), &JsValue::undefined(), context)?;
+
+        // 2. If CalendarEquals(temporalDate.[[Calendar]], other.[[Calendar]]) is false, throw a RangeError exception.
+        // 3. Let resolvedOptions be ? GetOptionsObject(options).
+        // 4. Let settings be ? GetDifferenceSettings(operation, resolvedOptions, date, « », day, day).
+        let settings = get_difference_settings(
+            since,
+            args.get_or_undefined(1),
+            UnitGroup::Date,
+            TemporalUnit::Day,
+            TemporalUnit::Day,
+            context,
+        )?;
+
+        // 5. If CompareISODate(temporalDate.[[ISODate]], other.[[ISODate]]) = 0, then
+        //     a. Return ! CreateTemporalDuration(0, 0, 0, 0, 0, 0, 0, 0, 0, 0).
+        // 6. Let dateDifference be CalendarDateUntil(calendar, temporalDate.[[ISODate]], other.[[ISODate]], settings.[[LargestUnit]]).
+        // 7. Let duration be CombineDateAndTimeDuration(dateDifference, 0).
+        let mut duration = InternalDuration::new(date.until(other, settings.largest_unit), 0);
+
+        // 8. If settings.[[SmallestUnit]] is not day or settings.[[RoundingIncrement]] ≠ 1, then
+        if date != other
+            && (settings.smallest_unit != TemporalUnit::Day || settings.rounding_increment != 1)
+        {
+            // a. Let isoDateTime be CombineISODateAndTimeRecord(temporalDate.[[ISODate]], MidnightTimeRecord()).
+            // b. Let isoDateTimeOther be CombineISODateAndTimeRecord(other.[[ISODate]], MidnightTimeRecord()).
+            // c. Let destEpochNs be GetUTCEpochNanoseconds(isoDateTimeOther).
+            // d. Set duration to ? RoundRelativeDuration(duration, destEpochNs, isoDateTime, unset, calendar, settings.[[LargestUnit]], settings.[[RoundingIncrement]], settings.[[SmallestUnit]], settings.[[RoundingMode]]).
+            let dest = IsoDateTime::new(other, IsoTime::default()).to_epoch_nanoseconds();
+            let relative = RelativeTo {
+                date_time: IsoDateTime::new(date, IsoTime::default()),
+                time_zone: None,
+            };
+            duration = round_relative_duration(duration, dest, relative, settings, context)?;
+        }
+
+        // 9. Let result be ! TemporalDurationFromInternal(duration, day).
+        // 10. If operation is since, set result to CreateNegatedTemporalDuration(result).
+        // 11. Return result.
+        Ok(
+            duration_from_difference(duration, TemporalUnit::Day, since)?
+                .create(None, context)?
+                .into(),
+        )
+    }
+
+    /// `Temporal.PlainDate.prototype.equals ( other )`
+    fn equals(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        let date = Self::this_date(this, context)?;
+        let other = to_temporal_date(args.get_or_undefined(0), &JsValue::undefined(), context)?;
+        Ok((date == other).into())
+    }
+
+    /// `Temporal.PlainDate.prototype.toPlainDateTime ( [ temporalTime ] )`
+    fn to_plain_date_time(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        let date = Self::this_date(this, context)?;
+
+        // 3. Let time be ? ToTimeRecordOrMidnight(temporalTime).
+        let time = args.get_or_undefined(0);
+        let time = if time.is_undefined() {
+            IsoTime::default()
+        } else {
+            to_temporal_time(time, &JsValue::undefined(), context)?
+        };
+
+        // 4. Let isoDateTime be CombineISODateAndTimeRecord(temporalDate.[[ISODate]], time).
+        // 5. Return ? CreateTemporalDateTime(isoDateTime, temporalDate.[[Calendar]]).
+        let date_time = IsoDateTime::new(date, time).check_limits()?;
+        Ok(PlainDateTime::new(date_time).create(None, context)?.into())
+    }
+
+    /// `Temporal.PlainDate.prototype.toZonedDateTime ( item )`
+    fn to_zoned_date_time(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. Let temporalDate be the this value.
+        // 2. Perform ? RequireInternalSlot(temporalDate, [[InitializedTemporalDate]]).
+        let date = Self::this_date(this, context)?;
+        let item = args.get_or_undefined(0);
+
+        // 3. If item is an Object, then
+        let (time_zone, time) = if let Some(object) = item.as_object() {
+            // a. Let timeZoneLike be ? Get(item, "timeZone").
+            let time_zone_like = object.get(utf16!("timeZone"), context)?;
+            if time_zone_like.is_undefined() {
+                // b. If timeZoneLike is undefined, then
+                //     i. Let timeZone be ? ToTemporalTimeZoneIdentifier(item).
+                //     ii. Let temporalTime be undefined.
+                (to_time_zone_slot(item, context)?, JsValue::undefined())
+            } else {
+                // c. Else,
+                //     i. Let timeZone be ? ToTemporalTimeZoneIdentifier(timeZoneLike).
+                //     ii. Let temporalTime be ? Get(item, "plainTime").
+                let time_zone = to_time_zone_slot(&time_zone_like, context)?;
+                (time_zone, object.get(utf16!("plainTime"), context)?)
+            }
+        } else {
+            // 4. Else,
+            //     a. Let timeZone be ? ToTemporalTimeZoneIdentifier(item).
+            //     b. Let temporalTime be undefined.
+            (to_time_zone_slot(item, context)?, JsValue::undefined())
+        };
+
+        // 5. If temporalTime is undefined, then
+        let epoch_ns = if time.is_undefined() {
+            // a. Let epochNs be ? GetStartOfDay(timeZone, temporalDate.[[ISODate]]).
+            time_zone.start_of_day(date, context)?
+        } else {
+            // 6. Else,
+            //     a. Set temporalTime to ? ToTemporalTime(temporalTime).
+            let time = to_temporal_time(&time, &JsValue::undefined(), context)?;
+            // b. Let isoDateTime be CombineISODateAndTimeRecord(temporalDate.[[ISODate]], temporalTime.[[Time]]).
+            // c. If ISODateTimeWithinLimits(isoDateTime) is false, throw a RangeError exception.
+            let date_time = IsoDateTime::new(date, time).check_limits()?;
+            // d. Let epochNs be ? GetEpochNanosecondsFor(timeZone, isoDateTime, compatible).
+            time_zone.epoch_nanoseconds_for(date_time, Disambiguation::Compatible, context)?
+        };
+
+        // 7. Return ! CreateTemporalZonedDateTime(epochNs, timeZone, temporalDate.[[Calendar]]).
+        Ok(ZonedDateTime::new(epoch_ns, time_zone)
+            .create(None, context)?
+            .into())
+    }
+
+    /// `Temporal.PlainDate.prototype.toString ( [ options ] )`
+    fn to_string(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        let date = Self::this_date(this, context)?;
+        let options = get_options_object(args.get_or_undefined(0))?;
+        let show_calendar = get_show_calendar(&options, context)?;
+        Ok(js_string!(format!(
+            "{}{}",
+            format_date(date),
+            format_calendar_annotation(show_calendar)
+        ))
+        .into())
+    }
+
+    /// `Temporal.PlainDate.prototype.toJSON ( )` and
+    /// `Temporal.PlainDate.prototype.toLocaleString ( [ locales [ , options ] ] )`
+    fn to_json(this: &JsValue, _: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        let date = Self::this_date(this, context)?;
+        Ok(js_string!(format_date(date)).into())
+    }
+}
+
+impl HasIsoDate for PlainDate {
+    fn this_date(this: &JsValue, _: &Context<'_>) -> JsResult<IsoDate> {
+        this.as_object()
+            .and_then(|object| object.borrow().as_plain_date().map(|date| date.date))
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("'this' is not a Temporal.PlainDate object")
+                    .into()
+            })
+    }
+}
+
+/// A Temporal class whose objects have an ISO date, sharing the date getters of `PlainDate`.
+pub(crate) trait HasIsoDate {
+    /// Returns the date of the `this` value, or throws a `TypeError` if it is not an object of
+    /// this class.
+    fn this_date(this: &JsValue, context: &Context<'_>) -> JsResult<IsoDate>;
+}
+
+/// Returns the getters of the date properties of the class `T`.
+pub(crate) fn date_getters<T: HasIsoDate>() -> [(&'static str, NativeFunctionPointer); 14] {
+    [
+        ("calendarId", get_calendar_id::<T>),
+        ("year", get_year::<T>),
+        ("month", get_month::<T>),
+        ("monthCode", get_month_code::<T>),
+        ("day", get_day::<T>),
+        ("dayOfWeek", get_day_of_week::<T>),
+        ("dayOfYear", get_day_of_year::<T>),
+        ("weekOfYear", get_week_of_year::<T>),
+        ("yearOfWeek", get_year_of_week::<T>),
+        ("daysInWeek", get_days_in_week::<T>),
+        ("daysInMonth", get_days_in_month::<T>),
+        ("daysInYear", get_days_in_year::<T>),
+        ("monthsInYear", get_months_in_year::<T>),
+        ("inLeapYear", get_in_leap_year::<T>),
+    ]
+}
+
+/// `get Temporal.*.prototype.calendarId`
+fn get_calendar_id<T: HasIsoDate>(
+    this: &JsValue,
+    _: &[JsValue],
+    context: &mut Context<'_>,
+) -> JsResult<JsValue> {
+    T::this_date(this, context)?;
+    Ok(js_string!(ISO8601).into())
+}
+
+/// `get Temporal.*.prototype.year`
+fn get_year<T: HasIsoDate>(
+    this: &JsValue,
+    _: &[JsValue],
+    context: &mut Context<'_>,
+) -> JsResult<JsValue> {
+    Ok(T::this_date(this, context)?.year.into())
+}
+
+/// `get Temporal.*.prototype.month`
+fn get_month<T: HasIsoDate>(
+    this: &JsValue,
+    _: &[JsValue],
+    context: &mut Context<'_>,
+) -> JsResult<JsValue> {
+    Ok(T::this_date(this, context)?.month.into())
+}
+
+/// `get Temporal.*.prototype.monthCode`
+fn get_month_code<T: HasIsoDate>(
+    this: &JsValue,
+    _: &[JsValue],
+    context: &mut Context<'_>,
+) -> JsResult<JsValue> {
+    let month = T::this_date(this, context)?.month;
+    Ok(js_string!(format!("M{month:02}")).into())
+}
+
+/// `get Temporal.*.prototype.day`
+fn get_day<T: HasIsoDate>(
+    this: &JsValue,
+    _: &[JsValue],
+    context: &mut Context<'_>,
+) -> JsResult<JsValue> {
+    Ok(T::this_date(this, context)?.day.into())
+}
+
+/// `get Temporal.*.prototype.dayOfWeek`
+fn get_day_of_week<T: HasIsoDate>(
+    this: &JsValue,
+    _: &[JsValue],
+    context: &mut Context<'_>,
+) -> JsResult<JsValue> {
+    Ok(T::this_date(this, context)?.day_of_week().into())
+}
+
+/// `get Temporal.*.prototype.dayOfYear`
+fn get_day_of_year<T: HasIsoDate>(
+    this: &JsValue,
+    _: &[JsValue],
+    context: &mut Context<'_>,
+) -> JsResult<JsValue> {
+    Ok(T::this_date(this, context)?.day_of_year().into())
+}
+
+/// `get Temporal.*.prototype.weekOfYear`
+fn get_week_of_year<T: HasIsoDate>(
+    this: &JsValue,
+    _: &[JsValue],
+    context: &mut Context<'_>,
+) -> JsResult<JsValue> {
+    Ok(T::this_date(this, context)?.week_of_year().1.into())
+}
+
+/// `get Temporal.*.prototype.yearOfWeek`
+fn get_year_of_week<T: HasIsoDate>(
+    this: &JsValue,
+    _: &[JsValue],
+    context: &mut Context<'_>,
+) -> JsResult<JsValue> {
+    Ok(T::this_date(this, context)?.week_of_year().0.into())
+}
+
+/// `get Temporal.*.prototype.daysInWeek`
+fn get_days_in_week<T: HasIsoDate>(
+    this: &JsValue,
+    _: &[JsValue],
+    context: &mut Context<'_>,
+) -> JsResult<JsValue> {
+    T::this_date(this, context)?;
+    Ok(7.into())
+}
+
+/// `get Temporal.*.prototype.daysInMonth`
+fn get_days_in_month<T: HasIsoDate>(
+    this: &JsValue,
+    _: &[JsValue],
+    context: &mut Context<'_>,
+) -> JsResult<JsValue> {
+    let date = T::this_date(this, context)?;
+    Ok(days_in_month(date.year, date.month).into())
+}
+
+/// `get Temporal.*.prototype.daysInYear`
+fn get_days_in_year<T: HasIsoDate>(
+    this: &JsValue,
+    _: &[JsValue],
+    context: &mut Context<'_>,
+) -> JsResult<JsValue> {
+    Ok(days_in_year(T::this_date(this, context)?.year).into())
+}
+
+/// `get Temporal.*.prototype.monthsInYear`
+fn get_months_in_year<T: HasIsoDate>(
+    this: &JsValue,
+    _: &[JsValue],
+    context: &mut Context<'_>,
+) -> JsResult<JsValue> {
+    T::this_date(this, context)?;
+    Ok(12.into())
+}
+
+/// `get Temporal.*.prototype.inLeapYear`
+fn get_in_leap_year<T: HasIsoDate>(
+    this: &JsValue,
+    _: &[JsValue],
+    context: &mut Context<'_>,
+) -> JsResult<JsValue> {
+    Ok(is_leap_year(T::this_date(this, context)?.year).into())
+}
+
+/// Checks the optional `calendar` argument of the constructors of the Temporal classes.
+pub(crate) fn check_calendar_argument(calendar: &JsValue) -> JsResult<()> {
+    if calendar.is_undefined() {
+        return Ok(());
+    }
+    let Some(calendar) = calendar.as_string() else {
+        return Err(JsNativeError::typ()
+            .with_message("the calendar must be a string")
+            .into());
+    };
+    canonicalize_calendar(&calendar.to_std_string_escaped())
+}
+
+/// Abstract operation [`ToDateDurationRecordWithoutTime ( duration )`][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-todatedurationrecordwithouttime
+pub(crate) fn date_duration_without_time(duration: &Duration) -> DateDuration {
+    // 1. Let internalDuration be ToInternalDurationRecordWith24HourDays(duration).
+    let internal = duration.to_internal_24h();
+
+    // 2. Let days be truncate(internalDuration.[[Time]] / nsPerDay).
+    // 3. Return ! CreateDateDurationRecord(internalDuration.[[Date]].[[Years]], internalDuration.[[Date]].[[Months]], internalDuration.[[Date]].[[Weeks]], days).
+    DateDuration {
+        days: (internal.time / super::iso::NS_PER_DAY) as i64,
+        ..internal.date
+    }
+}
+
+/// Abstract operation [`TemporalDateToString ( temporalDate, showCalendar )`][spec], without the
+/// calendar annotation.
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-temporaldatetostring
+pub(crate) fn format_date(date: IsoDate) -> String {
+    format!(
+        "{}-{:02}-{:02}",
+        pad_iso_year(date.year),
+        date.month,
+        date.day
+    )
+}
+
+/// Abstract operation [`ToTemporalDate ( item [ , options ] )`][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-totemporaldate
+pub(crate) fn to_temporal_date(
+    item: &JsValue,
+    options: &JsValue,
+    context: &mut Context<'_>,
+) -> JsResult<IsoDate> {
+    // 2. If item is an Object, then
+    if let Some(object) = item.as_object() {
+        // a-c. If item is a PlainDate, a ZonedDateTime or a PlainDateTime, return its date.
+        let date = {
+            let borrowed = object.borrow();
+            if let Some(date) = borrowed.as_plain_date() {
+                Some(date.date)
+            } else if let Some(date_time) = borrowed.as_plain_date_time() {
+                Some(date_time.date_time.date)
+            } else {
+                borrowed.as_zoned_date_time().map(|zoned| {
+                    zoned
+                        .time_zone
+                        .iso_date_time_for(zoned.epoch_ns, context)
+                        .date
+                })
+            }
+        };
+        if let Some(date) = date {
+            // i. Let resolvedOptions be ? GetOptionsObject(options).
+            // ii. Perform ? GetTemporalOverflowOption(resolvedOptions).
+            get_overflow(&get_options_object(options)?, context)?;
+            return Ok(date);
+        }
+
+        // d. Let calendar be ? GetTemporalCalendarIdentifierWithISODefault(item).
+        get_calendar_with_iso_default(object, context)?;
+
+        // e. Let fields be ? PrepareCalendarFields(calendar, item, « year, month, month-code, day », «», «»).
+        let fields = Fields::prepare(object, FieldNames::DATE, RequiredFields::None, context)?;
+
+        // f. Let resolvedOptions be ? GetOptionsObject(options).
+        // g. Let overflow be ? GetTemporalOverflowOption(resolvedOptions).
+        let overflow = get_overflow(&get_options_object(options)?, context)?;
+
+        // h. Let isoDate be ? CalendarDateFromFields(calendar, fields, overflow).
+        // i. Return ! CreateTemporalDate(isoDate, calendar).
+        return fields.to_date(overflow);
+    }
+
+    // 3. If item is not a String, throw a TypeError exception.
+    let string = require_string(item, "a date")?;
+
+    // 4. Let result be ? ParseISODateTime(item, « TemporalDateTimeString[~Zoned] »).
+    let parsed = parse_date_time(&string).ok_or_else(|| invalid_string("date", &string))?;
+    if parsed.offset == Some(ParsedOffset::Z) {
+        return Err(invalid_string("date", &string).into());
+    }
+
+    // 5-8. Let calendar be ? CanonicalizeCalendar(result.[[Calendar]]), or "iso8601" if it is empty.
+    check_calendar_annotation(parsed.calendar.as_deref())?;
+
+    // 9. Let resolvedOptions be ? GetOptionsObject(options).
+    // 10. Perform ? GetTemporalOverflowOption(resolvedOptions).
+    get_overflow(&get_options_object(options)?, context)?;
+
+    // 11. Let isoDate be CreateISODateRecord(result.[[Year]], result.[[Month]], result.[[Day]]).
+    // 12. Return ? CreateTemporalDate(isoDate, calendar).
+    parsed.date.check_limits()
+}
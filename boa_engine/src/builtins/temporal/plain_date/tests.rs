@@ -0,0 +1,103 @@
+use crate::{js_string, run_test_actions, JsNativeErrorKind, TestAction};
+
+#[test]
+fn constructor() {
+    run_test_actions([
+        TestAction::run("let d = new Temporal.PlainDate(2024, 2, 29)"),
+        TestAction::assert_eq("d.toString()", js_string!("2024-02-29")),
+        TestAction::assert_eq("d.calendarId", js_string!("iso8601")),
+        TestAction::assert_eq("d.monthCode", js_string!("M02")),
+        TestAction::assert_eq("d.dayOfWeek", 4),
+        TestAction::assert_eq("d.dayOfYear", 60),
+        TestAction::assert_eq("d.daysInMonth", 29),
+        TestAction::assert("d.inLeapYear"),
+        TestAction::assert_native_error(
+            "new Temporal.PlainDate(2023, 2, 29)",
+            JsNativeErrorKind::Range,
+            "day out of range for the month",
+        ),
+    ]);
+}
+
+#[test]
+fn week_of_year() {
+    run_test_actions([
+        TestAction::assert_eq("Temporal.PlainDate.from('2021-01-03').weekOfYear", 53),
+        TestAction::assert_eq("Temporal.PlainDate.from('2021-01-03').yearOfWeek", 2020),
+        TestAction::assert_eq("Temporal.PlainDate.from('2024-12-30').weekOfYear", 1),
+        TestAction::assert_eq("Temporal.PlainDate.from('2024-12-30').yearOfWeek", 2025),
+    ]);
+}
+
+#[test]
+fn from() {
+    run_test_actions([
+        TestAction::assert_eq(
+            "Temporal.PlainDate.from({ year: 2023, month: 2, day: 31 }).toString()",
+            js_string!("2023-02-28"),
+        ),
+        TestAction::assert_eq(
+            "Temporal.PlainDate.from({ year: 2023, monthCode: 'M03', day: 1 }).toString()",
+            js_string!("2023-03-01"),
+        ),
+        TestAction::assert_eq(
+            "Temporal.PlainDate.from('2023-03-01T10:00[u-ca=iso8601]').toString()",
+            js_string!("2023-03-01"),
+        ),
+        TestAction::assert_native_error(
+            "Temporal.PlainDate.from({ year: 2023, month: 2, day: 31 }, { overflow: 'reject' })",
+            JsNativeErrorKind::Range,
+            "day out of range for the month",
+        ),
+        TestAction::assert_native_error(
+            "Temporal.PlainDate.from('2023-03-01[u-ca=gregory]')",
+            JsNativeErrorKind::Range,
+            "unsupported calendar `gregory`",
+        ),
+    ]);
+}
+
+#[test]
+fn arithmetic() {
+    run_test_actions([
+        TestAction::run("let d = Temporal.PlainDate.from('2023-01-31')"),
+        TestAction::assert_eq("d.add({ months: 1 }).toString()", js_string!("2023-02-28")),
+        TestAction::assert_native_error(
+            "d.add({ months: 1 }, { overflow: 'reject' })",
+            JsNativeErrorKind::Range,
+            "day out of range for the month",
+        ),
+        TestAction::assert_eq("d.subtract('P1Y1D').toString()", js_string!("2022-01-30")),
+        TestAction::assert_eq("d.with({ day: 1 }).toString()", js_string!("2023-01-01")),
+        TestAction::assert_eq(
+            "d.until('2024-03-01', { largestUnit: 'year' }).toString()",
+            js_string!("P1Y1M1D"),
+        ),
+        TestAction::assert_eq("d.since('2023-01-01').toString()", js_string!("P30D")),
+        TestAction::assert_eq(
+            "d.until('2023-03-15', { smallestUnit: 'month', roundingMode: 'halfExpand' }).toString()",
+            js_string!("P1M"),
+        ),
+    ]);
+}
+
+#[test]
+fn conversions() {
+    run_test_actions([
+        TestAction::run("let d = Temporal.PlainDate.from('2023-06-15')"),
+        TestAction::assert_eq(
+            "d.toPlainDateTime('12:30').toString()",
+            js_string!("2023-06-15T12:30:00"),
+        ),
+        TestAction::assert_eq(
+            "d.toZonedDateTime('UTC').toString()",
+            js_string!("2023-06-15T00:00:00+00:00[UTC]"),
+        ),
+        TestAction::assert_eq(
+            "d.toString({ calendarName: 'always' })",
+            js_string!("2023-06-15[u-ca=iso8601]"),
+        ),
+        TestAction::assert("d.equals({ year: 2023, month: 6, day: 15 })"),
+        TestAction::assert_eq("Temporal.PlainDate.compare(d, '2023-06-16')", -1),
+    ]);
+}
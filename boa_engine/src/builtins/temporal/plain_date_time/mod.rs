@@ -0,0 +1,667 @@
+//! Boa's implementation of the `Temporal.PlainDateTime` builtin object.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/proposal-temporal/#sec-temporal-plaindatetime-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/PlainDateTime
+
+use boa_gc::{empty_trace, Finalize, Trace};
+use boa_profiler::Profiler;
+
+use crate::{
+    builtins::{
+        options::{get_option, get_options_object},
+        BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject,
+    },
+    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    js_string,
+    object::{JsObject, ObjectData},
+    property::Attribute,
+    realm::Realm,
+    string::utf16,
+    symbol::JsSymbol,
+    Context, JsArgs, JsNativeError, JsResult, JsValue,
+};
+
+use super::{
+    calendar::{
+        check_calendar_annotation, format_calendar_annotation, get_calendar,
+        get_calendar_with_iso_default, get_show_calendar, to_calendar_identifier,
+    },
+    constructor_without_new, create_temporal_object,
+    duration::{
+        duration_from_difference, options_or_unit, ordering_to_value, to_temporal_duration,
+        Duration,
+    },
+    fields::{to_partial_object, FieldNames, Fields, RequiredFields},
+    format_time_string, invalid_string,
+    iso::{DateDuration, IsoDate, IsoDateTime, IsoTime},
+    options::{
+        get_difference_settings, get_overflow, get_required_temporal_unit, get_rounding_increment,
+        get_string_precision_options, validate_rounding_increment, Overflow, Precision,
+        RoundingMode, TemporalUnit, UnitGroup,
+    },
+    parser::{parse_date_time, ParsedOffset},
+    plain_date::{check_calendar_argument, date_getters, format_date, HasIsoDate, PlainDate},
+    plain_time::{time_getters, to_temporal_time, to_time_arguments, HasIsoTime, PlainTime},
+    require_string,
+    rounding::difference_date_time_with_rounding,
+    time_zone::to_time_zone_slot,
+    to_integer_with_truncation, value_of, with_getters,
+    zoned_date_time::ZonedDateTime,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The `Temporal.PlainDateTime` builtin object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PlainDateTime {
+    pub(crate) date_time: IsoDateTime,
+}
+
+impl Finalize for PlainDateTime {}
+
+// SAFETY: `PlainDateTime` doesn't contain any traceable data.
+unsafe impl Trace for PlainDateTime {
+    empty_trace!();
+}
+
+impl IntrinsicObject for PlainDateTime {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        let builder = BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .static_method(Self::from, "from", 1)
+            .static_method(Self::compare, "compare", 2)
+            .property(
+                JsSymbol::to_string_tag(),
+                "Temporal.PlainDateTime",
+                Attribute::CONFIGURABLE,
+            )
+            .method(Self::get_calendar, "getCalendar", 0)
+            .method(Self::with, "with", 1)
+            .method(Self::with_plain_time, "withPlainTime", 0)
+            .method(Self::with_calendar, "withCalendar", 1)
+            .method(Self::add, "add", 1)
+            .method(Self::subtract, "subtract", 1)
+            .method(Self::until, "until", 1)
+            .method(Self::since, "since", 1)
+            .method(Self::round, "round", 1)
+            .method(Self::equals, "equals", 1)
+            .method(Self::to_string, "toString", 0)
+            .method(Self::to_json, "toJSON", 0)
+            .method(Self::to_json, "toLocaleString", 0)
+            .method(value_of, "valueOf", 0)
+            .method(Self::to_zoned_date_time, "toZonedDateTime", 1)
+            .method(Self::to_plain_date, "toPlainDate", 0)
+            .method(Self::to_plain_time, "toPlainTime", 0);
+
+        let builder = with_getters(builder, realm, &date_getters::<Self>());
+        with_getters(builder, realm, &time_getters::<Self>()).build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for PlainDateTime {
+    const NAME: &'static str = "PlainDateTime";
+}
+
+impl BuiltInConstructor for PlainDateTime {
+    const LENGTH: usize = 3;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::plain_date_time;
+
+    /// Constructor [`Temporal.PlainDateTime ( isoYear, isoMonth, isoDay [ , hour [ , minute [ , second [ , millisecond [ , microsecond [ , nanosecond [ , calendar ] ] ] ] ] ] ] )`][spec].
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(constructor_without_new(Self::NAME).into());
+        }
+
+        // 2. Set isoYear to ? ToIntegerWithTruncation(isoYear).
+        let year = to_integer_with_truncation(args.get_or_undefined(0), context)?;
+        // 3. Set isoMonth to ? ToIntegerWithTruncation(isoMonth).
+        let month = to_integer_with_truncation(args.get_or_undefined(1), context)?;
+        // 4. Set isoDay to ? ToIntegerWithTruncation(isoDay).
+        let day = to_integer_with_truncation(args.get_or_undefined(2), context)?;
+
+        // 5-10. Let each time field be ? ToIntegerWithTruncation(field), or 0 if it is undefined.
+        let time = to_time_arguments(args.get(3..).unwrap_or_default(), context)?;
+
+        // 11. If calendar is undefined, set calendar to "iso8601".
+        // 12. If calendar is not a String, throw a TypeError exception.
+        // 13. Set calendar to ? CanonicalizeCalendar(calendar).
+        check_calendar_argument(args.get_or_undefined(9))?;
+
+        // 14. If IsValidISODate(isoYear, isoMonth, isoDay) is false, throw a RangeError exception.
+        let date = IsoDate::regulate(year, month, day, Overflow::Reject)?;
+
+        // 16. If IsValidTime(hour, minute, second, millisecond, microsecond, nanosecond) is false, throw a RangeError exception.
+        let time = IsoTime::regulate(time, Overflow::Reject)?;
+
+        // 18. Let isoDateTime be CombineISODateAndTimeRecord(isoDate, timeRecord).
+        // 19. Return ? CreateTemporalDateTime(isoDateTime, calendar, NewTarget).
+        let date_time = IsoDateTime::new(date, time).check_limits()?;
+        Ok(Self::new(date_time)
+            .create(Some(new_target), context)?
+            .into())
+    }
+}
+
+impl PlainDateTime {
+    /// Creates a new plain date-time.
+    pub(crate) const fn new(date_time: IsoDateTime) -> Self {
+        Self { date_time }
+    }
+
+    /// Creates a new `Temporal.PlainDateTime` object with this data.
+    pub(crate) fn create(
+        self,
+        new_target: Option<&JsValue>,
+        context: &mut Context<'_>,
+    ) -> JsResult<JsObject> {
+        create_temporal_object(
+            ObjectData::plain_date_time(self),
+            new_target,
+            StandardConstructors::plain_date_time,
+            context,
+        )
+    }
+
+    /// Gets the date-time of the `this` value.
+    fn this_date_time(this: &JsValue) -> JsResult<IsoDateTime> {
+        this.as_object()
+            .and_then(|object| {
+                object
+                    .borrow()
+                    .as_plain_date_time()
+                    .map(|date_time| date_time.date_time)
+            })
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("'this' is not a Temporal.PlainDateTime object")
+                    .into()
+            })
+    }
+
+    /// `Temporal.PlainDateTime.from ( item [ , options ] )`
+    fn from(_: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        let date_time =
+            to_temporal_date_time(args.get_or_undefined(0), args.get_or_undefined(1), context)?;
+        Ok(Self::new(date_time).create(None, context)?.into())
+    }
+
+    /// `Temporal.PlainDateTime.compare ( one, two )`
+    fn compare(_: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        let undefined = JsValue::undefined();
+        let one = to_temporal_date_time(args.get_or_undefined(0), &undefined, context)?;
+        let two = to_temporal_date_time(args.get_or_undefined(1), &undefined, context)?;
+        Ok(ordering_to_value(one.cmp(&two)))
+    }
+
+    /// `Temporal.PlainDateTime.prototype.getCalendar ( )`
+    fn get_calendar(this: &JsValue, _: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        Self::this_date_time(this)?;
+        get_calendar(context)
+    }
+
+    /// `Temporal.PlainDateTime.prototype.with ( temporalDateTimeLike [ , options ] )`
+    fn with(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Let dateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(dateTime, [[InitializedTemporalDateTime]]).
+        let date_time = Self::this_date_time(this)?;
+
+        // 3. If ? IsPartialTemporalObject(temporalDateTimeLike) is false, throw a TypeError exception.
+        let partial = to_partial_object(args.get_or_undefined(0), context)?;
+
+        // 4. Let calendar be dateTime.[[Calendar]].
+        // 5. Let fields be ISODateToFields(calendar, dateTime.[[ISODateTime]].[[ISODate]], date).
+        // 6-11. Set the time fields of fields to the time of dateTime.
+        let fields = Fields::from_date(date_time.date).with_time(date_time.time);
+
+        // 12. Let partialDateTime be ? PrepareCalendarFields(calendar, temporalDateTimeLike, « year, month, month-code, day », « hour, minute, second, millisecond, microsecond, nanosecond », partial).
+        let partial = Fields::prepare(
+            &partial,
+            FieldNames::DATE_TIME,
+            RequiredFields::Partial,
+            context,
+        )?;
+
+        // 13. Set fields to CalendarMergeFields(calendar, fields, partialDateTime).
+        let fields = fields.merge(partial);
+
+        // 14. Let resolvedOptions be ? GetOptionsObject(options).
+        // 15. Let overflow be ? GetTemporalOverflowOption(resolvedOptions).
+        let overflow = get_overflow(&get_options_object(args.get_or_undefined(1))?, context)?;
+
+        // 16. Let result be ? InterpretTemporalDateTimeFields(calendar, fields, overflow).
+        // 17. Return ? CreateTemporalDateTime(result, calendar).
+        let date_time = interpret_date_time_fields(&fields, overflow)?;
+        Ok(Self::new(date_time).create(None, context)?.into())
+    }
+
+    /// `Temporal.PlainDateTime.prototype.withPlainTime ( [ plainTimeLike ] )`
+    fn with_plain_time(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        let date_time = Self::this_date_time(this)?;
+
+        // 3. Let time be ? ToTimeRecordOrMidnight(plainTimeLike).
+        let time = args.get_or_undefined(0);
+        let time = if time.is_undefined() {
+            IsoTime::default()
+        } else {
+            to_temporal_time(time, &JsValue::undefined(), context)?
+        };
+
+        // 4. Let isoDateTime be CombineISODateAndTimeRecord(dateTime.[[ISODateTime]].[[ISODate]], time).
+        // 5. Return ? CreateTemporalDateTime(isoDateTime, dateTime.[[Calendar]]).
+        let date_time = IsoDateTime::new(date_time.date, time).check_limits()?;
+        Ok(Self::new(date_time).create(None, context)?.into())
+    }
+
+    /// `Temporal.PlainDateTime.prototype.withCalendar ( calendarLike )`
+    fn with_calendar(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        let date_time = Self::this_date_time(this)?;
+        to_calendar_identifier(args.get_or_undefined(0), context)?;
+        Ok(Self::new(date_time).create(None, context)?.into())
+    }
+
+    /// `Temporal.PlainDateTime.prototype.add ( temporalDurationLike [ , options ] )`
+    fn add(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        let date_time = Self::this_date_time(this)?;
+        let duration = to_temporal_duration(args.get_or_undefined(0), context)?;
+        Self::add_duration(date_time, &duration, args.get_or_undefined(1), context)
+    }
+
+    /// `Temporal.PlainDateTime.prototype.subtract ( temporalDurationLike [ , options ] )`
+    fn subtract(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        let date_time = Self::this_date_time(this)?;
+        let duration = to_temporal_duration(args.get_or_undefined(0), context)?;
+        Self::add_duration(
+            date_time,
+            &duration.negated(),
+            args.get_or_undefined(1),
+            context,
+        )
+    }
+
+    /// Abstract operation [`AddDurationToDateTime ( operation, dateTime, temporalDurationLike, options )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-adddurationtodatetime
+    fn add_duration(
+        date_time: IsoDateTime,
+        duration: &Duration,
+        options: &JsValue,
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 3. Let resolvedOptions be ? GetOptionsObject(options).
+        // 4. Let overflow be ? GetTemporalOverflowOption(resolvedOptions).
+        let overflow = get_overflow(&get_options_object(options)?, context)?;
+
+        // 5. Let internalDuration be ToInternalDurationRecordWith24HourDays(duration).
+        let internal = duration.to_internal_24h();
+
+        // 6. Let timeResult be AddTime(dateTime.[[ISODateTime]].[[Time]], internalDuration.[[Time]]).
+        let (days, time) = IsoTime::balance(date_time.time.to_nanoseconds() + internal.time);
+
+        // 7. Let dateDuration be ? AdjustDateDurationRecord(internalDuration.[[Date]], timeResult.[[Days]]).
+        let date_duration = DateDuration {
+            days,
+            ..internal.date
+        };
+
+        // 8. Let addedDate be ? CalendarDateAdd(dateTime.[[Calendar]], dateTime.[[ISODateTime]].[[ISODate]], dateDuration, overflow).
+        let date = date_time.date.add(date_duration, overflow)?;
+
+        // 9. Let result be CombineISODateAndTimeRecord(addedDate, timeResult).
+        // 10. Return ? CreateTemporalDateTime(result, dateTime.[[Calendar]]).
+        let date_time = IsoDateTime::new(date, time).check_limits()?;
+        Ok(Self::new(date_time).create(None, context)?.into())
+    }
+
+    /// `Temporal.PlainDateTime.prototype.until ( other [ , options ] )`
+    fn until(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        Self::difference(false, this, args, context)
+    }
+
+    /// `Temporal.PlainDateTime.prototype.since ( other [ , options ] )`
+    fn since(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        Self::difference(true, this, args, context)
+    }
+
+    /// Abstract operation [`DifferenceTemporalPlainDateTime ( operation, dateTime, other, options )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-differencetemporalplaindatetime
+    fn difference(
+        since: bool,
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        let date_time = Self::this_date_time(this)?;
+
+        // 1. Set other to ? ToTemporalDateTime(other).
+        let other =
+            to_temporal_date_time(args.get_or_undefined(0), &JsValue::undefined(), context)?;
+
+        // 2. If CalendarEquals(dateTime.[[Calendar]], other.[[Calendar]]) is false, throw a RangeError exception.
+        // 3. Let resolvedOptions be ? GetOptionsObject(options).
+        // 4. Let settings be ? GetDifferenceSettings(operation, resolvedOptions, datetime, « », nanosecond, day).
+        let settings = get_difference_settings(
+            since,
+            args.get_or_undefined(1),
+            UnitGroup::DateTime,
+            TemporalUnit::Nanosecond,
+            TemporalUnit::Day,
+            context,
+        )?;
+
+        // 5. If CompareISODateTime(dateTime.[[ISODateTime]], other.[[ISODateTime]]) = 0, then
+        //     a. Return ! CreateTemporalDuration(0, 0, 0, 0, 0, 0, 0, 0, 0, 0).
+        // 6. Let internalDuration be ? DifferencePlainDateTimeWithRounding(dateTime.[[ISODateTime]], other.[[ISODateTime]], dateTime.[[Calendar]], settings.[[LargestUnit]], settings.[[RoundingIncrement]], settings.[[SmallestUnit]], settings.[[RoundingMode]]).
+        let internal = difference_date_time_with_rounding(date_time, other, settings, context)?;
+
+        // 7. Let result be ? TemporalDurationFromInternal(internalDuration, settings.[[LargestUnit]]).
+        // 8. If operation is since, set result to CreateNegatedTemporalDuration(result).
+        // 9. Return result.
+        Ok(
+            duration_from_difference(internal, settings.largest_unit, since)?
+                .create(None, context)?
+                .into(),
+        )
+    }
+
+    /// `Temporal.PlainDateTime.prototype.round ( roundTo )`
+    fn round(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Let dateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(dateTime, [[InitializedTemporalDateTime]]).
+        let date_time = Self::this_date_time(this)?;
+
+        // 3-5. Get the options object, or an object with a `smallestUnit` if roundTo is a string.
+        let round_to = options_or_unit(args.get_or_undefined(0), utf16!("smallestUnit"), context)?;
+
+        // 6. NOTE: The following steps read options and perform independent validation in alphabetical order.
+        // 7. Let roundingIncrement be ? GetRoundingIncrementOption(roundTo).
+        let rounding_increment = get_rounding_increment(&round_to, context)?;
+
+        // 8. Let roundingMode be ? GetRoundingModeOption(roundTo, half-expand).
+        let rounding_mode = get_option(&round_to, utf16!("roundingMode"), false, context)?
+            .unwrap_or(RoundingMode::HalfExpand);
+
+        // 9. Let smallestUnit be ? GetTemporalUnitValuedOption(roundTo, "smallestUnit", time, required, « day »).
+        let smallest_unit = get_required_temporal_unit(
+            &round_to,
+            utf16!("smallestUnit"),
+            UnitGroup::Time,
+            Some(TemporalUnit::Day),
+            context,
+        )?;
+
+        // 10. If smallestUnit is day, then
+        //     a. Let maximum be 1.
+        //     b. Let inclusive be true.
+        // 11. Else,
+        //     a. Let maximum be MaximumTemporalDurationRoundingIncrement(smallestUnit).
+        //     b. Assert: maximum is not unset.
+        //     c. Let inclusive be false.
+        // 12. Perform ? ValidateTemporalRoundingIncrement(roundingIncrement, maximum, inclusive).
+        match smallest_unit.maximum_rounding_increment() {
+            Some(maximum) => validate_rounding_increment(rounding_increment, maximum, false)?,
+            None => validate_rounding_increment(rounding_increment, 1, true)?,
+        }
+
+        // 13. If smallestUnit is nanosecond and roundingIncrement = 1, then
+        //     a. Return ! CreateTemporalDateTime(dateTime.[[ISODateTime]], dateTime.[[Calendar]]).
+        // 14. Let result be RoundISODateTime(dateTime.[[ISODateTime]], roundingIncrement, smallestUnit, roundingMode).
+        // 15. Return ? CreateTemporalDateTime(result, dateTime.[[Calendar]]).
+        let date_time =
+            round_date_time(date_time, rounding_increment, smallest_unit, rounding_mode)?;
+        Ok(Self::new(date_time).create(None, context)?.into())
+    }
+
+    /// `Temporal.PlainDateTime.prototype.equals ( other )`
+    fn equals(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        let date_time = Self::this_date_time(this)?;
+        let other =
+            to_temporal_date_time(args.get_or_undefined(0), &JsValue::undefined(), context)?;
+        Ok((date_time == other).into())
+    }
+
+    /// `Temporal.PlainDateTime.prototype.toString ( [ options ] )`
+    fn to_string(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Let dateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(dateTime, [[InitializedTemporalDateTime]]).
+        let date_time = Self::this_date_time(this)?;
+
+        // 3. Let resolvedOptions be ? GetOptionsObject(options).
+        let options = get_options_object(args.get_or_undefined(0))?;
+
+        // 4. NOTE: The following steps read options and perform independent validation in alphabetical order.
+        // 5. Let showCalendar be ? GetTemporalShowCalendarNameOption(resolvedOptions).
+        let show_calendar = get_show_calendar(&options, context)?;
+
+        // 6-10. Read the precision options.
+        let (precision, rounding_mode) = get_string_precision_options(&options, context)?;
+
+        // 11. Let result be RoundISODateTime(dateTime.[[ISODateTime]], precision.[[Increment]], precision.[[Unit]], roundingMode).
+        // 12. If ISODateTimeWithinLimits(result) is false, throw a RangeError exception.
+        let date_time = round_date_time(
+            date_time,
+            precision.increment,
+            precision.unit,
+            rounding_mode,
+        )?;
+
+        // 13. Return ISODateTimeToString(result, dateTime.[[Calendar]], precision.[[Precision]], showCalendar).
+        Ok(js_string!(format!(
+            "{}{}",
+            format_date_time(date_time, precision.precision),
+            format_calendar_annotation(show_calendar)
+        ))
+        .into())
+    }
+
+    /// `Temporal.PlainDateTime.prototype.toJSON ( )` and
+    /// `Temporal.PlainDateTime.prototype.toLocaleString ( [ locales [ , options ] ] )`
+    fn to_json(this: &JsValue, _: &[JsValue], _: &mut Context<'_>) -> JsResult<JsValue> {
+        let date_time = Self::this_date_time(this)?;
+        Ok(js_string!(format_date_time(date_time, Precision::Auto)).into())
+    }
+
+    /// `Temporal.PlainDateTime.prototype.toZonedDateTime ( temporalTimeZoneLike [ , options ] )`
+    fn to_zoned_date_time(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        let date_time = Self::this_date_time(this)?;
+
+        // 3. Let timeZone be ? ToTemporalTimeZoneIdentifier(temporalTimeZoneLike).
+        let time_zone = to_time_zone_slot(args.get_or_undefined(0), context)?;
+
+        // 4. Let resolvedOptions be ? GetOptionsObject(options).
+        // 5. Let disambiguation be ? GetTemporalDisambiguationOption(resolvedOptions).
+        let options = get_options_object(args.get_or_undefined(1))?;
+        let disambiguation =
+            get_option(&options, utf16!("disambiguation"), false, context)?.unwrap_or_default();
+
+        // 6. Let epochNs be ? GetEpochNanosecondsFor(timeZone, dateTime.[[ISODateTime]], disambiguation).
+        let epoch_ns = time_zone.epoch_nanoseconds_for(date_time, disambiguation, context)?;
+
+        // 7. Return ! CreateTemporalZonedDateTime(epochNs, timeZone, dateTime.[[Calendar]]).
+        Ok(ZonedDateTime::new(epoch_ns, time_zone)
+            .create(None, context)?
+            .into())
+    }
+
+    /// `Temporal.PlainDateTime.prototype.toPlainDate ( )`
+    fn to_plain_date(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        let date_time = Self::this_date_time(this)?;
+        Ok(PlainDate::new(date_time.date).create(None, context)?.into())
+    }
+
+    /// `Temporal.PlainDateTime.prototype.toPlainTime ( )`
+    fn to_plain_time(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        let date_time = Self::this_date_time(this)?;
+        Ok(PlainTime::new(date_time.time).create(None, context)?.into())
+    }
+}
+
+impl HasIsoDate for PlainDateTime {
+    fn this_date(this: &JsValue, _: &Context<'_>) -> JsResult<IsoDate> {
+        Ok(Self::this_date_time(this)?.date)
+    }
+}
+
+impl HasIsoTime for PlainDateTime {
+    fn this_time(this: &JsValue, _: &Context<'_>) -> JsResult<IsoTime> {
+        Ok(Self::this_date_time(this)?.time)
+    }
+}
+
+/// Abstract operation [`RoundISODateTime ( isoDateTime, increment, unit, roundingMode )`][spec]
+///
+/// Also checks that the result is within the limits of date-times.
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-roundisodatetime
+pub(crate) fn round_date_time(
+    date_time: IsoDateTime,
+    increment: u64,
+    unit: TemporalUnit,
+    mode: RoundingMode,
+) -> JsResult<IsoDateTime> {
+    // 1. Assert: ISODateTimeWithinLimits(isoDateTime) is true.
+    // 2. Let roundedTime be RoundTime(isoDateTime.[[Time]], increment, unit, roundingMode).
+    let (days, time) = date_time.time.round(increment, unit, mode);
+
+    // 3. Let balanceResult be BalanceISODate(isoDateTime.[[ISODate]].[[Year]], isoDateTime.[[ISODate]].[[Month]], isoDateTime.[[ISODate]].[[Day]] + roundedTime.[[Days]]).
+    // 4. Return CombineISODateAndTimeRecord(balanceResult, roundedTime).
+    IsoDateTime::new(date_time.date.add_days(days)?, time).check_limits()
+}
+
+/// Abstract operation [`ISODateTimeToString ( isoDateTime, calendar, precision, showCalendar )`][spec],
+/// without the calendar annotation.
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-isodatetimetostring
+pub(crate) fn format_date_time(date_time: IsoDateTime, precision: Precision) -> String {
+    format!(
+        "{}T{}",
+        format_date(date_time.date),
+        format_time_string(date_time.time, precision)
+    )
+}
+
+/// Abstract operation [`InterpretTemporalDateTimeFields ( calendar, fields, overflow )`][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-interprettemporaldatetimefields
+pub(crate) fn interpret_date_time_fields(
+    fields: &Fields,
+    overflow: Overflow,
+) -> JsResult<IsoDateTime> {
+    // 1. Let isoDate be ? CalendarDateFromFields(calendar, fields, overflow).
+    let date = fields.to_date(overflow)?;
+
+    // 2. Let time be ? RegulateTime(fields.[[Hour]], fields.[[Minute]], fields.[[Second]], fields.[[Millisecond]], fields.[[Microsecond]], fields.[[Nanosecond]], overflow).
+    let time = fields.to_time(overflow)?;
+
+    // 3. Return CombineISODateAndTimeRecord(isoDate, time).
+    IsoDateTime::new(date, time).check_limits()
+}
+
+/// Abstract operation [`ToTemporalDateTime ( item [ , options ] )`][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-totemporaldatetime
+pub(crate) fn to_temporal_date_time(
+    item: &JsValue,
+    options: &JsValue,
+    context: &mut Context<'_>,
+) -> JsResult<IsoDateTime> {
+    // 2. If item is an Object, then
+    if let Some(object) = item.as_object() {
+        // a-c. If item is a PlainDateTime, a ZonedDateTime or a PlainDate, return its date-time.
+        let date_time = {
+            let borrowed = object.borrow();
+            if let Some(date_time) = borrowed.as_plain_date_time() {
+                Some(date_time.date_time)
+            } else if let Some(zoned) = borrowed.as_zoned_date_time() {
+                Some(zoned.time_zone.iso_date_time_for(zoned.epoch_ns, context))
+            } else {
+                borrowed
+                    .as_plain_date()
+                    .map(|date| IsoDateTime::new(date.date, IsoTime::default()))
+            }
+        };
+        if let Some(date_time) = date_time {
+            // i. Let resolvedOptions be ? GetOptionsObject(options).
+            // ii. Perform ? GetTemporalOverflowOption(resolvedOptions).
+            get_overflow(&get_options_object(options)?, context)?;
+            return date_time.check_limits();
+        }
+
+        // d. Let calendar be ? GetTemporalCalendarIdentifierWithISODefault(item).
+        get_calendar_with_iso_default(object, context)?;
+
+        // e. Let fields be ? PrepareCalendarFields(calendar, item, « year, month, month-code, day », « hour, minute, second, millisecond, microsecond, nanosecond », «»).
+        let fields = Fields::prepare(object, FieldNames::DATE_TIME, RequiredFields::None, context)?;
+
+        // f. Let resolvedOptions be ? GetOptionsObject(options).
+        // g. Let overflow be ? GetTemporalOverflowOption(resolvedOptions).
+        let overflow = get_overflow(&get_options_object(options)?, context)?;
+
+        // h. Let result be ? InterpretTemporalDateTimeFields(calendar, fields, overflow).
+        // i. Return ? CreateTemporalDateTime(result, calendar).
+        return interpret_date_time_fields(&fields, overflow);
+    }
+
+    // 3. If item is not a String, throw a TypeError exception.
+    let string = require_string(item, "a date-time")?;
+
+    // 4. Let result be ? ParseISODateTime(item, « TemporalDateTimeString[~Zoned] »).
+    let parsed = parse_date_time(&string).ok_or_else(|| invalid_string("date-time", &string))?;
+    if parsed.offset == Some(ParsedOffset::Z) {
+        return Err(invalid_string("date-time", &string).into());
+    }
+
+    // 5. If result.[[Time]] is start-of-day, let time be MidnightTimeRecord(). Else, let time be result.[[Time]].
+    let time = parsed.time.unwrap_or_default();
+
+    // 6-9. Let calendar be ? CanonicalizeCalendar(result.[[Calendar]]), or "iso8601" if it is empty.
+    check_calendar_annotation(parsed.calendar.as_deref())?;
+
+    // 10. Let resolvedOptions be ? GetOptionsObject(options).
+    // 11. Perform ? GetTemporalOverflowOption(resolvedOptions).
+    get_overflow(&get_options_object(options)?, context)?;
+
+    // 12. Let isoDate be CreateISODateRecord(result.[[Year]], result.[[Month]], result.[[Day]]).
+    // 13. Let isoDateTime be CombineISODateAndTimeRecord(isoDate, time).
+    // 14. Return ? CreateTemporalDateTime(isoDateTime, calendar).
+    IsoDateTime::new(parsed.date, time).check_limits()
+}
@@ -0,0 +1,108 @@
+use crate::{js_string, run_test_actions, JsNativeErrorKind, TestAction};
+
+#[test]
+fn constructor() {
+    run_test_actions([
+        TestAction::run("let dt = new Temporal.PlainDateTime(2020, 3, 14, 15, 9, 26, 535)"),
+        TestAction::assert_eq("dt.toString()", js_string!("2020-03-14T15:09:26.535")),
+        TestAction::assert_eq("dt.year", 2020),
+        TestAction::assert_eq("dt.millisecond", 535),
+        TestAction::assert_eq(
+            "new Temporal.PlainDateTime(2020, 1, 1).toString()",
+            js_string!("2020-01-01T00:00:00"),
+        ),
+        TestAction::assert_native_error(
+            "new Temporal.PlainDateTime(-271821, 4, 18)",
+            JsNativeErrorKind::Range,
+            "date-time is outside the supported range",
+        ),
+    ]);
+}
+
+#[test]
+fn from() {
+    run_test_actions([
+        TestAction::assert_eq(
+            "Temporal.PlainDateTime.from('2020-03-14T15:09').toString()",
+            js_string!("2020-03-14T15:09:00"),
+        ),
+        TestAction::assert_eq(
+            "Temporal.PlainDateTime.from({ year: 2020, month: 3, day: 14, hour: 15 }).toString()",
+            js_string!("2020-03-14T15:00:00"),
+        ),
+        TestAction::assert_eq(
+            "Temporal.PlainDateTime.from(Temporal.PlainDate.from('2020-03-14')).toString()",
+            js_string!("2020-03-14T00:00:00"),
+        ),
+        TestAction::assert_native_error(
+            "Temporal.PlainDateTime.from('2020-03-14T15:09Z')",
+            JsNativeErrorKind::Range,
+            "`2020-03-14T15:09Z` is not a valid date-time string",
+        ),
+    ]);
+}
+
+#[test]
+fn arithmetic() {
+    run_test_actions([
+        TestAction::run("let dt = Temporal.PlainDateTime.from('2020-01-31T23:00')"),
+        TestAction::assert_eq(
+            "dt.add({ months: 1, hours: 2 }).toString()",
+            js_string!("2020-03-01T01:00:00"),
+        ),
+        TestAction::assert_eq(
+            "dt.subtract({ days: 31 }).toString()",
+            js_string!("2019-12-31T23:00:00"),
+        ),
+        TestAction::assert_eq(
+            "dt.with({ hour: 1 }).withPlainTime('12:00').toString()",
+            js_string!("2020-01-31T12:00:00"),
+        ),
+        TestAction::assert_eq(
+            "dt.until('2020-03-01T00:00', { largestUnit: 'month' }).toString()",
+            js_string!("P29DT1H"),
+        ),
+        TestAction::assert_eq(
+            "dt.since('2020-01-30T12:00').toString()",
+            js_string!("P1DT11H"),
+        ),
+    ]);
+}
+
+#[test]
+fn round_and_format() {
+    run_test_actions([
+        TestAction::run("let dt = Temporal.PlainDateTime.from('2020-01-31T23:59:59.999')"),
+        TestAction::assert_eq(
+            "dt.round('second').toString()",
+            js_string!("2020-02-01T00:00:00"),
+        ),
+        TestAction::assert_eq(
+            "dt.round('day').toString()",
+            js_string!("2020-02-01T00:00:00"),
+        ),
+        TestAction::assert_eq(
+            "dt.toString({ smallestUnit: 'minute' })",
+            js_string!("2020-01-31T23:59"),
+        ),
+        TestAction::assert_eq(
+            "dt.toString({ calendarName: 'critical', fractionalSecondDigits: 0 })",
+            js_string!("2020-01-31T23:59:59[!u-ca=iso8601]"),
+        ),
+    ]);
+}
+
+#[test]
+fn conversions() {
+    run_test_actions([
+        TestAction::run("let dt = Temporal.PlainDateTime.from('2020-01-31T23:00')"),
+        TestAction::assert_eq("dt.toPlainDate().toString()", js_string!("2020-01-31")),
+        TestAction::assert_eq("dt.toPlainTime().toString()", js_string!("23:00:00")),
+        TestAction::assert_eq(
+            "dt.toZonedDateTime('-03:00').toString()",
+            js_string!("2020-01-31T23:00:00-03:00[-03:00]"),
+        ),
+        TestAction::assert("dt.equals('2020-01-31T23:00')"),
+        TestAction::assert_eq("Temporal.PlainDateTime.compare(dt, '2020-02-01')", -1),
+    ]);
+}
@@ -0,0 +1,513 @@
+//! Boa's implementation of the `Temporal.PlainTime` builtin object.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/proposal-temporal/#sec-temporal-plaintime-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/PlainTime
+
+use boa_gc::{empty_trace, Finalize, Trace};
+use boa_profiler::Profiler;
+
+use crate::{
+    builtins::{
+        options::{get_option, get_options_object},
+        BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject,
+    },
+    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    js_string,
+    native_function::NativeFunctionPointer,
+    object::{JsObject, ObjectData},
+    property::Attribute,
+    realm::Realm,
+    string::utf16,
+    symbol::JsSymbol,
+    Context, JsArgs, JsNativeError, JsResult, JsValue,
+};
+
+use super::{
+    calendar::check_calendar_annotation,
+    constructor_without_new, create_temporal_object,
+    duration::{
+        duration_from_difference, options_or_unit, ordering_to_value, to_temporal_duration,
+        Duration,
+    },
+    fields::{to_partial_object, FieldNames, Fields, RequiredFields},
+    format_time_string, invalid_string,
+    iso::IsoTime,
+    options::{
+        get_difference_settings, get_overflow, get_required_temporal_unit, get_rounding_increment,
+        get_string_precision_options, validate_rounding_increment, Overflow, RoundingMode,
+        TemporalUnit, UnitGroup,
+    },
+    parser::parse_time,
+    require_string,
+    rounding::{round_time_duration, InternalDuration},
+    to_integer_with_truncation, value_of, with_getters,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The `Temporal.PlainTime` builtin object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PlainTime {
+    pub(crate) time: IsoTime,
+}
+
+impl Finalize for PlainTime {}
+
+// SAFETY: `PlainTime` doesn't contain any traceable data.
+unsafe impl Trace for PlainTime {
+    empty_trace!();
+}
+
+impl IntrinsicObject for PlainTime {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        let builder = BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .static_method(Self::from, "from", 1)
+            .static_method(Self::compare, "compare", 2)
+            .property(
+                JsSymbol::to_string_tag(),
+                "Temporal.PlainTime",
+                Attribute::CONFIGURABLE,
+            )
+            .method(Self::add, "add", 1)
+            .method(Self::subtract, "subtract", 1)
+            .method(Self::with, "with", 1)
+            .method(Self::until, "until", 1)
+            .method(Self::since, "since", 1)
+            .method(Self::round, "round", 1)
+            .method(Self::equals, "equals", 1)
+            .method(Self::to_string, "toString", 0)
+            .method(Self::to_json, "toJSON", 0)
+            .method(Self::to_json, "toLocaleString", 0)
+            .method(value_of, "valueOf", 0);
+
+        with_getters(builder, realm, &time_getters::<Self>()).build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for PlainTime {
+    const NAME: &'static str = "PlainTime";
+}
+
+impl BuiltInConstructor for PlainTime {
+    const LENGTH: usize = 0;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::plain_time;
+
+    /// Constructor [`Temporal.PlainTime ( [ hour [ , minute [ , second [ , millisecond [ , microsecond [ , nanosecond ] ] ] ] ] ] )`][spec].
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaintime
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, then
+        if new_target.is_undefined() {
+            // a. Throw a TypeError exception.
+            return Err(constructor_without_new(Self::NAME).into());
+        }
+
+        // 2-7. Let each field be ? ToIntegerWithTruncation(field), or 0 if it is undefined.
+        let fields = to_time_arguments(args, context)?;
+
+        // 8. If IsValidTime(hour, minute, second, millisecond, microsecond, nanosecond) is false, throw a RangeError exception.
+        // 9. Let time be CreateTimeRecord(hour, minute, second, millisecond, microsecond, nanosecond).
+        let time = IsoTime::regulate(fields, Overflow::Reject)?;
+
+        // 10. Return ? CreateTemporalTime(time, NewTarget).
+        Ok(Self::new(time).create(Some(new_target), context)?.into())
+    }
+}
+
+impl PlainTime {
+    /// Creates a new plain time.
+    pub(crate) const fn new(time: IsoTime) -> Self {
+        Self { time }
+    }
+
+    /// Creates a new `Temporal.PlainTime` object with this data.
+    pub(crate) fn create(
+        self,
+        new_target: Option<&JsValue>,
+        context: &mut Context<'_>,
+    ) -> JsResult<JsObject> {
+        create_temporal_object(
+            ObjectData::plain_time(self),
+            new_target,
+            StandardConstructors::plain_time,
+            context,
+        )
+    }
+
+    /// `Temporal.PlainTime.from ( item [ , options ] )`
+    fn from(_: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        let time = to_temporal_time(args.get_or_undefined(0), args.get_or_undefined(1), context)?;
+        Ok(Self::new(time).create(None, context)?.into())
+    }
+
+    /// `Temporal.PlainTime.compare ( one, two )`
+    fn compare(_: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        let undefined = JsValue::undefined();
+        let one = to_temporal_time(args.get_or_undefined(0), &undefined, context)?;
+        let two = to_temporal_time(args.get_or_undefined(1), &undefined, context)?;
+        Ok(ordering_to_value(one.cmp(&two)))
+    }
+
+    /// `Temporal.PlainTime.prototype.add ( temporalDurationLike )`
+    fn add(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        let time = Self::this_time(this, context)?;
+        let duration = to_temporal_duration(args.get_or_undefined(0), context)?;
+        Self::add_duration(time, &duration, context)
+    }
+
+    /// `Temporal.PlainTime.prototype.subtract ( temporalDurationLike )`
+    fn subtract(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        let time = Self::this_time(this, context)?;
+        let duration = to_temporal_duration(args.get_or_undefined(0), context)?;
+        Self::add_duration(time, &duration.negated(), context)
+    }
+
+    /// Abstract operation [`AddDurationToTime ( operation, temporalTime, temporalDurationLike )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-adddurationtotime
+    fn add_duration(
+        time: IsoTime,
+        duration: &Duration,
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 3. Let internalDuration be ToInternalDurationRecord(duration).
+        // 4. Let result be AddTime(temporalTime.[[Time]], internalDuration.[[Time]]).
+        let (_, time) = IsoTime::balance(time.to_nanoseconds() + duration.to_internal().time);
+
+        // 5. Return ! CreateTemporalTime(result).
+        Ok(Self::new(time).create(None, context)?.into())
+    }
+
+    /// `Temporal.PlainTime.prototype.with ( temporalTimeLike [ , options ] )`
+    fn with(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Let temporalTime be the this value.
+        // 2. Perform ? RequireInternalSlot(temporalTime, [[InitializedTemporalTime]]).
+        let time = Self::this_time(this, context)?;
+
+        // 3. If ? IsPartialTemporalObject(temporalTimeLike) is false, throw a TypeError exception.
+        let partial = to_partial_object(args.get_or_undefined(0), context)?;
+
+        // 4. Let partialTime be ? ToTemporalTimeRecord(temporalTimeLike, partial).
+        let partial =
+            Fields::prepare(&partial, FieldNames::TIME, RequiredFields::Partial, context)?;
+
+        // 5-16. Use the fields of partialTime, or the fields of temporalTime if they are undefined.
+        let fields = Fields::default().with_time(time).merge(partial);
+
+        // 17. Let resolvedOptions be ? GetOptionsObject(options).
+        // 18. Let overflow be ? GetTemporalOverflowOption(resolvedOptions).
+        let overflow = get_overflow(&get_options_object(args.get_or_undefined(1))?, context)?;
+
+        // 19. Let result be ? RegulateTime(hour, minute, second, millisecond, microsecond, nanosecond, overflow).
+        let time = fields.to_time(overflow)?;
+
+        // 20. Return ! CreateTemporalTime(result).
+        Ok(Self::new(time).create(None, context)?.into())
+    }
+
+    /// `Temporal.PlainTime.prototype.until ( other [ , options ] )`
+    fn until(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        Self::difference(false, this, args, context)
+    }
+
+    /// `Temporal.PlainTime.prototype.since ( other [ , options ] )`
+    fn since(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        Self::difference(true, this, args, context)
+    }
+
+    /// Abstract operation [`DifferenceTemporalPlainTime ( operation, temporalTime, other, options )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-differencetemporalplaintime
+    fn difference(
+        since: bool,
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        let time = Self::this_time(this, context)?;
+
+        // 1. Set other to ? ToTemporalTime(other).
+        let other = to_temporal_time(args.get_or_undefined(0), &JsValue::undefined(), context)?;
+
+        // 2. Let resolvedOptions be ? GetOptionsObject(options).
+        // 3. Let settings be ? GetDifferenceSettings(operation, resolvedOptions, time, « », nanosecond, hour).
+        let settings = get_difference_settings(
+            since,
+            args.get_or_undefined(1),
+            UnitGroup::Time,
+            TemporalUnit::Nanosecond,
+            TemporalUnit::Hour,
+            context,
+        )?;
+
+        // 4. Let timeDuration be DifferenceTime(temporalTime.[[Time]], other.[[Time]]).
+        // 5. Set timeDuration to ! RoundTimeDuration(timeDuration, settings.[[RoundingIncrement]], settings.[[SmallestUnit]], settings.[[RoundingMode]]).
+        let time = round_time_duration(
+            other.to_nanoseconds() - time.to_nanoseconds(),
+            settings.rounding_increment,
+            settings.smallest_unit,
+            settings.rounding_mode,
+        )?;
+
+        // 6. Let duration be CombineDateAndTimeDuration(ZeroDateDuration(), timeDuration).
+        // 7. Let result be ! TemporalDurationFromInternal(duration, settings.[[LargestUnit]]).
+        // 8. If operation is since, set result to CreateNegatedTemporalDuration(result).
+        // 9. Return result.
+        Ok(duration_from_difference(
+            InternalDuration::from_time(time),
+            settings.largest_unit,
+            since,
+        )?
+        .create(None, context)?
+        .into())
+    }
+
+    /// `Temporal.PlainTime.prototype.round ( roundTo )`
+    fn round(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Let temporalTime be the this value.
+        // 2. Perform ? RequireInternalSlot(temporalTime, [[InitializedTemporalTime]]).
+        let time = Self::this_time(this, context)?;
+
+        // 3-5. Get the options object, or an object with a `smallestUnit` if roundTo is a string.
+        let round_to = options_or_unit(args.get_or_undefined(0), utf16!("smallestUnit"), context)?;
+
+        // 6. NOTE: The following steps read options and perform independent validation in alphabetical order.
+        // 7. Let roundingIncrement be ? GetRoundingIncrementOption(roundTo).
+        let rounding_increment = get_rounding_increment(&round_to, context)?;
+
+        // 8. Let roundingMode be ? GetRoundingModeOption(roundTo, half-expand).
+        let rounding_mode = get_option(&round_to, utf16!("roundingMode"), false, context)?
+            .unwrap_or(RoundingMode::HalfExpand);
+
+        // 9. Let smallestUnit be ? GetTemporalUnitValuedOption(roundTo, "smallestUnit", time, required).
+        let smallest_unit = get_required_temporal_unit(
+            &round_to,
+            utf16!("smallestUnit"),
+            UnitGroup::Time,
+            None,
+            context,
+        )?;
+
+        // 10. Let maximum be MaximumTemporalDurationRoundingIncrement(smallestUnit).
+        // 11. Assert: maximum is not unset.
+        // 12. Perform ? ValidateTemporalRoundingIncrement(roundingIncrement, maximum, false).
+        if let Some(maximum) = smallest_unit.maximum_rounding_increment() {
+            validate_rounding_increment(rounding_increment, maximum, false)?;
+        }
+
+        // 13. Let result be RoundTime(temporalTime.[[Time]], roundingIncrement, smallestUnit, roundingMode).
+        let (_, time) = time.round(rounding_increment, smallest_unit, rounding_mode);
+
+        // 14. Return ! CreateTemporalTime(result).
+        Ok(Self::new(time).create(None, context)?.into())
+    }
+
+    /// `Temporal.PlainTime.prototype.equals ( other )`
+    fn equals(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        let time = Self::this_time(this, context)?;
+        let other = to_temporal_time(args.get_or_undefined(0), &JsValue::undefined(), context)?;
+        Ok((time == other).into())
+    }
+
+    /// `Temporal.PlainTime.prototype.toString ( [ options ] )`
+    fn to_string(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Let temporalTime be the this value.
+        // 2. Perform ? RequireInternalSlot(temporalTime, [[InitializedTemporalTime]]).
+        let time = Self::this_time(this, context)?;
+
+        // 3. Let resolvedOptions be ? GetOptionsObject(options).
+        let options = get_options_object(args.get_or_undefined(0))?;
+
+        // 4-9. Read the precision options.
+        let (precision, rounding_mode) = get_string_precision_options(&options, context)?;
+
+        // 10. Let roundResult be RoundTime(temporalTime.[[Time]], precision.[[Increment]], precision.[[Unit]], roundingMode).
+        let (_, time) = time.round(precision.increment, precision.unit, rounding_mode);
+
+        // 11. Return TimeRecordToString(roundResult, precision.[[Precision]]).
+        Ok(js_string!(format_time_string(time, precision.precision)).into())
+    }
+
+    /// `Temporal.PlainTime.prototype.toJSON ( )` and
+    /// `Temporal.PlainTime.prototype.toLocaleString ( [ locales [ , options ] ] )`
+    fn to_json(this: &JsValue, _: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        let time = Self::this_time(this, context)?;
+        Ok(js_string!(format_time_string(time, super::options::Precision::Auto)).into())
+    }
+}
+
+impl HasIsoTime for PlainTime {
+    fn this_time(this: &JsValue, _: &Context<'_>) -> JsResult<IsoTime> {
+        this.as_object()
+            .and_then(|object| object.borrow().as_plain_time().map(|time| time.time))
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("'this' is not a Temporal.PlainTime object")
+                    .into()
+            })
+    }
+}
+
+/// A Temporal class whose objects have a wall-clock time, sharing the time getters of
+/// `PlainTime`.
+pub(crate) trait HasIsoTime {
+    /// Returns the time of the `this` value, or throws a `TypeError` if it is not an object of
+    /// this class.
+    fn this_time(this: &JsValue, context: &Context<'_>) -> JsResult<IsoTime>;
+}
+
+/// Returns the getters of the time properties of the class `T`.
+pub(crate) fn time_getters<T: HasIsoTime>() -> [(&'static str, NativeFunctionPointer); 6] {
+    [
+        ("hour", get_hour::<T>),
+        ("minute", get_minute::<T>),
+        ("second", get_second::<T>),
+        ("millisecond", get_millisecond::<T>),
+        ("microsecond", get_microsecond::<T>),
+        ("nanosecond", get_nanosecond::<T>),
+    ]
+}
+
+/// `get Temporal.*.prototype.hour`
+fn get_hour<T: HasIsoTime>(
+    this: &JsValue,
+    _: &[JsValue],
+    context: &mut Context<'_>,
+) -> JsResult<JsValue> {
+    Ok(T::this_time(this, context)?.hour.into())
+}
+
+/// `get Temporal.*.prototype.minute`
+fn get_minute<T: HasIsoTime>(
+    this: &JsValue,
+    _: &[JsValue],
+    context: &mut Context<'_>,
+) -> JsResult<JsValue> {
+    Ok(T::this_time(this, context)?.minute.into())
+}
+
+/// `get Temporal.*.prototype.second`
+fn get_second<T: HasIsoTime>(
+    this: &JsValue,
+    _: &[JsValue],
+    context: &mut Context<'_>,
+) -> JsResult<JsValue> {
+    Ok(T::this_time(this, context)?.second.into())
+}
+
+/// `get Temporal.*.prototype.millisecond`
+fn get_millisecond<T: HasIsoTime>(
+    this: &JsValue,
+    _: &[JsValue],
+    context: &mut Context<'_>,
+) -> JsResult<JsValue> {
+    Ok(T::this_time(this, context)?.millisecond.into())
+}
+
+/// `get Temporal.*.prototype.microsecond`
+fn get_microsecond<T: HasIsoTime>(
+    this: &JsValue,
+    _: &[JsValue],
+    context: &mut Context<'_>,
+) -> JsResult<JsValue> {
+    Ok(T::this_time(this, context)?.microsecond.into())
+}
+
+/// `get Temporal.*.prototype.nanosecond`
+fn get_nanosecond<T: HasIsoTime>(
+    this: &JsValue,
+    _: &[JsValue],
+    context: &mut Context<'_>,
+) -> JsResult<JsValue> {
+    Ok(T::this_time(this, context)?.nanosecond.into())
+}
+
+/// Converts the optional time arguments of the `PlainTime` and `PlainDateTime` constructors,
+/// which default to zero.
+pub(crate) fn to_time_arguments(args: &[JsValue], context: &mut Context<'_>) -> JsResult<[f64; 6]> {
+    let mut fields = [0.0; 6];
+    for (i, field) in fields.iter_mut().enumerate() {
+        let value = args.get_or_undefined(i);
+        if !value.is_undefined() {
+            *field = to_integer_with_truncation(value, context)?;
+        }
+    }
+    Ok(fields)
+}
+
+/// Abstract operation [`ToTemporalTime ( item [ , options ] )`][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-totemporaltime
+pub(crate) fn to_temporal_time(
+    item: &JsValue,
+    options: &JsValue,
+    context: &mut Context<'_>,
+) -> JsResult<IsoTime> {
+    // 2. If item is an Object, then
+    if let Some(object) = item.as_object() {
+        // a-c. If item is a PlainTime, a PlainDateTime or a ZonedDateTime, return its time.
+        let time = {
+            let borrowed = object.borrow();
+            if let Some(time) = borrowed.as_plain_time() {
+                Some(time.time)
+            } else if let Some(date_time) = borrowed.as_plain_date_time() {
+                Some(date_time.date_time.time)
+            } else {
+                borrowed.as_zoned_date_time().map(|zoned| {
+                    zoned
+                        .time_zone
+                        .iso_date_time_for(zoned.epoch_ns, context)
+                        .time
+                })
+            }
+        };
+        if let Some(time) = time {
+            // i. Let resolvedOptions be ? GetOptionsObject(options).
+            // ii. Perform ? GetTemporalOverflowOption(resolvedOptions).
+            get_overflow(&get_options_object(options)?, context)?;
+            return Ok(time);
+        }
+
+        // d. Let result be ? ToTemporalTimeRecord(item).
+        let fields = Fields::prepare(object, FieldNames::TIME, RequiredFields::Partial, context)?;
+
+        // e. Let resolvedOptions be ? GetOptionsObject(options).
+        // f. Let overflow be ? GetTemporalOverflowOption(resolvedOptions).
+        let overflow = get_overflow(&get_options_object(options)?, context)?;
+
+        // g. Set result to ? RegulateTime(result.[[Hour]], result.[[Minute]], result.[[Second]], result.[[Millisecond]], result.[[Microsecond]], result.[[Nanosecond]], overflow).
+        return fields.to_time(overflow);
+    }
+
+    // 3. Else,
+    //     a. If item is not a String, throw a TypeError exception.
+    let string = require_string(item, "a time")?;
+
+    //     b. Let parseResult be ? ParseTemporalTimeString(item).
+    let (time, calendar) = parse_time(&string).ok_or_else(|| invalid_string("time", &string))?;
+    check_calendar_annotation(calendar.as_deref())?;
+
+    //     c. Let resolvedOptions be ? GetOptionsObject(options).
+    //     d. Perform ? GetTemporalOverflowOption(resolvedOptions).
+    get_overflow(&get_options_object(options)?, context)?;
+
+    // 4. Return ! CreateTemporalTime(result).
+    Ok(time)
+}
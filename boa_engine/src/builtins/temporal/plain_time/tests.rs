@@ -0,0 +1,83 @@
+use crate::{js_string, run_test_actions, JsNativeErrorKind, TestAction};
+
+#[test]
+fn constructor() {
+    run_test_actions([
+        TestAction::run("let t = new Temporal.PlainTime(13, 37, 5, 1, 2, 3)"),
+        TestAction::assert_eq("t.toString()", js_string!("13:37:05.001002003")),
+        TestAction::assert_eq("t.hour", 13),
+        TestAction::assert_eq("t.nanosecond", 3),
+        TestAction::assert_eq(
+            "new Temporal.PlainTime().toString()",
+            js_string!("00:00:00"),
+        ),
+        TestAction::assert_native_error(
+            "new Temporal.PlainTime(24)",
+            JsNativeErrorKind::Range,
+            "time field out of range",
+        ),
+    ]);
+}
+
+#[test]
+fn from() {
+    run_test_actions([
+        TestAction::assert_eq(
+            "Temporal.PlainTime.from('T0830').toString()",
+            js_string!("08:30:00"),
+        ),
+        TestAction::assert_eq(
+            "Temporal.PlainTime.from('2020-01-01T23:59:60').toString()",
+            js_string!("23:59:59"),
+        ),
+        TestAction::assert_eq(
+            "Temporal.PlainTime.from({ hour: 25, minute: 70 }).toString()",
+            js_string!("23:59:00"),
+        ),
+        TestAction::assert_native_error(
+            "Temporal.PlainTime.from('2020-01-01')",
+            JsNativeErrorKind::Range,
+            "`2020-01-01` is not a valid time string",
+        ),
+    ]);
+}
+
+#[test]
+fn arithmetic() {
+    run_test_actions([
+        TestAction::run("let t = Temporal.PlainTime.from('22:00')"),
+        TestAction::assert_eq("t.add({ hours: 3 }).toString()", js_string!("01:00:00")),
+        TestAction::assert_eq("t.subtract('PT22H1M').toString()", js_string!("23:59:00")),
+        TestAction::assert_eq("t.with({ minute: 15 }).toString()", js_string!("22:15:00")),
+        TestAction::assert_eq("t.until('01:30').toString()", js_string!("-PT20H30M")),
+        TestAction::assert_eq(
+            "t.since('21:15', { smallestUnit: 'hour', roundingMode: 'ceil' }).toString()",
+            js_string!("PT1H"),
+        ),
+    ]);
+}
+
+#[test]
+fn round() {
+    run_test_actions([
+        TestAction::run("let t = Temporal.PlainTime.from('11:44:30.123')"),
+        TestAction::assert_eq("t.round('minute').toString()", js_string!("11:45:00")),
+        TestAction::assert_eq(
+            "t.round({ smallestUnit: 'hour', roundingMode: 'floor' }).toString()",
+            js_string!("11:00:00"),
+        ),
+        TestAction::assert_eq(
+            "t.toString({ smallestUnit: 'millisecond' })",
+            js_string!("11:44:30.123"),
+        ),
+        TestAction::assert_eq(
+            "t.toString({ fractionalSecondDigits: 1, roundingMode: 'ceil' })",
+            js_string!("11:44:30.2"),
+        ),
+        TestAction::assert_native_error(
+            "t.round({ smallestUnit: 'minute', roundingIncrement: 7 })",
+            JsNativeErrorKind::Range,
+            "`roundingIncrement` does not divide evenly into the unit",
+        ),
+    ]);
+}
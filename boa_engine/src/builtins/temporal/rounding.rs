@@ -0,0 +1,767 @@
+//! The algorithms to compute, round and total durations relative to a starting date-time.
+//!
+//! Durations are handled internally as a [`InternalDuration`], which separates the date part,
+//! whose length depends on the starting point, from the exact time part.
+
+use crate::{Context, JsNativeError, JsResult};
+
+use super::{
+    iso::{DateDuration, IsoDate, IsoDateTime, NS_PER_DAY},
+    options::{DifferenceSettings, Disambiguation, Overflow, RoundingMode, TemporalUnit},
+    time_zone::{check_epoch_nanoseconds, TimeZoneSlot},
+};
+
+/// The maximum absolute value of the time part of a duration, which is `2^53` seconds minus one
+/// nanosecond.
+pub(crate) const MAX_TIME_DURATION: i128 = 9_007_199_254_740_992_000_000_000 - 1;
+
+/// A duration, split into a calendar date part and an exact time part in nanoseconds.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct InternalDuration {
+    pub(crate) date: DateDuration,
+    pub(crate) time: i128,
+}
+
+impl InternalDuration {
+    /// Creates a new internal duration.
+    pub(crate) const fn new(date: DateDuration, time: i128) -> Self {
+        Self { date, time }
+    }
+
+    /// Creates an internal duration without a date part.
+    pub(crate) const fn from_time(time: i128) -> Self {
+        Self {
+            date: DateDuration {
+                years: 0,
+                months: 0,
+                weeks: 0,
+                days: 0,
+            },
+            time,
+        }
+    }
+
+    /// Abstract operation [`InternalDurationSign ( internalDuration )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-internaldurationsign
+    pub(crate) fn sign(&self) -> i64 {
+        match self.date.sign() {
+            0 => self.time.signum() as i64,
+            sign => sign,
+        }
+    }
+}
+
+/// The starting point of a duration that is rounded or totaled.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct RelativeTo<'a> {
+    pub(crate) date_time: IsoDateTime,
+    pub(crate) time_zone: Option<&'a TimeZoneSlot>,
+}
+
+impl RelativeTo<'_> {
+    /// Returns the epoch nanoseconds of the starting time plus `duration`, which is interpreted in
+    /// the time zone if there is one, or as UTC otherwise.
+    fn epoch_nanoseconds_after(
+        &self,
+        duration: DateDuration,
+        context: &Context<'_>,
+    ) -> JsResult<i128> {
+        let date = self.date_time.date.add(duration, Overflow::Constrain)?;
+        let date_time = IsoDateTime::new(date, self.date_time.time);
+        match self.time_zone {
+            None => Ok(date_time.to_epoch_nanoseconds()),
+            Some(time_zone) => {
+                time_zone.epoch_nanoseconds_for(date_time, Disambiguation::Compatible, context)
+            }
+        }
+    }
+}
+
+/// The result of nudging a duration to its rounded value.
+#[derive(Debug, Clone, Copy)]
+struct Nudge {
+    duration: InternalDuration,
+    nudged_epoch_ns: i128,
+    expanded: bool,
+}
+
+/// Abstract operation [`RoundTimeDuration ( timeDuration, increment, unit, roundingMode )`][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-roundtimeduration
+pub(crate) fn round_time_duration(
+    time: i128,
+    increment: u64,
+    unit: TemporalUnit,
+    mode: RoundingMode,
+) -> JsResult<i128> {
+    let length = unit.nanoseconds().expect("unit must be a time unit or day");
+    let rounded = mode.round(time, length * i128::from(increment));
+    check_time_duration(rounded)
+}
+
+/// Checks that the time part of a duration is within the valid range.
+pub(crate) fn check_time_duration(time: i128) -> JsResult<i128> {
+    if time.abs() > MAX_TIME_DURATION {
+        return Err(JsNativeError::range()
+            .with_message("duration is outside the supported range")
+            .into());
+    }
+    Ok(time)
+}
+
+/// Abstract operation [`TotalTimeDuration ( timeDuration, unit )`][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-totaltimeduration
+pub(crate) fn total_time_duration(time: i128, unit: TemporalUnit) -> f64 {
+    let length = unit.nanoseconds().expect("unit must be a time unit or day");
+    (time / length) as f64 + (time % length) as f64 / length as f64
+}
+
+/// Abstract operation [`NudgeToCalendarUnit ( sign, duration, destEpochNs, isoDateTime, timeZone, calendar, increment, unit, roundingMode )`][spec]
+///
+/// Returns the nudge result and the total of the duration in `unit`.
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-nudgetocalendarunit
+#[allow(clippy::too_many_arguments)]
+fn nudge_to_calendar_unit(
+    sign: i64,
+    duration: InternalDuration,
+    dest_epoch_ns: i128,
+    relative_to: RelativeTo<'_>,
+    increment: u64,
+    unit: TemporalUnit,
+    mode: RoundingMode,
+    context: &Context<'_>,
+) -> JsResult<(Nudge, f64)> {
+    let date = duration.date;
+    let increment_i64 = increment as i64;
+    let truncate = |value: i64| RoundingMode::Trunc.round(value.into(), increment.into()) as i64;
+
+    let (r1, start, end) = match unit {
+        // 2. If unit is year, then
+        TemporalUnit::Year => {
+            let years = truncate(date.years);
+            let r2 = years + increment_i64 * sign;
+            (
+                years,
+                DateDuration {
+                    years,
+                    ..DateDuration::default()
+                },
+                DateDuration {
+                    years: r2,
+                    ..DateDuration::default()
+                },
+            )
+        }
+        // 3. Else if unit is month, then
+        TemporalUnit::Month => {
+            let months = truncate(date.months);
+            let r2 = months + increment_i64 * sign;
+            let base = DateDuration {
+                years: date.years,
+                ..DateDuration::default()
+            };
+            (
+                months,
+                DateDuration { months, ..base },
+                DateDuration { months: r2, ..base },
+            )
+        }
+        // 4. Else if unit is week, then
+        TemporalUnit::Week => {
+            let base = DateDuration {
+                years: date.years,
+                months: date.months,
+                ..DateDuration::default()
+            };
+            let weeks_start = relative_to.date_time.date.add(base, Overflow::Constrain)?;
+            let weeks_end = weeks_start.add_days(date.days)?;
+            let until = weeks_start.until(weeks_end, TemporalUnit::Week);
+            let weeks = truncate(date.weeks + until.weeks);
+            let r2 = weeks + increment_i64 * sign;
+            (
+                weeks,
+                DateDuration { weeks, ..base },
+                DateDuration { weeks: r2, ..base },
+            )
+        }
+        // 5. Else,
+        _ => {
+            let days = truncate(date.days);
+            let r2 = days + increment_i64 * sign;
+            (
+                days,
+                DateDuration { days, ..date },
+                DateDuration { days: r2, ..date },
+            )
+        }
+    };
+
+    // 7-11. Compute the epoch nanoseconds of the start and end of the rounding interval.
+    let start_epoch_ns = relative_to.epoch_nanoseconds_after(start, context)?;
+    let end_epoch_ns = relative_to.epoch_nanoseconds_after(end, context)?;
+
+    // 12. If sign is 1, then
+    //     a. If startEpochNs > destEpochNs or destEpochNs ≥ endEpochNs, throw a RangeError exception.
+    // 13. Else,
+    //     a. If endEpochNs ≥ destEpochNs or destEpochNs > startEpochNs, throw a RangeError exception.
+    let in_range = if sign == 1 {
+        start_epoch_ns <= dest_epoch_ns && dest_epoch_ns <= end_epoch_ns
+    } else {
+        end_epoch_ns <= dest_epoch_ns && dest_epoch_ns <= start_epoch_ns
+    };
+    if !in_range || start_epoch_ns == end_epoch_ns {
+        return Err(JsNativeError::range()
+            .with_message("cannot round the duration in its calendar")
+            .into());
+    }
+
+    // 14. Let progress be (destEpochNs - startEpochNs) / (endEpochNs - startEpochNs).
+    let numerator = (dest_epoch_ns - start_epoch_ns) * i128::from(sign);
+    let denominator = (end_epoch_ns - start_epoch_ns) * i128::from(sign);
+
+    // 15. Let total be r1 + progress × increment × sign.
+    let total =
+        (numerator as f64 / denominator as f64 * increment as f64).mul_add(sign as f64, r1 as f64);
+
+    // 16-18. Round the progress using the unsigned rounding mode of the sign.
+    let nudge = if mode.rounds_to_end(r1, increment, numerator, denominator, sign) {
+        Nudge {
+            duration: InternalDuration::new(end, 0),
+            nudged_epoch_ns: end_epoch_ns,
+            expanded: true,
+        }
+    } else {
+        Nudge {
+            duration: InternalDuration::new(start, 0),
+            nudged_epoch_ns: start_epoch_ns,
+            expanded: false,
+        }
+    };
+
+    // 19. Return the Record { [[NudgeResult]]: nudgeResult, [[Total]]: total }.
+    Ok((nudge, total))
+}
+
+/// Abstract operation [`NudgeToZonedTime ( sign, duration, isoDateTime, timeZone, calendar, increment, unit, roundingMode )`][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-nudgetozonedtime
+#[allow(clippy::too_many_arguments)]
+fn nudge_to_zoned_time(
+    sign: i64,
+    duration: InternalDuration,
+    relative_to: RelativeTo<'_>,
+    time_zone: &TimeZoneSlot,
+    increment: u64,
+    unit: TemporalUnit,
+    mode: RoundingMode,
+    context: &Context<'_>,
+) -> JsResult<Nudge> {
+    // 1. Let start be ? CalendarDateAdd(calendar, isoDateTime.[[ISODate]], duration.[[Date]], constrain).
+    let start = relative_to
+        .date_time
+        .date
+        .add(duration.date, Overflow::Constrain)?;
+
+    // 2. Let startDateTime be CombineISODateAndTimeRecord(start, isoDateTime.[[Time]]).
+    let start_date_time = IsoDateTime::new(start, relative_to.date_time.time);
+
+    // 3. Let endDate be BalanceISODate(start.[[Year]], start.[[Month]], start.[[Day]] + sign).
+    // 4. Let endDateTime be CombineISODateAndTimeRecord(endDate, isoDateTime.[[Time]]).
+    let end_date_time = IsoDateTime::new(start.add_days(sign)?, relative_to.date_time.time);
+
+    // 5. Let startEpochNs be ? GetEpochNanosecondsFor(timeZone, startDateTime, compatible).
+    let start_epoch_ns =
+        time_zone.epoch_nanoseconds_for(start_date_time, Disambiguation::Compatible, context)?;
+
+    // 6. Let endEpochNs be ? GetEpochNanosecondsFor(timeZone, endDateTime, compatible).
+    let end_epoch_ns =
+        time_zone.epoch_nanoseconds_for(end_date_time, Disambiguation::Compatible, context)?;
+
+    // 7. Let daySpan be TimeDurationFromEpochNanosecondsDifference(endEpochNs, startEpochNs).
+    let day_span = end_epoch_ns - start_epoch_ns;
+
+    // 8. If TimeDurationSign(daySpan) ≠ sign, throw a RangeError exception.
+    if day_span.signum() as i64 != sign {
+        return Err(JsNativeError::range()
+            .with_message("the time zone has a day of non-positive length")
+            .into());
+    }
+
+    // 9. Let unitLength be the value in the "Length in Nanoseconds" column of the row of Table 21 whose "Value" column contains unit.
+    let length = unit.nanoseconds().expect("unit must be a time unit") * i128::from(increment);
+
+    // 10. Let roundedTimeDuration be ? RoundTimeDurationToIncrement(duration.[[Time]], increment × unitLength, roundingMode).
+    let mut rounded = check_time_duration(mode.round(duration.time, length))?;
+
+    // 11. Let beyondDaySpan be ! AddTimeDuration(roundedTimeDuration, NegateTimeDuration(daySpan)).
+    let beyond_day_span = rounded - day_span;
+
+    // 12. If TimeDurationSign(beyondDaySpan) ≠ -sign, then
+    #[allow(clippy::if_not_else)]
+    let (expanded, day_delta, nudged_epoch_ns) = if beyond_day_span.signum() as i64 != -sign {
+        // a. Let didRoundBeyondDay be true.
+        // b. Let dayDelta be sign.
+        // c. Set roundedTimeDuration to ? RoundTimeDurationToIncrement(beyondDaySpan, increment × unitLength, roundingMode).
+        rounded = check_time_duration(mode.round(beyond_day_span, length))?;
+        // d. Let nudgedEpochNs be AddTimeDurationToEpochNanoseconds(roundedTimeDuration, endEpochNs).
+        (true, sign, rounded + end_epoch_ns)
+    } else {
+        // 13. Else,
+        //     a. Let didRoundBeyondDay be false.
+        //     b. Let dayDelta be 0.
+        //     c. Let nudgedEpochNs be AddTimeDurationToEpochNanoseconds(roundedTimeDuration, startEpochNs).
+        (false, 0, rounded + start_epoch_ns)
+    };
+
+    // 14. Let dateDuration be ! AdjustDateDurationRecord(duration.[[Date]], duration.[[Date]].[[Days]] + dayDelta).
+    let date = DateDuration {
+        days: duration.date.days + day_delta,
+        ..duration.date
+    };
+
+    // 15-16. Return the Duration Nudge Result Record.
+    Ok(Nudge {
+        duration: InternalDuration::new(date, rounded),
+        nudged_epoch_ns,
+        expanded,
+    })
+}
+
+/// Abstract operation [`NudgeToDayOrTime ( duration, destEpochNs, largestUnit, increment, smallestUnit, roundingMode )`][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-nudgetodayortime
+fn nudge_to_day_or_time(
+    duration: InternalDuration,
+    dest_epoch_ns: i128,
+    settings: DifferenceSettings,
+) -> JsResult<Nudge> {
+    // 1. Let timeDuration be ! Add24HourDaysToTimeDuration(duration.[[Time]], duration.[[Date]].[[Days]]).
+    let time = duration.time + i128::from(duration.date.days) * NS_PER_DAY;
+
+    // 2. Let unitLength be the value in the "Length in Nanoseconds" column of the row of Table 21 whose "Value" column contains smallestUnit.
+    // 3. Let roundedTime be ? RoundTimeDurationToIncrement(timeDuration, unitLength × increment, roundingMode).
+    let rounded = round_time_duration(
+        time,
+        settings.rounding_increment,
+        settings.smallest_unit,
+        settings.rounding_mode,
+    )?;
+
+    // 4. Let diffTime be ! AddTimeDuration(roundedTime, NegateTimeDuration(timeDuration)).
+    let diff_time = rounded - time;
+
+    // 5. Let wholeDays be truncate(TotalTimeDuration(timeDuration, day)).
+    let whole_days = time / NS_PER_DAY;
+
+    // 6. Let roundedWholeDays be truncate(TotalTimeDuration(roundedTime, day)).
+    let rounded_whole_days = rounded / NS_PER_DAY;
+
+    // 7. Let dayDelta be roundedWholeDays - wholeDays.
+    // 8. If dayDelta < 0, let dayDeltaSign be -1; else if dayDelta > 0, let dayDeltaSign be 1; else let dayDeltaSign be 0.
+    // 9. If dayDeltaSign = TimeDurationSign(timeDuration), let didExpandDays be true; else let didExpandDays be false.
+    let expanded = (rounded_whole_days - whole_days).signum() == time.signum();
+
+    // 10. Let nudgedEpochNs be AddTimeDurationToEpochNanoseconds(diffTime, destEpochNs).
+    let nudged_epoch_ns = diff_time + dest_epoch_ns;
+
+    // 11-12. Let days be roundedWholeDays if largestUnit is a date unit, and 0 otherwise.
+    let (days, remainder) = if settings.largest_unit.is_date_unit() {
+        (
+            rounded_whole_days as i64,
+            rounded - rounded_whole_days * NS_PER_DAY,
+        )
+    } else {
+        (0, rounded)
+    };
+
+    // 13. Let dateDuration be ! AdjustDateDurationRecord(duration.[[Date]], days).
+    let date = DateDuration {
+        days,
+        ..duration.date
+    };
+
+    // 14-15. Return the Duration Nudge Result Record.
+    Ok(Nudge {
+        duration: InternalDuration::new(date, remainder),
+        nudged_epoch_ns,
+        expanded,
+    })
+}
+
+/// Abstract operation [`BubbleRelativeDuration ( sign, duration, nudgedEpochNs, isoDateTime, timeZone, calendar, largestUnit, smallestUnit )`][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-bubblerelativeduration
+fn bubble_relative_duration(
+    sign: i64,
+    mut duration: InternalDuration,
+    nudged_epoch_ns: i128,
+    relative_to: RelativeTo<'_>,
+    largest_unit: TemporalUnit,
+    smallest_unit: TemporalUnit,
+    context: &Context<'_>,
+) -> JsResult<InternalDuration> {
+    // 1. If smallestUnit is largestUnit, return duration.
+    // 2-5. Try to bubble the rounded duration to each larger unit, from the smallest one.
+    let units = [TemporalUnit::Week, TemporalUnit::Month, TemporalUnit::Year];
+    for unit in units {
+        if unit <= smallest_unit || unit > largest_unit {
+            continue;
+        }
+        // a. If unit is not week, or largestUnit is week, then
+        if unit == TemporalUnit::Week && largest_unit != TemporalUnit::Week {
+            continue;
+        }
+
+        let date = duration.date;
+        let end = match unit {
+            // i. If unit is year, then
+            TemporalUnit::Year => DateDuration {
+                years: date.years + sign,
+                ..DateDuration::default()
+            },
+            // ii. Else if unit is month, then
+            TemporalUnit::Month => DateDuration {
+                years: date.years,
+                months: date.months + sign,
+                ..DateDuration::default()
+            },
+            // iii. Else,
+            _ => DateDuration {
+                years: date.years,
+                months: date.months,
+                weeks: date.weeks + sign,
+                days: 0,
+            },
+        };
+
+        // iv-viii. Compute the end epoch nanoseconds of the bubbled duration.
+        let end_epoch_ns = relative_to.epoch_nanoseconds_after(end, context)?;
+
+        // ix. Let beyondEnd be nudgedEpochNs - endEpochNs.
+        // x. If beyondEnd < 0, let beyondEndSign be -1; else if beyondEnd > 0, let beyondEndSign be 1; else let beyondEndSign be 0.
+        let beyond_end_sign = (nudged_epoch_ns - end_epoch_ns).signum() as i64;
+
+        // xi. If beyondEndSign ≠ -sign, then
+        //     1. Set duration to CombineDateAndTimeDuration(endDuration, 0).
+        // xii. Else,
+        //     1. Set done to true.
+        if beyond_end_sign == -sign {
+            break;
+        }
+        duration = InternalDuration::new(end, 0);
+    }
+
+    // 6. Return duration.
+    Ok(duration)
+}
+
+/// Abstract operation [`RoundRelativeDuration ( duration, destEpochNs, isoDateTime, timeZone, calendar, largestUnit, increment, smallestUnit, roundingMode )`][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-roundrelativeduration
+pub(crate) fn round_relative_duration(
+    duration: InternalDuration,
+    dest_epoch_ns: i128,
+    relative_to: RelativeTo<'_>,
+    settings: DifferenceSettings,
+    context: &Context<'_>,
+) -> JsResult<InternalDuration> {
+    let DifferenceSettings {
+        smallest_unit,
+        largest_unit,
+        rounding_mode,
+        rounding_increment,
+    } = settings;
+
+    // 1-3. Let irregularLengthUnit be true if smallestUnit is a calendar unit, or a day in a time zone.
+    let irregular = smallest_unit.is_calendar_unit()
+        || (relative_to.time_zone.is_some() && smallest_unit == TemporalUnit::Day);
+
+    // 4. If InternalDurationSign(duration) < 0, let sign be -1; else let sign be 1.
+    let sign = if duration.sign() < 0 { -1 } else { 1 };
+
+    // 5-7. Nudge the duration to its rounded value.
+    let nudge = if irregular {
+        nudge_to_calendar_unit(
+            sign,
+            duration,
+            dest_epoch_ns,
+            relative_to,
+            rounding_increment,
+            smallest_unit,
+            rounding_mode,
+            context,
+        )?
+        .0
+    } else if let Some(time_zone) = relative_to.time_zone {
+        nudge_to_zoned_time(
+            sign,
+            duration,
+            relative_to,
+            time_zone,
+            rounding_increment,
+            smallest_unit,
+            rounding_mode,
+            context,
+        )?
+    } else {
+        nudge_to_day_or_time(duration, dest_epoch_ns, settings)?
+    };
+
+    // 8. Set duration to nudgeResult.[[Duration]].
+    // 9. If nudgeResult.[[DidExpandCalendarUnit]] is true and smallestUnit is not week, then
+    if nudge.expanded && smallest_unit != TemporalUnit::Week {
+        // a. Let startUnit be LargerOfTwoTemporalUnits(smallestUnit, day).
+        // b. Set duration to ? BubbleRelativeDuration(sign, duration, nudgeResult.[[NudgedEpochNs]], isoDateTime, timeZone, calendar, largestUnit, startUnit).
+        return bubble_relative_duration(
+            sign,
+            nudge.duration,
+            nudge.nudged_epoch_ns,
+            relative_to,
+            largest_unit,
+            smallest_unit.max(TemporalUnit::Day),
+            context,
+        );
+    }
+
+    // 10. Return duration.
+    Ok(nudge.duration)
+}
+
+/// Abstract operation [`TotalRelativeDuration ( duration, destEpochNs, isoDateTime, timeZone, calendar, unit )`][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-totalrelativeduration
+pub(crate) fn total_relative_duration(
+    duration: InternalDuration,
+    dest_epoch_ns: i128,
+    relative_to: RelativeTo<'_>,
+    unit: TemporalUnit,
+    context: &Context<'_>,
+) -> JsResult<f64> {
+    // 1. If IsCalendarUnit(unit) is true, or timeZone is not unset and unit is day, then
+    if unit.is_calendar_unit() || (relative_to.time_zone.is_some() && unit == TemporalUnit::Day) {
+        // a. Let sign be InternalDurationSign(duration).
+        let sign = if duration.sign() < 0 { -1 } else { 1 };
+        // b. Let record be ? NudgeToCalendarUnit(sign, duration, destEpochNs, isoDateTime, timeZone, calendar, 1, unit, trunc).
+        // c. Return record.[[Total]].
+        return Ok(nudge_to_calendar_unit(
+            sign,
+            duration,
+            dest_epoch_ns,
+            relative_to,
+            1,
+            unit,
+            RoundingMode::Trunc,
+            context,
+        )?
+        .1);
+    }
+
+    // 2. Let timeDuration be ! Add24HourDaysToTimeDuration(duration.[[Time]], duration.[[Date]].[[Days]]).
+    let time = duration.time + i128::from(duration.date.days) * NS_PER_DAY;
+
+    // 3. Return TotalTimeDuration(timeDuration, unit).
+    Ok(total_time_duration(time, unit))
+}
+
+/// Abstract operation [`DifferencePlainDateTimeWithRounding ( isoDateTime1, isoDateTime2, calendar, largestUnit, roundingIncrement, smallestUnit, roundingMode )`][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-differenceplaindatetimewithrounding
+pub(crate) fn difference_date_time_with_rounding(
+    one: IsoDateTime,
+    two: IsoDateTime,
+    settings: DifferenceSettings,
+    context: &Context<'_>,
+) -> JsResult<InternalDuration> {
+    // 1. If CompareISODateTime(isoDateTime1, isoDateTime2) = 0, then
+    if one == two {
+        // a. Return CombineDateAndTimeDuration(ZeroDateDuration(), 0).
+        return Ok(InternalDuration::default());
+    }
+
+    // 2. If ISODateTimeWithinLimits(isoDateTime1) is false or ISODateTimeWithinLimits(isoDateTime2) is false, throw a RangeError exception.
+    one.check_limits()?;
+    two.check_limits()?;
+
+    // 3. Let diff be DifferenceISODateTime(isoDateTime1, isoDateTime2, calendar, largestUnit).
+    let (date, time) = one.until(two, settings.largest_unit);
+    let diff = InternalDuration::new(date, time);
+
+    // 4. If smallestUnit is nanosecond and roundingIncrement = 1, return diff.
+    if settings.is_noop() {
+        return Ok(diff);
+    }
+
+    // 5. Let destEpochNs be GetUTCEpochNanoseconds(isoDateTime2).
+    // 6. Return ? RoundRelativeDuration(diff, destEpochNs, isoDateTime1, unset, calendar, largestUnit, roundingIncrement, smallestUnit, roundingMode).
+    round_relative_duration(
+        diff,
+        two.to_epoch_nanoseconds(),
+        RelativeTo {
+            date_time: one,
+            time_zone: None,
+        },
+        settings,
+        context,
+    )
+}
+
+/// Abstract operation [`DifferenceZonedDateTime ( ns1, ns2, timeZone, calendar, largestUnit )`][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-differencezoneddatetime
+pub(crate) fn difference_zoned_date_time(
+    ns1: i128,
+    ns2: i128,
+    time_zone: &TimeZoneSlot,
+    largest_unit: TemporalUnit,
+    context: &Context<'_>,
+) -> JsResult<InternalDuration> {
+    // 1. If ns1 = ns2, return CombineDateAndTimeDuration(ZeroDateDuration(), 0).
+    if ns1 == ns2 {
+        return Ok(InternalDuration::default());
+    }
+
+    // 2. Let startDateTime be GetISODateTimeFor(timeZone, ns1).
+    let start = time_zone.iso_date_time_for(ns1, context);
+
+    // 3. Let endDateTime be GetISODateTimeFor(timeZone, ns2).
+    let end = time_zone.iso_date_time_for(ns2, context);
+
+    // 4. If CompareISODate(startDateTime.[[ISODate]], endDateTime.[[ISODate]]) = 0, then
+    if start.date == end.date {
+        // a. Let timeDuration be TimeDurationFromEpochNanosecondsDifference(ns2, ns1).
+        // b. Return CombineDateAndTimeDuration(ZeroDateDuration(), timeDuration).
+        return Ok(InternalDuration::from_time(ns2 - ns1));
+    }
+
+    // 5. If ns2 - ns1 < 0, let sign be -1; else let sign be 1.
+    let sign: i64 = if ns2 < ns1 { -1 } else { 1 };
+
+    // 6. If sign = 1, let maxDayCorrection be 2; else let maxDayCorrection be 1.
+    let max_day_correction = if sign == 1 { 2 } else { 1 };
+
+    // 7. Let dayCorrection be 0.
+    // 8. Let timeDuration be DifferenceTime(startDateTime.[[Time]], endDateTime.[[Time]]).
+    // 9. If TimeDurationSign(timeDuration) = -sign, set dayCorrection to dayCorrection + 1.
+    let time = end.time.to_nanoseconds() - start.time.to_nanoseconds();
+    let mut day_correction = i64::from(time.signum() as i64 == -sign);
+
+    // 10. Let success be false.
+    // 11. Repeat, while dayCorrection ≤ maxDayCorrection and success is false,
+    while day_correction <= max_day_correction {
+        // a. Let intermediateDate be BalanceISODate(endDateTime.[[ISODate]].[[Year]], endDateTime.[[ISODate]].[[Month]], endDateTime.[[ISODate]].[[Day]] - dayCorrection × sign).
+        let intermediate_date = end.date.add_days(-day_correction * sign)?;
+
+        // b. Let intermediateDateTime be CombineISODateAndTimeRecord(intermediateDate, startDateTime.[[Time]]).
+        let intermediate = IsoDateTime::new(intermediate_date, start.time);
+
+        // c. Let intermediateNs be ? GetEpochNanosecondsFor(timeZone, intermediateDateTime, compatible).
+        let intermediate_ns =
+            time_zone.epoch_nanoseconds_for(intermediate, Disambiguation::Compatible, context)?;
+
+        // d. Set timeDuration to TimeDurationFromEpochNanosecondsDifference(ns2, intermediateNs).
+        let time = ns2 - intermediate_ns;
+
+        // e. Let timeSign be TimeDurationSign(timeDuration).
+        // f. If sign ≠ -timeSign, then
+        if sign != -(time.signum() as i64) {
+            // 13. Let dateLargestUnit be LargerOfTwoTemporalUnits(largestUnit, day).
+            // 14. Let dateDifference be CalendarDateUntil(calendar, startDateTime.[[ISODate]], intermediateDateTime.[[ISODate]], dateLargestUnit).
+            let date = start
+                .date
+                .until(intermediate_date, largest_unit.max(TemporalUnit::Day));
+
+            // 15. Return CombineDateAndTimeDuration(dateDifference, timeDuration).
+            return Ok(InternalDuration::new(date, time));
+        }
+
+        // g. Set dayCorrection to dayCorrection + 1.
+        day_correction += 1;
+    }
+
+    // 12. Assert: success is true.
+    Err(JsNativeError::range()
+        .with_message("cannot compute the difference in the time zone")
+        .into())
+}
+
+/// Abstract operation [`DifferenceZonedDateTimeWithRounding ( ns1, ns2, timeZone, calendar, largestUnit, roundingIncrement, smallestUnit, roundingMode )`][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-differencezoneddatetimewithrounding
+pub(crate) fn difference_zoned_date_time_with_rounding(
+    ns1: i128,
+    ns2: i128,
+    time_zone: &TimeZoneSlot,
+    settings: DifferenceSettings,
+    context: &Context<'_>,
+) -> JsResult<InternalDuration> {
+    // 1. If TemporalUnitCategory(largestUnit) is time, then
+    if !settings.largest_unit.is_date_unit() {
+        // a. Let timeDuration be DifferenceInstant(ns1, ns2, roundingIncrement, smallestUnit, roundingMode).
+        // b. Return CombineDateAndTimeDuration(ZeroDateDuration(), timeDuration).
+        return Ok(InternalDuration::from_time(round_time_duration(
+            ns2 - ns1,
+            settings.rounding_increment,
+            settings.smallest_unit,
+            settings.rounding_mode,
+        )?));
+    }
+
+    // 2. Let difference be ? DifferenceZonedDateTime(ns1, ns2, timeZone, calendar, largestUnit).
+    let difference =
+        difference_zoned_date_time(ns1, ns2, time_zone, settings.largest_unit, context)?;
+
+    // 3. If smallestUnit is nanosecond and roundingIncrement = 1, return difference.
+    if settings.is_noop() {
+        return Ok(difference);
+    }
+
+    // 4. Let dateTime be GetISODateTimeFor(timeZone, ns1).
+    // 5. Return ? RoundRelativeDuration(difference, ns2, dateTime, timeZone, calendar, largestUnit, roundingIncrement, smallestUnit, roundingMode).
+    round_relative_duration(
+        difference,
+        ns2,
+        RelativeTo {
+            date_time: time_zone.iso_date_time_for(ns1, context),
+            time_zone: Some(time_zone),
+        },
+        settings,
+        context,
+    )
+}
+
+/// Abstract operation [`AddZonedDateTime ( epochNanoseconds, timeZone, calendar, duration, overflow )`][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-addzoneddatetime
+pub(crate) fn add_zoned_date_time(
+    epoch_ns: i128,
+    time_zone: &TimeZoneSlot,
+    duration: InternalDuration,
+    overflow: Overflow,
+    context: &Context<'_>,
+) -> JsResult<i128> {
+    // 1. If DateDurationSign(duration.[[Date]]) = 0, then
+    if duration.date.sign() == 0 {
+        // a. Return ? AddInstant(epochNanoseconds, duration.[[Time]]).
+        return check_epoch_nanoseconds(epoch_ns + duration.time);
+    }
+
+    // 2. Let isoDateTime be GetISODateTimeFor(timeZone, epochNanoseconds).
+    let date_time = time_zone.iso_date_time_for(epoch_ns, context);
+
+    // 3. Let addedDate be ? CalendarDateAdd(calendar, isoDateTime.[[ISODate]], duration.[[Date]], overflow).
+    let added: IsoDate = date_time.date.add(duration.date, overflow)?;
+
+    // 4. Let intermediateDateTime be CombineISODateAndTimeRecord(addedDate, isoDateTime.[[Time]]).
+    // 5. If ISODateTimeWithinLimits(intermediateDateTime) is false, throw a RangeError exception.
+    let intermediate = IsoDateTime::new(added, date_time.time).check_limits()?;
+
+    // 6. Let intermediateNs be ! GetEpochNanosecondsFor(timeZone, intermediateDateTime, compatible).
+    let intermediate_ns =
+        time_zone.epoch_nanoseconds_for(intermediate, Disambiguation::Compatible, context)?;
+
+    // 7. Return ? AddInstant(intermediateNs, duration.[[Time]]).
+    check_epoch_nanoseconds(intermediate_ns + duration.time)
+}
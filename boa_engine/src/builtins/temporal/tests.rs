@@ -0,0 +1,70 @@
+use crate::{js_string, run_test_actions, JsNativeErrorKind, TestAction};
+
+#[test]
+fn namespace() {
+    run_test_actions([
+        TestAction::assert_eq(
+            "Object.prototype.toString.call(Temporal)",
+            js_string!("[object Temporal]"),
+        ),
+        TestAction::assert_eq(
+            "Object.prototype.toString.call(new Temporal.Duration())",
+            js_string!("[object Temporal.Duration]"),
+        ),
+        TestAction::assert_native_error(
+            "new Temporal()",
+            JsNativeErrorKind::Type,
+            "not a constructor",
+        ),
+    ]);
+}
+
+#[test]
+fn calendar() {
+    run_test_actions([
+        TestAction::run("let cal = new Temporal.Calendar('ISO8601')"),
+        TestAction::assert_eq("cal.id", js_string!("iso8601")),
+        TestAction::assert_eq("cal.daysInMonth('2024-02-01')", 29),
+        TestAction::assert_eq(
+            "cal.dateAdd('2024-01-31', 'P1M').toString()",
+            js_string!("2024-02-29"),
+        ),
+        TestAction::assert_eq("cal.fields(['year', 'day']).join()", js_string!("year,day")),
+        TestAction::assert_native_error(
+            "new Temporal.Calendar('hebrew')",
+            JsNativeErrorKind::Range,
+            "unsupported calendar `hebrew`",
+        ),
+    ]);
+}
+
+#[test]
+fn time_zone() {
+    run_test_actions([
+        TestAction::run("let tz = new Temporal.TimeZone('+01:00')"),
+        TestAction::assert_eq("tz.id", js_string!("+01:00")),
+        TestAction::assert_eq(
+            "tz.getOffsetStringFor(Temporal.Instant.fromEpochMilliseconds(0))",
+            js_string!("+01:00"),
+        ),
+        TestAction::assert_eq(
+            "tz.getPlainDateTimeFor('2020-01-01T00:00Z').toString()",
+            js_string!("2020-01-01T01:00:00"),
+        ),
+        TestAction::assert_eq(
+            "tz.getInstantFor('2020-01-01T01:00').toString()",
+            js_string!("2020-01-01T00:00:00Z"),
+        ),
+        TestAction::assert_eq(
+            "new Temporal.TimeZone('etc/gmt0').id",
+            js_string!("Etc/GMT0"),
+        ),
+        TestAction::assert("new Temporal.TimeZone('Etc/GMT0').equals('UTC')"),
+        TestAction::assert("tz.equals('+01:00')"),
+        TestAction::assert_native_error(
+            "new Temporal.TimeZone('Mars/Olympus_Mons')",
+            JsNativeErrorKind::Range,
+            "unknown time zone `Mars/Olympus_Mons`",
+        ),
+    ]);
+}
@@ -0,0 +1,606 @@
+//! Boa's implementation of the `Temporal.TimeZone` builtin object, and of the time zone
+//! operations shared by the other Temporal classes.
+//!
+//! Named time zones are resolved using the [`TimeZoneProvider`] of the context, while offset
+//! time zones like `+05:30` are always available.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/proposal-temporal/#sec-temporal-timezone-objects
+
+use boa_gc::{empty_trace, Finalize, Trace};
+use boa_profiler::Profiler;
+
+use crate::{
+    builtins::{
+        options::{get_option, get_options_object},
+        Array, BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject,
+    },
+    context::{
+        intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+        TimeZoneProvider,
+    },
+    js_string,
+    object::{JsObject, ObjectData},
+    property::Attribute,
+    realm::Realm,
+    string::utf16,
+    symbol::JsSymbol,
+    Context, JsArgs, JsBigInt, JsNativeError, JsResult, JsValue,
+};
+
+use super::{
+    calendar::to_calendar_identifier,
+    constructor_without_new, create_temporal_object, format_offset_minutes,
+    format_offset_nanoseconds,
+    instant::{to_temporal_instant, Instant},
+    iso::{out_of_range, IsoDate, IsoDateTime, IsoTime, NS_MAX_INSTANT, NS_PER_DAY},
+    options::Disambiguation,
+    parser::{is_time_zone_identifier, parse_date_time, parse_offset_identifier, ParsedOffset},
+    plain_date_time::{to_temporal_date_time, PlainDateTime},
+    require_string, with_getters,
+};
+
+/// The time zone of a `ZonedDateTime` or a `Temporal.TimeZone`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum TimeZoneSlot {
+    /// A fixed UTC offset, in minutes.
+    Offset(i16),
+    /// A named time zone of the [`TimeZoneProvider`], by its case-normalized identifier.
+    Named(String),
+}
+
+impl TimeZoneSlot {
+    /// Returns the `UTC` time zone.
+    pub(crate) fn utc() -> Self {
+        Self::Named("UTC".to_owned())
+    }
+
+    /// Creates a time zone from a time zone identifier, which can be an offset or the name of an
+    /// available time zone.
+    pub(crate) fn from_identifier(identifier: &str, context: &Context<'_>) -> JsResult<Self> {
+        if let Some(minutes) = parse_offset_identifier(identifier) {
+            return Ok(Self::Offset(minutes));
+        }
+
+        // Abstract operation `GetAvailableNamedTimeZoneIdentifier ( timeZoneIdentifier )`
+        if is_time_zone_identifier(identifier) {
+            if let Some(name) = context.time_zone_provider().normalize(identifier) {
+                return Ok(Self::Named(name));
+            }
+        }
+
+        Err(JsNativeError::range()
+            .with_message(format!("unknown time zone `{identifier}`"))
+            .into())
+    }
+
+    /// Abstract operation [`ParseTemporalTimeZoneString ( timeZoneString )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-parsetemporaltimezonestring
+    pub(crate) fn from_string(string: &str, context: &Context<'_>) -> JsResult<Self> {
+        // 1. Let parseResult be ParseText(StringToCodePoints(timeZoneString), TimeZoneIdentifier).
+        // 2. If parseResult is a Parse Node, then
+        if is_time_zone_identifier(string) {
+            // a. Return ! ParseTimeZoneIdentifier(timeZoneString).
+            return Self::from_identifier(string, context);
+        }
+
+        // 3. Let result be ? ParseISODateTime(timeZoneString, « TemporalDateTimeString[+Zoned], TemporalDateTimeString[~Zoned], TemporalInstantString, TemporalTimeString, TemporalMonthDayString, TemporalYearMonthString »).
+        let Some(parsed) = parse_date_time(string) else {
+            return Err(JsNativeError::range()
+                .with_message(format!("`{string}` is not a valid time zone"))
+                .into());
+        };
+
+        // 4. Let timeZoneResult be result.[[TimeZone]].
+        // 5. If timeZoneResult.[[TimeZoneAnnotation]] is not empty, then
+        if let Some(annotation) = parsed.time_zone {
+            // a. Return ! ParseTimeZoneIdentifier(timeZoneResult.[[TimeZoneAnnotation]]).
+            return Self::from_identifier(&annotation, context);
+        }
+
+        match parsed.offset {
+            // 6. If timeZoneResult.[[Z]] is true, then
+            //     a. Return ! ParseTimeZoneIdentifier("UTC").
+            Some(ParsedOffset::Z) => Ok(Self::utc()),
+            // 7. If timeZoneResult.[[OffsetString]] is not empty, then
+            //     a. Return ? ParseTimeZoneIdentifier(timeZoneResult.[[OffsetString]]).
+            Some(ParsedOffset::Offset { nanoseconds, .. }) if nanoseconds % 60_000_000_000 == 0 => {
+                Ok(Self::Offset((nanoseconds / 60_000_000_000) as i16))
+            }
+            // 8. Throw a RangeError exception.
+            _ => Err(JsNativeError::range()
+                .with_message(format!("`{string}` does not contain a valid time zone"))
+                .into()),
+        }
+    }
+
+    /// Returns the identifier of the time zone.
+    pub(crate) fn identifier(&self) -> String {
+        match self {
+            Self::Offset(minutes) => format_offset_minutes(*minutes),
+            Self::Named(name) => name.clone(),
+        }
+    }
+
+    /// Abstract operation [`TimeZoneEquals ( one, two )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-timezoneequals
+    pub(crate) fn equals(&self, other: &Self, provider: &dyn TimeZoneProvider) -> bool {
+        match (self, other) {
+            (Self::Offset(one), Self::Offset(two)) => one == two,
+            (Self::Named(one), Self::Named(two)) => {
+                one == two
+                    || provider
+                        .primary_identifier(one)
+                        .eq_ignore_ascii_case(&provider.primary_identifier(two))
+            }
+            _ => false,
+        }
+    }
+
+    /// Abstract operation [`GetOffsetNanosecondsFor ( timeZone, epochNs )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-getoffsetnanosecondsfor
+    pub(crate) fn offset_nanoseconds_for(&self, epoch_ns: i128, context: &Context<'_>) -> i64 {
+        match self {
+            Self::Offset(minutes) => i64::from(*minutes) * 60_000_000_000,
+            Self::Named(name) => {
+                let seconds = epoch_ns.div_euclid(1_000_000_000) as i64;
+                i64::from(context.time_zone_provider().offset(name, seconds)) * 1_000_000_000
+            }
+        }
+    }
+
+    /// Abstract operation [`GetISODateTimeFor ( timeZone, epochNs )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-getisodatetimefor
+    pub(crate) fn iso_date_time_for(&self, epoch_ns: i128, context: &Context<'_>) -> IsoDateTime {
+        let offset = self.offset_nanoseconds_for(epoch_ns, context);
+        IsoDateTime::from_epoch_nanoseconds(epoch_ns + i128::from(offset))
+    }
+
+    /// Abstract operation [`GetPossibleEpochNanoseconds ( timeZone, isoDateTime )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-getpossibleepochnanoseconds
+    pub(crate) fn possible_epoch_nanoseconds(
+        &self,
+        date_time: IsoDateTime,
+        context: &Context<'_>,
+    ) -> JsResult<Vec<i128>> {
+        let local = date_time.to_epoch_nanoseconds();
+        let possible = match self {
+            Self::Offset(minutes) => vec![local - i128::from(*minutes) * 60_000_000_000],
+            Self::Named(name) => {
+                let seconds = local.div_euclid(1_000_000_000) as i64;
+                context
+                    .time_zone_provider()
+                    .possible_offsets(name, seconds)
+                    .into_iter()
+                    .map(|offset| local - i128::from(offset) * 1_000_000_000)
+                    .collect()
+            }
+        };
+
+        for &epoch_ns in &possible {
+            check_epoch_nanoseconds(epoch_ns)?;
+        }
+
+        Ok(possible)
+    }
+
+    /// Abstract operation [`GetEpochNanosecondsFor ( timeZone, isoDateTime, disambiguation )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-getepochnanosecondsfor
+    pub(crate) fn epoch_nanoseconds_for(
+        &self,
+        date_time: IsoDateTime,
+        disambiguation: Disambiguation,
+        context: &Context<'_>,
+    ) -> JsResult<i128> {
+        let possible = self.possible_epoch_nanoseconds(date_time, context)?;
+        self.disambiguate(&possible, date_time, disambiguation, context)
+    }
+
+    /// Abstract operation [`DisambiguatePossibleEpochNanoseconds ( possibleEpochNs, timeZone, isoDateTime, disambiguation )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-disambiguatepossibleepochnanoseconds
+    pub(crate) fn disambiguate(
+        &self,
+        possible: &[i128],
+        date_time: IsoDateTime,
+        disambiguation: Disambiguation,
+        context: &Context<'_>,
+    ) -> JsResult<i128> {
+        // 1. Let n be possibleEpochNs's length.
+        // 2. If n = 1, then
+        //     a. Return possibleEpochNs[0].
+        // 3. If n ≠ 0, then
+        match (possible, disambiguation) {
+            ([epoch_ns], _)
+            | ([epoch_ns, ..], Disambiguation::Earlier | Disambiguation::Compatible) => {
+                return Ok(*epoch_ns)
+            }
+            ([.., epoch_ns], Disambiguation::Later) => return Ok(*epoch_ns),
+            (_, Disambiguation::Reject) => {
+                return Err(JsNativeError::range()
+                    .with_message("the date-time is ambiguous in its time zone")
+                    .into())
+            }
+            _ => {}
+        }
+
+        // 5. Let before be the latest possible ISO Date-Time Record for which CompareISODateTime(before, isoDateTime) = -1 and ! GetPossibleEpochNanoseconds(timeZone, before) is not empty.
+        // 6-14. Let offsetBefore / offsetAfter be the offsets of the time zone a day before and after.
+        let utc = date_time.to_epoch_nanoseconds();
+        let day_before = check_epoch_nanoseconds(utc - NS_PER_DAY)?;
+        let offset_before = self.offset_nanoseconds_for(day_before, context);
+        let day_after = check_epoch_nanoseconds(utc + NS_PER_DAY)?;
+        let offset_after = self.offset_nanoseconds_for(day_after, context);
+
+        // 15. Let nanoseconds be offsetAfter - offsetBefore.
+        let nanoseconds = i128::from(offset_after - offset_before);
+
+        // 16. If disambiguation is earlier, then
+        let shifted = if disambiguation == Disambiguation::Earlier {
+            date_time.add_nanoseconds(-nanoseconds)?
+        } else {
+            date_time.add_nanoseconds(nanoseconds)?
+        };
+        let possible = self.possible_epoch_nanoseconds(shifted, context)?;
+        let epoch_ns = if disambiguation == Disambiguation::Earlier {
+            possible.first()
+        } else {
+            possible.last()
+        };
+        epoch_ns.copied().ok_or_else(|| out_of_range().into())
+    }
+
+    /// Abstract operation [`GetStartOfDay ( timeZone, isoDate )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-getstartofday
+    pub(crate) fn start_of_day(&self, date: IsoDate, context: &Context<'_>) -> JsResult<i128> {
+        // 1. Let isoDateTime be CombineISODateAndTimeRecord(isoDate, MidnightTimeRecord()).
+        let date_time = IsoDateTime::new(date, IsoTime::default());
+
+        // 2. Let possibleEpochNs be ? GetPossibleEpochNanoseconds(timeZone, isoDateTime).
+        let possible = self.possible_epoch_nanoseconds(date_time, context)?;
+
+        // 3. If possibleEpochNs is not empty, return possibleEpochNs[0].
+        if let Some(&epoch_ns) = possible.first() {
+            return Ok(epoch_ns);
+        }
+
+        // 4. Assert: IsOffsetTimeZoneIdentifier(timeZone) is false.
+        // 5. Let possibleEpochNsAfter be GetNamedTimeZoneEpochNanoseconds(timeZone, isoDateTimeAfter), where isoDateTimeAfter is the ISO Date-Time Record for which DifferenceISODateTime(isoDateTime, isoDateTimeAfter, "iso8601", hour).[[Time]] is the smallest possible value > 0 for which possibleEpochNsAfter is not empty (i.e., isoDateTimeAfter represents the first local time after the transition).
+        // 6. Assert: possibleEpochNsAfter's length = 1.
+        // 7. Return possibleEpochNsAfter[0].
+        let day_before = date_time.to_epoch_nanoseconds() - NS_PER_DAY;
+        self.next_transition(day_before, context)
+            .ok_or_else(|| out_of_range().into())
+    }
+
+    /// Abstract operation [`GetNamedTimeZoneNextTransition ( timeZoneIdentifier, epochNanoseconds )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-getnamedtimezonenexttransition
+    pub(crate) fn next_transition(&self, epoch_ns: i128, context: &Context<'_>) -> Option<i128> {
+        let Self::Named(name) = self else {
+            return None;
+        };
+        let seconds = epoch_ns.div_euclid(1_000_000_000) as i64;
+        context
+            .time_zone_provider()
+            .next_transition(name, seconds)
+            .map(|seconds| i128::from(seconds) * 1_000_000_000)
+            .filter(|&epoch_ns| epoch_ns.abs() <= NS_MAX_INSTANT)
+    }
+
+    /// Abstract operation [`GetNamedTimeZonePreviousTransition ( timeZoneIdentifier, epochNanoseconds )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-getnamedtimezoneprevioustransition
+    pub(crate) fn previous_transition(
+        &self,
+        epoch_ns: i128,
+        context: &Context<'_>,
+    ) -> Option<i128> {
+        let Self::Named(name) = self else {
+            return None;
+        };
+        // Round up, since the transition must be strictly before `epoch_ns`.
+        let seconds = (epoch_ns + 999_999_999).div_euclid(1_000_000_000) as i64;
+        context
+            .time_zone_provider()
+            .previous_transition(name, seconds)
+            .map(|seconds| i128::from(seconds) * 1_000_000_000)
+            .filter(|&epoch_ns| epoch_ns.abs() <= NS_MAX_INSTANT)
+    }
+}
+
+/// Checks that `epoch_ns` is a valid number of nanoseconds of an `Instant`.
+pub(crate) fn check_epoch_nanoseconds(epoch_ns: i128) -> JsResult<i128> {
+    if epoch_ns.abs() > NS_MAX_INSTANT {
+        return Err(out_of_range().into());
+    }
+    Ok(epoch_ns)
+}
+
+/// Abstract operation [`ToTemporalTimeZoneIdentifier ( temporalTimeZoneLike )`][spec]
+///
+/// Custom time zone objects are not supported, so the only objects accepted are
+/// `Temporal.TimeZone` and `Temporal.ZonedDateTime` objects.
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-totemporaltimezoneidentifier
+pub(crate) fn to_time_zone_slot(value: &JsValue, context: &Context<'_>) -> JsResult<TimeZoneSlot> {
+    // 1. If temporalTimeZoneLike is an Object, then
+    if let Some(object) = value.as_object() {
+        let object = object.borrow();
+        // a. If temporalTimeZoneLike has an [[InitializedTemporalZonedDateTime]] internal slot, then
+        //     i. Return temporalTimeZoneLike.[[TimeZone]].
+        if let Some(zdt) = object.as_zoned_date_time() {
+            return Ok(zdt.time_zone.clone());
+        }
+        if let Some(time_zone) = object.as_time_zone() {
+            return Ok(time_zone.slot.clone());
+        }
+        return Err(JsNativeError::typ()
+            .with_message("custom time zone objects are not supported")
+            .into());
+    }
+
+    // 2. If temporalTimeZoneLike is not a String, throw a TypeError exception.
+    let string = require_string(value, "a time zone")?;
+
+    // 3-7. Parse the string, returning the identifier of the time zone.
+    TimeZoneSlot::from_string(&string, context)
+}
+
+/// The `Temporal.TimeZone` builtin object.
+#[derive(Debug, Clone)]
+pub struct TimeZone {
+    pub(crate) slot: TimeZoneSlot,
+}
+
+impl Finalize for TimeZone {}
+
+// SAFETY: `TimeZone` doesn't contain any traceable data.
+unsafe impl Trace for TimeZone {
+    empty_trace!();
+}
+
+impl IntrinsicObject for TimeZone {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        let builder = BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .static_method(Self::from, "from", 1)
+            .property(
+                JsSymbol::to_string_tag(),
+                "Temporal.TimeZone",
+                Attribute::CONFIGURABLE,
+            )
+            .method(Self::equals, "equals", 1)
+            .method(
+                Self::get_offset_nanoseconds_for,
+                "getOffsetNanosecondsFor",
+                1,
+            )
+            .method(Self::get_offset_string_for, "getOffsetStringFor", 1)
+            .method(Self::get_plain_date_time_for, "getPlainDateTimeFor", 1)
+            .method(Self::get_instant_for, "getInstantFor", 1)
+            .method(Self::get_possible_instants_for, "getPossibleInstantsFor", 1)
+            .method(Self::get_next_transition, "getNextTransition", 1)
+            .method(Self::get_previous_transition, "getPreviousTransition", 1)
+            .method(Self::to_string, "toString", 0)
+            .method(Self::to_string, "toJSON", 0);
+
+        with_getters(builder, realm, &[("id", Self::id)]).build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for TimeZone {
+    const NAME: &'static str = "TimeZone";
+}
+
+impl BuiltInConstructor for TimeZone {
+    const LENGTH: usize = 1;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::time_zone;
+
+    /// Constructor [`Temporal.TimeZone ( identifier )`][spec].
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.timezone
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, then
+        if new_target.is_undefined() {
+            // a. Throw a TypeError exception.
+            return Err(constructor_without_new(Self::NAME).into());
+        }
+
+        // 2. Set identifier to ? ToString(identifier).
+        let identifier = args
+            .get_or_undefined(0)
+            .to_string(context)?
+            .to_std_string_escaped();
+
+        // 3. Let timeZone be ? ParseTimeZoneIdentifier(identifier).
+        let slot = TimeZoneSlot::from_identifier(&identifier, context)?;
+
+        // 4. Return ? CreateTemporalTimeZone(timeZone, NewTarget).
+        Ok(Self { slot }.create(Some(new_target), context)?.into())
+    }
+}
+
+impl TimeZone {
+    /// Creates a new `Temporal.TimeZone` object with this data.
+    pub(crate) fn create(
+        self,
+        new_target: Option<&JsValue>,
+        context: &mut Context<'_>,
+    ) -> JsResult<JsObject> {
+        create_temporal_object(
+            ObjectData::time_zone(self),
+            new_target,
+            StandardConstructors::time_zone,
+            context,
+        )
+    }
+
+    /// Gets the time zone of the `this` value.
+    fn this_slot(this: &JsValue) -> JsResult<TimeZoneSlot> {
+        this.as_object()
+            .and_then(|object| object.borrow().as_time_zone().map(|tz| tz.slot.clone()))
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("'this' is not a Temporal.TimeZone object")
+                    .into()
+            })
+    }
+
+    /// `Temporal.TimeZone.from ( item )`
+    fn from(_: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        let item = args.get_or_undefined(0);
+        if let Some(object) = item.as_object() {
+            if object.borrow().as_time_zone().is_some() {
+                return Ok(item.clone());
+            }
+        }
+        let slot = to_time_zone_slot(item, context)?;
+        Ok(Self { slot }.create(None, context)?.into())
+    }
+
+    /// `get Temporal.TimeZone.prototype.id`
+    fn id(this: &JsValue, _: &[JsValue], _: &mut Context<'_>) -> JsResult<JsValue> {
+        Ok(js_string!(Self::this_slot(this)?.identifier()).into())
+    }
+
+    /// `Temporal.TimeZone.prototype.equals ( timeZoneLike )`
+    fn equals(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        let slot = Self::this_slot(this)?;
+        let other = to_time_zone_slot(args.get_or_undefined(0), context)?;
+        Ok(slot.equals(&other, &*context.time_zone_provider()).into())
+    }
+
+    /// `Temporal.TimeZone.prototype.getOffsetNanosecondsFor ( instant )`
+    fn get_offset_nanoseconds_for(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        let slot = Self::this_slot(this)?;
+        let instant = to_temporal_instant(args.get_or_undefined(0), context)?;
+        Ok(slot.offset_nanoseconds_for(instant, context).into())
+    }
+
+    /// `Temporal.TimeZone.prototype.getOffsetStringFor ( instant )`
+    fn get_offset_string_for(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        let slot = Self::this_slot(this)?;
+        let instant = to_temporal_instant(args.get_or_undefined(0), context)?;
+        let offset = slot.offset_nanoseconds_for(instant, context);
+        Ok(js_string!(format_offset_nanoseconds(offset)).into())
+    }
+
+    /// `Temporal.TimeZone.prototype.getPlainDateTimeFor ( instant [ , calendarLike ] )`
+    fn get_plain_date_time_for(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        let slot = Self::this_slot(this)?;
+        let instant = to_temporal_instant(args.get_or_undefined(0), context)?;
+        let calendar = args.get_or_undefined(1);
+        if !calendar.is_undefined() {
+            to_calendar_identifier(calendar, context)?;
+        }
+        let date_time = slot.iso_date_time_for(instant, context);
+        Ok(PlainDateTime::new(date_time).create(None, context)?.into())
+    }
+
+    /// `Temporal.TimeZone.prototype.getInstantFor ( dateTime [ , options ] )`
+    fn get_instant_for(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        let slot = Self::this_slot(this)?;
+        let date_time =
+            to_temporal_date_time(args.get_or_undefined(0), &JsValue::undefined(), context)?;
+        let options = get_options_object(args.get_or_undefined(1))?;
+        let disambiguation =
+            get_option(&options, utf16!("disambiguation"), false, context)?.unwrap_or_default();
+        let epoch_ns = slot.epoch_nanoseconds_for(date_time, disambiguation, context)?;
+        Ok(Instant::new(epoch_ns).create(None, context)?.into())
+    }
+
+    /// `Temporal.TimeZone.prototype.getPossibleInstantsFor ( dateTime )`
+    fn get_possible_instants_for(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        let slot = Self::this_slot(this)?;
+        let date_time =
+            to_temporal_date_time(args.get_or_undefined(0), &JsValue::undefined(), context)?;
+        let possible = slot.possible_epoch_nanoseconds(date_time, context)?;
+        let instants = possible
+            .into_iter()
+            .map(|epoch_ns| Ok(Instant::new(epoch_ns).create(None, context)?.into()))
+            .collect::<JsResult<Vec<JsValue>>>()?;
+        Ok(Array::create_array_from_list(instants, context).into())
+    }
+
+    /// `Temporal.TimeZone.prototype.getNextTransition ( startingPoint )`
+    fn get_next_transition(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        let slot = Self::this_slot(this)?;
+        let start = to_temporal_instant(args.get_or_undefined(0), context)?;
+        match slot.next_transition(start, context) {
+            Some(epoch_ns) => Ok(Instant::new(epoch_ns).create(None, context)?.into()),
+            None => Ok(JsValue::null()),
+        }
+    }
+
+    /// `Temporal.TimeZone.prototype.getPreviousTransition ( startingPoint )`
+    fn get_previous_transition(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        let slot = Self::this_slot(this)?;
+        let start = to_temporal_instant(args.get_or_undefined(0), context)?;
+        match slot.previous_transition(start, context) {
+            Some(epoch_ns) => Ok(Instant::new(epoch_ns).create(None, context)?.into()),
+            None => Ok(JsValue::null()),
+        }
+    }
+
+    /// `Temporal.TimeZone.prototype.toString ( )` and `Temporal.TimeZone.prototype.toJSON ( )`
+    fn to_string(this: &JsValue, _: &[JsValue], _: &mut Context<'_>) -> JsResult<JsValue> {
+        Ok(js_string!(Self::this_slot(this)?.identifier()).into())
+    }
+}
+
+/// Returns the epoch nanoseconds of a `BigInt`, checking that they are a valid instant.
+pub(crate) fn bigint_to_epoch_nanoseconds(bigint: &JsBigInt) -> JsResult<i128> {
+    bigint
+        .to_i128()
+        .filter(|epoch_ns| epoch_ns.abs() <= NS_MAX_INSTANT)
+        .ok_or_else(|| out_of_range().into())
+}
@@ -0,0 +1,1212 @@
+//! Boa's implementation of the `Temporal.ZonedDateTime` builtin object.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/proposal-temporal/#sec-temporal-zoneddatetime-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/ZonedDateTime
+
+use std::fmt::Write;
+
+use boa_gc::{empty_trace, Finalize, Trace};
+use boa_profiler::Profiler;
+
+use crate::{
+    builtins::{
+        options::{get_option, get_options_object},
+        BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject,
+    },
+    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    js_string,
+    object::{JsObject, ObjectData},
+    property::Attribute,
+    realm::Realm,
+    string::utf16,
+    symbol::JsSymbol,
+    Context, JsArgs, JsBigInt, JsNativeError, JsResult, JsValue,
+};
+
+use super::{
+    calendar::{
+        check_calendar_annotation, format_calendar_annotation, get_calendar,
+        get_calendar_with_iso_default, get_show_calendar, to_calendar_identifier,
+    },
+    constructor_without_new, create_temporal_object,
+    duration::{
+        duration_from_difference, options_or_unit, ordering_to_value, to_temporal_duration,
+        Duration,
+    },
+    fields::{to_partial_object, FieldNames, Fields, RequiredFields},
+    format_offset_nanoseconds,
+    instant::{format_offset_rounded, round_instant, Instant},
+    invalid_string,
+    iso::{IsoDate, IsoDateTime, IsoTime},
+    options::{
+        get_difference_settings, get_overflow, get_required_temporal_unit, get_rounding_increment,
+        get_temporal_unit, to_seconds_string_precision, validate_rounding_increment, Direction,
+        Disambiguation, FractionalDigits, OffsetOption, Overflow, Precision, RoundingMode,
+        ShowOffset, ShowTimeZone, TemporalUnit, UnitGroup, UnitOption,
+    },
+    parser::{parse_date_time, ParsedOffset},
+    plain_date::{check_calendar_argument, date_getters, HasIsoDate, PlainDate},
+    plain_date_time::{
+        format_date_time, interpret_date_time_fields, round_date_time, PlainDateTime,
+    },
+    plain_time::{time_getters, to_temporal_time, HasIsoTime, PlainTime},
+    require_string,
+    rounding::{
+        add_zoned_date_time, check_time_duration, difference_zoned_date_time_with_rounding,
+        total_time_duration,
+    },
+    time_zone::{
+        bigint_to_epoch_nanoseconds, check_epoch_nanoseconds, to_time_zone_slot, TimeZone,
+        TimeZoneSlot,
+    },
+    value_of, with_getters,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The `Temporal.ZonedDateTime` builtin object.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ZonedDateTime {
+    /// The number of nanoseconds since the epoch.
+    pub(crate) epoch_ns: i128,
+    pub(crate) time_zone: TimeZoneSlot,
+}
+
+impl Finalize for ZonedDateTime {}
+
+// SAFETY: `ZonedDateTime` doesn't contain any traceable data.
+unsafe impl Trace for ZonedDateTime {
+    empty_trace!();
+}
+
+impl IntrinsicObject for ZonedDateTime {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        let builder = BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .static_method(Self::from, "from", 1)
+            .static_method(Self::compare, "compare", 2)
+            .property(
+                JsSymbol::to_string_tag(),
+                "Temporal.ZonedDateTime",
+                Attribute::CONFIGURABLE,
+            )
+            .method(Self::get_calendar, "getCalendar", 0)
+            .method(Self::get_time_zone, "getTimeZone", 0)
+            .method(Self::with, "with", 1)
+            .method(Self::with_plain_time, "withPlainTime", 0)
+            .method(Self::with_time_zone, "withTimeZone", 1)
+            .method(Self::with_calendar, "withCalendar", 1)
+            .method(Self::add, "add", 1)
+            .method(Self::subtract, "subtract", 1)
+            .method(Self::until, "until", 1)
+            .method(Self::since, "since", 1)
+            .method(Self::round, "round", 1)
+            .method(Self::equals, "equals", 1)
+            .method(Self::to_string, "toString", 0)
+            .method(Self::to_json, "toJSON", 0)
+            .method(Self::to_json, "toLocaleString", 0)
+            .method(value_of, "valueOf", 0)
+            .method(Self::start_of_day, "startOfDay", 0)
+            .method(Self::get_time_zone_transition, "getTimeZoneTransition", 1)
+            .method(Self::to_instant, "toInstant", 0)
+            .method(Self::to_plain_date, "toPlainDate", 0)
+            .method(Self::to_plain_time, "toPlainTime", 0)
+            .method(Self::to_plain_date_time, "toPlainDateTime", 0);
+
+        let builder = with_getters(
+            builder,
+            realm,
+            &[
+                ("timeZoneId", Self::get_time_zone_id),
+                ("hoursInDay", Self::get_hours_in_day),
+                ("epochMilliseconds", Self::get_epoch_milliseconds),
+                ("epochNanoseconds", Self::get_epoch_nanoseconds),
+                ("offsetNanoseconds", Self::get_offset_nanoseconds),
+                ("offset", Self::get_offset),
+            ],
+        );
+        let builder = with_getters(builder, realm, &date_getters::<Self>());
+        with_getters(builder, realm, &time_getters::<Self>()).build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for ZonedDateTime {
+    const NAME: &'static str = "ZonedDateTime";
+}
+
+impl BuiltInConstructor for ZonedDateTime {
+    const LENGTH: usize = 2;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::zoned_date_time;
+
+    /// Constructor [`Temporal.ZonedDateTime ( epochNanoseconds, timeZone [ , calendar ] )`][spec].
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, then
+        if new_target.is_undefined() {
+            // a. Throw a TypeError exception.
+            return Err(constructor_without_new(Self::NAME).into());
+        }
+
+        // 2. Set epochNanoseconds to ? ToBigInt(epochNanoseconds).
+        let epoch_ns = args.get_or_undefined(0).to_bigint(context)?;
+
+        // 3. If IsValidEpochNanoseconds(epochNanoseconds) is false, throw a RangeError exception.
+        let epoch_ns = bigint_to_epoch_nanoseconds(&epoch_ns)?;
+
+        // 4. If timeZone is not a String, throw a TypeError exception.
+        let Some(time_zone) = args.get_or_undefined(1).as_string() else {
+            return Err(JsNativeError::typ()
+                .with_message("the time zone must be a string")
+                .into());
+        };
+
+        // 5-9. Let timeZone be the result of parsing the time zone identifier.
+        let time_zone = TimeZoneSlot::from_identifier(&time_zone.to_std_string_escaped(), context)?;
+
+        // 10. If calendar is undefined, set calendar to "iso8601".
+        // 11. If calendar is not a String, throw a TypeError exception.
+        // 12. Set calendar to ? CanonicalizeCalendar(calendar).
+        check_calendar_argument(args.get_or_undefined(2))?;
+
+        // 13. Return ? CreateTemporalZonedDateTime(epochNanoseconds, timeZone, calendar, NewTarget).
+        Ok(Self::new(epoch_ns, time_zone)
+            .create(Some(new_target), context)?
+            .into())
+    }
+}
+
+impl ZonedDateTime {
+    /// Creates a new zoned date-time from a valid number of nanoseconds since the epoch.
+    pub(crate) const fn new(epoch_ns: i128, time_zone: TimeZoneSlot) -> Self {
+        Self {
+            epoch_ns,
+            time_zone,
+        }
+    }
+
+    /// Creates a new `Temporal.ZonedDateTime` object with this data.
+    pub(crate) fn create(
+        self,
+        new_target: Option<&JsValue>,
+        context: &mut Context<'_>,
+    ) -> JsResult<JsObject> {
+        create_temporal_object(
+            ObjectData::zoned_date_time(self),
+            new_target,
+            StandardConstructors::zoned_date_time,
+            context,
+        )
+    }
+
+    /// Returns the wall-clock date-time of this zoned date-time in its time zone.
+    pub(crate) fn date_time(&self, context: &Context<'_>) -> IsoDateTime {
+        self.time_zone.iso_date_time_for(self.epoch_ns, context)
+    }
+
+    /// Gets the zoned date-time of the `this` value.
+    fn this_zoned(this: &JsValue) -> JsResult<Self> {
+        this.as_object()
+            .and_then(|object| object.borrow().as_zoned_date_time().cloned())
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("'this' is not a Temporal.ZonedDateTime object")
+                    .into()
+            })
+    }
+
+    /// `Temporal.ZonedDateTime.from ( item [ , options ] )`
+    fn from(_: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        Ok(to_temporal_zoned_date_time(
+            args.get_or_undefined(0),
+            args.get_or_undefined(1),
+            context,
+        )?
+        .create(None, context)?
+        .into())
+    }
+
+    /// `Temporal.ZonedDateTime.compare ( one, two )`
+    fn compare(_: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        let undefined = JsValue::undefined();
+        let one = to_temporal_zoned_date_time(args.get_or_undefined(0), &undefined, context)?;
+        let two = to_temporal_zoned_date_time(args.get_or_undefined(1), &undefined, context)?;
+        Ok(ordering_to_value(one.epoch_ns.cmp(&two.epoch_ns)))
+    }
+
+    /// `get Temporal.ZonedDateTime.prototype.timeZoneId`
+    fn get_time_zone_id(this: &JsValue, _: &[JsValue], _: &mut Context<'_>) -> JsResult<JsValue> {
+        let zoned = Self::this_zoned(this)?;
+        Ok(js_string!(zoned.time_zone.identifier()).into())
+    }
+
+    /// `get Temporal.ZonedDateTime.prototype.hoursInDay`
+    fn get_hours_in_day(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned = Self::this_zoned(this)?;
+
+        // 3. Let timeZone be zonedDateTime.[[TimeZone]].
+        // 4. Let isoDateTime be GetISODateTimeFor(timeZone, zonedDateTime.[[EpochNanoseconds]]).
+        // 5. Let today be isoDateTime.[[ISODate]].
+        let today = zoned.date_time(context).date;
+
+        // 6. Let tomorrow be BalanceISODate(today.[[Year]], today.[[Month]], today.[[Day]] + 1).
+        let tomorrow = today.add_days(1)?;
+
+        // 7. Let todayNs be ? GetStartOfDay(timeZone, today).
+        let today_ns = zoned.time_zone.start_of_day(today, context)?;
+
+        // 8. Let tomorrowNs be ? GetStartOfDay(timeZone, tomorrow).
+        let tomorrow_ns = zoned.time_zone.start_of_day(tomorrow, context)?;
+
+        // 9. Let diff be TimeDurationFromEpochNanosecondsDifference(tomorrowNs, todayNs).
+        // 10. Return 𝔽(TotalTimeDuration(diff, hour)).
+        Ok(total_time_duration(tomorrow_ns - today_ns, TemporalUnit::Hour).into())
+    }
+
+    /// `get Temporal.ZonedDateTime.prototype.epochMilliseconds`
+    fn get_epoch_milliseconds(
+        this: &JsValue,
+        _: &[JsValue],
+        _: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        let zoned = Self::this_zoned(this)?;
+        Ok((zoned.epoch_ns.div_euclid(1_000_000) as f64).into())
+    }
+
+    /// `get Temporal.ZonedDateTime.prototype.epochNanoseconds`
+    fn get_epoch_nanoseconds(
+        this: &JsValue,
+        _: &[JsValue],
+        _: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        let zoned = Self::this_zoned(this)?;
+        Ok(JsBigInt::from(zoned.epoch_ns).into())
+    }
+
+    /// `get Temporal.ZonedDateTime.prototype.offsetNanoseconds`
+    fn get_offset_nanoseconds(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        let zoned = Self::this_zoned(this)?;
+        let offset = zoned
+            .time_zone
+            .offset_nanoseconds_for(zoned.epoch_ns, context);
+        Ok((offset as f64).into())
+    }
+
+    /// `get Temporal.ZonedDateTime.prototype.offset`
+    fn get_offset(this: &JsValue, _: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        let zoned = Self::this_zoned(this)?;
+        let offset = zoned
+            .time_zone
+            .offset_nanoseconds_for(zoned.epoch_ns, context);
+        Ok(js_string!(format_offset_nanoseconds(offset)).into())
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.getCalendar ( )`
+    fn get_calendar(this: &JsValue, _: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        Self::this_zoned(this)?;
+        get_calendar(context)
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.getTimeZone ( )`
+    fn get_time_zone(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        let zoned = Self::this_zoned(this)?;
+        Ok(TimeZone {
+            slot: zoned.time_zone,
+        }
+        .create(None, context)?
+        .into())
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.with ( temporalZonedDateTimeLike [ , options ] )`
+    fn with(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned = Self::this_zoned(this)?;
+
+        // 3. If ? IsPartialTemporalObject(temporalZonedDateTimeLike) is false, throw a TypeError exception.
+        let partial = to_partial_object(args.get_or_undefined(0), context)?;
+
+        // 4. Let epochNs be zonedDateTime.[[EpochNanoseconds]].
+        // 5. Let timeZone be zonedDateTime.[[TimeZone]].
+        // 6. Let calendar be zonedDateTime.[[Calendar]].
+        // 7. Let offsetNanoseconds be GetOffsetNanosecondsFor(timeZone, epochNs).
+        // 8. Let isoDateTime be GetISODateTimeFor(timeZone, epochNs).
+        let offset = zoned
+            .time_zone
+            .offset_nanoseconds_for(zoned.epoch_ns, context);
+        let date_time = zoned.date_time(context);
+
+        // 9. Let fields be ISODateToFields(calendar, isoDateTime.[[ISODate]], date).
+        // 10-15. Set the time fields of fields to the time of isoDateTime.
+        // 16. Set fields.[[OffsetString]] to FormatUTCOffsetNanoseconds(offsetNanoseconds).
+        let fields = Fields {
+            offset: Some(offset),
+            ..Fields::from_date(date_time.date).with_time(date_time.time)
+        };
+
+        // 17. Let partialZonedDateTime be ? PrepareCalendarFields(calendar, temporalZonedDateTimeLike, « year, month, month-code, day », « hour, minute, second, millisecond, microsecond, nanosecond, offset », partial).
+        let partial = Fields::prepare(
+            &partial,
+            FieldNames {
+                time_zone: false,
+                ..FieldNames::ZONED
+            },
+            RequiredFields::Partial,
+            context,
+        )?;
+
+        // 18. Set fields to CalendarMergeFields(calendar, fields, partialZonedDateTime).
+        let fields = fields.merge(partial);
+
+        // 19. Let resolvedOptions be ? GetOptionsObject(options).
+        // 20. Let disambiguation be ? GetTemporalDisambiguationOption(resolvedOptions).
+        // 21. Let offset be ? GetTemporalOffsetOption(resolvedOptions, prefer).
+        // 22. Let overflow be ? GetTemporalOverflowOption(resolvedOptions).
+        let (disambiguation, offset_option, overflow) =
+            get_zoned_options(args.get_or_undefined(1), OffsetOption::Prefer, context)?;
+
+        // 23. Let dateTimeResult be ? InterpretTemporalDateTimeFields(calendar, fields, overflow).
+        let date_time = interpret_date_time_fields(&fields, overflow)?;
+
+        // 24. Let newOffsetNanoseconds be ! ParseDateTimeUTCOffset(fields.[[OffsetString]]).
+        let offset = fields.offset.unwrap_or(offset);
+
+        // 25. Let epochNanoseconds be ? InterpretISODateTimeOffset(dateTimeResult.[[ISODate]], dateTimeResult.[[Time]], option, newOffsetNanoseconds, timeZone, disambiguation, offset, match-exactly).
+        let epoch_ns = interpret_iso_date_time_offset(
+            date_time.date,
+            Some(date_time.time),
+            OffsetBehaviour::Option(offset),
+            &zoned.time_zone,
+            disambiguation,
+            offset_option,
+            false,
+            context,
+        )?;
+
+        // 26. Return ! CreateTemporalZonedDateTime(epochNanoseconds, timeZone, calendar).
+        Ok(Self::new(epoch_ns, zoned.time_zone)
+            .create(None, context)?
+            .into())
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.withPlainTime ( [ plainTimeLike ] )`
+    fn with_plain_time(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned = Self::this_zoned(this)?;
+
+        // 3. Let timeZone be zonedDateTime.[[TimeZone]].
+        // 4. Let calendar be zonedDateTime.[[Calendar]].
+        // 5. Let isoDateTime be GetISODateTimeFor(timeZone, zonedDateTime.[[EpochNanoseconds]]).
+        let date = zoned.date_time(context).date;
+
+        let time = args.get_or_undefined(0);
+        let epoch_ns = if time.is_undefined() {
+            // 6. If plainTimeLike is undefined, then
+            //     a. Let epochNs be ? GetStartOfDay(timeZone, isoDateTime.[[ISODate]]).
+            zoned.time_zone.start_of_day(date, context)?
+        } else {
+            // 7. Else,
+            //     a. Let plainTime be ? ToTemporalTime(plainTimeLike).
+            let time = to_temporal_time(time, &JsValue::undefined(), context)?;
+
+            //     b. Let resultISODateTime be CombineISODateAndTimeRecord(isoDateTime.[[ISODate]], plainTime.[[Time]]).
+            //     c. Let epochNs be ? GetEpochNanosecondsFor(timeZone, resultISODateTime, compatible).
+            zoned.time_zone.epoch_nanoseconds_for(
+                IsoDateTime::new(date, time),
+                Disambiguation::Compatible,
+                context,
+            )?
+        };
+
+        // 8. Return ! CreateTemporalZonedDateTime(epochNs, timeZone, calendar).
+        Ok(Self::new(epoch_ns, zoned.time_zone)
+            .create(None, context)?
+            .into())
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.withTimeZone ( timeZoneLike )`
+    fn with_time_zone(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        let zoned = Self::this_zoned(this)?;
+        let time_zone = to_time_zone_slot(args.get_or_undefined(0), context)?;
+        Ok(Self::new(zoned.epoch_ns, time_zone)
+            .create(None, context)?
+            .into())
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.withCalendar ( calendarLike )`
+    fn with_calendar(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        let zoned = Self::this_zoned(this)?;
+        to_calendar_identifier(args.get_or_undefined(0), context)?;
+        Ok(zoned.create(None, context)?.into())
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.add ( temporalDurationLike [ , options ] )`
+    fn add(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        let zoned = Self::this_zoned(this)?;
+        let duration = to_temporal_duration(args.get_or_undefined(0), context)?;
+        zoned.add_duration(&duration, args.get_or_undefined(1), context)
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.subtract ( temporalDurationLike [ , options ] )`
+    fn subtract(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        let zoned = Self::this_zoned(this)?;
+        let duration = to_temporal_duration(args.get_or_undefined(0), context)?;
+        zoned.add_duration(&duration.negated(), args.get_or_undefined(1), context)
+    }
+
+    /// Abstract operation [`AddDurationToZonedDateTime ( operation, zonedDateTime, temporalDurationLike, options )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-adddurationtozoneddatetime
+    fn add_duration(
+        self,
+        duration: &Duration,
+        options: &JsValue,
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 3. Let resolvedOptions be ? GetOptionsObject(options).
+        // 4. Let overflow be ? GetTemporalOverflowOption(resolvedOptions).
+        let overflow = get_overflow(&get_options_object(options)?, context)?;
+
+        // 5. Let calendar be zonedDateTime.[[Calendar]].
+        // 6. Let timeZone be zonedDateTime.[[TimeZone]].
+        // 7. Let internalDuration be ToInternalDurationRecord(duration).
+        // 8. Let epochNanoseconds be ? AddZonedDateTime(zonedDateTime.[[EpochNanoseconds]], timeZone, calendar, internalDuration, overflow).
+        let epoch_ns = add_zoned_date_time(
+            self.epoch_ns,
+            &self.time_zone,
+            duration.to_internal(),
+            overflow,
+            context,
+        )?;
+
+        // 9. Return ! CreateTemporalZonedDateTime(epochNanoseconds, timeZone, calendar).
+        Ok(Self::new(epoch_ns, self.time_zone)
+            .create(None, context)?
+            .into())
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.until ( other [ , options ] )`
+    fn until(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        Self::difference(false, this, args, context)
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.since ( other [ , options ] )`
+    fn since(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        Self::difference(true, this, args, context)
+    }
+
+    /// Abstract operation [`DifferenceTemporalZonedDateTime ( operation, zonedDateTime, other, options )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-differencetemporalzoneddatetime
+    fn difference(
+        since: bool,
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        let zoned = Self::this_zoned(this)?;
+
+        // 1. Set other to ? ToTemporalZonedDateTime(other).
+        let other =
+            to_temporal_zoned_date_time(args.get_or_undefined(0), &JsValue::undefined(), context)?;
+
+        // 2. If CalendarEquals(zonedDateTime.[[Calendar]], other.[[Calendar]]) is false, then
+        //     a. Throw a RangeError exception.
+        // 3. Let resolvedOptions be ? GetOptionsObject(options).
+        // 4. Let settings be ? GetDifferenceSettings(operation, resolvedOptions, datetime, « », nanosecond, hour).
+        let settings = get_difference_settings(
+            since,
+            args.get_or_undefined(1),
+            UnitGroup::DateTime,
+            TemporalUnit::Nanosecond,
+            TemporalUnit::Hour,
+            context,
+        )?;
+
+        // 5. If TemporalUnitCategory(settings.[[LargestUnit]]) is time, then
+        //     a. Let internalDuration be DifferenceInstant(zonedDateTime.[[EpochNanoseconds]], other.[[EpochNanoseconds]], settings.[[RoundingIncrement]], settings.[[SmallestUnit]], settings.[[RoundingMode]]).
+        //     b. Let result be ! TemporalDurationFromInternal(internalDuration, settings.[[LargestUnit]]).
+        //     c. If operation is since, set result to CreateNegatedTemporalDuration(result).
+        //     d. Return result.
+        // 6. NOTE: To calculate differences in two different time zones, settings.[[LargestUnit]] must be a time unit, because day lengths can vary between time zones due to DST and other UTC offset shifts.
+        // 7. If TimeZoneEquals(zonedDateTime.[[TimeZone]], other.[[TimeZone]]) is false, then
+        //     a. Throw a RangeError exception.
+        let largest_unit = if settings.largest_unit.is_date_unit() {
+            if !zoned
+                .time_zone
+                .equals(&other.time_zone, &*context.time_zone_provider())
+            {
+                return Err(JsNativeError::range()
+                    .with_message(
+                        "cannot compute the difference in calendar units between different time zones",
+                    )
+                    .into());
+            }
+            TemporalUnit::Hour
+        } else {
+            settings.largest_unit
+        };
+
+        // 8. If zonedDateTime.[[EpochNanoseconds]] = other.[[EpochNanoseconds]], then
+        //     a. Return ! CreateTemporalDuration(0, 0, 0, 0, 0, 0, 0, 0, 0, 0).
+        // 9. Let internalDuration be ? DifferenceZonedDateTimeWithRounding(zonedDateTime.[[EpochNanoseconds]], other.[[EpochNanoseconds]], zonedDateTime.[[TimeZone]], zonedDateTime.[[Calendar]], settings.[[LargestUnit]], settings.[[RoundingIncrement]], settings.[[SmallestUnit]], settings.[[RoundingMode]]).
+        let internal = difference_zoned_date_time_with_rounding(
+            zoned.epoch_ns,
+            other.epoch_ns,
+            &zoned.time_zone,
+            settings,
+            context,
+        )?;
+
+        // 10. Let result be ? TemporalDurationFromInternal(internalDuration, hour).
+        // 11. If operation is since, set result to CreateNegatedTemporalDuration(result).
+        // 12. Return result.
+        Ok(duration_from_difference(internal, largest_unit, since)?
+            .create(None, context)?
+            .into())
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.round ( roundTo )`
+    fn round(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned = Self::this_zoned(this)?;
+
+        // 3-5. Get the options object, or an object with a `smallestUnit` if roundTo is a string.
+        let round_to = options_or_unit(args.get_or_undefined(0), utf16!("smallestUnit"), context)?;
+
+        // 6. NOTE: The following steps read options and perform independent validation in alphabetical order.
+        // 7. Let roundingIncrement be ? GetRoundingIncrementOption(roundTo).
+        let rounding_increment = get_rounding_increment(&round_to, context)?;
+
+        // 8. Let roundingMode be ? GetRoundingModeOption(roundTo, half-expand).
+        let rounding_mode = get_option(&round_to, utf16!("roundingMode"), false, context)?
+            .unwrap_or(RoundingMode::HalfExpand);
+
+        // 9. Let smallestUnit be ? GetTemporalUnitValuedOption(roundTo, "smallestUnit", time, required, « day »).
+        let smallest_unit = get_required_temporal_unit(
+            &round_to,
+            utf16!("smallestUnit"),
+            UnitGroup::Time,
+            Some(TemporalUnit::Day),
+            context,
+        )?;
+
+        // 10-12. Validate the rounding increment, which must be 1 for days.
+        match smallest_unit.maximum_rounding_increment() {
+            Some(maximum) => validate_rounding_increment(rounding_increment, maximum, false)?,
+            None => validate_rounding_increment(rounding_increment, 1, true)?,
+        }
+
+        // 13. If smallestUnit is nanosecond and roundingIncrement = 1, then
+        if smallest_unit == TemporalUnit::Nanosecond && rounding_increment == 1 {
+            // a. Return ! CreateTemporalZonedDateTime(zonedDateTime.[[EpochNanoseconds]], zonedDateTime.[[TimeZone]], zonedDateTime.[[Calendar]]).
+            return Ok(zoned.create(None, context)?.into());
+        }
+
+        // 14. Let thisNs be zonedDateTime.[[EpochNanoseconds]].
+        // 15. Let timeZone be zonedDateTime.[[TimeZone]].
+        // 16. Let calendar be zonedDateTime.[[Calendar]].
+        // 17. Let isoDateTime be GetISODateTimeFor(timeZone, thisNs).
+        let date_time = zoned.date_time(context);
+
+        let epoch_ns = if smallest_unit == TemporalUnit::Day {
+            // 18. If smallestUnit is day, then
+            //     a. Let dateStart be isoDateTime.[[ISODate]].
+            //     b. Let dateEnd be BalanceISODate(dateStart.[[Year]], dateStart.[[Month]], dateStart.[[Day]] + 1).
+            //     c. Let startNs be ? GetStartOfDay(timeZone, dateStart).
+            //     d. Assert: thisNs ≥ startNs.
+            //     e. Let endNs be ? GetStartOfDay(timeZone, dateEnd).
+            //     f. Assert: thisNs < endNs.
+            let start_ns = zoned.time_zone.start_of_day(date_time.date, context)?;
+            let end_ns = zoned
+                .time_zone
+                .start_of_day(date_time.date.add_days(1)?, context)?;
+
+            //     g. Let dayLengthNs be ℝ(endNs - startNs).
+            //     h. Let dayProgressNs be TimeDurationFromEpochNanosecondsDifference(thisNs, startNs).
+            //     i. Let roundedDayNs be ! RoundTimeDurationToIncrement(dayProgressNs, dayLengthNs, roundingMode).
+            let rounded = check_time_duration(
+                rounding_mode.round(zoned.epoch_ns - start_ns, end_ns - start_ns),
+            )?;
+
+            //     j. Let epochNanoseconds be AddTimeDurationToEpochNanoseconds(roundedDayNs, startNs).
+            check_epoch_nanoseconds(start_ns + rounded)?
+        } else {
+            // 19. Else,
+            //     a. Let roundResult be RoundISODateTime(isoDateTime, roundingIncrement, smallestUnit, roundingMode).
+            let rounded =
+                round_date_time(date_time, rounding_increment, smallest_unit, rounding_mode)?;
+
+            //     b. Let offsetNanoseconds be GetOffsetNanosecondsFor(timeZone, thisNs).
+            let offset = zoned
+                .time_zone
+                .offset_nanoseconds_for(zoned.epoch_ns, context);
+
+            //     c. Let epochNanoseconds be ? InterpretISODateTimeOffset(roundResult.[[ISODate]], roundResult.[[Time]], option, offsetNanoseconds, timeZone, compatible, prefer, match-exactly).
+            interpret_iso_date_time_offset(
+                rounded.date,
+                Some(rounded.time),
+                OffsetBehaviour::Option(offset),
+                &zoned.time_zone,
+                Disambiguation::Compatible,
+                OffsetOption::Prefer,
+                false,
+                context,
+            )?
+        };
+
+        // 20. Return ! CreateTemporalZonedDateTime(epochNanoseconds, timeZone, calendar).
+        Ok(Self::new(epoch_ns, zoned.time_zone)
+            .create(None, context)?
+            .into())
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.equals ( other )`
+    fn equals(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        let zoned = Self::this_zoned(this)?;
+        let other =
+            to_temporal_zoned_date_time(args.get_or_undefined(0), &JsValue::undefined(), context)?;
+        Ok((zoned.epoch_ns == other.epoch_ns
+            && zoned
+                .time_zone
+                .equals(&other.time_zone, &*context.time_zone_provider()))
+        .into())
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.toString ( [ options ] )`
+    fn to_string(this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned = Self::this_zoned(this)?;
+
+        // 3. Let resolvedOptions be ? GetOptionsObject(options).
+        let options = get_options_object(args.get_or_undefined(0))?;
+
+        // 4. NOTE: The following steps read options and perform independent validation in alphabetical order.
+        // 5. Let showCalendar be ? GetTemporalShowCalendarNameOption(resolvedOptions).
+        let show_calendar = get_show_calendar(&options, context)?;
+
+        // 6. Let digits be ? GetTemporalFractionalSecondDigitsOption(resolvedOptions).
+        let digits = get_option::<FractionalDigits>(
+            &options,
+            utf16!("fractionalSecondDigits"),
+            false,
+            context,
+        )?
+        .unwrap_or_default();
+
+        // 7. Let showOffset be ? GetTemporalShowOffsetOption(resolvedOptions).
+        let show_offset = get_option::<ShowOffset>(&options, utf16!("offset"), false, context)?
+            .unwrap_or_default();
+
+        // 8. Let roundingMode be ? GetRoundingModeOption(resolvedOptions, trunc).
+        let rounding_mode = get_option(&options, utf16!("roundingMode"), false, context)?
+            .unwrap_or(RoundingMode::Trunc);
+
+        // 9. Let smallestUnit be ? GetTemporalUnitValuedOption(resolvedOptions, "smallestUnit", time, unset).
+        let smallest_unit = get_temporal_unit(
+            &options,
+            utf16!("smallestUnit"),
+            UnitGroup::Time,
+            None,
+            false,
+            context,
+        )?;
+
+        // 10. Let showTimeZone be ? GetTemporalShowTimeZoneNameOption(resolvedOptions).
+        let show_time_zone =
+            get_option::<ShowTimeZone>(&options, utf16!("timeZoneName"), false, context)?
+                .unwrap_or_default();
+
+        // 11. If smallestUnit is hour, throw a RangeError exception.
+        let smallest_unit = match smallest_unit {
+            Some(UnitOption::Unit(TemporalUnit::Hour)) => {
+                return Err(JsNativeError::range()
+                    .with_message("`smallestUnit` cannot be `hour`")
+                    .into())
+            }
+            Some(UnitOption::Unit(unit)) => Some(unit),
+            _ => None,
+        };
+
+        // 12. Let precision be ToSecondsStringPrecisionRecord(smallestUnit, digits).
+        let precision = to_seconds_string_precision(smallest_unit, digits);
+
+        // 13. Return TemporalZonedDateTimeToString(zonedDateTime, precision.[[Precision]], showCalendar, showTimeZone, showOffset, precision.[[Increment]], precision.[[Unit]], roundingMode).
+        let epoch_ns = round_instant(
+            zoned.epoch_ns,
+            precision.increment,
+            precision.unit,
+            rounding_mode,
+        )?;
+        let string = zoned_date_time_to_string(
+            epoch_ns,
+            &zoned.time_zone,
+            precision.precision,
+            show_time_zone,
+            show_offset,
+            context,
+        );
+        Ok(js_string!(format!(
+            "{string}{}",
+            format_calendar_annotation(show_calendar)
+        ))
+        .into())
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.toJSON ( )` and
+    /// `Temporal.ZonedDateTime.prototype.toLocaleString ( [ locales [ , options ] ] )`
+    fn to_json(this: &JsValue, _: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        let zoned = Self::this_zoned(this)?;
+        Ok(js_string!(zoned_date_time_to_string(
+            zoned.epoch_ns,
+            &zoned.time_zone,
+            Precision::Auto,
+            ShowTimeZone::Auto,
+            ShowOffset::Auto,
+            context,
+        ))
+        .into())
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.startOfDay ( )`
+    fn start_of_day(this: &JsValue, _: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        let zoned = Self::this_zoned(this)?;
+        let date = zoned.date_time(context).date;
+        let epoch_ns = zoned.time_zone.start_of_day(date, context)?;
+        Ok(Self::new(epoch_ns, zoned.time_zone)
+            .create(None, context)?
+            .into())
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.getTimeZoneTransition ( directionParam )`
+    fn get_time_zone_transition(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned = Self::this_zoned(this)?;
+
+        // 3-5. Get the options object, or an object with a `direction` if directionParam is a string.
+        let options = options_or_unit(args.get_or_undefined(0), utf16!("direction"), context)?;
+
+        // 6. Let direction be ? GetDirectionOption(directionParam).
+        let direction = get_option::<Direction>(&options, utf16!("direction"), true, context)?
+            .expect("a required option cannot be `None`");
+
+        // 7. Let timeZone be zonedDateTime.[[TimeZone]].
+        // 8. If IsOffsetTimeZoneIdentifier(timeZone) is true, return null.
+        // 9. If direction is next, then
+        //     a. Let transition be GetNamedTimeZoneNextTransition(timeZone, zonedDateTime.[[EpochNanoseconds]]).
+        // 10. Else,
+        //     a. Let transition be GetNamedTimeZonePreviousTransition(timeZone, zonedDateTime.[[EpochNanoseconds]]).
+        let transition = match direction {
+            Direction::Next => zoned.time_zone.next_transition(zoned.epoch_ns, context),
+            Direction::Previous => zoned.time_zone.previous_transition(zoned.epoch_ns, context),
+        };
+
+        // 11. If transition is null, return null.
+        // 12. Return ! CreateTemporalZonedDateTime(transition, timeZone, zonedDateTime.[[Calendar]]).
+        match transition {
+            Some(epoch_ns) => Ok(Self::new(epoch_ns, zoned.time_zone)
+                .create(None, context)?
+                .into()),
+            None => Ok(JsValue::null()),
+        }
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.toInstant ( )`
+    fn to_instant(this: &JsValue, _: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        let zoned = Self::this_zoned(this)?;
+        Ok(Instant::new(zoned.epoch_ns).create(None, context)?.into())
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.toPlainDate ( )`
+    fn to_plain_date(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        let date_time = Self::this_zoned(this)?.date_time(context);
+        Ok(PlainDate::new(date_time.date).create(None, context)?.into())
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.toPlainTime ( )`
+    fn to_plain_time(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        let date_time = Self::this_zoned(this)?.date_time(context);
+        Ok(PlainTime::new(date_time.time).create(None, context)?.into())
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.toPlainDateTime ( )`
+    fn to_plain_date_time(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        let date_time = Self::this_zoned(this)?.date_time(context);
+        Ok(PlainDateTime::new(date_time).create(None, context)?.into())
+    }
+}
+
+impl HasIsoDate for ZonedDateTime {
+    fn this_date(this: &JsValue, context: &Context<'_>) -> JsResult<IsoDate> {
+        Ok(Self::this_zoned(this)?.date_time(context).date)
+    }
+}
+
+impl HasIsoTime for ZonedDateTime {
+    fn this_time(this: &JsValue, context: &Context<'_>) -> JsResult<IsoTime> {
+        Ok(Self::this_zoned(this)?.date_time(context).time)
+    }
+}
+
+/// How the UTC offset of a date-time string or property bag is used to find its exact time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum OffsetBehaviour {
+    /// Use the offset, in nanoseconds, according to the `offset` option.
+    Option(i64),
+    /// The date-time is in UTC, as with the `Z` designator.
+    Exact,
+    /// There is no offset, so the date-time is a wall-clock time of the time zone.
+    Wall,
+}
+
+/// Abstract operation [`InterpretISODateTimeOffset ( isoDate, time, offsetBehaviour, offsetNanoseconds, timeZone, disambiguation, offsetOption, matchBehaviour )`][spec]
+///
+/// A `time` of `None` means the start of the day, and `match_minutes` is `true` if the offset
+/// only needs to match the offset of the time zone when rounded to minutes.
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-interpretisodatetimeoffset
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn interpret_iso_date_time_offset(
+    date: IsoDate,
+    time: Option<IsoTime>,
+    offset_behaviour: OffsetBehaviour,
+    time_zone: &TimeZoneSlot,
+    disambiguation: Disambiguation,
+    offset_option: OffsetOption,
+    match_minutes: bool,
+    context: &Context<'_>,
+) -> JsResult<i128> {
+    // 1. If time is start-of-day, then
+    let Some(time) = time else {
+        // a. Assert: offsetBehaviour is wall.
+        // b. Assert: offsetNanoseconds is 0.
+        // c. Return ? GetStartOfDay(timeZone, isoDate).
+        return time_zone.start_of_day(date, context);
+    };
+
+    // 2. Let isoDateTime be CombineISODateAndTimeRecord(isoDate, time).
+    let date_time = IsoDateTime::new(date, time);
+
+    let offset = match (offset_behaviour, offset_option) {
+        // 3. If offsetBehaviour is wall, or offsetBehaviour is option and offsetOption is ignore, then
+        (OffsetBehaviour::Wall, _) | (OffsetBehaviour::Option(_), OffsetOption::Ignore) => {
+            // a. Return ? GetEpochNanosecondsFor(timeZone, isoDateTime, disambiguation).
+            return time_zone.epoch_nanoseconds_for(
+                date_time.check_limits()?,
+                disambiguation,
+                context,
+            );
+        }
+        // 4. If offsetBehaviour is exact, or offsetBehaviour is option and offsetOption is use, then
+        (OffsetBehaviour::Exact, _) => 0,
+        (OffsetBehaviour::Option(offset), OffsetOption::Use) => offset,
+        (OffsetBehaviour::Option(offset), _) => {
+            // 5. Assert: offsetBehaviour is option.
+            // 6. Assert: offsetOption is prefer or reject.
+            // 7. Perform ? CheckISODaysRange(isoDate).
+            date.check_limits()?;
+
+            // 8. Let utcEpochNanoseconds be GetUTCEpochNanoseconds(isoDateTime).
+            let utc = date_time.to_epoch_nanoseconds();
+
+            // 9. Let possibleEpochNs be ? GetPossibleEpochNanoseconds(timeZone, isoDateTime).
+            let possible = time_zone.possible_epoch_nanoseconds(date_time, context)?;
+
+            // 10. For each element candidate of possibleEpochNs, do
+            for &candidate in &possible {
+                // a. Let candidateOffset be utcEpochNanoseconds - candidate.
+                let candidate_offset = utc - candidate;
+
+                // b. If candidateOffset = offsetNanoseconds, then
+                //     i. Return candidate.
+                // c. If matchBehaviour is match-minutes, then
+                //     i. Let roundedCandidateNanoseconds be RoundNumberToIncrement(candidateOffset, 60 × 10**9, half-expand).
+                //     ii. If roundedCandidateNanoseconds = offsetNanoseconds, then
+                //         1. Return candidate.
+                if candidate_offset == i128::from(offset)
+                    || (match_minutes
+                        && RoundingMode::HalfExpand.round(candidate_offset, 60_000_000_000)
+                            == i128::from(offset))
+                {
+                    return Ok(candidate);
+                }
+            }
+
+            // 11. If offsetOption is reject, throw a RangeError exception.
+            if offset_option == OffsetOption::Reject {
+                return Err(JsNativeError::range()
+                    .with_message(format!(
+                        "the offset {} is not valid for the time zone `{}`",
+                        format_offset_nanoseconds(offset),
+                        time_zone.identifier()
+                    ))
+                    .into());
+            }
+
+            // 12. Return ? DisambiguatePossibleEpochNanoseconds(possibleEpochNs, timeZone, isoDateTime, disambiguation).
+            return time_zone.disambiguate(&possible, date_time, disambiguation, context);
+        }
+    };
+
+    // 4.a. Let balanced be BalanceISODateTime(isoDate.[[Year]], isoDate.[[Month]], isoDate.[[Day]], time.[[Hour]], time.[[Minute]], time.[[Second]], time.[[Millisecond]], time.[[Microsecond]], time.[[Nanosecond]] - offsetNanoseconds).
+    // b. Perform ? CheckISODaysRange(balanced.[[ISODate]]).
+    // c. Let epochNanoseconds be GetUTCEpochNanoseconds(balanced).
+    // d. If IsValidEpochNanoseconds(epochNanoseconds) is false, throw a RangeError exception.
+    // e. Return epochNanoseconds.
+    date.check_limits()?;
+    check_epoch_nanoseconds(date_time.to_epoch_nanoseconds() - i128::from(offset))
+}
+
+/// Gets the `disambiguation`, `offset` and `overflow` options used to create a zoned date-time.
+fn get_zoned_options(
+    options: &JsValue,
+    default_offset: OffsetOption,
+    context: &mut Context<'_>,
+) -> JsResult<(Disambiguation, OffsetOption, Overflow)> {
+    let options = get_options_object(options)?;
+    let disambiguation =
+        get_option(&options, utf16!("disambiguation"), false, context)?.unwrap_or_default();
+    let offset = get_option(&options, utf16!("offset"), false, context)?.unwrap_or(default_offset);
+    let overflow = get_overflow(&options, context)?;
+    Ok((disambiguation, offset, overflow))
+}
+
+/// Abstract operation [`TemporalZonedDateTimeToString ( zonedDateTime, precision, showCalendar, showTimeZone, showOffset [ , increment, unit, roundingMode ] )`][spec]
+///
+/// Formats an already rounded zoned date-time, without the calendar annotation.
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-temporalzoneddatetimetostring
+fn zoned_date_time_to_string(
+    epoch_ns: i128,
+    time_zone: &TimeZoneSlot,
+    precision: Precision,
+    show_time_zone: ShowTimeZone,
+    show_offset: ShowOffset,
+    context: &Context<'_>,
+) -> String {
+    // 5. Let offsetNanoseconds be GetOffsetNanosecondsFor(timeZone, epochNs).
+    let offset = time_zone.offset_nanoseconds_for(epoch_ns, context);
+
+    // 6. Let isoDateTime be GetISODateTimeFor(timeZone, epochNs).
+    // 7. Let dateTimeString be ISODateTimeToString(isoDateTime, "iso8601", precision, never).
+    let mut result = format_date_time(
+        IsoDateTime::from_epoch_nanoseconds(epoch_ns + i128::from(offset)),
+        precision,
+    );
+
+    // 8. If showOffset is never, then
+    //     a. Let offsetString be the empty String.
+    // 9. Else,
+    //     a. Let offsetString be FormatDateTimeUTCOffsetRounded(offsetNanoseconds).
+    if show_offset == ShowOffset::Auto {
+        result.push_str(&format_offset_rounded(offset));
+    }
+
+    // 10. If showTimeZone is never, then
+    //     a. Let timeZoneString be the empty String.
+    // 11. Else,
+    //     a. If showTimeZone is critical, let flag be "!"; else let flag be the empty String.
+    //     b. Let timeZoneString be the string-concatenation of the code unit 0x005B (LEFT SQUARE BRACKET), flag, timeZone, and the code unit 0x005D (RIGHT SQUARE BRACKET).
+    match show_time_zone {
+        ShowTimeZone::Never => {}
+        ShowTimeZone::Auto => {
+            let _ = write!(result, "[{}]", time_zone.identifier());
+        }
+        ShowTimeZone::Critical => {
+            let _ = write!(result, "[!{}]", time_zone.identifier());
+        }
+    }
+
+    // 12-13. Return the string-concatenation of dateTimeString, offsetString and timeZoneString.
+    result
+}
+
+/// Abstract operation [`ToTemporalZonedDateTime ( item [ , options ] )`][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-totemporalzoneddatetime
+pub(crate) fn to_temporal_zoned_date_time(
+    item: &JsValue,
+    options: &JsValue,
+    context: &mut Context<'_>,
+) -> JsResult<ZonedDateTime> {
+    // 2. Let offsetBehaviour be option.
+    // 3. Let matchBehaviour be match-exactly.
+    let mut match_minutes = false;
+
+    // 4. If item is an Object, then
+    let (date, time, offset_behaviour, time_zone, options) = if let Some(object) = item.as_object()
+    {
+        // a. If item has an [[InitializedTemporalZonedDateTime]] internal slot, then
+        let zoned = object.borrow().as_zoned_date_time().cloned();
+        if let Some(zoned) = zoned {
+            // i. NOTE: The following steps, and similar ones below, read options and perform independent validation in alphabetical order (GetTemporalDisambiguationOption reads "disambiguation", GetTemporalOffsetOption reads "offset", and GetTemporalOverflowOption reads "overflow").
+            // ii-v. Perform ? GetTemporalDisambiguationOption, GetTemporalOffsetOption and GetTemporalOverflowOption.
+            get_zoned_options(options, OffsetOption::Reject, context)?;
+
+            // vi. Return ! CreateTemporalZonedDateTime(item.[[EpochNanoseconds]], item.[[TimeZone]], item.[[Calendar]]).
+            return Ok(zoned);
+        }
+
+        // b. Let calendar be ? GetTemporalCalendarIdentifierWithISODefault(item).
+        get_calendar_with_iso_default(object, context)?;
+
+        // c. Let fields be ? PrepareCalendarFields(calendar, item, « year, month, month-code, day », « hour, minute, second, millisecond, microsecond, nanosecond, offset, time-zone », « time-zone »).
+        let fields = Fields::prepare(object, FieldNames::ZONED, RequiredFields::TimeZone, context)?;
+
+        // d. Let timeZone be fields.[[TimeZone]].
+        // e. Let offsetString be fields.[[OffsetString]].
+        // f-j. Read the options.
+        let options = get_zoned_options(options, OffsetOption::Reject, context)?;
+
+        // k. Let result be ? InterpretTemporalDateTimeFields(calendar, fields, overflow).
+        let date_time = interpret_date_time_fields(&fields, options.2)?;
+
+        // l. If offsetString is unset, then
+        //     i. Set offsetBehaviour to wall.
+        let offset_behaviour = fields
+            .offset
+            .map_or(OffsetBehaviour::Wall, OffsetBehaviour::Option);
+
+        let time_zone = fields.time_zone.expect("the `timeZone` field is required");
+        (
+            date_time.date,
+            Some(date_time.time),
+            offset_behaviour,
+            time_zone,
+            options,
+        )
+    } else {
+        // 5. Else,
+        //     a. If item is not a String, throw a TypeError exception.
+        let string = require_string(item, "a zoned date-time")?;
+
+        //     b. Let result be ? ParseISODateTime(item, « TemporalDateTimeString[+Zoned] »).
+        //     c. Let annotation be result.[[TimeZone]].[[TimeZoneAnnotation]].
+        //     d. Assert: annotation is not empty.
+        let parsed = parse_date_time(&string)
+            .filter(|parsed| parsed.time_zone.is_some())
+            .ok_or_else(|| invalid_string("zoned date-time", &string))?;
+
+        //     e. Let timeZone be ? ToTemporalTimeZoneIdentifier(annotation).
+        let time_zone = TimeZoneSlot::from_identifier(
+            parsed.time_zone.as_deref().unwrap_or_default(),
+            context,
+        )?;
+
+        //     f. Let offsetString be result.[[TimeZone]].[[OffsetString]].
+        //     g. If result.[[TimeZone]].[[Z]] is true, then
+        //         i. Set offsetBehaviour to exact.
+        //     h. Else if offsetString is empty, then
+        //         i. Set offsetBehaviour to wall.
+        //     l. Set matchBehaviour to match-minutes.
+        //     m. If offsetString has sub-minute precision, set matchBehaviour to match-exactly.
+        let offset_behaviour = match parsed.offset {
+            Some(ParsedOffset::Z) => OffsetBehaviour::Exact,
+            Some(ParsedOffset::Offset { nanoseconds, exact }) => {
+                match_minutes = !exact;
+                OffsetBehaviour::Option(nanoseconds)
+            }
+            None => OffsetBehaviour::Wall,
+        };
+
+        //     i. Let calendar be result.[[Calendar]].
+        //     j. If calendar is empty, set calendar to "iso8601".
+        //     k. Set calendar to ? CanonicalizeCalendar(calendar).
+        check_calendar_annotation(parsed.calendar.as_deref())?;
+
+        //     m-q. Read the options.
+        let options = get_zoned_options(options, OffsetOption::Reject, context)?;
+
+        //     r. Let isoDate be CreateISODateRecord(result.[[Year]], result.[[Month]], result.[[Day]]).
+        //     s. Let time be result.[[Time]].
+        (
+            parsed.date,
+            parsed.time,
+            offset_behaviour,
+            time_zone,
+            options,
+        )
+    };
+
+    // 6. If offsetBehaviour is option, then
+    //     a. Let offsetNanoseconds be ! ParseDateTimeUTCOffset(offsetString).
+    // 7. Else,
+    //     a. Let offsetNanoseconds be 0.
+    // 8. Let epochNanoseconds be ? InterpretISODateTimeOffset(isoDate, time, offsetBehaviour, offsetNanoseconds, timeZone, disambiguation, offsetOption, matchBehaviour).
+    let (disambiguation, offset_option, _) = options;
+    let epoch_ns = interpret_iso_date_time_offset(
+        date,
+        time,
+        offset_behaviour,
+        &time_zone,
+        disambiguation,
+        offset_option,
+        match_minutes,
+        context,
+    )?;
+
+    // 9. Return ! CreateTemporalZonedDateTime(epochNanoseconds, timeZone, calendar).
+    Ok(ZonedDateTime::new(epoch_ns, time_zone))
+}
@@ -0,0 +1,201 @@
+use std::rc::Rc;
+
+use crate::{
+    context::{ContextBuilder, TimeZoneProvider},
+    js_string, run_test_actions, run_test_actions_with, JsNativeErrorKind, TestAction,
+};
+
+/// A time zone provider with a single `Europe/Test` zone, which follows the 2024 daylight saving
+/// time rules of central Europe.
+struct TestTimeZoneProvider;
+
+impl TestTimeZoneProvider {
+    /// 2024-03-31T01:00:00Z and 2024-10-27T01:00:00Z.
+    const TRANSITIONS: [i64; 2] = [1_711_846_800, 1_729_990_800];
+}
+
+impl TimeZoneProvider for TestTimeZoneProvider {
+    fn normalize(&self, identifier: &str) -> Option<String> {
+        if identifier.eq_ignore_ascii_case("Europe/Test") {
+            Some("Europe/Test".to_owned())
+        } else if identifier.eq_ignore_ascii_case("UTC") {
+            Some("UTC".to_owned())
+        } else {
+            None
+        }
+    }
+
+    fn available_identifiers(&self) -> Vec<String> {
+        vec!["Europe/Test".to_owned(), "UTC".to_owned()]
+    }
+
+    fn offset(&self, identifier: &str, epoch_seconds: i64) -> i32 {
+        if identifier == "UTC" {
+            return 0;
+        }
+        let [start, end] = Self::TRANSITIONS;
+        if (start..end).contains(&epoch_seconds) {
+            7200
+        } else {
+            3600
+        }
+    }
+
+    fn possible_offsets(&self, identifier: &str, local_seconds: i64) -> Vec<i32> {
+        [7200, 3600]
+            .into_iter()
+            .filter(|&offset| self.offset(identifier, local_seconds - i64::from(offset)) == offset)
+            .collect()
+    }
+
+    fn next_transition(&self, identifier: &str, epoch_seconds: i64) -> Option<i64> {
+        if identifier == "UTC" {
+            return None;
+        }
+        Self::TRANSITIONS
+            .into_iter()
+            .find(|&transition| transition > epoch_seconds)
+    }
+
+    fn previous_transition(&self, identifier: &str, epoch_seconds: i64) -> Option<i64> {
+        if identifier == "UTC" {
+            return None;
+        }
+        Self::TRANSITIONS
+            .into_iter()
+            .rev()
+            .find(|&transition| transition < epoch_seconds)
+    }
+}
+
+#[test]
+fn fixed_offset_zones() {
+    run_test_actions([
+        TestAction::run("let zdt = Temporal.ZonedDateTime.from('2020-01-01T12:00[UTC]')"),
+        TestAction::assert_eq(
+            "zdt.toString()",
+            js_string!("2020-01-01T12:00:00+00:00[UTC]"),
+        ),
+        TestAction::assert_eq("zdt.epochMilliseconds", 1_577_880_000_000_i64),
+        TestAction::assert_eq("zdt.hoursInDay", 24),
+        TestAction::assert_eq("zdt.offset", js_string!("+00:00")),
+        TestAction::assert_eq(
+            "new Temporal.ZonedDateTime(0n, '+05:30').toString()",
+            js_string!("1970-01-01T05:30:00+05:30[+05:30]"),
+        ),
+        TestAction::assert_eq(
+            "new Temporal.ZonedDateTime(0n, 'etc/gmt+5').toString({ timeZoneName: 'never' })",
+            js_string!("1969-12-31T19:00:00-05:00"),
+        ),
+        TestAction::assert_eq(
+            "zdt.withTimeZone('-03:00').toPlainDateTime().toString()",
+            js_string!("2020-01-01T09:00:00"),
+        ),
+        TestAction::assert_native_error(
+            "new Temporal.ZonedDateTime(0n, 'Europe/Paris')",
+            JsNativeErrorKind::Range,
+            "unknown time zone `Europe/Paris`",
+        ),
+    ]);
+}
+
+#[test]
+fn arithmetic() {
+    run_test_actions([
+        TestAction::run("let zdt = Temporal.ZonedDateTime.from('2020-01-31T12:00+01:00[+01:00]')"),
+        TestAction::assert_eq(
+            "zdt.add({ months: 1, hours: 1 }).toString()",
+            js_string!("2020-02-29T13:00:00+01:00[+01:00]"),
+        ),
+        TestAction::assert_eq(
+            "zdt.until('2020-02-01T00:00+01:00[+01:00]').toString()",
+            js_string!("PT12H"),
+        ),
+        TestAction::assert_eq(
+            "zdt.since('2020-01-30T00:00+01:00[+01:00]', { largestUnit: 'day' }).toString()",
+            js_string!("P1DT12H"),
+        ),
+        TestAction::assert_eq(
+            "zdt.round({ smallestUnit: 'day' }).toString()",
+            js_string!("2020-02-01T00:00:00+01:00[+01:00]"),
+        ),
+        TestAction::assert_native_error(
+            "zdt.until(zdt.withTimeZone('UTC'), { largestUnit: 'day' })",
+            JsNativeErrorKind::Range,
+            "cannot compute the difference in calendar units between different time zones",
+        ),
+    ]);
+}
+
+#[test]
+fn named_zone_transitions() {
+    let context = &mut ContextBuilder::default()
+        .time_zone_provider(Rc::new(TestTimeZoneProvider))
+        .build()
+        .unwrap();
+
+    run_test_actions_with(
+        [
+            // Skipped wall-clock time.
+            TestAction::assert_eq(
+                "Temporal.ZonedDateTime.from('2024-03-31T02:30[europe/test]').toString()",
+                js_string!("2024-03-31T03:30:00+02:00[Europe/Test]"),
+            ),
+            TestAction::assert_eq(
+                "Temporal.ZonedDateTime.from('2024-03-31T02:30[Europe/Test]', { disambiguation: 'earlier' }).toString()",
+                js_string!("2024-03-31T01:30:00+01:00[Europe/Test]"),
+            ),
+            TestAction::assert_native_error(
+                "Temporal.ZonedDateTime.from('2024-03-31T02:30[Europe/Test]', { disambiguation: 'reject' })",
+                JsNativeErrorKind::Range,
+                "the date-time is ambiguous in its time zone",
+            ),
+            // Repeated wall-clock time.
+            TestAction::assert_eq(
+                "Temporal.ZonedDateTime.from('2024-10-27T02:30[Europe/Test]').offset",
+                js_string!("+02:00"),
+            ),
+            TestAction::assert_eq(
+                "Temporal.ZonedDateTime.from('2024-10-27T02:30[Europe/Test]', { disambiguation: 'later' }).offset",
+                js_string!("+01:00"),
+            ),
+            TestAction::assert_eq(
+                "Temporal.ZonedDateTime.from('2024-10-27T02:30+01:00[Europe/Test]').offset",
+                js_string!("+01:00"),
+            ),
+            TestAction::assert_native_error(
+                "Temporal.ZonedDateTime.from('2024-06-01T12:00+01:00[Europe/Test]')",
+                JsNativeErrorKind::Range,
+                "the offset +01:00 is not valid for the time zone `Europe/Test`",
+            ),
+            // Arithmetic and day lengths.
+            TestAction::run("let zdt = Temporal.ZonedDateTime.from('2024-03-31T01:30[Europe/Test]')"),
+            TestAction::assert_eq("zdt.hoursInDay", 23),
+            TestAction::assert_eq(
+                "zdt.add({ hours: 1 }).toString()",
+                js_string!("2024-03-31T03:30:00+02:00[Europe/Test]"),
+            ),
+            TestAction::assert_eq(
+                "zdt.add({ days: 1 }).toString()",
+                js_string!("2024-04-01T01:30:00+02:00[Europe/Test]"),
+            ),
+            TestAction::assert_eq(
+                "zdt.until('2024-04-01T01:30[Europe/Test]', { largestUnit: 'hour' }).toString()",
+                js_string!("PT23H"),
+            ),
+            TestAction::assert_eq(
+                "zdt.with({ hour: 12 }).startOfDay().toString()",
+                js_string!("2024-03-31T00:00:00+01:00[Europe/Test]"),
+            ),
+            TestAction::assert_eq(
+                "Temporal.ZonedDateTime.from('2024-01-01T00:00[Europe/Test]').getTimeZoneTransition('next').toString()",
+                js_string!("2024-03-31T03:00:00+02:00[Europe/Test]"),
+            ),
+            TestAction::assert_eq(
+                "zdt.getTimeZoneTransition({ direction: 'previous' })",
+                crate::JsValue::null(),
+            ),
+        ],
+        context,
+    );
+}
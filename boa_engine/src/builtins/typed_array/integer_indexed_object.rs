@@ -25,8 +25,10 @@ pub struct IntegerIndexed {
     #[unsafe_ignore_trace]
     typed_array_name: TypedArrayKind,
     byte_offset: u64,
-    byte_length: u64,
-    array_length: u64,
+    /// The `[[ByteLength]]` internal slot, which is `None` for length-tracking typed arrays.
+    byte_length: Option<u64>,
+    /// The `[[ArrayLength]]` internal slot, which is `None` for length-tracking typed arrays.
+    array_length: Option<u64>,
 }
 
 impl IntegerIndexed {
@@ -41,8 +43,8 @@ impl IntegerIndexed {
             viewed_array_buffer,
             typed_array_name,
             byte_offset,
-            byte_length,
-            array_length,
+            byte_length: Some(byte_length),
+            array_length: Some(array_length),
         }
     }
 
@@ -57,7 +59,7 @@ impl IntegerIndexed {
     pub(crate) fn is_detached(&self) -> bool {
         if let Some(obj) = &self.viewed_array_buffer {
             obj.borrow()
-                .as_array_buffer()
+                .as_buffer()
                 .expect("Typed array must have internal array buffer object")
                 .is_detached_buffer()
         } else {
@@ -65,6 +67,85 @@ impl IntegerIndexed {
         }
     }
 
+    /// Gets the byte length of the viewed buffer, or `None` if the buffer is detached.
+    ///
+    /// Typed arrays without a viewed buffer behave as if their buffer had exactly the size of the
+    /// typed array.
+    fn buffer_byte_length(&self) -> Option<u64> {
+        let Some(obj) = &self.viewed_array_buffer else {
+            return Some(self.byte_offset + self.byte_length.unwrap_or_default());
+        };
+        let buffer = obj.borrow();
+        let buffer = buffer
+            .as_buffer()
+            .expect("Typed array must have internal array buffer object");
+
+        (!buffer.is_detached_buffer()).then(|| buffer.array_buffer_byte_length())
+    }
+
+    /// Abstract operation `IsTypedArrayOutOfBounds ( taRecord )`.
+    ///
+    /// Checks if the typed array is outside the bounds of its viewed buffer, which is always the
+    /// case if the buffer is detached.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-istypedarrayoutofbounds
+    pub(crate) fn is_out_of_bounds(&self) -> bool {
+        self.checked_array_length().is_none()
+    }
+
+    /// Abstract operation `TypedArrayLength ( taRecord )`, returning `None` if the typed array is
+    /// out of bounds.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-typedarraylength
+    fn checked_array_length(&self) -> Option<u64> {
+        // IsTypedArrayOutOfBounds ( taRecord )
+        // 1. Let O be taRecord.[[Object]].
+        // 2. Let bufferByteLength be taRecord.[[CachedBufferByteLength]].
+        // 3. Assert: IsDetachedBuffer(O.[[ViewedArrayBuffer]]) is true if and only if bufferByteLength is detached.
+        // 4. If bufferByteLength is detached, return true.
+        let buffer_byte_length = self.buffer_byte_length()?;
+
+        // 5. Let byteOffsetStart be O.[[ByteOffset]].
+        // 6. If O.[[ArrayLength]] is auto, then
+        //     a. Let byteOffsetEnd be bufferByteLength.
+        // 7. Else,
+        //     a. Let elementSize be TypedArrayElementSize(O).
+        //     b. Let byteOffsetEnd be byteOffsetStart + O.[[ArrayLength]] × elementSize.
+        // 8. If byteOffsetStart > bufferByteLength or byteOffsetEnd > bufferByteLength, return true.
+        // 9. NOTE: 0-length TypedArrays are not considered out-of-bounds.
+        // 10. Return false.
+        if self.byte_offset > buffer_byte_length {
+            return None;
+        }
+
+        match self.array_length {
+            // TypedArrayLength ( taRecord )
+            // 1. Assert: IsTypedArrayOutOfBounds(taRecord) is false.
+            // 2. Let O be taRecord.[[Object]].
+            // 3. If O.[[ArrayLength]] is not auto, return O.[[ArrayLength]].
+            Some(array_length) => {
+                let byte_offset_end =
+                    self.byte_offset + array_length * self.typed_array_name.element_size();
+                (byte_offset_end <= buffer_byte_length).then_some(array_length)
+            }
+            // 4. Assert: IsFixedLengthArrayBuffer(O.[[ViewedArrayBuffer]]) is false.
+            // 5. Let byteOffset be O.[[ByteOffset]].
+            // 6. Let elementSize be TypedArrayElementSize(O).
+            // 7. Let byteLength be taRecord.[[CachedBufferByteLength]].
+            // 8. Assert: byteLength is not detached.
+            // 9. Return floor((byteLength - byteOffset) / elementSize).
+            None => {
+                Some((buffer_byte_length - self.byte_offset) / self.typed_array_name.element_size())
+            }
+        }
+    }
+
     /// Get the integer indexed object's byte offset.
     pub(crate) const fn byte_offset(&self) -> u64 {
         self.byte_offset
@@ -91,22 +172,52 @@ impl IntegerIndexed {
     }
 
     /// Get the integer indexed object's byte length.
-    pub const fn byte_length(&self) -> u64 {
+    ///
+    /// This implements `TypedArrayByteLength ( taRecord )`, returning `0` if the typed array is
+    /// out of bounds.
+    pub fn byte_length(&self) -> u64 {
+        // 1. If IsTypedArrayOutOfBounds(taRecord) is true, return 0.
+        // 2. Let length be TypedArrayLength(taRecord).
+        let Some(length) = self.checked_array_length() else {
+            return 0;
+        };
+
+        // 3. If length = 0, return 0.
+        // 4. Let O be taRecord.[[Object]].
+        // 5. If O.[[ByteLength]] is not auto, return O.[[ByteLength]].
+        // 6. Let elementSize be TypedArrayElementSize(O).
+        // 7. Return length × elementSize.
         self.byte_length
+            .unwrap_or_else(|| length * self.typed_array_name.element_size())
     }
 
     /// Set the integer indexed object's byte length.
     pub(crate) fn set_byte_length(&mut self, byte_length: u64) {
-        self.byte_length = byte_length;
+        self.byte_length = Some(byte_length);
     }
 
     /// Get the integer indexed object's array length.
-    pub const fn array_length(&self) -> u64 {
-        self.array_length
+    ///
+    /// This implements `TypedArrayLength ( taRecord )`, returning `0` if the typed array is out
+    /// of bounds.
+    pub fn array_length(&self) -> u64 {
+        self.checked_array_length().unwrap_or_default()
     }
 
     /// Set the integer indexed object's array length.
     pub(crate) fn set_array_length(&mut self, array_length: u64) {
-        self.array_length = array_length;
+        self.array_length = Some(array_length);
+    }
+
+    /// Checks if the integer indexed object tracks the length of its viewed buffer.
+    pub(crate) const fn is_length_tracking(&self) -> bool {
+        self.array_length.is_none()
+    }
+
+    /// Makes the integer indexed object track the length of its viewed buffer, setting its
+    /// `[[ByteLength]]` and `[[ArrayLength]]` internal slots to `auto`.
+    pub(crate) fn set_length_tracking(&mut self) {
+        self.byte_length = None;
+        self.array_length = None;
     }
 }
@@ -14,7 +14,7 @@
 
 use crate::{
     builtins::{
-        array::ArrayIterator,
+        array::{sort_indexed_properties, ArrayIterator, Holes},
         array_buffer::{ArrayBuffer, SharedMemoryOrder},
         iterable::iterable_to_list,
         typed_array::integer_indexed_object::{ContentType, IntegerIndexed},
@@ -23,7 +23,12 @@ use crate::{
     context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
     error::JsNativeError,
     js_string,
-    object::{internal_methods::get_prototype_from_constructor, JsObject, ObjectData, ObjectKind},
+    object::{
+        internal_methods::{
+            get_prototype_from_constructor, integer_indexed_element_set, is_valid_integer_index,
+        },
+        JsObject, ObjectData, ObjectKind,
+    },
     property::{Attribute, PropertyNameKind},
     realm::Realm,
     string::utf16,
@@ -37,6 +42,9 @@ use std::cmp::Ordering;
 
 pub mod integer_indexed_object;
 
+#[cfg(test)]
+mod tests;
+
 macro_rules! typed_array {
     ($ty:ident, $variant:ident, $name:literal, $global_object_name:ident) => {
         #[doc = concat!("JavaScript `", $name, "` built-in implementation.")]
@@ -157,7 +165,9 @@ macro_rules! typed_array {
                     if first_argument.is_typed_array() {
                         // 1. Perform ? InitializeTypedArrayFromTypedArray(O, firstArgument).
                         TypedArray::initialize_from_typed_array(&o, first_argument, context)?;
-                    } else if first_argument.is_array_buffer() {
+                    } else if first_argument.is_array_buffer()
+                        || first_argument.is_shared_array_buffer()
+                    {
                         // iii. Else if firstArgument has an [[ArrayBufferData]] internal slot, then
 
                         // 1. If numberOfArgs > 1, let byteOffset be args[1]; else let byteOffset be undefined.
@@ -336,7 +346,10 @@ impl IntrinsicObject for TypedArray {
             .method(Self::some, "some", 1)
             .method(Self::sort, "sort", 1)
             .method(Self::subarray, "subarray", 2)
+            .method(Self::to_reversed, "toReversed", 0)
+            .method(Self::to_sorted, "toSorted", 1)
             .method(Self::values, "values", 0)
+            .method(Self::with, "with", 2)
             // 23.2.3.29 %TypedArray%.prototype.toString ( )
             // The initial value of the %TypedArray%.prototype.toString data property is the same
             // built-in function object as the Array.prototype.toString method defined in 23.1.3.30.
@@ -552,9 +565,9 @@ impl TypedArray {
         let o = obj_borrow.as_typed_array().ok_or_else(|| {
             JsNativeError::typ().with_message("Value is not a typed array object")
         })?;
-        if o.is_detached() {
+        if o.is_out_of_bounds() {
             return Err(JsNativeError::typ()
-                .with_message("Buffer of the typed array is detached")
+                .with_message("typed array is outside the bounds of its inner buffer")
                 .into());
         }
 
@@ -633,15 +646,10 @@ impl TypedArray {
             JsNativeError::typ().with_message("Value is not a typed array object")
         })?;
 
-        // 4. Let buffer be O.[[ViewedArrayBuffer]].
-        // 5. If IsDetachedBuffer(buffer) is true, return +0𝔽.
-        // 6. Let size be O.[[ByteLength]].
-        // 7. Return 𝔽(size).
-        if typed_array.is_detached() {
-            Ok(0.into())
-        } else {
-            Ok(typed_array.byte_length().into())
-        }
+        // 4. Let taRecord be MakeTypedArrayWithBufferWitnessRecord(O, seq-cst).
+        // 5. Let size be TypedArrayByteLength(taRecord).
+        // 6. Return 𝔽(size).
+        Ok(typed_array.byte_length().into())
     }
 
     /// `23.2.3.4 get %TypedArray%.prototype.byteOffset`
@@ -666,11 +674,11 @@ impl TypedArray {
             JsNativeError::typ().with_message("Value is not a typed array object")
         })?;
 
-        // 4. Let buffer be O.[[ViewedArrayBuffer]].
-        // 5. If IsDetachedBuffer(buffer) is true, return +0𝔽.
+        // 4. Let taRecord be MakeTypedArrayWithBufferWitnessRecord(O, seq-cst).
+        // 5. If IsTypedArrayOutOfBounds(taRecord) is true, return +0𝔽.
         // 6. Let offset be O.[[ByteOffset]].
         // 7. Return 𝔽(offset).
-        if typed_array.is_detached() {
+        if typed_array.is_out_of_bounds() {
             Ok(0.into())
         } else {
             Ok(typed_array.byte_offset().into())
@@ -700,9 +708,9 @@ impl TypedArray {
             })?;
 
             // 2. Perform ? ValidateTypedArray(O).
-            if o.is_detached() {
+            if o.is_out_of_bounds() {
                 return Err(JsNativeError::typ()
-                    .with_message("Buffer of the typed array is detached")
+                    .with_message("typed array is outside the bounds of its inner buffer")
                     .into());
             }
 
@@ -766,32 +774,39 @@ impl TypedArray {
         if count > 0 {
             // a. NOTE: The copying must be performed in a manner that preserves the bit-level encoding of the source data.
             // b. Let buffer be O.[[ViewedArrayBuffer]].
-            // c. If IsDetachedBuffer(buffer) is true, throw a TypeError exception.
-            if o.is_detached() {
+            // c. Set taRecord to MakeTypedArrayWithBufferWitnessRecord(O, seq-cst).
+            // d. If IsTypedArrayOutOfBounds(taRecord) is true, throw a TypeError exception.
+            if o.is_out_of_bounds() {
                 return Err(JsNativeError::typ()
-                    .with_message("Buffer of the typed array is detached")
+                    .with_message("typed array is outside the bounds of its inner buffer")
                     .into());
             }
 
-            // d. Let typedArrayName be the String value of O.[[TypedArrayName]].
+            // e. Set len to TypedArrayLength(taRecord).
+            let len = o.array_length() as i64;
+
+            // f. Let typedArrayName be the String value of O.[[TypedArrayName]].
             let typed_array_name = o.typed_array_name();
 
-            // e. Let elementSize be the Element Size value specified in Table 73 for typedArrayName.
+            // g. Let elementSize be the Element Size value specified in Table 73 for typedArrayName.
             let element_size = typed_array_name.element_size() as i64;
 
-            // f. Let byteOffset be O.[[ByteOffset]].
+            // h. Let byteOffset be O.[[ByteOffset]].
             let byte_offset = o.byte_offset() as i64;
 
-            // g. Let toByteIndex be to × elementSize + byteOffset.
+            // i. Let bufferByteLimit be len × elementSize + byteOffset.
+            let buffer_byte_limit = len * element_size + byte_offset;
+
+            // j. Let toByteIndex be to × elementSize + byteOffset.
             let mut to_byte_index = to * element_size + byte_offset;
 
-            // h. Let fromByteIndex be from × elementSize + byteOffset.
+            // k. Let fromByteIndex be from × elementSize + byteOffset.
             let mut from_byte_index = from * element_size + byte_offset;
 
-            // i. Let countBytes be count × elementSize.
+            // l. Let countBytes be count × elementSize.
             let mut count_bytes = count * element_size;
 
-            // j. If fromByteIndex < toByteIndex and toByteIndex < fromByteIndex + countBytes, then
+            // m. If fromByteIndex < toByteIndex and toByteIndex < fromByteIndex + countBytes, then
             let direction = if from_byte_index < to_byte_index
                 && to_byte_index < from_byte_index + count_bytes
             {
@@ -804,7 +819,7 @@ impl TypedArray {
                 // i. Let direction be -1.
                 -1
             }
-            // k. Else,
+            // n. Else,
             else {
                 // i. Let direction be 1.
                 1
@@ -814,13 +829,20 @@ impl TypedArray {
                 .viewed_array_buffer()
                 .expect("Already checked for detached buffer");
             let mut buffer_obj_borrow = buffer_obj.borrow_mut();
-            let buffer = buffer_obj_borrow
-                .as_array_buffer_mut()
+            let mut buffer = buffer_obj_borrow
+                .as_buffer_mut()
                 .expect("Already checked for detached buffer");
 
-            // l. Repeat, while countBytes > 0,
+            // o. Repeat, while countBytes > 0,
             while count_bytes > 0 {
-                // i. Let value be GetValueFromBuffer(buffer, fromByteIndex, Uint8, true, Unordered).
+                // i. If fromByteIndex < bufferByteLimit and toByteIndex < bufferByteLimit, then
+                // ii. Else,
+                if from_byte_index >= buffer_byte_limit || to_byte_index >= buffer_byte_limit {
+                    // 1. Set countBytes to 0.
+                    break;
+                }
+
+                // 1. Let value be GetValueFromBuffer(buffer, fromByteIndex, Uint8, true, Unordered).
                 let value = buffer.get_value_from_buffer(
                     from_byte_index as u64,
                     TypedArrayKind::Uint8,
@@ -829,7 +851,7 @@ impl TypedArray {
                     None,
                 );
 
-                // ii. Perform SetValueInBuffer(buffer, toByteIndex, Uint8, value, true, Unordered).
+                // 2. Perform SetValueInBuffer(buffer, toByteIndex, Uint8, value, true, Unordered).
                 buffer.set_value_in_buffer(
                     to_byte_index as u64,
                     TypedArrayKind::Uint8,
@@ -839,13 +861,13 @@ impl TypedArray {
                     context,
                 )?;
 
-                // iii. Set fromByteIndex to fromByteIndex + direction.
+                // 3. Set fromByteIndex to fromByteIndex + direction.
                 from_byte_index += direction;
 
-                // iv. Set toByteIndex to toByteIndex + direction.
+                // 4. Set toByteIndex to toByteIndex + direction.
                 to_byte_index += direction;
 
-                // v. Set countBytes to countBytes - 1.
+                // 5. Set countBytes to countBytes - 1.
                 count_bytes -= 1;
             }
         }
@@ -869,10 +891,10 @@ impl TypedArray {
         if o.borrow()
             .as_typed_array()
             .ok_or_else(|| JsNativeError::typ().with_message("Value is not a typed array object"))?
-            .is_detached()
+            .is_out_of_bounds()
         {
             return Err(JsNativeError::typ()
-                .with_message("Buffer of the typed array is detached")
+                .with_message("typed array is outside the bounds of its inner buffer")
                 .into());
         }
 
@@ -904,9 +926,9 @@ impl TypedArray {
         let o = obj_borrow.as_typed_array().ok_or_else(|| {
             JsNativeError::typ().with_message("Value is not a typed array object")
         })?;
-        if o.is_detached() {
+        if o.is_out_of_bounds() {
             return Err(JsNativeError::typ()
-                .with_message("Buffer of the typed array is detached")
+                .with_message("typed array is outside the bounds of its inner buffer")
                 .into());
         }
 
@@ -971,9 +993,9 @@ impl TypedArray {
         let o = obj_borrow.as_typed_array().ok_or_else(|| {
             JsNativeError::typ().with_message("Value is not a typed array object")
         })?;
-        if o.is_detached() {
+        if o.is_out_of_bounds() {
             return Err(JsNativeError::typ()
-                .with_message("Buffer of the typed array is detached")
+                .with_message("typed array is outside the bounds of its inner buffer")
                 .into());
         }
 
@@ -1018,9 +1040,9 @@ impl TypedArray {
         };
 
         // 14. If IsDetachedBuffer(O.[[ViewedArrayBuffer]]) is true, throw a TypeError exception.
-        if o.is_detached() {
+        if o.is_out_of_bounds() {
             return Err(JsNativeError::typ()
-                .with_message("Buffer of the typed array is detached")
+                .with_message("typed array is outside the bounds of its inner buffer")
                 .into());
         }
 
@@ -1059,9 +1081,9 @@ impl TypedArray {
         let o = obj_borrow.as_typed_array().ok_or_else(|| {
             JsNativeError::typ().with_message("Value is not a typed array object")
         })?;
-        if o.is_detached() {
+        if o.is_out_of_bounds() {
             return Err(JsNativeError::typ()
-                .with_message("Buffer of the typed array is detached")
+                .with_message("typed array is outside the bounds of its inner buffer")
                 .into());
         }
 
@@ -1147,9 +1169,9 @@ impl TypedArray {
         let o = obj_borrow.as_typed_array().ok_or_else(|| {
             JsNativeError::typ().with_message("Value is not a typed array object")
         })?;
-        if o.is_detached() {
+        if o.is_out_of_bounds() {
             return Err(JsNativeError::typ()
-                .with_message("Buffer of the typed array is detached")
+                .with_message("typed array is outside the bounds of its inner buffer")
                 .into());
         }
 
@@ -1213,9 +1235,9 @@ impl TypedArray {
         let o = obj_borrow.as_typed_array().ok_or_else(|| {
             JsNativeError::typ().with_message("Value is not a typed array object")
         })?;
-        if o.is_detached() {
+        if o.is_out_of_bounds() {
             return Err(JsNativeError::typ()
-                .with_message("Buffer of the typed array is detached")
+                .with_message("typed array is outside the bounds of its inner buffer")
                 .into());
         }
 
@@ -1277,9 +1299,9 @@ impl TypedArray {
         let o = obj_borrow.as_typed_array().ok_or_else(|| {
             JsNativeError::typ().with_message("Value is not a typed array object")
         })?;
-        if o.is_detached() {
+        if o.is_out_of_bounds() {
             return Err(JsNativeError::typ()
-                .with_message("Buffer of the typed array is detached")
+                .with_message("typed array is outside the bounds of its inner buffer")
                 .into());
         }
 
@@ -1336,9 +1358,9 @@ impl TypedArray {
         let o = obj_borrow.as_typed_array().ok_or_else(|| {
             JsNativeError::typ().with_message("Value is not a typed array object")
         })?;
-        if o.is_detached() {
+        if o.is_out_of_bounds() {
             return Err(JsNativeError::typ()
-                .with_message("Buffer of the typed array is detached")
+                .with_message("typed array is outside the bounds of its inner buffer")
                 .into());
         }
 
@@ -1415,9 +1437,9 @@ impl TypedArray {
         let o = obj_borrow.as_typed_array().ok_or_else(|| {
             JsNativeError::typ().with_message("Value is not a typed array object")
         })?;
-        if o.is_detached() {
+        if o.is_out_of_bounds() {
             return Err(JsNativeError::typ()
-                .with_message("Buffer of the typed array is detached")
+                .with_message("typed array is outside the bounds of its inner buffer")
                 .into());
         }
 
@@ -1503,9 +1525,9 @@ impl TypedArray {
         let o = obj_borrow.as_typed_array().ok_or_else(|| {
             JsNativeError::typ().with_message("Value is not a typed array object")
         })?;
-        if o.is_detached() {
+        if o.is_out_of_bounds() {
             return Err(JsNativeError::typ()
-                .with_message("Buffer of the typed array is detached")
+                .with_message("typed array is outside the bounds of its inner buffer")
                 .into());
         }
 
@@ -1565,10 +1587,10 @@ impl TypedArray {
         if o.borrow()
             .as_typed_array()
             .ok_or_else(|| JsNativeError::typ().with_message("Value is not a typed array object"))?
-            .is_detached()
+            .is_out_of_bounds()
         {
             return Err(JsNativeError::typ()
-                .with_message("Buffer of the typed array is detached")
+                .with_message("typed array is outside the bounds of its inner buffer")
                 .into());
         }
 
@@ -1600,9 +1622,9 @@ impl TypedArray {
         let o = obj_borrow.as_typed_array().ok_or_else(|| {
             JsNativeError::typ().with_message("Value is not a typed array object")
         })?;
-        if o.is_detached() {
+        if o.is_out_of_bounds() {
             return Err(JsNativeError::typ()
-                .with_message("Buffer of the typed array is detached")
+                .with_message("typed array is outside the bounds of its inner buffer")
                 .into());
         }
 
@@ -1678,15 +1700,11 @@ impl TypedArray {
             JsNativeError::typ().with_message("Value is not a typed array object")
         })?;
 
-        // 4. Let buffer be O.[[ViewedArrayBuffer]].
-        // 5. If IsDetachedBuffer(buffer) is true, return +0𝔽.
-        // 6. Let length be O.[[ArrayLength]].
+        // 4. Let taRecord be MakeTypedArrayWithBufferWitnessRecord(O, seq-cst).
+        // 5. If IsTypedArrayOutOfBounds(taRecord) is true, return +0𝔽.
+        // 6. Let length be TypedArrayLength(taRecord).
         // 7. Return 𝔽(length).
-        if typed_array.is_detached() {
-            Ok(0.into())
-        } else {
-            Ok(typed_array.array_length().into())
-        }
+        Ok(typed_array.array_length().into())
     }
 
     /// `23.2.3.20 %TypedArray%.prototype.map ( callbackfn [ , thisArg ] )`
@@ -1709,9 +1727,9 @@ impl TypedArray {
         let o = obj_borrow.as_typed_array().ok_or_else(|| {
             JsNativeError::typ().with_message("Value is not a typed array object")
         })?;
-        if o.is_detached() {
+        if o.is_out_of_bounds() {
             return Err(JsNativeError::typ()
-                .with_message("Buffer of the typed array is detached")
+                .with_message("typed array is outside the bounds of its inner buffer")
                 .into());
         }
 
@@ -1775,9 +1793,9 @@ impl TypedArray {
         let o = obj_borrow.as_typed_array().ok_or_else(|| {
             JsNativeError::typ().with_message("Value is not a typed array object")
         })?;
-        if o.is_detached() {
+        if o.is_out_of_bounds() {
             return Err(JsNativeError::typ()
-                .with_message("Buffer of the typed array is detached")
+                .with_message("typed array is outside the bounds of its inner buffer")
                 .into());
         }
 
@@ -1860,9 +1878,9 @@ impl TypedArray {
         let o = obj_borrow.as_typed_array().ok_or_else(|| {
             JsNativeError::typ().with_message("Value is not a typed array object")
         })?;
-        if o.is_detached() {
+        if o.is_out_of_bounds() {
             return Err(JsNativeError::typ()
-                .with_message("Buffer of the typed array is detached")
+                .with_message("typed array is outside the bounds of its inner buffer")
                 .into());
         }
 
@@ -1948,9 +1966,9 @@ impl TypedArray {
         let o = obj_borrow.as_typed_array().ok_or_else(|| {
             JsNativeError::typ().with_message("Value is not a typed array object")
         })?;
-        if o.is_detached() {
+        if o.is_out_of_bounds() {
             return Err(JsNativeError::typ()
-                .with_message("Buffer of the typed array is detached")
+                .with_message("typed array is outside the bounds of its inner buffer")
                 .into());
         }
 
@@ -2072,9 +2090,9 @@ impl TypedArray {
 
         // 1. Let targetBuffer be target.[[ViewedArrayBuffer]].
         // 2. If IsDetachedBuffer(targetBuffer) is true, throw a TypeError exception.
-        if target_array.is_detached() {
+        if target_array.is_out_of_bounds() {
             return Err(JsNativeError::typ()
-                .with_message("Buffer of the typed array is detached")
+                .with_message("typed array is outside the bounds of its inner buffer")
                 .into());
         }
         let target_buffer_obj = target_array
@@ -2086,9 +2104,9 @@ impl TypedArray {
 
         // 4. Let srcBuffer be source.[[ViewedArrayBuffer]].
         // 5. If IsDetachedBuffer(srcBuffer) is true, throw a TypeError exception.
-        if source_array.is_detached() {
+        if source_array.is_out_of_bounds() {
             return Err(JsNativeError::typ()
-                .with_message("Buffer of the typed array is detached")
+                .with_message("typed array is outside the bounds of its inner buffer")
                 .into());
         }
         let mut src_buffer_obj = source_array
@@ -2146,37 +2164,32 @@ impl TypedArray {
                 .into());
         }
 
-        // TODO: Shared Array Buffer
         // 18. If both IsSharedArrayBuffer(srcBuffer) and IsSharedArrayBuffer(targetBuffer) are true, then
-
-        // a. If srcBuffer.[[ArrayBufferData]] and targetBuffer.[[ArrayBufferData]] are the same Shared Data Block values, let same be true; else let same be false.
-
+        //     a. If srcBuffer.[[ArrayBufferData]] and targetBuffer.[[ArrayBufferData]] are the same Shared Data Block values, let same be true; else let same be false.
         // 19. Else, let same be SameValue(srcBuffer, targetBuffer).
-        let same = JsObject::equals(&src_buffer_obj, target_buffer_obj);
+        let same = JsObject::equals(&src_buffer_obj, target_buffer_obj) || {
+            let src_buffer = src_buffer_obj.borrow();
+            let target_buffer = target_buffer_obj.borrow();
+            let src_buffer = src_buffer
+                .as_buffer()
+                .expect("Already checked for detached buffer");
+            let target_buffer = target_buffer
+                .as_buffer()
+                .expect("Already checked for detached buffer");
+            src_buffer.same_data_block(target_buffer)
+        };
 
         // 20. If same is true, then
         let mut src_byte_index = if same {
             // a. Let srcByteLength be source.[[ByteLength]].
             let src_byte_length = source_array.byte_length();
 
-            // b. Set srcBuffer to ? CloneArrayBuffer(srcBuffer, srcByteOffset, srcByteLength, %ArrayBuffer%).
-            // c. NOTE: %ArrayBuffer% is used to clone srcBuffer because is it known to not have any observable side-effects.
-            let array_buffer_constructor = context
-                .intrinsics()
-                .constructors()
-                .array_buffer()
-                .constructor()
-                .into();
+            // b. Set srcBuffer to ? CloneArrayBuffer(srcBuffer, srcByteOffset, srcByteLength).
             let s = src_buffer_obj
                 .borrow()
-                .as_array_buffer()
+                .as_buffer()
                 .expect("Already checked for detached buffer")
-                .clone_array_buffer(
-                    src_byte_offset,
-                    src_byte_length,
-                    &array_buffer_constructor,
-                    context,
-                )?;
+                .clone_array_buffer(src_byte_offset, src_byte_length, context)?;
             src_buffer_obj = s;
 
             // d. Let srcByteIndex be 0.
@@ -2195,7 +2208,7 @@ impl TypedArray {
 
         let src_buffer_obj_borrow = src_buffer_obj.borrow();
         let src_buffer = src_buffer_obj_borrow
-            .as_array_buffer()
+            .as_buffer()
             .expect("Must be an array buffer");
 
         // 24. If srcType is the same as targetType, then
@@ -2215,7 +2228,7 @@ impl TypedArray {
                 // ii. Perform SetValueInBuffer(targetBuffer, targetByteIndex, Uint8, value, true, Unordered).
                 target_buffer_obj
                     .borrow_mut()
-                    .as_array_buffer_mut()
+                    .as_buffer_mut()
                     .expect("Must be an array buffer")
                     .set_value_in_buffer(
                         target_byte_index,
@@ -2249,7 +2262,7 @@ impl TypedArray {
                 // ii. Perform SetValueInBuffer(targetBuffer, targetByteIndex, targetType, value, true, Unordered).
                 target_buffer_obj
                     .borrow_mut()
-                    .as_array_buffer_mut()
+                    .as_buffer_mut()
                     .expect("Must be an array buffer")
                     .set_value_in_buffer(
                         target_byte_index,
@@ -2283,40 +2296,32 @@ impl TypedArray {
         source: &JsValue,
         context: &mut Context<'_>,
     ) -> JsResult<()> {
-        let target_borrow = target.borrow();
-        let target_array = target_borrow
-            .as_typed_array()
-            .expect("Target must be a typed array");
-
-        // 1. Let targetBuffer be target.[[ViewedArrayBuffer]].
-        // 2. If IsDetachedBuffer(targetBuffer) is true, throw a TypeError exception.
-        if target_array.is_detached() {
-            return Err(JsNativeError::typ()
-                .with_message("Buffer of the typed array is detached")
-                .into());
-        }
-
-        // 3. Let targetLength be target.[[ArrayLength]].
-        let target_length = target_array.array_length();
-
-        // 4. Let targetName be the String value of target.[[TypedArrayName]].
-        // 6. Let targetType be the Element Type value in Table 73 for targetName.
-        let target_name = target_array.typed_array_name();
-
-        // 5. Let targetElementSize be the Element Size value specified in Table 73 for targetName.
-        let target_element_size = target_name.element_size();
+        let target_length = {
+            let target_borrow = target.borrow();
+            let target_array = target_borrow
+                .as_typed_array()
+                .expect("Target must be a typed array");
+
+            // 1. Let targetRecord be MakeTypedArrayWithBufferWitnessRecord(target, seq-cst).
+            // 2. If IsTypedArrayOutOfBounds(targetRecord) is true, throw a TypeError exception.
+            if target_array.is_out_of_bounds() {
+                return Err(JsNativeError::typ()
+                    .with_message("typed array is outside the bounds of its inner buffer")
+                    .into());
+            }
 
-        // 7. Let targetByteOffset be target.[[ByteOffset]].
-        let target_byte_offset = target_array.byte_offset();
+            // 3. Let targetLength be TypedArrayLength(targetRecord).
+            target_array.array_length()
+        };
 
-        // 8. Let src be ? ToObject(source).
+        // 4. Let src be ? ToObject(source).
         let src = source.to_object(context)?;
 
-        // 9. Let srcLength be ? LengthOfArrayLike(src).
+        // 5. Let srcLength be ? LengthOfArrayLike(src).
         let src_length = src.length_of_array_like(context)?;
 
         let target_offset = match target_offset {
-            // 10. If targetOffset is +∞, throw a RangeError exception.
+            // 6. If targetOffset = +∞, throw a RangeError exception.
             IntegerOrInfinity::PositiveInfinity => {
                 return Err(JsNativeError::range()
                     .with_message("Target offset cannot be Infinity")
@@ -2326,68 +2331,30 @@ impl TypedArray {
             _ => unreachable!(),
         };
 
-        // 11. If srcLength + targetOffset > targetLength, throw a RangeError exception.
+        // 7. If srcLength + targetOffset > targetLength, throw a RangeError exception.
         if src_length + target_offset > target_length {
             return Err(JsNativeError::range()
                 .with_message("Source object and target offset longer than target typed array")
                 .into());
         }
 
-        // 12. Let targetByteIndex be targetOffset × targetElementSize + targetByteOffset.
-        let mut target_byte_index = target_offset * target_element_size + target_byte_offset;
-
-        // 13. Let k be 0.
-        let mut k = 0;
-
-        // 14. Let limit be targetByteIndex + targetElementSize × srcLength.
-        let limit = target_byte_index + target_element_size * src_length;
-
-        // 15. Repeat, while targetByteIndex < limit,
-        while target_byte_index < limit {
+        // 8. Let k be 0.
+        // 9. Repeat, while k < srcLength,
+        for k in 0..src_length {
             // a. Let Pk be ! ToString(𝔽(k)).
             // b. Let value be ? Get(src, Pk).
             let value = src.get(k, context)?;
 
-            // c. If target.[[ContentType]] is BigInt, set value to ? ToBigInt(value).
-            // d. Otherwise, set value to ? ToNumber(value).
-            let value = if target_name.content_type() == ContentType::BigInt {
-                value.to_bigint(context)?.into()
-            } else {
-                value.to_number(context)?.into()
-            };
+            // c. Let targetIndex be targetOffset + k.
+            let target_index = target_offset + k;
 
-            let target_buffer_obj = target_array
-                .viewed_array_buffer()
-                .expect("Already checked for detached buffer");
-            let mut target_buffer_obj_borrow = target_buffer_obj.borrow_mut();
-            let target_buffer = target_buffer_obj_borrow
-                .as_array_buffer_mut()
-                .expect("Already checked for detached buffer");
-
-            // e. If IsDetachedBuffer(targetBuffer) is true, throw a TypeError exception.
-            if target_buffer.is_detached_buffer() {
-                return Err(JsNativeError::typ()
-                    .with_message("Cannot set value on detached array buffer")
-                    .into());
-            }
-
-            // f. Perform SetValueInBuffer(targetBuffer, targetByteIndex, targetType, value, true, Unordered).
-            target_buffer.set_value_in_buffer(
-                target_byte_index,
-                target_name,
-                &value,
-                SharedMemoryOrder::Unordered,
-                None,
-                context,
-            )?;
-
-            // g. Set k to k + 1.
-            k += 1;
+            // d. Perform ? TypedArraySetElement(target, targetIndex, value).
+            integer_indexed_element_set(target, target_index as usize, &value, context)?;
 
-            // h. Set targetByteIndex to targetByteIndex + targetElementSize.
-            target_byte_index += target_element_size;
+            // e. Set k to k + 1.
         }
 
+        // 10. Return unused.
         Ok(())
     }
 
@@ -2411,9 +2378,9 @@ impl TypedArray {
         let o = obj_borrow.as_typed_array().ok_or_else(|| {
             JsNativeError::typ().with_message("Value is not a typed array object")
         })?;
-        if o.is_detached() {
+        if o.is_out_of_bounds() {
             return Err(JsNativeError::typ()
-                .with_message("Buffer of the typed array is detached")
+                .with_message("typed array is outside the bounds of its inner buffer")
                 .into());
         }
 
@@ -2461,13 +2428,20 @@ impl TypedArray {
 
         // 14. If count > 0, then
         if count > 0 {
-            // a. If IsDetachedBuffer(O.[[ViewedArrayBuffer]]) is true, throw a TypeError exception.
-            if o.is_detached() {
+            // a. Set taRecord to MakeTypedArrayWithBufferWitnessRecord(O, seq-cst).
+            // b. If IsTypedArrayOutOfBounds(taRecord) is true, throw a TypeError exception.
+            if o.is_out_of_bounds() {
                 return Err(JsNativeError::typ()
-                    .with_message("Buffer of the typed array is detached")
+                    .with_message("typed array is outside the bounds of its inner buffer")
                     .into());
             }
 
+            // c. Set endIndex to min(endIndex, TypedArrayLength(taRecord)).
+            let r#final = std::cmp::min(r#final, o.array_length() as i64);
+
+            // d. Set countBytes to max(endIndex - startIndex, 0).
+            let count = std::cmp::max(r#final - k, 0) as u64;
+
             // b. Let srcName be the String value of O.[[TypedArrayName]].
             // c. Let srcType be the Element Type value in Table 73 for srcName.
             // d. Let targetName be the String value of A.[[TypedArrayName]].
@@ -2499,7 +2473,7 @@ impl TypedArray {
                 let src_buffer_obj = o.viewed_array_buffer().expect("Cannot be detached here");
                 let src_buffer_obj_borrow = src_buffer_obj.borrow();
                 let src_buffer = src_buffer_obj_borrow
-                    .as_array_buffer()
+                    .as_buffer()
                     .expect("Cannot be detached here");
 
                 // ii. Let targetBuffer be A.[[ViewedArrayBuffer]].
@@ -2507,8 +2481,8 @@ impl TypedArray {
                     .viewed_array_buffer()
                     .expect("Cannot be detached here");
                 let mut target_buffer_obj_borrow = target_buffer_obj.borrow_mut();
-                let target_buffer = target_buffer_obj_borrow
-                    .as_array_buffer_mut()
+                let mut target_buffer = target_buffer_obj_borrow
+                    .as_buffer_mut()
                     .expect("Cannot be detached here");
 
                 // iii. Let elementSize be the Element Size value specified in Table 73 for Element Type srcType.
@@ -2584,9 +2558,9 @@ impl TypedArray {
         let o = obj_borrow.as_typed_array().ok_or_else(|| {
             JsNativeError::typ().with_message("Value is not a typed array object")
         })?;
-        if o.is_detached() {
+        if o.is_out_of_bounds() {
             return Err(JsNativeError::typ()
-                .with_message("Buffer of the typed array is detached")
+                .with_message("typed array is outside the bounds of its inner buffer")
                 .into());
         }
 
@@ -2658,190 +2632,250 @@ impl TypedArray {
                 .with_message("TypedArray.sort must be called on typed array object")
         })?;
 
+        // 3. Perform ? ValidateTypedArray(obj).
         // 4. Let buffer be obj.[[ViewedArrayBuffer]].
         // 5. Let len be obj.[[ArrayLength]].
-        let (buffer, len) =
-            {
-                // 3. Perform ? ValidateTypedArray(obj).
-                let obj_borrow = obj.borrow();
-                let o = obj_borrow.as_typed_array().ok_or_else(|| {
-                    JsNativeError::typ()
-                        .with_message("TypedArray.sort must be called on typed array object")
-                })?;
-                if o.is_detached() {
-                    return Err(JsNativeError::typ().with_message(
-                    "TypedArray.sort called on typed array object with detached array buffer",
-                ).into());
-                }
+        let (buffer, len) = Self::validate_for_sort(obj, "TypedArray.sort")?;
 
-                (
-                    o.viewed_array_buffer()
-                        .expect("Already checked for detached buffer")
-                        .clone(),
-                    o.array_length(),
-                )
-            };
+        // 6. NOTE: The following closure performs a numeric comparison rather than the string comparison used in 23.1.3.30.
+        // 7. Let SortCompare be a new Abstract Closure with parameters (x, y) that captures comparefn and performs the following steps when called:
+        //     a. Return ? CompareTypedArrayElements(x, y, comparefn).
+        let sort_compare = |x: &JsValue, y: &JsValue, context: &mut Context<'_>| {
+            compare_typed_array_elements(x, y, compare_fn, &buffer, context)
+        };
 
-        // 4. Let items be a new empty List.
-        let mut items = Vec::with_capacity(len as usize);
+        // 8. Let sortedList be ? SortIndexedProperties(obj, len, SortCompare, read-through-holes).
+        let sorted = sort_indexed_properties(obj, len, sort_compare, Holes::ReadThrough, context)?;
 
-        // 5. Let k be 0.
-        // 6. Repeat, while k < len,
-        for k in 0..len {
-            // a. Let Pk be ! ToString(𝔽(k)).
-            // b. Let kPresent be ? HasProperty(obj, Pk).
-            // c. If kPresent is true, then
-            if obj.has_property(k, context)? {
-                // i. Let kValue be ? Get(obj, Pk).
-                let k_val = obj.get(k, context)?;
-                // ii. Append kValue to items.
-                items.push(k_val);
-            }
-            // d. Set k to k + 1.
+        // 9. Let j be 0.
+        // 10. Repeat, while j < len,
+        for (j, item) in sorted.into_iter().enumerate() {
+            // a. Perform ! Set(obj, ! ToString(𝔽(j)), sortedList[j], true).
+            obj.set(j, item, true, context)?;
+            // b. Set j to j + 1.
         }
 
-        // 7. Let itemCount be the number of elements in items.
-        let item_count = items.len();
-
-        let sort_compare = |x: &JsValue,
-                            y: &JsValue,
-                            compare_fn: Option<&JsObject>,
-                            context: &mut Context<'_>|
-         -> JsResult<Ordering> {
-            // 1. Assert: Both Type(x) and Type(y) are Number or both are BigInt.
-            // 2. If comparefn is not undefined, then
-            if let Some(obj) = compare_fn {
-                // a. Let v be ? ToNumber(? Call(comparefn, undefined, « x, y »)).
-                let v = obj
-                    .call(&JsValue::undefined(), &[x.clone(), y.clone()], context)?
-                    .to_number(context)?;
-
-                // b. If IsDetachedBuffer(buffer) is true, throw a TypeError exception.
-                if buffer
-                    .borrow()
-                    .as_array_buffer()
-                    .expect("Must be array buffer")
-                    .is_detached_buffer()
-                {
-                    return Err(JsNativeError::typ()
-                        .with_message("Cannot sort typed array with detached buffer")
-                        .into());
-                }
+        // 11. Return obj.
+        Ok(obj.clone().into())
+    }
 
-                // c. If v is NaN, return +0𝔽.
-                // d. Return v.
-                return Ok(v.partial_cmp(&0.0).unwrap_or(Ordering::Equal));
+    /// `%TypedArray%.prototype.toSorted ( comparefn )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.tosorted
+    pub(crate) fn to_sorted(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. If comparefn is not undefined and IsCallable(comparefn) is false, throw a TypeError exception.
+        let compare_fn = match args.first() {
+            None | Some(JsValue::Undefined) => None,
+            Some(JsValue::Object(obj)) if obj.is_callable() => Some(obj),
+            _ => {
+                return Err(JsNativeError::typ()
+                    .with_message("TypedArray.toSorted called with non-callable comparefn")
+                    .into())
             }
+        };
 
-            if let (JsValue::BigInt(x), JsValue::BigInt(y)) = (x, y) {
-                // 6. If x < y, return -1𝔽.
-                if x < y {
-                    return Ok(Ordering::Less);
-                }
+        // 2. Let O be the this value.
+        let o = this.as_object().ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("TypedArray.toSorted must be called on typed array object")
+        })?;
 
-                // 7. If x > y, return 1𝔽.
-                if x > y {
-                    return Ok(Ordering::Greater);
-                }
+        // 3. Perform ? ValidateTypedArray(O).
+        // 4. Let len be O.[[ArrayLength]].
+        let (buffer, len) = Self::validate_for_sort(o, "TypedArray.toSorted")?;
 
-                // 8. If x is -0𝔽 and y is +0𝔽, return -1𝔽.
-                if x.is_zero()
-                    && y.is_zero()
-                    && x.as_inner().is_negative()
-                    && y.as_inner().is_positive()
-                {
-                    return Ok(Ordering::Less);
-                }
+        // 5. Let A be ? TypedArrayCreateSameType(O, « 𝔽(len) »).
+        let a = Self::create_same_type(o, len, context)?;
 
-                // 9. If x is +0𝔽 and y is -0𝔽, return 1𝔽.
-                if x.is_zero()
-                    && y.is_zero()
-                    && x.as_inner().is_positive()
-                    && y.as_inner().is_negative()
-                {
-                    return Ok(Ordering::Greater);
-                }
-            } else {
-                let x = x
-                    .as_number()
-                    .expect("Typed array can only contain number or bigint");
-                let y = y
-                    .as_number()
-                    .expect("Typed array can only contain number or bigint");
-
-                // 3. If x and y are both NaN, return +0𝔽.
-                if x.is_nan() && y.is_nan() {
-                    return Ok(Ordering::Equal);
-                }
+        // 6. NOTE: The following closure performs a numeric comparison rather than the string comparison used in 23.1.3.34.
+        // 7. Let SortCompare be a new Abstract Closure with parameters (x, y) that captures comparefn and performs the following steps when called:
+        //     a. Return ? CompareTypedArrayElements(x, y, comparefn).
+        let sort_compare = |x: &JsValue, y: &JsValue, context: &mut Context<'_>| {
+            compare_typed_array_elements(x, y, compare_fn, &buffer, context)
+        };
 
-                // 4. If x is NaN, return 1𝔽.
-                if x.is_nan() {
-                    return Ok(Ordering::Greater);
-                }
+        // 8. Let sortedList be ? SortIndexedProperties(O, len, SortCompare, read-through-holes).
+        let sorted = sort_indexed_properties(o, len, sort_compare, Holes::ReadThrough, context)?;
 
-                // 5. If y is NaN, return -1𝔽.
-                if y.is_nan() {
-                    return Ok(Ordering::Less);
-                }
+        // 9. Let j be 0.
+        // 10. Repeat, while j < len,
+        for (j, item) in sorted.into_iter().enumerate() {
+            // a. Perform ! Set(A, ! ToString(𝔽(j)), sortedList[j], true).
+            a.set(j, item, true, context).expect("Set cannot fail here");
+            // b. Set j to j + 1.
+        }
 
-                // 6. If x < y, return -1𝔽.
-                if x < y {
-                    return Ok(Ordering::Less);
-                }
+        // 11. Return A.
+        Ok(a.into())
+    }
 
-                // 7. If x > y, return 1𝔽.
-                if x > y {
-                    return Ok(Ordering::Greater);
-                }
+    /// `%TypedArray%.prototype.toReversed ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.toreversed
+    pub(crate) fn to_reversed(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? ValidateTypedArray(O).
+        let o = this.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
 
-                // 8. If x is -0𝔽 and y is +0𝔽, return -1𝔽.
-                if x.is_zero() && y.is_zero() && x.is_sign_negative() && y.is_sign_positive() {
-                    return Ok(Ordering::Less);
-                }
+        // 3. Let length be O.[[ArrayLength]].
+        let length = {
+            let o_borrow = o.borrow();
+            let ta = o_borrow.as_typed_array().ok_or_else(|| {
+                JsNativeError::typ().with_message("Value is not a typed array object")
+            })?;
+            if ta.is_out_of_bounds() {
+                return Err(JsNativeError::typ()
+                    .with_message("typed array is outside the bounds of its inner buffer")
+                    .into());
+            }
+            ta.array_length()
+        };
 
-                // 9. If x is +0𝔽 and y is -0𝔽, return 1𝔽.
-                if x.is_zero() && y.is_zero() && x.is_sign_positive() && y.is_sign_negative() {
-                    return Ok(Ordering::Greater);
-                }
+        // 4. Let A be ? TypedArrayCreateSameType(O, « 𝔽(length) »).
+        let a = Self::create_same_type(o, length, context)?;
+
+        // 5. Let k be 0.
+        // 6. Repeat, while k < length,
+        for k in 0..length {
+            // a. Let from be ! ToString(𝔽(length - k - 1)).
+            // b. Let Pk be ! ToString(𝔽(k)).
+            // c. Let fromValue be ! Get(O, from).
+            let from_value = o
+                .get(length - k - 1, context)
+                .expect("Get cannot fail here");
+
+            // d. Perform ! Set(A, Pk, fromValue, true).
+            a.set(k, from_value, true, context)
+                .expect("Set cannot fail here");
+
+            // e. Set k to k + 1.
+        }
+
+        // 7. Return A.
+        Ok(a.into())
+    }
+
+    /// `%TypedArray%.prototype.with ( index, value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.with
+    pub(crate) fn with(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? ValidateTypedArray(O).
+        let o = this.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("Value is not a typed array object")
+        })?;
+
+        // 3. Let len be O.[[ArrayLength]].
+        let (len, content_type) = {
+            let o_borrow = o.borrow();
+            let ta = o_borrow.as_typed_array().ok_or_else(|| {
+                JsNativeError::typ().with_message("Value is not a typed array object")
+            })?;
+            if ta.is_out_of_bounds() {
+                return Err(JsNativeError::typ()
+                    .with_message("typed array is outside the bounds of its inner buffer")
+                    .into());
             }
+            (ta.array_length(), ta.typed_array_name().content_type())
+        };
+
+        // 4. Let relativeIndex be ? ToIntegerOrInfinity(index).
+        let relative_index = args.get_or_undefined(0).to_integer_or_infinity(context)?;
+
+        // 5. If relativeIndex ≥ 0, let actualIndex be relativeIndex.
+        // 6. Else, let actualIndex be len + relativeIndex.
+        let actual_index = match relative_index {
+            IntegerOrInfinity::Integer(i) if i >= 0 => Some(i as u64),
+            IntegerOrInfinity::Integer(i) => len.checked_sub(i.unsigned_abs()),
+            _ => None,
+        };
+
+        // 7. If O.[[ContentType]] is BigInt, let numericValue be ? ToBigInt(value).
+        // 8. Else, let numericValue be ? ToNumber(value).
+        let value = args.get_or_undefined(1);
+        let numeric_value: JsValue = match content_type {
+            ContentType::BigInt => value.to_bigint(context)?.into(),
+            ContentType::Number => value.to_number(context)?.into(),
+        };
 
-            // 10. Return +0𝔽.
-            Ok(Ordering::Equal)
+        // 9. If ! IsValidIntegerIndex(O, 𝔽(actualIndex)) is false, throw a RangeError exception.
+        let Some(actual_index) = actual_index.filter(|&i| is_valid_integer_index(o, i)) else {
+            return Err(JsNativeError::range()
+                .with_message("TypedArray.with: index out of range")
+                .into());
         };
 
-        // 8. Sort items using an implementation-defined sequence of calls to SortCompare.
-        // If any such call returns an abrupt completion, stop before performing any further
-        // calls to SortCompare or steps in this algorithm and return that completion.
-        let mut sort_err = Ok(());
-        items.sort_by(|x, y| {
-            if sort_err.is_ok() {
-                sort_compare(x, y, compare_fn, context).unwrap_or_else(|err| {
-                    sort_err = Err(err);
-                    Ordering::Equal
-                })
+        // 10. Let A be ? TypedArrayCreateSameType(O, « 𝔽(len) »).
+        let a = Self::create_same_type(o, len, context)?;
+
+        // 11. Let k be 0.
+        // 12. Repeat, while k < len,
+        for k in 0..len {
+            // a. Let Pk be ! ToString(𝔽(k)).
+            // b. If k is actualIndex, let fromValue be numericValue.
+            // c. Else, let fromValue be ! Get(O, Pk).
+            let from_value = if k == actual_index {
+                numeric_value.clone()
             } else {
-                Ordering::Equal
-            }
-        });
-        sort_err?;
+                o.get(k, context).expect("Get cannot fail here")
+            };
 
-        // 9. Let j be 0.
-        // 10. Repeat, while j < itemCount,
-        for (j, item) in items.into_iter().enumerate() {
-            // a. Perform ? Set(obj, ! ToString(𝔽(j)), items[j], true).
-            obj.set(j, item, true, context)?;
-            // b. Set j to j + 1.
+            // d. Perform ! Set(A, Pk, fromValue, true).
+            a.set(k, from_value, true, context)
+                .expect("Set cannot fail here");
+
+            // e. Set k to k + 1.
         }
 
-        // 11. Repeat, while j < len,
-        for j in item_count..(len as usize) {
-            // a. Perform ? DeletePropertyOrThrow(obj, ! ToString(𝔽(j))).
-            obj.delete_property_or_throw(j, context)?;
-            // b. Set j to j + 1.
+        // 13. Return A.
+        Ok(a.into())
+    }
+
+    /// Validates the typed array `obj` for the sort methods, returning its viewed buffer and
+    /// length.
+    fn validate_for_sort(obj: &JsObject, method: &str) -> JsResult<(JsObject, u64)> {
+        let obj_borrow = obj.borrow();
+        let o = obj_borrow.as_typed_array().ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message(format!("{method} must be called on typed array object"))
+        })?;
+        if o.is_out_of_bounds() {
+            return Err(JsNativeError::typ()
+                .with_message(format!(
+                    "{method} called on typed array object with detached array buffer"
+                ))
+                .into());
         }
 
-        // 12. Return obj.
-        Ok(obj.clone().into())
+        Ok((
+            o.viewed_array_buffer()
+                .expect("Already checked for detached buffer")
+                .clone(),
+            o.array_length(),
+        ))
     }
 
     /// `23.2.3.28 %TypedArray%.prototype.subarray ( begin, end )`
@@ -2861,74 +2895,87 @@ impl TypedArray {
         let obj = this.as_object().ok_or_else(|| {
             JsNativeError::typ().with_message("Value is not a typed array object")
         })?;
-        let obj_borrow = obj.borrow();
-        let o = obj_borrow.as_typed_array().ok_or_else(|| {
-            JsNativeError::typ().with_message("Value is not a typed array object")
-        })?;
+        let (buffer, src_length, kind, src_byte_offset, length_tracking) = {
+            let obj_borrow = obj.borrow();
+            let o = obj_borrow.as_typed_array().ok_or_else(|| {
+                JsNativeError::typ().with_message("Value is not a typed array object")
+            })?;
 
-        // 4. Let buffer be O.[[ViewedArrayBuffer]].
-        let buffer = o
-            .viewed_array_buffer()
-            .expect("Buffer cannot be detached here");
+            // 4. Let buffer be O.[[ViewedArrayBuffer]].
+            let buffer = o
+                .viewed_array_buffer()
+                .expect("Buffer cannot be detached here")
+                .clone();
 
-        // 5. Let srcLength be O.[[ArrayLength]].
-        let src_length = o.array_length() as i64;
+            // 5. Let srcRecord be MakeTypedArrayWithBufferWitnessRecord(O, seq-cst).
+            // 6. If IsTypedArrayOutOfBounds(srcRecord) is true, then
+            //     a. Let srcLength be 0.
+            // 7. Else,
+            //     a. Let srcLength be TypedArrayLength(srcRecord).
+            let src_length = o.array_length() as i64;
+
+            (
+                buffer,
+                src_length,
+                o.typed_array_name(),
+                o.byte_offset(),
+                o.is_length_tracking(),
+            )
+        };
 
-        // 6. Let relativeBegin be ? ToIntegerOrInfinity(begin).
+        // 8. Let relativeStart be ? ToIntegerOrInfinity(start).
         let begin_index = match args.get_or_undefined(0).to_integer_or_infinity(context)? {
-            // 7. If relativeBegin is -∞, let beginIndex be 0.
+            // 9. If relativeStart is -∞, let startIndex be 0.
             IntegerOrInfinity::NegativeInfinity => 0,
-            // 8. Else if relativeBegin < 0, let beginIndex be max(srcLength + relativeBegin, 0).
+            // 10. Else if relativeStart < 0, let startIndex be max(srcLength + relativeStart, 0).
             IntegerOrInfinity::Integer(i) if i < 0 => std::cmp::max(src_length + i, 0),
-            // 9. Else, let beginIndex be min(relativeBegin, srcLength).
+            // 11. Else, let startIndex be min(relativeStart, srcLength).
             IntegerOrInfinity::Integer(i) => std::cmp::min(i, src_length),
             IntegerOrInfinity::PositiveInfinity => src_length,
         };
 
-        // 10. If end is undefined, let relativeEnd be srcLength; else let relativeEnd be ? ToIntegerOrInfinity(end).
-        let end = args.get_or_undefined(1);
-        let relative_end = if end.is_undefined() {
-            IntegerOrInfinity::Integer(src_length)
-        } else {
-            end.to_integer_or_infinity(context)?
-        };
+        // 12. Let elementSize be TypedArrayElementSize(O).
+        let element_size = kind.element_size();
 
-        let end_index = match relative_end {
-            // 11. If relativeEnd is -∞, let endIndex be 0.
-            IntegerOrInfinity::NegativeInfinity => 0,
-            // 12. Else if relativeEnd < 0, let endIndex be max(srcLength + relativeEnd, 0).
-            IntegerOrInfinity::Integer(i) if i < 0 => std::cmp::max(src_length + i, 0),
-            // 13. Else, let endIndex be min(relativeEnd, srcLength).
-            IntegerOrInfinity::Integer(i) => std::cmp::min(i, src_length),
-            IntegerOrInfinity::PositiveInfinity => src_length,
-        };
+        // 13. Let srcByteOffset be O.[[ByteOffset]].
+        // 14. Let beginByteOffset be srcByteOffset + (startIndex × elementSize).
+        let begin_byte_offset = src_byte_offset + begin_index as u64 * element_size;
 
-        // 14. Let newLength be max(endIndex - beginIndex, 0).
-        let new_length = std::cmp::max(end_index - begin_index, 0);
+        let end = args.get_or_undefined(1);
 
-        // 15. Let constructorName be the String value of O.[[TypedArrayName]].
-        // 16. Let elementSize be the Element Size value specified in Table 73 for constructorName.
-        let element_size = o.typed_array_name().element_size();
+        // 15. If O.[[ArrayLength]] is auto and end is undefined, then
+        let arguments_list = if length_tracking && end.is_undefined() {
+            // a. Let argumentsList be « buffer, 𝔽(beginByteOffset) ».
+            vec![buffer.into(), begin_byte_offset.into()]
+        }
+        // 16. Else,
+        else {
+            // a. If end is undefined, let relativeEnd be srcLength; else let relativeEnd be ? ToIntegerOrInfinity(end).
+            let relative_end = if end.is_undefined() {
+                IntegerOrInfinity::Integer(src_length)
+            } else {
+                end.to_integer_or_infinity(context)?
+            };
 
-        // 17. Let srcByteOffset be O.[[ByteOffset]].
-        let src_byte_offset = o.byte_offset();
+            let end_index = match relative_end {
+                // b. If relativeEnd is -∞, let endIndex be 0.
+                IntegerOrInfinity::NegativeInfinity => 0,
+                // c. Else if relativeEnd < 0, let endIndex be max(srcLength + relativeEnd, 0).
+                IntegerOrInfinity::Integer(i) if i < 0 => std::cmp::max(src_length + i, 0),
+                // d. Else, let endIndex be min(relativeEnd, srcLength).
+                IntegerOrInfinity::Integer(i) => std::cmp::min(i, src_length),
+                IntegerOrInfinity::PositiveInfinity => src_length,
+            };
 
-        // 18. Let beginByteOffset be srcByteOffset + beginIndex × elementSize.
-        let begin_byte_offset = src_byte_offset + begin_index as u64 * element_size;
+            // e. Let newLength be max(endIndex - startIndex, 0).
+            let new_length = std::cmp::max(end_index - begin_index, 0);
 
-        // 19. Let argumentsList be « buffer, 𝔽(beginByteOffset), 𝔽(newLength) ».
-        // 20. Return ? TypedArraySpeciesCreate(O, argumentsList).
-        Ok(Self::species_create(
-            obj,
-            o.typed_array_name(),
-            &[
-                buffer.clone().into(),
-                begin_byte_offset.into(),
-                new_length.into(),
-            ],
-            context,
-        )?
-        .into())
+            // f. Let argumentsList be « buffer, 𝔽(beginByteOffset), 𝔽(newLength) ».
+            vec![buffer.into(), begin_byte_offset.into(), new_length.into()]
+        };
+
+        // 17. Return ? TypedArraySpeciesCreate(O, argumentsList).
+        Ok(Self::species_create(obj, kind, &arguments_list, context)?.into())
     }
 
     // TODO: 23.2.3.29 %TypedArray%.prototype.toLocaleString ( [ reserved1 [ , reserved2 ] ] )
@@ -2948,10 +2995,10 @@ impl TypedArray {
         if o.borrow()
             .as_typed_array()
             .ok_or_else(|| JsNativeError::typ().with_message("Value is not a typed array object"))?
-            .is_detached()
+            .is_out_of_bounds()
         {
             return Err(JsNativeError::typ()
-                .with_message("Buffer of the typed array is detached")
+                .with_message("typed array is outside the bounds of its inner buffer")
                 .into());
         }
 
@@ -3058,9 +3105,9 @@ impl TypedArray {
         let o = obj_borrow.as_typed_array().ok_or_else(|| {
             JsNativeError::typ().with_message("Value is not a typed array object")
         })?;
-        if o.is_detached() {
+        if o.is_out_of_bounds() {
             return Err(JsNativeError::typ()
-                .with_message("Buffer of the typed array is detached")
+                .with_message("typed array is outside the bounds of its inner buffer")
                 .into());
         }
 
@@ -3080,6 +3127,49 @@ impl TypedArray {
         Ok(new_typed_array.clone())
     }
 
+    /// `TypedArrayCreateSameType ( exemplar, argumentList )`
+    ///
+    /// Creates a new typed array of `length` elements, using the intrinsic constructor of the
+    /// kind of `exemplar`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-typedarray-create-same-type
+    fn create_same_type(
+        exemplar: &JsObject,
+        length: u64,
+        context: &mut Context<'_>,
+    ) -> JsResult<JsObject> {
+        let kind = exemplar
+            .borrow()
+            .as_typed_array()
+            .expect("exemplar must be a typed array")
+            .typed_array_name();
+
+        // 1. Let constructor be the intrinsic object associated with the constructor name exemplar.[[TypedArrayName]] in Table 71.
+        let constructor = match kind {
+            TypedArrayKind::Int8 => StandardConstructors::typed_int8_array,
+            TypedArrayKind::Uint8 => StandardConstructors::typed_uint8_array,
+            TypedArrayKind::Uint8Clamped => StandardConstructors::typed_uint8clamped_array,
+            TypedArrayKind::Int16 => StandardConstructors::typed_int16_array,
+            TypedArrayKind::Uint16 => StandardConstructors::typed_uint16_array,
+            TypedArrayKind::Int32 => StandardConstructors::typed_int32_array,
+            TypedArrayKind::Uint32 => StandardConstructors::typed_uint32_array,
+            TypedArrayKind::BigInt64 => StandardConstructors::typed_bigint64_array,
+            TypedArrayKind::BigUint64 => StandardConstructors::typed_biguint64_array,
+            TypedArrayKind::Float32 => StandardConstructors::typed_float32_array,
+            TypedArrayKind::Float64 => StandardConstructors::typed_float64_array,
+        }(context.intrinsics().constructors())
+        .constructor();
+
+        // 2. Let result be ? TypedArrayCreate(constructor, argumentList).
+        // 3. Assert: result has [[TypedArrayName]] and [[ContentType]] internal slots.
+        // 4. Assert: result.[[ContentType]] is exemplar.[[ContentType]].
+        // 5. Return result.
+        Self::create(&constructor, &[length.into()], context)
+    }
+
     /// <https://tc39.es/ecma262/#sec-allocatetypedarraybuffer>
     fn allocate_buffer(
         indexed: &mut IntegerIndexed,
@@ -3105,6 +3195,7 @@ impl TypedArray {
                 .constructor()
                 .into(),
             byte_length,
+            None,
             context,
         )?;
 
@@ -3221,87 +3312,75 @@ impl TypedArray {
             .expect("this must be a typed array");
 
         // 1. Let srcData be srcArray.[[ViewedArrayBuffer]].
-        // 2. If IsDetachedBuffer(srcData) is true, throw a TypeError exception.
-        if src_array.is_detached() {
-            return Err(JsNativeError::typ()
-                .with_message("Cannot initialize typed array from detached buffer")
-                .into());
-        }
         let src_data_obj = src_array
             .viewed_array_buffer()
-            .expect("Already checked for detached buffer");
+            .expect("a constructed typed array must have a buffer");
 
-        // 3. Let constructorName be the String value of O.[[TypedArrayName]].
-        // 4. Let elementType be the Element Type value in Table 73 for constructorName.
-        // 10. Let elementSize be the Element Size value specified in Table 73 for constructorName.
+        // 2. Let elementType be TypedArrayElementType(O).
+        // 3. Let elementSize be TypedArrayElementSize(O).
         let constructor_name = o_array.typed_array_name();
 
-        // 5. Let elementLength be srcArray.[[ArrayLength]].
-        let element_length = src_array.array_length();
-
-        // 6. Let srcName be the String value of srcArray.[[TypedArrayName]].
-        // 7. Let srcType be the Element Type value in Table 73 for srcName.
-        // 8. Let srcElementSize be the Element Size value specified in Table 73 for srcName.
+        // 4. Let srcType be TypedArrayElementType(srcArray).
+        // 5. Let srcElementSize be TypedArrayElementSize(srcArray).
         let src_name = src_array.typed_array_name();
 
-        // 9. Let srcByteOffset be srcArray.[[ByteOffset]].
+        // 6. Let srcByteOffset be srcArray.[[ByteOffset]].
         let src_byte_offset = src_array.byte_offset();
 
-        // 11. Let byteLength be elementSize × elementLength.
-        let byte_length = constructor_name.element_size() * element_length;
+        // 7. Let srcRecord be MakeTypedArrayWithBufferWitnessRecord(srcArray, seq-cst).
+        // 8. If IsTypedArrayOutOfBounds(srcRecord) is true, throw a TypeError exception.
+        if src_array.is_out_of_bounds() {
+            return Err(JsNativeError::typ()
+                .with_message("Cannot initialize typed array from detached buffer")
+                .into());
+        }
 
-        // 12. If IsSharedArrayBuffer(srcData) is false, then
-        // a. Let bufferConstructor be ? SpeciesConstructor(srcData, %ArrayBuffer%).
-        // TODO: Shared Array Buffer
-        // 13. Else,
-        // a. Let bufferConstructor be %ArrayBuffer%.
-        let buffer_constructor =
-            src_data_obj.species_constructor(StandardConstructors::array_buffer, context)?;
+        // 9. Let elementLength be TypedArrayLength(srcRecord).
+        let element_length = src_array.array_length();
+
+        // 10. Let byteLength be elementSize × elementLength.
+        let byte_length = constructor_name.element_size() * element_length;
 
         let src_data_obj_b = src_data_obj.borrow();
         let src_data = src_data_obj_b
-            .as_array_buffer()
+            .as_buffer()
             .expect("Already checked for detached buffer");
 
-        // 14. If elementType is the same as srcType, then
+        // 11. If elementType is srcType, then
         let data = if constructor_name == src_name {
-            // a. Let data be ? CloneArrayBuffer(srcData, srcByteOffset, byteLength, bufferConstructor).
-            src_data.clone_array_buffer(
-                src_byte_offset,
+            // a. Let data be ? CloneArrayBuffer(srcData, srcByteOffset, byteLength).
+            src_data.clone_array_buffer(src_byte_offset, byte_length, context)?
+        // 12. Else,
+        } else {
+            // a. Let data be ? AllocateArrayBuffer(%ArrayBuffer%, byteLength).
+            let data_obj = ArrayBuffer::allocate(
+                &context
+                    .intrinsics()
+                    .constructors()
+                    .array_buffer()
+                    .constructor()
+                    .into(),
                 byte_length,
-                &buffer_constructor.into(),
+                None,
                 context,
-            )?
-        // 15. Else,
-        } else {
-            // a. Let data be ? AllocateArrayBuffer(bufferConstructor, byteLength).
-            let data_obj = ArrayBuffer::allocate(&buffer_constructor.into(), byte_length, context)?;
+            )?;
             let mut data_obj_b = data_obj.borrow_mut();
-            let data = data_obj_b
-                .as_array_buffer_mut()
-                .expect("Must be ArrayBuffer");
+            let mut data = data_obj_b.as_buffer_mut().expect("Must be ArrayBuffer");
 
-            // b. If IsDetachedBuffer(srcData) is true, throw a TypeError exception.
-            if src_data.is_detached_buffer() {
-                return Err(JsNativeError::typ()
-                    .with_message("Cannot initialize typed array from detached buffer")
-                    .into());
-            }
-
-            // c. If srcArray.[[ContentType]] ≠ O.[[ContentType]], throw a TypeError exception.
+            // b. If srcArray.[[ContentType]] is not O.[[ContentType]], throw a TypeError exception.
             if src_name.content_type() != constructor_name.content_type() {
                 return Err(JsNativeError::typ()
                     .with_message("Cannot initialize typed array from different content type")
                     .into());
             }
 
-            // d. Let srcByteIndex be srcByteOffset.
+            // c. Let srcByteIndex be srcByteOffset.
             let mut src_byte_index = src_byte_offset;
-            // e. Let targetByteIndex be 0.
+            // d. Let targetByteIndex be 0.
             let mut target_byte_index = 0;
-            // f. Let count be elementLength.
+            // e. Let count be elementLength.
             let mut count = element_length;
-            // g. Repeat, while count > 0,
+            // f. Repeat, while count > 0,
             while count > 0 {
                 // i. Let value be GetValueFromBuffer(srcData, srcByteIndex, srcType, true, Unordered).
                 let value = src_data.get_value_from_buffer(
@@ -3335,10 +3414,10 @@ impl TypedArray {
             data_obj
         };
 
-        // 16. Set O.[[ViewedArrayBuffer]] to data.
-        // 17. Set O.[[ByteLength]] to byteLength.
-        // 18. Set O.[[ByteOffset]] to 0.
-        // 19. Set O.[[ArrayLength]] to elementLength.
+        // 13. Set O.[[ViewedArrayBuffer]] to data.
+        // 14. Set O.[[ByteLength]] to byteLength.
+        // 15. Set O.[[ByteOffset]] to 0.
+        // 16. Set O.[[ArrayLength]] to elementLength.
         drop(o_obj);
         *o.borrow_mut().kind_mut() = ObjectKind::IntegerIndexed(IntegerIndexed::new(
             Some(data),
@@ -3364,28 +3443,43 @@ impl TypedArray {
         length: &JsValue,
         context: &mut Context<'_>,
     ) -> JsResult<()> {
-        // 1. Let constructorName be the String value of O.[[TypedArrayName]].
-        // 2. Let elementSize be the Element Size value specified in Table 73 for constructorName.
+        // 1. Let elementSize be TypedArrayElementSize(O).
         let constructor_name = o
             .borrow()
             .as_typed_array()
             .expect("This must be a typed array")
             .typed_array_name();
+        let element_size = constructor_name.element_size();
 
-        // 3. Let offset be ? ToIndex(byteOffset).
+        // 2. Let offset be ? ToIndex(byteOffset).
         let offset = byte_offset.to_index(context)?;
 
-        // 4. If offset modulo elementSize ≠ 0, throw a RangeError exception.
-        if offset % constructor_name.element_size() != 0 {
+        // 3. If offset modulo elementSize ≠ 0, throw a RangeError exception.
+        if offset % element_size != 0 {
             return Err(JsNativeError::range()
                 .with_message("Invalid length for typed array")
                 .into());
         }
 
+        // 4. Let bufferIsFixedLength be IsFixedLengthArrayBuffer(buffer).
+        let buffer_is_fixed_length = buffer
+            .borrow()
+            .as_buffer()
+            .expect("This must be an ArrayBuffer")
+            .is_fixed_length();
+
+        // 5. If length is not undefined, then
+        //     a. Let newLength be ? ToIndex(length).
+        let new_length = if length.is_undefined() {
+            None
+        } else {
+            Some(length.to_index(context)?)
+        };
+
         let buffer_byte_length = {
             let buffer_obj_b = buffer.borrow();
             let buffer_array = buffer_obj_b
-                .as_array_buffer()
+                .as_buffer()
                 .expect("This must be an ArrayBuffer");
 
             // 6. If IsDetachedBuffer(buffer) is true, throw a TypeError exception.
@@ -3395,61 +3489,73 @@ impl TypedArray {
                     .into());
             }
 
-            // 7. Let bufferByteLength be buffer.[[ArrayBufferByteLength]].
+            // 7. Let bufferByteLength be ArrayBufferByteLength(buffer, seq-cst).
             buffer_array.array_buffer_byte_length()
         };
 
-        // 8. If length is undefined, then
-        let new_byte_length = if length.is_undefined() {
-            // a. If bufferByteLength modulo elementSize ≠ 0, throw a RangeError exception.
-            if buffer_byte_length % constructor_name.element_size() != 0 {
-                return Err(JsNativeError::range()
-                    .with_message("Invalid length for typed array")
-                    .into());
-            }
+        let mut o_obj_borrow = o.borrow_mut();
+        let o = o_obj_borrow
+            .as_typed_array_mut()
+            .expect("This must be an ArrayBuffer");
 
-            // b. Let newByteLength be bufferByteLength - offset.
-            let new_byte_length = buffer_byte_length as i64 - offset as i64;
+        match new_length {
+            // 8. If length is undefined and bufferIsFixedLength is false, then
+            None if !buffer_is_fixed_length => {
+                // a. If offset > bufferByteLength, throw a RangeError exception.
+                if offset > buffer_byte_length {
+                    return Err(JsNativeError::range()
+                        .with_message("Invalid length for typed array")
+                        .into());
+                }
 
-            // c. If newByteLength < 0, throw a RangeError exception.
-            if new_byte_length < 0 {
-                return Err(JsNativeError::range()
-                    .with_message("Invalid length for typed array")
-                    .into());
+                // b. Set O.[[ByteLength]] to auto.
+                // c. Set O.[[ArrayLength]] to auto.
+                o.set_length_tracking();
             }
+            // 9. Else,
+            _ => {
+                let new_byte_length = if let Some(new_length) = new_length {
+                    // b. Else,
+                    //     i. Let newByteLength be newLength × elementSize.
+                    let new_byte_length = new_length * element_size;
+
+                    //     ii. If offset + newByteLength > bufferByteLength, throw a RangeError exception.
+                    if offset + new_byte_length > buffer_byte_length {
+                        return Err(JsNativeError::range()
+                            .with_message("Invalid length for typed array")
+                            .into());
+                    }
 
-            new_byte_length as u64
-        // 9. Else,
-        } else {
-            // 5. If length is not undefined, then
-            // a. Let newLength be ? ToIndex(length).
-
-            // a. Let newByteLength be newLength × elementSize.
-            let new_byte_length = length.to_index(context)? * constructor_name.element_size();
+                    new_byte_length
+                } else {
+                    // a. If length is undefined, then
+                    //     i. If bufferByteLength modulo elementSize ≠ 0, throw a RangeError exception.
+                    if buffer_byte_length % element_size != 0 {
+                        return Err(JsNativeError::range()
+                            .with_message("Invalid length for typed array")
+                            .into());
+                    }
 
-            // b. If offset + newByteLength > bufferByteLength, throw a RangeError exception.
-            if offset + new_byte_length > buffer_byte_length {
-                return Err(JsNativeError::range()
-                    .with_message("Invalid length for typed array")
-                    .into());
-            }
+                    //     ii. Let newByteLength be bufferByteLength - offset.
+                    //     iii. If newByteLength < 0, throw a RangeError exception.
+                    buffer_byte_length.checked_sub(offset).ok_or_else(|| {
+                        JsNativeError::range().with_message("Invalid length for typed array")
+                    })?
+                };
 
-            new_byte_length
-        };
+                // c. Set O.[[ByteLength]] to newByteLength.
+                o.set_byte_length(new_byte_length);
 
-        let mut o_obj_borrow = o.borrow_mut();
-        let o = o_obj_borrow
-            .as_typed_array_mut()
-            .expect("This must be an ArrayBuffer");
+                // d. Set O.[[ArrayLength]] to newByteLength / elementSize.
+                o.set_array_length(new_byte_length / element_size);
+            }
+        }
 
         // 10. Set O.[[ViewedArrayBuffer]] to buffer.
         o.set_viewed_array_buffer(Some(buffer));
-        // 11. Set O.[[ByteLength]] to newByteLength.
-        o.set_byte_length(new_byte_length);
-        // 12. Set O.[[ByteOffset]] to offset.
+
+        // 11. Set O.[[ByteOffset]] to offset.
         o.set_byte_offset(offset);
-        // 13. Set O.[[ArrayLength]] to newByteLength / elementSize.
-        o.set_array_length(new_byte_length / constructor_name.element_size());
 
         Ok(())
     }
@@ -3549,6 +3655,112 @@ impl TypedArrayKind {
     }
 }
 
+/// Abstract operation `CompareTypedArrayElements ( x, y, comparefn )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-comparetypedarrayelements
+fn compare_typed_array_elements(
+    x: &JsValue,
+    y: &JsValue,
+    compare_fn: Option<&JsObject>,
+    buffer: &JsObject,
+    context: &mut Context<'_>,
+) -> JsResult<Ordering> {
+    // 1. Assert: Both Type(x) and Type(y) are Number or both are BigInt.
+    // 2. If comparefn is not undefined, then
+    if let Some(obj) = compare_fn {
+        // a. Let v be ? ToNumber(? Call(comparefn, undefined, « x, y »)).
+        let v = obj
+            .call(&JsValue::undefined(), &[x.clone(), y.clone()], context)?
+            .to_number(context)?;
+
+        // b. If IsDetachedBuffer(buffer) is true, throw a TypeError exception.
+        if buffer
+            .borrow()
+            .as_buffer()
+            .expect("Must be array buffer")
+            .is_detached_buffer()
+        {
+            return Err(JsNativeError::typ()
+                .with_message("Cannot sort typed array with detached buffer")
+                .into());
+        }
+
+        // c. If v is NaN, return +0𝔽.
+        // d. Return v.
+        return Ok(v.partial_cmp(&0.0).unwrap_or(Ordering::Equal));
+    }
+
+    if let (JsValue::BigInt(x), JsValue::BigInt(y)) = (x, y) {
+        // 6. If x < y, return -1𝔽.
+        if x < y {
+            return Ok(Ordering::Less);
+        }
+
+        // 7. If x > y, return 1𝔽.
+        if x > y {
+            return Ok(Ordering::Greater);
+        }
+
+        // 8. If x is -0𝔽 and y is +0𝔽, return -1𝔽.
+        if x.is_zero() && y.is_zero() && x.as_inner().is_negative() && y.as_inner().is_positive() {
+            return Ok(Ordering::Less);
+        }
+
+        // 9. If x is +0𝔽 and y is -0𝔽, return 1𝔽.
+        if x.is_zero() && y.is_zero() && x.as_inner().is_positive() && y.as_inner().is_negative() {
+            return Ok(Ordering::Greater);
+        }
+    } else {
+        let x = x
+            .as_number()
+            .expect("Typed array can only contain number or bigint");
+        let y = y
+            .as_number()
+            .expect("Typed array can only contain number or bigint");
+
+        // 3. If x and y are both NaN, return +0𝔽.
+        if x.is_nan() && y.is_nan() {
+            return Ok(Ordering::Equal);
+        }
+
+        // 4. If x is NaN, return 1𝔽.
+        if x.is_nan() {
+            return Ok(Ordering::Greater);
+        }
+
+        // 5. If y is NaN, return -1𝔽.
+        if y.is_nan() {
+            return Ok(Ordering::Less);
+        }
+
+        // 6. If x < y, return -1𝔽.
+        if x < y {
+            return Ok(Ordering::Less);
+        }
+
+        // 7. If x > y, return 1𝔽.
+        if x > y {
+            return Ok(Ordering::Greater);
+        }
+
+        // 8. If x is -0𝔽 and y is +0𝔽, return -1𝔽.
+        if x.is_zero() && y.is_zero() && x.is_sign_negative() && y.is_sign_positive() {
+            return Ok(Ordering::Less);
+        }
+
+        // 9. If x is +0𝔽 and y is -0𝔽, return 1𝔽.
+        if x.is_zero() && y.is_zero() && x.is_sign_positive() && y.is_sign_negative() {
+            return Ok(Ordering::Greater);
+        }
+    }
+
+    // 10. Return +0𝔽.
+    Ok(Ordering::Equal)
+}
+
 typed_array!(Int8Array, Int8, "Int8Array", typed_int8_array);
 typed_array!(Uint8Array, Uint8, "Uint8Array", typed_uint8_array);
 typed_array!(
@@ -0,0 +1,44 @@
+use crate::{run_test_actions, JsNativeErrorKind, TestAction};
+
+#[test]
+fn to_sorted() {
+    run_test_actions([
+        TestAction::run("const ta = new Int8Array([3, -1, 2]);"),
+        TestAction::assert_eq("ta.toSorted().join()", "-1,2,3"),
+        TestAction::assert_eq("ta.toSorted((a, b) => b - a).join()", "3,2,-1"),
+        TestAction::assert_eq("ta.join()", "3,-1,2"),
+        TestAction::assert("ta.toSorted() instanceof Int8Array"),
+        TestAction::assert_eq("new BigInt64Array([2n, -1n]).toSorted().join()", "-1,2"),
+    ]);
+}
+
+#[test]
+fn to_reversed() {
+    run_test_actions([
+        TestAction::run("const ta = new Float64Array([1, 2.5, NaN]);"),
+        TestAction::assert_eq("ta.toReversed().join()", "NaN,2.5,1"),
+        TestAction::assert_eq("ta.join()", "1,2.5,NaN"),
+        TestAction::assert("ta.toReversed() instanceof Float64Array"),
+    ]);
+}
+
+#[test]
+fn with() {
+    run_test_actions([
+        TestAction::run("const ta = new Uint8Array([1, 2, 3]);"),
+        TestAction::assert_eq("ta.with(0, 257).join()", "1,2,3"),
+        TestAction::assert_eq("ta.with(-1, 9).join()", "1,2,9"),
+        TestAction::assert_eq("ta.join()", "1,2,3"),
+        TestAction::assert_eq("new BigInt64Array([1n]).with(0, 5n).join()", "5"),
+        TestAction::assert_native_error(
+            "ta.with(3, 0)",
+            JsNativeErrorKind::Range,
+            "TypedArray.with: index out of range",
+        ),
+        TestAction::assert_native_error(
+            "new BigInt64Array(1).with(0, 1)",
+            JsNativeErrorKind::Type,
+            "cannot convert Number to a BigInt",
+        ),
+    ]);
+}
@@ -0,0 +1,631 @@
+use std::{cell::Cell, rc::Rc};
+
+use boa_gc::{Ephemeron, Finalize, Trace, WeakGc};
+use boa_profiler::Profiler;
+
+use crate::{
+    builtins::{
+        weak::can_be_held_weakly, BuiltInBuilder, BuiltInConstructor, BuiltInObject,
+        IntrinsicObject,
+    },
+    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    job::NativeJob,
+    object::{
+        internal_methods::get_prototype_from_constructor, JsFunction, JsObject, ObjectData,
+        VTableObject,
+    },
+    property::Attribute,
+    realm::Realm,
+    symbol::JsSymbol,
+    Context, JsArgs, JsNativeError, JsResult, JsValue,
+};
+
+/// Boa's implementation of ECMAScript's `FinalizationRegistry` builtin object.
+///
+/// A `FinalizationRegistry` lets the user request a cleanup callback to be called after a registered target
+/// object has been collected by the garbage collector.
+///
+/// More Information:
+///  - [ECMAScript Reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-finalization-registry-objects
+#[derive(Trace, Finalize)]
+pub struct FinalizationRegistry {
+    /// The `[[Realm]]` internal slot.
+    realm: Realm,
+
+    /// The `[[CleanupCallback]]` internal slot.
+    cleanup_callback: JsFunction,
+
+    /// The `[[Cells]]` internal slot.
+    cells: Vec<RegistryCell>,
+
+    /// Set by the garbage collector when the target of any of the cells gets collected.
+    #[unsafe_ignore_trace]
+    cleanup_pending: Rc<Cell<bool>>,
+}
+
+impl std::fmt::Debug for FinalizationRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FinalizationRegistry")
+            .field("realm", &self.realm)
+            .field("cleanup_callback", &self.cleanup_callback)
+            .field("cells", &self.cells.len())
+            .finish_non_exhaustive()
+    }
+}
+
+/// A `Record` of the `[[Cells]]` list of a `FinalizationRegistry`.
+#[derive(Trace, Finalize)]
+struct RegistryCell {
+    /// The `[[WeakRefTarget]]` field.
+    ///
+    /// Clearing the ephemeron finalizes its value, which flags the registry for cleanup.
+    target: Ephemeron<VTableObject, CleanupNotifier>,
+
+    /// The `[[HeldValue]]` field.
+    held_value: JsValue,
+
+    /// The `[[UnregisterToken]]` field.
+    unregister_token: Option<WeakGc<VTableObject>>,
+}
+
+/// The value of the ephemeron of a [`RegistryCell`].
+#[derive(Clone, Trace)]
+struct CleanupNotifier(#[unsafe_ignore_trace] Rc<Cell<bool>>);
+
+impl Finalize for CleanupNotifier {
+    fn finalize(&self) {
+        self.0.set(true);
+    }
+}
+
+impl IntrinsicObject for FinalizationRegistry {
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .property(
+                JsSymbol::to_string_tag(),
+                Self::NAME,
+                Attribute::CONFIGURABLE,
+            )
+            .method(Self::register, "register", 2)
+            .method(Self::unregister, "unregister", 1)
+            .method(Self::cleanup_some, "cleanupSome", 0)
+            .build();
+    }
+}
+
+impl BuiltInObject for FinalizationRegistry {
+    const NAME: &'static str = "FinalizationRegistry";
+
+    const ATTRIBUTE: Attribute = Attribute::WRITABLE.union(Attribute::CONFIGURABLE);
+}
+
+impl BuiltInConstructor for FinalizationRegistry {
+    /// The amount of arguments the `FinalizationRegistry` constructor takes.
+    const LENGTH: usize = 1;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::finalization_registry;
+
+    /// Constructor [`FinalizationRegistry ( cleanupCallback )`][cons]
+    ///
+    /// [cons]: https://tc39.es/ecma262/#sec-finalization-registry-cleanup-callback
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("FinalizationRegistry: cannot call constructor without `new`")
+                .into());
+        }
+
+        // 2. If IsCallable(cleanupCallback) is false, throw a TypeError exception.
+        let cleanup_callback = args
+            .get_or_undefined(0)
+            .as_callable()
+            .cloned()
+            .and_then(JsFunction::from_object)
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("FinalizationRegistry: cleanup callback must be callable")
+            })?;
+
+        // 3. Let finalizationRegistry be ? OrdinaryCreateFromConstructor(NewTarget, "%FinalizationRegistry.prototype%", « [[Realm]], [[CleanupCallback]], [[Cells]] »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::finalization_registry,
+            context,
+        )?;
+
+        // 4. Let fn be the active function object.
+        // 5. Set finalizationRegistry.[[Realm]] to fn.[[Realm]].
+        // 6. Set finalizationRegistry.[[CleanupCallback]] to HostMakeJobCallback(cleanupCallback).
+        // 7. Set finalizationRegistry.[[Cells]] to a new empty List.
+        // 8. Return finalizationRegistry.
+        Ok(Self::create(prototype, cleanup_callback, context).into())
+    }
+}
+
+impl FinalizationRegistry {
+    /// Creates a new `FinalizationRegistry` object with an empty list of cells, tracking it
+    /// on `context` to enqueue its cleanup jobs.
+    pub(crate) fn create(
+        prototype: JsObject,
+        cleanup_callback: JsFunction,
+        context: &mut Context<'_>,
+    ) -> JsObject {
+        let registry = JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            ObjectData::finalization_registry(Self {
+                realm: context.realm().clone(),
+                cleanup_callback,
+                cells: Vec::new(),
+                cleanup_pending: Rc::default(),
+            }),
+        );
+
+        context
+            .finalization_registries
+            .push(WeakGc::new(registry.inner()));
+
+        registry
+    }
+
+    /// Method [`FinalizationRegistry.prototype.register ( target, heldValue [ , unregisterToken ] )`][spec].
+    ///
+    /// Registers `target` on the registry, calling the cleanup callback with `heldValue` after
+    /// `target` gets collected.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-finalization-registry.prototype.register
+    pub(crate) fn register(
+        this: &JsValue,
+        args: &[JsValue],
+        _: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        let target = args.get_or_undefined(0);
+        let held_value = args.get_or_undefined(1);
+        let unregister_token = args.get_or_undefined(2);
+
+        // 1. Let finalizationRegistry be the this value.
+        // 2. Perform ? RequireInternalSlot(finalizationRegistry, [[Cells]]).
+        let registry = this
+            .as_object()
+            .filter(|registry| registry.is_finalization_registry())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(
+                    "FinalizationRegistry.prototype.register: expected `this` to be a `FinalizationRegistry` object",
+                )
+            })?;
+
+        // 3. If CanBeHeldWeakly(target) is false, throw a TypeError exception.
+        let target_key = can_be_held_weakly(target).ok_or_else(|| {
+            JsNativeError::typ().with_message(format!(
+                "FinalizationRegistry.prototype.register: expected target argument of type `object` or non-registered `symbol`, got target of type `{}`",
+                target.type_of()
+            ))
+        })?;
+
+        // 4. If SameValue(target, heldValue) is true, throw a TypeError exception.
+        if JsValue::same_value(target, held_value) {
+            return Err(JsNativeError::typ()
+                .with_message(
+                    "FinalizationRegistry.prototype.register: target and held value must be different",
+                )
+                .into());
+        }
+
+        // 5. If CanBeHeldWeakly(unregisterToken) is false, then
+        let unregister_token = match can_be_held_weakly(unregister_token) {
+            Some(token) => Some(WeakGc::new(token.inner())),
+            // b. Set unregisterToken to empty.
+            None if unregister_token.is_undefined() => None,
+            // a. If unregisterToken is not undefined, throw a TypeError exception.
+            None => {
+                return Err(JsNativeError::typ()
+                    .with_message(format!(
+                        "FinalizationRegistry.prototype.register: expected unregister token of type `object` or non-registered `symbol`, got token of type `{}`",
+                        unregister_token.type_of()
+                    ))
+                    .into())
+            }
+        };
+
+        let mut registry = registry.borrow_mut();
+        let registry = registry
+            .as_finalization_registry_mut()
+            .expect("must be a `FinalizationRegistry` object");
+
+        // 6. Let cell be the Record { [[WeakRefTarget]]: target, [[HeldValue]]: heldValue, [[UnregisterToken]]: unregisterToken }.
+        let cell = RegistryCell {
+            target: Ephemeron::new(
+                target_key.inner(),
+                CleanupNotifier(registry.cleanup_pending.clone()),
+            ),
+            held_value: held_value.clone(),
+            unregister_token,
+        };
+
+        // 7. Append cell to finalizationRegistry.[[Cells]].
+        registry.cells.push(cell);
+
+        // 8. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// Method [`FinalizationRegistry.prototype.unregister ( unregisterToken )`][spec].
+    ///
+    /// Removes all the cells registered with `unregisterToken`, returning `true` if any cell was
+    /// removed.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-finalization-registry.prototype.unregister
+    pub(crate) fn unregister(
+        this: &JsValue,
+        args: &[JsValue],
+        _: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. Let finalizationRegistry be the this value.
+        // 2. Perform ? RequireInternalSlot(finalizationRegistry, [[Cells]]).
+        let mut registry = this.as_object().map(JsObject::borrow_mut).ok_or_else(|| {
+            JsNativeError::typ().with_message(
+                "FinalizationRegistry.prototype.unregister: expected `this` to be a `FinalizationRegistry` object",
+            )
+        })?;
+        let registry = registry.as_finalization_registry_mut().ok_or_else(|| {
+            JsNativeError::typ().with_message(
+                "FinalizationRegistry.prototype.unregister: expected `this` to be a `FinalizationRegistry` object",
+            )
+        })?;
+
+        // 3. If CanBeHeldWeakly(unregisterToken) is false, throw a TypeError exception.
+        let unregister_token = args.get_or_undefined(0);
+        let unregister_token = can_be_held_weakly(unregister_token).ok_or_else(|| {
+            JsNativeError::typ().with_message(format!(
+                "FinalizationRegistry.prototype.unregister: expected unregister token of type `object` or non-registered `symbol`, got token of type `{}`",
+                unregister_token.type_of()
+            ))
+        })?;
+
+        // 4. Let removed be false.
+        let len = registry.cells.len();
+
+        // 5. For each Record { [[WeakRefTarget]], [[HeldValue]], [[UnregisterToken]] } cell of finalizationRegistry.[[Cells]], do
+        //     a. If cell.[[UnregisterToken]] is not empty and SameValue(cell.[[UnregisterToken]], unregisterToken) is true, then
+        //         i. Remove cell from finalizationRegistry.[[Cells]].
+        //         ii. Set removed to true.
+        registry.cells.retain(|cell| {
+            cell.unregister_token
+                .as_ref()
+                .and_then(WeakGc::upgrade)
+                .map_or(true, |token| {
+                    !JsObject::equals(&JsObject::from(token), &unregister_token)
+                })
+        });
+
+        // 6. Return removed.
+        Ok((registry.cells.len() != len).into())
+    }
+
+    /// Method [`FinalizationRegistry.prototype.cleanupSome ( [ callback ] )`][spec].
+    ///
+    /// Calls `callback`, or the cleanup callback of the registry if `callback` is not present,
+    /// for each of the registered targets that have been collected.
+    ///
+    /// [spec]: https://tc39.es/proposal-cleanup-some/#sec-finalization-registry.prototype.cleanupSome
+    pub(crate) fn cleanup_some(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. Let finalizationRegistry be the this value.
+        // 2. Perform ? RequireInternalSlot(finalizationRegistry, [[Cells]]).
+        let registry = this
+            .as_object()
+            .filter(|registry| registry.is_finalization_registry())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(
+                    "FinalizationRegistry.prototype.cleanupSome: expected `this` to be a `FinalizationRegistry` object",
+                )
+            })?;
+
+        // 3. If callback is present and IsCallable(callback) is false, throw a TypeError exception.
+        let callback = match args.get_or_undefined(0) {
+            JsValue::Undefined => None,
+            callback => Some(
+                callback
+                    .as_callable()
+                    .cloned()
+                    .and_then(JsFunction::from_object)
+                    .ok_or_else(|| {
+                        JsNativeError::typ().with_message(
+                            "FinalizationRegistry.prototype.cleanupSome: callback must be callable",
+                        )
+                    })?,
+            ),
+        };
+
+        // 4. Perform ? CleanupFinalizationRegistry(finalizationRegistry, callback).
+        Self::cleanup(registry, callback, context)?;
+
+        // 5. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// Abstract operation [`CleanupFinalizationRegistry ( finalizationRegistry [ , callback ] )`][spec].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `registry` is not a `FinalizationRegistry` object.
+    ///
+    /// [spec]: https://tc39.es/proposal-cleanup-some/#sec-cleanup-finalization-registry
+    pub(crate) fn cleanup(
+        registry: &JsObject,
+        callback: Option<JsFunction>,
+        context: &mut Context<'_>,
+    ) -> JsResult<()> {
+        // 1. Assert: finalizationRegistry has [[Cells]] and [[CleanupCallback]] internal slots.
+        // 2. If callback is not present or undefined, set callback to finalizationRegistry.[[CleanupCallback]].
+        let callback = callback.unwrap_or_else(|| {
+            registry
+                .borrow()
+                .as_finalization_registry()
+                .expect("must be a `FinalizationRegistry` object")
+                .cleanup_callback
+                .clone()
+        });
+
+        // 3. While finalizationRegistry.[[Cells]] contains a Record cell such that cell.[[WeakRefTarget]] is empty, an implementation may perform the following steps:
+        loop {
+            // a. Choose any such cell.
+            // b. Remove cell from finalizationRegistry.[[Cells]].
+            let held_value = {
+                let mut registry = registry.borrow_mut();
+                let cells = &mut registry
+                    .as_finalization_registry_mut()
+                    .expect("must be a `FinalizationRegistry` object")
+                    .cells;
+                let Some(index) = cells.iter().position(|cell| !cell.target.has_value()) else {
+                    break;
+                };
+                cells.remove(index).held_value.clone()
+            };
+
+            // c. Perform ? HostCallJobCallback(callback, undefined, « cell.[[HeldValue]] »).
+            callback.call(&JsValue::undefined(), &[held_value], context)?;
+        }
+
+        // 4. Return unused.
+        Ok(())
+    }
+
+    /// Abstract operation [`HostEnqueueFinalizationRegistryCleanupJob ( finalizationRegistry )`][spec].
+    ///
+    /// Enqueues a cleanup job on the [`JobQueue`] of `context` for every registry that had any of
+    /// its targets collected since the last call.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-host-cleanup-finalization-registry
+    /// [`JobQueue`]: crate::job::JobQueue
+    pub(crate) fn enqueue_cleanup_jobs(context: &mut Context<'_>) {
+        context
+            .finalization_registries
+            .retain(WeakGc::is_upgradable);
+
+        let registries = context
+            .finalization_registries
+            .iter()
+            .filter_map(WeakGc::upgrade)
+            .map(JsObject::from)
+            .collect::<Vec<_>>();
+
+        for registry in registries {
+            let realm = {
+                let registry = registry.borrow();
+                let registry = registry
+                    .as_finalization_registry()
+                    .expect("must be a `FinalizationRegistry` object");
+
+                if !registry.cleanup_pending.replace(false)
+                    || registry.cells.iter().all(|cell| cell.target.has_value())
+                {
+                    continue;
+                }
+
+                registry.realm.clone()
+            };
+
+            // 1. Let cleanupJob be a new Job Abstract Closure with no parameters that captures finalizationRegistry and performs the following steps when called:
+            let job = move |context: &mut Context<'_>| {
+                // a. Let cleanupResult be Completion(CleanupFinalizationRegistry(finalizationRegistry)).
+                // b. If cleanupResult is an abrupt completion, perform any host-defined steps for reporting the error.
+                Self::cleanup(&registry, None, context)?;
+
+                // c. Return unused.
+                Ok(JsValue::undefined())
+            };
+
+            // 2. Perform HostEnqueueGenericJob(cleanupJob, finalizationRegistry.[[Realm]]).
+            let job = NativeJob::with_realm(job, realm, context);
+            context.enqueue_job(job);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use crate::{
+        job::NativeJob, js_string, run_test_actions, JsNativeErrorKind, JsValue, TestAction,
+    };
+
+    #[test]
+    fn finalization_registry_cleanup() {
+        run_test_actions([
+            TestAction::run(indoc! {r#"
+                var held = [];
+                var registry = new FinalizationRegistry(value => held.push(value));
+                var token = {};
+                {
+                    registry.register({}, "first");
+                    registry.register({}, "second", token);
+                    registry.register({}, "third", token);
+                }
+            "#}),
+            TestAction::assert("registry.unregister(token)"),
+            TestAction::assert("!registry.unregister(token)"),
+            TestAction::inspect_context(|context| {
+                boa_gc::force_collect();
+                context.run_jobs();
+            }),
+            TestAction::assert_eq("held.join()", js_string!("first")),
+        ]);
+    }
+
+    #[test]
+    fn finalization_registry_symbol_targets() {
+        run_test_actions([
+            TestAction::run(indoc! {r#"
+                var held = [];
+                var registry = new FinalizationRegistry(value => held.push(value));
+                var target = Symbol("kept");
+                var token = Symbol("token");
+                registry.register(target, "kept");
+                {
+                    registry.register(Symbol("first"), "first");
+                    registry.register(Symbol("second"), "second", token);
+                }
+            "#}),
+            TestAction::assert("registry.unregister(token)"),
+            TestAction::inspect_context(|context| {
+                boa_gc::force_collect();
+                context.run_jobs();
+            }),
+            TestAction::assert_eq("held.join()", js_string!("first")),
+        ]);
+    }
+
+    #[test]
+    fn finalization_registry_symbol_only_reachable_from_garbage() {
+        run_test_actions([
+            TestAction::run(indoc! {r#"
+                var held = [];
+                var registry = new FinalizationRegistry(value => held.push(value));
+                {
+                    let target = Symbol("target");
+                    let obj = { target, [target]: 1 };
+                    obj.self = obj;
+                    registry.register(target, "target");
+                }
+            "#}),
+            TestAction::inspect_context(|context| {
+                boa_gc::force_collect();
+                context.run_jobs();
+            }),
+            TestAction::assert_eq("held.join()", js_string!("target")),
+        ]);
+    }
+
+    #[test]
+    fn finalization_registry_cleanup_after_job() {
+        run_test_actions([
+            TestAction::run(indoc! {r#"
+                var held = [];
+                var registry = new FinalizationRegistry(value => held.push(value));
+                {
+                    registry.register({}, "collected");
+                }
+            "#}),
+            TestAction::inspect_context(|context| {
+                // The collection runs inside a job, so the cleanup job must be enqueued by the
+                // job queue after it.
+                context.enqueue_job(NativeJob::new(|_| {
+                    boa_gc::force_collect();
+                    Ok(JsValue::undefined())
+                }));
+                context.run_jobs();
+            }),
+            TestAction::assert_eq("held.join()", js_string!("collected")),
+        ]);
+    }
+
+    #[test]
+    fn finalization_registry_cleanup_some() {
+        run_test_actions([
+            TestAction::run(indoc! {r"
+                var held = [];
+                var registry = new FinalizationRegistry(value => held.push(value));
+                var target = {};
+                registry.register(target, 1);
+                {
+                    registry.register({}, 2);
+                }
+            "}),
+            TestAction::inspect_context(|_| boa_gc::force_collect()),
+            TestAction::run("registry.cleanupSome(value => held.push(value * 10))"),
+            TestAction::assert_eq("held.join()", js_string!("20")),
+            TestAction::inspect_context(|context| {
+                // The cells cleaned up by `cleanupSome` must not be cleaned up again.
+                context.run_jobs();
+            }),
+            TestAction::assert_eq("held.join()", js_string!("20")),
+            TestAction::assert_eq("typeof target", js_string!("object")),
+        ]);
+    }
+
+    #[test]
+    fn finalization_registry_errors() {
+        run_test_actions([
+            TestAction::run("var registry = new FinalizationRegistry(() => {})"),
+            TestAction::assert_native_error(
+                "FinalizationRegistry(() => {})",
+                JsNativeErrorKind::Type,
+                "FinalizationRegistry: cannot call constructor without `new`",
+            ),
+            TestAction::assert_native_error(
+                "new FinalizationRegistry({})",
+                JsNativeErrorKind::Type,
+                "FinalizationRegistry: cleanup callback must be callable",
+            ),
+            TestAction::assert_native_error(
+                "var target = {}; registry.register(target, target)",
+                JsNativeErrorKind::Type,
+                "FinalizationRegistry.prototype.register: target and held value must be different",
+            ),
+            TestAction::assert_native_error(
+                "registry.register({}, 1, 1)",
+                JsNativeErrorKind::Type,
+                "FinalizationRegistry.prototype.register: expected unregister token of type `object` or non-registered `symbol`, got token of type `number`",
+            ),
+            TestAction::assert_native_error(
+                "registry.unregister(undefined)",
+                JsNativeErrorKind::Type,
+                "FinalizationRegistry.prototype.unregister: expected unregister token of type `object` or non-registered `symbol`, got token of type `undefined`",
+            ),
+            TestAction::assert_native_error(
+                "var sym = Symbol(); registry.register(sym, sym)",
+                JsNativeErrorKind::Type,
+                "FinalizationRegistry.prototype.register: target and held value must be different",
+            ),
+            TestAction::assert_native_error(
+                "registry.register(Symbol.for('target'), 1)",
+                JsNativeErrorKind::Type,
+                "FinalizationRegistry.prototype.register: expected target argument of type `object` or non-registered `symbol`, got target of type `symbol`",
+            ),
+            TestAction::assert_native_error(
+                "registry.cleanupSome(1)",
+                JsNativeErrorKind::Type,
+                "FinalizationRegistry.prototype.cleanupSome: callback must be callable",
+            ),
+            TestAction::assert_eq("registry.register({}, 1)", JsValue::undefined()),
+        ]);
+    }
+}
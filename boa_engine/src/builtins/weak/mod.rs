@@ -1,5 +1,47 @@
-//! Boa's implementation of ECMAScript's `WeakRef` object.
+//! Boa's implementation of ECMAScript's `WeakRef` and `FinalizationRegistry` objects.
 
+mod finalization_registry;
 mod weak_ref;
 
+pub(crate) use finalization_registry::FinalizationRegistry;
 pub(crate) use weak_ref::WeakRef;
+
+use crate::{builtins::symbol::Symbol, object::JsObject, symbol::WeakJsSymbol, JsValue};
+
+/// Abstract operation [`CanBeHeldWeakly ( v )`][spec].
+///
+/// Returns the object that keys `v` in weak collections, or `None` if `v` cannot be held
+/// weakly. Symbols are keyed by a hidden object that lives as long as the symbol is reachable.
+///
+/// [spec]: https://tc39.es/ecma262/#sec-canbeheldweakly
+pub(crate) fn can_be_held_weakly(v: &JsValue) -> Option<JsObject> {
+    match v {
+        // 1. If v is an Object, return true.
+        JsValue::Object(object) => Some(object.clone()),
+        // 2. If v is a Symbol and KeyForSymbol(v) is undefined, return true.
+        JsValue::Symbol(symbol) if !Symbol::is_registered(symbol) => Some(symbol.weak_key()),
+        // 3. Return false.
+        _ => None,
+    }
+}
+
+/// Gets the object that keys `v` in weak collections without creating it, returning `None`
+/// if `v` was never held weakly.
+pub(crate) fn existing_weak_key(v: &JsValue) -> Option<JsObject> {
+    match v {
+        JsValue::Object(object) => Some(object.clone()),
+        JsValue::Symbol(symbol) => symbol.existing_weak_key(),
+        _ => None,
+    }
+}
+
+/// Converts an object that keys a value in weak collections back to that value.
+///
+/// Returns `None` if the key represents a symbol that is no longer alive.
+pub(crate) fn weak_key_to_value(key: JsObject) -> Option<JsValue> {
+    let symbol = key.borrow().as_weak_symbol_key().map(WeakJsSymbol::upgrade);
+    match symbol {
+        Some(symbol) => symbol.map(JsValue::from),
+        None => Some(key.into()),
+    }
+}
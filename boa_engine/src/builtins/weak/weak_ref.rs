@@ -2,7 +2,10 @@ use boa_gc::{Finalize, Trace, WeakGc};
 use boa_profiler::Profiler;
 
 use crate::{
-    builtins::{BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject},
+    builtins::{
+        weak::{can_be_held_weakly, weak_key_to_value},
+        BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject,
+    },
     context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
     object::{internal_methods::get_prototype_from_constructor, JsObject, ObjectData},
     property::Attribute,
@@ -70,11 +73,12 @@ impl BuiltInConstructor for WeakRef {
                 .into());
         }
 
-        // 2. If target is not an Object, throw a TypeError exception.
-        let target = args.get(0).and_then(JsValue::as_object).ok_or_else(|| {
+        // 2. If CanBeHeldWeakly(target) is false, throw a TypeError exception.
+        let target = args.get_or_undefined(0);
+        let key = can_be_held_weakly(target).ok_or_else(|| {
             JsNativeError::typ().with_message(format!(
-                "WeakRef: expected target argument of type `object`, got target of type `{}`",
-                args.get_or_undefined(0).type_of()
+                "WeakRef: expected target argument of type `object` or non-registered `symbol`, got target of type `{}`",
+                target.type_of()
             ))
         })?;
 
@@ -85,7 +89,7 @@ impl BuiltInConstructor for WeakRef {
         let weak_ref = JsObject::from_proto_and_data_with_shared_shape(
             context.root_shape(),
             prototype,
-            ObjectData::weak_ref(WeakGc::new(target.inner())),
+            ObjectData::weak_ref(WeakGc::new(key.inner())),
         );
 
         // 4. Perform AddToKeptObjects(target).
@@ -99,8 +103,8 @@ impl BuiltInConstructor for WeakRef {
 impl WeakRef {
     /// Method [`WeakRef.prototype.deref ( )`][spec].
     ///
-    /// If the referenced object hasn't been collected, this method promotes a `WeakRef` into a
-    /// proper [`JsObject`], or returns `undefined` otherwise.
+    /// If the referenced object or symbol hasn't been collected, this method promotes a `WeakRef`
+    /// into its target, or returns `undefined` otherwise.
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-weak-ref.prototype.deref
     pub(crate) fn deref(
@@ -128,14 +132,15 @@ impl WeakRef {
         // https://tc39.es/ecma262/multipage/managing-memory.html#sec-weakrefderef
         // 1. Let target be weakRef.[[WeakRefTarget]].
         // 2. If target is not empty, then
-        if let Some(object) = weak_ref.upgrade() {
-            let object = JsObject::from(object);
-
+        if let Some(target) = weak_ref
+            .upgrade()
+            .and_then(|key| weak_key_to_value(key.into()))
+        {
             // a. Perform AddToKeptObjects(target).
-            context.kept_alive.push(object.clone());
+            context.kept_alive.push(target.clone());
 
             // b. Return target.
-            Ok(object.into())
+            Ok(target)
         } else {
             // 3. Return undefined.
             Ok(JsValue::undefined())
@@ -147,7 +152,7 @@ impl WeakRef {
 mod tests {
     use indoc::indoc;
 
-    use crate::{run_test_actions, JsValue, TestAction};
+    use crate::{run_test_actions, JsNativeErrorKind, JsValue, TestAction};
 
     #[test]
     fn weak_ref_collected() {
@@ -170,4 +175,35 @@ mod tests {
             TestAction::assert_eq("ptr.deref()", JsValue::undefined()),
         ]);
     }
+
+    #[test]
+    fn weak_ref_symbol_collected() {
+        run_test_actions([
+            TestAction::assert_with_op(
+                indoc! {r"
+                    var ptr;
+                    {
+                        let sym = Symbol('target');
+                        ptr = new WeakRef(sym);
+                    }
+                    ptr.deref()
+                "},
+                |v, _| v.is_symbol(),
+            ),
+            TestAction::inspect_context(|context| {
+                context.clear_kept_objects();
+                boa_gc::force_collect();
+            }),
+            TestAction::assert_eq("ptr.deref()", JsValue::undefined()),
+        ]);
+    }
+
+    #[test]
+    fn weak_ref_registered_symbol() {
+        run_test_actions([TestAction::assert_native_error(
+            "new WeakRef(Symbol.for('target'))",
+            JsNativeErrorKind::Type,
+            "WeakRef: expected target argument of type `object` or non-registered `symbol`, got target of type `symbol`",
+        )]);
+    }
 }
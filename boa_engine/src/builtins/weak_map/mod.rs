@@ -9,8 +9,9 @@
 
 use crate::{
     builtins::{
-        map::add_entries_from_iterable, BuiltInBuilder, BuiltInConstructor, BuiltInObject,
-        IntrinsicObject,
+        map::add_entries_from_iterable,
+        weak::{can_be_held_weakly, existing_weak_key},
+        BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject,
     },
     context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
     object::{internal_methods::get_prototype_from_constructor, JsObject, ObjectData},
@@ -23,6 +24,9 @@ use crate::{
 use boa_gc::{Finalize, Trace};
 use boa_profiler::Profiler;
 
+#[cfg(test)]
+mod tests;
+
 #[derive(Debug, Trace, Finalize)]
 pub(crate) struct WeakMap;
 
@@ -138,8 +142,8 @@ impl WeakMap {
         })?;
 
         // 3. Let entries be M.[[WeakMapData]].
-        // 4. If key is not an Object, return false.
-        let Some(key) = args.get_or_undefined(0).as_object() else {
+        // 4. If CanBeHeldWeakly(key) is false, return false.
+        let Some(key) = existing_weak_key(args.get_or_undefined(0)) else {
             return Ok(false.into());
         };
 
@@ -178,8 +182,8 @@ impl WeakMap {
         })?;
 
         // 3. Let entries be M.[[WeakMapData]].
-        // 4. If key is not an Object, return undefined.
-        let Some(key) = args.get_or_undefined(0).as_object() else {
+        // 4. If CanBeHeldWeakly(key) is false, return undefined.
+        let Some(key) = existing_weak_key(args.get_or_undefined(0)) else {
             return Ok(JsValue::undefined());
         };
 
@@ -215,8 +219,8 @@ impl WeakMap {
         })?;
 
         // 3. Let entries be M.[[WeakMapData]].
-        // 4. If key is not an Object, return false.
-        let Some(key) = args.get_or_undefined(0).as_object() else {
+        // 4. If CanBeHeldWeakly(key) is false, return false.
+        let Some(key) = existing_weak_key(args.get_or_undefined(0)) else {
             return Ok(false.into());
         };
 
@@ -252,12 +256,12 @@ impl WeakMap {
         })?;
 
         // 3. Let entries be M.[[WeakMapData]].
-        // 4. If key is not an Object, throw a TypeError exception.
+        // 4. If CanBeHeldWeakly(key) is false, throw a TypeError exception.
         let key = args.get_or_undefined(0);
-        let Some(key) = key.as_object() else {
+        let Some(key) = can_be_held_weakly(key) else {
             return Err(JsNativeError::typ()
                 .with_message(format!(
-                    "WeakMap.set: expected target argument of type `object`, got target of type `{}`",
+                    "WeakMap.set: expected target argument of type `object` or non-registered `symbol`, got target of type `{}`",
                     key.type_of()
                 )).into());
         };
@@ -0,0 +1,142 @@
+use crate::{
+    js_string, property::Attribute, run_test_actions, Context, JsNativeErrorKind, JsSymbol,
+    JsValue, Source, TestAction,
+};
+use indoc::indoc;
+
+#[test]
+fn symbol_keys() {
+    run_test_actions([
+        TestAction::run(indoc! {r"
+            var map = new WeakMap();
+            var unique = Symbol('unique');
+            map.set(unique, 1);
+            map.set(Symbol.iterator, 2);
+        "}),
+        TestAction::assert_eq("map.get(unique)", 1),
+        TestAction::assert_eq("map.get(Symbol.iterator)", 2),
+        TestAction::assert("map.has(unique)"),
+        TestAction::assert("!map.has(Symbol('unique'))"),
+        TestAction::assert("!map.has(Symbol.for('unique'))"),
+        TestAction::assert("map.delete(unique)"),
+        TestAction::assert("!map.has(unique)"),
+        TestAction::assert("!map.delete(Symbol())"),
+    ]);
+}
+
+#[test]
+fn registered_symbol_key() {
+    run_test_actions([
+        TestAction::assert_native_error(
+            "new WeakMap().set(Symbol.for('registered'), 1)",
+            JsNativeErrorKind::Type,
+            "WeakMap.set: expected target argument of type `object` or non-registered `symbol`, got target of type `symbol`",
+        ),
+        TestAction::assert_native_error(
+            "new WeakSet().add(Symbol.for('registered'))",
+            JsNativeErrorKind::Type,
+            "WeakSet.add: expected target argument of type `object` or non-registered `symbol`, got target of type `symbol`",
+        ),
+    ]);
+}
+
+#[test]
+fn weak_set_symbol_values() {
+    run_test_actions([
+        TestAction::run(indoc! {r"
+            var set = new WeakSet();
+            var unique = Symbol();
+            set.add(unique);
+        "}),
+        TestAction::assert("set.has(unique)"),
+        TestAction::assert("!set.has(Symbol())"),
+        TestAction::assert("set.delete(unique)"),
+        TestAction::assert("!set.has(unique)"),
+    ]);
+}
+
+#[test]
+fn symbol_key_held_outside_of_the_heap() {
+    run_test_actions([
+        TestAction::run(indoc! {r"
+            var map = new WeakMap();
+            globalThis.held = Symbol('held');
+            map.set(held, {});
+        "}),
+        TestAction::inspect_context(|context| {
+            let held = context
+                .global_object()
+                .get(js_string!("held"), context)
+                .unwrap();
+            context
+                .global_object()
+                .delete_property_or_throw(js_string!("held"), context)
+                .unwrap();
+            context.clear_kept_objects();
+            boa_gc::force_collect();
+            context
+                .register_global_property(js_string!("held"), held, Attribute::all())
+                .unwrap();
+        }),
+        TestAction::assert("map.has(held)"),
+    ]);
+}
+
+#[test]
+fn symbol_keys_in_other_thread() {
+    fn use_as_key(symbol: JsSymbol) {
+        let mut context = Context::default();
+        context
+            .register_global_property(js_string!("sym"), symbol, Attribute::all())
+            .unwrap();
+        let value = context
+            .eval(Source::from_bytes(
+                "var map = new WeakMap(); map.set(sym, 1); map.get(sym)",
+            ))
+            .unwrap();
+        assert_eq!(value, JsValue::new(1));
+    }
+
+    let symbol = JsSymbol::new(None).unwrap();
+    let in_thread = symbol.clone();
+    std::thread::spawn(move || use_as_key(in_thread))
+        .join()
+        .unwrap();
+    use_as_key(symbol);
+}
+
+#[test]
+fn symbol_key_held_by_other_thread() {
+    let symbol = JsSymbol::new(None).unwrap();
+    let (release, released) = std::sync::mpsc::channel::<()>();
+    let held = symbol.clone();
+    let thread = std::thread::spawn(move || {
+        released.recv().unwrap();
+        drop(held);
+    });
+
+    let mut context = Context::default();
+    context
+        .register_global_property(js_string!("sym"), symbol, Attribute::all())
+        .unwrap();
+    context
+        .eval(Source::from_bytes(indoc! {r"
+            var collected = false;
+            var registry = new FinalizationRegistry(() => { collected = true; });
+            registry.register(sym, 0);
+            delete globalThis.sym;
+        "}))
+        .unwrap();
+    let collect = |context: &mut Context<'_>| {
+        boa_gc::force_collect();
+        context.run_jobs();
+        context.eval(Source::from_bytes("collected")).unwrap()
+    };
+
+    // The symbol is only held by the other thread, which keeps its key alive.
+    assert_eq!(collect(&mut context), JsValue::new(false));
+
+    release.send(()).unwrap();
+    thread.join().unwrap();
+    assert_eq!(collect(&mut context), JsValue::new(true));
+}
@@ -8,7 +8,10 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakSet
 
 use crate::{
-    builtins::{BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject},
+    builtins::{
+        weak::{can_be_held_weakly, existing_weak_key},
+        BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject,
+    },
     context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
     object::{internal_methods::get_prototype_from_constructor, JsObject, ObjectData},
     property::Attribute,
@@ -149,12 +152,12 @@ impl WeakSet {
             JsNativeError::typ().with_message("WeakSet.add: called with non-object value")
         })?;
 
-        // 3. If Type(value) is not Object, throw a TypeError exception.
+        // 3. If CanBeHeldWeakly(value) is false, throw a TypeError exception.
         let value = args.get_or_undefined(0);
-        let Some(value) = args.get_or_undefined(0).as_object() else {
+        let Some(value) = can_be_held_weakly(value) else {
             return Err(JsNativeError::typ()
                 .with_message(format!(
-                    "WeakSet.add: expected target argument of type `object`, got target of type `{}`",
+                    "WeakSet.add: expected target argument of type `object` or non-registered `symbol`, got target of type `{}`",
                     value.type_of()
                 )).into());
         };
@@ -201,9 +204,8 @@ impl WeakSet {
             JsNativeError::typ().with_message("WeakSet.delete: called with non-object value")
         })?;
 
-        // 3. If Type(value) is not Object, return false.
-        let value = args.get_or_undefined(0);
-        let Some(value) = value.as_object() else {
+        // 3. If CanBeHeldWeakly(value) is false, return false.
+        let Some(value) = existing_weak_key(args.get_or_undefined(0)) else {
             return Ok(false.into());
         };
 
@@ -244,9 +246,8 @@ impl WeakSet {
         })?;
 
         // 3. Let entries be the List that is S.[[WeakSetData]].
-        // 4. If Type(value) is not Object, return false.
-        let value = args.get_or_undefined(0);
-        let Some(value) = value.as_object() else {
+        // 4. If CanBeHeldWeakly(value) is false, return false.
+        let Some(value) = existing_weak_key(args.get_or_undefined(0)) else {
             return Ok(false.into());
         };
 
@@ -1,9 +1,13 @@
 use super::{ByteCompiler, Literal};
-use crate::vm::{BindingOpcode, CodeBlockFlags, Opcode};
+use crate::{
+    builtins::function::decorators::DecoratedElementKind,
+    vm::{BindingOpcode, CodeBlockFlags, Opcode},
+};
 use boa_ast::{
     expression::Identifier,
-    function::{Class, ClassElement, FormalParameterList},
+    function::{Class, ClassElement, Decorator, FormalParameterList, PrivateName},
     property::{MethodDefinition, PropertyName},
+    Expression, Script,
 };
 use boa_gc::Gc;
 use boa_interner::Sym;
@@ -24,6 +28,7 @@ impl ByteCompiler<'_, '_> {
             self.current_environment.clone(),
             self.context,
         );
+        compiler.source_path.clone_from(&self.source_path);
 
         if let Some(class_name) = class.name() {
             if class.has_binding_identifier() {
@@ -73,6 +78,9 @@ impl ByteCompiler<'_, '_> {
         compiler.emit_opcode(Opcode::Return);
 
         let code = Gc::new(compiler.finish());
+
+        self.compile_decorators(class.decorators());
+
         let index = self.functions.len() as u32;
         self.functions.push(code);
         self.emit(Opcode::GetFunction, &[index]);
@@ -100,11 +108,13 @@ impl ByteCompiler<'_, '_> {
         let count_label = self.emit_opcode_with_operand(Opcode::PushPrivateEnvironment);
         let mut count = 0;
         for element in class.elements() {
-            match element {
+            match element.decorated().1 {
                 ClassElement::PrivateMethodDefinition(name, _)
                 | ClassElement::PrivateStaticMethodDefinition(name, _)
                 | ClassElement::PrivateFieldDefinition(name, _)
-                | ClassElement::PrivateStaticFieldDefinition(name, _) => {
+                | ClassElement::PrivateStaticFieldDefinition(name, _)
+                | ClassElement::PrivateAutoAccessorDefinition(name, _)
+                | ClassElement::PrivateStaticAutoAccessorDefinition(name, _) => {
                     count += 1;
                     let index = self.get_or_insert_private_name(*name);
                     self.emit_u32(index);
@@ -114,8 +124,30 @@ impl ByteCompiler<'_, '_> {
         }
         self.patch_jump_with_target(count_label, count);
 
+        // The stack is `class_decorators, prototype, class` for every element, which get
+        // evaluated in document order.
+        let mut accessor_count = 0;
         // TODO: set function name for getter and setters
         for element in class.elements() {
+            let (decorators, element) = element.decorated();
+            if !decorators.is_empty()
+                || matches!(
+                    element,
+                    ClassElement::AutoAccessorDefinition(..)
+                        | ClassElement::StaticAutoAccessorDefinition(..)
+                        | ClassElement::PrivateAutoAccessorDefinition(..)
+                        | ClassElement::PrivateStaticAutoAccessorDefinition(..)
+                )
+            {
+                self.compile_decorated_class_element(
+                    decorators,
+                    element,
+                    class_name,
+                    &mut accessor_count,
+                );
+                continue;
+            }
+
             match element {
                 ClassElement::StaticMethodDefinition(name, method_definition) => {
                     self.emit_opcode(Opcode::Dup);
@@ -248,155 +280,40 @@ impl ByteCompiler<'_, '_> {
                             self.compile_expr(name, true);
                         }
                     }
-                    let mut field_compiler = ByteCompiler::new(
-                        Sym::EMPTY_STRING,
-                        true,
-                        self.json_parse,
-                        self.current_environment.clone(),
-                        self.context,
-                    );
-                    field_compiler.push_compile_environment(false);
-                    field_compiler.create_immutable_binding(class_name.into(), true);
-                    field_compiler.push_compile_environment(true);
-                    if let Some(node) = field {
-                        field_compiler.compile_expr(node, true);
-                    } else {
-                        field_compiler.emit_opcode(Opcode::PushUndefined);
-                    }
-                    field_compiler.pop_compile_environment();
-                    field_compiler.pop_compile_environment();
-                    field_compiler.emit_opcode(Opcode::Return);
-
-                    let mut code = field_compiler.finish();
-                    code.class_field_initializer_name = Some(Sym::EMPTY_STRING);
-                    let code = Gc::new(code);
-                    let index = self.functions.len() as u32;
-                    self.functions.push(code);
-                    self.emit(Opcode::GetFunction, &[index]);
-                    self.emit_u8(0);
+                    self.compile_field_initializer(field.as_ref(), class_name);
                     self.emit_opcode(Opcode::PushClassField);
                 }
                 ClassElement::PrivateFieldDefinition(name, field) => {
                     self.emit_opcode(Opcode::Dup);
                     let name_index = self.get_or_insert_private_name(*name);
-                    let mut field_compiler = ByteCompiler::new(
-                        class_name,
-                        true,
-                        self.json_parse,
-                        self.current_environment.clone(),
-                        self.context,
-                    );
-                    field_compiler.push_compile_environment(false);
-                    field_compiler.create_immutable_binding(class_name.into(), true);
-                    field_compiler.push_compile_environment(true);
-                    if let Some(node) = field {
-                        field_compiler.compile_expr(node, true);
-                    } else {
-                        field_compiler.emit_opcode(Opcode::PushUndefined);
-                    }
-                    field_compiler.pop_compile_environment();
-                    field_compiler.pop_compile_environment();
-                    field_compiler.emit_opcode(Opcode::Return);
-
-                    let mut code = field_compiler.finish();
-                    code.class_field_initializer_name = Some(Sym::EMPTY_STRING);
-                    let code = Gc::new(code);
-                    let index = self.functions.len() as u32;
-                    self.functions.push(code);
-                    self.emit(Opcode::GetFunction, &[index]);
-                    self.emit_u8(0);
+                    self.compile_field_initializer(field.as_ref(), class_name);
                     self.emit(Opcode::PushClassFieldPrivate, &[name_index]);
                 }
                 ClassElement::StaticFieldDefinition(name, field) => {
                     self.emit_opcode(Opcode::Dup);
-                    self.emit_opcode(Opcode::Dup);
-                    let name_index = match name {
+                    match name {
                         PropertyName::Literal(name) => {
-                            Some(self.get_or_insert_name((*name).into()))
+                            self.emit_push_literal(Literal::String(
+                                self.interner().resolve_expect(*name).into_common(false),
+                            ));
                         }
                         PropertyName::Computed(name) => {
                             self.compile_expr(name, true);
-                            self.emit_opcode(Opcode::Swap);
-                            None
                         }
-                    };
-                    let mut field_compiler = ByteCompiler::new(
-                        class_name,
-                        true,
-                        self.json_parse,
-                        self.current_environment.clone(),
-                        self.context,
-                    );
-                    field_compiler.push_compile_environment(false);
-                    field_compiler.create_immutable_binding(class_name.into(), true);
-                    field_compiler.push_compile_environment(true);
-                    if let Some(node) = field {
-                        field_compiler.compile_expr(node, true);
-                    } else {
-                        field_compiler.emit_opcode(Opcode::PushUndefined);
-                    }
-                    field_compiler.pop_compile_environment();
-                    field_compiler.pop_compile_environment();
-                    field_compiler.emit_opcode(Opcode::Return);
-
-                    let mut code = field_compiler.finish();
-                    code.class_field_initializer_name = Some(Sym::EMPTY_STRING);
-                    let code = Gc::new(code);
-                    let index = self.functions.len() as u32;
-                    self.functions.push(code);
-                    self.emit(Opcode::GetFunction, &[index]);
-                    self.emit_u8(0);
-                    self.emit_opcode(Opcode::SetHomeObject);
-                    self.emit(Opcode::Call, &[0]);
-                    if let Some(name_index) = name_index {
-                        self.emit(Opcode::DefineOwnPropertyByName, &[name_index]);
-                    } else {
-                        self.emit_opcode(Opcode::DefineOwnPropertyByValue);
                     }
+                    self.compile_field_initializer(field.as_ref(), class_name);
+                    self.emit_opcode(Opcode::PushClassStaticField);
                 }
                 ClassElement::PrivateStaticFieldDefinition(name, field) => {
                     self.emit_opcode(Opcode::Dup);
-                    if let Some(node) = field {
-                        self.compile_expr(node, true);
-                    } else {
-                        self.emit_opcode(Opcode::PushUndefined);
-                    }
-                    let index = self.get_or_insert_private_name(*name);
-                    self.emit(Opcode::DefinePrivateField, &[index]);
+                    let name_index = self.get_or_insert_private_name(*name);
+                    self.compile_field_initializer(field.as_ref(), class_name);
+                    self.emit(Opcode::PushClassStaticFieldPrivate, &[name_index]);
                 }
                 ClassElement::StaticBlock(body) => {
                     self.emit_opcode(Opcode::Dup);
-                    let mut compiler = ByteCompiler::new(
-                        Sym::EMPTY_STRING,
-                        true,
-                        false,
-                        self.current_environment.clone(),
-                        self.context,
-                    );
-                    compiler.push_compile_environment(false);
-                    compiler.create_immutable_binding(class_name.into(), true);
-                    compiler.push_compile_environment(true);
-
-                    compiler.function_declaration_instantiation(
-                        body,
-                        &FormalParameterList::default(),
-                        false,
-                        true,
-                        false,
-                    );
-
-                    compiler.compile_statement_list(body.statements(), false, false);
-                    compiler.pop_compile_environment();
-                    compiler.pop_compile_environment();
-
-                    let code = Gc::new(compiler.finish());
-                    let index = self.functions.len() as u32;
-                    self.functions.push(code);
-                    self.emit(Opcode::GetFunction, &[index]);
-                    self.emit_u8(0);
-                    self.emit_opcode(Opcode::SetHomeObject);
-                    self.emit(Opcode::Call, &[0]);
-                    self.emit_opcode(Opcode::Pop);
+                    self.compile_static_block(body, class_name);
+                    self.emit_opcode(Opcode::PushClassStaticBlock);
                 }
                 // TODO: set names for private methods
                 ClassElement::PrivateMethodDefinition(name, method_definition) => {
@@ -434,15 +351,8 @@ impl ByteCompiler<'_, '_> {
                         }
                     }
                 }
-                ClassElement::MethodDefinition(..) => {}
-            }
-        }
-
-        self.emit_opcode(Opcode::Swap);
-
-        for element in class.elements() {
-            match element {
                 ClassElement::MethodDefinition(name, method_definition) => {
+                    self.emit_opcode(Opcode::Swap);
                     self.emit_opcode(Opcode::Dup);
                     // TODO: set names for getters and setters
                     match method_definition {
@@ -525,19 +435,19 @@ impl ByteCompiler<'_, '_> {
                             }
                         },
                     }
+                    self.emit_opcode(Opcode::Swap);
                 }
-                ClassElement::PrivateMethodDefinition(..)
-                | ClassElement::PrivateFieldDefinition(..)
-                | ClassElement::StaticFieldDefinition(..)
-                | ClassElement::PrivateStaticFieldDefinition(..)
-                | ClassElement::StaticMethodDefinition(..)
-                | ClassElement::PrivateStaticMethodDefinition(..)
-                | ClassElement::StaticBlock(..)
-                | ClassElement::FieldDefinition(..) => {}
+                ClassElement::AutoAccessorDefinition(..)
+                | ClassElement::StaticAutoAccessorDefinition(..)
+                | ClassElement::PrivateAutoAccessorDefinition(..)
+                | ClassElement::PrivateStaticAutoAccessorDefinition(..)
+                | ClassElement::Decorated(..) => unreachable!("handled above"),
             }
         }
 
+        self.emit_opcode(Opcode::Swap);
         self.emit_opcode(Opcode::Pop);
+        self.emit_opcode(Opcode::InitializeClass);
 
         if let Some(class_env) = class_env {
             let env_index = self.pop_compile_environment();
@@ -554,4 +464,227 @@ impl ByteCompiler<'_, '_> {
             );
         }
     }
+
+    /// Compiles the initializer of a field into a function, pushing it on the stack.
+    fn compile_field_initializer(&mut self, field: Option<&Expression>, class_name: Sym) {
+        let mut field_compiler = ByteCompiler::new(
+            Sym::EMPTY_STRING,
+            true,
+            self.json_parse,
+            self.current_environment.clone(),
+            self.context,
+        );
+        field_compiler.source_path.clone_from(&self.source_path);
+        field_compiler.push_compile_environment(false);
+        field_compiler.create_immutable_binding(class_name.into(), true);
+        field_compiler.push_compile_environment(true);
+        if let Some(node) = field {
+            field_compiler.compile_expr(node, true);
+        } else {
+            field_compiler.emit_opcode(Opcode::PushUndefined);
+        }
+        field_compiler.pop_compile_environment();
+        field_compiler.pop_compile_environment();
+        field_compiler.emit_opcode(Opcode::Return);
+
+        let mut code = field_compiler.finish();
+        code.class_field_initializer_name = Some(Sym::EMPTY_STRING);
+        let code = Gc::new(code);
+        let index = self.functions.len() as u32;
+        self.functions.push(code);
+        self.emit(Opcode::GetFunction, &[index]);
+        self.emit_u8(0);
+    }
+
+    /// Compiles a static block into a function, pushing it on the stack.
+    fn compile_static_block(&mut self, body: &Script, class_name: Sym) {
+        let mut compiler = ByteCompiler::new(
+            Sym::EMPTY_STRING,
+            true,
+            false,
+            self.current_environment.clone(),
+            self.context,
+        );
+        compiler.source_path.clone_from(&self.source_path);
+        compiler.push_compile_environment(false);
+        compiler.create_immutable_binding(class_name.into(), true);
+        compiler.push_compile_environment(true);
+
+        compiler.function_declaration_instantiation(
+            body,
+            &FormalParameterList::default(),
+            false,
+            true,
+            false,
+        );
+
+        compiler.compile_statement_list(body.statements(), false, false);
+        compiler.pop_compile_environment();
+        compiler.pop_compile_environment();
+
+        let code = Gc::new(compiler.finish());
+        let index = self.functions.len() as u32;
+        self.functions.push(code);
+        self.emit(Opcode::GetFunction, &[index]);
+        self.emit_u8(0);
+    }
+
+    /// Pushes a list of decorators on the stack as an array, or `undefined` if it is empty.
+    fn compile_decorators(&mut self, decorators: &[Decorator]) {
+        if decorators.is_empty() {
+            self.emit_opcode(Opcode::PushUndefined);
+            return;
+        }
+
+        self.emit_opcode(Opcode::PushNewArray);
+        for decorator in decorators {
+            self.compile_expr(decorator.expression(), true);
+            self.emit_opcode(Opcode::PushValueToArray);
+        }
+    }
+
+    /// Compiles a method definition, returning the kind of element it defines.
+    fn compile_method_definition(
+        &mut self,
+        method_definition: &MethodDefinition,
+        class_name: Sym,
+    ) -> DecoratedElementKind {
+        match method_definition {
+            MethodDefinition::Get(expr) => {
+                self.method(expr.into(), class_name);
+                DecoratedElementKind::Getter
+            }
+            MethodDefinition::Set(expr) => {
+                self.method(expr.into(), class_name);
+                DecoratedElementKind::Setter
+            }
+            MethodDefinition::Ordinary(expr) => {
+                self.method(expr.into(), class_name);
+                DecoratedElementKind::Method
+            }
+            MethodDefinition::Async(expr) => {
+                self.method(expr.into(), class_name);
+                DecoratedElementKind::Method
+            }
+            MethodDefinition::Generator(expr) => {
+                self.method(expr.into(), class_name);
+                DecoratedElementKind::Method
+            }
+            MethodDefinition::AsyncGenerator(expr) => {
+                self.method(expr.into(), class_name);
+                DecoratedElementKind::Method
+            }
+        }
+    }
+
+    /// Compiles a class element with decorators or an auto-accessor, whose definition is
+    /// deferred until the decorators of the class are applied.
+    ///
+    /// Auto-accessors are backed by a private field, which is pushed to the class right before
+    /// the accessor itself.
+    fn compile_decorated_class_element(
+        &mut self,
+        decorators: &[Decorator],
+        element: &ClassElement,
+        class_name: Sym,
+        accessor_count: &mut u32,
+    ) {
+        let is_static = matches!(
+            element,
+            ClassElement::StaticMethodDefinition(..)
+                | ClassElement::PrivateStaticMethodDefinition(..)
+                | ClassElement::StaticFieldDefinition(..)
+                | ClassElement::PrivateStaticFieldDefinition(..)
+                | ClassElement::StaticAutoAccessorDefinition(..)
+                | ClassElement::PrivateStaticAutoAccessorDefinition(..)
+        );
+
+        match element {
+            ClassElement::AutoAccessorDefinition(_, initializer)
+            | ClassElement::StaticAutoAccessorDefinition(_, initializer)
+            | ClassElement::PrivateAutoAccessorDefinition(_, initializer)
+            | ClassElement::PrivateStaticAutoAccessorDefinition(_, initializer) => {
+                // The name of the storage contains a space, so it cannot clash with any private
+                // name in the source code.
+                let storage = self
+                    .context
+                    .interner_mut()
+                    .get_or_intern(&*format!("accessor storage {accessor_count}"));
+                *accessor_count += 1;
+                let storage_index = self.get_or_insert_private_name(PrivateName::new(storage));
+
+                self.emit_opcode(Opcode::Dup);
+                self.compile_field_initializer(initializer.as_ref(), class_name);
+                if is_static {
+                    self.emit(Opcode::PushClassStaticFieldPrivate, &[storage_index]);
+                } else {
+                    self.emit(Opcode::PushClassFieldPrivate, &[storage_index]);
+                }
+            }
+            _ => {}
+        }
+
+        self.emit_opcode(Opcode::Dup);
+        self.compile_decorators(decorators);
+
+        let private_name = match element {
+            ClassElement::MethodDefinition(name, _)
+            | ClassElement::StaticMethodDefinition(name, _)
+            | ClassElement::FieldDefinition(name, _)
+            | ClassElement::StaticFieldDefinition(name, _)
+            | ClassElement::AutoAccessorDefinition(name, _)
+            | ClassElement::StaticAutoAccessorDefinition(name, _) => {
+                match name {
+                    PropertyName::Literal(name) => {
+                        self.emit_push_literal(Literal::String(
+                            self.interner().resolve_expect(*name).into_common(false),
+                        ));
+                    }
+                    PropertyName::Computed(name) => {
+                        self.compile_expr(name, true);
+                        self.emit_opcode(Opcode::ToPropertyKey);
+                    }
+                }
+                None
+            }
+            ClassElement::PrivateMethodDefinition(name, _)
+            | ClassElement::PrivateStaticMethodDefinition(name, _)
+            | ClassElement::PrivateFieldDefinition(name, _)
+            | ClassElement::PrivateStaticFieldDefinition(name, _)
+            | ClassElement::PrivateAutoAccessorDefinition(name, _)
+            | ClassElement::PrivateStaticAutoAccessorDefinition(name, _) => Some(*name),
+            ClassElement::StaticBlock(_) | ClassElement::Decorated(..) => {
+                unreachable!("static blocks and nested decorated elements cannot be decorated")
+            }
+        };
+
+        let kind = match element {
+            ClassElement::MethodDefinition(_, method_definition)
+            | ClassElement::StaticMethodDefinition(_, method_definition)
+            | ClassElement::PrivateMethodDefinition(_, method_definition)
+            | ClassElement::PrivateStaticMethodDefinition(_, method_definition) => {
+                self.compile_method_definition(method_definition, class_name)
+            }
+            ClassElement::FieldDefinition(_, field)
+            | ClassElement::StaticFieldDefinition(_, field)
+            | ClassElement::PrivateFieldDefinition(_, field)
+            | ClassElement::PrivateStaticFieldDefinition(_, field) => {
+                self.compile_field_initializer(field.as_ref(), class_name);
+                DecoratedElementKind::Field
+            }
+            _ => DecoratedElementKind::Accessor,
+        };
+
+        if let Some(name) = private_name {
+            let index = self.get_or_insert_private_name(name);
+            self.emit_opcode(Opcode::PushClassDecoratedPrivateElement);
+            self.emit_u8(kind as u8);
+            self.emit_u8(u8::from(is_static));
+            self.emit_u32(index);
+        } else {
+            self.emit_opcode(Opcode::PushClassDecoratedElement);
+            self.emit_u8(kind as u8);
+            self.emit_u8(u8::from(is_static));
+        }
+    }
 }
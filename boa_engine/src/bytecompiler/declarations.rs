@@ -227,7 +227,11 @@ impl ByteCompiler<'_, '_> {
                             self.create_mutable_binding(name, false);
                         }
                     }
-                    Declaration::Lexical(LexicalDeclaration::Const(declaration)) => {
+                    Declaration::Lexical(
+                        LexicalDeclaration::Const(declaration)
+                        | LexicalDeclaration::Using(declaration)
+                        | LexicalDeclaration::AwaitUsing(declaration),
+                    ) => {
                         for name in bound_names(declaration) {
                             self.create_immutable_binding(name, true);
                         }
@@ -265,6 +269,7 @@ impl ByteCompiler<'_, '_> {
                 .r#async(r#async)
                 .strict(self.strict())
                 .binding_identifier(Some(name.sym()))
+                .source_path(self.source_path.clone())
                 .compile(
                     parameters,
                     body,
@@ -315,7 +320,11 @@ impl ByteCompiler<'_, '_> {
         // 3. For each element d of declarations, do
         for d in &declarations {
             // i. If IsConstantDeclaration of d is true, then
-            if let LexicallyScopedDeclaration::LexicalDeclaration(LexicalDeclaration::Const(d)) = d
+            if let LexicallyScopedDeclaration::LexicalDeclaration(
+                LexicalDeclaration::Const(d)
+                | LexicalDeclaration::Using(d)
+                | LexicalDeclaration::AwaitUsing(d),
+            ) = d
             {
                 // a. For each element dn of the BoundNames of d, do
                 for dn in bound_names::<'_, VariableList>(d) {
@@ -640,7 +649,11 @@ impl ByteCompiler<'_, '_> {
                             self.create_mutable_binding(name, false);
                         }
                     }
-                    Declaration::Lexical(LexicalDeclaration::Const(declaration)) => {
+                    Declaration::Lexical(
+                        LexicalDeclaration::Const(declaration)
+                        | LexicalDeclaration::Using(declaration)
+                        | LexicalDeclaration::AwaitUsing(declaration),
+                    ) => {
                         for name in bound_names(declaration) {
                             self.create_immutable_binding(name, true);
                         }
@@ -677,6 +690,7 @@ impl ByteCompiler<'_, '_> {
                 .r#async(r#async)
                 .strict(self.strict())
                 .binding_identifier(Some(name.sym()))
+                .source_path(self.source_path.clone())
                 .compile(
                     parameters,
                     body,
@@ -961,7 +975,7 @@ impl ByteCompiler<'_, '_> {
                 self.emit_opcode(Opcode::RestParameterInit);
             }
             match parameter.variable().binding() {
-                Binding::Identifier(ident) => {
+                Binding::Identifier(ident, _) => {
                     self.create_mutable_binding(*ident, false);
                     if let Some(init) = parameter.variable().init() {
                         let skip = self.emit_opcode_with_operand(Opcode::JumpIfNotUndefined);
@@ -1143,7 +1157,11 @@ impl ByteCompiler<'_, '_> {
                             self.create_mutable_binding(name, false);
                         }
                     }
-                    Declaration::Lexical(LexicalDeclaration::Const(declaration)) => {
+                    Declaration::Lexical(
+                        LexicalDeclaration::Const(declaration)
+                        | LexicalDeclaration::Using(declaration)
+                        | LexicalDeclaration::AwaitUsing(declaration),
+                    ) => {
                         for name in bound_names(declaration) {
                             self.create_immutable_binding(name, true);
                         }
@@ -12,7 +12,7 @@ use crate::{
 use boa_ast::{
     expression::{
         access::{PropertyAccess, PropertyAccessField},
-        literal::{Literal as AstLiteral, TemplateElement, TemplateLiteral},
+        literal::{Literal as AstLiteral, LiteralKind, TemplateElement, TemplateLiteral},
         operator::Conditional,
     },
     Expression,
@@ -20,19 +20,19 @@ use boa_ast::{
 
 impl ByteCompiler<'_, '_> {
     fn compile_literal(&mut self, lit: &AstLiteral, use_expr: bool) {
-        match lit {
-            AstLiteral::String(v) => self.emit_push_literal(Literal::String(
+        match lit.kind() {
+            LiteralKind::String(v) => self.emit_push_literal(Literal::String(
                 self.interner().resolve_expect(*v).into_common(false),
             )),
-            AstLiteral::Int(v) => self.emit_push_integer(*v),
-            AstLiteral::Num(v) => self.emit_push_rational(*v),
-            AstLiteral::BigInt(v) => {
+            LiteralKind::Int(v) => self.emit_push_integer(*v),
+            LiteralKind::Num(v) => self.emit_push_rational(*v),
+            LiteralKind::BigInt(v) => {
                 self.emit_push_literal(Literal::BigInt(v.clone().into()));
             }
-            AstLiteral::Bool(true) => self.emit(Opcode::PushTrue, &[]),
-            AstLiteral::Bool(false) => self.emit(Opcode::PushFalse, &[]),
-            AstLiteral::Null => self.emit(Opcode::PushNull, &[]),
-            AstLiteral::Undefined => self.emit(Opcode::PushUndefined, &[]),
+            LiteralKind::Bool(true) => self.emit(Opcode::PushTrue, &[]),
+            LiteralKind::Bool(false) => self.emit(Opcode::PushFalse, &[]),
+            LiteralKind::Null => self.emit(Opcode::PushNull, &[]),
+            LiteralKind::Undefined => self.emit(Opcode::PushUndefined, &[]),
         }
 
         if !use_expr {
@@ -89,7 +89,7 @@ impl ByteCompiler<'_, '_> {
             Expression::ObjectLiteral(object) => {
                 self.compile_object_literal(object, use_expr);
             }
-            Expression::Identifier(name) => {
+            Expression::Identifier(name, _) => {
                 self.access_get(Access::Variable { name: *name }, use_expr);
             }
             Expression::PropertyAccess(access) => {
@@ -119,7 +119,7 @@ impl ByteCompiler<'_, '_> {
                     self.emit(Opcode::Pop, &[]);
                 }
             }
-            Expression::This => {
+            Expression::This(_) => {
                 self.access_get(Access::This, use_expr);
             }
             Expression::Spread(spread) => self.compile_expr(spread.target(), true),
@@ -322,17 +322,22 @@ impl ByteCompiler<'_, '_> {
             }
             Expression::ImportCall(import) => {
                 self.compile_expr(import.argument(), true);
+                if let Some(options) = import.options() {
+                    self.compile_expr(options, true);
+                } else {
+                    self.emit_opcode(Opcode::PushUndefined);
+                }
                 self.emit_opcode(Opcode::ImportCall);
                 if !use_expr {
                     self.emit_opcode(Opcode::Pop);
                 }
             }
-            Expression::NewTarget => {
+            Expression::NewTarget(_) => {
                 if use_expr {
                     self.emit_opcode(Opcode::NewTarget);
                 }
             }
-            Expression::ImportMeta => {
+            Expression::ImportMeta(_) => {
                 if use_expr {
                     self.emit_opcode(Opcode::ImportMeta);
                 }
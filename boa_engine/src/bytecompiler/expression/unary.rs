@@ -26,7 +26,7 @@ impl ByteCompiler<'_, '_> {
             UnaryOp::Tilde => Some(Opcode::BitNot),
             UnaryOp::TypeOf => {
                 match unary.target().flatten() {
-                    Expression::Identifier(identifier) => {
+                    Expression::Identifier(identifier, _) => {
                         let binding = self.get_binding_value(*identifier);
                         let index = self.get_or_insert_binding(binding);
                         self.emit(Opcode::GetNameOrUndefined, &[index]);
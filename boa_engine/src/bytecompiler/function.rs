@@ -8,9 +8,10 @@ use crate::{
 use boa_ast::function::{FormalParameterList, FunctionBody};
 use boa_gc::{Gc, GcRefCell};
 use boa_interner::Sym;
+use std::{path::Path, rc::Rc};
 
 /// `FunctionCompiler` is used to compile AST functions to bytecode.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 #[allow(clippy::struct_excessive_bools)]
 pub(crate) struct FunctionCompiler {
     name: Sym,
@@ -20,6 +21,7 @@ pub(crate) struct FunctionCompiler {
     arrow: bool,
     binding_identifier: Option<Sym>,
     class_name: Option<Sym>,
+    source_path: Option<Rc<Path>>,
 }
 
 impl FunctionCompiler {
@@ -33,6 +35,7 @@ impl FunctionCompiler {
             arrow: false,
             binding_identifier: None,
             class_name: None,
+            source_path: None,
         }
     }
 
@@ -83,6 +86,12 @@ impl FunctionCompiler {
         self
     }
 
+    /// Set the path of the source file containing the function.
+    pub(crate) fn source_path(mut self, source_path: Option<Rc<Path>>) -> Self {
+        self.source_path = source_path;
+        self
+    }
+
     /// Compile a function statement list and it's parameters into bytecode.
     pub(crate) fn compile(
         mut self,
@@ -97,6 +106,7 @@ impl FunctionCompiler {
 
         let mut compiler = ByteCompiler::new(self.name, self.strict, false, outer_env, context);
         compiler.length = length;
+        compiler.source_path = self.source_path;
         compiler.in_async_generator = self.generator && self.r#async;
 
         if self.arrow {
@@ -11,10 +11,10 @@ mod module;
 mod statement;
 mod utils;
 
-use std::cell::Cell;
+use std::{cell::Cell, path::Path, rc::Rc};
 
 use crate::{
-    builtins::function::ThisMode,
+    builtins::{disposable_stack::DisposeHint, function::ThisMode},
     environments::{BindingLocator, CompileTimeEnvironment},
     js_string,
     vm::{BindingOpcode, CodeBlock, CodeBlockFlags, Opcode},
@@ -32,7 +32,7 @@ use boa_ast::{
         FormalParameterList, Function, FunctionBody, Generator, PrivateName,
     },
     pattern::Pattern,
-    Declaration, Expression, Statement, StatementList, StatementListItem,
+    Declaration, Expression, Position, Span, Spanned, Statement, StatementList, StatementListItem,
 };
 use boa_gc::{Gc, GcRefCell};
 use boa_interner::{Interner, Sym};
@@ -41,6 +41,8 @@ use rustc_hash::FxHashMap;
 pub(crate) use function::FunctionCompiler;
 pub(crate) use jump_control::JumpControlInfo;
 
+use statement::using_declaration;
+
 /// Describes how a node has been defined in the source code.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub(crate) enum NodeKind {
@@ -184,7 +186,7 @@ enum Access<'a> {
 impl Access<'_> {
     const fn from_assign_target(target: &AssignTarget) -> Result<Access<'_>, &Pattern> {
         match target {
-            AssignTarget::Identifier(ident) => Ok(Access::Variable { name: *ident }),
+            AssignTarget::Identifier(ident, _) => Ok(Access::Variable { name: *ident }),
             AssignTarget::Access(access) => Ok(Access::Property { access }),
             AssignTarget::Pattern(pat) => Err(pat),
         }
@@ -192,9 +194,9 @@ impl Access<'_> {
 
     const fn from_expression(expr: &Expression) -> Option<Access<'_>> {
         match expr {
-            Expression::Identifier(name) => Some(Access::Variable { name: *name }),
+            Expression::Identifier(name, _) => Some(Access::Variable { name: *name }),
             Expression::PropertyAccess(access) => Some(Access::Property { access }),
-            Expression::This => Some(Access::This),
+            Expression::This(_) => Some(Access::This),
             Expression::Parenthesized(expr) => Self::from_expression(expr.expression()),
             _ => None,
         }
@@ -202,7 +204,7 @@ impl Access<'_> {
 
     const fn from_update_target(target: &UpdateTarget) -> Access<'_> {
         match target {
-            UpdateTarget::Identifier(name) => Access::Variable { name: *name },
+            UpdateTarget::Identifier(name, _) => Access::Variable { name: *name },
             UpdateTarget::PropertyAccess(access) => Access::Property { access },
         }
     }
@@ -253,6 +255,15 @@ pub struct ByteCompiler<'ctx, 'host> {
 
     pub(crate) code_block_flags: CodeBlockFlags,
 
+    /// Path of the source file being compiled, if any.
+    pub(crate) source_path: Option<Rc<Path>>,
+
+    /// Source positions of the instructions, as the program counters where they start.
+    pub(crate) positions: Vec<(u32, Position)>,
+
+    /// Source position of the node being compiled.
+    position: Option<Position>,
+
     literals_map: FxHashMap<Literal, u32>,
     names_map: FxHashMap<Identifier, u32>,
     private_names_map: FxHashMap<PrivateName, u32>,
@@ -299,6 +310,9 @@ impl<'ctx, 'host> ByteCompiler<'ctx, 'host> {
             compile_environments: Vec::default(),
             class_field_initializer_name: None,
             code_block_flags,
+            source_path: None,
+            positions: Vec::default(),
+            position: None,
 
             literals_map: FxHashMap::default(),
             names_map: FxHashMap::default(),
@@ -414,6 +428,35 @@ impl<'ctx, 'host> ByteCompiler<'ctx, 'host> {
         self.bytecode.len() as u32
     }
 
+    /// Records `span` as the source position of the next emitted instructions.
+    ///
+    /// Returns the previous position, which must be restored with [`Self::restore_position`]
+    /// once the node of `span` is compiled. Does nothing if the span is not known.
+    #[must_use]
+    pub(crate) fn emit_position(&mut self, span: Option<Span>) -> Option<Position> {
+        let enclosing = self.position;
+        if let Some(span) = span {
+            self.restore_position(Some(span.start()));
+        }
+        enclosing
+    }
+
+    /// Records `position` as the source position of the next emitted instructions, after
+    /// compiling a node nested in the node of `position`.
+    pub(crate) fn restore_position(&mut self, position: Option<Position>) {
+        let Some(position) = position else {
+            return;
+        };
+        self.position = Some(position);
+
+        let pc = self.next_opcode_location();
+        match self.positions.last_mut() {
+            Some((last_pc, last)) if *last_pc == pc => *last = position,
+            Some((_, last)) if *last == position => {}
+            _ => self.positions.push((pc, position)),
+        }
+    }
+
     pub(crate) fn emit(&mut self, opcode: Opcode, operands: &[u32]) {
         self.emit_opcode(opcode);
         for operand in operands {
@@ -715,7 +758,7 @@ impl<'ctx, 'host> ByteCompiler<'ctx, 'host> {
 
     /// Compile a [`StatementList`].
     pub fn compile_statement_list(&mut self, list: &StatementList, use_expr: bool, block: bool) {
-        if use_expr {
+        let expr_index = if use_expr {
             let list_until_loop_exit: Vec<_> = list
                 .statements()
                 .iter()
@@ -732,7 +775,7 @@ impl<'ctx, 'host> ByteCompiler<'ctx, 'host> {
                 .skip_while(|item| {
                     matches!(
                         item,
-                        &&StatementListItem::Statement(Statement::Empty | Statement::Var(_))
+                        &&StatementListItem::Statement(Statement::Empty(_) | Statement::Var(_))
                             | &&StatementListItem::Declaration(_)
                     )
                 })
@@ -742,20 +785,34 @@ impl<'ctx, 'host> ByteCompiler<'ctx, 'host> {
                 self.emit_opcode(Opcode::PushUndefined);
             }
 
-            for (i, item) in list.statements().iter().enumerate() {
-                self.compile_stmt_list_item(item, i + 1 == expr_index, block);
-            }
+            expr_index
         } else {
-            for item in list.statements() {
-                self.compile_stmt_list_item(item, false, block);
+            0
+        };
+
+        let mut dispose_scope = None;
+        for (i, item) in list.statements().iter().enumerate() {
+            if dispose_scope.is_none() && using_declaration(item).is_some() {
+                let r#async = list.statements()[i..]
+                    .iter()
+                    .filter_map(using_declaration)
+                    .any(LexicalDeclaration::is_await_using);
+                dispose_scope = Some(self.push_dispose_scope(r#async));
             }
+            self.compile_stmt_list_item(item, i + 1 == expr_index, block);
+        }
+
+        if let Some(dispose_scope) = dispose_scope {
+            self.pop_dispose_scope(dispose_scope);
         }
     }
 
     /// Compile an [`Expression`].
     #[inline]
     pub fn compile_expr(&mut self, expr: &Expression, use_expr: bool) {
+        let enclosing = self.emit_position(expr.span());
         self.compile_expr_impl(expr, use_expr);
+        self.restore_position(enclosing);
     }
 
     /// Compile a property access expression, prepending `this` to the property value in the stack.
@@ -933,7 +990,7 @@ impl<'ctx, 'host> ByteCompiler<'ctx, 'host> {
     fn compile_var_decl(&mut self, decl: &VarDeclaration) {
         for variable in decl.0.as_ref() {
             match variable.binding() {
-                Binding::Identifier(ident) => {
+                Binding::Identifier(ident, _) => {
                     let ident = ident;
                     if let Some(expr) = variable.init() {
                         self.compile_expr(expr, true);
@@ -961,7 +1018,7 @@ impl<'ctx, 'host> ByteCompiler<'ctx, 'host> {
             LexicalDeclaration::Let(decls) => {
                 for variable in decls.as_ref() {
                     match variable.binding() {
-                        Binding::Identifier(ident) => {
+                        Binding::Identifier(ident, _) => {
                             let ident = ident;
                             if let Some(expr) = variable.init() {
                                 self.compile_expr(expr, true);
@@ -985,7 +1042,7 @@ impl<'ctx, 'host> ByteCompiler<'ctx, 'host> {
             LexicalDeclaration::Const(decls) => {
                 for variable in decls.as_ref() {
                     match variable.binding() {
-                        Binding::Identifier(ident) => {
+                        Binding::Identifier(ident, _) => {
                             let init = variable
                                 .init()
                                 .expect("const declaration must have initializer");
@@ -1004,6 +1061,29 @@ impl<'ctx, 'host> ByteCompiler<'ctx, 'host> {
                     }
                 }
             }
+            LexicalDeclaration::Using(decls) | LexicalDeclaration::AwaitUsing(decls) => {
+                let hint = if decl.is_await_using() {
+                    DisposeHint::Async
+                } else {
+                    DisposeHint::Sync
+                };
+                for variable in decls.as_ref() {
+                    let init = variable
+                        .init()
+                        .expect("using declaration must have initializer");
+                    self.compile_expr(init, true);
+                    self.emit_opcode(Opcode::AddDisposableResource);
+                    self.emit_u8(hint.into());
+                    match variable.binding() {
+                        Binding::Identifier(ident, _) => {
+                            self.emit_binding(BindingOpcode::InitConst, *ident);
+                        }
+                        Binding::Pattern(pattern) => {
+                            self.compile_declaration_pattern(pattern, BindingOpcode::InitConst);
+                        }
+                    }
+                }
+            }
         };
     }
 
@@ -1074,6 +1154,7 @@ impl<'ctx, 'host> ByteCompiler<'ctx, 'host> {
             .strict(self.strict())
             .arrow(arrow)
             .binding_identifier(binding_identifier)
+            .source_path(self.source_path.clone())
             .compile(
                 parameters,
                 body,
@@ -1168,6 +1249,7 @@ impl<'ctx, 'host> ByteCompiler<'ctx, 'host> {
             .strict(self.strict())
             .arrow(arrow)
             .binding_identifier(binding_identifier)
+            .source_path(self.source_path.clone())
             .compile(
                 parameters,
                 body,
@@ -1229,6 +1311,7 @@ impl<'ctx, 'host> ByteCompiler<'ctx, 'host> {
             .arrow(arrow)
             .binding_identifier(binding_identifier)
             .class_name(class_name)
+            .source_path(self.source_path.clone())
             .compile(
                 parameters,
                 body,
@@ -1279,7 +1362,7 @@ impl<'ctx, 'host> ByteCompiler<'ctx, 'host> {
                 self.compile_optional_preserve_this(opt);
             }
             expr if kind == CallKind::Call => {
-                if let Expression::Identifier(ident) = expr {
+                if let Expression::Identifier(ident, _) = expr {
                     if *ident == Sym::EVAL {
                         kind = CallKind::CallEval;
                     }
@@ -1348,6 +1431,8 @@ impl<'ctx, 'host> ByteCompiler<'ctx, 'host> {
             compile_environments: self.compile_environments.into_boxed_slice(),
             class_field_initializer_name: self.class_field_initializer_name,
             flags: Cell::new(self.code_block_flags),
+            source_path: self.source_path,
+            positions: self.positions.into_boxed_slice(),
         }
     }
 
@@ -9,6 +9,7 @@ use boa_ast::{
 use boa_interner::Sym;
 
 use crate::{
+    builtins::disposable_stack::DisposeHint,
     bytecompiler::{Access, ByteCompiler},
     vm::{BindingOpcode, Opcode},
 };
@@ -23,6 +24,7 @@ impl ByteCompiler<'_, '_> {
         let mut let_binding_indices = None;
         let mut env_labels = None;
         let mut iteration_env_labels = None;
+        let mut dispose_scope = None;
 
         if let Some(init) = for_loop.init() {
             match init {
@@ -50,6 +52,9 @@ impl ByteCompiler<'_, '_> {
                         }
                         let_binding_indices = Some(indices);
                     }
+                    if decl.is_using() {
+                        dispose_scope = Some(self.push_dispose_scope(decl.is_await_using()));
+                    }
                     self.compile_lexical_decl(decl);
                 }
             }
@@ -108,6 +113,10 @@ impl ByteCompiler<'_, '_> {
         self.pop_loop_control_info();
         self.emit_opcode(Opcode::LoopEnd);
 
+        if let Some(dispose_scope) = dispose_scope {
+            self.pop_dispose_scope(dispose_scope);
+        }
+
         if !use_expr {
             self.emit_opcode(Opcode::Pop);
         }
@@ -130,7 +139,7 @@ impl ByteCompiler<'_, '_> {
     ) {
         // Handle https://tc39.es/ecma262/#prod-annexB-ForInOfStatement
         if let IterableLoopInitializer::Var(var) = for_in_loop.initializer() {
-            if let Binding::Identifier(ident) = var.binding() {
+            if let Binding::Identifier(ident, _) = var.binding() {
                 if let Some(init) = var.init() {
                     self.compile_expr(init, true);
                     self.emit_binding(BindingOpcode::InitVar, *ident);
@@ -180,7 +189,7 @@ impl ByteCompiler<'_, '_> {
         };
 
         match for_in_loop.initializer() {
-            IterableLoopInitializer::Identifier(ident) => {
+            IterableLoopInitializer::Identifier(ident, _) => {
                 self.emit_binding(BindingOpcode::InitVar, *ident);
             }
             IterableLoopInitializer::Access(access) => {
@@ -191,7 +200,7 @@ impl ByteCompiler<'_, '_> {
                 );
             }
             IterableLoopInitializer::Var(declaration) => match declaration.binding() {
-                Binding::Identifier(ident) => {
+                Binding::Identifier(ident, _) => {
                     self.emit_binding(BindingOpcode::InitVar, *ident);
                 }
                 Binding::Pattern(pattern) => {
@@ -199,7 +208,7 @@ impl ByteCompiler<'_, '_> {
                 }
             },
             IterableLoopInitializer::Let(declaration) => match declaration {
-                Binding::Identifier(ident) => {
+                Binding::Identifier(ident, _) => {
                     self.create_mutable_binding(*ident, false);
                     self.emit_binding(BindingOpcode::InitLet, *ident);
                 }
@@ -211,7 +220,7 @@ impl ByteCompiler<'_, '_> {
                 }
             },
             IterableLoopInitializer::Const(declaration) => match declaration {
-                Binding::Identifier(ident) => {
+                Binding::Identifier(ident, _) => {
                     self.create_immutable_binding(*ident, true);
                     self.emit_binding(BindingOpcode::InitConst, *ident);
                 }
@@ -225,6 +234,9 @@ impl ByteCompiler<'_, '_> {
             IterableLoopInitializer::Pattern(pattern) => {
                 self.compile_declaration_pattern(pattern, BindingOpcode::SetName);
             }
+            IterableLoopInitializer::Using(_) | IterableLoopInitializer::AwaitUsing(_) => {
+                unreachable!("using declarations are not allowed in the head of a for-in loop")
+            }
         }
 
         if !for_in_loop.body().returns_value() {
@@ -269,7 +281,9 @@ impl ByteCompiler<'_, '_> {
     ) {
         let initializer_bound_names = match for_of_loop.initializer() {
             IterableLoopInitializer::Let(declaration)
-            | IterableLoopInitializer::Const(declaration) => bound_names(declaration),
+            | IterableLoopInitializer::Const(declaration)
+            | IterableLoopInitializer::Using(declaration)
+            | IterableLoopInitializer::AwaitUsing(declaration) => bound_names(declaration),
             _ => Vec::new(),
         };
         if initializer_bound_names.is_empty() {
@@ -316,8 +330,9 @@ impl ByteCompiler<'_, '_> {
             Some(self.emit_opcode_with_operand(Opcode::PushDeclarativeEnvironment))
         };
 
+        let mut dispose_scope = None;
         match for_of_loop.initializer() {
-            IterableLoopInitializer::Identifier(ref ident) => {
+            IterableLoopInitializer::Identifier(ref ident, _) => {
                 let binding = self.set_mutable_binding(*ident);
                 let index = self.get_or_insert_binding(binding);
                 self.emit(Opcode::DefInitVar, &[index]);
@@ -333,7 +348,7 @@ impl ByteCompiler<'_, '_> {
                 // ignore initializers since those aren't allowed on for-of loops.
                 assert!(declaration.init().is_none());
                 match declaration.binding() {
-                    Binding::Identifier(ident) => {
+                    Binding::Identifier(ident, _) => {
                         self.emit_binding(BindingOpcode::InitVar, *ident);
                     }
                     Binding::Pattern(pattern) => {
@@ -342,7 +357,7 @@ impl ByteCompiler<'_, '_> {
                 }
             }
             IterableLoopInitializer::Let(declaration) => match declaration {
-                Binding::Identifier(ident) => {
+                Binding::Identifier(ident, _) => {
                     self.create_mutable_binding(*ident, false);
                     self.emit_binding(BindingOpcode::InitLet, *ident);
                 }
@@ -354,7 +369,7 @@ impl ByteCompiler<'_, '_> {
                 }
             },
             IterableLoopInitializer::Const(declaration) => match declaration {
-                Binding::Identifier(ident) => {
+                Binding::Identifier(ident, _) => {
                     self.create_immutable_binding(*ident, true);
                     self.emit_binding(BindingOpcode::InitConst, *ident);
                 }
@@ -365,6 +380,32 @@ impl ByteCompiler<'_, '_> {
                     self.compile_declaration_pattern(pattern, BindingOpcode::InitConst);
                 }
             },
+            IterableLoopInitializer::Using(declaration)
+            | IterableLoopInitializer::AwaitUsing(declaration) => {
+                let hint = if matches!(
+                    for_of_loop.initializer(),
+                    IterableLoopInitializer::AwaitUsing(_)
+                ) {
+                    DisposeHint::Async
+                } else {
+                    DisposeHint::Sync
+                };
+                dispose_scope = Some(self.push_dispose_scope(hint == DisposeHint::Async));
+                self.emit_opcode(Opcode::AddDisposableResource);
+                self.emit_u8(hint.into());
+                match declaration {
+                    Binding::Identifier(ident, _) => {
+                        self.create_immutable_binding(*ident, true);
+                        self.emit_binding(BindingOpcode::InitConst, *ident);
+                    }
+                    Binding::Pattern(pattern) => {
+                        for ident in bound_names(pattern) {
+                            self.create_immutable_binding(ident, true);
+                        }
+                        self.compile_declaration_pattern(pattern, BindingOpcode::InitConst);
+                    }
+                }
+            }
             IterableLoopInitializer::Pattern(pattern) => {
                 self.compile_declaration_pattern(pattern, BindingOpcode::SetName);
             }
@@ -376,6 +417,10 @@ impl ByteCompiler<'_, '_> {
         self.compile_stmt(for_of_loop.body(), true);
         self.emit_opcode(Opcode::LoopUpdateReturnValue);
 
+        if let Some(dispose_scope) = dispose_scope {
+            self.pop_dispose_scope(dispose_scope);
+        }
+
         if let Some(iteration_environment) = iteration_environment {
             let env_index = self.pop_compile_environment();
             self.patch_jump_with_target(iteration_environment, env_index);
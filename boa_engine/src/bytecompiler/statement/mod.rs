@@ -1,6 +1,6 @@
 use crate::{bytecompiler::ByteCompiler, vm::Opcode};
 
-use boa_ast::Statement;
+use boa_ast::{Spanned, Statement};
 
 mod block;
 mod r#break;
@@ -12,9 +12,17 @@ mod switch;
 mod r#try;
 mod with;
 
+pub(crate) use r#try::using_declaration;
+
 impl ByteCompiler<'_, '_> {
     /// Compiles a [`Statement`] `boa_ast` node.
     pub fn compile_stmt(&mut self, node: &Statement, use_expr: bool) {
+        let enclosing = self.emit_position(node.span());
+        self.compile_stmt_impl(node, use_expr);
+        self.restore_position(enclosing);
+    }
+
+    fn compile_stmt_impl(&mut self, node: &Statement, use_expr: bool) {
         match node {
             Statement::Var(var) => self.compile_var_decl(var),
             Statement::If(node) => self.compile_if(node, use_expr),
@@ -59,7 +67,7 @@ impl ByteCompiler<'_, '_> {
             Statement::Try(t) => self.compile_try(t, use_expr),
             Statement::Expression(expr) => self.compile_expr(expr, use_expr),
             Statement::With(with) => self.compile_with(with, use_expr),
-            Statement::Empty => {}
+            Statement::Empty(_) => {}
         }
     }
 }
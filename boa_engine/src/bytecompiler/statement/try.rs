@@ -3,11 +3,31 @@ use crate::{
     vm::{BindingOpcode, Opcode},
 };
 use boa_ast::{
-    declaration::Binding,
+    declaration::{Binding, LexicalDeclaration},
     operations::bound_names,
     statement::{Catch, Finally, Try},
+    Declaration, StatementListItem,
 };
 
+/// The implicit `try`/`finally` block that disposes the resources added by the `using`
+/// declarations of a scope.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DisposeScope {
+    catch_start: Label,
+    finally_loc: Label,
+    r#async: bool,
+}
+
+/// Gets the `using` or `await using` declaration of `item`, if it is one.
+pub(crate) const fn using_declaration(item: &StatementListItem) -> Option<&LexicalDeclaration> {
+    match item {
+        StatementListItem::Declaration(Declaration::Lexical(
+            decl @ (LexicalDeclaration::Using(_) | LexicalDeclaration::AwaitUsing(_)),
+        )) => Some(decl),
+        _ => None,
+    }
+}
+
 impl ByteCompiler<'_, '_> {
     pub(crate) fn compile_try(&mut self, t: &Try, use_expr: bool) {
         let try_start = self.next_opcode_location();
@@ -55,6 +75,57 @@ impl ByteCompiler<'_, '_> {
         }
     }
 
+    /// Starts a scope whose `using` declarations get their resources disposed when the scope
+    /// completes, either normally or abruptly.
+    ///
+    /// The resources are disposed asynchronously if `async` is `true`, which is required if the
+    /// scope contains any `await using` declaration.
+    pub(crate) fn push_dispose_scope(&mut self, r#async: bool) -> DisposeScope {
+        self.emit_opcode(Opcode::CreateDisposeCapability);
+
+        let try_start = self.next_opcode_location();
+        let (catch_start, finally_loc) = self.emit_opcode_with_two_operands(Opcode::TryStart);
+        self.patch_jump_with_target(finally_loc, u32::MAX);
+
+        self.push_init_finally_control_info();
+        self.push_try_control_info(true, try_start);
+
+        DisposeScope {
+            catch_start,
+            finally_loc,
+            r#async,
+        }
+    }
+
+    /// Ends a scope started by [`ByteCompiler::push_dispose_scope`], disposing its resources
+    /// in a `finally` block.
+    pub(crate) fn pop_dispose_scope(&mut self, scope: DisposeScope) {
+        self.emit_opcode(Opcode::TryEnd);
+
+        let finally = self.jump();
+        self.patch_jump(scope.catch_start);
+        self.patch_jump(finally);
+
+        let finally_start = self.next_opcode_location();
+        let finally_end = self.emit_opcode_with_operand(Opcode::FinallyStart);
+        self.pop_try_control_info(finally_start);
+        self.set_jump_control_start_address(finally_start);
+        self.patch_jump_with_target(scope.finally_loc, finally_start);
+
+        if scope.r#async {
+            self.emit_opcode(Opcode::AsyncDisposeResources);
+            self.emit_opcode(Opcode::Await);
+            self.emit_opcode(Opcode::GeneratorNext);
+            self.emit_opcode(Opcode::Pop);
+        } else {
+            self.emit_opcode(Opcode::DisposeResources);
+        }
+
+        self.pop_finally_control_info();
+        self.patch_jump(finally_end);
+        self.emit_opcode(Opcode::FinallyEnd);
+    }
+
     pub(crate) fn compile_catch_stmt(&mut self, catch: &Catch, finally: bool, use_expr: bool) {
         self.set_jump_control_in_catch(true);
         let catch_end = self.emit_opcode_with_operand(Opcode::CatchStart);
@@ -64,7 +135,7 @@ impl ByteCompiler<'_, '_> {
 
         if let Some(binding) = catch.parameter() {
             match binding {
-                Binding::Identifier(ident) => {
+                Binding::Identifier(ident, _) => {
                     self.create_mutable_binding(*ident, false);
                     self.emit_binding(BindingOpcode::InitLet, *ident);
                 }
@@ -0,0 +1,454 @@
+//! Heap snapshots in the `.heapsnapshot` format of V8.
+//!
+//! The snapshot is a JSON document that can be loaded in the memory tab of the Chrome developer
+//! tools. Every value allocated by the garbage collector is a node of the snapshot, and the
+//! references found by tracing it are its edges. The edges of objects are named after the
+//! properties that hold them.
+//!
+//! More information:
+//!  - [Chrome developer tools documentation][devtools]
+//!
+//! [devtools]: https://developer.chrome.com/docs/devtools/memory-problems/heap-snapshots
+
+use crate::{
+    object::{JsObject, Object, VTableObject},
+    property::{DescriptorKind, PropertyDescriptor, PropertyKey},
+    vm::CodeBlock,
+    Context, JsString, JsValue,
+};
+use boa_gc::{Gc, HeapNode};
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::io::{self, Write};
+
+/// The node types of the snapshot, indexed by [`NodeType`].
+const NODE_TYPES: [&str; 15] = [
+    "hidden",
+    "array",
+    "string",
+    "object",
+    "code",
+    "closure",
+    "regexp",
+    "number",
+    "native",
+    "synthetic",
+    "concatenated string",
+    "sliced string",
+    "symbol",
+    "bigint",
+    "object shape",
+];
+
+/// The edge types of the snapshot, indexed by [`EdgeType`].
+const EDGE_TYPES: [&str; 7] = [
+    "context", "element", "property", "internal", "hidden", "shortcut", "weak",
+];
+
+/// The number of fields of a node in the `nodes` array of the snapshot.
+const NODE_FIELD_COUNT: usize = 7;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NodeType {
+    Hidden = 0,
+    Array = 1,
+    Object = 3,
+    Code = 4,
+    Closure = 5,
+    RegExp = 6,
+    Synthetic = 9,
+    ObjectShape = 14,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum EdgeType {
+    Element = 1,
+    Property = 2,
+    Internal = 3,
+    Hidden = 4,
+    Shortcut = 5,
+}
+
+/// The name of an edge, which is either a string or an index.
+#[derive(Debug)]
+enum EdgeName {
+    Name(String),
+    Index(usize),
+}
+
+#[derive(Debug)]
+struct Edge {
+    kind: EdgeType,
+    name: EdgeName,
+    target: usize,
+}
+
+#[derive(Debug)]
+struct Node {
+    kind: NodeType,
+    name: String,
+    size: usize,
+    edges: Vec<Edge>,
+}
+
+/// Builds the snapshot of the heap of the current thread.
+#[derive(Debug, Default)]
+struct Snapshot {
+    nodes: Vec<Node>,
+
+    /// The index of the node of every value of the heap, by address.
+    indices: FxHashMap<usize, usize>,
+}
+
+impl Snapshot {
+    /// The index of the synthetic root node.
+    const ROOT: usize = 0;
+
+    /// The index of the synthetic node that references every rooted value.
+    const GC_ROOTS: usize = 1;
+
+    fn new(context: &Context<'_>) -> Self {
+        let mut snapshot = Self::default();
+        snapshot.nodes.push(synthetic(""));
+        snapshot.nodes.push(synthetic("(GC roots)"));
+
+        // The edges point to addresses until every node has an index.
+        boa_gc::walk_heap(|node| {
+            let index = snapshot.nodes.len();
+            snapshot.indices.insert(node.id(), index);
+            if node.is_rooted() {
+                let element = snapshot.nodes[Self::GC_ROOTS].edges.len();
+                snapshot.nodes[Self::GC_ROOTS].edges.push(Edge {
+                    kind: EdgeType::Element,
+                    name: EdgeName::Index(element + 1),
+                    target: node.id(),
+                });
+            }
+            snapshot.nodes.push(visit(node, context));
+        });
+
+        snapshot.resolve_edges();
+
+        let global = Gc::addr(context.global_object().inner());
+        let root = &mut snapshot.nodes[Self::ROOT].edges;
+        if let Some(&global) = snapshot.indices.get(&global) {
+            root.push(Edge {
+                kind: EdgeType::Shortcut,
+                name: EdgeName::Name("global".to_owned()),
+                target: global,
+            });
+        }
+        root.push(Edge {
+            kind: EdgeType::Element,
+            name: EdgeName::Index(1),
+            target: Self::GC_ROOTS,
+        });
+
+        snapshot
+    }
+
+    /// Replaces the addresses of the edges with node indices, and names the hidden edges.
+    fn resolve_edges(&mut self) {
+        let kinds: Vec<_> = self.nodes.iter().map(|node| node.kind).collect();
+        for node in &mut self.nodes {
+            node.edges
+                .retain_mut(|edge| match self.indices.get(&edge.target) {
+                    Some(&target) => {
+                        edge.target = target;
+                        true
+                    }
+                    None => false,
+                });
+
+            let mut hidden = 0;
+            for edge in &mut node.edges {
+                if !matches!(edge.kind, EdgeType::Hidden) {
+                    continue;
+                }
+                if node.kind != NodeType::ObjectShape && kinds[edge.target] == NodeType::ObjectShape
+                {
+                    edge.kind = EdgeType::Internal;
+                    edge.name = EdgeName::Name("map".to_owned());
+                } else {
+                    hidden += 1;
+                    edge.name = EdgeName::Index(hidden);
+                }
+            }
+        }
+    }
+
+    fn write<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        let mut strings = Strings::default();
+        let edge_count: usize = self.nodes.iter().map(|node| node.edges.len()).sum();
+
+        write!(
+            writer,
+            r#"{{"snapshot":{{"meta":{{"node_fields":["type","name","id","self_size","edge_count","trace_node_id","detachedness"],"node_types":[{},"string","number","number","number","number","number"],"edge_fields":["type","name_or_index","to_node"],"edge_types":[{},"string_or_number","node"],"trace_function_info_fields":["function_id","name","script_name","script_id","line","column"],"trace_node_fields":["id","function_info_index","count","size","children"],"sample_fields":["timestamp_us","last_assigned_id"],"location_fields":["object_index","script_id","line","column"]}},"node_count":{},"edge_count":{},"trace_function_count":0}},"#,
+            json_array(&NODE_TYPES),
+            json_array(&EDGE_TYPES),
+            self.nodes.len(),
+            edge_count,
+        )?;
+
+        writer.write_all(b"\n\"nodes\":[")?;
+        for (index, node) in self.nodes.iter().enumerate() {
+            let separator = if index == 0 { "" } else { ",\n" };
+            write!(
+                writer,
+                "{separator}{},{},{},{},{},0,0",
+                node.kind as u8,
+                strings.index(&node.name),
+                // V8 uses odd ids for the nodes of the heap.
+                index * 2 + 1,
+                node.size,
+                node.edges.len(),
+            )?;
+        }
+
+        writer.write_all(b"],\n\"edges\":[")?;
+        let edges = self.nodes.iter().flat_map(|node| &node.edges);
+        for (index, edge) in edges.enumerate() {
+            let separator = if index == 0 { "" } else { ",\n" };
+            let name = match &edge.name {
+                EdgeName::Name(name) => strings.index(name),
+                EdgeName::Index(index) => *index,
+            };
+            write!(
+                writer,
+                "{separator}{},{name},{}",
+                edge.kind as u8,
+                edge.target * NODE_FIELD_COUNT,
+            )?;
+        }
+
+        writer.write_all(
+            b"],\n\"trace_function_infos\":[],\n\"trace_tree\":[],\n\"samples\":[],\n\"locations\":[],\n\"strings\":[",
+        )?;
+        for (index, string) in strings.strings.iter().enumerate() {
+            let separator = if index == 0 { "" } else { ",\n" };
+            write!(writer, "{separator}{}", json_string(string))?;
+        }
+        writer.write_all(b"]}\n")?;
+
+        writer.flush()
+    }
+}
+
+/// The string table of the snapshot.
+#[derive(Debug, Default)]
+struct Strings {
+    strings: Vec<String>,
+    indices: FxHashMap<String, usize>,
+}
+
+impl Strings {
+    fn index(&mut self, string: &str) -> usize {
+        if let Some(&index) = self.indices.get(string) {
+            return index;
+        }
+        let index = self.strings.len();
+        self.strings.push(string.to_owned());
+        self.indices.insert(string.to_owned(), index);
+        index
+    }
+}
+
+fn json_string(string: &str) -> String {
+    serde_json::Value::from(string).to_string()
+}
+
+fn json_array(strings: &[&str]) -> String {
+    let strings: Vec<_> = strings.iter().map(|string| json_string(string)).collect();
+    format!("[{}]", strings.join(","))
+}
+
+fn synthetic(name: &str) -> Node {
+    Node {
+        kind: NodeType::Synthetic,
+        name: name.to_owned(),
+        size: 0,
+        edges: Vec::new(),
+    }
+}
+
+/// Returns the node of a value of the heap, with edges that point to addresses.
+fn visit(node: &HeapNode<'_>, context: &Context<'_>) -> Node {
+    let type_name = node.type_name();
+    let mut snapshot_node = Node {
+        kind: NodeType::Hidden,
+        name: type_name.to_owned(),
+        size: node.size(),
+        edges: Vec::new(),
+    };
+
+    let mut named = FxHashSet::default();
+    if let Some(object) = node.downcast_ref::<VTableObject>() {
+        // Objects that are being mutated don't get named edges.
+        if let Ok(borrowed) = object.object().try_borrow() {
+            snapshot_node.kind = object_type(object, &borrowed);
+            snapshot_node.name = object_name(snapshot_node.kind, &borrowed);
+            snapshot_node.edges = property_edges(&borrowed);
+            for edge in &snapshot_node.edges {
+                named.insert(edge.target);
+            }
+            if let Some(prototype) = borrowed.prototype() {
+                snapshot_node.edges.push(Edge {
+                    kind: EdgeType::Property,
+                    name: EdgeName::Name("__proto__".to_owned()),
+                    target: Gc::addr(prototype.inner()),
+                });
+            }
+        }
+    } else if let Some(code) = node.downcast_ref::<CodeBlock>() {
+        snapshot_node.kind = NodeType::Code;
+        snapshot_node.name = context.interner().resolve_expect(code.name()).to_string();
+    } else if type_name.contains("::shape::") {
+        snapshot_node.kind = NodeType::ObjectShape;
+    }
+
+    // References that are not held by properties are internal to the engine, and are named
+    // once the types of all the nodes are known.
+    for target in node.edges() {
+        if named.contains(&target) {
+            continue;
+        }
+        snapshot_node.edges.push(Edge {
+            kind: EdgeType::Hidden,
+            name: EdgeName::Index(0),
+            target,
+        });
+    }
+
+    snapshot_node
+}
+
+fn object_type(object: &VTableObject, borrowed: &Object) -> NodeType {
+    if object.vtable().__call__.is_some() {
+        NodeType::Closure
+    } else if borrowed.is_array() {
+        NodeType::Array
+    } else if borrowed.is_regexp() {
+        NodeType::RegExp
+    } else {
+        NodeType::Object
+    }
+}
+
+/// Returns the name of an object without running any user code.
+///
+/// Functions are named by their own `name` property, and other objects by the `name` of the
+/// constructor of their prototype.
+fn object_name(kind: NodeType, object: &Object) -> String {
+    let name = if kind == NodeType::Closure {
+        data_string(object, "name")
+    } else {
+        object
+            .prototype()
+            .and_then(|prototype| {
+                let prototype = prototype.try_borrow().ok()?;
+                data_object(&prototype, "constructor")
+            })
+            .and_then(|constructor| {
+                let constructor = constructor.try_borrow().ok()?;
+                data_string(&constructor, "name")
+            })
+    };
+
+    match (kind, name) {
+        (_, Some(name)) if !name.is_empty() => name,
+        (NodeType::Closure, _) => "(anonymous)".to_owned(),
+        (NodeType::Array, _) => "Array".to_owned(),
+        _ => "Object".to_owned(),
+    }
+}
+
+fn data_value(object: &Object, key: &str) -> Option<JsValue> {
+    match object.properties().get(&key.into())?.kind() {
+        DescriptorKind::Data {
+            value: Some(value), ..
+        } => Some(value.clone()),
+        _ => None,
+    }
+}
+
+fn data_object(object: &Object, key: &str) -> Option<JsObject> {
+    data_value(object, key)?.as_object().cloned()
+}
+
+fn data_string(object: &Object, key: &str) -> Option<String> {
+    data_value(object, key)?
+        .as_string()
+        .map(JsString::to_std_string_escaped)
+}
+
+/// Returns the edges of the properties of `object` that hold objects.
+fn property_edges(object: &Object) -> Vec<Edge> {
+    let mut edges = Vec::new();
+    let properties = object.properties();
+
+    for (index, property) in properties.index_properties() {
+        push_property_edges(&mut edges, &PropertyKey::Index(index), &property);
+    }
+    for key in properties.shape.keys() {
+        if let Some(property) = properties.get(&key) {
+            push_property_edges(&mut edges, &key, &property);
+        }
+    }
+
+    edges
+}
+
+fn push_property_edges(edges: &mut Vec<Edge>, key: &PropertyKey, property: &PropertyDescriptor) {
+    let mut push = |kind, name, value: Option<&JsValue>| {
+        if let Some(object) = value.and_then(JsValue::as_object) {
+            edges.push(Edge {
+                kind,
+                name,
+                target: Gc::addr(object.inner()),
+            });
+        }
+    };
+
+    match property.kind() {
+        DescriptorKind::Data { value, .. } => match key {
+            PropertyKey::Index(index) => push(
+                EdgeType::Element,
+                EdgeName::Index(*index as usize),
+                value.as_ref(),
+            ),
+            _ => push(
+                EdgeType::Property,
+                EdgeName::Name(key.to_string()),
+                value.as_ref(),
+            ),
+        },
+        DescriptorKind::Accessor { get, set } => {
+            push(
+                EdgeType::Property,
+                EdgeName::Name(format!("get {key}")),
+                get.as_ref(),
+            );
+            push(
+                EdgeType::Property,
+                EdgeName::Name(format!("set {key}")),
+                set.as_ref(),
+            );
+        }
+        DescriptorKind::Generic => {}
+    }
+}
+
+impl Context<'_> {
+    /// Writes a snapshot of the heap of the current thread to `writer`, in the `.heapsnapshot`
+    /// format of V8.
+    ///
+    /// This runs a garbage collection first, so that the snapshot only contains reachable values.
+    /// The snapshot can be loaded in the memory tab of the Chrome developer tools.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to `writer` fails.
+    pub fn write_heap_snapshot<W: Write>(&self, writer: W) -> io::Result<()> {
+        boa_gc::force_collect();
+        Snapshot::new(self).write(writer)
+    }
+}
@@ -3,6 +3,7 @@ use crate::{
     job::JobCallback,
     object::{JsFunction, JsObject},
     realm::Realm,
+    vm::RuntimeBudget,
     Context, JsResult, JsValue,
 };
 use chrono::{DateTime, FixedOffset, Local, LocalResult, NaiveDateTime, TimeZone, Utc};
@@ -154,6 +155,30 @@ pub trait HostHooks {
         Ok(())
     }
 
+    /// [`AgentCanSuspend ( )`][spec]
+    ///
+    /// Returns `true` if the host allows `Atomics.wait` to suspend the thread of the [`Context`],
+    /// which can be undesirable on the main thread of an application.
+    ///
+    /// Defaults to `true`.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-agentcansuspend
+    fn can_block(&self) -> bool {
+        true
+    }
+
+    /// Called when the code running in the [`Context`] exhausts a budget of its
+    /// [`RuntimeLimits`][crate::vm::RuntimeLimits].
+    ///
+    /// Returns `true` to refuel the budget and continue running, or `false` to abort the running
+    /// code with an uncatchable [`RuntimeLimit`][crate::JsNativeErrorKind::RuntimeLimit] error.
+    /// The limits can be changed with [`Context::runtime_limits_mut`] before refueling.
+    ///
+    /// Defaults to `false`.
+    fn runtime_budget_exhausted(&self, _budget: RuntimeBudget, _context: &mut Context<'_>) -> bool {
+        false
+    }
+
     /// Creates the global object of a new [`Context`] from the initial intrinsics.
     ///
     /// Equivalent to the step 7 of [`InitializeHostDefinedRealm ( )`][ihdr].
@@ -4,6 +4,7 @@ use icu_collator::{Collator, CollatorError, CollatorOptions};
 use icu_list::{ListError, ListFormatter, ListLength};
 use icu_locid_transform::{LocaleCanonicalizer, LocaleExpander, LocaleTransformError};
 use icu_normalizer::{ComposingNormalizer, DecomposingNormalizer, NormalizerError};
+use icu_plurals::{PluralRuleType, PluralRules, PluralsError};
 use icu_provider::{
     AnyProvider, AsDeserializingBufferProvider, AsDowncastingAnyProvider, BufferProvider,
     DataError, DataLocale, DataProvider, DataRequest, DataResponse, KeyedDataMarker, MaybeSendSync,
@@ -127,6 +128,28 @@ impl BoaProvider<'_> {
         }
     }
 
+    /// Creates a new [`PluralRules`] from the provided [`DataProvider`] and rule type.
+    pub(crate) fn try_new_plural_rules(
+        &self,
+        locale: &DataLocale,
+        rule_type: PluralRuleType,
+    ) -> Result<PluralRules, PluralsError> {
+        match (*self, rule_type) {
+            (BoaProvider::Buffer(buf), PluralRuleType::Ordinal) => {
+                PluralRules::try_new_ordinal_with_buffer_provider(buf, locale)
+            }
+            (BoaProvider::Buffer(buf), _) => {
+                PluralRules::try_new_cardinal_with_buffer_provider(buf, locale)
+            }
+            (BoaProvider::Any(any), PluralRuleType::Ordinal) => {
+                PluralRules::try_new_ordinal_with_any_provider(any, locale)
+            }
+            (BoaProvider::Any(any), _) => {
+                PluralRules::try_new_cardinal_with_any_provider(any, locale)
+            }
+        }
+    }
+
     /// Creates a new [`NativeSegmenter`] from the provided [`DataProvider`] and options.
     pub(crate) fn try_new_segmenter(
         &self,
@@ -0,0 +1,65 @@
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+/// A handle to interrupt the code running in a [`Context`][crate::Context] from another thread.
+///
+/// The handle is obtained with [`Context::interrupt_handle`][crate::Context::interrupt_handle].
+/// After [`InterruptHandle::interrupt`] is called, the running code is aborted with an
+/// uncatchable [`RuntimeLimit`][crate::JsNativeErrorKind::RuntimeLimit] error the next time it
+/// checks for interrupts, which is at the start of every loop iteration and before every call to
+/// a function or a constructor. Calls to builtins that don't call back into JavaScript, like
+/// sorting a large array with the default comparator, are not interrupted until they return.
+///
+/// An interrupt applies to the evaluation of a script, a module or a job that is in progress when
+/// it is requested. It is consumed by the abort, and a request that wasn't handled, because it
+/// was made while the context was idle or after the code passed its last check, is discarded
+/// when the next evaluation starts. The context can keep running code afterwards in both cases.
+///
+/// # Examples
+///
+/// ```
+/// use boa_engine::{Context, Source};
+/// use std::{thread, time::Duration};
+///
+/// let mut context = Context::default();
+/// let handle = context.interrupt_handle();
+///
+/// let interrupter = thread::spawn(move || {
+///     thread::sleep(Duration::from_millis(10));
+///     handle.interrupt();
+/// });
+///
+/// let result = context.eval(Source::from_bytes("try { while (true) {} } catch {}"));
+/// assert!(result.is_err());
+/// interrupter.join().unwrap();
+///
+/// // The context can be used again after an interrupt.
+/// let result = context.eval(Source::from_bytes("1 + 1"));
+/// assert_eq!(result.unwrap().as_number(), Some(2.0));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct InterruptHandle {
+    interrupted: Arc<AtomicBool>,
+}
+
+impl InterruptHandle {
+    /// Requests the context of this handle to abort the code it is running.
+    ///
+    /// This has no effect if the context isn't running any code.
+    pub fn interrupt(&self) {
+        self.interrupted.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns `true` if an interrupt was requested and hasn't been handled by the context yet.
+    #[must_use]
+    pub fn is_interrupted(&self) -> bool {
+        self.interrupted.load(Ordering::Relaxed)
+    }
+
+    /// Clears the interrupt, returning `true` if one was requested.
+    pub(crate) fn take(&self) -> bool {
+        self.interrupted.swap(false, Ordering::Relaxed)
+    }
+}
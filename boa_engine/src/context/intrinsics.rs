@@ -107,6 +107,8 @@ pub struct StandardConstructors {
     async_function: StandardConstructor,
     generator_function: StandardConstructor,
     async_generator_function: StandardConstructor,
+    iterator: StandardConstructor,
+    async_iterator: StandardConstructor,
     array: StandardConstructor,
     bigint: StandardConstructor,
     number: StandardConstructor,
@@ -122,6 +124,7 @@ pub struct StandardConstructors {
     eval_error: StandardConstructor,
     uri_error: StandardConstructor,
     aggregate_error: StandardConstructor,
+    suppressed_error: StandardConstructor,
     map: StandardConstructor,
     set: StandardConstructor,
     typed_array: StandardConstructor,
@@ -137,20 +140,49 @@ pub struct StandardConstructors {
     typed_float32_array: StandardConstructor,
     typed_float64_array: StandardConstructor,
     array_buffer: StandardConstructor,
+    shared_array_buffer: StandardConstructor,
     data_view: StandardConstructor,
     date_time_format: StandardConstructor,
     promise: StandardConstructor,
     weak_ref: StandardConstructor,
+    finalization_registry: StandardConstructor,
+    disposable_stack: StandardConstructor,
+    async_disposable_stack: StandardConstructor,
+    shadow_realm: StandardConstructor,
     weak_map: StandardConstructor,
     weak_set: StandardConstructor,
     #[cfg(feature = "intl")]
     collator: StandardConstructor,
     #[cfg(feature = "intl")]
+    display_names: StandardConstructor,
+    #[cfg(feature = "intl")]
     list_format: StandardConstructor,
     #[cfg(feature = "intl")]
     locale: StandardConstructor,
     #[cfg(feature = "intl")]
+    number_format: StandardConstructor,
+    #[cfg(feature = "intl")]
+    plural_rules: StandardConstructor,
+    #[cfg(feature = "intl")]
+    relative_time_format: StandardConstructor,
+    #[cfg(feature = "intl")]
     segmenter: StandardConstructor,
+    #[cfg(feature = "temporal")]
+    calendar: StandardConstructor,
+    #[cfg(feature = "temporal")]
+    duration: StandardConstructor,
+    #[cfg(feature = "temporal")]
+    instant: StandardConstructor,
+    #[cfg(feature = "temporal")]
+    plain_date: StandardConstructor,
+    #[cfg(feature = "temporal")]
+    plain_date_time: StandardConstructor,
+    #[cfg(feature = "temporal")]
+    plain_time: StandardConstructor,
+    #[cfg(feature = "temporal")]
+    time_zone: StandardConstructor,
+    #[cfg(feature = "temporal")]
+    zoned_date_time: StandardConstructor,
 }
 
 impl Default for StandardConstructors {
@@ -169,6 +201,8 @@ impl Default for StandardConstructors {
             },
             async_function: StandardConstructor::default(),
             generator_function: StandardConstructor::default(),
+            iterator: StandardConstructor::default(),
+            async_iterator: StandardConstructor::default(),
             array: StandardConstructor::with_prototype(JsObject::from_proto_and_data(
                 None,
                 ObjectData::array(),
@@ -196,6 +230,7 @@ impl Default for StandardConstructors {
             eval_error: StandardConstructor::default(),
             uri_error: StandardConstructor::default(),
             aggregate_error: StandardConstructor::default(),
+            suppressed_error: StandardConstructor::default(),
             map: StandardConstructor::default(),
             set: StandardConstructor::default(),
             typed_array: StandardConstructor::default(),
@@ -211,20 +246,49 @@ impl Default for StandardConstructors {
             typed_float32_array: StandardConstructor::default(),
             typed_float64_array: StandardConstructor::default(),
             array_buffer: StandardConstructor::default(),
+            shared_array_buffer: StandardConstructor::default(),
             data_view: StandardConstructor::default(),
             date_time_format: StandardConstructor::default(),
             promise: StandardConstructor::default(),
             weak_ref: StandardConstructor::default(),
+            finalization_registry: StandardConstructor::default(),
+            disposable_stack: StandardConstructor::default(),
+            async_disposable_stack: StandardConstructor::default(),
+            shadow_realm: StandardConstructor::default(),
             weak_map: StandardConstructor::default(),
             weak_set: StandardConstructor::default(),
             #[cfg(feature = "intl")]
             collator: StandardConstructor::default(),
             #[cfg(feature = "intl")]
+            display_names: StandardConstructor::default(),
+            #[cfg(feature = "intl")]
             list_format: StandardConstructor::default(),
             #[cfg(feature = "intl")]
             locale: StandardConstructor::default(),
             #[cfg(feature = "intl")]
+            number_format: StandardConstructor::default(),
+            #[cfg(feature = "intl")]
+            plural_rules: StandardConstructor::default(),
+            #[cfg(feature = "intl")]
+            relative_time_format: StandardConstructor::default(),
+            #[cfg(feature = "intl")]
             segmenter: StandardConstructor::default(),
+            #[cfg(feature = "temporal")]
+            calendar: StandardConstructor::default(),
+            #[cfg(feature = "temporal")]
+            duration: StandardConstructor::default(),
+            #[cfg(feature = "temporal")]
+            instant: StandardConstructor::default(),
+            #[cfg(feature = "temporal")]
+            plain_date: StandardConstructor::default(),
+            #[cfg(feature = "temporal")]
+            plain_date_time: StandardConstructor::default(),
+            #[cfg(feature = "temporal")]
+            plain_time: StandardConstructor::default(),
+            #[cfg(feature = "temporal")]
+            time_zone: StandardConstructor::default(),
+            #[cfg(feature = "temporal")]
+            zoned_date_time: StandardConstructor::default(),
         }
     }
 }
@@ -241,6 +305,28 @@ impl StandardConstructors {
         &self.async_generator_function
     }
 
+    /// Returns the `Iterator` constructor.
+    ///
+    /// More information:
+    ///  - [Iterator Helpers proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-iterator-helpers/#sec-iterator-constructor
+    #[inline]
+    pub const fn iterator(&self) -> &StandardConstructor {
+        &self.iterator
+    }
+
+    /// Returns the `AsyncIterator` constructor.
+    ///
+    /// More information:
+    ///  - [Async Iterator Helpers proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-async-iterator-helpers/#sec-asynciterator-constructor
+    #[inline]
+    pub const fn async_iterator(&self) -> &StandardConstructor {
+        &self.async_iterator
+    }
+
     /// Returns the `Object` constructor.
     ///
     /// More information:
@@ -472,6 +558,17 @@ impl StandardConstructors {
         &self.aggregate_error
     }
 
+    /// Returns the `SuppressedError` constructor.
+    ///
+    /// More information:
+    ///  - [Explicit Resource Management proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-suppressederror-constructor
+    #[inline]
+    pub const fn suppressed_error(&self) -> &StandardConstructor {
+        &self.suppressed_error
+    }
+
     /// Returns the `Map` constructor.
     ///
     /// More information:
@@ -637,6 +734,17 @@ impl StandardConstructors {
         &self.array_buffer
     }
 
+    /// Returns the `SharedArrayBuffer` constructor.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-sharedarraybuffer-constructor
+    #[inline]
+    pub const fn shared_array_buffer(&self) -> &StandardConstructor {
+        &self.shared_array_buffer
+    }
+
     /// Returns the `DataView` constructor.
     ///
     /// More information:
@@ -681,6 +789,50 @@ impl StandardConstructors {
         &self.weak_ref
     }
 
+    /// Returns the `FinalizationRegistry` constructor.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-finalization-registry-constructor
+    #[inline]
+    pub const fn finalization_registry(&self) -> &StandardConstructor {
+        &self.finalization_registry
+    }
+
+    /// Returns the `DisposableStack` constructor.
+    ///
+    /// More information:
+    ///  - [Explicit Resource Management proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack-constructor
+    #[inline]
+    pub const fn disposable_stack(&self) -> &StandardConstructor {
+        &self.disposable_stack
+    }
+
+    /// Returns the `AsyncDisposableStack` constructor.
+    ///
+    /// More information:
+    ///  - [Explicit Resource Management proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack-constructor
+    #[inline]
+    pub const fn async_disposable_stack(&self) -> &StandardConstructor {
+        &self.async_disposable_stack
+    }
+
+    /// Returns the `ShadowRealm` constructor.
+    ///
+    /// More information:
+    ///  - [ShadowRealm proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-shadowrealm-constructor
+    #[inline]
+    pub const fn shadow_realm(&self) -> &StandardConstructor {
+        &self.shadow_realm
+    }
+
     /// Returns the `WeakMap` constructor.
     ///
     /// More information:
@@ -727,6 +879,18 @@ impl StandardConstructors {
         &self.list_format
     }
 
+    /// Returns the `Intl.DisplayNames` constructor.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl-displaynames-constructor
+    #[inline]
+    #[cfg(feature = "intl")]
+    pub const fn display_names(&self) -> &StandardConstructor {
+        &self.display_names
+    }
+
     /// Returns the `Intl.Locale` constructor.
     ///
     /// More information:
@@ -739,6 +903,42 @@ impl StandardConstructors {
         &self.locale
     }
 
+    /// Returns the `Intl.NumberFormat` constructor.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.numberformat
+    #[inline]
+    #[cfg(feature = "intl")]
+    pub const fn number_format(&self) -> &StandardConstructor {
+        &self.number_format
+    }
+
+    /// Returns the `Intl.PluralRules` constructor.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.pluralrules
+    #[inline]
+    #[cfg(feature = "intl")]
+    pub const fn plural_rules(&self) -> &StandardConstructor {
+        &self.plural_rules
+    }
+
+    /// Returns the `Intl.RelativeTimeFormat` constructor.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.relativetimeformat
+    #[inline]
+    #[cfg(feature = "intl")]
+    pub const fn relative_time_format(&self) -> &StandardConstructor {
+        &self.relative_time_format
+    }
+
     /// Returns the `Intl.Segmenter` constructor.
     ///
     /// More information:
@@ -750,6 +950,102 @@ impl StandardConstructors {
     pub const fn segmenter(&self) -> &StandardConstructor {
         &self.segmenter
     }
+
+    /// Returns the `Temporal.Calendar` constructor.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-calendar-constructor
+    #[inline]
+    #[cfg(feature = "temporal")]
+    pub const fn calendar(&self) -> &StandardConstructor {
+        &self.calendar
+    }
+
+    /// Returns the `Temporal.Duration` constructor.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-duration-constructor
+    #[inline]
+    #[cfg(feature = "temporal")]
+    pub const fn duration(&self) -> &StandardConstructor {
+        &self.duration
+    }
+
+    /// Returns the `Temporal.Instant` constructor.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-instant-constructor
+    #[inline]
+    #[cfg(feature = "temporal")]
+    pub const fn instant(&self) -> &StandardConstructor {
+        &self.instant
+    }
+
+    /// Returns the `Temporal.PlainDate` constructor.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-plaindate-constructor
+    #[inline]
+    #[cfg(feature = "temporal")]
+    pub const fn plain_date(&self) -> &StandardConstructor {
+        &self.plain_date
+    }
+
+    /// Returns the `Temporal.PlainDateTime` constructor.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-plaindatetime-constructor
+    #[inline]
+    #[cfg(feature = "temporal")]
+    pub const fn plain_date_time(&self) -> &StandardConstructor {
+        &self.plain_date_time
+    }
+
+    /// Returns the `Temporal.PlainTime` constructor.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-plaintime-constructor
+    #[inline]
+    #[cfg(feature = "temporal")]
+    pub const fn plain_time(&self) -> &StandardConstructor {
+        &self.plain_time
+    }
+
+    /// Returns the `Temporal.TimeZone` constructor.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-timezone-constructor
+    #[inline]
+    #[cfg(feature = "temporal")]
+    pub const fn time_zone(&self) -> &StandardConstructor {
+        &self.time_zone
+    }
+
+    /// Returns the `Temporal.ZonedDateTime` constructor.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-zoneddatetime-constructor
+    #[inline]
+    #[cfg(feature = "temporal")]
+    pub const fn zoned_date_time(&self) -> &StandardConstructor {
+        &self.zoned_date_time
+    }
 }
 
 /// Cached intrinsic objects
@@ -764,6 +1060,9 @@ pub struct IntrinsicObjects {
     /// [`%JSON%`](https://tc39.es/ecma262/#sec-json)
     json: JsObject,
 
+    /// [`%Atomics%`](https://tc39.es/ecma262/#sec-atomics)
+    atomics: JsObject,
+
     /// [`%ThrowTypeError%`](https://tc39.es/ecma262/#sec-%throwtypeerror%)
     throw_type_error: JsFunction,
 
@@ -812,6 +1111,14 @@ pub struct IntrinsicObjects {
     /// [`%SegmentsPrototype%`](https://tc39.es/ecma402/#sec-%segmentsprototype%-object)
     #[cfg(feature = "intl")]
     segments_prototype: JsObject,
+
+    /// [`%Temporal%`](https://tc39.es/proposal-temporal/#sec-temporal-objects)
+    #[cfg(feature = "temporal")]
+    temporal: JsObject,
+
+    /// [`%Temporal.Now%`](https://tc39.es/proposal-temporal/#sec-temporal-now-object)
+    #[cfg(feature = "temporal")]
+    temporal_now: JsObject,
 }
 
 impl Default for IntrinsicObjects {
@@ -820,6 +1127,7 @@ impl Default for IntrinsicObjects {
             reflect: JsObject::default(),
             math: JsObject::default(),
             json: JsObject::default(),
+            atomics: JsObject::default(),
             throw_type_error: JsFunction::empty_intrinsic_function(false),
             array_prototype_values: JsFunction::empty_intrinsic_function(false),
             iterator_prototypes: IteratorPrototypes::default(),
@@ -839,6 +1147,10 @@ impl Default for IntrinsicObjects {
             intl: JsObject::default(),
             #[cfg(feature = "intl")]
             segments_prototype: JsObject::default(),
+            #[cfg(feature = "temporal")]
+            temporal: JsObject::default(),
+            #[cfg(feature = "temporal")]
+            temporal_now: JsObject::default(),
         }
     }
 }
@@ -915,6 +1227,13 @@ impl IntrinsicObjects {
         self.json.clone()
     }
 
+    /// Gets the [`%Atomics%`][spec] intrinsic object.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomics
+    pub fn atomics(&self) -> JsObject {
+        self.atomics.clone()
+    }
+
     /// Gets the [`%isFinite%`][spec] intrinsic function.
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-isfinite-number
@@ -974,6 +1293,22 @@ impl IntrinsicObjects {
     pub fn segments_prototype(&self) -> JsObject {
         self.segments_prototype.clone()
     }
+
+    /// Gets the [`%Temporal%`][spec] intrinsic object.
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-objects
+    #[cfg(feature = "temporal")]
+    pub fn temporal(&self) -> JsObject {
+        self.temporal.clone()
+    }
+
+    /// Gets the [`%Temporal.Now%`][spec] intrinsic object.
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-now-object
+    #[cfg(feature = "temporal")]
+    pub fn temporal_now(&self) -> JsObject {
+        self.temporal_now.clone()
+    }
 }
 
 /// Contains commonly used [`ObjectTemplate`]s.
@@ -1,36 +1,49 @@
 //! The ECMAScript context.
 
+#[cfg(feature = "heap-snapshot")]
+mod heap_snapshot;
 mod hooks;
 #[cfg(feature = "intl")]
 pub(crate) mod icu;
+mod interrupt;
 pub mod intrinsics;
 mod maybe_shared;
+#[cfg(any(feature = "intl", feature = "temporal"))]
+mod time_zone;
 
 pub use hooks::{DefaultHooks, HostHooks};
 #[cfg(feature = "intl")]
 pub use icu::{BoaProvider, IcuError};
+pub use interrupt::InterruptHandle;
 use intrinsics::Intrinsics;
 pub use maybe_shared::MaybeShared;
+#[cfg(any(feature = "intl", feature = "temporal"))]
+pub use time_zone::{DefaultTimeZoneProvider, TimeZoneProvider, ZoneInfoTimeZoneProvider};
 
 #[cfg(not(feature = "intl"))]
 pub use std::marker::PhantomData;
-use std::{io::Read, path::Path, rc::Rc};
+use std::{io::Read, path::Path, rc::Rc, time::Instant};
 
 use crate::{
-    builtins,
+    builtins::{
+        self,
+        atomics::{AsyncWaiters, Atomics},
+        weak::FinalizationRegistry,
+    },
     class::{Class, ClassBuilder},
     job::{JobQueue, NativeJob, SimpleJobQueue},
     module::{ModuleLoader, SimpleModuleLoader},
     native_function::NativeFunction,
-    object::{shape::RootShape, FunctionObjectBuilder, JsObject},
+    object::{shape::RootShape, FunctionObjectBuilder, JsObject, VTableObject},
     optimizer::{Optimizer, OptimizerOptions, OptimizerStatistics},
     property::{Attribute, PropertyDescriptor, PropertyKey},
     realm::Realm,
     script::Script,
     vm::{CallFrame, Vm},
-    JsResult, JsValue, Source,
+    JsNativeError, JsResult, JsValue, Source,
 };
 use boa_ast::{expression::Identifier, StatementList};
+use boa_gc::{GcConfig, GcStatistics, WeakGc};
 use boa_interner::Interner;
 use boa_profiler::Profiler;
 
@@ -94,7 +107,20 @@ pub struct Context<'host> {
 
     pub(crate) vm: Vm,
 
-    pub(crate) kept_alive: Vec<JsObject>,
+    /// Requests to abort the running code, possibly from other threads.
+    pub(crate) interrupt_handle: InterruptHandle,
+
+    pub(crate) kept_alive: Vec<JsValue>,
+
+    /// The `FinalizationRegistry` objects created by this context.
+    pub(crate) finalization_registries: Vec<WeakGc<VTableObject>>,
+
+    /// The number of garbage collections that had run when the cleanup jobs of the
+    /// `FinalizationRegistry` objects were last enqueued.
+    collections: usize,
+
+    /// The pending `Atomics.waitAsync` calls of this context.
+    pub(crate) async_waiters: AsyncWaiters,
 
     /// ICU related utilities
     #[cfg(feature = "intl")]
@@ -106,6 +132,10 @@ pub struct Context<'host> {
 
     module_loader: MaybeShared<'host, dyn ModuleLoader>,
 
+    /// Time zone data of the `Temporal` and `Intl.DateTimeFormat` builtins.
+    #[cfg(any(feature = "intl", feature = "temporal"))]
+    time_zone_provider: Rc<dyn TimeZoneProvider>,
+
     optimizer_options: OptimizerOptions,
     root_shape: RootShape,
 
@@ -403,12 +433,53 @@ impl<'host> Context<'host> {
     }
 
     /// Runs all the jobs in the job queue.
+    ///
+    /// This also enqueues the cleanup jobs of all the `FinalizationRegistry` objects that had
+    /// any of their targets collected since the last call, and the jobs resolving the promises of
+    /// the `Atomics.waitAsync` calls that were notified since the last call.
     #[inline]
     pub fn run_jobs(&mut self) {
+        self.enqueue_cleanup_jobs();
+        self.enqueue_notified_waiter_jobs();
         self.job_queue().run_jobs(self);
         self.clear_kept_objects();
     }
 
+    /// Enqueues the cleanup jobs of all the `FinalizationRegistry` objects that had any of their
+    /// targets collected since the last call.
+    ///
+    /// This does nothing unless a garbage collection ran since the last call, so [`JobQueue`]
+    /// implementations can call this after running each job.
+    #[inline]
+    pub fn enqueue_cleanup_jobs(&mut self) {
+        let collections = boa_gc::statistics().collections;
+        if collections != self.collections {
+            self.collections = collections;
+            FinalizationRegistry::enqueue_cleanup_jobs(self);
+        }
+    }
+
+    /// Enqueues the jobs resolving the promises of the `Atomics.waitAsync` calls of this context
+    /// that were notified since the last call, possibly by another thread.
+    ///
+    /// [`JobQueue`] implementations should call this after running each job, and after
+    /// [`Context::wait_for_notified_waiters`] returns `true`.
+    #[inline]
+    pub fn enqueue_notified_waiter_jobs(&mut self) {
+        Atomics::enqueue_notified_waiter_jobs(self);
+    }
+
+    /// Blocks the current thread until an `Atomics.waitAsync` call of this context is notified,
+    /// or until `deadline`.
+    ///
+    /// Returns `true` if a call was notified, including the calls notified before this call whose
+    /// jobs were not enqueued yet. [`JobQueue`] implementations can use this instead of sleeping
+    /// while waiting for their next timeout job.
+    #[inline]
+    pub fn wait_for_notified_waiters(&self, deadline: Instant) -> bool {
+        self.async_waiters.wait_for_notified(deadline)
+    }
+
     /// Abstract operation [`ClearKeptObjects`][clear].
     ///
     /// Clears all objects maintained alive by calls to the [`AddToKeptObjects`][add] abstract
@@ -423,8 +494,13 @@ impl<'host> Context<'host> {
     }
 
     /// Retrieves the current stack trace of the context.
+    ///
+    /// The frames are yielded starting from the innermost one. The location of every frame can
+    /// be obtained with [`CallFrame::position`] and [`CodeBlock::source_path`].
+    ///
+    /// [`CodeBlock::source_path`]: crate::vm::CodeBlock::source_path
     #[inline]
-    pub fn stack_trace(&mut self) -> impl Iterator<Item = &CallFrame> {
+    pub fn stack_trace(&self) -> impl Iterator<Item = &CallFrame> {
         self.vm.frames.iter().rev()
     }
 
@@ -477,6 +553,42 @@ impl<'host> Context<'host> {
     pub fn runtime_limits_mut(&mut self) -> &mut RuntimeLimits {
         &mut self.vm.runtime_limits
     }
+
+    /// Returns an [`InterruptHandle`] that aborts the code running in this context.
+    ///
+    /// The handle can be sent to other threads, and all the handles of a context share the
+    /// same interrupt.
+    #[inline]
+    #[must_use]
+    pub fn interrupt_handle(&self) -> InterruptHandle {
+        self.interrupt_handle.clone()
+    }
+
+    /// Get the [`GcConfig`] of the garbage collector.
+    ///
+    /// The garbage collector is shared by all the contexts of a thread, so this is the same
+    /// configuration for all of them.
+    #[inline]
+    #[must_use]
+    pub fn gc_config(&self) -> GcConfig {
+        boa_gc::config()
+    }
+
+    /// Set the [`GcConfig`] of the garbage collector.
+    ///
+    /// The garbage collector is shared by all the contexts of a thread, so this changes the
+    /// configuration for all of them.
+    #[inline]
+    pub fn set_gc_config(&mut self, config: GcConfig) {
+        boa_gc::set_config(config);
+    }
+
+    /// Get the [`GcStatistics`] of the garbage collector.
+    #[inline]
+    #[must_use]
+    pub fn gc_statistics(&self) -> GcStatistics {
+        boa_gc::statistics()
+    }
 }
 
 // ==== Private API ====
@@ -683,6 +795,26 @@ impl Context<'_> {
     pub(crate) const fn is_strict(&self) -> bool {
         self.strict
     }
+
+    /// Checks the limits that are not tied to a specific operation, aborting the execution with
+    /// an uncatchable error if any of them was exceeded.
+    ///
+    /// This is called on loop back-edges and function calls.
+    pub(crate) fn check_runtime_limits(&self) -> JsResult<()> {
+        if self.interrupt_handle.take() {
+            return Err(JsNativeError::runtime_limit()
+                .with_message("Execution interrupted")
+                .into());
+        }
+
+        if boa_gc::take_heap_limit_exceeded() {
+            return Err(JsNativeError::runtime_limit()
+                .with_message("Maximum heap size exceeded")
+                .into());
+        }
+
+        Ok(())
+    }
 }
 
 impl<'host> Context<'host> {
@@ -699,6 +831,12 @@ impl<'host> Context<'host> {
     pub(crate) const fn icu(&self) -> &icu::Icu<'host> {
         &self.icu
     }
+
+    /// Get the time zone data provider of the `Temporal` and `Intl.DateTimeFormat` builtins.
+    #[cfg(any(feature = "intl", feature = "temporal"))]
+    pub(crate) fn time_zone_provider(&self) -> Rc<dyn TimeZoneProvider> {
+        self.time_zone_provider.clone()
+    }
 }
 
 /// Builder for the [`Context`] type.
@@ -718,10 +856,14 @@ pub struct ContextBuilder<'icu, 'hooks, 'queue, 'module> {
     host_hooks: Option<MaybeShared<'hooks, dyn HostHooks>>,
     job_queue: Option<MaybeShared<'queue, dyn JobQueue>>,
     module_loader: Option<MaybeShared<'module, dyn ModuleLoader>>,
+    runtime_limits: Option<RuntimeLimits>,
+    gc_config: Option<GcConfig>,
     #[cfg(feature = "intl")]
     icu: Option<icu::Icu<'icu>>,
     #[cfg(not(feature = "intl"))]
     icu: PhantomData<&'icu ()>,
+    #[cfg(any(feature = "intl", feature = "temporal"))]
+    time_zone_provider: Option<Rc<dyn TimeZoneProvider>>,
     #[cfg(feature = "fuzz")]
     instructions_remaining: usize,
 }
@@ -734,6 +876,9 @@ impl std::fmt::Debug for ContextBuilder<'_, '_, '_, '_> {
         struct HostHooks;
         #[derive(Clone, Copy, Debug)]
         struct ModuleLoader;
+        #[cfg(any(feature = "intl", feature = "temporal"))]
+        #[derive(Clone, Copy, Debug)]
+        struct TimeZoneProvider;
 
         let mut out = f.debug_struct("ContextBuilder");
 
@@ -743,11 +888,19 @@ impl std::fmt::Debug for ContextBuilder<'_, '_, '_, '_> {
             .field(
                 "module_loader",
                 &self.module_loader.as_ref().map(|_| ModuleLoader),
-            );
+            )
+            .field("runtime_limits", &self.runtime_limits)
+            .field("gc_config", &self.gc_config);
 
         #[cfg(feature = "intl")]
         out.field("icu", &self.icu);
 
+        #[cfg(any(feature = "intl", feature = "temporal"))]
+        out.field(
+            "time_zone_provider",
+            &self.time_zone_provider.as_ref().map(|_| TimeZoneProvider),
+        );
+
         #[cfg(feature = "fuzz")]
         out.field("instructions_remaining", &self.instructions_remaining);
 
@@ -845,6 +998,39 @@ impl<'icu, 'hooks, 'queue, 'module> ContextBuilder<'icu, 'hooks, 'queue, 'module
         }
     }
 
+    /// Initializes the [`RuntimeLimits`] of the context.
+    #[must_use]
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn runtime_limits(mut self, runtime_limits: RuntimeLimits) -> Self {
+        self.runtime_limits = Some(runtime_limits);
+        self
+    }
+
+    /// Sets the [`GcConfig`] of the garbage collector when the context is built.
+    ///
+    /// The garbage collector is shared by all the contexts of a thread, so this changes the
+    /// configuration for all of them.
+    #[must_use]
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn gc_config(mut self, config: GcConfig) -> Self {
+        self.gc_config = Some(config);
+        self
+    }
+
+    /// Initializes the [`TimeZoneProvider`] used by the `Temporal` and `Intl.DateTimeFormat`
+    /// builtins for the context.
+    ///
+    /// Defaults to [`DefaultTimeZoneProvider`], which only provides the `UTC` and `Etc/GMT±N`
+    /// time zones.
+    ///
+    /// This function is only available if the `intl` or `temporal` features are enabled.
+    #[cfg(any(feature = "intl", feature = "temporal"))]
+    #[must_use]
+    pub fn time_zone_provider(mut self, provider: Rc<dyn TimeZoneProvider>) -> Self {
+        self.time_zone_provider = Some(provider);
+        self
+    }
+
     /// Specifies the number of instructions remaining to the [`Context`].
     ///
     /// This function is only available if the `fuzz` feature is enabled.
@@ -870,13 +1056,21 @@ impl<'icu, 'hooks, 'queue, 'module> ContextBuilder<'icu, 'hooks, 'queue, 'module
             let hooks: &dyn HostHooks = &DefaultHooks;
             hooks.into()
         });
+        if let Some(config) = self.gc_config {
+            boa_gc::set_config(config);
+        }
+
         let realm = Realm::create(&*host_hooks, &root_shape);
-        let vm = Vm::new(realm.environment().clone());
+        let mut vm = Vm::new(realm.environment().clone());
+        if let Some(runtime_limits) = self.runtime_limits {
+            vm.runtime_limits = runtime_limits;
+        }
 
         let mut context = Context {
             realm,
             interner: self.interner.unwrap_or_default(),
             vm,
+            interrupt_handle: InterruptHandle::default(),
             strict: false,
             #[cfg(feature = "intl")]
             icu: self.icu.unwrap_or_else(|| {
@@ -887,6 +1081,9 @@ impl<'icu, 'hooks, 'queue, 'module> ContextBuilder<'icu, 'hooks, 'queue, 'module
             #[cfg(feature = "fuzz")]
             instructions_remaining: self.instructions_remaining,
             kept_alive: Vec::new(),
+            finalization_registries: Vec::new(),
+            collections: boa_gc::statistics().collections,
+            async_waiters: AsyncWaiters::default(),
             host_hooks,
             job_queue: self.job_queue.unwrap_or_else(|| {
                 let queue: Rc<dyn JobQueue> = Rc::new(SimpleJobQueue::new());
@@ -899,6 +1096,10 @@ impl<'icu, 'hooks, 'queue, 'module> ContextBuilder<'icu, 'hooks, 'queue, 'module
                 );
                 loader.into()
             }),
+            #[cfg(any(feature = "intl", feature = "temporal"))]
+            time_zone_provider: self
+                .time_zone_provider
+                .unwrap_or_else(|| Rc::new(DefaultTimeZoneProvider)),
             optimizer_options: OptimizerOptions::OPTIMIZE_ALL,
             root_shape,
             parser_identifier: 0,
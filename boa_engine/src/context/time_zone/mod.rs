@@ -0,0 +1,159 @@
+//! Time zone data used by the `Temporal` and `Intl.DateTimeFormat` builtins.
+
+mod zoneinfo;
+
+pub use zoneinfo::ZoneInfoTimeZoneProvider;
+
+/// A provider of the time zone data used by the `Temporal` and `Intl.DateTimeFormat` builtins.
+///
+/// Boa doesn't bundle the IANA time zone database, so by default only the `UTC` time zone and
+/// the fixed-offset `Etc/GMT±N` zones are available. Hosts can use [`ZoneInfoTimeZoneProvider`]
+/// to read the database installed on the system, or implement this trait on top of their own
+/// copy of it, and pass the provider to [`ContextBuilder::time_zone_provider`].
+///
+/// All instants are represented as seconds since the Unix epoch, and all offsets as seconds east
+/// of UTC. Identifiers passed to the methods are always ones returned by
+/// [`TimeZoneProvider::normalize`].
+///
+/// [`ContextBuilder::time_zone_provider`]: super::ContextBuilder::time_zone_provider
+pub trait TimeZoneProvider {
+    /// Returns the case-normalized form of the available named time zone `identifier`, which is
+    /// matched ASCII case-insensitively, or `None` if the time zone is not available.
+    fn normalize(&self, identifier: &str) -> Option<String>;
+
+    /// Returns the primary identifier of the named time zone `identifier`, resolving links such
+    /// as `Asia/Calcutta` to `Asia/Kolkata`.
+    ///
+    /// Defaults to returning `identifier` itself.
+    fn primary_identifier(&self, identifier: &str) -> String {
+        identifier.to_owned()
+    }
+
+    /// Returns the primary identifiers of all the available named time zones.
+    fn available_identifiers(&self) -> Vec<String>;
+
+    /// Returns the offset of the time zone `identifier` at the instant `epoch_seconds`.
+    fn offset(&self, identifier: &str, epoch_seconds: i64) -> i32;
+
+    /// Returns the offsets of the time zone `identifier` that map some instant to the wall-clock
+    /// time `local_seconds`, expressed as seconds since the epoch as if it was UTC.
+    ///
+    /// The result must be sorted from the largest offset to the smallest one, which orders the
+    /// corresponding instants from earliest to latest. It is empty if the local time is skipped
+    /// by a transition, and has more than one offset if it is repeated.
+    fn possible_offsets(&self, identifier: &str, local_seconds: i64) -> Vec<i32>;
+
+    /// Returns the first transition of the time zone `identifier` after `epoch_seconds`, if any.
+    fn next_transition(&self, identifier: &str, epoch_seconds: i64) -> Option<i64>;
+
+    /// Returns the last transition of the time zone `identifier` before `epoch_seconds`, if any.
+    fn previous_transition(&self, identifier: &str, epoch_seconds: i64) -> Option<i64>;
+
+    /// Returns the identifier of the current time zone of the host, if it is known.
+    ///
+    /// Defaults to `None`, in which case the UTC offset of the host at the current instant is
+    /// used instead.
+    fn host_time_zone(&self) -> Option<String> {
+        None
+    }
+}
+
+/// The default [`TimeZoneProvider`], which provides `UTC`, its aliases, and the `Etc/GMT±N`
+/// fixed-offset time zones.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DefaultTimeZoneProvider;
+
+impl DefaultTimeZoneProvider {
+    /// The aliases of `UTC` in the IANA time zone database.
+    const UTC_ALIASES: [&'static str; 18] = [
+        "UTC",
+        "Etc/UTC",
+        "Etc/UCT",
+        "Etc/Universal",
+        "Etc/Zulu",
+        "Etc/GMT",
+        "Etc/GMT+0",
+        "Etc/GMT-0",
+        "Etc/GMT0",
+        "Etc/Greenwich",
+        "GMT",
+        "GMT+0",
+        "GMT-0",
+        "GMT0",
+        "Greenwich",
+        "UCT",
+        "Universal",
+        "Zulu",
+    ];
+
+    /// Returns the fixed offset in seconds of a time zone identifier, if it is provided.
+    fn fixed_offset(identifier: &str) -> Option<i32> {
+        if Self::UTC_ALIASES
+            .iter()
+            .any(|alias| alias.eq_ignore_ascii_case(identifier))
+        {
+            return Some(0);
+        }
+
+        // The sign of the `Etc/GMT±N` zones is inverted, to follow the POSIX convention.
+        let name = identifier.get(..7)?;
+        if !name.eq_ignore_ascii_case("Etc/GMT") {
+            return None;
+        }
+        let (sign, hours) = match identifier[7..].split_at(1) {
+            ("+", hours) => (-1, hours),
+            ("-", hours) => (1, hours),
+            _ => return None,
+        };
+        if hours.starts_with('0') || !hours.bytes().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+        let hours = hours.parse::<i32>().ok()?;
+        let valid = if sign == -1 { hours <= 12 } else { hours <= 14 };
+        valid.then_some(sign * hours * 3600)
+    }
+}
+
+impl TimeZoneProvider for DefaultTimeZoneProvider {
+    fn normalize(&self, identifier: &str) -> Option<String> {
+        if let Some(alias) = Self::UTC_ALIASES
+            .iter()
+            .find(|alias| alias.eq_ignore_ascii_case(identifier))
+        {
+            return Some((*alias).to_owned());
+        }
+        Self::fixed_offset(identifier).map(|_| format!("Etc/GMT{}", &identifier[7..]))
+    }
+
+    fn primary_identifier(&self, identifier: &str) -> String {
+        if Self::fixed_offset(identifier) == Some(0) {
+            "UTC".to_owned()
+        } else {
+            identifier.to_owned()
+        }
+    }
+
+    fn available_identifiers(&self) -> Vec<String> {
+        let mut identifiers = vec!["UTC".to_owned()];
+        identifiers.extend((1..=14).map(|hours| format!("Etc/GMT-{hours}")));
+        identifiers.extend((1..=12).map(|hours| format!("Etc/GMT+{hours}")));
+        identifiers.sort_unstable();
+        identifiers
+    }
+
+    fn offset(&self, identifier: &str, _epoch_seconds: i64) -> i32 {
+        Self::fixed_offset(identifier).unwrap_or_default()
+    }
+
+    fn possible_offsets(&self, identifier: &str, _local_seconds: i64) -> Vec<i32> {
+        vec![Self::fixed_offset(identifier).unwrap_or_default()]
+    }
+
+    fn next_transition(&self, _identifier: &str, _epoch_seconds: i64) -> Option<i64> {
+        None
+    }
+
+    fn previous_transition(&self, _identifier: &str, _epoch_seconds: i64) -> Option<i64> {
+        None
+    }
+}
@@ -0,0 +1,731 @@
+//! A [`TimeZoneProvider`] reading the time zones of a `TZif` database, such as the one installed
+//! in `/usr/share/zoneinfo` by most Unix systems.
+
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    fs,
+    io::Read,
+    path::{Path, PathBuf},
+    rc::Rc,
+};
+
+use super::{DefaultTimeZoneProvider, TimeZoneProvider};
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// A [`TimeZoneProvider`] backed by a directory of [TZif][rfc] files.
+///
+/// The zones and links of the database are listed from its `tzdata.zi` file if there is one,
+/// and by looking for `TZif` files in the directory otherwise. Zones are loaded the first time
+/// they are used.
+///
+/// Time zones that aren't part of the database, including all of them if the directory doesn't
+/// exist, fall back to the ones of [`DefaultTimeZoneProvider`].
+///
+/// [rfc]: https://www.rfc-editor.org/rfc/rfc8536
+#[derive(Debug)]
+pub struct ZoneInfoTimeZoneProvider {
+    directory: PathBuf,
+    /// The zone and link names of the database, indexed by their ASCII lowercase form.
+    identifiers: HashMap<String, String>,
+    /// The zone names that the links of the database point to.
+    links: HashMap<String, String>,
+    zones: RefCell<HashMap<String, Option<Rc<Zone>>>>,
+}
+
+impl ZoneInfoTimeZoneProvider {
+    /// The directory of the system time zone database, unless overridden by `TZDIR`.
+    const SYSTEM_DIRECTORY: &'static str = "/usr/share/zoneinfo";
+
+    /// Creates a new provider for the time zone database in `directory`.
+    pub fn new<P: Into<PathBuf>>(directory: P) -> Self {
+        let directory = directory.into();
+        let mut identifiers = HashMap::new();
+        let mut links = HashMap::new();
+
+        if let Ok(index) = fs::read_to_string(directory.join("tzdata.zi")) {
+            for line in index.lines() {
+                let mut fields = line.split_ascii_whitespace();
+                match (fields.next(), fields.next(), fields.next()) {
+                    (Some("Z"), Some(name), _) => {
+                        identifiers.insert(name.to_ascii_lowercase(), name.to_owned());
+                    }
+                    (Some("L"), Some(target), Some(name)) => {
+                        identifiers.insert(name.to_ascii_lowercase(), name.to_owned());
+                        links.insert(name.to_owned(), target.to_owned());
+                    }
+                    _ => {}
+                }
+            }
+        } else {
+            Self::scan(&directory, "", &mut identifiers);
+        }
+
+        Self {
+            directory,
+            identifiers,
+            links,
+            zones: RefCell::default(),
+        }
+    }
+
+    /// Creates a new provider for the time zone database of the system, which is located in the
+    /// directory given by the `TZDIR` environment variable or in `/usr/share/zoneinfo`.
+    #[must_use]
+    pub fn system() -> Self {
+        Self::new(
+            std::env::var_os("TZDIR")
+                .map_or_else(|| PathBuf::from(Self::SYSTEM_DIRECTORY), PathBuf::from),
+        )
+    }
+
+    /// Adds the `TZif` files of `directory` to `identifiers`, naming them by their path relative
+    /// to the root of the database.
+    fn scan(directory: &Path, prefix: &str, identifiers: &mut HashMap<String, String>) {
+        let Ok(entries) = fs::read_dir(directory) else {
+            return;
+        };
+        for entry in entries.flatten() {
+            let Ok(name) = entry.file_name().into_string() else {
+                continue;
+            };
+            let identifier = if prefix.is_empty() {
+                name.clone()
+            } else {
+                format!("{prefix}/{name}")
+            };
+            let path = entry.path();
+            if path.is_dir() {
+                // These hold copies of the database with other conventions for leap seconds.
+                if name != "posix" && name != "right" {
+                    Self::scan(&path, &identifier, identifiers);
+                }
+            } else if name.starts_with(|c: char| c.is_ascii_uppercase()) && is_tzif(&path) {
+                identifiers.insert(identifier.to_ascii_lowercase(), identifier);
+            }
+        }
+    }
+
+    /// Gets the zone `identifier` of the database, loading it if needed.
+    fn zone(&self, identifier: &str) -> Option<Rc<Zone>> {
+        if !self
+            .identifiers
+            .contains_key(&identifier.to_ascii_lowercase())
+        {
+            return None;
+        }
+        // Links may not have a file of their own, so they are loaded from the zone they point to.
+        let identifier = self
+            .links
+            .get(identifier)
+            .map_or(identifier, String::as_str);
+        self.zones
+            .borrow_mut()
+            .entry(identifier.to_owned())
+            .or_insert_with(|| {
+                fs::read(self.directory.join(identifier))
+                    .ok()
+                    .and_then(|data| Zone::parse(&data))
+                    .map(Rc::new)
+            })
+            .clone()
+    }
+}
+
+impl TimeZoneProvider for ZoneInfoTimeZoneProvider {
+    fn normalize(&self, identifier: &str) -> Option<String> {
+        self.identifiers
+            .get(&identifier.to_ascii_lowercase())
+            .cloned()
+            .or_else(|| DefaultTimeZoneProvider.normalize(identifier))
+    }
+
+    fn primary_identifier(&self, identifier: &str) -> String {
+        let primary = self
+            .links
+            .get(identifier)
+            .map_or(identifier, String::as_str);
+
+        // The UTC zones of the database, like `Etc/UTC`, have `UTC` as their primary identifier.
+        DefaultTimeZoneProvider.primary_identifier(primary)
+    }
+
+    fn available_identifiers(&self) -> Vec<String> {
+        if self.identifiers.is_empty() {
+            return DefaultTimeZoneProvider.available_identifiers();
+        }
+        let mut identifiers = self
+            .identifiers
+            .values()
+            .filter(|identifier| !self.links.contains_key(*identifier))
+            .map(|identifier| self.primary_identifier(identifier))
+            .collect::<Vec<_>>();
+        identifiers.sort_unstable();
+        identifiers.dedup();
+        identifiers
+    }
+
+    fn offset(&self, identifier: &str, epoch_seconds: i64) -> i32 {
+        match self.zone(identifier) {
+            Some(zone) => zone.offset(epoch_seconds),
+            None => DefaultTimeZoneProvider.offset(identifier, epoch_seconds),
+        }
+    }
+
+    fn possible_offsets(&self, identifier: &str, local_seconds: i64) -> Vec<i32> {
+        match self.zone(identifier) {
+            Some(zone) => zone.possible_offsets(local_seconds),
+            None => DefaultTimeZoneProvider.possible_offsets(identifier, local_seconds),
+        }
+    }
+
+    fn next_transition(&self, identifier: &str, epoch_seconds: i64) -> Option<i64> {
+        self.zone(identifier)?.next_transition(epoch_seconds)
+    }
+
+    fn previous_transition(&self, identifier: &str, epoch_seconds: i64) -> Option<i64> {
+        self.zone(identifier)?.previous_transition(epoch_seconds)
+    }
+
+    fn host_time_zone(&self) -> Option<String> {
+        let name = std::env::var("TZ")
+            .ok()
+            .map(|tz| tz.trim_start_matches(':').to_owned())
+            .or_else(|| {
+                let target = fs::read_link("/etc/localtime").ok()?;
+                Some(target.to_str()?.rsplit_once("zoneinfo/")?.1.to_owned())
+            })?;
+        self.normalize(&name)
+    }
+}
+
+/// Checks if the file at `path` starts with the magic bytes of `TZif` files.
+fn is_tzif(path: &Path) -> bool {
+    let mut magic = [0; 4];
+    fs::File::open(path)
+        .and_then(|mut file| file.read_exact(&mut magic))
+        .is_ok()
+        && magic == *b"TZif"
+}
+
+/// The offsets of a time zone, as read from a `TZif` file.
+#[derive(Debug)]
+struct Zone {
+    /// The instants at which the offset of the zone changes, in ascending order.
+    transitions: Vec<i64>,
+    /// The offset before the first transition, followed by the offset after each transition.
+    offsets: Vec<i32>,
+    /// The rule giving the offsets after the last transition.
+    rule: Option<PosixRule>,
+}
+
+impl Zone {
+    /// Parses the contents of a `TZif` file, using the 64-bit data of the version 2 and later
+    /// formats if it is present.
+    fn parse(data: &[u8]) -> Option<Self> {
+        let mut reader = Reader(data);
+        let mut header = Header::read(&mut reader)?;
+        let mut time_size = 4;
+        if header.version >= b'2' {
+            reader.take(header.data_len(time_size))?;
+            header = Header::read(&mut reader)?;
+            time_size = 8;
+        }
+
+        let times = (0..header.transitions)
+            .map(|_| reader.int(time_size))
+            .collect::<Option<Vec<_>>>()?;
+        let indices = reader.take(header.transitions)?;
+        let types = (0..header.types)
+            .map(|_| {
+                let offset = i32::try_from(reader.int(4)?).ok()?;
+                reader.take(2)?;
+                Some(offset)
+            })
+            .collect::<Option<Vec<_>>>()?;
+        reader.take(
+            header.data_len(time_size) - header.transitions * (time_size + 1) - header.types * 6,
+        )?;
+
+        // The footer holds a POSIX `TZ` string between two newlines.
+        let rule = (header.version >= b'2')
+            .then(|| {
+                let footer = reader.0.strip_prefix(b"\n")?;
+                let end = footer.iter().position(|&b| b == b'\n')?;
+                PosixRule::parse(std::str::from_utf8(&footer[..end]).ok()?)
+            })
+            .flatten();
+
+        let mut transitions = Vec::with_capacity(times.len());
+        let mut offsets = vec![*types.first()?];
+        for (time, &index) in times.into_iter().zip(indices) {
+            let offset = *types.get(usize::from(index))?;
+            if Some(&offset) != offsets.last() {
+                transitions.push(time);
+                offsets.push(offset);
+            }
+        }
+
+        Some(Self {
+            transitions,
+            offsets,
+            rule,
+        })
+    }
+
+    fn offset(&self, epoch_seconds: i64) -> i32 {
+        let index = self.transitions.partition_point(|&t| t <= epoch_seconds);
+        match &self.rule {
+            Some(rule) if index == self.transitions.len() => rule.offset(epoch_seconds),
+            _ => self.offsets[index],
+        }
+    }
+
+    fn possible_offsets(&self, local_seconds: i64) -> Vec<i32> {
+        // Offsets are less than a day away from UTC, so the offsets that map some instant to
+        // `local_seconds` are the ones in effect during the days around it.
+        let end = local_seconds + SECONDS_PER_DAY;
+        let mut instant = local_seconds - SECONDS_PER_DAY;
+        let mut offsets = vec![self.offset(instant)];
+        while let Some(transition) = self.next_transition(instant).filter(|&t| t <= end) {
+            offsets.push(self.offset(transition));
+            instant = transition;
+        }
+
+        offsets.retain(|&offset| self.offset(local_seconds - i64::from(offset)) == offset);
+        offsets.sort_unstable_by(|a, b| b.cmp(a));
+        offsets.dedup();
+        offsets
+    }
+
+    fn next_transition(&self, epoch_seconds: i64) -> Option<i64> {
+        let index = self.transitions.partition_point(|&t| t <= epoch_seconds);
+        if let Some(&transition) = self.transitions.get(index) {
+            return Some(transition);
+        }
+        self.rule
+            .as_ref()?
+            .transitions_around(epoch_seconds)
+            .into_iter()
+            .find(|&t| t > epoch_seconds)
+    }
+
+    fn previous_transition(&self, epoch_seconds: i64) -> Option<i64> {
+        let index = self.transitions.partition_point(|&t| t < epoch_seconds);
+        let last = index.checked_sub(1).map(|index| self.transitions[index]);
+        if index == self.transitions.len() {
+            let ruled = self.rule.as_ref().and_then(|rule| {
+                rule.transitions_around(epoch_seconds)
+                    .into_iter()
+                    .rev()
+                    .find(|&t| t < epoch_seconds)
+            });
+            if let Some(ruled) = ruled.filter(|&t| last.map_or(true, |last| t > last)) {
+                return Some(ruled);
+            }
+        }
+        last
+    }
+}
+
+/// The header of a block of data of a `TZif` file.
+struct Header {
+    version: u8,
+    ut_indicators: usize,
+    std_indicators: usize,
+    leap_seconds: usize,
+    transitions: usize,
+    types: usize,
+    designation_bytes: usize,
+}
+
+impl Header {
+    fn read(reader: &mut Reader<'_>) -> Option<Self> {
+        if reader.take(4)? != b"TZif" {
+            return None;
+        }
+        let version = reader.take(16)?[0];
+        let mut count = || usize::try_from(reader.int(4)?).ok();
+        Some(Self {
+            version: version.max(b'1'),
+            ut_indicators: count()?,
+            std_indicators: count()?,
+            leap_seconds: count()?,
+            transitions: count()?,
+            types: count()?,
+            designation_bytes: count()?,
+        })
+    }
+
+    /// Gets the length of the data block following this header, for a given size of times.
+    const fn data_len(&self, time_size: usize) -> usize {
+        self.transitions * (time_size + 1)
+            + self.types * 6
+            + self.designation_bytes
+            + self.leap_seconds * (time_size + 4)
+            + self.std_indicators
+            + self.ut_indicators
+    }
+}
+
+/// A cursor over big-endian binary data.
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Option<&'a [u8]> {
+        if len > self.0.len() {
+            return None;
+        }
+        let (bytes, rest) = self.0.split_at(len);
+        self.0 = rest;
+        Some(bytes)
+    }
+
+    /// Reads a signed integer of `size` bytes, which must be 4 or 8.
+    fn int(&mut self, size: usize) -> Option<i64> {
+        let bytes = self.take(size)?;
+        Some(if size == 4 {
+            i64::from(i32::from_be_bytes(bytes.try_into().ok()?))
+        } else {
+            i64::from_be_bytes(bytes.try_into().ok()?)
+        })
+    }
+}
+
+/// A POSIX `TZ` string like `CET-1CEST,M3.5.0,M10.5.0/3`, describing a standard offset and an
+/// optional yearly daylight saving time.
+#[derive(Debug)]
+struct PosixRule {
+    std_offset: i32,
+    dst: Option<DstRule>,
+}
+
+/// The daylight saving time of a [`PosixRule`].
+#[derive(Debug)]
+struct DstRule {
+    offset: i32,
+    /// The start date and its local standard time, in seconds.
+    start: (RuleDate, i32),
+    /// The end date and its local daylight saving time, in seconds.
+    end: (RuleDate, i32),
+}
+
+/// A day of the year in a [`PosixRule`].
+#[derive(Debug, Clone, Copy)]
+enum RuleDate {
+    /// `Jn`: the day `n` between 1 and 365, never counting February 29.
+    Julian(u16),
+    /// `n`: the zero-based day `n` between 0 and 365, counting February 29.
+    Zero(u16),
+    /// `Mm.w.d`: the day `d` of the week `w` of the month `m`, where the week 5 is the last one.
+    MonthWeekDay(u8, u8, u8),
+}
+
+impl PosixRule {
+    fn parse(string: &str) -> Option<Self> {
+        let mut parser = RuleParser(string.as_bytes());
+        parser.name()?;
+        let std_offset = -parser.time()?;
+        if parser.0.is_empty() {
+            return Some(Self {
+                std_offset,
+                dst: None,
+            });
+        }
+
+        parser.name()?;
+        let offset = if parser.0.first() == Some(&b',') {
+            std_offset + 3600
+        } else {
+            -parser.time()?
+        };
+        parser.expect(b',')?;
+        let start = parser.date_time()?;
+        parser.expect(b',')?;
+        let end = parser.date_time()?;
+
+        parser.0.is_empty().then_some(Self {
+            std_offset,
+            dst: Some(DstRule { offset, start, end }),
+        })
+    }
+
+    fn offset(&self, epoch_seconds: i64) -> i32 {
+        let Some(dst) = &self.dst else {
+            return self.std_offset;
+        };
+        let year = year_of(epoch_seconds + i64::from(self.std_offset));
+        let (start, end) = self.transitions(dst, year);
+        let in_dst = if start < end {
+            start <= epoch_seconds && epoch_seconds < end
+        } else {
+            !(end <= epoch_seconds && epoch_seconds < start)
+        };
+        if in_dst {
+            dst.offset
+        } else {
+            self.std_offset
+        }
+    }
+
+    /// Gets the instants at which daylight saving time starts and ends in `year`.
+    fn transitions(&self, dst: &DstRule, year: i64) -> (i64, i64) {
+        let instant = |(date, time): (RuleDate, i32), offset: i32| {
+            date.epoch_days(year) * SECONDS_PER_DAY + i64::from(time) - i64::from(offset)
+        };
+        (
+            instant(dst.start, self.std_offset),
+            instant(dst.end, dst.offset),
+        )
+    }
+
+    /// Gets the sorted transitions of the year of `epoch_seconds` and the years around it.
+    fn transitions_around(&self, epoch_seconds: i64) -> Vec<i64> {
+        let Some(dst) = &self.dst else {
+            return Vec::new();
+        };
+        let year = year_of(epoch_seconds);
+        let mut transitions = (year - 1..=year + 1)
+            .flat_map(|year| {
+                let (start, end) = self.transitions(dst, year);
+                [start, end]
+            })
+            .collect::<Vec<_>>();
+        transitions.sort_unstable();
+        transitions
+    }
+}
+
+impl RuleDate {
+    /// Gets the number of days from the Unix epoch to this date in `year`.
+    fn epoch_days(self, year: i64) -> i64 {
+        match self {
+            Self::Julian(day) => {
+                let leap_day = is_leap_year(year) && day >= 60;
+                epoch_days(year, 1, 1) + i64::from(day) - 1 + i64::from(leap_day)
+            }
+            Self::Zero(day) => epoch_days(year, 1, 1) + i64::from(day),
+            Self::MonthWeekDay(month, week, weekday) => {
+                let first = epoch_days(year, month, 1);
+                let next_month = if month == 12 {
+                    epoch_days(year + 1, 1, 1)
+                } else {
+                    epoch_days(year, month + 1, 1)
+                };
+                // The Unix epoch was a Thursday.
+                let first_weekday = (first + 4).rem_euclid(7);
+                let mut day = first
+                    + (i64::from(weekday) - first_weekday).rem_euclid(7)
+                    + (i64::from(week) - 1) * 7;
+                while day >= next_month {
+                    day -= 7;
+                }
+                day
+            }
+        }
+    }
+}
+
+/// A parser of the fields of a [`PosixRule`].
+struct RuleParser<'a>(&'a [u8]);
+
+impl RuleParser<'_> {
+    fn expect(&mut self, byte: u8) -> Option<()> {
+        self.0 = self.0.strip_prefix(&[byte])?;
+        Some(())
+    }
+
+    /// Skips a zone abbreviation, which is either alphabetic or quoted with angle brackets.
+    fn name(&mut self) -> Option<()> {
+        let len = if self.0.first() == Some(&b'<') {
+            self.0.iter().position(|&b| b == b'>')? + 1
+        } else {
+            self.0
+                .iter()
+                .take_while(|b| b.is_ascii_alphabetic())
+                .count()
+        };
+        (len >= 3).then(|| self.0 = &self.0[len..])
+    }
+
+    fn number(&mut self) -> Option<i32> {
+        let len = self.0.iter().take_while(|b| b.is_ascii_digit()).count();
+        let number = std::str::from_utf8(&self.0[..len]).ok()?.parse().ok()?;
+        self.0 = &self.0[len..];
+        Some(number)
+    }
+
+    /// Parses a signed time of the form `[+-]hh[:mm[:ss]]`, in seconds.
+    fn time(&mut self) -> Option<i32> {
+        let sign = match self.0.first() {
+            Some(b'-') => -1,
+            Some(b'+') => 1,
+            _ => 0,
+        };
+        if sign != 0 {
+            self.0 = &self.0[1..];
+        }
+        let mut seconds = self.number()? * 3600;
+        for unit in [60, 1] {
+            if self.expect(b':').is_none() {
+                break;
+            }
+            seconds += self.number()? * unit;
+        }
+        Some(if sign < 0 { -seconds } else { seconds })
+    }
+
+    /// Parses a date followed by an optional time, which defaults to 02:00:00.
+    fn date_time(&mut self) -> Option<(RuleDate, i32)> {
+        let date = match self.0.first()? {
+            b'J' => {
+                self.0 = &self.0[1..];
+                RuleDate::Julian(
+                    u16::try_from(self.number()?)
+                        .ok()
+                        .filter(|d| (1..=365).contains(d))?,
+                )
+            }
+            b'M' => {
+                self.0 = &self.0[1..];
+                let month = self.number()?;
+                self.expect(b'.')?;
+                let week = self.number()?;
+                self.expect(b'.')?;
+                let weekday = self.number()?;
+                if !(1..=12).contains(&month) || !(1..=5).contains(&week) || weekday > 6 {
+                    return None;
+                }
+                RuleDate::MonthWeekDay(month as u8, week as u8, weekday as u8)
+            }
+            _ => RuleDate::Zero(u16::try_from(self.number()?).ok().filter(|&d| d <= 365)?),
+        };
+        let time = if self.expect(b'/').is_some() {
+            self.time()?
+        } else {
+            7200
+        };
+        Some((date, time))
+    }
+}
+
+const fn is_leap_year(year: i64) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+/// Gets the number of days from the Unix epoch to a date of the proleptic Gregorian calendar.
+fn epoch_days(year: i64, month: u8, day: u8) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month = i64::from((month + 9) % 12);
+    let day_of_year = (153 * month + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Gets the year of the proleptic Gregorian calendar of an instant.
+fn year_of(epoch_seconds: i64) -> i64 {
+    let days = epoch_seconds.div_euclid(SECONDS_PER_DAY) + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    era * 400 + year_of_era + i64::from(month_index >= 10)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{epoch_days, year_of, Zone, ZoneInfoTimeZoneProvider};
+    use crate::context::TimeZoneProvider;
+
+    /// Builds a version 2 `TZif` file with a single transition at the epoch, from UTC to `+01:00`,
+    /// followed by the Central European rule.
+    fn tzif() -> Vec<u8> {
+        let mut data = Vec::new();
+        let header = |data: &mut Vec<u8>, counts: [u32; 6]| {
+            data.extend_from_slice(b"TZif2");
+            data.extend_from_slice(&[0; 15]);
+            for count in counts {
+                data.extend_from_slice(&count.to_be_bytes());
+            }
+        };
+        header(&mut data, [0; 6]);
+        header(&mut data, [0, 0, 0, 1, 2, 8]);
+        data.extend_from_slice(&0i64.to_be_bytes());
+        data.push(1);
+        for (offset, designation) in [(0i32, 0u8), (3600, 4)] {
+            data.extend_from_slice(&offset.to_be_bytes());
+            data.extend_from_slice(&[0, designation]);
+        }
+        data.extend_from_slice(b"UTC\0CET\0");
+        data.extend_from_slice(b"\nCET-1CEST,M3.5.0,M10.5.0/3\n");
+        data
+    }
+
+    #[test]
+    fn civil_dates() {
+        assert_eq!(epoch_days(1970, 1, 1), 0);
+        assert_eq!(epoch_days(2024, 3, 31), 19_813);
+        assert_eq!(epoch_days(1969, 12, 31), -1);
+        assert_eq!(year_of(0), 1970);
+        assert_eq!(year_of(-1), 1969);
+        assert_eq!(year_of(1_704_067_200), 2024);
+    }
+
+    #[test]
+    fn zone_offsets_and_transitions() {
+        let zone = Zone::parse(&tzif()).expect("the TZif data should be valid");
+
+        // 2024-03-31T01:00:00Z and 2024-10-27T01:00:00Z.
+        let dst_start = 1_711_846_800;
+        let dst_end = 1_729_990_800;
+
+        assert_eq!(zone.offset(-1), 0);
+        assert_eq!(zone.offset(0), 3600);
+        assert_eq!(zone.offset(dst_start - 1), 3600);
+        assert_eq!(zone.offset(dst_start), 7200);
+        assert_eq!(zone.offset(dst_end - 1), 7200);
+        assert_eq!(zone.offset(dst_end), 3600);
+
+        assert_eq!(zone.next_transition(-10), Some(0));
+        assert_eq!(zone.next_transition(1_704_067_200), Some(dst_start));
+        assert_eq!(zone.next_transition(dst_start), Some(dst_end));
+        assert_eq!(zone.previous_transition(dst_end), Some(dst_start));
+        assert_eq!(zone.previous_transition(1), Some(0));
+        assert_eq!(zone.previous_transition(0), None);
+
+        // 2024-03-31T02:30 is skipped, and 2024-10-27T02:30 is repeated.
+        assert_eq!(zone.possible_offsets(dst_start + 5400), Vec::<i32>::new());
+        assert_eq!(zone.possible_offsets(dst_end + 5400), vec![7200, 3600]);
+        assert_eq!(zone.possible_offsets(1_704_067_200), vec![3600]);
+    }
+
+    #[test]
+    fn database_directory() {
+        let directory = std::env::temp_dir().join(format!("boa-zoneinfo-{}", std::process::id()));
+        std::fs::create_dir_all(directory.join("Europe")).unwrap();
+        std::fs::write(directory.join("Europe/Test"), tzif()).unwrap();
+        std::fs::write(
+            directory.join("tzdata.zi"),
+            "Z Europe/Test 0 - UTC 1970\nL Europe/Test Europe/Alias\n",
+        )
+        .unwrap();
+
+        let provider = ZoneInfoTimeZoneProvider::new(&directory);
+        assert_eq!(
+            provider.normalize("europe/alias").as_deref(),
+            Some("Europe/Alias")
+        );
+        assert_eq!(provider.primary_identifier("Europe/Alias"), "Europe/Test");
+        assert_eq!(provider.offset("Europe/Alias", 0), 3600);
+        assert_eq!(provider.normalize("etc/utc").as_deref(), Some("Etc/UTC"));
+        assert_eq!(provider.normalize("Europe/Paris"), None);
+        assert_eq!(provider.available_identifiers(), ["Europe/Test"]);
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+}
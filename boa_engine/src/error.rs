@@ -1,7 +1,10 @@
 //! Error-related types and conversions.
 
 use crate::{
-    builtins::{error::ErrorKind, Array},
+    builtins::{
+        error::{Error, ErrorKind, StackFrame},
+        Array,
+    },
     object::JsObject,
     object::ObjectData,
     property::PropertyDescriptor,
@@ -176,6 +179,18 @@ impl JsError {
         }
     }
 
+    /// Records the frames of the currently executing call stack as the stack of this error.
+    ///
+    /// This does nothing for opaque errors, and for native errors that already recorded a stack.
+    pub(crate) fn with_stack_frames(self, context: &Context<'_>) -> Self {
+        match &self.inner {
+            Repr::Native(e) if e.stack_frames.is_none() => {
+                e.clone().with_stack_frames(context).into()
+            }
+            _ => self,
+        }
+    }
+
     /// Unwraps the inner error if this contains a native error.
     /// Otherwise, inspects the opaque error and tries to extract the
     /// necessary information to construct a native error similar to the provided
@@ -293,6 +308,20 @@ impl JsError {
 
                         JsNativeErrorKind::Aggregate(error_list)
                     }
+                    ErrorKind::Suppressed => {
+                        let mut get = |property| {
+                            obj.get(property, context)
+                                .map(|value| Box::new(Self::from_opaque(value)))
+                                .map_err(|e| TryNativeError::InaccessibleProperty {
+                                    property,
+                                    source: e,
+                                })
+                        };
+                        JsNativeErrorKind::Suppressed {
+                            error: get("error")?,
+                            suppressed: get("suppressed")?,
+                        }
+                    }
                 };
 
                 let realm = try_get_property("constructor", context)?
@@ -307,6 +336,7 @@ impl JsError {
                     message,
                     cause: cause.map(|v| Box::new(Self::from_opaque(v))),
                     realm: Some(realm),
+                    stack_frames: None,
                 })
             }
         }
@@ -371,6 +401,15 @@ impl JsError {
         }
         self
     }
+
+    /// Checks if this is a [`JsNativeErrorKind::RuntimeLimit`] error.
+    ///
+    /// These errors cannot be caught by scripts, so they must be propagated to the caller
+    /// instead of being converted into a rejected promise or a thrown value.
+    pub(crate) fn is_runtime_limit(&self) -> bool {
+        self.as_native()
+            .map_or(false, JsNativeError::is_runtime_limit)
+    }
 }
 
 impl From<boa_parser::Error> for JsError {
@@ -426,6 +465,7 @@ pub struct JsNativeError {
     #[source]
     cause: Option<Box<JsError>>,
     realm: Option<Realm>,
+    stack_frames: Option<Box<[StackFrame]>>,
 }
 
 impl std::fmt::Debug for JsNativeError {
@@ -446,6 +486,7 @@ impl JsNativeError {
             message,
             cause,
             realm: None,
+            stack_frames: None,
         }
     }
 
@@ -479,6 +520,39 @@ impl JsNativeError {
         matches!(self.kind, JsNativeErrorKind::Aggregate(_))
     }
 
+    /// Creates a new `JsNativeError` of kind `SuppressedError` from the error that was thrown
+    /// and the error it suppressed, with empty `message` and undefined `cause`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use boa_engine::{JsNativeError, JsNativeErrorKind};
+    /// let error = JsNativeError::suppressed(
+    ///     JsNativeError::typ().into(),
+    ///     JsNativeError::range().into(),
+    /// );
+    ///
+    /// assert!(matches!(error.kind, JsNativeErrorKind::Suppressed { .. }));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn suppressed(error: JsError, suppressed: JsError) -> Self {
+        Self::new(
+            JsNativeErrorKind::Suppressed {
+                error: Box::new(error),
+                suppressed: Box::new(suppressed),
+            },
+            Box::default(),
+            None,
+        )
+    }
+
+    /// Check if it's a [`JsNativeErrorKind::Suppressed`].
+    #[inline]
+    pub const fn is_suppressed(&self) -> bool {
+        matches!(self.kind, JsNativeErrorKind::Suppressed { .. })
+    }
+
     /// Creates a new `JsNativeError` of kind `Error`, with empty `message` and undefined `cause`.
     ///
     /// # Examples
@@ -774,6 +848,7 @@ impl JsNativeError {
             message,
             cause,
             realm,
+            stack_frames,
         } = self;
         let constructors = realm.as_ref().map_or_else(
             || context.intrinsics().constructors(),
@@ -784,6 +859,10 @@ impl JsNativeError {
                 constructors.aggregate_error().prototype(),
                 ErrorKind::Aggregate,
             ),
+            JsNativeErrorKind::Suppressed { .. } => (
+                constructors.suppressed_error().prototype(),
+                ErrorKind::Suppressed,
+            ),
             JsNativeErrorKind::Error => (constructors.error().prototype(), ErrorKind::Error),
             JsNativeErrorKind::Eval => (constructors.eval_error().prototype(), ErrorKind::Eval),
             JsNativeErrorKind::Range => (constructors.range_error().prototype(), ErrorKind::Range),
@@ -807,10 +886,13 @@ impl JsNativeError {
             }
         };
 
+        let stack = stack_frames
+            .clone()
+            .unwrap_or_else(|| Error::capture_stack_frames(context));
         let o = JsObject::from_proto_and_data_with_shared_shape(
             context.root_shape(),
             prototype,
-            ObjectData::error(tag),
+            ObjectData::error(tag, stack),
         );
 
         o.create_non_enumerable_data_property_or_throw(utf16!("message"), &**message, context);
@@ -840,6 +922,18 @@ impl JsNativeError {
             )
             .expect("The spec guarantees this succeeds for a newly created object ");
         }
+
+        if let JsNativeErrorKind::Suppressed { error, suppressed } = kind {
+            let error = error.to_opaque(context);
+            o.create_non_enumerable_data_property_or_throw(utf16!("error"), error, context);
+            let suppressed = suppressed.to_opaque(context);
+            o.create_non_enumerable_data_property_or_throw(
+                utf16!("suppressed"),
+                suppressed,
+                context,
+            );
+        }
+
         o
     }
 
@@ -848,6 +942,15 @@ impl JsNativeError {
         self.realm = Some(realm);
         self
     }
+
+    /// Records the frames of the currently executing call stack as the stack of this error,
+    /// unless a stack was already recorded.
+    pub(crate) fn with_stack_frames(mut self, context: &Context<'_>) -> Self {
+        if self.stack_frames.is_none() {
+            self.stack_frames = Some(Error::capture_stack_frames(context));
+        }
+        self
+    }
 }
 
 impl From<boa_parser::Error> for JsNativeError {
@@ -944,6 +1047,20 @@ pub enum JsNativeErrorKind {
     /// [d_uri]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/decodeURI
     Uri,
 
+    /// An error that was thrown while another error was being handled, for example while
+    /// disposing the resources of a `using` declaration.
+    ///
+    /// More information:
+    /// - [Explicit Resource Management proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-suppressederror-objects
+    Suppressed {
+        /// The error that was thrown.
+        error: Box<JsError>,
+        /// The error that was being handled when `error` was thrown.
+        suppressed: Box<JsError>,
+    },
+
     /// Error thrown when no instructions remain. Only used in a fuzzing context; not a valid JS
     /// error variant.
     #[cfg(feature = "fuzz")]
@@ -965,6 +1082,7 @@ impl PartialEq<ErrorKind> for JsNativeErrorKind {
                 | (Self::Syntax, ErrorKind::Syntax)
                 | (Self::Type, ErrorKind::Type)
                 | (Self::Uri, ErrorKind::Uri)
+                | (Self::Suppressed { .. }, ErrorKind::Suppressed)
         )
     }
 }
@@ -980,6 +1098,7 @@ impl std::fmt::Display for JsNativeErrorKind {
             Self::Syntax => "SyntaxError",
             Self::Type => "TypeError",
             Self::Uri => "UriError",
+            Self::Suppressed { .. } => "SuppressedError",
             Self::RuntimeLimit => "RuntimeLimit",
             #[cfg(feature = "fuzz")]
             Self::NoInstructionsRemain => "NoInstructionsRemain",
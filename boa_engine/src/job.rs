@@ -17,7 +17,15 @@
 //! [Job]: https://tc39.es/ecma262/#sec-jobs
 //! [JobCallback]: https://tc39.es/ecma262/#sec-jobcallback-records
 
-use std::{any::Any, cell::RefCell, collections::VecDeque, fmt::Debug, future::Future, pin::Pin};
+use std::{
+    any::Any,
+    cell::RefCell,
+    collections::VecDeque,
+    fmt::Debug,
+    future::Future,
+    pin::Pin,
+    time::{Duration, Instant},
+};
 
 use crate::{
     object::{JsFunction, NativeObject},
@@ -225,6 +233,16 @@ pub trait JobQueue {
     /// to do this will leave the inner `Promise` in the `pending` state, which won't call any `then`
     /// or `catch` handlers, even if `future` was already completed.
     fn enqueue_future_job(&self, future: FutureJob, context: &mut Context<'_>);
+
+    /// [`HostEnqueueTimeoutJob ( timeoutJob, realm, milliseconds )`][spec].
+    ///
+    /// Enqueues a [`NativeJob`] that must be run once at least `timeout` has elapsed. This is
+    /// used to resolve the promises of `Atomics.waitAsync` calls that time out.
+    ///
+    /// The default implementation never runs the job, which leaves those promises pending.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-hostenqueuetimeoutjob
+    fn enqueue_timeout_job(&self, _job: NativeJob, _timeout: Duration, _: &mut Context<'_>) {}
 }
 
 /// A job queue that does nothing.
@@ -256,9 +274,17 @@ impl JobQueue for IdleJobQueue {
 /// This is the default job queue for the [`Context`], but it is mostly pretty limited for
 /// custom event queues.
 ///
+/// Once all the promise jobs have run, [`JobQueue::run_jobs`] waits until the next timeout job
+/// is due and runs it, until no job is left. `Atomics.waitAsync` calls notified by another thread
+/// while waiting resolve their promises right away.
+///
 /// To disable running promise jobs on the engine, see [`IdleJobQueue`].
 #[derive(Default)]
-pub struct SimpleJobQueue(RefCell<VecDeque<NativeJob>>);
+pub struct SimpleJobQueue {
+    promise_jobs: RefCell<VecDeque<NativeJob>>,
+    /// The timeout jobs, with the instant after which they can run.
+    timeout_jobs: RefCell<Vec<(Instant, NativeJob)>>,
+}
 
 impl Debug for SimpleJobQueue {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -272,6 +298,21 @@ impl SimpleJobQueue {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Gets the index and the deadline of the timeout job with the earliest deadline.
+    fn next_timeout(&self) -> Option<(usize, Instant)> {
+        self.timeout_jobs
+            .borrow()
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, (deadline, _))| *deadline)
+            .map(|(index, (deadline, _))| (index, *deadline))
+    }
+
+    fn clear(&self) {
+        self.promise_jobs.borrow_mut().clear();
+        self.timeout_jobs.borrow_mut().clear();
+    }
 }
 
 impl JobQueue for SimpleJobQueue {
@@ -280,19 +321,40 @@ impl JobQueue for SimpleJobQueue {
         // TODO
         // Let scriptOrModule be ...
         // TODO
-        self.0.borrow_mut().push_back(job);
+        self.promise_jobs.borrow_mut().push_back(job);
     }
 
     fn run_jobs(&self, context: &mut Context<'_>) {
-        // Yeah, I have no idea why Rust extends the lifetime of a `RefCell` that should be immediately
-        // dropped after calling `pop_front`.
-        let mut next_job = self.0.borrow_mut().pop_front();
-        while let Some(job) = next_job {
-            if job.call(context).is_err() {
-                self.0.borrow_mut().clear();
+        loop {
+            // Yeah, I have no idea why Rust extends the lifetime of a `RefCell` that should be immediately
+            // dropped after calling `pop_front`.
+            let mut next_job = self.promise_jobs.borrow_mut().pop_front();
+            while let Some(job) = next_job {
+                if job.call(context).is_err() {
+                    self.clear();
+                    return;
+                }
+                context.enqueue_cleanup_jobs();
+                context.enqueue_notified_waiter_jobs();
+                next_job = self.promise_jobs.borrow_mut().pop_front();
+            }
+
+            // No promise job can be enqueued until the next timeout job runs, or until an
+            // `Atomics.waitAsync` call is notified by another thread.
+            let Some((index, deadline)) = self.next_timeout() else {
                 return;
             };
-            next_job = self.0.borrow_mut().pop_front();
+            if context.wait_for_notified_waiters(deadline) {
+                context.enqueue_notified_waiter_jobs();
+                continue;
+            }
+            let (_, job) = self.timeout_jobs.borrow_mut().remove(index);
+            if job.call(context).is_err() {
+                self.clear();
+                return;
+            }
+            context.enqueue_cleanup_jobs();
+            context.enqueue_notified_waiter_jobs();
         }
     }
 
@@ -300,4 +362,11 @@ impl JobQueue for SimpleJobQueue {
         let job = pollster::block_on(future);
         self.enqueue_promise_job(job, context);
     }
+
+    fn enqueue_timeout_job(&self, job: NativeJob, timeout: Duration, _: &mut Context<'_>) {
+        // Timeouts too large to be represented never elapse.
+        if let Some(deadline) = Instant::now().checked_add(timeout) {
+            self.timeout_jobs.borrow_mut().push((deadline, job));
+        }
+    }
 }
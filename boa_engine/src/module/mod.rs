@@ -22,7 +22,11 @@
 //! [module]: https://tc39.es/ecma262/#sec-abstract-module-records
 
 mod source;
+mod synthetic;
+#[cfg(test)]
+mod tests;
 use source::SourceTextModule;
+pub use synthetic::{SyntheticModule, SyntheticModuleInitializer};
 
 use std::cell::{Cell, RefCell};
 use std::hash::Hash;
@@ -44,7 +48,10 @@ use crate::object::FunctionObjectBuilder;
 use crate::script::Script;
 use crate::vm::ActiveRunnable;
 use crate::{
-    builtins::promise::{PromiseCapability, PromiseState},
+    builtins::{
+        promise::{PromiseCapability, PromiseState},
+        Json,
+    },
     environments::DeclarativeEnvironment,
     object::{JsObject, JsPromise, ObjectData},
     realm::Realm,
@@ -72,12 +79,63 @@ impl From<ActiveRunnable> for Referrer {
     }
 }
 
+/// A request to load a module, made from an import declaration, an export declaration or a
+/// dynamic `import()` call.
+///
+/// Contains the specifier of the requested module alongside its [import attributes][attr].
+///
+/// [attr]: https://tc39.es/proposal-import-attributes/#modulerequest-record
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ModuleRequest {
+    specifier: JsString,
+    attributes: Box<[(JsString, JsString)]>,
+}
+
+impl ModuleRequest {
+    /// Creates a new `ModuleRequest` from its specifier and its list of attributes.
+    ///
+    /// The attributes are sorted by key, making two requests with the same attributes in
+    /// different order equal.
+    #[must_use]
+    pub fn new(specifier: JsString, mut attributes: Vec<(JsString, JsString)>) -> Self {
+        attributes.sort_by(|(a, _), (b, _)| a.cmp(b));
+        Self {
+            specifier,
+            attributes: attributes.into_boxed_slice(),
+        }
+    }
+
+    /// Gets the specifier of the requested module.
+    #[inline]
+    #[must_use]
+    pub const fn specifier(&self) -> &JsString {
+        &self.specifier
+    }
+
+    /// Gets the list of `(key, value)` attributes of this request, sorted by key.
+    #[inline]
+    #[must_use]
+    pub const fn attributes(&self) -> &[(JsString, JsString)] {
+        &self.attributes
+    }
+
+    /// Gets the value of the attribute `key`, if it exists.
+    #[inline]
+    #[must_use]
+    pub fn attribute(&self, key: &str) -> Option<&JsString> {
+        self.attributes
+            .iter()
+            .find(|(k, _)| *k == *key)
+            .map(|(_, v)| v)
+    }
+}
+
 /// Module loading related host hooks.
 ///
 /// This trait allows to customize the behaviour of the engine on module load requests and
 /// `import.meta` requests.
 pub trait ModuleLoader {
-    /// Host hook [`HostLoadImportedModule ( referrer, specifier, hostDefined, payload )`][spec].
+    /// Host hook [`HostLoadImportedModule ( referrer, moduleRequest, hostDefined, payload )`][spec].
     ///
     /// This hook allows to customize the module loading functionality of the engine. Technically,
     /// this should call the [`FinishLoadingImportedModule`][finish] operation, but this simpler API just provides
@@ -85,25 +143,27 @@ pub trait ModuleLoader {
     ///
     /// # Requirements
     ///
-    /// - The host environment must perform `FinishLoadingImportedModule(referrer, specifier, payload, result)`,
+    /// - The host environment must perform `FinishLoadingImportedModule(referrer, moduleRequest, payload, result)`,
     /// where result is either a normal completion containing the loaded Module Record or a throw
     /// completion, either synchronously or asynchronously. This is equivalent to calling the `finish_load`
     /// callback.
-    /// - If this operation is called multiple times with the same `(referrer, specifier)` pair and
-    /// it performs FinishLoadingImportedModule(referrer, specifier, payload, result) where result
+    /// - If this operation is called multiple times with the same `(referrer, moduleRequest)` pair and
+    /// it performs FinishLoadingImportedModule(referrer, moduleRequest, payload, result) where result
     /// is a normal completion, then it must perform
-    /// `FinishLoadingImportedModule(referrer, specifier, payload, result)` with the same result each
+    /// `FinishLoadingImportedModule(referrer, moduleRequest, payload, result)` with the same result each
     /// time.
+    /// - If `moduleRequest` has a `type` attribute, the loaded module must be of the requested type,
+    /// or the load must fail.
     /// - The operation must treat payload as an opaque value to be passed through to
     /// `FinishLoadingImportedModule`. (can be ignored)
     ///
-    /// [spec]: https://tc39.es/ecma262/#sec-HostLoadImportedModule
+    /// [spec]: https://tc39.es/proposal-import-attributes/#sec-HostLoadImportedModule
     /// [finish]: https://tc39.es/ecma262/#sec-FinishLoadingImportedModule
     #[allow(clippy::type_complexity)]
     fn load_imported_module(
         &self,
         referrer: Referrer,
-        specifier: JsString,
+        request: ModuleRequest,
         finish_load: Box<dyn FnOnce(JsResult<Module>, &mut Context<'_>)>,
         context: &mut Context<'_>,
     );
@@ -127,15 +187,39 @@ pub trait ModuleLoader {
         _context: &mut Context<'_>,
     ) {
     }
+
+    /// Host hook [`HostGetSupportedImportAttributes ( )`][spec].
+    ///
+    /// Returns the list of import attribute keys supported by this host. Module requests
+    /// containing any other attribute key are rejected before calling
+    /// [`ModuleLoader::load_imported_module`].
+    ///
+    /// The default implementation only supports the `type` attribute.
+    ///
+    /// [spec]: https://tc39.es/proposal-import-attributes/#sec-hostgetsupportedimportattributes
+    fn supported_import_attributes(&self) -> &[&'static str] {
+        &["type"]
+    }
 }
 
 /// A simple module loader that loads modules relative to a root path.
+///
+/// Files imported with a `type: "json"` attribute are loaded as JSON modules. All other
+/// files are parsed as ECMAScript modules.
+///
+/// Modules are cached per realm, so a `ShadowRealm` importing a file gets its own instance of
+/// that module instead of sharing the one loaded by the main realm. They are also cached per
+/// `type` attribute, so importing the same file with different types doesn't reuse the module
+/// of the other type.
 #[derive(Debug)]
 pub struct SimpleModuleLoader {
     root: PathBuf,
-    module_map: GcRefCell<FxHashMap<PathBuf, Module>>,
+    module_map: GcRefCell<FxHashMap<ModuleMapKey, Vec<Module>>>,
 }
 
+/// The path and `type` attribute a module was imported with.
+type ModuleMapKey = (PathBuf, Option<JsString>);
+
 impl SimpleModuleLoader {
     /// Creates a new `SimpleModuleLoader` from a root module path.
     pub fn new<P: AsRef<Path>>(root: P) -> JsResult<Self> {
@@ -151,29 +235,75 @@ impl SimpleModuleLoader {
         })
     }
 
-    /// Inserts a new module onto the module map.
+    /// Inserts a new module onto the module map, as the module imported from `path` without a
+    /// `type` attribute.
+    ///
+    /// This replaces any module previously inserted for the same path and realm.
     #[inline]
     pub fn insert(&self, path: PathBuf, module: Module) {
-        self.module_map.borrow_mut().insert(path, module);
+        self.insert_with_type(path, None, module);
     }
 
-    /// Gets a module from its original path.
+    /// Inserts a new module onto the module map, as the module imported from `path` with the
+    /// `type` attribute `ty`.
+    fn insert_with_type(&self, path: PathBuf, ty: Option<JsString>, module: Module) {
+        let mut map = self.module_map.borrow_mut();
+        let modules = map.entry((path, ty)).or_default();
+        modules.retain(|m| m.realm() != module.realm());
+        modules.push(module);
+    }
+
+    /// Gets the module imported from its original path without a `type` attribute.
+    ///
+    /// If the path was loaded by several realms, this returns the first module inserted.
     #[inline]
     pub fn get(&self, path: &Path) -> Option<Module> {
-        self.module_map.borrow().get(path).cloned()
+        self.module_map
+            .borrow()
+            .get(&(path.to_path_buf(), None))
+            .and_then(|modules| modules.first().cloned())
+    }
+
+    /// Gets the module imported from `path` with the `type` attribute `ty` within `realm`.
+    fn get_in_realm(&self, path: PathBuf, ty: Option<JsString>, realm: &Realm) -> Option<Module> {
+        self.module_map
+            .borrow()
+            .get(&(path, ty))?
+            .iter()
+            .find(|m| m.realm() == realm)
+            .cloned()
     }
 }
 
 impl ModuleLoader for SimpleModuleLoader {
     fn load_imported_module(
         &self,
-        _referrer: Referrer,
-        specifier: JsString,
+        referrer: Referrer,
+        request: ModuleRequest,
         finish_load: Box<dyn FnOnce(JsResult<Module>, &mut Context<'_>)>,
         context: &mut Context<'_>,
     ) {
+        let realm = match &referrer {
+            Referrer::Module(module) => module.realm().clone(),
+            Referrer::Realm(realm) => realm.clone(),
+            Referrer::Script(script) => script.realm().clone(),
+        };
         let result = (|| {
-            let path = specifier
+            let ty = request.attribute("type").cloned();
+            let is_json = match &ty {
+                None => false,
+                Some(ty) if *ty == *"json" => true,
+                Some(ty) => {
+                    return Err(JsNativeError::typ()
+                        .with_message(format!(
+                            "unsupported module type `{}`",
+                            ty.to_std_string_escaped()
+                        ))
+                        .into())
+                }
+            };
+            let path = request
+                .specifier()
                 .to_std_string()
                 .map_err(|err| JsNativeError::typ().with_message(err.to_string()))?;
             let short_path = Path::new(&path);
@@ -186,7 +316,17 @@ impl ModuleLoader for SimpleModuleLoader {
                     ))
                     .with_cause(JsError::from_opaque(js_string!(err.to_string()).into()))
             })?;
-            if let Some(module) = self.get(&path) {
+            if let Some(module) = self.get_in_realm(path.clone(), ty.clone(), &realm) {
+                return Ok(module);
+            }
+            if is_json {
+                let source = std::fs::read_to_string(&path).map_err(|err| {
+                    JsNativeError::typ()
+                        .with_message(format!("could not open file `{}`", short_path.display()))
+                        .with_cause(JsError::from_opaque(js_string!(err.to_string()).into()))
+                })?;
+                let module = Module::parse_json(js_string!(source), context)?;
+                self.insert_with_type(path, ty, module.clone());
                 return Ok(module);
             }
             let source = Source::from_filepath(&path).map_err(|err| {
@@ -194,12 +334,12 @@ impl ModuleLoader for SimpleModuleLoader {
                     .with_message(format!("could not open file `{}`", short_path.display()))
                     .with_cause(JsError::from_opaque(js_string!(err.to_string()).into()))
             })?;
-            let module = Module::parse(source, None, context).map_err(|err| {
+            let module = Module::parse(source, Some(realm.clone()), context).map_err(|err| {
                 JsNativeError::syntax()
                     .with_message(format!("could not parse module `{}`", short_path.display()))
                     .with_cause(err)
             })?;
-            self.insert(path, module.clone());
+            self.insert_with_type(path, ty, module.clone());
             Ok(module)
         })();
 
@@ -242,8 +382,7 @@ pub(crate) enum ModuleKind {
     /// A [**Source Text Module Record**](https://tc39.es/ecma262/#sec-source-text-module-records)
     SourceText(SourceTextModule),
     /// A [**Synthetic Module Record**](https://tc39.es/proposal-json-modules/#sec-synthetic-module-records)
-    #[allow(unused)]
-    Synthetic,
+    Synthetic(SyntheticModule),
 }
 
 /// Return value of the [`Module::resolve_export`] operation.
@@ -306,11 +445,30 @@ impl Module {
         context: &mut Context<'_>,
     ) -> JsResult<Self> {
         let _timer = Profiler::global().start_event("Module parsing", "Main");
+        let path = src.path().map(Rc::from);
         let mut parser = Parser::new(src);
         parser.set_identifier(context.next_parser_identifier());
         let module = parser.parse_module(context.interner_mut())?;
 
-        let src = SourceTextModule::new(module);
+        let supported = context
+            .module_loader()
+            .supported_import_attributes()
+            .to_vec();
+        for attributes in module.items().requests().values() {
+            for attribute in attributes.iter() {
+                let key = context
+                    .interner()
+                    .resolve_expect(attribute.key())
+                    .to_string();
+                if !supported.contains(&key.as_str()) {
+                    return Err(JsNativeError::syntax()
+                        .with_message(format!("unsupported import attribute `{key}`"))
+                        .into());
+                }
+            }
+        }
+
+        let src = SourceTextModule::new(module, path);
 
         let module = Self {
             inner: Gc::new(Inner {
@@ -327,6 +485,97 @@ impl Module {
         Ok(module)
     }
 
+    /// Abstract operation [`CreateSyntheticModule ( exportNames, evaluationSteps, realm )`][spec].
+    ///
+    /// Creates a new synthetic module exporting `export_names`, whose values are set by
+    /// `evaluation_steps` when the module is evaluated.
+    ///
+    /// # Examples
+    /// ```
+    /// # use boa_engine::{js_string, Context, JsValue, Module};
+    /// # use boa_engine::module::SyntheticModuleInitializer;
+    /// let context = &mut Context::default();
+    ///
+    /// let module = Module::synthetic(
+    ///     &[js_string!("answer")],
+    ///     SyntheticModuleInitializer::from_copy_closure(|module, context| {
+    ///         module.set_export(&js_string!("answer"), 42.into(), context)
+    ///     }),
+    ///     None,
+    ///     context,
+    /// );
+    ///
+    /// module.load_link_evaluate(context).unwrap();
+    /// context.run_jobs();
+    ///
+    /// let namespace = module.namespace(context);
+    /// assert_eq!(
+    ///     namespace.get(js_string!("answer"), context).unwrap(),
+    ///     JsValue::from(42)
+    /// );
+    /// ```
+    ///
+    /// [spec]: https://tc39.es/proposal-json-modules/#sec-create-synthetic-module
+    pub fn synthetic(
+        export_names: &[JsString],
+        evaluation_steps: SyntheticModuleInitializer,
+        realm: Option<Realm>,
+        context: &mut Context<'_>,
+    ) -> Self {
+        let mut names = Vec::with_capacity(export_names.len());
+        for name in export_names {
+            let name = context.interner_mut().get_or_intern(&**name);
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+
+        let synth = SyntheticModule::new(names, evaluation_steps);
+
+        let module = Self {
+            inner: Gc::new(Inner {
+                realm: realm.unwrap_or_else(|| context.realm().clone()),
+                environment: GcRefCell::default(),
+                namespace: GcRefCell::default(),
+                kind: ModuleKind::Synthetic(synth.clone()),
+                host_defined: (),
+            }),
+        };
+
+        synth.set_parent(module.clone());
+
+        module
+    }
+
+    /// Abstract operation [`ParseJSONModule ( source )`][spec].
+    ///
+    /// Parses `json` as a JSON text, returning a synthetic module whose `default` export is the
+    /// parsed value.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `SyntaxError` if `json` is not a valid JSON text.
+    ///
+    /// [spec]: https://tc39.es/proposal-json-modules/#sec-parse-json-module
+    pub fn parse_json(json: JsString, context: &mut Context<'_>) -> JsResult<Self> {
+        // 1. Let jsonParse be realm's intrinsic object named "%JSON.parse%".
+        // 2. Let json be ? Call(jsonParse, undefined, « source »).
+        let value = Json::parse(&JsValue::undefined(), &[json.into()], context)?;
+
+        // 3. Return CreateDefaultExportSyntheticModule(json).
+        Ok(Self::synthetic(
+            &[js_string!("default")],
+            SyntheticModuleInitializer::from_copy_closure_with_captures(
+                |module, value, context| {
+                    module.set_export(&js_string!("default"), value.clone(), context)
+                },
+                value,
+            ),
+            None,
+            context,
+        ))
+    }
+
     /// Gets the realm of this `Module`.
     #[inline]
     pub fn realm(&self) -> &Realm {
@@ -387,7 +636,9 @@ impl Module {
                 JsPromise::from_object(pc.promise().clone())
                     .expect("promise created from the %Promise% intrinsic is always native")
             }
-            ModuleKind::Synthetic => todo!("synthetic.load()"),
+            // A synthetic module doesn't have any dependencies, so it's loaded right away.
+            ModuleKind::Synthetic(_) => JsPromise::resolve(JsValue::undefined(), context)
+                .expect("creating a promise from the %Promise% intrinsic cannot fail"),
         }
     }
 
@@ -442,7 +693,7 @@ impl Module {
     fn get_exported_names(&self, export_star_set: &mut Vec<SourceTextModule>) -> FxHashSet<Sym> {
         match self.kind() {
             ModuleKind::SourceText(src) => src.get_exported_names(export_star_set),
-            ModuleKind::Synthetic => todo!("synthetic.get_exported_names()"),
+            ModuleKind::Synthetic(synth) => synth.get_exported_names(),
         }
     }
 
@@ -465,7 +716,7 @@ impl Module {
     ) -> Result<ResolvedBinding, ResolveExportError> {
         match self.kind() {
             ModuleKind::SourceText(src) => src.resolve_export(export_name, resolve_set),
-            ModuleKind::Synthetic => todo!("synthetic.resolve_export()"),
+            ModuleKind::Synthetic(synth) => synth.resolve_export(export_name),
         }
     }
 
@@ -484,7 +735,10 @@ impl Module {
     pub fn link(&self, context: &mut Context<'_>) -> JsResult<()> {
         match self.kind() {
             ModuleKind::SourceText(src) => src.link(context),
-            ModuleKind::Synthetic => todo!("synthetic.link()"),
+            ModuleKind::Synthetic(synth) => {
+                synth.link();
+                Ok(())
+            }
         }
     }
 
@@ -499,11 +753,10 @@ impl Module {
     ) -> JsResult<usize> {
         match self.kind() {
             ModuleKind::SourceText(src) => src.inner_link(stack, index, context),
-            #[allow(unreachable_code)]
             // If module is not a Cyclic Module Record, then
-            ModuleKind::Synthetic => {
+            ModuleKind::Synthetic(synth) => {
                 // a. Perform ? module.Link().
-                todo!("synthetic.link()");
+                synth.link();
                 // b. Return index.
                 Ok(index)
             }
@@ -527,7 +780,7 @@ impl Module {
     pub fn evaluate(&self, context: &mut Context<'_>) -> JsPromise {
         match self.kind() {
             ModuleKind::SourceText(src) => src.evaluate(context),
-            ModuleKind::Synthetic => todo!("synthetic.evaluate()"),
+            ModuleKind::Synthetic(synth) => synth.evaluate(context),
         }
     }
 
@@ -543,10 +796,9 @@ impl Module {
         match self.kind() {
             ModuleKind::SourceText(src) => src.inner_evaluate(stack, index, None, context),
             // 1. If module is not a Cyclic Module Record, then
-            #[allow(unused, clippy::diverging_sub_expression)]
-            ModuleKind::Synthetic => {
+            ModuleKind::Synthetic(synth) => {
                 // a. Let promise be ! module.Evaluate().
-                let promise: JsPromise = todo!("module.Evaluate()");
+                let promise = synth.evaluate(context);
                 let state = promise.state()?;
                 match state {
                     PromiseState::Pending => {
@@ -2,13 +2,14 @@ use std::{
     cell::Cell,
     collections::HashSet,
     hash::{BuildHasherDefault, Hash},
+    path::Path,
     rc::Rc,
 };
 
 use boa_ast::{
     declaration::{
-        ExportEntry, ImportEntry, ImportName, IndirectExportEntry, LexicalDeclaration,
-        LocalExportEntry, ReExportImportName,
+        ExportEntry, ImportAttribute, ImportEntry, ImportName, IndirectExportEntry,
+        LexicalDeclaration, LocalExportEntry, ReExportImportName,
     },
     operations::{
         bound_names, contains, lexically_scoped_declarations, var_scoped_declarations,
@@ -17,7 +18,7 @@ use boa_ast::{
 };
 use boa_gc::{custom_trace, empty_trace, Finalize, Gc, GcRefCell, Trace};
 use boa_interner::Sym;
-use indexmap::IndexSet;
+use indexmap::IndexMap;
 use rustc_hash::{FxHashMap, FxHashSet, FxHasher};
 
 use crate::{
@@ -31,11 +32,12 @@ use crate::{
         create_function_object_fast, create_generator_function_object, ActiveRunnable, CallFrame,
         CodeBlock, CompletionRecord, Opcode,
     },
-    Context, JsArgs, JsError, JsNativeError, JsObject, JsResult, JsString, JsValue, NativeFunction,
+    Context, JsArgs, JsError, JsNativeError, JsObject, JsResult, JsValue, NativeFunction,
 };
 
 use super::{
-    BindingName, GraphLoadingState, Module, Referrer, ResolveExportError, ResolvedBinding,
+    BindingName, GraphLoadingState, Module, ModuleRequest, Referrer, ResolveExportError,
+    ResolvedBinding,
 };
 
 /// Information for the [**Depth-first search**] algorithm used in the
@@ -282,8 +284,9 @@ struct Inner {
 #[derive(Debug)]
 struct ModuleCode {
     has_tla: bool,
-    requested_modules: IndexSet<Sym, BuildHasherDefault<FxHasher>>,
+    requested_modules: IndexMap<Sym, Box<[ImportAttribute]>, BuildHasherDefault<FxHasher>>,
     source: boa_ast::Module,
+    path: Option<Rc<Path>>,
     import_entries: Vec<ImportEntry>,
     local_export_entries: Vec<LocalExportEntry>,
     indirect_export_entries: Vec<IndirectExportEntry>,
@@ -310,7 +313,7 @@ impl SourceTextModule {
     /// Contains part of the abstract operation [`ParseModule`][parse].
     ///
     /// [parse]: https://tc39.es/ecma262/#sec-parsemodule
-    pub(super) fn new(code: boa_ast::Module) -> Self {
+    pub(super) fn new(code: boa_ast::Module, path: Option<Rc<Path>>) -> Self {
         // 3. Let requestedModules be the ModuleRequests of body.
         let requested_modules = code.items().requests();
         // 4. Let importEntries be ImportEntries of body.
@@ -398,6 +401,7 @@ impl SourceTextModule {
                 import_meta: GcRefCell::default(),
                 code: ModuleCode {
                     source: code,
+                    path,
                     requested_modules,
                     has_tla,
                     import_entries,
@@ -426,7 +430,7 @@ impl SourceTextModule {
                 .pending_modules
                 .set(state.pending_modules.get() + requested.len());
             // d. For each String required of module.[[RequestedModules]], do
-            for &required in requested {
+            for (&required, attributes) in requested {
                 // i. If module.[[LoadedModules]] contains a Record whose [[Specifier]] is required, then
                 let loaded = self.inner.loaded_modules.borrow().get(&required).cloned();
                 if let Some(loaded) = loaded {
@@ -438,15 +442,26 @@ impl SourceTextModule {
                     //       1. Perform HostLoadImportedModule(module, required, state.[[HostDefined]], state).
                     //       2. NOTE: HostLoadImportedModule will call FinishLoadingImportedModule, which re-enters
                     //          the graph loading process through ContinueModuleLoading.
-                    let name_specifier: JsString = context
-                        .interner()
-                        .resolve_expect(required)
-                        .into_common(false);
+                    let interner = context.interner();
+                    let request = ModuleRequest::new(
+                        interner.resolve_expect(required).into_common(false),
+                        attributes
+                            .iter()
+                            .map(|attribute| {
+                                (
+                                    interner.resolve_expect(attribute.key()).into_common(false),
+                                    interner
+                                        .resolve_expect(attribute.value())
+                                        .into_common(false),
+                                )
+                            })
+                            .collect(),
+                    );
                     let src = self.clone();
                     let state = state.clone();
                     context.module_loader().load_imported_module(
                         Referrer::Module(self.parent()),
-                        name_specifier,
+                        request,
                         Box::new(move |completion, context| {
                             // FinishLoadingImportedModule ( referrer, specifier, payload, result )
                             // https://tc39.es/ecma262/#sec-FinishLoadingImportedModule
@@ -777,7 +792,7 @@ impl SourceTextModule {
 
         // 9. For each String required of module.[[RequestedModules]], do
 
-        for required in &self.inner.code.requested_modules {
+        for required in self.inner.code.requested_modules.keys() {
             // a. Let requiredModule be GetImportedModule(module, required).
             let required_module = self.inner.loaded_modules.borrow()[required].clone();
 
@@ -1082,7 +1097,7 @@ impl SourceTextModule {
         stack.push(self.clone());
 
         // 11. For each String required of module.[[RequestedModules]], do
-        for &required in &self.inner.code.requested_modules {
+        for &required in self.inner.code.requested_modules.keys() {
             // a. Let requiredModule be GetImportedModule(module, required).
             let required_module = self.inner.loaded_modules.borrow()[&required].clone();
             // b. Set index to ? InnerModuleEvaluation(requiredModule, stack, index).
@@ -1151,7 +1166,10 @@ impl SourceTextModule {
         if pending_async_dependencies > 0 || self.inner.code.has_tla {
             // a. Assert: module.[[AsyncEvaluation]] is false and was never previously set to true.
             {
-                let Status::Evaluating { async_eval_index, .. } = &mut *self.inner.status.borrow_mut() else {
+                let Status::Evaluating {
+                    async_eval_index, ..
+                } = &mut *self.inner.status.borrow_mut()
+                else {
                     unreachable!("self should still be in the evaluating state")
                 };
 
@@ -1326,7 +1344,11 @@ impl SourceTextModule {
                     // i. Assert: m.[[Status]] is evaluating-async.
                     // ii. Assert: m.[[EvaluationError]] is empty.
                     // iii. Assert: m.[[AsyncEvaluation]] is true.
-                    let Status::EvaluatingAsync { pending_async_dependencies, .. } = &mut *m.inner.status.borrow_mut() else {
+                    let Status::EvaluatingAsync {
+                        pending_async_dependencies,
+                        ..
+                    } = &mut *m.inner.status.borrow_mut()
+                    else {
                         unreachable!("i. Assert: m.[[Status]] is evaluating-async.");
                     };
                     // iv. Assert: m.[[PendingAsyncDependencies]] > 0.
@@ -1410,6 +1432,7 @@ impl SourceTextModule {
 
         let mut compiler =
             ByteCompiler::new(Sym::MAIN, true, false, module_compile_env.clone(), context);
+        compiler.source_path.clone_from(&self.inner.code.path);
         let mut imports = Vec::new();
 
         let (codeblock, functions) = {
@@ -1552,9 +1575,11 @@ impl SourceTextModule {
                         continue;
                     }
                     // i. If IsConstantDeclaration of d is true, then
-                    LexicallyScopedDeclaration::LexicalDeclaration(LexicalDeclaration::Const(
-                        c,
-                    )) => {
+                    LexicallyScopedDeclaration::LexicalDeclaration(
+                        LexicalDeclaration::Const(c)
+                        | LexicalDeclaration::Using(c)
+                        | LexicalDeclaration::AwaitUsing(c),
+                    ) => {
                         // a. For each element dn of the BoundNames of d, do
                         for name in bound_names(c) {
                             // 1. Perform ! env.CreateImmutableBinding(dn, true).
@@ -1833,7 +1858,10 @@ fn async_module_execution_fulfilled(module: &SourceTextModule, context: &mut Con
 
     // 10. Let sortedExecList be a List whose elements are the elements of execList, in the order in which they had their [[AsyncEvaluation]] fields set to true in InnerModuleEvaluation.
     ancestors.sort_by_cached_key(|m| {
-        let Status::EvaluatingAsync { async_eval_index, .. } = &*m.inner.status.borrow() else {
+        let Status::EvaluatingAsync {
+            async_eval_index, ..
+        } = &*m.inner.status.borrow()
+        else {
             unreachable!("GatherAvailableAncestors: i. Assert: m.[[Status]] is evaluating-async.");
         };
 
@@ -0,0 +1,402 @@
+use boa_ast::expression::Identifier;
+use boa_gc::{custom_trace, Finalize, Gc, GcRefCell, Trace};
+use boa_interner::Sym;
+use rustc_hash::FxHashSet;
+
+use crate::{
+    environments::{CompileTimeEnvironment, EnvironmentStack},
+    object::JsPromise,
+    vm::ActiveRunnable,
+    Context, JsNativeError, JsResult, JsString, JsValue,
+};
+
+use super::{BindingName, Module, ResolveExportError, ResolvedBinding};
+
+trait TraceableInitializer: Trace {
+    fn call(&self, module: &SyntheticModule, context: &mut Context<'_>) -> JsResult<()>;
+}
+
+#[derive(Trace, Finalize)]
+struct Initializer<F, T>
+where
+    F: Fn(&SyntheticModule, &T, &mut Context<'_>) -> JsResult<()>,
+    T: Trace,
+{
+    // SAFETY: `SyntheticModuleInitializer`'s safe API ensures only `Copy` closures are stored; its
+    // unsafe API, on the other hand, explains the invariants to hold in order for this to be safe,
+    // shifting the responsibility to the caller.
+    #[unsafe_ignore_trace]
+    f: F,
+    captures: T,
+}
+
+impl<F, T> TraceableInitializer for Initializer<F, T>
+where
+    F: Fn(&SyntheticModule, &T, &mut Context<'_>) -> JsResult<()>,
+    T: Trace,
+{
+    fn call(&self, module: &SyntheticModule, context: &mut Context<'_>) -> JsResult<()> {
+        (self.f)(module, &self.captures, context)
+    }
+}
+
+/// The evaluation steps of a [**Synthetic Module Record**][spec].
+///
+/// The evaluation steps of a synthetic module are called when the module gets evaluated, and
+/// must set the values of its exports using [`SyntheticModule::set_export`].
+///
+/// # Caveats
+///
+/// By limitations of the Rust language, the garbage collector currently cannot inspect closures
+/// in order to trace their captured variables. This means that only [`Copy`] closures are 100% safe
+/// to use. All other closures can also be stored in a `SyntheticModuleInitializer`, albeit by using
+/// an `unsafe` API, but note that passing closures implicitly capturing traceable types could cause
+/// **Undefined Behaviour**.
+///
+/// [spec]: https://tc39.es/proposal-json-modules/#sec-synthetic-module-records
+#[derive(Clone)]
+pub struct SyntheticModuleInitializer {
+    inner: InitializerInner,
+}
+
+#[derive(Clone)]
+enum InitializerInner {
+    PointerFn(fn(&SyntheticModule, &mut Context<'_>) -> JsResult<()>),
+    Closure(Gc<dyn TraceableInitializer>),
+}
+
+impl Finalize for SyntheticModuleInitializer {
+    fn finalize(&self) {
+        if let InitializerInner::Closure(c) = &self.inner {
+            c.finalize();
+        }
+    }
+}
+
+// Manual implementation because deriving `Trace` triggers the `single_use_lifetimes` lint.
+// SAFETY: Only closures can contain `Trace` captures, so this implementation is safe.
+unsafe impl Trace for SyntheticModuleInitializer {
+    custom_trace!(this, {
+        if let InitializerInner::Closure(c) = &this.inner {
+            mark(c);
+        }
+    });
+}
+
+impl std::fmt::Debug for SyntheticModuleInitializer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SyntheticModuleInitializer")
+            .finish_non_exhaustive()
+    }
+}
+
+impl SyntheticModuleInitializer {
+    /// Creates a `SyntheticModuleInitializer` from a function pointer.
+    #[inline]
+    pub fn from_fn_ptr(function: fn(&SyntheticModule, &mut Context<'_>) -> JsResult<()>) -> Self {
+        Self {
+            inner: InitializerInner::PointerFn(function),
+        }
+    }
+
+    /// Creates a `SyntheticModuleInitializer` from a `Copy` closure.
+    pub fn from_copy_closure<F>(closure: F) -> Self
+    where
+        F: Fn(&SyntheticModule, &mut Context<'_>) -> JsResult<()> + Copy + 'static,
+    {
+        // SAFETY: The `Copy` bound ensures there are no traceable types inside the closure.
+        unsafe { Self::from_closure(closure) }
+    }
+
+    /// Creates a `SyntheticModuleInitializer` from a `Copy` closure and a list of traceable
+    /// captures.
+    pub fn from_copy_closure_with_captures<F, T>(closure: F, captures: T) -> Self
+    where
+        F: Fn(&SyntheticModule, &T, &mut Context<'_>) -> JsResult<()> + Copy + 'static,
+        T: Trace + 'static,
+    {
+        // SAFETY: The `Copy` bound ensures there are no traceable types inside the closure.
+        unsafe { Self::from_closure_with_captures(closure, captures) }
+    }
+
+    /// Creates a new `SyntheticModuleInitializer` from a closure.
+    ///
+    /// # Safety
+    ///
+    /// Passing a closure that contains a captured variable that needs to be traced by the garbage
+    /// collector could cause an use after free, memory corruption or other kinds of **Undefined
+    /// Behaviour**. See <https://github.com/Manishearth/rust-gc/issues/50> for a technical explanation
+    /// on why that is the case.
+    pub unsafe fn from_closure<F>(closure: F) -> Self
+    where
+        F: Fn(&SyntheticModule, &mut Context<'_>) -> JsResult<()> + 'static,
+    {
+        // SAFETY: The caller must ensure the invariants of the closure hold.
+        unsafe {
+            Self::from_closure_with_captures(
+                move |module, (), context| closure(module, context),
+                (),
+            )
+        }
+    }
+
+    /// Create a new `SyntheticModuleInitializer` from a closure and a list of traceable captures.
+    ///
+    /// # Safety
+    ///
+    /// Passing a closure that contains a captured variable that needs to be traced by the garbage
+    /// collector could cause an use after free, memory corruption or other kinds of **Undefined
+    /// Behaviour**. See <https://github.com/Manishearth/rust-gc/issues/50> for a technical explanation
+    /// on why that is the case.
+    pub unsafe fn from_closure_with_captures<F, T>(closure: F, captures: T) -> Self
+    where
+        F: Fn(&SyntheticModule, &T, &mut Context<'_>) -> JsResult<()> + 'static,
+        T: Trace + 'static,
+    {
+        // Hopefully, this unsafe operation will be replaced by the `CoerceUnsized` API in the
+        // future: https://github.com/rust-lang/rust/issues/18598
+        let ptr = Gc::into_raw(Gc::new(Initializer {
+            f: closure,
+            captures,
+        }));
+        // SAFETY: The pointer returned by `into_raw` is only used to coerce to a trait object,
+        // meaning this is safe.
+        unsafe {
+            Self {
+                inner: InitializerInner::Closure(Gc::from_raw(ptr)),
+            }
+        }
+    }
+
+    /// Calls this `SyntheticModuleInitializer`, forwarding the arguments to the corresponding
+    /// function.
+    #[inline]
+    pub(crate) fn call(&self, module: &SyntheticModule, context: &mut Context<'_>) -> JsResult<()> {
+        match self.inner {
+            InitializerInner::PointerFn(f) => f(module, context),
+            InitializerInner::Closure(ref c) => c.call(module, context),
+        }
+    }
+}
+
+/// ECMAScript's [**Synthetic Module Record**][spec].
+///
+/// A synthetic module exposes a fixed list of exports, whose values are set by its evaluation
+/// steps instead of by ECMAScript code. This allows hosts to expose native modules, and is used
+/// to implement JSON modules.
+///
+/// [spec]: https://tc39.es/proposal-json-modules/#sec-synthetic-module-records
+#[derive(Clone, Trace, Finalize)]
+pub struct SyntheticModule {
+    inner: Gc<Inner>,
+}
+
+impl std::fmt::Debug for SyntheticModule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SyntheticModule")
+            .field("export_names", &self.inner.export_names)
+            .field("eval_steps", &self.inner.eval_steps)
+            .finish_non_exhaustive()
+    }
+}
+
+#[derive(Trace, Finalize)]
+struct Inner {
+    parent: GcRefCell<Option<Module>>,
+    #[unsafe_ignore_trace]
+    export_names: Vec<Sym>,
+    eval_steps: SyntheticModuleInitializer,
+}
+
+impl SyntheticModule {
+    /// Creates a new `SyntheticModule` from its list of export names and its evaluation steps.
+    ///
+    /// Contains part of the abstract operation [`CreateSyntheticModule`][spec].
+    ///
+    /// [spec]: https://tc39.es/proposal-json-modules/#sec-create-synthetic-module
+    pub(super) fn new(export_names: Vec<Sym>, eval_steps: SyntheticModuleInitializer) -> Self {
+        Self {
+            inner: Gc::new(Inner {
+                parent: GcRefCell::default(),
+                export_names,
+                eval_steps,
+            }),
+        }
+    }
+
+    /// Sets the parent module of this synthetic module.
+    pub(super) fn set_parent(&self, parent: Module) {
+        *self.inner.parent.borrow_mut() = Some(parent);
+    }
+
+    /// Gets the parent module of this synthetic module.
+    fn parent(&self) -> Module {
+        self.inner
+            .parent
+            .borrow()
+            .clone()
+            .expect("parent module must be initialized")
+    }
+
+    /// Abstract operation [`SetSyntheticModuleExport ( module, exportName, exportValue )`][spec].
+    ///
+    /// Sets the value of the export `export_name` of this module. This should only be called
+    /// from the evaluation steps of the module.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `export_name` is not in the list of exports of this module, or if
+    /// the module has not been linked.
+    ///
+    /// [spec]: https://tc39.es/proposal-json-modules/#sec-setsyntheticmoduleexport
+    #[allow(clippy::missing_panics_doc)]
+    pub fn set_export(
+        &self,
+        export_name: &JsString,
+        export_value: JsValue,
+        context: &mut Context<'_>,
+    ) -> JsResult<()> {
+        let name = context
+            .interner()
+            .get(&**export_name)
+            .filter(|name| self.inner.export_names.contains(name))
+            .ok_or_else(|| {
+                JsNativeError::reference().with_message(format!(
+                    "cannot set name `{}` which was not included in the list of exports",
+                    export_name.to_std_string_escaped()
+                ))
+            })?;
+
+        let environment = self.parent().environment().ok_or_else(|| {
+            JsNativeError::typ().with_message("cannot set the exports of an unlinked module")
+        })?;
+
+        // 1. Return ? module.[[Environment]].SetMutableBinding(name, value, true).
+        let locator = environment
+            .compile_env()
+            .borrow()
+            .get_binding(Identifier::new(name))
+            .expect("linking must create a binding for every export");
+        environment.set(locator.binding_index(), export_value);
+
+        Ok(())
+    }
+
+    /// Concrete method [`GetExportedNames ( [ exportStarSet ] )`][spec].
+    ///
+    /// [spec]: https://tc39.es/proposal-json-modules/#sec-smr-getexportednames
+    pub(super) fn get_exported_names(&self) -> FxHashSet<Sym> {
+        // 1. Return module.[[ExportNames]].
+        self.inner.export_names.iter().copied().collect()
+    }
+
+    /// Concrete method [`ResolveExport ( exportName )`][spec].
+    ///
+    /// [spec]: https://tc39.es/proposal-json-modules/#sec-smr-resolveexport
+    pub(super) fn resolve_export(
+        &self,
+        export_name: Sym,
+    ) -> Result<ResolvedBinding, ResolveExportError> {
+        // 1. If module.[[ExportNames]] does not contain exportName, return null.
+        if !self.inner.export_names.contains(&export_name) {
+            return Err(ResolveExportError::NotFound);
+        }
+
+        // 2. Return ResolvedBinding Record { [[Module]]: module, [[BindingName]]: exportName }.
+        Ok(ResolvedBinding {
+            module: self.parent(),
+            binding_name: BindingName::Name(Identifier::new(export_name)),
+        })
+    }
+
+    /// Concrete method [`Link ( )`][spec].
+    ///
+    /// [spec]: https://tc39.es/proposal-json-modules/#sec-smr-Link
+    pub(super) fn link(&self) {
+        let parent = self.parent();
+
+        // Every module importing this module links it again, but the exports must only be
+        // initialized once.
+        if parent.environment().is_some() {
+            return;
+        }
+
+        // 1. Let realm be module.[[Realm]].
+        // 2. Assert: realm is not undefined.
+        // 3. Let env be NewModuleEnvironment(realm.[[GlobalEnv]]).
+        let global_env = parent.realm().environment().clone();
+        let mut module_compile_env = CompileTimeEnvironment::new(global_env.compile_env(), true);
+
+        // 5. For each String exportName in module.[[ExportNames]], do
+        for &name in &self.inner.export_names {
+            // a. Perform ! envRec.CreateMutableBinding(exportName, false).
+            module_compile_env.create_mutable_binding(Identifier::new(name), false);
+        }
+
+        let mut envs = EnvironmentStack::new(global_env);
+        envs.push_module(Gc::new(GcRefCell::new(module_compile_env)));
+        let env = envs
+            .current()
+            .as_declarative()
+            .cloned()
+            .expect("module environment must be a declarative environment");
+
+        // b. Perform ! envRec.InitializeBinding(exportName, undefined).
+        for index in 0..self.inner.export_names.len() {
+            env.set(index as u32, JsValue::undefined());
+        }
+
+        // 4. Set module.[[Environment]] to env.
+        *parent.inner.environment.borrow_mut() = Some(env);
+
+        // 6. Return unused.
+    }
+
+    /// Concrete method [`Evaluate ( )`][spec].
+    ///
+    /// [spec]: https://tc39.es/proposal-json-modules/#sec-smr-Evaluate
+    pub(super) fn evaluate(&self, context: &mut Context<'_>) -> JsPromise {
+        let parent = self.parent();
+        let mut realm = parent.realm().clone();
+
+        // 1. Suspend the currently running execution context.
+        // 2. Let moduleContext be a new ECMAScript code execution context.
+        // 3. Set the Function of moduleContext to null.
+        let active_function = context.vm.active_function.take();
+
+        // 4. Set the Realm of moduleContext to module.[[Realm]].
+        context.swap_realm(&mut realm);
+
+        // 5. Set the ScriptOrModule of moduleContext to module.
+        let active_runnable = context
+            .vm
+            .active_runnable
+            .replace(ActiveRunnable::Module(parent));
+
+        // 6. Set the VariableEnvironment of moduleContext to module.[[Environment]].
+        // 7. Set the LexicalEnvironment of moduleContext to module.[[Environment]].
+        // 8. Push moduleContext on to the execution context stack; moduleContext is now the
+        //    running execution context.
+        // Native evaluation steps don't access the environments of the execution context.
+
+        // 9. Let result be the result of performing module.[[EvaluationSteps]](module).
+        let result = self.inner.eval_steps.call(self, context);
+
+        // 10. Suspend moduleContext and remove it from the execution context stack.
+        // 11. Resume the context that is now on the top of the execution context stack as the
+        //     running execution context.
+        context.vm.active_function = active_function;
+        context.vm.active_runnable = active_runnable;
+        context.swap_realm(&mut realm);
+
+        // 12. Let pc be ! NewPromiseCapability(%Promise%).
+        // 13. IfAbruptRejectPromise(result, pc).
+        // 14. Perform ! pc.[[Resolve]](result).
+        // 15. Return pc.[[Promise]].
+        match result {
+            Ok(()) => JsPromise::resolve(JsValue::undefined(), context),
+            Err(err) => JsPromise::reject(err, context),
+        }
+        .expect("creating a promise from the %Promise% intrinsic cannot fail")
+    }
+}
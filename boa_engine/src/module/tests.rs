@@ -0,0 +1,216 @@
+use std::{fs, path::PathBuf};
+
+use indoc::indoc;
+
+use crate::{
+    builtins::promise::PromiseState,
+    js_string,
+    module::{
+        ModuleLoader, ModuleRequest, Referrer, SimpleModuleLoader, SyntheticModuleInitializer,
+    },
+    native_function::NativeFunction,
+    object::FunctionObjectBuilder,
+    run_test_actions_with, Context, JsArgs, JsError, JsNativeError, JsNativeErrorKind, JsResult,
+    JsValue, Module, Source, TestAction,
+};
+
+/// A temporary directory with a set of files, removed when dropped.
+struct TestDir(PathBuf);
+
+impl TestDir {
+    fn new(name: &str, files: &[(&str, &str)]) -> Self {
+        let path = std::env::temp_dir().join(format!("boa-{name}-{}", std::process::id()));
+        fs::create_dir_all(&path).unwrap();
+        for (file, contents) in files {
+            fs::write(path.join(file), contents).unwrap();
+        }
+        Self(path)
+    }
+}
+
+impl Drop for TestDir {
+    fn drop(&mut self) {
+        fs::remove_dir_all(&self.0).ok();
+    }
+}
+
+fn context_with_loader<'a>(loader: &'a (dyn ModuleLoader + 'static)) -> Context<'a> {
+    Context::builder().module_loader(loader).build().unwrap()
+}
+
+/// Loads, links and evaluates the module `source`, returning the settled state of its promise.
+fn evaluate(source: &str, context: &mut Context<'_>) -> (Module, PromiseState) {
+    let module = Module::parse(Source::from_bytes(source), None, context).unwrap();
+    let promise = module.load_link_evaluate(context).unwrap();
+    context.run_jobs();
+    let state = promise.state().unwrap();
+    (module, state)
+}
+
+#[test]
+fn static_json_import() {
+    let dir = TestDir::new(
+        "static-json-import",
+        &[("data.json", r#"{ "answer": 42 }"#)],
+    );
+    let loader = SimpleModuleLoader::new(&dir.0).unwrap();
+    let context = &mut context_with_loader(&loader);
+
+    let (module, state) = evaluate(
+        indoc! {r#"
+            import data from "./data.json" with { type: "json" };
+            export const answer = data.answer;
+        "#},
+        context,
+    );
+
+    assert_eq!(state, PromiseState::Fulfilled(JsValue::undefined()));
+    assert_eq!(
+        module
+            .namespace(context)
+            .get(js_string!("answer"), context)
+            .unwrap(),
+        JsValue::from(42)
+    );
+}
+
+#[test]
+fn static_json_import_without_type() {
+    let dir = TestDir::new(
+        "static-json-untyped",
+        &[("data.json", r#"{ "answer": 42 }"#)],
+    );
+    let loader = SimpleModuleLoader::new(&dir.0).unwrap();
+    let context = &mut context_with_loader(&loader);
+
+    let (_, state) = evaluate(r#"import data from "./data.json";"#, context);
+
+    let PromiseState::Rejected(reason) = state else {
+        panic!("the import of a JSON file without a type must be rejected, got {state:?}");
+    };
+    let error = JsError::from_opaque(reason).try_native(context).unwrap();
+    assert_eq!(error.kind, JsNativeErrorKind::Syntax);
+}
+
+#[test]
+fn static_import_with_unsupported_attribute() {
+    let context = &mut Context::default();
+
+    let error = Module::parse(
+        Source::from_bytes(r#"import data from "./data.json" with { kind: "json" };"#),
+        None,
+        context,
+    )
+    .unwrap_err()
+    .try_native(context)
+    .unwrap();
+
+    assert_eq!(error.kind, JsNativeErrorKind::Syntax);
+    assert_eq!(error.message(), "unsupported import attribute `kind`");
+}
+
+#[test]
+fn dynamic_json_import() {
+    let dir = TestDir::new(
+        "dynamic-json-import",
+        &[("data.json", r#"{ "answer": 42 }"#)],
+    );
+    let loader = SimpleModuleLoader::new(&dir.0).unwrap();
+    let context = &mut context_with_loader(&loader);
+
+    context
+        .eval(Source::from_bytes(indoc! {r#"
+                let answer, untyped, unknownKey, unknownType;
+                import("./data.json", { with: { type: "json" } })
+                    .then((ns) => { answer = ns.default.answer; });
+                import("./data.json").catch((e) => { untyped = e; });
+                import("./data.json", { with: { kind: "json" } }).catch((e) => { unknownKey = e; });
+                import("./data.json", { with: { type: "css" } }).catch((e) => { unknownType = e; });
+            "#}))
+        .unwrap();
+    context.run_jobs();
+    run_test_actions_with(
+        [
+            TestAction::assert_eq("answer", 42),
+            TestAction::assert("untyped instanceof SyntaxError"),
+            TestAction::assert("unknownKey instanceof TypeError"),
+            TestAction::assert_eq(
+                "unknownKey.message",
+                js_string!("unsupported import attribute `kind`"),
+            ),
+            TestAction::assert("unknownType instanceof TypeError"),
+            TestAction::assert_eq(
+                "unknownType.message",
+                js_string!("unsupported module type `css`"),
+            ),
+        ],
+        context,
+    );
+}
+
+/// A module loader serving native modules under the `host:` prefix.
+struct HostModuleLoader;
+
+impl ModuleLoader for HostModuleLoader {
+    fn load_imported_module(
+        &self,
+        referrer: Referrer,
+        request: ModuleRequest,
+        finish_load: Box<dyn FnOnce(JsResult<Module>, &mut Context<'_>)>,
+        context: &mut Context<'_>,
+    ) {
+        let Referrer::Module(referrer) = referrer else {
+            unreachable!("host modules are only imported from modules in these tests");
+        };
+        let result = match request.specifier().to_std_string_escaped().as_str() {
+            "host:math" => Ok(Module::synthetic(
+                &[js_string!("answer"), js_string!("double")],
+                SyntheticModuleInitializer::from_copy_closure(|module, context| {
+                    let double = FunctionObjectBuilder::new(
+                        context,
+                        NativeFunction::from_fn_ptr(|_, args, context| {
+                            Ok((args.get_or_undefined(0).to_number(context)? * 2.0).into())
+                        }),
+                    )
+                    .build();
+                    module.set_export(&js_string!("answer"), 42.into(), context)?;
+                    module.set_export(&js_string!("double"), double.into(), context)
+                }),
+                Some(referrer.realm().clone()),
+                context,
+            )),
+            _ => Err(JsNativeError::typ().with_message("module not found").into()),
+        };
+        finish_load(result, context);
+    }
+}
+
+#[test]
+fn host_synthetic_module() {
+    let context = &mut context_with_loader(&HostModuleLoader);
+
+    let (module, state) = evaluate(
+        indoc! {r#"
+            import { answer, double } from "host:math";
+            export const result = double(answer);
+            export let missing;
+            try {
+                await import("host:missing");
+            } catch (e) {
+                missing = e.message;
+            }
+        "#},
+        context,
+    );
+
+    assert_eq!(state, PromiseState::Fulfilled(JsValue::undefined()));
+    let namespace = module.namespace(context);
+    assert_eq!(
+        namespace.get(js_string!("result"), context).unwrap(),
+        JsValue::from(84)
+    );
+    assert_eq!(
+        namespace.get(js_string!("missing"), context).unwrap(),
+        JsValue::from(js_string!("module not found"))
+    );
+}
@@ -373,6 +373,78 @@ impl JsArray {
             context,
         )
     }
+
+    /// Calls `Array.prototype.toReversed()`.
+    #[inline]
+    #[allow(clippy::missing_panics_doc)]
+    pub fn to_reversed(&self, context: &mut Context<'_>) -> JsResult<Self> {
+        let object = Array::to_reversed(&self.inner.clone().into(), &[], context)?
+            .as_object()
+            .cloned()
+            .expect("Array.prototype.toReversed should always return object");
+
+        Self::from_object(object)
+    }
+
+    /// Calls `Array.prototype.toSorted()`.
+    #[inline]
+    #[allow(clippy::missing_panics_doc)]
+    pub fn to_sorted(
+        &self,
+        compare_fn: Option<JsFunction>,
+        context: &mut Context<'_>,
+    ) -> JsResult<Self> {
+        let object = Array::to_sorted(
+            &self.inner.clone().into(),
+            &[compare_fn.into_or_undefined()],
+            context,
+        )?
+        .as_object()
+        .cloned()
+        .expect("Array.prototype.toSorted should always return object");
+
+        Self::from_object(object)
+    }
+
+    /// Calls `Array.prototype.toSpliced()`.
+    #[inline]
+    #[allow(clippy::missing_panics_doc)]
+    pub fn to_spliced(
+        &self,
+        start: Option<u32>,
+        skip_count: Option<u32>,
+        items: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<Self> {
+        let mut args = vec![start.into_or_undefined(), skip_count.into_or_undefined()];
+        args.extend_from_slice(items);
+
+        let object = Array::to_spliced(&self.inner.clone().into(), &args, context)?
+            .as_object()
+            .cloned()
+            .expect("Array.prototype.toSpliced should always return object");
+
+        Self::from_object(object)
+    }
+
+    /// Calls `Array.prototype.with()`.
+    #[inline]
+    #[allow(clippy::missing_panics_doc)]
+    pub fn with<T>(&self, index: i64, value: T, context: &mut Context<'_>) -> JsResult<Self>
+    where
+        T: Into<JsValue>,
+    {
+        let object = Array::with(
+            &self.inner.clone().into(),
+            &[index.into(), value.into()],
+            context,
+        )?
+        .as_object()
+        .cloned()
+        .expect("Array.prototype.with should always return object");
+
+        Self::from_object(object)
+    }
 }
 
 impl From<JsArray> for JsObject {
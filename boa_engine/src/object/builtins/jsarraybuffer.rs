@@ -47,6 +47,48 @@ impl JsArrayBuffer {
                 .constructor()
                 .into(),
             byte_length as u64,
+            None,
+            context,
+        )?;
+
+        Ok(Self { inner })
+    }
+
+    /// Create a new resizable array buffer with byte length, which can grow up to
+    /// `max_byte_length` bytes.
+    ///
+    /// ```
+    /// # use boa_engine::{
+    /// # object::builtins::JsArrayBuffer,
+    /// # Context, JsResult
+    /// # };
+    /// # fn main() -> JsResult<()> {
+    /// # // Initialize context
+    /// # let context = &mut Context::default();
+    /// // Creates a blank array buffer of 4 bytes, that can grow up to 8 bytes
+    /// let array_buffer = JsArrayBuffer::new_resizable(4, 8, context)?;
+    ///
+    /// assert!(array_buffer.is_resizable());
+    /// assert_eq!(array_buffer.max_byte_length(), Some(8));
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn new_resizable(
+        byte_length: usize,
+        max_byte_length: usize,
+        context: &mut Context<'_>,
+    ) -> JsResult<Self> {
+        let inner = ArrayBuffer::allocate(
+            &context
+                .intrinsics()
+                .constructors()
+                .array_buffer()
+                .constructor()
+                .into(),
+            byte_length as u64,
+            Some(max_byte_length as u64),
             context,
         )?;
 
@@ -107,6 +149,7 @@ impl JsArrayBuffer {
             ObjectData::array_buffer(ArrayBuffer {
                 array_buffer_data: Some(block),
                 array_buffer_byte_length: byte_length as u64,
+                array_buffer_max_byte_length: None,
                 array_buffer_detach_key: JsValue::Undefined,
             }),
         );
@@ -157,6 +200,210 @@ impl JsArrayBuffer {
             .expect("expected a number") as usize
     }
 
+    /// Returns the maximum byte length the array buffer can be resized to, or `None` if the
+    /// array buffer is not resizable.
+    ///
+    /// ```
+    /// # use boa_engine::{
+    /// # object::builtins::JsArrayBuffer,
+    /// # Context, JsResult,
+    /// # };
+    /// # fn main() -> JsResult<()> {
+    /// # // Initialize context
+    /// # let context = &mut Context::default();
+    /// let fixed = JsArrayBuffer::new(4, context)?;
+    /// let resizable = JsArrayBuffer::new_resizable(4, 16, context)?;
+    ///
+    /// assert_eq!(fixed.max_byte_length(), None);
+    /// assert_eq!(resizable.max_byte_length(), Some(16));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the array buffer is currently mutably borrowed.
+    #[inline]
+    #[must_use]
+    pub fn max_byte_length(&self) -> Option<usize> {
+        self.inner
+            .borrow()
+            .as_array_buffer()
+            .expect("inner must be an ArrayBuffer")
+            .array_buffer_max_byte_length
+            .map(|max| max as usize)
+    }
+
+    /// Returns `true` if the array buffer can be resized.
+    ///
+    /// ```
+    /// # use boa_engine::{
+    /// # object::builtins::JsArrayBuffer,
+    /// # Context, JsResult,
+    /// # };
+    /// # fn main() -> JsResult<()> {
+    /// # // Initialize context
+    /// # let context = &mut Context::default();
+    /// let array_buffer = JsArrayBuffer::new_resizable(0, 4, context)?;
+    ///
+    /// assert!(array_buffer.is_resizable());
+    /// assert!(!JsArrayBuffer::new(4, context)?.is_resizable());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the array buffer is currently mutably borrowed.
+    #[inline]
+    #[must_use]
+    pub fn is_resizable(&self) -> bool {
+        !self
+            .inner
+            .borrow()
+            .as_array_buffer()
+            .expect("inner must be an ArrayBuffer")
+            .is_fixed_length()
+    }
+
+    /// Returns `true` if the array buffer is detached.
+    ///
+    /// ```
+    /// # use boa_engine::{
+    /// # object::builtins::JsArrayBuffer,
+    /// # Context, JsResult,
+    /// # };
+    /// # fn main() -> JsResult<()> {
+    /// # // Initialize context
+    /// # let context = &mut Context::default();
+    /// let array_buffer = JsArrayBuffer::new(4, context)?;
+    /// assert!(!array_buffer.is_detached());
+    ///
+    /// array_buffer.transfer(None, context)?;
+    /// assert!(array_buffer.is_detached());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the array buffer is currently mutably borrowed.
+    #[inline]
+    #[must_use]
+    pub fn is_detached(&self) -> bool {
+        self.inner
+            .borrow()
+            .as_array_buffer()
+            .expect("inner must be an ArrayBuffer")
+            .is_detached_buffer()
+    }
+
+    /// Resizes the array buffer to `new_byte_length` bytes.
+    ///
+    /// Throws a `TypeError` if the array buffer is not resizable or is detached, and a
+    /// `RangeError` if `new_byte_length` is bigger than its maximum byte length.
+    ///
+    /// ```
+    /// # use boa_engine::{
+    /// # object::builtins::JsArrayBuffer,
+    /// # Context, JsResult,
+    /// # };
+    /// # fn main() -> JsResult<()> {
+    /// # // Initialize context
+    /// # let context = &mut Context::default();
+    /// let array_buffer = JsArrayBuffer::new_resizable(2, 8, context)?;
+    ///
+    /// array_buffer.resize(6, context)?;
+    /// assert_eq!(array_buffer.byte_length(context), 6);
+    ///
+    /// assert!(array_buffer.resize(10, context).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn resize(&self, new_byte_length: usize, context: &mut Context<'_>) -> JsResult<()> {
+        ArrayBuffer::resize(
+            &self.inner.clone().into(),
+            &[new_byte_length.into()],
+            context,
+        )?;
+        Ok(())
+    }
+
+    /// Moves the contents of the array buffer into a new array buffer of `new_byte_length` bytes
+    /// (or the same byte length if `None`), detaching this array buffer.
+    ///
+    /// The new array buffer is resizable if this array buffer is resizable.
+    ///
+    /// ```
+    /// # use boa_engine::{
+    /// # object::builtins::JsArrayBuffer,
+    /// # Context, JsResult,
+    /// # };
+    /// # fn main() -> JsResult<()> {
+    /// # // Initialize context
+    /// # let context = &mut Context::default();
+    /// let array_buffer = JsArrayBuffer::from_byte_block(vec![1, 2, 3, 4], context)?;
+    ///
+    /// let transferred = array_buffer.transfer(Some(2), context)?;
+    ///
+    /// assert!(array_buffer.is_detached());
+    /// assert_eq!(transferred.take()?, vec![1, 2]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn transfer(
+        &self,
+        new_byte_length: Option<usize>,
+        context: &mut Context<'_>,
+    ) -> JsResult<Self> {
+        let inner = ArrayBuffer::copy_and_detach(
+            &self.inner.clone().into(),
+            &new_byte_length.map_or_else(JsValue::undefined, JsValue::from),
+            true,
+            context,
+        )?;
+
+        Ok(Self { inner })
+    }
+
+    /// Moves the contents of the array buffer into a new fixed length array buffer of
+    /// `new_byte_length` bytes (or the same byte length if `None`), detaching this array buffer.
+    ///
+    /// ```
+    /// # use boa_engine::{
+    /// # object::builtins::JsArrayBuffer,
+    /// # Context, JsResult,
+    /// # };
+    /// # fn main() -> JsResult<()> {
+    /// # // Initialize context
+    /// # let context = &mut Context::default();
+    /// let array_buffer = JsArrayBuffer::new_resizable(4, 8, context)?;
+    ///
+    /// let transferred = array_buffer.transfer_to_fixed_length(None, context)?;
+    ///
+    /// assert!(!transferred.is_resizable());
+    /// assert_eq!(transferred.byte_length(context), 4);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn transfer_to_fixed_length(
+        &self,
+        new_byte_length: Option<usize>,
+        context: &mut Context<'_>,
+    ) -> JsResult<Self> {
+        let inner = ArrayBuffer::copy_and_detach(
+            &self.inner.clone().into(),
+            &new_byte_length.map_or_else(JsValue::undefined, JsValue::from),
+            false,
+            context,
+        )?;
+
+        Ok(Self { inner })
+    }
+
     /// Take the inner `ArrayBuffer`'s `array_buffer_data` field and replace it with `None`
     ///
     /// Note: This causes the pre-existing `JsArrayBuffer` to become detached.
@@ -102,7 +102,7 @@ impl JsDataView {
             prototype,
             ObjectData::data_view(DataView {
                 viewed_array_buffer: (**array_buffer).clone(),
-                byte_length,
+                byte_length: Some(byte_length),
                 byte_offset,
             }),
         );
@@ -0,0 +1,192 @@
+//! A Rust API wrapper for Boa's `FinalizationRegistry` Builtin ECMAScript Object
+use std::ops::Deref;
+
+use boa_gc::{Finalize, Trace};
+
+use crate::{
+    builtins::weak::FinalizationRegistry,
+    error::JsNativeError,
+    object::{JsFunction, JsObject, JsObjectType},
+    value::TryFromJs,
+    Context, JsResult, JsValue,
+};
+
+/// `JsFinalizationRegistry` provides a wrapper for Boa's implementation of the ECMAScript
+/// `FinalizationRegistry` object.
+///
+/// This can be used to release native resources owned by JavaScript objects after the objects
+/// get collected by the garbage collector. The cleanup callback runs as a job on the
+/// [`JobQueue`][crate::job::JobQueue] of the context the next time its jobs are run.
+///
+/// # Examples
+///
+/// ```
+/// # use boa_engine::{
+/// #     object::{builtins::JsFinalizationRegistry, FunctionObjectBuilder, ObjectInitializer},
+/// #     property::Attribute,
+/// #     Context, JsArgs, JsResult, JsValue, NativeFunction,
+/// # };
+/// # fn main() -> JsResult<()> {
+/// let context = &mut Context::default();
+///
+/// context.register_global_property("released", JsValue::undefined(), Attribute::all());
+///
+/// // The cleanup callback receives the held value of every collected target, which can be used
+/// // to find the native resource that needs to be released.
+/// let cleanup = FunctionObjectBuilder::new(
+///     context,
+///     NativeFunction::from_fn_ptr(|_, args, context| {
+///         let handle = args.get_or_undefined(0).clone();
+///         context
+///             .global_object()
+///             .set("released", handle, true, context)?;
+///         Ok(JsValue::undefined())
+///     }),
+/// )
+/// .build();
+///
+/// let registry = JsFinalizationRegistry::new(cleanup, context);
+///
+/// let wrapper = ObjectInitializer::new(context).build();
+/// registry.register(&wrapper, 42.into(), None, context)?;
+///
+/// drop(wrapper);
+/// boa_gc::force_collect();
+/// context.run_jobs();
+///
+/// assert_eq!(
+///     context.global_object().get("released", context)?,
+///     JsValue::from(42)
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct JsFinalizationRegistry {
+    inner: JsObject,
+}
+
+impl JsFinalizationRegistry {
+    /// Creates a new `FinalizationRegistry` object that calls `cleanup_callback` with the held
+    /// value of every registered target that gets collected.
+    ///
+    /// Same as JavaScript's `new FinalizationRegistry(cleanupCallback)`.
+    #[inline]
+    pub fn new(cleanup_callback: JsFunction, context: &mut Context<'_>) -> Self {
+        let prototype = context
+            .intrinsics()
+            .constructors()
+            .finalization_registry()
+            .prototype();
+        let inner = FinalizationRegistry::create(prototype, cleanup_callback, context);
+
+        Self { inner }
+    }
+
+    /// Creates a `JsFinalizationRegistry` from a `FinalizationRegistry` [`JsObject`], if the
+    /// object is not a `FinalizationRegistry` throw a `TypeError`.
+    #[inline]
+    pub fn from_object(object: JsObject) -> JsResult<Self> {
+        if object.is_finalization_registry() {
+            Ok(Self { inner: object })
+        } else {
+            Err(JsNativeError::typ()
+                .with_message("object is not a FinalizationRegistry")
+                .into())
+        }
+    }
+
+    /// Registers `target` on the registry, calling the cleanup callback with `held_value` after
+    /// `target` gets collected.
+    ///
+    /// Same as JavaScript's `registry.register(target, heldValue, unregisterToken)`.
+    #[inline]
+    pub fn register(
+        &self,
+        target: &JsObject,
+        held_value: JsValue,
+        unregister_token: Option<&JsObject>,
+        context: &mut Context<'_>,
+    ) -> JsResult<()> {
+        FinalizationRegistry::register(
+            &self.inner.clone().into(),
+            &[
+                target.clone().into(),
+                held_value,
+                unregister_token
+                    .cloned()
+                    .map_or_else(JsValue::undefined, Into::into),
+            ],
+            context,
+        )?;
+
+        Ok(())
+    }
+
+    /// Removes all the targets registered with `unregister_token`, returning `true` if any
+    /// target was removed.
+    ///
+    /// Same as JavaScript's `registry.unregister(unregisterToken)`.
+    #[inline]
+    pub fn unregister(
+        &self,
+        unregister_token: &JsObject,
+        context: &mut Context<'_>,
+    ) -> JsResult<bool> {
+        FinalizationRegistry::unregister(
+            &self.inner.clone().into(),
+            &[unregister_token.clone().into()],
+            context,
+        )
+        .map(|removed| removed.to_boolean())
+    }
+
+    /// Calls `callback`, or the cleanup callback of the registry if `callback` is `None`, for
+    /// each of the registered targets that have already been collected.
+    ///
+    /// Same as JavaScript's `registry.cleanupSome(callback)`.
+    #[inline]
+    pub fn cleanup_some(
+        &self,
+        callback: Option<JsFunction>,
+        context: &mut Context<'_>,
+    ) -> JsResult<()> {
+        FinalizationRegistry::cleanup(&self.inner, callback, context)
+    }
+}
+
+impl From<JsFinalizationRegistry> for JsObject {
+    #[inline]
+    fn from(o: JsFinalizationRegistry) -> Self {
+        o.inner.clone()
+    }
+}
+
+impl From<JsFinalizationRegistry> for JsValue {
+    #[inline]
+    fn from(o: JsFinalizationRegistry) -> Self {
+        o.inner.clone().into()
+    }
+}
+
+impl Deref for JsFinalizationRegistry {
+    type Target = JsObject;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl JsObjectType for JsFinalizationRegistry {}
+
+impl TryFromJs for JsFinalizationRegistry {
+    fn try_from_js(value: &JsValue, _context: &mut Context<'_>) -> JsResult<Self> {
+        match value {
+            JsValue::Object(o) => Self::from_object(o.clone()),
+            _ => Err(JsNativeError::typ()
+                .with_message("value is not a FinalizationRegistry object")
+                .into()),
+        }
+    }
+}
@@ -210,6 +210,43 @@ impl JsPromise {
         (promise, resolvers)
     }
 
+    /// Creates a new pending promise and returns it together with its resolving functions.
+    ///
+    /// Equivalent to calling the [`Promise.withResolvers()`] static method on `%Promise%`, and the
+    /// same as [`JsPromise::new_pending`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # use boa_engine::{
+    /// #    object::builtins::JsPromise,
+    /// #    builtins::promise::PromiseState,
+    /// #    Context, JsValue, js_string
+    /// # };
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let context = &mut Context::default();
+    ///
+    /// let (promise, resolvers) = JsPromise::with_resolvers(context);
+    ///
+    /// assert_eq!(promise.state()?, PromiseState::Pending);
+    ///
+    /// resolvers
+    ///     .resolve
+    ///     .call(&JsValue::undefined(), &[js_string!("done").into()], context)?;
+    ///
+    /// assert_eq!(promise.state()?, PromiseState::Fulfilled(js_string!("done").into()));
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Promise.withResolvers()`]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/withResolvers
+    #[inline]
+    pub fn with_resolvers(context: &mut Context<'_>) -> (Self, ResolvingFunctions) {
+        Self::new_pending(context)
+    }
+
     /// Wraps an existing object with the `JsPromise` interface, returning `Err` if the object
     /// is not a valid promise.
     ///
@@ -131,6 +131,14 @@ impl JsRegExp {
             .map(|v| v.as_boolean().expect("value must be a bool"))
     }
 
+    /// Returns a boolean value for whether the `v` flag is present in `JsRegExp` flags
+    #[inline]
+    #[allow(clippy::missing_panics_doc)]
+    pub fn unicode_sets(&self, context: &mut Context<'_>) -> JsResult<bool> {
+        RegExp::get_unicode_sets(&self.inner.clone().into(), &[], context)
+            .map(|v| v.as_boolean().expect("value must be a bool"))
+    }
+
     /// Returns a boolean value for whether the `y` flag is present in `JsRegExp` flags
     #[inline]
     pub fn sticky(&self, context: &mut Context<'_>) -> JsResult<bool> {
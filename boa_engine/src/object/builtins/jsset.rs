@@ -141,6 +141,106 @@ impl JsSet {
         )
     }
 
+    /// Returns a new `Set` containing the elements of this set and of `other`.
+    ///
+    /// `other` can be any set-like object.
+    ///
+    /// Same as JavaScript's `set.union(other)`.
+    #[inline]
+    pub fn union(&self, other: &JsObject, context: &mut Context<'_>) -> JsResult<Self> {
+        match Set::union(&self.inner.clone().into(), &[other.clone().into()], context)? {
+            JsValue::Object(inner) => Ok(Self { inner }),
+            _ => unreachable!("`union` must always return a Set"),
+        }
+    }
+
+    /// Returns a new `Set` containing the elements that are in both this set and `other`.
+    ///
+    /// `other` can be any set-like object.
+    ///
+    /// Same as JavaScript's `set.intersection(other)`.
+    #[inline]
+    pub fn intersection(&self, other: &JsObject, context: &mut Context<'_>) -> JsResult<Self> {
+        match Set::intersection(&self.inner.clone().into(), &[other.clone().into()], context)? {
+            JsValue::Object(inner) => Ok(Self { inner }),
+            _ => unreachable!("`intersection` must always return a Set"),
+        }
+    }
+
+    /// Returns a new `Set` containing the elements of this set that are not in `other`.
+    ///
+    /// `other` can be any set-like object.
+    ///
+    /// Same as JavaScript's `set.difference(other)`.
+    #[inline]
+    pub fn difference(&self, other: &JsObject, context: &mut Context<'_>) -> JsResult<Self> {
+        match Set::difference(&self.inner.clone().into(), &[other.clone().into()], context)? {
+            JsValue::Object(inner) => Ok(Self { inner }),
+            _ => unreachable!("`difference` must always return a Set"),
+        }
+    }
+
+    /// Returns a new `Set` containing the elements that are in either this set or `other`,
+    /// but not in both.
+    ///
+    /// `other` can be any set-like object.
+    ///
+    /// Same as JavaScript's `set.symmetricDifference(other)`.
+    #[inline]
+    pub fn symmetric_difference(
+        &self,
+        other: &JsObject,
+        context: &mut Context<'_>,
+    ) -> JsResult<Self> {
+        match Set::symmetric_difference(
+            &self.inner.clone().into(),
+            &[other.clone().into()],
+            context,
+        )? {
+            JsValue::Object(inner) => Ok(Self { inner }),
+            _ => unreachable!("`symmetricDifference` must always return a Set"),
+        }
+    }
+
+    /// Returns `true` if all the elements of this set are in `other`.
+    ///
+    /// `other` can be any set-like object.
+    ///
+    /// Same as JavaScript's `set.isSubsetOf(other)`.
+    #[inline]
+    pub fn is_subset_of(&self, other: &JsObject, context: &mut Context<'_>) -> JsResult<bool> {
+        match Set::is_subset_of(&self.inner.clone().into(), &[other.clone().into()], context)? {
+            JsValue::Boolean(bool) => Ok(bool),
+            _ => unreachable!("`isSubsetOf` must always return a bool"),
+        }
+    }
+
+    /// Returns `true` if all the elements of `other` are in this set.
+    ///
+    /// `other` can be any set-like object.
+    ///
+    /// Same as JavaScript's `set.isSupersetOf(other)`.
+    #[inline]
+    pub fn is_superset_of(&self, other: &JsObject, context: &mut Context<'_>) -> JsResult<bool> {
+        match Set::is_superset_of(&self.inner.clone().into(), &[other.clone().into()], context)? {
+            JsValue::Boolean(bool) => Ok(bool),
+            _ => unreachable!("`isSupersetOf` must always return a bool"),
+        }
+    }
+
+    /// Returns `true` if this set has no elements in common with `other`.
+    ///
+    /// `other` can be any set-like object.
+    ///
+    /// Same as JavaScript's `set.isDisjointFrom(other)`.
+    #[inline]
+    pub fn is_disjoint_from(&self, other: &JsObject, context: &mut Context<'_>) -> JsResult<bool> {
+        match Set::is_disjoint_from(&self.inner.clone().into(), &[other.clone().into()], context)? {
+            JsValue::Boolean(bool) => Ok(bool),
+            _ => unreachable!("`isDisjointFrom` must always return a bool"),
+        }
+    }
+
     /// Utility: Creates `JsSet` from `JsObject`, if not a Set throw `TypeError`.
     #[inline]
     pub fn from_object(object: JsObject) -> JsResult<Self> {
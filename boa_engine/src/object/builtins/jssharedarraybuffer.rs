@@ -0,0 +1,160 @@
+//! A Rust API wrapper for Boa's `SharedArrayBuffer` Builtin ECMAScript Object
+use crate::{
+    builtins::array_buffer::SharedArrayBuffer,
+    error::JsNativeError,
+    object::{JsObject, JsObjectType, ObjectData},
+    value::TryFromJs,
+    Context, JsResult, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+use std::ops::Deref;
+
+/// `JsSharedArrayBuffer` provides a wrapper for Boa's implementation of the ECMAScript `SharedArrayBuffer` object
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct JsSharedArrayBuffer {
+    inner: JsObject,
+}
+
+impl JsSharedArrayBuffer {
+    /// Create a new shared array buffer with byte length.
+    ///
+    /// ```
+    /// # use boa_engine::{
+    /// # object::builtins::JsSharedArrayBuffer,
+    /// # Context, JsResult
+    /// # };
+    /// # fn main() -> JsResult<()> {
+    /// # // Initialize context
+    /// # let context = &mut Context::default();
+    /// // Creates a blank shared array buffer of n bytes
+    /// let buffer = JsSharedArrayBuffer::new(4, context)?;
+    ///
+    /// assert_eq!(buffer.byte_length(), 4);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn new(byte_length: usize, context: &mut Context<'_>) -> JsResult<Self> {
+        let buffer = SharedArrayBuffer::new(byte_length as u64)?;
+
+        Ok(Self::from_buffer(buffer, context))
+    }
+
+    /// Create a new shared array buffer from an existing [`SharedArrayBuffer`].
+    ///
+    /// The new object shares its data with `buffer`, which can be used to share memory between
+    /// contexts living in different threads.
+    ///
+    /// ```
+    /// # use boa_engine::{
+    /// # object::builtins::JsSharedArrayBuffer,
+    /// # property::Attribute,
+    /// # Context, JsResult, Source,
+    /// # };
+    /// # fn main() -> JsResult<()> {
+    /// let buffer = JsSharedArrayBuffer::new(4, &mut Context::default())?.inner();
+    ///
+    /// let handle = std::thread::spawn(move || {
+    ///     let context = &mut Context::default();
+    ///     let shared = JsSharedArrayBuffer::from_buffer(buffer, context);
+    ///     context
+    ///         .register_global_property("shared", shared, Attribute::all())
+    ///         .unwrap();
+    ///     context
+    ///         .eval(Source::from_bytes("Atomics.store(new Int32Array(shared), 0, 42)"))
+    ///         .unwrap();
+    /// });
+    /// # handle.join().unwrap();
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn from_buffer(buffer: SharedArrayBuffer, context: &mut Context<'_>) -> Self {
+        let proto = context
+            .intrinsics()
+            .constructors()
+            .shared_array_buffer()
+            .prototype();
+
+        let inner = JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            proto,
+            ObjectData::shared_array_buffer(buffer),
+        );
+
+        Self { inner }
+    }
+
+    /// Create a [`JsSharedArrayBuffer`] from a [`JsObject`], if the object is not a shared array buffer throw a `TypeError`.
+    ///
+    /// This does not clone the fields of the shared array buffer, it only does a shallow clone of the object.
+    #[inline]
+    pub fn from_object(object: JsObject) -> JsResult<Self> {
+        if object.is_shared_array_buffer() {
+            Ok(Self { inner: object })
+        } else {
+            Err(JsNativeError::typ()
+                .with_message("object is not a SharedArrayBuffer")
+                .into())
+        }
+    }
+
+    /// Returns the byte length of the shared array buffer.
+    #[inline]
+    #[must_use]
+    pub fn byte_length(&self) -> usize {
+        self.inner().len()
+    }
+
+    /// Gets the inner [`SharedArrayBuffer`], which can be sent to another thread and shared with
+    /// another context through [`JsSharedArrayBuffer::from_buffer`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the object is currently mutably borrowed.
+    #[inline]
+    #[must_use]
+    pub fn inner(&self) -> SharedArrayBuffer {
+        self.borrow()
+            .as_shared_array_buffer()
+            .expect("should be a shared array buffer")
+            .clone()
+    }
+}
+
+impl From<JsSharedArrayBuffer> for JsObject {
+    #[inline]
+    fn from(value: JsSharedArrayBuffer) -> Self {
+        value.inner.clone()
+    }
+}
+
+impl From<JsSharedArrayBuffer> for JsValue {
+    #[inline]
+    fn from(value: JsSharedArrayBuffer) -> Self {
+        value.inner.clone().into()
+    }
+}
+
+impl Deref for JsSharedArrayBuffer {
+    type Target = JsObject;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl JsObjectType for JsSharedArrayBuffer {}
+
+impl TryFromJs for JsSharedArrayBuffer {
+    fn try_from_js(value: &JsValue, _context: &mut Context<'_>) -> JsResult<Self> {
+        match value {
+            JsValue::Object(o) => Self::from_object(o.clone()),
+            _ => Err(JsNativeError::typ()
+                .with_message("value is not a SharedArrayBuffer object")
+                .into()),
+        }
+    }
+}
@@ -222,6 +222,38 @@ impl JsTypedArray {
         Ok(self.clone())
     }
 
+    /// Calls `TypedArray.prototype.toReversed()`.
+    #[inline]
+    pub fn to_reversed(&self, context: &mut Context<'_>) -> JsResult<Self> {
+        let object = TypedArray::to_reversed(&self.inner, &[], context)?;
+
+        Ok(Self { inner: object })
+    }
+
+    /// Calls `TypedArray.prototype.toSorted()`.
+    #[inline]
+    pub fn to_sorted(
+        &self,
+        compare_fn: Option<JsFunction>,
+        context: &mut Context<'_>,
+    ) -> JsResult<Self> {
+        let object =
+            TypedArray::to_sorted(&self.inner, &[compare_fn.into_or_undefined()], context)?;
+
+        Ok(Self { inner: object })
+    }
+
+    /// Calls `TypedArray.prototype.with()`.
+    #[inline]
+    pub fn with<T>(&self, index: i64, value: T, context: &mut Context<'_>) -> JsResult<Self>
+    where
+        T: Into<JsValue>,
+    {
+        let object = TypedArray::with(&self.inner, &[index.into(), value.into()], context)?;
+
+        Ok(Self { inner: object })
+    }
+
     /// Calls `TypedArray.prototype.slice()`.
     #[inline]
     pub fn slice(
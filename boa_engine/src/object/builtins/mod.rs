@@ -6,6 +6,7 @@ mod jsarray;
 mod jsarraybuffer;
 mod jsdataview;
 mod jsdate;
+mod jsfinalization_registry;
 mod jsfunction;
 mod jsgenerator;
 mod jsmap;
@@ -15,12 +16,14 @@ mod jsproxy;
 mod jsregexp;
 mod jsset;
 mod jsset_iterator;
+mod jssharedarraybuffer;
 mod jstypedarray;
 
 pub use jsarray::*;
 pub use jsarraybuffer::*;
 pub use jsdataview::*;
 pub use jsdate::*;
+pub use jsfinalization_registry::*;
 pub use jsfunction::*;
 pub use jsgenerator::*;
 pub use jsmap::*;
@@ -30,4 +33,5 @@ pub use jsproxy::{JsProxy, JsProxyBuilder, JsRevocableProxy};
 pub use jsregexp::JsRegExp;
 pub use jsset::*;
 pub use jsset_iterator::*;
+pub use jssharedarraybuffer::*;
 pub use jstypedarray::*;
@@ -268,12 +268,14 @@ pub(crate) fn integer_indexed_exotic_own_property_keys(
         "integer indexed exotic method should only be callable from integer indexed objects",
     );
 
-    // 1. Let keys be a new empty List.
-    let mut keys = if inner.is_detached() {
+    // 1. Let taRecord be MakeTypedArrayWithBufferWitnessRecord(O, seq-cst).
+    // 2. Let keys be a new empty List.
+    let mut keys = if inner.is_out_of_bounds() {
         vec![]
     } else {
-        // 2. If IsDetachedBuffer(O.[[ViewedArrayBuffer]]) is false, then
-        //     a. For each integer i starting with 0 such that i < O.[[ArrayLength]], in ascending order, do
+        // 3. If IsTypedArrayOutOfBounds(taRecord) is false, then
+        //     a. Let length be TypedArrayLength(taRecord).
+        //     b. For each integer i such that 0 ≤ i < length, in ascending order, do
         //         i. Add ! ToString(𝔽(i)) as the last element of keys.
         (0..inner.array_length())
             .map(|index| PropertyKey::Index(index as u32))
@@ -312,10 +314,16 @@ pub(crate) fn is_valid_integer_index(obj: &JsObject, index: u64) -> bool {
     //       once we get the index it is already converted into unsigned integer
     //       index, it cannot be `-0`.
 
-    // 4. If ℝ(index) < 0 or ℝ(index) ≥ O.[[ArrayLength]], return false.
-    // 5. Return true.
-
-    !inner.is_detached() && index < inner.array_length()
+    // 4. Let taRecord be MakeTypedArrayWithBufferWitnessRecord(O, unordered).
+    // 5. NOTE: Bounds checking is not a synchronizing operation when O's backing buffer is a growable SharedArrayBuffer.
+    // 6. If IsTypedArrayOutOfBounds(taRecord) is true, return false.
+    // 7. Let length be TypedArrayLength(taRecord).
+    // 8. If ℝ(index) < 0 or ℝ(index) ≥ length, return false.
+    // 9. Return true.
+    //
+    // NOTE: `array_length` returns 0 for detached or out of bounds typed arrays, which
+    //       covers steps 1 and 6.
+    index < inner.array_length()
 }
 
 /// Abstract operation `IntegerIndexedElementGet ( O, index )`.
@@ -339,7 +347,7 @@ fn integer_indexed_element_get(obj: &JsObject, index: u64) -> Option<JsValue> {
         .expect("Already checked for detached buffer");
     let buffer_obj_borrow = buffer_obj.borrow();
     let buffer = buffer_obj_borrow
-        .as_array_buffer()
+        .as_buffer()
         .expect("Already checked for detached buffer");
 
     // 2. Let offset be O.[[ByteOffset]].
@@ -371,18 +379,22 @@ fn integer_indexed_element_get(obj: &JsObject, index: u64) -> Option<JsValue> {
 ///  - [ECMAScript reference][spec]
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-integerindexedelementset
-fn integer_indexed_element_set(
+pub(crate) fn integer_indexed_element_set(
     obj: &JsObject,
     index: usize,
     value: &JsValue,
     context: &mut Context<'_>,
 ) -> JsResult<()> {
-    let obj_borrow = obj.borrow();
-    let inner = obj_borrow.as_typed_array().expect(
-        "integer indexed exotic method should only be callable from integer indexed objects",
-    );
+    let content_type = obj
+        .borrow()
+        .as_typed_array()
+        .expect(
+            "integer indexed exotic method should only be callable from integer indexed objects",
+        )
+        .typed_array_name()
+        .content_type();
 
-    let num_value = if inner.typed_array_name().content_type() == ContentType::BigInt {
+    let num_value = if content_type == ContentType::BigInt {
         // 1. If O.[[ContentType]] is BigInt, let numValue be ? ToBigInt(value).
         value.to_bigint(context)?.into()
     } else {
@@ -392,6 +404,11 @@ fn integer_indexed_element_set(
 
     // 3. If ! IsValidIntegerIndex(O, index) is true, then
     if is_valid_integer_index(obj, index as u64) {
+        let obj_borrow = obj.borrow();
+        let inner = obj_borrow
+            .as_typed_array()
+            .expect("Already checked for typed array");
+
         // a. Let offset be O.[[ByteOffset]].
         let offset = inner.byte_offset();
 
@@ -409,8 +426,8 @@ fn integer_indexed_element_set(
             .viewed_array_buffer()
             .expect("Already checked for detached buffer");
         let mut buffer_obj_borrow = buffer_obj.borrow_mut();
-        let buffer = buffer_obj_borrow
-            .as_array_buffer_mut()
+        let mut buffer = buffer_obj_borrow
+            .as_buffer_mut()
             .expect("Already checked for detached buffer");
 
         // f. Perform SetValueInBuffer(O.[[ViewedArrayBuffer]], indexedPosition, elementType, numValue, true, Unordered).
@@ -24,8 +24,10 @@ pub(super) mod integer_indexed;
 pub(super) mod module_namespace;
 pub(super) mod proxy;
 pub(super) mod string;
+pub(super) mod wrapped_function;
 
 pub(crate) use array::ARRAY_EXOTIC_INTERNAL_METHODS;
+pub(crate) use integer_indexed::{integer_indexed_element_set, is_valid_integer_index};
 
 impl JsObject {
     /// Internal method `[[GetPrototypeOf]]`
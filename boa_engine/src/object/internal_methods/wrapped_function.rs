@@ -0,0 +1,89 @@
+use crate::{
+    builtins::shadow_realm::{boundary_error, get_wrapped_value},
+    object::JsObject,
+    Context, JsResult, JsValue,
+};
+
+use super::{InternalObjectMethods, ORDINARY_INTERNAL_METHODS};
+
+/// Definitions of the internal object methods for wrapped function exotic objects.
+///
+/// More information:
+///  - [ShadowRealm proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-shadowrealm/#sec-wrapped-function-exotic-objects
+pub(crate) static WRAPPED_FUNCTION_EXOTIC_INTERNAL_METHODS: InternalObjectMethods =
+    InternalObjectMethods {
+        __call__: Some(wrapped_function_exotic_call),
+        ..ORDINARY_INTERNAL_METHODS
+    };
+
+/// Internal method `[[Call]]` for Wrapped Function Exotic Objects
+///
+/// More information:
+///  - [ShadowRealm proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-shadowrealm/#sec-wrapped-function-exotic-objects-call-thisargument-argumentslist
+#[track_caller]
+fn wrapped_function_exotic_call(
+    obj: &JsObject,
+    this: &JsValue,
+    arguments_list: &[JsValue],
+    context: &mut Context<'_>,
+) -> JsResult<JsValue> {
+    let (target, caller_realm) = {
+        let obj = obj.borrow();
+        let wrapped = obj.as_wrapped_function().expect(
+            "wrapped function exotic method should only be callable from wrapped function objects",
+        );
+        (wrapped.target().clone(), wrapped.realm().clone())
+    };
+
+    // 1. Let target be F.[[WrappedTargetFunction]].
+    // 2. Assert: IsCallable(target) is true.
+    // 3. Let callerContext be the running execution context.
+    // 4. NOTE: Any exception objects produced after this point are associated with callerRealm.
+    // 5. Let calleeContext be PrepareForWrappedFunctionCall(F).
+    let old_realm = context.enter_realm(caller_realm.clone());
+    let context = &mut context.guard(move |ctx| {
+        ctx.enter_realm(old_realm);
+    });
+
+    // 6. Let result be Completion(OrdinaryWrappedFunctionCall(F, thisArgument, argumentsList)).
+    let result = (|| {
+        // `OrdinaryWrappedFunctionCall ( F, thisArgument, argumentsList )`
+        // https://tc39.es/proposal-shadowrealm/#sec-ordinary-wrapped-function-call
+
+        // 1. Let target be F.[[WrappedTargetFunction]].
+        // 2. Assert: IsCallable(target) is true.
+        // 3. Let targetRealm be ? GetFunctionRealm(target).
+        let target_realm = target.get_function_realm(context)?;
+
+        // 4. Let callerRealm be ? GetFunctionRealm(F).
+        // 5. NOTE: Any exception objects produced after this point are associated with callerRealm.
+        // 6. Let wrappedArgs be a new empty List.
+        // 7. For each element arg of argumentsList, do
+        //     a. Let wrappedValue be ? GetWrappedValue(targetRealm, arg).
+        //     b. Append wrappedValue to wrappedArgs.
+        let wrapped_args = arguments_list
+            .iter()
+            .map(|arg| get_wrapped_value(&target_realm, arg, context))
+            .collect::<JsResult<Vec<_>>>()?;
+
+        // 8. Let wrappedThisArgument be ? GetWrappedValue(targetRealm, thisArgument).
+        let wrapped_this = get_wrapped_value(&target_realm, this, context)?;
+
+        // 9. Let result be ? Call(target, wrappedThisArgument, wrappedArgs).
+        let result = target.call(&wrapped_this, &wrapped_args, context)?;
+
+        // 10. Return ? GetWrappedValue(callerRealm, result).
+        get_wrapped_value(&caller_realm, &result, context)
+    })();
+
+    // 7. Remove calleeContext from the execution context stack and restore callerContext as the running execution context.
+    // 8. If result.[[Type]] is normal or result.[[Type]] is return, then
+    //     a. Return result.[[Value]].
+    // 9. Assert: result.[[Type]] is throw.
+    // 10. Throw a TypeError exception.
+    result.map_err(boundary_error)
+}
@@ -52,6 +52,19 @@ pub struct VTableObject {
     vtable: &'static InternalObjectMethods,
 }
 
+#[cfg(feature = "heap-snapshot")]
+impl VTableObject {
+    /// Returns the object of this `VTableObject`.
+    pub(crate) const fn object(&self) -> &GcRefCell<Object> {
+        &self.object
+    }
+
+    /// Returns the internal methods of the object.
+    pub(crate) const fn vtable(&self) -> &'static InternalObjectMethods {
+        self.vtable
+    }
+}
+
 impl Default for JsObject {
     fn default() -> Self {
         let data = ObjectData::ordinary();
@@ -408,6 +421,17 @@ impl JsObject {
         self.borrow().is_array_buffer()
     }
 
+    /// Checks if it's a `SharedArrayBuffer` object.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the object is currently mutably borrowed.
+    #[inline]
+    #[track_caller]
+    pub fn is_shared_array_buffer(&self) -> bool {
+        self.borrow().is_shared_array_buffer()
+    }
+
     /// Checks if it is a `Map` object.
     ///
     /// # Panics
@@ -672,6 +696,39 @@ impl JsObject {
         self.borrow().is_promise()
     }
 
+    /// Checks if it's a `FinalizationRegistry` object.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the object is currently mutably borrowed.
+    #[inline]
+    #[track_caller]
+    pub fn is_finalization_registry(&self) -> bool {
+        self.borrow().is_finalization_registry()
+    }
+
+    /// Checks if it's a `DisposableStack` object.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the object is currently mutably borrowed.
+    #[inline]
+    #[track_caller]
+    pub fn is_disposable_stack(&self) -> bool {
+        self.borrow().is_disposable_stack()
+    }
+
+    /// Checks if it's an `AsyncDisposableStack` object.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the object is currently mutably borrowed.
+    #[inline]
+    #[track_caller]
+    pub fn is_async_disposable_stack(&self) -> bool {
+        self.borrow().is_async_disposable_stack()
+    }
+
     /// Checks if it's an ordinary object.
     ///
     /// # Panics
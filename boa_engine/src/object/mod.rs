@@ -24,6 +24,7 @@ use self::{
             PROXY_EXOTIC_INTERNAL_METHODS_WITH_CALL,
         },
         string::STRING_EXOTIC_INTERNAL_METHODS,
+        wrapped_function::WRAPPED_FUNCTION_EXOTIC_INTERNAL_METHODS,
         InternalObjectMethods, ORDINARY_INTERNAL_METHODS,
     },
     shape::Shape,
@@ -32,19 +33,34 @@ use self::{
 use crate::builtins::intl::{
     collator::Collator,
     date_time_format::DateTimeFormat,
+    display_names::DisplayNames,
     list_format::ListFormat,
+    number_format::NumberFormat,
+    plural_rules::PluralRules,
+    relative_time_format::RelativeTimeFormat,
     segmenter::{SegmentIterator, Segmenter, Segments},
 };
+#[cfg(feature = "temporal")]
+use crate::builtins::temporal::{
+    Calendar, Duration, Instant, PlainDate, PlainDateTime, PlainTime, TimeZone, ZonedDateTime,
+};
 use crate::{
     builtins::{
         array::ArrayIterator,
-        array_buffer::ArrayBuffer,
+        array_buffer::{
+            utils::{BufferRef, BufferRefMut},
+            ArrayBuffer, SharedArrayBuffer,
+        },
         async_generator::AsyncGenerator,
-        error::ErrorKind,
+        disposable_stack::{AsyncDisposableStack, DisposableStack},
+        error::{ErrorKind, StackFrame},
         function::{arguments::Arguments, FunctionKind},
         function::{arguments::ParameterMap, BoundFunction, ConstructorKind, Function},
         generator::Generator,
-        iterable::AsyncFromSyncIterator,
+        iterable::{
+            AsyncFromSyncIterator, AsyncIteratorHelper, IteratorHelper, WrapForValidAsyncIterator,
+            WrapForValidIterator,
+        },
         map::ordered_map::OrderedMap,
         map::MapIterator,
         object::for_in_iterator::ForInIterator,
@@ -52,15 +68,19 @@ use crate::{
         regexp::RegExpStringIterator,
         set::ordered_set::OrderedSet,
         set::SetIterator,
+        shadow_realm::WrappedFunction,
         string::StringIterator,
         typed_array::{integer_indexed_object::IntegerIndexed, TypedArrayKind},
+        weak::FinalizationRegistry,
         DataView, Date, Promise, RegExp,
     },
     js_string,
     module::ModuleNamespace,
     native_function::NativeFunction,
     property::{Attribute, PropertyDescriptor, PropertyKey},
+    realm::Realm,
     string::utf16,
+    symbol::WeakJsSymbol,
     Context, JsBigInt, JsString, JsSymbol, JsValue,
 };
 
@@ -179,6 +199,11 @@ impl PrivateName {
     pub(crate) const fn new(description: Sym, id: usize) -> Self {
         Self { description, id }
     }
+
+    /// Returns the `[[Description]]` of the private name.
+    pub(crate) const fn description(&self) -> Sym {
+        self.description
+    }
 }
 
 /// The representation of private object elements.
@@ -237,6 +262,9 @@ pub enum ObjectKind {
     /// The `ArrayBuffer` object kind.
     ArrayBuffer(ArrayBuffer),
 
+    /// The `SharedArrayBuffer` object kind.
+    SharedArrayBuffer(SharedArrayBuffer),
+
     /// The `Map` object kind.
     Map(OrderedMap<JsValue>),
 
@@ -261,12 +289,27 @@ pub enum ObjectKind {
     /// The `ForInIterator` object kind.
     ForInIterator(ForInIterator),
 
+    /// The `IteratorHelper` object kind.
+    IteratorHelper(IteratorHelper),
+
+    /// The `WrapForValidIterator` object kind.
+    WrapForValidIterator(WrapForValidIterator),
+
+    /// The `AsyncIteratorHelper` object kind.
+    AsyncIteratorHelper(AsyncIteratorHelper),
+
+    /// The `WrapForValidAsyncIterator` object kind.
+    WrapForValidAsyncIterator(WrapForValidAsyncIterator),
+
     /// The `Function` object kind.
     Function(Function),
 
     /// The `BoundFunction` object kind.
     BoundFunction(BoundFunction),
 
+    /// The `WrappedFunction` object kind.
+    WrappedFunction(WrappedFunction),
+
     /// The `Generator` object kind.
     Generator(Generator),
 
@@ -291,8 +334,8 @@ pub enum ObjectKind {
     /// The `Symbol` object kind.
     Symbol(JsSymbol),
 
-    /// The `Error` object kind.
-    Error(ErrorKind),
+    /// The `Error` object kind, with the frames of the call stack captured when it was created.
+    Error(ErrorKind, Box<[StackFrame]>),
 
     /// The ordinary object kind.
     Ordinary,
@@ -321,12 +364,27 @@ pub enum ObjectKind {
     /// The `WeakRef` object kind.
     WeakRef(WeakGc<VTableObject>),
 
+    /// The `FinalizationRegistry` object kind.
+    FinalizationRegistry(FinalizationRegistry),
+
+    /// The `DisposableStack` object kind.
+    DisposableStack(DisposableStack),
+
+    /// The `AsyncDisposableStack` object kind.
+    AsyncDisposableStack(AsyncDisposableStack),
+
+    /// The `ShadowRealm` object kind.
+    ShadowRealm(Realm),
+
     /// The `WeakMap` object kind.
     WeakMap(boa_gc::WeakMap<VTableObject, JsValue>),
 
     /// The `WeakSet` object kind.
     WeakSet(boa_gc::WeakMap<VTableObject, ()>),
 
+    /// The object kind of the hidden object that represents a symbol in weak collections.
+    WeakSymbolKey(WeakJsSymbol),
+
     /// The `ModuleNamespace` object kind.
     ModuleNamespace(ModuleNamespace),
 
@@ -338,6 +396,10 @@ pub enum ObjectKind {
     #[cfg(feature = "intl")]
     DateTimeFormat(Box<DateTimeFormat>),
 
+    /// The `Intl.DisplayNames` object kind.
+    #[cfg(feature = "intl")]
+    DisplayNames(Box<DisplayNames>),
+
     /// The `Intl.ListFormat` object kind.
     #[cfg(feature = "intl")]
     ListFormat(Box<ListFormat>),
@@ -346,6 +408,18 @@ pub enum ObjectKind {
     #[cfg(feature = "intl")]
     Locale(Box<icu_locid::Locale>),
 
+    /// The `Intl.NumberFormat` object kind.
+    #[cfg(feature = "intl")]
+    NumberFormat(Box<NumberFormat>),
+
+    /// The `Intl.PluralRules` object kind.
+    #[cfg(feature = "intl")]
+    PluralRules(Box<PluralRules>),
+
+    /// The `Intl.RelativeTimeFormat` object kind.
+    #[cfg(feature = "intl")]
+    RelativeTimeFormat(Box<RelativeTimeFormat>),
+
     /// The `Intl.Segmenter` object kind.
     #[cfg(feature = "intl")]
     Segmenter(Segmenter),
@@ -357,6 +431,38 @@ pub enum ObjectKind {
     /// The `Segment Iterator` object kind.
     #[cfg(feature = "intl")]
     SegmentIterator(SegmentIterator),
+
+    /// The `Temporal.Calendar` object kind.
+    #[cfg(feature = "temporal")]
+    Calendar(Box<Calendar>),
+
+    /// The `Temporal.Duration` object kind.
+    #[cfg(feature = "temporal")]
+    Duration(Box<Duration>),
+
+    /// The `Temporal.Instant` object kind.
+    #[cfg(feature = "temporal")]
+    Instant(Box<Instant>),
+
+    /// The `Temporal.PlainDate` object kind.
+    #[cfg(feature = "temporal")]
+    PlainDate(Box<PlainDate>),
+
+    /// The `Temporal.PlainDateTime` object kind.
+    #[cfg(feature = "temporal")]
+    PlainDateTime(Box<PlainDateTime>),
+
+    /// The `Temporal.PlainTime` object kind.
+    #[cfg(feature = "temporal")]
+    PlainTime(Box<PlainTime>),
+
+    /// The `Temporal.TimeZone` object kind.
+    #[cfg(feature = "temporal")]
+    TimeZone(Box<TimeZone>),
+
+    /// The `Temporal.ZonedDateTime` object kind.
+    #[cfg(feature = "temporal")]
+    ZonedDateTime(Box<ZonedDateTime>),
 }
 
 unsafe impl Trace for ObjectKind {
@@ -365,13 +471,19 @@ unsafe impl Trace for ObjectKind {
             Self::AsyncFromSyncIterator(a) => mark(a),
             Self::ArrayIterator(i) => mark(i),
             Self::ArrayBuffer(b) => mark(b),
+            Self::SharedArrayBuffer(b) => mark(b),
             Self::Map(m) => mark(m),
             Self::MapIterator(i) => mark(i),
             Self::RegExpStringIterator(i) => mark(i),
             Self::DataView(v) => mark(v),
             Self::ForInIterator(i) => mark(i),
+            Self::IteratorHelper(i) => mark(i),
+            Self::WrapForValidIterator(i) => mark(i),
+            Self::AsyncIteratorHelper(i) => mark(i),
+            Self::WrapForValidAsyncIterator(i) => mark(i),
             Self::Function(f) | Self::GeneratorFunction(f) | Self::AsyncGeneratorFunction(f) => mark(f),
             Self::BoundFunction(f) => mark(f),
+            Self::WrappedFunction(f) => mark(f),
             Self::Generator(g) => mark(g),
             Self::Set(s) => mark(s),
             Self::SetIterator(i) => mark(i),
@@ -383,8 +495,13 @@ unsafe impl Trace for ObjectKind {
             Self::Promise(p) => mark(p),
             Self::AsyncGenerator(g) => mark(g),
             Self::WeakRef(wr) => mark(wr),
+            Self::FinalizationRegistry(fr) => mark(fr),
+            Self::DisposableStack(ds) => mark(ds),
+            Self::AsyncDisposableStack(ds) => mark(ds),
+            Self::ShadowRealm(r) => mark(r),
             Self::WeakMap(wm) => mark(wm),
             Self::WeakSet(ws) => mark(ws),
+            Self::WeakSymbolKey(s) => mark(s),
             Self::ModuleNamespace(m) => mark(m),
             #[cfg(feature = "intl")]
             Self::DateTimeFormat(f) => mark(f),
@@ -395,18 +512,35 @@ unsafe impl Trace for ObjectKind {
             #[cfg(feature = "intl")]
             Self::SegmentIterator(it) => mark(it),
             #[cfg(feature = "intl")]
-            Self::ListFormat(_) | Self::Locale(_) | Self::Segmenter(_) => {}
+            Self::NumberFormat(nf) => mark(nf),
+            #[cfg(feature = "intl")]
+            Self::RelativeTimeFormat(rtf) => mark(rtf),
+            #[cfg(feature = "intl")]
+            Self::DisplayNames(_)
+            | Self::ListFormat(_)
+            | Self::Locale(_)
+            | Self::PluralRules(_)
+            | Self::Segmenter(_) => {}
+            #[cfg(feature = "temporal")]
+            Self::Calendar(_)
+            | Self::Duration(_)
+            | Self::Instant(_)
+            | Self::PlainDate(_)
+            | Self::PlainDateTime(_)
+            | Self::PlainTime(_)
+            | Self::TimeZone(_)
+            | Self::ZonedDateTime(_) => {}
             Self::RegExp(_)
             | Self::BigInt(_)
             | Self::Boolean(_)
             | Self::String(_)
             | Self::Date(_)
             | Self::Array
-            | Self::Error(_)
             | Self::Ordinary
             | Self::Global
-            | Self::Number(_)
-            | Self::Symbol(_) => {}
+            | Self::Number(_) => {}
+            Self::Symbol(s) => mark(s),
+            Self::Error(_, stack) => mark(stack),
         }
     }}
 }
@@ -469,6 +603,15 @@ impl ObjectData {
         }
     }
 
+    /// Create the `SharedArrayBuffer` object data
+    #[must_use]
+    pub fn shared_array_buffer(shared_array_buffer: SharedArrayBuffer) -> Self {
+        Self {
+            kind: ObjectKind::SharedArrayBuffer(shared_array_buffer),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
     /// Create the `Map` object data
     #[must_use]
     pub fn map(map: OrderedMap<JsValue>) -> Self {
@@ -537,6 +680,40 @@ impl ObjectData {
         }
     }
 
+    /// Create the `IteratorHelper` object data
+    pub fn iterator_helper(iterator_helper: IteratorHelper) -> Self {
+        Self {
+            kind: ObjectKind::IteratorHelper(iterator_helper),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `WrapForValidIterator` object data
+    pub fn wrap_for_valid_iterator(wrap_for_valid_iterator: WrapForValidIterator) -> Self {
+        Self {
+            kind: ObjectKind::WrapForValidIterator(wrap_for_valid_iterator),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `AsyncIteratorHelper` object data
+    pub fn async_iterator_helper(async_iterator_helper: AsyncIteratorHelper) -> Self {
+        Self {
+            kind: ObjectKind::AsyncIteratorHelper(async_iterator_helper),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `WrapForValidAsyncIterator` object data
+    pub fn wrap_for_valid_async_iterator(
+        wrap_for_valid_async_iterator: WrapForValidAsyncIterator,
+    ) -> Self {
+        Self {
+            kind: ObjectKind::WrapForValidAsyncIterator(wrap_for_valid_async_iterator),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
     /// Create the `ForInIterator` object data
     pub fn for_in_iterator(for_in_iterator: ForInIterator) -> Self {
         Self {
@@ -569,6 +746,15 @@ impl ObjectData {
         }
     }
 
+    /// Create the `WrappedFunction` object data
+    #[must_use]
+    pub fn wrapped_function(wrapped_function: WrappedFunction) -> Self {
+        Self {
+            kind: ObjectKind::WrappedFunction(wrapped_function),
+            internal_methods: &WRAPPED_FUNCTION_EXOTIC_INTERNAL_METHODS,
+        }
+    }
+
     /// Create the `Generator` object data
     pub fn generator(generator: Generator) -> Self {
         Self {
@@ -639,9 +825,9 @@ impl ObjectData {
     }
 
     /// Create the `Error` object data
-    pub(crate) fn error(error: ErrorKind) -> Self {
+    pub(crate) fn error(error: ErrorKind, stack: Box<[StackFrame]>) -> Self {
         Self {
-            kind: ObjectKind::Error(error),
+            kind: ObjectKind::Error(error, stack),
             internal_methods: &ORDINARY_INTERNAL_METHODS,
         }
     }
@@ -698,6 +884,42 @@ impl ObjectData {
         }
     }
 
+    /// Creates the `FinalizationRegistry` object data
+    #[must_use]
+    pub fn finalization_registry(finalization_registry: FinalizationRegistry) -> Self {
+        Self {
+            kind: ObjectKind::FinalizationRegistry(finalization_registry),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Creates the `DisposableStack` object data
+    #[must_use]
+    pub fn disposable_stack(disposable_stack: DisposableStack) -> Self {
+        Self {
+            kind: ObjectKind::DisposableStack(disposable_stack),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Creates the `AsyncDisposableStack` object data
+    #[must_use]
+    pub fn async_disposable_stack(async_disposable_stack: AsyncDisposableStack) -> Self {
+        Self {
+            kind: ObjectKind::AsyncDisposableStack(async_disposable_stack),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Creates the `ShadowRealm` object data
+    #[must_use]
+    pub fn shadow_realm(realm: Realm) -> Self {
+        Self {
+            kind: ObjectKind::ShadowRealm(realm),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
     /// Create the `WeakMap` object data
     #[must_use]
     pub fn weak_map(weak_map: boa_gc::WeakMap<VTableObject, JsValue>) -> Self {
@@ -716,6 +938,15 @@ impl ObjectData {
         }
     }
 
+    /// Create the data of the object that represents a symbol in weak collections
+    #[must_use]
+    pub fn weak_symbol_key(symbol: WeakJsSymbol) -> Self {
+        Self {
+            kind: ObjectKind::WeakSymbolKey(symbol),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
     /// Create the `NativeObject` object data
     #[must_use]
     pub fn native_object<T: NativeObject>(native_object: T) -> Self {
@@ -773,6 +1004,46 @@ impl ObjectData {
         }
     }
 
+    /// Create the `DisplayNames` object data
+    #[cfg(feature = "intl")]
+    #[must_use]
+    pub fn display_names(display_names: DisplayNames) -> Self {
+        Self {
+            kind: ObjectKind::DisplayNames(Box::new(display_names)),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `NumberFormat` object data
+    #[cfg(feature = "intl")]
+    #[must_use]
+    pub fn number_format(number_format: NumberFormat) -> Self {
+        Self {
+            kind: ObjectKind::NumberFormat(Box::new(number_format)),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `PluralRules` object data
+    #[cfg(feature = "intl")]
+    #[must_use]
+    pub fn plural_rules(plural_rules: PluralRules) -> Self {
+        Self {
+            kind: ObjectKind::PluralRules(Box::new(plural_rules)),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `RelativeTimeFormat` object data
+    #[cfg(feature = "intl")]
+    #[must_use]
+    pub fn relative_time_format(relative_time_format: RelativeTimeFormat) -> Self {
+        Self {
+            kind: ObjectKind::RelativeTimeFormat(Box::new(relative_time_format)),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
     /// Create the `Locale` object data
     #[cfg(feature = "intl")]
     #[must_use]
@@ -812,6 +1083,86 @@ impl ObjectData {
             internal_methods: &ORDINARY_INTERNAL_METHODS,
         }
     }
+
+    /// Create the `Temporal.Calendar` object data
+    #[cfg(feature = "temporal")]
+    #[must_use]
+    pub fn calendar(calendar: Calendar) -> Self {
+        Self {
+            kind: ObjectKind::Calendar(Box::new(calendar)),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `Temporal.Duration` object data
+    #[cfg(feature = "temporal")]
+    #[must_use]
+    pub fn duration(duration: Duration) -> Self {
+        Self {
+            kind: ObjectKind::Duration(Box::new(duration)),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `Temporal.Instant` object data
+    #[cfg(feature = "temporal")]
+    #[must_use]
+    pub fn instant(instant: Instant) -> Self {
+        Self {
+            kind: ObjectKind::Instant(Box::new(instant)),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `Temporal.PlainDate` object data
+    #[cfg(feature = "temporal")]
+    #[must_use]
+    pub fn plain_date(plain_date: PlainDate) -> Self {
+        Self {
+            kind: ObjectKind::PlainDate(Box::new(plain_date)),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `Temporal.PlainDateTime` object data
+    #[cfg(feature = "temporal")]
+    #[must_use]
+    pub fn plain_date_time(plain_date_time: PlainDateTime) -> Self {
+        Self {
+            kind: ObjectKind::PlainDateTime(Box::new(plain_date_time)),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `Temporal.PlainTime` object data
+    #[cfg(feature = "temporal")]
+    #[must_use]
+    pub fn plain_time(plain_time: PlainTime) -> Self {
+        Self {
+            kind: ObjectKind::PlainTime(Box::new(plain_time)),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `Temporal.TimeZone` object data
+    #[cfg(feature = "temporal")]
+    #[must_use]
+    pub fn time_zone(time_zone: TimeZone) -> Self {
+        Self {
+            kind: ObjectKind::TimeZone(Box::new(time_zone)),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `Temporal.ZonedDateTime` object data
+    #[cfg(feature = "temporal")]
+    #[must_use]
+    pub fn zoned_date_time(zoned_date_time: ZonedDateTime) -> Self {
+        Self {
+            kind: ObjectKind::ZonedDateTime(Box::new(zoned_date_time)),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
 }
 
 impl Debug for ObjectKind {
@@ -823,9 +1174,15 @@ impl Debug for ObjectKind {
             Self::Array => "Array",
             Self::ArrayIterator(_) => "ArrayIterator",
             Self::ArrayBuffer(_) => "ArrayBuffer",
+            Self::SharedArrayBuffer(_) => "SharedArrayBuffer",
             Self::ForInIterator(_) => "ForInIterator",
+            Self::IteratorHelper(_) => "IteratorHelper",
+            Self::WrapForValidIterator(_) => "WrapForValidIterator",
+            Self::AsyncIteratorHelper(_) => "AsyncIteratorHelper",
+            Self::WrapForValidAsyncIterator(_) => "WrapForValidAsyncIterator",
             Self::Function(_) => "Function",
             Self::BoundFunction(_) => "BoundFunction",
+            Self::WrappedFunction(_) => "WrappedFunction",
             Self::Generator(_) => "Generator",
             Self::GeneratorFunction(_) => "GeneratorFunction",
             Self::RegExp(_) => "RegExp",
@@ -837,7 +1194,7 @@ impl Debug for ObjectKind {
             Self::String(_) => "String",
             Self::StringIterator(_) => "StringIterator",
             Self::Symbol(_) => "Symbol",
-            Self::Error(_) => "Error",
+            Self::Error(..) => "Error",
             Self::Ordinary => "Ordinary",
             Self::Proxy(_) => "Proxy",
             Self::Boolean(_) => "Boolean",
@@ -851,23 +1208,52 @@ impl Debug for ObjectKind {
             Self::DataView(_) => "DataView",
             Self::Promise(_) => "Promise",
             Self::WeakRef(_) => "WeakRef",
+            Self::FinalizationRegistry(_) => "FinalizationRegistry",
+            Self::DisposableStack(_) => "DisposableStack",
+            Self::AsyncDisposableStack(_) => "AsyncDisposableStack",
+            Self::ShadowRealm(_) => "ShadowRealm",
             Self::WeakMap(_) => "WeakMap",
             Self::WeakSet(_) => "WeakSet",
+            Self::WeakSymbolKey(_) => "WeakSymbolKey",
             Self::ModuleNamespace(_) => "ModuleNamespace",
             #[cfg(feature = "intl")]
             Self::Collator(_) => "Collator",
             #[cfg(feature = "intl")]
             Self::DateTimeFormat(_) => "DateTimeFormat",
             #[cfg(feature = "intl")]
+            Self::DisplayNames(_) => "DisplayNames",
+            #[cfg(feature = "intl")]
             Self::ListFormat(_) => "ListFormat",
             #[cfg(feature = "intl")]
             Self::Locale(_) => "Locale",
             #[cfg(feature = "intl")]
+            Self::NumberFormat(_) => "NumberFormat",
+            #[cfg(feature = "intl")]
+            Self::PluralRules(_) => "PluralRules",
+            #[cfg(feature = "intl")]
+            Self::RelativeTimeFormat(_) => "RelativeTimeFormat",
+            #[cfg(feature = "intl")]
             Self::Segmenter(_) => "Segmenter",
             #[cfg(feature = "intl")]
             Self::Segments(_) => "Segments",
             #[cfg(feature = "intl")]
             Self::SegmentIterator(_) => "SegmentIterator",
+            #[cfg(feature = "temporal")]
+            Self::Calendar(_) => "Calendar",
+            #[cfg(feature = "temporal")]
+            Self::Duration(_) => "Duration",
+            #[cfg(feature = "temporal")]
+            Self::Instant(_) => "Instant",
+            #[cfg(feature = "temporal")]
+            Self::PlainDate(_) => "PlainDate",
+            #[cfg(feature = "temporal")]
+            Self::PlainDateTime(_) => "PlainDateTime",
+            #[cfg(feature = "temporal")]
+            Self::PlainTime(_) => "PlainTime",
+            #[cfg(feature = "temporal")]
+            Self::TimeZone(_) => "TimeZone",
+            #[cfg(feature = "temporal")]
+            Self::ZonedDateTime(_) => "ZonedDateTime",
         })
     }
 }
@@ -971,6 +1357,41 @@ impl Object {
         }
     }
 
+    /// Checks if the object is a `SharedArrayBuffer` object.
+    #[inline]
+    pub const fn is_shared_array_buffer(&self) -> bool {
+        matches!(self.kind, ObjectKind::SharedArrayBuffer(_))
+    }
+
+    /// Gets the shared array buffer data if the object is a `SharedArrayBuffer`.
+    #[inline]
+    pub const fn as_shared_array_buffer(&self) -> Option<&SharedArrayBuffer> {
+        match &self.kind {
+            ObjectKind::SharedArrayBuffer(buffer) => Some(buffer),
+            _ => None,
+        }
+    }
+
+    /// Gets the buffer data if the object is an `ArrayBuffer` or a `SharedArrayBuffer`.
+    #[inline]
+    pub(crate) const fn as_buffer(&self) -> Option<BufferRef<'_>> {
+        match &self.kind {
+            ObjectKind::ArrayBuffer(buffer) => Some(BufferRef::Buffer(buffer)),
+            ObjectKind::SharedArrayBuffer(buffer) => Some(BufferRef::SharedBuffer(buffer)),
+            _ => None,
+        }
+    }
+
+    /// Gets the mutable buffer data if the object is an `ArrayBuffer` or a `SharedArrayBuffer`.
+    #[inline]
+    pub(crate) fn as_buffer_mut(&mut self) -> Option<BufferRefMut<'_>> {
+        match &mut self.kind {
+            ObjectKind::ArrayBuffer(buffer) => Some(BufferRefMut::Buffer(buffer)),
+            ObjectKind::SharedArrayBuffer(buffer) => Some(BufferRefMut::SharedBuffer(buffer)),
+            _ => None,
+        }
+    }
+
     /// Checks if the object is a `ArrayIterator` object.
     #[inline]
     pub const fn is_array_iterator(&self) -> bool {
@@ -1031,6 +1452,60 @@ impl Object {
         }
     }
 
+    /// Gets the `IteratorHelper` data if the object is a `IteratorHelper`.
+    #[inline]
+    pub const fn as_iterator_helper(&self) -> Option<&IteratorHelper> {
+        match &self.kind {
+            ObjectKind::IteratorHelper(data) => Some(data),
+            _ => None,
+        }
+    }
+
+    /// Gets the mutable `IteratorHelper` data if the object is a `IteratorHelper`.
+    #[inline]
+    pub fn as_iterator_helper_mut(&mut self) -> Option<&mut IteratorHelper> {
+        match &mut self.kind {
+            ObjectKind::IteratorHelper(data) => Some(data),
+            _ => None,
+        }
+    }
+
+    /// Gets the `WrapForValidIterator` data if the object is a `WrapForValidIterator`.
+    #[inline]
+    pub const fn as_wrap_for_valid_iterator(&self) -> Option<&WrapForValidIterator> {
+        match &self.kind {
+            ObjectKind::WrapForValidIterator(data) => Some(data),
+            _ => None,
+        }
+    }
+
+    /// Gets the `AsyncIteratorHelper` data if the object is a `AsyncIteratorHelper`.
+    #[inline]
+    pub const fn as_async_iterator_helper(&self) -> Option<&AsyncIteratorHelper> {
+        match &self.kind {
+            ObjectKind::AsyncIteratorHelper(data) => Some(data),
+            _ => None,
+        }
+    }
+
+    /// Gets the mutable `AsyncIteratorHelper` data if the object is a `AsyncIteratorHelper`.
+    #[inline]
+    pub fn as_async_iterator_helper_mut(&mut self) -> Option<&mut AsyncIteratorHelper> {
+        match &mut self.kind {
+            ObjectKind::AsyncIteratorHelper(data) => Some(data),
+            _ => None,
+        }
+    }
+
+    /// Gets the `WrapForValidAsyncIterator` data if the object is a `WrapForValidAsyncIterator`.
+    #[inline]
+    pub const fn as_wrap_for_valid_async_iterator(&self) -> Option<&WrapForValidAsyncIterator> {
+        match &self.kind {
+            ObjectKind::WrapForValidAsyncIterator(data) => Some(data),
+            _ => None,
+        }
+    }
+
     /// Checks if the object is a `Map` object.
     #[inline]
     pub const fn is_map(&self) -> bool {
@@ -1169,6 +1644,15 @@ impl Object {
         }
     }
 
+    /// Gets the wrapped function data if the object is a `WrappedFunction`.
+    #[inline]
+    pub const fn as_wrapped_function(&self) -> Option<&WrappedFunction> {
+        match self.kind {
+            ObjectKind::WrappedFunction(ref wrapped_function) => Some(wrapped_function),
+            _ => None,
+        }
+    }
+
     /// Checks if the object is a `Generator` object.
     #[inline]
     pub const fn is_generator(&self) -> bool {
@@ -1211,14 +1695,14 @@ impl Object {
     /// Checks if the object is a `Error` object.
     #[inline]
     pub const fn is_error(&self) -> bool {
-        matches!(self.kind, ObjectKind::Error(_))
+        matches!(self.kind, ObjectKind::Error(..))
     }
 
     /// Gets the error data if the object is a `Error`.
     #[inline]
     pub const fn as_error(&self) -> Option<ErrorKind> {
         match self.kind {
-            ObjectKind::Error(e) => Some(e),
+            ObjectKind::Error(e, _) => Some(e),
             _ => None,
         }
     }
@@ -1521,6 +2005,16 @@ impl Object {
         }
     }
 
+    /// Gets the data of this object if the object is the weak key of a symbol.
+    #[inline]
+    #[must_use]
+    pub const fn as_weak_symbol_key(&self) -> Option<&WeakJsSymbol> {
+        match self.kind {
+            ObjectKind::WeakSymbolKey(ref symbol) => Some(symbol),
+            _ => None,
+        }
+    }
+
     /// Gets the prototype instance of this object.
     #[inline]
     pub fn prototype(&self) -> JsPrototype {
@@ -1593,6 +2087,87 @@ impl Object {
         }
     }
 
+    /// Checks if it is a `FinalizationRegistry` object.
+    #[inline]
+    pub const fn is_finalization_registry(&self) -> bool {
+        matches!(self.kind, ObjectKind::FinalizationRegistry(_))
+    }
+
+    /// Gets the `FinalizationRegistry` data if the object is a `FinalizationRegistry`.
+    #[inline]
+    pub const fn as_finalization_registry(&self) -> Option<&FinalizationRegistry> {
+        match self.kind {
+            ObjectKind::FinalizationRegistry(ref registry) => Some(registry),
+            _ => None,
+        }
+    }
+
+    /// Gets the mutable `FinalizationRegistry` data if the object is a `FinalizationRegistry`.
+    #[inline]
+    pub fn as_finalization_registry_mut(&mut self) -> Option<&mut FinalizationRegistry> {
+        match self.kind {
+            ObjectKind::FinalizationRegistry(ref mut registry) => Some(registry),
+            _ => None,
+        }
+    }
+
+    /// Checks if it is a `DisposableStack` object.
+    #[inline]
+    pub const fn is_disposable_stack(&self) -> bool {
+        matches!(self.kind, ObjectKind::DisposableStack(_))
+    }
+
+    /// Gets the `DisposableStack` data if the object is a `DisposableStack`.
+    #[inline]
+    pub const fn as_disposable_stack(&self) -> Option<&DisposableStack> {
+        match self.kind {
+            ObjectKind::DisposableStack(ref stack) => Some(stack),
+            _ => None,
+        }
+    }
+
+    /// Gets the mutable `DisposableStack` data if the object is a `DisposableStack`.
+    #[inline]
+    pub fn as_disposable_stack_mut(&mut self) -> Option<&mut DisposableStack> {
+        match self.kind {
+            ObjectKind::DisposableStack(ref mut stack) => Some(stack),
+            _ => None,
+        }
+    }
+
+    /// Checks if it is an `AsyncDisposableStack` object.
+    #[inline]
+    pub const fn is_async_disposable_stack(&self) -> bool {
+        matches!(self.kind, ObjectKind::AsyncDisposableStack(_))
+    }
+
+    /// Gets the `AsyncDisposableStack` data if the object is an `AsyncDisposableStack`.
+    #[inline]
+    pub const fn as_async_disposable_stack(&self) -> Option<&AsyncDisposableStack> {
+        match self.kind {
+            ObjectKind::AsyncDisposableStack(ref stack) => Some(stack),
+            _ => None,
+        }
+    }
+
+    /// Gets the mutable `AsyncDisposableStack` data if the object is an `AsyncDisposableStack`.
+    #[inline]
+    pub fn as_async_disposable_stack_mut(&mut self) -> Option<&mut AsyncDisposableStack> {
+        match self.kind {
+            ObjectKind::AsyncDisposableStack(ref mut stack) => Some(stack),
+            _ => None,
+        }
+    }
+
+    /// Gets the realm of the `ShadowRealm` if the object is a `ShadowRealm`.
+    #[inline]
+    pub const fn as_shadow_realm(&self) -> Option<&Realm> {
+        match self.kind {
+            ObjectKind::ShadowRealm(ref realm) => Some(realm),
+            _ => None,
+        }
+    }
+
     /// Gets a reference to the module namespace if the object is a `ModuleNamespace`.
     #[inline]
     pub const fn as_module_namespace(&self) -> Option<&ModuleNamespace> {
@@ -1658,6 +2233,76 @@ impl Object {
         }
     }
 
+    /// Gets the `NumberFormat` data if the object is a `NumberFormat`.
+    #[inline]
+    #[cfg(feature = "intl")]
+    pub const fn as_number_format(&self) -> Option<&NumberFormat> {
+        match self.kind {
+            ObjectKind::NumberFormat(ref nf) => Some(nf),
+            _ => None,
+        }
+    }
+
+    /// Gets a mutable reference to the `NumberFormat` data if the object is a `NumberFormat`.
+    #[inline]
+    #[cfg(feature = "intl")]
+    pub fn as_number_format_mut(&mut self) -> Option<&mut NumberFormat> {
+        match &mut self.kind {
+            ObjectKind::NumberFormat(nf) => Some(nf),
+            _ => None,
+        }
+    }
+
+    /// Gets the `DateTimeFormat` data if the object is a `DateTimeFormat`.
+    #[inline]
+    #[cfg(feature = "intl")]
+    pub const fn as_date_time_format(&self) -> Option<&DateTimeFormat> {
+        match self.kind {
+            ObjectKind::DateTimeFormat(ref dtf) => Some(dtf),
+            _ => None,
+        }
+    }
+
+    /// Gets a mutable reference to the `DateTimeFormat` data if the object is a `DateTimeFormat`.
+    #[inline]
+    #[cfg(feature = "intl")]
+    pub fn as_date_time_format_mut(&mut self) -> Option<&mut DateTimeFormat> {
+        match &mut self.kind {
+            ObjectKind::DateTimeFormat(dtf) => Some(dtf),
+            _ => None,
+        }
+    }
+
+    /// Gets the `DisplayNames` data if the object is a `DisplayNames`.
+    #[inline]
+    #[cfg(feature = "intl")]
+    pub const fn as_display_names(&self) -> Option<&DisplayNames> {
+        match self.kind {
+            ObjectKind::DisplayNames(ref dn) => Some(dn),
+            _ => None,
+        }
+    }
+
+    /// Gets the `PluralRules` data if the object is a `PluralRules`.
+    #[inline]
+    #[cfg(feature = "intl")]
+    pub const fn as_plural_rules(&self) -> Option<&PluralRules> {
+        match self.kind {
+            ObjectKind::PluralRules(ref pr) => Some(pr),
+            _ => None,
+        }
+    }
+
+    /// Gets the `RelativeTimeFormat` data if the object is a `RelativeTimeFormat`.
+    #[inline]
+    #[cfg(feature = "intl")]
+    pub const fn as_relative_time_format(&self) -> Option<&RelativeTimeFormat> {
+        match self.kind {
+            ObjectKind::RelativeTimeFormat(ref rtf) => Some(rtf),
+            _ => None,
+        }
+    }
+
     /// Checks if it is a `Segmenter` object.
     #[inline]
     #[cfg(feature = "intl")]
@@ -1695,6 +2340,86 @@ impl Object {
         }
     }
 
+    /// Gets the `Temporal.Calendar` data if the object is a `Temporal.Calendar`.
+    #[inline]
+    #[cfg(feature = "temporal")]
+    pub const fn as_calendar(&self) -> Option<&Calendar> {
+        match self.kind {
+            ObjectKind::Calendar(ref c) => Some(c),
+            _ => None,
+        }
+    }
+
+    /// Gets the `Temporal.Duration` data if the object is a `Temporal.Duration`.
+    #[inline]
+    #[cfg(feature = "temporal")]
+    pub const fn as_duration(&self) -> Option<&Duration> {
+        match self.kind {
+            ObjectKind::Duration(ref d) => Some(d),
+            _ => None,
+        }
+    }
+
+    /// Gets the `Temporal.Instant` data if the object is a `Temporal.Instant`.
+    #[inline]
+    #[cfg(feature = "temporal")]
+    pub const fn as_instant(&self) -> Option<&Instant> {
+        match self.kind {
+            ObjectKind::Instant(ref i) => Some(i),
+            _ => None,
+        }
+    }
+
+    /// Gets the `Temporal.PlainDate` data if the object is a `Temporal.PlainDate`.
+    #[inline]
+    #[cfg(feature = "temporal")]
+    pub const fn as_plain_date(&self) -> Option<&PlainDate> {
+        match self.kind {
+            ObjectKind::PlainDate(ref pd) => Some(pd),
+            _ => None,
+        }
+    }
+
+    /// Gets the `Temporal.PlainDateTime` data if the object is a `Temporal.PlainDateTime`.
+    #[inline]
+    #[cfg(feature = "temporal")]
+    pub const fn as_plain_date_time(&self) -> Option<&PlainDateTime> {
+        match self.kind {
+            ObjectKind::PlainDateTime(ref pdt) => Some(pdt),
+            _ => None,
+        }
+    }
+
+    /// Gets the `Temporal.PlainTime` data if the object is a `Temporal.PlainTime`.
+    #[inline]
+    #[cfg(feature = "temporal")]
+    pub const fn as_plain_time(&self) -> Option<&PlainTime> {
+        match self.kind {
+            ObjectKind::PlainTime(ref pt) => Some(pt),
+            _ => None,
+        }
+    }
+
+    /// Gets the `Temporal.TimeZone` data if the object is a `Temporal.TimeZone`.
+    #[inline]
+    #[cfg(feature = "temporal")]
+    pub const fn as_time_zone(&self) -> Option<&TimeZone> {
+        match self.kind {
+            ObjectKind::TimeZone(ref tz) => Some(tz),
+            _ => None,
+        }
+    }
+
+    /// Gets the `Temporal.ZonedDateTime` data if the object is a `Temporal.ZonedDateTime`.
+    #[inline]
+    #[cfg(feature = "temporal")]
+    pub const fn as_zoned_date_time(&self) -> Option<&ZonedDateTime> {
+        match self.kind {
+            ObjectKind::ZonedDateTime(ref zdt) => Some(zdt),
+            _ => None,
+        }
+    }
+
     /// Return `true` if it is a native object and the native type is `T`.
     pub fn is<T>(&self) -> bool
     where
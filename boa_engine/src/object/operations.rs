@@ -684,6 +684,10 @@ impl JsObject {
             return fun.get_function_realm(context);
         }
 
+        if let Some(wrapped) = constructor.as_wrapped_function() {
+            return Ok(wrapped.realm().clone());
+        }
+
         if let Some(proxy) = constructor.as_proxy() {
             let (fun, _) = proxy.try_data()?;
             drop(constructor);
@@ -937,26 +941,33 @@ impl JsObject {
         context: &mut Context<'_>,
     ) -> JsResult<()> {
         // 2. Let initializer be fieldRecord.[[Initializer]].
-        let initializer = match field_record {
-            ClassFieldDefinition::Public(_, function)
-            | ClassFieldDefinition::Private(_, function) => function,
+        let (initializer, initializers) = match field_record {
+            ClassFieldDefinition::Public(_, function, initializers)
+            | ClassFieldDefinition::Private(_, function, initializers) => (function, initializers),
         };
 
         // 3. If initializer is not empty, then
         // a. Let initValue be ? Call(initializer, receiver).
         // 4. Else, let initValue be undefined.
-        let init_value = initializer.call(&self.clone().into(), &[], context)?;
+        let mut init_value = initializer.call(&self.clone().into(), &[], context)?;
+
+        // Decorators proposal:
+        // 5. For each element initializer of fieldRecord.[[Initializers]], do
+        for initializer in initializers {
+            // a. Set initValue to ? Call(initializer, receiver, « initValue »).
+            init_value = initializer.call(&self.clone().into(), &[init_value], context)?;
+        }
 
         match field_record {
             // 1. Let fieldName be fieldRecord.[[Name]].
             // 5. If fieldName is a Private Name, then
-            ClassFieldDefinition::Private(field_name, _) => {
+            ClassFieldDefinition::Private(field_name, _, _) => {
                 // a. Perform ? PrivateFieldAdd(receiver, fieldName, initValue).
                 self.private_field_add(field_name, init_value, context)?;
             }
             // 1. Let fieldName be fieldRecord.[[Name]].
             // 6. Else,
-            ClassFieldDefinition::Public(field_name, _) => {
+            ClassFieldDefinition::Public(field_name, _, _) => {
                 // a. Assert: IsPropertyKey(fieldName) is true.
                 // b. Perform ? CreateDataPropertyOrThrow(receiver, fieldName, initValue).
                 self.create_data_property_or_throw(field_name.clone(), init_value, context)?;
@@ -980,21 +991,39 @@ impl JsObject {
         constructor: &Self,
         context: &mut Context<'_>,
     ) -> JsResult<()> {
-        let constructor_borrow = constructor.borrow();
-        let constructor_function = constructor_borrow
-            .as_function()
-            .expect("class constructor must be function object");
+        let (methods, initializers, fields) = {
+            let constructor_borrow = constructor.borrow();
+            let constructor_function = constructor_borrow
+                .as_function()
+                .expect("class constructor must be function object");
+            (
+                constructor_function.get_private_methods().to_vec(),
+                constructor_function
+                    .decorators()
+                    .map(|decorators| decorators.instance_initializers.clone())
+                    .unwrap_or_default(),
+                constructor_function.get_fields().to_vec(),
+            )
+        };
 
         // 1. Let methods be the value of constructor.[[PrivateMethods]].
         // 2. For each PrivateElement method of methods, do
-        for (name, method) in constructor_function.get_private_methods() {
+        for (name, method) in &methods {
             // a. Perform ? PrivateMethodOrAccessorAdd(O, method).
             self.private_method_or_accessor_add(name, method, context)?;
         }
 
+        // Decorators proposal:
+        // 3. Let initializers be the value of constructor.[[Initializers]].
+        // 4. For each element initializer of initializers, do
+        for initializer in &initializers {
+            // a. Perform ? Call(initializer, O).
+            initializer.call(&self.clone().into(), &[], context)?;
+        }
+
         // 3. Let fields be the value of constructor.[[Fields]].
         // 4. For each element fieldRecord of fields, do
-        for field_record in constructor_function.get_fields() {
+        for field_record in &fields {
             // a. Perform ? DefineField(O, fieldRecord).
             self.define_field(field_record, context)?;
         }
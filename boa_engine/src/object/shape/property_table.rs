@@ -1,5 +1,6 @@
 use std::{cell::RefCell, rc::Rc};
 
+use boa_gc::{Finalize, Trace};
 use rustc_hash::FxHashMap;
 
 use crate::{
@@ -8,12 +9,44 @@ use crate::{
 };
 
 /// The internal representation of [`PropertyTable`].
-#[derive(Default, Debug, Clone)]
+///
+/// The symbols of the keys are not rooted, and are traced through the shapes that own the table
+/// instead, so that they don't keep the weak keys of the symbols alive.
+#[derive(Default, Debug)]
 pub(crate) struct PropertyTableInner {
     pub(crate) map: FxHashMap<PropertyKey, (u32, Slot)>,
     pub(crate) keys: Vec<(PropertyKey, Slot)>,
 }
 
+impl Clone for PropertyTableInner {
+    fn clone(&self) -> Self {
+        self.clone_count(self.keys.len() as u32)
+    }
+}
+
+impl Finalize for PropertyTableInner {}
+
+// SAFETY: The symbols of the keys are unrooted when they are inserted, so they only need to
+//         be traced.
+unsafe impl Trace for PropertyTableInner {
+    unsafe fn trace(&self) {
+        for (key, _) in &self.keys {
+            if let PropertyKey::Symbol(symbol) = key {
+                // SAFETY: The symbol is owned by the table.
+                unsafe { symbol.trace() }
+            }
+        }
+    }
+
+    unsafe fn root(&self) {}
+
+    unsafe fn unroot(&self) {}
+
+    fn run_finalizer(&self) {
+        Finalize::finalize(self);
+    }
+}
+
 impl PropertyTableInner {
     /// Returns all the keys, in insertion order.
     pub(crate) fn keys(&self) -> Vec<PropertyKey> {
@@ -48,8 +81,8 @@ impl PropertyTableInner {
         let mut map = FxHashMap::default();
 
         for (index, (key, slot)) in self.keys.iter().take(n).enumerate() {
-            keys.push((key.clone(), *slot));
-            map.insert(key.clone(), (index as u32, *slot));
+            keys.push((key.clone().into_unrooted(), *slot));
+            map.insert(key.clone().into_unrooted(), (index as u32, *slot));
         }
 
         Self { map, keys }
@@ -59,8 +92,8 @@ impl PropertyTableInner {
     pub(crate) fn insert(&mut self, key: PropertyKey, attributes: SlotAttributes) {
         let slot = Slot::from_previous(self.keys.last().map(|x| x.1), attributes);
         let index = self.keys.len() as u32;
-        self.keys.push((key.clone(), slot));
-        let value = self.map.insert(key, (index, slot));
+        self.keys.push((key.clone().into_unrooted(), slot));
+        let value = self.map.insert(key.into_unrooted(), (index, slot));
         debug_assert!(value.is_none());
     }
 }
@@ -68,11 +101,30 @@ impl PropertyTableInner {
 /// Represents an ordered property table, that maps [`PropertyTable`] to [`Slot`].
 ///
 /// This is shared between [`crate::object::shape::SharedShape`].
-#[derive(Default, Debug, Clone)]
+#[derive(Default, Debug, Clone, Finalize)]
 pub(crate) struct PropertyTable {
     pub(super) inner: Rc<RefCell<PropertyTableInner>>,
 }
 
+// SAFETY: The keys of the table only need to be traced, see [`PropertyTableInner`]. Tables are
+//         only borrowed mutably to insert keys, which doesn't trigger a collection.
+unsafe impl Trace for PropertyTable {
+    unsafe fn trace(&self) {
+        if let Ok(inner) = self.inner.try_borrow() {
+            // SAFETY: The keys are owned by the table.
+            unsafe { inner.trace() }
+        }
+    }
+
+    unsafe fn root(&self) {}
+
+    unsafe fn unroot(&self) {}
+
+    fn run_finalizer(&self) {
+        Finalize::finalize(self);
+    }
+}
+
 impl PropertyTable {
     /// Returns the inner representation of a [`PropertyTable`].
     pub(super) fn inner(&self) -> &RefCell<PropertyTableInner> {
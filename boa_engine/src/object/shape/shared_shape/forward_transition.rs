@@ -62,6 +62,12 @@ impl ForwardTransition {
             properties.map.retain(|_, v| v.is_upgradable());
         }
 
+        // Transitions don't keep the symbols of their keys alive, the shapes they lead to
+        // trace them instead.
+        let key = TransitionKey {
+            property_key: key.property_key.into_unrooted(),
+            attributes: key.attributes,
+        };
         properties.map.insert(key, WeakGc::new(value));
     }
 
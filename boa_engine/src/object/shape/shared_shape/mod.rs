@@ -96,9 +96,6 @@ struct Inner {
     /// Instance prototype `__proto__`.
     prototype: JsPrototype,
 
-    // SAFETY: This is safe because nothing in [`PropertyTable`]
-    //         needs tracing
-    #[unsafe_ignore_trace]
     property_table: PropertyTable,
 
     /// The previous shape in the transition chain.
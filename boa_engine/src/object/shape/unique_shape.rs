@@ -1,6 +1,6 @@
 use std::{cell::RefCell, fmt::Debug};
 
-use boa_gc::{Finalize, Gc, GcRefCell, Trace};
+use boa_gc::{custom_trace, Finalize, Gc, GcRefCell, Trace};
 
 use crate::property::PropertyKey;
 
@@ -10,18 +10,26 @@ use super::{
 };
 
 /// The internal representation of [`UniqueShape`].
-#[derive(Default, Debug, Trace, Finalize)]
+#[derive(Default, Debug, Finalize)]
 struct Inner {
     /// The property table that maps a [`PropertyKey`] to a slot in the objects storage.
-    //
-    // SAFETY: This is safe becasue nothing in this field needs tracing.
-    #[unsafe_ignore_trace]
     property_table: RefCell<PropertyTableInner>,
 
     /// The prototype of the shape.
     prototype: GcRefCell<JsPrototype>,
 }
 
+// SAFETY: The property table is never borrowed mutably while allocating, so its keys are
+//         always traced.
+unsafe impl Trace for Inner {
+    custom_trace!(this, {
+        if let Ok(property_table) = this.property_table.try_borrow() {
+            mark(&*property_table);
+        }
+        mark(&this.prototype);
+    });
+}
+
 /// Represents a [`Shape`] that is not shared with any other object.
 ///
 /// This is useful for objects that are inherently unique like,
@@ -84,25 +92,22 @@ impl UniqueShape {
         // shift elements
         property_table.keys.remove(index);
 
-        // The property that was deleted was not the last property added.
-        // Therefore we need to create a new unique shape,
-        // to invalidate any pointers to this shape i.e inline caches.
-        let mut property_table = std::mem::take(&mut *property_table);
+        let table = &mut *property_table;
 
         // If it is not the last property that was deleted,
         // then update all the property slots that are after it.
-        if index != property_table.keys.len() {
+        if index != table.keys.len() {
             // Get the previous value before the value at index,
             //
             // NOTE: calling wrapping_sub when usize index is 0 will wrap into usize::MAX
             //       which will return None, avoiding unneeded checks.
-            let mut previous_slot = property_table.keys.get(index.wrapping_sub(1)).map(|x| x.1);
+            let mut previous_slot = table.keys.get(index.wrapping_sub(1)).map(|x| x.1);
 
             // Update all slot positions
-            for (index, (key, slot)) in property_table.keys.iter_mut().enumerate().skip(index) {
+            for (index, (key, slot)) in table.keys.iter_mut().enumerate().skip(index) {
                 *slot = Slot::from_previous(previous_slot, slot.attributes);
 
-                let Some((map_index, map_slot)) = property_table.map.get_mut(key) else {
+                let Some((map_index, map_slot)) = table.map.get_mut(key) else {
                     unreachable!("There should already be a property")
                 };
                 *map_index = index as u32;
@@ -111,9 +116,13 @@ impl UniqueShape {
                 previous_slot = Some(*slot);
             }
         }
+        drop(property_table);
 
+        // The property that was deleted was not the last property added.
+        // Therefore we need to create a new unique shape,
+        // to invalidate any pointers to this shape i.e inline caches.
         let prototype = self.inner.prototype.borrow_mut().take();
-        Self::new(prototype, property_table)
+        self.move_to_new_shape(prototype)
     }
 
     /// Does a property lookup on the [`UniqueShape`] returning the [`Slot`] where it's
@@ -167,10 +176,7 @@ impl UniqueShape {
             ChangeTransitionAction::Remove
         };
 
-        // The property that was deleted was not the last property added.
-        // Therefore we need to create a new unique shape,
-        // to invalidate any pointers to this shape i.e inline caches.
-        let mut property_table = std::mem::take(&mut *property_table);
+        let table = &mut *property_table;
 
         // Update all slot positions, after the target property.
         //
@@ -196,19 +202,23 @@ impl UniqueShape {
         //                          previous ----/              \-------- next
         //
         let next = index + 1;
-        for (key, slot) in property_table.keys.iter_mut().skip(next) {
+        for (key, slot) in table.keys.iter_mut().skip(next) {
             *slot = Slot::from_previous(Some(previous_slot), slot.attributes);
 
-            let Some((_, map_slot)) = property_table.map.get_mut(key) else {
+            let Some((_, map_slot)) = table.map.get_mut(key) else {
                 unreachable!("There should already be a property")
             };
             *map_slot = *slot;
 
             previous_slot = *slot;
         }
+        drop(property_table);
 
+        // The property that was deleted was not the last property added.
+        // Therefore we need to create a new unique shape,
+        // to invalidate any pointers to this shape i.e inline caches.
         let prototype = self.inner.prototype.borrow_mut().take();
-        let shape = Self::new(prototype, property_table);
+        let shape = self.move_to_new_shape(prototype);
 
         ChangeTransition {
             shape: shape.into(),
@@ -220,12 +230,20 @@ impl UniqueShape {
     ///
     /// This will cause the current shape to be invalidated, and a new [`UniqueShape`] will be returned.
     pub(crate) fn change_prototype_transition(&self, prototype: JsPrototype) -> Self {
-        let mut property_table = self.inner.property_table.borrow_mut();
-
         // We need to create a new unique shape,
         // to invalidate any pointers to this shape i.e inline caches.
-        let property_table = std::mem::take(&mut *property_table);
-        Self::new(prototype, property_table)
+        self.move_to_new_shape(prototype)
+    }
+
+    /// Moves the property table of this shape to a new [`UniqueShape`] with the given prototype.
+    ///
+    /// The symbols of the property table are only traced through its shape, so the new shape
+    /// is allocated before the table is moved out of this one.
+    fn move_to_new_shape(&self, prototype: JsPrototype) -> Self {
+        let shape = Self::default();
+        let property_table = std::mem::take(&mut *self.property_table().borrow_mut());
+        shape.override_internal(property_table, prototype);
+        shape
     }
 
     /// Gets all keys first strings then symbols in creation order.
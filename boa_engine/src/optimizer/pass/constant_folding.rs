@@ -3,7 +3,7 @@ use crate::{
 };
 use boa_ast::{
     expression::{
-        literal::Literal,
+        literal::{Literal, LiteralKind},
         operator::{
             binary::{ArithmeticOp, BinaryOp, BitwiseOp, LogicalOp, RelationalOp},
             unary::UnaryOp,
@@ -14,28 +14,28 @@ use boa_ast::{
 };
 
 fn literal_to_js_value(literal: &Literal, context: &mut Context<'_>) -> JsValue {
-    match literal {
-        Literal::String(v) => JsValue::new(JsString::from(
+    match literal.kind() {
+        LiteralKind::String(v) => JsValue::new(JsString::from(
             context.interner().resolve_expect(*v).utf16(),
         )),
-        Literal::Num(v) => JsValue::new(*v),
-        Literal::Int(v) => JsValue::new(*v),
-        Literal::BigInt(v) => JsValue::new(JsBigInt::new(v.clone())),
-        Literal::Bool(v) => JsValue::new(*v),
-        Literal::Null => JsValue::null(),
-        Literal::Undefined => JsValue::undefined(),
+        LiteralKind::Num(v) => JsValue::new(*v),
+        LiteralKind::Int(v) => JsValue::new(*v),
+        LiteralKind::BigInt(v) => JsValue::new(JsBigInt::new(v.clone())),
+        LiteralKind::Bool(v) => JsValue::new(*v),
+        LiteralKind::Null => JsValue::null(),
+        LiteralKind::Undefined => JsValue::undefined(),
     }
 }
 
-fn js_value_to_literal(value: JsValue, context: &mut Context<'_>) -> Literal {
+fn js_value_to_literal(value: JsValue, context: &mut Context<'_>) -> LiteralKind {
     match value {
-        JsValue::Null => Literal::Null,
-        JsValue::Undefined => Literal::Undefined,
-        JsValue::Boolean(v) => Literal::Bool(v),
-        JsValue::String(v) => Literal::String(context.interner_mut().get_or_intern(v.as_ref())),
-        JsValue::Rational(v) => Literal::Num(v),
-        JsValue::Integer(v) => Literal::Int(v),
-        JsValue::BigInt(v) => Literal::BigInt(Box::new(v.as_inner().clone())),
+        JsValue::Null => LiteralKind::Null,
+        JsValue::Undefined => LiteralKind::Undefined,
+        JsValue::Boolean(v) => LiteralKind::Bool(v),
+        JsValue::String(v) => LiteralKind::String(context.interner_mut().get_or_intern(v.as_ref())),
+        JsValue::Rational(v) => LiteralKind::Num(v),
+        JsValue::Integer(v) => LiteralKind::Int(v),
+        JsValue::BigInt(v) => LiteralKind::BigInt(Box::new(v.as_inner().clone())),
         JsValue::Object(_) | JsValue::Symbol(_) => {
             unreachable!("value must not be a object or symbol")
         }
@@ -85,10 +85,10 @@ impl ConstantFolding {
                 literal_to_js_value(literal, context).type_of(),
             )),
             (_, UnaryOp::Delete) => {
-                return PassAction::Replace(Expression::Literal(Literal::Bool(true)))
+                return PassAction::Replace(Expression::from(LiteralKind::Bool(true)))
             }
             (_, UnaryOp::Void) => {
-                return PassAction::Replace(Expression::Literal(Literal::Undefined))
+                return PassAction::Replace(Expression::from(LiteralKind::Undefined))
             }
         };
 
@@ -97,7 +97,7 @@ impl ConstantFolding {
             return PassAction::Keep;
         };
 
-        PassAction::Replace(Expression::Literal(js_value_to_literal(value, context)))
+        PassAction::Replace(Expression::from(js_value_to_literal(value, context)))
     }
 
     fn constant_fold_binary_expr(
@@ -124,16 +124,16 @@ impl ConstantFolding {
             if !matches!(binary.rhs(), Expression::Literal(_)) {
                 // If left-hand side is already undefined then just keep it,
                 // so we don't cause an infinite loop.
-                if *binary.lhs() == Expression::Literal(Literal::Undefined) {
+                if *binary.lhs() == Expression::from(LiteralKind::Undefined) {
                     return PassAction::Keep;
                 }
 
-                *binary.lhs_mut() = Expression::Literal(Literal::Undefined);
+                *binary.lhs_mut() = Expression::from(LiteralKind::Undefined);
                 return PassAction::Modified;
             }
 
             // We take rhs, by replacing with a dummy value.
-            let rhs = std::mem::replace(binary.rhs_mut(), Expression::Literal(Literal::Undefined));
+            let rhs = std::mem::replace(binary.rhs_mut(), Expression::from(LiteralKind::Undefined));
             return PassAction::Replace(rhs);
         }
 
@@ -156,23 +156,41 @@ impl ConstantFolding {
             let expr = match op {
                 LogicalOp::And => {
                     if lhs.to_boolean() {
-                        std::mem::replace(binary.rhs_mut(), Expression::Literal(Literal::Undefined))
+                        std::mem::replace(
+                            binary.rhs_mut(),
+                            Expression::from(LiteralKind::Undefined),
+                        )
                     } else {
-                        std::mem::replace(binary.lhs_mut(), Expression::Literal(Literal::Undefined))
+                        std::mem::replace(
+                            binary.lhs_mut(),
+                            Expression::from(LiteralKind::Undefined),
+                        )
                     }
                 }
                 LogicalOp::Or => {
                     if lhs.to_boolean() {
-                        std::mem::replace(binary.lhs_mut(), Expression::Literal(Literal::Undefined))
+                        std::mem::replace(
+                            binary.lhs_mut(),
+                            Expression::from(LiteralKind::Undefined),
+                        )
                     } else {
-                        std::mem::replace(binary.rhs_mut(), Expression::Literal(Literal::Undefined))
+                        std::mem::replace(
+                            binary.rhs_mut(),
+                            Expression::from(LiteralKind::Undefined),
+                        )
                     }
                 }
                 LogicalOp::Coalesce => {
                     if lhs.is_null_or_undefined() {
-                        std::mem::replace(binary.rhs_mut(), Expression::Literal(Literal::Undefined))
+                        std::mem::replace(
+                            binary.rhs_mut(),
+                            Expression::from(LiteralKind::Undefined),
+                        )
                     } else {
-                        std::mem::replace(binary.lhs_mut(), Expression::Literal(Literal::Undefined))
+                        std::mem::replace(
+                            binary.lhs_mut(),
+                            Expression::from(LiteralKind::Undefined),
+                        )
                     }
                 }
             };
@@ -224,6 +242,6 @@ impl ConstantFolding {
             return PassAction::Keep;
         };
 
-        PassAction::Replace(Expression::Literal(js_value_to_literal(value, context)))
+        PassAction::Replace(Expression::from(js_value_to_literal(value, context)))
     }
 }
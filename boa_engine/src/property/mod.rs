@@ -582,6 +582,17 @@ pub enum PropertyKey {
     Index(u32),
 }
 
+impl PropertyKey {
+    /// Unroots the symbol of this key, so that it can be stored in the property tables and
+    /// transitions of shapes, which are traced through the shapes.
+    pub(crate) fn into_unrooted(self) -> Self {
+        match self {
+            Self::Symbol(symbol) => Self::Symbol(symbol.into_unrooted()),
+            key => key,
+        }
+    }
+}
+
 /// Utility function for parsing [`PropertyKey`].
 fn parse_u32_index<I, T>(mut input: I) -> Option<u32>
 where
@@ -8,7 +8,7 @@
 //! [spec]: https://tc39.es/ecma262/#sec-scripts
 //! [script]: https://tc39.es/ecma262/#sec-script-records
 
-use std::io::Read;
+use std::{io::Read, path::Path, rc::Rc};
 
 use boa_gc::{Finalize, Gc, GcRefCell, Trace};
 use boa_interner::Sym;
@@ -36,6 +36,7 @@ impl std::fmt::Debug for Script {
         f.debug_struct("Script")
             .field("realm", &self.inner.realm.addr())
             .field("code", &self.inner.source)
+            .field("path", &self.inner.path)
             .field("loaded_modules", &self.inner.loaded_modules)
             .field("host_defined", &self.inner.host_defined)
             .finish()
@@ -47,6 +48,8 @@ struct Inner {
     realm: Realm,
     #[unsafe_ignore_trace]
     source: boa_ast::Script,
+    #[unsafe_ignore_trace]
+    path: Option<Rc<Path>>,
     codeblock: GcRefCell<Option<Gc<CodeBlock>>>,
     loaded_modules: GcRefCell<FxHashMap<JsString, Module>>,
     host_defined: (),
@@ -58,6 +61,11 @@ impl Script {
         &self.inner.realm
     }
 
+    /// Gets the path of the file this script was loaded from, if any.
+    pub fn path(&self) -> Option<&Path> {
+        self.inner.path.as_deref()
+    }
+
     /// Gets the loaded modules of this script.
     pub(crate) fn loaded_modules(&self) -> &GcRefCell<FxHashMap<JsString, Module>> {
         &self.inner.loaded_modules
@@ -74,6 +82,7 @@ impl Script {
         context: &mut Context<'_>,
     ) -> JsResult<Self> {
         let _timer = Profiler::global().start_event("Script parsing", "Main");
+        let path = src.path().map(Rc::from);
         let mut parser = Parser::new(src);
         parser.set_identifier(context.next_parser_identifier());
         if context.is_strict() {
@@ -88,6 +97,7 @@ impl Script {
             inner: Gc::new(Inner {
                 realm: realm.unwrap_or_else(|| context.realm().clone()),
                 source: code,
+                path,
                 codeblock: GcRefCell::default(),
                 loaded_modules: GcRefCell::default(),
                 host_defined: (),
@@ -114,6 +124,7 @@ impl Script {
             self.inner.realm.environment().compile_env(),
             context,
         );
+        compiler.source_path.clone_from(&self.inner.path);
         // TODO: move to `Script::evaluate` to make this operation infallible.
         compiler.global_declaration_instantiation(&self.inner.source)?;
         compiler.compile_statement_list(self.inner.source.statements(), true, false);
@@ -77,8 +77,12 @@ impl StaticJsStrings {
     well_known_statics! {
         /// Gets the empty string (`""`) `JsString`.
         (empty_string, ""),
+        /// Gets the static `JsString` for `"Symbol.asyncDispose"`.
+        (symbol_async_dispose, "Symbol.asyncDispose"),
         /// Gets the static `JsString` for `"Symbol.asyncIterator"`.
         (symbol_async_iterator, "Symbol.asyncIterator"),
+        /// Gets the static `JsString` for `"Symbol.dispose"`.
+        (symbol_dispose, "Symbol.dispose"),
         /// Gets the static `JsString` for `"Symbol.hasInstance"`.
         (symbol_has_instance, "Symbol.hasInstance"),
         /// Gets the static `JsString` for `"Symbol.isConcatSpreadable"`.
@@ -302,6 +306,7 @@ const RAW_STATICS: &[&[u16]] = &[
     utf16!("multiline"),
     utf16!("dotAll"),
     utf16!("unicode"),
+    utf16!("unicodeSets"),
     utf16!("sticky"),
     utf16!("source"),
     utf16!("get hasIndices"),
@@ -310,6 +315,7 @@ const RAW_STATICS: &[&[u16]] = &[
     utf16!("get multiline"),
     utf16!("get dotAll"),
     utf16!("get unicode"),
+    utf16!("get unicodeSets"),
     utf16!("get sticky"),
     utf16!("get flags"),
     utf16!("get source"),
@@ -318,7 +324,9 @@ const RAW_STATICS: &[&[u16]] = &[
     utf16!("for"),
     utf16!("keyFor"),
     utf16!("description"),
+    utf16!("asyncDispose"),
     utf16!("asyncIterator"),
+    utf16!("dispose"),
     utf16!("hasInstance"),
     utf16!("species"),
     utf16!("unscopables"),
@@ -343,6 +351,7 @@ const RAW_STATICS: &[&[u16]] = &[
     // Error objects
     utf16!("Error"),
     utf16!("AggregateError"),
+    utf16!("SuppressedError"),
     utf16!("TypeError"),
     utf16!("RangeError"),
     utf16!("SyntaxError"),
@@ -570,8 +579,12 @@ const RAW_STATICS: &[&[u16]] = &[
     utf16!("_"),
     utf16!("$"),
     // Well known symbols
+    utf16!("Symbol.asyncDispose"),
+    utf16!("[Symbol.asyncDispose]"),
     utf16!("Symbol.asyncIterator"),
     utf16!("[Symbol.asyncIterator]"),
+    utf16!("Symbol.dispose"),
+    utf16!("[Symbol.dispose]"),
     utf16!("Symbol.hasInstance"),
     utf16!("[Symbol.hasInstance]"),
     utf16!("Symbol.isConcatSpreadable"),
@@ -20,22 +20,31 @@
     clippy::undocumented_unsafe_blocks,
     clippy::missing_safety_doc
 )]
+// Remove when/if https://github.com/rust-lang/rust/issues/95228 stabilizes.
+#![allow(unstable_name_collisions)]
 
 use crate::{
     js_string,
+    object::{JsObject, ObjectData},
     string::{common::StaticJsStrings, utf16},
     tagged::{Tagged, UnwrappedTagged},
     JsString,
 };
-use boa_gc::{empty_trace, Finalize, Trace};
+use boa_gc::{empty_trace, Finalize, Gc, Trace};
 
 use num_enum::{IntoPrimitive, TryFromPrimitive};
+use once_cell::unsync::OnceCell;
+use rustc_hash::FxHashMap;
+use sptr::Strict;
 
 use std::{
+    cell::RefCell,
     hash::{Hash, Hasher},
+    mem::{align_of, ManuallyDrop},
+    ptr::NonNull,
     sync::{
-        atomic::{AtomicU64, Ordering},
-        Arc,
+        atomic::{AtomicPtr, AtomicU64, AtomicUsize, Ordering},
+        Arc, Weak,
     },
 };
 
@@ -45,6 +54,9 @@ use std::{
 /// and internal engine symbols.
 const RESERVED_SYMBOL_HASHES: u64 = 127;
 
+/// The bit of the pointer of a [`JsSymbol`] that is set if the handle is rooted.
+const ROOTED_BIT: usize = 0b10;
+
 fn get_id() -> Option<u64> {
     // Symbol hash.
     //
@@ -62,7 +74,9 @@ fn get_id() -> Option<u64> {
 #[derive(Debug, Clone, Copy, TryFromPrimitive, IntoPrimitive)]
 #[repr(u8)]
 enum WellKnown {
+    AsyncDispose,
     AsyncIterator,
+    Dispose,
     HasInstance,
     IsConcatSpreadable,
     Iterator,
@@ -80,7 +94,9 @@ enum WellKnown {
 impl WellKnown {
     const fn description(self) -> JsString {
         match self {
+            Self::AsyncDispose => StaticJsStrings::symbol_async_dispose(),
             Self::AsyncIterator => StaticJsStrings::symbol_async_iterator(),
+            Self::Dispose => StaticJsStrings::symbol_dispose(),
             Self::HasInstance => StaticJsStrings::symbol_has_instance(),
             Self::IsConcatSpreadable => StaticJsStrings::symbol_is_concat_spreadable(),
             Self::Iterator => StaticJsStrings::symbol_iterator(),
@@ -109,29 +125,151 @@ impl WellKnown {
     }
 }
 
+thread_local! {
+    /// The objects that represent symbols in the weak collections of the current thread.
+    static WEAK_KEYS: WeakKeys = WeakKeys::default();
+}
+
+/// The objects that represent symbols in the weak collections of a thread, by the hash of the
+/// symbol.
+///
+/// The handles to the objects are never rooted. Instead, the objects are kept alive by tracing
+/// the symbols in the heap of the thread, and by the tracer of the thread if their symbol is held
+/// outside of any heap. Since the tracer checks the root count of the symbols when the heap is
+/// marked, handles held by other threads keep the objects alive as well.
+#[derive(Default)]
+struct WeakKeys {
+    keys: RefCell<FxHashMap<u64, WeakKey>>,
+    tracer: OnceCell<Gc<WeakKeysTracer>>,
+}
+
+impl Drop for WeakKeys {
+    fn drop(&mut self) {
+        // The heap of the thread may already be destroyed, and destroying it frees the tracer
+        // anyway.
+        if let Some(tracer) = self.tracer.take() {
+            std::mem::forget(tracer);
+        }
+    }
+}
+
+/// The object that represents a symbol in the weak collections of a thread.
+struct WeakKey {
+    object: JsObject,
+    symbol: WeakRepr,
+}
+
+/// A rooted value that marks the weak keys of the symbols held outside of the heap.
+struct WeakKeysTracer;
+
+impl Finalize for WeakKeysTracer {}
+
+// SAFETY: the handles to the weak keys are never rooted, and the entries are removed when the
+// objects are collected.
+unsafe impl Trace for WeakKeysTracer {
+    unsafe fn trace(&self) {
+        // The keys may already be destroyed if the thread is exiting.
+        let _ = WEAK_KEYS.try_with(|weak_keys| {
+            for key in weak_keys.keys.borrow().values() {
+                if key.symbol.is_held() {
+                    // SAFETY: the entry is removed when the object is collected.
+                    unsafe { key.object.trace() }
+                }
+            }
+        });
+    }
+
+    unsafe fn root(&self) {}
+
+    unsafe fn unroot(&self) {}
+
+    fn run_finalizer(&self) {
+        Finalize::finalize(self);
+    }
+}
+
 /// The inner representation of a JavaScript symbol.
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 struct Inner {
     hash: u64,
     description: Option<JsString>,
+
+    /// The number of rooted handles to this symbol, which are the handles held outside of the
+    /// garbage collected heap.
+    roots: AtomicUsize,
 }
 
 /// This represents a JavaScript symbol primitive.
+///
+/// Like [`Gc`], each handle is rooted unless it is stored in a garbage collected value, which
+/// lets weak collections hold symbols weakly.
 pub struct JsSymbol {
-    repr: Tagged<Inner>,
+    /// The tagged pointer of the symbol, with the root flag of the handle.
+    repr: AtomicPtr<Inner>,
 }
 
-// SAFETY: `JsSymbol` uses `Arc` to do the reference counting, making this type thread-safe.
+// SAFETY: `JsSymbol` uses `Arc` to do the reference counting, and an atomic pointer to store
+// the root flag of the handle, making this type thread-safe.
+#[allow(clippy::non_send_fields_in_send_ty)]
 unsafe impl Send for JsSymbol {}
-// SAFETY: `JsSymbol` uses `Arc` to do the reference counting, making this type thread-safe.
+// SAFETY: `JsSymbol` uses `Arc` to do the reference counting, and an atomic pointer to store
+// the root flag of the handle, making this type thread-safe.
 unsafe impl Sync for JsSymbol {}
 
 impl Finalize for JsSymbol {}
 
-// Safety: JsSymbol does not contain any objects which needs to be traced,
-// so this is safe.
+// SAFETY: the root flag of the handle is kept in sync with the root count of the symbol, and
+// tracing the symbol marks the object that represents it in weak collections.
 unsafe impl Trace for JsSymbol {
-    empty_trace!();
+    unsafe fn trace(&self) {
+        let Some(inner) = self.inner() else {
+            return;
+        };
+        // The keys may already be destroyed if the thread is exiting.
+        let _ = WEAK_KEYS.try_with(|weak_keys| {
+            if let Some(key) = weak_keys.keys.borrow().get(&inner.hash) {
+                // SAFETY: the entry is removed when the object is collected.
+                unsafe { key.object.trace() }
+            }
+        });
+    }
+
+    unsafe fn root(&self) {
+        let Some(inner) = self.inner() else {
+            return;
+        };
+        assert!(!self.is_rooted(), "Can't double-root a JsSymbol");
+        self.set_rooted(true);
+        inner.inc_roots();
+    }
+
+    unsafe fn unroot(&self) {
+        let Some(inner) = self.inner() else {
+            return;
+        };
+        assert!(self.is_rooted(), "Can't double-unroot a JsSymbol");
+        self.set_rooted(false);
+        inner.dec_roots();
+    }
+
+    fn run_finalizer(&self) {
+        Finalize::finalize(self);
+    }
+}
+
+impl Inner {
+    fn inc_roots(&self) {
+        self.roots.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn dec_roots(&self) {
+        self.roots.fetch_sub(1, Ordering::Relaxed);
+    }
+
+    /// Returns `true` if any handle to the symbol is held outside of the heap, by any thread.
+    fn is_held(&self) -> bool {
+        self.roots.load(Ordering::Relaxed) > 0
+    }
 }
 
 macro_rules! well_known_symbols {
@@ -139,7 +277,7 @@ macro_rules! well_known_symbols {
         $(
             $(#[$attr])* pub(crate) const fn $name() -> JsSymbol {
                 JsSymbol {
-                    repr: Tagged::from_tag($variant.tag()),
+                    repr: AtomicPtr::new(Tagged::from_tag($variant.tag()).as_ptr()),
                 }
             }
         )+
@@ -152,21 +290,92 @@ impl JsSymbol {
     /// Returns `None` if the maximum number of possible symbols has been reached (`u64::MAX`).
     #[inline]
     #[must_use]
+    #[allow(clippy::arc_with_non_send_sync)]
     pub fn new(description: Option<JsString>) -> Option<Self> {
         let hash = get_id()?;
-        let arc = Arc::new(Inner { hash, description });
+        let arc = Arc::new(Inner {
+            hash,
+            description,
+            roots: AtomicUsize::new(1),
+        });
+
+        // SAFETY: Pointers returned by `Arc::into_raw` must be non-null.
+        Some(unsafe { Self::from_raw_rooted(Arc::into_raw(arc).cast_mut()) })
+    }
 
-        Some(Self {
-            // SAFETY: Pointers returned by `Arc::into_raw` must be non-null.
-            repr: unsafe { Tagged::from_ptr(Arc::into_raw(arc).cast_mut()) },
-        })
+    /// Creates a rooted handle from a pointer returned by `Arc::into_raw`.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be non null, and the caller must account for the root of the handle.
+    unsafe fn from_raw_rooted(ptr: *mut Inner) -> Self {
+        debug_assert!(align_of::<Inner>() > ROOTED_BIT);
+        Self {
+            repr: AtomicPtr::new(Strict::map_addr(ptr, |addr| addr | ROOTED_BIT)),
+        }
+    }
+
+    /// Returns the tagged pointer of the symbol, with the root flag of the handle.
+    fn tagged(&self) -> Tagged<Inner> {
+        // SAFETY: the pointer is always created from a non null pointer or from a tag.
+        unsafe { Tagged::from_ptr(self.repr.load(Ordering::Relaxed)) }
+    }
+
+    /// Returns the unwrapped representation of the symbol, without the root flag.
+    fn unwrap(&self) -> UnwrappedTagged<Inner> {
+        match self.tagged().unwrap() {
+            UnwrappedTagged::Ptr(ptr) => {
+                let ptr = Strict::map_addr(ptr.as_ptr(), |addr| addr & !ROOTED_BIT);
+                // SAFETY: clearing the root flag of a non null pointer keeps it non null.
+                UnwrappedTagged::Ptr(unsafe { NonNull::new_unchecked(ptr) })
+            }
+            UnwrappedTagged::Tag(tag) => UnwrappedTagged::Tag(tag),
+        }
+    }
+
+    /// Returns the inner representation of the symbol, or `None` for well known symbols.
+    fn inner(&self) -> Option<&Inner> {
+        match self.unwrap() {
+            UnwrappedTagged::Ptr(ptr) => {
+                // SAFETY: `ptr` comes from `Arc`, which ensures the validity of the pointer
+                // as long as we correctly call `Arc::from_raw` on `Drop`.
+                Some(unsafe { ptr.as_ref() })
+            }
+            UnwrappedTagged::Tag(_) => None,
+        }
+    }
+
+    fn is_rooted(&self) -> bool {
+        self.tagged().addr() & ROOTED_BIT != 0
+    }
+
+    fn set_rooted(&self, rooted: bool) {
+        let UnwrappedTagged::Ptr(ptr) = self.unwrap() else {
+            return;
+        };
+        let ptr = if rooted {
+            Strict::map_addr(ptr.as_ptr(), |addr| addr | ROOTED_BIT)
+        } else {
+            ptr.as_ptr()
+        };
+        self.repr.store(ptr, Ordering::Relaxed);
+    }
+
+    /// Unroots this handle so that it can be stored in a value that is not garbage collected,
+    /// but is traced by the garbage collected values that own it.
+    pub(crate) fn into_unrooted(self) -> Self {
+        if self.is_rooted() {
+            // SAFETY: the handle is rooted.
+            unsafe { self.unroot() }
+        }
+        self
     }
 
     /// Returns the `Symbol`s description.
     #[inline]
     #[must_use]
     pub fn description(&self) -> Option<JsString> {
-        match self.repr.unwrap() {
+        match self.unwrap() {
             UnwrappedTagged::Ptr(ptr) => {
                 // SAFETY: `ptr` comes from `Arc`, which ensures the validity of the pointer
                 // as long as we corrently call `Arc::from_raw` on `Drop`.
@@ -187,7 +396,7 @@ impl JsSymbol {
     #[inline]
     #[must_use]
     pub fn hash(&self) -> u64 {
-        match self.repr.unwrap() {
+        match self.unwrap() {
             UnwrappedTagged::Ptr(ptr) => {
                 // SAFETY: `ptr` comes from `Arc`, which ensures the validity of the pointer
                 // as long as we correctly call `Arc::from_raw` on `Drop`.
@@ -201,6 +410,63 @@ impl JsSymbol {
         }
     }
 
+    /// Gets the object that represents this symbol as the key of weak collections in the
+    /// current thread, creating it if it does not exist yet.
+    ///
+    /// The object lives as long as the symbol is reachable, either from the heap of the
+    /// current thread or from outside of any heap.
+    pub(crate) fn weak_key(&self) -> JsObject {
+        if let Some(key) = self.existing_weak_key() {
+            return key;
+        }
+
+        // Allocating can trigger a garbage collection, so the tracer and the key are created
+        // before borrowing the keys of the thread.
+        WEAK_KEYS.with(|weak_keys| {
+            weak_keys.tracer.get_or_init(|| Gc::new(WeakKeysTracer));
+        });
+        let object = JsObject::from_proto_and_data(
+            None,
+            ObjectData::weak_symbol_key(WeakJsSymbol {
+                hash: self.hash(),
+                repr: self.downgrade(),
+            }),
+        );
+        let key = WeakKey {
+            object: object.clone(),
+            symbol: self.downgrade(),
+        };
+        // SAFETY: the handle was just created, so it is rooted.
+        unsafe { key.object.unroot() }
+        WEAK_KEYS.with(|weak_keys| weak_keys.keys.borrow_mut().insert(self.hash(), key));
+        object
+    }
+
+    /// Gets the object that represents this symbol as the key of weak collections in the
+    /// current thread, if it was already created by [`JsSymbol::weak_key`].
+    pub(crate) fn existing_weak_key(&self) -> Option<JsObject> {
+        WEAK_KEYS.with(|weak_keys| {
+            weak_keys
+                .keys
+                .borrow()
+                .get(&self.hash())
+                .map(|key| key.object.clone())
+        })
+    }
+
+    /// Creates a weak reference that does not keep this symbol alive.
+    fn downgrade(&self) -> WeakRepr {
+        match self.unwrap() {
+            UnwrappedTagged::Ptr(ptr) => {
+                // SAFETY: the pointer returned by `self.unwrap` must be a valid pointer
+                // that came from an `Arc::into_raw` call.
+                let arc = ManuallyDrop::new(unsafe { Arc::from_raw(ptr.as_ptr().cast_const()) });
+                WeakRepr::Unique(Arc::downgrade(&arc))
+            }
+            UnwrappedTagged::Tag(tag) => WeakRepr::WellKnown(tag),
+        }
+    }
+
     /// Abstract operation `SymbolDescriptiveString ( sym )`
     ///
     /// More info:
@@ -216,8 +482,12 @@ impl JsSymbol {
     }
 
     well_known_symbols! {
+        /// Gets the static `JsSymbol` for `"Symbol.asyncDispose"`.
+        (async_dispose, WellKnown::AsyncDispose),
         /// Gets the static `JsSymbol` for `"Symbol.asyncIterator"`.
         (async_iterator, WellKnown::AsyncIterator),
+        /// Gets the static `JsSymbol` for `"Symbol.dispose"`.
+        (dispose, WellKnown::Dispose),
         /// Gets the static `JsSymbol` for `"Symbol.hasInstance"`.
         (has_instance, WellKnown::HasInstance),
         /// Gets the static `JsSymbol` for `"Symbol.isConcatSpreadable"`.
@@ -247,31 +517,98 @@ impl JsSymbol {
 
 impl Clone for JsSymbol {
     fn clone(&self) -> Self {
-        if let UnwrappedTagged::Ptr(ptr) = self.repr.unwrap() {
-            // SAFETY: the pointer returned by `self.repr` must be a valid pointer
-            // that came from an `Arc::into_raw` call.
-            unsafe {
-                let arc = Arc::from_raw(ptr.as_ptr().cast_const());
-                // Don't need the Arc since `self` is already a copyable pointer, just need to
-                // trigger the `clone` impl.
-                std::mem::forget(arc.clone());
-                std::mem::forget(arc);
-            }
+        let UnwrappedTagged::Ptr(ptr) = self.unwrap() else {
+            return Self {
+                repr: AtomicPtr::new(self.tagged().as_ptr()),
+            };
+        };
+
+        // SAFETY: the pointer returned by `self.unwrap` must be a valid pointer
+        // that came from an `Arc::into_raw` call.
+        unsafe {
+            Arc::increment_strong_count(ptr.as_ptr().cast_const());
+            ptr.as_ref().inc_roots();
+            Self::from_raw_rooted(ptr.as_ptr())
         }
-        Self { repr: self.repr }
     }
 }
 
 impl Drop for JsSymbol {
     fn drop(&mut self) {
-        if let UnwrappedTagged::Ptr(ptr) = self.repr.unwrap() {
-            // SAFETY: the pointer returned by `self.repr` must be a valid pointer
+        if let UnwrappedTagged::Ptr(ptr) = self.unwrap() {
+            // SAFETY: the pointer returned by `self.unwrap` must be a valid pointer
             // that came from an `Arc::into_raw` call.
-            unsafe { drop(Arc::from_raw(ptr.as_ptr().cast_const())) }
+            unsafe {
+                if self.is_rooted() {
+                    ptr.as_ref().dec_roots();
+                }
+                drop(Arc::from_raw(ptr.as_ptr().cast_const()));
+            }
+        }
+    }
+}
+
+/// The data of the object that represents a [`JsSymbol`] in weak collections.
+///
+/// This does not keep the symbol alive. Collecting the object removes it from the weak keys of
+/// the thread, and a new one is created if the symbol is held weakly again.
+#[derive(Debug, Finalize)]
+pub struct WeakJsSymbol {
+    hash: u64,
+    repr: WeakRepr,
+}
+
+#[derive(Debug, Clone)]
+enum WeakRepr {
+    WellKnown(usize),
+    Unique(Weak<Inner>),
+}
+
+impl WeakRepr {
+    /// Returns `true` if any handle to the symbol is held outside of the heap.
+    ///
+    /// Well known symbols are always held by the engine.
+    fn is_held(&self) -> bool {
+        match self {
+            Self::WellKnown(_) => true,
+            Self::Unique(weak) => weak.upgrade().map_or(false, |inner| inner.is_held()),
+        }
+    }
+}
+
+// SAFETY: `WeakJsSymbol` does not contain any objects which needs to be traced.
+unsafe impl Trace for WeakJsSymbol {
+    empty_trace!();
+}
+
+impl WeakJsSymbol {
+    /// Gets the symbol represented by the object, if it is still alive.
+    #[must_use]
+    pub fn upgrade(&self) -> Option<JsSymbol> {
+        match &self.repr {
+            WeakRepr::WellKnown(tag) => Some(JsSymbol {
+                repr: AtomicPtr::new(Tagged::from_tag(*tag).as_ptr()),
+            }),
+            WeakRepr::Unique(weak) => {
+                let arc = weak.upgrade()?;
+                arc.inc_roots();
+                // SAFETY: Pointers returned by `Arc::into_raw` must be non-null.
+                Some(unsafe { JsSymbol::from_raw_rooted(Arc::into_raw(arc).cast_mut()) })
+            }
         }
     }
 }
 
+impl Drop for WeakJsSymbol {
+    fn drop(&mut self) {
+        // The keys may already be destroyed if the thread is exiting.
+        let _ = WEAK_KEYS.try_with(|weak_keys| {
+            // The handle is unrooted, so dropping it doesn't touch the object being dropped.
+            weak_keys.keys.borrow_mut().remove(&self.hash);
+        });
+    }
+}
+
 impl std::fmt::Debug for JsSymbol {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("JsSymbol")
@@ -88,6 +88,11 @@ impl<T> Tagged<T> {
         }
     }
 
+    /// Gets the inner pointer, which is not a valid pointer if `self` is a tagged pointer.
+    pub(crate) const fn as_ptr(self) -> *mut T {
+        self.0.as_ptr()
+    }
+
     /// Gets the address of the inner pointer.
     #[allow(unused)]
     pub(crate) fn addr(self) -> usize {
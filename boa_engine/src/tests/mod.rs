@@ -481,3 +481,70 @@ fn template_literal() {
         "result: 10 and 20",
     )]);
 }
+
+#[cfg(feature = "heap-snapshot")]
+#[test]
+fn heap_snapshot() {
+    run_test_actions([
+        TestAction::run(indoc! {r"
+            function Point() {}
+            globalThis.snapshotTarget = { point: new Point(), list: [{}] };
+        "}),
+        TestAction::inspect_context(|context| {
+            let mut bytes = Vec::new();
+            context.write_heap_snapshot(&mut bytes).unwrap();
+            let snapshot: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+
+            let nodes = snapshot["nodes"].as_array().unwrap();
+            let edges = snapshot["edges"].as_array().unwrap();
+            let strings = snapshot["strings"].as_array().unwrap();
+            let node_count = snapshot["snapshot"]["node_count"].as_u64().unwrap() as usize;
+            let edge_count = snapshot["snapshot"]["edge_count"].as_u64().unwrap() as usize;
+            assert_eq!(nodes.len(), node_count * 7);
+            assert_eq!(edges.len(), edge_count * 3);
+
+            let field = |values: &[serde_json::Value], index: usize| {
+                values[index].as_u64().unwrap() as usize
+            };
+            let string = |index: usize| strings[index].as_str().unwrap();
+
+            // The edges of node `n` follow the edges of the nodes before it.
+            let mut first_edges = Vec::with_capacity(node_count);
+            let mut first_edge = 0;
+            for node in 0..node_count {
+                first_edges.push(first_edge);
+                first_edge += field(nodes, node * 7 + 4);
+            }
+            assert_eq!(first_edge, edge_count);
+
+            let node_name = |node: usize| string(field(nodes, node * 7 + 1));
+            let node_type = |node: usize| field(nodes, node * 7);
+            let property = |node: usize, name: &str| {
+                let start = first_edges[node];
+                (start..start + field(nodes, node * 7 + 4)).find_map(|edge| {
+                    let is_named = field(edges, edge * 3) != 1 && field(edges, edge * 3) != 4;
+                    (is_named && string(field(edges, edge * 3 + 1)) == name)
+                        .then(|| field(edges, edge * 3 + 2) / 7)
+                })
+            };
+
+            // The root node has a shortcut to the global object.
+            let global = property(0, "global").unwrap();
+            let target = property(global, "snapshotTarget").unwrap();
+            assert_eq!(node_name(target), "Object");
+
+            let point = property(target, "point").unwrap();
+            assert_eq!(node_name(point), "Point");
+            let prototype = property(point, "__proto__").unwrap();
+            let constructor = property(prototype, "constructor").unwrap();
+            assert_eq!(node_name(constructor), "Point");
+            assert_eq!(node_type(constructor), 5);
+
+            let list = property(target, "list").unwrap();
+            assert_eq!(node_type(list), 1);
+            let start = first_edges[list];
+            assert!((start..start + field(nodes, list * 7 + 4))
+                .any(|edge| field(edges, edge * 3) == 1 && field(edges, edge * 3 + 1) == 0));
+        }),
+    ]);
+}
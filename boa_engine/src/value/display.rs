@@ -194,7 +194,7 @@ pub(crate) fn log_string_from(x: &JsValue, print_internals: bool, print_children
                         format!("Set({size})")
                     }
                 }
-                ObjectKind::Error(_) => {
+                ObjectKind::Error(..) => {
                     let name: Cow<'static, str> = v
                         .get_property(&utf16!("name").into())
                         .as_ref()
@@ -6,9 +6,12 @@ mod abrupt_record;
 mod env_stack;
 
 use crate::{
-    builtins::promise::PromiseCapability, environments::BindingLocator, object::JsObject,
+    builtins::{disposable_stack::DisposeCapability, promise::PromiseCapability},
+    environments::BindingLocator,
+    object::JsObject,
     vm::CodeBlock,
 };
+use boa_ast::Position;
 use boa_gc::{Finalize, Gc, Trace};
 use thin_vec::ThinVec;
 
@@ -43,6 +46,9 @@ pub struct CallFrame {
 
     // The stack of bindings being updated.
     pub(crate) binding_stack: Vec<BindingLocator>,
+
+    // The dispose capabilities of the `using` declaration scopes currently being evaluated.
+    pub(crate) dispose_capabilities: Vec<DisposeCapability>,
 }
 
 /// ---- `CallFrame` public API ----
@@ -52,6 +58,13 @@ impl CallFrame {
     pub const fn code_block(&self) -> &Gc<CodeBlock> {
         &self.code_block
     }
+
+    /// Retrieves the source position this call frame is currently executing, if known.
+    #[inline]
+    #[must_use]
+    pub fn position(&self) -> Option<Position> {
+        self.code_block.position(self.pc)
+    }
 }
 
 /// ---- `CallFrame` creation methods ----
@@ -73,6 +86,7 @@ impl CallFrame {
             async_generator: None,
             iterators: ThinVec::new(),
             binding_stack: Vec::new(),
+            dispose_capabilities: Vec::new(),
         }
     }
 
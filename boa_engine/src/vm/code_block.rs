@@ -19,11 +19,14 @@ use crate::{
     Context, JsError, JsResult, JsString, JsValue,
 };
 use bitflags::bitflags;
-use boa_ast::function::{FormalParameterList, PrivateName};
+use boa_ast::{
+    function::{FormalParameterList, PrivateName},
+    Position,
+};
 use boa_gc::{empty_trace, Finalize, Gc, GcRefCell, Trace};
 use boa_interner::Sym;
 use boa_profiler::Profiler;
-use std::{cell::Cell, collections::VecDeque, mem::size_of};
+use std::{cell::Cell, collections::VecDeque, mem::size_of, path::Path, rc::Rc};
 use thin_vec::ThinVec;
 
 #[cfg(any(feature = "trace", feature = "flowgraph"))]
@@ -135,6 +138,14 @@ pub struct CodeBlock {
     /// The `[[ClassFieldInitializerName]]` internal slot.
     #[unsafe_ignore_trace]
     pub(crate) class_field_initializer_name: Option<Sym>,
+
+    /// Path of the source file this function was defined in.
+    #[unsafe_ignore_trace]
+    pub(crate) source_path: Option<Rc<Path>>,
+
+    /// Source positions of instructions, sorted by their program counter.
+    #[unsafe_ignore_trace]
+    pub(crate) positions: Box<[(u32, Position)]>,
 }
 
 /// ---- `CodeBlock` public API ----
@@ -158,6 +169,8 @@ impl CodeBlock {
             params: FormalParameterList::default(),
             compile_environments: Box::default(),
             class_field_initializer_name: None,
+            source_path: None,
+            positions: Box::default(),
         }
     }
 
@@ -167,6 +180,24 @@ impl CodeBlock {
         self.name
     }
 
+    /// Retrieves the path of the source file this code block was compiled from, if any.
+    #[must_use]
+    pub fn source_path(&self) -> Option<&Path> {
+        self.source_path.as_deref()
+    }
+
+    /// Gets the source position of the instruction that was executing when the program counter
+    /// advanced to `pc`.
+    ///
+    /// Returns `None` if no position was recorded before `pc`.
+    #[must_use]
+    pub fn position(&self, pc: u32) -> Option<Position> {
+        let index = self
+            .positions
+            .partition_point(|(position_pc, _)| *position_pc < pc);
+        index.checked_sub(1).map(|index| self.positions[index].1)
+    }
+
     /// Check if the function is traced.
     #[cfg(feature = "trace")]
     pub(crate) fn traceable(&self) -> bool {
@@ -273,7 +304,7 @@ impl CodeBlock {
                 }
                 .to_owned()
             }
-            Opcode::RotateLeft | Opcode::RotateRight => {
+            Opcode::RotateLeft | Opcode::RotateRight | Opcode::AddDisposableResource => {
                 let result = self.read::<u8>(*pc).to_string();
                 *pc += size_of::<u8>();
                 result
@@ -423,6 +454,7 @@ impl CodeBlock {
             | Opcode::SetPrivateGetter
             | Opcode::GetPrivateField
             | Opcode::PushClassFieldPrivate
+            | Opcode::PushClassStaticFieldPrivate
             | Opcode::PushClassPrivateGetter
             | Opcode::PushClassPrivateSetter
             | Opcode::PushClassPrivateMethod
@@ -434,6 +466,25 @@ impl CodeBlock {
                     interner.resolve_expect(self.private_names[operand as usize].description()),
                 )
             }
+            Opcode::PushClassDecoratedElement => {
+                let kind = self.read::<u8>(*pc);
+                *pc += size_of::<u8>();
+                let is_static = self.read::<u8>(*pc);
+                *pc += size_of::<u8>();
+                format!("kind: {kind}, static: {is_static}")
+            }
+            Opcode::PushClassDecoratedPrivateElement => {
+                let kind = self.read::<u8>(*pc);
+                *pc += size_of::<u8>();
+                let is_static = self.read::<u8>(*pc);
+                *pc += size_of::<u8>();
+                let operand = self.read::<u32>(*pc);
+                *pc += size_of::<u32>();
+                format!(
+                    "kind: {kind}, static: {is_static}, {operand:04}: '{}'",
+                    interner.resolve_expect(self.private_names[operand as usize].description()),
+                )
+            }
             Opcode::PushPrivateEnvironment => {
                 let count = self.read::<u32>(*pc);
                 *pc += size_of::<u32>() * (count as usize + 1);
@@ -541,6 +592,9 @@ impl CodeBlock {
             | Opcode::Yield
             | Opcode::GeneratorNext
             | Opcode::PushClassField
+            | Opcode::PushClassStaticField
+            | Opcode::PushClassStaticBlock
+            | Opcode::InitializeClass
             | Opcode::SuperCallDerived
             | Opcode::Await
             | Opcode::NewTarget
@@ -556,6 +610,9 @@ impl CodeBlock {
             | Opcode::SetNameByLocator
             | Opcode::PopPrivateEnvironment
             | Opcode::ImportCall
+            | Opcode::CreateDisposeCapability
+            | Opcode::DisposeResources
+            | Opcode::AsyncDisposeResources
             | Opcode::Nop => String::new(),
             Opcode::Reserved1
             | Opcode::Reserved2
@@ -602,17 +659,7 @@ impl CodeBlock {
             | Opcode::Reserved43
             | Opcode::Reserved44
             | Opcode::Reserved45
-            | Opcode::Reserved46
-            | Opcode::Reserved47
-            | Opcode::Reserved48
-            | Opcode::Reserved49
-            | Opcode::Reserved50
-            | Opcode::Reserved51
-            | Opcode::Reserved52
-            | Opcode::Reserved53
-            | Opcode::Reserved54
-            | Opcode::Reserved55
-            | Opcode::Reserved56 => unreachable!("Reserved opcodes are unrechable"),
+            | Opcode::Reserved46 => unreachable!("Reserved opcodes are unrechable"),
         }
     }
 }
@@ -737,6 +784,8 @@ pub(crate) fn create_function_object(
                 private_methods: ThinVec::new(),
                 class_object: None,
                 script_or_module,
+                static_elements: ThinVec::new(),
+                decorators: None,
             },
             context.realm().clone(),
         )
@@ -818,6 +867,8 @@ pub(crate) fn create_function_object_fast(
             private_methods: ThinVec::new(),
             class_object: None,
             script_or_module,
+            static_elements: ThinVec::new(),
+            decorators: None,
         }
     };
 
@@ -971,6 +1022,8 @@ impl JsObject {
         args: &[JsValue],
         context: &mut Context<'_>,
     ) -> JsResult<JsValue> {
+        context.check_runtime_limits()?;
+
         let old_realm = context.realm().clone();
         let old_active_fn = context.vm.active_function.clone();
 
@@ -1105,6 +1158,11 @@ impl JsObject {
         let mut last_env = code.compile_environments.len() - 1;
 
         if let Some(class_object) = class_object {
+            let class_binding = class_object
+                .borrow()
+                .as_function()
+                .expect("class object must be a function")
+                .class_binding(&class_object);
             let index = context
                 .vm
                 .environments
@@ -1112,7 +1170,7 @@ impl JsObject {
             context
                 .vm
                 .environments
-                .put_lexical_value(index, 0, class_object.into());
+                .put_lexical_value(index, 0, class_binding.into());
             last_env -= 1;
         }
 
@@ -1209,7 +1267,10 @@ impl JsObject {
         std::mem::swap(&mut context.vm.active_runnable, &mut script_or_module);
 
         if let Some(promise_capability) = promise_capability {
-            Ok(promise_capability.promise().clone().into())
+            match result {
+                Err(err) if err.is_runtime_limit() => Err(err),
+                _ => Ok(promise_capability.promise().clone().into()),
+            }
         } else if gen {
             result?;
             let proto = this_function_object
@@ -1283,6 +1344,8 @@ impl JsObject {
         this_target: &JsValue,
         context: &mut Context<'_>,
     ) -> JsResult<Self> {
+        context.check_runtime_limits()?;
+
         let old_realm = context.realm().clone();
         let old_active_fn = context.vm.active_function.clone();
         let context = &mut context.guard(move |ctx| {
@@ -1382,6 +1445,11 @@ impl JsObject {
                 let mut last_env = code.compile_environments.len() - 1;
 
                 if code.has_binding_identifier() {
+                    let class_binding = self
+                        .borrow()
+                        .as_function()
+                        .expect("constructor must be a function")
+                        .class_binding(self);
                     let index = context
                         .vm
                         .environments
@@ -1389,7 +1457,7 @@ impl JsObject {
                     context
                         .vm
                         .environments
-                        .put_lexical_value(index, 0, self.clone().into());
+                        .put_lexical_value(index, 0, class_binding.into());
                     last_env -= 1;
                 }
 
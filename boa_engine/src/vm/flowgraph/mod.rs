@@ -59,7 +59,7 @@ impl CodeBlock {
                     graph.add_node(previous_pc, NodeShape::None, label.into(), Color::None);
                     graph.add_edge(previous_pc, pc, None, Color::None, EdgeStyle::Line);
                 }
-                Opcode::RotateLeft | Opcode::RotateRight => {
+                Opcode::RotateLeft | Opcode::RotateRight | Opcode::AddDisposableResource => {
                     pc += size_of::<u8>();
                     graph.add_node(previous_pc, NodeShape::None, label.into(), Color::None);
                     graph.add_edge(previous_pc, pc, None, Color::None, EdgeStyle::Line);
@@ -70,6 +70,16 @@ impl CodeBlock {
                     graph.add_node(previous_pc, NodeShape::None, label.into(), Color::None);
                     graph.add_edge(previous_pc, pc, None, Color::None, EdgeStyle::Line);
                 }
+                Opcode::PushClassDecoratedElement => {
+                    pc += size_of::<u8>() * 2;
+                    graph.add_node(previous_pc, NodeShape::None, label.into(), Color::None);
+                    graph.add_edge(previous_pc, pc, None, Color::None, EdgeStyle::Line);
+                }
+                Opcode::PushClassDecoratedPrivateElement => {
+                    pc += size_of::<u8>() * 2 + size_of::<u32>();
+                    graph.add_node(previous_pc, NodeShape::None, label.into(), Color::None);
+                    graph.add_edge(previous_pc, pc, None, Color::None, EdgeStyle::Line);
+                }
                 Opcode::PushInt16 => {
                     pc += size_of::<i16>();
                     graph.add_node(previous_pc, NodeShape::None, label.into(), Color::None);
@@ -452,6 +462,7 @@ impl CodeBlock {
                 | Opcode::GetPrivateField
                 | Opcode::DeletePropertyByName
                 | Opcode::PushClassFieldPrivate
+                | Opcode::PushClassStaticFieldPrivate
                 | Opcode::PushClassPrivateGetter
                 | Opcode::PushClassPrivateSetter
                 | Opcode::PushClassPrivateMethod
@@ -581,6 +592,9 @@ impl CodeBlock {
                 | Opcode::Yield
                 | Opcode::GeneratorNext
                 | Opcode::PushClassField
+                | Opcode::PushClassStaticField
+                | Opcode::PushClassStaticBlock
+                | Opcode::InitializeClass
                 | Opcode::SuperCallDerived
                 | Opcode::Await
                 | Opcode::NewTarget
@@ -596,6 +610,9 @@ impl CodeBlock {
                 | Opcode::PushObjectEnvironment
                 | Opcode::PopPrivateEnvironment
                 | Opcode::ImportCall
+                | Opcode::CreateDisposeCapability
+                | Opcode::DisposeResources
+                | Opcode::AsyncDisposeResources
                 | Opcode::Nop => {
                     graph.add_node(previous_pc, NodeShape::None, label.into(), Color::None);
                     graph.add_edge(previous_pc, pc, None, Color::None, EdgeStyle::Line);
@@ -667,17 +684,7 @@ impl CodeBlock {
                 | Opcode::Reserved43
                 | Opcode::Reserved44
                 | Opcode::Reserved45
-                | Opcode::Reserved46
-                | Opcode::Reserved47
-                | Opcode::Reserved48
-                | Opcode::Reserved49
-                | Opcode::Reserved50
-                | Opcode::Reserved51
-                | Opcode::Reserved52
-                | Opcode::Reserved53
-                | Opcode::Reserved54
-                | Opcode::Reserved55
-                | Opcode::Reserved56 => unreachable!("Reserved opcodes are unrechable"),
+                | Opcode::Reserved46 => unreachable!("Reserved opcodes are unrechable"),
             }
         }
 
@@ -4,24 +4,20 @@
 //! This module will provide an instruction set for the AST to use, various traits,
 //! plus an interpreter to execute those instructions
 
-#[cfg(feature = "fuzz")]
-use crate::JsNativeError;
 use crate::{
     builtins::async_generator::{AsyncGenerator, AsyncGeneratorState},
     environments::{DeclarativeEnvironment, EnvironmentStack},
     script::Script,
     vm::code_block::Readable,
-    Context, JsError, JsObject, JsResult, JsValue, Module,
+    Context, JsError, JsNativeError, JsObject, JsResult, JsValue, Module,
 };
 
 use boa_gc::{custom_trace, Finalize, Gc, Trace};
 use boa_profiler::Profiler;
-use std::mem::size_of;
+use std::{mem::size_of, time::Instant};
 
 #[cfg(feature = "trace")]
 use boa_interner::ToInternedString;
-#[cfg(feature = "trace")]
-use std::time::Instant;
 
 mod call_frame;
 mod code_block;
@@ -33,7 +29,8 @@ mod runtime_limits;
 #[cfg(feature = "flowgraph")]
 pub mod flowgraph;
 
-pub use runtime_limits::RuntimeLimits;
+pub(crate) use runtime_limits::RuntimeUsage;
+pub use runtime_limits::{RuntimeBudget, RuntimeLimits};
 pub use {call_frame::CallFrame, code_block::CodeBlock, opcode::Opcode};
 
 pub(crate) use {
@@ -43,7 +40,7 @@ pub(crate) use {
         CodeBlockFlags,
     },
     completion_record::CompletionRecord,
-    opcode::BindingOpcode,
+    opcode::{load_dynamic_import, BindingOpcode},
 };
 
 #[cfg(test)]
@@ -59,6 +56,7 @@ pub struct Vm {
     #[cfg(feature = "trace")]
     pub(crate) trace: bool,
     pub(crate) runtime_limits: RuntimeLimits,
+    pub(crate) runtime_usage: RuntimeUsage,
     pub(crate) active_function: Option<JsObject>,
     pub(crate) active_runnable: Option<ActiveRunnable>,
 }
@@ -90,6 +88,7 @@ impl Vm {
             #[cfg(feature = "trace")]
             trace: false,
             runtime_limits: RuntimeLimits::default(),
+            runtime_usage: RuntimeUsage::default(),
             active_function: None,
             active_runnable: None,
         }
@@ -157,7 +156,11 @@ pub(crate) enum CompletionType {
 }
 
 impl Context<'_> {
-    fn execute_instruction(&mut self) -> JsResult<CompletionType> {
+    fn execute_instruction(&mut self, has_runtime_budget: bool) -> JsResult<CompletionType> {
+        if has_runtime_budget {
+            self.consume_runtime_budget()?;
+        }
+
         let opcode: Opcode = {
             let _timer = Profiler::global().start_event("Opcode retrieval", "vm");
 
@@ -174,6 +177,56 @@ impl Context<'_> {
         opcode.execute(self)
     }
 
+    /// Accounts for the execution of an instruction in the budgets of the [`RuntimeLimits`].
+    ///
+    /// This is only called if [`RuntimeLimits::has_runtime_budget`] is `true`, so runs without
+    /// budgets don't pay for it.
+    #[inline(never)]
+    fn consume_runtime_budget(&mut self) -> JsResult<()> {
+        let usage = &mut self.vm.runtime_usage;
+        usage.instructions += 1;
+        if usage.instructions > self.vm.runtime_limits.instruction_limit() {
+            self.exhaust_runtime_budget(RuntimeBudget::Instructions)?;
+        }
+
+        let usage = &mut self.vm.runtime_usage;
+        if let Some(time_limit) = self.vm.runtime_limits.time_limit() {
+            if usage.instructions % RuntimeUsage::TIME_CHECK_INTERVAL == 0 {
+                let start = *usage.start.get_or_insert_with(Instant::now);
+                if start.elapsed() > time_limit {
+                    self.exhaust_runtime_budget(RuntimeBudget::Time)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Lets the host refuel an exhausted budget, or aborts the execution.
+    #[cold]
+    fn exhaust_runtime_budget(&mut self, budget: RuntimeBudget) -> JsResult<()> {
+        if self.host_hooks().runtime_budget_exhausted(budget, self) {
+            let usage = &mut self.vm.runtime_usage;
+            match budget {
+                RuntimeBudget::Instructions => usage.instructions = 0,
+                RuntimeBudget::Time => usage.start = Some(Instant::now()),
+            }
+            return Ok(());
+        }
+
+        let message = match budget {
+            RuntimeBudget::Instructions => format!(
+                "Maximum instruction count {} exceeded",
+                self.vm.runtime_limits.instruction_limit()
+            ),
+            RuntimeBudget::Time => format!(
+                "Maximum execution time {:?} exceeded",
+                self.vm.runtime_limits.time_limit().unwrap_or_default()
+            ),
+        };
+        Err(JsNativeError::runtime_limit().with_message(message).into())
+    }
+
     pub(crate) fn run(&mut self) -> CompletionRecord {
         #[cfg(feature = "trace")]
         const COLUMN_WIDTH: usize = 26;
@@ -188,6 +241,14 @@ impl Context<'_> {
 
         let _timer = Profiler::global().start_event("run", "vm");
 
+        // Every eval or job starts with a single frame, and runs nested in it share its budgets.
+        // Interrupts only abort the run they were requested for, so a request that arrived after
+        // the last check point of the previous run is discarded.
+        if self.vm.frames.len() == 1 {
+            self.vm.runtime_usage.reset(&self.vm.runtime_limits);
+            self.interrupt_handle.take();
+        }
+
         #[cfg(feature = "trace")]
         if self.vm.trace {
             let msg = if self.vm.frames.last().is_some() {
@@ -224,6 +285,14 @@ impl Context<'_> {
         // The relevant spec section is 3. in [AsyncBlockStart](https://tc39.es/ecma262/#sec-asyncblockstart).
         let promise_capability = self.vm.frame().promise_capability.clone();
 
+        // Changes to the limits made during a run apply from the next run, so this is checked once.
+        let has_runtime_budget = self.vm.runtime_limits.has_runtime_budget();
+
+        // The heap limit of this context applies to the allocations of the code it runs, so it is
+        // set in the collector until the run ends.
+        let previous_heap_limit = boa_gc::heap_limit();
+        boa_gc::set_heap_limit(self.vm.runtime_limits.heap_limit());
+
         let execution_completion = loop {
             // 1. Exit the execution loop if program counter ever is equal to or exceeds the amount of instructions
             if self.vm.frame().code_block.bytecode.len() <= self.vm.frame().pc as usize {
@@ -258,7 +327,7 @@ impl Context<'_> {
                     .instruction_operands(&mut pc, self.interner());
 
                 let instant = Instant::now();
-                let result = self.execute_instruction();
+                let result = self.execute_instruction(has_runtime_budget);
                 let duration = instant.elapsed();
 
                 println!(
@@ -275,11 +344,11 @@ impl Context<'_> {
 
                 result
             } else {
-                self.execute_instruction()
+                self.execute_instruction(has_runtime_budget)
             };
 
             #[cfg(not(feature = "trace"))]
-            let result = self.execute_instruction();
+            let result = self.execute_instruction(has_runtime_budget);
 
             // 2. Evaluate the result of executing the instruction.
             match result {
@@ -307,12 +376,29 @@ impl Context<'_> {
                         // If we hit the execution step limit, bubble up the error to the
                         // (Rust) caller instead of trying to handle as an exception.
                         if native_error.is_runtime_limit() {
+                            // Pop the environments of the frame, since no handler will do it.
+                            let env_num: usize = self
+                                .vm
+                                .frame_mut()
+                                .env_stack
+                                .drain(1..)
+                                .map(|entry| entry.env_num())
+                                .sum();
+                            let global = &mut self.vm.frame_mut().env_stack[0];
+                            let env_num = env_num + global.env_num();
+                            global.clear_env_num();
+                            let env_truncation_len =
+                                self.vm.environments.len().saturating_sub(env_num);
+                            self.vm.environments.truncate(env_truncation_len);
+
                             self.vm.err = Some(err);
                             break CompletionType::Throw;
                         }
                     }
 
-                    self.vm.err = Some(err);
+                    // Native errors are converted to error objects lazily, possibly after
+                    // unwinding some frames, so the stack must be recorded where they are raised.
+                    self.vm.err = Some(err.with_stack_frames(self));
 
                     // If this frame has not evaluated the throw as an AbruptCompletion, then evaluate it
                     let evaluation = Opcode::Throw
@@ -328,6 +414,8 @@ impl Context<'_> {
             }
         };
 
+        boa_gc::set_heap_limit(previous_heap_limit);
+
         // Early return immediately after loop.
         if self.vm.frame().r#yield {
             let result = self.vm.pop();
@@ -393,10 +481,13 @@ impl Context<'_> {
                 }
                 CompletionType::Throw => {
                     let err = self.vm.err.take().expect("Take must exist on a Throw");
-                    promise
-                        .reject()
-                        .call(&JsValue::undefined(), &[err.to_opaque(self)], self)
-                        .expect("cannot fail per spec");
+                    // Runtime limit errors cannot be caught, so the caller receives them instead.
+                    if !err.is_runtime_limit() {
+                        promise
+                            .reject()
+                            .call(&JsValue::undefined(), &[err.to_opaque(self)], self)
+                            .expect("cannot fail per spec");
+                    }
                     self.vm.err = Some(err);
                 }
             }
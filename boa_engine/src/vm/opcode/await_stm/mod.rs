@@ -4,7 +4,7 @@ use crate::{
     builtins::{generator::GeneratorContext, Promise},
     native_function::NativeFunction,
     object::FunctionObjectBuilder,
-    vm::{opcode::Operation, CompletionType, GeneratorResumeKind},
+    vm::{opcode::Operation, CompletionRecord, CompletionType, GeneratorResumeKind},
     Context, JsArgs, JsResult, JsValue,
 };
 
@@ -45,7 +45,7 @@ impl Operation for Await {
                     // d. Resume the suspended evaluation of asyncContext using NormalCompletion(value) as the result of the operation that suspended it.
                     let mut gen = captures.borrow_mut().take().expect("should only run once");
 
-                    gen.resume(
+                    let completion = gen.resume(
                         Some(args.get_or_undefined(0).clone()),
                         GeneratorResumeKind::Normal,
                         context,
@@ -63,6 +63,13 @@ impl Operation for Await {
                             .context = Some(gen);
                     }
 
+                    // Runtime limit errors cannot be caught, so they are propagated to the job runner.
+                    if let CompletionRecord::Throw(err) = completion {
+                        if err.is_runtime_limit() {
+                            return Err(err);
+                        }
+                    }
+
                     // e. Assert: When we reach this step, asyncContext has already been removed from the execution context stack and prevContext is the currently running execution context.
                     // f. Return undefined.
                     Ok(JsValue::undefined())
@@ -89,7 +96,7 @@ impl Operation for Await {
 
                     let mut gen = captures.borrow_mut().take().expect("should only run once");
 
-                    gen.resume(
+                    let completion = gen.resume(
                         Some(args.get_or_undefined(0).clone()),
                         GeneratorResumeKind::Throw,
                         context,
@@ -107,6 +114,13 @@ impl Operation for Await {
                             .context = Some(gen);
                     }
 
+                    // Runtime limit errors cannot be caught, so they are propagated to the job runner.
+                    if let CompletionRecord::Throw(err) = completion {
+                        if err.is_runtime_limit() {
+                            return Err(err);
+                        }
+                    }
+
                     Ok(JsValue::undefined())
                 },
                 captures,
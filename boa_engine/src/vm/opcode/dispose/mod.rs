@@ -0,0 +1,138 @@
+use crate::{
+    builtins::disposable_stack::{DisposableResource, DisposeCapability, DisposeHint},
+    vm::{call_frame::AbruptCompletionRecord, opcode::Operation, CompletionType},
+    Context, JsError, JsNativeError, JsResult,
+};
+
+/// Pops the dispose capability of the current `using` declaration scope.
+fn pop_dispose_capability(context: &mut Context<'_>) -> DisposeCapability {
+    context
+        .vm
+        .frame_mut()
+        .dispose_capabilities
+        .pop()
+        .expect("a dispose capability must exist")
+}
+
+/// Checks if the `finally` block disposing the resources was entered by a throw completion.
+fn is_throw_pending(context: &Context<'_>) -> bool {
+    context
+        .vm
+        .frame()
+        .abrupt_completion
+        .map_or(false, AbruptCompletionRecord::is_throw)
+}
+
+/// `CreateDisposeCapability` implements the Opcode Operation for `Opcode::CreateDisposeCapability`
+///
+/// Operation:
+///  - Creates a new dispose capability for the resources of a `using` declaration scope.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct CreateDisposeCapability;
+
+impl Operation for CreateDisposeCapability {
+    const NAME: &'static str = "CreateDisposeCapability";
+    const INSTRUCTION: &'static str = "INST - CreateDisposeCapability";
+
+    fn execute(context: &mut Context<'_>) -> JsResult<CompletionType> {
+        context
+            .vm
+            .frame_mut()
+            .dispose_capabilities
+            .push(DisposeCapability::default());
+        Ok(CompletionType::Normal)
+    }
+}
+
+/// `AddDisposableResource` implements the Opcode Operation for `Opcode::AddDisposableResource`
+///
+/// Operation:
+///  - Adds the value on top of the stack as a disposable resource of the current dispose capability.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct AddDisposableResource;
+
+impl Operation for AddDisposableResource {
+    const NAME: &'static str = "AddDisposableResource";
+    const INSTRUCTION: &'static str = "INST - AddDisposableResource";
+
+    fn execute(context: &mut Context<'_>) -> JsResult<CompletionType> {
+        let hint = DisposeHint::from(context.vm.read::<u8>());
+        let value = context.vm.pop();
+        context.vm.push(value.clone());
+
+        if let Some(resource) = DisposableResource::from_value(value, hint, None, context)? {
+            context
+                .vm
+                .frame_mut()
+                .dispose_capabilities
+                .last_mut()
+                .expect("a dispose capability must exist")
+                .push(resource);
+        }
+
+        Ok(CompletionType::Normal)
+    }
+}
+
+/// `DisposeResources` implements the Opcode Operation for `Opcode::DisposeResources`
+///
+/// Operation:
+///  - Pops the current dispose capability and disposes its resources.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DisposeResources;
+
+impl Operation for DisposeResources {
+    const NAME: &'static str = "DisposeResources";
+    const INSTRUCTION: &'static str = "INST - DisposeResources";
+
+    fn execute(context: &mut Context<'_>) -> JsResult<CompletionType> {
+        let capability = pop_dispose_capability(context);
+
+        if !is_throw_pending(context) {
+            capability.dispose_resources(None, context)?;
+            return Ok(CompletionType::Normal);
+        }
+
+        // The pending error is rethrown by the `FinallyEnd` of the scope, so any error thrown
+        // while disposing must replace it on the stack.
+        let pending = JsError::from_opaque(context.vm.pop());
+        let error = capability
+            .dispose_resources(Some(pending), context)
+            .expect_err("disposing with a pending error must return an error");
+        if error
+            .as_native()
+            .map_or(false, JsNativeError::is_runtime_limit)
+        {
+            return Err(error);
+        }
+        let error = error.to_opaque(context);
+        context.vm.push(error);
+
+        Ok(CompletionType::Normal)
+    }
+}
+
+/// `AsyncDisposeResources` implements the Opcode Operation for `Opcode::AsyncDisposeResources`
+///
+/// Operation:
+///  - Pops the current dispose capability and disposes its resources asynchronously, pushing the
+///    promise that settles when all of them have been disposed.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct AsyncDisposeResources;
+
+impl Operation for AsyncDisposeResources {
+    const NAME: &'static str = "AsyncDisposeResources";
+    const INSTRUCTION: &'static str = "INST - AsyncDisposeResources";
+
+    fn execute(context: &mut Context<'_>) -> JsResult<CompletionType> {
+        let capability = pop_dispose_capability(context);
+
+        // The promise rejects with the pending error if there's one, which gets thrown again
+        // after awaiting it.
+        let pending = is_throw_pending(context).then(|| JsError::from_opaque(context.vm.pop()));
+        let promise = capability.dispose_resources_async(pending, context)?;
+        context.vm.push(promise);
+
+        Ok(CompletionType::Normal)
+    }
+}
@@ -64,6 +64,8 @@ impl Operation for LoopContinue {
         let env_truncation_len = context.vm.environments.len().saturating_sub(env_num);
         context.vm.environments.truncate(env_truncation_len);
 
+        context.check_runtime_limits()?;
+
         Ok(CompletionType::Normal)
     }
 }
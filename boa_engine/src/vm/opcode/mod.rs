@@ -10,6 +10,7 @@ mod control_flow;
 mod copy;
 mod define;
 mod delete;
+mod dispose;
 mod dup;
 mod environment;
 mod generator;
@@ -49,6 +50,8 @@ pub(crate) use define::*;
 #[doc(inline)]
 pub(crate) use delete::*;
 #[doc(inline)]
+pub(crate) use dispose::*;
+#[doc(inline)]
 pub(crate) use dup::*;
 #[doc(inline)]
 pub(crate) use environment::*;
@@ -1024,6 +1027,54 @@ generate_impl! {
         /// Stack: class, method **=>**
         PushClassPrivateMethod,
 
+        /// Push a static field to the class, to be defined when the class is initialized.
+        ///
+        /// Operands:
+        ///
+        /// Stack: class, field_name, field_function **=>**
+        PushClassStaticField,
+
+        /// Push a static private field to the class, to be defined when the class is initialized.
+        ///
+        /// Operands: private_name_index: `u32`
+        ///
+        /// Stack: class, field_function **=>**
+        PushClassStaticFieldPrivate,
+
+        /// Push a static block to the class, to be evaluated when the class is initialized.
+        ///
+        /// Operands:
+        ///
+        /// Stack: class, block_function **=>**
+        PushClassStaticBlock,
+
+        /// Push a decorated element or an auto-accessor to the class.
+        ///
+        /// The value is absent for auto-accessors, whose storage is the last private field
+        /// pushed to the class.
+        ///
+        /// Operands: kind: `u8`, is_static: `u8`
+        ///
+        /// Stack: class, decorators, key, value **=>**
+        PushClassDecoratedElement,
+
+        /// Push a decorated private element or a private auto-accessor to the class.
+        ///
+        /// The value is absent for auto-accessors, whose storage is the last private field
+        /// pushed to the class.
+        ///
+        /// Operands: kind: `u8`, is_static: `u8`, private_name_index: `u32`
+        ///
+        /// Stack: class, decorators, value **=>**
+        PushClassDecoratedPrivateElement,
+
+        /// Apply the decorators of a class and evaluate its static elements.
+        ///
+        /// Operands:
+        ///
+        /// Stack: class_decorators, class **=>** class
+        InitializeClass,
+
         /// Deletes a property by name of an object.
         ///
         /// Like `delete object.key`
@@ -1173,6 +1224,41 @@ generate_impl! {
         /// Stack: **=>**
         FinallyEnd,
 
+        /// Creates a new dispose capability for the resources of a `using` declaration scope.
+        ///
+        /// Operands:
+        ///
+        /// Stack: **=>**
+        CreateDisposeCapability,
+
+        /// Adds the value on top of the stack as a disposable resource of the current dispose
+        /// capability.
+        ///
+        /// Operands: hint: `u8`
+        ///
+        /// Stack: value **=>** value
+        AddDisposableResource,
+
+        /// Pops the current dispose capability and disposes its resources.
+        ///
+        /// If a throw completion is pending, errors thrown while disposing are merged into the
+        /// pending error on top of the stack.
+        ///
+        /// Operands:
+        ///
+        /// Stack: **=>**
+        DisposeResources,
+
+        /// Pops the current dispose capability and disposes its resources asynchronously.
+        ///
+        /// If a throw completion is pending, the pending error is popped and the returned promise
+        /// rejects with it.
+        ///
+        /// Operands:
+        ///
+        /// Stack: **=>** promise
+        AsyncDisposeResources,
+
         /// Jumps to a target location and pops the environments involved.
         ///
         /// Operands: Jump Address: u32, Target address: u32
@@ -1240,7 +1326,7 @@ generate_impl! {
         ///
         /// Operands:
         ///
-        /// Stack: specifier **=>** promise
+        /// Stack: specifier, options **=>** promise
         ImportCall,
 
         /// Pop the two values of the stack, strict equal compares the two values,
@@ -1752,26 +1838,6 @@ generate_impl! {
         Reserved45 => Reserved,
         /// Reserved [`Opcode`].
         Reserved46 => Reserved,
-        /// Reserved [`Opcode`].
-        Reserved47 => Reserved,
-        /// Reserved [`Opcode`].
-        Reserved48 => Reserved,
-        /// Reserved [`Opcode`].
-        Reserved49 => Reserved,
-        /// Reserved [`Opcode`].
-        Reserved50 => Reserved,
-        /// Reserved [`Opcode`].
-        Reserved51 => Reserved,
-        /// Reserved [`Opcode`].
-        Reserved52 => Reserved,
-        /// Reserved [`Opcode`].
-        Reserved53 => Reserved,
-        /// Reserved [`Opcode`].
-        Reserved54 => Reserved,
-        /// Reserved [`Opcode`].
-        Reserved55 => Reserved,
-        /// Reserved [`Opcode`].
-        Reserved56 => Reserved,
     }
 }
 
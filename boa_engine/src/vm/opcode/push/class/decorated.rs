@@ -0,0 +1,266 @@
+use crate::{
+    builtins::function::{
+        decorators::{
+            create_auto_accessor, initialize_class, private_description, push_decorated_element,
+            DecoratedElement, DecoratedElementKind, ElementKey, ElementValue,
+        },
+        set_function_name, ClassFieldDefinition, ClassStaticElement,
+    },
+    js_string,
+    object::{JsFunction, JsObject, PrivateName, PROTOTYPE},
+    property::PropertyKey,
+    vm::{opcode::Operation, CompletionType},
+    Context, JsResult, JsValue,
+};
+use thin_vec::ThinVec;
+
+/// Collects the decorators of an element, which are either `undefined` or an array.
+fn decorator_list(decorators: &JsValue, context: &mut Context<'_>) -> JsResult<Vec<JsValue>> {
+    let Some(decorators) = decorators.as_object() else {
+        return Ok(Vec::new());
+    };
+    let length = decorators.length_of_array_like(context)?;
+    (0..length)
+        .map(|index| decorators.get(index, context))
+        .collect()
+}
+
+/// Prepares a decorated element of a class and records it in the class, so that its decorators
+/// get applied when the class is initialized.
+#[allow(clippy::too_many_arguments)]
+fn push_element(
+    class: &JsObject,
+    is_static: bool,
+    kind: DecoratedElementKind,
+    key: ElementKey,
+    name: &PropertyKey,
+    value: &JsValue,
+    decorators: Vec<JsValue>,
+    context: &mut Context<'_>,
+) -> JsResult<()> {
+    let value = match kind {
+        DecoratedElementKind::Method
+        | DecoratedElementKind::Getter
+        | DecoratedElementKind::Setter => {
+            let function = value
+                .as_object()
+                .expect("method must be function object")
+                .clone();
+            let home_object = if is_static {
+                class.clone()
+            } else {
+                class
+                    .get(PROTOTYPE, context)?
+                    .as_object()
+                    .expect("class prototype must be an object")
+                    .clone()
+            };
+            let prefix = match kind {
+                DecoratedElementKind::Getter => Some(js_string!("get")),
+                DecoratedElementKind::Setter => Some(js_string!("set")),
+                _ => None,
+            };
+            set_function_name(&function, name, prefix, context);
+            {
+                let mut function_mut = function.borrow_mut();
+                let function_mut = function_mut
+                    .as_function_mut()
+                    .expect("method must be function object");
+                function_mut.set_home_object(home_object);
+                function_mut.set_class_object(class.clone());
+            }
+            match kind {
+                DecoratedElementKind::Method => ElementValue::Method(function),
+                DecoratedElementKind::Getter => ElementValue::Getter(function),
+                _ => ElementValue::Setter(function),
+            }
+        }
+        DecoratedElementKind::Field => {
+            let function = value
+                .as_object()
+                .expect("field value must be function object");
+            {
+                let mut function_mut = function.borrow_mut();
+                let function_mut = function_mut
+                    .as_function_mut()
+                    .expect("field value must be function object");
+                function_mut.set_home_object(class.clone());
+                function_mut.set_class_object(class.clone());
+            }
+            let function = JsFunction::from_object_unchecked(function.clone());
+            let field = match &key {
+                ElementKey::Public(key) => {
+                    ClassFieldDefinition::Public(key.clone(), function, ThinVec::new())
+                }
+                ElementKey::Private(name) => {
+                    ClassFieldDefinition::Private(*name, function, ThinVec::new())
+                }
+            };
+
+            let mut class_mut = class.borrow_mut();
+            let class_function = class_mut
+                .as_function_mut()
+                .expect("class must be function object");
+            let index = if is_static {
+                let elements = class_function
+                    .static_elements_mut()
+                    .expect("class must be an ordinary function");
+                elements.push(ClassStaticElement::Field(field));
+                elements.len() - 1
+            } else {
+                let fields = class_function
+                    .fields_mut()
+                    .expect("class must be an ordinary function");
+                fields.push(field);
+                fields.len() - 1
+            };
+            ElementValue::Field(index)
+        }
+        DecoratedElementKind::Accessor => {
+            // The backing storage of the accessor is the private field pushed right before it.
+            let (storage, index) = last_private_field(class, is_static);
+            let (getter, setter) = create_auto_accessor(name, storage, context);
+            ElementValue::Accessor {
+                getter,
+                setter,
+                storage: index,
+            }
+        }
+    };
+
+    push_decorated_element(
+        class,
+        DecoratedElement::new(is_static, key, value, decorators),
+    );
+    Ok(())
+}
+
+/// Returns the name and the index of the last private field pushed to a class.
+fn last_private_field(class: &JsObject, is_static: bool) -> (PrivateName, usize) {
+    let mut class_mut = class.borrow_mut();
+    let class_function = class_mut
+        .as_function_mut()
+        .expect("class must be function object");
+    let field = if is_static {
+        let elements = class_function
+            .static_elements_mut()
+            .expect("class must be an ordinary function");
+        elements.last().and_then(|element| match element {
+            ClassStaticElement::Field(field) => Some((field.clone(), elements.len() - 1)),
+            ClassStaticElement::Block(_) => None,
+        })
+    } else {
+        let fields = class_function
+            .fields_mut()
+            .expect("class must be an ordinary function");
+        fields.last().map(|field| (field.clone(), fields.len() - 1))
+    };
+    match field {
+        Some((ClassFieldDefinition::Private(name, _, _), index)) => (name, index),
+        _ => unreachable!("auto-accessors must be preceded by their storage field"),
+    }
+}
+
+/// `PushClassDecoratedElement` implements the Opcode Operation for `Opcode::PushClassDecoratedElement`
+///
+/// Operation:
+///  - Push a decorated element or an auto-accessor to the class.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct PushClassDecoratedElement;
+
+impl Operation for PushClassDecoratedElement {
+    const NAME: &'static str = "PushClassDecoratedElement";
+    const INSTRUCTION: &'static str = "INST - PushClassDecoratedElement";
+
+    fn execute(context: &mut Context<'_>) -> JsResult<CompletionType> {
+        let kind = DecoratedElementKind::from_u8(context.vm.read::<u8>());
+        let is_static = context.vm.read::<u8>() != 0;
+        let value = if kind == DecoratedElementKind::Accessor {
+            JsValue::undefined()
+        } else {
+            context.vm.pop()
+        };
+        let key = context.vm.pop();
+        let decorators = context.vm.pop();
+        let class = context.vm.pop();
+        let class = class.as_object().expect("class must be function object");
+
+        let key = key.to_property_key(context)?;
+        let decorators = decorator_list(&decorators, context)?;
+        push_element(
+            class,
+            is_static,
+            kind,
+            ElementKey::Public(key.clone()),
+            &key,
+            &value,
+            decorators,
+            context,
+        )?;
+        Ok(CompletionType::Normal)
+    }
+}
+
+/// `PushClassDecoratedPrivateElement` implements the Opcode Operation for `Opcode::PushClassDecoratedPrivateElement`
+///
+/// Operation:
+///  - Push a decorated private element or a private auto-accessor to the class.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct PushClassDecoratedPrivateElement;
+
+impl Operation for PushClassDecoratedPrivateElement {
+    const NAME: &'static str = "PushClassDecoratedPrivateElement";
+    const INSTRUCTION: &'static str = "INST - PushClassDecoratedPrivateElement";
+
+    fn execute(context: &mut Context<'_>) -> JsResult<CompletionType> {
+        let kind = DecoratedElementKind::from_u8(context.vm.read::<u8>());
+        let is_static = context.vm.read::<u8>() != 0;
+        let index = context.vm.read::<u32>();
+        let name = context.vm.frame().code_block.private_names[index as usize];
+        let value = if kind == DecoratedElementKind::Accessor {
+            JsValue::undefined()
+        } else {
+            context.vm.pop()
+        };
+        let decorators = context.vm.pop();
+        let class = context.vm.pop();
+        let class = class.as_object().expect("class must be function object");
+
+        let name = class.private_name(name.description());
+        let description = private_description(name, context).into();
+        let decorators = decorator_list(&decorators, context)?;
+        push_element(
+            class,
+            is_static,
+            kind,
+            ElementKey::Private(name),
+            &description,
+            &value,
+            decorators,
+            context,
+        )?;
+        Ok(CompletionType::Normal)
+    }
+}
+
+/// `InitializeClass` implements the Opcode Operation for `Opcode::InitializeClass`
+///
+/// Operation:
+///  - Apply the decorators of a class and evaluate its static elements.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct InitializeClass;
+
+impl Operation for InitializeClass {
+    const NAME: &'static str = "InitializeClass";
+    const INSTRUCTION: &'static str = "INST - InitializeClass";
+
+    fn execute(context: &mut Context<'_>) -> JsResult<CompletionType> {
+        let class = context.vm.pop();
+        let class_decorators = context.vm.pop();
+        let class = class.as_object().expect("class must be function object");
+
+        let class = initialize_class(class, &class_decorators, context)?;
+        context.vm.push(class);
+        Ok(CompletionType::Normal)
+    }
+}
@@ -1,8 +1,10 @@
 use crate::{
+    builtins::function::{ClassFieldDefinition, ClassStaticElement},
     object::JsFunction,
     vm::{opcode::Operation, CompletionType},
     Context, JsResult,
 };
+use thin_vec::ThinVec;
 
 /// `PushClassField` implements the Opcode Operation for `Opcode::PushClassField`
 ///
@@ -86,3 +88,133 @@ impl Operation for PushClassFieldPrivate {
         Ok(CompletionType::Normal)
     }
 }
+
+/// `PushClassStaticField` implements the Opcode Operation for `Opcode::PushClassStaticField`
+///
+/// Operation:
+///  - Push a static field to the class, to be defined when the class is initialized.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct PushClassStaticField;
+
+impl Operation for PushClassStaticField {
+    const NAME: &'static str = "PushClassStaticField";
+    const INSTRUCTION: &'static str = "INST - PushClassStaticField";
+
+    fn execute(context: &mut Context<'_>) -> JsResult<CompletionType> {
+        let field_function_value = context.vm.pop();
+        let field_name_value = context.vm.pop();
+        let class_value = context.vm.pop();
+
+        let field_name_key = field_name_value.to_property_key(context)?;
+        let field_function_object = field_function_value
+            .as_object()
+            .expect("field value must be function object");
+        let mut field_function_object_borrow = field_function_object.borrow_mut();
+        let field_function = field_function_object_borrow
+            .as_function_mut()
+            .expect("field value must be function object");
+        let class_object = class_value
+            .as_object()
+            .expect("class must be function object");
+        field_function.set_home_object(class_object.clone());
+        field_function.set_class_object(class_object.clone());
+        class_object
+            .borrow_mut()
+            .as_function_mut()
+            .expect("class must be function object")
+            .static_elements_mut()
+            .expect("class must be an ordinary function")
+            .push(ClassStaticElement::Field(ClassFieldDefinition::Public(
+                field_name_key,
+                JsFunction::from_object_unchecked(field_function_object.clone()),
+                ThinVec::new(),
+            )));
+        Ok(CompletionType::Normal)
+    }
+}
+
+/// `PushClassStaticFieldPrivate` implements the Opcode Operation for `Opcode::PushClassStaticFieldPrivate`
+///
+/// Operation:
+///  - Push a static private field to the class, to be defined when the class is initialized.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct PushClassStaticFieldPrivate;
+
+impl Operation for PushClassStaticFieldPrivate {
+    const NAME: &'static str = "PushClassStaticFieldPrivate";
+    const INSTRUCTION: &'static str = "INST - PushClassStaticFieldPrivate";
+
+    fn execute(context: &mut Context<'_>) -> JsResult<CompletionType> {
+        let index = context.vm.read::<u32>();
+        let name = context.vm.frame().code_block.private_names[index as usize];
+        let field_function_value = context.vm.pop();
+        let class_value = context.vm.pop();
+
+        let field_function_object = field_function_value
+            .as_object()
+            .expect("field value must be function object");
+        let mut field_function_object_borrow = field_function_object.borrow_mut();
+        let field_function = field_function_object_borrow
+            .as_function_mut()
+            .expect("field value must be function object");
+        let class_object = class_value
+            .as_object()
+            .expect("class must be function object");
+        field_function.set_home_object(class_object.clone());
+        field_function.set_class_object(class_object.clone());
+
+        class_object
+            .borrow_mut()
+            .as_function_mut()
+            .expect("class must be function object")
+            .static_elements_mut()
+            .expect("class must be an ordinary function")
+            .push(ClassStaticElement::Field(ClassFieldDefinition::Private(
+                class_object.private_name(name.description()),
+                JsFunction::from_object_unchecked(field_function_object.clone()),
+                ThinVec::new(),
+            )));
+        Ok(CompletionType::Normal)
+    }
+}
+
+/// `PushClassStaticBlock` implements the Opcode Operation for `Opcode::PushClassStaticBlock`
+///
+/// Operation:
+///  - Push a static block to the class, to be evaluated when the class is initialized.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct PushClassStaticBlock;
+
+impl Operation for PushClassStaticBlock {
+    const NAME: &'static str = "PushClassStaticBlock";
+    const INSTRUCTION: &'static str = "INST - PushClassStaticBlock";
+
+    fn execute(context: &mut Context<'_>) -> JsResult<CompletionType> {
+        let block_function_value = context.vm.pop();
+        let class_value = context.vm.pop();
+
+        let block_function_object = block_function_value
+            .as_object()
+            .expect("static block must be function object");
+        let mut block_function_object_borrow = block_function_object.borrow_mut();
+        let block_function = block_function_object_borrow
+            .as_function_mut()
+            .expect("static block must be function object");
+        let class_object = class_value
+            .as_object()
+            .expect("class must be function object");
+        block_function.set_home_object(class_object.clone());
+        block_function.set_class_object(class_object.clone());
+
+        class_object
+            .borrow_mut()
+            .as_function_mut()
+            .expect("class must be function object")
+            .static_elements_mut()
+            .expect("class must be an ordinary function")
+            .push(ClassStaticElement::Block(
+                JsFunction::from_object_unchecked(block_function_object.clone()),
+            ));
+        Ok(CompletionType::Normal)
+    }
+}
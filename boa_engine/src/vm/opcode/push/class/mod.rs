@@ -6,9 +6,11 @@ use crate::{
     Context, JsResult, JsValue,
 };
 
+pub(crate) mod decorated;
 pub(crate) mod field;
 pub(crate) mod private;
 
+pub(crate) use decorated::*;
 pub(crate) use field::*;
 pub(crate) use private::*;
 
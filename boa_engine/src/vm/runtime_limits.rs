@@ -1,3 +1,5 @@
+use std::time::{Duration, Instant};
+
 /// Represents the limits of different runtime operations.
 #[derive(Debug, Clone, Copy)]
 pub struct RuntimeLimits {
@@ -9,6 +11,15 @@ pub struct RuntimeLimits {
 
     /// Max function recursion limit
     resursion_limit: usize,
+
+    /// Max executed instructions per run before an error is thrown.
+    instruction_limit: u64,
+
+    /// Max elapsed time per run before an error is thrown.
+    time_limit: Option<Duration>,
+
+    /// Max heap size in bytes before an error is thrown.
+    heap_limit: Option<usize>,
 }
 
 impl Default for RuntimeLimits {
@@ -18,6 +29,9 @@ impl Default for RuntimeLimits {
             loop_iteration_limit: u64::MAX,
             resursion_limit: 400,
             stack_size_limit: 1024,
+            instruction_limit: u64::MAX,
+            time_limit: None,
+            heap_limit: None,
         }
     }
 }
@@ -75,4 +89,115 @@ impl RuntimeLimits {
     pub fn set_recursion_limit(&mut self, value: usize) {
         self.resursion_limit = value;
     }
+
+    /// Return the instruction limit.
+    ///
+    /// If a run of the VM, like the evaluation of a script or a job, executes more instructions
+    /// than the limit, [`HostHooks::runtime_budget_exhausted`] decides whether to refuel the
+    /// budget or to throw an error.
+    ///
+    /// The limit value [`u64::MAX`] means that there is no limit.
+    ///
+    /// [`HostHooks::runtime_budget_exhausted`]: crate::context::HostHooks::runtime_budget_exhausted
+    #[inline]
+    #[must_use]
+    pub const fn instruction_limit(&self) -> u64 {
+        self.instruction_limit
+    }
+
+    /// Set the instruction limit.
+    ///
+    /// Setting the limit to [`u64::MAX`] means that there is no limit.
+    #[inline]
+    pub fn set_instruction_limit(&mut self, value: u64) {
+        self.instruction_limit = value;
+    }
+
+    /// Disable instruction limit.
+    #[inline]
+    pub fn disable_instruction_limit(&mut self) {
+        self.instruction_limit = u64::MAX;
+    }
+
+    /// Get the time limit.
+    ///
+    /// If a run of the VM, like the evaluation of a script or a job, takes more time than the
+    /// limit, [`HostHooks::runtime_budget_exhausted`] decides whether to refuel the budget or to
+    /// throw an error. The time is checked periodically while running bytecode, so the time
+    /// spent in a single builtin call is not interrupted.
+    ///
+    /// [`HostHooks::runtime_budget_exhausted`]: crate::context::HostHooks::runtime_budget_exhausted
+    #[inline]
+    #[must_use]
+    pub const fn time_limit(&self) -> Option<Duration> {
+        self.time_limit
+    }
+
+    /// Set the time limit, or disable it with `None`.
+    ///
+    /// The time is measured with [`Instant`], so this must not be set on platforms that don't
+    /// support it.
+    #[inline]
+    pub fn set_time_limit(&mut self, value: Option<Duration>) {
+        self.time_limit = value;
+    }
+
+    /// Get the heap limit.
+    ///
+    /// If an allocation made while this context runs code would grow the garbage collected heap
+    /// past the limit, a collection is run, and if it cannot bring the heap below the limit, an
+    /// error is thrown at the next loop iteration or function call. The heap is shared by all the
+    /// contexts of a thread, so this accounts for the values of all of them.
+    ///
+    /// Only the boxes allocated by the garbage collector are counted, not the memory that values
+    /// own outside of them, like the elements of an array or the contents of a string. See
+    /// [`boa_gc::set_heap_limit`] for the details.
+    #[inline]
+    #[must_use]
+    pub const fn heap_limit(&self) -> Option<usize> {
+        self.heap_limit
+    }
+
+    /// Set the heap limit, or disable it with `None`.
+    #[inline]
+    pub fn set_heap_limit(&mut self, value: Option<usize>) {
+        self.heap_limit = value;
+    }
+
+    /// Returns `true` if the execution of every instruction must be accounted for, which is the
+    /// case if there is an instruction or a time limit.
+    pub(crate) const fn has_runtime_budget(&self) -> bool {
+        self.instruction_limit != u64::MAX || self.time_limit.is_some()
+    }
+}
+
+/// A budget of the [`RuntimeLimits`] that a single run of the VM can exhaust.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RuntimeBudget {
+    /// The [`RuntimeLimits::instruction_limit`].
+    Instructions,
+
+    /// The [`RuntimeLimits::time_limit`].
+    Time,
+}
+
+/// How much of its budgets the current run of the VM has used.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct RuntimeUsage {
+    /// The number of executed instructions.
+    pub(crate) instructions: u64,
+
+    /// The start of the run, if there is a time limit.
+    pub(crate) start: Option<Instant>,
+}
+
+impl RuntimeUsage {
+    /// The number of instructions executed between checks of the time limit.
+    pub(crate) const TIME_CHECK_INTERVAL: u64 = 1024;
+
+    /// Resets the usage of all the budgets at the start of a run.
+    pub(crate) fn reset(&mut self, limits: &RuntimeLimits) {
+        self.instructions = 0;
+        self.start = limits.time_limit().map(|_| Instant::now());
+    }
 }
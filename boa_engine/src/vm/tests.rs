@@ -1,5 +1,11 @@
-use crate::{run_test_actions, JsNativeErrorKind, JsValue, TestAction};
+use crate::{
+    context::{ContextBuilder, HostHooks},
+    run_test_actions, run_test_actions_with,
+    vm::RuntimeBudget,
+    Context, JsNativeErrorKind, JsValue, Source, TestAction,
+};
 use indoc::indoc;
+use std::{cell::Cell, time::Duration};
 
 #[test]
 fn typeof_string() {
@@ -309,3 +315,209 @@ fn recursion_runtime_limit() {
         ),
     ]);
 }
+
+#[test]
+fn heap_runtime_limit() {
+    run_test_actions([
+        TestAction::inspect_context(|context| {
+            context
+                .runtime_limits_mut()
+                .set_heap_limit(Some(1024 * 1024));
+        }),
+        TestAction::assert_native_error(
+            indoc! {r"
+                (() => {
+                    const values = [];
+                    while (true) {
+                        values.push({});
+                    }
+                })()
+            "},
+            JsNativeErrorKind::RuntimeLimit,
+            "Maximum heap size exceeded",
+        ),
+        TestAction::assert_native_error(
+            indoc! {r"
+                try {
+                    (() => {
+                        const values = [];
+                        while (true) {
+                            values.push({});
+                        }
+                    })()
+                } catch {}
+            "},
+            JsNativeErrorKind::RuntimeLimit,
+            "Maximum heap size exceeded",
+        ),
+        TestAction::assert_native_error(
+            indoc! {r"
+                (async () => {
+                    const values = [];
+                    while (true) {
+                        values.push({});
+                    }
+                })()
+            "},
+            JsNativeErrorKind::RuntimeLimit,
+            "Maximum heap size exceeded",
+        ),
+        TestAction::inspect_context(|context| {
+            assert!(context.gc_statistics().collections > 0);
+            context.runtime_limits_mut().set_heap_limit(None);
+        }),
+        TestAction::assert_eq("[1, 2, 3].length", 3),
+    ]);
+}
+
+#[test]
+fn heap_runtime_limit_is_per_context() {
+    let context = &mut Context::default();
+    context
+        .runtime_limits_mut()
+        .set_heap_limit(Some(1024 * 1024));
+
+    run_test_actions_with(
+        [
+            TestAction::inspect_context(|_| {
+                let unlimited = &mut Context::default();
+                let length = unlimited
+                    .eval(Source::from_bytes(indoc! {r"
+                        const values = [];
+                        for (let i = 0; i < 20000; i++) {
+                            values.push({});
+                        }
+                        values.length
+                    "}))
+                    .unwrap();
+                assert_eq!(length, JsValue::new(20_000));
+            }),
+            TestAction::assert_native_error(
+                indoc! {r"
+                    const values = [];
+                    while (true) {
+                        values.push({});
+                    }
+                "},
+                JsNativeErrorKind::RuntimeLimit,
+                "Maximum heap size exceeded",
+            ),
+        ],
+        context,
+    );
+}
+
+#[test]
+fn interrupt_handle() {
+    run_test_actions([
+        TestAction::inspect_context(|context| {
+            let handle = context.interrupt_handle();
+            std::thread::spawn(move || {
+                std::thread::sleep(std::time::Duration::from_millis(10));
+                handle.interrupt();
+            });
+        }),
+        TestAction::assert_native_error(
+            indoc! {r"
+                try {
+                    while (true) {}
+                } catch {}
+            "},
+            JsNativeErrorKind::RuntimeLimit,
+            "Execution interrupted",
+        ),
+        TestAction::inspect_context(|context| {
+            assert!(!context.interrupt_handle().is_interrupted());
+            context.interrupt_handle().interrupt();
+        }),
+        TestAction::assert_eq(
+            indoc! {r"
+                function f() {
+                    return 3;
+                }
+                f();
+            "},
+            3,
+        ),
+        TestAction::inspect_context(|context| {
+            assert!(!context.interrupt_handle().is_interrupted());
+        }),
+    ]);
+}
+
+#[test]
+fn instruction_limit() {
+    run_test_actions([
+        TestAction::inspect_context(|context| {
+            context.runtime_limits_mut().set_instruction_limit(10_000);
+        }),
+        TestAction::assert_eq(
+            "let sum = 0; for (let i = 0; i < 100; i++) { sum += i; } sum",
+            4950,
+        ),
+        TestAction::assert_native_error(
+            indoc! {r"
+                try {
+                    for (let i = 0; i < 100000; i++) {}
+                } catch {}
+            "},
+            JsNativeErrorKind::RuntimeLimit,
+            "Maximum instruction count 10000 exceeded",
+        ),
+        // The budget is per run, so the next script starts with a full budget.
+        TestAction::assert_eq("[1, 2, 3].length", 3),
+    ]);
+}
+
+#[test]
+fn time_limit() {
+    run_test_actions([
+        TestAction::inspect_context(|context| {
+            context
+                .runtime_limits_mut()
+                .set_time_limit(Some(Duration::from_millis(10)));
+        }),
+        TestAction::assert_native_error(
+            "while (true) {}",
+            JsNativeErrorKind::RuntimeLimit,
+            "Maximum execution time 10ms exceeded",
+        ),
+        TestAction::assert_eq("[1, 2, 3].length", 3),
+    ]);
+}
+
+#[derive(Default)]
+struct RefuelHooks {
+    refuels: Cell<u32>,
+}
+
+impl HostHooks for RefuelHooks {
+    fn runtime_budget_exhausted(&self, budget: RuntimeBudget, _context: &mut Context<'_>) -> bool {
+        assert_eq!(budget, RuntimeBudget::Instructions);
+        self.refuels.set(self.refuels.get() + 1);
+        self.refuels.get() <= 3
+    }
+}
+
+#[test]
+fn runtime_budget_refuel() {
+    let refuel_hooks = RefuelHooks::default();
+    let hooks: &dyn HostHooks = &refuel_hooks;
+    let context = &mut ContextBuilder::default().host_hooks(hooks).build().unwrap();
+    context.runtime_limits_mut().set_instruction_limit(1000);
+
+    run_test_actions_with(
+        [
+            TestAction::assert_native_error(
+                "while (true) {}",
+                JsNativeErrorKind::RuntimeLimit,
+                "Maximum instruction count 1000 exceeded",
+            ),
+            TestAction::inspect_context(|context| {
+                context.runtime_limits_mut().disable_instruction_limit();
+            }),
+        ],
+        context,
+    );
+    assert_eq!(refuel_hooks.refuels.get(), 4);
+}
@@ -1,8 +1,10 @@
 // This example demonstrates how to use visitors to modify an AST. Namely, the visitors shown here
-// are used to swap the operands of commutable arithmetic operations. For an example which simply
-// inspects the AST without modifying it, see symbol_visitor.rs.
+// are used to swap the operands of commutable arithmetic operations, and the modified AST is then
+// printed back as JavaScript source code. For an example which simply inspects the AST without
+// modifying it, see symbol_visitor.rs.
 
 use boa_ast::{
+    codegen::{Config, ToSource},
     expression::operator::{
         binary::{ArithmeticOp, BinaryOp},
         Binary,
@@ -11,7 +13,6 @@ use boa_ast::{
     Expression,
 };
 use boa_engine::{Context, Source};
-use boa_interner::ToInternedString;
 use boa_parser::Parser;
 use core::ops::ControlFlow;
 use std::{convert::Infallible, path::Path};
@@ -78,5 +79,5 @@ fn main() {
         ControlFlow::Continue(_)
     ));
 
-    println!("{}", script.to_interned_string(ctx.interner()));
+    print!("{}", script.to_source(ctx.interner(), Config::new()));
 }
@@ -1,6 +1,9 @@
 //! A garbage collected cell implementation
 
-use crate::trace::{Finalize, Trace};
+use crate::{
+    is_marking,
+    trace::{Finalize, Trace},
+};
 use std::{
     cell::{Cell, UnsafeCell},
     cmp::Ordering,
@@ -462,12 +465,26 @@ impl<T: Trace + ?Sized, U: ?Sized> Drop for GcRefMut<'_, T, U> {
         debug_assert!(self.gc_cell.flags.get().borrowed() == BorrowState::Writing);
         // Restore the rooted state of the GcCell's contents to the state of the GcCell.
         // During the lifetime of the GcCellRefMut, the GcCell's contents are rooted.
+        //
+        // A rooted cell doesn't need the write barrier: its contents stay rooted, so the final
+        // step of an incremental marking cycle finds them when it rescans the roots. Cells in the
+        // heap are only rooted while the cell that contains them is mutably borrowed, and the
+        // barrier of that borrow traces them when it ends.
         if !self.gc_cell.flags.get().rooted() {
             // SAFETY: If `GcCell` is no longer rooted, then unroot it. This should be safe
             // as the internal `GcBox` should be guaranteed to have at least 1 root.
             unsafe {
                 (*self.gc_cell.cell.get()).unroot();
             }
+
+            // Write barrier: the cell may have been traced before this borrow by an
+            // incremental marking cycle, so its new contents have to be traced as well.
+            if is_marking() {
+                // SAFETY: the contents are reachable through the cell, which is still borrowed.
+                unsafe {
+                    (*self.gc_cell.cell.get()).trace();
+                }
+            }
         }
         self.gc_cell
             .flags
@@ -0,0 +1,129 @@
+//! Walking the values of the heap, used to take heap snapshots.
+
+use crate::{internals::GcBoxHeader, Collector, GcBox, Trace, BOA_GC, MARK_STACK};
+use std::{any::TypeId, fmt, mem, ptr::NonNull};
+
+/// A value of the heap of the current thread, visited by [`walk_heap`].
+pub struct HeapNode<'a> {
+    node: &'a GcBox<dyn Trace>,
+}
+
+impl HeapNode<'_> {
+    /// Returns the address of the value, which identifies it during the walk.
+    ///
+    /// This is the same address returned by [`Gc::addr`][crate::Gc::addr] for pointers to the
+    /// value.
+    #[must_use]
+    pub fn id(&self) -> usize {
+        self.node.addr()
+    }
+
+    /// Returns the size in bytes of the allocation of the value.
+    #[must_use]
+    pub fn size(&self) -> usize {
+        mem::size_of_val(self.node)
+    }
+
+    /// Returns the name of the type of the value.
+    #[must_use]
+    pub fn type_name(&self) -> &'static str {
+        (self.node.header.vtable.type_name)()
+    }
+
+    /// Returns `true` if the value is referenced from outside the heap.
+    #[must_use]
+    pub fn is_rooted(&self) -> bool {
+        self.node.header.roots() > 0
+    }
+
+    /// Returns a reference to the value if it has the type `T`.
+    #[must_use]
+    pub fn downcast_ref<T: Trace + 'static>(&self) -> Option<&T> {
+        if (self.node.header.vtable.type_id)() != TypeId::of::<T>() {
+            return None;
+        }
+
+        // SAFETY: the type of the value was checked above.
+        let node = unsafe { NonNull::from(self.node).cast::<GcBox<T>>().as_ref() };
+        Some(node.value())
+    }
+
+    /// Returns the ids of the values referenced by this value, in tracing order.
+    ///
+    /// Values referenced more than once are only returned once. Weak references, like the key
+    /// of an [`Ephemeron`][crate::Ephemeron], are not returned, since they don't keep their
+    /// target alive.
+    #[must_use]
+    pub fn edges(&self) -> Vec<usize> {
+        // No value is marked while the heap is walked, so tracing this value marks and pushes
+        // to the mark stack exactly the values it references.
+        // SAFETY: the node is alive for the duration of the walk.
+        unsafe {
+            self.node.value().trace();
+        }
+
+        let references = MARK_STACK.with(|stack| mem::take(&mut *stack.borrow_mut()));
+        references
+            .into_iter()
+            .map(|(node, _)| {
+                // SAFETY: the mark stack only contains pointers to live boxes, and the header
+                // is at the start of a `GcBox`.
+                unsafe { node.cast::<GcBoxHeader>().as_ref().unmark() };
+                node.as_ptr() as usize
+            })
+            .collect()
+    }
+}
+
+impl fmt::Debug for HeapNode<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HeapNode")
+            .field("id", &self.id())
+            .field("size", &self.size())
+            .field("type_name", &self.type_name())
+            .field("is_rooted", &self.is_rooted())
+            .finish()
+    }
+}
+
+/// Calls `f` on every value of the heap of the current thread.
+///
+/// Values that are unreachable but haven't been collected yet are also visited, so this should
+/// usually be called right after [`force_collect`][crate::force_collect]. If an incremental
+/// marking cycle is in progress, it is finished first.
+///
+/// # Panics
+///
+/// Panics if `f` allocates a garbage-collected value or runs a collection.
+pub fn walk_heap<F>(mut f: F)
+where
+    F: FnMut(&HeapNode<'_>),
+{
+    BOA_GC.with(|current| {
+        {
+            let mut gc = current.borrow_mut();
+            if gc.runtime.marking.is_some() {
+                Collector::pause(&mut gc, Collector::collect);
+            }
+        }
+
+        let gc = current.borrow();
+        let mut next = gc.strong_start.get();
+        while let Some(node) = next {
+            // SAFETY: every node of the heap is alive until it is swept, and the collector
+            // can't run while the heap is borrowed.
+            let node = unsafe { node.as_ref() };
+            f(&HeapNode { node });
+            next = node.header.next.get();
+        }
+
+        // Finding the edges of a value also marks the ephemerons it holds.
+        let mut next = gc.weak_start.get();
+        while let Some(eph) = next {
+            // SAFETY: every ephemeron of the heap is alive until it is swept.
+            let eph = unsafe { eph.as_ref() };
+            eph.header().unmark();
+            next = eph.header().next.get();
+        }
+    });
+}
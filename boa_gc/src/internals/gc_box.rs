@@ -1,4 +1,6 @@
-use crate::Trace;
+use crate::{mark_gray, Trace};
+#[cfg(feature = "heap-snapshot")]
+use std::any::TypeId;
 use std::{
     cell::Cell,
     fmt,
@@ -10,6 +12,22 @@ const MARK_MASK: usize = 1 << (usize::BITS - 1);
 const ROOTS_MASK: usize = !MARK_MASK;
 const ROOTS_MAX: usize = ROOTS_MASK;
 
+/// Traces the value of a type-erased `GcBox`.
+///
+/// The pointer must point to a live `GcBox` of the type the function was instantiated with.
+pub(crate) type TraceFn = unsafe fn(NonNull<()>);
+
+/// The operations of a `GcBox` that depend on the type of its value.
+///
+/// Heap snapshots need to identify the type of every value, so with the `heap-snapshot` feature
+/// the header points to this table instead of only storing the trace function of the value.
+#[cfg(feature = "heap-snapshot")]
+pub(crate) struct GcBoxVTable {
+    pub(crate) trace_value: TraceFn,
+    pub(crate) type_id: fn() -> TypeId,
+    pub(crate) type_name: fn() -> &'static str,
+}
+
 /// The `GcBoxheader` contains the `GcBox`'s current state for the `Collector`'s
 /// Mark/Sweep as well as a pointer to the next node in the heap.
 ///
@@ -21,18 +39,38 @@ const ROOTS_MAX: usize = ROOTS_MASK;
 /// `Collector` during the sweep phase.
 pub(crate) struct GcBoxHeader {
     roots: Cell<usize>,
+    #[cfg(not(feature = "heap-snapshot"))]
+    trace_value: TraceFn,
+    #[cfg(feature = "heap-snapshot")]
+    pub(crate) vtable: &'static GcBoxVTable,
     pub(crate) next: Cell<Option<NonNull<GcBox<dyn Trace>>>>,
 }
 
 impl GcBoxHeader {
-    /// Creates a new `GcBoxHeader` with a root of 1 and next set to None.
-    pub(crate) fn new() -> Self {
+    /// Creates a new `GcBoxHeader` for a value of type `T`, with a root of 1 and next set to None.
+    pub(crate) fn new<T: Trace + 'static>() -> Self {
         Self {
             roots: Cell::new(1),
+            #[cfg(not(feature = "heap-snapshot"))]
+            trace_value: GcBox::<T>::trace_value,
+            #[cfg(feature = "heap-snapshot")]
+            vtable: &GcBox::<T>::VTABLE,
             next: Cell::new(None),
         }
     }
 
+    /// Returns the function that traces the value of the `GcBox`.
+    #[cfg(not(feature = "heap-snapshot"))]
+    pub(crate) fn trace_value(&self) -> TraceFn {
+        self.trace_value
+    }
+
+    /// Returns the function that traces the value of the `GcBox`.
+    #[cfg(feature = "heap-snapshot")]
+    pub(crate) fn trace_value(&self) -> TraceFn {
+        self.vtable.trace_value
+    }
+
     /// Returns the `GcBoxHeader`'s current root count
     pub(crate) fn roots(&self) -> usize {
         self.roots.get() & ROOTS_MASK
@@ -84,20 +122,43 @@ impl fmt::Debug for GcBoxHeader {
 }
 
 /// A garbage collected allocation.
+///
+/// The header is at the start of the allocation, so a type-erased pointer to the box also
+/// points to its header.
 #[derive(Debug)]
+#[repr(C)]
 pub struct GcBox<T: Trace + ?Sized + 'static> {
     pub(crate) header: GcBoxHeader,
     value: T,
 }
 
 impl<T: Trace> GcBox<T> {
+    #[cfg(feature = "heap-snapshot")]
+    const VTABLE: GcBoxVTable = GcBoxVTable {
+        trace_value: Self::trace_value,
+        type_id: TypeId::of::<T>,
+        type_name: std::any::type_name::<T>,
+    };
+
     /// Returns a new `GcBox` with a rooted `GcBoxHeader`.
     pub(crate) fn new(value: T) -> Self {
         Self {
-            header: GcBoxHeader::new(),
+            header: GcBoxHeader::new::<T>(),
             value,
         }
     }
+
+    /// Traces the value of the `GcBox` pointed to by `this`.
+    ///
+    /// # Safety
+    ///
+    /// `this` must point to a live `GcBox<T>`.
+    unsafe fn trace_value(this: NonNull<()>) {
+        // SAFETY: the caller ensures `this` points to a live `GcBox<T>`.
+        unsafe {
+            this.cast::<Self>().as_ref().value.trace();
+        }
+    }
 }
 
 impl<T: Trace + ?Sized> GcBox<T> {
@@ -108,16 +169,20 @@ impl<T: Trace + ?Sized> GcBox<T> {
         ptr::eq(&this.header, &other.header)
     }
 
-    /// Marks this `GcBox` and traces its value.
+    /// Marks this `GcBox` and schedules its value to be traced.
+    ///
+    /// The value is traced when the `Collector` drains its mark stack, which avoids
+    /// overflowing the native stack on deep object graphs and allows marking the heap
+    /// incrementally.
     pub(crate) unsafe fn mark_and_trace(&self) {
         if !self.header.is_marked() {
             self.header.mark();
-            // SAFETY: if `GcBox::trace_inner()` has been called, then,
+            // SAFETY: if `GcBox::mark_and_trace()` has been called, then,
             // this box must have been deemed as reachable via tracing
             // from a root, which by extension means that value has not
-            // been dropped either.
+            // been dropped either, and won't be dropped until the mark stack is drained.
             unsafe {
-                self.value.trace();
+                mark_gray(NonNull::from(self).cast(), self.header.trace_value());
             }
         }
     }
@@ -145,4 +210,11 @@ impl<T: Trace + ?Sized> GcBox<T> {
     pub(crate) fn is_marked(&self) -> bool {
         self.header.is_marked()
     }
+
+    /// Returns the address of this `GcBox`, which identifies it in a heap walk.
+    #[cfg(feature = "heap-snapshot")]
+    pub(crate) fn addr(&self) -> usize {
+        let ptr: *const Self = self;
+        ptr.cast::<u8>() as usize
+    }
 }
@@ -6,3 +6,6 @@ pub(crate) use self::ephemeron_box::{EphemeronBox, ErasedEphemeronBox};
 pub(crate) use self::weak_map_box::{ErasedWeakMapBox, WeakMapBox};
 
 pub use self::gc_box::GcBox;
+pub(crate) use self::gc_box::TraceFn;
+#[cfg(feature = "heap-snapshot")]
+pub(crate) use self::gc_box::GcBoxHeader;
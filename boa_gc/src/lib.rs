@@ -4,6 +4,9 @@
 //! **`boa_gc`** is a mark-sweep garbage collector that implements a [`Trace`] and [`Finalize`] trait
 //! for garbage collected values.
 //!
+//! The heap can either be marked all at once when a collection is triggered, or incrementally in
+//! small steps interleaved with the program (see [`GcConfig::set_incremental_budget`]).
+//!
 #![doc = include_str!("../../ABOUT.md")]
 #![doc(
     html_logo_url = "https://raw.githubusercontent.com/boa-dev/boa/main/assets/logo.svg",
@@ -71,20 +74,25 @@
 extern crate self as boa_gc;
 
 mod cell;
+#[cfg(feature = "heap-snapshot")]
+mod heap;
 mod pointers;
 mod trace;
 
 pub(crate) mod internals;
 
 use boa_profiler::Profiler;
-use internals::{EphemeronBox, ErasedEphemeronBox, ErasedWeakMapBox, WeakMapBox};
+use internals::{EphemeronBox, ErasedEphemeronBox, ErasedWeakMapBox, TraceFn, WeakMapBox};
 use std::{
     cell::{Cell, RefCell},
     collections::HashMap,
     mem,
     ptr::NonNull,
+    time::Duration,
 };
 
+#[cfg(feature = "heap-snapshot")]
+pub use crate::heap::{walk_heap, HeapNode};
 pub use crate::trace::{Finalize, Trace};
 pub use boa_macros::{Finalize, Trace};
 pub use cell::{GcRef, GcRefCell, GcRefMut};
@@ -95,7 +103,21 @@ type GcPointer = NonNull<GcBox<dyn Trace>>;
 type EphemeronPointer = NonNull<dyn ErasedEphemeronBox>;
 type ErasedWeakMapBoxPointer = NonNull<dyn ErasedWeakMapBox>;
 
+/// The fraction of the threshold that has to be allocated between incremental marking steps.
+const INCREMENTAL_STEP_DIVISOR: usize = 8;
+
+/// The minimum number of bytes that have to be allocated between incremental marking steps.
+const MIN_INCREMENTAL_STEP: usize = 4096;
+
+/// The number of values traced between checks of the budget of a marking step.
+const MARK_STEP_CHECK_INTERVAL: usize = 64;
+
+/// The number of values traced by a marking step on targets without a clock.
+const UNTIMED_MARK_STEP: usize = 4096;
+
 thread_local!(static GC_DROPPING: Cell<bool> = Cell::new(false));
+thread_local!(static GC_MARKING: Cell<bool> = Cell::new(false));
+thread_local!(static MARK_STACK: RefCell<Vec<(NonNull<()>, TraceFn)>> = RefCell::new(Vec::new()));
 thread_local!(static BOA_GC: RefCell<BoaGc> = RefCell::new( BoaGc {
     config: GcConfig::default(),
     runtime: GcRuntimeData::default(),
@@ -104,28 +126,166 @@ thread_local!(static BOA_GC: RefCell<BoaGc> = RefCell::new( BoaGc {
     weak_map_start: Cell::new(None),
 }));
 
-#[derive(Debug, Clone, Copy)]
-struct GcConfig {
+/// The configuration of the garbage collector of a thread.
+///
+/// The collector runs when the number of allocated bytes exceeds its current threshold. If more
+/// than [`GcConfig::used_space_percentage`] of the threshold is still in use after a collection,
+/// the threshold is multiplied by [`GcConfig::growth_factor`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GcConfig {
     threshold: usize,
     used_space_percentage: usize,
+    growth_factor: f64,
+    incremental_budget: Option<Duration>,
 }
 
-// Setting the defaults to an arbitrary value currently.
-//
-// TODO: Add a configure later
 impl Default for GcConfig {
     fn default() -> Self {
         Self {
             threshold: 1024,
             used_space_percentage: 80,
+            growth_factor: 1.25,
+            incremental_budget: None,
         }
     }
 }
 
+impl GcConfig {
+    /// Returns the number of allocated bytes that triggers the first collection.
+    #[must_use]
+    pub const fn threshold(&self) -> usize {
+        self.threshold
+    }
+
+    /// Sets the number of allocated bytes that triggers the first collection.
+    pub fn set_threshold(&mut self, threshold: usize) {
+        self.threshold = threshold;
+    }
+
+    /// Returns the percentage of the threshold that has to be in use after a collection to
+    /// grow the threshold.
+    #[must_use]
+    pub const fn used_space_percentage(&self) -> usize {
+        self.used_space_percentage
+    }
+
+    /// Sets the percentage of the threshold that has to be in use after a collection to grow
+    /// the threshold.
+    ///
+    /// Values greater than `100` are clamped to `100`.
+    pub fn set_used_space_percentage(&mut self, percentage: usize) {
+        self.used_space_percentage = percentage.min(100);
+    }
+
+    /// Returns the factor by which the live bytes are multiplied to compute the next threshold.
+    #[must_use]
+    pub const fn growth_factor(&self) -> f64 {
+        self.growth_factor
+    }
+
+    /// Sets the factor by which the live bytes are multiplied to compute the next threshold.
+    ///
+    /// Factors smaller than `1.0` are clamped to `1.0`.
+    pub fn set_growth_factor(&mut self, factor: f64) {
+        self.growth_factor = factor.max(1.0);
+    }
+
+    /// Returns the time budget of each incremental marking step, or `None` if the heap is
+    /// marked all at once.
+    #[must_use]
+    pub const fn incremental_budget(&self) -> Option<Duration> {
+        self.incremental_budget
+    }
+
+    /// Sets the time budget of each incremental marking step.
+    ///
+    /// With a budget, reaching the threshold starts a marking cycle instead of a full
+    /// collection. The cycle then traces the heap in steps of at most `budget`, run every time a
+    /// fraction of the threshold is allocated, and only the final step, which sweeps the
+    /// unreachable values, pauses the program for longer. `None` marks the heap all at once.
+    ///
+    /// Marking relies on all the [`Gc`] pointers stored inside of garbage collected values being
+    /// modified through a [`GcRefCell`], which notifies the collector when a mutable borrow ends.
+    ///
+    /// On targets without a clock, each step traces a fixed number of values instead.
+    pub fn set_incremental_budget(&mut self, budget: Option<Duration>) {
+        self.incremental_budget = budget;
+    }
+
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss
+    )]
+    fn next_threshold(&self, live_bytes: usize) -> usize {
+        (live_bytes as f64 * self.growth_factor) as usize
+    }
+}
+
+/// Statistics about the garbage collector of a thread.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GcStatistics {
+    /// The number of bytes currently allocated, including unreachable values that have not
+    /// been collected yet.
+    pub allocated_bytes: usize,
+
+    /// The number of bytes that survived the last collection.
+    pub live_bytes: usize,
+
+    /// The number of garbage collected values currently allocated.
+    pub allocated_objects: usize,
+
+    /// The number of ephemerons currently allocated.
+    pub allocated_ephemerons: usize,
+
+    /// The number of allocated bytes that triggers the next collection.
+    pub threshold: usize,
+
+    /// The number of collections run so far.
+    pub collections: usize,
+
+    /// The number of incremental marking steps run so far.
+    pub marking_steps: usize,
+
+    /// Whether an incremental marking cycle is in progress.
+    pub marking: bool,
+
+    /// The duration of the last pause of the program, either a full collection or an incremental
+    /// marking step.
+    pub last_pause: Duration,
+
+    /// The duration of the longest pause.
+    pub max_pause: Duration,
+
+    /// The total duration of all the pauses.
+    pub total_pause: Duration,
+}
+
 #[derive(Default, Debug, Clone, Copy)]
 struct GcRuntimeData {
     collections: usize,
+    marking_steps: usize,
     bytes_allocated: usize,
+    live_bytes: usize,
+    objects: usize,
+    ephemerons: usize,
+    threshold: Option<usize>,
+    heap_limit: Option<usize>,
+    heap_limit_exceeded: bool,
+    marking: Option<MarkingCycle>,
+    last_pause: Duration,
+    max_pause: Duration,
+    total_pause: Duration,
+}
+
+/// The state of an incremental marking cycle.
+#[derive(Debug, Clone, Copy)]
+struct MarkingCycle {
+    /// The threshold that started the cycle.
+    threshold: usize,
+
+    /// The number of allocated bytes that triggers the next marking step.
+    next_step: usize,
 }
 
 #[derive(Debug)]
@@ -137,6 +297,13 @@ struct BoaGc {
     weak_map_start: Cell<Option<ErasedWeakMapBoxPointer>>,
 }
 
+impl BoaGc {
+    /// Returns the number of allocated bytes that triggers the next collection.
+    fn threshold(&self) -> usize {
+        self.runtime.threshold.unwrap_or(self.config.threshold)
+    }
+}
+
 impl Drop for BoaGc {
     fn drop(&mut self) {
         Collector::dump(self);
@@ -186,6 +353,7 @@ impl Allocator {
             let mut gc = st.borrow_mut();
 
             Self::manage_state(&mut gc);
+            Self::check_heap_limit(&mut gc, element_size);
             value.header.next.set(gc.strong_start.take());
             // Safety: value cannot be a null pointer, since `Box` cannot return null pointers.
             let ptr = unsafe { NonNull::new_unchecked(Box::into_raw(Box::new(value))) };
@@ -193,6 +361,7 @@ impl Allocator {
 
             gc.strong_start.set(Some(erased));
             gc.runtime.bytes_allocated += element_size;
+            gc.runtime.objects += 1;
 
             ptr
         })
@@ -207,6 +376,7 @@ impl Allocator {
             let mut gc = st.borrow_mut();
 
             Self::manage_state(&mut gc);
+            Self::check_heap_limit(&mut gc, element_size);
             value.header.next.set(gc.weak_start.take());
             // Safety: value cannot be a null pointer, since `Box` cannot return null pointers.
             let ptr = unsafe { NonNull::new_unchecked(Box::into_raw(Box::new(value))) };
@@ -214,6 +384,7 @@ impl Allocator {
 
             gc.weak_start.set(Some(erased));
             gc.runtime.bytes_allocated += element_size;
+            gc.runtime.ephemerons += 1;
 
             ptr
         })
@@ -246,15 +417,61 @@ impl Allocator {
     }
 
     fn manage_state(gc: &mut BoaGc) {
-        if gc.runtime.bytes_allocated > gc.config.threshold {
-            Collector::collect(gc);
-
-            if gc.runtime.bytes_allocated
-                > gc.config.threshold / 100 * gc.config.used_space_percentage
-            {
-                gc.config.threshold =
-                    gc.runtime.bytes_allocated / gc.config.used_space_percentage * 100;
+        if let Some(cycle) = gc.runtime.marking {
+            if gc.runtime.bytes_allocated > cycle.next_step {
+                let finished = Collector::pause(gc, Collector::marking_step);
+                if finished {
+                    Self::update_threshold(gc, cycle.threshold);
+                }
             }
+            return;
+        }
+
+        let threshold = gc.threshold();
+        if gc.runtime.bytes_allocated > threshold {
+            let finished = if gc.config.incremental_budget.is_some() {
+                Collector::pause(gc, |gc| {
+                    Collector::start_marking(gc, threshold);
+                    Collector::marking_step(gc)
+                })
+            } else {
+                Collector::pause(gc, |gc| {
+                    Collector::collect(gc);
+                    true
+                })
+            };
+
+            if finished {
+                Self::update_threshold(gc, threshold);
+            }
+        }
+    }
+
+    /// Runs a single collection if allocating `size` more bytes would exceed the heap limit, and
+    /// flags the limit as exceeded if the collection cannot make enough room.
+    ///
+    /// Until the flag is taken, the limit is not checked again, so that the allocations that
+    /// follow don't collect the heap every time.
+    fn check_heap_limit(gc: &mut BoaGc, size: usize) {
+        let Some(limit) = gc.runtime.heap_limit else {
+            return;
+        };
+        if gc.runtime.heap_limit_exceeded || gc.runtime.bytes_allocated + size <= limit {
+            return;
+        }
+
+        Collector::pause(gc, Collector::collect_all);
+        gc.runtime.heap_limit_exceeded = gc.runtime.bytes_allocated + size > limit;
+    }
+
+    /// Grows the threshold if the heap is still mostly in use after a collection.
+    fn update_threshold(gc: &mut BoaGc, threshold: usize) {
+        if gc.runtime.bytes_allocated > threshold / 100 * gc.config.used_space_percentage {
+            gc.runtime.threshold = Some(
+                gc.config
+                    .next_threshold(gc.runtime.bytes_allocated)
+                    .max(threshold),
+            );
         }
     }
 }
@@ -276,12 +493,110 @@ struct Unreachables {
 /// A better approach in a more concurrent structure may be to reorder.
 ///
 /// Mark -> Sweep -> Finalize
+///
+/// With an incremental budget, the first mark phase is split into steps: the roots are marked
+/// when the cycle starts, and each step traces values from the mark stack until the budget is
+/// exhausted. Values allocated during the cycle start unmarked, and [`GcRefMut`] pushes the
+/// contents of a cell to the mark stack when a mutable borrow ends, so the final step only has
+/// to rescan the roots and trace what changed since the cycle started.
 struct Collector;
 
 impl Collector {
+    /// Runs `f`, recording its duration as a pause of the program.
+    fn pause<R>(gc: &mut BoaGc, f: impl FnOnce(&mut BoaGc) -> R) -> R {
+        let start = now();
+        let result = f(gc);
+        if let Some(start) = start {
+            let pause = start.elapsed();
+            gc.runtime.last_pause = pause;
+            gc.runtime.max_pause = gc.runtime.max_pause.max(pause);
+            gc.runtime.total_pause += pause;
+        }
+        result
+    }
+
+    /// Starts an incremental marking cycle by marking all the rooted nodes.
+    fn start_marking(gc: &mut BoaGc, threshold: usize) {
+        let _timer = Profiler::global().start_event("Gc Start Marking", "gc");
+        let mut strong = &gc.strong_start;
+        while let Some(node) = strong.get() {
+            // SAFETY: node must be valid as this phase cannot drop any node.
+            let node_ref = unsafe { node.as_ref() };
+            if node_ref.header.roots() > 0 {
+                // SAFETY: the reference to node must be valid as it is rooted.
+                unsafe {
+                    node_ref.mark_and_trace();
+                }
+            }
+            strong = &node_ref.header.next;
+        }
+
+        GC_MARKING.with(|marking| marking.set(true));
+        gc.runtime.marking = Some(MarkingCycle {
+            threshold,
+            next_step: gc.runtime.bytes_allocated,
+        });
+    }
+
+    /// Runs an incremental marking step, finishing the collection if there is nothing left to
+    /// trace.
+    ///
+    /// Returns `true` if the collection finished.
+    fn marking_step(gc: &mut BoaGc) -> bool {
+        let _timer = Profiler::global().start_event("Gc Marking Step", "gc");
+        gc.runtime.marking_steps += 1;
+
+        let budget = gc.config.incremental_budget.unwrap_or_default();
+
+        if Self::drain_mark_stack(Some(budget)) {
+            Self::collect(gc);
+            return true;
+        }
+
+        if let Some(cycle) = &mut gc.runtime.marking {
+            let step = (cycle.threshold / INCREMENTAL_STEP_DIVISOR).max(MIN_INCREMENTAL_STEP);
+            cycle.next_step = gc.runtime.bytes_allocated + step;
+        }
+        false
+    }
+
+    /// Traces the values in the mark stack until it is empty or `budget` is exhausted.
+    ///
+    /// Returns `true` if the mark stack is empty.
+    fn drain_mark_stack(budget: Option<Duration>) -> bool {
+        let start = budget.and_then(|_| now());
+        let mut traced = 0usize;
+        loop {
+            let Some((node, trace_value)) = MARK_STACK.with(|stack| stack.borrow_mut().pop())
+            else {
+                return true;
+            };
+
+            // SAFETY: nodes are only pushed to the mark stack when they are reachable, and
+            // are not dropped until the mark stack is drained.
+            unsafe { trace_value(node) };
+            traced += 1;
+
+            if let Some(budget) = budget {
+                if traced % MARK_STEP_CHECK_INTERVAL == 0 {
+                    let exhausted = start.map_or(traced >= UNTIMED_MARK_STEP, |start| {
+                        start.elapsed() >= budget
+                    });
+                    if exhausted {
+                        return MARK_STACK.with(|stack| stack.borrow().is_empty());
+                    }
+                }
+            }
+        }
+    }
+
     /// Run a collection on the full heap.
+    ///
+    /// If an incremental marking cycle is in progress, this finishes it.
     fn collect(gc: &mut BoaGc) {
         let _timer = Profiler::global().start_event("Gc Full Collection", "gc");
+        GC_MARKING.with(|marking| marking.set(false));
+        gc.runtime.marking = None;
         gc.runtime.collections += 1;
         let unreachables = Self::mark_heap(&gc.strong_start, &gc.weak_start, &gc.weak_map_start);
 
@@ -297,11 +612,7 @@ impl Collector {
 
         // SAFETY: The head of our linked list is always valid per the invariants of our GC.
         unsafe {
-            Self::sweep(
-                &gc.strong_start,
-                &gc.weak_start,
-                &mut gc.runtime.bytes_allocated,
-            );
+            Self::sweep(&gc.strong_start, &gc.weak_start, &mut gc.runtime);
         }
 
         // Weak maps have to be cleared after the sweep, since the process dereferences GcBoxes.
@@ -322,6 +633,18 @@ impl Collector {
                 let _unmarked_node = unsafe { Box::from_raw(w.as_ptr()) };
             }
         }
+
+        gc.runtime.live_bytes = gc.runtime.bytes_allocated;
+    }
+
+    /// Run a collection on the full heap that frees all the unreachable values.
+    fn collect_all(gc: &mut BoaGc) {
+        // Values that became unreachable after an incremental marking cycle traced them are only
+        // collected by the next cycle.
+        if gc.runtime.marking.is_some() {
+            Self::collect(gc);
+        }
+        Self::collect(gc);
     }
 
     /// Walk the heap and mark any nodes deemed reachable
@@ -352,6 +675,7 @@ impl Collector {
             }
             strong = &node_ref.header.next;
         }
+        Self::drain_mark_stack(None);
 
         // 0.1. Early return if there are no ephemerons in the GC
         if weak.get().is_none() {
@@ -373,12 +697,18 @@ impl Collector {
         while let Some(eph) = weak.get() {
             // SAFETY: node must be valid as this phase cannot drop any node.
             let eph_ref = unsafe { eph.as_ref() };
+            // Ephemerons held outside of the heap are always reachable, even if no traced node
+            // points to them.
+            if eph_ref.header().roots() > 0 {
+                eph_ref.header().mark();
+            }
             // SAFETY: the garbage collector ensures `eph_ref` always points to valid data.
             if unsafe { !eph_ref.trace() } {
                 pending_ephemerons.push(eph);
             }
             weak = &eph_ref.header().next;
         }
+        Self::drain_mark_stack(None);
 
         // 2. Trace all the weak pointers in the live weak maps to make sure they do not get swept.
         while let Some(w) = weak_map.get() {
@@ -390,6 +720,7 @@ impl Collector {
 
             weak_map = node_ref.next();
         }
+        Self::drain_mark_stack(None);
 
         // 3. Iterate through all pending ephemerons, removing the ones which have been successfully
         // traced. If there are no changes in the pending ephemerons list, it means that there are no
@@ -402,6 +733,7 @@ impl Collector {
                 // SAFETY: the garbage collector ensures `eph_ref` always points to valid data.
                 unsafe { !eph_ref.trace() }
             });
+            Self::drain_mark_stack(None);
 
             if previous_len == pending_ephemerons.len() {
                 break;
@@ -450,7 +782,7 @@ impl Collector {
     unsafe fn sweep(
         mut strong: &Cell<Option<NonNull<GcBox<dyn Trace>>>>,
         mut weak: &Cell<Option<NonNull<dyn ErasedEphemeronBox>>>,
-        total_allocated: &mut usize,
+        runtime: &mut GcRuntimeData,
     ) {
         let _timer = Profiler::global().start_event("Gc Sweeping", "gc");
         let _guard = DropGuard::new();
@@ -466,7 +798,8 @@ impl Collector {
                 // The caller must ensure all pointers were allocated by `Box::into_raw(Box::new(..))`.
                 let unmarked_node = unsafe { Box::from_raw(node.as_ptr()) };
                 let unallocated_bytes = mem::size_of_val(&*unmarked_node);
-                *total_allocated -= unallocated_bytes;
+                runtime.bytes_allocated -= unallocated_bytes;
+                runtime.objects -= 1;
                 strong.set(unmarked_node.header.next.take());
             }
         }
@@ -483,7 +816,8 @@ impl Collector {
                 // The caller must ensure all pointers were allocated by `Box::into_raw(Box::new(..))`.
                 let unmarked_eph = unsafe { Box::from_raw(eph.as_ptr()) };
                 let unallocated_bytes = mem::size_of_val(&*unmarked_eph);
-                *total_allocated -= unallocated_bytes;
+                runtime.bytes_allocated -= unallocated_bytes;
+                runtime.ephemerons -= 1;
                 weak.set(unmarked_eph.header().next.take());
             }
         }
@@ -491,6 +825,11 @@ impl Collector {
 
     // Clean up the heap when BoaGc is dropped
     fn dump(gc: &mut BoaGc) {
+        GC_MARKING.with(|marking| marking.set(false));
+        gc.runtime.marking = None;
+        // The mark stack may already be destroyed if the thread is exiting.
+        let _ = MARK_STACK.try_with(|stack| stack.borrow_mut().clear());
+
         // Weak maps have to be dropped first, since the process dereferences GcBoxes.
         // This can be done without initializing a dropguard since no GcBox's are being dropped.
         let weak_map_head = &gc.weak_map_start;
@@ -531,11 +870,113 @@ pub fn force_collect() {
         let mut gc = current.borrow_mut();
 
         if gc.runtime.bytes_allocated > 0 {
-            Collector::collect(&mut gc);
+            Collector::pause(&mut gc, Collector::collect_all);
         }
     });
 }
 
+/// Returns the configuration of the garbage collector of the current thread.
+#[must_use]
+pub fn config() -> GcConfig {
+    BOA_GC.with(|current| current.borrow().config)
+}
+
+/// Sets the configuration of the garbage collector of the current thread.
+///
+/// This resets the current threshold to [`GcConfig::threshold`].
+pub fn set_config(config: GcConfig) {
+    BOA_GC.with(|current| {
+        let mut gc = current.borrow_mut();
+        gc.config = config;
+        gc.runtime.threshold = None;
+    });
+}
+
+/// Returns the maximum number of bytes the heap of the current thread can use, if any.
+#[must_use]
+pub fn heap_limit() -> Option<usize> {
+    BOA_GC.with(|current| current.borrow().runtime.heap_limit)
+}
+
+/// Sets the maximum number of bytes the heap of the current thread can use, or removes the limit
+/// with `None`.
+///
+/// The limit applies to [`GcStatistics::allocated_bytes`], which only counts the boxes allocated
+/// by the collector, that is the size of every [`GcBox`] and [`Ephemeron`] with its header. The
+/// memory that values own outside of the heap, like the buffer of a `Vec` or of a string, is not
+/// counted.
+///
+/// If an allocation would grow the heap past the limit, a full collection is run. If the heap
+/// is still over the limit afterwards, the allocation succeeds anyway, and
+/// [`take_heap_limit_exceeded`] returns `true`, so that the embedder can abort the running code.
+/// No other collection is run for the limit until then.
+pub fn set_heap_limit(limit: Option<usize>) {
+    BOA_GC.with(|current| {
+        let runtime = &mut current.borrow_mut().runtime;
+        if runtime.heap_limit != limit {
+            runtime.heap_limit = limit;
+            runtime.heap_limit_exceeded = false;
+        }
+    });
+}
+
+/// Returns `true` if a collection could not bring the heap below the limit set by
+/// [`set_heap_limit`] since the last call to this function, and resets the flag.
+#[must_use]
+pub fn take_heap_limit_exceeded() -> bool {
+    BOA_GC.with(|current| mem::take(&mut current.borrow_mut().runtime.heap_limit_exceeded))
+}
+
+/// Returns statistics about the garbage collector of the current thread.
+#[must_use]
+pub fn statistics() -> GcStatistics {
+    BOA_GC.with(|current| {
+        let gc = current.borrow();
+        GcStatistics {
+            allocated_bytes: gc.runtime.bytes_allocated,
+            live_bytes: gc.runtime.live_bytes,
+            allocated_objects: gc.runtime.objects,
+            allocated_ephemerons: gc.runtime.ephemerons,
+            threshold: gc.threshold(),
+            collections: gc.runtime.collections,
+            marking_steps: gc.runtime.marking_steps,
+            marking: gc.runtime.marking.is_some(),
+            last_pause: gc.runtime.last_pause,
+            max_pause: gc.runtime.max_pause,
+            total_pause: gc.runtime.total_pause,
+        }
+    })
+}
+
+/// Pushes a marked node to the mark stack, so that its value is traced later.
+///
+/// # Safety
+///
+/// `node` must point to a live `GcBox` that stays alive until the mark stack is drained, and
+/// `trace_value` must be the trace function of its type.
+pub(crate) unsafe fn mark_gray(node: NonNull<()>, trace_value: TraceFn) {
+    MARK_STACK.with(|stack| stack.borrow_mut().push((node, trace_value)));
+}
+
+/// Returns `true` if an incremental marking cycle is in progress.
+pub(crate) fn is_marking() -> bool {
+    // The flag may already be destroyed if the thread is exiting.
+    GC_MARKING.try_with(Cell::get).unwrap_or(false)
+}
+
+/// Returns the current instant, or `None` on targets without a clock.
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+#[allow(clippy::unnecessary_wraps)]
+fn now() -> Option<std::time::Instant> {
+    Some(std::time::Instant::now())
+}
+
+/// Returns the current instant, or `None` on targets without a clock.
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+const fn now() -> Option<std::time::Instant> {
+    None
+}
+
 #[cfg(test)]
 mod test;
 
@@ -59,6 +59,12 @@ impl<T: Trace + ?Sized> Gc<T> {
         GcBox::ptr_eq(this.inner(), other.inner())
     }
 
+    /// Returns the address of the allocation, which identifies it in [`walk_heap`][crate::walk_heap].
+    #[cfg(feature = "heap-snapshot")]
+    pub fn addr(this: &Self) -> usize {
+        this.inner().addr()
+    }
+
     /// Constructs a `Gc<T>` from a raw pointer.
     ///
     /// The raw pointer must have been returned by a previous call to [`Gc<U>::into_raw`][Gc::into_raw]
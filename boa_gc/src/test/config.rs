@@ -0,0 +1,96 @@
+use super::{run_test, Harness};
+use crate::{
+    config, force_collect, heap_limit, set_config, set_heap_limit, statistics,
+    take_heap_limit_exceeded, Gc, GcConfig, WeakGc,
+};
+
+#[test]
+fn statistics_track_allocations() {
+    run_test(|| {
+        let first = Gc::new(1_u32);
+        let second = Gc::new(2_u32);
+        let weak = WeakGc::new(&first);
+
+        let stats = statistics();
+        assert_eq!(stats.allocated_objects, 2);
+        assert_eq!(stats.allocated_ephemerons, 1);
+        assert_eq!(stats.collections, 0);
+
+        drop(second);
+        force_collect();
+        let stats = statistics();
+        assert_eq!(stats.collections, 1);
+        assert_eq!(stats.allocated_objects, 1);
+        assert_eq!(stats.allocated_ephemerons, 1);
+        assert_eq!(stats.live_bytes, stats.allocated_bytes);
+        assert!(stats.max_pause >= stats.last_pause);
+        assert!(stats.total_pause >= stats.last_pause);
+
+        drop(weak);
+        drop(first);
+        force_collect();
+        let stats = statistics();
+        assert_eq!(stats.allocated_objects, 0);
+        assert_eq!(stats.allocated_ephemerons, 0);
+        Harness::assert_empty_gc();
+    });
+}
+
+#[test]
+fn custom_threshold() {
+    run_test(|| {
+        let mut gc_config = config();
+        gc_config.set_threshold(usize::MAX);
+        set_config(gc_config);
+        assert_eq!(statistics().threshold, usize::MAX);
+
+        let values = (0..1000).map(Gc::new).collect::<Vec<_>>();
+        Harness::assert_collections(0);
+        drop(values);
+
+        let mut gc_config = config();
+        gc_config.set_threshold(0);
+        set_config(gc_config);
+
+        let _value = Gc::new(0_u32);
+        Harness::assert_collections(1);
+    });
+}
+
+#[test]
+fn threshold_grows_by_factor() {
+    run_test(|| {
+        let mut gc_config = GcConfig::default();
+        gc_config.set_threshold(0);
+        gc_config.set_growth_factor(3.0);
+        set_config(gc_config);
+
+        let _first = Gc::new(0_u64);
+        let _second = Gc::new(0_u64);
+        Harness::assert_collections(1);
+
+        let stats = statistics();
+        assert_eq!(stats.threshold, stats.live_bytes * 3);
+    });
+}
+
+#[test]
+fn exceeded_heap_limit() {
+    run_test(|| {
+        let mut gc_config = config();
+        gc_config.set_threshold(usize::MAX);
+        set_config(gc_config);
+        set_heap_limit(Some(1024));
+        assert_eq!(heap_limit(), Some(1024));
+
+        let values = (0..100).map(Gc::new).collect::<Vec<_>>();
+        Harness::assert_collections(1);
+        assert!(take_heap_limit_exceeded());
+        assert!(!take_heap_limit_exceeded());
+
+        drop(values);
+        let _value = Gc::new(0_u32);
+        Harness::assert_collections(2);
+        assert!(!take_heap_limit_exceeded());
+    });
+}
@@ -0,0 +1,68 @@
+use super::run_test;
+use crate::{force_collect, walk_heap, Finalize, Gc, Trace, WeakGc};
+
+#[derive(Trace, Finalize)]
+struct Node {
+    value: usize,
+    children: Vec<Gc<Self>>,
+}
+
+#[test]
+fn walk_nodes_and_edges() {
+    run_test(|| {
+        let leaf = Gc::new(Node {
+            value: 1,
+            children: Vec::new(),
+        });
+        let root = Gc::new(Node {
+            value: 0,
+            children: vec![leaf.clone(), leaf.clone()],
+        });
+        let leaf_id = Gc::addr(&leaf);
+        let root_id = Gc::addr(&root);
+        drop(leaf);
+        force_collect();
+
+        let mut nodes = Vec::new();
+        walk_heap(|node| {
+            let value = node.downcast_ref::<Node>().map(|node| node.value);
+            assert!(node.downcast_ref::<u64>().is_none());
+            assert!(node.size() > 0);
+            assert!(node.type_name().ends_with("Node"));
+            nodes.push((node.id(), value, node.is_rooted(), node.edges()));
+        });
+        nodes.sort_by_key(|(_, value, _, _)| *value);
+
+        assert_eq!(
+            nodes,
+            vec![
+                (root_id, Some(0), true, vec![leaf_id]),
+                (leaf_id, Some(1), false, Vec::new()),
+            ]
+        );
+
+        // Walking the heap doesn't mark anything.
+        drop(root);
+        force_collect();
+        walk_heap(|_| panic!("the heap should be empty"));
+    });
+}
+
+#[test]
+fn weak_references_are_not_edges() {
+    run_test(|| {
+        let key = Gc::new(0_u64);
+        let weak = Gc::new(WeakGc::new(&key));
+
+        walk_heap(|node| {
+            if node.downcast_ref::<WeakGc<u64>>().is_some() {
+                assert!(node.edges().is_empty());
+            }
+        });
+
+        // Walking the heap doesn't keep the ephemerons alive.
+        drop(key);
+        force_collect();
+        assert!(weak.upgrade().is_none());
+    });
+}
@@ -0,0 +1,182 @@
+use super::run_test;
+use crate::{
+    config, force_collect, set_config, statistics, Finalize, Gc, GcRefCell, Trace, WeakGc,
+};
+use std::time::Duration;
+
+#[derive(Trace, Finalize)]
+struct Node {
+    value: usize,
+    next: Option<Gc<Self>>,
+}
+
+fn list(len: usize) -> Gc<Node> {
+    let mut head = Gc::new(Node {
+        value: 0,
+        next: None,
+    });
+    for value in 1..len {
+        head = Gc::new(Node {
+            value,
+            next: Some(head),
+        });
+    }
+    head
+}
+
+fn list_len(head: &Gc<Node>) -> usize {
+    let mut len = 1;
+    let mut node = head.next.clone();
+    while let Some(next) = node {
+        len += 1;
+        node = next.next.clone();
+    }
+    len
+}
+
+/// Allocates garbage until an incremental marking cycle starts.
+fn start_marking() {
+    let mut gc_config = config();
+    gc_config.set_threshold(statistics().allocated_bytes);
+    gc_config.set_incremental_budget(Some(Duration::ZERO));
+    set_config(gc_config);
+
+    while !statistics().marking {
+        drop(Gc::new(0_u64));
+    }
+}
+
+#[test]
+fn deep_graph() {
+    run_test(|| {
+        let head = list(100_000);
+
+        force_collect();
+        assert_eq!(list_len(&head), 100_000);
+        assert_eq!(head.value, 99_999);
+
+        drop(head);
+        force_collect();
+        assert_eq!(statistics().allocated_objects, 0);
+    });
+}
+
+#[test]
+fn incremental_cycle() {
+    run_test(|| {
+        let head = list(10_000);
+        start_marking();
+
+        let collections = statistics().collections;
+        while statistics().marking {
+            drop(Gc::new(0_u64));
+        }
+
+        let stats = statistics();
+        assert_eq!(stats.collections, collections + 1);
+        assert!(stats.marking_steps > 1);
+        assert_eq!(list_len(&head), 10_000);
+
+        drop(head);
+        force_collect();
+        assert_eq!(statistics().allocated_objects, 0);
+    });
+}
+
+#[test]
+fn write_barrier() {
+    run_test(|| {
+        // Allocated first, so that it is traced by the first marking step.
+        let holder: Gc<GcRefCell<Option<Gc<Node>>>> = Gc::new(GcRefCell::new(None));
+        let head = list(10_000);
+        start_marking();
+
+        let value = Gc::new(Node {
+            value: 42,
+            next: None,
+        });
+        let weak = WeakGc::new(&value);
+        *holder.borrow_mut() = Some(value);
+        assert!(statistics().marking);
+
+        force_collect();
+        assert!(weak.upgrade().is_some());
+        assert_eq!(holder.borrow().as_ref().map(|node| node.value), Some(42));
+        assert_eq!(list_len(&head), 10_000);
+    });
+}
+
+#[derive(Trace, Finalize)]
+struct Holder {
+    cell: Gc<GcRefCell<Option<Gc<Node>>>>,
+}
+
+/// Allocates garbage until the current incremental marking cycle finishes.
+fn finish_marking() {
+    while statistics().marking {
+        drop(Gc::new(0_u64));
+    }
+}
+
+#[test]
+fn write_barrier_on_traced_cell() {
+    run_test(|| {
+        // The cell is only reachable from the holder, and the holder is the oldest root, so
+        // both are traced by the first marking step.
+        let holder = Gc::new(Holder {
+            cell: Gc::new(GcRefCell::new(None)),
+        });
+        let head = list(10_000);
+        start_marking();
+        assert!(statistics().marking_steps > 0);
+
+        let value = Gc::new(Node {
+            value: 42,
+            next: None,
+        });
+        let weak = WeakGc::new(&value);
+        *holder.cell.borrow_mut() = Some(value);
+        assert!(statistics().marking);
+
+        finish_marking();
+        assert!(weak.upgrade().is_some());
+        assert_eq!(
+            holder.cell.borrow().as_ref().map(|node| node.value),
+            Some(42)
+        );
+        assert_eq!(list_len(&head), 10_000);
+    });
+}
+
+#[test]
+fn mutation_of_rooted_cell_during_marking() {
+    run_test(|| {
+        let holder: Gc<GcRefCell<GcRefCell<Option<Gc<Node>>>>> =
+            Gc::new(GcRefCell::new(GcRefCell::new(None)));
+        let head = list(10_000);
+        start_marking();
+
+        let value = Gc::new(Node {
+            value: 42,
+            next: None,
+        });
+        let weak = WeakGc::new(&value);
+        {
+            // Borrowing the outer cell roots the inner one, so the inner borrow doesn't run the
+            // write barrier and the cycle has to find the value through the roots.
+            let outer = holder.borrow_mut();
+            *outer.borrow_mut() = Some(value);
+            assert!(statistics().marking);
+
+            finish_marking();
+            assert!(weak.upgrade().is_some());
+        }
+
+        assert!(weak.upgrade().is_some());
+        assert_eq!(
+            holder.borrow().borrow().as_ref().map(|node| node.value),
+            Some(42)
+        );
+        assert_eq!(list_len(&head), 10_000);
+    });
+}
@@ -2,6 +2,10 @@ use crate::BOA_GC;
 
 mod allocation;
 mod cell;
+mod config;
+#[cfg(feature = "heap-snapshot")]
+mod heap;
+mod incremental;
 mod weak;
 mod weak_map;
 
@@ -26,6 +26,25 @@ fn eph_weak_gc_test() {
     });
 }
 
+#[test]
+fn eph_rooted_weak_gc_test() {
+    run_test(|| {
+        let gc_value = Gc::new(3);
+        let weak = WeakGc::new(&gc_value);
+
+        // Collecting an unrelated value must not clear a `WeakGc` with a live target.
+        drop(Gc::new(4));
+        force_collect();
+
+        assert_eq!(*weak.upgrade().expect("WeakGc is still live here"), 3);
+
+        drop(gc_value);
+        force_collect();
+
+        assert!(weak.upgrade().is_none());
+    });
+}
+
 #[test]
 fn eph_ephemeron_test() {
     run_test(|| {
@@ -77,6 +96,12 @@ fn eph_allocation_chains() {
             drop(gc_value);
             force_collect();
 
+            assert!(wrap.upgrade().is_none());
+            assert_eq!(eph.value().expect("key is still live"), 3);
+
+            drop(wrap);
+            force_collect();
+
             assert!(eph.value().is_none());
         }
     });
@@ -158,7 +183,7 @@ fn eph_self_referential() {
             *root.inner.inner.borrow_mut() = Some(eph.clone());
 
             assert!(eph.value().is_some());
-            Harness::assert_exact_bytes_allocated(80);
+            Harness::assert_exact_bytes_allocated(88);
         }
 
         *root.inner.inner.borrow_mut() = None;
@@ -204,7 +229,7 @@ fn eph_self_referential_chain() {
 
             assert!(eph_start.value().is_some());
             assert!(eph_chain2.value().is_some());
-            Harness::assert_exact_bytes_allocated(240);
+            Harness::assert_exact_bytes_allocated(272);
         }
 
         *root.borrow_mut() = None;
@@ -149,5 +149,7 @@ static_syms! {
     "name",
     "await",
     ("*default*", DEFAULT_EXPORT),
-    "meta"
+    "meta",
+    "accessor",
+    "using"
 }
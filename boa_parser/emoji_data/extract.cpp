@@ -0,0 +1,76 @@
+// Extracts the emoji properties of strings used by `v` mode regular expressions from ICU4C.
+//
+// Usage: extract <unicode|sequences>
+//
+// `sequences` prints one `property \t code points` line per string of the properties, with the
+// code points as space separated hexadecimal numbers. See `generate.py` for how the output is
+// turned into Rust tables.
+
+#include <unicode/uchar.h>
+#include <unicode/uniset.h>
+#include <unicode/unistr.h>
+#include <unicode/usetiter.h>
+
+#include <cstdlib>
+#include <iostream>
+#include <string>
+
+using namespace icu;
+
+namespace {
+
+// The properties of strings other than `RGI_Emoji`, which is the union of all of them.
+const char* const PROPERTIES[] = {
+    "Basic_Emoji",
+    "Emoji_Keycap_Sequence",
+    "RGI_Emoji_Flag_Sequence",
+    "RGI_Emoji_Modifier_Sequence",
+    "RGI_Emoji_Tag_Sequence",
+    "RGI_Emoji_ZWJ_Sequence",
+};
+
+void emit(const char* property, const UnicodeString& string) {
+    std::cout << property << '\t' << std::hex << std::uppercase;
+    for (int32_t i = 0; i < string.length(); i = string.moveIndex32(i, 1)) {
+        std::cout << (i > 0 ? " " : "") << string.char32At(i);
+    }
+    std::cout << std::dec << '\n';
+}
+
+void sequences() {
+    for (const char* property : PROPERTIES) {
+        UErrorCode status = U_ZERO_ERROR;
+        UnicodeSet set(UnicodeString("[:") + property + ":]", status);
+        if (U_FAILURE(status)) {
+            std::cerr << "unsupported property " << property << ": " << u_errorName(status)
+                      << '\n';
+            std::exit(1);
+        }
+        // Iterates over the code points of the set, then over its other strings.
+        UnicodeSetIterator iterator(set);
+        while (iterator.next()) {
+            emit(property, iterator.getString());
+        }
+    }
+}
+
+}  // namespace
+
+int main(int argc, char** argv) {
+    if (argc < 2) {
+        std::cerr << "usage: extract <unicode|sequences>\n";
+        return 1;
+    }
+    std::string command = argv[1];
+    if (command == "unicode") {
+        UVersionInfo version;
+        u_getUnicodeVersion(version);
+        std::cout << int(version[0]) << '.' << int(version[1]) << '\n';
+    } else if (command == "sequences") {
+        sequences();
+    } else {
+        std::cerr << "unknown command " << command << '\n';
+        return 1;
+    }
+    return 0;
+}
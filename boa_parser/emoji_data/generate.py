@@ -0,0 +1,56 @@
+#!/usr/bin/env python3
+"""Generates `boa_parser/src/lexer/regex/emoji.rs`, the emoji properties of strings.
+
+The sequences are extracted from the ICU4C library installed on the system, using `extract.cpp`:
+
+    c++ -std=c++17 extract.cpp -o extract -licuuc -licudata
+    python3 generate.py ./extract
+
+Every property is a list of strings sorted by code points, including the single code points of
+`Basic_Emoji`. `RGI_Emoji` is not stored, since it's the union of the other properties.
+"""
+
+import os
+import subprocess
+import sys
+
+OUTPUT = os.path.join(os.path.dirname(os.path.abspath(__file__)), '..', 'src', 'lexer', 'regex',
+                      'emoji.rs')
+HEADER = """//! The sequences of the emoji properties of strings.
+//! Version: Unicode Emoji {version}
+//!
+//! This file is generated by `boa_parser/emoji_data/generate.py`. Please do not modify it directly."""
+
+
+def constant(prop):
+    """Converts a property name to the name of its Rust constant."""
+    return prop.upper()
+
+
+def string(code_points):
+    """Returns the Rust string literal of a list of code points."""
+    return '"%s"' % ''.join('\\u{%X}' % c for c in code_points)
+
+
+if __name__ == '__main__':
+    EXTRACT = os.path.abspath(sys.argv[1])
+    VERSION = subprocess.run([EXTRACT, 'unicode'], check=True, capture_output=True,
+                             encoding='utf-8').stdout.strip()
+    OUT = subprocess.run([EXTRACT, 'sequences'], check=True, capture_output=True,
+                         encoding='utf-8').stdout
+
+    properties = {}
+    for line in OUT.splitlines():
+        prop, code_points = line.split('\t')
+        properties.setdefault(prop, []).append([int(c, 16) for c in code_points.split()])
+
+    lines = [HEADER.format(version=VERSION)]
+    for prop, sequences in properties.items():
+        lines.append('')
+        lines.append('/// `%s`' % prop)
+        lines.append('pub(super) static %s: [&str; %d] = [' % (constant(prop), len(sequences)))
+        for sequence in sorted(sequences):
+            lines.append('    %s,' % string(sequence))
+        lines.append('];')
+    with open(OUTPUT, 'w', encoding='utf-8') as file:
+        file.write('\n'.join(lines) + '\n')
@@ -3,7 +3,7 @@
 use crate::lexer::{Cursor, Error, Span, Token, TokenKind, Tokenizer};
 use bitflags::bitflags;
 use boa_ast::Position;
-use boa_interner::{Interner, Sym};
+use boa_interner::Interner;
 use boa_profiler::Profiler;
 use regress::{Flags, Regex};
 use std::{
@@ -11,6 +11,9 @@ use std::{
     str::{self, FromStr},
 };
 
+mod emoji;
+mod unicode_sets;
+
 /// Regex literal lexing.
 ///
 /// Lexes Division, Assigndiv or Regex literal.
@@ -121,7 +124,10 @@ impl<R> Tokenizer<R> for RegexLiteral {
 
         let flags_str = unsafe { str::from_utf8_unchecked(flags.as_slice()) };
         if let Ok(body_str) = str::from_utf8(body.as_slice()) {
-            if let Err(error) = Regex::with_flags(body_str, flags_str) {
+            let flags = RegExpFlags::from_str(flags_str)
+                .map_err(|message| Error::Syntax(message.into(), flags_start))?;
+
+            if let Err(error) = create_matcher(body_str.chars().map(u32::from), flags) {
                 return Err(Error::Syntax(
                     format!("Invalid regular expression literal: {error}").into(),
                     start_pos,
@@ -131,7 +137,7 @@ impl<R> Tokenizer<R> for RegexLiteral {
             Ok(Token::new(
                 TokenKind::regular_expression_literal(
                     interner.get_or_intern(body_str),
-                    parse_regex_flags(flags_str, flags_start, interner)?,
+                    interner.get_or_intern(flags.to_string().as_str()),
                 ),
                 Span::new(start_pos, cursor.pos()),
             ))
@@ -144,6 +150,29 @@ impl<R> Tokenizer<R> for RegexLiteral {
     }
 }
 
+/// Compiles the regular expression `pattern`, given as code points, with the given `flags`.
+///
+/// Patterns using the `v` flag are translated into the equivalent `u` mode pattern before being
+/// handed to the regex backend.
+///
+/// # Errors
+///
+/// Returns an error if `pattern` is not a valid pattern for the given `flags`.
+pub fn create_matcher<I>(pattern: I, flags: RegExpFlags) -> Result<Regex, regress::Error>
+where
+    I: IntoIterator<Item = u32>,
+    I::IntoIter: Clone,
+{
+    if !flags.contains(RegExpFlags::UNICODE_SETS) {
+        return Regex::from_unicode(pattern.into_iter(), Flags::from(flags));
+    }
+
+    let pattern = pattern.into_iter().collect::<Vec<_>>();
+    let pattern = unicode_sets::translate(&pattern, flags.contains(RegExpFlags::IGNORE_CASE))
+        .map_err(|text| regress::Error { text })?;
+    Regex::from_unicode(pattern.into_iter(), Flags::from(flags))
+}
+
 bitflags! {
     /// Flags of a regular expression.
     #[derive(Debug, Default, Copy, Clone)]
@@ -170,6 +199,10 @@ bitflags! {
         /// Whether the regular expression result exposes the start and end indices of
         /// captured substrings.
         const HAS_INDICES = 0b0100_0000;
+
+        /// Whether or not Unicode features are enabled, along with the set notation and
+        /// properties of strings of the `v` flag.
+        const UNICODE_SETS = 0b1000_0000;
     }
 }
 
@@ -187,6 +220,7 @@ impl FromStr for RegExpFlags {
                 b'u' => Self::UNICODE,
                 b'y' => Self::STICKY,
                 b'd' => Self::HAS_INDICES,
+                b'v' => Self::UNICODE_SETS,
                 _ => return Err(format!("invalid regular expression flag {}", char::from(c))),
             };
 
@@ -199,14 +233,11 @@ impl FromStr for RegExpFlags {
             flags.insert(new_flag);
         }
 
-        Ok(flags)
-    }
-}
+        if flags.contains(Self::UNICODE | Self::UNICODE_SETS) {
+            return Err("regular expression flags u and v cannot be used together".to_owned());
+        }
 
-fn parse_regex_flags(s: &str, start: Position, interner: &mut Interner) -> Result<Sym, Error> {
-    match RegExpFlags::from_str(s) {
-        Err(message) => Err(Error::Syntax(message.into(), start)),
-        Ok(flags) => Ok(interner.get_or_intern(flags.to_string().as_str())),
+        Ok(flags)
     }
 }
 
@@ -231,6 +262,9 @@ impl ToString for RegExpFlags {
         if self.contains(Self::UNICODE) {
             s.push('u');
         }
+        if self.contains(Self::UNICODE_SETS) {
+            s.push('v');
+        }
         if self.contains(Self::STICKY) {
             s.push('y');
         }
@@ -244,7 +278,7 @@ impl From<RegExpFlags> for Flags {
             icase: value.contains(RegExpFlags::IGNORE_CASE),
             multiline: value.contains(RegExpFlags::MULTILINE),
             dot_all: value.contains(RegExpFlags::DOT_ALL),
-            unicode: value.contains(RegExpFlags::UNICODE),
+            unicode: value.intersects(RegExpFlags::UNICODE | RegExpFlags::UNICODE_SETS),
             ..Self::default()
         }
     }
@@ -0,0 +1,946 @@
+//! Translation of `v` flag (`unicodeSets`) regular expressions.
+//!
+//! The regex backend only supports the `u` flag, so patterns using the `v` flag are rewritten
+//! into an equivalent `u` mode pattern before being compiled:
+//!
+//! - Class set operations are expressed with lookaheads, i.e. `[A&&B]` becomes `(?=[A])[B]`,
+//!   and `[A--B]` becomes `(?![B])[A]`.
+//! - Class string disjunctions (`\q{...}`) become alternations, ordered from the longest to the
+//!   shortest string as required by the specification.
+//! - Properties of strings become a trie of their strings, which are read from the Unicode emoji
+//!   data of the `emoji` tables. The trie also tries longer strings first.
+//!
+//! Everything outside character classes has the same syntax in `u` and `v` mode, so it's
+//! forwarded unchanged to the backend, which is also in charge of validating it.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#prod-ClassSetExpression
+
+use super::emoji;
+use regress::{Flags, Regex};
+
+/// Returns the `u` mode pattern matching the property of strings `name`, if any.
+fn property_of_strings(name: &str) -> Option<Vec<u32>> {
+    let properties: &[&[&str]] = match name {
+        "Basic_Emoji" => &[&emoji::BASIC_EMOJI],
+        "Emoji_Keycap_Sequence" => &[&emoji::EMOJI_KEYCAP_SEQUENCE],
+        "RGI_Emoji_Flag_Sequence" => &[&emoji::RGI_EMOJI_FLAG_SEQUENCE],
+        "RGI_Emoji_Modifier_Sequence" => &[&emoji::RGI_EMOJI_MODIFIER_SEQUENCE],
+        "RGI_Emoji_Tag_Sequence" => &[&emoji::RGI_EMOJI_TAG_SEQUENCE],
+        "RGI_Emoji_ZWJ_Sequence" => &[&emoji::RGI_EMOJI_ZWJ_SEQUENCE],
+        "RGI_Emoji" => &[
+            &emoji::BASIC_EMOJI,
+            &emoji::EMOJI_KEYCAP_SEQUENCE,
+            &emoji::RGI_EMOJI_FLAG_SEQUENCE,
+            &emoji::RGI_EMOJI_MODIFIER_SEQUENCE,
+            &emoji::RGI_EMOJI_TAG_SEQUENCE,
+            &emoji::RGI_EMOJI_ZWJ_SEQUENCE,
+        ],
+        _ => return None,
+    };
+
+    let mut strings = properties
+        .iter()
+        .flat_map(|strings| strings.iter())
+        .map(|string| string.chars().map(u32::from).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    strings.sort_unstable();
+    strings.dedup();
+
+    let mut pattern = Vec::new();
+    push_trie(&mut pattern, &strings, 0);
+    Some(pattern)
+}
+
+/// Pushes the pattern matching any of `strings` into `pattern`, as a trie of their code points
+/// after the first `depth` ones, which are the same for all of them.
+///
+/// `strings` must be sorted. The alternatives of a node try longer strings first, so the pattern
+/// matches the longest string at a given position, as an alternation ordered from the longest to
+/// the shortest string would.
+fn push_trie(pattern: &mut Vec<u32>, strings: &[Vec<u32>], depth: usize) {
+    // Being sorted, the string that ends at this node comes first.
+    let (end, strings) = match strings {
+        [first, rest @ ..] if first.len() == depth => (true, rest),
+        strings => (false, strings),
+    };
+
+    let mut alternatives = Vec::new();
+    let mut class = Vec::new();
+    let mut range: Option<(u32, u32)> = None;
+    let mut rest = strings;
+    while let Some(first) = rest.first() {
+        let c = first[depth];
+        let (children, next) = rest.split_at(
+            rest.iter()
+                .position(|s| s[depth] != c)
+                .unwrap_or(rest.len()),
+        );
+        rest = next;
+        if let [child] = children {
+            if child.len() == depth + 1 {
+                // Merges the single code point leaves into a class of ranges.
+                match &mut range {
+                    Some((_, last)) if *last + 1 == c => *last = c,
+                    _ => {
+                        if let Some(range) = range.replace((c, c)) {
+                            push_range(&mut class, range);
+                        }
+                    }
+                }
+                continue;
+            }
+        }
+        let mut alternative = Vec::new();
+        push_code_point(&mut alternative, c);
+        push_trie(&mut alternative, children, depth + 1);
+        alternatives.push(alternative);
+    }
+    if let Some(range) = range {
+        push_range(&mut class, range);
+    }
+    if !class.is_empty() {
+        let mut alternative = Vec::new();
+        push_str(&mut alternative, "[");
+        alternative.extend(class);
+        push_str(&mut alternative, "]");
+        alternatives.push(alternative);
+    }
+
+    match &alternatives[..] {
+        [] => {}
+        [alternative] if !end => pattern.extend_from_slice(alternative),
+        alternatives => {
+            push_str(pattern, "(?:");
+            for (i, alternative) in alternatives.iter().enumerate() {
+                if i > 0 {
+                    push_str(pattern, "|");
+                }
+                pattern.extend_from_slice(alternative);
+            }
+            if end {
+                push_str(pattern, "|");
+            }
+            push_str(pattern, ")");
+        }
+    }
+}
+
+/// Translates `pattern`, a `v` mode pattern, into an equivalent `u` mode pattern.
+pub(super) fn translate(pattern: &[u32], ignore_case: bool) -> Result<Vec<u32>, String> {
+    let mut translator = Translator {
+        pattern,
+        position: 0,
+        ignore_case,
+    };
+    let mut output = Vec::with_capacity(pattern.len());
+
+    while let Some(c) = translator.next() {
+        match char::from_u32(c) {
+            Some('\\') => match translator.peek().and_then(char::from_u32) {
+                Some(kind @ ('p' | 'P')) if translator.peek_n(1) == Some('{' as u32) => {
+                    translator.position += 1;
+                    let name = translator.property_name()?;
+                    match property_of_strings(&name) {
+                        Some(_) if kind == 'P' => {
+                            return Err(format!("invalid negated property of strings {name}"));
+                        }
+                        Some(property) => {
+                            push_str(&mut output, "(?:");
+                            output.extend(property);
+                            push_str(&mut output, ")");
+                        }
+                        None => {
+                            push_str(&mut output, &format!(r"\{kind}{{{name}}}"));
+                        }
+                    }
+                }
+                Some(_) => {
+                    output.push(c);
+                    output.extend(translator.next());
+                }
+                None => output.push(c),
+            },
+            Some('[') => {
+                let set = translator.class()?;
+                output.extend(set.to_pattern());
+            }
+            _ => output.push(c),
+        }
+    }
+
+    Ok(output)
+}
+
+/// The set of code points matched by a character class, in `u` mode syntax.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum CharSet {
+    /// The contents of a `u` mode character class.
+    Class { negated: bool, body: Vec<u32> },
+
+    /// A `u` mode expression that always matches exactly one code point.
+    Expression(Vec<u32>),
+}
+
+impl Default for CharSet {
+    fn default() -> Self {
+        Self::Class {
+            negated: false,
+            body: Vec::new(),
+        }
+    }
+}
+
+impl CharSet {
+    /// Returns `true` if the set doesn't contain any code point.
+    fn is_empty(&self) -> bool {
+        matches!(self, Self::Class { negated: false, body } if body.is_empty())
+    }
+
+    /// Returns the `u` mode expression matching a code point of the set.
+    fn to_pattern(&self) -> Vec<u32> {
+        let mut pattern = Vec::new();
+        match self {
+            Self::Class { negated, body } => {
+                push_str(&mut pattern, if *negated { "[^" } else { "[" });
+                pattern.extend_from_slice(body);
+                push_str(&mut pattern, "]");
+            }
+            Self::Expression(expression) => {
+                push_str(&mut pattern, "(?:");
+                pattern.extend_from_slice(expression);
+                push_str(&mut pattern, ")");
+            }
+        }
+        pattern
+    }
+
+    /// Returns the union of the two sets.
+    fn union(self, other: Self) -> Self {
+        match (self, other) {
+            (set, other) if other.is_empty() => set,
+            (set, other) if set.is_empty() => other,
+            (
+                Self::Class {
+                    negated: false,
+                    mut body,
+                },
+                Self::Class {
+                    negated: false,
+                    body: other,
+                },
+            ) => {
+                body.extend(other);
+                Self::Class {
+                    negated: false,
+                    body,
+                }
+            }
+            (set, other) => {
+                let mut expression = set.to_pattern();
+                push_str(&mut expression, "|");
+                expression.extend(other.to_pattern());
+                Self::Expression(expression)
+            }
+        }
+    }
+
+    /// Returns the code points of `self` that are matched by the lookahead `pattern`.
+    fn lookahead(self, positive: bool, pattern: &[u32]) -> Self {
+        let mut expression = Vec::new();
+        push_str(&mut expression, if positive { "(?=" } else { "(?!" });
+        expression.extend_from_slice(pattern);
+        push_str(&mut expression, ")");
+        expression.extend(self.to_pattern());
+        Self::Expression(expression)
+    }
+
+    /// Returns the intersection of the two sets.
+    fn intersection(self, other: &Self) -> Self {
+        if self.is_empty() || other.is_empty() {
+            return Self::default();
+        }
+        self.lookahead(true, &other.to_pattern())
+    }
+
+    /// Returns the difference of the two sets.
+    fn subtraction(self, other: &Self) -> Self {
+        if self.is_empty() || other.is_empty() {
+            return self;
+        }
+        self.lookahead(false, &other.to_pattern())
+    }
+
+    /// Returns the complement of the set.
+    fn complement(self) -> Self {
+        match self {
+            Self::Class { negated, body } => Self::Class {
+                negated: !negated,
+                body,
+            },
+            Self::Expression(_) => {
+                let mut pattern = Vec::new();
+                push_str(&mut pattern, r"[\u{0}-\u{10FFFF}]");
+                Self::Expression(pattern).lookahead(false, &self.to_pattern())
+            }
+        }
+    }
+}
+
+/// The set of strings matched by a class set expression.
+#[derive(Debug, Clone, Default)]
+struct ClassSet {
+    /// The strings of one code point of the set.
+    chars: CharSet,
+
+    /// The strings of the set that don't have exactly one code point.
+    strings: Vec<Vec<u32>>,
+
+    /// The properties of strings of the set, as `u` mode patterns.
+    properties: Vec<Vec<u32>>,
+
+    /// The static semantics `MayContainStrings` of the expression.
+    may_contain_strings: bool,
+}
+
+impl ClassSet {
+    /// Creates a set from a set of code points.
+    fn from_chars(chars: CharSet) -> Self {
+        Self {
+            chars,
+            ..Self::default()
+        }
+    }
+
+    /// Returns the `u` mode pattern matching any string of the set, trying longer strings first.
+    fn to_pattern(&self) -> Vec<u32> {
+        if self.strings.is_empty() && self.properties.is_empty() {
+            return self.chars.to_pattern();
+        }
+
+        let mut strings = self.strings.iter().collect::<Vec<_>>();
+        strings.sort_by_key(|string| std::cmp::Reverse(string.len()));
+
+        let mut alternatives = Vec::new();
+        for string in strings.iter().filter(|string| !string.is_empty()) {
+            let mut alternative = Vec::new();
+            for &c in *string {
+                push_code_point(&mut alternative, c);
+            }
+            alternatives.push(alternative);
+        }
+        for property in &self.properties {
+            alternatives.push(property.clone());
+        }
+        if !self.chars.is_empty() {
+            alternatives.push(self.chars.to_pattern());
+        }
+        if strings.iter().any(|string| string.is_empty()) {
+            alternatives.push(Vec::new());
+        }
+
+        let mut pattern = Vec::new();
+        push_str(&mut pattern, "(?:");
+        for (i, alternative) in alternatives.into_iter().enumerate() {
+            if i > 0 {
+                push_str(&mut pattern, "|");
+            }
+            pattern.extend(alternative);
+        }
+        push_str(&mut pattern, ")");
+        pattern
+    }
+
+    /// Adds a string to the set.
+    fn insert_string(&mut self, string: Vec<u32>) {
+        if let [c] = string[..] {
+            let mut body = Vec::new();
+            push_code_point(&mut body, c);
+            self.chars = std::mem::take(&mut self.chars).union(CharSet::Class {
+                negated: false,
+                body,
+            });
+        } else {
+            self.may_contain_strings = true;
+            if !self.strings.contains(&string) {
+                self.strings.push(string);
+            }
+        }
+    }
+
+    /// Returns the pattern matching any of the properties of strings of the set.
+    fn properties_pattern(&self) -> Vec<u32> {
+        let mut pattern = Vec::new();
+        push_str(&mut pattern, "(?:");
+        for (i, property) in self.properties.iter().enumerate() {
+            if i > 0 {
+                push_str(&mut pattern, "|");
+            }
+            pattern.extend_from_slice(property);
+        }
+        push_str(&mut pattern, ")");
+        pattern
+    }
+
+    /// Returns the union of the two sets.
+    fn union(mut self, other: Self) -> Self {
+        self.chars = self.chars.union(other.chars);
+        for string in other.strings {
+            if !self.strings.contains(&string) {
+                self.strings.push(string);
+            }
+        }
+        for property in other.properties {
+            if !self.properties.contains(&property) {
+                self.properties.push(property);
+            }
+        }
+        self.may_contain_strings |= other.may_contain_strings;
+        self
+    }
+
+    /// Returns the intersection of the two sets.
+    fn intersection(self, other: &Self, ignore_case: bool) -> Result<Self, String> {
+        let mut result = Self {
+            chars: self.chars.clone().intersection(&other.chars),
+            strings: Vec::new(),
+            properties: Vec::new(),
+            may_contain_strings: self.may_contain_strings && other.may_contain_strings,
+        };
+
+        for (set, other) in [(&self, other), (other, &self)] {
+            for string in &set.strings {
+                if other.contains(string, ignore_case)? && !result.strings.contains(string) {
+                    result.strings.push(string.clone());
+                }
+            }
+
+            if set.properties.is_empty() {
+                continue;
+            }
+
+            // Properties of strings are intersected by requiring a match of the other operand at
+            // the same position.
+            if other.may_contain_strings {
+                let other_pattern = other.to_pattern();
+                for property in &set.properties {
+                    if other.properties.contains(property) {
+                        if !result.properties.contains(property) {
+                            result.properties.push(property.clone());
+                        }
+                    } else {
+                        let mut intersection = Vec::new();
+                        push_str(&mut intersection, "(?=");
+                        intersection.extend_from_slice(&other_pattern);
+                        push_str(&mut intersection, ")");
+                        intersection.extend_from_slice(property);
+                        result.properties.push(intersection);
+                    }
+                }
+            } else if !other.chars.is_empty() {
+                let chars = other
+                    .chars
+                    .clone()
+                    .lookahead(true, &set.properties_pattern());
+                result.chars = std::mem::take(&mut result.chars).union(chars);
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Returns the difference of the two sets.
+    fn subtraction(self, other: &Self, ignore_case: bool) -> Result<Self, String> {
+        let mut chars = self.chars.subtraction(&other.chars);
+        if !other.properties.is_empty() && !chars.is_empty() {
+            chars = chars.lookahead(false, &other.properties_pattern());
+        }
+
+        let mut strings = Vec::new();
+        for string in self.strings {
+            if !other.contains(&string, ignore_case)? {
+                strings.push(string);
+            }
+        }
+
+        let other_pattern = other.to_pattern();
+        let properties = self
+            .properties
+            .into_iter()
+            .filter(|property| !other.properties.contains(property))
+            .map(|property| {
+                let mut subtraction = Vec::new();
+                push_str(&mut subtraction, "(?!");
+                subtraction.extend_from_slice(&other_pattern);
+                push_str(&mut subtraction, ")");
+                subtraction.extend(property);
+                subtraction
+            })
+            .collect();
+
+        Ok(Self {
+            chars,
+            strings,
+            properties,
+            may_contain_strings: self.may_contain_strings,
+        })
+    }
+
+    /// Returns `true` if the set contains `string`.
+    fn contains(&self, string: &[u32], ignore_case: bool) -> Result<bool, String> {
+        if self.strings.iter().any(|s| s == string) {
+            return Ok(true);
+        }
+        if !ignore_case && self.properties.is_empty() && string.len() != 1 {
+            return Ok(false);
+        }
+        let Some(string) = string
+            .iter()
+            .map(|&c| char::from_u32(c))
+            .collect::<Option<String>>()
+        else {
+            return Ok(false);
+        };
+
+        let mut pattern = Vec::new();
+        push_str(&mut pattern, "^");
+        pattern.extend(self.to_pattern());
+        push_str(&mut pattern, "$");
+        let matcher = Regex::from_unicode(
+            pattern.into_iter(),
+            Flags {
+                icase: ignore_case,
+                unicode: true,
+                ..Flags::default()
+            },
+        )
+        .map_err(|error| error.text)?;
+
+        Ok(matcher.find(&string).is_some())
+    }
+}
+
+/// An operand of a class set expression.
+enum Operand {
+    /// A single `ClassSetCharacter`, which can also start a `ClassSetRange`.
+    Character(u32),
+
+    /// A nested class, a character class escape or a class string disjunction.
+    Set(ClassSet),
+}
+
+impl Operand {
+    fn into_set(self) -> ClassSet {
+        match self {
+            Self::Character(c) => {
+                let mut set = ClassSet::default();
+                set.insert_string(vec![c]);
+                set
+            }
+            Self::Set(set) => set,
+        }
+    }
+}
+
+/// The kind of a class set expression.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operation {
+    Union,
+    Intersection,
+    Subtraction,
+}
+
+struct Translator<'a> {
+    pattern: &'a [u32],
+    position: usize,
+    ignore_case: bool,
+}
+
+impl Translator<'_> {
+    fn peek(&self) -> Option<u32> {
+        self.pattern.get(self.position).copied()
+    }
+
+    fn peek_n(&self, n: usize) -> Option<u32> {
+        self.pattern.get(self.position + n).copied()
+    }
+
+    fn next(&mut self) -> Option<u32> {
+        let c = self.peek()?;
+        self.position += 1;
+        Some(c)
+    }
+
+    fn next_char(&mut self) -> Result<char, String> {
+        self.next()
+            .and_then(char::from_u32)
+            .ok_or_else(|| "unterminated character class".to_owned())
+    }
+
+    fn next_is(&self, c: char) -> bool {
+        self.peek() == Some(c as u32)
+    }
+
+    fn next_is_double(&self, c: char) -> bool {
+        self.next_is(c) && self.peek_n(1) == Some(c as u32)
+    }
+
+    /// Parses the `{Name}` part of a `\p{Name}` escape.
+    fn property_name(&mut self) -> Result<String, String> {
+        self.position += 1;
+        let mut name = String::new();
+        loop {
+            match self
+                .next_char()
+                .map_err(|_| "invalid property name".to_owned())?
+            {
+                '}' => return Ok(name),
+                c => name.push(c),
+            }
+        }
+    }
+
+    /// Parses a `CharacterClass` in `v` mode, right after its opening `[`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#prod-CharacterClass
+    fn class(&mut self) -> Result<ClassSet, String> {
+        let negated = self.next_is('^');
+        if negated {
+            self.position += 1;
+        }
+
+        let set = self.class_set_expression()?;
+
+        if self.next() != Some(']' as u32) {
+            return Err("unterminated character class".to_owned());
+        }
+
+        if !negated {
+            return Ok(set);
+        }
+
+        // It is a Syntax Error if MayContainStrings of the ClassContents is true.
+        if set.may_contain_strings {
+            return Err("negated character class may contain strings".to_owned());
+        }
+
+        Ok(ClassSet::from_chars(set.chars.complement()))
+    }
+
+    /// Parses a `ClassSetExpression`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#prod-ClassSetExpression
+    fn class_set_expression(&mut self) -> Result<ClassSet, String> {
+        if self.next_is(']') {
+            return Ok(ClassSet::default());
+        }
+
+        let first = self.operand()?;
+        let operation = if self.next_is_double('&') {
+            Operation::Intersection
+        } else if self.next_is_double('-') {
+            Operation::Subtraction
+        } else {
+            Operation::Union
+        };
+
+        if operation == Operation::Union {
+            return self.class_union(first);
+        }
+
+        let mut set = first.into_set();
+        while !self.next_is(']') {
+            let (operator, name) = match operation {
+                Operation::Intersection => ('&', "intersection"),
+                _ => ('-', "subtraction"),
+            };
+            if !self.next_is_double(operator) {
+                return Err(format!("invalid set operation in character class {name}"));
+            }
+            self.position += 2;
+            if operation == Operation::Intersection && self.next_is('&') {
+                return Err("invalid character in character class".to_owned());
+            }
+
+            let operand = self.operand()?.into_set();
+            set = match operation {
+                Operation::Intersection => set.intersection(&operand, self.ignore_case)?,
+                _ => set.subtraction(&operand, self.ignore_case)?,
+            };
+        }
+
+        Ok(set)
+    }
+
+    /// Parses a `ClassUnion`, starting with the already parsed `first` operand.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#prod-ClassUnion
+    fn class_union(&mut self, first: Operand) -> Result<ClassSet, String> {
+        let mut set = ClassSet::default();
+        let mut operand = first;
+        loop {
+            match operand {
+                // ClassSetRange :: ClassSetCharacter - ClassSetCharacter
+                Operand::Character(start) if self.next_is('-') && !self.next_is_double('-') => {
+                    self.position += 1;
+                    let Operand::Character(end) = self.operand()? else {
+                        return Err("invalid character class range".to_owned());
+                    };
+
+                    // It is a Syntax Error if the CharacterValue of the first ClassSetCharacter
+                    // is strictly greater than the CharacterValue of the second ClassSetCharacter.
+                    if start > end {
+                        return Err("range out of order in character class".to_owned());
+                    }
+
+                    let mut body = Vec::new();
+                    push_range(&mut body, (start, end));
+                    set = set.union(ClassSet::from_chars(CharSet::Class {
+                        negated: false,
+                        body,
+                    }));
+                }
+                operand => set = set.union(operand.into_set()),
+            }
+
+            if self.next_is(']') {
+                return Ok(set);
+            }
+            if self.next_is_double('&') || self.next_is_double('-') {
+                return Err("invalid set operation in character class union".to_owned());
+            }
+            operand = self.operand()?;
+        }
+    }
+
+    /// Parses a `ClassSetOperand` or a `ClassSetCharacter`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#prod-ClassSetOperand
+    fn operand(&mut self) -> Result<Operand, String> {
+        match self.peek().and_then(char::from_u32) {
+            Some('[') => {
+                self.position += 1;
+                return self.class().map(Operand::Set);
+            }
+            Some('\\') => {
+                let escape = self.peek_n(1).and_then(char::from_u32);
+                match escape {
+                    Some(c @ ('d' | 'D' | 's' | 'S' | 'w' | 'W')) => {
+                        self.position += 2;
+                        let mut body = Vec::new();
+                        push_str(&mut body, &format!(r"\{c}"));
+                        return Ok(Operand::Set(ClassSet::from_chars(CharSet::Class {
+                            negated: false,
+                            body,
+                        })));
+                    }
+                    Some(kind @ ('p' | 'P')) => {
+                        self.position += 2;
+                        if !self.next_is('{') {
+                            return Err("invalid property name".to_owned());
+                        }
+                        let name = self.property_name()?;
+                        return match property_of_strings(&name) {
+                            Some(_) if kind == 'P' => {
+                                Err(format!("invalid negated property of strings {name}"))
+                            }
+                            Some(property) => Ok(Operand::Set(ClassSet {
+                                properties: vec![property],
+                                may_contain_strings: true,
+                                ..ClassSet::default()
+                            })),
+                            // The backend doesn't support property escapes inside of classes.
+                            None => {
+                                let mut expression = Vec::new();
+                                push_str(&mut expression, &format!(r"\{kind}{{{name}}}"));
+                                Ok(Operand::Set(ClassSet::from_chars(CharSet::Expression(
+                                    expression,
+                                ))))
+                            }
+                        };
+                    }
+                    Some('q') => {
+                        self.position += 2;
+                        return self.class_string_disjunction().map(Operand::Set);
+                    }
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+
+        self.class_set_character().map(Operand::Character)
+    }
+
+    /// Parses a `ClassStringDisjunction`, right after its `\q`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#prod-ClassStringDisjunction
+    fn class_string_disjunction(&mut self) -> Result<ClassSet, String> {
+        if self.next() != Some('{' as u32) {
+            return Err("invalid class string disjunction".to_owned());
+        }
+
+        let mut set = ClassSet::default();
+        let mut string = Vec::new();
+        loop {
+            match self.peek().and_then(char::from_u32) {
+                Some('}') => {
+                    self.position += 1;
+                    set.insert_string(string);
+                    return Ok(set);
+                }
+                Some('|') => {
+                    self.position += 1;
+                    set.insert_string(std::mem::take(&mut string));
+                }
+                Some(_) => string.push(self.class_set_character()?),
+                None => return Err("unterminated class string disjunction".to_owned()),
+            }
+        }
+    }
+
+    /// Parses a `ClassSetCharacter`, returning its code point.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#prod-ClassSetCharacter
+    fn class_set_character(&mut self) -> Result<u32, String> {
+        let c = self.next_char()?;
+        if c != '\\' {
+            // ClassSetSyntaxCharacter
+            if matches!(c, '(' | ')' | '[' | ']' | '{' | '}' | '/' | '-' | '|') {
+                return Err(format!("invalid character '{c}' in character class"));
+            }
+
+            // ClassSetReservedDoublePunctuator
+            if "&!#$%*+,.:;<=>?@^`~".contains(c) && self.next_is(c) {
+                return Err(format!("invalid set operation '{c}{c}' in character class"));
+            }
+
+            return Ok(c as u32);
+        }
+
+        let escape = self.next_char()?;
+        let value = match escape {
+            'b' => 0x08,
+            // ClassSetReservedPunctuator
+            '&' | '-' | '!' | '#' | '%' | ',' | ':' | ';' | '<' | '=' | '>' | '@' | '`' | '~' => {
+                escape as u32
+            }
+            // ControlEscape
+            'f' => 0x0C,
+            'n' => 0x0A,
+            'r' => 0x0D,
+            't' => 0x09,
+            'v' => 0x0B,
+            'c' => match self.next().and_then(char::from_u32) {
+                Some(letter) if letter.is_ascii_alphabetic() => letter as u32 % 32,
+                _ => return Err("invalid control escape in character class".to_owned()),
+            },
+            '0' if !self.peek().map_or(false, |c| (0x30..=0x39).contains(&c)) => 0,
+            'x' => self.hex_digits(2)?,
+            'u' => self.unicode_escape()?,
+            // IdentityEscape[+UnicodeMode] :: SyntaxCharacter | /
+            '^' | '$' | '\\' | '.' | '*' | '+' | '?' | '(' | ')' | '[' | ']' | '{' | '}' | '|'
+            | '/' => escape as u32,
+            _ => return Err(format!("invalid escape '\\{escape}' in character class")),
+        };
+
+        Ok(value)
+    }
+
+    /// Parses exactly `count` hexadecimal digits.
+    fn hex_digits(&mut self, count: usize) -> Result<u32, String> {
+        let mut value = 0;
+        for _ in 0..count {
+            let digit = self
+                .next()
+                .and_then(char::from_u32)
+                .and_then(|c| c.to_digit(16))
+                .ok_or_else(|| "invalid hexadecimal escape in character class".to_owned())?;
+            value = value * 16 + digit;
+        }
+        Ok(value)
+    }
+
+    /// Parses a `RegExpUnicodeEscapeSequence[+UnicodeMode]`, right after its `\u`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#prod-RegExpUnicodeEscapeSequence
+    fn unicode_escape(&mut self) -> Result<u32, String> {
+        if self.next_is('{') {
+            self.position += 1;
+            let mut value: u32 = 0;
+            let mut digits = 0;
+            loop {
+                match self.next().and_then(char::from_u32) {
+                    Some('}') if digits > 0 => return Ok(value),
+                    Some(c) if c.is_ascii_hexdigit() => {
+                        digits += 1;
+                        value = value * 16 + c.to_digit(16).expect("must be a hex digit");
+                        if value > 0x10_FFFF {
+                            return Err("invalid unicode escape in character class".to_owned());
+                        }
+                    }
+                    _ => return Err("invalid unicode escape in character class".to_owned()),
+                }
+            }
+        }
+
+        let value = self.hex_digits(4)?;
+
+        // \u LeadSurrogate \u TrailSurrogate
+        if (0xD800..=0xDBFF).contains(&value)
+            && self.next_is('\\')
+            && self.peek_n(1) == Some('u' as u32)
+        {
+            let position = self.position;
+            self.position += 2;
+            if let Ok(trail @ 0xDC00..=0xDFFF) = self.hex_digits(4) {
+                return Ok(0x10000 + ((value - 0xD800) << 10) + (trail - 0xDC00));
+            }
+            self.position = position;
+        }
+
+        Ok(value)
+    }
+}
+
+/// Pushes the code points of `string` into `pattern`.
+fn push_str(pattern: &mut Vec<u32>, string: &str) {
+    pattern.extend(string.chars().map(u32::from));
+}
+
+/// Pushes the `u` mode escape of the code point `c` into `pattern`.
+fn push_code_point(pattern: &mut Vec<u32>, c: u32) {
+    push_str(pattern, &format!(r"\u{{{c:X}}}"));
+}
+
+/// Pushes the `u` mode class range from `start` to `end` into `pattern`.
+fn push_range(pattern: &mut Vec<u32>, (start, end): (u32, u32)) {
+    push_code_point(pattern, start);
+    if end != start {
+        push_str(pattern, "-");
+        push_code_point(pattern, end);
+    }
+}
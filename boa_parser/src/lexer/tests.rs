@@ -188,7 +188,7 @@ fn check_punctuators() {
     // https://tc39.es/ecma262/#sec-punctuators
     let s = "{ ( ) [ ] . ... ; , < > <= >= == != === !== \
              + - * % -- << >> >>> & | ^ ! ~ && || ? : \
-             = += -= *= &= **= ++ ** <<= >>= >>>= &= |= ^= => ?? ??= &&= ||= ?.";
+             = += -= *= &= **= ++ ** <<= >>= >>>= &= |= ^= => ?? ??= &&= ||= ?. @";
     let mut lexer = Lexer::new(s.as_bytes());
     let interner = &mut Interner::default();
 
@@ -247,6 +247,7 @@ fn check_punctuators() {
         TokenKind::Punctuator(Punctuator::AssignBoolAnd),
         TokenKind::Punctuator(Punctuator::AssignBoolOr),
         TokenKind::Punctuator(Punctuator::Optional),
+        TokenKind::Punctuator(Punctuator::At),
     ];
 
     expect_tokens(&mut lexer, &expected, interner);
@@ -697,6 +698,50 @@ fn regex_literal_flags_err() {
         .expect_err("Lexer did not handle regex literal with error");
 }
 
+#[test]
+fn regex_literal_unicode_sets() {
+    let mut lexer = Lexer::new(&br"/[\p{L}--[a-z]&&\q{abc|d}]/vg"[..]);
+    let interner = &mut Interner::default();
+
+    lexer
+        .next(interner)
+        .expect_err("Lexer did not handle mixed set operations");
+
+    let mut lexer = Lexer::new(&br"/[[\p{L}--[a-z]]&&\q{abc|d}]/vg"[..]);
+    let interner = &mut Interner::default();
+
+    let expected = [TokenKind::regular_expression_literal(
+        interner.get_or_intern_static(
+            "[[\\p{L}--[a-z]]&&\\q{abc|d}]",
+            utf16!("[[\\p{L}--[a-z]]&&\\q{abc|d}]"),
+        ),
+        interner.get_or_intern_static("gv", utf16!("gv")),
+    )];
+
+    expect_tokens(&mut lexer, &expected, interner);
+}
+
+#[test]
+fn regex_literal_unicode_sets_err() {
+    for source in [
+        &br"/a/uv"[..],
+        &br"/[(]/v"[..],
+        &br"/[a&&&b]/v"[..],
+        &br"/[a!!b]/v"[..],
+        &br"/[^\q{ab}]/v"[..],
+        &br"/\P{RGI_Emoji}/v"[..],
+        &br"/[^\p{RGI_Emoji}]/v"[..],
+        &br"/[z-a]/v"[..],
+    ] {
+        let mut lexer = Lexer::new(source);
+        let interner = &mut Interner::default();
+
+        lexer
+            .next(interner)
+            .expect_err("Lexer did not handle invalid unicode sets regex literal");
+    }
+}
+
 #[test]
 fn addition_no_spaces() {
     let mut lexer = Lexer::new(&b"1+1"[..]);
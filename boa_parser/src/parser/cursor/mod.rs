@@ -6,7 +6,7 @@ use crate::{
     parser::{OrAbrupt, ParseResult},
     Error,
 };
-use boa_ast::{Position, Punctuator};
+use boa_ast::{Position, Punctuator, Span};
 use boa_interner::Interner;
 use buffered_lexer::BufferedLexer;
 use std::io::Read;
@@ -37,6 +37,9 @@ pub(super) struct Cursor<R> {
 
     /// Tracks the number of tagged templates that are currently being parsed.
     tagged_templates_count: u32,
+
+    /// The span of the last consumed token.
+    last_token: Span,
 }
 
 impl<R> Cursor<R>
@@ -51,6 +54,7 @@ where
             json_parse: false,
             identifier: 0,
             tagged_templates_count: 0,
+            last_token: Position::new(1, 1).into(),
         }
     }
 
@@ -73,7 +77,9 @@ where
         start: Position,
         interner: &mut Interner,
     ) -> ParseResult<Token> {
-        self.buffered_lexer.lex_regex(start, interner)
+        let token = self.buffered_lexer.lex_regex(start, interner)?;
+        self.last_token = token.span();
+        Ok(token)
     }
 
     pub(super) fn lex_template(
@@ -81,12 +87,33 @@ where
         start: Position,
         interner: &mut Interner,
     ) -> ParseResult<Token> {
-        self.buffered_lexer.lex_template(start, interner)
+        let token = self.buffered_lexer.lex_template(start, interner)?;
+        self.last_token = token.span();
+        Ok(token)
     }
 
     /// Advances the cursor and returns the next token.
     pub(super) fn next(&mut self, interner: &mut Interner) -> ParseResult<Option<Token>> {
-        self.buffered_lexer.next(true, interner)
+        let token = self.buffered_lexer.next(true, interner)?;
+        if let Some(token) = &token {
+            self.last_token = token.span();
+        }
+        Ok(token)
+    }
+
+    /// Gets the end position of the last consumed token.
+    ///
+    /// This is the start of the source code if no tokens have been consumed yet.
+    pub(super) const fn last_token_end(&self) -> Position {
+        self.last_token.end()
+    }
+
+    /// Gets the span of the last consumed token.
+    ///
+    /// This is an empty span at the start of the source code if no tokens have been consumed
+    /// yet.
+    pub(super) const fn last_token_span(&self) -> Span {
+        self.last_token
     }
 
     /// Advances the cursor without returning the next token.
@@ -214,7 +241,13 @@ where
     ) -> ParseResult<()> {
         match self.peek_semicolon(interner)? {
             SemicolonResult::Found(Some(tk)) => match *tk.kind() {
-                TokenKind::Punctuator(Punctuator::Semicolon) | TokenKind::LineTerminator => {
+                TokenKind::Punctuator(Punctuator::Semicolon) => {
+                    let span = tk.span();
+                    let _next = self.buffered_lexer.next(false, interner)?;
+                    self.last_token = span;
+                    Ok(())
+                }
+                TokenKind::LineTerminator => {
                     let _next = self.buffered_lexer.next(false, interner)?;
                     Ok(())
                 }
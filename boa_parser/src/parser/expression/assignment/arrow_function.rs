@@ -21,12 +21,12 @@ use crate::{
 use ast::operations::{bound_names, lexically_declared_names};
 use boa_ast::{
     self as ast,
-    declaration::Variable,
+    declaration::{Binding, Variable},
     expression::Identifier,
     function::{FormalParameter, FormalParameterList},
     operations::{contains, ContainsSymbol},
     statement::Return,
-    Expression, Punctuator, StatementList,
+    Expression, Punctuator, Span, StatementList,
 };
 use boa_interner::Interner;
 use boa_profiler::Profiler;
@@ -80,6 +80,7 @@ where
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
         let _timer = Profiler::global().start_event("ArrowFunction", "Parsing");
         let next_token = cursor.peek(0, interner).or_abrupt()?;
+        let start = next_token.span().start();
 
         let (params, params_start_position) = if next_token.kind()
             == &TokenKind::Punctuator(Punctuator::OpenParen)
@@ -99,9 +100,10 @@ where
             let param = BindingIdentifier::new(self.allow_yield, self.allow_await)
                 .parse(cursor, interner)
                 .set_context("arrow function")?;
+            let binding = Binding::Identifier(param, cursor.last_token_span().into());
             (
                 FormalParameterList::try_from(FormalParameter::new(
-                    Variable::from_identifier(param, None),
+                    Variable::from_binding(binding, None),
                     false,
                 ))
                 .expect("a single binding identifier without init is always a valid param list"),
@@ -164,7 +166,8 @@ where
             interner,
         )?;
 
-        Ok(ast::function::ArrowFunction::new(self.name, params, body))
+        Ok(ast::function::ArrowFunction::new(self.name, params, body)
+            .with_span(Span::new(start, cursor.last_token_end())))
     }
 }
 
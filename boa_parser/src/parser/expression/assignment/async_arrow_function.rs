@@ -23,11 +23,11 @@ use ast::{
 };
 use boa_ast::{
     self as ast,
-    declaration::Variable,
+    declaration::{Binding, Variable},
     expression::Identifier,
     function::{FormalParameter, FormalParameterList},
     statement::Return,
-    Punctuator, StatementList,
+    Punctuator, Span, StatementList,
 };
 use boa_interner::Interner;
 use boa_profiler::Profiler;
@@ -73,7 +73,10 @@ where
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
         let _timer = Profiler::global().start_event("AsyncArrowFunction", "Parsing");
 
-        cursor.expect((Keyword::Async, false), "async arrow function", interner)?;
+        let start = cursor
+            .expect((Keyword::Async, false), "async arrow function", interner)?
+            .span()
+            .start();
         cursor.peek_expect_no_lineterminator(0, "async arrow function", interner)?;
 
         let next_token = cursor.peek(0, interner).or_abrupt()?;
@@ -93,9 +96,10 @@ where
             let param = BindingIdentifier::new(self.allow_yield, true)
                 .parse(cursor, interner)
                 .set_context("async arrow function")?;
+            let binding = Binding::Identifier(param, cursor.last_token_span().into());
             (
                 FormalParameterList::try_from(FormalParameter::new(
-                    Variable::from_identifier(param, None),
+                    Variable::from_binding(binding, None),
                     false,
                 ))
                 .expect("a single binding identifier without init is always a valid param list"),
@@ -150,9 +154,10 @@ where
             interner,
         )?;
 
-        Ok(ast::function::AsyncArrowFunction::new(
-            self.name, params, body,
-        ))
+        Ok(
+            ast::function::AsyncArrowFunction::new(self.name, params, body)
+                .with_span(Span::new(start, cursor.last_token_end())),
+        )
     }
 }
 
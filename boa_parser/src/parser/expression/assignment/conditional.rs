@@ -11,12 +11,12 @@ use crate::{
     lexer::TokenKind,
     parser::{
         expression::{AssignmentExpression, ShortCircuitExpression},
-        AllowAwait, AllowIn, AllowYield, Cursor, ParseResult, TokenParser,
+        AllowAwait, AllowIn, AllowYield, Cursor, OrAbrupt, ParseResult, TokenParser,
     },
 };
 use boa_ast::{
     expression::{operator::Conditional, Identifier},
-    Expression, Punctuator,
+    Expression, Punctuator, Span,
 };
 use boa_interner::Interner;
 use boa_profiler::Profiler;
@@ -69,6 +69,8 @@ where
 
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
         let _timer = Profiler::global().start_event("ConditionalExpression", "Parsing");
+
+        let start = cursor.peek(0, interner).or_abrupt()?.span().start();
         let lhs = ShortCircuitExpression::new(
             self.name,
             self.allow_in,
@@ -92,7 +94,9 @@ where
                     self.allow_await,
                 )
                 .parse(cursor, interner)?;
-                return Ok(Conditional::new(lhs, then_clause, else_clause).into());
+                return Ok(Conditional::new(lhs, then_clause, else_clause)
+                    .with_span(Span::new(start, cursor.last_token_end()))
+                    .into());
             }
         }
 
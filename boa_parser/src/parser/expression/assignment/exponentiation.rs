@@ -19,7 +19,7 @@ use boa_ast::{
         operator::{binary::ArithmeticOp, Binary},
         Identifier,
     },
-    Expression, Keyword, Punctuator,
+    Expression, Keyword, Punctuator, Span,
 };
 use boa_interner::Interner;
 use boa_profiler::Profiler;
@@ -70,6 +70,7 @@ where
         let _timer = Profiler::global().start_event("ExponentiationExpression", "Parsing");
 
         let next = cursor.peek(0, interner).or_abrupt()?;
+        let start = next.span().start();
         match next.kind() {
             TokenKind::Keyword((Keyword::Delete | Keyword::Void | Keyword::TypeOf, _))
             | TokenKind::Punctuator(
@@ -95,6 +96,7 @@ where
                     lhs,
                     self.parse(cursor, interner)?,
                 )
+                .with_span(Span::new(start, cursor.last_token_end()))
                 .into());
             }
         }
@@ -33,7 +33,7 @@ use boa_ast::{
         Identifier,
     },
     operations::{bound_names, contains, lexically_declared_names, ContainsSymbol},
-    Expression, Keyword, Punctuator,
+    Expression, Keyword, Punctuator, Span,
 };
 use boa_interner::Interner;
 use boa_profiler::Profiler;
@@ -230,7 +230,11 @@ where
                 interner,
             )?;
 
-            return Ok(boa_ast::function::ArrowFunction::new(self.name, parameters, body).into());
+            return Ok(
+                boa_ast::function::ArrowFunction::new(self.name, parameters, body)
+                    .with_span(Span::new(position, cursor.last_token_end()))
+                    .into(),
+            );
         }
 
         // Review if we are trying to assign to an invalid left hand side expression.
@@ -241,11 +245,13 @@ where
                     cursor.set_goal(InputElement::RegExp);
 
                     if let Some(target) = AssignTarget::from_expression(&lhs, cursor.strict()) {
-                        if let AssignTarget::Identifier(ident) = target {
+                        if let AssignTarget::Identifier(ident, _) = target {
                             self.name = Some(ident);
                         }
                         let expr = self.parse(cursor, interner)?;
-                        lhs = Assign::new(AssignOp::Assign, target, expr).into();
+                        lhs = Assign::new(AssignOp::Assign, target, expr)
+                            .with_span(Span::new(position, cursor.last_token_end()))
+                            .into();
                     } else {
                         return Err(Error::lex(LexError::Syntax(
                             "Invalid left-hand side in assignment".into(),
@@ -263,13 +269,15 @@ where
                             || assignop == AssignOp::BoolOr
                             || assignop == AssignOp::Coalesce
                         {
-                            if let AssignTarget::Identifier(ident) = target {
+                            if let AssignTarget::Identifier(ident, _) = target {
                                 self.name = Some(ident);
                             }
                         }
 
                         let rhs = self.parse(cursor, interner)?;
-                        lhs = Assign::new(assignop, target, rhs).into();
+                        lhs = Assign::new(assignop, target, rhs)
+                            .with_span(Span::new(position, cursor.last_token_end()))
+                            .into();
                     } else {
                         return Err(Error::lex(LexError::Syntax(
                             "Invalid left-hand side in assignment".into(),
@@ -12,7 +12,7 @@ use crate::{
     lexer::TokenKind,
     parser::{AllowAwait, AllowIn, Cursor, OrAbrupt, ParseResult, TokenParser},
 };
-use boa_ast::{expression::Yield, Expression, Keyword, Punctuator};
+use boa_ast::{expression::Yield, Expression, Keyword, Punctuator, Span};
 use boa_interner::Interner;
 use boa_profiler::Profiler;
 use std::io::Read;
@@ -54,17 +54,22 @@ where
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
         let _timer = Profiler::global().start_event("YieldExpression", "Parsing");
 
-        cursor.expect(
-            TokenKind::Keyword((Keyword::Yield, false)),
-            "yield expression",
-            interner,
-        )?;
+        let start = cursor
+            .expect(
+                TokenKind::Keyword((Keyword::Yield, false)),
+                "yield expression",
+                interner,
+            )?
+            .span()
+            .start();
 
         if matches!(
             cursor.peek_is_line_terminator(0, interner)?,
             Some(true) | None
         ) {
-            return Ok(Yield::new(None, false).into());
+            return Ok(Yield::new(None, false)
+                .with_span(Span::new(start, cursor.last_token_end()))
+                .into());
         }
 
         let token = cursor.peek(0, interner).or_abrupt()?;
@@ -73,7 +78,9 @@ where
                 cursor.advance(interner);
                 let expr = AssignmentExpression::new(None, self.allow_in, true, self.allow_await)
                     .parse(cursor, interner)?;
-                Ok(Yield::new(Some(expr), true).into())
+                Ok(Yield::new(Some(expr), true)
+                    .with_span(Span::new(start, cursor.last_token_end()))
+                    .into())
             }
             TokenKind::IdentifierName(_)
             | TokenKind::Punctuator(
@@ -111,9 +118,13 @@ where
             | TokenKind::TemplateMiddle(_) => {
                 let expr = AssignmentExpression::new(None, self.allow_in, true, self.allow_await)
                     .parse(cursor, interner)?;
-                Ok(Yield::new(Some(expr), false).into())
+                Ok(Yield::new(Some(expr), false)
+                    .with_span(Span::new(start, cursor.last_token_end()))
+                    .into())
             }
-            _ => Ok(Yield::new(None, false).into()),
+            _ => Ok(Yield::new(None, false)
+                .with_span(Span::new(start, cursor.last_token_end()))
+                .into()),
         }
     }
 }
@@ -12,7 +12,7 @@ use crate::{
     lexer::TokenKind,
     parser::{AllowYield, Cursor, ParseResult, TokenParser},
 };
-use boa_ast::{expression::Await, Keyword};
+use boa_ast::{expression::Await, Keyword, Span};
 use boa_interner::Interner;
 use std::io::Read;
 
@@ -48,12 +48,15 @@ where
     type Output = Await;
 
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
-        cursor.expect(
-            TokenKind::Keyword((Keyword::Await, false)),
-            "Await expression parsing",
-            interner,
-        )?;
+        let start = cursor
+            .expect(
+                TokenKind::Keyword((Keyword::Await, false)),
+                "Await expression parsing",
+                interner,
+            )?
+            .span()
+            .start();
         let expr = UnaryExpression::new(None, self.allow_yield, true).parse(cursor, interner)?;
-        Ok(expr.into())
+        Ok(Await::from(expr).with_span(Span::new(start, cursor.last_token_end())))
     }
 }
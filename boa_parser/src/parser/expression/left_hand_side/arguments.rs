@@ -15,7 +15,7 @@ use crate::{
     },
     Error,
 };
-use boa_ast::{expression::Spread, Expression, Punctuator};
+use boa_ast::{expression::Spread, Expression, Punctuator, Span};
 use boa_interner::Interner;
 use boa_profiler::Profiler;
 use std::io::Read;
@@ -29,14 +29,14 @@ use std::io::Read;
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Glossary/Argument
 /// [spec]: https://tc39.es/ecma262/#prod-Arguments
 #[derive(Debug, Clone, Copy)]
-pub(in crate::parser::expression) struct Arguments {
+pub(in crate::parser) struct Arguments {
     allow_yield: AllowYield,
     allow_await: AllowAwait,
 }
 
 impl Arguments {
     /// Creates a new `Arguments` parser.
-    pub(in crate::parser::expression) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    pub(in crate::parser) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
     where
         Y: Into<AllowYield>,
         A: Into<AllowAwait>,
@@ -96,12 +96,14 @@ where
                 }
             }
 
-            if cursor.next_if(Punctuator::Spread, interner)?.is_some() {
+            if let Some(spread) = cursor.next_if(Punctuator::Spread, interner)? {
+                let start = spread.span().start();
                 args.push(
                     Spread::new(
                         AssignmentExpression::new(None, true, self.allow_yield, self.allow_await)
                             .parse(cursor, interner)?,
                     )
+                    .with_span(Span::new(start, cursor.last_token_end()))
                     .into(),
                 );
             } else {
@@ -23,7 +23,7 @@ use boa_ast::{
         access::{PrivatePropertyAccess, SimplePropertyAccess},
         Call,
     },
-    Punctuator,
+    Position, Punctuator, Span,
 };
 use boa_interner::{Interner, Sym};
 use boa_profiler::Profiler;
@@ -39,6 +39,7 @@ use std::io::Read;
 pub(super) struct CallExpression {
     allow_yield: AllowYield,
     allow_await: AllowAwait,
+    start: Position,
     first_member_expr: ast::Expression,
 }
 
@@ -47,6 +48,7 @@ impl CallExpression {
     pub(super) fn new<Y, A>(
         allow_yield: Y,
         allow_await: A,
+        start: Position,
         first_member_expr: ast::Expression,
     ) -> Self
     where
@@ -56,6 +58,7 @@ impl CallExpression {
         Self {
             allow_yield: allow_yield.into(),
             allow_await: allow_await.into(),
+            start,
             first_member_expr,
         }
     }
@@ -75,7 +78,9 @@ where
         let lhs = if token.kind() == &TokenKind::Punctuator(Punctuator::OpenParen) {
             let args =
                 Arguments::new(self.allow_yield, self.allow_await).parse(cursor, interner)?;
-            Call::new(self.first_member_expr, args).into()
+            Call::new(self.first_member_expr, args)
+                .with_span(Span::new(self.start, cursor.last_token_end()))
+                .into()
         } else {
             let next_token = cursor.next(interner)?.expect("token vanished");
             return Err(Error::expected(
@@ -86,7 +91,8 @@ where
             ));
         };
 
-        CallExpressionTail::new(self.allow_yield, self.allow_await, lhs).parse(cursor, interner)
+        CallExpressionTail::new(self.allow_yield, self.allow_await, self.start, lhs)
+            .parse(cursor, interner)
     }
 }
 
@@ -95,12 +101,18 @@ where
 pub(super) struct CallExpressionTail {
     allow_yield: AllowYield,
     allow_await: AllowAwait,
+    start: Position,
     call: ast::Expression,
 }
 
 impl CallExpressionTail {
     /// Creates a new `CallExpressionTail` parser.
-    pub(super) fn new<Y, A>(allow_yield: Y, allow_await: A, call: ast::Expression) -> Self
+    pub(super) fn new<Y, A>(
+        allow_yield: Y,
+        allow_await: A,
+        start: Position,
+        call: ast::Expression,
+    ) -> Self
     where
         Y: Into<AllowYield>,
         A: Into<AllowAwait>,
@@ -108,6 +120,7 @@ impl CallExpressionTail {
         Self {
             allow_yield: allow_yield.into(),
             allow_await: allow_await.into(),
+            start,
             call,
         }
     }
@@ -128,30 +141,43 @@ where
                 TokenKind::Punctuator(Punctuator::OpenParen) => {
                     let args = Arguments::new(self.allow_yield, self.allow_await)
                         .parse(cursor, interner)?;
-                    lhs = ast::Expression::from(Call::new(lhs, args));
+                    lhs = ast::Expression::from(
+                        Call::new(lhs, args)
+                            .with_span(Span::new(self.start, cursor.last_token_end())),
+                    );
                 }
                 TokenKind::Punctuator(Punctuator::Dot) => {
                     cursor.advance(interner);
 
-                    let access = match cursor.next(interner).or_abrupt()?.kind() {
+                    let next = cursor.next(interner).or_abrupt()?;
+                    let span = Span::new(self.start, cursor.last_token_end());
+
+                    let access = match next.kind() {
                         TokenKind::IdentifierName((name, _)) => {
-                            SimplePropertyAccess::new(lhs, *name).into()
-                        }
-                        TokenKind::Keyword((kw, _)) => {
-                            SimplePropertyAccess::new(lhs, kw.to_sym()).into()
+                            SimplePropertyAccess::new(lhs, *name).with_span(span).into()
                         }
+                        TokenKind::Keyword((kw, _)) => SimplePropertyAccess::new(lhs, kw.to_sym())
+                            .with_span(span)
+                            .into(),
                         TokenKind::BooleanLiteral((true, _)) => {
-                            SimplePropertyAccess::new(lhs, Sym::TRUE).into()
+                            SimplePropertyAccess::new(lhs, Sym::TRUE)
+                                .with_span(span)
+                                .into()
                         }
                         TokenKind::BooleanLiteral((false, _)) => {
-                            SimplePropertyAccess::new(lhs, Sym::FALSE).into()
-                        }
-                        TokenKind::NullLiteral(_) => {
-                            SimplePropertyAccess::new(lhs, Sym::NULL).into()
-                        }
-                        TokenKind::PrivateIdentifier(name) => {
-                            PrivatePropertyAccess::new(lhs, PrivateName::new(*name)).into()
+                            SimplePropertyAccess::new(lhs, Sym::FALSE)
+                                .with_span(span)
+                                .into()
                         }
+                        TokenKind::NullLiteral(_) => SimplePropertyAccess::new(lhs, Sym::NULL)
+                            .with_span(span)
+                            .into(),
+                        TokenKind::PrivateIdentifier(name) => PrivatePropertyAccess::new(
+                            lhs,
+                            PrivateName::new(*name).with_span(next.span()),
+                        )
+                        .with_span(span)
+                        .into(),
                         _ => {
                             return Err(Error::expected(
                                 ["identifier".to_owned()],
@@ -169,8 +195,11 @@ where
                     let idx = Expression::new(None, true, self.allow_yield, self.allow_await)
                         .parse(cursor, interner)?;
                     cursor.expect(Punctuator::CloseBracket, "call expression", interner)?;
-                    lhs =
-                        ast::Expression::PropertyAccess(SimplePropertyAccess::new(lhs, idx).into());
+                    lhs = ast::Expression::PropertyAccess(
+                        SimplePropertyAccess::new(lhs, idx)
+                            .with_span(Span::new(self.start, cursor.last_token_end()))
+                            .into(),
+                    );
                 }
                 TokenKind::TemplateNoSubstitution { .. } | TokenKind::TemplateMiddle { .. } => {
                     lhs = TaggedTemplateLiteral::new(
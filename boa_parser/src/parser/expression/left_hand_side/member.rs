@@ -25,7 +25,7 @@ use boa_ast::{
         },
         Call, Identifier, New,
     },
-    Keyword, Punctuator,
+    Keyword, Punctuator, Span,
 };
 use boa_interner::{Interner, Sym};
 use boa_profiler::Profiler;
@@ -117,7 +117,7 @@ where
                     ));
                 }
 
-                ast::Expression::ImportMeta
+                ast::Expression::ImportMeta(Span::new(position, cursor.last_token_end()).into())
             }
             TokenKind::Keyword((Keyword::New, false)) => {
                 cursor.advance(interner);
@@ -132,7 +132,9 @@ where
                             ));
                         }
                         TokenKind::IdentifierName((Sym::TARGET, ContainsEscapeSequence(false))) => {
-                            ast::Expression::NewTarget
+                            ast::Expression::NewTarget(
+                                Span::new(position, cursor.last_token_end()).into(),
+                            )
                         }
                         _ => {
                             return Err(Error::general(
@@ -152,7 +154,8 @@ where
                         }
                         _ => Box::new([]),
                     };
-                    let call_node = Call::new(lhs_inner, args);
+                    let call_node = Call::new(lhs_inner, args)
+                        .with_span(Span::new(position, cursor.last_token_end()));
 
                     ast::Expression::from(New::from(call_node))
                 };
@@ -164,20 +167,24 @@ where
                 match token.kind() {
                     TokenKind::Punctuator(Punctuator::Dot) => {
                         let token = cursor.next(interner).or_abrupt()?;
+                        let span = Span::new(position, cursor.last_token_end());
                         let field = match token.kind() {
                             TokenKind::IdentifierName((name, _)) => {
                                 SuperPropertyAccess::new(PropertyAccessField::from(*name))
+                                    .with_span(span)
                             }
                             TokenKind::Keyword((kw, _)) => {
-                                SuperPropertyAccess::new(kw.to_sym().into())
+                                SuperPropertyAccess::new(kw.to_sym().into()).with_span(span)
                             }
                             TokenKind::BooleanLiteral((true, _)) => {
-                                SuperPropertyAccess::new(Sym::TRUE.into())
+                                SuperPropertyAccess::new(Sym::TRUE.into()).with_span(span)
                             }
                             TokenKind::BooleanLiteral((false, _)) => {
-                                SuperPropertyAccess::new(Sym::FALSE.into())
+                                SuperPropertyAccess::new(Sym::FALSE.into()).with_span(span)
+                            }
+                            TokenKind::NullLiteral(_) => {
+                                SuperPropertyAccess::new(Sym::NULL.into()).with_span(span)
                             }
-                            TokenKind::NullLiteral(_) => SuperPropertyAccess::new(Sym::NULL.into()),
                             TokenKind::PrivateIdentifier(_) => {
                                 return Err(Error::general(
                                     "unexpected private identifier",
@@ -199,7 +206,9 @@ where
                             .parse(cursor, interner)?;
                         cursor.expect(Punctuator::CloseBracket, "super property", interner)?;
                         ast::Expression::PropertyAccess(
-                            SuperPropertyAccess::new(expr.into()).into(),
+                            SuperPropertyAccess::new(expr.into())
+                                .with_span(Span::new(position, cursor.last_token_end()))
+                                .into(),
                         )
                     }
                     _ => {
@@ -225,26 +234,34 @@ where
                         .expect("dot punctuator token disappeared"); // We move the parser forward.
 
                     let token = cursor.next(interner).or_abrupt()?;
+                    let span = Span::new(position, cursor.last_token_end());
 
                     let access = match token.kind() {
                         TokenKind::IdentifierName((name, _)) => {
-                            SimplePropertyAccess::new(lhs, *name).into()
-                        }
-                        TokenKind::Keyword((kw, _)) => {
-                            SimplePropertyAccess::new(lhs, kw.to_sym()).into()
+                            SimplePropertyAccess::new(lhs, *name).with_span(span).into()
                         }
+                        TokenKind::Keyword((kw, _)) => SimplePropertyAccess::new(lhs, kw.to_sym())
+                            .with_span(span)
+                            .into(),
                         TokenKind::BooleanLiteral((true, _)) => {
-                            SimplePropertyAccess::new(lhs, Sym::TRUE).into()
+                            SimplePropertyAccess::new(lhs, Sym::TRUE)
+                                .with_span(span)
+                                .into()
                         }
                         TokenKind::BooleanLiteral((false, _)) => {
-                            SimplePropertyAccess::new(lhs, Sym::FALSE).into()
-                        }
-                        TokenKind::NullLiteral(_) => {
-                            SimplePropertyAccess::new(lhs, Sym::NULL).into()
-                        }
-                        TokenKind::PrivateIdentifier(name) => {
-                            PrivatePropertyAccess::new(lhs, PrivateName::new(*name)).into()
+                            SimplePropertyAccess::new(lhs, Sym::FALSE)
+                                .with_span(span)
+                                .into()
                         }
+                        TokenKind::NullLiteral(_) => SimplePropertyAccess::new(lhs, Sym::NULL)
+                            .with_span(span)
+                            .into(),
+                        TokenKind::PrivateIdentifier(name) => PrivatePropertyAccess::new(
+                            lhs,
+                            PrivateName::new(*name).with_span(token.span()),
+                        )
+                        .with_span(span)
+                        .into(),
                         _ => {
                             return Err(Error::expected(
                                 ["identifier".to_owned()],
@@ -264,8 +281,11 @@ where
                     let idx = Expression::new(None, true, self.allow_yield, self.allow_await)
                         .parse(cursor, interner)?;
                     cursor.expect(Punctuator::CloseBracket, "member expression", interner)?;
-                    lhs =
-                        ast::Expression::PropertyAccess(SimplePropertyAccess::new(lhs, idx).into());
+                    lhs = ast::Expression::PropertyAccess(
+                        SimplePropertyAccess::new(lhs, idx)
+                            .with_span(Span::new(position, cursor.last_token_end()))
+                            .into(),
+                    );
                 }
                 TokenKind::TemplateNoSubstitution { .. } | TokenKind::TemplateMiddle { .. } => {
                     lhs = TaggedTemplateLiteral::new(
@@ -10,7 +10,7 @@
 #[cfg(test)]
 mod tests;
 
-mod arguments;
+pub(super) mod arguments;
 mod call;
 mod member;
 mod optional;
@@ -28,13 +28,13 @@ use crate::{
             },
             AssignmentExpression,
         },
-        AllowAwait, AllowYield, Cursor, ParseResult, TokenParser,
+        AllowAwait, AllowYield, Cursor, OrAbrupt, ParseResult, TokenParser,
     },
     Error,
 };
 use boa_ast::{
     expression::{Identifier, ImportCall, SuperCall},
-    Expression, Keyword, Punctuator,
+    Expression, Keyword, Punctuator, Span,
 };
 use boa_interner::Interner;
 use boa_profiler::Profiler;
@@ -119,11 +119,15 @@ where
 
         cursor.set_goal(InputElement::TemplateTail);
 
+        let start = cursor.peek(0, interner).or_abrupt()?.span().start();
+
         let mut lhs = if is_keyword_call(Keyword::Super, cursor, interner)? {
             cursor.advance(interner);
             let args =
                 Arguments::new(self.allow_yield, self.allow_await).parse(cursor, interner)?;
-            SuperCall::new(args).into()
+            SuperCall::new(args)
+                .with_span(Span::new(start, cursor.last_token_end()))
+                .into()
         } else if is_keyword_call(Keyword::Import, cursor, interner)? {
             // `import`
             cursor.advance(interner);
@@ -132,6 +136,18 @@ where
 
             let arg = AssignmentExpression::new(None, true, self.allow_yield, self.allow_await)
                 .parse(cursor, interner)?;
+            let mut call = ImportCall::new(arg);
+
+            if cursor.next_if(Punctuator::Comma, interner)?.is_some()
+                && cursor.peek(0, interner).or_abrupt()?.kind()
+                    != &TokenKind::Punctuator(Punctuator::CloseParen)
+            {
+                let options =
+                    AssignmentExpression::new(None, true, self.allow_yield, self.allow_await)
+                        .parse(cursor, interner)?;
+                call = call.with_options(options);
+                cursor.next_if(Punctuator::Comma, interner)?;
+            }
 
             cursor.expect(
                 TokenKind::Punctuator(Punctuator::CloseParen),
@@ -142,7 +158,9 @@ where
             CallExpressionTail::new(
                 self.allow_yield,
                 self.allow_await,
-                ImportCall::new(arg).into(),
+                start,
+                call.with_span(Span::new(start, cursor.last_token_end()))
+                    .into(),
             )
             .parse(cursor, interner)?
         } else {
@@ -150,7 +168,7 @@ where
                 .parse(cursor, interner)?;
             if let Some(tok) = cursor.peek(0, interner)? {
                 if tok.kind() == &TokenKind::Punctuator(Punctuator::OpenParen) {
-                    member = CallExpression::new(self.allow_yield, self.allow_await, member)
+                    member = CallExpression::new(self.allow_yield, self.allow_await, start, member)
                         .parse(cursor, interner)?;
                 }
             }
@@ -159,7 +177,7 @@ where
 
         if let Some(tok) = cursor.peek(0, interner)? {
             if tok.kind() == &TokenKind::Punctuator(Punctuator::Optional) {
-                lhs = OptionalExpression::new(self.allow_yield, self.allow_await, lhs)
+                lhs = OptionalExpression::new(self.allow_yield, self.allow_await, start, lhs)
                     .parse(cursor, interner)?
                     .into();
             }
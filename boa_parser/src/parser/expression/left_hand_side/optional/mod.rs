@@ -13,7 +13,7 @@ use ast::function::PrivateName;
 use boa_ast::{
     self as ast,
     expression::{access::PropertyAccessField, Optional, OptionalOperation, OptionalOperationKind},
-    Punctuator,
+    Position, Punctuator, Span,
 };
 use boa_interner::{Interner, Sym};
 use boa_profiler::Profiler;
@@ -31,6 +31,7 @@ use std::io::Read;
 pub(in crate::parser) struct OptionalExpression {
     allow_yield: AllowYield,
     allow_await: AllowAwait,
+    start: Position,
     target: ast::Expression,
 }
 
@@ -39,6 +40,7 @@ impl OptionalExpression {
     pub(in crate::parser) fn new<Y, A>(
         allow_yield: Y,
         allow_await: A,
+        start: Position,
         target: ast::Expression,
     ) -> Self
     where
@@ -48,6 +50,7 @@ impl OptionalExpression {
         Self {
             allow_yield: allow_yield.into(),
             allow_await: allow_await.into(),
+            start,
             target,
         }
     }
@@ -88,7 +91,7 @@ where
                 },
                 TokenKind::PrivateIdentifier(name) => {
                     OptionalOperationKind::PrivatePropertyAccess {
-                        field: PrivateName::new(*name),
+                        field: PrivateName::new(*name).with_span(token.span()),
                     }
                 }
                 _ => {
@@ -165,6 +168,7 @@ where
             items.push(OptionalOperation::new(item, shorted));
         }
 
-        Ok(Optional::new(self.target, items.into()))
+        Ok(Optional::new(self.target, items.into())
+            .with_span(Span::new(self.start, cursor.last_token_end())))
     }
 }
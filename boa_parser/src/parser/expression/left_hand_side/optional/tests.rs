@@ -18,7 +18,7 @@ fn simple() {
         r#"5?.name"#,
         vec![Statement::Expression(
             Optional::new(
-                Literal::Int(5).into(),
+                Literal::from(5).into(),
                 vec![OptionalOperation::new(
                     OptionalOperationKind::SimplePropertyAccess {
                         field: PropertyAccessField::Const(
@@ -56,14 +56,14 @@ fn complex_chain() {
                     ),
                     OptionalOperation::new(
                         OptionalOperationKind::Call {
-                            args: vec![Expression::Literal(Literal::Bool(true))].into(),
+                            args: vec![Expression::Literal(Literal::from(true))].into(),
                         },
                         false,
                     ),
                     OptionalOperation::new(
                         OptionalOperationKind::SimplePropertyAccess {
                             field: PropertyAccessField::Expr(Box::new(
-                                Literal::String(interner.get_or_intern_static("c", utf16!("c")))
+                                Literal::from(interner.get_or_intern_static("c", utf16!("c")))
                                     .into(),
                             )),
                         },
@@ -6,7 +6,7 @@ use crate::{
     },
     Error,
 };
-use boa_ast::{self as ast, expression::TaggedTemplate, Position, Punctuator};
+use boa_ast::{self as ast, expression::TaggedTemplate, Position, Punctuator, Span, Spanned};
 use boa_interner::Interner;
 use boa_profiler::Profiler;
 use std::io::Read;
@@ -55,6 +55,7 @@ where
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
         let _timer = Profiler::global().start_event("TaggedTemplateLiteral", "Parsing");
 
+        let start = self.tag.span().map_or(self.start, Span::start);
         let mut raws = Vec::new();
         let mut cookeds = Vec::new();
         let mut exprs = Vec::new();
@@ -85,7 +86,8 @@ where
                         cookeds.into_boxed_slice(),
                         exprs.into_boxed_slice(),
                         cursor.tagged_template_identifier(),
-                    ));
+                    )
+                    .with_span(Span::new(start, cursor.last_token_end())));
                 }
                 _ => {
                     return Err(Error::general(
@@ -1,7 +1,7 @@
-use crate::parser::tests::check_script_parser;
+use crate::{parser::tests::check_script_parser, Parser, Source};
 use boa_ast::{
     expression::{access::SimplePropertyAccess, Call, Identifier},
-    Expression, Statement,
+    Expression, Position, Span, Spanned, Statement, StatementListItem,
 };
 use boa_interner::Interner;
 use boa_macros::utf16;
@@ -63,3 +63,31 @@ fn check_member_properties() {
     check_member_property_identifier!("null");
     check_member_property_identifier!("let");
 }
+
+#[test]
+fn check_call_spans() {
+    let interner = &mut Interner::default();
+    let script = Parser::new(Source::from_bytes("a.b(c);\n  f()(1, 2);"))
+        .parse_script(interner)
+        .expect("failed to parse");
+
+    let spans = script
+        .statements()
+        .statements()
+        .iter()
+        .map(|item| match item {
+            StatementListItem::Statement(Statement::Expression(Expression::Call(call))) => {
+                call.span().expect("call must have a span")
+            }
+            _ => unreachable!("expected a call expression"),
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        spans,
+        [
+            Span::new(Position::new(1, 1), Position::new(1, 7)),
+            Span::new(Position::new(2, 3), Position::new(2, 12)),
+        ]
+    );
+}
@@ -37,7 +37,7 @@ use boa_ast::{
         Identifier,
     },
     function::PrivateName,
-    Keyword, Position, Punctuator,
+    Keyword, Position, Punctuator, Span,
 };
 use boa_interner::{Interner, Sym};
 use boa_profiler::Profiler;
@@ -45,8 +45,8 @@ use std::io::Read;
 
 pub(super) use self::{assignment::AssignmentExpression, primary::Initializer};
 pub(in crate::parser) use {
-    identifiers::{BindingIdentifier, LabelIdentifier},
-    left_hand_side::LeftHandSideExpression,
+    identifiers::{BindingIdentifier, IdentifierReference, LabelIdentifier},
+    left_hand_side::{arguments::Arguments, LeftHandSideExpression},
     primary::object_initializer::{
         AsyncGeneratorMethod, AsyncMethod, GeneratorMethod, PropertyName,
     },
@@ -84,6 +84,7 @@ macro_rules! expression {
                     cursor.set_goal($goal.unwrap());
                 }
 
+                let start = cursor.peek(0, interner).or_abrupt()?.span().start();
                 let mut lhs = $lower::new($( self.$low_param ),*).parse(cursor, interner)?;
                 self.name = None;
                 while let Some(tok) = cursor.peek(0, interner)? {
@@ -94,7 +95,7 @@ macro_rules! expression {
                                 op.as_binary_op().expect("Could not get binary operation."),
                                 lhs,
                                 $lower::new($( self.$low_param ),*).parse(cursor, interner)?
-                            ).into();
+                            ).with_span(Span::new(start, cursor.last_token_end())).into();
                         }
                         _ => break
                     }
@@ -153,6 +154,8 @@ where
     ) -> ParseResult<Self::Output> {
         let _timer = Profiler::global().start_event("Expression", "Parsing");
 
+        cursor.set_goal(InputElement::RegExp);
+        let start = cursor.peek(0, interner).or_abrupt()?.span().start();
         let mut lhs =
             AssignmentExpression::new(self.name, self.allow_in, self.allow_yield, self.allow_await)
                 .parse(cursor, interner)?;
@@ -187,6 +190,7 @@ where
                         )
                         .parse(cursor, interner)?,
                     )
+                    .with_span(Span::new(start, cursor.last_token_end()))
                     .into();
                 }
                 _ => break,
@@ -271,6 +275,7 @@ where
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
         let _timer = Profiler::global().start_event("ShortCircuitExpression", "Parsing");
 
+        let start = cursor.peek(0, interner).or_abrupt()?.span().start();
         let mut current_node =
             BitwiseORExpression::new(self.name, self.allow_in, self.allow_yield, self.allow_await)
                 .parse(cursor, interner)?;
@@ -297,7 +302,9 @@ where
                     .parse(cursor, interner)?;
 
                     current_node =
-                        Binary::new(BinaryOp::Logical(LogicalOp::And), current_node, rhs).into();
+                        Binary::new(BinaryOp::Logical(LogicalOp::And), current_node, rhs)
+                            .with_span(Span::new(start, cursor.last_token_end()))
+                            .into();
                 }
                 TokenKind::Punctuator(Punctuator::BoolOr) => {
                     if previous == PreviousExpr::Coalesce {
@@ -317,8 +324,9 @@ where
                         PreviousExpr::Logical,
                     )
                     .parse(cursor, interner)?;
-                    current_node =
-                        Binary::new(BinaryOp::Logical(LogicalOp::Or), current_node, rhs).into();
+                    current_node = Binary::new(BinaryOp::Logical(LogicalOp::Or), current_node, rhs)
+                        .with_span(Span::new(start, cursor.last_token_end()))
+                        .into();
                 }
                 TokenKind::Punctuator(Punctuator::Coalesce) => {
                     if previous == PreviousExpr::Logical {
@@ -340,6 +348,7 @@ where
                     .parse(cursor, interner)?;
                     current_node =
                         Binary::new(BinaryOp::Logical(LogicalOp::Coalesce), current_node, rhs)
+                            .with_span(Span::new(start, cursor.last_token_end()))
                             .into();
                 }
                 _ => break,
@@ -565,6 +574,7 @@ where
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
         let _timer = Profiler::global().start_event("Relation Expression", "Parsing");
 
+        let start = cursor.peek(0, interner).or_abrupt()?.span().start();
         if self.allow_in.0 {
             let token = cursor.peek(0, interner).or_abrupt()?;
             if let TokenKind::PrivateIdentifier(identifier) = token.kind() {
@@ -585,7 +595,9 @@ where
                             ShiftExpression::new(self.name, self.allow_yield, self.allow_await)
                                 .parse(cursor, interner)?;
 
-                        return Ok(BinaryInPrivate::new(PrivateName::new(identifier), rhs).into());
+                        return Ok(BinaryInPrivate::new(PrivateName::new(identifier), rhs)
+                            .with_span(Span::new(start, cursor.last_token_end()))
+                            .into());
                     }
                     _ => {}
                 }
@@ -610,6 +622,7 @@ where
                         ShiftExpression::new(self.name, self.allow_yield, self.allow_await)
                             .parse(cursor, interner)?,
                     )
+                    .with_span(Span::new(start, cursor.last_token_end()))
                     .into();
                 }
                 TokenKind::Keyword((Keyword::InstanceOf | Keyword::In, true)) => {
@@ -629,6 +642,7 @@ where
                         ShiftExpression::new(self.name, self.allow_yield, self.allow_await)
                             .parse(cursor, interner)?,
                     )
+                    .with_span(Span::new(start, cursor.last_token_end()))
                     .into();
                 }
                 _ => break,
@@ -20,7 +20,7 @@ use crate::{
 };
 use boa_ast::{
     expression::{literal, Spread},
-    Punctuator,
+    Punctuator, Span,
 };
 use boa_interner::Interner;
 use boa_profiler::Profiler;
@@ -98,11 +98,16 @@ where
                     ));
                 }
                 TokenKind::Punctuator(Punctuator::Spread) => {
+                    let start = token.span().start();
                     cursor.advance(interner);
                     let node =
                         AssignmentExpression::new(None, true, self.allow_yield, self.allow_await)
                             .parse(cursor, interner)?;
-                    elements.push(Some(Spread::new(node).into()));
+                    elements.push(Some(
+                        Spread::new(node)
+                            .with_span(Span::new(start, cursor.last_token_end()))
+                            .into(),
+                    ));
                     next_comma = true;
                     last_spread = true;
                 }
@@ -36,23 +36,23 @@ use crate::{
             identifiers::IdentifierReference, primary::template::TemplateLiteral,
             BindingIdentifier, Expression,
         },
-        statement::{ArrayBindingPattern, ObjectBindingPattern},
+        statement::{ArrayBindingPattern, DecoratorList, ObjectBindingPattern},
         AllowAwait, AllowYield, Cursor, OrAbrupt, ParseResult, TokenParser,
     },
     Error,
 };
 use boa_ast::{
     self as ast,
-    declaration::Variable,
+    declaration::{Binding, Variable},
     expression::{
-        literal::Literal,
+        literal::{Literal, LiteralKind},
         operator::{assign::AssignTarget, binary::BinaryOp},
         Call, Identifier, New, Parenthesized,
     },
     function::{FormalParameter, FormalParameterList},
     operations::{contains, ContainsSymbol},
-    pattern::{ArrayPatternElement, ObjectPatternElement, Pattern},
-    Keyword, Punctuator, Span,
+    pattern::{ArrayPattern, ObjectPattern, Pattern},
+    Keyword, Position, Punctuator, Span,
 };
 use boa_interner::{Interner, Sym};
 use boa_profiler::Profiler;
@@ -114,26 +114,43 @@ where
             )),
             TokenKind::Keyword((Keyword::This, false)) => {
                 cursor.advance(interner);
-                Ok(ast::Expression::This)
+                Ok(ast::Expression::This(
+                    Span::new(tok_position, cursor.last_token_end()).into(),
+                ))
             }
             TokenKind::Keyword((Keyword::Function, _)) => {
                 cursor.advance(interner);
                 let next_token = cursor.peek(0, interner).or_abrupt()?;
                 if next_token.kind() == &TokenKind::Punctuator(Punctuator::Mul) {
-                    GeneratorExpression::new(self.name)
-                        .parse(cursor, interner)
-                        .map(Into::into)
+                    let generator = GeneratorExpression::new(self.name).parse(cursor, interner)?;
+                    Ok(generator
+                        .with_span(Span::new(tok_position, cursor.last_token_end()))
+                        .into())
                 } else {
-                    FunctionExpression::new(self.name)
-                        .parse(cursor, interner)
-                        .map(Into::into)
+                    let function = FunctionExpression::new(self.name).parse(cursor, interner)?;
+                    Ok(function
+                        .with_span(Span::new(tok_position, cursor.last_token_end()))
+                        .into())
                 }
             }
             TokenKind::Keyword((Keyword::Class, _)) => {
                 cursor.advance(interner);
-                ClassExpression::new(self.name, self.allow_yield, self.allow_await)
-                    .parse(cursor, interner)
-                    .map(Into::into)
+                let class = ClassExpression::new(self.name, self.allow_yield, self.allow_await)
+                    .parse(cursor, interner)?;
+                Ok(class
+                    .with_span(Span::new(tok_position, cursor.last_token_end()))
+                    .into())
+            }
+            TokenKind::Punctuator(Punctuator::At) => {
+                let decorators = DecoratorList::new(self.allow_yield, self.allow_await)
+                    .parse(cursor, interner)?;
+                cursor.expect((Keyword::Class, false), "class expression", interner)?;
+                let class = ClassExpression::new(self.name, self.allow_yield, self.allow_await)
+                    .parse(cursor, interner)?;
+                Ok(class
+                    .with_decorators(decorators)
+                    .with_span(Span::new(tok_position, cursor.last_token_end()))
+                    .into())
             }
             TokenKind::Keyword((Keyword::Async, contain_escaped_char)) => {
                 let contain_escaped_char = *contain_escaped_char;
@@ -153,18 +170,29 @@ where
                         cursor.advance(interner);
                         match cursor.peek(1, interner)?.map(Token::kind) {
                             Some(TokenKind::Punctuator(Punctuator::Mul)) => {
-                                AsyncGeneratorExpression::new(self.name)
-                                    .parse(cursor, interner)
-                                    .map(Into::into)
+                                let generator = AsyncGeneratorExpression::new(self.name)
+                                    .parse(cursor, interner)?;
+                                Ok(generator
+                                    .with_span(Span::new(tok_position, cursor.last_token_end()))
+                                    .into())
+                            }
+                            _ => {
+                                let function = AsyncFunctionExpression::new(self.name)
+                                    .parse(cursor, interner)?;
+                                Ok(function
+                                    .with_span(Span::new(tok_position, cursor.last_token_end()))
+                                    .into())
                             }
-                            _ => AsyncFunctionExpression::new(self.name)
-                                .parse(cursor, interner)
-                                .map(Into::into),
                         }
                     }
-                    _ => IdentifierReference::new(self.allow_yield, self.allow_await)
-                        .parse(cursor, interner)
-                        .map(Into::into),
+                    _ => {
+                        let ident = IdentifierReference::new(self.allow_yield, self.allow_await)
+                            .parse(cursor, interner)?;
+                        Ok(ast::Expression::Identifier(
+                            ident,
+                            cursor.last_token_span().into(),
+                        ))
+                    }
                 }
             }
             TokenKind::Punctuator(Punctuator::OpenParen) => {
@@ -174,6 +202,7 @@ where
                     self.name,
                     self.allow_yield,
                     self.allow_await,
+                    tok_position,
                 )
                 .parse(cursor, interner)?;
                 Ok(expr)
@@ -181,35 +210,47 @@ where
             TokenKind::Punctuator(Punctuator::OpenBracket) => {
                 cursor.advance(interner);
                 cursor.set_goal(InputElement::RegExp);
-                ArrayLiteral::new(self.allow_yield, self.allow_await)
-                    .parse(cursor, interner)
-                    .map(Into::into)
+                let array = ArrayLiteral::new(self.allow_yield, self.allow_await)
+                    .parse(cursor, interner)?;
+                Ok(array
+                    .with_span(Span::new(tok_position, cursor.last_token_end()))
+                    .into())
             }
             TokenKind::Punctuator(Punctuator::OpenBlock) => {
                 cursor.advance(interner);
                 cursor.set_goal(InputElement::RegExp);
-                ObjectLiteral::new(self.allow_yield, self.allow_await)
-                    .parse(cursor, interner)
-                    .map(Into::into)
+                let object = ObjectLiteral::new(self.allow_yield, self.allow_await)
+                    .parse(cursor, interner)?;
+                Ok(object
+                    .with_span(Span::new(tok_position, cursor.last_token_end()))
+                    .into())
             }
             TokenKind::BooleanLiteral((boolean, _)) => {
-                let node = Literal::from(*boolean).into();
+                let node = Literal::from(*boolean).with_span(tok.span()).into();
                 cursor.advance(interner);
                 Ok(node)
             }
             TokenKind::NullLiteral(_) => {
+                let node = Literal::from(LiteralKind::Null)
+                    .with_span(tok.span())
+                    .into();
                 cursor.advance(interner);
-                Ok(Literal::Null.into())
+                Ok(node)
             }
             TokenKind::IdentifierName(_)
             | TokenKind::Keyword((
                 Keyword::Let | Keyword::Yield | Keyword::Await | Keyword::Of,
                 _,
-            )) => IdentifierReference::new(self.allow_yield, self.allow_await)
-                .parse(cursor, interner)
-                .map(Into::into),
+            )) => {
+                let ident = IdentifierReference::new(self.allow_yield, self.allow_await)
+                    .parse(cursor, interner)?;
+                Ok(ast::Expression::Identifier(
+                    ident,
+                    cursor.last_token_span().into(),
+                ))
+            }
             TokenKind::StringLiteral((lit, _)) => {
-                let node = Literal::from(*lit).into();
+                let node = Literal::from(*lit).with_span(tok.span()).into();
                 cursor.advance(interner);
                 Ok(node)
             }
@@ -219,30 +260,34 @@ where
                         .to_owned_cooked(interner)
                         .map_err(Error::lex)?,
                 )
+                .with_span(tok.span())
                 .into();
                 cursor.advance(interner);
                 Ok(node)
             }
             TokenKind::NumericLiteral(Numeric::Integer(num)) => {
-                let node = Literal::from(*num).into();
+                let node = Literal::from(*num).with_span(tok.span()).into();
                 cursor.advance(interner);
                 Ok(node)
             }
             TokenKind::NumericLiteral(Numeric::Rational(num)) => {
-                let node = Literal::from(*num).into();
+                let node = Literal::from(*num).with_span(tok.span()).into();
                 cursor.advance(interner);
                 Ok(node)
             }
             TokenKind::NumericLiteral(Numeric::BigInt(num)) => {
-                let node = Literal::from(num.clone()).into();
+                let node = Literal::from(num.clone()).with_span(tok.span()).into();
                 cursor.advance(interner);
                 Ok(node)
             }
             TokenKind::RegularExpressionLiteral(body, flags) => {
-                let node = ast::Expression::from(New::from(Call::new(
-                    Identifier::new(Sym::REGEXP).into(),
-                    vec![Literal::from(*body).into(), Literal::from(*flags).into()].into(),
-                )));
+                let node = ast::Expression::from(New::from(
+                    Call::new(
+                        Identifier::new(Sym::REGEXP).into(),
+                        vec![Literal::from(*body).into(), Literal::from(*flags).into()].into(),
+                    )
+                    .with_span(tok.span()),
+                ));
                 cursor.advance(interner);
                 Ok(node)
             }
@@ -252,10 +297,13 @@ where
                 let tok = cursor.lex_regex(position, interner)?;
 
                 if let TokenKind::RegularExpressionLiteral(body, flags) = *tok.kind() {
-                    Ok(ast::Expression::from(New::from(Call::new(
-                        Identifier::new(Sym::REGEXP).into(),
-                        vec![Literal::from(body).into(), Literal::from(flags).into()].into(),
-                    ))))
+                    Ok(ast::Expression::from(New::from(
+                        Call::new(
+                            Identifier::new(Sym::REGEXP).into(),
+                            vec![Literal::from(body).into(), Literal::from(flags).into()].into(),
+                        )
+                        .with_span(tok.span()),
+                    )))
                 } else {
                     // A regex was expected and nothing else.
                     Err(Error::unexpected(
@@ -297,11 +345,12 @@ pub(super) struct CoverParenthesizedExpressionAndArrowParameterList {
     name: Option<Identifier>,
     allow_yield: AllowYield,
     allow_await: AllowAwait,
+    start: Position,
 }
 
 impl CoverParenthesizedExpressionAndArrowParameterList {
     /// Creates a new `CoverParenthesizedExpressionAndArrowParameterList` parser.
-    pub(super) fn new<N, Y, A>(name: N, allow_yield: Y, allow_await: A) -> Self
+    pub(super) fn new<N, Y, A>(name: N, allow_yield: Y, allow_await: A, start: Position) -> Self
     where
         N: Into<Option<Identifier>>,
         Y: Into<AllowYield>,
@@ -311,6 +360,7 @@ impl CoverParenthesizedExpressionAndArrowParameterList {
             name: name.into(),
             allow_yield: allow_yield.into(),
             allow_await: allow_await.into(),
+            start,
         }
     }
 }
@@ -325,9 +375,9 @@ where
         #[derive(Debug)]
         enum InnerExpression {
             Expression(ast::Expression),
-            SpreadObject(Vec<ObjectPatternElement>),
-            SpreadArray(Vec<ArrayPatternElement>),
-            SpreadBinding(Identifier),
+            SpreadObject(ObjectPattern),
+            SpreadArray(ArrayPattern),
+            SpreadBinding(Identifier, Span),
         }
 
         let _timer = Profiler::global().start_event(
@@ -365,7 +415,10 @@ where
                     _ => {
                         let binding = BindingIdentifier::new(self.allow_yield, self.allow_await)
                             .parse(cursor, interner)?;
-                        expressions.push(InnerExpression::SpreadBinding(binding));
+                        expressions.push(InnerExpression::SpreadBinding(
+                            binding,
+                            cursor.last_token_span(),
+                        ));
                     }
                 }
 
@@ -426,7 +479,10 @@ where
                                             self.allow_await,
                                         )
                                         .parse(cursor, interner)?;
-                                        expressions.push(InnerExpression::SpreadBinding(binding));
+                                        expressions.push(InnerExpression::SpreadBinding(
+                                            binding,
+                                            cursor.last_token_span(),
+                                        ));
                                     }
                                 }
 
@@ -492,9 +548,10 @@ where
                 ));
             }
             if let InnerExpression::Expression(expression) = &expressions[0] {
-                return Ok(ast::Expression::Parenthesized(Parenthesized::new(
-                    expression.clone(),
-                )));
+                return Ok(ast::Expression::Parenthesized(
+                    Parenthesized::new(expression.clone())
+                        .with_span(Span::new(self.start, span.end())),
+                ));
             }
             return Err(Error::unexpected(
                 Punctuator::CloseParen,
@@ -528,8 +585,9 @@ where
                     let parameter = FormalParameter::new(declaration, true);
                     parameters.push(parameter);
                 }
-                InnerExpression::SpreadBinding(ident) => {
-                    let declaration = Variable::from_identifier(ident, None);
+                InnerExpression::SpreadBinding(ident, span) => {
+                    let declaration =
+                        Variable::from_binding(Binding::Identifier(ident, span.into()), None);
                     let parameter = FormalParameter::new(declaration, true);
                     parameters.push(parameter);
                 }
@@ -566,21 +624,21 @@ fn expression_to_formal_parameters(
     span: Span,
 ) -> ParseResult<()> {
     match node {
-        ast::Expression::Identifier(identifier) if strict && *identifier == Sym::EVAL => {
+        ast::Expression::Identifier(identifier, _) if strict && *identifier == Sym::EVAL => {
             return Err(Error::general(
                 "parameter name 'eval' not allowed in strict mode",
                 span.start(),
             ));
         }
-        ast::Expression::Identifier(identifier) if strict && *identifier == Sym::ARGUMENTS => {
+        ast::Expression::Identifier(identifier, _) if strict && *identifier == Sym::ARGUMENTS => {
             return Err(Error::general(
                 "parameter name 'arguments' not allowed in strict mode",
                 span.start(),
             ));
         }
-        ast::Expression::Identifier(identifier) => {
+        ast::Expression::Identifier(identifier, span) => {
             parameters.push(FormalParameter::new(
-                Variable::from_identifier(*identifier, None),
+                Variable::from_binding(Binding::Identifier(*identifier, *span), None),
                 false,
             ));
         }
@@ -589,9 +647,12 @@ fn expression_to_formal_parameters(
             expression_to_formal_parameters(bin_op.rhs(), parameters, strict, span)?;
         }
         ast::Expression::Assign(assign) => match assign.lhs() {
-            AssignTarget::Identifier(ident) => {
+            AssignTarget::Identifier(ident, span) => {
                 parameters.push(FormalParameter::new(
-                    Variable::from_identifier(*ident, Some(assign.rhs().clone())),
+                    Variable::from_binding(
+                        Binding::Identifier(*ident, *span),
+                        Some(assign.rhs().clone()),
+                    ),
                     false,
                 ));
             }
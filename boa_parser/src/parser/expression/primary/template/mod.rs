@@ -14,7 +14,7 @@ use crate::{
 };
 use boa_ast::{
     expression::literal::{self, TemplateElement},
-    Position, Punctuator,
+    Position, Punctuator, Span,
 };
 use boa_interner::{Interner, Sym};
 use boa_profiler::Profiler;
@@ -98,7 +98,8 @@ where
                         .map_err(Error::lex)?;
 
                     elements.push(TemplateElement::String(cooked));
-                    return Ok(literal::TemplateLiteral::new(elements.into()));
+                    return Ok(literal::TemplateLiteral::new(elements.into())
+                        .with_span(Span::new(self.start, cursor.last_token_end())));
                 }
                 _ => return Err(Error::general("cannot parse template literal", self.start)),
             }
@@ -21,7 +21,7 @@ use boa_ast::{
         operator::{unary::UnaryOp, Unary},
         Identifier,
     },
-    Expression, Keyword, Punctuator,
+    Expression, Keyword, Punctuator, Span,
 };
 use boa_interner::Interner;
 use boa_profiler::Profiler;
@@ -79,7 +79,7 @@ where
                 let target = self.parse(cursor, interner)?;
 
                 match target.flatten() {
-                    Expression::Identifier(_) if cursor.strict() => {
+                    Expression::Identifier(..) if cursor.strict() => {
                         return Err(Error::lex(LexError::Syntax(
                             "cannot delete variables in strict mode".into(),
                             token_start,
@@ -94,31 +94,45 @@ where
                     _ => {}
                 }
 
-                Ok(Unary::new(UnaryOp::Delete, target).into())
+                Ok(Unary::new(UnaryOp::Delete, target)
+                    .with_span(Span::new(token_start, cursor.last_token_end()))
+                    .into())
             }
             TokenKind::Keyword((Keyword::Void, false)) => {
                 cursor.advance(interner);
-                Ok(Unary::new(UnaryOp::Void, self.parse(cursor, interner)?).into())
+                Ok(Unary::new(UnaryOp::Void, self.parse(cursor, interner)?)
+                    .with_span(Span::new(token_start, cursor.last_token_end()))
+                    .into())
             }
             TokenKind::Keyword((Keyword::TypeOf, false)) => {
                 cursor.advance(interner);
-                Ok(Unary::new(UnaryOp::TypeOf, self.parse(cursor, interner)?).into())
+                Ok(Unary::new(UnaryOp::TypeOf, self.parse(cursor, interner)?)
+                    .with_span(Span::new(token_start, cursor.last_token_end()))
+                    .into())
             }
             TokenKind::Punctuator(Punctuator::Add) => {
                 cursor.advance(interner);
-                Ok(Unary::new(UnaryOp::Plus, self.parse(cursor, interner)?).into())
+                Ok(Unary::new(UnaryOp::Plus, self.parse(cursor, interner)?)
+                    .with_span(Span::new(token_start, cursor.last_token_end()))
+                    .into())
             }
             TokenKind::Punctuator(Punctuator::Sub) => {
                 cursor.advance(interner);
-                Ok(Unary::new(UnaryOp::Minus, self.parse(cursor, interner)?).into())
+                Ok(Unary::new(UnaryOp::Minus, self.parse(cursor, interner)?)
+                    .with_span(Span::new(token_start, cursor.last_token_end()))
+                    .into())
             }
             TokenKind::Punctuator(Punctuator::Neg) => {
                 cursor.advance(interner);
-                Ok(Unary::new(UnaryOp::Tilde, self.parse(cursor, interner)?).into())
+                Ok(Unary::new(UnaryOp::Tilde, self.parse(cursor, interner)?)
+                    .with_span(Span::new(token_start, cursor.last_token_end()))
+                    .into())
             }
             TokenKind::Punctuator(Punctuator::Not) => {
                 cursor.advance(interner);
-                Ok(Unary::new(UnaryOp::Not, self.parse(cursor, interner)?).into())
+                Ok(Unary::new(UnaryOp::Not, self.parse(cursor, interner)?)
+                    .with_span(Span::new(token_start, cursor.last_token_end()))
+                    .into())
             }
             TokenKind::Keyword((Keyword::Await, true)) if self.allow_await.0 => {
                 Err(Error::general(
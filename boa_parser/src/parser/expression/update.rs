@@ -24,7 +24,7 @@ use boa_ast::{
         },
         Identifier,
     },
-    Expression, Position, Punctuator,
+    Expression, Position, Punctuator, Span,
 };
 use boa_interner::Interner;
 use boa_profiler::Profiler;
@@ -71,11 +71,11 @@ fn as_simple(
     strict: bool,
 ) -> ParseResult<Option<UpdateTarget>> {
     match expr {
-        Expression::Identifier(ident) => {
+        Expression::Identifier(ident, span) => {
             if strict {
                 check_strict_arguments_or_eval(*ident, position)?;
             }
-            Ok(Some(UpdateTarget::Identifier(*ident)))
+            Ok(Some(UpdateTarget::Identifier(*ident, *span)))
         }
         Expression::PropertyAccess(access) => {
             Ok(Some(UpdateTarget::PropertyAccess(access.clone())))
@@ -113,7 +113,11 @@ where
                             position,
                         )))
                     },
-                    |target| Ok(Update::new(UpdateOp::IncrementPre, target).into()),
+                    |target| {
+                        Ok(Update::new(UpdateOp::IncrementPre, target)
+                            .with_span(Span::new(position, cursor.last_token_end()))
+                            .into())
+                    },
                 );
             }
             TokenKind::Punctuator(Punctuator::Dec) => {
@@ -132,7 +136,11 @@ where
                             position,
                         )))
                     },
-                    |target| Ok(Update::new(UpdateOp::DecrementPre, target).into()),
+                    |target| {
+                        Ok(Update::new(UpdateOp::DecrementPre, target)
+                            .with_span(Span::new(position, cursor.last_token_end()))
+                            .into())
+                    },
                 );
             }
             _ => {}
@@ -161,7 +169,11 @@ where
                                 token_start,
                             )))
                         },
-                        |target| Ok(Update::new(UpdateOp::IncrementPost, target).into()),
+                        |target| {
+                            Ok(Update::new(UpdateOp::IncrementPost, target)
+                                .with_span(Span::new(position, cursor.last_token_end()))
+                                .into())
+                        },
                     );
                 }
                 TokenKind::Punctuator(Punctuator::Dec) => {
@@ -177,7 +189,11 @@ where
                                 token_start,
                             )))
                         },
-                        |target| Ok(Update::new(UpdateOp::DecrementPost, target).into()),
+                        |target| {
+                            Ok(Update::new(UpdateOp::DecrementPost, target)
+                                .with_span(Span::new(position, cursor.last_token_end()))
+                                .into())
+                        },
                     );
                 }
                 _ => {}
@@ -25,9 +25,9 @@ use ast::{
 };
 use boa_ast::{
     self as ast,
-    declaration::Variable,
+    declaration::{Binding, Variable},
     function::{FormalParameterList, FormalParameterListFlags},
-    Punctuator,
+    Punctuator, Span,
 };
 use boa_interner::{Interner, Sym};
 use boa_profiler::Profiler;
@@ -75,8 +75,9 @@ where
         let Some(start_position) = cursor
             .peek(0, interner)?
             .filter(|&tok| tok.kind() != &TokenKind::Punctuator(Punctuator::CloseParen))
-            .map(|tok| tok.span().start()) else {
-            return Ok( FormalParameterList::default());
+            .map(|tok| tok.span().start())
+        else {
+            return Ok(FormalParameterList::default());
         };
 
         let mut params = Vec::new();
@@ -250,7 +251,10 @@ where
 
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
         let _timer = Profiler::global().start_event("BindingRestElement", "Parsing");
-        cursor.expect(Punctuator::Spread, "rest parameter", interner)?;
+        let start = cursor
+            .expect(Punctuator::Spread, "rest parameter", interner)?
+            .span()
+            .start();
 
         if let Some(t) = cursor.peek(0, interner)? {
             let declaration = match *t.kind() {
@@ -283,6 +287,7 @@ where
                 _ => {
                     let params = BindingIdentifier::new(self.allow_yield, self.allow_await)
                         .parse(cursor, interner)?;
+                    let binding = Binding::Identifier(params, cursor.last_token_span().into());
                     let init = cursor
                         .peek(0, interner)?
                         .cloned()
@@ -296,10 +301,11 @@ where
                         })
                         .transpose()?;
 
-                    Variable::from_identifier(params, init)
+                    Variable::from_binding(binding, init)
                 }
             };
-            Ok(Self::Output::new(declaration, true))
+            Ok(Self::Output::new(declaration, true)
+                .with_span(Span::new(start, cursor.last_token_end())))
         } else {
             Ok(Self::Output::new(
                 Variable::from_identifier(Sym::EMPTY_STRING.into(), None),
@@ -347,6 +353,7 @@ where
         let _timer = Profiler::global().start_event("FormalParameter", "Parsing");
 
         if let Some(t) = cursor.peek(0, interner)? {
+            let start = t.span().start();
             let declaration = match *t.kind() {
                 TokenKind::Punctuator(Punctuator::OpenBlock) => {
                     let bindings = ObjectBindingPattern::new(self.allow_yield, self.allow_await)
@@ -383,6 +390,7 @@ where
                 _ => {
                     let ident = BindingIdentifier::new(self.allow_yield, self.allow_await)
                         .parse(cursor, interner)?;
+                    let binding = Binding::Identifier(ident, cursor.last_token_span().into());
                     let init = if cursor.peek(0, interner)?.map_or(false, |tok| {
                         tok.kind() == &TokenKind::Punctuator(Punctuator::Assign)
                     }) {
@@ -394,10 +402,11 @@ where
                         None
                     };
 
-                    Variable::from_identifier(ident, init)
+                    Variable::from_binding(binding, init)
                 }
             };
-            Ok(Self::Output::new(declaration, false))
+            Ok(Self::Output::new(declaration, false)
+                .with_span(Span::new(start, cursor.last_token_end())))
         } else {
             Ok(Self::Output::new(
                 Variable::from_identifier(Sym::EMPTY_STRING.into(), None),
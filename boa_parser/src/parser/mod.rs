@@ -326,6 +326,10 @@ where
         )
         .parse(cursor, interner)?;
 
+        // It is a Syntax Error if the goal symbol is Script and StatementList directly contains
+        // a using declaration.
+        statement::check_no_using_declarations(&body, "at the top level of scripts")?;
+
         if !self.direct_eval {
             // It is a Syntax Error if StatementList Contains super unless the source text containing super is eval
             // code that is being processed by a direct eval.
@@ -20,7 +20,7 @@ use crate::{
 };
 use boa_ast::{
     operations::{lexically_declared_names_legacy, var_declared_names},
-    statement, Punctuator,
+    statement, Punctuator, Span,
 };
 use boa_interner::Interner;
 use boa_profiler::Profiler;
@@ -77,11 +77,15 @@ where
 
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
         let _timer = Profiler::global().start_event("Block", "Parsing");
-        cursor.expect(Punctuator::OpenBlock, "block", interner)?;
+        let start = cursor
+            .expect(Punctuator::OpenBlock, "block", interner)?
+            .span()
+            .start();
         if let Some(tk) = cursor.peek(0, interner)? {
             if tk.kind() == &TokenKind::Punctuator(Punctuator::CloseBlock) {
                 cursor.advance(interner);
-                return Ok(statement::Block::from(vec![]));
+                return Ok(statement::Block::from(vec![])
+                    .with_span(Span::new(start, cursor.last_token_end())));
             }
         }
         let position = cursor.peek(0, interner).or_abrupt()?.span().start();
@@ -126,6 +130,6 @@ where
             }
         }
 
-        Ok(statement_list)
+        Ok(statement_list.with_span(Span::new(start, cursor.last_token_end())))
     }
 }
@@ -5,11 +5,7 @@ use boa_ast::{
     declaration::{VarDeclaration, Variable},
     expression::{
         literal::Literal,
-        operator::{
-            assign::AssignOp,
-            update::{UpdateOp, UpdateTarget},
-            Assign, Update,
-        },
+        operator::{assign::AssignOp, update::UpdateOp, Assign, Update},
         Call, Identifier,
     },
     function::{FormalParameterList, Function, FunctionBody},
@@ -58,7 +54,7 @@ fn non_empty() {
             .into(),
             Statement::Expression(Expression::from(Update::new(
                 UpdateOp::IncrementPost,
-                UpdateTarget::Identifier(Identifier::new(a)),
+                Identifier::new(a).into(),
             )))
             .into(),
         ],
@@ -100,7 +96,7 @@ fn non_empty() {
             .into(),
             Statement::Expression(Expression::from(Update::new(
                 UpdateOp::IncrementPost,
-                UpdateTarget::Identifier(Identifier::new(a)),
+                Identifier::new(a).into(),
             )))
             .into(),
         ],
@@ -132,7 +128,7 @@ fn hoisting() {
             .into(),
             Statement::Expression(Expression::from(Update::new(
                 UpdateOp::IncrementPost,
-                UpdateTarget::Identifier(Identifier::new(a)),
+                Identifier::new(a).into(),
             )))
             .into(),
             Declaration::Function(Function::new(
@@ -168,7 +164,7 @@ fn hoisting() {
             .into(),
             Statement::Expression(Expression::from(Update::new(
                 UpdateOp::IncrementPost,
-                UpdateTarget::Identifier(Identifier::new(a)),
+                Identifier::new(a).into(),
             )))
             .into(),
             Statement::Var(VarDeclaration(
@@ -18,7 +18,7 @@ use crate::{
         AllowAwait, AllowYield, ParseResult, TokenParser,
     },
 };
-use boa_ast::{statement::Break, Keyword, Punctuator};
+use boa_ast::{statement::Break, Keyword, Punctuator, Span};
 use boa_interner::Interner;
 use boa_profiler::Profiler;
 use std::io::Read;
@@ -59,7 +59,10 @@ where
 
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
         let _timer = Profiler::global().start_event("BreakStatement", "Parsing");
-        cursor.expect((Keyword::Break, false), "break statement", interner)?;
+        let start = cursor
+            .expect((Keyword::Break, false), "break statement", interner)?
+            .span()
+            .start();
 
         let label = if let SemicolonResult::Found(tok) = cursor.peek_semicolon(interner)? {
             if tok.map(Token::kind) == Some(&TokenKind::Punctuator(Punctuator::Semicolon)) {
@@ -76,6 +79,6 @@ where
             Some(label)
         };
 
-        Ok(Break::new(label))
+        Ok(Break::new(label).with_span(Span::new(start, cursor.last_token_end())))
     }
 }
@@ -18,7 +18,7 @@ use crate::{
         AllowAwait, AllowYield, ParseResult, TokenParser,
     },
 };
-use boa_ast::{statement::Continue, Keyword, Punctuator};
+use boa_ast::{statement::Continue, Keyword, Punctuator, Span};
 use boa_interner::Interner;
 use boa_profiler::Profiler;
 use std::io::Read;
@@ -59,7 +59,10 @@ where
 
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
         let _timer = Profiler::global().start_event("ContinueStatement", "Parsing");
-        cursor.expect((Keyword::Continue, false), "continue statement", interner)?;
+        let start = cursor
+            .expect((Keyword::Continue, false), "continue statement", interner)?
+            .span()
+            .start();
 
         let label = if let SemicolonResult::Found(tok) = cursor.peek_semicolon(interner)? {
             if let Some(token) = tok {
@@ -84,6 +87,6 @@ where
             Some(label)
         };
 
-        Ok(Continue::new(label))
+        Ok(Continue::new(label).with_span(Span::new(start, cursor.last_token_end())))
     }
 }
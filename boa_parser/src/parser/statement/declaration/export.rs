@@ -28,7 +28,7 @@ use std::io::Read;
 
 use super::{
     hoistable::{AsyncFunctionDeclaration, AsyncGeneratorDeclaration, GeneratorDeclaration},
-    Declaration, FromClause, FunctionDeclaration,
+    Declaration, FromClause, FunctionDeclaration, WithClause,
 };
 
 /// Parses an export declaration.
@@ -81,19 +81,25 @@ where
 
                         let specifier =
                             FromClause::new("export declaration").parse(cursor, interner)?;
+                        let attributes =
+                            WithClause::new("export declaration").parse(cursor, interner)?;
 
                         AstExportDeclaration::ReExport {
                             kind: ReExportKind::Namespaced { name: Some(alias) },
                             specifier,
+                            attributes,
                         }
                     }
                     TokenKind::IdentifierName((Sym::FROM, _)) => {
                         let specifier =
                             FromClause::new("export declaration").parse(cursor, interner)?;
+                        let attributes =
+                            WithClause::new("export declaration").parse(cursor, interner)?;
 
                         AstExportDeclaration::ReExport {
                             kind: ReExportKind::Namespaced { name: None },
                             specifier,
+                            attributes,
                         }
                     }
                     _ => {
@@ -121,12 +127,15 @@ where
                 ) {
                     let specifier =
                         FromClause::new("export declaration").parse(cursor, interner)?;
+                    let attributes =
+                        WithClause::new("export declaration").parse(cursor, interner)?;
 
                     cursor.expect_semicolon("named re-exports", interner)?;
 
                     AstExportDeclaration::ReExport {
                         kind: ReExportKind::Named { names },
                         specifier,
+                        attributes,
                     }
                 } else {
                     cursor.expect_semicolon("named exports", interner)?;
@@ -198,7 +207,8 @@ where
                             )
                         }
                     }
-                    TokenKind::Keyword((Keyword::Class, false)) => {
+                    TokenKind::Keyword((Keyword::Class, false))
+                    | TokenKind::Punctuator(Punctuator::At) => {
                         AstExportDeclaration::DefaultClassDeclaration(
                             ClassDeclaration::new(false, true, true).parse(cursor, interner)?,
                         )
@@ -213,9 +223,16 @@ where
                     }
                 }
             }
-            _ => AstExportDeclaration::Declaration(
-                Declaration::new(false, true).parse(cursor, interner)?,
-            ),
+            _ => {
+                let declaration = Declaration::new(false, true).parse(cursor, interner)?;
+                if matches!(&declaration, boa_ast::Declaration::Lexical(decl) if decl.is_using()) {
+                    return Err(Error::general(
+                        "using declarations cannot be exported",
+                        span.start(),
+                    ));
+                }
+                AstExportDeclaration::Declaration(declaration)
+            }
         };
 
         Ok(export_clause)
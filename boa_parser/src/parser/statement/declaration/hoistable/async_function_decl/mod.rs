@@ -5,7 +5,7 @@ use crate::parser::{
     statement::declaration::hoistable::{parse_callable_declaration, CallableDeclaration},
     AllowAwait, AllowDefault, AllowYield, Cursor, ParseResult, TokenParser,
 };
-use boa_ast::{function::AsyncFunction, Keyword};
+use boa_ast::{function::AsyncFunction, Keyword, Span};
 use boa_interner::Interner;
 use std::io::Read;
 
@@ -77,11 +77,14 @@ where
     type Output = AsyncFunction;
 
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
-        cursor.expect(
-            (Keyword::Async, false),
-            "async function declaration",
-            interner,
-        )?;
+        let start = cursor
+            .expect(
+                (Keyword::Async, false),
+                "async function declaration",
+                interner,
+            )?
+            .span()
+            .start();
         cursor.peek_expect_no_lineterminator(0, "async function declaration", interner)?;
         cursor.expect(
             (Keyword::Function, false),
@@ -91,11 +94,9 @@ where
 
         let result = parse_callable_declaration(&self, cursor, interner)?;
 
-        Ok(AsyncFunction::new(
-            Some(result.0),
-            result.1,
-            result.2,
-            false,
-        ))
+        Ok(
+            AsyncFunction::new(Some(result.0), result.1, result.2, false)
+                .with_span(Span::new(start, cursor.last_token_end())),
+        )
     }
 }
@@ -10,7 +10,7 @@ use crate::parser::{
     statement::declaration::hoistable::{parse_callable_declaration, CallableDeclaration},
     AllowAwait, AllowDefault, AllowYield, Cursor, ParseResult, TokenParser,
 };
-use boa_ast::{function::AsyncGenerator, Keyword, Punctuator};
+use boa_ast::{function::AsyncGenerator, Keyword, Punctuator, Span};
 use boa_interner::Interner;
 use std::io::Read;
 
@@ -90,11 +90,14 @@ where
     type Output = AsyncGenerator;
 
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
-        cursor.expect(
-            (Keyword::Async, false),
-            "async generator declaration",
-            interner,
-        )?;
+        let start = cursor
+            .expect(
+                (Keyword::Async, false),
+                "async generator declaration",
+                interner,
+            )?
+            .span()
+            .start();
         cursor.peek_expect_no_lineterminator(0, "async generator declaration", interner)?;
         cursor.expect(
             (Keyword::Function, false),
@@ -105,11 +108,9 @@ where
 
         let result = parse_callable_declaration(&self, cursor, interner)?;
 
-        Ok(AsyncGenerator::new(
-            Some(result.0),
-            result.1,
-            result.2,
-            false,
-        ))
+        Ok(
+            AsyncGenerator::new(Some(result.0), result.1, result.2, false)
+                .with_span(Span::new(start, cursor.last_token_end())),
+        )
     }
 }
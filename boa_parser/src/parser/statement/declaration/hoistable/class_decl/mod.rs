@@ -5,8 +5,9 @@ use crate::{
     lexer::{token::ContainsEscapeSequence, Error as LexError, TokenKind},
     parser::{
         expression::{
-            AssignmentExpression, AsyncGeneratorMethod, AsyncMethod, BindingIdentifier,
-            GeneratorMethod, LeftHandSideExpression, PropertyName,
+            Arguments, AssignmentExpression, AsyncGeneratorMethod, AsyncMethod, BindingIdentifier,
+            Expression as ExpressionParser, GeneratorMethod, IdentifierReference,
+            LeftHandSideExpression, PropertyName,
         },
         function::{FormalParameters, FunctionBody, UniqueFormalParameters, FUNCTION_BREAK_TOKENS},
         statement::StatementList,
@@ -22,11 +23,14 @@ use ast::{
 };
 use boa_ast::{
     self as ast,
-    expression::Identifier,
-    function::{self, Class, FormalParameterList, Function},
+    expression::{
+        access::{PrivatePropertyAccess, SimplePropertyAccess},
+        Call, Identifier, Parenthesized,
+    },
+    function::{self, Class, Decorator, FormalParameterList, Function},
     operations::{contains, contains_arguments, has_direct_super, ContainsSymbol},
     property::{ClassElementName, MethodDefinition},
-    Expression, Keyword, Punctuator,
+    Expression, Keyword, Punctuator, Span,
 };
 use boa_interner::{Interner, Sym};
 use boa_macros::utf16;
@@ -71,6 +75,9 @@ where
     type Output = Class;
 
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
+        let start = cursor.peek(0, interner).or_abrupt()?.span().start();
+        let decorators =
+            DecoratorList::new(self.allow_yield, self.allow_await).parse(cursor, interner)?;
         cursor.expect((Keyword::Class, false), "class declaration", interner)?;
         let strict = cursor.strict();
         cursor.set_strict(true);
@@ -95,13 +102,17 @@ where
         };
         cursor.set_strict(strict);
 
-        ClassTail::new(
+        let class = ClassTail::new(
             name,
             has_binding_identifier,
             self.allow_yield,
             self.allow_await,
         )
-        .parse(cursor, interner)
+        .parse(cursor, interner)?;
+
+        Ok(class
+            .with_decorators(decorators)
+            .with_span(Span::new(start, cursor.last_token_end())))
     }
 }
 
@@ -258,6 +269,138 @@ where
     }
 }
 
+/// `DecoratorList` parsing.
+///
+/// Parses the (possibly empty) list of decorators applied to a class or a class element.
+///
+/// More information:
+///  - [Decorators proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-decorators/#prod-DecoratorList
+#[derive(Debug, Clone, Copy)]
+pub(in crate::parser) struct DecoratorList {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl DecoratorList {
+    /// Creates a new `DecoratorList` parser.
+    pub(in crate::parser) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for DecoratorList
+where
+    R: Read,
+{
+    type Output = Box<[Decorator]>;
+
+    fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
+        let mut decorators = Vec::new();
+
+        // Decorators are part of the class definition, which is always strict mode code.
+        let strict = cursor.strict();
+        cursor.set_strict(true);
+        while let Some(token) = cursor.next_if(Punctuator::At, interner)? {
+            let start = token.span().start();
+            let token = cursor.peek(0, interner).or_abrupt()?;
+            let expression = if token.kind() == &TokenKind::Punctuator(Punctuator::OpenParen) {
+                // DecoratorParenthesizedExpression : ( Expression )
+                let paren_start = token.span().start();
+                cursor.advance(interner);
+                let expression =
+                    ExpressionParser::new(None, true, self.allow_yield, self.allow_await)
+                        .parse(cursor, interner)?;
+                cursor.expect(Punctuator::CloseParen, "decorator", interner)?;
+                Parenthesized::new(expression)
+                    .with_span(Span::new(paren_start, cursor.last_token_end()))
+                    .into()
+            } else {
+                // DecoratorMemberExpression : IdentifierReference
+                // DecoratorCallExpression : DecoratorMemberExpression Arguments
+                let ident = IdentifierReference::new(self.allow_yield, self.allow_await)
+                    .parse(cursor, interner)?;
+                let mut expression = Expression::Identifier(ident, cursor.last_token_span().into());
+
+                // DecoratorMemberExpression : DecoratorMemberExpression . IdentifierName
+                // DecoratorMemberExpression : DecoratorMemberExpression . PrivateIdentifier
+                while cursor.next_if(Punctuator::Dot, interner)?.is_some() {
+                    let token = cursor.next(interner).or_abrupt()?;
+                    let span = Span::new(start, cursor.last_token_end());
+                    let access = match token.kind() {
+                        TokenKind::IdentifierName((name, _)) => {
+                            SimplePropertyAccess::new(expression, *name)
+                                .with_span(span)
+                                .into()
+                        }
+                        TokenKind::Keyword((kw, _)) => {
+                            SimplePropertyAccess::new(expression, kw.to_sym())
+                                .with_span(span)
+                                .into()
+                        }
+                        TokenKind::BooleanLiteral((true, _)) => {
+                            SimplePropertyAccess::new(expression, Sym::TRUE)
+                                .with_span(span)
+                                .into()
+                        }
+                        TokenKind::BooleanLiteral((false, _)) => {
+                            SimplePropertyAccess::new(expression, Sym::FALSE)
+                                .with_span(span)
+                                .into()
+                        }
+                        TokenKind::NullLiteral(_) => {
+                            SimplePropertyAccess::new(expression, Sym::NULL)
+                                .with_span(span)
+                                .into()
+                        }
+                        TokenKind::PrivateIdentifier(name) => PrivatePropertyAccess::new(
+                            expression,
+                            PrivateName::new(*name).with_span(token.span()),
+                        )
+                        .with_span(span)
+                        .into(),
+                        _ => {
+                            return Err(Error::expected(
+                                ["identifier".to_owned()],
+                                token.to_string(interner),
+                                token.span(),
+                                "decorator",
+                            ));
+                        }
+                    };
+                    expression = Expression::PropertyAccess(access);
+                }
+
+                if cursor.peek(0, interner).or_abrupt()?.kind()
+                    == &TokenKind::Punctuator(Punctuator::OpenParen)
+                {
+                    let args = Arguments::new(self.allow_yield, self.allow_await)
+                        .parse(cursor, interner)?;
+                    expression = Call::new(expression, args)
+                        .with_span(Span::new(start, cursor.last_token_end()))
+                        .into();
+                }
+
+                expression
+            };
+            decorators.push(
+                Decorator::new(expression).with_span(Span::new(start, cursor.last_token_end())),
+            );
+        }
+        cursor.set_strict(strict);
+
+        Ok(decorators.into())
+    }
+}
+
 /// `ClassBody` parsing.
 ///
 /// More information:
@@ -320,7 +463,7 @@ where
                         constructor = Some(c);
                     }
                     (None, Some(element)) => {
-                        match &element {
+                        match element.decorated().1 {
                             function::ClassElement::PrivateMethodDefinition(name, method) => {
                                 // It is a Syntax Error if PropName of MethodDefinition is not "constructor" and HasDirectSuper of MethodDefinition is true.
                                 if has_direct_super(method) {
@@ -453,7 +596,8 @@ where
                                     }
                                 }
                             }
-                            function::ClassElement::PrivateFieldDefinition(name, init) => {
+                            function::ClassElement::PrivateFieldDefinition(name, init)
+                            | function::ClassElement::PrivateAutoAccessorDefinition(name, init) => {
                                 if let Some(node) = init {
                                     if contains(node, ContainsSymbol::SuperCall) {
                                         return Err(Error::lex(LexError::Syntax(
@@ -472,7 +616,11 @@ where
                                     ));
                                 }
                             }
-                            function::ClassElement::PrivateStaticFieldDefinition(name, init) => {
+                            function::ClassElement::PrivateStaticFieldDefinition(name, init)
+                            | function::ClassElement::PrivateStaticAutoAccessorDefinition(
+                                name,
+                                init,
+                            ) => {
                                 if let Some(node) = init {
                                     if contains(node, ContainsSymbol::SuperCall) {
                                         return Err(Error::lex(LexError::Syntax(
@@ -505,7 +653,9 @@ where
                                 }
                             }
                             function::ClassElement::FieldDefinition(_, Some(node))
-                            | function::ClassElement::StaticFieldDefinition(_, Some(node)) => {
+                            | function::ClassElement::StaticFieldDefinition(_, Some(node))
+                            | function::ClassElement::AutoAccessorDefinition(_, Some(node))
+                            | function::ClassElement::StaticAutoAccessorDefinition(_, Some(node)) => {
                                 if contains(node, ContainsSymbol::SuperCall) {
                                     return Err(Error::lex(LexError::Syntax(
                                         "invalid super usage".into(),
@@ -576,6 +726,32 @@ where
 
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
         let token = cursor.peek(0, interner).or_abrupt()?;
+        if token.kind() == &TokenKind::Punctuator(Punctuator::At) {
+            let position = token.span().start();
+            let decorators =
+                DecoratorList::new(self.allow_yield, self.allow_await).parse(cursor, interner)?;
+            return match self.parse(cursor, interner)? {
+                (None, Some(function::ClassElement::StaticBlock(_))) => Err(Error::general(
+                    "decorators are not allowed on class static blocks",
+                    position,
+                )),
+                (None, Some(element)) => Ok((
+                    None,
+                    Some(function::ClassElement::Decorated(
+                        decorators,
+                        Box::new(element),
+                    )),
+                )),
+                (Some(_), _) => Err(Error::general(
+                    "decorators are not allowed on class constructors",
+                    position,
+                )),
+                (None, None) => Err(Error::general(
+                    "decorators must be followed by a class element",
+                    position,
+                )),
+            };
+        }
         let r#static = match token.kind() {
             TokenKind::Punctuator(Punctuator::Semicolon) => {
                 cursor.advance(interner);
@@ -1122,6 +1298,108 @@ where
                     }
                 }
             }
+            TokenKind::IdentifierName((Sym::ACCESSOR, ContainsEscapeSequence(false)))
+                if is_keyword =>
+            {
+                cursor.advance(interner);
+                let token = cursor.peek(0, interner).or_abrupt()?;
+                let name_position = token.span().start();
+                let is_line_terminator = cursor.peek_is_line_terminator(0, interner).or_abrupt()?;
+                let token = cursor.peek(0, interner).or_abrupt()?;
+                match token.kind() {
+                    // `accessor` followed by a line terminator is a field named "accessor".
+                    _ if is_line_terminator => {
+                        cursor.expect_semicolon("expected semicolon", interner)?;
+                        if r#static {
+                            function::ClassElement::StaticFieldDefinition(
+                                ast::property::PropertyName::Literal(Sym::ACCESSOR),
+                                None,
+                            )
+                        } else {
+                            function::ClassElement::FieldDefinition(
+                                ast::property::PropertyName::Literal(Sym::ACCESSOR),
+                                None,
+                            )
+                        }
+                    }
+                    TokenKind::PrivateIdentifier(Sym::CONSTRUCTOR) => {
+                        return Err(Error::general(
+                            "class constructor may not be a private method",
+                            name_position,
+                        ))
+                    }
+                    TokenKind::PrivateIdentifier(name) => {
+                        let name = *name;
+                        let name_private = interner.get_or_intern(
+                            [utf16!("#"), interner.resolve_expect(name).utf16()]
+                                .concat()
+                                .as_slice(),
+                        );
+                        cursor.advance(interner);
+                        let rhs =
+                            self.accessor_initializer(Some(name_private.into()), cursor, interner)?;
+                        if r#static {
+                            function::ClassElement::PrivateStaticAutoAccessorDefinition(
+                                PrivateName::new(name),
+                                rhs,
+                            )
+                        } else {
+                            function::ClassElement::PrivateAutoAccessorDefinition(
+                                PrivateName::new(name),
+                                rhs,
+                            )
+                        }
+                    }
+                    TokenKind::IdentifierName(_)
+                    | TokenKind::StringLiteral(_)
+                    | TokenKind::NumericLiteral(_)
+                    | TokenKind::Keyword(_)
+                    | TokenKind::NullLiteral(_)
+                    | TokenKind::Punctuator(Punctuator::OpenBracket) => {
+                        let name = PropertyName::new(self.allow_yield, self.allow_await)
+                            .parse(cursor, interner)?;
+                        if let Some(name) = name.literal() {
+                            if r#static {
+                                if [Sym::CONSTRUCTOR, Sym::PROTOTYPE].contains(&name) {
+                                    return Err(Error::general(
+                                        "class may not have static accessor definitions named 'constructor' or 'prototype'",
+                                        name_position,
+                                    ));
+                                }
+                            } else if name == Sym::CONSTRUCTOR {
+                                return Err(Error::general(
+                                    "class may not have accessor definitions named 'constructor'",
+                                    name_position,
+                                ));
+                            }
+                        }
+                        let rhs = self.accessor_initializer(
+                            name.literal().map(Into::into),
+                            cursor,
+                            interner,
+                        )?;
+                        if r#static {
+                            function::ClassElement::StaticAutoAccessorDefinition(name, rhs)
+                        } else {
+                            function::ClassElement::AutoAccessorDefinition(name, rhs)
+                        }
+                    }
+                    _ => {
+                        cursor.expect_semicolon("expected semicolon", interner)?;
+                        if r#static {
+                            function::ClassElement::StaticFieldDefinition(
+                                ast::property::PropertyName::Literal(Sym::ACCESSOR),
+                                None,
+                            )
+                        } else {
+                            function::ClassElement::FieldDefinition(
+                                ast::property::PropertyName::Literal(Sym::ACCESSOR),
+                                None,
+                            )
+                        }
+                    }
+                }
+            }
             TokenKind::PrivateIdentifier(Sym::CONSTRUCTOR) => {
                 return Err(Error::general(
                     "class constructor may not be a private method",
@@ -1338,7 +1616,11 @@ where
             function::ClassElement::FieldDefinition(_, Some(node))
             | function::ClassElement::StaticFieldDefinition(_, Some(node))
             | function::ClassElement::PrivateFieldDefinition(_, Some(node))
-            | function::ClassElement::PrivateStaticFieldDefinition(_, Some(node)) => {
+            | function::ClassElement::PrivateStaticFieldDefinition(_, Some(node))
+            | function::ClassElement::AutoAccessorDefinition(_, Some(node))
+            | function::ClassElement::StaticAutoAccessorDefinition(_, Some(node))
+            | function::ClassElement::PrivateAutoAccessorDefinition(_, Some(node))
+            | function::ClassElement::PrivateStaticAutoAccessorDefinition(_, Some(node)) => {
                 if contains_arguments(node) {
                     return Err(Error::general(
                         "'arguments' not allowed in class field definition",
@@ -1383,3 +1665,29 @@ where
         Ok((None, Some(element)))
     }
 }
+
+impl ClassElement {
+    /// Parses the optional initializer of an auto-accessor, followed by the ending semicolon.
+    fn accessor_initializer<R>(
+        self,
+        name: Option<Identifier>,
+        cursor: &mut Cursor<R>,
+        interner: &mut Interner,
+    ) -> ParseResult<Option<Expression>>
+    where
+        R: Read,
+    {
+        let rhs = if cursor.next_if(Punctuator::Assign, interner)?.is_some() {
+            let strict = cursor.strict();
+            cursor.set_strict(true);
+            let rhs = AssignmentExpression::new(name, true, self.allow_yield, self.allow_await)
+                .parse(cursor, interner)?;
+            cursor.set_strict(strict);
+            Some(rhs)
+        } else {
+            None
+        };
+        cursor.expect_semicolon("expected semicolon", interner)?;
+        Ok(rhs)
+    }
+}
@@ -1,4 +1,4 @@
-use crate::parser::tests::check_script_parser;
+use crate::parser::tests::{check_invalid_script, check_script_parser};
 use boa_ast::{
     declaration::{LexicalDeclaration, Variable, VariableList},
     expression::{
@@ -6,9 +6,11 @@ use boa_ast::{
         literal::Literal,
         Call, Identifier,
     },
-    function::{Class, ClassElement, FormalParameterList, Function, FunctionBody},
+    function::{
+        Class, ClassElement, Decorator, FormalParameterList, Function, FunctionBody, PrivateName,
+    },
     property::{MethodDefinition, PropertyName},
-    Declaration, Expression, Statement, StatementList, StatementListItem,
+    Declaration, Expression, NodeSpan, Statement, StatementList, StatementListItem,
 };
 use boa_interner::Interner;
 use boa_macros::utf16;
@@ -102,7 +104,7 @@ fn check_new_target_with_property_access() {
 
     let new_target = Expression::PropertyAccess(
         SimplePropertyAccess::new(
-            Expression::NewTarget,
+            Expression::NewTarget(NodeSpan::none()),
             interner.get_or_intern_static("name", utf16!("name")),
         )
         .into(),
@@ -171,3 +173,104 @@ fn check_new_target_with_property_access() {
         interner,
     );
 }
+
+#[test]
+fn check_decorators() {
+    let interner = &mut Interner::default();
+    let dec = interner.get_or_intern_static("dec", utf16!("dec"));
+    let ns = interner.get_or_intern_static("ns", utf16!("ns"));
+    let method = interner.get_or_intern_static("method", utf16!("method"));
+
+    let elements = vec![ClassElement::Decorated(
+        vec![
+            Decorator::new(Identifier::new(dec).into()),
+            Decorator::new(
+                Call::new(
+                    PropertyAccess::Simple(SimplePropertyAccess::new(
+                        Identifier::new(ns).into(),
+                        dec,
+                    ))
+                    .into(),
+                    vec![Literal::from(1).into()].into(),
+                )
+                .into(),
+            ),
+        ]
+        .into(),
+        Box::new(ClassElement::MethodDefinition(
+            PropertyName::Literal(method),
+            MethodDefinition::Ordinary(Function::new(
+                None,
+                FormalParameterList::default(),
+                FunctionBody::default(),
+            )),
+        )),
+    )];
+
+    check_script_parser(
+        "@dec class A {
+            @dec @ns.dec(1) method() { }
+         }
+        ",
+        [Declaration::Class(
+            Class::new(
+                Some(interner.get_or_intern_static("A", utf16!("A")).into()),
+                None,
+                None,
+                elements.into(),
+                true,
+            )
+            .with_decorators(vec![Decorator::new(Identifier::new(dec).into())].into()),
+        )
+        .into()],
+        interner,
+    );
+}
+
+#[test]
+fn check_auto_accessors() {
+    let interner = &mut Interner::default();
+    let x = interner.get_or_intern_static("x", utf16!("x"));
+    let y = interner.get_or_intern_static("y", utf16!("y"));
+    let accessor = interner.get_or_intern_static("accessor", utf16!("accessor"));
+
+    let elements = vec![
+        ClassElement::AutoAccessorDefinition(
+            PropertyName::Literal(x),
+            Some(Literal::from(1).into()),
+        ),
+        ClassElement::PrivateStaticAutoAccessorDefinition(PrivateName::new(y), None),
+        ClassElement::FieldDefinition(PropertyName::Literal(accessor), None),
+        ClassElement::FieldDefinition(PropertyName::Literal(x), None),
+    ];
+
+    check_script_parser(
+        "class A {
+            accessor x = 1;
+            static accessor #y;
+            accessor
+            x
+         }
+        ",
+        [Declaration::Class(Class::new(
+            Some(interner.get_or_intern_static("A", utf16!("A")).into()),
+            None,
+            None,
+            elements.into(),
+            true,
+        ))
+        .into()],
+        interner,
+    );
+}
+
+#[test]
+fn check_invalid_decorators() {
+    check_invalid_script("class A { @dec constructor() {} }");
+    check_invalid_script("class A { @dec static {} }");
+    check_invalid_script("class A { @dec; }");
+    check_invalid_script("@dec let x = 1;");
+    check_invalid_script("@dec.[x] class A {}");
+    check_invalid_script("@dec() () class A {}");
+    check_invalid_script("class A { accessor constructor; }");
+}
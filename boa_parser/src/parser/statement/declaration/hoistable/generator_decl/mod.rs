@@ -5,7 +5,7 @@ use crate::parser::{
     statement::declaration::hoistable::{parse_callable_declaration, CallableDeclaration},
     AllowAwait, AllowDefault, AllowYield, Cursor, ParseResult, TokenParser,
 };
-use boa_ast::{function::Generator, Keyword, Punctuator};
+use boa_ast::{function::Generator, Keyword, Punctuator, Span};
 use boa_interner::Interner;
 use std::io::Read;
 
@@ -77,15 +77,19 @@ where
     type Output = Generator;
 
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
-        cursor.expect(
-            (Keyword::Function, false),
-            "generator declaration",
-            interner,
-        )?;
+        let start = cursor
+            .expect(
+                (Keyword::Function, false),
+                "generator declaration",
+                interner,
+            )?
+            .span()
+            .start();
         cursor.expect(Punctuator::Mul, "generator declaration", interner)?;
 
         let result = parse_callable_declaration(&self, cursor, interner)?;
 
-        Ok(Generator::new(Some(result.0), result.1, result.2, false))
+        Ok(Generator::new(Some(result.0), result.1, result.2, false)
+            .with_span(Span::new(start, cursor.last_token_end())))
     }
 }
@@ -13,7 +13,10 @@ use crate::{
     lexer::TokenKind,
     parser::{
         cursor::Cursor,
-        statement::{declaration::FromClause, BindingIdentifier},
+        statement::{
+            declaration::{FromClause, WithClause},
+            BindingIdentifier,
+        },
         Error, OrAbrupt, ParseResult, TokenParser,
     },
 };
@@ -23,7 +26,7 @@ use boa_ast::{
         ImportSpecifier as AstImportSpecifier, ModuleSpecifier,
     },
     expression::Identifier,
-    Keyword, Punctuator,
+    Keyword, Punctuator, Span,
 };
 use boa_interner::{Interner, Sym};
 use boa_profiler::Profiler;
@@ -78,7 +81,10 @@ where
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
         let _timer = Profiler::global().start_event("ImportDeclaration", "Parsing");
 
-        cursor.expect((Keyword::Import, false), "import declaration", interner)?;
+        let start = cursor
+            .expect((Keyword::Import, false), "import declaration", interner)?
+            .span()
+            .start();
 
         let tok = cursor.peek(0, interner).or_abrupt()?;
 
@@ -87,13 +93,16 @@ where
                 let module_identifier = *module_identifier;
 
                 cursor.advance(interner);
+                let attributes = WithClause::new("import declaration").parse(cursor, interner)?;
                 cursor.expect_semicolon("import declaration", interner)?;
 
                 return Ok(AstImportDeclaration::new(
                     None,
                     ImportKind::DefaultOrUnnamed,
                     ModuleSpecifier::new(module_identifier),
-                ));
+                )
+                .with_attributes(attributes)
+                .with_span(Span::new(start, cursor.last_token_end())));
             }
             TokenKind::Punctuator(Punctuator::OpenBlock) => {
                 let list = NamedImports.parse(cursor, interner)?;
@@ -155,8 +164,13 @@ where
         };
 
         let module_identifier = FromClause::new("import declaration").parse(cursor, interner)?;
+        let attributes = WithClause::new("import declaration").parse(cursor, interner)?;
+        cursor.expect_semicolon("import declaration", interner)?;
 
-        Ok(import_clause.with_specifier(module_identifier))
+        Ok(import_clause
+            .with_specifier(module_identifier)
+            .with_attributes(attributes)
+            .with_span(Span::new(start, cursor.last_token_end())))
     }
 }
 
@@ -1,14 +1,16 @@
 //! Lexical declaration parsing.
 //!
-//! This parses `let` and `const` declarations.
+//! This parses `let`, `const`, `using` and `await using` declarations.
 //!
 //! More information:
 //!  - [ECMAScript specification][spec]
+//!  - [Explicit Resource Management proposal][proposal]
 //!
 //! [spec]: https://tc39.es/ecma262/#sec-let-and-const-declarations
+//! [proposal]: https://tc39.es/proposal-explicit-resource-management/#sec-let-const-using-and-await-using-declarations
 
 use crate::{
-    lexer::{Error as LexError, TokenKind},
+    lexer::{Error as LexError, Token, TokenKind},
     parser::{
         cursor::{Cursor, SemicolonResult},
         expression::Initializer,
@@ -18,7 +20,12 @@ use crate::{
     Error,
 };
 use ast::operations::bound_names;
-use boa_ast::{self as ast, declaration::Variable, pattern::Pattern, Keyword, Punctuator};
+use boa_ast::{
+    self as ast,
+    declaration::{Binding, Variable},
+    pattern::Pattern,
+    Keyword, Punctuator, Span,
+};
 use boa_interner::{Interner, Sym};
 use boa_profiler::Profiler;
 use std::io::Read;
@@ -69,44 +76,138 @@ where
         let _timer = Profiler::global().start_event("LexicalDeclaration", "Parsing");
         let tok = cursor.next(interner).or_abrupt()?;
 
-        let lexical_declaration = match tok.kind() {
-            TokenKind::Keyword((Keyword::Const | Keyword::Let, true)) => {
+        let kind = match tok.kind() {
+            TokenKind::Keyword((Keyword::Const | Keyword::Let | Keyword::Await, true)) => {
                 return Err(Error::general(
                     "Keyword must not contain escaped characters",
                     tok.span().start(),
                 ))
             }
-            TokenKind::Keyword((Keyword::Const, false)) => BindingList::new(
-                self.allow_in,
-                self.allow_yield,
-                self.allow_await,
-                true,
-                self.loop_init,
-            )
-            .parse(cursor, interner)?,
-            TokenKind::Keyword((Keyword::Let, false)) => BindingList::new(
-                self.allow_in,
-                self.allow_yield,
-                self.allow_await,
-                false,
-                self.loop_init,
-            )
-            .parse(cursor, interner)?,
+            TokenKind::Keyword((Keyword::Const, false)) => BindingListKind::Const,
+            TokenKind::Keyword((Keyword::Let, false)) => BindingListKind::Let,
+            TokenKind::IdentifierName((Sym::USING, _)) => BindingListKind::Using,
+            TokenKind::Keyword((Keyword::Await, false)) => {
+                cursor.advance(interner);
+                BindingListKind::AwaitUsing
+            }
             _ => unreachable!("unknown token found: {:?}", tok),
         };
 
+        let lexical_declaration = BindingList::new(
+            self.allow_in,
+            self.allow_yield,
+            self.allow_await,
+            kind,
+            self.loop_init,
+        )
+        .parse(cursor, interner)?;
+
         if !self.loop_init {
             cursor.expect_semicolon("lexical declaration", interner)?;
         }
 
-        Ok(lexical_declaration)
+        let span = Span::new(tok.span().start(), cursor.last_token_end());
+        Ok(match lexical_declaration {
+            ast::declaration::LexicalDeclaration::Const(list) => {
+                ast::declaration::LexicalDeclaration::Const(list.with_span(span))
+            }
+            ast::declaration::LexicalDeclaration::Let(list) => {
+                ast::declaration::LexicalDeclaration::Let(list.with_span(span))
+            }
+            ast::declaration::LexicalDeclaration::Using(list) => {
+                ast::declaration::LexicalDeclaration::Using(list.with_span(span))
+            }
+            ast::declaration::LexicalDeclaration::AwaitUsing(list) => {
+                ast::declaration::LexicalDeclaration::AwaitUsing(list.with_span(span))
+            }
+        })
+    }
+}
+
+/// Checks if the next tokens start a `using` or an `await using` declaration.
+///
+/// Since `using` is not a reserved word, a `using` declaration is only recognized if `using` is
+/// followed by a binding identifier on the same line. The same applies to `await using`, which
+/// additionally requires `await` and `using` to be on the same line and is only recognized
+/// where `await` expressions are allowed.
+///
+/// More information:
+///  - [Explicit Resource Management proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-explicit-resource-management/#prod-UsingDeclaration
+pub(in crate::parser) fn is_using_declaration<R: Read>(
+    cursor: &mut Cursor<R>,
+    allow_await: AllowAwait,
+    interner: &mut Interner,
+) -> ParseResult<bool> {
+    let skip_n = usize::from(
+        cursor
+            .peek_is_line_terminator(0, interner)?
+            .unwrap_or(false),
+    );
+
+    let using_index = match cursor.peek(0, interner)?.map(Token::kind) {
+        Some(TokenKind::IdentifierName((Sym::USING, _))) => 0,
+        Some(TokenKind::Keyword((Keyword::Await, _))) if allow_await.0 => {
+            if cursor
+                .peek_is_line_terminator(skip_n + 1, interner)?
+                .unwrap_or(true)
+            {
+                return Ok(false);
+            }
+            match cursor.peek(1, interner)?.map(Token::kind) {
+                Some(TokenKind::IdentifierName((Sym::USING, _))) => 1,
+                _ => return Ok(false),
+            }
+        }
+        _ => return Ok(false),
+    };
+
+    if cursor
+        .peek_is_line_terminator(skip_n + using_index + 1, interner)?
+        .unwrap_or(true)
+    {
+        return Ok(false);
+    }
+
+    Ok(matches!(
+        cursor.peek(using_index + 1, interner)?.map(Token::kind),
+        Some(
+            TokenKind::IdentifierName(_)
+                | TokenKind::Keyword((
+                    Keyword::Async | Keyword::Await | Keyword::Let | Keyword::Yield,
+                    _
+                ))
+        )
+    ))
+}
+
+/// The kind of lexical declaration a [`BindingList`] belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BindingListKind {
+    Let,
+    Const,
+    Using,
+    AwaitUsing,
+}
+
+impl BindingListKind {
+    /// Returns the keyword used to introduce the declaration, for error messages.
+    const fn as_str(self) -> &'static str {
+        match self {
+            Self::Let => "let",
+            Self::Const => "const",
+            Self::Using => "using",
+            Self::AwaitUsing => "await using",
+        }
     }
 }
 
 /// Parses a binding list.
 ///
-/// It will return an error if a `const` declaration is being parsed and there is no
-/// initializer.
+/// It will return an error if a `const`, `using` or `await using` declaration is being parsed
+/// and there is no initializer, or if a `using` or `await using` declaration contains a binding
+/// pattern.
 ///
 /// More information:
 ///  - [ECMAScript specification][spec]
@@ -117,7 +218,7 @@ struct BindingList {
     allow_in: AllowIn,
     allow_yield: AllowYield,
     allow_await: AllowAwait,
-    is_const: bool,
+    kind: BindingListKind,
     loop_init: bool,
 }
 
@@ -127,7 +228,7 @@ impl BindingList {
         allow_in: I,
         allow_yield: Y,
         allow_await: A,
-        is_const: bool,
+        kind: BindingListKind,
         loop_init: bool,
     ) -> Self
     where
@@ -139,7 +240,7 @@ impl BindingList {
             allow_in: allow_in.into(),
             allow_yield: allow_yield.into(),
             allow_await: allow_await.into(),
-            is_const,
+            kind,
             loop_init,
         }
     }
@@ -159,23 +260,35 @@ where
         let mut decls = Vec::new();
 
         loop {
+            let position = cursor.peek(0, interner).or_abrupt()?.span().start();
             let decl = LexicalBinding::new(self.allow_in, self.allow_yield, self.allow_await)
                 .parse(cursor, interner)?;
 
-            if self.is_const {
-                let init_is_some = decl.init().is_some();
+            if matches!(
+                self.kind,
+                BindingListKind::Using | BindingListKind::AwaitUsing
+            ) && matches!(decl.binding(), Binding::Pattern(_))
+            {
+                return Err(Error::general(
+                    format!(
+                        "binding patterns are not allowed in {} declarations",
+                        self.kind.as_str()
+                    ),
+                    position,
+                ));
+            }
 
-                if init_is_some || self.loop_init {
-                    decls.push(decl);
-                } else {
-                    let next = cursor.next(interner).or_abrupt()?;
-                    return Err(Error::general(
-                        "Expected initializer for const declaration",
-                        next.span().start(),
-                    ));
-                }
-            } else {
+            if self.kind == BindingListKind::Let || decl.init().is_some() || self.loop_init {
                 decls.push(decl);
+            } else {
+                let next = cursor.next(interner).or_abrupt()?;
+                return Err(Error::general(
+                    format!(
+                        "Expected initializer for {} declaration",
+                        self.kind.as_str()
+                    ),
+                    next.span().start(),
+                ));
             }
 
             match cursor.peek_semicolon(interner)? {
@@ -218,11 +331,12 @@ where
             .try_into()
             .expect("`LexicalBinding` must return at least one variable");
 
-        if self.is_const {
-            Ok(ast::declaration::LexicalDeclaration::Const(decls))
-        } else {
-            Ok(ast::declaration::LexicalDeclaration::Let(decls))
-        }
+        Ok(match self.kind {
+            BindingListKind::Let => ast::declaration::LexicalDeclaration::Let(decls),
+            BindingListKind::Const => ast::declaration::LexicalDeclaration::Const(decls),
+            BindingListKind::Using => ast::declaration::LexicalDeclaration::Using(decls),
+            BindingListKind::AwaitUsing => ast::declaration::LexicalDeclaration::AwaitUsing(decls),
+        })
     }
 }
 
@@ -293,7 +407,8 @@ where
                     )));
                 }
 
-                Ok(Variable::from_pattern(declaration, init))
+                Ok(Variable::from_pattern(declaration, init)
+                    .with_span(Span::new(position, cursor.last_token_end())))
             }
             TokenKind::Punctuator(Punctuator::OpenBracket) => {
                 let bindings = ArrayBindingPattern::new(self.allow_yield, self.allow_await)
@@ -321,11 +436,13 @@ where
                     )));
                 }
 
-                Ok(Variable::from_pattern(declaration, init))
+                Ok(Variable::from_pattern(declaration, init)
+                    .with_span(Span::new(position, cursor.last_token_end())))
             }
             _ => {
                 let ident = BindingIdentifier::new(self.allow_yield, self.allow_await)
                     .parse(cursor, interner)?;
+                let binding = Binding::Identifier(ident, cursor.last_token_span().into());
 
                 if ident == Sym::LET {
                     return Err(Error::lex(LexError::Syntax(
@@ -351,7 +468,8 @@ where
                 } else {
                     None
                 };
-                Ok(Variable::from_identifier(ident, init))
+                Ok(Variable::from_binding(binding, init)
+                    .with_span(Span::new(position, cursor.last_token_end())))
             }
         }
     }
@@ -17,17 +17,18 @@ mod tests;
 pub(in crate::parser) use self::{
     export::ExportDeclaration,
     hoistable::{
-        class_decl::ClassTail, ClassDeclaration, FunctionDeclaration, HoistableDeclaration,
+        class_decl::{ClassTail, DecoratorList},
+        ClassDeclaration, FunctionDeclaration, HoistableDeclaration,
     },
     import::ImportDeclaration,
-    lexical::LexicalDeclaration,
+    lexical::{is_using_declaration, LexicalDeclaration},
 };
 use crate::{
     lexer::TokenKind,
     parser::{AllowAwait, AllowYield, Cursor, OrAbrupt, ParseResult, TokenParser},
     Error,
 };
-use boa_ast::{self as ast, Keyword};
+use boa_ast::{self as ast, Keyword, Punctuator};
 use boa_interner::{Interner, Sym};
 use boa_profiler::Profiler;
 use std::io::Read;
@@ -70,11 +71,13 @@ where
         let tok = cursor.peek(0, interner).or_abrupt()?;
 
         match tok.kind() {
-            TokenKind::Keyword((Keyword::Function | Keyword::Async | Keyword::Class, _)) => {
+            TokenKind::Keyword((Keyword::Function | Keyword::Async | Keyword::Class, _))
+            | TokenKind::Punctuator(Punctuator::At) => {
                 HoistableDeclaration::new(self.allow_yield, self.allow_await, false)
                     .parse(cursor, interner)
             }
-            TokenKind::Keyword((Keyword::Const | Keyword::Let, _)) => {
+            TokenKind::Keyword((Keyword::Const | Keyword::Let | Keyword::Await, _))
+            | TokenKind::IdentifierName((Sym::USING, _)) => {
                 LexicalDeclaration::new(true, self.allow_yield, self.allow_await, false)
                     .parse(cursor, interner)
                     .map(Into::into)
@@ -133,9 +136,117 @@ where
                 tok.to_string(interner),
                 tok.span(),
                 self.context,
-            ))
+            ));
         };
 
         Ok((*from).into())
     }
 }
+
+/// Parses an optional `with` clause, containing the import attributes of a module request.
+///
+/// Returns an empty list of attributes if the next token is not `with`.
+///
+/// More information:
+///  - [Import attributes proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-import-attributes/#prod-WithClause
+#[derive(Debug, Clone, Copy)]
+struct WithClause {
+    context: &'static str,
+}
+
+impl WithClause {
+    /// Creates a new `with` clause parser
+    #[inline]
+    const fn new(context: &'static str) -> Self {
+        Self { context }
+    }
+}
+
+impl<R> TokenParser<R> for WithClause
+where
+    R: Read,
+{
+    type Output = Box<[ast::declaration::ImportAttribute]>;
+
+    fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
+        let _timer = Profiler::global().start_event("WithClause", "Parsing");
+
+        match cursor.peek(0, interner)? {
+            Some(tok) if tok.kind() == &TokenKind::Keyword((Keyword::With, false)) => {
+                cursor.advance(interner);
+            }
+            _ => return Ok(Box::default()),
+        }
+
+        cursor.expect(Punctuator::OpenBlock, self.context, interner)?;
+
+        let mut attributes: Vec<ast::declaration::ImportAttribute> = Vec::new();
+
+        loop {
+            let tok = cursor.next(interner).or_abrupt()?;
+
+            let key = match tok.kind() {
+                TokenKind::Punctuator(Punctuator::CloseBlock) => break,
+                TokenKind::IdentifierName((name, _)) | TokenKind::StringLiteral((name, _)) => *name,
+                TokenKind::Keyword((kw, _)) => kw.to_sym(),
+                TokenKind::BooleanLiteral((true, _)) => Sym::TRUE,
+                TokenKind::BooleanLiteral((false, _)) => Sym::FALSE,
+                TokenKind::NullLiteral(_) => Sym::NULL,
+                _ => {
+                    return Err(Error::expected(
+                        [
+                            Punctuator::CloseBlock.to_string(),
+                            "string literal".to_owned(),
+                            "identifier".to_owned(),
+                        ],
+                        tok.to_string(interner),
+                        tok.span(),
+                        self.context,
+                    ))
+                }
+            };
+
+            if attributes.iter().any(|attribute| attribute.key() == key) {
+                return Err(Error::general(
+                    "duplicate import attribute key",
+                    tok.span().start(),
+                ));
+            }
+
+            cursor.expect(Punctuator::Colon, self.context, interner)?;
+
+            let tok = cursor.next(interner).or_abrupt()?;
+            let TokenKind::StringLiteral((value, _)) = tok.kind() else {
+                return Err(Error::expected(
+                    ["string literal".to_owned()],
+                    tok.to_string(interner),
+                    tok.span(),
+                    self.context,
+                ));
+            };
+
+            attributes.push(ast::declaration::ImportAttribute::new(key, *value));
+
+            let tok = cursor.next(interner).or_abrupt()?;
+            match tok.kind() {
+                TokenKind::Punctuator(Punctuator::Comma) => {}
+                TokenKind::Punctuator(Punctuator::CloseBlock) => break,
+                _ => {
+                    return Err(Error::expected(
+                        [
+                            Punctuator::Comma.to_string(),
+                            Punctuator::CloseBlock.to_string(),
+                        ],
+                        tok.to_string(interner),
+                        tok.span(),
+                        self.context,
+                    ))
+                }
+            }
+        }
+
+        Ok(attributes.into_boxed_slice())
+    }
+}
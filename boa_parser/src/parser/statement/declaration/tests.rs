@@ -1,7 +1,7 @@
 use crate::parser::tests::{check_invalid_script, check_script_parser};
 use boa_ast::{
     declaration::{LexicalDeclaration, VarDeclaration, Variable},
-    expression::literal::Literal,
+    expression::{literal::Literal, Identifier},
     Declaration, Statement,
 };
 use boa_interner::{Interner, Sym};
@@ -349,3 +349,57 @@ fn multiple_const_declaration() {
         interner,
     );
 }
+
+/// Checks `using` declaration parsing.
+#[test]
+fn using_declaration() {
+    let interner = &mut Interner::default();
+    check_script_parser(
+        "{ using a = b; }",
+        vec![Statement::Block(
+            vec![Declaration::Lexical(LexicalDeclaration::Using(
+                vec![Variable::from_identifier(
+                    interner.get_or_intern_static("a", utf16!("a")).into(),
+                    Some(Identifier::new(interner.get_or_intern_static("b", utf16!("b"))).into()),
+                )]
+                .try_into()
+                .unwrap(),
+            ))
+            .into()]
+            .into(),
+        )
+        .into()],
+        interner,
+    );
+}
+
+/// Checks that `using` can still be used as an identifier.
+#[test]
+fn using_identifier() {
+    let interner = &mut Interner::default();
+    check_script_parser(
+        "var using = 5;",
+        vec![Statement::Var(VarDeclaration(
+            vec![Variable::from_identifier(
+                interner
+                    .get_or_intern_static("using", utf16!("using"))
+                    .into(),
+                Some(Literal::from(5).into()),
+            )]
+            .try_into()
+            .unwrap(),
+        ))
+        .into()],
+        interner,
+    );
+}
+
+/// Checks invalid `using` declarations.
+#[test]
+fn invalid_using_declaration() {
+    check_invalid_script("using a = b;");
+    check_invalid_script("{ using a; }");
+    check_invalid_script("{ using {a} = b; }");
+    check_invalid_script("switch (x) { case 1: using a = b; }");
+    check_invalid_script("for (using a in b) {}");
+}
@@ -12,7 +12,7 @@ use crate::{
 };
 use boa_ast::{
     statement::{Block, If},
-    Declaration, Keyword, Punctuator, StatementListItem,
+    Declaration, Keyword, Punctuator, Span, StatementListItem,
 };
 use boa_interner::Interner;
 use boa_profiler::Profiler;
@@ -60,7 +60,10 @@ where
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
         let _timer = Profiler::global().start_event("IfStatement", "Parsing");
 
-        cursor.expect((Keyword::If, false), "if statement", interner)?;
+        let start = cursor
+            .expect((Keyword::If, false), "if statement", interner)?
+            .span()
+            .start();
         cursor.expect(Punctuator::OpenParen, "if statement", interner)?;
 
         let condition = Expression::new(None, true, self.allow_yield, self.allow_await)
@@ -154,6 +157,7 @@ where
             None
         };
 
-        Ok(If::new(condition, then_node, else_stmt))
+        Ok(If::new(condition, then_node, else_stmt)
+            .with_span(Span::new(start, cursor.last_token_end())))
     }
 }
@@ -15,7 +15,7 @@ use crate::{
     },
     Error,
 };
-use boa_ast::{statement::DoWhileLoop, Keyword, Punctuator};
+use boa_ast::{statement::DoWhileLoop, Keyword, Punctuator, Span};
 use boa_interner::Interner;
 use boa_profiler::Profiler;
 use std::io::Read;
@@ -64,7 +64,10 @@ where
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
         let _timer = Profiler::global().start_event("DoWhileStatement", "Parsing");
 
-        cursor.expect((Keyword::Do, false), "do while statement", interner)?;
+        let start = cursor
+            .expect((Keyword::Do, false), "do while statement", interner)?
+            .span()
+            .start();
 
         let position = cursor.peek(0, interner).or_abrupt()?.span().start();
 
@@ -113,6 +116,6 @@ where
             cursor.advance(interner);
         }
 
-        Ok(DoWhileLoop::new(body, cond))
+        Ok(DoWhileLoop::new(body, cond).with_span(Span::new(start, cursor.last_token_end())))
     }
 }
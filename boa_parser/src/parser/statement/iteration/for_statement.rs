@@ -11,7 +11,7 @@ use crate::{
     lexer::{Error as LexError, TokenKind},
     parser::{
         expression::{AssignmentExpression, Expression},
-        statement::declaration::LexicalDeclaration,
+        statement::declaration::{is_using_declaration, LexicalDeclaration},
         statement::{variable::VariableDeclarationList, Statement},
         AllowAwait, AllowReturn, AllowYield, Cursor, OrAbrupt, ParseResult, TokenParser,
     },
@@ -27,7 +27,7 @@ use boa_ast::{
         iteration::{ForLoopInitializer, IterableLoopInitializer},
         ForInLoop, ForLoop, ForOfLoop,
     },
-    Keyword, Position, Punctuator,
+    Keyword, Position, Punctuator, Span,
 };
 use boa_interner::{Interner, Sym};
 use boa_profiler::Profiler;
@@ -77,7 +77,10 @@ where
 
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
         let _timer = Profiler::global().start_event("ForStatement", "Parsing");
-        cursor.expect((Keyword::For, false), "for statement", interner)?;
+        let start = cursor
+            .expect((Keyword::For, false), "for statement", interner)?
+            .span()
+            .start();
 
         let mut r#await = false;
 
@@ -107,6 +110,7 @@ where
             }
         };
 
+        let is_using = is_using_declaration(cursor, self.allow_await, interner)?;
         let init = match cursor.peek(0, interner).or_abrupt()?.kind() {
             TokenKind::Keyword((Keyword::Var, _)) => {
                 cursor.advance(interner);
@@ -120,8 +124,12 @@ where
                 if !cursor.strict() {
                     if let Some(token) = cursor.peek(1, interner)? {
                         if token.kind() == &TokenKind::Keyword((Keyword::In, false)) {
-                            cursor.advance(interner);
-                            break 'exit boa_ast::Expression::Identifier(Sym::LET.into()).into();
+                            let span = cursor.next(interner).or_abrupt()?.span();
+                            break 'exit boa_ast::Expression::Identifier(
+                                Sym::LET.into(),
+                                span.into(),
+                            )
+                            .into();
                         }
                     }
                 }
@@ -135,6 +143,16 @@ where
                     .parse(cursor, interner)?
                     .into(),
             ),
+            TokenKind::IdentifierName((Sym::USING, _))
+            | TokenKind::Keyword((Keyword::Await, _))
+                if is_using =>
+            {
+                Some(
+                    LexicalDeclaration::new(false, self.allow_yield, self.allow_await, true)
+                        .parse(cursor, interner)?
+                        .into(),
+                )
+            }
             TokenKind::Keyword((Keyword::Async, false)) if !r#await => {
                 match cursor.peek(1, interner).or_abrupt()?.kind() {
                     TokenKind::Keyword((Keyword::Of, _)) => {
@@ -206,7 +224,10 @@ where
                 // Checks are only applicable to lexical bindings.
                 if matches!(
                     &init,
-                    IterableLoopInitializer::Const(_) | IterableLoopInitializer::Let(_)
+                    IterableLoopInitializer::Const(_)
+                        | IterableLoopInitializer::Let(_)
+                        | IterableLoopInitializer::Using(_)
+                        | IterableLoopInitializer::AwaitUsing(_)
                 ) {
                     // It is a Syntax Error if the BoundNames of ForDeclaration contains "let".
                     // It is a Syntax Error if any element of the BoundNames of ForDeclaration also occurs in the VarDeclaredNames of Statement.
@@ -234,26 +255,35 @@ where
                         }
                     }
                 }
+                let span = Span::new(start, cursor.last_token_end());
                 return Ok(if in_loop {
-                    ForInLoop::new(init, expr, body).into()
+                    ForInLoop::new(init, expr, body).with_span(span).into()
                 } else {
-                    ForOfLoop::new(init, expr, body, r#await).into()
+                    ForOfLoop::new(init, expr, body, r#await)
+                        .with_span(span)
+                        .into()
                 });
             }
             (init, _) => init,
         };
 
-        if let Some(ForLoopInitializer::Lexical(ast::declaration::LexicalDeclaration::Const(
-            ref list,
-        ))) = init
-        {
-            for decl in list.as_ref() {
-                if decl.init().is_none() {
-                    return Err(Error::general(
-                        "Expected initializer for const declaration",
-                        position,
-                    ));
-                }
+        if let Some(ForLoopInitializer::Lexical(ref decl)) = init {
+            if decl.is_const()
+                && decl
+                    .variable_list()
+                    .as_ref()
+                    .iter()
+                    .any(|var| var.init().is_none())
+            {
+                let kind = match decl {
+                    ast::declaration::LexicalDeclaration::Using(_) => "using",
+                    ast::declaration::LexicalDeclaration::AwaitUsing(_) => "await using",
+                    _ => "const",
+                };
+                return Err(Error::general(
+                    format!("Expected initializer for {kind} declaration"),
+                    position,
+                ));
             }
         }
 
@@ -306,7 +336,9 @@ where
             }
         }
 
-        Ok(ForLoop::new(init, cond, step, body).into())
+        Ok(ForLoop::new(init, cond, step, body)
+            .with_span(Span::new(start, cursor.last_token_end()))
+            .into())
     }
 }
 
@@ -323,7 +355,7 @@ fn initializer_to_iterable_loop_initializer(
                 expr = p.expression().clone();
             }
             match expr {
-                ast::Expression::Identifier(ident)
+                ast::Expression::Identifier(ident, _)
                     if strict && [Sym::EVAL, Sym::ARGUMENTS].contains(&ident.sym()) =>
                 {
                     Err(Error::lex(LexError::Syntax(
@@ -332,8 +364,8 @@ fn initializer_to_iterable_loop_initializer(
                         position,
                     )))
                 }
-                ast::Expression::Identifier(ident) => {
-                    Ok(IterableLoopInitializer::Identifier(ident))
+                ast::Expression::Identifier(ident, span) => {
+                    Ok(IterableLoopInitializer::Identifier(ident, span))
                 }
                 ast::Expression::ArrayLiteral(array) => array
                     .to_pattern(strict)
@@ -378,6 +410,22 @@ fn initializer_to_iterable_loop_initializer(
                     ast::declaration::LexicalDeclaration::Let(_) => {
                         IterableLoopInitializer::Let(declaration.binding().clone())
                     }
+                    ast::declaration::LexicalDeclaration::Using(_)
+                    | ast::declaration::LexicalDeclaration::AwaitUsing(_)
+                        if in_loop =>
+                    {
+                        return Err(Error::lex(LexError::Syntax(
+                            "a using declaration is not allowed in the head of a for-in loop"
+                                .into(),
+                            position,
+                        )));
+                    }
+                    ast::declaration::LexicalDeclaration::Using(_) => {
+                        IterableLoopInitializer::Using(declaration.binding().clone())
+                    }
+                    ast::declaration::LexicalDeclaration::AwaitUsing(_) => {
+                        IterableLoopInitializer::AwaitUsing(declaration.binding().clone())
+                    }
                 })
             }
             _ => Err(Error::lex(LexError::Syntax(
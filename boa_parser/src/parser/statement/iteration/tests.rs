@@ -5,10 +5,7 @@ use boa_ast::{
         access::SimplePropertyAccess,
         literal::Literal,
         operator::{
-            assign::AssignOp,
-            binary::RelationalOp,
-            update::{UpdateOp, UpdateTarget},
-            Assign, Binary, Update,
+            assign::AssignOp, binary::RelationalOp, update::UpdateOp, Assign, Binary, Update,
         },
         Call, Identifier,
     },
@@ -88,9 +85,7 @@ fn check_do_while_semicolon_insertion() {
                     RelationalOp::LessThan.into(),
                     Update::new(
                         UpdateOp::IncrementPost,
-                        UpdateTarget::Identifier(Identifier::new(
-                            interner.get_or_intern_static("i", utf16!("i")),
-                        )),
+                        Identifier::new(interner.get_or_intern_static("i", utf16!("i"))).into(),
                     )
                     .into(),
                     Literal::from(10).into(),
@@ -163,9 +158,7 @@ fn check_do_while_semicolon_insertion_no_space() {
                     RelationalOp::LessThan.into(),
                     Update::new(
                         UpdateOp::IncrementPost,
-                        UpdateTarget::Identifier(Identifier::new(
-                            interner.get_or_intern_static("i", utf16!("i")),
-                        )),
+                        Identifier::new(interner.get_or_intern_static("i", utf16!("i"))).into(),
                     )
                     .into(),
                     Literal::from(10).into(),
@@ -241,7 +234,7 @@ fn do_while_spaces() {
                 Break::new(None),
             ))])
             .into(),
-            Literal::Bool(true).into(),
+            Literal::from(true).into(),
         ))
         .into()],
         &mut Interner::default(),
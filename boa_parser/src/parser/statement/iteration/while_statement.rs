@@ -5,7 +5,7 @@ use crate::{
     },
     Error,
 };
-use boa_ast::{statement::WhileLoop, Keyword, Punctuator};
+use boa_ast::{statement::WhileLoop, Keyword, Punctuator, Span};
 use boa_interner::Interner;
 use boa_profiler::Profiler;
 use std::io::Read;
@@ -53,7 +53,10 @@ where
 
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
         let _timer = Profiler::global().start_event("WhileStatement", "Parsing");
-        cursor.expect((Keyword::While, false), "while statement", interner)?;
+        let start = cursor
+            .expect((Keyword::While, false), "while statement", interner)?
+            .span()
+            .start();
 
         cursor.expect(Punctuator::OpenParen, "while statement", interner)?;
 
@@ -72,6 +75,6 @@ where
             return Err(Error::wrong_labelled_function_declaration(position));
         }
 
-        Ok(WhileLoop::new(cond, body))
+        Ok(WhileLoop::new(cond, body).with_span(Span::new(start, cursor.last_token_end())))
     }
 }
@@ -8,7 +8,7 @@ use crate::{
     },
     Error,
 };
-use boa_ast::{self as ast, Keyword, Punctuator};
+use boa_ast::{self as ast, Keyword, Punctuator, Span};
 use boa_interner::Interner;
 use boa_profiler::Profiler;
 use std::io::Read;
@@ -52,6 +52,7 @@ where
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
         let _timer = Profiler::global().start_event("Label", "Parsing");
 
+        let start = cursor.peek(0, interner).or_abrupt()?.span().start();
         let label = LabelIdentifier::new(self.allow_yield, self.allow_await)
             .parse(cursor, interner)?
             .sym();
@@ -83,6 +84,7 @@ where
                 .into(),
         };
 
-        Ok(ast::statement::Labelled::new(labelled_item, label))
+        Ok(ast::statement::Labelled::new(labelled_item, label)
+            .with_span(Span::new(start, cursor.last_token_end())))
     }
 }
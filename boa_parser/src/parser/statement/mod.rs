@@ -26,7 +26,9 @@ use self::{
     block::BlockStatement,
     break_stm::BreakStatement,
     continue_stm::ContinueStatement,
-    declaration::{Declaration, ExportDeclaration, ImportDeclaration},
+    declaration::{
+        is_using_declaration, ClassDeclaration, Declaration, ExportDeclaration, ImportDeclaration,
+    },
     expression::ExpressionStatement,
     if_stm::IfStatement,
     iteration::{DoWhileStatement, ForStatement, WhileStatement},
@@ -52,15 +54,16 @@ use ast::{
 };
 use boa_ast::{
     self as ast,
-    pattern::{ArrayPattern, ArrayPatternElement, ObjectPatternElement},
-    Keyword, Punctuator,
+    expression::literal::LiteralKind,
+    pattern::{ArrayPattern, ArrayPatternElement, ObjectPattern, ObjectPatternElement},
+    Keyword, Punctuator, Span, Spanned,
 };
-use boa_interner::Interner;
+use boa_interner::{Interner, Sym};
 use boa_macros::utf16;
 use boa_profiler::Profiler;
 use std::io::Read;
 
-pub(in crate::parser) use declaration::ClassTail;
+pub(in crate::parser) use declaration::{ClassTail, DecoratorList};
 
 /// Statement parsing.
 ///
@@ -199,7 +202,7 @@ where
             TokenKind::Punctuator(Punctuator::Semicolon) => {
                 // parse the EmptyStatement
                 cursor.advance(interner);
-                Ok(ast::Statement::Empty)
+                Ok(ast::Statement::Empty(cursor.last_token_span().into()))
             }
             TokenKind::IdentifierName(_)
             | TokenKind::Keyword((Keyword::Await | Keyword::Yield, false)) => {
@@ -313,11 +316,18 @@ where
                     .parse(cursor, interner)?;
 
             if directive_prologues {
-                match &item {
+                let directive = match &item {
                     ast::StatementListItem::Statement(ast::Statement::Expression(
-                        ast::Expression::Literal(ast::expression::literal::Literal::String(string)),
-                    )) if !strict => {
-                        if interner.resolve_expect(*string).join(
+                        ast::Expression::Literal(literal),
+                    )) => match literal.kind() {
+                        LiteralKind::String(string) => Some(*string),
+                        _ => None,
+                    },
+                    _ => None,
+                };
+                match directive {
+                    Some(string) if !strict => {
+                        if interner.resolve_expect(string).join(
                             |s| s == "use strict",
                             |g| g == utf16!("use strict"),
                             true,
@@ -346,14 +356,10 @@ where
                             }
                         }
                     }
-                    ast::StatementListItem::Statement(ast::Statement::Expression(
-                        ast::Expression::Literal(ast::expression::literal::Literal::String(
-                            _string,
-                        )),
-                    )) => {
+                    Some(_) => {
                         // TODO: should store directives in some place
                     }
-                    _ => {
+                    None => {
                         directive_prologues = false;
                         directives_stack.clear();
                     }
@@ -415,9 +421,24 @@ where
         match *tok.kind() {
             TokenKind::Keyword(
                 (Keyword::Function | Keyword::Class | Keyword::Const, _) | (Keyword::Let, false),
-            ) => Declaration::new(self.allow_yield, self.allow_await)
-                .parse(cursor, interner)
-                .map(ast::StatementListItem::from),
+            )
+            | TokenKind::Punctuator(Punctuator::At) => {
+                Declaration::new(self.allow_yield, self.allow_await)
+                    .parse(cursor, interner)
+                    .map(ast::StatementListItem::from)
+            }
+            TokenKind::IdentifierName((Sym::USING, _))
+            | TokenKind::Keyword((Keyword::Await, _)) => {
+                if is_using_declaration(cursor, self.allow_await, interner)? {
+                    Declaration::new(self.allow_yield, self.allow_await)
+                        .parse(cursor, interner)
+                        .map(ast::StatementListItem::from)
+                } else {
+                    Statement::new(self.allow_yield, self.allow_await, self.allow_return)
+                        .parse(cursor, interner)
+                        .map(ast::StatementListItem::from)
+                }
+            }
             TokenKind::Keyword((Keyword::Async, false)) => {
                 let skip_n = if cursor.peek_is_line_terminator(0, interner).or_abrupt()? {
                     2
@@ -476,16 +497,19 @@ impl<R> TokenParser<R> for ObjectBindingPattern
 where
     R: Read,
 {
-    type Output = Vec<ObjectPatternElement>;
+    type Output = ObjectPattern;
 
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
         let _timer = Profiler::global().start_event("ObjectBindingPattern", "Parsing");
 
-        cursor.expect(
-            TokenKind::Punctuator(Punctuator::OpenBlock),
-            "object binding pattern",
-            interner,
-        )?;
+        let start = cursor
+            .expect(
+                TokenKind::Punctuator(Punctuator::OpenBlock),
+                "object binding pattern",
+                interner,
+            )?
+            .span()
+            .start();
 
         let mut patterns = Vec::new();
         let mut property_names = Vec::new();
@@ -501,7 +525,8 @@ where
                         "object binding pattern",
                         interner,
                     )?;
-                    return Ok(patterns);
+                    return Ok(ObjectPattern::new(patterns.into())
+                        .with_span(Span::new(start, cursor.last_token_end())));
                 }
                 TokenKind::Punctuator(Punctuator::Spread) => {
                     cursor.expect(
@@ -520,7 +545,8 @@ where
                         ident,
                         excluded_keys: property_names,
                     });
-                    return Ok(patterns);
+                    return Ok(ObjectPattern::new(patterns.into())
+                        .with_span(Span::new(start, cursor.last_token_end())));
                 }
                 _ => {
                     let is_property_name = match token.kind() {
@@ -594,16 +620,14 @@ where
                                                 .parse(cursor, interner)?;
                                                 patterns.push(ObjectPatternElement::Pattern {
                                                     name: property_name,
-                                                    pattern: ArrayPattern::new(bindings.into())
-                                                        .into(),
+                                                    pattern: bindings.into(),
                                                     default_init: Some(init),
                                                 });
                                             }
                                             _ => {
                                                 patterns.push(ObjectPatternElement::Pattern {
                                                     name: property_name,
-                                                    pattern: ArrayPattern::new(bindings.into())
-                                                        .into(),
+                                                    pattern: bindings.into(),
                                                     default_init: None,
                                                 });
                                             }
@@ -719,16 +743,19 @@ impl<R> TokenParser<R> for ArrayBindingPattern
 where
     R: Read,
 {
-    type Output = Vec<ArrayPatternElement>;
+    type Output = ArrayPattern;
 
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
         let _timer = Profiler::global().start_event("ArrayBindingPattern", "Parsing");
 
-        cursor.expect(
-            TokenKind::Punctuator(Punctuator::OpenBracket),
-            "array binding pattern",
-            interner,
-        )?;
+        let start = cursor
+            .expect(
+                TokenKind::Punctuator(Punctuator::OpenBracket),
+                "array binding pattern",
+                interner,
+            )?
+            .span()
+            .start();
 
         let mut patterns = Vec::new();
         let mut last_elision_or_first = true;
@@ -741,7 +768,8 @@ where
                         "array binding pattern",
                         interner,
                     )?;
-                    return Ok(patterns);
+                    return Ok(ArrayPattern::new(patterns.into())
+                        .with_span(Span::new(start, cursor.last_token_end())));
                 }
                 TokenKind::Punctuator(Punctuator::Comma) => {
                     cursor.expect(
@@ -795,7 +823,8 @@ where
                         interner,
                     )?;
 
-                    return Ok(patterns);
+                    return Ok(ArrayPattern::new(patterns.into())
+                        .with_span(Span::new(start, cursor.last_token_end())));
                 }
                 TokenKind::Punctuator(Punctuator::OpenBlock) => {
                     last_elision_or_first = false;
@@ -964,6 +993,43 @@ where
             TokenKind::Keyword((Keyword::Export, false)) => ExportDeclaration
                 .parse(cursor, interner)
                 .map(Self::Output::ExportDeclaration),
+            TokenKind::Punctuator(Punctuator::At) => {
+                let position = tok.span().start();
+                let decorators = DecoratorList::new(false, true).parse(cursor, interner)?;
+
+                // Decorators can either be placed before or after the `export` keyword.
+                let tok = cursor.peek(0, interner).or_abrupt()?;
+                if tok.kind() != &TokenKind::Keyword((Keyword::Export, false)) {
+                    let class =
+                        ClassDeclaration::new(false, true, false).parse(cursor, interner)?;
+                    return Ok(Self::Output::StatementListItem(
+                        boa_ast::Declaration::Class(class.with_decorators(decorators)).into(),
+                    ));
+                }
+
+                match ExportDeclaration.parse(cursor, interner)? {
+                    boa_ast::declaration::ExportDeclaration::Declaration(
+                        boa_ast::Declaration::Class(class),
+                    ) if class.decorators().is_empty() => Ok(Self::Output::ExportDeclaration(
+                        boa_ast::declaration::ExportDeclaration::Declaration(
+                            class.with_decorators(decorators).into(),
+                        ),
+                    )),
+                    boa_ast::declaration::ExportDeclaration::DefaultClassDeclaration(class)
+                        if class.decorators().is_empty() =>
+                    {
+                        Ok(Self::Output::ExportDeclaration(
+                            boa_ast::declaration::ExportDeclaration::DefaultClassDeclaration(
+                                class.with_decorators(decorators),
+                            ),
+                        ))
+                    }
+                    _ => Err(Error::general(
+                        "decorators can only be applied to class declarations",
+                        position,
+                    )),
+                }
+            }
             TokenKind::Keyword((Keyword::Import, false)) => {
                 if ImportDeclaration::test(cursor, interner)? {
                     ImportDeclaration
@@ -981,3 +1047,30 @@ where
         }
     }
 }
+
+/// Returns an error if `list` directly contains a `using` or an `await using` declaration.
+///
+/// `using` declarations are not allowed at the top level of scripts or directly inside the
+/// clauses of a `switch` statement.
+///
+/// More information:
+///  - [Explicit Resource Management proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-let-const-using-and-await-using-declarations-static-semantics-early-errors
+pub(in crate::parser) fn check_no_using_declarations(
+    list: &ast::StatementList,
+    context: &str,
+) -> ParseResult<()> {
+    for item in list.statements() {
+        if let ast::StatementListItem::Declaration(ast::Declaration::Lexical(decl)) = item {
+            if decl.is_using() {
+                let position = decl.span().map_or_else(|| Position::new(1, 1), Span::start);
+                return Err(Error::general(
+                    format!("using declarations are not allowed {context}"),
+                    position,
+                ));
+            }
+        }
+    }
+    Ok(())
+}
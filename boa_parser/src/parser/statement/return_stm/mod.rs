@@ -6,7 +6,7 @@ use crate::{
         AllowAwait, AllowYield, ParseResult, TokenParser,
     },
 };
-use boa_ast::{statement::Return, Keyword, Punctuator};
+use boa_ast::{statement::Return, Keyword, Punctuator, Span};
 use boa_interner::Interner;
 use boa_profiler::Profiler;
 use std::io::Read;
@@ -47,14 +47,17 @@ where
 
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
         let _timer = Profiler::global().start_event("ReturnStatement", "Parsing");
-        cursor.expect((Keyword::Return, false), "return statement", interner)?;
+        let start = cursor
+            .expect((Keyword::Return, false), "return statement", interner)?
+            .span()
+            .start();
 
         if let SemicolonResult::Found(tok) = cursor.peek_semicolon(interner)? {
             if tok.map(Token::kind) == Some(&TokenKind::Punctuator(Punctuator::Semicolon)) {
                 cursor.advance(interner);
             }
 
-            return Ok(Return::new(None));
+            return Ok(Return::new(None).with_span(Span::new(start, cursor.last_token_end())));
         }
 
         let expr = Expression::new(None, true, self.allow_yield, self.allow_await)
@@ -62,6 +65,6 @@ where
 
         cursor.expect_semicolon("return statement", interner)?;
 
-        Ok(Return::new(Some(expr)))
+        Ok(Return::new(Some(expr)).with_span(Span::new(start, cursor.last_token_end())))
     }
 }
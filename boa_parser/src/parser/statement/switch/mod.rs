@@ -4,13 +4,14 @@ mod tests;
 use crate::{
     lexer::TokenKind,
     parser::{
-        expression::Expression, statement::StatementList, AllowAwait, AllowReturn, AllowYield,
-        Cursor, OrAbrupt, ParseResult, TokenParser,
+        expression::Expression,
+        statement::{check_no_using_declarations, StatementList},
+        AllowAwait, AllowReturn, AllowYield, Cursor, OrAbrupt, ParseResult, TokenParser,
     },
     Error,
 };
 use ast::operations::{lexically_declared_names_legacy, var_declared_names};
-use boa_ast::{self as ast, statement, statement::Switch, Keyword, Punctuator};
+use boa_ast::{self as ast, statement, statement::Switch, Keyword, Punctuator, Span};
 use boa_interner::Interner;
 use boa_profiler::Profiler;
 use rustc_hash::FxHashMap;
@@ -62,7 +63,10 @@ where
 
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
         let _timer = Profiler::global().start_event("SwitchStatement", "Parsing");
-        cursor.expect((Keyword::Switch, false), "switch statement", interner)?;
+        let start = cursor
+            .expect((Keyword::Switch, false), "switch statement", interner)?
+            .span()
+            .start();
         cursor.expect(Punctuator::OpenParen, "switch statement", interner)?;
 
         let condition = Expression::new(None, true, self.allow_yield, self.allow_await)
@@ -75,7 +79,8 @@ where
         let cases = CaseBlock::new(self.allow_yield, self.allow_await, self.allow_return)
             .parse(cursor, interner)?;
 
-        let switch = Switch::new(condition, cases);
+        let switch =
+            Switch::new(condition, cases).with_span(Span::new(start, cursor.last_token_end()));
 
         // It is a Syntax Error if the LexicallyDeclaredNames of CaseBlock contains any duplicate
         // entries, unless the source text matched by this production is not strict mode code and the
@@ -153,6 +158,7 @@ where
 
         loop {
             let token = cursor.next(interner).or_abrupt()?;
+            let start = token.span().start();
             match token.kind() {
                 TokenKind::Keyword((Keyword::Case | Keyword::Default, true)) => {
                     return Err(Error::general(
@@ -176,8 +182,12 @@ where
                         false,
                     )
                     .parse(cursor, interner)?;
+                    check_no_using_declarations(&statement_list, "in switch case clauses")?;
 
-                    cases.push(statement::Case::new(cond, statement_list));
+                    cases.push(
+                        statement::Case::new(cond, statement_list)
+                            .with_span(Span::new(start, cursor.last_token_end())),
+                    );
                 }
                 TokenKind::Keyword((Keyword::Default, false)) => {
                     if has_default_case {
@@ -200,8 +210,12 @@ where
                         false,
                     )
                     .parse(cursor, interner)?;
+                    check_no_using_declarations(&statement_list, "in switch case clauses")?;
 
-                    cases.push(statement::Case::default(statement_list));
+                    cases.push(
+                        statement::Case::default(statement_list)
+                            .with_span(Span::new(start, cursor.last_token_end())),
+                    );
 
                     has_default_case = true;
                 }
@@ -9,7 +9,7 @@ use crate::{
 use boa_ast::{
     declaration::Binding,
     operations::{bound_names, lexically_declared_names, var_declared_names},
-    statement, Keyword, Punctuator,
+    statement, Keyword, Punctuator, Span,
 };
 use boa_interner::Interner;
 use boa_profiler::Profiler;
@@ -55,7 +55,10 @@ where
 
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
         let _timer = Profiler::global().start_event("Catch", "Parsing");
-        cursor.expect((Keyword::Catch, false), "try statement", interner)?;
+        let start = cursor
+            .expect((Keyword::Catch, false), "try statement", interner)?
+            .span()
+            .start();
         let position = cursor.peek(0, interner).or_abrupt()?.span().start();
         let catch_param = if cursor.next_if(Punctuator::OpenParen, interner)?.is_some() {
             let catch_param =
@@ -102,7 +105,7 @@ where
                     ));
                 }
             }
-            if !matches!(&catch_param, Some(Binding::Identifier(_))) {
+            if !matches!(&catch_param, Some(Binding::Identifier(..))) {
                 for name in var_declared_names(&catch_block) {
                     if bound_names.contains(&name) {
                         return Err(Error::general(
@@ -114,7 +117,8 @@ where
             }
         }
 
-        let catch_node = statement::Catch::new(catch_param, catch_block);
+        let catch_node = statement::Catch::new(catch_param, catch_block)
+            .with_span(Span::new(start, cursor.last_token_end()));
         Ok(catch_node)
     }
 }
@@ -169,9 +173,13 @@ where
                 Ok(Binding::Pattern(pat.into()))
             }
             TokenKind::IdentifierName(_) => {
+                let start = token.span().start();
                 let ident = BindingIdentifier::new(self.allow_yield, self.allow_await)
                     .parse(cursor, interner)?;
-                Ok(Binding::Identifier(ident))
+                Ok(Binding::Identifier(
+                    ident,
+                    Span::new(start, cursor.last_token_end()).into(),
+                ))
             }
             _ => Err(Error::expected(
                 [String::from("pattern"), String::from("binding identifier")],
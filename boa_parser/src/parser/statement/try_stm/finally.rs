@@ -1,7 +1,7 @@
 use crate::parser::{
     statement::block::Block, AllowAwait, AllowReturn, AllowYield, Cursor, ParseResult, TokenParser,
 };
-use boa_ast::{statement, Keyword};
+use boa_ast::{statement, Keyword, Span};
 use boa_interner::Interner;
 use boa_profiler::Profiler;
 use std::io::Read;
@@ -45,11 +45,12 @@ where
 
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
         let _timer = Profiler::global().start_event("Finally", "Parsing");
-        cursor.expect((Keyword::Finally, false), "try statement", interner)?;
-        Ok(
-            Block::new(self.allow_yield, self.allow_await, self.allow_return)
-                .parse(cursor, interner)?
-                .into(),
-        )
+        let start = cursor
+            .expect((Keyword::Finally, false), "try statement", interner)?
+            .span()
+            .start();
+        let block = Block::new(self.allow_yield, self.allow_await, self.allow_return)
+            .parse(cursor, interner)?;
+        Ok(statement::Finally::from(block).with_span(Span::new(start, cursor.last_token_end())))
     }
 }
@@ -13,7 +13,7 @@ use crate::{
 };
 use boa_ast::{
     statement::{ErrorHandler, Try},
-    Keyword,
+    Keyword, Span,
 };
 use boa_interner::Interner;
 use boa_profiler::Profiler;
@@ -59,7 +59,10 @@ where
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
         let _timer = Profiler::global().start_event("TryStatement", "Parsing");
         // TRY
-        cursor.expect((Keyword::Try, false), "try statement", interner)?;
+        let start = cursor
+            .expect((Keyword::Try, false), "try statement", interner)?
+            .span()
+            .start();
 
         let try_clause = Block::new(self.allow_yield, self.allow_await, self.allow_return)
             .parse(cursor, interner)?;
@@ -118,6 +121,6 @@ where
             (None, None) => unreachable!(),
         };
 
-        Ok(Try::new(try_clause, handler))
+        Ok(Try::new(try_clause, handler).with_span(Span::new(start, cursor.last_token_end())))
     }
 }
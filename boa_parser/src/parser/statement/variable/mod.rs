@@ -10,8 +10,8 @@ use crate::{
     },
 };
 use boa_ast::{
-    declaration::{VarDeclaration, Variable},
-    Keyword, Punctuator,
+    declaration::{Binding, VarDeclaration, Variable, VariableList},
+    Keyword, Punctuator, Span,
 };
 use boa_interner::Interner;
 use boa_profiler::Profiler;
@@ -55,14 +55,20 @@ where
 
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
         let _timer = Profiler::global().start_event("VariableStatement", "Parsing");
-        cursor.expect((Keyword::Var, false), "variable statement", interner)?;
+        let start = cursor
+            .expect((Keyword::Var, false), "variable statement", interner)?
+            .span()
+            .start();
 
-        let decl_list = VariableDeclarationList::new(true, self.allow_yield, self.allow_await)
-            .parse(cursor, interner)?;
+        let VarDeclaration(list) =
+            VariableDeclarationList::new(true, self.allow_yield, self.allow_await)
+                .parse(cursor, interner)?;
 
         cursor.expect_semicolon("variable statement", interner)?;
 
-        Ok(decl_list)
+        Ok(VarDeclaration(
+            list.with_span(Span::new(start, cursor.last_token_end())),
+        ))
     }
 }
 
@@ -108,6 +114,7 @@ where
     type Output = VarDeclaration;
 
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
+        let start = cursor.peek(0, interner).or_abrupt()?.span().start();
         let mut list = Vec::new();
 
         loop {
@@ -121,9 +128,13 @@ where
             }
         }
 
-        Ok(VarDeclaration(list.try_into().expect(
-            "`VariableDeclaration` must parse at least one variable",
-        )))
+        let list: VariableList = list
+            .try_into()
+            .expect("`VariableDeclaration` must parse at least one variable");
+
+        Ok(VarDeclaration(
+            list.with_span(Span::new(start, cursor.last_token_end())),
+        ))
     }
 }
 
@@ -164,6 +175,7 @@ where
 
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
         let peek_token = cursor.peek(0, interner).or_abrupt()?;
+        let start = peek_token.span().start();
 
         match peek_token.kind() {
             TokenKind::Punctuator(Punctuator::OpenBlock) => {
@@ -183,7 +195,8 @@ where
                     None
                 };
 
-                Ok(Variable::from_pattern(bindings.into(), init))
+                Ok(Variable::from_pattern(bindings.into(), init)
+                    .with_span(Span::new(start, cursor.last_token_end())))
             }
             TokenKind::Punctuator(Punctuator::OpenBracket) => {
                 let bindings = ArrayBindingPattern::new(self.allow_yield, self.allow_await)
@@ -202,11 +215,13 @@ where
                     None
                 };
 
-                Ok(Variable::from_pattern(bindings.into(), init))
+                Ok(Variable::from_pattern(bindings.into(), init)
+                    .with_span(Span::new(start, cursor.last_token_end())))
             }
             _ => {
                 let ident = BindingIdentifier::new(self.allow_yield, self.allow_await)
                     .parse(cursor, interner)?;
+                let binding = Binding::Identifier(ident, cursor.last_token_span().into());
 
                 let init = if cursor
                     .peek(0, interner)?
@@ -225,7 +240,8 @@ where
                 } else {
                     None
                 };
-                Ok(Variable::from_identifier(ident, init))
+                Ok(Variable::from_binding(binding, init)
+                    .with_span(Span::new(start, cursor.last_token_end())))
             }
         }
     }
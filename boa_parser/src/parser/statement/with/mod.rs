@@ -7,7 +7,7 @@ use crate::{
     },
     Error,
 };
-use boa_ast::{statement::With, Keyword, Punctuator};
+use boa_ast::{statement::With, Keyword, Punctuator, Span};
 use boa_interner::Interner;
 use boa_profiler::Profiler;
 use std::io::Read;
@@ -56,7 +56,7 @@ where
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
         let _timer = Profiler::global().start_event("WithStatement", "Parsing");
 
-        let position = cursor
+        let start = cursor
             .expect((Keyword::With, false), "with statement", interner)?
             .span()
             .start();
@@ -65,7 +65,7 @@ where
         if cursor.strict() {
             return Err(Error::general(
                 "with statement not allowed in strict mode",
-                position,
+                start,
             ));
         }
 
@@ -84,6 +84,6 @@ where
             return Err(Error::wrong_labelled_function_declaration(position));
         }
 
-        Ok(With::new(expression, statement))
+        Ok(With::new(expression, statement).with_span(Span::new(start, cursor.last_token_end())))
     }
 }
@@ -0,0 +1,194 @@
+//! Tests for the source code generated from the AST.
+
+use super::check_round_trip;
+use crate::{Parser, Source};
+use boa_ast::{
+    codegen::{Config, Indent, ToSource},
+    expression::{
+        literal::ObjectLiteral,
+        operator::{
+            binary::{ArithmeticOp, BinaryOp},
+            Binary,
+        },
+        Identifier,
+    },
+    Expression, Statement,
+};
+use boa_interner::Interner;
+use boa_macros::utf16;
+
+/// Parses the given script and checks that its generated source code parses back to the same
+/// script.
+#[track_caller]
+fn check_script_round_trip(js: &str) {
+    let interner = &mut Interner::default();
+    let script = Parser::new(Source::from_bytes(js))
+        .parse_script(interner)
+        .expect("failed to parse");
+    check_round_trip(&script, interner);
+}
+
+/// Parses the given module and checks that its generated source code parses back to the same
+/// module.
+#[track_caller]
+fn check_module_round_trip(js: &str) {
+    let interner = &mut Interner::default();
+    let module = Parser::new(Source::from_bytes(js))
+        .parse_module(interner)
+        .expect("failed to parse");
+    for config in [Config::new(), Config::minified()] {
+        let source = module.to_source(interner, config);
+        let reparsed = Parser::new(Source::from_bytes(&source))
+            .parse_module(interner)
+            .unwrap_or_else(|e| panic!("failed to parse generated code `{source}`: {e}"));
+        assert_eq!(reparsed, module, "generated code: `{source}`");
+    }
+}
+
+#[test]
+fn round_trip_expressions() {
+    for js in [
+        "a = b ? c : d ? e : f;",
+        "(a, b) ? (c, d) : (e, f);",
+        "a ** -b; (-a) ** b; a ** b ** c; (a ** b) ** c;",
+        "a ?? b ?? c; (a || b) ?? c; a || (b ?? c);",
+        "a - -b; a + +b; a - --b; a++ + ++b; !(a < !--b);",
+        "typeof typeof a; void 0; delete a[b];",
+        "x = a in b; for (var i = (a in b); i;) {}",
+        "new (a())(); new a.b(); new (a().b)(); (new a).b; new new a()();",
+        "a?.b.c?.[d]?.(e).f; a?.#b;",
+        "`a${b}c${`d${e}`}`; tag`a\\n${b}\\u{61}`; `\\${}\\``;",
+        "1 .toString(); 1.5.toString(); 1e21.toFixed(); (-1).toString();",
+        "0.000001; 1e-7; 123456789012345680000; 1.7976931348623157e308; 0x10; 10n;",
+        "/a[/]b/gi.test(c); a / /b/;",
+        r#"" \0\x01\"'\\"; 'a"b';"#,
+        "({ a, b: 1, [c]: 2, 3: 4, 'd e': 5, ...f, get g() {}, set g(v) {}, *h() {}, async i() {}, async *j() {} });",
+        "[, a, , ...b]; [a, ,]; [...a,];",
+        "({ a, b: [c, d = 1], ...e } = f); [a.b, ...c[d]] = e;",
+        "(function () {}); (function* f() {}); (async function () {}); (class {});",
+        "(a => a); async (a) => { await a; }; () => ({}); (a = 1, ...b) => a, b;",
+        "(() => {})(); (function () {}).call(); (class {}).name;",
+        "function* g() { yield; yield a; yield* b; x = yield; }",
+        "let[a] = b;",
+        "a = function () {}; export_default = class extends (a, b) {};",
+    ] {
+        check_script_round_trip(js);
+    }
+}
+
+#[test]
+fn round_trip_statements() {
+    for js in [
+        "if (a) if (b) c; else d;",
+        "if (a) { if (b) c; } else d;",
+        "if (a) for (;;) if (b) c; else d;",
+        "if (a) b; else if (c) d; else { e; }",
+        "do a(); while (b) c();",
+        "do { a(); } while (b);",
+        "for (let i = 0, j; i < 10; i++) continue;",
+        "for (const [a, b] of c) ; async function f() { for await (x of y) {} }",
+        "for (var a in b) break; for (a.b in c) {} for ([a] of b) {}",
+        "a: b: for (;;) { continue a; break b; }",
+        "switch (a) { case 1: case 2: b(); break; default: c(); }",
+        "try { a(); } catch { b(); } finally { c(); }",
+        "try {} catch ({ message }) {}",
+        "with (a) b;",
+        "throw new Error('a');",
+        "var a = 1, b; let c; const d = 2;",
+        "function f(a, [b], { c }, d = 1, ...e) { return a; } function g(a) { 'use strict'; }",
+        "class A extends B { constructor() { super(); } static #a = 1; #b; get c() { return this.#b; } static { init(); } 'd e'() {} [f] = 1; static async *g() {} #h() { return #b in this; } }",
+        "{ using a = b, c = null; } for (using d of e) ; for (using f = g; ;) break;",
+        "async function h() { await using i = j; for (await using k of l) {} }",
+        "{} ; {} {}",
+        "'use strict'; a;",
+        "({}).a = 1; ({} = a);",
+    ] {
+        check_script_round_trip(js);
+    }
+}
+
+#[test]
+fn round_trip_module() {
+    check_module_round_trip(
+        r#"
+        import "a";
+        import b from "b";
+        import c, * as d from "c";
+        import e, { f, g as h, "i j" as k } from "d";
+        import {} from "e";
+        export * from "f";
+        export * as l from "g";
+        export * as "m n" from "h";
+        export { o, p as q, r as "s t" } from "i";
+        export { "u v" } from "j";
+        var w;
+        export { w as x, w };
+        export var y = 1;
+        export const z = 2;
+        export function fun() {}
+        export default function () {}
+        "#,
+    );
+    check_module_round_trip("export default class {}");
+    check_module_round_trip("export default async function* named() {}");
+    check_module_round_trip("export default (function () {});");
+    check_module_round_trip("export default ({ a: 1 });");
+    check_module_round_trip("export default 1 + 2;");
+    check_module_round_trip("await a; import.meta.url;");
+    check_module_round_trip(
+        r#"
+        import a from "a" with { type: "json" };
+        import "b" with { type: "json", "other key": "value" };
+        export * from "c" with { type: "json" };
+        export { d } from "d" with {};
+        import("e", { with: { type: "json" } });
+        "#,
+    );
+}
+
+#[test]
+fn pretty_output() {
+    let interner = &mut Interner::default();
+    let script = Parser::new(Source::from_bytes(
+        "function f(a,b){if(a){return{a,b}}else return b}while(x)y()",
+    ))
+    .parse_script(interner)
+    .expect("failed to parse");
+
+    assert_eq!(
+        script.to_source(interner, Config::new()),
+        "function f(a, b) {\n    if (a) {\n        return { a, b };\n    } else\n        return b;\n}\nwhile (x)\n    y();\n"
+    );
+    assert_eq!(
+        script.to_source(interner, Config::new().with_indent(Indent::Tabs)),
+        "function f(a, b) {\n\tif (a) {\n\t\treturn { a, b };\n\t} else\n\t\treturn b;\n}\nwhile (x)\n\ty();\n"
+    );
+    assert_eq!(
+        script.to_source(interner, Config::minified()),
+        "function f(a,b){if(a){return{a,b};}else return b;}while(x)y();"
+    );
+}
+
+#[test]
+fn parentheses_from_precedence() {
+    let interner = &mut Interner::default();
+    let a = interner.get_or_intern_static("a", utf16!("a"));
+    let b = interner.get_or_intern_static("b", utf16!("b"));
+    let c = interner.get_or_intern_static("c", utf16!("c"));
+
+    // `(a + b) * c`, as an optimization pass could produce it without any `Parenthesized` node.
+    let expr = Expression::from(Binary::new(
+        BinaryOp::Arithmetic(ArithmeticOp::Mul),
+        Binary::new(
+            BinaryOp::Arithmetic(ArithmeticOp::Add),
+            Identifier::new(a).into(),
+            Identifier::new(b).into(),
+        )
+        .into(),
+        Identifier::new(c).into(),
+    ));
+    assert_eq!(expr.to_source(interner, Config::new()), "(a + b) * c");
+
+    let statement = Statement::Expression(ObjectLiteral::from(vec![]).into());
+    assert_eq!(statement.to_source(interner, Config::new()), "({});");
+}
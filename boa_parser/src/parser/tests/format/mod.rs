@@ -32,10 +32,11 @@ fn test_formatting(source: &'static str) {
         .join("\n");
     let source = Source::from_bytes(source);
     let interner = &mut Interner::default();
-    let result = Parser::new(source)
+    let script = Parser::new(source)
         .parse_script(interner)
-        .expect("parsing failed")
-        .to_interned_string(interner);
+        .expect("parsing failed");
+    super::check_round_trip(&script, interner);
+    let result = script.to_interned_string(interner);
     if scenario != result {
         eprint!("========= Expected:\n{scenario}");
         eprint!("========= Got:\n{result}");
@@ -1,19 +1,21 @@
 //! Tests for the parser.
 
+mod codegen;
 mod format;
 
 use std::convert::TryInto;
 
 use crate::{Parser, Source};
 use boa_ast::{
-    declaration::{Declaration, LexicalDeclaration, VarDeclaration, Variable},
+    codegen::{Config, ToSource},
+    declaration::{Binding, Declaration, LexicalDeclaration, VarDeclaration, Variable},
     expression::{
         access::SimplePropertyAccess,
         literal::{Literal, ObjectLiteral},
         operator::{
             assign::AssignOp,
             binary::{ArithmeticOp, BinaryOp, LogicalOp, RelationalOp},
-            update::{UpdateOp, UpdateTarget},
+            update::UpdateOp,
             Assign, Binary, Update,
         },
         Call, Identifier, New, Parenthesized,
@@ -22,25 +24,41 @@ use boa_ast::{
         ArrowFunction, FormalParameter, FormalParameterList, FormalParameterListFlags, Function,
         FunctionBody,
     },
+    pattern::{ObjectPatternElement, Pattern},
     property::PropertyDefinition,
     statement::{If, Return},
-    Expression, Script, Statement, StatementList, StatementListItem,
+    Expression, NodeSpan, Position, Script, Span, Spanned, Statement, StatementList,
+    StatementListItem,
 };
 use boa_interner::Interner;
 use boa_macros::utf16;
 
 /// Checks that the given JavaScript string gives the expected expression.
+///
+/// This also checks that the generated source code of the script parses back to the same script.
 #[track_caller]
 pub(super) fn check_script_parser<L>(js: &str, expr: L, interner: &mut Interner)
 where
     L: Into<Box<[StatementListItem]>>,
 {
-    assert_eq!(
-        Parser::new(Source::from_bytes(js))
+    let script = Parser::new(Source::from_bytes(js))
+        .parse_script(interner)
+        .expect("failed to parse");
+    assert_eq!(script, Script::new(StatementList::from(expr.into())));
+    check_round_trip(&script, interner);
+}
+
+/// Checks that the generated source code of the script, both pretty printed and minified, parses
+/// back to the same script.
+#[track_caller]
+pub(super) fn check_round_trip(script: &Script, interner: &mut Interner) {
+    for config in [Config::new(), Config::minified()] {
+        let source = script.to_source(interner, config);
+        let reparsed = Parser::new(Source::from_bytes(&source))
             .parse_script(interner)
-            .expect("failed to parse"),
-        Script::new(StatementList::from(expr.into()))
-    );
+            .unwrap_or_else(|e| panic!("failed to parse generated code `{source}`: {e}"));
+        assert_eq!(&reparsed, script, "generated code: `{source}`");
+    }
 }
 
 /// Checks that the given javascript string creates a parse error.
@@ -120,7 +138,7 @@ fn hoisting() {
             .into(),
             Statement::Expression(Expression::from(Update::new(
                 UpdateOp::IncrementPost,
-                UpdateTarget::Identifier(Identifier::new(a)),
+                Identifier::new(a).into(),
             )))
             .into(),
             Declaration::Function(Function::new(
@@ -153,7 +171,7 @@ fn hoisting() {
             .into(),
             Statement::Expression(Expression::from(Update::new(
                 UpdateOp::IncrementPost,
-                UpdateTarget::Identifier(Identifier::new(a)),
+                Identifier::new(a).into(),
             )))
             .into(),
             Statement::Var(VarDeclaration(
@@ -178,13 +196,13 @@ fn ambigous_regex_divide_expression() {
             RelationalOp::StrictEqual.into(),
             Binary::new(
                 ArithmeticOp::Div.into(),
-                Literal::Int(1).into(),
+                Literal::from(1).into(),
                 Identifier::new(interner.get_or_intern_static("a", utf16!("a"))).into(),
             )
             .into(),
             Binary::new(
                 ArithmeticOp::Div.into(),
-                Literal::Int(1).into(),
+                Literal::from(1).into(),
                 Identifier::new(interner.get_or_intern_static("b", utf16!("b"))).into(),
             )
             .into(),
@@ -207,20 +225,20 @@ fn two_divisions_in_expression() {
             Binary::new(
                 RelationalOp::StrictNotEqual.into(),
                 Identifier::new(a).into(),
-                Literal::Int(0).into(),
+                Literal::from(0).into(),
             )
             .into(),
             Binary::new(
                 RelationalOp::StrictEqual.into(),
                 Binary::new(
                     ArithmeticOp::Div.into(),
-                    Literal::Int(1).into(),
+                    Literal::from(1).into(),
                     Identifier::new(a).into(),
                 )
                 .into(),
                 Binary::new(
                     ArithmeticOp::Div.into(),
-                    Literal::Int(1).into(),
+                    Literal::from(1).into(),
                     Identifier::new(interner.get_or_intern_static("b", utf16!("b"))).into(),
                 )
                 .into(),
@@ -246,7 +264,7 @@ fn comment_semi_colon_insertion() {
             Declaration::Lexical(LexicalDeclaration::Let(
                 vec![Variable::from_identifier(
                     interner.get_or_intern_static("a", utf16!("a")).into(),
-                    Some(Literal::Int(10).into()),
+                    Some(Literal::from(10).into()),
                 )]
                 .try_into()
                 .unwrap(),
@@ -255,7 +273,7 @@ fn comment_semi_colon_insertion() {
             Declaration::Lexical(LexicalDeclaration::Let(
                 vec![Variable::from_identifier(
                     interner.get_or_intern_static("b", utf16!("b")).into(),
-                    Some(Literal::Int(20).into()),
+                    Some(Literal::from(20).into()),
                 )]
                 .try_into()
                 .unwrap(),
@@ -282,7 +300,7 @@ fn multiline_comment_semi_colon_insertion() {
             Declaration::Lexical(LexicalDeclaration::Let(
                 vec![Variable::from_identifier(
                     interner.get_or_intern_static("a", utf16!("a")).into(),
-                    Some(Literal::Int(10).into()),
+                    Some(Literal::from(10).into()),
                 )]
                 .try_into()
                 .unwrap(),
@@ -291,7 +309,7 @@ fn multiline_comment_semi_colon_insertion() {
             Declaration::Lexical(LexicalDeclaration::Let(
                 vec![Variable::from_identifier(
                     interner.get_or_intern_static("b", utf16!("b")).into(),
-                    Some(Literal::Int(20).into()),
+                    Some(Literal::from(20).into()),
                 )]
                 .try_into()
                 .unwrap(),
@@ -315,7 +333,7 @@ fn multiline_comment_no_lineterminator() {
             Declaration::Lexical(LexicalDeclaration::Let(
                 vec![Variable::from_identifier(
                     interner.get_or_intern_static("a", utf16!("a")).into(),
-                    Some(Literal::Int(10).into()),
+                    Some(Literal::from(10).into()),
                 )]
                 .try_into()
                 .unwrap(),
@@ -324,7 +342,7 @@ fn multiline_comment_no_lineterminator() {
             Declaration::Lexical(LexicalDeclaration::Let(
                 vec![Variable::from_identifier(
                     interner.get_or_intern_static("b", utf16!("b")).into(),
-                    Some(Literal::Int(20).into()),
+                    Some(Literal::from(20).into()),
                 )]
                 .try_into()
                 .unwrap(),
@@ -351,7 +369,7 @@ fn assignment_line_terminator() {
             Declaration::Lexical(LexicalDeclaration::Let(
                 vec![Variable::from_identifier(
                     interner.get_or_intern_static("a", utf16!("a")).into(),
-                    Some(Literal::Int(3).into()),
+                    Some(Literal::from(3).into()),
                 )]
                 .try_into()
                 .unwrap(),
@@ -388,7 +406,7 @@ fn assignment_multiline_terminator() {
             Declaration::Lexical(LexicalDeclaration::Let(
                 vec![Variable::from_identifier(
                     a.into(),
-                    Some(Literal::Int(3).into()),
+                    Some(Literal::from(3).into()),
                 )]
                 .try_into()
                 .unwrap(),
@@ -435,11 +453,7 @@ fn increment_in_comma_op() {
             Parenthesized::new(
                 Binary::new(
                     BinaryOp::Comma,
-                    Update::new(
-                        UpdateOp::IncrementPost,
-                        UpdateTarget::Identifier(Identifier::new(b)),
-                    )
-                    .into(),
+                    Update::new(UpdateOp::IncrementPost, Identifier::new(b).into()).into(),
                     Identifier::new(b).into(),
                 )
                 .into(),
@@ -527,8 +541,8 @@ fn empty_statement() {
             if(a) ;
         ",
         vec![
-            Statement::Empty.into(),
-            Statement::Empty.into(),
+            Statement::Empty(NodeSpan::none()).into(),
+            Statement::Empty(NodeSpan::none()).into(),
             Statement::Var(VarDeclaration(
                 vec![Variable::from_identifier(
                     a.into(),
@@ -538,7 +552,12 @@ fn empty_statement() {
                 .unwrap(),
             ))
             .into(),
-            Statement::If(If::new(Identifier::new(a).into(), Statement::Empty, None)).into(),
+            Statement::If(If::new(
+                Identifier::new(a).into(),
+                Statement::Empty(NodeSpan::none()),
+                None,
+            ))
+            .into(),
         ],
         interner,
     );
@@ -560,9 +579,9 @@ fn empty_statement_ends_directive_prologues() {
             let public = 5;
         "#,
         vec![
-            Statement::Expression(Expression::from(Literal::String(a))).into(),
-            Statement::Empty.into(),
-            Statement::Expression(Expression::from(Literal::String(use_strict))).into(),
+            Statement::Expression(Expression::from(Literal::from(a))).into(),
+            Statement::Empty(NodeSpan::none()).into(),
+            Statement::Expression(Expression::from(Literal::from(use_strict))).into(),
             Declaration::Lexical(LexicalDeclaration::Let(
                 vec![Variable::from_identifier(
                     public,
@@ -619,3 +638,312 @@ fn deny_unicode_escape_in_true_expression() {
 fn deny_unicode_escape_in_null_expression() {
     check_invalid_script(r"let x = n\u{75}ll;");
 }
+
+/// Checks that the parser records the location of the nodes it parses.
+#[test]
+fn node_spans() {
+    let span = |start: (u32, u32), end: (u32, u32)| {
+        Some(Span::new(
+            Position::new(start.0, start.1),
+            Position::new(end.0, end.1),
+        ))
+    };
+
+    let interner = &mut Interner::default();
+    let script = Parser::new(Source::from_bytes("let { a } = b;\nif (a) {\n  a + 1;\n}"))
+        .parse_script(interner)
+        .expect("failed to parse");
+    let items = script.statements().statements();
+
+    assert_eq!(items[0].span(), span((1, 1), (1, 15)));
+    assert_eq!(items[1].span(), span((2, 1), (4, 2)));
+
+    let variable = match &items[0] {
+        StatementListItem::Declaration(Declaration::Lexical(LexicalDeclaration::Let(list))) => {
+            &list.as_ref()[0]
+        }
+        _ => unreachable!("expected a lexical declaration"),
+    };
+    assert_eq!(variable.span(), span((1, 5), (1, 14)));
+    assert_eq!(variable.binding().span(), span((1, 5), (1, 10)));
+    assert_eq!(
+        variable.init().and_then(Spanned::span),
+        span((1, 13), (1, 14))
+    );
+
+    let body = match &items[1] {
+        StatementListItem::Statement(Statement::If(if_statement)) => {
+            assert_eq!(if_statement.cond().span(), span((2, 5), (2, 6)));
+            if_statement.body()
+        }
+        _ => unreachable!("expected an if statement"),
+    };
+    assert_eq!(body.span(), span((2, 8), (4, 2)));
+
+    let addition = match body {
+        Statement::Block(block) => &block.statement_list().statements()[0],
+        _ => unreachable!("expected a block statement"),
+    };
+    assert_eq!(addition.span(), span((3, 3), (3, 8)));
+}
+
+/// Checks that the parser records the location of the keyword-like expressions.
+#[test]
+fn keyword_expression_spans() {
+    let span = |start: (u32, u32), end: (u32, u32)| {
+        Some(Span::new(
+            Position::new(start.0, start.1),
+            Position::new(end.0, end.1),
+        ))
+    };
+
+    let interner = &mut Interner::default();
+    let script = Parser::new(Source::from_bytes(
+        "this;\nfunction f() {\n  new.target;\n}",
+    ))
+    .parse_script(interner)
+    .expect("failed to parse");
+    let items = script.statements().statements();
+
+    match &items[0] {
+        StatementListItem::Statement(Statement::Expression(this @ Expression::This(_))) => {
+            assert_eq!(this.span(), span((1, 1), (1, 5)));
+        }
+        _ => unreachable!("expected a `this` expression"),
+    }
+    match &items[1] {
+        StatementListItem::Declaration(Declaration::Function(function)) => {
+            let new_target = &function.body().statements()[0];
+            assert_eq!(new_target.span(), span((3, 3), (3, 13)));
+        }
+        _ => unreachable!("expected a function declaration"),
+    }
+
+    let module = Parser::new(Source::from_bytes("import.meta;"))
+        .parse_module(interner)
+        .expect("failed to parse");
+    assert_eq!(module.items().items()[0].span(), span((1, 1), (1, 12)));
+}
+
+/// Builds the span between two `(line, column)` pairs.
+#[allow(clippy::unnecessary_wraps)]
+fn span(start: (u32, u32), end: (u32, u32)) -> Option<Span> {
+    Some(Span::new(
+        Position::new(start.0, start.1),
+        Position::new(end.0, end.1),
+    ))
+}
+
+/// Returns the expression of an expression statement.
+#[track_caller]
+fn expression_of(item: &StatementListItem) -> &Expression {
+    match item {
+        StatementListItem::Statement(Statement::Expression(expression)) => expression,
+        _ => unreachable!("expected an expression statement"),
+    }
+}
+
+#[test]
+fn identifier_spans() {
+    let interner = &mut Interner::default();
+    let script = Parser::new(Source::from_bytes(
+        "a;\nfoo = bar;\nx++;\nfor (y of z) {}\ntry {} catch (e) {}\n(p, ...q) => p;",
+    ))
+    .parse_script(interner)
+    .expect("failed to parse");
+    let items = script.statements().statements();
+
+    assert_eq!(expression_of(&items[0]).span(), span((1, 1), (1, 2)));
+
+    match expression_of(&items[1]) {
+        Expression::Assign(assign) => {
+            assert_eq!(assign.lhs().span(), span((2, 1), (2, 4)));
+            assert_eq!(assign.rhs().span(), span((2, 7), (2, 10)));
+        }
+        _ => unreachable!("expected an assignment"),
+    }
+
+    match expression_of(&items[2]) {
+        Expression::Update(update) => {
+            assert_eq!(update.target().span(), span((3, 1), (3, 2)));
+        }
+        _ => unreachable!("expected an update expression"),
+    }
+
+    match &items[3] {
+        StatementListItem::Statement(Statement::ForOfLoop(for_of)) => {
+            assert_eq!(for_of.initializer().span(), span((4, 6), (4, 7)));
+            assert_eq!(for_of.iterable().span(), span((4, 11), (4, 12)));
+        }
+        _ => unreachable!("expected a for-of loop"),
+    }
+
+    match &items[4] {
+        StatementListItem::Statement(Statement::Try(try_statement)) => {
+            let parameter = try_statement.catch().and_then(|c| c.parameter());
+            assert_eq!(parameter.and_then(Spanned::span), span((5, 15), (5, 16)));
+        }
+        _ => unreachable!("expected a try statement"),
+    }
+
+    match expression_of(&items[5]) {
+        Expression::ArrowFunction(arrow) => {
+            let parameters = arrow.parameters().as_ref();
+            assert_eq!(
+                parameters[0].variable().binding().span(),
+                span((6, 2), (6, 3))
+            );
+            assert_eq!(
+                parameters[1].variable().binding().span(),
+                span((6, 8), (6, 9))
+            );
+        }
+        _ => unreachable!("expected an arrow function"),
+    }
+}
+
+#[test]
+fn literal_spans() {
+    let interner = &mut Interner::default();
+    let script = Parser::new(Source::from_bytes(
+        "1;\n'str';\ntrue;\nnull;\n2n;\n`t${a}`;\n[1, 2];\n({ a: 1 });",
+    ))
+    .parse_script(interner)
+    .expect("failed to parse");
+    let items = script.statements().statements();
+
+    assert_eq!(expression_of(&items[0]).span(), span((1, 1), (1, 2)));
+    assert_eq!(expression_of(&items[1]).span(), span((2, 1), (2, 6)));
+    assert_eq!(expression_of(&items[2]).span(), span((3, 1), (3, 5)));
+    assert_eq!(expression_of(&items[3]).span(), span((4, 1), (4, 5)));
+    assert_eq!(expression_of(&items[4]).span(), span((5, 1), (5, 3)));
+    assert_eq!(expression_of(&items[5]).span(), span((6, 1), (6, 8)));
+    assert_eq!(expression_of(&items[6]).span(), span((7, 1), (7, 7)));
+    match expression_of(&items[7]) {
+        Expression::Parenthesized(parenthesized) => {
+            assert_eq!(parenthesized.span(), span((8, 1), (8, 11)));
+            assert_eq!(parenthesized.expression().span(), span((8, 2), (8, 10)));
+        }
+        _ => unreachable!("expected a parenthesized expression"),
+    }
+}
+
+#[test]
+fn declaration_spans() {
+    let interner = &mut Interner::default();
+    let script = Parser::new(Source::from_bytes(
+        "var v = 1;\nconst k = 2;\nfunction f(a, b = 1, ...c) {}\nclass C {}",
+    ))
+    .parse_script(interner)
+    .expect("failed to parse");
+    let items = script.statements().statements();
+
+    match &items[0] {
+        StatementListItem::Statement(Statement::Var(VarDeclaration(list))) => {
+            let variable = &list.as_ref()[0];
+            assert_eq!(variable.span(), span((1, 5), (1, 10)));
+            assert_eq!(variable.binding().span(), span((1, 5), (1, 6)));
+        }
+        _ => unreachable!("expected a var declaration"),
+    }
+
+    match &items[1] {
+        StatementListItem::Declaration(Declaration::Lexical(LexicalDeclaration::Const(list))) => {
+            assert_eq!(items[1].span(), span((2, 1), (2, 13)));
+            assert_eq!(list.as_ref()[0].binding().span(), span((2, 7), (2, 8)));
+        }
+        _ => unreachable!("expected a const declaration"),
+    }
+
+    match &items[2] {
+        StatementListItem::Declaration(Declaration::Function(function)) => {
+            assert_eq!(function.span(), span((3, 1), (3, 30)));
+            let parameters = function.parameters().as_ref();
+            assert_eq!(parameters[0].span(), span((3, 12), (3, 13)));
+            assert_eq!(parameters[1].span(), span((3, 15), (3, 20)));
+            assert_eq!(
+                parameters[1].variable().binding().span(),
+                span((3, 15), (3, 16))
+            );
+            assert_eq!(
+                parameters[2].variable().binding().span(),
+                span((3, 25), (3, 26))
+            );
+        }
+        _ => unreachable!("expected a function declaration"),
+    }
+
+    match &items[3] {
+        StatementListItem::Declaration(Declaration::Class(class)) => {
+            assert_eq!(class.span(), span((4, 1), (4, 11)));
+        }
+        _ => unreachable!("expected a class declaration"),
+    }
+}
+
+#[test]
+fn pattern_spans() {
+    let interner = &mut Interner::default();
+    let script = Parser::new(Source::from_bytes(
+        "let { a, b: [c] } = o;\n[d, e] = f;\nfunction g({ h }, [i]) {}",
+    ))
+    .parse_script(interner)
+    .expect("failed to parse");
+    let items = script.statements().statements();
+
+    match &items[0] {
+        StatementListItem::Declaration(Declaration::Lexical(LexicalDeclaration::Let(list))) => {
+            let binding = list.as_ref()[0].binding();
+            assert_eq!(binding.span(), span((1, 5), (1, 18)));
+            match binding {
+                Binding::Pattern(Pattern::Object(object)) => match &object.bindings()[1] {
+                    ObjectPatternElement::Pattern { pattern, .. } => {
+                        assert_eq!(pattern.span(), span((1, 13), (1, 16)));
+                    }
+                    _ => unreachable!("expected a nested pattern"),
+                },
+                _ => unreachable!("expected an object pattern"),
+            }
+        }
+        _ => unreachable!("expected a let declaration"),
+    }
+
+    match expression_of(&items[1]) {
+        Expression::Assign(assign) => {
+            assert_eq!(assign.lhs().span(), span((2, 1), (2, 7)));
+        }
+        _ => unreachable!("expected an assignment"),
+    }
+
+    match &items[2] {
+        StatementListItem::Declaration(Declaration::Function(function)) => {
+            let parameters = function.parameters().as_ref();
+            assert_eq!(
+                parameters[0].variable().binding().span(),
+                span((3, 12), (3, 17))
+            );
+            assert_eq!(
+                parameters[1].variable().binding().span(),
+                span((3, 19), (3, 22))
+            );
+        }
+        _ => unreachable!("expected a function declaration"),
+    }
+}
+
+#[test]
+fn empty_statement_spans() {
+    let interner = &mut Interner::default();
+    let script = Parser::new(Source::from_bytes(";\nif (a) ;"))
+        .parse_script(interner)
+        .expect("failed to parse");
+    let items = script.statements().statements();
+
+    assert_eq!(items[0].span(), span((1, 1), (1, 2)));
+    match &items[1] {
+        StatementListItem::Statement(Statement::If(if_statement)) => {
+            assert_eq!(if_statement.body().span(), span((2, 8), (2, 9)));
+        }
+        _ => unreachable!("expected an if statement"),
+    }
+}
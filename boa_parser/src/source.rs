@@ -85,6 +85,11 @@ impl<'path, R: Read> Source<'path, R> {
     pub const fn from_reader(reader: R, path: Option<&'path Path>) -> Self {
         Self { reader, path }
     }
+
+    /// Gets the path of the file this source was read from, if any.
+    pub const fn path(&self) -> Option<&'path Path> {
+        self.path
+    }
 }
 
 #[cfg(test)]
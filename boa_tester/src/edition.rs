@@ -87,6 +87,14 @@ static FEATURE_EDITION: phf::Map<&'static str, SpecEdition> = phf::phf_map! {
     // https://github.com/tc39/proposal-decorators
     "decorators" => SpecEdition::ESNext,
 
+    // Explicit Resource Management
+    // https://github.com/tc39/proposal-explicit-resource-management
+    "explicit-resource-management" => SpecEdition::ESNext,
+
+    // Iterator Helpers
+    // https://github.com/tc39/proposal-iterator-helpers
+    "iterator-helpers" => SpecEdition::ESNext,
+
     // Duplicate named capturing groups
     // https://github.com/tc39/proposal-duplicate-named-capturing-groups
     "regexp-duplicate-named-groups" => SpecEdition::ESNext,
@@ -104,6 +112,14 @@ static FEATURE_EDITION: phf::Map<&'static str, SpecEdition> = phf::phf_map! {
     "String.prototype.isWellFormed" => SpecEdition::ESNext,
     "String.prototype.toWellFormed" => SpecEdition::ESNext,
 
+    // Promise.withResolvers
+    // https://github.com/tc39/proposal-promise-with-resolvers
+    "promise-with-resolvers" => SpecEdition::ESNext,
+
+    // Set methods
+    // https://github.com/tc39/proposal-set-methods
+    "set-methods" => SpecEdition::ESNext,
+
     // https://github.com/tc39/proposal-intl-enumeration
     "Intl-enumeration" => SpecEdition::ESNext,
 